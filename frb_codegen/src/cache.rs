@@ -0,0 +1,153 @@
+//! Skip regenerating a block whose inputs did not change since the last run, see `--no-cache`.
+
+use std::collections::hash_map::DefaultHasher;
+use std::fs;
+use std::hash::{Hash, Hasher};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+use cargo_metadata::MetadataCommand;
+use log::{debug, warn};
+use quote::ToTokens;
+
+use crate::config::Opts;
+use crate::watch::try_watched_files;
+
+/// Part of the key, so that upgrading the codegen regenerates everything.
+const VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// The inputs of one block, to be compared with the ones of the last successful run.
+///
+/// Each block has its own file in the target directory, storing the key of its inputs
+/// and the hash of each output. The file is replaced atomically, so concurrent runs never
+/// read a half-written cache: at worst, both of them regenerate.
+pub(crate) struct Cache {
+    path: PathBuf,
+    key: u64,
+}
+
+impl Cache {
+    /// Hash the inputs of `config`, or return `None` if the crate cannot be resolved,
+    /// in which case the block is always regenerated.
+    pub fn new(config: &Opts, all_symbols: &[String]) -> Option<Self> {
+        let metadata = match MetadataCommand::new()
+            .manifest_path(&config.manifest_path)
+            .no_deps()
+            .exec()
+        {
+            Ok(metadata) => metadata,
+            Err(err) => {
+                warn!(
+                    "Not caching the codegen, since cargo metadata failed: {}",
+                    err
+                );
+                return None;
+            }
+        };
+        let target_dir: &Path = metadata.target_directory.as_ref();
+        let mut block = DefaultHasher::new();
+        (&config.rust_input_paths, &config.dart_output_path).hash(&mut block);
+        let path = target_dir
+            .join("flutter_rust_bridge")
+            .join(format!("codegen_{:016x}", block.finish()));
+
+        let mut key = DefaultHasher::new();
+        VERSION.hash(&mut key);
+        format!("{:?}", config).hash(&mut key);
+        all_symbols.hash(&mut key);
+        fs::read(&config.manifest_path).ok().hash(&mut key);
+        for file in try_watched_files(config)? {
+            tokens_of(&file).hash(&mut key);
+            file.hash(&mut key);
+        }
+        Some(Self {
+            path,
+            key: key.finish(),
+        })
+    }
+
+    /// Whether the last successful run had the same inputs, and its outputs are untouched.
+    pub fn is_fresh(&self) -> bool {
+        let content = match fs::read_to_string(&self.path) {
+            Ok(content) => content,
+            Err(_) => return false,
+        };
+        let key = format_hash(self.key);
+        let mut lines = content.lines();
+        if lines.next() != Some(VERSION) || lines.next() != Some(key.as_str()) {
+            debug!("Cache miss: the inputs changed");
+            return false;
+        }
+        lines.all(|line| match line.split_once(' ') {
+            Some((hash, output)) => {
+                let current = hash_file(Path::new(output)).map(format_hash);
+                let fresh = current.as_deref() == Some(hash);
+                if !fresh {
+                    debug!("Cache miss: {} changed", output);
+                }
+                fresh
+            }
+            None => false,
+        })
+    }
+
+    /// Record the outputs of a successful run. A failure is not fatal, it only means that
+    /// the next run regenerates even if nothing changed.
+    pub fn store(&self, config: &Opts) {
+        if let Err(err) = self.try_store(config) {
+            warn!("Could not write the codegen cache: {}", err);
+        }
+    }
+
+    fn try_store(&self, config: &Opts) -> anyhow::Result<()> {
+        let dir = self.path.parent().unwrap();
+        fs::create_dir_all(dir)?;
+        // Written next to the cache and renamed over it, so that readers see either version.
+        let mut file = tempfile::NamedTempFile::new_in(dir)?;
+        writeln!(file, "{}\n{}", VERSION, format_hash(self.key))?;
+        for output in output_paths(config) {
+            if let Some(hash) = hash_file(&output) {
+                writeln!(file, "{} {}", format_hash(hash), output.display())?;
+            }
+        }
+        file.persist(&self.path)?;
+        Ok(())
+    }
+}
+
+/// All the files a block may write, whether or not this configuration produces them.
+fn output_paths(config: &Opts) -> Vec<PathBuf> {
+    let mut paths = vec![
+        PathBuf::from(&config.rust_output_path),
+        config.rust_io_output_path(),
+        config.rust_wasm_output_path(),
+        PathBuf::from(&config.dart_output_path),
+        config.dart_io_output_path(),
+        config.dart_wasm_output_path(),
+        config.dart_freezed_path(),
+    ];
+    paths.extend(config.dart_decl_output_path.iter().map(PathBuf::from));
+    paths.extend(config.c_output_path.iter().map(PathBuf::from));
+    paths
+}
+
+/// Comments and formatting do not change the generated code, so only the tokens are hashed.
+/// Doc comments are kept, since they end up in the generated Dart code.
+fn tokens_of(path: &Path) -> Option<String> {
+    let content = fs::read_to_string(path).ok()?;
+    Some(match syn::parse_file(&content) {
+        Ok(file) => file.into_token_stream().to_string(),
+        Err(_) => content,
+    })
+}
+
+fn hash_file(path: &Path) -> Option<u64> {
+    let content = fs::read(path).ok()?;
+    let mut hasher = DefaultHasher::new();
+    content.hash(&mut hasher);
+    Some(hasher.finish())
+}
+
+fn format_hash(hash: u64) -> String {
+    format!("{:016x}", hash)
+}
@@ -335,7 +335,10 @@ fn ffigen(
 
 pub fn format_rust(path: &[PathBuf]) -> Result {
     debug!("execute format_rust path={:?}", path);
-    let res = execute_command("rustfmt", path, None)?;
+    // The generated code uses `async move` blocks, which rustfmt rejects under its default 2015 edition
+    let mut args = vec![PathBuf::from("--edition"), PathBuf::from("2018")];
+    args.extend(path.iter().cloned());
+    let res = execute_command("rustfmt", &args, None)?;
     if !res.status.success() {
         return Err(Error::Rustfmt(
             String::from_utf8_lossy(&res.stderr).to_string(),
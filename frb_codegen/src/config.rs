@@ -1,4 +1,5 @@
 use std::borrow::Cow;
+use std::collections::HashMap;
 use std::env;
 use std::ffi::OsString;
 use std::fs;
@@ -10,12 +11,13 @@ use anyhow::{anyhow, Context, Result};
 use clap::IntoApp;
 use clap::Parser;
 use convert_case::{Case, Casing};
+use itertools::Itertools;
 use serde::Deserialize;
 use toml::Value;
 
-use crate::ir::IrFile;
+use crate::ir::{dart_namespace_of, IrFile};
 use crate::parser;
-use crate::utils::BlockIndex;
+use crate::utils::{mod_from_rust_path, BlockIndex};
 
 #[derive(Parser, Debug, PartialEq, Eq, Deserialize, Default)]
 #[clap(
@@ -24,7 +26,8 @@ use crate::utils::BlockIndex;
     setting(clap::AppSettings::DeriveDisplayOrder)
 )]
 pub struct RawOpts {
-    /// Path of input Rust code
+    /// Path of input Rust code. Several files, separated by commas or matched by a `*`
+    /// wildcard in the file name, are merged into one set of bindings
     #[clap(short, long, required = true, multiple_values = true)]
     pub rust_input: Vec<String>,
     /// Path of output generated Dart code
@@ -51,6 +54,27 @@ pub struct RawOpts {
     /// Line length for Dart formatting
     #[clap(long, default_value = "80")]
     pub dart_format_line_length: u32,
+    /// How the Dart classes of structs are generated
+    #[clap(long, value_enum, default_value = "plain")]
+    pub dart_class_style: DartClassStyle,
+    /// Generate `==` and `hashCode` comparing the fields of plain struct classes
+    #[clap(long)]
+    pub dart_value_equality: bool,
+    /// Generate `copyWith` for plain struct classes
+    #[clap(long)]
+    pub dart_copy_with: bool,
+    /// Generate `toJson` and `fromJson` for the Dart classes of structs and enums
+    #[clap(long)]
+    pub dart_json: bool,
+    /// Make all the parameters of the Dart functions `required`, including the nullable ones
+    #[clap(long)]
+    pub dart_required_params: bool,
+    /// Receive and send Rust `char`s as Dart `int` runes instead of one-character `String`s
+    #[clap(long)]
+    pub dart_char_as_int: bool,
+    /// Put the functions of each Rust input file in a Dart namespace named after its module
+    #[clap(long)]
+    pub dart_namespaces: bool,
     /// Skip automatically adding `mod bridge_generated;` to `lib.rs`
     #[clap(long)]
     pub skip_add_mod_to_lib: bool,
@@ -76,14 +100,42 @@ pub struct RawOpts {
     /// Inline declaration of Rust bridge modules
     #[clap(long)]
     pub inline_rust: bool,
+    /// Record the bytes moved by each call in debug builds, see `flutter_rust_bridge::wire_size`
+    #[clap(long)]
+    pub record_wire_size: bool,
+    /// Also generate a synchronous `...Drain` function for each function taking a `StreamSink`,
+    /// returning all the items at once, for tests
+    #[clap(long)]
+    pub drain_streams: bool,
     /// Skip dependencies check.
     #[clap(long)]
     pub skip_deps_check: bool,
+    /// Keep running and regenerate whenever the input Rust files change
+    #[clap(long)]
+    pub watch: bool,
+    /// Always regenerate, even if the inputs did not change since the last run
+    #[clap(long)]
+    pub no_cache: bool,
+    /// Write the parsed functions and types of each block as JSON to this path, or to stdout
+    /// if it is `-`. Implies --no-cache
+    #[clap(long, multiple_values = true)]
+    pub dump_ir: Option<Vec<String>>,
+}
+
+#[derive(clap::ValueEnum, Debug, Clone, Copy, PartialEq, Eq, Deserialize, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum DartClassStyle {
+    /// Plain classes, unless the struct has `#[frb(dart_metadata=("freezed"))]`
+    #[default]
+    Plain,
+    /// `@freezed` classes for all the structs
+    Freezed,
 }
 
 #[derive(Debug, Clone)]
 pub struct Opts {
-    pub rust_input_path: String,
+    /// All the input files of this block, the first one being used to guess the defaults
+    pub rust_input_paths: Vec<String>,
     pub dart_output_path: String,
     pub dart_decl_output_path: Option<String>,
     pub c_output_path: Vec<String>,
@@ -91,6 +143,13 @@ pub struct Opts {
     pub rust_output_path: String,
     pub class_name: String,
     pub dart_format_line_length: u32,
+    pub dart_class_style: DartClassStyle,
+    pub dart_value_equality: bool,
+    pub dart_copy_with: bool,
+    pub dart_json: bool,
+    pub dart_required_params: bool,
+    pub dart_char_as_int: bool,
+    pub dart_namespaces: bool,
     pub skip_add_mod_to_lib: bool,
     pub llvm_path: Vec<String>,
     pub llvm_compiler_opts: String,
@@ -101,6 +160,11 @@ pub struct Opts {
     pub skip_deps_check: bool,
     pub wasm_enabled: bool,
     pub inline_rust: bool,
+    pub record_wire_size: bool,
+    pub drain_streams: bool,
+    pub cache: bool,
+    /// See `--dump-ir`.
+    pub dump_ir_path: Option<String>,
 }
 
 fn bail(err: clap::ErrorKind, message: Cow<str>) {
@@ -109,7 +173,16 @@ fn bail(err: clap::ErrorKind, message: Cow<str>) {
 
 pub fn parse(raw: RawOpts) -> Vec<Opts> {
     // rust input path(s)
-    let rust_input_paths = get_valid_canon_paths(&raw.rust_input);
+    let rust_inputs = raw
+        .rust_input
+        .iter()
+        .filter(|p| !p.trim().is_empty())
+        .map(|p| expand_rust_input(p))
+        .collect::<Vec<_>>();
+    let rust_input_paths = rust_inputs
+        .iter()
+        .map(|paths| paths[0].clone())
+        .collect::<Vec<_>>();
 
     // dart output path(s)
     let dart_output_paths = get_valid_canon_paths(&raw.dart_output);
@@ -207,17 +280,40 @@ pub fn parse(raw: RawOpts) -> Vec<Opts> {
         .as_ref()
         .map(|s| canon_path(s.as_str()));
     let dart_format_line_length = raw.dart_format_line_length;
+    let dart_class_style = raw.dart_class_style;
+    let dart_value_equality = raw.dart_value_equality;
+    let dart_copy_with = raw.dart_copy_with;
+    let dart_json = raw.dart_json;
+    let dart_required_params = raw.dart_required_params;
+    let dart_char_as_int = raw.dart_char_as_int;
+    let dart_namespaces = raw.dart_namespaces;
     let llvm_paths = get_llvm_paths(&raw.llvm_path);
     let llvm_compiler_opts = raw.llvm_compiler_opts.clone().unwrap_or_default();
     let skip_add_mod_to_lib = raw.skip_add_mod_to_lib;
     let build_runner = !raw.no_build_runner;
     let wasm = raw.wasm;
     let inline_rust = raw.inline_rust;
+    let record_wire_size = raw.record_wire_size;
+    let drain_streams = raw.drain_streams;
+    // dump path(s)
+    let dump_ir_paths = raw.dump_ir.map(|paths| {
+        if paths.len() != rust_input_paths.len() {
+            bail(
+                clap::ErrorKind::WrongNumberOfValues,
+                "--dump-ir's inputs should match --rust-input's length".into(),
+            );
+        }
+        paths
+            .into_iter()
+            .map(|path| if path == "-" { path } else { canon_path(&path) })
+            .collect::<Vec<_>>()
+    });
+    let cache = !raw.no_cache;
 
     (0..rust_input_paths.len())
         .map(|i| {
             Opts {
-                rust_input_path: rust_input_paths[i].clone(),
+                rust_input_paths: rust_inputs[i].clone(),
                 dart_output_path: dart_output_paths[i].clone(),
                 dart_decl_output_path: dart_decl_output_path.clone(),
                 c_output_path: refined_c_outputs[i].clone(),
@@ -225,6 +321,13 @@ pub fn parse(raw: RawOpts) -> Vec<Opts> {
                 rust_output_path: rust_output_paths[i].clone(),
                 class_name: class_names[i].clone(),
                 dart_format_line_length,
+                dart_class_style,
+                dart_value_equality,
+                dart_copy_with,
+                dart_json,
+                dart_required_params,
+                dart_char_as_int,
+                dart_namespaces,
                 skip_add_mod_to_lib, //same for all rust api blocks
                 llvm_path: llvm_paths.clone(),
                 llvm_compiler_opts: llvm_compiler_opts.clone(),
@@ -235,6 +338,10 @@ pub fn parse(raw: RawOpts) -> Vec<Opts> {
                 skip_deps_check,
                 wasm_enabled: wasm,
                 inline_rust,
+                record_wire_size,
+                drain_streams,
+                cache,
+                dump_ir_path: dump_ir_paths.as_ref().map(|paths| paths[i].clone()),
             }
         })
         .collect()
@@ -362,6 +469,65 @@ fn get_valid_canon_paths(paths: &[String]) -> Vec<String> {
         .collect::<Vec<_>>()
 }
 
+/// Expand one `--rust-input` value, which may be a comma-separated list of paths whose file
+/// names may contain `*` and `?` wildcards.
+fn expand_rust_input(raw: &str) -> Vec<String> {
+    let mut paths = Vec::new();
+    for each in raw.split(',').map(str::trim).filter(|p| !p.is_empty()) {
+        let path = canon_pathbuf(each);
+        let file_name = path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .unwrap_or("");
+        if !file_name.contains(['*', '?']) {
+            paths.push(path_to_string(path).unwrap());
+            continue;
+        }
+        let dir = path.parent().unwrap();
+        let mut matched = fs::read_dir(dir)
+            .unwrap_or_else(|_| panic!("fail to read directory of --rust-input: {}", each))
+            .filter_map(|entry| entry.ok())
+            .filter(|entry| entry.path().is_file())
+            .filter(|entry| matches_wildcard(file_name, &entry.file_name().to_string_lossy()))
+            .map(|entry| path_to_string(entry.path()).unwrap())
+            .collect::<Vec<_>>();
+        if matched.is_empty() {
+            bail(
+                clap::ErrorKind::ValueValidation,
+                format!("--rust-input {each} does not match any file").into(),
+            );
+        }
+        // make the output stable whatever the order of the file system
+        matched.sort();
+        paths.extend(matched);
+    }
+    if paths.is_empty() {
+        bail(
+            clap::ErrorKind::EmptyValue,
+            "--rust-input should not be empty".into(),
+        );
+    }
+    paths.into_iter().unique().collect()
+}
+
+fn matches_wildcard(pattern: &str, name: &str) -> bool {
+    match pattern.chars().next() {
+        None => name.is_empty(),
+        Some('*') => (0..=name.len())
+            .filter(|i| name.is_char_boundary(*i))
+            .any(|i| matches_wildcard(&pattern[1..], &name[i..])),
+        Some(c) => {
+            let mut rest = name.chars();
+            match rest.next() {
+                Some(n) if c == '?' || c == n => {
+                    matches_wildcard(&pattern[c.len_utf8()..], rest.as_str())
+                }
+                _ => false,
+            }
+        }
+    }
+}
+
 fn format_fail_to_guess_error(name: &str) -> String {
     format!("fail to guess {name}, please specify it manually in command line arguments")
 }
@@ -462,21 +628,66 @@ fn path_to_string(path: PathBuf) -> Result<String, OsString> {
 impl Opts {
     pub fn get_ir_file(&self) -> Result<IrFile> {
         // info!("Phase: Parse source code to AST");
-        let source_rust_content = fs::read_to_string(&self.rust_input_path).with_context(|| {
-            format!(
-                "Failed to read rust input file \"{}\"",
-                self.rust_input_path
-            )
-        })?;
-        let file_ast = syn::parse_file(&source_rust_content).unwrap();
+        let namespaces = self.dart_namespaces()?;
+        let sources = self
+            .rust_input_paths
+            .iter()
+            .zip(namespaces)
+            .map(|(path, namespace)| {
+                let content = fs::read_to_string(path)
+                    .with_context(|| format!("Failed to read rust input file \"{path}\""))?;
+                let file = syn::parse_file(&content).unwrap();
+                Ok(parser::Source {
+                    path: path.clone(),
+                    content,
+                    file,
+                    namespace,
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
 
         // info!("Phase: Parse AST to IR");
 
-        Ok(parser::parse(
-            &source_rust_content,
-            file_ast,
-            &self.manifest_path,
-        ))
+        let mut ir_file = parser::parse(&sources, &self.manifest_path, self.dart_char_as_int)?;
+        if self.dart_class_style == DartClassStyle::Freezed {
+            ir_file.use_freezed_for_structs();
+        }
+        if self.dart_required_params {
+            ir_file.require_all_params();
+        }
+        if self.drain_streams {
+            ir_file.add_drain_funcs();
+        }
+        if self.block_index != BlockIndex::PRIMARY {
+            ir_file.suffix_warm_up(self.block_index);
+        }
+        Ok(ir_file)
+    }
+
+    /// The Rust modules of the input files, e.g. `api::user`.
+    pub fn rust_input_mods(&self) -> Vec<String> {
+        self.rust_input_paths
+            .iter()
+            .map(|path| mod_from_rust_path(path, &self.rust_crate_dir))
+            .collect()
+    }
+
+    /// The namespace of the functions of each input file, if `--dart-namespaces` is given.
+    fn dart_namespaces(&self) -> Result<Vec<Option<String>>> {
+        let mods = self.rust_input_mods();
+        if !self.dart_namespaces {
+            return Ok(vec![None; mods.len()]);
+        }
+        let mut seen = HashMap::new();
+        for rust_mod in &mods {
+            if let Some(other) = seen.insert(dart_namespace_of(rust_mod), rust_mod) {
+                anyhow::bail!(
+                    "modules `{other}` and `{rust_mod}` would both become the Dart namespace `{}`",
+                    dart_namespace_of(rust_mod)
+                );
+            }
+        }
+        Ok(mods.into_iter().map(Some).collect())
     }
 
     pub fn dart_api_class_name(&self) -> String {
@@ -556,6 +767,15 @@ mod test {
         (directory, file_name)
     }
 
+    #[test]
+    fn test_matches_wildcard() {
+        assert!(matches_wildcard("*.rs", "user.rs"));
+        assert!(matches_wildcard("api_?.rs", "api_1.rs"));
+        assert!(matches_wildcard("*", ""));
+        assert!(!matches_wildcard("*.rs", "user.dart"));
+        assert!(!matches_wildcard("api_?.rs", "api_12.rs"));
+    }
+
     #[test]
     #[should_panic]
     fn test_coutput_with_no_input_block() {
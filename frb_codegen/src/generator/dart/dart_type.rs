@@ -0,0 +1,110 @@
+//! Checks for the hand-written Dart classes of `#[frb(dart_type = ...)]` and
+//! `#[frb(custom_codec(..))]`.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::{bail, Context};
+use log::warn;
+use regex::Regex;
+
+use crate::ir::{IrDartImport, IrFile, IrType, IrTypeDelegate};
+use crate::tools::dart_package_name;
+
+/// Ensure the conversion functions of the custom Dart types, and the codecs of the custom
+/// codecs, exist when their library belongs to the Dart package being generated, or is
+/// imported relatively to the generated file.
+pub fn check_dart_types(
+    ir_file: &IrFile,
+    dart_root: &str,
+    dart_output_dir: &Path,
+) -> anyhow::Result<()> {
+    let package = dart_package_name(dart_root);
+    let dart_types = ir_file.struct_pool.values().filter_map(|src| {
+        let dart_type = src.dart_type.as_ref()?;
+        Some((
+            src.name.clone(),
+            dart_type.import.clone(),
+            vec![dart_type.from_raw.clone(), dart_type.to_raw.clone()],
+        ))
+    });
+    let codecs = (ir_file.distinct_types(true, true).into_iter()).filter_map(|ty| match ty {
+        IrType::Delegate(IrTypeDelegate::CustomCodec(codec)) => Some((
+            codec.name,
+            codec.import,
+            vec![
+                format!("{}.encode", codec.dart),
+                format!("{}.decode", codec.dart),
+            ],
+        )),
+        _ => None,
+    });
+    for (name, import, funcs) in dart_types.chain(codecs) {
+        check_library(
+            &name,
+            &import,
+            &funcs,
+            package.as_deref(),
+            dart_root,
+            dart_output_dir,
+        )?;
+    }
+    Ok(())
+}
+
+fn check_library(
+    name: &str,
+    import: &IrDartImport,
+    funcs: &[String],
+    package: Option<&str>,
+    dart_root: &str,
+    dart_output_dir: &Path,
+) -> anyhow::Result<()> {
+    let uri = &import.uri;
+    let path = match resolve_library(uri, package, dart_root, dart_output_dir) {
+        Some(path) => path,
+        None => {
+            warn!("Cannot locate `{uri}`, so the conversion functions of `{name}` are not checked");
+            return Ok(());
+        }
+    };
+    let source = fs::read_to_string(&path).with_context(|| {
+        format!(
+            "Cannot read {}, the library of the Dart type of `{name}`",
+            path.display(),
+        )
+    })?;
+    for func in funcs {
+        if !defines(&source, func) {
+            bail!(
+                "The conversion function `{func}` of the Dart type of `{name}` is not found in {}",
+                path.display()
+            );
+        }
+    }
+    Ok(())
+}
+
+fn resolve_library(
+    uri: &str,
+    package: Option<&str>,
+    dart_root: &str,
+    dart_output_dir: &Path,
+) -> Option<PathBuf> {
+    if let Some(rest) = uri.strip_prefix("package:") {
+        let (name, path) = rest.split_once('/')?;
+        (Some(name) == package).then(|| Path::new(dart_root).join("lib").join(path))
+    } else if uri.contains(':') {
+        None
+    } else {
+        Some(dart_output_dir.join(uri))
+    }
+}
+
+/// Whether `source` seems to define `func`, which may also be prefixed, as in `Money.fromRaw`.
+fn defines(source: &str, func: &str) -> bool {
+    let name = func.rsplit('.').next().unwrap_or(func);
+    Regex::new(&format!(r"\b{}\s*\(", regex::escape(name)))
+        .unwrap()
+        .is_match(source)
+}
@@ -10,22 +10,42 @@ pub(crate) fn generate_api_func(
     func: &IrFunc,
     ir_file: &IrFile,
     common_api2wire_body: &str,
+    dart_api_class_name: &str,
 ) -> GeneratedApiFunc {
-    let raw_func_param_list = func
-        .inputs
+    let raw_func_param_list = (func.inputs.iter())
+        .map(|input| input.dart_param(func.required_params))
+        .collect_vec();
+    // Unless an argument already takes the name, cancelable functions accept a per-call
+    // timeout, which cancels them. Other functions could not be stopped by it.
+    let has_timeout = matches!(func.mode, IrFuncMode::Normal)
+        && func.cancellation_argument_index.is_some()
+        && !func
+            .inputs
+            .iter()
+            .any(|input| input.name.dart_style() == "timeout");
+    let callback_param_list = func
+        .callbacks
         .iter()
-        .map(|input| {
+        .map(|callback| {
             format!(
-                "{}{} {}",
-                input.ty.dart_required_modifier(),
-                input.ty.dart_api_type(),
-                input.name.dart_style()
+                "required {} {}",
+                callback.dart_api_type(),
+                callback.name.dart_style()
             )
         })
         .collect::<Vec<_>>();
-    let full_func_param_list = [raw_func_param_list, vec!["dynamic hint".to_owned()]].concat();
+    let full_func_param_list = [
+        raw_func_param_list,
+        callback_param_list,
+        vec!["dynamic hint".to_owned()],
+        has_timeout
+            .then(|| "Duration? timeout".to_owned())
+            .into_iter()
+            .collect(),
+    ]
+    .concat();
 
-    let prepare_args = func
+    let mut prepare_args = func
         .inputs
         .iter()
         .enumerate()
@@ -55,28 +75,68 @@ pub(crate) fn generate_api_func(
         } else {
             vec![]
         },
+        if func.cancellation_argument_index.is_some() {
+            vec!["cancelId_".to_owned()]
+        } else {
+            vec![]
+        },
+        func.callbacks
+            .iter()
+            .map(|callback| format!("{}Port_", callback.name.dart_style()))
+            .collect_vec(),
         (0..prepare_args.len())
             .map(|index| format!("arg{index}"))
             .collect_vec(),
     ]
     .concat();
 
+    // A namespaced function is implemented by a private method, which its namespace forwards to.
+    let (dart_func_name, const_meta_field_name) = match &func.namespace {
+        Some(_) => (
+            format!("_{}", func.flat_name().to_case(Case::Camel)),
+            format!("_k{}ConstMeta", func.flat_name().to_case(Case::Pascal)),
+        ),
+        None => (
+            func.dart_style(),
+            format!("k{}ConstMeta", func.dart_style().to_case(Case::Pascal)),
+        ),
+    };
     let func_expr = format!(
         "{} {}({{ {} }})",
-        func.mode.dart_return_type(&func.output.dart_api_type()),
-        func.name.to_case(Case::Camel),
+        func.dart_return_type(),
+        dart_func_name,
         full_func_param_list.join(","),
     );
 
-    let execute_func_name = match func.mode {
-        IrFuncMode::Normal => "_platform.executeNormal",
-        IrFuncMode::Sync => "_platform.executeSync",
-        IrFuncMode::Stream { .. } => "_platform.executeStream",
+    let execute_func_name = match (&func.mode, &func.iterator) {
+        (_, Some(IrFuncIterator::Returned { item, .. })) => format!(
+            "_platform.executeIterator<{}, {}>",
+            func.output.dart_api_type(),
+            item.dart_api_type()
+        ),
+        (IrFuncMode::Normal, _) if func.cancellation_argument_index.is_some() => {
+            "_platform.executeCancelable".to_owned()
+        }
+        (IrFuncMode::Normal, _) => "_platform.executeNormal".to_owned(),
+        (IrFuncMode::Sync, _) if func.drain.is_some() => "_platform.executeSyncDrain".to_owned(),
+        (IrFuncMode::Sync, _) if func.inline => "_platform.executeSyncAsFuture".to_owned(),
+        (IrFuncMode::Sync | IrFuncMode::Direct, _) => "_platform.executeSync".to_owned(),
+        (IrFuncMode::Stream { .. }, _) if func.stream_initial.is_some() => {
+            "_platform.executeStreamWithInitial".to_owned()
+        }
+        (IrFuncMode::Stream { .. }, _) => "_platform.executeStream".to_owned(),
     };
 
-    let const_meta_field_name = format!("k{}ConstMeta", func.name.to_case(Case::Pascal));
+    let function_name = func.dart_style();
+    let public_func_expr = format!(
+        "{} {function_name}({{ {} }})",
+        func.dart_return_type(),
+        full_func_param_list.join(","),
+    );
+    let public_const_meta_field_name =
+        format!("k{}ConstMeta", func.dart_style().to_case(Case::Pascal));
 
-    let signature = format!("{func_expr};");
+    let signature = format!("{public_func_expr};");
 
     let comments = dart_comments(&func.comments);
 
@@ -125,38 +185,152 @@ pub(crate) fn generate_api_func(
         format!("_wire2api_{}", func.output.safe_ident())
     };
 
+    let parse_error_data = func
+        .error_output
+        .as_ref()
+        .map(|error_output| match error_output {
+            IrType::Tuple(tuple) if tuple.is_coded_error() => {
+                let values = (0..tuple.arity())
+                    .map(|idx| format!("e.{}", IrTypeTuple::dart_field(idx)))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                format!(
+                    "parseErrorData: (d) {{ final e = _wire2api_{}(d); return FrbCodedException({values}); }},",
+                    error_output.safe_ident()
+                )
+            }
+            _ => format!("parseErrorData: _wire2api_{},", error_output.safe_ident()),
+        })
+        .unwrap_or_default();
+
     let is_sync = matches!(func.mode, IrFuncMode::Sync);
-    let implementation = format!(
-        "{} {{
+    if func.cancellation_argument_index.is_some() {
+        prepare_args.push("final cancelId_ = _platform.createCancelId();".to_owned());
+    }
+    prepare_args.extend(func.callbacks.iter().map(|callback| {
+        let name = callback.name.dart_style();
+        match &callback.arg {
+            Some(arg) => format!(
+                "final {name}Port_ = _platform.createCallbackPort<{}>({name}, _wire2api_{});",
+                arg.dart_api_type(),
+                arg.safe_ident()
+            ),
+            None => format!(
+                "final {name}Port_ = _platform.createCallbackPort<void>((_) => {name}(), (_) {{}});"
+            ),
+        }
+    }));
+
+    // The body calling the task, either through `executeSync` which throws the error, or
+    // through `executeSyncResult` which returns it.
+    let call_task = |func_expr: &str, execute_func_name: &str| {
+        format!(
+            "{} {{
             {}
-            return {}({task}(
+            return {}({cancel_id}{task}(
             callFfi: ({args}) => _platform.inner.{}({}),
             parseSuccessData: {},
             {}
-        ));}}",
-        func_expr,
-        prepare_args.join("\n"),
-        execute_func_name,
-        func.wire_func_name(),
-        wire_param_list.join(", "),
-        parse_success_data,
-        task_common_args,
-        task = if is_sync {
-            "FlutterRustBridgeSyncTask"
-        } else {
-            "FlutterRustBridgeTask"
-        },
-        args = if is_sync { "" } else { "port_" },
-    );
+            {}
+        ){extra_args});}}",
+            func_expr,
+            prepare_args.join("\n"),
+            execute_func_name,
+            func.wire_func_name(),
+            wire_param_list.join(", "),
+            parse_success_data,
+            parse_error_data,
+            task_common_args,
+            task = if is_sync {
+                "FlutterRustBridgeSyncTask"
+            } else {
+                "FlutterRustBridgeTask"
+            },
+            args = if is_sync { "" } else { "port_" },
+            cancel_id = if func.cancellation_argument_index.is_some() {
+                "cancelId_, "
+            } else {
+                ""
+            },
+            extra_args = if let Some(initial) = &func.stream_initial {
+                format!(
+                    ", parseInitial: _wire2api_{}{}",
+                    initial.safe_ident(),
+                    if let IrFuncMode::Stream {
+                        broadcast: true, ..
+                    } = func.mode
+                    {
+                        ", broadcast: true"
+                    } else {
+                        ""
+                    }
+                )
+            } else if let IrFuncMode::Stream {
+                broadcast: true, ..
+            } = func.mode
+            {
+                ", broadcast: true".to_owned()
+            } else if let Some(IrFuncIterator::Returned { next, .. }) = &func.iterator {
+                format!(
+                    ", next: (iterator) => {}(iterator: iterator)",
+                    next.to_case(Case::Camel)
+                )
+            } else if has_timeout {
+                ", timeout: timeout".to_owned()
+            } else {
+                String::new()
+            },
+        )
+    };
 
-    let companion_field_signature =
-        format!("FlutterRustBridgeTaskConstMeta get {const_meta_field_name};");
+    let mut implementation = if let IrFuncMode::Direct = func.mode {
+        // No task: the result comes straight from the C ABI.
+        let call = format!(
+            "_platform.inner.{}({})",
+            func.wire_func_name(),
+            wire_param_list.join(", ")
+        );
+        let call = match func.output {
+            Primitive(IrTypePrimitive::Unit) => format!("{call};"),
+            _ => format!("return _wire2api_{}({call});", func.output.safe_ident()),
+        };
+        format!("{func_expr} {{ {} {call} }}", prepare_args.join("\n"))
+    } else {
+        call_task(&func_expr, &execute_func_name)
+    };
+
+    let mut companion_field_signature =
+        format!("FlutterRustBridgeTaskConstMeta get {public_const_meta_field_name};");
+
+    // The `...Result` companion of a fallible sync function.
+    let sync_result_exprs = func.has_sync_result().then(|| {
+        let return_type = format!("SyncResult<{}>", func.output.dart_api_type());
+        let params = full_func_param_list.join(",");
+        (
+            format!("{return_type} {dart_func_name}Result({{ {params} }})"),
+            format!("{return_type} {function_name}Result({{ {params} }})"),
+        )
+    });
+    if let Some((func_expr, public_func_expr)) = &sync_result_exprs {
+        companion_field_signature += &format!(
+            "
+
+            /// Same as [{function_name}], except that the error is returned in the [SyncResult]
+            /// instead of being thrown.
+            {public_func_expr};"
+        );
+        implementation += &format!(
+            "\n\n{}",
+            call_task(func_expr, "_platform.executeSyncResult")
+        );
+    }
 
     let companion_field_implementation = format!(
         "
         FlutterRustBridgeTaskConstMeta get {const_meta_field_name} => const FlutterRustBridgeTaskConstMeta(
             debugName: \"{}\",
             argNames: [{}],
+            function: {}.{function_name},
         );
         ",
         func.name,
@@ -165,14 +339,47 @@ pub(crate) fn generate_api_func(
             .map(|input| format!("\"{}\"", input.name.dart_style()))
             .collect::<Vec<_>>()
             .join(", "),
+        dart_functions_enum_name(dart_api_class_name),
     );
 
+    let forwarding_implementation = if func.namespace.is_some() {
+        let args = func
+            .inputs
+            .iter()
+            .map(|input| input.name.dart_style())
+            .chain(
+                func.callbacks
+                    .iter()
+                    .map(|callback| callback.name.dart_style()),
+            )
+            .map(|name| format!("{0}: {0}", name))
+            .chain(std::iter::once("hint: hint".to_owned()))
+            .chain(has_timeout.then(|| "timeout: timeout".to_owned()))
+            .collect::<Vec<_>>()
+            .join(", ");
+        let mut forwarding = format!(
+            "{public_func_expr} => _api.{dart_func_name}({args});
+
+            FlutterRustBridgeTaskConstMeta get {public_const_meta_field_name} => _api.{const_meta_field_name};"
+        );
+        if let Some((_, public_result_expr)) = &sync_result_exprs {
+            forwarding +=
+                &format!("\n\n{public_result_expr} => _api.{dart_func_name}Result({args});");
+        }
+        forwarding
+    } else {
+        String::new()
+    };
+
     GeneratedApiFunc {
         signature,
         implementation,
         comments,
         companion_field_signature,
         companion_field_implementation,
+        namespace: func.dart_namespace(),
+        forwarding_implementation,
+        function_name: Some(function_name),
     }
 }
 
@@ -181,6 +388,7 @@ pub(crate) fn generate_opaque_getters(ty: &IrType) -> GeneratedApiFunc {
         "
     DropFnType get dropOpaque{0};
     ShareFnType get shareOpaque{0};
+    ShareFnType get sendOpaque{0};
     OpaqueTypeFinalizer get {0}Finalizer;
     ",
         ty.dart_api_type(),
@@ -190,6 +398,7 @@ pub(crate) fn generate_opaque_getters(ty: &IrType) -> GeneratedApiFunc {
         "
         DropFnType get dropOpaque{0} => _platform.inner.drop_opaque_{0};
         ShareFnType get shareOpaque{0} => _platform.inner.share_opaque_{0};
+        ShareFnType get sendOpaque{0} => _platform.inner.send_opaque_{0};
         OpaqueTypeFinalizer get {0}Finalizer => _platform.{0}Finalizer;
         ",
         ty.dart_api_type()
@@ -201,5 +410,35 @@ pub(crate) fn generate_opaque_getters(ty: &IrType) -> GeneratedApiFunc {
         comments: String::new(),
         companion_field_signature: String::new(),
         companion_field_implementation: String::new(),
+        namespace: None,
+        forwarding_implementation: String::new(),
+        function_name: None,
     }
 }
+
+/// Lets the functions taking a `SharedBuffer` receive one, since only the generated
+/// class can reach the wire functions allocating it.
+pub(crate) fn generate_shared_buffer_constructors() -> Vec<GeneratedApiFunc> {
+    let constructor = |signature: &str, body: &str, comments: &str| GeneratedApiFunc {
+        signature: format!("{signature};"),
+        implementation: format!("{signature} => {body};"),
+        comments: comments.to_owned(),
+        companion_field_signature: String::new(),
+        companion_field_implementation: String::new(),
+        namespace: None,
+        forwarding_implementation: String::new(),
+        function_name: None,
+    };
+    vec![
+        constructor(
+            "SharedBuffer createSharedBuffer(List<int> data)",
+            "SharedBuffer.copy(_platform.inner, data)",
+            "/// Copies [data] once into memory owned by Rust, which any number of calls can then borrow.\n",
+        ),
+        constructor(
+            "SharedBuffer allocateSharedBuffer(int length, void Function(SharedBufferWriter bytes) fill)",
+            "SharedBuffer.fill(_platform.inner, length, fill)",
+            "/// Lets [fill] write [length] bytes directly into memory owned by Rust, without any copy.\n",
+        ),
+    ]
+}
@@ -0,0 +1,415 @@
+//! The `toJson` and `fromJson` of the Dart classes, see `--dart-json`.
+//!
+//! Keys are the Rust names of the fields, and enums with data are tagged with the Rust name
+//! of their variant, so that the shape does not depend on the generated Dart code.
+
+use std::collections::HashSet;
+
+use crate::ir::IrType::*;
+use crate::ir::*;
+use crate::method_utils::MethodNamingUtil;
+
+/// Whether `ty` can be converted to and from JSON. Opaque types, custom Dart types and
+/// structs with methods, which need the bridge, cannot.
+pub(crate) fn supports_json(ty: &IrType, ir_file: &IrFile) -> bool {
+    supports_json_inner(ty, ir_file, &mut HashSet::new())
+}
+
+fn supports_json_inner(ty: &IrType, ir_file: &IrFile, visiting: &mut HashSet<String>) -> bool {
+    match ty {
+        Primitive(primitive) => !matches!(primitive, IrTypePrimitive::Unit),
+        PrimitiveList(list) => !is_64_bit(&list.primitive),
+        Optional(IrTypeOptional { inner }) | Boxed(IrTypeBoxed { inner, .. }) => {
+            supports_json_inner(inner, ir_file, visiting)
+        }
+        GeneralList(IrTypeGeneralList { inner }) => supports_json_inner(inner, ir_file, visiting),
+        Map(map) => {
+            json_key(&map.key).is_some() && supports_json_inner(&map.value, ir_file, visiting)
+        }
+        Tuple(tuple) => tuple
+            .values
+            .iter()
+            .all(|ty| supports_json_inner(ty, ir_file, visiting)),
+        StructRef(st) => {
+            let src = st.get(ir_file);
+            // Recursive types are checked once, on their first visit.
+            if !visiting.insert(src.name.clone()) {
+                return true;
+            }
+            src.dart_type.is_none()
+                && !MethodNamingUtil::has_methods(&src.name, ir_file)
+                && src
+                    .fields
+                    .iter()
+                    .all(|field| supports_json_inner(&field.ty, ir_file, visiting))
+        }
+        EnumRef(enu) => {
+            let src = enu.get(ir_file);
+            if !visiting.insert(src.name.clone()) {
+                return true;
+            }
+            src.variants().iter().all(|variant| match &variant.kind {
+                IrVariantKind::Value => true,
+                IrVariantKind::Struct(st) => st
+                    .fields
+                    .iter()
+                    .all(|field| supports_json_inner(&field.ty, ir_file, visiting)),
+            })
+        }
+        Delegate(delegate) => match delegate {
+            IrTypeDelegate::Array(IrTypeDelegateArray::PrimitiveArray { primitive, .. })
+            | IrTypeDelegate::ZeroCopyBufferVecPrimitive(primitive) => !is_64_bit(primitive),
+            IrTypeDelegate::Array(IrTypeDelegateArray::GeneralArray { general, .. }) => {
+                supports_json_inner(general, ir_file, visiting)
+            }
+            IrTypeDelegate::BTreeSet(inner) => supports_json_inner(inner, ir_file, visiting),
+            IrTypeDelegate::Arc(_)
+            | IrTypeDelegate::Weak(_)
+            | IrTypeDelegate::Instant
+            | IrTypeDelegate::BoxDyn(_)
+            | IrTypeDelegate::ImplTrait(_)
+            | IrTypeDelegate::DynRef(_)
+            | IrTypeDelegate::PathRef
+            | IrTypeDelegate::SliceRef(_)
+            // Like the other 64-bit typed lists.
+            | IrTypeDelegate::SizeList(_) => false,
+            _ => true,
+        },
+        SyncReturn(_) | DartOpaque(_) | RustOpaque(_) => false,
+    }
+}
+
+/// 64-bit typed lists hold `BigInt`s on the web, which have no JSON representation.
+fn is_64_bit(primitive: &IrTypePrimitive) -> bool {
+    matches!(primitive, IrTypePrimitive::I64 | IrTypePrimitive::U64)
+}
+
+/// JSON objects only have string keys, so integer keys are written in decimal.
+/// Returns how to encode and decode the key `k`.
+fn json_key(key: &IrType) -> Option<(&'static str, String)> {
+    match key {
+        Delegate(IrTypeDelegate::String) => Some(("k", "k".to_owned())),
+        Primitive(primitive) if primitive.dart_api_type() == "int" => {
+            Some(("k.toString()", "int.parse(k)".to_owned()))
+        }
+        _ => None,
+    }
+}
+
+/// The Dart expression converting `value`, of type `ty`, into something `jsonEncode` accepts.
+/// `depth` keeps the names of nested closures apart.
+pub(crate) fn to_json(ty: &IrType, value: &str, depth: usize) -> String {
+    let v = format!("v{depth}");
+    match ty {
+        Primitive(_) => value.to_owned(),
+        PrimitiveList(IrTypePrimitiveList { primitive }) => {
+            primitive_list_to_json(primitive, value)
+        }
+        Optional(IrTypeOptional { inner }) => {
+            format!(
+                "{value} == null ? null : {}",
+                to_json(inner, &format!("{value}!"), depth)
+            )
+        }
+        Boxed(IrTypeBoxed { inner, .. }) => to_json(inner, value, depth),
+        GeneralList(IrTypeGeneralList { inner }) => format!(
+            "{value}.map(({v}) => {}).toList()",
+            to_json(inner, &v, depth + 1)
+        ),
+        Map(map) => {
+            let (key, _) = json_key(&map.key).unwrap();
+            format!(
+                "{value}.map((k, {v}) => MapEntry({key}, {}))",
+                to_json(&map.value, &v, depth + 1)
+            )
+        }
+        Tuple(tuple) => format!(
+            "[{}]",
+            (tuple.values.iter().enumerate())
+                .map(|(idx, ty)| to_json(ty, &format!("{value}.item{}", idx + 1), depth))
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+        StructRef(_) | EnumRef(_) => format!("{value}.toJson()"),
+        Delegate(delegate) => match delegate {
+            IrTypeDelegate::Array(IrTypeDelegateArray::PrimitiveArray { primitive, .. })
+            | IrTypeDelegate::ZeroCopyBufferVecPrimitive(primitive) => {
+                primitive_list_to_json(primitive, value)
+            }
+            IrTypeDelegate::Array(IrTypeDelegateArray::GeneralArray { general, .. }) => format!(
+                "{value}.map(({v}) => {}).toList()",
+                to_json(general, &v, depth + 1)
+            ),
+            IrTypeDelegate::BTreeSet(inner) => format!(
+                "{value}.map(({v}) => {}).toList()",
+                to_json(inner, &v, depth + 1)
+            ),
+            IrTypeDelegate::PrimitiveEnum { .. } => format!("{value}.name"),
+            IrTypeDelegate::NonZero(inner) => to_json(inner, value, depth),
+            IrTypeDelegate::I128 | IrTypeDelegate::U128 => format!("{value}.toString()"),
+            #[cfg(feature = "chrono")]
+            IrTypeDelegate::Time(IrTypeTime::Duration) => format!("{value}.inMicroseconds"),
+            #[cfg(feature = "chrono")]
+            IrTypeDelegate::Time(_) => format!("{value}.toIso8601String()"),
+            #[cfg(feature = "uuid")]
+            IrTypeDelegate::Uuid => format!("{value}.uuid"),
+            #[cfg(feature = "uuid")]
+            IrTypeDelegate::Uuids => format!("{value}.map(({v}) => {v}.uuid).toList()"),
+            #[cfg(feature = "bytes")]
+            IrTypeDelegate::Bytes => format!("base64Encode({value})"),
+            #[cfg(feature = "rust_decimal")]
+            IrTypeDelegate::Decimal => format!("{value}.toString()"),
+            IrTypeDelegate::CowBytes => format!("base64Encode({value})"),
+            IrTypeDelegate::SystemTime => format!("{value}.toIso8601String()"),
+            IrTypeDelegate::Range { .. } => {
+                format!("{{'start': {value}.start, 'end': {value}.end}}")
+            }
+            IrTypeDelegate::IpAddr => format!("{value}.address"),
+            IrTypeDelegate::CustomCodec(codec) => {
+                format!("base64Encode({}.encode({value}))", codec.dart_codec())
+            }
+            IrTypeDelegate::SocketAddr => format!(
+                "{{'ip': {value}.ip.address, 'port': {value}.port, 'scope_id': {value}.scopeId}}"
+            ),
+            _ => value.to_owned(),
+        },
+        SyncReturn(_) | DartOpaque(_) | RustOpaque(_) => {
+            unreachable!("{} has no JSON representation", ty.dart_api_type())
+        }
+    }
+}
+
+/// The Dart expression converting `json`, decoded by `jsonDecode`, back into the type `ty`.
+pub(crate) fn from_json(ty: &IrType, json: &str, depth: usize) -> String {
+    let v = format!("v{depth}");
+    let list = |inner: &IrType| {
+        format!(
+            "({json} as List<dynamic>).map(({v}) => {}).toList()",
+            from_json(inner, &v, depth + 1)
+        )
+    };
+    match ty {
+        Primitive(primitive) => match primitive.dart_api_type().as_str() {
+            "double" => format!("({json} as num).toDouble()"),
+            dart_type => format!("{json} as {dart_type}"),
+        },
+        PrimitiveList(IrTypePrimitiveList { primitive }) => {
+            primitive_list_from_json(primitive, &ty.dart_api_type(), json)
+        }
+        Optional(IrTypeOptional { inner }) => {
+            format!("{json} == null ? null : {}", from_json(inner, json, depth))
+        }
+        Boxed(IrTypeBoxed { inner, .. }) => from_json(inner, json, depth),
+        GeneralList(IrTypeGeneralList { inner }) => list(inner),
+        Map(map) => {
+            let (_, key) = json_key(&map.key).unwrap();
+            format!(
+                "({json} as Map<String, dynamic>).map((k, {v}) => MapEntry({key}, {}))",
+                from_json(&map.value, &v, depth + 1)
+            )
+        }
+        Tuple(tuple) => format!(
+            "Tuple{}({})",
+            tuple.values.len(),
+            (tuple.values.iter().enumerate())
+                .map(|(idx, ty)| from_json(ty, &format!("({json} as List<dynamic>)[{idx}]"), depth))
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+        StructRef(_) | EnumRef(_) => format!(
+            "{}.fromJson({json} as Map<String, dynamic>)",
+            ty.dart_api_type()
+        ),
+        Delegate(delegate) => match delegate {
+            IrTypeDelegate::Array(array) => format!(
+                "{}({})",
+                array.dart_api_type(),
+                from_json(&array.get_delegate(), json, depth)
+            ),
+            IrTypeDelegate::ZeroCopyBufferVecPrimitive(primitive) => {
+                primitive_list_from_json(primitive, &ty.dart_api_type(), json)
+            }
+            IrTypeDelegate::BTreeSet(inner) => format!(
+                "({json} as List<dynamic>).map(({v}) => {}).toSet()",
+                from_json(inner, &v, depth + 1)
+            ),
+            IrTypeDelegate::StringList => format!("({json} as List<dynamic>).cast<String>()"),
+            IrTypeDelegate::PrimitiveEnum { ir, .. } => {
+                format!("{}.values.byName({json} as String)", ir.dart_api_type())
+            }
+            IrTypeDelegate::I128 | IrTypeDelegate::U128 => {
+                format!("BigInt.parse({json} as String)")
+            }
+            IrTypeDelegate::NonZero(inner) => from_json(inner, json, depth),
+            #[cfg(feature = "chrono")]
+            IrTypeDelegate::Time(IrTypeTime::Duration) => {
+                format!("Duration(microseconds: {json} as int)")
+            }
+            #[cfg(feature = "chrono")]
+            IrTypeDelegate::Time(_) => format!("DateTime.parse({json} as String)"),
+            #[cfg(feature = "uuid")]
+            IrTypeDelegate::Uuid => format!("UuidValue({json} as String)"),
+            #[cfg(feature = "uuid")]
+            IrTypeDelegate::Uuids => {
+                format!("({json} as List<dynamic>).map(({v}) => UuidValue({v} as String)).toList()")
+            }
+            #[cfg(feature = "bytes")]
+            IrTypeDelegate::Bytes => format!("base64Decode({json} as String)"),
+            #[cfg(feature = "rust_decimal")]
+            IrTypeDelegate::Decimal => format!("Decimal.parse({json} as String)"),
+            IrTypeDelegate::CowBytes => format!("base64Decode({json} as String)"),
+            IrTypeDelegate::SystemTime => format!("DateTime.parse({json} as String)"),
+            IrTypeDelegate::Range { bound, inclusive } => {
+                let bound = |key: &str| match bound {
+                    IrTypePrimitive::F32 | IrTypePrimitive::F64 => {
+                        format!("({json}['{key}'] as num).toDouble()")
+                    }
+                    _ => format!("{json}['{key}'] as int"),
+                };
+                format!(
+                    "RustRange({}, {}, inclusive: {inclusive})",
+                    bound("start"),
+                    bound("end")
+                )
+            }
+            IrTypeDelegate::IpAddr => format!("IpAddress.parse({json} as String)"),
+            IrTypeDelegate::CustomCodec(codec) => format!(
+                "{}.decode(base64Decode({json} as String))",
+                codec.dart_codec()
+            ),
+            IrTypeDelegate::SocketAddr => format!(
+                "SocketAddress(IpAddress.parse({json}['ip'] as String), {json}['port'] as int, \
+                scopeId: {json}['scope_id'] as int)"
+            ),
+            _ => format!("{json} as {}", ty.dart_api_type()),
+        },
+        SyncReturn(_) | DartOpaque(_) | RustOpaque(_) => {
+            unreachable!("{} has no JSON representation", ty.dart_api_type())
+        }
+    }
+}
+
+/// Bytes are written in base64, other typed lists as lists of numbers.
+fn primitive_list_to_json(primitive: &IrTypePrimitive, value: &str) -> String {
+    match primitive {
+        IrTypePrimitive::U8 => format!("base64Encode({value})"),
+        _ => format!("{value}.toList()"),
+    }
+}
+
+fn primitive_list_from_json(primitive: &IrTypePrimitive, dart_type: &str, json: &str) -> String {
+    match primitive {
+        IrTypePrimitive::U8 => format!("base64Decode({json} as String)"),
+        IrTypePrimitive::F32 | IrTypePrimitive::F64 => format!(
+            "{dart_type}.fromList(({json} as List<dynamic>).map((v) => (v as num).toDouble()).toList())"
+        ),
+        _ => format!("{dart_type}.fromList(({json} as List<dynamic>).cast<int>())"),
+    }
+}
+
+/// The `toJson` and `fromJson` members of the class of `st`, whose Dart fields are `fields`.
+/// `fromJson` is a static method for `freezed` classes, which would otherwise expect
+/// `json_serializable` to implement it.
+pub(crate) fn struct_json_methods(st: &IrStruct, fields: &[IrField], is_static: bool) -> String {
+    let class_name = st.dart_class_name();
+    let entries = (fields.iter())
+        .map(|field| {
+            format!(
+                "'{}': {},",
+                field.name.rust_style(),
+                to_json(&field.ty, &field.name.dart_style(), 0)
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("");
+    format!(
+        "{} {class_name}{}fromJson(Map<String, dynamic> json) => {class_name}({});
+
+        Map<String, dynamic> toJson() => {{ {entries} }};",
+        if is_static { "static" } else { "factory" },
+        if is_static { " " } else { "." },
+        fields_from_json(fields, "json", !st.dart_positional),
+    )
+}
+
+/// The arguments rebuilding `fields` from the JSON object `json`. Struct classes
+/// take named arguments unless `#[frb(positional)]`, while tuple-like enum variants
+/// take positional ones.
+fn fields_from_json(fields: &[IrField], json: &str, named: bool) -> String {
+    (fields.iter())
+        .map(|field| {
+            let value = from_json(
+                &field.ty,
+                &format!("{json}['{}']", field.name.rust_style()),
+                0,
+            );
+            if named {
+                format!("{}: {value},", field.name.dart_style())
+            } else {
+                format!("{value},")
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("")
+}
+
+/// The `toJson` and `fromJson` members of the `freezed` class of `enu`, which is tagged with
+/// the name of the variant, e.g. `{"tag": "Interrupted", "content": {"received": 42}}`.
+pub(crate) fn enum_json_methods(enu: &IrEnum) -> String {
+    let name = &enu.name;
+    let cases = (enu.variants().iter())
+        .map(|variant| {
+            let args = match &variant.kind {
+                IrVariantKind::Value => String::new(),
+                IrVariantKind::Struct(st) => {
+                    fields_from_json(&st.fields, "content", st.is_fields_named)
+                }
+            };
+            format!(
+                "case '{}': return {name}.{}({args});",
+                variant.name.rust_style(),
+                variant.name.dart_style()
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+    let branches = (enu.variants().iter())
+        .map(|variant| {
+            let content = match &variant.kind {
+                IrVariantKind::Value => String::new(),
+                IrVariantKind::Struct(st) => format!(
+                    "'content': {{ {} }},",
+                    (st.fields.iter())
+                        .map(|field| format!(
+                            "'{}': {},",
+                            field.name.rust_style(),
+                            to_json(&field.ty, &format!("self.{}", field.name.dart_style()), 0)
+                        ))
+                        .collect::<Vec<_>>()
+                        .join("")
+                ),
+            };
+            format!(
+                "if (self is {}) return {{ 'tag': '{}', {content} }};",
+                variant.wrapper_name.rust_style(),
+                variant.name.rust_style()
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+    format!(
+        "static {name} fromJson(Map<String, dynamic> json) {{
+            final content = json['content'];
+            switch (json['tag']) {{
+                {cases}
+                default: throw FormatException('Unknown variant of {name}', json['tag']);
+            }}
+        }}
+
+        Map<String, dynamic> toJson() {{
+            final self = this;
+            {branches}
+            throw StateError('unreachable');
+        }}"
+    )
+}
@@ -1,18 +1,23 @@
+mod dart_type;
 mod func;
+mod json;
 mod ty;
 mod ty_boxed;
 mod ty_dart_opaque;
 mod ty_delegate;
 mod ty_enum;
 mod ty_general_list;
+mod ty_map;
 mod ty_optional;
 mod ty_primitive;
 mod ty_primitive_list;
 mod ty_rust_opaque;
 mod ty_struct;
 mod ty_sync_return;
+mod ty_tuple;
 mod wasm;
 
+pub use dart_type::check_dart_types;
 use func::*;
 use std::borrow::Cow;
 use std::collections::HashSet;
@@ -27,12 +32,14 @@ pub use ty_dart_opaque::*;
 pub use ty_delegate::*;
 pub use ty_enum::*;
 pub use ty_general_list::*;
+pub use ty_map::*;
 pub use ty_optional::*;
 pub use ty_primitive::*;
 pub use ty_primitive_list::*;
 pub use ty_rust_opaque::*;
 pub use ty_struct::*;
 pub use ty_sync_return::*;
+pub use ty_tuple::*;
 
 use convert_case::{Case, Casing};
 use log::debug;
@@ -67,7 +74,12 @@ pub fn generate(ir_file: &IrFile, config: &Opts, wasm_funcs: &[IrFuncDisplay]) -
         &common_header,
         generate_freezed_header(dart_output_file_root, needs_freezed),
         generate_import_header(get_dart_imports(ir_file), spec.import_array.as_deref()),
-        generate_dart_declaration_body(dart_api_class_name, dart_funcs, dart_structs),
+        generate_dart_declaration_body(
+            dart_api_class_name,
+            dart_funcs,
+            dart_structs,
+            &ir_file.consts,
+        ),
     );
 
     let impl_code = generate_dart_implementation_code(
@@ -119,6 +131,9 @@ impl DartApiSpec {
                 )
                 .structs()
             })
+            .chain(ir_file.interfaces.iter().map(|interface| {
+                ty_rust_opaque::generate_interface(interface, ir_file, &dart_api_class_name)
+            }))
             .collect::<Vec<_>>();
         let dart_api2wire_funcs = distinct_input_types
             .iter()
@@ -129,7 +144,14 @@ impl DartApiSpec {
         let mut dart_funcs = ir_file
             .funcs
             .iter()
-            .map(|f| generate_api_func(f, ir_file, &dart_api2wire_funcs.common))
+            .map(|f| {
+                generate_api_func(
+                    f,
+                    ir_file,
+                    &dart_api2wire_funcs.common,
+                    &dart_api_class_name,
+                )
+            })
             .collect::<Vec<_>>();
         dart_funcs.extend(
             distinct_output_types
@@ -137,6 +159,12 @@ impl DartApiSpec {
                 .filter(|ty| ty.is_rust_opaque() || ty.is_sync_rust_opaque())
                 .map(generate_opaque_getters),
         );
+        if distinct_input_types
+            .iter()
+            .any(|ty| matches!(ty, IrType::Delegate(IrTypeDelegate::SharedBuffer)))
+        {
+            dart_funcs.extend(generate_shared_buffer_constructors());
+        }
 
         let dart_api_fill_to_wire_funcs = distinct_input_types
             .iter()
@@ -174,13 +202,16 @@ impl DartApiSpec {
 
         let needs_freezed = distinct_types.iter().any(|ty| match ty {
             EnumRef(_) => true,
-            StructRef(st) => st.freezed,
+            StructRef(st) => st.get(ir_file).using_freezed(),
             _ => false,
         });
 
-        let import_array = distinct_types
-            .iter()
-            .any(IrType::is_array)
+        let uses_value_equality = config.dart_value_equality
+            && distinct_types.iter().any(|ty| match ty {
+                StructRef(st) => !st.get(ir_file).using_freezed(),
+                _ => false,
+            });
+        let import_array = (uses_value_equality || distinct_types.iter().any(IrType::is_array))
             .then(|| "import 'package:collection/collection.dart';".to_owned());
 
         DartApiSpec {
@@ -212,7 +243,7 @@ fn generate_freezed_header(dart_output_file_root: &str, needs_freezed: bool) ->
 }
 
 fn generate_import_header(
-    imports: HashSet<&IrDartImport>,
+    imports: HashSet<IrDartImport>,
     import_array: Option<&str>,
 ) -> DartBasicCode {
     if !imports.is_empty() || import_array.is_some() {
@@ -237,7 +268,7 @@ fn generate_import_header(
 fn generate_common_header() -> DartBasicCode {
     DartBasicCode {
         import: format!(
-            "{}{}",
+            "{}{}{}",
             "import 'dart:convert';
             import 'dart:async';
             import 'package:meta/meta.dart';
@@ -247,17 +278,34 @@ fn generate_common_header() -> DartBasicCode {
             } else {
                 ""
             },
+            if cfg!(feature = "rust_decimal") {
+                "\nimport 'package:decimal/decimal.dart';"
+            } else {
+                ""
+            },
         ),
         part: "".to_string(),
         body: "".to_string(),
     }
 }
 
-fn get_dart_imports(ir_file: &IrFile) -> HashSet<&IrDartImport> {
+fn get_dart_imports(ir_file: &IrFile) -> HashSet<IrDartImport> {
+    let codecs = ir_file
+        .distinct_types(true, true)
+        .into_iter()
+        .filter_map(|ty| match ty {
+            IrType::Delegate(IrTypeDelegate::CustomCodec(codec)) => Some(codec.import),
+            _ => None,
+        });
     ir_file
         .struct_pool
         .values()
-        .flat_map(|s| s.dart_metadata.iter().flat_map(|it| &it.library))
+        .flat_map(|s| {
+            (s.dart_metadata.iter().flat_map(|it| &it.library))
+                .chain(s.dart_type.as_ref().map(|it| &it.import))
+        })
+        .cloned()
+        .chain(codecs)
         .collect()
 }
 
@@ -265,28 +313,120 @@ fn generate_dart_declaration_body(
     dart_api_class_name: &str,
     dart_funcs: &[GeneratedApiFunc],
     dart_structs: &[String],
+    consts: &[IrConst],
 ) -> String {
+    let consts = consts
+        .iter()
+        .map(|it| {
+            format!(
+                "{}const {} {} = {};",
+                dart_comments(&it.comments),
+                it.ty.dart_api_type(),
+                it.name.dart_style(),
+                it.value
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n\n");
+    let signatures = |namespace: Option<&str>| {
+        dart_funcs
+            .iter()
+            .filter(|func| func.namespace.as_deref() == namespace)
+            .map(|func| {
+                format!(
+                    "{}{}\n\n{}",
+                    func.comments, func.signature, func.companion_field_signature,
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n\n")
+    };
+    let namespaces = dart_namespaces(dart_funcs);
+    let namespace_getters = namespaces
+        .iter()
+        .map(|namespace| {
+            format!(
+                "{} get {namespace};",
+                dart_namespace_class_name(dart_api_class_name, namespace)
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n\n");
+    let namespace_classes = namespaces
+        .iter()
+        .map(|namespace| {
+            format!(
+                "abstract class {} {{
+                    {}
+                }}",
+                dart_namespace_class_name(dart_api_class_name, namespace),
+                signatures(Some(namespace)),
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n\n");
     format!(
         "
+        {6}
+
         abstract class {0} {{
             {1}
+
+            {2}
         }}
 
-        {2}
+        {3}
+
+        {4}
+
+        {5}
         ",
         dart_api_class_name,
-        dart_funcs
-            .iter()
-            .map(|func| format!(
-                "{}{}\n\n{}",
-                func.comments, func.signature, func.companion_field_signature,
-            ))
-            .collect::<Vec<_>>()
-            .join("\n\n"),
+        namespace_getters,
+        signatures(None),
+        namespace_classes,
+        generate_functions_enum(dart_api_class_name, dart_funcs),
         dart_structs.join("\n\n"),
+        consts,
     )
 }
 
+/// Dart does not allow empty enums, so a block without functions has none.
+fn generate_functions_enum(dart_api_class_name: &str, dart_funcs: &[GeneratedApiFunc]) -> String {
+    let values = dart_funcs
+        .iter()
+        .filter_map(|func| func.function_name.as_deref())
+        .collect::<Vec<_>>();
+    if values.is_empty() {
+        return String::new();
+    }
+    format!(
+        "/// Every function of [{dart_api_class_name}], see [FlutterRustBridgeTaskConstMeta.function].
+        enum {} {{
+            {}
+        }}",
+        dart_functions_enum_name(dart_api_class_name),
+        values.join(",\n"),
+    )
+}
+
+pub(crate) fn dart_functions_enum_name(dart_api_class_name: &str) -> String {
+    format!("{dart_api_class_name}Function")
+}
+
+/// The namespaces of `dart_funcs`, in the order of their first function.
+fn dart_namespaces(dart_funcs: &[GeneratedApiFunc]) -> Vec<&str> {
+    dart_funcs
+        .iter()
+        .filter_map(|func| func.namespace.as_deref())
+        .unique()
+        .collect()
+}
+
+fn dart_namespace_class_name(dart_api_class_name: &str, namespace: &str) -> String {
+    format!("{dart_api_class_name}{}", namespace.to_case(Case::Pascal))
+}
+
 fn section_header(header: &str) -> String {
     format!("// Section: {header}\n")
 }
@@ -349,6 +489,12 @@ fn generate_dart_implementation_body(spec: &DartApiSpec, config: &Opts) -> Acc<D
         },
     });
 
+    let namespaces = dart_namespaces(dart_funcs);
+    lines.extend(namespaces.iter().map(|namespace| {
+        let class_name = dart_namespace_class_name(&dart_api_class_name, namespace);
+        format!("late final {class_name} {namespace} = _{class_name}Impl(this);")
+    }));
+
     lines.extend(dart_funcs.iter().map(|func| {
         format!(
             "{}\n\n{}",
@@ -366,6 +512,25 @@ fn generate_dart_implementation_body(spec: &DartApiSpec, config: &Opts) -> Acc<D
 
     lines.push("}\n".into());
 
+    lines.extend(namespaces.iter().map(|namespace| {
+        let class_name = dart_namespace_class_name(&dart_api_class_name, namespace);
+        let forwards = dart_funcs
+            .iter()
+            .filter(|func| func.namespace.as_deref() == Some(namespace))
+            .map(|func| func.forwarding_implementation.clone())
+            .collect::<Vec<_>>()
+            .join("\n\n");
+        format!(
+            "class _{class_name}Impl implements {class_name} {{
+                final {dart_api_impl_class_name} _api;
+                _{class_name}Impl(this._api);
+
+                {forwards}
+            }}
+            "
+        )
+    }));
+
     lines.push_all(section_header("api2wire"));
     lines.push_acc(dart_api2wire_funcs.clone());
 
@@ -482,6 +647,13 @@ pub(crate) struct GeneratedApiFunc {
     comments: String,
     companion_field_signature: String,
     companion_field_implementation: String,
+    /// The Dart namespace whose class exposes the function, see [IrFunc::dart_namespace].
+    /// The function itself is then a private method of the `_Impl` class.
+    namespace: Option<String>,
+    /// The public method of the namespace class, forwarding to the private one.
+    forwarding_implementation: String,
+    /// The value of the [dart_functions_enum_name] enum naming the function, if it is one.
+    function_name: Option<String>,
 }
 
 fn generate_api2wire_func(ty: &IrType, ir_file: &IrFile, config: &Opts) -> Acc<String> {
@@ -575,6 +747,15 @@ fn gen_wire2api_simple_type_cast(s: &str) -> String {
     format!("return raw as {s};")
 }
 
+/// Converts an iterable into the list type expected by `api2wire_*` of the given list.
+fn gen_api2wire_list(list: &IrType, iterable: &str) -> String {
+    let list_expr = match list {
+        PrimitiveList(_) => format!("{}.fromList({iterable}.toList())", list.dart_api_type()),
+        _ => format!("{iterable}.toList()"),
+    };
+    format!("api2wire_{}({list_expr})", list.safe_ident())
+}
+
 /// A trailing newline is included if comments is not empty.
 fn dart_comments(comments: &[IrComment]) -> String {
     let mut comments = comments
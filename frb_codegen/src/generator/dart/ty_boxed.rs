@@ -1,6 +1,6 @@
 use crate::generator::dart::gen_wire2api_simple_type_cast;
 use crate::generator::dart::ty::*;
-use crate::ir::IrType::{DartOpaque, Delegate, EnumRef, Primitive, RustOpaque, StructRef};
+use crate::ir::IrType::{DartOpaque, Delegate, EnumRef, Primitive, RustOpaque, StructRef, Tuple};
 use crate::ir::*;
 use crate::target::Acc;
 use crate::type_dart_generator_struct;
@@ -76,11 +76,17 @@ impl TypeDartGeneratorTrait for TypeBoxedGenerator<'_> {
     fn wire2api_body(&self) -> String {
         match &*self.ir.inner {
             StructRef(_)
+            | Tuple(_)
             | DartOpaque(_)
             | RustOpaque(_)
             | EnumRef(_)
             | Primitive(IrTypePrimitive::I64 | IrTypePrimitive::U64 | IrTypePrimitive::Usize)
-            | Delegate(IrTypeDelegate::Array(_) | IrTypeDelegate::PrimitiveEnum { .. }) => {
+            | Delegate(
+                IrTypeDelegate::Array(_)
+                | IrTypeDelegate::PrimitiveEnum { .. }
+                | IrTypeDelegate::Char { .. }
+                | IrTypeDelegate::NonZero(_),
+            ) => {
                 format!("return _wire2api_{}(raw);", self.ir.inner.safe_ident())
             }
             #[cfg(feature = "chrono")]
@@ -1,5 +1,5 @@
-use crate::generator::dart::gen_wire2api_simple_type_cast;
 use crate::generator::dart::ty::*;
+use crate::generator::dart::{gen_api2wire_list, gen_wire2api_simple_type_cast};
 use crate::ir::*;
 use crate::target::Acc;
 use crate::type_dart_generator_struct;
@@ -8,7 +8,7 @@ type_dart_generator_struct!(TypeDelegateGenerator, IrTypeDelegate);
 
 impl TypeDartGeneratorTrait for TypeDelegateGenerator<'_> {
     fn api2wire_body(&self) -> Acc<Option<String>> {
-        match self.ir {
+        match &self.ir {
             IrTypeDelegate::Array(ref array) => match array {
                 IrTypeDelegateArray::GeneralArray { .. } => Acc::distribute(Some(format!(
                     "return api2wire_{}(raw);",
@@ -35,7 +35,9 @@ impl TypeDartGeneratorTrait for TypeDelegateGenerator<'_> {
                 wasm: Some("return raw;".into()),
                 ..Default::default()
             },
-            IrTypeDelegate::ZeroCopyBufferVecPrimitive(_) => {
+            IrTypeDelegate::ZeroCopyBufferVecPrimitive(_)
+            | IrTypeDelegate::SliceRef(_)
+            | IrTypeDelegate::SizeList(_) => {
                 // In this case, even though the body is the same, their types are different
                 // and must be split.
                 let body = format!(
@@ -56,12 +58,31 @@ impl TypeDartGeneratorTrait for TypeDelegateGenerator<'_> {
                 wasm: Some("return raw;".into()),
                 ..Default::default()
             },
-            IrTypeDelegate::PrimitiveEnum { ref repr, .. } => {
-                format!("return api2wire_{}(raw.index);", repr.safe_ident()).into()
+            IrTypeDelegate::PrimitiveEnum { ref ir, ref repr } => {
+                let value = if ir.get(self.context.ir_file).has_discriminants() {
+                    "discriminant"
+                } else {
+                    "index"
+                };
+                format!("return api2wire_{}(raw.{value});", repr.safe_ident()).into()
+            }
+            IrTypeDelegate::I128 | IrTypeDelegate::U128 => Acc::distribute(Some(format!(
+                "return api2wire_uint_8_list(api2wireInt128(raw, signed: {}));",
+                self.ir == IrTypeDelegate::I128
+            ))),
+            IrTypeDelegate::Char { as_int: true } => "return api2wire_u32(raw);".into(),
+            IrTypeDelegate::NonZero(inner) => {
+                format!("return api2wire_{}(raw);", inner.safe_ident()).into()
+            }
+            IrTypeDelegate::Char { as_int: false } => {
+                "return api2wire_u32(api2wireChar(raw));".into()
             }
             #[cfg(feature = "chrono")]
             IrTypeDelegate::Time(ref ir) => match ir {
-                IrTypeTime::Utc | IrTypeTime::Local | IrTypeTime::Naive => Acc {
+                IrTypeTime::Utc
+                | IrTypeTime::Local
+                | IrTypeTime::FixedOffset
+                | IrTypeTime::Naive => Acc {
                     io: Some("return api2wire_i64(raw.microsecondsSinceEpoch);".into()),
                     wasm: Some("return api2wire_i64(raw.millisecondsSinceEpoch);".into()),
                     ..Default::default()
@@ -80,6 +101,66 @@ impl TypeDartGeneratorTrait for TypeDelegateGenerator<'_> {
             IrTypeDelegate::Uuids => Acc::distribute(Some(
                 "return api2wire_uint_8_list(api2wireConcatenateBytes(raw));".into(),
             )),
+            #[cfg(feature = "bytes")]
+            IrTypeDelegate::Bytes => {
+                Acc::distribute(Some("return api2wire_uint_8_list(raw);".into()))
+            }
+            #[cfg(feature = "rust_decimal")]
+            IrTypeDelegate::Decimal => {
+                Acc::distribute(Some("return api2wire_String(raw.toString());".into()))
+            }
+            IrTypeDelegate::BTreeSet(_) => Acc::distribute(Some(format!(
+                "return {};",
+                gen_api2wire_list(&self.ir.get_delegate(), "raw")
+            ))),
+            IrTypeDelegate::Weak(opaque) => Acc::distribute(Some(format!(
+                "return api2wire_{}(raw);",
+                opaque.weak().safe_ident()
+            ))),
+            IrTypeDelegate::Instant => Acc::distribute(Some(format!(
+                "return api2wire_{}(raw);",
+                IrTypeRustOpaque::instant().safe_ident()
+            ))),
+            IrTypeDelegate::Arc(opaque)
+            | IrTypeDelegate::BoxDyn(opaque)
+            | IrTypeDelegate::ImplTrait(opaque)
+            | IrTypeDelegate::DynRef(opaque) => Acc::distribute(Some(format!(
+                "return api2wire_{}(raw);",
+                opaque.safe_ident()
+            ))),
+            IrTypeDelegate::Backtrace | IrTypeDelegate::CowStr => {
+                Acc::distribute(Some("return api2wire_String(raw);".to_owned()))
+            }
+            IrTypeDelegate::CowBytes => {
+                Acc::distribute(Some("return api2wire_uint_8_list(raw);".to_owned()))
+            }
+            IrTypeDelegate::IpAddr | IrTypeDelegate::SocketAddr => Acc::distribute(Some(format!(
+                "return api2wire_uint_8_list(api2wire{}(raw));",
+                self.ir.dart_api_type()
+            ))),
+            IrTypeDelegate::CustomCodec(codec) => Acc::distribute(Some(format!(
+                "return api2wire_uint_8_list({}.encode(raw));",
+                codec.dart_codec()
+            ))),
+            IrTypeDelegate::PathBuf | IrTypeDelegate::PathRef => Acc::distribute(Some(
+                "return api2wire_uint_16_list(Uint16List.fromList(raw.codeUnits));".to_owned(),
+            )),
+            IrTypeDelegate::SharedBuffer => {
+                Acc::distribute(Some("return api2wire_usize(raw.address);".to_owned()))
+            }
+            IrTypeDelegate::SystemTime => Acc {
+                io: Some("return api2wire_i64(raw.microsecondsSinceEpoch);".into()),
+                wasm: Some("return api2wire_i64(raw.millisecondsSinceEpoch);".into()),
+                ..Default::default()
+            },
+            IrTypeDelegate::Range { inclusive, .. } => {
+                let list = self.ir.get_delegate();
+                Acc::distribute(Some(format!(
+                    "return api2wire_{}({}.fromList(api2wireRange(raw, inclusive: {inclusive})));",
+                    list.safe_ident(),
+                    list.dart_api_type()
+                )))
+            }
         }
     }
 
@@ -100,38 +181,118 @@ impl TypeDartGeneratorTrait for TypeDelegateGenerator<'_> {
 
             IrTypeDelegate::ZeroCopyBufferVecPrimitive(
                 IrTypePrimitive::I64 | IrTypePrimitive::U64,
-            ) => {
+            )
+            | IrTypeDelegate::SliceRef(_)
+            | IrTypeDelegate::SizeList(_) => {
                 format!(
                     "return _wire2api_{}(raw);",
                     self.ir.get_delegate().safe_ident()
                 )
             }
-            IrTypeDelegate::String | IrTypeDelegate::ZeroCopyBufferVecPrimitive(_) => {
+            IrTypeDelegate::String
+            | IrTypeDelegate::Backtrace
+            | IrTypeDelegate::CowStr
+            | IrTypeDelegate::CowBytes
+            | IrTypeDelegate::ZeroCopyBufferVecPrimitive(_) => {
                 gen_wire2api_simple_type_cast(&self.ir.dart_api_type())
             }
+            IrTypeDelegate::PathBuf | IrTypeDelegate::PathRef => {
+                "return String.fromCharCodes(_wire2api_uint_16_list(raw));".to_owned()
+            }
             IrTypeDelegate::StringList => {
                 "return (raw as List<dynamic>).cast<String>();".to_owned()
             }
+            IrTypeDelegate::IpAddr | IrTypeDelegate::SocketAddr => format!(
+                "return wire2api{}(_wire2api_uint_8_list(raw));",
+                self.ir.dart_api_type()
+            ),
+            IrTypeDelegate::CustomCodec(codec) => format!(
+                "return {}.decode(_wire2api_uint_8_list(raw));",
+                codec.dart_codec()
+            ),
             IrTypeDelegate::PrimitiveEnum { ir, .. } => {
-                format!("return {}.values[raw];", ir.dart_api_type())
+                let enu = ir.get(self.context.ir_file);
+                if enu.has_discriminants() {
+                    let cases = (enu.variants().iter().zip(enu.wire_values()))
+                        .map(|(variant, value)| {
+                            format!(
+                                "case {value}: return {}.{};",
+                                enu.name,
+                                variant.dart_value()
+                            )
+                        })
+                        .collect::<String>();
+                    format!(
+                        "switch (raw as int) {{
+                            {cases}
+                            default: throw ArgumentError.value(raw, 'raw', 'Unknown discriminant of {}');
+                        }}",
+                        enu.name
+                    )
+                } else {
+                    format!("return {}.values[raw];", ir.dart_api_type())
+                }
+            }
+            IrTypeDelegate::I128 | IrTypeDelegate::U128 => format!(
+                "return wire2apiInt128(_wire2api_uint_8_list(raw), signed: {});",
+                self.ir == IrTypeDelegate::I128
+            ),
+            IrTypeDelegate::Char { as_int: true } => "return raw as int;".to_owned(),
+            IrTypeDelegate::NonZero(inner) => {
+                format!("return _wire2api_{}(raw);", inner.safe_ident())
+            }
+            IrTypeDelegate::Char { as_int: false } => {
+                "return String.fromCharCode(raw as int);".to_owned()
             }
             #[cfg(feature = "chrono")]
             IrTypeDelegate::Time(ir) => match ir {
-                IrTypeTime::Local | IrTypeTime::Naive | IrTypeTime::Utc => {
-                    let is_utc = ir == &IrTypeTime::Naive || ir == &IrTypeTime::Utc;
+                IrTypeTime::Local
+                | IrTypeTime::Naive
+                | IrTypeTime::Utc
+                | IrTypeTime::FixedOffset => {
+                    let is_utc = ir != &IrTypeTime::Local;
                     format!("return wire2apiTimestamp(ts: _wire2api_i64(raw), isUtc: {is_utc});")
                 }
                 IrTypeTime::Duration => "return wire2apiDuration(_wire2api_i64(raw));".to_owned(),
             },
             #[cfg(feature = "uuid")]
-            IrTypeDelegate::Uuid => {
-                "return UuidValue.fromByteList(_wire2api_uint_8_list(raw));".to_owned()
-            }
+            IrTypeDelegate::Uuid => "return wire2apiUuid(_wire2api_uint_8_list(raw));".to_owned(),
             #[cfg(feature = "uuid")]
             IrTypeDelegate::Uuids => "
             final bytes = _wire2api_uint_8_list(raw);
             return wire2apiUuids(bytes);"
                 .to_owned(),
+            #[cfg(feature = "bytes")]
+            IrTypeDelegate::Bytes => gen_wire2api_simple_type_cast(&self.ir.dart_api_type()),
+            #[cfg(feature = "rust_decimal")]
+            IrTypeDelegate::Decimal => "return Decimal.parse(raw as String);".to_owned(),
+            IrTypeDelegate::BTreeSet(inner) => format!(
+                "return (raw as List<dynamic>).map(_wire2api_{}).toSet();",
+                inner.safe_ident()
+            ),
+            IrTypeDelegate::Arc(opaque)
+            | IrTypeDelegate::BoxDyn(opaque)
+            | IrTypeDelegate::ImplTrait(opaque)
+            | IrTypeDelegate::DynRef(opaque) => {
+                format!("return _wire2api_{}(raw);", opaque.safe_ident())
+            }
+            IrTypeDelegate::Weak(opaque) => {
+                format!("return _wire2api_{}(raw);", opaque.weak().safe_ident())
+            }
+            IrTypeDelegate::Instant => format!(
+                "return _wire2api_{}(raw);",
+                IrTypeRustOpaque::instant().safe_ident()
+            ),
+            IrTypeDelegate::SystemTime => {
+                "return wire2apiTimestamp(ts: _wire2api_i64(raw), isUtc: true);".to_owned()
+            }
+            IrTypeDelegate::Range { inclusive, .. } => format!(
+                "return wire2apiRange(_wire2api_{}(raw), inclusive: {inclusive});",
+                self.ir.get_delegate().safe_ident()
+            ),
+            IrTypeDelegate::SharedBuffer => {
+                panic!("A `SharedBuffer` can only be an argument, it cannot be sent to Dart")
+            }
         }
     }
 
@@ -148,8 +309,10 @@ impl TypeDartGeneratorTrait for TypeDelegateGenerator<'_> {
                 class {0} extends NonGrowableListView<{1}> {{
                     static const arraySize = {2};
                     {0}({3} inner)
-                        : assert(inner.length == arraySize),
-                          super(inner);
+                        : super(inner.length == arraySize
+                              ? inner
+                              : throw ArgumentError.value(inner, 'inner',
+                                  'expected length $arraySize but see ${{inner.length}}'));
                     {0}.unchecked({3} inner)
                         : super(inner);
                     {4}
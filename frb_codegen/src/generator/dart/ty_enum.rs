@@ -1,6 +1,7 @@
 use itertools::Itertools;
 
 use crate::generator::dart::dart_comments;
+use crate::generator::dart::json::{enum_json_methods, supports_json};
 use crate::generator::dart::ty::*;
 use crate::ir::*;
 use crate::target::Acc;
@@ -8,6 +9,13 @@ use crate::type_dart_generator_struct;
 
 type_dart_generator_struct!(TypeEnumRefGenerator, IrTypeEnumRef);
 
+impl TypeEnumRefGenerator<'_> {
+    fn has_json(&self) -> bool {
+        self.context.config.dart_json
+            && supports_json(&IrType::EnumRef(self.ir.clone()), self.context.ir_file)
+    }
+}
+
 impl TypeDartGeneratorTrait for TypeEnumRefGenerator<'_> {
     fn api2wire_body(&self) -> Acc<Option<String>> {
         let variants = (self.ir.get(self.context.ir_file).variants())
@@ -163,11 +171,8 @@ impl TypeDartGeneratorTrait for TypeEnumRefGenerator<'_> {
                 .map(|variant| {
                     let args = match &variant.kind {
                         IrVariantKind::Value => "".to_owned(),
-                        IrVariantKind::Struct(IrStruct {
-                            is_fields_named: false,
-                            fields,
-                            ..
-                        }) => {
+                        IrVariantKind::Struct(st) if !st.is_fields_named => {
+                            let fields = &st.fields;
                             let types = fields.iter().map(|field| &field.ty).collect::<Vec<_>>();
                             let split = optional_boundary_index(&types);
                             let types = fields
@@ -216,13 +221,22 @@ impl TypeDartGeneratorTrait for TypeEnumRefGenerator<'_> {
                     )
                 })
                 .collect::<Vec<_>>();
+            let json_methods = if self.has_json() {
+                format!("const {}._();\n\n{}", self.ir.name, enum_json_methods(src))
+            } else {
+                String::new()
+            };
             format!(
-                "@freezed
+                "{2}@freezed
                 class {0} with _${0} {{
                     {1}
+
+                    {3}
                 }}",
                 self.ir.name,
-                variants.join("\n")
+                variants.join("\n"),
+                comments,
+                json_methods,
             )
         } else {
             let variants = src
@@ -232,16 +246,41 @@ impl TypeDartGeneratorTrait for TypeEnumRefGenerator<'_> {
                     format!(
                         "{}{},",
                         dart_comments(&variant.comments),
-                        variant.name.rust_style()
+                        variant.dart_value()
                     )
                 })
                 .collect::<Vec<_>>()
                 .join("\n");
+            // Enums cannot have members before Dart 2.17, and are decoded with `values.byName`.
+            let json_extension = if self.has_json() {
+                format!(
+                    "extension {0}Json on {0} {{
+                        String toJson() => name;
+                    }}",
+                    self.ir.name
+                )
+            } else {
+                String::new()
+            };
+            let discriminant_extension = if src.has_discriminants() {
+                format!(
+                    "extension {0}Discriminant on {0} {{
+                        /// The discriminant of the variant in Rust.
+                        int get discriminant => const [{1}][index];
+                    }}",
+                    self.ir.name,
+                    src.wire_values().iter().join(", ")
+                )
+            } else {
+                String::new()
+            };
             format!(
                 "{}enum {} {{
                     {}
-                }}",
-                comments, self.ir.name, variants
+                }}
+
+                {}{}",
+                comments, self.ir.name, variants, json_extension, discriminant_extension
             )
         }
     }
@@ -11,12 +11,21 @@ impl TypeDartGeneratorTrait for TypeGeneralListGenerator<'_> {
         let ident = self.ir.safe_ident();
         let context = self.context.config.block_index;
         let inner = self.ir.inner.safe_ident();
+        // E.g. a `Decimal`, which is sent as a separately allocated string, or a `char`, which
+        // is a plain primitive on the wire.
+        let is_assigned = matches!(*self.ir.inner, IrType::Delegate(_))
+            && (!self.ir.inner.rust_ptr_modifier().is_empty() || self.ir.inner.is_primitive());
+        let fill = if self.ir.inner.is_list_like() || is_assigned {
+            format!("ans.ref.ptr[i] = api2wire_{inner}(raw[i]);")
+        } else {
+            format!("_api_fill_to_wire_{inner}(raw[i], ans.ref.ptr[i]);")
+        };
 
         Acc {
             io: Some(format!(
                 "final ans = inner.new_{ident}_{context}(raw.length);
                 for (var i = 0; i < raw.length; ++i) {{
-                    _api_fill_to_wire_{inner}(raw[i], ans.ref.ptr[i]);
+                    {fill}
                 }}
                 return ans;
                 "
@@ -32,9 +41,30 @@ impl TypeDartGeneratorTrait for TypeGeneralListGenerator<'_> {
     }
 
     fn wire2api_body(&self) -> String {
-        format!(
-            "return (raw as List<dynamic>).map(_wire2api_{}).toList();",
-            self.ir.inner.safe_ident()
-        )
+        let inner = self.ir.inner.safe_ident();
+        if let IrType::RustOpaque(opaque) = &*self.ir.inner {
+            // Every element owns a reference on the Rust side, so the ones that are not
+            // wrapped yet when a conversion fails are released instead of leaked.
+            return format!(
+                "final list = raw as List<dynamic>;
+                final ans = <{0}>[];
+                try {{
+                    for (final item in list) {{
+                        ans.add(_wire2api_{inner}(item));
+                    }}
+                }} catch (_) {{
+                    for (final handle in ans) {{
+                        handle.dispose();
+                    }}
+                    for (final item in list.skip(ans.length + 1)) {{
+                        dropOpaque{0}(FrbOpaqueBase.initPtr(item[0]));
+                    }}
+                    rethrow;
+                }}
+                return ans;",
+                opaque.dart_api_type()
+            );
+        }
+        format!("return (raw as List<dynamic>).map(_wire2api_{inner}).toList();")
     }
 }
@@ -0,0 +1,44 @@
+use crate::generator::dart::gen_api2wire_list;
+use crate::generator::dart::ty::*;
+use crate::ir::*;
+use crate::target::Acc;
+use crate::type_dart_generator_struct;
+
+type_dart_generator_struct!(TypeMapGenerator, IrTypeMap);
+
+impl TypeDartGeneratorTrait for TypeMapGenerator<'_> {
+    fn api2wire_body(&self) -> Acc<Option<String>> {
+        let ident = self.ir.safe_ident();
+        let context = self.context.config.block_index;
+        let keys = gen_api2wire_list(&self.ir.key_list(), "raw.keys");
+        let values = gen_api2wire_list(&self.ir.value_list(), "raw.values");
+
+        Acc {
+            io: Some(format!(
+                "final ans = inner.new_{ident}_{context}();
+                ans.ref.keys = {keys};
+                ans.ref.values = {values};
+                return ans;"
+            )),
+            wasm: self
+                .context
+                .config
+                .wasm_enabled
+                .then(|| format!("return [{keys}, {values}];")),
+            ..Default::default()
+        }
+    }
+
+    fn wire2api_body(&self) -> String {
+        format!(
+            "final arr = raw as List<dynamic>;
+            if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${{arr.length}}');
+            return Map.fromIterables(
+                (arr[0] as List<dynamic>).map(_wire2api_{}),
+                (arr[1] as List<dynamic>).map(_wire2api_{}),
+            );",
+            self.ir.key.safe_ident(),
+            self.ir.value.safe_ident(),
+        )
+    }
+}
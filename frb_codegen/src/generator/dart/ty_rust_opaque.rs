@@ -1,5 +1,10 @@
+use itertools::Itertools;
+
+use crate::generator::dart::dart_comments;
 use crate::generator::dart::ty::*;
+use crate::generator::dart::ty_struct::generate_api_method;
 use crate::ir::*;
+use crate::method_utils::FunctionName;
 use crate::target::Acc;
 use crate::type_dart_generator_struct;
 
@@ -31,24 +36,125 @@ impl TypeDartGeneratorTrait for TypeRustOpaqueGenerator<'_> {
     }
 
     fn structs(&self) -> String {
-        let field_bridge = format!(
-            "final {} bridge;",
-            self.context.config.dart_api_class_name(),
-        );
+        let dart_api_class_name = self.context.config.dart_api_class_name();
+        let field_bridge = format!("final {dart_api_class_name} bridge;");
+        let interfaces = (self.context.ir_file.interfaces.iter())
+            .filter(|it| it.implementors.contains(&self.ir.inner_rust))
+            .collect::<Vec<_>>();
+        let methods = self
+            .context
+            .ir_file
+            .funcs
+            .iter()
+            .filter(|f| {
+                let f = FunctionName::deserialize(&f.name);
+                f.is_method_for_struct(&self.ir.inner_rust)
+                    || f.is_static_method_for_struct(&self.ir.inner_rust)
+            })
+            .map(|func| {
+                let method = generate_api_method(func, dart_api_class_name.clone());
+                let annotation = if interfaces.iter().any(|it| it.has_method(func)) {
+                    "@override\n"
+                } else {
+                    ""
+                };
+                format!(
+                    "{annotation}{}=>{};\n\n",
+                    method.signature, method.implementation
+                )
+            })
+            .collect::<Vec<_>>()
+            .concat();
+        // The handle of a `Weak<T>` is upgraded by the function generated for its type.
+        let upgrade = (self.context.ir_file.funcs.iter())
+            .find(|func| {
+                func.weak_upgrade && func.inputs[0].ty == IrType::RustOpaque(self.ir.clone())
+            })
+            .map(|func| {
+                format!(
+                    "{}{} upgrade({{dynamic hint}}) => bridge.{}(weak: this, hint: hint);",
+                    dart_comments(&func.comments),
+                    func.dart_return_type(),
+                    func.dart_style()
+                )
+            })
+            .unwrap_or_default();
+        let duration_since = (self.context.ir_file.funcs.iter())
+            .find(|func| func.duration_since && self.ir == IrTypeRustOpaque::instant())
+            .map(|func| {
+                format!(
+                    "{}{} durationSince({{required Instant earlier, dynamic hint}}) => bridge.{}(that: this, earlier: earlier, hint: hint);",
+                    dart_comments(&func.comments),
+                    func.dart_return_type(),
+                    func.dart_style()
+                )
+            })
+            .unwrap_or_default();
+        let implements = if interfaces.is_empty() {
+            String::new()
+        } else {
+            format!(
+                " implements {}",
+                interfaces.iter().map(|it| it.name.as_str()).join(", ")
+            )
+        };
         format!(
-            "@sealed class {0} extends FrbOpaque {{
+            "@sealed class {0} extends FrbOpaque{implements} {{
                 {field_bridge}
                     {0}.fromRaw(int ptr, int size, this.bridge) : super.unsafe(ptr, size);
+
+                    /// Creates another handle to the same Rust object, which stays alive until every handle is disposed.
+                    {0} clone() => {0}.fromRaw(shareRaw(), externalSize, bridge);
+
+                    /// Turns a token made by [toSendable], possibly in another isolate, into a handle.
+                    factory {0}.fromSendable(SendableOpaque token, {dart_api_class_name} bridge) =>
+                        {0}.fromRaw(token.takeAddress({0}), token.externalSize, bridge);
                     @override
                     DropFnType get dropFn => bridge.dropOpaque{0};
                     
                     @override
                     ShareFnType get shareFn => bridge.shareOpaque{0};
 
+                    @override
+                    ShareFnType get sendFn => bridge.sendOpaque{0};
+
                     @override
                     OpaqueTypeFinalizer get staticFinalizer => bridge.{0}Finalizer;
+
+                    {methods}
+
+                    {upgrade}
+
+                    {duration_since}
             }}",
             self.ir.dart_api_type()
         )
     }
 }
+
+/// The abstract class of a trait implemented by opaque types, with the signatures of the
+/// methods of their handle classes.
+pub(super) fn generate_interface(
+    interface: &IrInterface,
+    ir_file: &IrFile,
+    dart_api_class_name: &str,
+) -> String {
+    let methods = (interface.methods.iter())
+        .map(|method| {
+            let func = interface.func(ir_file, &interface.implementors[0], method);
+            format!(
+                "{}{};",
+                dart_comments(&func.comments),
+                generate_api_method(func, dart_api_class_name.to_owned()).signature
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n\n");
+    format!(
+        "{}abstract class {} {{
+            {methods}
+        }}",
+        dart_comments(&interface.comments),
+        interface.name
+    )
+}
@@ -1,28 +1,29 @@
+use crate::generator::dart::json::{struct_json_methods, supports_json};
 use crate::generator::dart::ty::*;
 use crate::generator::dart::{dart_comments, dart_metadata, GeneratedApiMethod};
 use crate::ir::*;
 use crate::method_utils::FunctionName;
 use crate::target::Acc;
 use crate::type_dart_generator_struct;
-use convert_case::{Case, Casing};
+use crate::utils::dart_string_literal;
 
 type_dart_generator_struct!(TypeStructRefGenerator, IrTypeStructRef);
 
 impl TypeDartGeneratorTrait for TypeStructRefGenerator<'_> {
     fn api2wire_body(&self) -> Acc<Option<String>> {
+        let src = self.ir.get(self.context.ir_file);
+        let (prepare, raw) = to_raw(src, "raw");
         Acc {
             wasm: self.context.config.wasm_enabled.then(|| {
                 format!(
-                    "return [{}];",
-                    self.ir
-                        .get(self.context.ir_file)
-                        .fields
+                    "{prepare}return [{}];",
+                    src.fields
                         .iter()
                         .map(|field| {
                             format!(
-                                "api2wire_{}(raw.{})",
+                                "api2wire_{}({})",
                                 field.ty.safe_ident(),
-                                field.name.dart_style()
+                                field_value(field, &raw, self.context.ir_file)
                             )
                         })
                         .collect::<Vec<_>>()
@@ -35,28 +36,29 @@ impl TypeDartGeneratorTrait for TypeStructRefGenerator<'_> {
 
     fn api_fill_to_wire_body(&self) -> Option<String> {
         let s = self.ir.get(self.context.ir_file);
+        let (prepare, api_obj) = to_raw(s, "apiObj");
         Some(
-            s.fields
-                .iter()
-                .map(|field| {
-                    if field.ty.is_struct() {
-                        format!(
-                            "_api_fill_to_wire_{}(apiObj.{}, wireObj.{});",
-                            field.ty.safe_ident(),
-                            field.name.dart_style(),
-                            field.name.rust_style(),
-                        )
-                    } else {
-                        format!(
-                            "wireObj.{} = api2wire_{}(apiObj.{});",
-                            field.name.rust_style(),
-                            field.ty.safe_ident(),
-                            field.name.dart_style()
-                        )
-                    }
-                })
-                .collect::<Vec<_>>()
-                .join("\n"),
+            prepare
+                + &s.fields
+                    .iter()
+                    .map(|field| {
+                        let value = field_value(field, &api_obj, self.context.ir_file);
+                        if field.ty.is_struct() {
+                            format!(
+                                "_api_fill_to_wire_{}({value}, wireObj.{});",
+                                field.ty.safe_ident(),
+                                field.name.rust_style(),
+                            )
+                        } else {
+                            format!(
+                                "wireObj.{} = api2wire_{}({value});",
+                                field.name.rust_style(),
+                                field.ty.safe_ident(),
+                            )
+                        }
+                    })
+                    .collect::<Vec<_>>()
+                    .join("\n"),
         )
     }
 
@@ -69,31 +71,63 @@ impl TypeDartGeneratorTrait for TypeStructRefGenerator<'_> {
             f.is_method_for_struct(&src.name) || f.is_static_method_for_struct(&src.name)
         });
         let has_methods = methods.next().is_some();
-        let mut inner = s
-            .fields
-            .iter()
-            .enumerate()
-            .map(|(idx, field)| {
-                format!(
-                    "{}: _wire2api_{}(arr[{}]),",
-                    field.name.dart_style(),
-                    field.ty.safe_ident(),
-                    idx
-                )
-            })
-            .collect::<Vec<_>>();
+        // Flattened fields are decoded first, so that their own fields can be passed on.
+        let mut flattened = String::new();
+        let mut inner = Vec::new();
+        if s.repr_c {
+            let (offsets, _) = s.repr_c_layout();
+            inner.extend(s.fields.iter().zip(offsets).map(|(field, offset)| {
+                s.dart_constructor_arg(&field.name.dart_style(), &repr_c_read(&field.ty, offset))
+            }));
+        } else {
+            for (idx, field) in s.fields.iter().enumerate() {
+                let value = format!("_wire2api_{}(arr[{idx}])", field.ty.safe_ident());
+                match &field.ty {
+                    IrType::StructRef(ty) if field.flatten => {
+                        let local = field.name.dart_style();
+                        flattened += &format!("final {local} = {value};\n");
+                        let inlined = ty
+                            .get(self.context.ir_file)
+                            .dart_fields(self.context.ir_file);
+                        inner.extend((inlined.iter()).map(|f| {
+                            let name = f.name.dart_style();
+                            s.dart_constructor_arg(&name, &format!("{local}.{name}"))
+                        }));
+                    }
+                    _ => inner.push(s.dart_constructor_arg(&field.name.dart_style(), &value)),
+                }
+            }
+        }
+        // Named arguments go after the positional ones.
         if has_methods {
-            inner.insert(0, "bridge: this,".to_string());
+            if s.dart_positional {
+                inner.push("bridge: this,".to_string());
+            } else {
+                inner.insert(0, "bridge: this,".to_string());
+            }
         }
         let inner = inner.join("\n");
 
+        let value = format!("{}({})", s.dart_class_name(), inner);
+        let value = match &s.dart_type {
+            Some(dart_type) => format!("{}({value})", dart_type.from_raw),
+            None => value,
+        };
+        if s.repr_c {
+            let (_, size) = s.repr_c_layout();
+            return format!(
+                "final bytes = raw as Uint8List;
+                if (bytes.length != {size}) throw Exception('unexpected bytes length: expect {size} but see ${{bytes.length}}');
+                final data = ByteData.sublistView(bytes);
+                return {value};"
+            );
+        }
         format!(
             "final arr = raw as List<dynamic>;
                 if (arr.length != {}) throw Exception('unexpected arr length: expect {} but see ${{arr.length}}');
-                return {}({});",
+                {flattened}return {value};",
             s.fields.len(),
             s.fields.len(),
-            s.name, inner,
         )
     }
 
@@ -113,9 +147,16 @@ impl TypeDartGeneratorTrait for TypeStructRefGenerator<'_> {
             .collect::<Vec<_>>();
 
         let has_methods = !methods.is_empty();
+        if has_methods && src.dart_type.is_some() {
+            panic!(
+                "Struct `{}` has a custom Dart type, so it cannot have methods",
+                src.name
+            );
+        }
+        let class_name = src.dart_class_name();
         let methods = methods
             .iter()
-            .map(|func| generate_api_method(func, src, self.context.config.dart_api_class_name()))
+            .map(|func| generate_api_method(func, self.context.config.dart_api_class_name()))
             .collect::<Vec<_>>();
 
         let methods_string = methods
@@ -123,46 +164,78 @@ impl TypeDartGeneratorTrait for TypeStructRefGenerator<'_> {
             .map(|g| format!("{}=>{};\n\n", g.signature.clone(), g.implementation.clone()))
             .collect::<Vec<_>>()
             .concat();
+        let has_json = self.context.config.dart_json
+            && supports_json(&IrType::StructRef(self.ir.clone()), ir_file);
+        let fields = src.dart_fields(ir_file);
+        let assert_message =
+            |condition: &str| dart_string_literal(&format!("Invalid {class_name}: {condition}"));
         let extra_argument = "required this.bridge,".to_string();
         let field_bridge = format!(
             "final {} bridge;",
             self.context.config.dart_api_class_name(),
         );
         if src.using_freezed() {
-            let mut constructor_params = src
-                .fields
+            let constructor_params = fields
                 .iter()
                 .map(|f| {
                     format!(
-                        "{} {} {},",
-                        f.ty.dart_required_modifier(),
+                        "{}{} {} {},",
+                        dart_comments(&f.comments),
+                        if src.dart_positional {
+                            ""
+                        } else {
+                            f.ty.dart_required_modifier()
+                        },
                         f.ty.dart_api_type(),
                         f.name.dart_style()
                     )
                 })
                 .collect::<Vec<_>>();
-            if has_methods {
-                constructor_params.insert(0, extra_argument);
-            }
-            let constructor_params = constructor_params.join("");
+            let constructor_params = constructor_params_list(
+                src,
+                constructor_params,
+                has_methods.then_some(extra_argument),
+            );
+            let asserts = src
+                .dart_asserts
+                .iter()
+                .map(|condition| {
+                    format!(
+                        "@Assert({}, {})\n",
+                        dart_string_literal(condition),
+                        assert_message(condition)
+                    )
+                })
+                .collect::<Vec<_>>()
+                .concat();
 
+            // Members of a `freezed` class need its private constructor.
+            let json_methods = if has_json {
+                format!(
+                    "const {class_name}._();\n\n{}",
+                    struct_json_methods(src, &fields, true)
+                )
+            } else {
+                String::new()
+            };
             format!(
                 "{}{}class {} with _${} {{
-                const factory {}({{{}}}) = _{};
-                {}
+                {}const factory {}({}) = _{};
+                {}{}
             }}",
                 comments,
                 metadata,
-                self.ir.name,
-                self.ir.name,
-                self.ir.name,
+                class_name,
+                class_name,
+                asserts,
+                class_name,
                 constructor_params,
-                self.ir.name,
-                methods_string
+                class_name,
+                methods_string,
+                json_methods,
             )
         } else {
-            let mut field_declarations = src
-                .fields
+            let mut field_declarations = fields
                 .iter()
                 .map(|f| {
                     let comments = dart_comments(&f.comments);
@@ -180,61 +253,173 @@ impl TypeDartGeneratorTrait for TypeStructRefGenerator<'_> {
             }
             let field_declarations = field_declarations.join("\n");
 
-            let mut constructor_params = src
-                .fields
+            let constructor_params = fields
                 .iter()
                 .map(|f| {
                     format!(
                         "{}this.{},",
-                        f.ty.dart_required_modifier(),
+                        if src.dart_positional {
+                            ""
+                        } else {
+                            f.ty.dart_required_modifier()
+                        },
                         f.name.dart_style()
                     )
                 })
                 .collect::<Vec<_>>();
-            if has_methods {
-                constructor_params.insert(0, extra_argument);
-            }
+            let constructor_params = constructor_params_list(
+                src,
+                constructor_params,
+                has_methods.then_some(extra_argument),
+            );
+            let initializers = if src.dart_asserts.is_empty() {
+                String::new()
+            } else {
+                format!(
+                    " : {}",
+                    src.dart_asserts
+                        .iter()
+                        .map(|condition| format!(
+                            "assert({condition}, {})",
+                            assert_message(condition)
+                        ))
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                )
+            };
+
+            let value_equality = if self.context.config.dart_value_equality {
+                generate_value_equality(src, &fields)
+            } else {
+                String::new()
+            };
 
-            let constructor_params = constructor_params.join("");
+            let copy_with = if self.context.config.dart_copy_with {
+                generate_copy_with(src, &fields, has_methods)
+            } else {
+                String::new()
+            };
+
+            let json_methods = if has_json {
+                struct_json_methods(src, &fields, false)
+            } else {
+                String::new()
+            };
 
             format!(
                 "{}{}class {} {{
                 {}
 
-                {}{}({}{}{});
+                {}{}({}){};
 
-                {}
+                {}{}{}{}
             }}",
                 comments,
                 metadata,
-                self.ir.name,
-                if constructor_params.is_empty() {
+                class_name,
+                field_declarations,
+                if constructor_params.is_empty() || src.has_dart_const_constructor(ir_file) {
                     "const "
                 } else {
                     ""
                 },
-                field_declarations,
-                self.ir.name,
-                if constructor_params.is_empty() {
-                    ""
-                } else {
-                    "{"
-                },
+                class_name,
                 constructor_params,
-                if constructor_params.is_empty() {
-                    ""
-                } else {
-                    "}"
-                },
-                methods_string
+                initializers,
+                methods_string,
+                copy_with,
+                value_equality,
+                json_methods,
             )
         }
     }
 }
 
-fn generate_api_method(
+/// The parameter list of the constructor of the class of `src`, from the parameters of its
+/// fields and then of `bridge`, if it has methods, which is always named.
+fn constructor_params_list(src: &IrStruct, fields: Vec<String>, bridge: Option<String>) -> String {
+    let (mut positional, mut named) = if src.dart_positional {
+        (fields, vec![])
+    } else {
+        (vec![], fields)
+    };
+    named.extend(bridge);
+    if !named.is_empty() {
+        positional.push(format!("{{{}}}", named.concat()));
+    }
+    positional.concat()
+}
+
+/// A copy with some fields replaced, see `--dart-copy-with`. A nullable parameter defaults to
+/// `copyWithUnset` instead of `null`, so that passing `null` explicitly clears the field.
+fn generate_copy_with(src: &IrStruct, fields: &[IrField], has_methods: bool) -> String {
+    let params = fields
+        .iter()
+        .map(|f| match &f.ty {
+            IrType::Optional(_) => format!("Object? {} = copyWithUnset,", f.name.dart_style()),
+            ty => format!("{}? {},", ty.dart_api_type(), f.name.dart_style()),
+        })
+        .collect::<Vec<_>>();
+    let args = fields
+        .iter()
+        .map(|f| {
+            let name = f.name.dart_style();
+            let value = match &f.ty {
+                IrType::Optional(_) => format!(
+                    "identical({name}, copyWithUnset) ? this.{name} : {name} as {}",
+                    f.ty.dart_api_type()
+                ),
+                _ => format!("{name} ?? this.{name}"),
+            };
+            src.dart_constructor_arg(&name, &value)
+        })
+        .collect::<Vec<_>>();
+    format!(
+        "{name} copyWith({params}) => {name}({args}{bridge});\n\n",
+        name = src.dart_class_name(),
+        params = if params.is_empty() {
+            String::new()
+        } else {
+            format!("{{{}}}", params.concat())
+        },
+        bridge = if has_methods { "bridge: bridge," } else { "" },
+        args = args.concat(),
+    )
+}
+
+/// Structural `==` and `hashCode`, see `--dart-value-equality`. Lists, including typed data,
+/// maps and sets are compared element by element.
+fn generate_value_equality(src: &IrStruct, fields: &[IrField]) -> String {
+    let names = fields
+        .iter()
+        .map(|f| f.name.dart_style())
+        .collect::<Vec<_>>();
+    let equals = names
+        .iter()
+        .map(|name| format!("&& const DeepCollectionEquality().equals({name}, other.{name})"))
+        .collect::<Vec<_>>()
+        .join("");
+    let hash = names
+        .iter()
+        .map(|name| format!("const DeepCollectionEquality().hash({name}),"))
+        .collect::<Vec<_>>()
+        .join("");
+    format!(
+        "@override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is {name} && other.runtimeType == runtimeType {equals};
+
+        @override
+        int get hashCode => Object.hashAll([runtimeType, {hash}]);",
+        name = src.dart_class_name(),
+    )
+}
+
+/// A method of the Dart class of its struct, which is either a struct or an opaque type,
+/// forwarding to the API class.
+pub(super) fn generate_api_method(
     func: &IrFunc,
-    ir_struct: &IrStruct,
     dart_api_class_name: String,
 ) -> GeneratedApiMethod {
     let f = FunctionName::deserialize(&func.name);
@@ -243,14 +428,7 @@ fn generate_api_method(
         .inputs
         .iter()
         .skip(skip_count) //skip the first as it's the method 'self'
-        .map(|input| {
-            format!(
-                "{}{} {}",
-                input.ty.dart_required_modifier(),
-                input.ty.dart_api_type(),
-                input.name.dart_style()
-            )
-        })
+        .map(|input| input.dart_param(func.required_params))
         .collect::<Vec<_>>();
 
     if f.is_static_method() {
@@ -259,21 +437,11 @@ fn generate_api_method(
 
     let full_func_param_list = [raw_func_param_list, vec!["dynamic hint".to_string()]].concat();
 
-    let static_function_name = f.method_name();
-
     let partial = format!(
         "{} {} {}({{ {} }})",
         if f.is_static_method() { "static" } else { "" },
-        func.mode.dart_return_type(&func.output.dart_api_type()),
-        if f.is_static_method() {
-            if static_function_name == "new" {
-                format!("new{}", ir_struct.name)
-            } else {
-                static_function_name.to_case(Case::Camel)
-            }
-        } else {
-            f.method_name().to_case(Case::Camel)
-        },
+        func.dart_return_type(),
+        func.dart_method_name(),
         full_func_param_list.join(","),
     );
 
@@ -289,19 +457,32 @@ fn generate_api_method(
     let implementation = if f.is_static_method() {
         arg_names.push("hint: hint".to_string());
         let arg_names = arg_names.concat();
-        format!(
-            "bridge.{}({})",
-            func.name.clone().to_case(Case::Camel),
-            arg_names
-        )
+        format!("bridge.{}({})", func.dart_style(), arg_names)
     } else {
         let arg_names = arg_names.concat();
-        format!(
-            "bridge.{}({}: this, {})",
-            func.name.clone().to_case(Case::Camel),
+        let is_opaque = matches!(func.inputs[0].ty, IrType::RustOpaque(_));
+        // The handle is disposed once sent, as Rust takes the object out of it.
+        let this = if func.consumes_receiver && is_opaque {
+            "this..move = true"
+        } else {
+            "this"
+        };
+        let call = format!(
+            "bridge.{}({}: {this}, {})",
+            func.dart_style(),
             func.inputs[0].name.dart_style(),
             arg_names
-        )
+        );
+        match (&func.mode, &func.iterator) {
+            // Closed when the handle is disposed.
+            (IrFuncMode::Stream { broadcast, .. }, _) if is_opaque && !func.consumes_receiver => {
+                format!("bindStream({call}, broadcast: {broadcast})")
+            }
+            (_, Some(IrFuncIterator::Returned { .. })) if is_opaque && !func.consumes_receiver => {
+                format!("bindStream({call})")
+            }
+            _ => call,
+        }
     };
 
     GeneratedApiMethod {
@@ -309,3 +490,58 @@ fn generate_api_method(
         implementation,
     }
 }
+
+/// The Dart expression holding `field` of the struct `obj`. A flattened field is rebuilt
+/// from the fields inlined in `obj`.
+fn field_value(field: &IrField, obj: &str, ir_file: &IrFile) -> String {
+    let inner = match &field.ty {
+        IrType::StructRef(ty) if field.flatten => ty.get(ir_file),
+        _ => return format!("{obj}.{}", field.name.dart_style()),
+    };
+    if (ir_file.funcs.iter()).any(|f| {
+        let f = FunctionName::deserialize(&f.name);
+        f.is_method_for_struct(&inner.name) || f.is_static_method_for_struct(&inner.name)
+    }) {
+        panic!(
+            "Struct `{}` has methods, so it cannot be flattened",
+            inner.name
+        );
+    }
+    let args = (inner.dart_fields(ir_file).iter())
+        .map(|f| {
+            let name = f.name.dart_style();
+            inner.dart_constructor_arg(&name, &format!("{obj}.{name}"))
+        })
+        .collect::<Vec<_>>()
+        .join("");
+    format!("{}({args})", inner.dart_class_name())
+}
+
+/// The statement converting `obj` for structs with a custom Dart type, and the expression
+/// holding the fields to send.
+fn to_raw(src: &IrStruct, obj: &str) -> (String, String) {
+    match &src.dart_type {
+        Some(dart_type) => (
+            format!("final fields = {}({obj});\n", dart_type.to_raw),
+            "fields".to_owned(),
+        ),
+        None => (String::new(), obj.to_owned()),
+    }
+}
+
+/// Reads the primitive `ty` at `offset` of the `data` of a `#[frb(repr_c)]` struct.
+fn repr_c_read(ty: &IrType, offset: usize) -> String {
+    let getter = match ty {
+        IrType::Primitive(IrTypePrimitive::Bool) => return format!("data.getUint8({offset}) != 0"),
+        IrType::Primitive(IrTypePrimitive::U8) => return format!("data.getUint8({offset})"),
+        IrType::Primitive(IrTypePrimitive::I8) => return format!("data.getInt8({offset})"),
+        IrType::Primitive(IrTypePrimitive::U16) => "getUint16",
+        IrType::Primitive(IrTypePrimitive::I16) => "getInt16",
+        IrType::Primitive(IrTypePrimitive::U32) => "getUint32",
+        IrType::Primitive(IrTypePrimitive::I32) => "getInt32",
+        IrType::Primitive(IrTypePrimitive::F32) => "getFloat32",
+        IrType::Primitive(IrTypePrimitive::F64) => "getFloat64",
+        _ => unreachable!("not a field of a `repr_c` struct: {:?}", ty),
+    };
+    format!("data.{getter}({offset}, Endian.little)")
+}
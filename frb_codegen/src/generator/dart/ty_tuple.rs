@@ -0,0 +1,75 @@
+use crate::generator::dart::ty::*;
+use crate::ir::*;
+use crate::target::Acc;
+use crate::type_dart_generator_struct;
+
+type_dart_generator_struct!(TypeTupleGenerator, IrTypeTuple);
+
+impl TypeDartGeneratorTrait for TypeTupleGenerator<'_> {
+    fn api2wire_body(&self) -> Acc<Option<String>> {
+        Acc {
+            wasm: self.context.config.wasm_enabled.then(|| {
+                format!(
+                    "return [{}];",
+                    self.ir
+                        .values
+                        .iter()
+                        .enumerate()
+                        .map(|(idx, ty)| {
+                            format!(
+                                "api2wire_{}(raw.{})",
+                                ty.safe_ident(),
+                                IrTypeTuple::dart_field(idx)
+                            )
+                        })
+                        .collect::<Vec<_>>()
+                        .join(",")
+                )
+            }),
+            ..Default::default()
+        }
+    }
+
+    fn api_fill_to_wire_body(&self) -> Option<String> {
+        Some(
+            self.ir
+                .values
+                .iter()
+                .enumerate()
+                .map(|(idx, ty)| {
+                    let wire_field = IrTypeTuple::wire_field(idx);
+                    let dart_field = IrTypeTuple::dart_field(idx);
+                    if ty.is_struct() {
+                        format!(
+                            "_api_fill_to_wire_{}(apiObj.{dart_field}, wireObj.{wire_field});",
+                            ty.safe_ident(),
+                        )
+                    } else {
+                        format!(
+                            "wireObj.{wire_field} = api2wire_{}(apiObj.{dart_field});",
+                            ty.safe_ident(),
+                        )
+                    }
+                })
+                .collect::<Vec<_>>()
+                .join("\n"),
+        )
+    }
+
+    fn wire2api_body(&self) -> String {
+        let values = self
+            .ir
+            .values
+            .iter()
+            .enumerate()
+            .map(|(idx, ty)| format!("_wire2api_{}(arr[{idx}]),", ty.safe_ident()))
+            .collect::<Vec<_>>()
+            .join("\n");
+        format!(
+            "final arr = raw as List<dynamic>;
+                if (arr.length != {len}) throw Exception('unexpected arr length: expect {len} but see ${{arr.length}}');
+                return Tuple{len}({values});",
+            len = self.ir.arity(),
+        )
+    }
+}
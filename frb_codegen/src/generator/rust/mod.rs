@@ -4,12 +4,15 @@ mod ty_dart_opaque;
 mod ty_delegate;
 mod ty_enum;
 mod ty_general_list;
+mod ty_map;
 mod ty_optional;
 mod ty_primitive;
 mod ty_primitive_list;
 mod ty_rust_opaque;
 mod ty_struct;
 mod ty_sync_return;
+mod ty_tuple;
+mod wire_size;
 
 pub use ty::*;
 pub use ty_boxed::*;
@@ -17,12 +20,14 @@ pub use ty_dart_opaque::*;
 pub use ty_delegate::*;
 pub use ty_enum::*;
 pub use ty_general_list::*;
+pub use ty_map::*;
 pub use ty_optional::*;
 pub use ty_primitive::*;
 pub use ty_primitive_list::*;
 pub use ty_rust_opaque::*;
 pub use ty_struct::*;
 pub use ty_sync_return::*;
+pub use ty_tuple::*;
 
 use std::collections::HashSet;
 use std::fmt::Display;
@@ -38,6 +43,7 @@ use crate::{ir::*, Opts};
 use itertools::Itertools;
 
 pub const HANDLER_NAME: &str = "FLUTTER_RUST_BRIDGE_HANDLER";
+const INIT_NAME: &str = "frb_init";
 
 pub struct Output {
     pub code: Acc<String>,
@@ -55,9 +61,9 @@ impl Output {
     }
 }
 
-pub fn generate(ir_file: &IrFile, rust_wire_mod: &str, config: &Opts) -> Output {
+pub fn generate(ir_file: &IrFile, rust_wire_mods: &[String], config: &Opts) -> Output {
     let mut generator = Generator::new(config);
-    let code = generator.generate(ir_file, rust_wire_mod);
+    let code = generator.generate(ir_file, rust_wire_mods);
 
     Output {
         code,
@@ -79,7 +85,7 @@ impl<'a> Generator<'a> {
         }
     }
 
-    fn generate(&mut self, ir_file: &IrFile, rust_wire_mod: &str) -> Acc<String> {
+    fn generate(&mut self, ir_file: &IrFile, rust_wire_mods: &[String]) -> Acc<String> {
         let mut lines = Acc::<Vec<_>>::default();
 
         let distinct_input_types = ir_file.distinct_types(true, false);
@@ -89,7 +95,11 @@ impl<'a> Generator<'a> {
         lines.push(code_header());
 
         lines.push(String::new());
-        lines.push(format!("use crate::{rust_wire_mod}::*;"));
+        lines.extend(
+            rust_wire_mods
+                .iter()
+                .map(|rust_wire_mod| format!("use crate::{rust_wire_mod}::*;")),
+        );
         lines.push("use flutter_rust_bridge::*;".to_owned());
         lines.push("use core::panic::UnwindSafe;".to_owned());
         lines.push("use std::sync::Arc;".to_owned());
@@ -99,7 +109,7 @@ impl<'a> Generator<'a> {
         lines.push(self.section_header_comment("imports"));
         lines.extend(self.generate_imports(
             ir_file,
-            rust_wire_mod,
+            rust_wire_mods,
             &distinct_input_types,
             &distinct_output_types,
         ));
@@ -119,7 +129,10 @@ impl<'a> Generator<'a> {
                 .filter_map(|ty| self.generate_wrapper_struct(ty, ir_file)),
         );
         lines.push(self.section_header_comment("static checks"));
-        let static_checks: Vec<_> = distinct_output_types
+        // Mirrors only received from Dart must be checked too, otherwise a variant missing
+        // from the mirror would go unnoticed.
+        let static_checks: Vec<_> = ir_file
+            .distinct_types(true, true)
             .iter()
             .filter_map(|ty| self.generate_static_checks(ty, ir_file))
             .collect();
@@ -155,6 +168,21 @@ impl<'a> Generator<'a> {
                 .map(|ty| self.generate_impl_intodart(ty, ir_file)),
         );
 
+        if self.config.record_wire_size {
+            lines.push(self.section_header_comment("wire sizes"));
+            lines.extend(
+                ir_file
+                    .distinct_types(true, true)
+                    .iter()
+                    .filter_map(|ty| wire_size::generate_wire_size_func(ty, ir_file)),
+            );
+        }
+
+        if let Some(init) = ir_file.funcs.iter().find(|func| func.init) {
+            lines.push(self.section_header_comment("init"));
+            lines.push(generate_init(init));
+        }
+
         lines.push(self.section_header_comment("executor"));
         lines.push(self.generate_executor(ir_file));
 
@@ -215,7 +243,7 @@ impl<'a> Generator<'a> {
     fn generate_imports(
         &self,
         ir_file: &IrFile,
-        rust_wire_mod: &str,
+        rust_wire_mods: &[String],
         distinct_input_types: &[IrType],
         distinct_output_types: &[IrType],
     ) -> impl Iterator<Item = String> {
@@ -230,8 +258,12 @@ impl<'a> Generator<'a> {
             .chain(output_type_imports)
             // Filter out `None` and unwrap
             .flatten()
-            // Don't include imports from the API file
-            .filter(|import| !import.starts_with(&format!("use crate::{rust_wire_mod}::")))
+            // Don't include imports from the API files
+            .filter(|import| {
+                !rust_wire_mods.iter().any(|rust_wire_mod| {
+                    import.starts_with(&format!("use crate::{rust_wire_mod}::"))
+                })
+            })
             // de-duplicate
             .collect::<HashSet<String>>()
             .into_iter()
@@ -271,6 +303,20 @@ impl<'a> Generator<'a> {
         } else {
             Acc::default()
         };
+        if func.cancellation_argument_index.is_some() {
+            params += Acc::new(|_| vec!["cancel_id_: i32".to_owned()]);
+        }
+        params += (func.callbacks)
+            .iter()
+            .map(|callback| {
+                let name = callback.name.rust_style();
+                Acc {
+                    io: format!("{name}: i64"),
+                    wasm: format!("{name}: MessagePort"),
+                    common: format!("{name}: MessagePort"),
+                }
+            })
+            .collect();
         params += (func.inputs)
             .iter()
             .map(|field| {
@@ -295,16 +341,43 @@ impl<'a> Generator<'a> {
             vec![],
             func.inputs
                 .iter()
-                .map(|field| format!("api_{}", field.name.rust_style()))
+                .map(|field| match &field.ty {
+                    // Borrow the trait object out of the received `RustOpaque<Box<dyn Trait>>`.
+                    IrType::Delegate(IrTypeDelegate::DynRef(_)) => {
+                        format!("&**api_{}", field.name.rust_style())
+                    }
+                    IrType::Delegate(IrTypeDelegate::PathRef | IrTypeDelegate::SliceRef(_)) => {
+                        format!("&api_{}", field.name.rust_style())
+                    }
+                    _ if field.borrowed => format!("&api_{}", field.name.rust_style()),
+                    _ => format!("api_{}", field.name.rust_style()),
+                })
                 .collect::<Vec<_>>(),
         ]
         .concat();
-        if let IrFuncMode::Stream { argument_index } = func.mode {
-            inner_func_params.insert(argument_index, "task_callback.stream_sink()".to_string());
+        // Inserted in the order of their positions, so that each index is valid when used.
+        let mut extra_params = (func.mode.stream_argument_index())
+            .map(|index| (index, "task_callback.stream_sink()".to_owned()))
+            .into_iter()
+            .chain(func.drain.map(|index| (index, "sink_.clone()".to_owned())))
+            .chain(
+                func.cancellation_argument_index
+                    .map(|index| (index, "cancellation_token".to_owned())),
+            )
+            .chain(func.callbacks.iter().map(|callback| {
+                (
+                    callback.argument_index,
+                    format!("api_{}", callback.name.rust_style()),
+                )
+            }))
+            .collect::<Vec<_>>();
+        extra_params.sort();
+        for (index, param) in extra_params {
+            inner_func_params.insert(index, param);
         }
         let wrap_info_obj = format!(
             "WrapInfo{{ debug_name: \"{}\", port: {}, mode: FfiCallMode::{} }}",
-            func.name,
+            func.flat_name(),
             if func.mode.has_port_argument() {
                 "Some(port_)"
             } else {
@@ -313,17 +386,98 @@ impl<'a> Generator<'a> {
             func.mode.ffi_call_mode(),
         );
 
-        let code_wire2api = func
-            .inputs
-            .iter()
-            .map(|field| format!("let api_{0} = {0}.wire2api();", field.name.rust_style()))
+        let has_init = ir_file.funcs.iter().any(|func| func.init);
+        let code_wire2api = has_init
+            .then(|| format!("{INIT_NAME}();"))
+            .into_iter()
+            .chain(func.cancellation_argument_index.map(|_| {
+                "let cancellation_token = CancellationToken::register(cancel_id_);".to_owned()
+            }))
+            .chain(func.callbacks.iter().map(|callback| {
+                let name = callback.name.rust_style();
+                match &callback.arg {
+                    Some(arg) => format!(
+                        "let api_{name} = support::dart_callback::<{}>({name});",
+                        arg.rust_api_type()
+                    ),
+                    None => format!(
+                        "let api_{name} = {{ let callback = support::dart_callback::<()>({name}); move || callback(()) }};"
+                    ),
+                }
+            }))
+            .chain(
+                func.inputs
+                    .iter()
+                    .map(|field| format!("let api_{0} = {0}.wire2api();", field.name.rust_style())),
+            )
+            .chain((self.config.record_wire_size && !func.inputs.is_empty()).then(|| {
+                let records = func.inputs.iter().map(|field| {
+                    let name = field.name.rust_style();
+                    format!(
+                        "support::record_wire_size(\"{}\", support::WireDirection::ToRust, Some(\"{name}\"), {});",
+                        func.flat_name(),
+                        wire_size::wire_size(&field.ty, &format!("(&api_{name})"))
+                    )
+                });
+                format!("if support::wire_size_enabled() {{ {} }}", records.collect::<String>())
+            }))
+            .chain(func.drain.map(|_| "let sink_ = StreamSink::buffered();".to_owned()))
             .collect::<Vec<_>>()
             .join("");
 
-        let code_call_inner_func = if f.is_non_static_method() || f.is_static_method() {
+        // The init function has already run, see `generate_init`.
+        let code_call_inner_func = if func.init || func.warm_up {
+            "()".to_owned()
+        } else if func.duration_since {
+            format!(
+                "support::duration_since(&{}, &{})",
+                inner_func_params[0], inner_func_params[1]
+            )
+        } else if func.weak_upgrade {
+            format!("{}.upgrade().map(RustOpaque::from)", inner_func_params[0])
+        } else if !func.dispatch.is_empty() {
+            let args = inner_func_params[1..].join(", ");
+            let arms = (func.dispatch.iter())
+                .map(|target| {
+                    let call = format!("{}({args})", target.rust_call_path);
+                    format!(
+                        "\"{}\" => {},",
+                        target.id,
+                        if target.fallible {
+                            call
+                        } else {
+                            format!("Ok({call})")
+                        }
+                    )
+                })
+                .collect::<String>();
+            let ids = (func.dispatch.iter())
+                .map(|target| format!("\"{}\"", target.id))
+                .join(", ");
+            format!(
+                "match {}.as_str() {{ {arms} id => Err(support::unknown_dispatch_id(id, &[{ids}])) }}",
+                inner_func_params[0]
+            )
+        } else if let Some(IrFuncIterator::Next) = &func.iterator {
+            format!("{}.next_item()", inner_func_params[0])
+        } else if let Some(IrFuncChanges::Stream) = &func.changes {
+            format!(
+                "support::listen_changes(&{}, {})",
+                inner_func_params[0], inner_func_params[1]
+            )
+        } else if f.is_non_static_method() || f.is_static_method() {
             let method_name = if f.is_non_static_method() {
-                inner_func_params[0] = format!("&{}", inner_func_params[0]);
-                FunctionName::deserialize(&func.name).method_name()
+                let method_name = f.method_name();
+                inner_func_params[0] = match &func.inputs[0].ty {
+                    // Only possible if no other handle, in Dart or Rust, shares the object.
+                    IrType::RustOpaque(opaque) if func.consumes_receiver => format!(
+                        "{}.try_unwrap().unwrap_or_else(|_| panic!(\"`{}::{method_name}` takes `self`, but the object is still shared by other handles\"))",
+                        inner_func_params[0], opaque.inner_rust,
+                    ),
+                    _ if func.consumes_receiver => inner_func_params[0].clone(),
+                    _ => format!("&{}", inner_func_params[0]),
+                };
+                method_name
             } else if f.is_static_method() {
                 FunctionName::deserialize(&func.name)
                     .static_method_name()
@@ -331,51 +485,224 @@ impl<'a> Generator<'a> {
             } else {
                 panic!("{} is not a method, nor a static method.", func.name)
             };
-            TypeRustGenerator::new(func.output.clone(), ir_file, self.config).wrap_obj(
-                format!(
-                    r"{}::{}({})",
-                    struct_name.unwrap(),
-                    method_name,
-                    inner_func_params.join(", ")
-                ),
-                func.fallible,
+            format!(
+                r"{}::{}({})",
+                struct_name.unwrap(),
+                method_name,
+                inner_func_params.join(", ")
             )
         } else {
-            TypeRustGenerator::new(func.output.clone(), ir_file, self.config).wrap_obj(
-                format!("{}({})", func.name, inner_func_params.join(", ")),
-                func.fallible,
+            format!(
+                "{}({})",
+                func.rust_call_path(),
+                inner_func_params.join(", ")
             )
         };
-        let code_call_inner_func_result = if func.fallible {
+        // The future is given to the handler, which may spawn it on the async runtime, unless the
+        // call cannot return before it completes.
+        let spawns_future = func.is_async
+            && !func.init
+            && matches!(func.mode, IrFuncMode::Normal | IrFuncMode::Stream { .. })
+            && func.iterator.is_none();
+        // The arguments, including a borrowed receiver, live in the closure until this returns.
+        let code_call_inner_func = if spawns_future {
+            if func.cancellation_argument_index.is_some() {
+                format!("cancellation_token.clone().scope_future({code_call_inner_func}).await")
+            } else {
+                format!("{code_call_inner_func}.await")
+            }
+        } else if func.is_async && !func.init {
+            format!("support::block_on({code_call_inner_func})")
+        } else {
+            code_call_inner_func
+        };
+        // The items are taken from the buffered sink once the function returns.
+        let code_call_inner_func = match (func.drain, func.fallible) {
+            (Some(_), false) => {
+                format!("{{ {code_call_inner_func}; sink_.drain().map(SyncReturn) }}")
+            }
+            (Some(_), true) => {
+                format!("{code_call_inner_func}.and_then(|_| sink_.drain()).map(SyncReturn)")
+            }
+            (None, _) => code_call_inner_func,
+        };
+        // Dart cannot hold Rust borrows, so the borrowed value is copied before the call ends.
+        let code_call_inner_func = match (func.borrowed_output, func.fallible) {
+            (true, false) => format!("{code_call_inner_func}.to_owned()"),
+            (true, true) => format!("{code_call_inner_func}.map(ToOwned::to_owned)"),
+            (false, _) => code_call_inner_func,
+        };
+        // Errors leave the object as it was, so only successful calls notify.
+        let code_call_inner_func = match (&func.changes, func.fallible) {
+            (Some(IrFuncChanges::Notifies), false) => format!(
+                "{{ let output = {code_call_inner_func}; support::notify_changes(&api_that); output }}"
+            ),
+            (Some(IrFuncChanges::Notifies), true) => format!(
+                "{{ let output = {code_call_inner_func}; if output.is_ok() {{ support::notify_changes(&api_that); }} output }}"
+            ),
+            _ => code_call_inner_func,
+        };
+        // Makes the token reachable through `CancellationToken::current` while the call runs.
+        let code_call_inner_func = if func.cancellation_argument_index.is_some() && !spawns_future {
+            format!("cancellation_token.clone().scope(|| {code_call_inner_func})")
+        } else {
+            code_call_inner_func
+        };
+        // The iterator is kept along with the arguments it may borrow, which are moved into it.
+        let code_call_inner_func = if let Some(IrFuncIterator::Returned { .. }) = &func.iterator {
+            let borrowed = func
+                .inputs
+                .iter()
+                .enumerate()
+                .filter(|(index, input)| {
+                    (*index == 0 && f.is_non_static_method() && !func.consumes_receiver)
+                        || input.borrowed
+                        || matches!(
+                            input.ty,
+                            IrType::Delegate(
+                                IrTypeDelegate::PathRef
+                                    | IrTypeDelegate::SliceRef(_)
+                                    | IrTypeDelegate::DynRef(_)
+                            )
+                        )
+                })
+                .map(|(_, input)| format!("api_{},", input.name.rust_style()))
+                .collect::<String>();
+            if borrowed.is_empty() {
+                format!("RustOpaque::new(DartIterator::new({code_call_inner_func}))")
+            } else {
+                format!(
+                    "RustOpaque::new(DartIterator::borrowing(({borrowed}), move |({borrowed})| Box::new({code_call_inner_func})))"
+                )
+            }
+        } else {
+            code_call_inner_func
+        };
+        // A `#[frb(sync)]` function returns the value itself, which the handler takes in a
+        // `SyncReturn`.
+        let code_call_inner_func = match (func.inline, func.fallible) {
+            (true, false) => format!("SyncReturn({code_call_inner_func})"),
+            (true, true) => format!("{code_call_inner_func}.map(SyncReturn)"),
+            (false, _) => code_call_inner_func,
+        };
+        let code_call_inner_func = match &func.error_output {
+            // For streams, this is the error of the items, which the `StreamSink` sends by itself.
+            Some(_) if matches!(func.mode, IrFuncMode::Stream { .. }) || func.drain.is_some() => {
+                code_call_inner_func
+            }
+            Some(error_output) => format!(
+                "{code_call_inner_func}.map_err(|e| support::custom_error({}))",
+                TypeRustGenerator::new(error_output.clone(), ir_file, self.config)
+                    .wrap_obj("e".to_owned(), false)
+            ),
+            None => code_call_inner_func,
+        };
+        // Measured before being converted, for the value to be the one returned by the user.
+        let code_call_inner_func = if self.config.record_wire_size
+            && !matches!(func.mode, IrFuncMode::Stream { .. })
+            && func.drain.is_none()
+        {
+            let record = format!(
+                "support::record_wire_size(\"{}\", support::WireDirection::ToDart, None, {})",
+                func.flat_name(),
+                wire_size::wire_size(&func.output, "output")
+            );
+            if func.fallible {
+                format!(
+                    "{{ let result = {code_call_inner_func}; if support::wire_size_enabled() {{ if let Ok(output) = &result {{ {record}; }} }} result }}"
+                )
+            } else {
+                format!(
+                    "{{ let output = {code_call_inner_func}; if support::wire_size_enabled() {{ let output = &output; {record}; }} output }}"
+                )
+            }
+        } else {
+            code_call_inner_func
+        };
+        // The handler ignores what a stream function returns, so the value is sent beforehand.
+        let code_call_inner_func = match &func.stream_initial {
+            Some(initial) => {
+                let send = format!(
+                    "task_callback.stream_initial({});",
+                    TypeRustGenerator::new(initial.clone(), ir_file, self.config)
+                        .wrap_obj("initial".to_owned(), false)
+                );
+                if func.fallible {
+                    format!("{code_call_inner_func}.map(|initial| {{ {send} }})")
+                } else {
+                    format!("{{ let initial = {code_call_inner_func}; {send} }}")
+                }
+            }
+            None => code_call_inner_func,
+        };
+        // The drained items are already in their Dart form.
+        let code_call_inner_func = if func.drain.is_some() {
             code_call_inner_func
+        } else {
+            TypeRustGenerator::new(func.output.clone(), ir_file, self.config)
+                .wrap_obj(code_call_inner_func, func.fallible)
+        };
+        let code_call_inner_func_result = if func.fallible || func.drain.is_some() {
+            code_call_inner_func.clone()
         } else {
             format!("Ok({code_call_inner_func})")
         };
 
-        let (handler_func_name, return_type, code_closure) = match func.mode {
+        let direct_return_type = func.output.rust_wire_type(Io);
+        let (return_type, body) = match func.mode {
             IrFuncMode::Sync => (
-                "wrap_sync",
                 Some("support::WireSyncReturn"),
                 format!(
-                    "{code_wire2api}
-                    {code_call_inner_func_result}"
+                    "{HANDLER_NAME}.wrap_sync({wrap_info_obj}, move || {{
+                        {code_wire2api}
+                        {code_call_inner_func_result}
+                    }})"
                 ),
             ),
-            IrFuncMode::Normal | IrFuncMode::Stream { .. } => (
-                "wrap",
-                None,
-                format!("{code_wire2api} move |task_callback| {code_call_inner_func_result}"),
+            // Bypasses the handler, since nothing has to be sent to Dart.
+            IrFuncMode::Direct => (
+                (!matches!(func.output, IrType::Primitive(IrTypePrimitive::Unit)))
+                    .then_some(direct_return_type.as_str()),
+                format!(
+                    "support::wrap_direct(\"{}\", move || {{ {code_wire2api} {code_call_inner_func} }})",
+                    func.flat_name()
+                ),
             ),
+            IrFuncMode::Normal | IrFuncMode::Stream { .. } => {
+                let wrap = if spawns_future {
+                    format!(
+                        "{HANDLER_NAME}.wrap_async({wrap_info_obj}, move || {{
+                            {code_wire2api} move |task_callback| async move {{ {code_call_inner_func_result} }}
+                        }})"
+                    )
+                } else {
+                    format!(
+                        "{HANDLER_NAME}.wrap({wrap_info_obj}, move || {{
+                            {code_wire2api} move |task_callback| {code_call_inner_func_result}
+                        }})"
+                    )
+                };
+                (
+                    None,
+                    match &func.worker_pool {
+                        Some(pool) => format!("support::with_worker_pool({pool:?}, || {wrap})"),
+                        None => wrap,
+                    },
+                )
+            }
         };
 
-        let body = format!(
-            "{HANDLER_NAME}.{handler_func_name}({wrap_info_obj}, move || {{ {code_closure} }})"
-        );
         let redirect_body = format!(
             "{}_impl({})",
             func.wire_func_name(),
             (func.mode.has_port_argument().then_some("port_"))
                 .into_iter()
+                .chain(func.cancellation_argument_index.map(|_| "cancel_id_"))
+                .chain(
+                    func.callbacks
+                        .iter()
+                        .map(|callback| callback.name.rust_style())
+                )
                 .chain(func.inputs.iter().map(|arg| arg.name.rust_style()))
                 .collect::<Vec<_>>()
                 .join(","),
@@ -495,6 +822,12 @@ impl<'a> Generator<'a> {
                         )
                     })
             }
+            // Not `Clone`, since the elements are not required to be.
+            IrType::Map(_) | IrType::Tuple(_) | IrType::Delegate(IrTypeDelegate::BTreeSet(_)) => {
+                TypeRustGenerator::new(ty.clone(), ir_file, self.config)
+                    .wrapper_struct()
+                    .map(|wrapper| format!("struct {}({});", wrapper, ty.rust_api_type()))
+            }
             _ => None,
         }
     }
@@ -542,6 +875,24 @@ pub fn generate_import(api_type: &IrType, ir_file: &IrFile, config: &Opts) -> Op
     TypeRustGenerator::new(api_type.clone(), ir_file, config).imports()
 }
 
+/// The function running the `#[frb(init)]` function `init` exactly once, which every wire
+/// function calls first, on the calling thread, before decoding its arguments. Concurrent
+/// callers wait for it to complete, and if it panics, so do all the calls.
+fn generate_init(init: &IrFunc) -> String {
+    let call = format!("{}()", init.rust_call_path());
+    format!(
+        "fn {INIT_NAME}() {{
+            static INIT: std::sync::Once = std::sync::Once::new();
+            INIT.call_once(|| {});
+        }}",
+        if init.is_async {
+            format!("support::block_on({call})")
+        } else {
+            call
+        }
+    )
+}
+
 pub fn generate_list_allocate_func(
     collector: &mut ExternFuncCollector,
     safe_ident: &str,
@@ -550,19 +901,31 @@ pub fn generate_list_allocate_func(
     block_index: BlockIndex,
 ) -> String {
     // let wasm = false;
+    // E.g. a `char` or a primitive enum, which is a plain primitive on the wire.
+    let null_value = if inner.is_primitive() {
+        format!("<{}>::default()", inner.rust_wire_type(Target::Io))
+    } else {
+        format!(
+            "<{}{}>::new_with_null_ptr()",
+            inner.rust_ptr_modifier(),
+            inner.rust_wire_type(Target::Io)
+        )
+    };
     collector.generate(
         &format!("new_{safe_ident}_{block_index}"),
         [("len: i32", "int")],
-        Some(&[
-            list.rust_wire_modifier(Target::Io).as_str(),
-            list.rust_wire_type(Target::Io).as_str()
-        ].concat()),
+        Some(
+            &[
+                list.rust_wire_modifier(Target::Io).as_str(),
+                list.rust_wire_type(Target::Io).as_str(),
+            ]
+            .concat(),
+        ),
         &format!(
-            "let wrap = {} {{ ptr: support::new_leak_vec_ptr(<{}{}>::new_with_null_ptr(), len), len }};
+            "let wrap = {} {{ ptr: support::new_leak_vec_ptr({}, len), len }};
                 support::new_leak_box_ptr(wrap)",
             list.rust_wire_type(Target::Io),
-            inner.rust_ptr_modifier(),
-            inner.rust_wire_type(Target::Io)
+            null_value
         ),
         Io,
     )
@@ -95,12 +95,14 @@ pub enum TypeRustGenerator<'a> {
     PrimitiveList(TypePrimitiveListGenerator<'a>),
     Optional(TypeOptionalGenerator<'a>),
     GeneralList(TypeGeneralListGenerator<'a>),
+    Map(TypeMapGenerator<'a>),
     StructRef(TypeStructRefGenerator<'a>),
     Boxed(TypeBoxedGenerator<'a>),
     EnumRef(TypeEnumRefGenerator<'a>),
     SyncReturn(TypeSyncReturnGenerator<'a>),
     DartOpaque(TypeDartOpaqueGenerator<'a>),
     RustOpaque(TypeRustOpaqueGenerator<'a>),
+    Tuple(TypeTupleGenerator<'a>),
 }
 
 impl<'a> TypeRustGenerator<'a> {
@@ -112,12 +114,14 @@ impl<'a> TypeRustGenerator<'a> {
             PrimitiveList(ir) => TypePrimitiveListGenerator { ir, context }.into(),
             Optional(ir) => TypeOptionalGenerator { ir, context }.into(),
             GeneralList(ir) => TypeGeneralListGenerator { ir, context }.into(),
+            Map(ir) => TypeMapGenerator { ir, context }.into(),
             StructRef(ir) => TypeStructRefGenerator { ir, context }.into(),
             Boxed(ir) => TypeBoxedGenerator { ir, context }.into(),
             EnumRef(ir) => TypeEnumRefGenerator { ir, context }.into(),
             SyncReturn(ir) => TypeSyncReturnGenerator::new(ir, context).into(),
             DartOpaque(ir) => TypeDartOpaqueGenerator { ir, context }.into(),
             RustOpaque(ir) => TypeRustOpaqueGenerator { ir, context }.into(),
+            Tuple(ir) => TypeTupleGenerator { ir, context }.into(),
         }
     }
 }
@@ -1,3 +1,5 @@
+use convert_case::{Case, Casing};
+
 use crate::generator::rust::ty::*;
 use crate::generator::rust::{
     generate_list_allocate_func, ExternFuncCollector, TypeGeneralListGenerator,
@@ -26,6 +28,22 @@ macro_rules! delegate_enum {
 impl TypeRustGeneratorTrait for TypeDelegateGenerator<'_> {
     fn wire2api_body(&self) -> Acc<Option<String>> {
         match &self.ir {
+            IrTypeDelegate::Array(IrTypeDelegateArray::PrimitiveArray { primitive, .. })
+                if !matches!(primitive, IrTypePrimitive::Bool | IrTypePrimitive::Unit) =>
+            {
+                Acc {
+                    io: Some(
+                        "unsafe {
+                            let wrap = support::box_from_leak_ptr(self);
+                            let vec = support::vec_from_leak_ptr(wrap.ptr, wrap.len);
+                            support::from_slice_to_array(&vec)
+                        }"
+                        .into(),
+                    ),
+                    wasm: Some("support::from_slice_to_array(&self)".into()),
+                    ..Default::default()
+                }
+            }
             IrTypeDelegate::Array(array) => {
                 let acc =
                     Some(
@@ -59,11 +77,10 @@ impl TypeRustGeneratorTrait for TypeDelegateGenerator<'_> {
             },
             IrTypeDelegate::PrimitiveEnum { ir, .. } => {
                 let enu = ir.get(self.context.ir_file);
-                let variants =
-                    (enu.variants().iter().enumerate())
-                        .map(|(idx, variant)| format!("{} => {}::{},", idx, enu.name, variant.name))
-                        .collect::<Vec<_>>()
-                        .join("\n");
+                let variants = (enu.variants().iter().zip(enu.wire_values()))
+                    .map(|(variant, value)| format!("{} => {}::{},", value, enu.name, variant.name))
+                    .collect::<Vec<_>>()
+                    .join("\n");
                 format!(
                     "match self {{
                         {}
@@ -73,6 +90,15 @@ impl TypeRustGeneratorTrait for TypeDelegateGenerator<'_> {
                     enu.name
                 ).into()
             },
+            IrTypeDelegate::I128 | IrTypeDelegate::U128 => Acc::distribute(Some(format!(
+                "let bytes: Vec<u8> = self.wire2api(); wire2api_{}(bytes)",
+                self.ir.safe_ident()
+            ))),
+            IrTypeDelegate::Char { .. } => Acc::distribute(Some("wire2api_char(self)".into())),
+            IrTypeDelegate::NonZero(inner) => Acc::distribute(Some(format!(
+                "let value: {} = self.wire2api(); wire2api_non_zero(value)",
+                inner.rust_api_type()
+            ))),
             #[cfg(feature = "chrono")]
             IrTypeDelegate::Time(ir) => {
                 if ir == &IrTypeTime::Duration {
@@ -87,10 +113,12 @@ impl TypeRustGeneratorTrait for TypeDelegateGenerator<'_> {
                     "chrono::NaiveDateTime::from_timestamp_opt(s, ns).expect(\"invalid or out-of-range datetime\")";
                 let codegen_utc = format!("chrono::DateTime::<chrono::Utc>::from_utc({codegen_naive}, chrono::Utc)");
                 let codegen_local = format!("chrono::DateTime::<chrono::Local>::from({codegen_utc})");
+                let codegen_fixed_offset = format!("chrono::DateTime::<chrono::FixedOffset>::from({codegen_utc})");
                 let codegen_conversion = match ir {
                     IrTypeTime::Naive => codegen_naive,
                     IrTypeTime::Utc => codegen_utc.as_str(),
                     IrTypeTime::Local => codegen_local.as_str(),
+                    IrTypeTime::FixedOffset => codegen_fixed_offset.as_str(),
                     IrTypeTime::Duration => unreachable!(),
                 };
                 Acc {
@@ -110,6 +138,90 @@ impl TypeRustGeneratorTrait for TypeDelegateGenerator<'_> {
                     "let multiple: Vec<u8> = self.wire2api(); wire2api_uuids(multiple)".into(),
                 ),
             ),
+            #[cfg(feature = "bytes")]
+            IrTypeDelegate::Bytes => Acc::distribute(Some(
+                "let vec: Vec<u8> = self.wire2api(); wire2api_bytes(vec)".into(),
+            )),
+            IrTypeDelegate::Arc(opaque) => Acc {
+                io: Some(format!(
+                    "let opaque: RustOpaque<{}> = self.wire2api(); opaque.into_arc()",
+                    opaque.inner_rust
+                )),
+                // Covered by `wire2api_jsvalue` instead
+                ..Default::default()
+            },
+            IrTypeDelegate::Weak(opaque) => Acc {
+                io: Some(format!(
+                    "let opaque: RustOpaque<{}> = self.wire2api(); std::sync::Weak::clone(&opaque)",
+                    opaque.weak().inner_rust
+                )),
+                // Covered by `wire2api_jsvalue` instead
+                ..Default::default()
+            },
+            IrTypeDelegate::Instant => Acc {
+                io: Some(
+                    "let opaque: RustOpaque<std::time::Instant> = self.wire2api(); *opaque".into(),
+                ),
+                // Covered by `wire2api_jsvalue` instead
+                ..Default::default()
+            },
+            // Only received as `&dyn Trait`, which borrows from the `RustOpaque` itself.
+            IrTypeDelegate::BoxDyn(_) | IrTypeDelegate::ImplTrait(_) | IrTypeDelegate::DynRef(_) => {
+                Default::default()
+            }
+            // Borrowed from the received `Vec`, see `generate_wire_func`.
+            IrTypeDelegate::SliceRef(_) => Default::default(),
+            IrTypeDelegate::BTreeSet(_) => {
+                let acc = Some(format!(
+                    "let vec: {} = self.wire2api(); vec.into_iter().collect()",
+                    self.ir.get_delegate().rust_api_type()
+                ));
+                // Covered by `wire2api_jsvalue` instead
+                if self.ir.rust_wire_type(Target::Wasm) == "JsValue" {
+                    return Acc {
+                        io: acc,
+                        ..Default::default()
+                    };
+                }
+                Acc::distribute(acc)
+            }
+            IrTypeDelegate::Backtrace => Acc::distribute(Some(
+                "let _: String = self.wire2api(); std::backtrace::Backtrace::disabled()".into(),
+            )),
+            IrTypeDelegate::SystemTime => Acc::distribute(Some("wire2api_system_time(self)".into())),
+            IrTypeDelegate::Range { inclusive, .. } => Acc::distribute(Some(format!(
+                "let bounds: {} = self.wire2api(); wire2api_range{}(bounds)",
+                self.ir.get_delegate().rust_api_type(),
+                if *inclusive { "_inclusive" } else { "" }
+            ))),
+            IrTypeDelegate::SizeList(_) => Acc::distribute(Some(format!(
+                "let list: {} = self.wire2api(); wire2api_size_list(list)",
+                self.ir.get_delegate().rust_api_type(),
+            ))),
+            IrTypeDelegate::SharedBuffer => Acc::distribute(Some(
+                "flutter_rust_bridge::SharedBuffer::from_wire(self)".into(),
+            )),
+            IrTypeDelegate::PathBuf | IrTypeDelegate::PathRef => Acc::distribute(Some(
+                "let units: Vec<u16> = self.wire2api(); wire2api_path(units)".into(),
+            )),
+            IrTypeDelegate::IpAddr | IrTypeDelegate::SocketAddr => Acc::distribute(Some(format!(
+                "let bytes: Vec<u8> = self.wire2api(); wire2api_{}(bytes)",
+                self.ir.safe_ident().to_case(Case::Snake)
+            ))),
+            IrTypeDelegate::CustomCodec(codec) => Acc::distribute(Some(format!(
+                "let bytes: Vec<u8> = self.wire2api(); {}::decode(&bytes)",
+                codec.rust
+            ))),
+            IrTypeDelegate::CowStr => Acc::distribute(Some(
+                "let s: String = self.wire2api(); std::borrow::Cow::Owned(s)".into(),
+            )),
+            #[cfg(feature = "rust_decimal")]
+            IrTypeDelegate::Decimal => Acc::distribute(Some(
+                "let s: String = self.wire2api(); wire2api_decimal(s)".into(),
+            )),
+            IrTypeDelegate::CowBytes => Acc::distribute(Some(
+                "let vec: Vec<u8> = self.wire2api(); std::borrow::Cow::Owned(vec)".into(),
+            )),
         }
     }
 
@@ -147,6 +259,21 @@ impl TypeRustGeneratorTrait for TypeDelegateGenerator<'_> {
     }
 
     fn impl_intodart(&self) -> String {
+        if let IrTypeDelegate::BTreeSet(inner) = &self.ir {
+            let name = self.wrapper_struct().unwrap();
+            let gen =
+                TypeRustGenerator::new(*inner.clone(), self.context.ir_file, self.context.config);
+            let item = gen.convert_to_dart(gen.wrap_obj("v".to_owned(), false));
+            return format!(
+                "impl support::IntoDart for {name} {{
+                    fn into_dart(self) -> support::DartAbi {{
+                        self.0.into_iter().map(|v| {item}).collect::<Vec<_>>().into_dart()
+                    }}
+                }}
+                impl support::IntoDartExceptPrimitive for {name} {{}}
+                "
+            );
+        }
         if let IrTypeDelegate::PrimitiveEnum { ir, .. } = &self.ir {
             let src = ir.get(self.context.ir_file);
             let (name, self_path): (&str, &str) = match &src.wrapper_name {
@@ -154,11 +281,8 @@ impl TypeRustGeneratorTrait for TypeDelegateGenerator<'_> {
                 None => (&src.name, "Self"),
             };
             let self_ref = self.self_access("self".to_owned());
-            let variants = src
-                .variants()
-                .iter()
-                .enumerate()
-                .map(|(idx, variant)| format!("{}::{} => {},", self_path, variant.name, idx))
+            let variants = (src.variants().iter().zip(src.wire_values()))
+                .map(|(variant, value)| format!("{}::{} => {},", self_path, variant.name, value))
                 .collect::<Vec<_>>()
                 .join("\n");
             return format!(
@@ -189,16 +313,105 @@ impl TypeRustGeneratorTrait for TypeDelegateGenerator<'_> {
             }
             #[cfg(feature = "chrono")]
             IrTypeDelegate::Time(_) => "Wire2Api::<i64>::wire2api(self).wire2api()".into(),
+            IrTypeDelegate::SystemTime => "Wire2Api::<i64>::wire2api(self).wire2api()".into(),
+            IrTypeDelegate::Range { inclusive, .. } => format!(
+                "let bounds: {} = self.wire2api(); wire2api_range{}(bounds)",
+                self.ir.get_delegate().rust_api_type(),
+                if *inclusive { "_inclusive" } else { "" }
+            )
+            .into(),
+            IrTypeDelegate::SizeList(_) => format!(
+                "let list: {} = self.wire2api(); wire2api_size_list(list)",
+                self.ir.get_delegate().rust_api_type(),
+            )
+            .into(),
             #[cfg(feature = "uuid")]
             IrTypeDelegate::Uuid | IrTypeDelegate::Uuids => {
                 "self.unchecked_into::<js_sys::Uint8Array>().to_vec().into_boxed_slice().wire2api()"
                     .into()
             }
+            #[cfg(feature = "bytes")]
+            IrTypeDelegate::Bytes => {
+                "self.unchecked_into::<js_sys::Uint8Array>().to_vec().into_boxed_slice().wire2api()"
+                    .into()
+            }
+            IrTypeDelegate::I128 | IrTypeDelegate::U128 => {
+                "self.unchecked_into::<js_sys::Uint8Array>().to_vec().into_boxed_slice().wire2api()"
+                    .into()
+            }
+            IrTypeDelegate::Char { .. } => "wire2api_char(self.unchecked_into_f64() as u32)".into(),
+            IrTypeDelegate::NonZero(inner) => format!(
+                "let value: {} = self.wire2api(); wire2api_non_zero(value)",
+                inner.rust_api_type()
+            )
+            .into(),
+            IrTypeDelegate::Array(IrTypeDelegateArray::PrimitiveArray { length, primitive })
+                if !matches!(
+                    primitive,
+                    IrTypePrimitive::I64 | IrTypePrimitive::U64 | IrTypePrimitive::Bool | IrTypePrimitive::Unit
+                ) =>
+            {
+                format!(
+                    "let buf = self.unchecked_into::<{}>();
+                    assert_eq!(buf.length() as usize, {length}, \"Expected an array of length {length} but it was {{}}\", buf.length());
+                    let mut ans = [Default::default(); {length}];
+                    buf.copy_to(&mut ans);
+                    ans",
+                    IrTypePrimitiveList { primitive: primitive.clone() }.rust_wasm_wire_type(),
+                )
+                .into()
+            }
             IrTypeDelegate::Array(array) => format!(
                 "let vec: Vec<{}> = self.wire2api(); support::from_vec_to_array(vec)",
                 array.inner_rust_api_type()
             )
             .into(),
+            IrTypeDelegate::BTreeSet(_) => format!(
+                "let vec: {} = self.wire2api(); vec.into_iter().collect()",
+                self.ir.get_delegate().rust_api_type()
+            )
+            .into(),
+            IrTypeDelegate::Arc(opaque) => format!(
+                "let opaque: RustOpaque<{}> = self.wire2api(); opaque.into_arc()",
+                opaque.inner_rust
+            )
+            .into(),
+            IrTypeDelegate::Weak(opaque) => format!(
+                "let opaque: RustOpaque<{}> = self.wire2api(); std::sync::Weak::clone(&opaque)",
+                opaque.weak().inner_rust
+            )
+            .into(),
+            IrTypeDelegate::Instant => {
+                "let opaque: RustOpaque<std::time::Instant> = self.wire2api(); *opaque".into()
+            }
+            IrTypeDelegate::Backtrace => {
+                "let _: String = self.wire2api(); std::backtrace::Backtrace::disabled()".into()
+            }
+            IrTypeDelegate::PathBuf | IrTypeDelegate::PathRef => {
+                "wire2api_path(self.unchecked_into::<js_sys::Uint16Array>().to_vec())".into()
+            }
+            IrTypeDelegate::IpAddr | IrTypeDelegate::SocketAddr => format!(
+                "wire2api_{}(self.unchecked_into::<js_sys::Uint8Array>().to_vec())",
+                self.ir.safe_ident().to_case(Case::Snake)
+            )
+            .into(),
+            IrTypeDelegate::CustomCodec(codec) => format!(
+                "{}::decode(&self.unchecked_into::<js_sys::Uint8Array>().to_vec())",
+                codec.rust
+            )
+            .into(),
+            IrTypeDelegate::CowStr => {
+                "std::borrow::Cow::Owned(self.as_string().expect(\"non-UTF-8 string, or not a string\"))"
+                    .into()
+            }
+            IrTypeDelegate::CowBytes => {
+                "std::borrow::Cow::Owned(self.unchecked_into::<js_sys::Uint8Array>().to_vec())".into()
+            }
+            #[cfg(feature = "rust_decimal")]
+            IrTypeDelegate::Decimal => {
+                "wire2api_decimal(self.as_string().expect(\"non-UTF-8 string, or not a string\"))"
+                    .into()
+            }
             _ => return None,
         })
     }
@@ -208,10 +421,190 @@ impl TypeRustGeneratorTrait for TypeDelegateGenerator<'_> {
     }
 
     fn wrapper_struct(&self) -> Option<String> {
+        if let IrTypeDelegate::BTreeSet(_) = &self.ir {
+            return Some(format!("mirror_{}", self.ir.safe_ident()));
+        }
         delegate_enum!(self, wrapper_struct(), None)
     }
 
     fn wrap_obj(&self, obj: String, wired_fallible_func: bool) -> String {
+        if let IrTypeDelegate::BTreeSet(_) = &self.ir {
+            let wrapper = self.wrapper_struct().unwrap();
+            return if wired_fallible_func {
+                format!("Ok({wrapper}({obj}?))")
+            } else {
+                format!("{wrapper}({obj})")
+            };
+        }
+        if let IrTypeDelegate::I128 | IrTypeDelegate::U128 = &self.ir {
+            return if wired_fallible_func {
+                format!("Ok({obj}?.to_le_bytes().to_vec())")
+            } else {
+                format!("{obj}.to_le_bytes().to_vec()")
+            };
+        }
+        if let IrTypeDelegate::NonZero(inner) = &self.ir {
+            let inner =
+                TypeRustGenerator::new(*inner.clone(), self.context.ir_file, self.context.config);
+            return if wired_fallible_func {
+                format!("Ok({})", inner.wrap_obj(format!("{obj}?.get()"), false))
+            } else {
+                inner.wrap_obj(format!("{obj}.get()"), false)
+            };
+        }
+        if let IrTypeDelegate::Char { .. } = &self.ir {
+            return if wired_fallible_func {
+                format!("Ok({obj}? as u32)")
+            } else {
+                format!("{obj} as u32")
+            };
+        }
+        if let IrTypeDelegate::PathBuf = &self.ir {
+            return if wired_fallible_func {
+                format!("Ok(api2wire_path({obj}?))")
+            } else {
+                format!("api2wire_path({obj})")
+            };
+        }
+        if let IrTypeDelegate::SliceRef(_) = &self.ir {
+            return if wired_fallible_func {
+                format!("Ok({obj}?.to_vec())")
+            } else {
+                format!("{obj}.to_vec()")
+            };
+        }
+        if let IrTypeDelegate::PathRef = &self.ir {
+            return if wired_fallible_func {
+                format!("Ok(api2wire_path({obj}?.to_path_buf()))")
+            } else {
+                format!("api2wire_path({obj}.to_path_buf())")
+            };
+        }
+        if let IrTypeDelegate::IpAddr | IrTypeDelegate::SocketAddr = &self.ir {
+            let func = format!("api2wire_{}", self.ir.safe_ident().to_case(Case::Snake));
+            return if wired_fallible_func {
+                format!("Ok({func}({obj}?))")
+            } else {
+                format!("{func}({obj})")
+            };
+        }
+        if let IrTypeDelegate::CustomCodec(codec) = &self.ir {
+            return if wired_fallible_func {
+                format!("Ok({}::encode(&{obj}?))", codec.rust)
+            } else {
+                format!("{}::encode(&{obj})", codec.rust)
+            };
+        }
+        // Borrowed data is copied, while an owned buffer is moved out as is.
+        if let IrTypeDelegate::CowStr = &self.ir {
+            return if wired_fallible_func {
+                format!("Ok({obj}?.into_owned())")
+            } else {
+                format!("{obj}.into_owned()")
+            };
+        }
+        if let IrTypeDelegate::CowBytes = &self.ir {
+            return if wired_fallible_func {
+                format!("Ok(ZeroCopyBuffer({obj}?.into_owned()))")
+            } else {
+                format!("ZeroCopyBuffer({obj}.into_owned())")
+            };
+        }
+        if let IrTypeDelegate::SystemTime = &self.ir {
+            return if wired_fallible_func {
+                format!("Ok(api2wire_system_time({obj}?))")
+            } else {
+                format!("api2wire_system_time({obj})")
+            };
+        }
+        if let IrTypeDelegate::Range { bound, inclusive } = &self.ir {
+            let func = format!(
+                "api2wire_range{}::<{}, _>",
+                if *inclusive { "_inclusive" } else { "" },
+                IrTypeDelegate::list_wire_primitive(bound).rust_api_type()
+            );
+            let list = TypeRustGenerator::new(
+                self.ir.get_delegate(),
+                self.context.ir_file,
+                self.context.config,
+            );
+            return if wired_fallible_func {
+                format!("Ok({})", list.wrap_obj(format!("{func}({obj}?)"), false))
+            } else {
+                list.wrap_obj(format!("{func}({obj})"), false)
+            };
+        }
+        if let IrTypeDelegate::SizeList(primitive) = &self.ir {
+            let func = format!(
+                "api2wire_size_list::<{}, _>",
+                IrTypeDelegate::list_wire_primitive(primitive).rust_api_type()
+            );
+            return if wired_fallible_func {
+                format!("Ok({func}({obj}?))")
+            } else {
+                format!("{func}({obj})")
+            };
+        }
+        if let IrTypeDelegate::Backtrace = &self.ir {
+            return if wired_fallible_func {
+                format!("Ok({obj}?.to_string())")
+            } else {
+                format!("{obj}.to_string()")
+            };
+        }
+        #[cfg(feature = "rust_decimal")]
+        if let IrTypeDelegate::Decimal = &self.ir {
+            return if wired_fallible_func {
+                format!("Ok({obj}?.to_string())")
+            } else {
+                format!("{obj}.to_string()")
+            };
+        }
+        #[cfg(feature = "bytes")]
+        if let IrTypeDelegate::Bytes = &self.ir {
+            return if wired_fallible_func {
+                format!("Ok(api2wire_bytes({obj}?))")
+            } else {
+                format!("api2wire_bytes({obj})")
+            };
+        }
+        if let IrTypeDelegate::Arc(_) = &self.ir {
+            return if wired_fallible_func {
+                format!("Ok(RustOpaque::from({obj}?))")
+            } else {
+                format!("RustOpaque::from({obj})")
+            };
+        }
+        if let IrTypeDelegate::Weak(_) | IrTypeDelegate::Instant = &self.ir {
+            return if wired_fallible_func {
+                format!("Ok(RustOpaque::new({obj}?))")
+            } else {
+                format!("RustOpaque::new({obj})")
+            };
+        }
+        if let IrTypeDelegate::BoxDyn(_) = &self.ir {
+            return if wired_fallible_func {
+                format!("Ok(RustOpaque::new({obj}?))")
+            } else {
+                format!("RustOpaque::new({obj})")
+            };
+        }
+        if let IrTypeDelegate::ImplTrait(opaque) = &self.ir {
+            let inner_rust = &opaque.inner_rust;
+            return if wired_fallible_func {
+                format!("Ok(RustOpaque::new(Box::new({obj}?) as {inner_rust}))")
+            } else {
+                format!("RustOpaque::new(Box::new({obj}) as {inner_rust})")
+            };
+        }
+        #[cfg(feature = "chrono")]
+        if let IrTypeDelegate::Time(IrTypeTime::FixedOffset) = &self.ir {
+            return if wired_fallible_func {
+                format!("Ok(chrono::DateTime::<chrono::Utc>::from({obj}?))")
+            } else {
+                format!("chrono::DateTime::<chrono::Utc>::from({obj})")
+            };
+        }
         delegate_enum!(self, wrap_obj(obj, wired_fallible_func), obj)
     }
 
@@ -220,6 +613,12 @@ impl TypeRustGeneratorTrait for TypeDelegateGenerator<'_> {
     }
 
     fn static_checks(&self) -> Option<String> {
+        if let IrTypeDelegate::CustomCodec(IrTypeCustomCodec { name, rust, .. }) = &self.ir {
+            return Some(format!(
+                "let _: fn(&{name}) -> Vec<u8> = {rust}::encode;
+                let _: fn(&[u8]) -> {name} = {rust}::decode;"
+            ));
+        }
         delegate_enum!(self, static_checks(), None)
     }
 }
@@ -198,8 +198,9 @@ impl TypeRustGeneratorTrait for TypeEnumRefGenerator<'_> {
         }
     }
 
-    fn wrap_obj(&self, obj: String, _wired_fallible_func: bool) -> String {
+    fn wrap_obj(&self, obj: String, wired_fallible_func: bool) -> String {
         match self.wrapper_struct() {
+            Some(wrapper) if wired_fallible_func => format!("Ok({wrapper}({obj}?))"),
             Some(wrapper) => format!("{wrapper}({obj})"),
             None => obj,
         }
@@ -232,7 +233,9 @@ impl TypeRustGeneratorTrait for TypeEnumRefGenerator<'_> {
                                     self.context.ir_file,
                                     self.context.config,
                                 );
-                                gen.convert_to_dart(field.name.rust_style().to_owned())
+                                gen.convert_to_dart(
+                                    gen.wrap_obj(field.name.rust_style().to_owned(), false),
+                                )
                             }))
                             .collect::<Vec<_>>();
                         let pattern = st
@@ -44,17 +44,16 @@ impl TypeRustGeneratorTrait for TypeGeneralListGenerator<'_> {
             self.context.ir_file,
             self.context.config,
         );
-        inner
-            .wrapper_struct()
-            .map(|wrapper| {
-                format!(
-                    "{}.into_iter().map(|v| {}({})).collect::<Vec<_>>()",
-                    obj,
-                    wrapper,
-                    inner.self_access("v".to_owned())
-                )
-            })
-            .unwrap_or(obj)
+        let wrapped = match inner.wrapper_struct() {
+            Some(wrapper) => format!("{}({})", wrapper, inner.self_access("v".to_owned())),
+            // E.g. a nested list, whose own elements may have to be wrapped.
+            None => inner.wrap_obj("v".to_owned(), false),
+        };
+        if wrapped == "v" {
+            obj
+        } else {
+            format!("{obj}.into_iter().map(|v| {wrapped}).collect::<Vec<_>>()")
+        }
     }
 
     fn allocate_funcs(
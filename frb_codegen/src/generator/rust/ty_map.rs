@@ -0,0 +1,137 @@
+use crate::generator::rust::ty::*;
+use crate::generator::rust::{ExternFuncCollector, NO_PARAMS};
+use crate::ir::*;
+use crate::target::{Acc, Target};
+use crate::type_rust_generator_struct;
+use crate::utils::BlockIndex;
+
+type_rust_generator_struct!(TypeMapGenerator, IrTypeMap);
+
+impl TypeMapGenerator<'_> {
+    fn element_into_dart(&self, ty: &IrType, obj: &str) -> String {
+        let gen = TypeRustGenerator::new(ty.clone(), self.context.ir_file, self.context.config);
+        gen.convert_to_dart(gen.wrap_obj(obj.to_owned(), false))
+    }
+}
+
+impl TypeRustGeneratorTrait for TypeMapGenerator<'_> {
+    fn wire2api_body(&self) -> Acc<Option<String>> {
+        let key_list = self.ir.key_list().rust_api_type();
+        let value_list = self.ir.value_list().rust_api_type();
+        Acc {
+            io: Some(format!(
+                "let wrap = unsafe {{ support::box_from_leak_ptr(self) }};
+                let keys: {key_list} = wrap.keys.wire2api();
+                let values: {value_list} = wrap.values.wire2api();
+                keys.into_iter().zip(values).collect()"
+            )),
+            wasm: Some(format!(
+                "let self_ = self.dyn_into::<JsArray>().unwrap();
+                assert_eq!(self_.length(), 2, \"Expected 2 elements, got {{}}\", self_.length());
+                let keys: {key_list} = self_.get(0).wire2api();
+                let values: {value_list} = self_.get(1).wire2api();
+                keys.into_iter().zip(values).collect()"
+            )),
+            ..Default::default()
+        }
+    }
+
+    fn wire_struct_fields(&self) -> Option<Vec<String>> {
+        let key_list = self.ir.key_list();
+        let value_list = self.ir.value_list();
+        Some(vec![
+            format!(
+                "keys: {}{}",
+                key_list.rust_wire_modifier(Target::Io),
+                key_list.rust_wire_type(Target::Io)
+            ),
+            format!(
+                "values: {}{}",
+                value_list.rust_wire_modifier(Target::Io),
+                value_list.rust_wire_type(Target::Io)
+            ),
+        ])
+    }
+
+    fn wrapper_struct(&self) -> Option<String> {
+        Some(format!("mirror_{}", self.ir.safe_ident()))
+    }
+
+    fn wrap_obj(&self, obj: String, wired_fallible_func: bool) -> String {
+        let wrapper = self.wrapper_struct().unwrap();
+        if wired_fallible_func {
+            format!("Ok({wrapper}({obj}?))")
+        } else {
+            format!("{wrapper}({obj})")
+        }
+    }
+
+    fn impl_intodart(&self) -> String {
+        let name = self.wrapper_struct().unwrap();
+        let key = self.element_into_dart(&self.ir.key, "k");
+        let value = self.element_into_dart(&self.ir.value, "v");
+        format!(
+            "impl support::IntoDart for {name} {{
+                fn into_dart(self) -> support::DartAbi {{
+                    let (keys, values): (Vec<_>, Vec<_>) = self.0.into_iter().unzip();
+                    vec![
+                        keys.into_iter().map(|k| {key}).collect::<Vec<_>>().into_dart(),
+                        values.into_iter().map(|v| {value}).collect::<Vec<_>>().into_dart(),
+                    ]
+                    .into_dart()
+                }}
+            }}
+            impl support::IntoDartExceptPrimitive for {name} {{}}
+            "
+        )
+    }
+
+    fn allocate_funcs(
+        &self,
+        collector: &mut ExternFuncCollector,
+        block_index: BlockIndex,
+    ) -> Acc<Option<String>> {
+        Acc {
+            io: Some(
+                collector.generate(
+                    &format!("new_{}_{}", self.ir.safe_ident(), block_index),
+                    NO_PARAMS,
+                    Some(
+                        &[
+                            self.ir.rust_wire_modifier(Target::Io),
+                            self.ir.rust_wire_type(Target::Io),
+                        ]
+                        .concat(),
+                    ),
+                    &format!(
+                        "support::new_leak_box_ptr({}::new_with_null_ptr())",
+                        self.ir.rust_wire_type(Target::Io)
+                    ),
+                    Target::Io,
+                ),
+            ),
+            ..Default::default()
+        }
+    }
+
+    fn new_with_nullptr(&self, _collector: &mut ExternFuncCollector) -> String {
+        format!(
+            r#"impl NewWithNullPtr for {0} {{
+                    fn new_with_null_ptr() -> Self {{
+                        Self {{
+                            keys: core::ptr::null_mut(),
+                            values: core::ptr::null_mut(),
+                        }}
+                    }}
+                }}
+
+                impl Default for {0} {{
+                    fn default() -> Self {{
+                        Self::new_with_null_ptr()
+                    }}
+                }}
+            "#,
+            self.ir.rust_wire_type(Target::Io),
+        )
+    }
+}
@@ -6,6 +6,29 @@ use crate::type_rust_generator_struct;
 
 type_rust_generator_struct!(TypeOptionalGenerator, IrTypeOptional);
 
+impl TypeOptionalGenerator<'_> {
+    /// How the value inside the option is wrapped before being sent to Dart, if it needs to be.
+    fn wrap_inner(&self) -> Option<String> {
+        // An architecture has been created so that the inner type of optional field is often an IrTypeBoxed
+        // which does not exist in the real API. Going through it would dereference `v`,
+        // which is not a `Box` at all, so we look at the type behind it instead.
+        let inner = match &*self.ir.inner {
+            IrType::Boxed(IrTypeBoxed {
+                exist_in_real_api: false,
+                inner,
+            }) => inner,
+            inner => inner,
+        };
+        let wrapped = TypeRustGenerator::new(
+            IrType::clone(inner),
+            self.context.ir_file,
+            self.context.config,
+        )
+        .wrap_obj("v".to_owned(), false);
+        (wrapped != "v").then_some(wrapped)
+    }
+}
+
 impl TypeRustGeneratorTrait for TypeOptionalGenerator<'_> {
     fn wire2api_body(&self) -> Acc<Option<String>> {
         Acc {
@@ -25,24 +48,12 @@ impl TypeRustGeneratorTrait for TypeOptionalGenerator<'_> {
             .then(|| "(!self.is_undefined() && !self.is_null()).then(|| self.wire2api())".into())
     }
 
-    fn convert_to_dart(&self, obj: String) -> String {
-        let inner = TypeRustGenerator::new(
-            *self.ir.inner.clone(),
-            self.context.ir_file,
-            self.context.config,
-        );
-        let obj = match inner.wrapper_struct() {
-            // An architecture has been created so that the inner type of optional field is always IrTypeBoxed.
-            // Here, too, if we use inner.self_access("v".to_owned()), since it will go to self_access in TypeBoxedGenerator,
-            // the dereferenced value *v was returned from there, which gave the error that the external struct could not be dereferenced.
-            //
-            // For now, removed the parameter inner.self_access("v".to_owned())
-            // and then added a bit of a hacky method here (not used self_access function),
-            // as it only covers mirrored optional fields.
-            Some(wrapper) => format!("{obj}.map(|v| {wrapper}(v))"),
+    fn wrap_obj(&self, obj: String, wired_fallible_func: bool) -> String {
+        match self.wrap_inner() {
+            Some(wrapped) if wired_fallible_func => format!("Ok({obj}?.map(|v| {wrapped}))"),
+            Some(wrapped) => format!("{obj}.map(|v| {wrapped})"),
             None => obj,
-        };
-        format!("{obj}.into_dart()")
+        }
     }
 
     fn imports(&self) -> Option<String> {
@@ -116,6 +116,19 @@ impl TypeRustGeneratorTrait for TypeRustOpaqueGenerator<'_> {
                     ),
                     target,
                 ),
+                // Same as sharing, for a handle used by another isolate, which is only sound
+                // if the object is thread safe: null tells Dart that it is not.
+                collector.generate(
+                    &format!("send_opaque_{}", self.ir.safe_ident()),
+                    [("ptr: *const c_void", "")],
+                    Some("*const c_void"),
+                    &format!(
+                        "if !flutter_rust_bridge::is_send_sync!({0}) {{ return core::ptr::null(); }}
+                        unsafe {{Arc::<{0}>::increment_strong_count(ptr as _); ptr}}",
+                        self.ir.inner_rust
+                    ),
+                    target,
+                ),
             ]
             .join("\n")
         };
@@ -132,10 +145,16 @@ impl TypeRustGeneratorTrait for TypeRustOpaqueGenerator<'_> {
 
     fn new_with_nullptr(&self, _collector: &mut ExternFuncCollector) -> String {
         format!(
-            "impl NewWithNullPtr for {} {{
+            "impl NewWithNullPtr for {0} {{
                 fn new_with_null_ptr() -> Self {{
                     Self {{ ptr: core::ptr::null() }}
                 }}
+            }}
+
+            impl Default for {0} {{
+                fn default() -> Self {{
+                    Self::new_with_null_ptr()
+                }}
             }}",
             self.ir.rust_wire_type(crate::target::Target::Io)
         )
@@ -28,6 +28,14 @@ impl TypeRustGeneratorTrait for TypeStructRefGenerator<'_> {
                     ..Default::default()
                 }
             })
+            .chain(api_struct.skipped_fields.iter().map(|field| {
+                let init = format!("{}: {}", field.name, field.value);
+                Acc {
+                    wasm: init.clone(),
+                    io: init,
+                    ..Default::default()
+                }
+            }))
             .collect();
 
         let (left, right) = api_struct.brackets_pair();
@@ -144,18 +152,51 @@ impl TypeRustGeneratorTrait for TypeStructRefGenerator<'_> {
                     self.context.ir_file,
                     self.context.config,
                 );
+                let obj = format!("self{unwrap}.{field_ref}");
+                if field.zero_copy {
+                    return match &field.ty {
+                        IrType::Optional(_) => format!("{obj}.map(ZeroCopyBuffer).into_dart()"),
+                        _ => format!("ZeroCopyBuffer({obj}).into_dart()"),
+                    };
+                }
                 // wired_fallible is always false here, this parameter is only used for generate_wire_func
-                gen.convert_to_dart(gen.wrap_obj(format!("self{unwrap}.{field_ref}"), false))
+                gen.convert_to_dart(gen.wrap_obj(obj, false))
             })
             .collect::<Vec<_>>()
             .join(",\n");
 
         let name = match &src.wrapper_name {
-            Some(wrapper) => wrapper,
-            None => &src.name,
+            Some(wrapper) => wrapper.clone(),
+            None => self.ir.rust_api_type(),
         };
 
-        let vec = if src.is_empty() {
+        let vec = if src.repr_c {
+            let (offsets, size) = src.repr_c_layout();
+            let writes = (src.fields.iter().zip(offsets).enumerate())
+                .map(|(i, (field, offset))| {
+                    let field_ref = if src.is_fields_named {
+                        field.name.rust_style().to_string()
+                    } else {
+                        i.to_string()
+                    };
+                    let (value, size) = match &field.ty {
+                        IrType::Primitive(IrTypePrimitive::Bool) => {
+                            (format!("u8::from(self{unwrap}.{field_ref})"), 1)
+                        }
+                        IrType::Primitive(primitive) => (
+                            format!("self{unwrap}.{field_ref}"),
+                            primitive.repr_c_size().unwrap(),
+                        ),
+                        _ => unreachable!(),
+                    };
+                    format!(
+                        "bytes[{offset}..{}].copy_from_slice(&{value}.to_le_bytes());",
+                        offset + size
+                    )
+                })
+                .collect::<String>();
+            format!("ZeroCopyBuffer({{ let mut bytes = vec![0u8; {size}]; {writes} bytes }})")
+        } else if src.is_empty() {
             "Vec::<u8>::new()".to_string()
         } else {
             format!(
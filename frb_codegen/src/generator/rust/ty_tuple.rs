@@ -0,0 +1,135 @@
+use crate::generator::rust::ty::*;
+use crate::generator::rust::ExternFuncCollector;
+use crate::ir::*;
+use crate::target::{Acc, Target};
+use crate::type_rust_generator_struct;
+
+type_rust_generator_struct!(TypeTupleGenerator, IrTypeTuple);
+
+impl TypeRustGeneratorTrait for TypeTupleGenerator<'_> {
+    fn wire2api_body(&self) -> Acc<Option<String>> {
+        let fields: Acc<Vec<_>> = (0..self.ir.arity())
+            .map(|idx| Acc {
+                wasm: format!("self_.get({idx}).wire2api()"),
+                io: format!("self.{}.wire2api()", IrTypeTuple::wire_field(idx)),
+                ..Default::default()
+            })
+            .collect();
+
+        Acc {
+            io: Some(format!("({},)", fields.io.join(","))),
+            wasm: Some(format!(
+                "
+                let self_ = self.dyn_into::<JsArray>().unwrap();
+                assert_eq!(self_.length(), {len}, \"Expected {len} elements, got {{}}\", self_.length());
+                ({fields},)
+                ",
+                fields = fields.wasm.join(","),
+                len = self.ir.arity(),
+            )),
+            ..Default::default()
+        }
+    }
+
+    fn wire_struct_fields(&self) -> Option<Vec<String>> {
+        Some(
+            self.ir
+                .values
+                .iter()
+                .enumerate()
+                .map(|(idx, ty)| {
+                    format!(
+                        "{}: {}{}",
+                        IrTypeTuple::wire_field(idx),
+                        ty.rust_wire_modifier(Target::Io),
+                        ty.rust_wire_type(Target::Io)
+                    )
+                })
+                .collect(),
+        )
+    }
+
+    fn wrapper_struct(&self) -> Option<String> {
+        Some(format!("mirror_{}", self.ir.safe_ident()))
+    }
+
+    fn wrap_obj(&self, obj: String, wired_fallible_func: bool) -> String {
+        let wrapper = self.wrapper_struct().unwrap();
+        if wired_fallible_func {
+            format!("Ok({wrapper}({obj}?))")
+        } else {
+            format!("{wrapper}({obj})")
+        }
+    }
+
+    fn impl_intodart(&self) -> String {
+        let name = self.wrapper_struct().unwrap();
+        let pattern = (0..self.ir.arity())
+            .map(IrTypeTuple::wire_field)
+            .collect::<Vec<_>>()
+            .join(",");
+        let body = self
+            .ir
+            .values
+            .iter()
+            .enumerate()
+            .map(|(idx, ty)| {
+                let gen =
+                    TypeRustGenerator::new(ty.clone(), self.context.ir_file, self.context.config);
+                gen.convert_to_dart(gen.wrap_obj(IrTypeTuple::wire_field(idx), false))
+            })
+            .collect::<Vec<_>>()
+            .join(",\n");
+        format!(
+            "impl support::IntoDart for {name} {{
+                fn into_dart(self) -> support::DartAbi {{
+                    let ({pattern},) = self.0;
+                    vec![
+                        {body}
+                    ].into_dart()
+                }}
+            }}
+            impl support::IntoDartExceptPrimitive for {name} {{}}
+            "
+        )
+    }
+
+    fn new_with_nullptr(&self, _collector: &mut ExternFuncCollector) -> String {
+        let body = self
+            .ir
+            .values
+            .iter()
+            .enumerate()
+            .map(|(idx, ty)| {
+                format!(
+                    "{}: {},",
+                    IrTypeTuple::wire_field(idx),
+                    if ty.rust_wire_is_pointer(Target::Io) {
+                        "core::ptr::null_mut()".to_owned()
+                    } else if ty.is_rust_opaque() || ty.is_dart_opaque() {
+                        format!("{}::new_with_null_ptr()", ty.rust_wire_type(Target::Io))
+                    } else {
+                        "Default::default()".to_owned()
+                    }
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+        format!(
+            r#"impl NewWithNullPtr for {0} {{
+                    fn new_with_null_ptr() -> Self {{
+                        Self {{ {1} }}
+                    }}
+                }}
+
+                impl Default for {0} {{
+                    fn default() -> Self {{
+                        Self::new_with_null_ptr()
+                    }}
+                }}
+            "#,
+            self.ir.rust_wire_type(Target::Io),
+            body,
+        )
+    }
+}
@@ -0,0 +1,135 @@
+//! The code recording the bytes moved by each call, see `--record-wire-size`.
+
+use crate::ir::*;
+
+/// An expression of the bytes taken by `value`, a reference to a `ty`, as counted by
+/// `flutter_rust_bridge::wire_size::WireSize`.
+pub(super) fn wire_size(ty: &IrType, value: &str) -> String {
+    let sum_of = |inner: &IrType| {
+        format!(
+            "{value}.iter().map(|it| {}).sum::<usize>()",
+            wire_size(inner, "it")
+        )
+    };
+    match ty {
+        IrType::Primitive(IrTypePrimitive::Unit) => "0".to_owned(),
+        IrType::PrimitiveList(_)
+        | IrType::Delegate(IrTypeDelegate::SliceRef(_) | IrTypeDelegate::SizeList(_)) => {
+            format!("std::mem::size_of_val(&{value}[..])")
+        }
+        IrType::Delegate(IrTypeDelegate::StringList) => {
+            format!("{value}.iter().map(String::len).sum::<usize>()")
+        }
+        #[cfg(feature = "uuid")]
+        IrType::Delegate(IrTypeDelegate::Uuids) => format!("std::mem::size_of_val(&{value}[..])"),
+        #[cfg(feature = "bytes")]
+        IrType::Delegate(IrTypeDelegate::Bytes) => format!("{value}.len()"),
+        IrType::Delegate(
+            IrTypeDelegate::String | IrTypeDelegate::CowStr | IrTypeDelegate::CowBytes,
+        ) => {
+            format!("{value}.len()")
+        }
+        IrType::Delegate(IrTypeDelegate::PathBuf | IrTypeDelegate::PathRef) => {
+            format!("{value}.as_os_str().len()")
+        }
+        IrType::Delegate(IrTypeDelegate::ZeroCopyBufferVecPrimitive(_)) => {
+            format!("std::mem::size_of_val(&{value}.0[..])")
+        }
+        IrType::Delegate(IrTypeDelegate::Array(IrTypeDelegateArray::GeneralArray {
+            general,
+            ..
+        })) => sum_of(general),
+        IrType::Delegate(IrTypeDelegate::BTreeSet(inner)) => sum_of(inner),
+        IrType::GeneralList(IrTypeGeneralList { inner }) => sum_of(inner),
+        IrType::Optional(IrTypeOptional { inner }) => format!(
+            "{value}.as_ref().map_or(0, |it| {})",
+            wire_size(inner, "it")
+        ),
+        IrType::Map(IrTypeMap {
+            key, value: val, ..
+        }) => format!(
+            "{value}.iter().map(|(key, value)| {} + {}).sum::<usize>()",
+            wire_size(key, "key"),
+            wire_size(val, "value")
+        ),
+        IrType::Boxed(IrTypeBoxed {
+            exist_in_real_api: true,
+            inner,
+        }) => wire_size(inner, &format!("(&**{value})")),
+        IrType::Boxed(IrTypeBoxed { inner, .. }) => wire_size(inner, value),
+        IrType::SyncReturn(inner) => {
+            wire_size(&inner.clone().into_inner(), &format!("(&{value}.0)"))
+        }
+        IrType::Tuple(IrTypeTuple { values }) if !values.is_empty() => values
+            .iter()
+            .enumerate()
+            .map(|(i, ty)| wire_size(ty, &format!("(&{value}.{i})")))
+            .collect::<Vec<_>>()
+            .join(" + "),
+        IrType::StructRef(_) | IrType::EnumRef(_) => {
+            format!("wire_size_{}({value})", ty.safe_ident())
+        }
+        _ => format!("std::mem::size_of_val({value})"),
+    }
+}
+
+/// The function called by [wire_size] for a struct or an enum, so that recursive types work.
+pub(super) fn generate_wire_size_func(ty: &IrType, ir_file: &IrFile) -> Option<String> {
+    let sum = |fields: Vec<String>| match fields.is_empty() {
+        true => "0".to_owned(),
+        false => fields.join(" + "),
+    };
+    let body = match ty {
+        IrType::StructRef(st) => {
+            let src = st.get(ir_file);
+            sum(src
+                .fields
+                .iter()
+                .enumerate()
+                .map(|(i, field)| {
+                    let field_ref = if src.is_fields_named {
+                        field.name.rust_style().to_owned()
+                    } else {
+                        i.to_string()
+                    };
+                    wire_size(&field.ty, &format!("(&value.{field_ref})"))
+                })
+                .collect())
+        }
+        IrType::EnumRef(enu) => {
+            let src = enu.get(ir_file);
+            let arms = src
+                .variants()
+                .iter()
+                .map(|variant| match &variant.kind {
+                    IrVariantKind::Value => format!("{}::{} => 0,", src.name, variant.name),
+                    IrVariantKind::Struct(st) => {
+                        let (left, right) = st.brackets_pair();
+                        format!(
+                            "{}::{}{left}{}{right} => {},",
+                            src.name,
+                            variant.name,
+                            st.fields
+                                .iter()
+                                .map(|field| field.name.rust_style())
+                                .collect::<Vec<_>>()
+                                .join(", "),
+                            sum(st
+                                .fields
+                                .iter()
+                                .map(|field| wire_size(&field.ty, field.name.rust_style()))
+                                .collect())
+                        )
+                    }
+                })
+                .collect::<Vec<_>>();
+            format!("match value {{ {} }}", arms.join("\n"))
+        }
+        _ => return None,
+    };
+    Some(format!(
+        "fn wire_size_{}(value: &{}) -> usize {{ {body} }}",
+        ty.safe_ident(),
+        ty.rust_api_type()
+    ))
+}
@@ -1,6 +1,7 @@
 use crate::ir::*;
+use serde::Serialize;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct IrDartAnnotation {
     pub content: String,
     pub library: Option<IrDartImport>,
@@ -1,4 +1,6 @@
-#[derive(Debug, Clone)]
+use serde::Serialize;
+
+#[derive(Debug, Clone, Serialize)]
 pub struct IrComment(String);
 
 impl IrComment {
@@ -12,9 +14,28 @@ impl From<&str> for IrComment {
         if input.contains('\n') {
             // Dart's formatter has issues with block comments
             // so we convert them ahead of time.
-            let formatted = input
-                .split('\n')
+            let mut lines = input.split('\n').collect::<Vec<_>>();
+            // `/**` and `*/` usually sit on their own lines
+            if lines.first().is_some_and(|line| line.trim().is_empty()) {
+                lines.remove(0);
+            }
+            if lines.last().is_some_and(|line| line.trim().is_empty()) {
+                lines.pop();
+            }
+            // Strip the ` * ` decoration of block comments, but not the indentation after it,
+            // so that code examples stay as they are.
+            let decorated = lines
+                .iter()
+                .all(|line| line.trim().is_empty() || line.trim_start().starts_with('*'));
+            let formatted = lines
                 .into_iter()
+                .map(|line| {
+                    if decorated {
+                        line.trim_start().trim_start_matches('*')
+                    } else {
+                        line
+                    }
+                })
                 .map(|e| format!("///{e}"))
                 .collect::<Vec<_>>()
                 .join("\n");
@@ -0,0 +1,15 @@
+use serde::Serialize;
+
+use crate::ir::*;
+
+/// A `pub const` or `pub static` of the Rust input whose value is a literal, emitted as a
+/// Dart `const`.
+#[derive(Debug, Clone, Serialize)]
+pub struct IrConst {
+    pub name: IrIdent,
+    /// A primitive, or a `String` for a `&str`.
+    pub ty: IrType,
+    /// The value as a Dart literal.
+    pub value: String,
+    pub comments: Vec<IrComment>,
+}
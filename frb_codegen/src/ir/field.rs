@@ -1,9 +1,43 @@
 use crate::ir::*;
+use serde::Serialize;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct IrField {
     pub ty: IrType,
     pub name: IrIdent,
     pub is_final: bool,
     pub comments: Vec<IrComment>,
+    /// The Dart expression an argument defaults to, set by `#[frb(default = "..")]`.
+    pub default: Option<String>,
+    /// Whether the fields of this struct field are inlined in the Dart class of its parent,
+    /// set by `#[frb(flatten)]`. It is still sent as a nested struct.
+    pub flatten: bool,
+    /// Whether the buffer of this struct field is moved to Dart instead of being copied, set by
+    /// `#[frb(zero_copy)]` on a `Vec` of numbers, possibly in an `Option`.
+    pub zero_copy: bool,
+    /// Whether a function argument is taken as `&T`, borrowed from the `T` received from Dart.
+    pub borrowed: bool,
+}
+
+impl IrField {
+    /// The declaration of this field as a named parameter of a Dart function.
+    pub fn dart_param(&self, required: bool) -> String {
+        match &self.default {
+            Some(default) => format!(
+                "{} {} = {default}",
+                self.ty.dart_api_type(),
+                self.name.dart_style()
+            ),
+            None => format!(
+                "{}{} {}",
+                if required {
+                    "required "
+                } else {
+                    self.ty.dart_required_modifier()
+                },
+                self.ty.dart_api_type(),
+                self.name.dart_style()
+            ),
+        }
+    }
 }
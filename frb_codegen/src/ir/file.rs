@@ -1,7 +1,9 @@
+use crate::method_utils::FunctionName;
 use crate::target::Target;
-use crate::utils::mod_from_rust_path;
+use crate::utils::BlockIndex;
 use crate::{generator, ir::*, Opts};
-use std::collections::{HashMap, HashSet};
+use serde::Serialize;
+use std::collections::{BTreeMap, HashMap, HashSet};
 
 pub type IrStructPool = HashMap<String, IrStruct>;
 pub type IrEnumPool = HashMap<String, IrEnum>;
@@ -11,6 +13,9 @@ pub struct IrFile {
     pub funcs: Vec<IrFunc>,
     pub struct_pool: IrStructPool,
     pub enum_pool: IrEnumPool,
+    /// The constants of the input files, in their order.
+    pub consts: Vec<IrConst>,
+    pub interfaces: Vec<IrInterface>,
     pub has_executor: bool,
 }
 
@@ -30,16 +35,120 @@ impl IrFile {
             }
             if include_func_output {
                 func.output.visit_types(f, self);
+                if let Some(error_output) = &func.error_output {
+                    error_output.visit_types(f, self);
+                }
+                if let Some(initial) = &func.stream_initial {
+                    initial.visit_types(f, self);
+                }
+                for callback in &func.callbacks {
+                    if let Some(arg) = &callback.arg {
+                        arg.visit_types(f, self);
+                    }
+                }
+            }
+        }
+    }
+
+    /// The functions, the types they use and the constants as pretty-printed JSON, see `--dump-ir`.
+    pub fn to_json(&self) -> String {
+        #[derive(Serialize)]
+        struct Dump<'a> {
+            funcs: &'a [IrFunc],
+            // Sorted, so that the dumps of two runs can be compared.
+            structs: BTreeMap<&'a str, &'a IrStruct>,
+            enums: BTreeMap<&'a str, &'a IrEnum>,
+            consts: &'a [IrConst],
+        }
+
+        serde_json::to_string_pretty(&Dump {
+            funcs: &self.funcs,
+            structs: (self.struct_pool.iter())
+                .map(|(name, st)| (name.as_str(), st))
+                .collect(),
+            enums: (self.enum_pool.iter())
+                .map(|(name, en)| (name.as_str(), en))
+                .collect(),
+            consts: &self.consts,
+        })
+        .unwrap()
+    }
+
+    /// Generate all the structs as `@freezed` classes, see `--dart-class-style`.
+    pub fn use_freezed_for_structs(&mut self) {
+        for st in self.struct_pool.values_mut() {
+            if !st.using_freezed() && st.dart_type.is_none() {
+                st.dart_metadata.insert(
+                    0,
+                    IrDartAnnotation {
+                        content: "freezed".to_owned(),
+                        library: None,
+                    },
+                );
             }
         }
     }
 
+    /// Add the `...Drain` companion of each free function taking a `StreamSink`, see
+    /// `--drain-streams`.
+    pub fn add_drain_funcs(&mut self) {
+        let drains = (self.funcs.iter())
+            .filter(|func| {
+                func.iterator.is_none()
+                    && func.changes.is_none()
+                    && func.cancellation_argument_index.is_none()
+                    && FunctionName::deserialize(&func.name).struct_name().is_none()
+            })
+            .filter_map(|func| {
+                let index = func.mode.stream_argument_index()?;
+                let mut comments = func.comments.clone();
+                if !comments.is_empty() {
+                    comments.push(IrComment::from(""));
+                }
+                comments.push(IrComment::from(
+                    format!(
+                        " Runs [{}] synchronously and returns all its items, for tests. Throws if the stream is still open once the Rust function returns.",
+                        func.dart_style()
+                    )
+                    .as_str(),
+                ));
+                Some(IrFunc {
+                    mode: IrFuncMode::Sync,
+                    drain: Some(index),
+                    stream_initial: None,
+                    inline: false,
+                    worker_pool: None,
+                    comments,
+                    ..func.clone()
+                })
+            })
+            .collect::<Vec<_>>();
+        self.funcs.extend(drains);
+    }
+
+    /// Give the generated `warm_up` of a block other than the primary one its own symbol, e.g.
+    /// `warm_up_1`, as all the blocks are compiled into the same library. It is still `warmUp`
+    /// on the API class of the block.
+    pub fn suffix_warm_up(&mut self, block_index: BlockIndex) {
+        for func in self.funcs.iter_mut().filter(|func| func.warm_up) {
+            func.dart_name = Some(func.dart_style());
+            func.name = format!("{}_{}", func.name, block_index);
+        }
+    }
+
+    /// Make the parameters of all the functions `required`, see `--dart-required-params`.
+    pub fn require_all_params(&mut self) {
+        for func in &mut self.funcs {
+            func.required_params = true;
+        }
+    }
+
     pub fn get_c_struct_names(&self) -> Vec<String> {
         let c_struct_names = self
             .distinct_types(true, true)
             .iter()
             .filter_map(|ty| {
-                if let IrType::StructRef(_) = ty {
+                if let IrType::StructRef(_) | IrType::Tuple(_) = ty {
                     Some(ty.rust_wire_type(Target::Io))
                 } else {
                     None
@@ -77,11 +186,7 @@ impl IrFile {
     }
 
     pub fn generate_rust(&self, config: &Opts) -> generator::rust::Output {
-        generator::rust::generate(
-            self,
-            &mod_from_rust_path(&config.rust_input_path, &config.rust_crate_dir),
-            config,
-        )
+        generator::rust::generate(self, &config.rust_input_mods(), config)
     }
 
     pub fn generate_dart(
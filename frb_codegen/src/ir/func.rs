@@ -1,14 +1,149 @@
+use convert_case::{Case, Casing};
+use itertools::Itertools;
+use serde::Serialize;
+
 use crate::consts::*;
+use crate::method_utils::FunctionName;
 use crate::{ir::*, target::Target};
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct IrFunc {
     pub name: String,
+    /// Set by `#[frb(dart_name = "..")]`, see [dart_style](Self::dart_style).
+    pub dart_name: Option<String>,
     pub inputs: Vec<IrField>,
     pub output: IrType,
+    /// The error type of a fallible function, or of the `Result` items of a `StreamSink`,
+    /// if it is sent to Dart as structured data.
+    /// [None] for `anyhow` errors, which are flattened into a message.
+    pub error_output: Option<IrType>,
     pub fallible: bool,
     pub mode: IrFuncMode,
+    /// Position of the `CancellationToken` argument, which is not visible in Dart.
+    pub cancellation_argument_index: Option<usize>,
+    /// The `impl Fn(T)` arguments, which are Dart closures.
+    pub callbacks: Vec<IrCallback>,
+    /// Set for methods taking `self` by value. On an opaque type, the call disposes the Dart
+    /// handle and moves the object out of it.
+    pub consumes_receiver: bool,
+    /// Set if the function returns a borrow, e.g. `&str`, possibly in a `Result`. The
+    /// [output](Self::output) is then its owned counterpart, which the call copies it into.
+    pub borrowed_output: bool,
+    /// Set for an `async fn`, whose future is run to completion by the thread of the call.
+    pub is_async: bool,
+    /// Set by `#[frb(sync)]`: the function runs on the calling thread like a `SyncReturn` one,
+    /// whose [output](Self::output) it has, while Dart receives a `Future` completed by the call.
+    pub inline: bool,
+    /// The thread pool set by `#[frb(worker_pool = "..")]`, [None] for the default one.
+    pub worker_pool: Option<String>,
+    /// Set by `#[frb(required_params)]` or `--dart-required-params`: the Dart parameters are all
+    /// `required`, including the nullable ones, unless they have a default value.
+    pub required_params: bool,
+    /// Set by `#[frb(init)]`: the function is run once, before the first call of any function
+    /// of its block, and calling it from Dart afterwards does nothing.
+    pub init: bool,
+    /// Set for the `warm_up` function generated for each block, which does nothing but go
+    /// through the handler, its worker pool and the port, so that they are ready before the
+    /// first real call.
+    pub warm_up: bool,
+    /// Set for the function generated for each `Weak<T>` handle type, which upgrades its
+    /// argument to a `RustOpaque<T>`, or [None] if the object is gone.
+    pub weak_upgrade: bool,
+    /// Set for the function generated when `Instant` is used, which returns the time elapsed
+    /// between two of its handles.
+    pub duration_since: bool,
+    /// Set for the `...Drain` companion of a function taking a `StreamSink`, generated by
+    /// `--drain-streams`, to the position of the sink. It runs synchronously on a buffered sink,
+    /// and returns all the items, whose type is the [output](Self::output), at once.
+    pub drain: Option<usize>,
+    /// The `#[frb(dispatchable)]` functions called by the generated `dispatch` function,
+    /// empty for the other functions.
+    pub dispatch: Vec<IrDispatchTarget>,
     pub comments: Vec<IrComment>,
+    /// The Rust module of the function, e.g. `api::user`, if it is put in a Dart namespace.
+    /// See `--dart-namespaces`.
+    pub namespace: Option<String>,
+    /// Set if this is one instantiation of a function generic over const parameters.
+    pub const_instance: Option<IrConstInstance>,
+    /// Set if the function returns an `impl Iterator`, or advances one, see [IrFuncIterator].
+    pub iterator: Option<IrFuncIterator>,
+    /// Set if the method notifies the change streams of its object, or is one, see [IrFuncChanges].
+    pub changes: Option<IrFuncChanges>,
+    /// The value returned by a function taking a `StreamSink`, unless it is `()`. Dart receives
+    /// it together with the stream, in a `StreamWithInitial`.
+    pub stream_initial: Option<IrType>,
+    /// The name of the function in the Rust input, [None] if it is not known.
+    pub span: Option<IrSpan>,
+}
+
+/// A function called by `dispatch` when its first argument is [id](Self::id).
+#[derive(Debug, Clone, Serialize)]
+pub struct IrDispatchTarget {
+    /// The Dart name of the function, which identifies it.
+    pub id: String,
+    pub rust_call_path: String,
+    pub fallible: bool,
+}
+
+/// A returned `impl Iterator<Item = T>` is kept in a `DartIterator<T>`, whose opaque handle
+/// is the [output](IrFunc::output) of the function. Dart receives a `Stream` instead, which
+/// pulls each item through the `next` function generated for the handle type.
+#[derive(Debug, Clone, Serialize)]
+pub enum IrFuncIterator {
+    Returned {
+        item: IrType,
+        /// The name of the generated `next` function.
+        next: String,
+    },
+    /// The generated function returning the next item of its `DartIterator<T>` argument, or
+    /// [None] once it is exhausted.
+    Next,
+}
+
+/// An opaque object with `#[frb(notifies)]` methods gets a generated `changes` method, returning
+/// a stream with an event after each successful call of one of them.
+#[derive(Debug, Clone, Serialize)]
+pub enum IrFuncChanges {
+    /// A `#[frb(notifies)]` method, which does not notify if it returns an error.
+    Notifies,
+    /// The generated `changes` method.
+    Stream,
+}
+
+/// A Dart closure received as an `impl Fn(T)` or `impl Fn()` argument.
+///
+/// It is sent across as a port, and each call of the Rust closure posts its argument to
+/// the Dart isolate, like the items of a `StreamSink`.
+#[derive(Debug, Clone, Serialize)]
+pub struct IrCallback {
+    /// Position among the arguments of the Rust function.
+    pub argument_index: usize,
+    pub name: IrIdent,
+    /// The argument of the closure, [None] for `impl Fn()`.
+    pub arg: Option<IrType>,
+}
+
+impl IrCallback {
+    /// E.g. `void Function(String)`.
+    pub fn dart_api_type(&self) -> String {
+        format!(
+            "void Function({})",
+            self.arg
+                .as_ref()
+                .map(IrType::dart_api_type)
+                .unwrap_or_default()
+        )
+    }
+}
+
+/// The values picked by `#[frb(instantiate(..))]` for the const generic parameters of a function.
+#[derive(Debug, Clone, Serialize)]
+pub struct IrConstInstance {
+    /// The name of the generic function, while [IrFunc::name] is suffixed with the values
+    /// if there are several instantiations.
+    pub rust_name: String,
+    /// In the order of the parameters.
+    pub values: Vec<usize>,
 }
 
 /// A stand-in for [`IrFunc`] used for output only.
@@ -39,6 +174,14 @@ impl IrFuncDisplay {
                     ty: "NativePortType".to_owned(),
                 })
                 .into_iter()
+                .chain(func.cancellation_argument_index.map(|_| IrParam {
+                    name: "cancel_id_".to_owned(),
+                    ty: "int".to_owned(),
+                }))
+                .chain(func.callbacks.iter().map(|callback| IrParam {
+                    name: callback.name.rust_style().to_owned(),
+                    ty: "NativePortType".to_owned(),
+                }))
                 .chain(func.inputs.iter().map(|input| IrParam {
                     name: input.name.rust_style().to_owned(),
                     ty: input.ty.dart_wire_type(target),
@@ -54,32 +197,138 @@ impl IrFuncDisplay {
 }
 
 impl IrFunc {
+    /// The name of the function on the API class: the one given by `#[frb(dart_name = "..")]`,
+    /// or the camel case of the Rust name. A method keeps the latter, while its Dart name is
+    /// used on its own class, see [dart_method_name](Self::dart_method_name).
+    pub fn dart_style(&self) -> String {
+        let name = match &self.dart_name {
+            Some(name)
+                if FunctionName::deserialize(&self.name)
+                    .struct_name()
+                    .is_none() =>
+            {
+                name.clone()
+            }
+            _ => self.name.to_case(Case::Camel),
+        };
+        match self.drain {
+            Some(_) => format!("{name}Drain"),
+            None => name,
+        }
+    }
+
+    /// The name of a method on the Dart class of its struct.
+    pub fn dart_method_name(&self) -> String {
+        if let Some(name) = &self.dart_name {
+            return name.clone();
+        }
+        let f = FunctionName::deserialize(&self.name);
+        match f.method_name().as_str() {
+            "new" if f.is_static_method() => format!("new{}", f.struct_name().unwrap()),
+            name => name.to_case(Case::Camel),
+        }
+    }
+
     pub fn wire_func_name(&self) -> String {
-        format!("wire_{}", self.name)
+        format!("wire_{}", self.flat_name())
+    }
+
+    /// The name prefixed with the namespace, unique among all the functions of the block.
+    pub fn flat_name(&self) -> String {
+        let name = match &self.namespace {
+            Some(namespace) => format!("{}_{}", namespace.replace("::", "_"), self.name),
+            None => self.name.clone(),
+        };
+        match self.drain {
+            Some(_) => format!("{name}_drain"),
+            None => name,
+        }
+    }
+
+    /// The path under which the generated Rust code calls the function.
+    pub fn rust_call_path(&self) -> String {
+        let name = match &self.const_instance {
+            Some(IrConstInstance { rust_name, values }) => {
+                format!("{rust_name}::<{}>", values.iter().join(", "))
+            }
+            None => self.name.clone(),
+        };
+        match &self.namespace {
+            Some(namespace) => format!("crate::{namespace}::{name}"),
+            None => name,
+        }
+    }
+
+    /// The name of the Dart getter exposing the namespace, e.g. `user` for `api::user`.
+    pub fn dart_namespace(&self) -> Option<String> {
+        self.namespace
+            .as_ref()
+            .map(|namespace| dart_namespace_of(namespace))
+    }
+
+    /// Whether the Dart function has a `...Result` companion, returning a `SyncResult` which
+    /// holds the error instead of throwing it.
+    pub fn has_sync_result(&self) -> bool {
+        self.mode == IrFuncMode::Sync && self.fallible && !self.inline && self.drain.is_none()
+    }
+
+    /// The return type of the function in the Dart API.
+    pub fn dart_return_type(&self) -> String {
+        let inner = self.output.dart_api_type();
+        if self.cancellation_argument_index.is_some() {
+            format!("CancelableFuture<{inner}>")
+        } else if let Some(initial) = &self.stream_initial {
+            format!(
+                "Future<StreamWithInitial<{}, {inner}>>",
+                initial.dart_api_type()
+            )
+        } else if let Some(IrFuncIterator::Returned { item, .. }) = &self.iterator {
+            format!("Stream<{}>", item.dart_api_type())
+        } else if self.drain.is_some() {
+            format!("List<{inner}>")
+        } else if self.inline {
+            format!("Future<{inner}>")
+        } else {
+            self.mode.dart_return_type(&inner)
+        }
     }
 }
 
+/// See [IrFunc::dart_namespace].
+pub fn dart_namespace_of(rust_mod: &str) -> String {
+    rust_mod.rsplit("::").next().unwrap().to_case(Case::Camel)
+}
+
 /// Represents a function's output type
 #[derive(Debug, Clone)]
 pub enum IrFuncOutput {
-    ResultType(IrType),
+    ResultType { ok: IrType, error: Option<IrType> },
     Type(IrType),
 }
 
-/// Represents the type of an argument to a function
+/// Represents the type of an argument to a function. A `&T` argument, other than the special
+/// cases of [IrTypeDelegate], is `Borrowed` with the owned type.
 #[derive(Debug, Clone)]
 pub enum IrFuncArg {
-    StreamSinkType(IrType),
+    StreamSinkType { item: IrType, error: Option<IrType> },
+    CancellationToken,
+    Callback(Option<IrType>),
     Type(IrType),
+    Borrowed(IrType),
 }
 
-#[derive(Debug, Clone, PartialOrd, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialOrd, PartialEq, Eq, Serialize)]
 pub enum IrFuncMode {
     Normal,
     Sync,
+    /// `#[frb(no_isolate)]`: a synchronous call whose primitive result is returned as is
+    /// through the C ABI, instead of being encoded into a `SyncReturn`.
+    Direct,
     Stream {
         // The index of StreamSink in the function arguments
         argument_index: usize,
+        /// Whether the Dart stream is a broadcast stream, see `#[frb(broadcast)]`.
+        broadcast: bool,
     },
 }
 
@@ -88,7 +337,7 @@ impl IrFuncMode {
     pub fn dart_return_type(&self, inner: &str) -> String {
         match self {
             Self::Normal => format!("Future<{inner}>"),
-            Self::Sync => inner.to_string(),
+            Self::Sync | Self::Direct => inner.to_string(),
             Self::Stream { .. } => format!("Stream<{inner}>"),
         }
     }
@@ -97,14 +346,14 @@ impl IrFuncMode {
     pub fn ffi_call_mode(&self) -> &'static str {
         match self {
             Self::Normal => "Normal",
-            Self::Sync => "Sync",
+            Self::Sync | Self::Direct => "Sync",
             Self::Stream { .. } => "Stream",
         }
     }
 
     #[inline]
     pub fn has_port_argument(&self) -> bool {
-        !matches!(self, Self::Sync)
+        !matches!(self, Self::Sync | Self::Direct)
     }
 
     #[inline]
@@ -117,4 +366,12 @@ impl IrFuncMode {
     pub fn dart_port_var(&self) -> Option<&str> {
         self.has_port_argument().then_some("port_")
     }
+
+    /// Position of the `StreamSink` among the arguments of the Rust function.
+    pub fn stream_argument_index(&self) -> Option<usize> {
+        match self {
+            Self::Stream { argument_index, .. } => Some(*argument_index),
+            _ => None,
+        }
+    }
 }
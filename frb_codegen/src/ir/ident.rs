@@ -1,8 +1,12 @@
 use convert_case::{Case, Casing};
+use serde::Serialize;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct IrIdent {
     pub raw: String,
+    /// Set by `#[frb(dart_name = "..")]`, replacing [dart_style](Self::dart_style).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dart_name: Option<String>,
 }
 
 impl std::fmt::Display for IrIdent {
@@ -13,7 +17,14 @@ impl std::fmt::Display for IrIdent {
 
 impl IrIdent {
     pub fn new(raw: String) -> IrIdent {
-        IrIdent { raw }
+        IrIdent {
+            raw,
+            dart_name: None,
+        }
+    }
+
+    pub fn with_dart_name(self, dart_name: Option<String>) -> IrIdent {
+        IrIdent { dart_name, ..self }
     }
 
     pub fn rust_style(&self) -> &str {
@@ -21,6 +32,9 @@ impl IrIdent {
     }
 
     pub fn dart_style(&self) -> String {
-        self.raw.to_case(Case::Camel)
+        match &self.dart_name {
+            Some(name) => name.clone(),
+            None => self.raw.to_case(Case::Camel),
+        }
     }
 }
@@ -0,0 +1,44 @@
+use crate::ir::*;
+use crate::method_utils::{FunctionName, MethodInfo};
+use serde::Serialize;
+
+/// A `pub` trait of the input implemented by opaque types, which becomes a Dart abstract class
+/// implemented by their handle classes.
+#[derive(Debug, Clone, Serialize)]
+pub struct IrInterface {
+    pub name: String,
+    pub comments: Vec<IrComment>,
+    /// The Rust names of the methods taking `&self`, in their order in the trait. The others
+    /// cannot be called through a handle, so they are left out.
+    pub methods: Vec<String>,
+    /// The opaque types implementing the trait, in the order of their `impl` blocks.
+    pub implementors: Vec<String>,
+}
+
+impl IrInterface {
+    /// The generated method `method` of `implementor`, whose Dart signature is shared by
+    /// the handle classes of all the implementors.
+    pub fn func<'a>(&self, ir_file: &'a IrFile, implementor: &str, method: &str) -> &'a IrFunc {
+        let name = FunctionName::new(
+            method,
+            MethodInfo::NonStatic {
+                struct_name: implementor.to_owned(),
+            },
+        )
+        .serialize();
+        (ir_file.funcs.iter())
+            .find(|func| func.name == name)
+            .unwrap_or_else(|| panic!("missing method `{}::{}`", implementor, method))
+    }
+
+    /// Whether `func` is the method of an implementor for one of [Self::methods].
+    pub fn has_method(&self, func: &IrFunc) -> bool {
+        let f = FunctionName::deserialize(&func.name);
+        f.is_non_static_method()
+            && self
+                .implementors
+                .iter()
+                .any(|name| f.is_method_for_struct(name))
+            && self.methods.contains(&f.method_name())
+    }
+}
@@ -1,39 +1,49 @@
 mod annotation;
 mod comment;
+mod constant;
 mod field;
 mod file;
 mod func;
 mod ident;
 mod import;
+mod interface;
+mod span;
 mod ty;
 mod ty_boxed;
 mod ty_dart_opaque;
 mod ty_delegate;
 mod ty_enum;
 mod ty_general_list;
+mod ty_map;
 mod ty_optional;
 mod ty_primitive;
 mod ty_primitive_list;
 mod ty_rust_opaque;
 mod ty_struct;
 mod ty_sync_return;
+mod ty_tuple;
 
 pub use annotation::*;
 pub use comment::*;
+pub use constant::*;
 pub use field::*;
 pub use file::*;
 pub use func::*;
 pub use ident::*;
 pub use import::*;
+pub use interface::*;
+pub use span::*;
 pub use ty::*;
 pub use ty_boxed::*;
 pub use ty_dart_opaque::*;
 pub use ty_delegate::*;
 pub use ty_enum::*;
 pub use ty_general_list::*;
+pub use ty_map::*;
 pub use ty_optional::*;
 pub use ty_primitive::*;
 pub use ty_primitive_list::*;
 pub use ty_rust_opaque::*;
 pub use ty_struct::*;
 pub use ty_sync_return::*;
+pub use ty_tuple::*;
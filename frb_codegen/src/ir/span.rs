@@ -0,0 +1,22 @@
+use serde::Serialize;
+
+/// Where an item is defined in the Rust input, see `--dump-ir`.
+#[derive(Debug, Clone, Serialize)]
+pub struct IrSpan {
+    pub file: String,
+    /// 1-based.
+    pub line: usize,
+    /// 0-based, in characters.
+    pub column: usize,
+}
+
+impl IrSpan {
+    pub fn new(file: impl Into<String>, span: proc_macro2::Span) -> Self {
+        let start = span.start();
+        Self {
+            file: file.into(),
+            line: start.line,
+            column: start.column,
+        }
+    }
+}
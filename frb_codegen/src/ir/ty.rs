@@ -1,3 +1,4 @@
+use serde::Serialize;
 use std::collections::HashSet;
 
 use crate::{ir::*, target::Target};
@@ -6,19 +7,21 @@ use IrType::*;
 
 /// Remark: "Ty" instead of "Type", since "type" is a reserved word in Rust.
 #[enum_dispatch(IrTypeTrait)]
-#[derive(Debug, Clone, Hash, Eq, PartialEq)]
+#[derive(Debug, Clone, Hash, Eq, PartialEq, Serialize)]
 pub enum IrType {
     Primitive(IrTypePrimitive),
     Delegate(IrTypeDelegate),
     PrimitiveList(IrTypePrimitiveList),
     Optional(IrTypeOptional),
     GeneralList(IrTypeGeneralList),
+    Map(IrTypeMap),
     StructRef(IrTypeStructRef),
     Boxed(IrTypeBoxed),
     EnumRef(IrTypeEnumRef),
     SyncReturn(IrTypeSyncReturn),
     DartOpaque(IrTypeDartOpaque),
     RustOpaque(IrTypeRustOpaque),
+    Tuple(IrTypeTuple),
 }
 
 impl IrType {
@@ -61,17 +64,43 @@ impl IrType {
     #[inline]
     pub fn rust_ptr_modifier(&self) -> &'static str {
         match self {
-            Optional(_) | Delegate(IrTypeDelegate::String) => "*mut ",
+            Optional(_) => "*mut ",
+            _ if self.is_list_like() => "*mut ",
+            // E.g. a `Decimal` or a `PathBuf`, sent as a separately allocated string or bytes.
+            Delegate(delegate) => delegate.get_delegate().rust_ptr_modifier(),
             _ => "",
         }
     }
 
+    /// Collections that are allocated on their own and passed by pointer,
+    /// instead of being filled in place. 128-bit integers count too, as they travel as byte lists.
+    #[inline]
+    pub fn is_list_like(&self) -> bool {
+        if let Delegate(IrTypeDelegate::NonZero(inner)) = self {
+            return inner.is_list_like();
+        }
+        matches!(
+            self,
+            GeneralList(_)
+                | PrimitiveList(_)
+                | Map(_)
+                | Delegate(
+                    IrTypeDelegate::StringList
+                        | IrTypeDelegate::BTreeSet(_)
+                        | IrTypeDelegate::I128
+                        | IrTypeDelegate::U128
+                )
+        )
+    }
+
     #[inline]
     pub fn as_primitive(&self) -> Option<&IrTypePrimitive> {
         match self {
             Primitive(repr) | Delegate(IrTypeDelegate::PrimitiveEnum { repr, .. }) => Some(repr),
             #[cfg(feature = "chrono")]
             Delegate(IrTypeDelegate::Time(_)) => Some(&IrTypePrimitive::I64),
+            Delegate(IrTypeDelegate::Char { .. }) => Some(&IrTypePrimitive::U32),
+            Delegate(IrTypeDelegate::NonZero(inner)) => inner.as_primitive(),
             _ => None,
         }
     }
@@ -88,7 +117,7 @@ impl IrType {
 
     #[inline]
     pub fn is_struct(&self) -> bool {
-        matches!(self, StructRef(_) | EnumRef(_))
+        matches!(self, StructRef(_) | EnumRef(_) | Tuple(_))
     }
 
     #[inline]
@@ -115,11 +144,22 @@ impl IrType {
     pub fn is_js_value(&self) -> bool {
         match self {
             Self::GeneralList(_)
+            | Self::Map(_)
             | Self::StructRef(_)
             | Self::EnumRef(_)
             | Self::RustOpaque(_)
-            | Self::DartOpaque(_) => true,
+            | Self::DartOpaque(_)
+            | Self::Tuple(_) => true,
             Self::Boxed(IrTypeBoxed { inner, .. }) => inner.is_js_value(),
+            Self::Delegate(
+                delegate @ (IrTypeDelegate::BTreeSet(_)
+                | IrTypeDelegate::Arc(_)
+                | IrTypeDelegate::Weak(_)
+                | IrTypeDelegate::Instant
+                | IrTypeDelegate::BoxDyn(_)
+                | IrTypeDelegate::ImplTrait(_)
+                | IrTypeDelegate::DynRef(_)),
+            ) => delegate.get_delegate().is_js_value(),
             _ => false,
         }
     }
@@ -1,7 +1,8 @@
 use crate::ir::*;
 use crate::target::Target;
+use serde::Serialize;
 
-#[derive(Debug, Clone, Hash, Eq, PartialEq)]
+#[derive(Debug, Clone, Hash, Eq, PartialEq, Serialize)]
 pub struct IrTypeBoxed {
     /// if false, means that we automatically add it when transforming it - it does not exist in real api.
     pub exist_in_real_api: bool,
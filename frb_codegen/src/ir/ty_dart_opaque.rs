@@ -1,6 +1,7 @@
 use crate::ir::*;
+use serde::Serialize;
 
-#[derive(Debug, Clone, Hash, Eq, PartialEq)]
+#[derive(Debug, Clone, Hash, Eq, PartialEq, Serialize)]
 pub struct IrTypeDartOpaque;
 
 impl IrTypeTrait for IrTypeDartOpaque {
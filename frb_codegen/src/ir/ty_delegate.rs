@@ -1,14 +1,17 @@
 use convert_case::{Case, Casing};
+use serde::Serialize;
 
 use crate::ir::*;
 use crate::target::Target;
 use strum_macros::Display;
 
 #[cfg(feature = "chrono")]
-#[derive(Debug, Clone, Hash, Eq, PartialEq, Display)]
+#[derive(Debug, Clone, Hash, Eq, PartialEq, Display, Serialize)]
 pub enum IrTypeTime {
     Local,
     Utc,
+    /// Normalized to UTC before being sent to Dart, so the original offset is lost.
+    FixedOffset,
     Naive,
     Duration,
 }
@@ -19,6 +22,7 @@ impl IrTypeTime {
         match self {
             IrTypeTime::Local => "Local",
             IrTypeTime::Utc => "Utc",
+            IrTypeTime::FixedOffset => "FixedOffset",
             IrTypeTime::Duration => "Duration",
             IrTypeTime::Naive => "Naive",
         }
@@ -26,7 +30,7 @@ impl IrTypeTime {
 }
 
 /// types that delegate to another type
-#[derive(Debug, Clone, Hash, Eq, PartialEq)]
+#[derive(Debug, Clone, Hash, Eq, PartialEq, Serialize)]
 pub enum IrTypeDelegate {
     Array(IrTypeDelegateArray),
     String,
@@ -37,14 +41,113 @@ pub enum IrTypeDelegate {
         /// Allows for `#[repr]`'s other than [i32]
         repr: IrTypePrimitive,
     },
+    /// Sent as its 16 little-endian bytes, since neither side of the wire has a native 128-bit integer.
+    I128,
+    /// Same as [IrTypeDelegate::I128].
+    U128,
+    /// Sent as its Unicode scalar value, and exposed to Dart as a one-character `String`
+    /// unless `--dart-char-as-int` is given.
+    Char {
+        as_int: bool,
+    },
+    /// A `std::num::NonZero*` integer, sent as the integer it wraps, i.e. a primitive
+    /// or one of [IrTypeDelegate::I128] and [IrTypeDelegate::U128].
+    NonZero(Box<IrType>),
     #[cfg(feature = "chrono")]
     Time(IrTypeTime),
     #[cfg(feature = "uuid")]
     Uuid,
     #[cfg(feature = "uuid")]
     Uuids,
+    /// A `bytes::Bytes`, sent as the bytes of its visible slice.
+    #[cfg(feature = "bytes")]
+    Bytes,
+    /// A `rust_decimal::Decimal`, sent as its canonical string so that no digit is lost.
+    #[cfg(feature = "rust_decimal")]
+    Decimal,
+    /// Sent as a list in sorted order, which the Dart `Set` keeps.
+    BTreeSet(Box<IrType>),
+    /// An `Arc<T>` used directly in the API, sent across as a `RustOpaque<T>` sharing the same reference count.
+    Arc(IrTypeRustOpaque),
+    /// A `Cow<str>`, received as an owned string and sent by value.
+    CowStr,
+    /// A `Cow<[u8]>`, received as an owned buffer and sent by value.
+    CowBytes,
+    /// A `std::path::PathBuf`, sent as the UTF-16 code units of a Dart string.
+    /// See `wire2api_path` for how paths that are not valid Unicode are kept intact.
+    PathBuf,
+    /// A `&std::path::Path` argument, borrowed from a received [IrTypeDelegate::PathBuf].
+    PathRef,
+    /// A `&[T]` argument of a primitive `T`, borrowed from the received `Vec<T>`.
+    SliceRef(IrTypePrimitive),
+    /// A `std::net::IpAddr`, sent as `4` or `6` followed by its octets.
+    IpAddr,
+    /// A `std::net::SocketAddr`, sent as its ip followed by the port and, for IPv6, the scope id.
+    SocketAddr,
+    /// A `std::sync::Weak<T>`, sent across as a `RustOpaque<Weak<T>>`, whose Dart handle is
+    /// upgraded by a generated function. Holds the opaque type of `T`.
+    Weak(IrTypeRustOpaque),
+    /// A `std::time::Instant`, sent across as a `RustOpaque<Instant>`, since it only makes sense
+    /// to the Rust side. Its Dart handle has a generated `durationSince` method.
+    Instant,
+    /// A returned `Box<dyn Trait>`, sent across as a `RustOpaque<Box<dyn Trait>>`.
+    BoxDyn(IrTypeRustOpaque),
+    /// A returned `impl Trait`, boxed into the same opaque type as [IrTypeDelegate::BoxDyn].
+    ImplTrait(IrTypeRustOpaque),
+    /// A `&dyn Trait` argument, borrowed from the opaque handle of a [IrTypeDelegate::BoxDyn].
+    DynRef(IrTypeRustOpaque),
+    /// A `std::backtrace::Backtrace`, typically a field of an error, sent to Dart as its text.
+    /// It cannot be rebuilt from Dart, so a disabled backtrace is received instead.
+    Backtrace,
+    /// A `SharedBuffer` argument, sent as the address of bytes that Dart registered once.
+    SharedBuffer,
+    /// A `std::time::SystemTime`, sent as a signed timestamp since the Unix epoch.
+    SystemTime,
+    /// A `std::ops::Range`, or a `RangeInclusive` if `inclusive`, sent as the list of its two
+    /// bounds. See [IrTypeDelegate::list_wire_primitive] for the type of the list.
+    Range {
+        bound: IrTypePrimitive,
+        inclusive: bool,
+    },
+    /// A `Vec<usize>`, sent as a typed list of 64-bit integers.
+    SizeList(IrTypePrimitive),
+    /// A struct or an enum of `#[frb(custom_codec(..))]`, sent as the bytes of its codecs.
+    CustomCodec(IrTypeCustomCodec),
 }
-#[derive(Clone, Debug, Hash, Eq, PartialEq)]
+
+/// A type serialized by hand-written functions, from
+/// `#[frb(custom_codec(rust = "crate::codecs::user", dart = "UserCodec" import "package:my_app/user.dart"))]`.
+///
+/// Rust encodes it with `encode(&T) -> Vec<u8>` and decodes it with `decode(&[u8]) -> T` from
+/// the `rust` module, Dart with the static `encode` and `decode` of the `dart` class.
+#[derive(Debug, Clone, Hash, Eq, PartialEq, Serialize)]
+pub struct IrTypeCustomCodec {
+    /// The name of the type in Rust, and of the Dart class found in `import`.
+    pub name: String,
+    pub rust: String,
+    pub dart: String,
+    pub import: IrDartImport,
+}
+
+impl IrTypeCustomCodec {
+    /// `name` with the prefix of the import, if any.
+    pub fn dart_type(&self) -> String {
+        self.with_import_alias(&self.name)
+    }
+
+    /// The class of the Dart codec, with the prefix of the import, if any.
+    pub fn dart_codec(&self) -> String {
+        self.with_import_alias(&self.dart)
+    }
+
+    fn with_import_alias(&self, name: &str) -> String {
+        match &self.import.alias {
+            Some(alias) => format!("{alias}.{name}"),
+            None => name.to_owned(),
+        }
+    }
+}
+#[derive(Clone, Debug, Hash, Eq, PartialEq, Serialize)]
 pub enum IrTypeDelegateArray {
     GeneralArray {
         length: usize,
@@ -165,6 +268,13 @@ impl IrTypeDelegate {
             }
             IrTypeDelegate::StringList => IrType::Delegate(IrTypeDelegate::String),
             IrTypeDelegate::PrimitiveEnum { repr, .. } => IrType::Primitive(repr.clone()),
+            IrTypeDelegate::I128 | IrTypeDelegate::U128 => {
+                IrType::PrimitiveList(IrTypePrimitiveList {
+                    primitive: IrTypePrimitive::U8,
+                })
+            }
+            IrTypeDelegate::Char { .. } => IrType::Primitive(IrTypePrimitive::U32),
+            IrTypeDelegate::NonZero(inner) => *inner.clone(),
             #[cfg(feature = "chrono")]
             IrTypeDelegate::Time(_) => IrType::Primitive(IrTypePrimitive::I64),
             #[cfg(feature = "uuid")]
@@ -175,6 +285,55 @@ impl IrTypeDelegate {
             IrTypeDelegate::Uuids => IrType::PrimitiveList(IrTypePrimitiveList {
                 primitive: IrTypePrimitive::U8,
             }),
+            #[cfg(feature = "bytes")]
+            IrTypeDelegate::Bytes => IrType::PrimitiveList(IrTypePrimitiveList {
+                primitive: IrTypePrimitive::U8,
+            }),
+            #[cfg(feature = "rust_decimal")]
+            IrTypeDelegate::Decimal => IrType::Delegate(IrTypeDelegate::String),
+            IrTypeDelegate::CowStr => IrType::Delegate(IrTypeDelegate::String),
+            IrTypeDelegate::PathBuf | IrTypeDelegate::PathRef => {
+                IrType::PrimitiveList(IrTypePrimitiveList {
+                    primitive: IrTypePrimitive::U16,
+                })
+            }
+            IrTypeDelegate::SliceRef(primitive) => IrType::PrimitiveList(IrTypePrimitiveList {
+                primitive: primitive.clone(),
+            }),
+            IrTypeDelegate::CowBytes | IrTypeDelegate::IpAddr | IrTypeDelegate::SocketAddr => {
+                IrType::PrimitiveList(IrTypePrimitiveList {
+                    primitive: IrTypePrimitive::U8,
+                })
+            }
+            IrTypeDelegate::BTreeSet(inner) => list_of(inner),
+            IrTypeDelegate::Arc(opaque)
+            | IrTypeDelegate::BoxDyn(opaque)
+            | IrTypeDelegate::ImplTrait(opaque)
+            | IrTypeDelegate::DynRef(opaque) => IrType::RustOpaque(opaque.clone()),
+            IrTypeDelegate::Weak(opaque) => IrType::RustOpaque(opaque.weak()),
+            IrTypeDelegate::Instant => IrType::RustOpaque(IrTypeRustOpaque::instant()),
+            IrTypeDelegate::Backtrace => IrType::Delegate(IrTypeDelegate::String),
+            IrTypeDelegate::SharedBuffer => IrType::Primitive(IrTypePrimitive::Usize),
+            IrTypeDelegate::SystemTime => IrType::Primitive(IrTypePrimitive::I64),
+            IrTypeDelegate::Range { bound, .. } => IrType::PrimitiveList(IrTypePrimitiveList {
+                primitive: Self::list_wire_primitive(bound),
+            }),
+            IrTypeDelegate::SizeList(primitive) => IrType::PrimitiveList(IrTypePrimitiveList {
+                primitive: Self::list_wire_primitive(primitive),
+            }),
+            IrTypeDelegate::CustomCodec(_) => IrType::PrimitiveList(IrTypePrimitiveList {
+                primitive: IrTypePrimitive::U8,
+            }),
+        }
+    }
+
+    /// The element type of the list carrying values of `primitive`, e.g. the bounds of a
+    /// [IrTypeDelegate::Range], which is a 64-bit integer for `usize`, since there are no
+    /// typed lists of it.
+    pub fn list_wire_primitive(primitive: &IrTypePrimitive) -> IrTypePrimitive {
+        match primitive {
+            IrTypePrimitive::Usize => IrTypePrimitive::U64,
+            _ => primitive.clone(),
         }
     }
 }
@@ -193,12 +352,44 @@ impl IrTypeTrait for IrTypeDelegate {
                 "ZeroCopyBuffer_".to_owned() + &self.get_delegate().dart_api_type()
             }
             IrTypeDelegate::PrimitiveEnum { ir, .. } => ir.safe_ident(),
+            IrTypeDelegate::I128 => "i128".to_owned(),
+            IrTypeDelegate::U128 => "u128".to_owned(),
+            IrTypeDelegate::Char { .. } => "char".to_owned(),
+            IrTypeDelegate::NonZero(inner) => format!("NonZero_{}", inner.safe_ident()),
             #[cfg(feature = "chrono")]
             IrTypeDelegate::Time(ir) => format!("Chrono_{}", ir.safe_ident()),
             #[cfg(feature = "uuid")]
             IrTypeDelegate::Uuid => "Uuid".to_owned(),
             #[cfg(feature = "uuid")]
             IrTypeDelegate::Uuids => "Uuids".to_owned(),
+            #[cfg(feature = "bytes")]
+            IrTypeDelegate::Bytes => "Bytes".to_owned(),
+            #[cfg(feature = "rust_decimal")]
+            IrTypeDelegate::Decimal => "Decimal".to_owned(),
+            IrTypeDelegate::CowStr => "Cow_String".to_owned(),
+            IrTypeDelegate::PathBuf => "PathBuf".to_owned(),
+            IrTypeDelegate::PathRef => "PathRef".to_owned(),
+            IrTypeDelegate::SliceRef(primitive) => format!("SliceRef_{}", primitive.safe_ident()),
+            IrTypeDelegate::CowBytes => "Cow_Bytes".to_owned(),
+            IrTypeDelegate::IpAddr => "IpAddr".to_owned(),
+            IrTypeDelegate::SocketAddr => "SocketAddr".to_owned(),
+            IrTypeDelegate::BTreeSet(inner) => format!("btree_set_{}", inner.safe_ident()),
+            IrTypeDelegate::Arc(opaque) => format!("Arc_{}", opaque.safe_ident()),
+            IrTypeDelegate::Weak(opaque) => format!("Weak_{}", opaque.safe_ident()),
+            IrTypeDelegate::Instant => "std_Instant".to_owned(),
+            IrTypeDelegate::BoxDyn(opaque) => format!("BoxDyn_{}", opaque.safe_ident()),
+            IrTypeDelegate::ImplTrait(opaque) => format!("Impl_{}", opaque.safe_ident()),
+            IrTypeDelegate::DynRef(opaque) => format!("DynRef_{}", opaque.safe_ident()),
+            IrTypeDelegate::Backtrace => "Backtrace".to_owned(),
+            IrTypeDelegate::SharedBuffer => "SharedBuffer".to_owned(),
+            IrTypeDelegate::SystemTime => "SystemTime".to_owned(),
+            IrTypeDelegate::Range { bound, inclusive } => format!(
+                "Range{}_{}",
+                if *inclusive { "Inclusive" } else { "" },
+                bound.safe_ident()
+            ),
+            IrTypeDelegate::SizeList(primitive) => format!("{}_list", primitive.safe_ident()),
+            IrTypeDelegate::CustomCodec(codec) => format!("Codec_{}", codec.name),
         }
     }
 
@@ -209,21 +400,56 @@ impl IrTypeTrait for IrTypeDelegate {
             IrTypeDelegate::StringList => "List<String>".to_owned(),
             IrTypeDelegate::ZeroCopyBufferVecPrimitive(_) => self.get_delegate().dart_api_type(),
             IrTypeDelegate::PrimitiveEnum { ir, .. } => ir.dart_api_type(),
+            IrTypeDelegate::I128 | IrTypeDelegate::U128 => "BigInt".to_owned(),
+            IrTypeDelegate::Char { as_int: true } => "int".to_owned(),
+            IrTypeDelegate::Char { as_int: false } => "String".to_owned(),
+            IrTypeDelegate::NonZero(inner) => inner.dart_api_type(),
             #[cfg(feature = "chrono")]
             IrTypeDelegate::Time(ir) => match ir {
-                IrTypeTime::Local | IrTypeTime::Utc | IrTypeTime::Naive => "DateTime".to_string(),
+                IrTypeTime::Local
+                | IrTypeTime::Utc
+                | IrTypeTime::FixedOffset
+                | IrTypeTime::Naive => "DateTime".to_string(),
                 IrTypeTime::Duration => "Duration".to_string(),
             },
             #[cfg(feature = "uuid")]
             IrTypeDelegate::Uuid => "UuidValue".to_owned(),
             #[cfg(feature = "uuid")]
             IrTypeDelegate::Uuids => "List<UuidValue>".to_owned(),
+            #[cfg(feature = "bytes")]
+            IrTypeDelegate::Bytes => "Uint8List".to_owned(),
+            #[cfg(feature = "rust_decimal")]
+            IrTypeDelegate::Decimal => "Decimal".to_owned(),
+            IrTypeDelegate::CowStr => "String".to_owned(),
+            IrTypeDelegate::PathBuf | IrTypeDelegate::PathRef => "String".to_owned(),
+            IrTypeDelegate::SliceRef(_) => self.get_delegate().dart_api_type(),
+            IrTypeDelegate::CowBytes => "Uint8List".to_owned(),
+            IrTypeDelegate::IpAddr => "IpAddress".to_owned(),
+            IrTypeDelegate::SocketAddr => "SocketAddress".to_owned(),
+            IrTypeDelegate::BTreeSet(inner) => format!("Set<{}>", inner.dart_api_type()),
+            IrTypeDelegate::Arc(opaque)
+            | IrTypeDelegate::BoxDyn(opaque)
+            | IrTypeDelegate::ImplTrait(opaque)
+            | IrTypeDelegate::DynRef(opaque) => opaque.dart_api_type(),
+            IrTypeDelegate::Weak(opaque) => opaque.weak().dart_api_type(),
+            IrTypeDelegate::Instant => IrTypeRustOpaque::instant().dart_api_type(),
+            IrTypeDelegate::Backtrace => "String".to_owned(),
+            IrTypeDelegate::SharedBuffer => "SharedBuffer".to_owned(),
+            IrTypeDelegate::SystemTime => "DateTime".to_owned(),
+            IrTypeDelegate::Range { bound, .. } => format!("RustRange<{}>", bound.dart_api_type()),
+            IrTypeDelegate::SizeList(_) => self.get_delegate().dart_api_type(),
+            IrTypeDelegate::CustomCodec(codec) => codec.dart_type(),
         }
     }
 
     fn dart_wire_type(&self, target: Target) -> String {
         match (self, target) {
-            (IrTypeDelegate::String, Target::Wasm) => "String".into(),
+            (
+                IrTypeDelegate::String | IrTypeDelegate::Backtrace | IrTypeDelegate::CowStr,
+                Target::Wasm,
+            ) => "String".into(),
+            #[cfg(feature = "rust_decimal")]
+            (IrTypeDelegate::Decimal, Target::Wasm) => "String".into(),
             (IrTypeDelegate::StringList, Target::Wasm) => "List<String>".into(),
             (IrTypeDelegate::StringList, _) => "ffi.Pointer<wire_StringList>".to_owned(),
             _ => self.get_delegate().dart_wire_type(target),
@@ -241,23 +467,71 @@ impl IrTypeTrait for IrTypeDelegate {
                 format!("ZeroCopyBuffer<{}>", self.get_delegate().rust_api_type())
             }
             IrTypeDelegate::PrimitiveEnum { ir, .. } => ir.rust_api_type(),
+            IrTypeDelegate::I128 | IrTypeDelegate::U128 => self.safe_ident(),
+            IrTypeDelegate::Char { .. } => "char".to_owned(),
+            IrTypeDelegate::NonZero(inner) => {
+                let inner = inner.rust_api_type();
+                format!(
+                    "std::num::NonZero{}{}",
+                    inner[..1].to_uppercase(),
+                    &inner[1..]
+                )
+            }
             #[cfg(feature = "chrono")]
             IrTypeDelegate::Time(ir) => match ir {
                 IrTypeTime::Naive => "chrono::NaiveDateTime".to_owned(),
                 IrTypeTime::Local => "chrono::DateTime::<chrono::Local>".to_owned(),
                 IrTypeTime::Utc => "chrono::DateTime::<chrono::Utc>".to_owned(),
+                IrTypeTime::FixedOffset => "chrono::DateTime::<chrono::FixedOffset>".to_owned(),
                 IrTypeTime::Duration => "chrono::Duration".to_owned(),
             },
             #[cfg(feature = "uuid")]
             IrTypeDelegate::Uuid => "uuid::Uuid".to_owned(),
             #[cfg(feature = "uuid")]
             IrTypeDelegate::Uuids => "Vec<uuid::Uuid>".to_owned(),
+            #[cfg(feature = "bytes")]
+            IrTypeDelegate::Bytes => "bytes::Bytes".to_owned(),
+            #[cfg(feature = "rust_decimal")]
+            IrTypeDelegate::Decimal => "rust_decimal::Decimal".to_owned(),
+            IrTypeDelegate::CowStr => "std::borrow::Cow<'static, str>".to_owned(),
+            // The function receives a reference into the buffer, see `generate_wire_func`.
+            IrTypeDelegate::PathBuf | IrTypeDelegate::PathRef => "std::path::PathBuf".to_owned(),
+            IrTypeDelegate::SliceRef(_) => self.get_delegate().rust_api_type(),
+            IrTypeDelegate::CowBytes => "std::borrow::Cow<'static, [u8]>".to_owned(),
+            IrTypeDelegate::IpAddr => "std::net::IpAddr".to_owned(),
+            IrTypeDelegate::SocketAddr => "std::net::SocketAddr".to_owned(),
+            IrTypeDelegate::Arc(opaque) => format!("std::sync::Arc<{}>", opaque.inner_rust),
+            IrTypeDelegate::Weak(opaque) => opaque.weak().inner_rust,
+            IrTypeDelegate::Instant => IrTypeRustOpaque::instant().inner_rust,
+            IrTypeDelegate::BoxDyn(opaque) | IrTypeDelegate::ImplTrait(opaque) => {
+                opaque.inner_rust.clone()
+            }
+            // The function receives a reference into the handle, see `generate_wire_func`.
+            IrTypeDelegate::DynRef(opaque) => opaque.rust_api_type(),
+            IrTypeDelegate::BTreeSet(inner) => {
+                format!("std::collections::BTreeSet<{}>", inner.rust_api_type())
+            }
+            IrTypeDelegate::Backtrace => "std::backtrace::Backtrace".to_owned(),
+            IrTypeDelegate::SharedBuffer => "flutter_rust_bridge::SharedBuffer".to_owned(),
+            IrTypeDelegate::SystemTime => "std::time::SystemTime".to_owned(),
+            IrTypeDelegate::Range { bound, inclusive } => format!(
+                "std::ops::Range{}<{}>",
+                if *inclusive { "Inclusive" } else { "" },
+                bound.rust_api_type()
+            ),
+            IrTypeDelegate::SizeList(primitive) => format!("Vec<{}>", primitive.rust_api_type()),
+            IrTypeDelegate::CustomCodec(codec) => codec.name.clone(),
         }
     }
 
     fn rust_wire_type(&self, target: Target) -> String {
         match (self, target) {
-            (IrTypeDelegate::String, Target::Wasm) => "String".into(),
+            (
+                IrTypeDelegate::String | IrTypeDelegate::Backtrace | IrTypeDelegate::CowStr,
+                Target::Wasm,
+            ) => "String".into(),
+            #[cfg(feature = "rust_decimal")]
+            (IrTypeDelegate::Decimal, Target::Wasm) => "String".into(),
             (IrTypeDelegate::StringList, Target::Io) => "wire_StringList".to_owned(),
             (IrTypeDelegate::StringList, Target::Wasm) => "JsValue".into(),
             _ => self.get_delegate().rust_wire_type(target),
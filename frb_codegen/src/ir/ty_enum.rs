@@ -1,8 +1,9 @@
 use crate::ir::*;
 use crate::target::Target;
 use convert_case::{Case, Casing};
+use serde::Serialize;
 
-#[derive(Debug, Clone, Hash, Eq, PartialEq)]
+#[derive(Debug, Clone, Hash, Eq, PartialEq, Serialize)]
 pub struct IrTypeEnumRef {
     pub name: String,
 }
@@ -51,12 +52,14 @@ impl IrTypeTrait for IrTypeEnumRef {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct IrEnum {
     pub name: String,
     pub wrapper_name: Option<String>,
     pub path: Vec<String>,
     pub comments: Vec<IrComment>,
+    /// The name of the enum in the Rust input.
+    pub span: IrSpan,
     variants: Vec<IrVariant>,
     is_struct: bool,
 }
@@ -67,6 +70,7 @@ impl IrEnum {
         wrapper_name: Option<String>,
         path: Vec<String>,
         comments: Vec<IrComment>,
+        span: IrSpan,
         mut variants: Vec<IrVariant>,
     ) -> Self {
         fn wrap_box(ty: &mut IrType) {
@@ -95,6 +99,7 @@ impl IrEnum {
             wrapper_name,
             path,
             comments,
+            span,
             variants,
             is_struct,
         }
@@ -107,18 +112,50 @@ impl IrEnum {
     pub fn is_struct(&self) -> bool {
         self.is_struct
     }
+
+    /// Whether a variant has an explicit discriminant, e.g. `NotFound = 404`.
+    pub fn has_discriminants(&self) -> bool {
+        self.variants
+            .iter()
+            .any(|variant| variant.discriminant.is_some())
+    }
+
+    /// The integers sending the variants of an enum without fields. As in Rust, a variant
+    /// without an explicit discriminant takes the one of the previous variant plus one.
+    pub fn wire_values(&self) -> Vec<i64> {
+        let mut next = 0;
+        (self.variants.iter())
+            .map(|variant| {
+                let value = variant.discriminant.unwrap_or(next);
+                next = value + 1;
+                value
+            })
+            .collect()
+    }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct IrVariant {
     pub name: IrIdent,
     pub wrapper_name: IrIdent,
     pub comments: Vec<IrComment>,
     pub kind: IrVariantKind,
+    /// The explicit discriminant of the variant, see [IrEnum::wire_values].
+    pub discriminant: Option<i64>,
+}
+
+impl IrVariant {
+    /// The name of the value of a Dart enum. Unlike the other identifiers, it keeps the case of Rust.
+    pub fn dart_value(&self) -> &str {
+        self.name
+            .dart_name
+            .as_deref()
+            .unwrap_or(self.name.rust_style())
+    }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub enum IrVariantKind {
     Value,
-    Struct(IrStruct),
+    Struct(Box<IrStruct>),
 }
@@ -1,7 +1,8 @@
 use crate::ir::*;
 use crate::target::Target;
+use serde::Serialize;
 
-#[derive(Debug, Clone, Hash, Eq, PartialEq)]
+#[derive(Debug, Clone, Hash, Eq, PartialEq, Serialize)]
 pub struct IrTypeGeneralList {
     pub inner: Box<IrType>,
 }
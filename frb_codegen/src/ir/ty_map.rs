@@ -0,0 +1,118 @@
+use crate::ir::IrType::*;
+use crate::ir::*;
+use crate::target::Target;
+use serde::Serialize;
+
+#[derive(Debug, Clone, Hash, Eq, PartialEq, Serialize)]
+pub enum IrTypeMapKind {
+    HashMap,
+    /// Entries are sent in sorted order, and the Dart `Map` keeps that order.
+    BTreeMap,
+}
+
+impl IrTypeMapKind {
+    fn safe_ident(&self) -> &str {
+        match self {
+            IrTypeMapKind::HashMap => "hash_map",
+            IrTypeMapKind::BTreeMap => "btree_map",
+        }
+    }
+
+    fn rust_api_type(&self) -> &str {
+        match self {
+            IrTypeMapKind::HashMap => "std::collections::HashMap",
+            IrTypeMapKind::BTreeMap => "std::collections::BTreeMap",
+        }
+    }
+}
+
+/// A map, which is sent across the wire as two parallel lists of keys and values.
+#[derive(Debug, Clone, Hash, Eq, PartialEq, Serialize)]
+pub struct IrTypeMap {
+    pub kind: IrTypeMapKind,
+    pub key: Box<IrType>,
+    pub value: Box<IrType>,
+}
+
+impl IrTypeMap {
+    /// The list type used to carry the keys on the wire.
+    pub fn key_list(&self) -> IrType {
+        list_of(&self.key)
+    }
+
+    /// The list type used to carry the values on the wire.
+    pub fn value_list(&self) -> IrType {
+        list_of(&self.value)
+    }
+}
+
+/// Mirrors how the parser picks the wire representation of a `Vec<T>`.
+pub fn list_of(inner: &IrType) -> IrType {
+    match inner {
+        Primitive(IrTypePrimitive::Usize) => {
+            Delegate(IrTypeDelegate::SizeList(IrTypePrimitive::Usize))
+        }
+        Primitive(primitive) => PrimitiveList(IrTypePrimitiveList {
+            primitive: primitive.clone(),
+        }),
+        Delegate(IrTypeDelegate::String) => Delegate(IrTypeDelegate::StringList),
+        #[cfg(feature = "uuid")]
+        Delegate(IrTypeDelegate::Uuid) => Delegate(IrTypeDelegate::Uuids),
+        others => GeneralList(IrTypeGeneralList {
+            inner: Box::new(others.clone()),
+        }),
+    }
+}
+
+impl IrTypeTrait for IrTypeMap {
+    fn visit_children_types<F: FnMut(&IrType) -> bool>(&self, f: &mut F, ir_file: &IrFile) {
+        self.key_list().visit_types(f, ir_file);
+        self.value_list().visit_types(f, ir_file);
+    }
+
+    fn safe_ident(&self) -> String {
+        format!(
+            "{}_{}_{}",
+            self.kind.safe_ident(),
+            self.key.safe_ident(),
+            self.value.safe_ident()
+        )
+    }
+
+    fn dart_api_type(&self) -> String {
+        format!(
+            "Map<{}, {}>",
+            self.key.dart_api_type(),
+            self.value.dart_api_type()
+        )
+    }
+
+    fn dart_wire_type(&self, target: Target) -> String {
+        if let Target::Wasm = target {
+            "List<dynamic>".into()
+        } else {
+            format!("ffi.Pointer<wire_{}>", self.safe_ident())
+        }
+    }
+
+    fn rust_api_type(&self) -> String {
+        format!(
+            "{}<{}, {}>",
+            self.kind.rust_api_type(),
+            self.key.rust_api_type(),
+            self.value.rust_api_type()
+        )
+    }
+
+    fn rust_wire_type(&self, target: Target) -> String {
+        if let Target::Wasm = target {
+            "JsValue".into()
+        } else {
+            format!("wire_{}", self.safe_ident())
+        }
+    }
+
+    fn rust_wire_is_pointer(&self, target: Target) -> bool {
+        !target.is_wasm()
+    }
+}
@@ -1,8 +1,9 @@
 use crate::ir::IrType::*;
 use crate::ir::*;
 use crate::target::Target;
+use serde::Serialize;
 
-#[derive(Debug, Clone, Hash, Eq, PartialEq)]
+#[derive(Debug, Clone, Hash, Eq, PartialEq, Serialize)]
 pub struct IrTypeOptional {
     pub inner: Box<IrType>,
 }
@@ -32,7 +33,7 @@ impl IrTypeOptional {
     }
 
     pub fn is_list(&self) -> bool {
-        matches!(&*self.inner, GeneralList(_) | PrimitiveList(_))
+        matches!(&*self.inner, GeneralList(_) | PrimitiveList(_) | Map(_))
     }
 
     pub fn is_delegate(&self) -> bool {
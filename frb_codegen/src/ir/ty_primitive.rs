@@ -1,7 +1,8 @@
 use crate::ir::*;
 use crate::target::Target;
+use serde::Serialize;
 
-#[derive(Debug, Clone, Hash, Eq, PartialEq)]
+#[derive(Debug, Clone, Hash, Eq, PartialEq, Serialize)]
 pub enum IrTypePrimitive {
     U8,
     I8,
@@ -75,6 +76,23 @@ impl IrTypeTrait for IrTypePrimitive {
 }
 
 impl IrTypePrimitive {
+    /// The size, which is also the alignment, of the primitive in the bytes of a
+    /// `#[frb(repr_c)]` struct, [None] if it cannot be a field of one.
+    ///
+    /// 64-bit integers are left out, as `ByteData` cannot read them on the web.
+    pub fn repr_c_size(&self) -> Option<usize> {
+        match self {
+            IrTypePrimitive::U8 | IrTypePrimitive::I8 | IrTypePrimitive::Bool => Some(1),
+            IrTypePrimitive::U16 | IrTypePrimitive::I16 => Some(2),
+            IrTypePrimitive::U32 | IrTypePrimitive::I32 | IrTypePrimitive::F32 => Some(4),
+            IrTypePrimitive::F64 => Some(8),
+            IrTypePrimitive::U64
+            | IrTypePrimitive::I64
+            | IrTypePrimitive::Usize
+            | IrTypePrimitive::Unit => None,
+        }
+    }
+
     /// Representations of primitives within Dart's pointers, e.g. `ffi.Pointer<ffi.Uint8>`.
     /// This is enforced on Dart's side, and should be used instead of `dart_wire_type`
     /// whenever primitives are put behind a pointer.
@@ -95,6 +113,18 @@ impl IrTypePrimitive {
             IrTypePrimitive::Unit => "ffi.Void",
         }
     }
+
+    /// Whether it is one of the integer types, which have a `NonZero` counterpart.
+    pub fn is_integer(&self) -> bool {
+        !matches!(
+            self,
+            IrTypePrimitive::F32
+                | IrTypePrimitive::F64
+                | IrTypePrimitive::Bool
+                | IrTypePrimitive::Unit
+        )
+    }
+
     pub fn try_from_rust_str(s: &str) -> Option<Self> {
         match s {
             "u8" => Some(IrTypePrimitive::U8),
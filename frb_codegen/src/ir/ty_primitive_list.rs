@@ -1,8 +1,9 @@
 use crate::ir::*;
 use crate::target::Target;
 use convert_case::{Case, Casing};
+use serde::Serialize;
 
-#[derive(Debug, Clone, Hash, Eq, PartialEq)]
+#[derive(Debug, Clone, Hash, Eq, PartialEq, Serialize)]
 pub struct IrTypePrimitiveList {
     pub primitive: IrTypePrimitive,
 }
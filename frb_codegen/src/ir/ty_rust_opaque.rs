@@ -1,11 +1,12 @@
 use crate::{ir::*, target::Target};
+use serde::Serialize;
 
 use convert_case::{Case, Casing};
 use lazy_static::lazy_static;
 use quote::ToTokens;
 use regex::Regex;
 
-#[derive(Debug, Clone, Hash, Eq, PartialEq)]
+#[derive(Debug, Clone, Hash, Eq, PartialEq, Serialize)]
 pub struct IrTypeRustOpaque {
     pub inner_rust: String,
     pub inner_dart: String,
@@ -58,6 +59,22 @@ impl IrTypeRustOpaque {
             inner_dart: "void".to_owned(),
         }
     }
+
+    /// The handle of a `std::time::Instant`, see [IrTypeDelegate::Instant].
+    pub fn instant() -> Self {
+        Self {
+            inner_rust: "std::time::Instant".to_owned(),
+            inner_dart: "Instant".to_owned(),
+        }
+    }
+
+    /// The handle of a `Weak<T>` pointing to this type, see [IrTypeDelegate::Weak].
+    pub fn weak(&self) -> Self {
+        Self {
+            inner_rust: format!("std::sync::Weak<{}>", self.inner_rust),
+            inner_dart: format!("Weak{}", self.inner_dart),
+        }
+    }
 }
 
 impl IrTypeTrait for IrTypeRustOpaque {
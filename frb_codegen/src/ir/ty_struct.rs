@@ -1,12 +1,19 @@
+use serde::Serialize;
+use std::collections::HashSet;
+
 use crate::ir::*;
 use crate::target::Target;
 use convert_case::{Case, Casing};
 
-#[derive(Debug, Clone, Hash, Eq, PartialEq)]
+#[derive(Debug, Clone, Hash, Eq, PartialEq, Serialize)]
 pub struct IrTypeStructRef {
     pub name: String,
     pub freezed: bool,
     pub empty: bool,
+    /// Set for instantiations of generic structs, e.g. `Pair::<i32>` for the struct named `PairI32`.
+    pub rust_type: Option<String>,
+    /// The hand-written Dart class used in place of the generated one, see [IrDartType].
+    pub dart_type: Option<String>,
 }
 impl IrTypeStructRef {
     pub fn get<'a>(&self, f: &'a IrFile) -> &'a IrStruct {
@@ -22,10 +29,12 @@ impl IrTypeTrait for IrTypeStructRef {
     }
 
     fn safe_ident(&self) -> String {
-        self.dart_api_type().to_case(Case::Snake)
+        self.name.to_case(Case::Snake)
     }
     fn dart_api_type(&self) -> String {
-        self.name.to_string()
+        self.dart_type
+            .clone()
+            .unwrap_or_else(|| self.name.to_string())
     }
     fn dart_wire_type(&self, target: Target) -> String {
         if target.is_wasm() {
@@ -36,7 +45,9 @@ impl IrTypeTrait for IrTypeStructRef {
     }
 
     fn rust_api_type(&self) -> String {
-        self.name.to_string()
+        self.rust_type
+            .clone()
+            .unwrap_or_else(|| self.name.to_string())
     }
 
     fn rust_wire_type(&self, target: Target) -> String {
@@ -48,7 +59,7 @@ impl IrTypeTrait for IrTypeStructRef {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct IrStruct {
     pub name: String,
     pub wrapper_name: Option<String>,
@@ -57,6 +68,79 @@ pub struct IrStruct {
     pub is_fields_named: bool,
     pub dart_metadata: Vec<IrDartAnnotation>,
     pub comments: Vec<IrComment>,
+    pub dart_type: Option<IrDartType>,
+    /// The Dart conditions from `#[frb(assert = "..")]`, asserted by the constructor.
+    pub dart_asserts: Vec<String>,
+    /// Whether the Dart constructor takes the fields as positional parameters, from
+    /// `#[frb(positional)]`.
+    pub dart_positional: bool,
+    /// The fields marked `#[frb(skip)]`, which are neither sent nor part of the Dart class.
+    pub skipped_fields: Vec<IrSkippedField>,
+    /// Set by `#[frb(repr_c)]` on a struct of numbers and booleans: it is sent to Dart as a
+    /// single block of bytes, laid out like a `#[repr(C)]` struct, see [Self::repr_c_layout].
+    pub repr_c: bool,
+    /// The name of the struct in the Rust input, [None] for the fields of an enum variant.
+    pub span: Option<IrSpan>,
+}
+
+/// See [IrStruct::has_dart_const_constructor]. A struct which is already being checked, through
+/// a recursive field, is assumed to be constant.
+fn has_dart_const_constructor(
+    src: &IrStruct,
+    ir_file: &IrFile,
+    visiting: &mut HashSet<String>,
+) -> bool {
+    if !visiting.insert(src.name.clone()) {
+        return true;
+    }
+    src.dart_asserts.is_empty()
+        && (src.dart_fields(ir_file).iter())
+            .all(|field| field.is_final && has_dart_const_values(&field.ty, ir_file, visiting))
+}
+
+/// Whether the Dart type of `ty` has constant values, unlike e.g. typed data or opaque handles.
+fn has_dart_const_values(ty: &IrType, ir_file: &IrFile, visiting: &mut HashSet<String>) -> bool {
+    match ty {
+        IrType::Primitive(_) | IrType::EnumRef(_) => true,
+        IrType::Delegate(
+            IrTypeDelegate::String
+            | IrTypeDelegate::StringList
+            | IrTypeDelegate::Char { .. }
+            | IrTypeDelegate::PrimitiveEnum { .. }
+            | IrTypeDelegate::Time(IrTypeTime::Duration),
+        ) => true,
+        IrType::GeneralList(list) => has_dart_const_values(&list.inner, ir_file, visiting),
+        IrType::Optional(optional) => has_dart_const_values(&optional.inner, ir_file, visiting),
+        IrType::Boxed(boxed) => has_dart_const_values(&boxed.inner, ir_file, visiting),
+        IrType::StructRef(struct_ref) if struct_ref.dart_type.is_none() => {
+            let src = struct_ref.get(ir_file);
+            src.using_freezed() || has_dart_const_constructor(src, ir_file, visiting)
+        }
+        _ => false,
+    }
+}
+
+/// A field left out of the Dart class by `#[frb(skip)]`.
+#[derive(Debug, Clone, Serialize)]
+pub struct IrSkippedField {
+    pub name: String,
+    /// The Rust expression the field is set to when the struct is received from Dart,
+    /// `Default::default()` unless given as `#[frb(skip = "..")]`.
+    pub value: String,
+}
+
+/// A hand-written Dart class standing in for a struct, from
+/// `#[frb(dart_type = ("Money" import "package:my_app/money.dart", from_raw = "moneyFromRaw", to_raw = "moneyToRaw"))]`.
+///
+/// The generated class is then named `{struct}Raw`, and only used by the two conversion functions.
+#[derive(Debug, Clone, Serialize)]
+pub struct IrDartType {
+    pub name: String,
+    pub import: IrDartImport,
+    /// Dart function turning the generated `{struct}Raw` into `name`.
+    pub from_raw: String,
+    /// Dart function turning `name` into the generated `{struct}Raw`.
+    pub to_raw: String,
 }
 
 impl IrStruct {
@@ -75,4 +159,78 @@ impl IrStruct {
     pub fn is_empty(&self) -> bool {
         self.fields.is_empty()
     }
+
+    /// The offset of each field in the bytes of a [repr_c](Self::repr_c) struct, and their
+    /// total size. Each field is aligned to its size, and the size to the largest field.
+    pub fn repr_c_layout(&self) -> (Vec<usize>, usize) {
+        let mut offsets = Vec::new();
+        let (mut size, mut align) = (0, 1);
+        for field in &self.fields {
+            let field_size = match &field.ty {
+                IrType::Primitive(primitive) => primitive.repr_c_size(),
+                _ => None,
+            }
+            .unwrap_or_else(|| panic!("field `{}` cannot be in a `repr_c` struct", field.name));
+            size = align_up(size, field_size);
+            offsets.push(size);
+            size += field_size;
+            align = align.max(field_size);
+        }
+        (offsets, align_up(size, align))
+    }
+
+    /// Whether the constructor of the plain Dart class can be `const`: its fields are all final
+    /// and of types which have constant values, and it has no asserts, which may call
+    /// non-constant code.
+    pub fn has_dart_const_constructor(&self, ir_file: &IrFile) -> bool {
+        has_dart_const_constructor(self, ir_file, &mut HashSet::new())
+    }
+
+    /// The argument passing `value` as the field `name` to the Dart constructor.
+    pub fn dart_constructor_arg(&self, name: &str, value: &str) -> String {
+        if self.dart_positional {
+            format!("{value},")
+        } else {
+            format!("{name}: {value},")
+        }
+    }
+
+    /// The fields of the Dart class, where each `#[frb(flatten)]` field is replaced by
+    /// the fields of its struct.
+    ///
+    /// Panics if two of them have the same name.
+    pub fn dart_fields(&self, ir_file: &IrFile) -> Vec<IrField> {
+        let mut fields = Vec::new();
+        for field in &self.fields {
+            match &field.ty {
+                IrType::StructRef(inner) if field.flatten => {
+                    fields.extend(inner.get(ir_file).dart_fields(ir_file))
+                }
+                _ => fields.push(field.clone()),
+            }
+        }
+        let mut names = HashSet::new();
+        for field in &fields {
+            if !names.insert(field.name.dart_style()) {
+                panic!(
+                    "Struct `{}` has more than one field named `{}` once its flattened fields are inlined",
+                    self.name,
+                    field.name.dart_style()
+                );
+            }
+        }
+        fields
+    }
+
+    /// The name of the generated Dart class.
+    pub fn dart_class_name(&self) -> String {
+        match &self.dart_type {
+            Some(_) => format!("{}Raw", self.name),
+            None => self.name.clone(),
+        }
+    }
+}
+
+fn align_up(offset: usize, align: usize) -> usize {
+    offset.div_ceil(align) * align
 }
@@ -1,8 +1,9 @@
 use delegate::delegate;
+use serde::Serialize;
 
 use crate::{ir::*, target::Target};
 
-#[derive(Debug, Clone, Hash, Eq, PartialEq)]
+#[derive(Debug, Clone, Hash, Eq, PartialEq, Serialize)]
 pub struct IrTypeSyncReturn(Box<IrType>);
 
 impl IrTypeSyncReturn {
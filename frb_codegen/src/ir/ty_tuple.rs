@@ -0,0 +1,98 @@
+use crate::ir::*;
+use crate::target::Target;
+use serde::Serialize;
+
+/// Smallest tuple arity with a matching `TupleN` class in the Dart runtime.
+pub const TUPLE_MIN_ARITY: usize = 2;
+/// Largest tuple arity with a matching `TupleN` class in the Dart runtime.
+pub const TUPLE_MAX_ARITY: usize = 6;
+
+/// A tuple such as `(i32, String)`, which is sent across the wire field by field,
+/// like a tuple struct.
+#[derive(Debug, Clone, Hash, Eq, PartialEq, Serialize)]
+pub struct IrTypeTuple {
+    pub values: Vec<IrType>,
+}
+
+impl IrTypeTuple {
+    pub fn arity(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Name of the field holding the `idx`-th value in the wire struct.
+    pub fn wire_field(idx: usize) -> String {
+        format!("field{idx}")
+    }
+
+    /// Name of the getter for the `idx`-th value in the Dart `TupleN` class.
+    pub fn dart_field(idx: usize) -> String {
+        format!("item{}", idx + 1)
+    }
+
+    /// Whether this is the error of a `Result<T, (Code, String)>`, possibly with a source
+    /// error as third value, which Dart throws as an `FrbCodedException` instead of a tuple.
+    /// `Code` must be an enum without fields, so that Dart can switch on it.
+    pub fn is_coded_error(&self) -> bool {
+        use IrTypeDelegate::{PrimitiveEnum, String};
+        match self.values.as_slice() {
+            [IrType::Delegate(PrimitiveEnum { .. }), IrType::Delegate(String), rest @ ..] => {
+                rest.len() <= 1
+            }
+            _ => false,
+        }
+    }
+}
+
+impl IrTypeTrait for IrTypeTuple {
+    fn visit_children_types<F: FnMut(&IrType) -> bool>(&self, f: &mut F, ir_file: &IrFile) {
+        for value in &self.values {
+            value.visit_types(f, ir_file);
+        }
+    }
+
+    fn safe_ident(&self) -> String {
+        let values = self
+            .values
+            .iter()
+            .map(IrType::safe_ident)
+            .collect::<Vec<_>>()
+            .join("_");
+        format!("tuple{}_{values}", self.arity())
+    }
+
+    fn dart_api_type(&self) -> String {
+        let values = self
+            .values
+            .iter()
+            .map(IrType::dart_api_type)
+            .collect::<Vec<_>>()
+            .join(", ");
+        format!("Tuple{}<{values}>", self.arity())
+    }
+
+    fn dart_wire_type(&self, target: Target) -> String {
+        if target.is_wasm() {
+            "List<dynamic>".into()
+        } else {
+            self.rust_wire_type(target)
+        }
+    }
+
+    fn rust_api_type(&self) -> String {
+        let values = self
+            .values
+            .iter()
+            .map(IrType::rust_api_type)
+            .collect::<Vec<_>>()
+            .join(", ");
+        format!("({values})")
+    }
+
+    fn rust_wire_type(&self, target: Target) -> String {
+        if let Target::Wasm = target {
+            "JsValue".into()
+        } else {
+            format!("wire_{}", self.safe_ident())
+        }
+    }
+}
@@ -18,14 +18,17 @@ use crate::target::Acc;
 use crate::target::Target;
 use crate::utils::*;
 
+mod cache;
 mod config;
 mod tools;
+mod watch;
 
 pub use crate::commands::ensure_tools_available;
 pub use crate::config::parse as config_parse;
 pub use crate::config::Opts;
 pub use crate::config::RawOpts;
 pub use crate::utils::get_symbols_if_no_duplicates;
+pub use crate::watch::watch;
 
 #[macro_use]
 mod commands;
@@ -44,19 +47,39 @@ mod utils;
 use error::*;
 
 pub fn frb_codegen(config: &config::Opts, all_symbols: &[String]) -> anyhow::Result<()> {
+    info!("Picked config: {:?}", config);
+
+    // The IR is dumped while parsing, which a cached run skips, so `--dump-ir` always parses.
+    let cache = if config.cache && config.dump_ir_path.is_none() {
+        cache::Cache::new(config, all_symbols)
+    } else {
+        None
+    };
+    if cache.as_ref().is_some_and(cache::Cache::is_fresh) {
+        info!("Skipped: the inputs did not change since the last run, use --no-cache to regenerate anyway");
+        return Ok(());
+    }
+
     let dart_root = config.dart_root_or_default();
     ensure_tools_available(&dart_root, config.skip_deps_check)?;
 
-    info!("Picked config: {:?}", config);
-
     let rust_output_dir = Path::new(&config.rust_output_path).parent().unwrap();
     let dart_output_dir = Path::new(&config.dart_output_path).parent().unwrap();
 
     info!("Phase: Parse source code to AST, then to IR");
     let raw_ir_file = config.get_ir_file()?;
+    if let Some(path) = &config.dump_ir_path {
+        let json = raw_ir_file.to_json();
+        if path == "-" {
+            println!("{json}");
+        } else {
+            fs::write(path, json)?;
+        }
+    }
 
     info!("Phase: Transform IR");
     let ir_file = transformer::transform(raw_ir_file);
+    generator::dart::check_dart_types(&ir_file, &dart_root, dart_output_dir)?;
 
     info!("Phase: Generate Rust code");
     fs::create_dir_all(rust_output_dir)?;
@@ -197,6 +220,10 @@ pub fn frb_codegen(config: &config::Opts, all_symbols: &[String]) -> anyhow::Res
         )
     )?;
 
+    if let Some(cache) = cache {
+        cache.store(config);
+    }
+
     info!("Success!");
     Ok(())
 }
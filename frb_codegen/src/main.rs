@@ -2,7 +2,7 @@ use std::process::exit;
 
 use clap::Parser;
 use lib_flutter_rust_bridge_codegen::{
-    config_parse, frb_codegen, get_symbols_if_no_duplicates, init_logger, RawOpts,
+    config_parse, frb_codegen, get_symbols_if_no_duplicates, init_logger, watch, RawOpts,
 };
 use log::{debug, error, info};
 
@@ -11,6 +11,7 @@ fn main() -> anyhow::Result<()> {
     let raw_opts = RawOpts::parse();
     init_logger("./logs/", raw_opts.verbose).unwrap();
 
+    let watch_enabled = raw_opts.watch;
     let configs = config_parse(raw_opts);
     debug!("configs={:?}", configs);
 
@@ -23,6 +24,10 @@ fn main() -> anyhow::Result<()> {
         }
     }
 
+    if watch_enabled {
+        watch(&configs);
+    }
+
     info!("Now go and use it :)");
     Ok(())
 }
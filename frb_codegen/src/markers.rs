@@ -1,5 +1,15 @@
+use lazy_static::lazy_static;
+use regex::Regex;
 use syn::*;
 
+/// The reserved words of Dart, which cannot be used as identifiers.
+const DART_RESERVED_WORDS: &[&str] = &[
+    "assert", "break", "case", "catch", "class", "const", "continue", "default", "do", "else",
+    "enum", "extends", "false", "final", "finally", "for", "if", "in", "is", "new", "null",
+    "rethrow", "return", "super", "switch", "this", "throw", "true", "try", "var", "void", "while",
+    "with",
+];
+
 /// Extract a path from marker `#[frb(mirror(path), ..)]`
 pub fn extract_mirror_marker(attrs: &[Attribute]) -> Vec<Path> {
     attrs
@@ -31,17 +41,196 @@ pub fn extract_mirror_marker(attrs: &[Attribute]) -> Vec<Path> {
         .unwrap_or_default()
 }
 
+/// Extract the values of the const generic parameters from each
+/// `#[frb(instantiate(N = 16, ..), ..)]` marker, in order.
+pub fn extract_instantiations(attrs: &[Attribute]) -> Vec<Vec<(String, usize)>> {
+    attrs
+        .iter()
+        .filter(|attr| attr.path.is_ident("frb"))
+        .filter_map(|attr| match attr.parse_meta() {
+            Ok(Meta::List(MetaList { nested, .. })) => Some(nested),
+            _ => None,
+        })
+        .flatten()
+        .filter_map(|meta| match meta {
+            NestedMeta::Meta(Meta::List(MetaList { path, nested, .. }))
+                if path.is_ident("instantiate") =>
+            {
+                Some(nested.iter().map(parse_const_value).collect())
+            }
+            _ => None,
+        })
+        .collect()
+}
+
+fn parse_const_value(meta: &NestedMeta) -> (String, usize) {
+    match meta {
+        NestedMeta::Meta(Meta::NameValue(MetaNameValue {
+            path,
+            lit: Lit::Int(value),
+            ..
+        })) if path.get_ident().is_some() => (
+            path.get_ident().unwrap().to_string(),
+            value.base10_parse().unwrap(),
+        ),
+        _ => panic!(
+            "Invalid `#[frb(instantiate(..))]`, expected `NAME = integer` but got `{}`",
+            quote::quote!(#meta)
+        ),
+    }
+}
+
 /// Checks if the `#[frb(non_final)]` attribute is present.
 pub fn has_non_final(attrs: &[Attribute]) -> bool {
+    has_flag(attrs, "non_final")
+}
+
+/// Checks if the `#[frb(positional)]` attribute is present.
+pub fn has_positional(attrs: &[Attribute]) -> bool {
+    has_flag(attrs, "positional")
+}
+
+/// Checks if the `#[frb(flatten)]` attribute is present.
+pub fn has_flatten(attrs: &[Attribute]) -> bool {
+    has_flag(attrs, "flatten")
+}
+
+/// Checks if the `#[frb(repr_c)]` attribute is present.
+pub fn has_repr_c(attrs: &[Attribute]) -> bool {
+    has_flag(attrs, "repr_c")
+}
+
+/// Checks if the `#[frb(zero_copy)]` attribute is present.
+pub fn has_zero_copy(attrs: &[Attribute]) -> bool {
+    has_flag(attrs, "zero_copy")
+}
+
+/// Checks if the `#[frb(broadcast)]` attribute is present.
+pub fn has_broadcast(attrs: &[Attribute]) -> bool {
+    has_flag(attrs, "broadcast")
+}
+
+/// Checks if the `#[frb(sync)]` attribute is present.
+pub fn has_sync(attrs: &[Attribute]) -> bool {
+    has_flag(attrs, "sync")
+}
+
+/// Checks if the `#[frb(dispatchable)]` attribute is present.
+pub fn has_dispatchable(attrs: &[Attribute]) -> bool {
+    has_flag(attrs, "dispatchable")
+}
+
+/// Checks if the `#[frb(ignore)]` attribute is present.
+pub fn has_ignore(attrs: &[Attribute]) -> bool {
+    has_flag(attrs, "ignore")
+}
+
+/// Checks if the `#[frb(required_params)]` attribute is present.
+pub fn has_required_params(attrs: &[Attribute]) -> bool {
+    has_flag(attrs, "required_params")
+}
+
+/// Checks if the `#[frb(init)]` attribute is present.
+pub fn has_init(attrs: &[Attribute]) -> bool {
+    has_flag(attrs, "init")
+}
+
+/// Checks if the `#[frb(notifies)]` attribute is present.
+pub fn has_notifies(attrs: &[Attribute]) -> bool {
+    has_flag(attrs, "notifies")
+}
+
+/// Checks if the `#[frb(no_isolate)]` attribute is present.
+pub fn has_no_isolate(attrs: &[Attribute]) -> bool {
+    has_flag(attrs, "no_isolate")
+}
+
+/// Extract the pool name from marker `#[frb(worker_pool = "name")]`.
+pub fn extract_worker_pool(attrs: &[Attribute]) -> Option<String> {
+    extract_str_value(attrs, "worker_pool")
+}
+
+/// Extract the Rust expression a field is set to from marker `#[frb(skip)]`, which is
+/// `Default::default()`, or `#[frb(skip = "expr")]`.
+pub fn extract_skip(attrs: &[Attribute]) -> Option<String> {
+    extract_str_value(attrs, "skip")
+        .or_else(|| has_flag(attrs, "skip").then(|| "Default::default()".to_owned()))
+}
+
+/// Extract the Dart expression from marker `#[frb(default = "expr")]` on an argument.
+pub fn extract_default(attrs: &[Attribute]) -> Option<String> {
+    extract_str_value(attrs, "default")
+}
+
+/// Extract the Dart identifier from marker `#[frb(dart_name = "name")]`, which replaces the
+/// one derived from the Rust name.
+///
+/// Panics if it is not a legal public Dart identifier.
+pub fn extract_dart_name(attrs: &[Attribute]) -> Option<String> {
+    lazy_static! {
+        static ref IDENTIFIER: Regex = Regex::new(r"^[a-zA-Z$][a-zA-Z0-9_$]*$").unwrap();
+    }
+    let name = extract_str_value(attrs, "dart_name")?;
+    if !IDENTIFIER.is_match(&name) || DART_RESERVED_WORDS.contains(&name.as_str()) {
+        panic!(
+            "Invalid `#[frb(dart_name = \"{}\")]`, expected a Dart identifier that is \
+            neither private nor a reserved word",
+            name
+        );
+    }
+    Some(name)
+}
+
+/// Extract the Dart expressions from each marker `#[frb(assert = "expr")]` on a struct, in order.
+pub fn extract_asserts(attrs: &[Attribute]) -> Vec<String> {
+    extract_str_values(attrs, "assert")
+}
+
+fn extract_str_value(attrs: &[Attribute], key: &str) -> Option<String> {
     attrs
         .iter()
         .filter(|attr| attr.path.is_ident("frb"))
-        .any(|attr| {
-            match attr.parse_meta() {
-            Ok(Meta::List(MetaList { nested, .. })) => nested.iter().any(|meta| {
-                matches!(meta, NestedMeta::Meta(Meta::Path(path)) if path.is_ident("non_final"))
+        .find_map(|attr| match attr.parse_meta() {
+            Ok(Meta::List(MetaList { nested, .. })) => nested.iter().find_map(|meta| match meta {
+                NestedMeta::Meta(Meta::NameValue(MetaNameValue {
+                    path,
+                    lit: Lit::Str(name),
+                    ..
+                })) if path.is_ident(key) => Some(name.value()),
+                _ => None,
             }),
+            _ => None,
+        })
+}
+
+fn extract_str_values(attrs: &[Attribute], key: &str) -> Vec<String> {
+    attrs
+        .iter()
+        .filter(|attr| attr.path.is_ident("frb"))
+        .filter_map(|attr| match attr.parse_meta() {
+            Ok(Meta::List(MetaList { nested, .. })) => Some(nested),
+            _ => None,
+        })
+        .flatten()
+        .filter_map(|meta| match meta {
+            NestedMeta::Meta(Meta::NameValue(MetaNameValue {
+                path,
+                lit: Lit::Str(value),
+                ..
+            })) if path.is_ident(key) => Some(value.value()),
+            _ => None,
+        })
+        .collect()
+}
+
+fn has_flag(attrs: &[Attribute], flag: &str) -> bool {
+    attrs
+        .iter()
+        .filter(|attr| attr.path.is_ident("frb"))
+        .any(|attr| match attr.parse_meta() {
+            Ok(Meta::List(MetaList { nested, .. })) => nested.iter().any(
+                |meta| matches!(meta, NestedMeta::Meta(Meta::Path(path)) if path.is_ident(flag)),
+            ),
             _ => false,
-        }
         })
 }
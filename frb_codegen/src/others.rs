@@ -21,6 +21,10 @@ pub const DUMMY_WIRE_CODE_FOR_BINDGEN: &str = r#"
     #[no_mangle] pub unsafe extern "C" fn drop_dart_object(ptr: usize) { panic!("dummy code") }
     #[no_mangle] pub unsafe extern "C" fn new_dart_opaque(handle: Dart_Handle) -> usize { panic!("dummy code") }
     #[no_mangle] pub unsafe extern "C" fn init_frb_dart_api_dl(obj: *mut c_void) -> isize { panic!("dummy code") }
+    #[no_mangle] pub unsafe extern "C" fn new_cancel_id() -> i32 { panic!("dummy code") }
+    #[no_mangle] pub unsafe extern "C" fn cancel_task(id: i32) { panic!("dummy code") }
+    #[no_mangle] pub unsafe extern "C" fn new_shared_buffer(len: usize) -> usize { panic!("dummy code") }
+    #[no_mangle] pub unsafe extern "C" fn drop_shared_buffer(address: usize) { panic!("dummy code") }
 
     pub struct DartCObject;
     pub type WireSyncReturn = *mut DartCObject;
@@ -33,7 +37,11 @@ lazy_static! {
         "store_dart_post_cobject".to_owned(),
         "get_dart_object".to_owned(),
         "drop_dart_object".to_owned(),
-        "new_dart_opaque".to_owned()
+        "new_dart_opaque".to_owned(),
+        "new_cancel_id".to_owned(),
+        "cancel_task".to_owned(),
+        "new_shared_buffer".to_owned(),
+        "drop_shared_buffer".to_owned(),
     ];
 }
 
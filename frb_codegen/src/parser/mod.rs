@@ -1,10 +1,15 @@
 pub(crate) mod ty;
 
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
+use std::convert::TryInto;
 use std::string::String;
 
-use log::debug;
-use quote::quote;
+use convert_case::{Case, Casing};
+use itertools::Itertools;
+use lazy_static::lazy_static;
+use log::{debug, warn};
+use quote::{quote, ToTokens};
+use regex::Regex;
 use syn::parse::{Parse, ParseStream};
 use syn::punctuated::Punctuated;
 use syn::token::Colon;
@@ -14,14 +19,17 @@ use topological_sort::TopologicalSort;
 use crate::ir::*;
 
 use crate::generator::rust::HANDLER_NAME;
+use crate::markers;
 use crate::method_utils::FunctionName;
 use crate::parser::ty::TypeParser;
 use crate::source_graph::Crate;
+use crate::utils::dart_string_literal;
 
 use self::ty::convert_ident_str;
 
 const STREAM_SINK_IDENT: &str = "StreamSink";
 const RESULT_IDENT: &str = "Result";
+const CANCELLATION_TOKEN_IDENT: &str = "CancellationToken";
 
 pub(crate) fn topo_resolve(src: HashMap<String, Type>) -> HashMap<String, Type> {
     // Some types that cannot be Handled.
@@ -90,66 +98,241 @@ pub(crate) fn topo_resolve(src: HashMap<String, Type>) -> HashMap<String, Type>
     ret
 }
 
-pub fn parse(source_rust_content: &str, file: File, manifest_path: &str) -> IrFile {
-    let crate_map = Crate::new(manifest_path);
+/// One input file of a block.
+pub struct Source {
+    pub path: String,
+    pub content: String,
+    pub file: File,
+    /// The Rust module whose functions are put in a Dart namespace, see `--dart-namespaces`.
+    pub namespace: Option<String>,
+}
 
-    let mut src_fns = extract_fns_from_file(&file);
-    src_fns.extend(extract_methods_from_file(&file));
+/// Parse the input files of a block into a single [IrFile], failing if several of them
+/// define a function with the same name outside of namespaces.
+pub fn parse(
+    sources: &[Source],
+    manifest_path: &str,
+    dart_char_as_int: bool,
+) -> anyhow::Result<IrFile> {
+    let crate_map = Crate::new(manifest_path);
     let src_structs = crate_map.root_module.collect_structs_to_vec();
     let src_enums = crate_map.root_module.collect_enums_to_vec();
+    let ignored_types = (src_structs.iter().map(|(name, s)| (name, &s.src.attrs)))
+        .chain(src_enums.iter().map(|(name, e)| (name, &e.src.attrs)))
+        .filter(|(_, attrs)| markers::has_ignore(attrs))
+        .map(|(name, _)| name.clone())
+        .collect::<HashSet<_>>();
+    // Neither the items marked `#[frb(ignore)]` nor the methods of the ignored types are
+    // generated, so their types are not even parsed.
+    let is_ignored = |f: &ItemFn| {
+        markers::has_ignore(&f.attrs)
+            || (FunctionName::deserialize(&f.sig.ident.to_string()).struct_name())
+                .is_some_and(|name| ignored_types.contains(&name))
+    };
+
+    let opaque_names = collect_opaque_names(sources.iter().map(|source| &source.file));
+    let traits = collect_traits(sources.iter().map(|source| &source.file));
+    let mut interfaces: Vec<IrInterface> = Vec::new();
+    let mut src_fns = Vec::new();
+    let mut fn_origins = HashMap::new();
+    for source in sources {
+        // Methods stay out of namespaces, as they are reached through their struct in Dart.
+        let fns = extract_fns_from_file(&source.file)
+            .into_iter()
+            .map(|f| (f, source.namespace.clone()))
+            .chain(
+                extract_methods_from_file(&source.file, &opaque_names)
+                    .into_iter()
+                    .map(|f| (f, None)),
+            )
+            .chain(
+                extract_interface_methods_from_file(&source.file, &opaque_names, &traits)
+                    .into_iter()
+                    .map(|f| (f, None)),
+            )
+            .filter(|(f, _)| !is_ignored(f))
+            .collect::<Vec<_>>();
+        for (_, item_trait, implementor) in interface_impls(&source.file, &opaque_names, &traits)
+            .filter(|(_, _, implementor)| !ignored_types.contains(implementor))
+        {
+            let name = item_trait.ident.to_string();
+            match interfaces
+                .iter_mut()
+                .find(|interface| interface.name == name)
+            {
+                Some(interface) => interface.implementors.push(implementor),
+                None => interfaces.push(IrInterface {
+                    name,
+                    comments: extract_comments(&item_trait.attrs),
+                    methods: interface_methods(item_trait)
+                        .map(|method| method.sig.ident.to_string())
+                        .collect(),
+                    implementors: vec![implementor],
+                }),
+            }
+        }
+        for (f, namespace) in &fns {
+            let key = (namespace.clone(), f.sig.ident.to_string());
+            if let Some(other) = fn_origins.insert(key, &source.path) {
+                anyhow::bail!(
+                    "function `{}` is defined in both {} and {}",
+                    f.sig.ident,
+                    other,
+                    source.path
+                );
+            }
+        }
+        src_fns.extend(
+            fns.into_iter()
+                .map(|(f, namespace)| (f, namespace, source.path.clone())),
+        );
+    }
     let src_types = crate_map.root_module.collect_types_to_pool();
     let src_types = topo_resolve(src_types);
 
-    let parser = Parser::new(TypeParser::new(src_structs, src_enums, src_types));
-    parser.parse(source_rust_content, src_fns)
+    let has_executor = sources
+        .iter()
+        .any(|source| source.content.contains(HANDLER_NAME));
+    let consts = sources
+        .iter()
+        .flat_map(|source| extract_consts_from_file(&source.file))
+        .collect();
+
+    let parser = Parser::new(TypeParser::new(
+        src_structs,
+        src_enums,
+        src_types,
+        dart_char_as_int,
+    ));
+    let ir_file = parser.parse(has_executor, src_fns, consts, interfaces);
+    check_custom_codecs(&ir_file, &crate_map)?;
+    Ok(ir_file)
 }
 
 struct Parser<'a> {
     type_parser: TypeParser<'a>,
+    /// The `next` functions of the `DartIterator<T>` handles returned by the functions.
+    iterator_nexts: Vec<IrFunc>,
+    /// The `changes` methods of the opaque types with `#[frb(notifies)]` methods.
+    change_streams: Vec<IrFunc>,
 }
 
 impl<'a> Parser<'a> {
     pub fn new(type_parser: TypeParser<'a>) -> Self {
-        Parser { type_parser }
+        Parser {
+            type_parser,
+            iterator_nexts: Vec::new(),
+            change_streams: Vec::new(),
+        }
     }
 }
 
 impl<'a> Parser<'a> {
-    fn parse(mut self, source_rust_content: &str, src_fns: Vec<ItemFn>) -> IrFile {
-        let funcs = src_fns.iter().map(|f| self.parse_function(f)).collect();
-
-        let has_executor = source_rust_content.contains(HANDLER_NAME);
+    /// Each function comes with its namespace and the path of its file.
+    fn parse(
+        mut self,
+        has_executor: bool,
+        src_fns: Vec<(ItemFn, Option<String>, String)>,
+        consts: Vec<IrConst>,
+        interfaces: Vec<IrInterface>,
+    ) -> IrFile {
+        let (dispatchable, src_fns): (Vec<_>, Vec<_>) = src_fns
+            .into_iter()
+            .partition(|(f, _, _)| markers::has_dispatchable(&f.attrs));
+        let mut parse_src_fns = |src_fns: &[(ItemFn, Option<String>, String)]| -> Vec<IrFunc> {
+            src_fns
+                .iter()
+                .flat_map(|(f, namespace, path)| {
+                    self.parse_function_instances(f)
+                        .into_iter()
+                        .map(move |func| IrFunc {
+                            namespace: namespace.clone(),
+                            span: Some(IrSpan::new(path.as_str(), f.sig.ident.span())),
+                            ..func
+                        })
+                })
+                .collect()
+        };
+        let mut funcs = parse_src_fns(&src_fns);
+        // The `#[frb(dispatchable)]` functions are only reachable through `dispatch`.
+        let dispatchable = parse_src_fns(&dispatchable);
+        if !dispatchable.is_empty() {
+            funcs.push(dispatch_func(dispatchable));
+        }
+        funcs.append(&mut self.iterator_nexts);
+        funcs.append(&mut self.change_streams);
+        // A function of the input with that name takes its place.
+        if !funcs.iter().any(|func| func.name == WARM_UP_NAME) {
+            funcs.push(warm_up_func());
+        }
+        let inits = (funcs.iter())
+            .filter(|func| func.init)
+            .map(|func| format!("`{}`", func.name))
+            .collect::<Vec<_>>();
+        if inits.len() > 1 {
+            panic!(
+                "Only one function may be `#[frb(init)]`, but found {}",
+                inits.join(", ")
+            );
+        }
 
         let (struct_pool, enum_pool) = self.type_parser.consume();
 
-        IrFile {
+        let mut ir_file = IrFile {
             funcs,
             struct_pool,
             enum_pool,
+            consts,
+            interfaces,
             has_executor,
+        };
+        let mut weak_targets = Vec::new();
+        let mut uses_instant = false;
+        // Each type only once, as the recursive ones would be visited forever.
+        for ty in ir_file.distinct_types(true, true) {
+            match ty {
+                IrType::Delegate(IrTypeDelegate::Weak(opaque)) => weak_targets.push(opaque),
+                IrType::Delegate(IrTypeDelegate::Instant) => uses_instant = true,
+                _ => {}
+            }
         }
+        ir_file
+            .funcs
+            .extend(weak_targets.iter().map(weak_upgrade_func));
+        if uses_instant {
+            ir_file.funcs.push(duration_since_func());
+        }
+        check_dart_names(&ir_file);
+        ir_file
     }
 
     /// Attempts to parse the type from the return part of a function signature. There is a special
     /// case for top-level `Result` types.
     pub fn try_parse_fn_output_type(&mut self, ty: &syn::Type) -> Option<IrFuncOutput> {
-        let ty = &self.type_parser.resolve_alias(ty);
+        let ty = &self.type_parser.resolve_alias(ty).clone();
         let inner = ty::SupportedInnerType::try_from_syn_type(ty)?;
         match inner {
-            ty::SupportedInnerType::Path(ty::SupportedPathType {
-                ident,
-                generic: Some(generic),
-            }) if ident == RESULT_IDENT => Some(IrFuncOutput::ResultType(
-                self.type_parser.convert_to_ir_type(*generic)?,
-            )),
+            ty::SupportedInnerType::Path(ty::SupportedPathType { ident, generics })
+                if ident == RESULT_IDENT && !generics.is_empty() =>
+            {
+                let mut generics = generics.into_iter();
+                let ok = self.type_parser.convert_to_ir_type(generics.next()?)?;
+                // `anyhow::Error` keeps being reported as a message, other errors are sent
+                // as structured data.
+                let error = generics
+                    .next()
+                    .filter(|_| !is_anyhow_error(ty))
+                    .and_then(|error| self.type_parser.convert_to_ir_type(error));
+                Some(IrFuncOutput::ResultType { ok, error })
+            }
             _ => Some(IrFuncOutput::Type(
                 self.type_parser.convert_to_ir_type(inner)?,
             )),
         }
     }
 
-    /// Attempts to parse the type from an argument of a function signature. There is a special
-    /// case for top-level `StreamSink` types.
+    /// Attempts to parse the type from an argument of a function signature. There are special
+    /// cases for top-level `StreamSink` and `CancellationToken` types.
     pub fn try_parse_fn_arg_type(&mut self, ty: &syn::Type) -> Option<IrFuncArg> {
         match ty {
             syn::Type::Path(syn::TypePath { path, .. }) => {
@@ -162,22 +345,177 @@ impl<'a> Parser<'a> {
                             // Unwrap is safe here because args.len() == 1
                             match args.last().unwrap() {
                                 syn::GenericArgument::Type(t) => {
-                                    Some(IrFuncArg::StreamSinkType(self.type_parser.parse_type(t)))
+                                    Some(self.parse_stream_sink_item_type(t))
                                 }
                                 _ => None,
                             }
                         }
                         _ => None,
                     }
+                } else if last_segment.ident == CANCELLATION_TOKEN_IDENT {
+                    Some(IrFuncArg::CancellationToken)
                 } else {
                     Some(IrFuncArg::Type(self.type_parser.parse_type(ty)))
                 }
             }
-            syn::Type::Array(_) => Some(IrFuncArg::Type(self.type_parser.parse_type(ty))),
+            syn::Type::Array(_) | syn::Type::Tuple(_) => {
+                Some(IrFuncArg::Type(self.type_parser.parse_type(ty)))
+            }
+            syn::Type::Reference(syn::TypeReference {
+                mutability: None, ..
+            }) => Some(match self.type_parser.convert_verbatim_to_ir_type(ty) {
+                Some(ty) => IrFuncArg::Type(ty),
+                // Any other `&T` borrows the `T` received from Dart.
+                None => IrFuncArg::Borrowed(self.type_parser.parse_type(&owned_output_type(ty)?)),
+            }),
+            syn::Type::ImplTrait(syn::TypeImplTrait { bounds, .. }) => {
+                let inputs = bounds.iter().find_map(|bound| match bound {
+                    TypeParamBound::Trait(TraitBound { path, .. }) => {
+                        let last = path.segments.last()?;
+                        match &last.arguments {
+                            PathArguments::Parenthesized(args)
+                                if matches!(
+                                    last.ident.to_string().as_str(),
+                                    "Fn" | "FnMut" | "FnOnce"
+                                ) =>
+                            {
+                                Some(args)
+                            }
+                            _ => None,
+                        }
+                    }
+                    _ => None,
+                })?;
+                if inputs.inputs.len() > 1 || !matches!(inputs.output, ReturnType::Default) {
+                    panic!(
+                        "Unsupported callback `{}`: Dart closures can only take up to one argument \
+                        and return nothing, since they are called asynchronously",
+                        type_to_string(ty)
+                    );
+                }
+                let arg = inputs
+                    .inputs
+                    .first()
+                    .map(|arg| self.type_parser.parse_type(arg));
+                Some(IrFuncArg::Callback(arg))
+            }
             _ => None,
         }
     }
 
+    /// Parses the item type of a `StreamSink`. For `Result<T, E>` items, `Ok` values become
+    /// data events of the Dart stream, while `Err` values become error events.
+    fn parse_stream_sink_item_type(&mut self, ty: &syn::Type) -> IrFuncArg {
+        let resolved = self.type_parser.resolve_alias(ty);
+        if let Some(ty::SupportedInnerType::Path(ty::SupportedPathType { ident, generics })) =
+            ty::SupportedInnerType::try_from_syn_type(resolved)
+        {
+            if ident == RESULT_IDENT && generics.len() == 2 {
+                let [item, error]: [IrType; 2] = generics
+                    .into_iter()
+                    .map(|generic| {
+                        let generic_str = generic.to_string();
+                        self.type_parser
+                            .convert_to_ir_type(generic)
+                            .unwrap_or_else(|| {
+                                panic!(
+                                    "Failed to parse `{generic_str}` in `StreamSink<{}>`",
+                                    type_to_string(ty)
+                                )
+                            })
+                    })
+                    .collect::<Vec<_>>()
+                    .try_into()
+                    .unwrap();
+                return IrFuncArg::StreamSinkType {
+                    item,
+                    error: Some(error),
+                };
+            }
+        }
+        IrFuncArg::StreamSinkType {
+            item: self.type_parser.parse_type(ty),
+            error: None,
+        }
+    }
+
+    /// Parse one function per `#[frb(instantiate(..))]` of a function generic over const
+    /// parameters, suffixed with the values if there are several of them.
+    fn parse_function_instances(&mut self, func: &ItemFn) -> Vec<IrFunc> {
+        let func_name = func.sig.ident.to_string();
+        let params = (func.sig.generics.params.iter())
+            .filter_map(|param| match param {
+                GenericParam::Const(param) => Some(param.ident.to_string()),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        let instantiations = markers::extract_instantiations(&func.attrs);
+        if params.is_empty() {
+            if !instantiations.is_empty() {
+                panic!(
+                    "Function `{}` has `#[frb(instantiate(..))]` but no const generic parameter",
+                    func_name
+                );
+            }
+            return vec![self.parse_function(func)];
+        }
+        if instantiations.is_empty() {
+            panic!(
+                "Function `{func_name}` is generic over `const {}`, please pick the values to bridge with \
+                `#[frb(instantiate({} = ..))]`",
+                params.join(", const "),
+                params[0],
+            );
+        }
+
+        let suffixed = instantiations.len() > 1;
+        instantiations
+            .into_iter()
+            .map(|instantiation| {
+                let values = instantiation.into_iter().collect::<HashMap<_, _>>();
+                let ordered = (params.iter())
+                    .map(|param| {
+                        *values.get(param).unwrap_or_else(|| {
+                            panic!(
+                                "`#[frb(instantiate(..))]` of function `{}` has no value for `{}`",
+                                func_name, param
+                            )
+                        })
+                    })
+                    .collect::<Vec<_>>();
+                if values.len() != params.len() {
+                    panic!(
+                        "`#[frb(instantiate(..))]` of function `{}` has values for unknown parameters",
+                        func_name
+                    );
+                }
+
+                let mut instance = func.clone();
+                for input in instance.sig.inputs.iter_mut() {
+                    if let FnArg::Typed(pat_type) = input {
+                        substitute_consts(&mut pat_type.ty, &values);
+                    }
+                }
+                if let ReturnType::Type(_, ty) = &mut instance.sig.output {
+                    substitute_consts(ty, &values);
+                }
+                let name = if suffixed {
+                    format!("{func_name}_{}", ordered.iter().join("_"))
+                } else {
+                    func_name.clone()
+                };
+                IrFunc {
+                    name,
+                    const_instance: Some(IrConstInstance {
+                        rust_name: func_name.clone(),
+                        values: ordered,
+                    }),
+                    ..self.parse_function(&instance)
+                }
+            })
+            .collect()
+    }
+
     fn parse_function(&mut self, func: &ItemFn) -> IrFunc {
         debug!("parse_function function name: {:?}", func.sig.ident);
 
@@ -188,37 +526,88 @@ impl<'a> Parser<'a> {
         let mut output = None;
         let mut mode: Option<IrFuncMode> = None;
         let mut fallible = true;
+        let mut error_output = None;
+        let mut cancellation_argument_index = None;
+        let mut callbacks = Vec::new();
+        let mut consumes_receiver = false;
+        let mut stream_initial = None;
 
         for (i, sig_input) in sig.inputs.iter().enumerate() {
             if let FnArg::Typed(ref pat_type) = sig_input {
                 let name = if let Pat::Ident(ref pat_ident) = *pat_type.pat {
+                    // The receiver of a method is only bound by reference if it is borrowed.
+                    if i == 0 && FunctionName::deserialize(&func_name).is_non_static_method() {
+                        consumes_receiver = pat_ident.by_ref.is_none();
+                    }
                     format!("{}", pat_ident.ident)
                 } else {
                     panic!("unexpected pat_type={:?}", pat_type)
                 };
-                match self.try_parse_fn_arg_type(&pat_type.ty).unwrap_or_else(|| {
+                let arg = self.try_parse_fn_arg_type(&pat_type.ty).unwrap_or_else(|| {
                     panic!(
                         "Failed to parse function argument type `{}`",
                         type_to_string(&pat_type.ty)
                     )
-                }) {
-                    IrFuncArg::StreamSinkType(ty) => {
-                        output = Some(ty);
-                        mode = Some(IrFuncMode::Stream { argument_index: i });
-                        fallible = match &sig.output {
-                            ReturnType::Default => false,
-                            ReturnType::Type(_, ty) => !matches!(
-                                self.try_parse_fn_output_type(ty),
-                                Some(IrFuncOutput::Type(_))
-                            ),
+                });
+                let borrowed = matches!(arg, IrFuncArg::Borrowed(_));
+                match arg {
+                    IrFuncArg::StreamSinkType { item, error } => {
+                        output = Some(item);
+                        error_output = error;
+                        mode = Some(IrFuncMode::Stream {
+                            argument_index: i,
+                            broadcast: markers::has_broadcast(&func.attrs),
+                        });
+                        let returned = match &sig.output {
+                            ReturnType::Default => {
+                                Some(IrFuncOutput::Type(IrType::Primitive(IrTypePrimitive::Unit)))
+                            }
+                            ReturnType::Type(_, ty) => self.try_parse_fn_output_type(ty),
+                        };
+                        fallible = !matches!(returned, Some(IrFuncOutput::Type(_)));
+                        let initial = returned.map(|returned| match returned {
+                            IrFuncOutput::Type(ty) => ty,
+                            IrFuncOutput::ResultType { ok, .. } => ok,
+                        });
+                        // Anything but `()` is sent to Dart along with the stream.
+                        stream_initial = initial
+                            .filter(|ty| !matches!(ty, IrType::Primitive(IrTypePrimitive::Unit)));
+                    }
+                    IrFuncArg::Callback(arg) => callbacks.push(IrCallback {
+                        argument_index: i,
+                        name: IrIdent::new(name),
+                        arg,
+                    }),
+                    IrFuncArg::CancellationToken => {
+                        if cancellation_argument_index.replace(i).is_some() {
+                            panic!(
+                                "Function `{}` has more than one `CancellationToken`",
+                                func_name
+                            );
                         }
                     }
-                    IrFuncArg::Type(ty) => {
+                    IrFuncArg::Type(ty) | IrFuncArg::Borrowed(ty) => {
+                        if matches!(
+                            ty,
+                            IrType::Delegate(
+                                IrTypeDelegate::BoxDyn(_) | IrTypeDelegate::ImplTrait(_)
+                            )
+                        ) {
+                            panic!(
+                                "Argument `{}` of function `{}` is a trait object, \
+                                which cannot be taken back from Dart. Please take `&dyn Trait` instead.",
+                                name, func_name
+                            );
+                        }
                         inputs.push(IrField {
                             name: IrIdent::new(name),
                             ty,
                             is_final: true,
                             comments: extract_comments(&pat_type.attrs),
+                            default: markers::extract_default(&pat_type.attrs),
+                            flatten: false,
+                            zero_copy: false,
+                            borrowed,
                         });
                     }
                 }
@@ -227,16 +616,31 @@ impl<'a> Parser<'a> {
             }
         }
 
+        let mut borrowed_output = false;
+        let mut iterator = None;
         if output.is_none() {
             output = Some(match &sig.output {
+                ReturnType::Type(_, ty) if iterator_item_type(ty).is_some() => {
+                    fallible = false;
+                    let (handle, returned) =
+                        self.parse_iterator_output(&func_name, iterator_item_type(ty).unwrap());
+                    iterator = Some(returned);
+                    IrType::RustOpaque(handle)
+                }
                 ReturnType::Type(_, ty) => {
+                    let owned = owned_output_type(ty);
+                    borrowed_output = owned.is_some();
+                    let ty = owned.as_ref().unwrap_or(ty);
                     match self.try_parse_fn_output_type(ty).unwrap_or_else(|| {
                         panic!(
                             "Failed to parse function output type `{}`",
                             type_to_string(ty)
                         )
                     }) {
-                        IrFuncOutput::ResultType(ty) => ty,
+                        IrFuncOutput::ResultType { ok, error } => {
+                            error_output = error;
+                            ok
+                        }
                         IrFuncOutput::Type(ty) => {
                             fallible = false;
                             ty
@@ -255,17 +659,603 @@ impl<'a> Parser<'a> {
             });
         }
 
+        if cancellation_argument_index.is_some() && mode != Some(IrFuncMode::Normal) {
+            panic!(
+                "Function `{}` takes a `CancellationToken`, \
+                which is only supported by functions returning a `Future` in Dart",
+                func_name
+            );
+        }
+
+        if iterator.is_some()
+            && (cancellation_argument_index.is_some()
+                || markers::has_sync(&func.attrs)
+                || markers::has_no_isolate(&func.attrs))
+        {
+            panic!(
+                "Function `{}` returns an iterator, which Dart pulls as a `Stream`, \
+                so it cannot take a `CancellationToken` nor be `#[frb(sync)]` or `#[frb(no_isolate)]`",
+                func_name
+            );
+        }
+
+        let mut output = output.expect("unsupported output");
+        let inline = markers::has_sync(&func.attrs);
+        if inline {
+            if mode != Some(IrFuncMode::Normal)
+                || cancellation_argument_index.is_some()
+                || markers::has_no_isolate(&func.attrs)
+            {
+                panic!(
+                    "Function `{}` cannot be `#[frb(sync)]`: only functions returning a \
+                    `Future` in Dart can, without a `CancellationToken` nor `#[frb(no_isolate)]`",
+                    func_name
+                );
+            }
+            output = IrType::SyncReturn(IrTypeSyncReturn::new(output));
+            mode = Some(IrFuncMode::Sync);
+        }
+        if markers::has_no_isolate(&func.attrs) {
+            if let IrType::SyncReturn(sync_return) = output {
+                output = sync_return.into_inner();
+            }
+            let is_direct_arg = |ty: &IrType| {
+                matches!(
+                    ty,
+                    IrType::Primitive(_)
+                        | IrType::Delegate(IrTypeDelegate::Array(
+                            IrTypeDelegateArray::PrimitiveArray { .. }
+                        ))
+                )
+            };
+            if !matches!(mode, Some(IrFuncMode::Normal | IrFuncMode::Sync))
+                || fallible
+                || cancellation_argument_index.is_some()
+                || !callbacks.is_empty()
+                || !matches!(output, IrType::Primitive(_))
+                || !inputs.iter().all(|input| is_direct_arg(&input.ty))
+            {
+                panic!(
+                    "Function `{}` cannot be `#[frb(no_isolate)]`: its arguments must be \
+                    primitives or arrays of primitives, and it must return a primitive or nothing, \
+                    without a `Result`, `StreamSink`, `CancellationToken` or callback",
+                    func_name
+                );
+            }
+            mode = Some(IrFuncMode::Direct);
+        }
+
+        let worker_pool = markers::extract_worker_pool(&func.attrs);
+        if worker_pool.is_some()
+            && !matches!(mode, Some(IrFuncMode::Normal | IrFuncMode::Stream { .. }))
+        {
+            panic!(
+                "Function `{}` cannot have a `worker_pool`, since it runs on the calling thread",
+                func_name
+            );
+        }
+
+        let init = markers::has_init(&func.attrs);
+        let f = FunctionName::deserialize(&func_name);
+        if init
+            && (f.is_non_static_method()
+                || f.is_static_method()
+                || !inputs.is_empty()
+                || !callbacks.is_empty()
+                || cancellation_argument_index.is_some()
+                || !matches!(sig.output, ReturnType::Default))
+        {
+            panic!(
+                "Function `{}` cannot be `#[frb(init)]`: it must be a free function \
+                taking no arguments and returning nothing",
+                func_name
+            );
+        }
+
+        let notifies = markers::has_notifies(&func.attrs);
+        if notifies {
+            match inputs.first() {
+                Some(IrField {
+                    ty: IrType::RustOpaque(opaque),
+                    ..
+                }) if f.is_non_static_method()
+                    && !consumes_receiver
+                    && !matches!(mode, Some(IrFuncMode::Stream { .. }))
+                    && iterator.is_none() =>
+                {
+                    self.add_change_stream(f.struct_name().unwrap(), opaque.clone())
+                }
+                _ => panic!(
+                    "Method `{}` cannot be `#[frb(notifies)]`: it must take `&self` of \
+                    an opaque type, and return neither a stream nor an iterator",
+                    func_name
+                ),
+            }
+        }
+
         IrFunc {
             name: func_name,
+            dart_name: markers::extract_dart_name(&func.attrs),
             inputs,
-            output: output.expect("unsupported output"),
+            output,
+            error_output,
             fallible,
             mode: mode.expect("missing mode"),
+            cancellation_argument_index,
+            callbacks,
+            consumes_receiver,
+            borrowed_output,
+            is_async: sig.asyncness.is_some(),
+            inline,
+            worker_pool,
+            required_params: markers::has_required_params(&func.attrs),
+            init,
+            warm_up: false,
+            weak_upgrade: false,
+            duration_since: false,
+            drain: None,
+            dispatch: vec![],
             comments: extract_comments(&func.attrs),
+            namespace: None,
+            const_instance: None,
+            iterator,
+            changes: notifies.then_some(IrFuncChanges::Notifies),
+            stream_initial,
+            span: None,
+        }
+    }
+
+    /// Add the `changes` method of the opaque type `struct_name`, unless it already has one.
+    fn add_change_stream(&mut self, struct_name: String, opaque: IrTypeRustOpaque) {
+        let name = FunctionName::new(
+            "changes",
+            crate::method_utils::MethodInfo::NonStatic { struct_name },
+        )
+        .serialize();
+        if self.change_streams.iter().any(|func| func.name == name) {
+            return;
+        }
+        self.change_streams.push(IrFunc {
+            name,
+            dart_name: None,
+            inputs: vec![IrField {
+                ty: IrType::RustOpaque(opaque),
+                name: IrIdent::new("that".to_owned()),
+                is_final: true,
+                comments: vec![],
+                default: None,
+                flatten: false,
+                zero_copy: false,
+                borrowed: false,
+            }],
+            output: IrType::Primitive(IrTypePrimitive::Unit),
+            error_output: None,
+            fallible: false,
+            mode: IrFuncMode::Stream {
+                argument_index: 1,
+                broadcast: false,
+            },
+            cancellation_argument_index: None,
+            callbacks: vec![],
+            consumes_receiver: false,
+            borrowed_output: false,
+            is_async: false,
+            inline: false,
+            worker_pool: None,
+            required_params: false,
+            init: false,
+            warm_up: false,
+            weak_upgrade: false,
+            duration_since: false,
+            drain: None,
+            dispatch: vec![],
+            comments: vec![IrComment::from(
+                " An event after each successful call of a `#[frb(notifies)]` method of this object.",
+            )],
+            namespace: None,
+            const_instance: None,
+            iterator: None,
+            changes: Some(IrFuncChanges::Stream),
+            stream_initial: None,
+            span: None,
+        });
+    }
+
+    /// For a function returning `impl Iterator<Item = item>`, the opaque `DartIterator<T>` handle
+    /// which keeps the iterator. The `next` function of the handle type is added on first use.
+    fn parse_iterator_output(
+        &mut self,
+        func_name: &str,
+        item: &Type,
+    ) -> (IrTypeRustOpaque, IrFuncIterator) {
+        let item_ty = self.type_parser.parse_type(item);
+        if let IrType::Optional(_) = item_ty {
+            panic!(
+                "Function `{}` returns an iterator of `Option`s, which is not supported, \
+                since `None` ends the Dart stream. Use an enum instead.",
+                func_name
+            );
+        }
+        let handle = IrTypeRustOpaque::from(format!("DartIterator<{}>", item_ty.rust_api_type()));
+        let next = format!("next_{}", handle.inner_dart.to_case(Case::Snake));
+        if !self.iterator_nexts.iter().any(|func| func.name == next) {
+            self.iterator_nexts.push(IrFunc {
+                name: next.clone(),
+                dart_name: None,
+                inputs: vec![IrField {
+                    ty: IrType::RustOpaque(handle.clone()),
+                    name: IrIdent::new("iterator".to_owned()),
+                    is_final: true,
+                    comments: vec![],
+                    default: None,
+                    flatten: false,
+                    zero_copy: false,
+                    borrowed: false,
+                }],
+                output: self.type_parser.parse_type(&parse_quote!(Option<#item>)),
+                error_output: None,
+                fallible: false,
+                mode: IrFuncMode::Normal,
+                cancellation_argument_index: None,
+                callbacks: vec![],
+                consumes_receiver: false,
+                borrowed_output: false,
+                is_async: false,
+                inline: false,
+                worker_pool: None,
+                required_params: false,
+                init: false,
+                warm_up: false,
+                weak_upgrade: false,
+                duration_since: false,
+                drain: None,
+                dispatch: vec![],
+                comments: vec![IrComment::from(
+                    " Pulls the next item of an iterator returned by Rust, or `null` once it is exhausted.",
+                )],
+                namespace: None,
+                const_instance: None,
+                iterator: Some(IrFuncIterator::Next),
+                changes: None,
+                stream_initial: None,
+                span: None,
+            });
+        }
+        (
+            handle,
+            IrFuncIterator::Returned {
+                item: item_ty,
+                next,
+            },
+        )
+    }
+}
+
+const WARM_UP_NAME: &str = "warm_up";
+const DISPATCH_NAME: &str = "dispatch";
+
+/// The function calling the `#[frb(dispatchable)]` function identified by its `id` argument,
+/// by its Dart name. They must all be free functions with the same arguments and output.
+fn dispatch_func(targets: Vec<IrFunc>) -> IrFunc {
+    let first = &targets[0];
+    for func in &targets {
+        if FunctionName::deserialize(&func.name)
+            .struct_name()
+            .is_some()
+            || func.mode != IrFuncMode::Normal
+            || func.inline
+            || func.is_async
+            || func.borrowed_output
+            || func.error_output.is_some()
+            || !func.callbacks.is_empty()
+            || func.cancellation_argument_index.is_some()
+            || func.iterator.is_some()
+            || func.init
+        {
+            panic!(
+                "Function `{}` cannot be `#[frb(dispatchable)]`: it must be a free function \
+                which is neither async nor sync, takes no callback nor `CancellationToken`, \
+                and returns an owned value, possibly in an `anyhow::Result`",
+                func.name
+            );
+        }
+        let same_inputs = (func.inputs.iter().map(|input| (&input.ty, input.borrowed)))
+            .eq(first.inputs.iter().map(|input| (&input.ty, input.borrowed)));
+        if !same_inputs || func.output != first.output {
+            panic!(
+                "The `#[frb(dispatchable)]` functions `{}` and `{}` must have the same arguments \
+                and output",
+                first.name, func.name
+            );
+        }
+    }
+    let dispatch = (targets.iter())
+        .map(|func| IrDispatchTarget {
+            id: func.dart_style(),
+            rust_call_path: func.rust_call_path(),
+            fallible: func.fallible,
+        })
+        .collect::<Vec<_>>();
+    if let Some(id) = dispatch.iter().map(|target| &target.id).duplicates().next() {
+        panic!(
+            "Several `#[frb(dispatchable)]` functions are identified by `{}`",
+            id
+        );
+    }
+    let comment = format!(
+        " Calls the function identified by `id`, one of {}, or throws if there is none.",
+        (dispatch.iter())
+            .map(|target| format!("`{}`", target.id))
+            .join(", ")
+    );
+    let id = IrField {
+        ty: IrType::Delegate(IrTypeDelegate::String),
+        name: IrIdent::new("id".to_owned()),
+        is_final: true,
+        comments: vec![],
+        default: None,
+        flatten: false,
+        zero_copy: false,
+        borrowed: false,
+    };
+    IrFunc {
+        name: DISPATCH_NAME.to_owned(),
+        dart_name: None,
+        inputs: [vec![id], first.inputs.clone()].concat(),
+        output: first.output.clone(),
+        error_output: None,
+        fallible: true,
+        mode: IrFuncMode::Normal,
+        cancellation_argument_index: None,
+        callbacks: vec![],
+        consumes_receiver: false,
+        borrowed_output: false,
+        is_async: false,
+        inline: false,
+        worker_pool: None,
+        required_params: false,
+        init: false,
+        warm_up: false,
+        weak_upgrade: false,
+        duration_since: false,
+        drain: None,
+        dispatch,
+        comments: vec![IrComment::from(comment.as_str())],
+        namespace: None,
+        const_instance: None,
+        iterator: None,
+        changes: None,
+        stream_initial: None,
+        span: None,
+    }
+}
+
+fn warm_up_func() -> IrFunc {
+    IrFunc {
+        name: WARM_UP_NAME.to_owned(),
+        dart_name: None,
+        inputs: vec![],
+        output: IrType::Primitive(IrTypePrimitive::Unit),
+        error_output: None,
+        fallible: false,
+        mode: IrFuncMode::Normal,
+        cancellation_argument_index: None,
+        callbacks: vec![],
+        consumes_receiver: false,
+        borrowed_output: false,
+        is_async: false,
+        inline: false,
+        worker_pool: None,
+        required_params: false,
+        init: false,
+        warm_up: true,
+        weak_upgrade: false,
+        duration_since: false,
+        drain: None,
+        dispatch: vec![],
+        comments: vec![IrComment::from(
+            " Starts the worker pool and runs the `#[frb(init)]` function, if any, so that the first real call does not wait for them. Calling it again does nothing.",
+        )],
+        namespace: None,
+        const_instance: None,
+        iterator: None,
+        changes: None,
+        stream_initial: None,
+        span: None,
+    }
+}
+
+/// The function upgrading the handles of `Weak<T>`, for the opaque type `target` of `T`.
+fn weak_upgrade_func(target: &IrTypeRustOpaque) -> IrFunc {
+    let handle = target.weak();
+    IrFunc {
+        name: format!("upgrade_{}", handle.inner_dart.to_case(Case::Snake)),
+        dart_name: None,
+        inputs: vec![IrField {
+            ty: IrType::RustOpaque(handle),
+            name: IrIdent::new("weak".to_owned()),
+            is_final: true,
+            comments: vec![],
+            default: None,
+            flatten: false,
+            zero_copy: false,
+            borrowed: false,
+        }],
+        output: IrType::Optional(IrTypeOptional::new_boxed(IrType::RustOpaque(
+            target.clone(),
+        ))),
+        error_output: None,
+        fallible: false,
+        mode: IrFuncMode::Normal,
+        cancellation_argument_index: None,
+        callbacks: vec![],
+        consumes_receiver: false,
+        borrowed_output: false,
+        is_async: false,
+        inline: false,
+        worker_pool: None,
+        required_params: false,
+        init: false,
+        warm_up: false,
+        weak_upgrade: true,
+        duration_since: false,
+        drain: None,
+        dispatch: vec![],
+        comments: vec![IrComment::from(
+            " A new handle to the object of a weak handle, or `null` if it has been dropped.",
+        )],
+        namespace: None,
+        const_instance: None,
+        iterator: None,
+        changes: None,
+        stream_initial: None,
+        span: None,
+    }
+}
+
+/// The function returning the time elapsed between two `Instant` handles, see
+/// [IrTypeDelegate::Instant].
+fn duration_since_func() -> IrFunc {
+    let instant = |name: &str| IrField {
+        ty: IrType::RustOpaque(IrTypeRustOpaque::instant()),
+        name: IrIdent::new(name.to_owned()),
+        is_final: true,
+        comments: vec![],
+        default: None,
+        flatten: false,
+        zero_copy: false,
+        borrowed: false,
+    };
+    IrFunc {
+        name: "instant_duration_since".to_owned(),
+        dart_name: None,
+        inputs: vec![instant("that"), instant("earlier")],
+        output: IrType::Delegate(IrTypeDelegate::Time(IrTypeTime::Duration)),
+        error_output: None,
+        fallible: false,
+        mode: IrFuncMode::Normal,
+        cancellation_argument_index: None,
+        callbacks: vec![],
+        consumes_receiver: false,
+        borrowed_output: false,
+        is_async: false,
+        inline: false,
+        worker_pool: None,
+        required_params: false,
+        init: false,
+        warm_up: false,
+        weak_upgrade: false,
+        duration_since: true,
+        drain: None,
+        dispatch: vec![],
+        comments: vec![IrComment::from(
+            " The time elapsed from `earlier` to `that`, or zero if `earlier` is the later one.",
+        )],
+        namespace: None,
+        const_instance: None,
+        iterator: None,
+        changes: None,
+        stream_initial: None,
+        span: None,
+    }
+}
+
+/// Panics if two functions of the same Dart class, two fields of a struct, two variants of
+/// an enum or two constants have the same Dart name, e.g. because of `#[frb(dart_name = "..")]`.
+fn check_dart_names(ir_file: &IrFile) {
+    fn check_unique(scope: &str, names: impl IntoIterator<Item = (String, String)>) {
+        let mut seen = HashMap::new();
+        for (dart_name, rust_name) in names {
+            if let Some(other) = seen.insert(dart_name.clone(), rust_name.clone()) {
+                panic!(
+                    "`{}` and `{}` of {} have the same Dart name `{}`, \
+                    please rename one of them with `#[frb(dart_name = \"..\")]`",
+                    other, rust_name, scope, dart_name
+                );
+            }
+        }
+    }
+    fn check_fields(scope: &str, fields: &[IrField]) {
+        check_unique(
+            scope,
+            (fields.iter()).map(|field| (field.name.dart_style(), field.name.to_string())),
+        );
+    }
+
+    // The functions of a namespace are generated on their own class, and methods on the class
+    // of their struct in addition to the API class.
+    let mut classes = HashMap::<String, Vec<(String, String)>>::new();
+    for func in &ir_file.funcs {
+        let f = FunctionName::deserialize(&func.name);
+        let class = match &func.namespace {
+            Some(namespace) => format!("namespace `{namespace}`"),
+            None => "the API class".to_owned(),
+        };
+        let names = classes.entry(class).or_default();
+        names.push((func.dart_style(), func.name.clone()));
+        if func.has_sync_result() {
+            names.push((
+                format!("{}Result", func.dart_style()),
+                format!("{} (as a `SyncResult`)", func.name),
+            ));
+        }
+        if let Some(struct_name) = f.struct_name() {
+            classes
+                .entry(format!("class `{struct_name}`"))
+                .or_default()
+                .push((func.dart_method_name(), f.method_name()));
+        }
+    }
+    for (class, names) in classes {
+        check_unique(&class, names);
+    }
+    check_unique(
+        "the constants",
+        (ir_file.consts.iter()).map(|it| (it.name.dart_style(), it.name.to_string())),
+    );
+
+    for st in ir_file.struct_pool.values() {
+        check_fields(&format!("struct `{}`", st.name), &st.fields);
+    }
+    for enu in ir_file.enum_pool.values() {
+        check_unique(
+            &format!("enum `{}`", enu.name),
+            (enu.variants().iter()).map(|variant| {
+                let dart_name = if enu.is_struct() {
+                    variant.name.dart_style()
+                } else {
+                    (variant.name.dart_name.clone()).unwrap_or_else(|| variant.name.to_string())
+                };
+                (dart_name, variant.name.to_string())
+            }),
+        );
+        for variant in enu.variants() {
+            if let IrVariantKind::Struct(st) = &variant.kind {
+                check_fields(
+                    &format!("variant `{}::{}`", enu.name, variant.name),
+                    &st.fields,
+                );
+            }
         }
     }
 }
 
+/// Whether the error type of `Result<T, E>` is `anyhow::Error`.
+fn is_anyhow_error(result: &Type) -> bool {
+    let args = match result {
+        Type::Path(TypePath { path, .. }) => match path.segments.last().map(|s| &s.arguments) {
+            Some(PathArguments::AngleBracketed(args)) => args,
+            _ => return false,
+        },
+        _ => return false,
+    };
+    matches!(
+        args.args.iter().nth(1),
+        Some(GenericArgument::Type(Type::Path(TypePath { path, .. })))
+            if path.segments.first().is_some_and(|s| s.ident == "anyhow")
+    )
+}
+
 fn extract_fns_from_file(file: &File) -> Vec<ItemFn> {
     let mut src_fns = Vec::new();
 
@@ -280,7 +1270,141 @@ fn extract_fns_from_file(file: &File) -> Vec<ItemFn> {
     src_fns
 }
 
-fn extract_methods_from_file(file: &File) -> Vec<ItemFn> {
+/// The `pub const` and `pub static` items of `file` which Dart can have as constants: the
+/// ones holding a literal of a primitive type or `&str`. The others are skipped.
+fn extract_consts_from_file(file: &File) -> Vec<IrConst> {
+    file.items
+        .iter()
+        .filter_map(|item| match item {
+            Item::Const(ItemConst {
+                vis: Visibility::Public(_),
+                attrs,
+                ident,
+                ty,
+                expr,
+                ..
+            })
+            | Item::Static(ItemStatic {
+                vis: Visibility::Public(_),
+                mutability: None,
+                attrs,
+                ident,
+                ty,
+                expr,
+                ..
+            }) if !markers::has_ignore(attrs) => {
+                let ty = parse_const_type(ty)?;
+                let value = dart_const_literal(&ty, expr);
+                if value.is_none() {
+                    warn!(
+                        "`{ident}` is not exposed to Dart, as its value is not a literal \
+                        which Dart can represent"
+                    );
+                }
+                Some(IrConst {
+                    name: IrIdent::new(ident.to_string())
+                        .with_dart_name(markers::extract_dart_name(attrs)),
+                    ty,
+                    value: value?,
+                    comments: extract_comments(attrs),
+                })
+            }
+            _ => None,
+        })
+        .collect()
+}
+
+fn parse_const_type(ty: &Type) -> Option<IrType> {
+    match ty {
+        Type::Reference(TypeReference {
+            mutability: None,
+            elem,
+            ..
+        }) if matches!(&**elem, Type::Path(path) if path.path.is_ident("str")) => {
+            Some(IrType::Delegate(IrTypeDelegate::String))
+        }
+        Type::Path(TypePath { qself: None, path }) => path
+            .get_ident()
+            .and_then(|ident| IrTypePrimitive::try_from_rust_str(&ident.to_string()))
+            .filter(|primitive| *primitive != IrTypePrimitive::Unit)
+            .map(IrType::Primitive),
+        _ => None,
+    }
+}
+
+/// The Dart literal of `expr`, a constant of type `ty`, if it is a literal Dart can represent.
+fn dart_const_literal(ty: &IrType, expr: &Expr) -> Option<String> {
+    let (negative, lit) = match expr {
+        Expr::Lit(ExprLit { lit, .. }) => (false, lit),
+        Expr::Unary(ExprUnary {
+            op: UnOp::Neg(_),
+            expr,
+            ..
+        }) => match &**expr {
+            Expr::Lit(ExprLit { lit, .. }) => (true, lit),
+            _ => return None,
+        },
+        _ => return None,
+    };
+    let sign = if negative { "-" } else { "" };
+    match (ty, lit) {
+        (IrType::Primitive(IrTypePrimitive::Bool), Lit::Bool(lit)) if !negative => {
+            Some(lit.value.to_string())
+        }
+        (IrType::Primitive(IrTypePrimitive::F32 | IrTypePrimitive::F64), Lit::Float(lit)) => {
+            // Rust accepts `1.`, which Dart does not.
+            let digits = lit.base10_digits();
+            Some(match digits.strip_suffix('.') {
+                Some(int) => format!("{sign}{int}.0"),
+                None => format!("{sign}{digits}"),
+            })
+        }
+        (IrType::Primitive(IrTypePrimitive::F32 | IrTypePrimitive::F64), Lit::Int(lit)) => {
+            Some(format!("{sign}{}.0", lit.base10_digits()))
+        }
+        // Dart integers are 64-bit, so the largest `u64`s cannot be written.
+        (IrType::Primitive(_), Lit::Int(lit)) => format!("{sign}{}", lit.base10_digits())
+            .parse::<i64>()
+            .ok()
+            .map(|value| value.to_string()),
+        (IrType::Delegate(IrTypeDelegate::String), Lit::Str(lit)) if !negative => {
+            Some(dart_string_literal(&lit.value()))
+        }
+        _ => None,
+    }
+}
+
+/// The types used as `RustOpaque<T>` in the signatures and type aliases of `files`. Their
+/// methods are put on the Dart handle class, and receive the handle instead of a copy.
+fn collect_opaque_names<'a>(files: impl IntoIterator<Item = &'a File>) -> HashSet<String> {
+    lazy_static! {
+        static ref OPAQUE: Regex = Regex::new(r"\bRustOpaque\s*<\s*(\w+)\s*>").unwrap();
+    }
+    let mut names = HashSet::new();
+    let mut visit = |tokens: &dyn ToTokens| {
+        let tokens = tokens.to_token_stream().to_string();
+        names.extend(OPAQUE.captures_iter(&tokens).map(|c| c[1].to_owned()));
+    };
+    for file in files {
+        for item in &file.items {
+            match item {
+                Item::Fn(item_fn) if !markers::has_ignore(&item_fn.attrs) => visit(&item_fn.sig),
+                Item::Impl(item_impl) => {
+                    for item in &item_impl.items {
+                        if let ImplItem::Method(item_method) = item {
+                            visit(&item_method.sig);
+                        }
+                    }
+                }
+                Item::Type(item_type) => visit(&item_type.ty),
+                _ => {}
+            }
+        }
+    }
+    names
+}
+
+fn extract_methods_from_file(file: &File, opaque_names: &HashSet<String>) -> Vec<ItemFn> {
     let mut src_fns = Vec::new();
 
     for item in file.items.iter() {
@@ -288,7 +1412,7 @@ fn extract_methods_from_file(file: &File) -> Vec<ItemFn> {
             for item in &item_impl.items {
                 if let ImplItem::Method(item_method) = item {
                     if let Visibility::Public(_) = &item_method.vis {
-                        let f = item_method_to_function(item_impl, item_method)
+                        let f = item_method_to_function(item_impl, item_method, opaque_names)
                             .expect("item implementation is unsupported");
                         src_fns.push(f);
                     }
@@ -300,10 +1424,98 @@ fn extract_methods_from_file(file: &File) -> Vec<ItemFn> {
     src_fns
 }
 
+/// The `pub` traits of `files`, which may be implemented by opaque types, see [IrInterface].
+fn collect_traits<'a>(files: impl IntoIterator<Item = &'a File>) -> HashMap<String, &'a ItemTrait> {
+    files
+        .into_iter()
+        .flat_map(|file| &file.items)
+        .filter_map(|item| match item {
+            Item::Trait(item_trait) if matches!(item_trait.vis, Visibility::Public(_)) => {
+                Some((item_trait.ident.to_string(), item_trait))
+            }
+            _ => None,
+        })
+        .collect()
+}
+
+/// The methods of a trait taking `&self`, which make up its Dart interface.
+fn interface_methods(item_trait: &ItemTrait) -> impl Iterator<Item = &TraitItemMethod> {
+    item_trait.items.iter().filter_map(|item| match item {
+        TraitItem::Method(method)
+            if matches!(
+                method.sig.inputs.first(),
+                Some(FnArg::Receiver(Receiver {
+                    reference: Some(_),
+                    mutability: None,
+                    ..
+                }))
+            ) =>
+        {
+            Some(method)
+        }
+        _ => None,
+    })
+}
+
+/// The `impl Trait for T` blocks of `file` for an opaque `T` and one of `traits`, along with
+/// the trait and the name of `T`.
+fn interface_impls<'a>(
+    file: &'a File,
+    opaque_names: &'a HashSet<String>,
+    traits: &'a HashMap<String, &'a ItemTrait>,
+) -> impl Iterator<Item = (&'a ItemImpl, &'a ItemTrait, String)> {
+    file.items.iter().filter_map(move |item| {
+        let item_impl = match item {
+            Item::Impl(item_impl) => item_impl,
+            _ => return None,
+        };
+        let (_, trait_path, _) = item_impl.trait_.as_ref()?;
+        let item_trait = traits.get(&trait_path.segments.last()?.ident.to_string())?;
+        let implementor = match item_impl.self_ty.as_ref() {
+            Type::Path(p) => p.path.segments.first()?.ident.to_string(),
+            _ => return None,
+        };
+        opaque_names
+            .contains(&implementor)
+            .then_some((item_impl, *item_trait, implementor))
+    })
+}
+
+/// The methods of the Dart interfaces implemented by the opaque types of `file`, as if they
+/// were declared in an `impl` block of each type, including the ones with a default body.
+fn extract_interface_methods_from_file(
+    file: &File,
+    opaque_names: &HashSet<String>,
+    traits: &HashMap<String, &ItemTrait>,
+) -> Vec<ItemFn> {
+    interface_impls(file, opaque_names, traits)
+        .flat_map(|(item_impl, item_trait, _)| {
+            interface_methods(item_trait).map(move |method| {
+                let item_method = ImplItemMethod {
+                    attrs: method.attrs.clone(),
+                    vis: parse_quote!(pub),
+                    defaultness: None,
+                    sig: method.sig.clone(),
+                    block: parse_quote!({}),
+                };
+                item_method_to_function(item_impl, &item_method, opaque_names)
+                    .expect("item implementation is unsupported")
+            })
+        })
+        .collect()
+}
+
 // Converts an item implementation (something like fn(&self, ...)) into a function where `&self` is a named parameter to `&Self`
-fn item_method_to_function(item_impl: &ItemImpl, item_method: &ImplItemMethod) -> Option<ItemFn> {
+// For opaque types, `self` becomes the handle, i.e. `RustOpaque<Self>`, and is only bound by
+// value if the method consumes it.
+fn item_method_to_function(
+    item_impl: &ItemImpl,
+    item_method: &ImplItemMethod,
+    opaque_names: &HashSet<String>,
+) -> Option<ItemFn> {
     if let Type::Path(p) = item_impl.self_ty.as_ref() {
         let struct_name = p.path.segments.first().unwrap().ident.to_string();
+        let is_opaque = opaque_names.contains(&struct_name);
         let span = item_method.sig.ident.span();
         let is_static_method = {
             let Signature { inputs, .. } = &item_method.sig;
@@ -352,11 +1564,11 @@ fn item_method_to_function(item_impl: &ItemImpl, item_method: &ImplItemMethod) -
         };
 
         Some(ItemFn {
-            attrs: vec![],
+            attrs: item_method.attrs.clone(),
             vis: item_method.vis.clone(),
             sig: Signature {
                 constness: None,
-                asyncness: None,
+                asyncness: item_method.sig.asyncness,
                 unsafety: None,
                 abi: None,
                 fn_token: item_method.sig.fn_token,
@@ -368,21 +1580,44 @@ fn item_method_to_function(item_impl: &ItemImpl, item_method: &ImplItemMethod) -
                     .inputs
                     .iter()
                     .map(|input| {
-                        if let FnArg::Receiver(Receiver { mutability, .. }) = input {
-                            let mut segments = Punctuated::new();
-                            segments.push(PathSegment {
+                        if let FnArg::Receiver(Receiver {
+                            reference,
+                            mutability,
+                            ..
+                        }) = input
+                        {
+                            let self_segment = PathSegment {
                                 ident: Ident::new(struct_name.as_str(), span),
                                 arguments: PathArguments::None,
-                            });
-                            if mutability.is_some() {
+                            };
+                            let mut segments = Punctuated::new();
+                            if is_opaque {
+                                segments.push(PathSegment {
+                                    ident: Ident::new("RustOpaque", span),
+                                    arguments: PathArguments::AngleBracketed(
+                                        parse_quote!(<#self_segment>),
+                                    ),
+                                });
+                            } else {
+                                segments.push(self_segment);
+                            }
+                            if reference.is_some() && mutability.is_some() {
+                                if is_opaque {
+                                    panic!(
+                                        "`{struct_name}::{}` takes `&mut self`, but `RustOpaque<{struct_name}>` \
+                                        may be shared by several Dart handles. Please use interior mutability, \
+                                        e.g. a `Mutex` field, or take `self` to consume the handle.",
+                                        item_method.sig.ident
+                                    );
+                                }
                                 panic!("mutable methods are unsupported for safety reasons");
                             }
                             FnArg::Typed(PatType {
                                 attrs: vec![],
                                 pat: Box::new(Pat::Ident(PatIdent {
                                     attrs: vec![],
-                                    by_ref: Some(syn::token::Ref { span }),
-                                    mutability: *mutability,
+                                    by_ref: reference.as_ref().map(|_| syn::token::Ref { span }),
+                                    mutability: None,
                                     ident: Ident::new("that", span),
                                     subpat: None,
                                 })),
@@ -427,8 +1662,15 @@ fn extract_comments(attrs: &[Attribute]) -> Vec<IrComment> {
 pub mod frb_keyword {
     syn::custom_keyword!(mirror);
     syn::custom_keyword!(non_final);
+    syn::custom_keyword!(broadcast);
     syn::custom_keyword!(dart_metadata);
+    syn::custom_keyword!(dart_type);
+    syn::custom_keyword!(from_raw);
+    syn::custom_keyword!(to_raw);
     syn::custom_keyword!(import);
+    syn::custom_keyword!(custom_codec);
+    syn::custom_keyword!(rust);
+    syn::custom_keyword!(dart);
 }
 
 #[derive(Clone, Debug)]
@@ -489,6 +1731,27 @@ impl Parse for MetadataAnnotations {
     }
 }
 
+impl Parse for IrDartType {
+    fn parse(input: ParseStream<'_>) -> Result<Self> {
+        let content;
+        parenthesized!(content in input);
+        let name: LitStr = content.parse()?;
+        let _ = content.parse::<frb_keyword::import>()?;
+        let import: IrDartImport = content.parse()?;
+        let _: Token![,] = content.parse()?;
+        let from_raw: NamedOption<frb_keyword::from_raw, LitStr> = content.parse()?;
+        let _: Token![,] = content.parse()?;
+        let to_raw: NamedOption<frb_keyword::to_raw, LitStr> = content.parse()?;
+        let _: Option<Token![,]> = content.parse()?;
+        Ok(Self {
+            name: name.value(),
+            import,
+            from_raw: from_raw.value.value(),
+            to_raw: to_raw.value.value(),
+        })
+    }
+}
+
 #[derive(Clone, Debug)]
 pub struct DartImports(Vec<IrDartImport>);
 
@@ -519,10 +1782,43 @@ impl Parse for DartImports {
     }
 }
 
+/// The `(rust = "..", dart = ".." import "..")` of `#[frb(custom_codec(..))]`, see [IrTypeCustomCodec].
+#[derive(Clone, Debug)]
+pub struct CustomCodecOption {
+    rust: String,
+    dart: String,
+    import: IrDartImport,
+}
+
+impl Parse for CustomCodecOption {
+    fn parse(input: ParseStream<'_>) -> Result<Self> {
+        let _ = input.parse::<frb_keyword::custom_codec>()?;
+        let content;
+        parenthesized!(content in input);
+        let rust: NamedOption<frb_keyword::rust, LitStr> = content.parse()?;
+        if let Err(err) = parse_str::<Path>(&rust.value.value()) {
+            return Err(syn::Error::new(rust.value.span(), err));
+        }
+        let _: Token![,] = content.parse()?;
+        let dart: NamedOption<frb_keyword::dart, LitStr> = content.parse()?;
+        let _ = content.parse::<frb_keyword::import>()?;
+        let import: IrDartImport = content.parse()?;
+        let _: Option<Token![,]> = content.parse()?;
+        Ok(Self {
+            rust: rust.value.value(),
+            dart: dart.value.value(),
+            import,
+        })
+    }
+}
+
 enum FrbOption {
     Mirror(MirrorOption),
     NonFinal,
+    Broadcast,
     Metadata(NamedOption<frb_keyword::dart_metadata, MetadataAnnotations>),
+    DartType(NamedOption<frb_keyword::dart_type, IrDartType>),
+    CustomCodec(CustomCodecOption),
 }
 
 impl Parse for FrbOption {
@@ -534,8 +1830,16 @@ impl Parse for FrbOption {
             input
                 .parse::<frb_keyword::non_final>()
                 .map(|_| FrbOption::NonFinal)
+        } else if lookahead.peek(frb_keyword::broadcast) {
+            input
+                .parse::<frb_keyword::broadcast>()
+                .map(|_| FrbOption::Broadcast)
         } else if lookahead.peek(frb_keyword::dart_metadata) {
             input.parse().map(FrbOption::Metadata)
+        } else if lookahead.peek(frb_keyword::dart_type) {
+            input.parse().map(FrbOption::DartType)
+        } else if lookahead.peek(frb_keyword::custom_codec) {
+            input.parse().map(FrbOption::CustomCodec)
         } else {
             Err(lookahead.error())
         }
@@ -556,6 +1860,170 @@ fn extract_metadata(attrs: &[Attribute]) -> Vec<IrDartAnnotation> {
         .collect()
 }
 
+fn extract_dart_type(attrs: &[Attribute]) -> Option<IrDartType> {
+    attrs
+        .iter()
+        .filter(|attr| attr.path.is_ident("frb"))
+        .find_map(|attr| match attr.parse_args::<FrbOption>() {
+            Ok(FrbOption::DartType(NamedOption { name: _, value })) => Some(value),
+            Err(err) if attr.tokens.to_string().contains("dart_type") => {
+                panic!("Invalid `#[frb(dart_type = ...)]`: {}", err)
+            }
+            _ => None,
+        })
+}
+
+/// The codec of the struct or enum `name`, from its `#[frb(custom_codec(..))]`.
+fn extract_custom_codec(attrs: &[Attribute], name: &str) -> Option<IrTypeCustomCodec> {
+    attrs
+        .iter()
+        .filter(|attr| attr.path.is_ident("frb"))
+        .find_map(|attr| match attr.parse_args::<FrbOption>() {
+            Ok(FrbOption::CustomCodec(CustomCodecOption { rust, dart, import })) => {
+                Some(IrTypeCustomCodec {
+                    name: name.to_owned(),
+                    rust,
+                    dart,
+                    import,
+                })
+            }
+            Err(err) if attr.tokens.to_string().contains("custom_codec") => {
+                panic!("Invalid `#[frb(custom_codec(..))]` of `{}`: {}", name, err)
+            }
+            _ => None,
+        })
+}
+
+/// Ensure the modules of the custom codecs define `encode` and `decode`, when they belong to
+/// the crate. Their signatures are checked by the compiler, see `static_checks`.
+fn check_custom_codecs(ir_file: &IrFile, crate_map: &Crate) -> anyhow::Result<()> {
+    for ty in ir_file.distinct_types(true, true) {
+        let codec = match ty {
+            IrType::Delegate(IrTypeDelegate::CustomCodec(codec)) => codec,
+            _ => continue,
+        };
+        let path = codec.rust.split("::").map(str::trim).collect::<Vec<_>>();
+        let module = match crate_map.root_module.find_module(&path) {
+            Some(module) => module,
+            None => {
+                warn!(
+                    "Cannot locate `{}` in the crate, so the codec of `{}` is not checked",
+                    codec.rust, codec.name
+                );
+                continue;
+            }
+        };
+        for func in ["encode", "decode"] {
+            if !module.defines_fn(func) {
+                anyhow::bail!(
+                    "The codec of `{}` has no function `{func}` in `{}`",
+                    codec.name,
+                    codec.rust
+                );
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Replace the const generic parameters used as array lengths in `ty` by their values.
+fn substitute_consts(ty: &mut Type, values: &HashMap<String, usize>) {
+    match ty {
+        Type::Array(TypeArray { elem, len, .. }) => {
+            substitute_consts(elem, values);
+            if let Expr::Path(ExprPath { path, .. }) = len {
+                if let Some(value) = path
+                    .get_ident()
+                    .and_then(|ident| values.get(&ident.to_string()))
+                {
+                    *len = parse_str(&value.to_string()).unwrap();
+                }
+            }
+        }
+        Type::Path(TypePath { path, .. }) => {
+            for segment in path.segments.iter_mut() {
+                if let PathArguments::AngleBracketed(args) = &mut segment.arguments {
+                    for arg in args.args.iter_mut() {
+                        if let GenericArgument::Type(ty) = arg {
+                            substitute_consts(ty, values);
+                        }
+                    }
+                }
+            }
+        }
+        Type::Reference(TypeReference { elem, .. })
+        | Type::Slice(TypeSlice { elem, .. })
+        | Type::Paren(TypeParen { elem, .. })
+        | Type::Group(TypeGroup { elem, .. }) => substitute_consts(elem, values),
+        Type::Tuple(TypeTuple { elems, .. }) => {
+            for elem in elems.iter_mut() {
+                substitute_consts(elem, values);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// The `T` of a returned `impl Iterator<Item = T>`, possibly with other bounds such as `Send`.
+fn iterator_item_type(ty: &Type) -> Option<&Type> {
+    let bounds = match ty {
+        Type::ImplTrait(TypeImplTrait { bounds, .. }) => bounds,
+        _ => return None,
+    };
+    bounds.iter().find_map(|bound| match bound {
+        TypeParamBound::Trait(TraitBound { path, .. }) => {
+            let last = path.segments.last()?;
+            match &last.arguments {
+                PathArguments::AngleBracketed(args) if last.ident == "Iterator" => {
+                    args.args.iter().find_map(|arg| match arg {
+                        GenericArgument::Binding(Binding { ident, ty, .. }) if ident == "Item" => {
+                            Some(ty)
+                        }
+                        _ => None,
+                    })
+                }
+                _ => None,
+            }
+        }
+        _ => None,
+    })
+}
+
+/// For a function returning a borrow, e.g. `&str` or `Result<&[u8]>`, the owned type that
+/// the generated code copies it into, e.g. `String` or `Result<Vec<u8>>`. For a `&T` argument,
+/// the owned type which is received from Dart and then borrowed.
+fn owned_output_type(ty: &Type) -> Option<Type> {
+    match ty {
+        Type::Reference(TypeReference {
+            elem,
+            mutability: None,
+            ..
+        }) => match elem.as_ref() {
+            Type::Path(TypePath { path, .. }) if path.is_ident("str") => Some(parse_quote!(String)),
+            Type::Slice(TypeSlice { elem, .. }) => Some(parse_quote!(Vec<#elem>)),
+            Type::Path(_) => Some(elem.as_ref().clone()),
+            _ => None,
+        },
+        Type::Path(TypePath { path, .. })
+            if path.segments.last().unwrap().ident == RESULT_IDENT =>
+        {
+            let mut owned = ty.clone();
+            if let Type::Path(TypePath { path, .. }) = &mut owned {
+                if let PathArguments::AngleBracketed(args) =
+                    &mut path.segments.last_mut().unwrap().arguments
+                {
+                    if let Some(GenericArgument::Type(ok)) = args.args.first_mut() {
+                        *ok = owned_output_type(ok)?;
+                        return Some(owned);
+                    }
+                }
+            }
+            None
+        }
+        _ => None,
+    }
+}
+
 /// syn -> string https://github.com/dtolnay/syn/issues/294
 fn type_to_string(ty: &Type) -> String {
     quote!(#ty).to_string().replace(' ', "")
@@ -563,11 +2031,201 @@ fn type_to_string(ty: &Type) -> String {
 
 #[cfg(test)]
 mod tests {
-    use std::collections::HashMap;
+    use std::collections::{HashMap, HashSet};
+    use std::path::PathBuf;
+
+    use quote::{quote, ToTokens};
+
+    use syn::{parse_quote, parse_str, File, FnArg, ItemStruct, Type};
+
+    use crate::ir::{IrFile, IrTypeTrait};
+    use crate::markers::{extract_dart_name, extract_skip};
+    use crate::method_utils::FunctionName;
+    use crate::parser::{
+        collect_opaque_names, collect_traits, extract_consts_from_file, extract_custom_codec,
+        extract_interface_methods_from_file, extract_methods_from_file, interface_impls,
+        is_anyhow_error, owned_output_type, substitute_consts, topo_resolve,
+    };
+    use crate::source_graph::{Struct, Visibility};
+
+    #[test]
+    fn test_optional_lists_and_lists_of_optionals_differ() {
+        let mut parser =
+            super::ty::TypeParser::new(HashMap::new(), HashMap::new(), HashMap::new(), false);
+        let mut parse = |ty: &str| parser.parse_type(&parse_str::<Type>(ty).unwrap());
+        let optional_list = parse("Option<Vec<i32>>");
+        let list_of_optionals = parse("Vec<Option<i32>>");
+        assert_eq!(optional_list.dart_api_type(), "Int32List?");
+        assert_eq!(list_of_optionals.dart_api_type(), "List<int?>");
+        assert_ne!(optional_list.safe_ident(), list_of_optionals.safe_ident());
+        assert_eq!(
+            parse("Option<Vec<Option<String>>>").dart_api_type(),
+            "List<String?>?"
+        );
+    }
+
+    #[test]
+    fn test_arc_is_shared_as_opaque() {
+        let mut parser =
+            super::ty::TypeParser::new(HashMap::new(), HashMap::new(), HashMap::new(), false);
+        let mut parse = |ty: &str| parser.parse_type(&parse_str::<Type>(ty).unwrap());
+        let arc = parse("Arc<Counter>");
+        assert_eq!(
+            arc.dart_api_type(),
+            parse("RustOpaque<Counter>").dart_api_type()
+        );
+        assert_eq!(arc.rust_api_type(), "std::sync::Arc<Counter>");
+        assert_ne!(arc.safe_ident(), parse("RustOpaque<Counter>").safe_ident());
+        let rc = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| parse("Rc<Counter>")));
+        assert!(rc.is_err());
+    }
+
+    #[test]
+    fn test_dart_const_constructor() {
+        let items: Vec<ItemStruct> = vec![
+            parse_quote! {
+                pub struct Point { pub x: f64, pub label: Option<String>, pub tags: Vec<String> }
+            },
+            parse_quote! {
+                pub struct Mixed { pub origin: Point, pub pixels: Vec<u8> }
+            },
+            parse_quote! {
+                pub struct Route { pub points: Vec<Point>, pub next: Option<Box<Route>> }
+            },
+        ];
+        let srcs = (items.into_iter())
+            .map(|src| Struct {
+                ident: src.ident.clone(),
+                src,
+                visibility: Visibility::Public,
+                path: vec![],
+                mirror: false,
+                file_path: PathBuf::new(),
+            })
+            .collect::<Vec<_>>();
+        let mut parser = super::ty::TypeParser::new(
+            srcs.iter()
+                .map(|src| (src.ident.to_string(), src))
+                .collect(),
+            HashMap::new(),
+            HashMap::new(),
+            false,
+        );
+        for src in &srcs {
+            parser.parse_type(&parse_str::<Type>(&src.ident.to_string()).unwrap());
+        }
+        let (struct_pool, enum_pool) = parser.consume();
+        let ir_file = IrFile {
+            funcs: vec![],
+            struct_pool,
+            enum_pool,
+            consts: vec![],
+            interfaces: vec![],
+            has_executor: false,
+        };
+        let is_const = |name: &str| ir_file.struct_pool[name].has_dart_const_constructor(&ir_file);
+        assert!(is_const("Point"));
+        // A `Uint8List` cannot be constant.
+        assert!(!is_const("Mixed"));
+        assert!(is_const("Route"));
+    }
+
+    #[test]
+    fn test_custom_codec() {
+        let item: ItemStruct = parse_quote! {
+            #[frb(custom_codec(rust = "crate::codecs::user", dart = "UserCodec" import "codecs.dart" as codecs))]
+            pub struct User(Vec<u8>);
+        };
+        let codec = extract_custom_codec(&item.attrs, "User").unwrap();
+        assert_eq!(codec.rust, "crate::codecs::user");
+        assert_eq!(codec.dart_type(), "codecs.User");
+        assert_eq!(codec.dart_codec(), "codecs.UserCodec");
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid `#[frb(custom_codec(..))]` of `User`")]
+    fn test_custom_codec_needs_a_rust_path() {
+        let item: ItemStruct = parse_quote! {
+            #[frb(custom_codec(rust = "not a path", dart = "UserCodec" import "codecs.dart"))]
+            pub struct User(Vec<u8>);
+        };
+        extract_custom_codec(&item.attrs, "User");
+    }
+
+    #[test]
+    fn test_only_anyhow_errors_are_flattened() {
+        let is_anyhow = |ty: &str| is_anyhow_error(&parse_str::<Type>(ty).unwrap());
+        assert!(is_anyhow("Result<String, anyhow::Error>"));
+        assert!(!is_anyhow("Result<String, String>"));
+        assert!(!is_anyhow("Result<String, MyError>"));
+    }
+
+    #[test]
+    fn test_opaque_methods_take_the_handle() {
+        let file = parse_str::<File>(
+            "pub fn open() -> RustOpaque<Database> { todo!() }
+            impl Database {
+                pub fn get(&self, key: String) -> String { todo!() }
+                pub fn close(self) {}
+            }",
+        )
+        .unwrap();
+        let opaque_names = collect_opaque_names([&file]);
+        assert_eq!(opaque_names, HashSet::from(["Database".to_owned()]));
+        let receivers = extract_methods_from_file(&file, &opaque_names)
+            .into_iter()
+            .map(|f| match f.sig.inputs.first() {
+                Some(FnArg::Typed(pat_type)) => quote!(#pat_type).to_string(),
+                _ => unreachable!(),
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            receivers,
+            [
+                "ref that : RustOpaque < Database >",
+                "that : RustOpaque < Database >"
+            ]
+        );
+    }
 
-    use syn::{parse_str, Type};
+    #[test]
+    fn test_interface_methods() {
+        let file = parse_str::<File>(
+            "pub fn circle() -> RustOpaque<Circle> { todo!() }
+            pub trait Drawable: DartSafe {
+                fn draw(&self, scale: f64) -> String;
+                fn name(&self) -> String { todo!() }
+                fn new() -> Self where Self: Sized;
+            }
+            impl Drawable for Circle {
+                fn draw(&self, scale: f64) -> String { todo!() }
+                fn new() -> Self { todo!() }
+            }
+            impl Drawable for NotOpaque {
+                fn draw(&self, scale: f64) -> String { todo!() }
+                fn new() -> Self { todo!() }
+            }",
+        )
+        .unwrap();
+        let opaque_names = collect_opaque_names([&file]);
+        let traits = collect_traits([&file]);
+        let names = extract_interface_methods_from_file(&file, &opaque_names, &traits)
+            .into_iter()
+            .map(|f| FunctionName::deserialize(&f.sig.ident.to_string()).method_name())
+            .collect::<Vec<_>>();
+        assert_eq!(names, ["draw", "name"]);
+        let implementors = interface_impls(&file, &opaque_names, &traits)
+            .map(|(_, _, implementor)| implementor)
+            .collect::<Vec<_>>();
+        assert_eq!(implementors, ["Circle"]);
+    }
 
-    use crate::parser::topo_resolve;
+    #[test]
+    fn test_substitute_consts() {
+        let mut ty = parse_str::<Type>("Result<Vec<[u8; N]>>").unwrap();
+        substitute_consts(&mut ty, &HashMap::from([("N".to_string(), 32)]));
+        assert_eq!(ty, parse_str::<Type>("Result<Vec<[u8; 32]>>").unwrap());
+    }
 
     #[test]
     fn test_topo_resolve_primary_type_with_nest() {
@@ -613,4 +2271,91 @@ mod tests {
         let output = topo_resolve(input);
         assert_eq!(&output, &expect);
     }
+    #[test]
+    fn test_owned_output_type() {
+        let owned = |ty: &str| {
+            owned_output_type(&parse_str::<Type>(ty).unwrap())
+                .map(|it| it.to_token_stream().to_string().replace(' ', ""))
+        };
+        assert_eq!(owned("&str").as_deref(), Some("String"));
+        assert_eq!(owned("&'a [u8]").as_deref(), Some("Vec<u8>"));
+        assert_eq!(owned("&Title").as_deref(), Some("Title"));
+        assert_eq!(
+            owned("Result<&str, MyError>").as_deref(),
+            Some("Result<String,MyError>")
+        );
+        assert_eq!(owned("Result<String>"), None);
+        assert_eq!(owned("&mut str"), None);
+        assert_eq!(owned("&dyn Shape"), None);
+    }
+
+    #[test]
+    fn test_extract_dart_name() {
+        let attrs = |src: &str| {
+            parse_str::<syn::ItemFn>(&format!("{src} fn f() {{}}"))
+                .unwrap()
+                .attrs
+        };
+        assert_eq!(
+            extract_dart_name(&attrs(r#"#[frb(dart_name = "fetchUser")]"#)).as_deref(),
+            Some("fetchUser")
+        );
+        assert_eq!(extract_dart_name(&attrs("#[frb(mirror(Foo))]")), None);
+        for invalid in ["_private", "class", "2d", "a-b"] {
+            let attrs = attrs(&format!(r#"#[frb(dart_name = "{invalid}")]"#));
+            assert!(std::panic::catch_unwind(|| extract_dart_name(&attrs)).is_err());
+        }
+    }
+
+    #[test]
+    fn test_extract_skip() {
+        let attrs = |src: &str| {
+            parse_str::<syn::FieldsNamed>(&format!("{{ {src} a: u8 }}"))
+                .unwrap()
+                .named[0]
+                .attrs
+                .clone()
+        };
+        assert_eq!(
+            extract_skip(&attrs("#[frb(skip)]")).as_deref(),
+            Some("Default::default()")
+        );
+        assert_eq!(
+            extract_skip(&attrs(r#"#[frb(skip = "Cache::empty()")]"#)).as_deref(),
+            Some("Cache::empty()")
+        );
+        assert_eq!(extract_skip(&attrs("#[frb(non_final)]")), None);
+    }
+
+    #[test]
+    fn test_extract_consts() {
+        let file = parse_str::<File>(
+            r#"
+            /// The most users at once.
+            pub const MAX_USERS: u32 = 1_000;
+            pub const OFFSET: i64 = -0x10;
+            pub const RATIO: f64 = 1.;
+            pub static GREETING: &str = "Hi '$name'\n";
+            pub static mut COUNTER: u32 = 0;
+            pub const COMPUTED: u32 = 1 + 1;
+            pub const HUGE: u64 = u64::MAX;
+            const PRIVATE: u32 = 1;
+            pub const CHAR: char = 'a';
+            "#,
+        )
+        .unwrap();
+        let consts = extract_consts_from_file(&file)
+            .into_iter()
+            .map(|it| (it.name.dart_style(), it.value))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            consts,
+            [
+                ("maxUsers".to_owned(), "1000".to_owned()),
+                ("offset".to_owned(), "-16".to_owned()),
+                ("ratio".to_owned(), "1.0".to_owned()),
+                ("greeting".to_owned(), r#"'Hi \'\$name\'\n'"#.to_owned()),
+            ]
+        );
+    }
 }
@@ -1,6 +1,8 @@
 use std::collections::{HashMap, HashSet};
+use std::convert::TryFrom;
 use std::string::String;
 
+use convert_case::{Case, Casing};
 use syn::*;
 
 use crate::ir::IrType::*;
@@ -10,7 +12,9 @@ use crate::markers;
 
 use crate::source_graph::{Enum, Struct};
 
-use crate::parser::{extract_comments, extract_metadata, type_to_string};
+use crate::parser::{
+    extract_comments, extract_custom_codec, extract_dart_type, extract_metadata, type_to_string,
+};
 
 pub struct TypeParser<'a> {
     src_structs: HashMap<String, &'a Struct>,
@@ -22,6 +26,12 @@ pub struct TypeParser<'a> {
 
     parsed_enums: HashSet<String>,
     enum_pool: IrEnumPool,
+
+    /// Type arguments of the generic struct instantiation being parsed, keyed by parameter name.
+    generic_substitutions: HashMap<String, IrType>,
+
+    /// See `--dart-char-as-int`.
+    dart_char_as_int: bool,
 }
 
 impl<'a> TypeParser<'a> {
@@ -29,6 +39,7 @@ impl<'a> TypeParser<'a> {
         src_structs: HashMap<String, &'a Struct>,
         src_enums: HashMap<String, &'a Enum>,
         src_types: HashMap<String, Type>,
+        dart_char_as_int: bool,
     ) -> Self {
         TypeParser {
             src_structs,
@@ -38,6 +49,8 @@ impl<'a> TypeParser<'a> {
             enum_pool: HashMap::new(),
             parsing_or_parsed_struct_names: HashSet::new(),
             parsed_enums: HashSet::new(),
+            generic_substitutions: HashMap::new(),
+            dart_char_as_int,
         }
     }
 
@@ -49,9 +62,9 @@ impl<'a> TypeParser<'a> {
 /// Generic intermediate representation of a type that can appear inside a function signature.
 #[derive(Debug)]
 pub enum SupportedInnerType {
-    /// Path types with up to 1 generic type argument on the final segment. All segments before
-    /// the last segment are ignored. The generic type argument must also be a valid
-    /// `SupportedInnerType`.
+    /// Path types with generic type arguments on the final segment. All segments before
+    /// the last segment are ignored. The generic type arguments must also be valid
+    /// `SupportedInnerType`s.
     Path(SupportedPathType),
     /// Array type
     Array(Box<Self>, usize),
@@ -59,6 +72,8 @@ pub enum SupportedInnerType {
     Verbatim(Box<syn::Type>),
     /// The unit type `()`.
     Unit,
+    /// Tuple types with at least one element, such as `(i32, String)`.
+    Tuple(Vec<Self>),
 }
 
 impl std::fmt::Display for SupportedInnerType {
@@ -68,24 +83,38 @@ impl std::fmt::Display for SupportedInnerType {
             Self::Array(u, len) => write!(f, "[{u}; {len}]"),
             Self::Verbatim(ver) => write!(f, "{}", quote::quote!(#ver)),
             Self::Unit => write!(f, "()"),
+            Self::Tuple(elems) => {
+                let elems = elems
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                write!(f, "({elems},)")
+            }
         }
     }
 }
 
-/// Represents a named type, with an optional path and up to 1 generic type argument.
+/// Represents a named type, with an optional path and its generic type arguments.
 #[derive(Debug)]
 pub struct SupportedPathType {
     pub ident: syn::Ident,
-    pub generic: Option<Box<SupportedInnerType>>,
+    pub generics: Vec<SupportedInnerType>,
 }
 
 impl std::fmt::Display for SupportedPathType {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
         let ident = self.ident.to_string();
-        if let Some(generic) = &self.generic {
-            write!(f, "{ident}<{generic}>")
-        } else {
+        if self.generics.is_empty() {
             write!(f, "{ident}")
+        } else {
+            let generics = self
+                .generics
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+                .join(", ");
+            write!(f, "{ident}<{generics}>")
         }
     }
 }
@@ -100,19 +129,22 @@ impl SupportedInnerType {
                 match last_segment.arguments {
                     syn::PathArguments::None => Some(SupportedInnerType::Path(SupportedPathType {
                         ident: last_segment.ident,
-                        generic: None,
+                        generics: vec![],
                     })),
                     syn::PathArguments::AngleBracketed(a) => {
-                        let generic = match a.args.into_iter().next() {
-                            Some(syn::GenericArgument::Type(t)) => {
-                                Some(Box::new(SupportedInnerType::try_from_syn_type(&t)?))
-                            }
-                            _ => None,
-                        };
+                        let generics = a
+                            .args
+                            .into_iter()
+                            .filter_map(|arg| match arg {
+                                syn::GenericArgument::Type(t) => Some(t),
+                                _ => None,
+                            })
+                            .map(|t| SupportedInnerType::try_from_syn_type(&t))
+                            .collect::<Option<Vec<_>>>()?;
 
                         Some(SupportedInnerType::Path(SupportedPathType {
                             ident: last_segment.ident,
-                            generic,
+                            generics,
                         }))
                     }
                     _ => Some(SupportedInnerType::Verbatim(Box::new(ty.clone()))),
@@ -120,11 +152,17 @@ impl SupportedInnerType {
             }
             syn::Type::Array(syn::TypeArray { elem, len, .. }) => {
                 let len: usize = match len {
-                    syn::Expr::Lit(lit) => match &lit.lit {
-                        syn::Lit::Int(x) => x.base10_parse().unwrap(),
-                        _ => panic!("Cannot parse array length"),
-                    },
-                    _ => panic!("Cannot parse array length"),
+                    syn::Expr::Lit(syn::ExprLit {
+                        lit: syn::Lit::Int(x),
+                        ..
+                    }) => x.base10_parse().unwrap(),
+                    _ => panic!(
+                        "Cannot resolve the length `{}` of `{}` to an integer literal. \
+                        The const generic parameters of a function can be picked with \
+                        `#[frb(instantiate(N = ..))]`",
+                        quote::quote!(#len),
+                        type_to_string(ty)
+                    ),
                 };
                 Some(SupportedInnerType::Array(
                     Box::new(SupportedInnerType::try_from_syn_type(elem)?),
@@ -134,6 +172,12 @@ impl SupportedInnerType {
             syn::Type::Tuple(syn::TypeTuple { elems, .. }) if elems.is_empty() => {
                 Some(SupportedInnerType::Unit)
             }
+            syn::Type::Tuple(syn::TypeTuple { elems, .. }) => Some(SupportedInnerType::Tuple(
+                elems
+                    .iter()
+                    .map(SupportedInnerType::try_from_syn_type)
+                    .collect::<Option<Vec<_>>>()?,
+            )),
             _ => Some(SupportedInnerType::Verbatim(Box::new(ty.clone()))),
         }
     }
@@ -174,10 +218,64 @@ impl<'a> TypeParser<'a> {
             SupportedInnerType::Path(p) => self.convert_path_to_ir_type(p),
             SupportedInnerType::Array(p, len) => self.convert_array_to_ir_type(*p, len),
             SupportedInnerType::Unit => Some(IrType::Primitive(IrTypePrimitive::Unit)),
-            SupportedInnerType::Verbatim(_) => None,
+            SupportedInnerType::Tuple(elems) => self.convert_tuple_to_ir_type(elems),
+            SupportedInnerType::Verbatim(ty) => self.convert_verbatim_to_ir_type(&ty),
+        }
+    }
+
+    /// Trait objects are sent as opaque handles to a `Box<dyn Trait>`, and `&Path` like a `PathBuf`.
+    /// Other verbatim types are not supported.
+    pub fn convert_verbatim_to_ir_type(&mut self, ty: &syn::Type) -> Option<IrType> {
+        match ty {
+            syn::Type::ImplTrait(TypeImplTrait { bounds, .. }) => {
+                Some(Delegate(IrTypeDelegate::ImplTrait(boxed_dyn(bounds))))
+            }
+            syn::Type::Reference(TypeReference {
+                elem,
+                mutability: None,
+                ..
+            }) => match elem.as_ref() {
+                syn::Type::TraitObject(TypeTraitObject { bounds, .. }) => {
+                    Some(Delegate(IrTypeDelegate::DynRef(boxed_dyn(bounds))))
+                }
+                syn::Type::Path(TypePath { path, .. })
+                    if path.segments.last().is_some_and(|it| it.ident == "Path") =>
+                {
+                    Some(Delegate(IrTypeDelegate::PathRef))
+                }
+                syn::Type::Slice(TypeSlice { elem, .. }) => match elem.as_ref() {
+                    syn::Type::Path(TypePath { path, .. }) => path
+                        .get_ident()
+                        .and_then(|ident| IrTypePrimitive::try_from_rust_str(&ident.to_string()))
+                        .filter(|primitive| {
+                            !matches!(primitive, IrTypePrimitive::Bool | IrTypePrimitive::Unit)
+                        })
+                        .map(|primitive| Delegate(IrTypeDelegate::SliceRef(primitive))),
+                    _ => None,
+                },
+                _ => None,
+            },
+            _ => None,
         }
     }
 
+    /// Converts a tuple type into an `IrType` if possible.
+    pub fn convert_tuple_to_ir_type(&mut self, elems: Vec<SupportedInnerType>) -> Option<IrType> {
+        if !(TUPLE_MIN_ARITY..=TUPLE_MAX_ARITY).contains(&elems.len()) {
+            panic!(
+                "Tuples must have between {} and {} elements, but got {}",
+                TUPLE_MIN_ARITY,
+                TUPLE_MAX_ARITY,
+                elems.len()
+            );
+        }
+        let values = elems
+            .into_iter()
+            .map(|elem| self.convert_to_ir_type(elem))
+            .collect::<Option<Vec<_>>>()?;
+        Some(Tuple(IrTypeTuple { values }))
+    }
+
     /// Converts an array type into an `IrType` if possible.
     pub fn convert_array_to_ir_type(
         &mut self,
@@ -201,8 +299,11 @@ impl<'a> TypeParser<'a> {
     /// Converts a path type into an `IrType` if possible.
     pub fn convert_path_to_ir_type(&mut self, p: SupportedPathType) -> Option<IrType> {
         let p_as_str = format!("{}", &p);
-        let ident_string = &p.ident.to_string();
-        if let Some(generic) = p.generic {
+        let ident = p.ident.clone();
+        let ident_string = &ident.to_string();
+        let mut generics = p.generics.into_iter();
+        if let Some(generic) = generics.next() {
+            let generic = Box::new(generic);
             match ident_string.as_str() {
                 "SyncReturn" => {
                     // Disallow nested SyncReturn
@@ -234,6 +335,9 @@ impl<'a> TypeParser<'a> {
                         Some(IrType::Delegate(IrTypeDelegate::Uuids))
                     }
                     _ => self.convert_to_ir_type(*generic).map(|inner| match inner {
+                        Primitive(IrTypePrimitive::Usize) => {
+                            Delegate(IrTypeDelegate::SizeList(IrTypePrimitive::Usize))
+                        }
                         Primitive(primitive) => PrimitiveList(IrTypePrimitiveList { primitive }),
                         others => GeneralList(IrTypeGeneralList {
                             inner: Box::new(others),
@@ -261,19 +365,67 @@ impl<'a> TypeParser<'a> {
                     SupportedInnerType::Unit => {
                         Some(IrType::RustOpaque(IrTypeRustOpaque::new_unit()))
                     }
+                    tuple @ SupportedInnerType::Tuple(_) => Some(IrType::RustOpaque(
+                        IrTypeRustOpaque::from(tuple.to_string()),
+                    )),
+                },
+                "Arc" => {
+                    let opaque = match *generic {
+                        SupportedInnerType::Verbatim(ver) => IrTypeRustOpaque::from(ver.as_ref()),
+                        SupportedInnerType::Unit => IrTypeRustOpaque::new_unit(),
+                        others => IrTypeRustOpaque::from(others.to_string()),
+                    };
+                    Some(Delegate(IrTypeDelegate::Arc(opaque)))
+                }
+                "Weak" => match *generic {
+                    SupportedInnerType::Verbatim(ver) => Some(Delegate(IrTypeDelegate::Weak(
+                        IrTypeRustOpaque::from(ver.as_ref()),
+                    ))),
+                    SupportedInnerType::Unit => None,
+                    others => Some(Delegate(IrTypeDelegate::Weak(IrTypeRustOpaque::from(
+                        others.to_string(),
+                    )))),
+                },
+                // The lifetime is dropped, since a borrow cannot outlive the call anyway.
+                "Cow" => match *generic {
+                    SupportedInnerType::Path(SupportedPathType { ident, .. }) if ident == "str" => {
+                        Some(Delegate(IrTypeDelegate::CowStr))
+                    }
+                    SupportedInnerType::Verbatim(ver) => match *ver {
+                        syn::Type::Slice(TypeSlice { elem, .. })
+                            if matches!(*elem, syn::Type::Path(ref path) if path.path.is_ident("u8")) =>
+                        {
+                            Some(Delegate(IrTypeDelegate::CowBytes))
+                        }
+                        _ => None,
+                    },
+                    _ => None,
+                },
+                "Rc" => panic!(
+                    "`{}` cannot be shared with Dart, since it is not thread-safe. Please use `Arc` instead.",
+                    p_as_str
+                ),
+                "Box" => match *generic {
+                    SupportedInnerType::Verbatim(ver) => match *ver {
+                        syn::Type::TraitObject(TypeTraitObject { bounds, .. }) => {
+                            Some(Delegate(IrTypeDelegate::BoxDyn(boxed_dyn(&bounds))))
+                        }
+                        _ => None,
+                    },
+                    generic => self.convert_to_ir_type(generic).map(|inner| {
+                        Boxed(IrTypeBoxed {
+                            exist_in_real_api: true,
+                            inner: Box::new(inner),
+                        })
+                    }),
                 },
-                "Box" => self.convert_to_ir_type(*generic).map(|inner| {
-                    Boxed(IrTypeBoxed {
-                        exist_in_real_api: true,
-                        inner: Box::new(inner),
-                    })
-                }),
                 "Option" => {
-                    // Disallow nested Option
+                    // Disallow nested Option, since Dart collapses `T??` into `T?` and
+                    // `Some(None)` could not be told apart from `None`.
                     if matches!(generic.as_ref(), SupportedInnerType:: Path(SupportedPathType { ident, .. }) if ident == "Option")
                     {
                         panic!(
-                            "Nested optionals without indirection are not supported. (Option<Option<{}>>)",
+                            "Nested optionals are not supported, since Dart cannot tell `Some(None)` from `None`. Use an enum instead. ({})",
                             p_as_str
                         );
                     }
@@ -282,10 +434,18 @@ impl<'a> TypeParser<'a> {
                         match self.convert_to_ir_type(*generic)? {
                             inner @ (StructRef(..)
                             | EnumRef(..)
+                            | Tuple(..)
                             | RustOpaque(..)
                             | DartOpaque(..)
                             | Primitive(..)
-                            | Delegate(IrTypeDelegate::PrimitiveEnum { .. })) => {
+                            | Delegate(
+                                IrTypeDelegate::PrimitiveEnum { .. }
+                                | IrTypeDelegate::Arc(_)
+                                | IrTypeDelegate::Weak(_)
+                                | IrTypeDelegate::Instant
+                                | IrTypeDelegate::BoxDyn(_)
+                                | IrTypeDelegate::ImplTrait(_),
+                            )) => {
                                 IrTypeOptional::new_boxed(inner)
                             }
                             #[cfg(feature = "chrono")]
@@ -302,57 +462,148 @@ impl<'a> TypeParser<'a> {
                         match ident.to_string().as_str() {
                             "Utc" => Some(Delegate(IrTypeDelegate::Time(IrTypeTime::Utc))),
                             "Local" => Some(Delegate(IrTypeDelegate::Time(IrTypeTime::Local))),
+                            "FixedOffset" => {
+                                Some(Delegate(IrTypeDelegate::Time(IrTypeTime::FixedOffset)))
+                            }
                             _ => panic!("Unknown DateTime generic offset"),
                         }
                     }
                     _ => panic!("Invalid DateTime generic"),
                 },
+                "HashMap" | "BTreeMap" => {
+                    let value = generics.next()?;
+                    Some(Map(IrTypeMap {
+                        kind: if ident_string == "HashMap" {
+                            IrTypeMapKind::HashMap
+                        } else {
+                            IrTypeMapKind::BTreeMap
+                        },
+                        key: Box::new(self.convert_to_ir_type(*generic)?),
+                        value: Box::new(self.convert_to_ir_type(value)?),
+                    }))
+                }
+                "BTreeSet" => self
+                    .convert_to_ir_type(*generic)
+                    .map(|inner| Delegate(IrTypeDelegate::BTreeSet(Box::new(inner)))),
+                "Range" | "RangeInclusive" if !self.src_structs.contains_key(ident_string) => {
+                    match self.convert_to_ir_type(*generic)? {
+                        Primitive(bound)
+                            if !matches!(bound, IrTypePrimitive::Bool | IrTypePrimitive::Unit) =>
+                        {
+                            Some(Delegate(IrTypeDelegate::Range {
+                                bound,
+                                inclusive: ident_string == "RangeInclusive",
+                            }))
+                        }
+                        other => panic!(
+                            "Unsupported `{ident_string}<{}>`, only ranges of numbers are supported",
+                            other.rust_api_type()
+                        ),
+                    }
+                }
+                _ if self.src_structs.contains_key(ident_string) => {
+                    let args = std::iter::once(*generic).chain(generics).collect();
+                    Some(self.convert_generic_struct_to_ir_type(&ident, args))
+                }
                 _ => None,
             }
         } else {
+            if let Some(substituted) = self.generic_substitutions.get(ident_string) {
+                return Some(substituted.clone());
+            }
             #[cfg(feature = "chrono")]
             match ident_string.as_str() {
                 "Duration" => return Some(Delegate(IrTypeDelegate::Time(IrTypeTime::Duration))),
                 "NaiveDateTime" => return Some(Delegate(IrTypeDelegate::Time(IrTypeTime::Naive))),
+                // Only with `chrono`, which its `durationSince` method returns.
+                "Instant" => return Some(Delegate(IrTypeDelegate::Instant)),
                 _ => {}
             };
             #[cfg(feature = "uuid")]
             if ident_string.as_str() == "Uuid" {
                 return Some(Delegate(IrTypeDelegate::Uuid));
             }
+            #[cfg(feature = "bytes")]
+            if ident_string.as_str() == "Bytes" {
+                return Some(Delegate(IrTypeDelegate::Bytes));
+            }
+            #[cfg(feature = "rust_decimal")]
+            if ident_string.as_str() == "Decimal" {
+                return Some(Delegate(IrTypeDelegate::Decimal));
+            }
             if ident_string.as_str() == "DartOpaque" {
                 return Some(DartOpaque(IrTypeDartOpaque {}));
             }
+            match ident_string.as_str() {
+                "i128" => return Some(Delegate(IrTypeDelegate::I128)),
+                "u128" => return Some(Delegate(IrTypeDelegate::U128)),
+                "char" => {
+                    return Some(Delegate(IrTypeDelegate::Char {
+                        as_int: self.dart_char_as_int,
+                    }))
+                }
+                "NonZeroI128" => return Some(non_zero(Delegate(IrTypeDelegate::I128))),
+                "NonZeroU128" => return Some(non_zero(Delegate(IrTypeDelegate::U128))),
+                "PathBuf" => return Some(Delegate(IrTypeDelegate::PathBuf)),
+                "IpAddr" => return Some(Delegate(IrTypeDelegate::IpAddr)),
+                "SocketAddr" => return Some(Delegate(IrTypeDelegate::SocketAddr)),
+                "SystemTime" => return Some(Delegate(IrTypeDelegate::SystemTime)),
+                _ => {}
+            };
+
+            if let Some(primitive) = ident_string
+                .strip_prefix("NonZero")
+                .and_then(|int| IrTypePrimitive::try_from_rust_str(&int.to_lowercase()))
+                .filter(IrTypePrimitive::is_integer)
+            {
+                return Some(non_zero(Primitive(primitive)));
+            }
 
             IrTypePrimitive::try_from_rust_str(ident_string)
                 .map(Primitive)
                 .or_else(|| {
                     if ident_string == "String" {
                         Some(IrType::Delegate(IrTypeDelegate::String))
-                    } else if self.src_structs.contains_key(ident_string) {
+                    } else if let Some(codec) = self.custom_codec(ident_string) {
+                        Some(Delegate(IrTypeDelegate::CustomCodec(codec)))
+                    } else if let Some(src_struct) = self.src_structs.get(ident_string) {
+                        if !generic_type_params(src_struct).is_empty() {
+                            panic!(
+                                "Generic struct `{}` is used without type arguments. \
+                                Only concrete instantiations such as `{}<i32>` can be generated.",
+                                ident_string, ident_string
+                            );
+                        }
+                        if markers::has_ignore(&src_struct.src.attrs) {
+                            panic!(
+                                "Struct `{}` is marked `#[frb(ignore)]`, \
+                                but a generated function uses it",
+                                ident_string
+                            );
+                        }
                         if !self.parsing_or_parsed_struct_names.contains(ident_string) {
                             self.parsing_or_parsed_struct_names
                                 .insert(ident_string.to_owned());
-                            let api_struct = self.parse_struct_core(&p.ident);
+                            let api_struct = self
+                                .with_generic_substitutions(HashMap::new(), |parser| {
+                                    parser.parse_struct_core(&ident)
+                                });
                             self.struct_pool.insert(ident_string.to_owned(), api_struct);
                         }
 
-                        Some(StructRef(IrTypeStructRef {
-                            name: ident_string.to_owned(),
-                            freezed: self
-                                .struct_pool
-                                .get(ident_string)
-                                .map(IrStruct::using_freezed)
-                                .unwrap_or(false),
-                            empty: self
-                                .struct_pool
-                                .get(ident_string)
-                                .map(IrStruct::is_empty)
-                                .unwrap_or(false),
-                        }))
-                    } else if self.src_enums.contains_key(ident_string) {
+                        Some(self.struct_ref(ident_string.to_owned(), None))
+                    } else if let Some(src_enum) = self.src_enums.get(ident_string) {
+                        if markers::has_ignore(&src_enum.src.attrs) {
+                            panic!(
+                                "Enum `{}` is marked `#[frb(ignore)]`, \
+                                but a generated function uses it",
+                                ident_string
+                            );
+                        }
                         if self.parsed_enums.insert(ident_string.to_owned()) {
-                            let enu = self.parse_enum_core(&p.ident);
+                            let enu = self.with_generic_substitutions(HashMap::new(), |parser| {
+                                parser.parse_enum_core(&ident)
+                            });
                             self.enum_pool.insert(ident_string.to_owned(), enu);
                         }
 
@@ -370,6 +621,10 @@ impl<'a> TypeParser<'a> {
                                 repr: IrTypePrimitive::I32,
                             }))
                         }
+                    } else if ident_string == "Backtrace" {
+                        Some(Delegate(IrTypeDelegate::Backtrace))
+                    } else if ident_string == "SharedBuffer" {
+                        Some(Delegate(IrTypeDelegate::SharedBuffer))
                     } else {
                         None
                     }
@@ -379,6 +634,103 @@ impl<'a> TypeParser<'a> {
 }
 
 impl<'a> TypeParser<'a> {
+    fn custom_codec(&self, name: &str) -> Option<IrTypeCustomCodec> {
+        let attrs = match (self.src_structs.get(name), self.src_enums.get(name)) {
+            (Some(src_struct), _) => &src_struct.src.attrs,
+            (_, Some(src_enum)) => &src_enum.src.attrs,
+            _ => return None,
+        };
+        extract_custom_codec(attrs, name)
+    }
+
+    fn struct_ref(&self, name: String, rust_type: Option<String>) -> IrType {
+        let src = self.struct_pool.get(&name);
+        // Still being parsed if it is recursive.
+        let dart_type = match src {
+            Some(src) => src.dart_type.as_ref().map(|it| it.name.clone()),
+            None => self
+                .src_structs
+                .get(&name)
+                .and_then(|src| extract_dart_type(&src.src.attrs))
+                .map(|it| it.name),
+        };
+        StructRef(IrTypeStructRef {
+            freezed: src.map(IrStruct::using_freezed).unwrap_or(false),
+            empty: src.map(IrStruct::is_empty).unwrap_or(false),
+            dart_type,
+            name,
+            rust_type,
+        })
+    }
+
+    /// Monomorphizes a generic struct: `Pair<i32>` becomes a struct named `PairI32`,
+    /// whose fields are parsed with `T` substituted by `i32`.
+    fn convert_generic_struct_to_ir_type(
+        &mut self,
+        ident: &syn::Ident,
+        args: Vec<SupportedInnerType>,
+    ) -> IrType {
+        let generic_name = ident.to_string();
+        let args = args
+            .into_iter()
+            .map(|arg| {
+                let arg_str = arg.to_string();
+                self.convert_to_ir_type(arg).unwrap_or_else(|| {
+                    panic!(
+                        "Cannot instantiate generic struct `{}` with `{}`, \
+                        type arguments must be concrete supported types",
+                        generic_name, arg_str
+                    )
+                })
+            })
+            .collect::<Vec<_>>();
+        let params = generic_type_params(self.src_structs[&generic_name]);
+        if params.len() != args.len() {
+            panic!(
+                "Generic struct `{generic_name}` expects {} type arguments, but got {}",
+                params.len(),
+                args.len()
+            );
+        }
+
+        let name = format!(
+            "{generic_name}{}",
+            args.iter()
+                .map(|arg| arg.safe_ident().to_case(Case::Pascal))
+                .collect::<String>()
+        );
+        let rust_type = format!(
+            "{generic_name}::<{}>",
+            args.iter()
+                .map(IrType::rust_api_type)
+                .collect::<Vec<_>>()
+                .join(", ")
+        );
+        if self.parsing_or_parsed_struct_names.insert(name.clone()) {
+            let substitutions = params.into_iter().zip(args).collect();
+            let mut api_struct = self.with_generic_substitutions(substitutions, |parser| {
+                parser.parse_struct_core(ident)
+            });
+            api_struct.wrapper_name = api_struct.wrapper_name.map(|_| format!("mirror_{name}"));
+            api_struct.name = name.clone();
+            self.struct_pool.insert(name.clone(), api_struct);
+        }
+        self.struct_ref(name, Some(rust_type))
+    }
+
+    /// Runs `f` with the given type arguments in scope, so that nested structs and enums
+    /// never see the substitutions of the struct that refers to them.
+    fn with_generic_substitutions<T>(
+        &mut self,
+        substitutions: HashMap<String, IrType>,
+        f: impl FnOnce(&mut Self) -> T,
+    ) -> T {
+        let outer = std::mem::replace(&mut self.generic_substitutions, substitutions);
+        let ans = f(self);
+        self.generic_substitutions = outer;
+        ans
+    }
+
     fn parse_enum_core(&mut self, ident: &syn::Ident) -> IrEnum {
         let src_enum = self.src_enums[&ident.to_string()];
         let name = src_enum.ident.to_string();
@@ -394,7 +746,8 @@ impl<'a> TypeParser<'a> {
             .variants
             .iter()
             .map(|variant| IrVariant {
-                name: IrIdent::new(variant.ident.to_string()),
+                name: IrIdent::new(variant.ident.to_string())
+                    .with_dart_name(markers::extract_dart_name(&variant.attrs)),
                 wrapper_name: IrIdent::new(format!("{}_{}", src_enum.ident, variant.ident)),
                 comments: extract_comments(&variant.attrs),
                 kind: match variant.fields.iter().next() {
@@ -405,13 +758,19 @@ impl<'a> TypeParser<'a> {
                         ..
                     }) => {
                         let variant_ident = variant.ident.to_string();
-                        IrVariantKind::Struct(IrStruct {
+                        IrVariantKind::Struct(Box::new(IrStruct {
                             name: variant_ident,
                             wrapper_name: None,
                             path: None,
                             is_fields_named: field_ident.is_some(),
                             dart_metadata: extract_metadata(attrs),
                             comments: extract_comments(attrs),
+                            dart_type: None,
+                            dart_asserts: vec![],
+                            dart_positional: false,
+                            skipped_fields: vec![],
+                            repr_c: false,
+                            span: None,
                             fields: variant
                                 .fields
                                 .iter()
@@ -423,23 +782,44 @@ impl<'a> TypeParser<'a> {
                                             .as_ref()
                                             .map(ToString::to_string)
                                             .unwrap_or_else(|| format!("field{idx}")),
-                                    ),
+                                    )
+                                    .with_dart_name(markers::extract_dart_name(&field.attrs)),
                                     ty: self.parse_type(&field.ty),
                                     is_final: true,
                                     comments: extract_comments(&field.attrs),
+                                    default: None,
+                                    flatten: false,
+                                    zero_copy: false,
+                                    borrowed: false,
                                 })
                                 .collect(),
-                        })
+                        }))
                     }
                 },
+                discriminant: variant
+                    .discriminant
+                    .as_ref()
+                    .map(|(_, expr)| parse_discriminant(&name, &variant.ident, expr)),
             })
             .collect();
-        IrEnum::new(name, wrapper_name, path, comments, variants)
+        let span = IrSpan::new(
+            src_enum.file_path.display().to_string(),
+            src_enum.src.ident.span(),
+        );
+        let enu = IrEnum::new(name, wrapper_name, path, comments, span, variants);
+        if enu.is_struct() && enu.has_discriminants() {
+            panic!(
+                "Enum `{}` has explicit discriminants, which are only supported for enums without fields",
+                enu.name
+            );
+        }
+        enu
     }
 
     fn parse_struct_core(&mut self, ident: &syn::Ident) -> IrStruct {
         let src_struct = self.src_structs[&ident.to_string()];
         let mut fields = Vec::new();
+        let mut skipped_fields = Vec::new();
 
         let (is_fields_named, struct_fields) = match &src_struct.src.fields {
             Fields::Named(FieldsNamed { named, .. }) => (true, named),
@@ -452,12 +832,61 @@ impl<'a> TypeParser<'a> {
                 .ident
                 .as_ref()
                 .map_or(format!("field{idx}"), ToString::to_string);
+            if let Some(value) = markers::extract_skip(&field.attrs) {
+                if !is_fields_named {
+                    panic!(
+                        "Field `{}` of tuple struct `{}` cannot be skipped, \
+                        only the fields of structs with named fields can",
+                        field_name, ident
+                    );
+                }
+                skipped_fields.push(IrSkippedField {
+                    name: field_name,
+                    value,
+                });
+                continue;
+            }
             let field_type = self.parse_type(&field.ty);
+            let flatten = markers::has_flatten(&field.attrs);
+            if flatten
+                && !matches!(
+                    field_type,
+                    IrType::StructRef(IrTypeStructRef {
+                        dart_type: None,
+                        ..
+                    })
+                )
+            {
+                panic!(
+                    "Field `{}` of struct `{}` cannot be flattened, \
+                    as it is not a struct with a generated Dart class",
+                    field_name, ident
+                );
+            }
+            let zero_copy = markers::has_zero_copy(&field.attrs)
+                && match &field_type {
+                    IrType::PrimitiveList(_) => true,
+                    IrType::Optional(IrTypeOptional { inner }) => {
+                        matches!(**inner, IrType::PrimitiveList(_))
+                    }
+                    _ => {
+                        log::warn!(
+                            "Field `{field_name}` of struct `{ident}` is not a `Vec` of numbers, \
+                            so it is copied despite `#[frb(zero_copy)]`"
+                        );
+                        false
+                    }
+                };
             fields.push(IrField {
-                name: IrIdent::new(field_name),
+                name: IrIdent::new(field_name)
+                    .with_dart_name(markers::extract_dart_name(&field.attrs)),
                 ty: field_type,
                 is_final: !markers::has_non_final(&field.attrs),
                 comments: extract_comments(&field.attrs),
+                default: None,
+                flatten,
+                zero_copy,
+                borrowed: false,
             });
         }
 
@@ -470,14 +899,97 @@ impl<'a> TypeParser<'a> {
         let path = Some(src_struct.path.clone());
         let metadata = extract_metadata(&src_struct.src.attrs);
         let comments = extract_comments(&src_struct.src.attrs);
+        let dart_type = extract_dart_type(&src_struct.src.attrs);
+        let dart_asserts = markers::extract_asserts(&src_struct.src.attrs);
+        let dart_positional = markers::has_positional(&src_struct.src.attrs);
+        let repr_c = markers::has_repr_c(&src_struct.src.attrs)
+            && {
+                let unsupported = (fields.iter())
+                .find(|field| {
+                    !matches!(&field.ty, IrType::Primitive(primitive) if primitive.repr_c_size().is_some())
+                })
+                .map(|field| field.name.to_string());
+                match unsupported {
+                    None if !fields.is_empty() => true,
+                    _ => {
+                        log::warn!(
+                        "Struct `{ident}` is sent field by field despite `#[frb(repr_c)]`, as {}",
+                        match unsupported {
+                            Some(name) => format!(
+                                "its field `{name}` is not a number of at most 32 bits, an `f64` or a `bool`"
+                            ),
+                            None => "it has no fields".to_owned(),
+                        }
+                    );
+                        false
+                    }
+                }
+            };
+        let span = IrSpan::new(
+            src_struct.file_path.display().to_string(),
+            src_struct.src.ident.span(),
+        );
         IrStruct {
             name,
             wrapper_name,
             path,
             fields,
+            skipped_fields,
+            repr_c,
             is_fields_named,
             dart_metadata: metadata,
             comments,
+            dart_type,
+            dart_asserts,
+            dart_positional,
+            span: Some(span),
         }
     }
 }
+
+/// The value of an explicit discriminant, which is sent as an `i32`.
+fn parse_discriminant(enum_name: &str, variant: &syn::Ident, expr: &Expr) -> i64 {
+    let value = match expr {
+        Expr::Lit(ExprLit {
+            lit: Lit::Int(int), ..
+        }) => int.base10_parse::<i64>().ok(),
+        Expr::Unary(ExprUnary {
+            op: UnOp::Neg(_),
+            expr,
+            ..
+        }) => match &**expr {
+            Expr::Lit(ExprLit {
+                lit: Lit::Int(int), ..
+            }) => int.base10_parse::<i64>().ok().map(|value| -value),
+            _ => None,
+        },
+        _ => None,
+    };
+    match value {
+        Some(value) if i32::try_from(value).is_ok() => value,
+        _ => panic!(
+            "The discriminant of `{enum_name}::{variant}` must be an integer literal fitting in an `i32`, found `{}`",
+            quote::quote!(#expr)
+        ),
+    }
+}
+
+/// Names of the type parameters of a struct, such as `T` for `struct Pair<T>`.
+fn generic_type_params(src_struct: &Struct) -> Vec<String> {
+    src_struct
+        .src
+        .generics
+        .type_params()
+        .map(|param| param.ident.to_string())
+        .collect()
+}
+
+/// A `std::num::NonZero*` wrapping `inner`.
+fn non_zero(inner: IrType) -> IrType {
+    Delegate(IrTypeDelegate::NonZero(Box::new(inner)))
+}
+
+/// `Box<dyn #bounds>`, the opaque type trait objects are sent to Dart as.
+fn boxed_dyn(bounds: &punctuated::Punctuated<TypeParamBound, Token![+]>) -> IrTypeRustOpaque {
+    IrTypeRustOpaque::from(format!("Box<dyn {}>", quote::quote!(#bounds)))
+}
@@ -123,6 +123,8 @@ pub struct Struct {
     pub visibility: Visibility,
     pub path: Vec<String>,
     pub mirror: bool,
+    /// The file defining the item, which may hold the definition of its module as well.
+    pub file_path: PathBuf,
 }
 
 impl Debug for Struct {
@@ -144,6 +146,8 @@ pub struct Enum {
     pub visibility: Visibility,
     pub path: Vec<String>,
     pub mirror: bool,
+    /// The file defining the item, which may hold the definition of its module as well.
+    pub file_path: PathBuf,
 }
 
 impl Debug for Enum {
@@ -296,6 +300,7 @@ impl Module {
                                 path
                             },
                             mirror,
+                            file_path: self.file_path.clone(),
                         }
                     }));
                 }
@@ -314,6 +319,7 @@ impl Module {
                                 path
                             },
                             mirror,
+                            file_path: self.file_path.clone(),
                         }
                     }));
                 }
@@ -427,6 +433,42 @@ impl Module {
         }
     }
 
+    /// The module at `path`, e.g. `["crate", "api", "user"]`, if it is this module or one of its descendants.
+    pub fn find_module(&self, path: &[&str]) -> Option<&Module> {
+        if !path.starts_with(
+            &self
+                .module_path
+                .iter()
+                .map(String::as_str)
+                .collect::<Vec<_>>(),
+        ) {
+            return None;
+        }
+        if path.len() == self.module_path.len() {
+            return Some(self);
+        }
+        let scope = self.scope.as_ref()?;
+        scope
+            .modules
+            .iter()
+            .find_map(|module| module.find_module(path))
+    }
+
+    /// Whether a function named `name` is defined directly in this module, or possibly
+    /// imported into it by a `use`.
+    pub fn defines_fn(&self, name: &str) -> bool {
+        let items = match &self.source {
+            Some(ModuleSource::File(file)) => &file.items,
+            Some(ModuleSource::ModuleInFile(items)) => items,
+            None => return false,
+        };
+        items.iter().any(|item| match item {
+            syn::Item::Fn(func) => func.sig.ident == name,
+            syn::Item::Use(item_use) => imports_name(&item_use.tree, name),
+            _ => false,
+        })
+    }
+
     pub fn collect_structs<'a>(&'a self, container: &mut HashMap<String, &'a Struct>) {
         let scope = self.scope.as_ref().unwrap();
         for scope_struct in &scope.structs {
@@ -649,3 +691,14 @@ fn flatten_use_tree(use_tree: &UseTree) -> Vec<Vec<String>> {
 
     result.into_iter().map(|val| val.0).collect()
 }
+
+/// Whether `tree` brings an item named `name` into scope, including through a glob.
+fn imports_name(tree: &UseTree, name: &str) -> bool {
+    match tree {
+        UseTree::Path(path) => imports_name(&path.tree, name),
+        UseTree::Name(use_name) => use_name.ident == name,
+        UseTree::Rename(rename) => rename.rename == name,
+        UseTree::Glob(_) => true,
+        UseTree::Group(group) => group.items.iter().any(|tree| imports_name(tree, name)),
+    }
+}
@@ -126,10 +126,17 @@ pub enum PackageManager {
 /// used to deserialize `dependencies` and `dev_dependencies` from pubspec.yaml
 #[derive(Debug, Deserialize)]
 struct Pubspec {
+    pub name: Option<String>,
     pub dependencies: Option<HashMap<String, Option<PackageVersion>>>,
     pub dev_dependencies: Option<HashMap<String, Option<PackageVersion>>>,
 }
 
+/// The name of the Dart package at `dart_root`, if its pubspec.yaml can be read.
+pub(crate) fn dart_package_name(dart_root: &str) -> Option<String> {
+    let pubspec = read_file(dart_root, DartToolchain::manifest_filename()).ok()?;
+    serde_yaml::from_str::<Pubspec>(&pubspec).ok()?.name
+}
+
 #[inline]
 fn read_file(at: &str, filename: &str) -> anyhow::Result<String> {
     let file = PathBuf::from(at).join(filename);
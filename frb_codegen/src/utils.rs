@@ -50,8 +50,13 @@ pub fn get_symbols_if_no_duplicates(configs: &[crate::Opts]) -> Result<Vec<Strin
     for config in configs {
         let raw_ir_file = config.get_ir_file()?;
 
-        // for checking explicit api duplication
-        explicit_raw_symbols.extend(raw_ir_file.funcs.iter().map(|f| f.name.clone()));
+        // for checking explicit api duplication, which the `--drain-streams` companions share
+        // with the function they drain
+        explicit_raw_symbols.extend(
+            (raw_ir_file.funcs.iter())
+                .filter(|f| f.drain.is_none())
+                .map(|f| f.name.clone()),
+        );
 
         // for avoiding redundant generation in dart
         all_symbols.extend(raw_ir_file.get_all_symbols(config));
@@ -96,3 +101,23 @@ impl std::path::Path {
         self.file_name().and_then(OsStr::to_str)
     }
 }
+
+/// A single-quoted Dart string holding `s`, with `$` escaped so that nothing is interpolated.
+pub fn dart_string_literal(s: &str) -> String {
+    let mut literal = String::from("'");
+    for c in s.chars() {
+        match c {
+            '\\' | '\'' | '$' => {
+                literal.push('\\');
+                literal.push(c);
+            }
+            '\n' => literal.push_str("\\n"),
+            '\r' => literal.push_str("\\r"),
+            '\t' => literal.push_str("\\t"),
+            c if c.is_control() => literal.push_str(&format!("\\u{{{:x}}}", c as u32)),
+            c => literal.push(c),
+        }
+    }
+    literal.push('\'');
+    literal
+}
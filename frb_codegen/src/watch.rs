@@ -0,0 +1,189 @@
+//! Regenerate the bridge whenever the input Rust files change, see `--watch`.
+
+use std::collections::{BTreeSet, HashMap};
+use std::fs;
+use std::panic::{self, AssertUnwindSafe};
+use std::path::PathBuf;
+use std::thread;
+use std::time::{Duration, SystemTime};
+
+use log::{error, info, warn};
+
+use crate::config::Opts;
+use crate::source_graph::{Crate, Module};
+use crate::{frb_codegen, get_symbols_if_no_duplicates};
+
+/// How often the files are checked for changes.
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+/// How long the files must stay untouched before regenerating, so that a burst of saves
+/// only triggers one rebuild.
+const DEBOUNCE: Duration = Duration::from_millis(300);
+
+/// Modification time of every watched file, or `None` if it does not exist (anymore).
+type Snapshot = HashMap<PathBuf, Option<SystemTime>>;
+
+/// Watch the input files of `configs` and regenerate on change. Never returns; stop it with Ctrl-C.
+pub fn watch(configs: &[Opts]) -> ! {
+    let mut watched = configs.iter().map(watched_files).collect::<Vec<_>>();
+    let mut snapshot = take_snapshot(&watched);
+    info!(
+        "Watching {} files for changes, press Ctrl-C to stop",
+        snapshot.len()
+    );
+
+    loop {
+        thread::sleep(POLL_INTERVAL);
+        let mut latest = take_snapshot(&watched);
+        if latest == snapshot {
+            continue;
+        }
+        // Wait for the burst of saves to settle down.
+        loop {
+            thread::sleep(DEBOUNCE);
+            let settled = take_snapshot(&watched);
+            if settled == latest {
+                break;
+            }
+            latest = settled;
+        }
+        let changed = changed_files(&snapshot, &latest);
+
+        for path in &changed {
+            if path.exists() {
+                info!("Changed: {}", path.display());
+            } else {
+                warn!("Removed or renamed: {}", path.display());
+            }
+        }
+        let affected = configs
+            .iter()
+            .zip(&watched)
+            .filter(|(_, files)| changed.iter().any(|path| files.contains(path)))
+            .map(|(config, _)| config)
+            .collect::<Vec<_>>();
+        for (config, result) in affected.iter().zip(regenerate(configs, &affected)) {
+            match result {
+                Ok(()) => info!(
+                    "Regenerated {} and {}",
+                    config.rust_output_path, config.dart_output_path
+                ),
+                Err(err) => error!(
+                    "Failed to regenerate {}: {}",
+                    config.rust_input_paths.join(", "),
+                    err
+                ),
+            }
+        }
+
+        // Modules may have been added or removed, and the generated files must not
+        // trigger another rebuild, so start over from the current state.
+        for (files, config) in watched.iter_mut().zip(configs) {
+            if let Some(new_files) = try_watched_files(config) {
+                *files = new_files;
+            }
+        }
+        snapshot = take_snapshot(&watched);
+    }
+}
+
+/// Regenerate `affected`, catching the panics the codegen uses for invalid input so that a
+/// half-written file does not stop the watcher.
+fn regenerate(configs: &[Opts], affected: &[&Opts]) -> Vec<anyhow::Result<()>> {
+    let all_symbols = match catch(|| get_symbols_if_no_duplicates(configs)) {
+        Ok(all_symbols) => all_symbols,
+        Err(err) => {
+            let message = err.to_string();
+            return affected
+                .iter()
+                .map(|_| Err(anyhow::anyhow!("{}", message)))
+                .collect();
+        }
+    };
+    affected
+        .iter()
+        .map(|config| catch(|| frb_codegen(config, &all_symbols)))
+        .collect()
+}
+
+fn catch<T>(f: impl FnOnce() -> anyhow::Result<T>) -> anyhow::Result<T> {
+    panic::catch_unwind(AssertUnwindSafe(f)).unwrap_or_else(|_| {
+        Err(anyhow::anyhow!(
+            "the codegen panicked, see the message above"
+        ))
+    })
+}
+
+/// The input files and all the module files of their crate.
+fn watched_files(config: &Opts) -> BTreeSet<PathBuf> {
+    try_watched_files(config).unwrap_or_else(|| {
+        warn!(
+            "Could not resolve the modules of {}, only watching the input files",
+            config.manifest_path
+        );
+        config.rust_input_paths.iter().map(PathBuf::from).collect()
+    })
+}
+
+/// The input files and all the module files of their crate, or `None` if the crate cannot be resolved.
+pub(crate) fn try_watched_files(config: &Opts) -> Option<BTreeSet<PathBuf>> {
+    let krate = panic::catch_unwind(|| Crate::new(&config.manifest_path)).ok()?;
+    let mut files = config.rust_input_paths.iter().map(PathBuf::from).collect();
+    collect_module_files(&krate.root_module, &mut files);
+    Some(files)
+}
+
+fn collect_module_files(module: &Module, files: &mut BTreeSet<PathBuf>) {
+    files.insert(module.file_path.clone());
+    if let Some(scope) = &module.scope {
+        for module in &scope.modules {
+            collect_module_files(module, files);
+        }
+    }
+}
+
+fn take_snapshot(watched: &[BTreeSet<PathBuf>]) -> Snapshot {
+    watched
+        .iter()
+        .flatten()
+        .map(|path| {
+            let modified = fs::metadata(path).and_then(|meta| meta.modified()).ok();
+            (path.clone(), modified)
+        })
+        .collect()
+}
+
+fn changed_files(old: &Snapshot, new: &Snapshot) -> BTreeSet<PathBuf> {
+    new.iter()
+        .filter(|(path, modified)| old.get(*path) != Some(modified))
+        .map(|(path, _)| path.clone())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn changed_files_include_the_added_and_removed_ones() {
+        let time = |secs| Some(SystemTime::UNIX_EPOCH + Duration::from_secs(secs));
+        let snapshot = |entries: &[(&str, Option<SystemTime>)]| {
+            (entries.iter())
+                .map(|(path, modified)| (PathBuf::from(path), *modified))
+                .collect::<Snapshot>()
+        };
+        let old = snapshot(&[
+            ("same.rs", time(1)),
+            ("edited.rs", time(1)),
+            ("removed.rs", time(1)),
+        ]);
+        let new = snapshot(&[
+            ("same.rs", time(1)),
+            ("edited.rs", time(2)),
+            ("removed.rs", None),
+            ("added.rs", time(1)),
+        ]);
+        let changed = changed_files(&old, &new);
+        let expected = ["added.rs", "edited.rs", "removed.rs"].map(PathBuf::from);
+        assert_eq!(changed.into_iter().collect::<Vec<_>>(), expected);
+    }
+}
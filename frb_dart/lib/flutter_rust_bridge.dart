@@ -2,4 +2,7 @@ export 'src/basic.dart';
 export 'src/helpers.dart';
 export 'src/platform_independent.dart';
 export 'src/typed_data.dart';
+export 'src/net.dart';
+export 'src/range.dart';
+export 'src/tuple.dart';
 export 'src/load.dart';
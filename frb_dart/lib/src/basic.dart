@@ -3,6 +3,8 @@ import 'dart:async';
 import 'package:flutter_rust_bridge/src/platform_independent.dart';
 import 'package:flutter_rust_bridge/src/utils.dart';
 import 'package:meta/meta.dart';
+import 'cancelable.dart';
+export 'cancelable.dart';
 import 'ffi.dart';
 export 'ffi.dart';
 import 'isolate.dart';
@@ -10,6 +12,7 @@ export 'isolate.dart';
 
 final _instances = <Type>{};
 final _streamSinkNameIndex = <String, int>{};
+var _nextCallbackId = 0;
 
 class _DropIdPortGenerator {
   static final instance = _DropIdPortGenerator._();
@@ -19,6 +22,13 @@ class _DropIdPortGenerator {
   String create() => '__frb_dart_opaque_drop_${nextPort++}';
 }
 
+/// Observes a call to Rust, see [FlutterRustBridgeBase.callObserver].
+///
+/// It is invoked right before the call, and the function it returns, if any, is
+/// invoked once the call is over, with the error it failed with, or `null`.
+typedef FlutterRustBridgeCallObserver = void Function(Object? error)? Function(
+    FlutterRustBridgeTaskConstMeta constMeta);
+
 /// Base class for generated bindings of Flutter Rust Bridge.
 /// Normally, users do not extend this class manually. Instead,
 /// users should directly use the generated class.
@@ -31,6 +41,17 @@ abstract class FlutterRustBridgeBase<T extends FlutterRustBridgeWireBase> {
   @protected
   final T inner;
 
+  /// Observes every call to Rust, of all the generated classes, e.g. for logging
+  /// or metrics. [FlutterRustBridgeTaskConstMeta.function] tells which function
+  /// is called.
+  ///
+  /// A `Future` call is over once Rust returns, even if it was cancelled or timed
+  /// out before. A stream call is over once the stream is done or its
+  /// subscription is cancelled, and errors of its items are not reported.
+  ///
+  /// Calls are not observed at all while it is `null`, which is the default.
+  static FlutterRustBridgeCallObserver? callObserver;
+
   late final _dropPort = _initDropPort();
   NativePortType get dropPort => _dropPort.sendPort.nativePort;
 
@@ -63,43 +84,216 @@ abstract class FlutterRustBridgeBase<T extends FlutterRustBridgeWireBase> {
   /// Execute a normal ffi call. Usually called by generated code instead of manually called.
   @protected
   Future<S> executeNormal<S>(FlutterRustBridgeTask<S> task) {
+    final onDone = callObserver?.call(task.constMeta);
     final completer = Completer<dynamic>();
     final sendPort = singleCompletePort(completer);
     task.callFfi(sendPort.nativePort);
-    return completer.future.then((dynamic raw) =>
-        _transformRust2DartMessage(raw, task.parseSuccessData));
+    var future = completer.future.then((dynamic raw) =>
+        _transformRust2DartMessage(
+            raw, task.parseSuccessData, task.parseErrorData));
+    if (onDone != null) {
+      future = future.then((value) {
+        onDone(null);
+        return value;
+      }, onError: (Object err, StackTrace st) {
+        onDone(err);
+        return Future<S>.error(err, st);
+      });
+    }
+    return future;
+  }
+
+  /// Runs [task], which returns the handle of a Rust iterator, then pulls its
+  /// items with [next] one at a time, whenever the returned stream asks for more.
+  ///
+  /// The handle is disposed once the iterator is exhausted, or when the
+  /// subscription is cancelled, which drops the iterator in Rust.
+  @protected
+  Stream<T> executeIterator<H extends FrbOpaque, T extends Object>(
+      FlutterRustBridgeTask<H> task,
+      {required Future<T?> Function(H iterator) next}) async* {
+    final iterator = await executeNormal(task);
+    try {
+      while (true) {
+        final item = await next(iterator);
+        if (item == null) return;
+        yield item;
+      }
+    } finally {
+      iterator.dispose();
+    }
+  }
+
+  /// Similar to [executeNormal], except that the returned future can be cancelled.
+  /// [cancelId] identifies the call to Rust and comes from [createCancelId].
+  ///
+  /// If [timeout] elapses first, the call is cancelled, and the future completes
+  /// with a [FlutterRustBridgeTimeoutException].
+  @protected
+  CancelableFuture<S> executeCancelable<S>(
+      int cancelId, FlutterRustBridgeTask<S> task,
+      {Duration? timeout}) {
+    return CancelableFuture(
+      executeNormal(task),
+      onCancel: () => inner.cancel_task(cancelId),
+      debugName: task.debugName,
+      timeout: timeout,
+    );
+  }
+
+  /// Creates an id for a call to Rust, used by [executeCancelable]. Rust allocates
+  /// it, so that the calls of different isolates get different ids.
+  @protected
+  int createCancelId() => inner.new_cancel_id();
+
+  /// Creates the port through which Rust calls [callback], for an `impl Fn(T)`
+  /// argument. Each value sent by Rust is decoded by [parse].
+  ///
+  /// The port stays open, keeping [callback] alive, until Rust drops the closure.
+  @protected
+  NativePortType createCallbackPort<S>(
+      void Function(S) callback, S Function(dynamic) parse) {
+    final receivePort = broadcastPort('__frb_callback_${_nextCallbackId++}');
+    receivePort.listen((dynamic raw) {
+      switch (raw[0]) {
+        case _RUST2DART_ACTION_SUCCESS:
+          assert(raw.length == 2);
+          callback(parse(raw[1]));
+          break;
+        case _RUST2DART_ACTION_CLOSE_STREAM:
+          receivePort.close();
+          break;
+        default:
+          throw Exception('Unsupported callback message, raw=$raw');
+      }
+    });
+    return receivePort.sendPort.nativePort;
   }
 
   /// Similar to [executeNormal], except that this will return synchronously
   @protected
   S executeSync<S>(FlutterRustBridgeSyncTask task) {
+    final observer = callObserver;
+    if (observer == null) return _executeSync(task);
+    final onDone = observer(task.constMeta);
+    final S result;
+    try {
+      result = _executeSync(task);
+    } catch (err) {
+      onDone?.call(err);
+      rethrow;
+    }
+    onDone?.call(null);
+    return result;
+  }
+
+  /// Same as [executeSync], except that the result completes the returned
+  /// [Future], see `#[frb(sync)]`. The call itself is made right away.
+  @protected
+  Future<S> executeSyncAsFuture<S>(FlutterRustBridgeSyncTask task) =>
+      Future.sync(() => executeSync<S>(task));
+
+  /// Same as [executeSync], except that the error is returned in the
+  /// [SyncResult] instead of being thrown.
+  @protected
+  SyncResult<S> executeSyncResult<S>(FlutterRustBridgeSyncTask task) {
+    final observer = callObserver;
+    if (observer == null) return _executeSyncResult(task);
+    final onDone = observer(task.constMeta);
+    final result = _executeSyncResult<S>(task);
+    onDone?.call(result.error);
+    return result;
+  }
+
+  S _executeSync<S>(FlutterRustBridgeSyncTask task) {
+    final result = _executeSyncResult<S>(task);
+    if (result.isOk) return result.value;
+    throw result.error!;
+  }
+
+  SyncResult<S> _executeSyncResult<S>(FlutterRustBridgeSyncTask task) {
     final WireSyncReturn syncReturn;
     try {
       syncReturn = task.callFfi();
     } catch (err, st) {
-      throw FfiException('EXECUTE_SYNC_ABORT', '$err', st);
+      return SyncResult.error(FfiException('EXECUTE_SYNC_ABORT', '$err', st));
     }
     try {
       final syncReturnAsDartObject = wireSyncReturnIntoDart(syncReturn);
       assert(syncReturnAsDartObject.length == 2);
       final rawReturn = syncReturnAsDartObject[0];
-      final isSuccess = syncReturnAsDartObject[1];
-      if (isSuccess) {
-        return task.parseSuccessData(rawReturn);
-      } else {
-        throw FfiException('EXECUTE_SYNC', rawReturn as String, null);
+      final kind = syncReturnAsDartObject[1];
+      switch (kind) {
+        case _SYNC_RETURN_SUCCESS:
+          return SyncResult.ok(task.parseSuccessData(rawReturn));
+        case _SYNC_RETURN_ERROR:
+          return SyncResult.error(
+              FfiException('EXECUTE_SYNC', rawReturn as String, null));
+        case _SYNC_RETURN_CUSTOM_ERROR:
+          final parseErrorData = task.parseErrorData;
+          return SyncResult.error(parseErrorData == null
+              ? FfiException('EXECUTE_SYNC',
+                  'Unexpected custom error, raw=$rawReturn', null)
+              : parseErrorData(rawReturn));
+        case _SYNC_RETURN_PANIC:
+          return SyncResult.error(
+              FrbPanicException(rawReturn[0], rawReturn[1]));
+        default:
+          throw Exception('Unsupported sync return, kind=$kind');
       }
     } catch (err, st) {
-      if (err is FfiException) rethrow;
-      throw FfiException('EXECUTE_SYNC_ABORT', '$err', st);
+      return SyncResult.error(FfiException('EXECUTE_SYNC_ABORT', '$err', st));
     } finally {
       inner.free_WireSyncReturn(syncReturn);
     }
   }
 
+  /// Same as [executeSync], for the `...Drain` companion of a stream function,
+  /// see `--drain-streams`: the Rust function runs to completion, and the items
+  /// of its stream are returned at once. Throws the first error of the items, if
+  /// any, or if the stream is still open once the Rust function returns.
+  @protected
+  List<S> executeSyncDrain<S>(FlutterRustBridgeSyncTask<S> task) {
+    final messages = executeSync<List<dynamic>>(FlutterRustBridgeSyncTask(
+      callFfi: task.callFfi,
+      parseSuccessData: (raw) => raw as List<dynamic>,
+      parseErrorData: task.parseErrorData,
+      constMeta: task.constMeta,
+      argValues: task.argValues,
+      hint: task.hint,
+    ));
+    final items = <S>[];
+    for (final raw in messages) {
+      try {
+        items.add(_transformRust2DartMessage(
+            raw, task.parseSuccessData, task.parseErrorData));
+      } on _CloseStreamException {
+        break;
+      }
+    }
+    return items;
+  }
+
   /// Similar to [executeNormal], except that this will return a [Stream] instead of a [Future].
+  ///
+  /// The Rust function is only called when the stream is first listened to, and
+  /// its events are buffered until they are consumed, so none of them are lost.
+  /// The stream is single-subscription unless [broadcast] is set, in which case
+  /// events only reach the listeners present when they arrive.
+  @protected
+  Stream<S> executeStream<S>(FlutterRustBridgeTask<S> task,
+      {bool broadcast = false}) {
+    var stream = executeRawStream(task);
+    final observer = callObserver;
+    if (observer != null) stream = _observeStream(stream, task, observer);
+    if (broadcast) return stream.asBroadcastStream();
+    return _SingleSubscriptionStream(stream, task.constMeta.debugName);
+  }
+
+  /// The events of [task], before [executeStream] decides how they can be listened to.
+  /// Override this instead of [executeStream] to run code before the Rust function is called.
   @protected
-  Stream<S> executeStream<S>(FlutterRustBridgeTask<S> task) async* {
+  Stream<S> executeRawStream<S>(FlutterRustBridgeTask<S> task) async* {
     final func = task.constMeta.debugName;
     final nextIndex = _streamSinkNameIndex.update(func, (value) => value + 1,
         ifAbsent: () => 0);
@@ -108,8 +302,14 @@ abstract class FlutterRustBridgeBase<T extends FlutterRustBridgeWireBase> {
     task.callFfi(receivePort.sendPort.nativePort);
 
     await for (final raw in receivePort) {
+      // Errors of `Result` items do not terminate the stream.
+      if (raw[0] == _RUST2DART_ACTION_CUSTOM_ERROR) {
+        yield* Stream.error(_parseCustomError(raw, task.parseErrorData));
+        continue;
+      }
       try {
-        yield _transformRust2DartMessage(raw, task.parseSuccessData);
+        yield _transformRust2DartMessage(
+            raw, task.parseSuccessData, task.parseErrorData);
       } on _CloseStreamException {
         receivePort.close();
         break;
@@ -117,8 +317,91 @@ abstract class FlutterRustBridgeBase<T extends FlutterRustBridgeWireBase> {
     }
   }
 
+  Stream<S> _observeStream<S>(Stream<S> stream, FlutterRustBridgeTask<S> task,
+      FlutterRustBridgeCallObserver observer) async* {
+    final onDone = observer(task.constMeta);
+    try {
+      yield* stream;
+    } finally {
+      onDone?.call(null);
+    }
+  }
+
+  /// Similar to [executeStream], for a Rust function which also returns a value.
+  /// The future completes once the function returns, with that value and the
+  /// stream of the items sent by its `StreamSink`, or with its error.
+  ///
+  /// Unlike [executeStream], this calls the Rust function right away. No item is
+  /// lost in between: the items, including those sent before the function
+  /// returns, are buffered until [StreamWithInitial.updates] is listened to.
+  @protected
+  Future<StreamWithInitial<I, S>> executeStreamWithInitial<I, S>(
+      FlutterRustBridgeTask<S> task,
+      {required I Function(dynamic) parseInitial,
+      bool broadcast = false}) {
+    final func = task.constMeta.debugName;
+    final nextIndex = _streamSinkNameIndex.update(func, (value) => value + 1,
+        ifAbsent: () => 0);
+    final receivePort = broadcastPort('__frb_streamsink_${func}_$nextIndex');
+    final onDone = callObserver?.call(task.constMeta);
+    final initial = Completer<StreamWithInitial<I, S>>();
+    late final StreamController<S> updates;
+    var closed = false;
+    var finished = false;
+
+    void finish([Object? error]) {
+      if (finished) return;
+      finished = true;
+      receivePort.close();
+      updates.close();
+      onDone?.call(error);
+    }
+
+    // Cancelling the updates ends the call, as it does for [executeStream].
+    updates = StreamController<S>(onCancel: finish);
+
+    receivePort.listen((dynamic raw) {
+      switch (raw[0]) {
+        case _RUST2DART_ACTION_STREAM_INITIAL:
+          assert(raw.length == 2);
+          initial.complete(StreamWithInitial(
+              parseInitial(raw[1]),
+              broadcast
+                  ? updates.stream.asBroadcastStream()
+                  : _SingleSubscriptionStream(updates.stream, func)));
+          // The sink may have been closed before the function returned.
+          if (closed) finish();
+          break;
+        case _RUST2DART_ACTION_CLOSE_STREAM:
+          closed = true;
+          if (initial.isCompleted) finish();
+          break;
+        case _RUST2DART_ACTION_CUSTOM_ERROR:
+          updates.addError(_parseCustomError(raw, task.parseErrorData));
+          break;
+        default:
+          try {
+            updates.add(_transformRust2DartMessage(
+                raw, task.parseSuccessData, task.parseErrorData));
+          } catch (error, stackTrace) {
+            // The error of the Rust function itself, or of its panic.
+            if (initial.isCompleted) {
+              updates.addError(error, stackTrace);
+            } else {
+              initial.completeError(error, stackTrace);
+            }
+            finish(error);
+          }
+      }
+    });
+    task.callFfi(receivePort.sendPort.nativePort);
+    return initial.future;
+  }
+
   S _transformRust2DartMessage<S>(
-      List<dynamic> raw, S Function(dynamic) parseSuccessData) {
+      List<dynamic> raw,
+      S Function(dynamic) parseSuccessData,
+      Object Function(dynamic)? parseErrorData) {
     final action = raw[0];
     switch (action) {
       case _RUST2DART_ACTION_SUCCESS:
@@ -126,15 +409,29 @@ abstract class FlutterRustBridgeBase<T extends FlutterRustBridgeWireBase> {
         return parseSuccessData(raw[1]);
       case _RUST2DART_ACTION_ERROR:
         assert(raw.length == 4);
+        if (raw[1] == FrbPanicException.panicErrorCode) {
+          throw FrbPanicException(raw[2], raw[3]);
+        }
         throw FfiException(raw[1], raw[2], raw[3]);
       case _RUST2DART_ACTION_CLOSE_STREAM:
         assert(raw.length == 1);
         throw _CloseStreamException();
+      case _RUST2DART_ACTION_CUSTOM_ERROR:
+        throw _parseCustomError(raw, parseErrorData);
       default:
         throw Exception('Unsupported message, action=$action raw=$raw');
     }
   }
 
+  Object _parseCustomError(
+      List<dynamic> raw, Object Function(dynamic)? parseErrorData) {
+    assert(raw.length == 2);
+    if (parseErrorData == null) {
+      return FfiException('CUSTOM_ERROR', 'Unexpected custom error, raw=$raw');
+    }
+    return parseErrorData(raw[1]);
+  }
+
   // ignore: constant_identifier_names
   static const _RUST2DART_ACTION_SUCCESS = 0;
 
@@ -143,6 +440,24 @@ abstract class FlutterRustBridgeBase<T extends FlutterRustBridgeWireBase> {
 
   // ignore: constant_identifier_names
   static const _RUST2DART_ACTION_CLOSE_STREAM = 2;
+
+  // ignore: constant_identifier_names
+  static const _RUST2DART_ACTION_CUSTOM_ERROR = 3;
+
+  // ignore: constant_identifier_names
+  static const _RUST2DART_ACTION_STREAM_INITIAL = 4;
+
+  // ignore: constant_identifier_names
+  static const _SYNC_RETURN_SUCCESS = 0;
+
+  // ignore: constant_identifier_names
+  static const _SYNC_RETURN_ERROR = 1;
+
+  // ignore: constant_identifier_names
+  static const _SYNC_RETURN_CUSTOM_ERROR = 2;
+
+  // ignore: constant_identifier_names
+  static const _SYNC_RETURN_PANIC = 3;
 }
 
 /// A task to call FFI function.
@@ -154,9 +469,13 @@ class FlutterRustBridgeTask<S> extends FlutterRustBridgeBaseTask {
   /// Parse the returned data from the underlying function
   final S Function(dynamic) parseSuccessData;
 
+  /// Parse the structured errors sent from the underlying function, if it has a custom error type
+  final Object Function(dynamic)? parseErrorData;
+
   const FlutterRustBridgeTask({
     required this.callFfi,
     required this.parseSuccessData,
+    this.parseErrorData,
     required FlutterRustBridgeTaskConstMeta constMeta,
     required List<dynamic> argValues,
     required dynamic hint,
@@ -176,9 +495,13 @@ class FlutterRustBridgeSyncTask<S> extends FlutterRustBridgeBaseTask {
   /// Parse the returned data from the underlying function
   final S Function(dynamic) parseSuccessData;
 
+  /// Parse the structured error returned from the underlying function, if it has a custom error type
+  final Object Function(dynamic)? parseErrorData;
+
   const FlutterRustBridgeSyncTask({
     required this.callFfi,
     required this.parseSuccessData,
+    this.parseErrorData,
     required FlutterRustBridgeTaskConstMeta constMeta,
     required List<dynamic> argValues,
     required dynamic hint,
@@ -189,4 +512,80 @@ class FlutterRustBridgeSyncTask<S> extends FlutterRustBridgeBaseTask {
         );
 }
 
+/// The outcome of a synchronous call, which holds its error instead of
+/// throwing it.
+///
+/// It is returned by the `...Result` companion of each synchronous function
+/// returning a `Result`, for loops where the cost of exceptions matters.
+/// [error] is what the function itself would throw, e.g. the custom error type
+/// of the function, a [FfiException] or a [FrbPanicException].
+@immutable
+class SyncResult<T> {
+  final T? _value;
+
+  /// The error of the call, or `null` if it succeeded.
+  final Object? error;
+
+  const SyncResult.ok(T value)
+      : _value = value,
+        error = null;
+
+  const SyncResult.error(Object this.error) : _value = null;
+
+  bool get isOk => error == null;
+
+  /// The value returned by the call.
+  ///
+  /// Throws a [StateError] if the call failed.
+  T get value {
+    if (!isOk) throw StateError('The call failed with $error');
+    return _value as T;
+  }
+
+  @override
+  String toString() =>
+      isOk ? 'SyncResult.ok($_value)' : 'SyncResult.error($error)';
+}
+
+/// The value returned by a Rust function taking a `StreamSink`, along with the
+/// stream of the items it sends, see
+/// [FlutterRustBridgeBase.executeStreamWithInitial].
+@immutable
+class StreamWithInitial<I, S> {
+  /// The value returned by the Rust function.
+  final I initial;
+
+  /// The items sent through the `StreamSink`, from the first one.
+  final Stream<S> updates;
+
+  const StreamWithInitial(this.initial, this.updates);
+
+  @override
+  String toString() => 'StreamWithInitial($initial)';
+}
+
 class _CloseStreamException {}
+
+/// Fails clearly when listened to more than once, instead of the generic
+/// "Stream has already been listened to".
+class _SingleSubscriptionStream<T> extends Stream<T> {
+  final Stream<T> _inner;
+  final String _debugName;
+  var _listened = false;
+
+  _SingleSubscriptionStream(this._inner, this._debugName);
+
+  @override
+  StreamSubscription<T> listen(void Function(T event)? onData,
+      {Function? onError, void Function()? onDone, bool? cancelOnError}) {
+    if (_listened) {
+      throw StateError(
+          'The stream returned by `$_debugName` is single-subscription and has '
+          'already been listened to. Call `asBroadcastStream()` on it, or mark '
+          'the Rust function with `#[frb(broadcast)]`, to listen more than once.');
+    }
+    _listened = true;
+    return _inner.listen(onData,
+        onError: onError, onDone: onDone, cancelOnError: cancelOnError);
+  }
+}
@@ -0,0 +1,73 @@
+import 'dart:async';
+
+import 'package:meta/meta.dart';
+
+import 'platform_independent.dart';
+
+/// A [Future] of a Rust function taking a `CancellationToken`, which can be
+/// cancelled with [cancel].
+class CancelableFuture<T> implements Future<T> {
+  final _completer = Completer<T>();
+  final void Function() _onCancel;
+  final String _debugName;
+  Timer? _timer;
+
+  /// This constructor should never be called manually.
+  @internal
+  CancelableFuture(
+    Future<T> future, {
+    required void Function() onCancel,
+    required String debugName,
+    Duration? timeout,
+  })  : _onCancel = onCancel,
+        _debugName = debugName {
+    future.then((value) {
+      if (!_completer.isCompleted) _completer.complete(value);
+      _timer?.cancel();
+    }, onError: (Object error, StackTrace stackTrace) {
+      if (!_completer.isCompleted) _completer.completeError(error, stackTrace);
+      _timer?.cancel();
+    });
+    if (timeout != null) {
+      final stackTrace = StackTrace.current;
+      _timer = Timer(
+          timeout,
+          () => _cancelWith(FlutterRustBridgeTimeoutException(
+              timeout, debugName, stackTrace)));
+    }
+  }
+
+  /// Signals the `CancellationToken` on the Rust side and completes this future
+  /// with a [FlutterRustBridgeCancelledException] right away.
+  ///
+  /// Whatever the Rust function returns afterwards is ignored. Does nothing if
+  /// the future has already completed.
+  void cancel() => _cancelWith(FlutterRustBridgeCancelledException(_debugName));
+
+  void _cancelWith(Object error) {
+    if (_completer.isCompleted) return;
+    _timer?.cancel();
+    _onCancel();
+    _completer.completeError(error, StackTrace.current);
+  }
+
+  @override
+  Stream<T> asStream() => _completer.future.asStream();
+
+  @override
+  Future<T> catchError(Function onError, {bool Function(Object error)? test}) =>
+      _completer.future.catchError(onError, test: test);
+
+  @override
+  Future<R> then<R>(FutureOr<R> Function(T value) onValue,
+          {Function? onError}) =>
+      _completer.future.then(onValue, onError: onError);
+
+  @override
+  Future<T> timeout(Duration timeLimit, {FutureOr<T> Function()? onTimeout}) =>
+      _completer.future.timeout(timeLimit, onTimeout: onTimeout);
+
+  @override
+  Future<T> whenComplete(FutureOr<void> Function() action) =>
+      _completer.future.whenComplete(action);
+}
@@ -1,3 +1,5 @@
+import 'dart:async';
+
 import 'package:meta/meta.dart';
 import 'ffi/io.dart' if (dart.library.html) 'ffi/web.dart';
 
@@ -36,9 +38,25 @@ abstract class FrbOpaque extends FrbOpaqueBase {
   /// This function should never be called manually.
   ShareFnType get shareFn;
 
+  /// Rust type specific share function for another isolate, which returns a
+  /// null pointer if the Rust type is not `Send + Sync`.
+  ///
+  /// This function should never be called manually.
+  ShareFnType get sendFn => throw UnsupportedError(
+      'Regenerate the bindings to send $runtimeType to another isolate.');
+
+  /// Size of the backing Rust object, as reported to the finalizer.
+  @internal
+  final int externalSize;
+
+  /// Closes the streams returned by the methods of this handle, see [bindStream].
+  final _streamClosers = <void Function()>{};
+
   /// This constructor should never be called manually.
   @internal
-  FrbOpaque.unsafe(int ptr, int size) : _ptr = FrbOpaqueBase.initPtr(ptr) {
+  FrbOpaque.unsafe(int ptr, int size)
+      : _ptr = FrbOpaqueBase.initPtr(ptr),
+        externalSize = size {
     if (ptr != 0) {
       FrbOpaqueBase.finalizerAttach(this, _ptr, size, staticFinalizer);
     }
@@ -52,7 +70,12 @@ abstract class FrbOpaque extends FrbOpaqueBase {
   /// When passed into a Rust function, Rust enacts *shared ownership*,
   /// if this pointer is shared with Rust when [dispose] is called,
   /// ownership is fully transferred to Rust else this pointer is cleared.
+  ///
+  /// Streams returned by the methods of this handle are closed as well.
   void dispose() {
+    for (final close in List.of(_streamClosers)) {
+      close();
+    }
     if (!isStale()) {
       var ptr = _ptr;
       _ptr = FrbOpaqueBase.nullPtr();
@@ -79,8 +102,177 @@ abstract class FrbOpaque extends FrbOpaqueBase {
     }
   }
 
+  /// Increments inner reference counter and returns the raw address of the
+  /// underlying Rust object, so that another handle can be built on it.
+  ///
+  /// Throws a [StateError] if called after [dispose].
+  @internal
+  int shareRaw() {
+    if (isStale()) {
+      throw StateError('Use after dispose.');
+    }
+    return FrbOpaqueBase.addressOf(shareFn(_ptr));
+  }
+
+  /// Increments inner reference counter and returns a token for it, which can
+  /// be sent to another isolate of the same process, and turned back into a
+  /// handle to the same Rust object there, e.g. with `Model.fromSendable`.
+  ///
+  /// The Rust object stays alive until the handles of all the isolates are
+  /// disposed. Since the isolates may use it at the same time, this throws an
+  /// [UnsupportedError] if its Rust type is not `Send + Sync`, and a
+  /// [StateError] if called after [dispose].
+  SendableOpaque toSendable() {
+    if (isStale()) {
+      throw StateError('Use after dispose.');
+    }
+    final ptr = sendFn(_ptr);
+    if (FrbOpaqueBase.isStalePtr(ptr)) {
+      throw UnsupportedError(
+          '$runtimeType cannot be sent to another isolate, since its Rust type is not Send + Sync.');
+    }
+    final address = FrbOpaqueBase.addressOf(ptr);
+    return SendableOpaque._(address, externalSize, '$runtimeType');
+  }
+
+  /// Ties [stream], returned by a method of this handle, to the lifetime of this
+  /// handle: [dispose] closes it, which stops the Rust side from sending more
+  /// events. Each call gets its own stream, so concurrent streams of the same
+  /// handle do not interfere.
+  @internal
+  Stream<T> bindStream<T>(Stream<T> stream, {bool broadcast = false}) {
+    StreamSubscription<T>? subscription;
+    late final StreamController<T> controller;
+    void close() {
+      _streamClosers.remove(close);
+      subscription?.cancel();
+      subscription = null;
+      controller.close();
+    }
+
+    void onListen() {
+      subscription = stream.listen(controller.add,
+          onError: controller.addError, onDone: close);
+    }
+
+    controller = broadcast
+        ? StreamController<T>.broadcast(onListen: onListen, onCancel: close)
+        : StreamController<T>(
+            onListen: onListen,
+            onPause: () => subscription?.pause(),
+            onResume: () => subscription?.resume(),
+            onCancel: close);
+    _streamClosers.add(close);
+    return controller.stream;
+  }
+
   /// Checks whether [dispose] has been called at any point during the lifetime
   /// of this pointer. This does not guarantee that the backing memory has
   /// actually been reclaimed.
   bool isStale() => FrbOpaqueBase.isStalePtr(_ptr);
 }
+
+/// A reference to the Rust object of an [FrbOpaque] handle, made by
+/// [FrbOpaque.toSendable], which can be sent to another isolate.
+///
+/// It holds one count of the Rust reference counter, which is taken over by the
+/// handle rebuilt from it: turn each token back into a handle exactly once.
+/// A token which is never used keeps the Rust object alive.
+@immutable
+class SendableOpaque {
+  final int _address;
+
+  /// Size of the backing Rust object, as reported to the finalizer.
+  @internal
+  final int externalSize;
+
+  final String _typeName;
+
+  const SendableOpaque._(this._address, this.externalSize, this._typeName);
+
+  /// Checks that this token refers to an object of [type] before rebuilding a
+  /// handle on it. This should never be used manually.
+  @internal
+  int takeAddress(Type type) {
+    if (_typeName != '$type') {
+      throw ArgumentError.value(this, 'token',
+          'Expected a token for $type but got one for $_typeName');
+    }
+    return _address;
+  }
+}
+
+/// Frees a batch of [FrbOpaque] handles at once.
+///
+/// Handles are registered with [add], or with [FrbOpaqueArenaExtension.ownedBy]
+/// where they are created, and [dispose] frees all of them. Since disposing a
+/// handle twice does nothing, handles that were already disposed, or moved into
+/// Rust, are skipped, so every Rust object is released exactly once.
+///
+/// ```dart
+/// final arena = OpaqueArena();
+/// try {
+///   final a = api.createBuffer().ownedBy(arena);
+///   final b = api.createBuffer().ownedBy(arena);
+///   api.merge(a: a, b: b);
+/// } finally {
+///   arena.dispose();
+/// }
+/// ```
+class OpaqueArena {
+  final _handles = <FrbOpaque>[];
+  bool _isDisposed = false;
+
+  /// Whether [dispose] has been called.
+  bool get isDisposed => _isDisposed;
+
+  /// Registers [handle] to be freed when this arena is disposed, and returns it.
+  ///
+  /// Throws a [StateError] if this arena is already disposed, after disposing
+  /// [handle] so that it does not leak.
+  T add<T extends FrbOpaque>(T handle) {
+    if (_isDisposed) {
+      handle.dispose();
+      throw StateError('Cannot add a handle to a disposed OpaqueArena.');
+    }
+    _handles.add(handle);
+    return handle;
+  }
+
+  /// Registers all of [handles], see [add].
+  void addAll(Iterable<FrbOpaque> handles) => handles.forEach(add);
+
+  /// Disposes every registered handle. Calling it again does nothing.
+  void dispose() {
+    _isDisposed = true;
+    final handles = List.of(_handles);
+    _handles.clear();
+    for (final handle in handles) {
+      handle.dispose();
+    }
+  }
+}
+
+/// Runs [computation] with a fresh [OpaqueArena], which is disposed when it
+/// completes, whether it returns, throws or, for a [Future], completes with an error.
+R withOpaqueArena<R>(R Function(OpaqueArena arena) computation) {
+  final arena = OpaqueArena();
+  final R result;
+  try {
+    result = computation(arena);
+  } catch (_) {
+    arena.dispose();
+    rethrow;
+  }
+  if (result is Future) {
+    result.whenComplete(arena.dispose).ignore();
+  } else {
+    arena.dispose();
+  }
+  return result;
+}
+
+extension FrbOpaqueArenaExtension<T extends FrbOpaque> on T {
+  /// Registers this handle in [arena], see [OpaqueArena.add].
+  T ownedBy(OpaqueArena arena) => arena.add(this);
+}
@@ -1,7 +1,10 @@
+import 'dart:collection';
 import 'dart:ffi' as ffi;
 import 'dart:ffi';
+import 'dart:typed_data';
 export 'dart:ffi' show NativePort, DynamicLibrary;
 import 'package:flutter_rust_bridge/src/ffi/dart_cobject.dart';
+import 'package:meta/meta.dart';
 
 import 'stub.dart' show FlutterRustBridgeWireBase;
 export 'stub.dart'
@@ -47,7 +50,124 @@ class FrbOpaqueBase implements Finalizable {
   static PlatformPointer initPtr(int ptr) => ffi.Pointer.fromAddress(ptr);
   static PlatformPointer nullPtr() => ffi.Pointer.fromAddress(0);
   static bool isStalePtr(PlatformPointer ptr) => ptr.address == 0;
+  static int addressOf(PlatformPointer ptr) => ptr.address;
   static void finalizerAttach(FrbOpaqueBase opaque, PlatformPointer ptr,
           int size, OpaqueTypeFinalizer finalizer) =>
       finalizer.attach(opaque, ptr, detach: opaque, externalSize: size);
 }
+
+/// Bytes copied once into memory owned by Rust, and lent to any number of
+/// calls taking a `SharedBuffer`, instead of being copied at each call.
+///
+/// The bytes can only be read afterwards, see [bytes], so they never change
+/// while Rust uses them. Call [dispose] once they are not needed anymore: Rust
+/// frees them when the calls still using them are done.
+class SharedBuffer {
+  final FlutterRustBridgeWireBase _wire;
+  int _address;
+  final Uint8List _bytes;
+
+  SharedBuffer._(this._wire, this._address, this._bytes);
+
+  /// Usually called through the `createSharedBuffer` method of the generated class.
+  factory SharedBuffer.copy(FlutterRustBridgeWireBase wire, List<int> data) {
+    final address = wire.new_shared_buffer(data.length);
+    final bytes =
+        ffi.Pointer<ffi.Uint8>.fromAddress(address).asTypedList(data.length);
+    bytes.setAll(0, data);
+    return SharedBuffer._(wire, address, bytes);
+  }
+
+  /// Usually called through the `allocateSharedBuffer` method of the generated class.
+  ///
+  /// [fill] receives a writable view of the [length] bytes owned by Rust,
+  /// e.g. to read a file into them, which is sealed once it returns.
+  factory SharedBuffer.fill(FlutterRustBridgeWireBase wire, int length,
+      void Function(SharedBufferWriter bytes) fill) {
+    final address = wire.new_shared_buffer(length);
+    final bytes =
+        ffi.Pointer<ffi.Uint8>.fromAddress(address).asTypedList(length);
+    final writer = SharedBufferWriter._(bytes);
+    try {
+      fill(writer);
+    } catch (_) {
+      wire.drop_shared_buffer(address);
+      rethrow;
+    } finally {
+      writer._seal();
+    }
+    return SharedBuffer._(wire, address, bytes);
+  }
+
+  /// A read-only view of the bytes, which does not copy them.
+  ///
+  /// Throws a [StateError] once the buffer is disposed.
+  Uint8List get bytes => UnmodifiableUint8ListView(_checkedBytes);
+
+  int get length => _bytes.length;
+
+  bool get isDisposed => _address == 0;
+
+  /// Identifies the buffer on the Rust side. This should never be used manually.
+  @internal
+  int get address {
+    _checkedBytes;
+    return _address;
+  }
+
+  Uint8List get _checkedBytes {
+    if (isDisposed) throw StateError('SharedBuffer used after it was disposed');
+    return _bytes;
+  }
+
+  /// Releases the bytes. Calling it again does nothing.
+  void dispose() {
+    if (isDisposed) return;
+    _wire.drop_shared_buffer(_address);
+    _address = 0;
+  }
+}
+
+/// The bytes of a [SharedBuffer] being filled by the callback of
+/// [SharedBuffer.fill].
+///
+/// It is sealed once the callback returns, after which using it throws a
+/// [StateError], so that the bytes never change while Rust uses them, even if
+/// the callback kept it around.
+class SharedBufferWriter extends ListMixin<int> {
+  Uint8List? _bytes;
+
+  SharedBufferWriter._(this._bytes);
+
+  /// Whether the callback has returned, so that the bytes can only be read
+  /// through [SharedBuffer.bytes].
+  bool get isSealed => _bytes == null;
+
+  Uint8List get _checkedBytes {
+    final bytes = _bytes;
+    if (bytes == null) {
+      throw StateError('SharedBuffer written after it was filled');
+    }
+    return bytes;
+  }
+
+  void _seal() => _bytes = null;
+
+  @override
+  int get length => _checkedBytes.length;
+
+  @override
+  set length(int newLength) =>
+      throw UnsupportedError('Cannot change the length of a SharedBuffer');
+
+  @override
+  int operator [](int index) => _checkedBytes[index];
+
+  @override
+  void operator []=(int index, int value) => _checkedBytes[index] = value;
+
+  @override
+  void setRange(int start, int end, Iterable<int> iterable,
+          [int skipCount = 0]) =>
+      _checkedBytes.setRange(start, end, iterable, skipCount);
+}
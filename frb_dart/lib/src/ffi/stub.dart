@@ -10,7 +10,9 @@ export 'io.dart' if (dart.library.html) 'web.dart'
         DartApiDl,
         NativePortType,
         PlatformPointer,
-        OpaqueTypeFinalizer;
+        OpaqueTypeFinalizer,
+        SharedBuffer,
+        SharedBufferWriter;
 import '../isolate.dart' show SendPort;
 
 /// This class, together with its subclasses, are only for internal usage.
@@ -42,6 +44,26 @@ abstract class FlutterRustBridgeWireBase {
   void free_WireSyncReturn(WireSyncReturn val) {
     throw UnimplementedError();
   }
+
+  // ignore: non_constant_identifier_names
+  int new_cancel_id() {
+    throw UnimplementedError();
+  }
+
+  // ignore: non_constant_identifier_names
+  void cancel_task(int id) {
+    throw UnimplementedError();
+  }
+
+  // ignore: non_constant_identifier_names
+  int new_shared_buffer(int len) {
+    throw UnimplementedError();
+  }
+
+  // ignore: non_constant_identifier_names
+  void drop_shared_buffer(int address) {
+    throw UnimplementedError();
+  }
 }
 
 extension NativeType on SendPort {
@@ -1,5 +1,6 @@
 import 'dart:async';
 import 'dart:html';
+import 'dart:typed_data';
 
 import 'package:flutter_rust_bridge/flutter_rust_bridge.dart';
 export 'package:js/js.dart';
@@ -91,6 +92,12 @@ external Object getDartObject(int ptr);
 @JS("wasm_bindgen.drop_dart_object")
 // ignore: non_constant_identifier_names
 external void dropDartObject(int ptr);
+@JS("wasm_bindgen.new_cancel_id")
+// ignore: non_constant_identifier_names
+external int newCancelId();
+@JS("wasm_bindgen.cancel_task")
+// ignore: non_constant_identifier_names
+external void cancelTask(int id);
 
 abstract class FlutterRustBridgeWireBase {
   void storeDartPostCObject() {}
@@ -107,6 +114,24 @@ abstract class FlutterRustBridgeWireBase {
     dropDartObject(ptr);
   }
 
+  // ignore: non_constant_identifier_names
+  int new_cancel_id() {
+    return newCancelId();
+  }
+
+  // ignore: non_constant_identifier_names
+  void cancel_task(int id) {
+    cancelTask(id);
+  }
+
+  // ignore: non_constant_identifier_names
+  int new_shared_buffer(int len) {
+    throw UnsupportedError('SharedBuffer is not supported on the web');
+  }
+
+  // ignore: non_constant_identifier_names
+  void drop_shared_buffer(int address) {}
+
   // ignore: non_constant_identifier_names
   int new_dart_opaque(Object obj, NativePortType port) {
     throw UnimplementedError();
@@ -135,7 +160,38 @@ class FrbOpaqueBase {
   static PlatformPointer initPtr(int ptr) => ptr;
   static PlatformPointer nullPtr() => 0;
   static bool isStalePtr(PlatformPointer ptr) => ptr == 0;
+  static int addressOf(PlatformPointer ptr) => ptr;
   static void finalizerAttach(FrbOpaqueBase opaque, PlatformPointer ptr, int _,
           OpaqueTypeFinalizer finalizer) =>
       finalizer.attach(opaque, ptr, detach: opaque);
 }
+
+/// Bytes lent to any number of calls taking a `SharedBuffer`.
+///
+/// Dart cannot write into the memory of a WASM module, so they are not
+/// supported on the web, and creating one throws an [UnsupportedError].
+class SharedBuffer {
+  SharedBuffer._();
+
+  factory SharedBuffer.copy(FlutterRustBridgeWireBase wire, List<int> data) =>
+      throw UnsupportedError('SharedBuffer is not supported on the web');
+
+  factory SharedBuffer.fill(FlutterRustBridgeWireBase wire, int length,
+          void Function(SharedBufferWriter bytes) fill) =>
+      throw UnsupportedError('SharedBuffer is not supported on the web');
+
+  Uint8List get bytes => throw UnsupportedError('unreachable');
+
+  int get length => throw UnsupportedError('unreachable');
+
+  bool get isDisposed => throw UnsupportedError('unreachable');
+
+  int get address => throw UnsupportedError('unreachable');
+
+  void dispose() {}
+}
+
+/// The bytes of a [SharedBuffer] being filled, which is never created on the web.
+abstract class SharedBufferWriter implements List<int> {
+  bool get isSealed;
+}
@@ -13,6 +13,17 @@ const bool kIsWeb = identical(0, 0.0);
 
 const uuidSizeInBytes = 16;
 
+/// The default value of the nullable parameters of a generated `copyWith`,
+/// which keeps the field, while an explicit `null` clears it.
+const Object copyWithUnset = _CopyWithUnset();
+
+class _CopyWithUnset {
+  const _CopyWithUnset();
+
+  @override
+  String toString() => 'copyWithUnset';
+}
+
 /// Allow custom setup hooks before ffi can be executed.
 /// All other ffi calls will wait (async) until the setup ffi finishes.
 ///
@@ -47,9 +58,9 @@ mixin FlutterRustBridgeSetupMixin<T extends FlutterRustBridgeWireBase>
   }
 
   @override
-  Stream<S> executeStream<S>(FlutterRustBridgeTask<S> task) async* {
+  Stream<S> executeRawStream<S>(FlutterRustBridgeTask<S> task) async* {
     await _beforeExecute(task);
-    yield* super.executeStream(task);
+    yield* super.executeRawStream(task);
   }
 
   Future<void> _beforeExecute<S>(FlutterRustBridgeTask<S> task) async {
@@ -95,6 +106,7 @@ mixin FlutterRustBridgeTimeoutMixin<T extends FlutterRustBridgeWireBase>
   }
 
   /// The time limit for methods using [executeNormal]. Return null means *disable* this functionality.
+  /// Return null means *disable* this functionality.
   @protected
   Duration? get timeLimitForExecuteNormal;
 }
@@ -155,10 +167,73 @@ Uint8List api2wireConcatenateBytes(List<UuidValue> raw) {
   return builder.toBytes();
 }
 
+/// Builds a [UuidValue] from its raw bytes, keeping their layout as-is.
+///
+/// Throws a [FormatException] if [raw] is not exactly [uuidSizeInBytes] long.
+UuidValue wire2apiUuid(Uint8List raw) {
+  if (raw.lengthInBytes != uuidSizeInBytes) {
+    throw FormatException(
+        'Invalid uuid: expected $uuidSizeInBytes bytes but got ${raw.lengthInBytes}');
+  }
+  return UuidValue.fromByteList(raw);
+}
+
+/// Splits concatenated raw bytes into [UuidValue]s.
+///
+/// Throws a [FormatException] if the length of [raw] is not a multiple of [uuidSizeInBytes].
 List<UuidValue> wire2apiUuids(Uint8List raw) {
+  if (raw.lengthInBytes % uuidSizeInBytes != 0) {
+    throw FormatException(
+        'Invalid uuids: expected a multiple of $uuidSizeInBytes bytes but got ${raw.lengthInBytes}');
+  }
   return List<UuidValue>.generate(
       raw.lengthInBytes ~/ uuidSizeInBytes,
       (int i) => UuidValue.fromByteList(
           Uint8List.view(raw.buffer, i * uuidSizeInBytes, uuidSizeInBytes)),
       growable: false);
 }
+
+final _int128Mask = (BigInt.one << 128) - BigInt.one;
+final _byteMask = BigInt.from(0xff);
+
+/// Encodes [raw] as the 16 little-endian bytes of a Rust `i128` (if [signed]) or `u128`.
+///
+/// Throws an [ArgumentError] if [raw] does not fit in that type, instead of silently truncating it.
+Uint8List api2wireInt128(BigInt raw, {required bool signed}) {
+  final min = signed ? -(BigInt.one << 127) : BigInt.zero;
+  final max = signed ? (BigInt.one << 127) - BigInt.one : _int128Mask;
+  if (raw < min || raw > max) {
+    throw ArgumentError.value(
+        raw, 'raw', 'out of range for ${signed ? 'i128' : 'u128'}');
+  }
+  // `&` treats negative numbers as two's complement, which is what Rust expects.
+  var value = raw & _int128Mask;
+  final bytes = Uint8List(16);
+  for (var i = 0; i < bytes.length; i++) {
+    bytes[i] = (value & _byteMask).toInt();
+    value >>= 8;
+  }
+  return bytes;
+}
+
+/// Decodes the 16 little-endian bytes of a Rust `i128` (if [signed]) or `u128`.
+BigInt wire2apiInt128(Uint8List raw, {required bool signed}) {
+  var ans = BigInt.zero;
+  for (var i = raw.length - 1; i >= 0; i--) {
+    ans = (ans << 8) | BigInt.from(raw[i]);
+  }
+  return signed ? ans.toSigned(128) : ans;
+}
+
+/// Encodes a one-character [raw] as the Unicode scalar value of a Rust `char`.
+///
+/// Throws an [ArgumentError] if [raw] is not exactly one character, or is an unpaired
+/// surrogate, which Rust rejects.
+int api2wireChar(String raw) {
+  final runes = raw.runes;
+  if (runes.length != 1 || (runes.first >= 0xd800 && runes.first <= 0xdfff)) {
+    throw ArgumentError.value(
+        raw, 'raw', 'expected a single Unicode scalar value for a char');
+  }
+  return runes.first;
+}
@@ -0,0 +1,162 @@
+import 'dart:typed_data';
+
+import 'package:meta/meta.dart';
+
+/// The version of an [IpAddress].
+enum IpAddressType { v4, v6 }
+
+/// A Rust `std::net::IpAddr`.
+///
+/// It mirrors the getters of `InternetAddress` from `dart:io`, which is not
+/// available on the web. Use `InternetAddress.fromRawAddress(ip.rawAddress)`
+/// to convert it on native platforms.
+@immutable
+class IpAddress {
+  /// The octets of the address in network order, 4 for IPv4 and 16 for IPv6.
+  final Uint8List rawAddress;
+
+  /// Throws an [ArgumentError] if [rawAddress] is neither 4 nor 16 bytes long.
+  IpAddress.fromRawAddress(Uint8List rawAddress)
+      : rawAddress = Uint8List.fromList(rawAddress) {
+    if (rawAddress.length != 4 && rawAddress.length != 16) {
+      throw ArgumentError.value(rawAddress, 'rawAddress',
+          'expected 4 or 16 bytes but got ${rawAddress.length}');
+    }
+  }
+
+  /// Parses an address such as `127.0.0.1` or `::1`.
+  ///
+  /// Throws a [FormatException] if [address] is not a valid address.
+  factory IpAddress.parse(String address) =>
+      IpAddress.fromRawAddress(Uint8List.fromList(address.contains(':')
+          ? Uri.parseIPv6Address(address)
+          : Uri.parseIPv4Address(address)));
+
+  IpAddressType get type =>
+      rawAddress.length == 4 ? IpAddressType.v4 : IpAddressType.v6;
+
+  /// The textual form of the address, with the longest run of zero groups of
+  /// an IPv6 address shortened to `::`.
+  String get address {
+    if (type == IpAddressType.v4) return rawAddress.join('.');
+    final groups = List<int>.generate(
+        8, (i) => (rawAddress[2 * i] << 8) | rawAddress[2 * i + 1]);
+    var bestStart = -1, bestLength = 1;
+    for (var start = 0; start < groups.length;) {
+      var end = start;
+      while (end < groups.length && groups[end] == 0) {
+        end++;
+      }
+      if (end - start > bestLength) {
+        bestStart = start;
+        bestLength = end - start;
+      }
+      start = end == start ? start + 1 : end;
+    }
+    String hex(Iterable<int> groups) =>
+        groups.map((group) => group.toRadixString(16)).join(':');
+    if (bestStart < 0) return hex(groups);
+    return '${hex(groups.take(bestStart))}::'
+        '${hex(groups.skip(bestStart + bestLength))}';
+  }
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is IpAddress &&
+          other.rawAddress.length == rawAddress.length &&
+          Iterable<int>.generate(rawAddress.length)
+              .every((i) => other.rawAddress[i] == rawAddress[i]);
+
+  @override
+  int get hashCode => Object.hashAll(rawAddress);
+
+  @override
+  String toString() => address;
+}
+
+/// A Rust `std::net::SocketAddr`.
+@immutable
+class SocketAddress {
+  final IpAddress ip;
+  final int port;
+
+  /// The scope id of an IPv6 address, e.g. the interface of a link-local
+  /// address. Always 0 for IPv4.
+  ///
+  /// The IPv6 flow info is not kept.
+  final int scopeId;
+
+  const SocketAddress(this.ip, this.port, {this.scopeId = 0});
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is SocketAddress &&
+          other.ip == ip &&
+          other.port == port &&
+          other.scopeId == scopeId;
+
+  @override
+  int get hashCode => Object.hash(ip, port, scopeId);
+
+  @override
+  String toString() => ip.type == IpAddressType.v4
+      ? '$ip:$port'
+      : '[$ip${scopeId == 0 ? '' : '%$scopeId'}]:$port';
+}
+
+const _ipV4 = 4;
+const _ipV6 = 6;
+
+/// Encodes [raw] as `4` or `6` followed by its octets, as expected by Rust.
+Uint8List api2wireIpAddress(IpAddress raw) => Uint8List.fromList([
+      raw.type == IpAddressType.v4 ? _ipV4 : _ipV6,
+      ...raw.rawAddress,
+    ]);
+
+/// Decodes an address encoded by [api2wireIpAddress].
+///
+/// Throws a [FormatException] if [raw] is not a valid encoding.
+IpAddress wire2apiIpAddress(Uint8List raw) {
+  final length = _ipAddressLength(raw);
+  if (raw.length != length) {
+    throw FormatException('Invalid ip address: expected $length bytes', raw);
+  }
+  return IpAddress.fromRawAddress(Uint8List.sublistView(raw, 1));
+}
+
+/// Encodes [raw] as its ip, followed by the port as 2 big-endian bytes and,
+/// for IPv6 only, the scope id as 4 big-endian bytes.
+Uint8List api2wireSocketAddress(SocketAddress raw) {
+  final ip = api2wireIpAddress(raw.ip);
+  final isV4 = raw.ip.type == IpAddressType.v4;
+  final bytes = ByteData(ip.length + (isV4 ? 2 : 6));
+  bytes.buffer.asUint8List().setAll(0, ip);
+  bytes.setUint16(ip.length, raw.port);
+  if (!isV4) bytes.setUint32(ip.length + 2, raw.scopeId);
+  return bytes.buffer.asUint8List();
+}
+
+/// Decodes a socket address encoded by [api2wireSocketAddress].
+///
+/// Throws a [FormatException] if [raw] is not a valid encoding.
+SocketAddress wire2apiSocketAddress(Uint8List raw) {
+  final ipLength = _ipAddressLength(raw);
+  final isV4 = raw[0] == _ipV4;
+  final length = ipLength + (isV4 ? 2 : 6);
+  if (raw.length != length) {
+    throw FormatException(
+        'Invalid socket address: expected $length bytes', raw);
+  }
+  final ip = wire2apiIpAddress(Uint8List.sublistView(raw, 0, ipLength));
+  final bytes = ByteData.sublistView(raw, ipLength);
+  return SocketAddress(ip, bytes.getUint16(0),
+      scopeId: isV4 ? 0 : bytes.getUint32(2));
+}
+
+int _ipAddressLength(Uint8List raw) {
+  if (raw.isNotEmpty && raw[0] == _ipV4) return 5;
+  if (raw.isNotEmpty && raw[0] == _ipV6) return 17;
+  throw FormatException('Invalid ip address', raw);
+}
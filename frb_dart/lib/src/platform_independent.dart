@@ -1,3 +1,5 @@
+import 'dart:async';
+
 import 'package:meta/meta.dart';
 
 /// Base class for various kinds of tasks.
@@ -44,10 +46,16 @@ class FlutterRustBridgeTaskConstMeta {
   /// A list of arguments to the task.
   final List<String> argNames;
 
+  /// The value of the generated `...Function` enum naming the task, e.g. to
+  /// tell calls apart in `FlutterRustBridgeBase.callObserver` without
+  /// comparing strings.
+  final Enum? function;
+
   /// Create a new task metadata.
   const FlutterRustBridgeTaskConstMeta({
     required this.debugName,
     required this.argNames,
+    this.function,
   });
 
   @override
@@ -84,9 +92,56 @@ class FfiException {
   String toString() => 'FfiException($code, $message, $details)';
 }
 
-/// Exception when timeout happens using [FlutterRustBridgeTimeoutMixin]
+/// The error of a Rust function returning `Result<T, (Code, String)>`, where
+/// `Code` is an enum without fields, so that [code] can be switched on instead
+/// of parsing the [message].
+///
+/// With `Result<T, (Code, String, Source)>`, the third value is kept as the
+/// [source] of the error.
+@immutable
+class FrbCodedException<C> implements Exception {
+  /// The machine-readable code of the error.
+  final C code;
+
+  /// The human-readable message of the error.
+  final String message;
+
+  /// The error which caused this one, if any.
+  final Object? source;
+
+  const FrbCodedException(this.code, this.message, [this.source]);
+
+  @override
+  String toString() => source == null
+      ? 'FrbCodedException($code, $message)'
+      : 'FrbCodedException($code, $message)\nCaused by: $source';
+}
+
+/// A panic of a Rust function, caught before it could unwind into Dart.
+///
+/// It is an [FfiException] with the `PANIC_ERROR` code, whose [details] are
+/// the [backtrace].
+class FrbPanicException extends FfiException {
+  /// The Rust backtrace of the panic. Only captured if `RUST_BACKTRACE` or
+  /// `RUST_LIB_BACKTRACE` is set for the Rust side.
+  final String? backtrace;
+
+  const FrbPanicException(String message, [this.backtrace])
+      : super(panicErrorCode, message, backtrace);
+
+  /// The [code] of a panic.
+  static const panicErrorCode = 'PANIC_ERROR';
+
+  @override
+  String toString() => backtrace == null
+      ? 'FrbPanicException($message)'
+      : 'FrbPanicException($message)\n$backtrace';
+}
+
+/// Exception when timeout happens, either from the `timeout` argument of a
+/// generated function or using [FlutterRustBridgeTimeoutMixin]
 @immutable
-class FlutterRustBridgeTimeoutException {
+class FlutterRustBridgeTimeoutException implements TimeoutException {
   /// The duration to trigger timeout
   final Duration duration;
 
@@ -99,11 +154,27 @@ class FlutterRustBridgeTimeoutException {
   const FlutterRustBridgeTimeoutException(
       this.duration, this.debugName, this.stackTrace);
 
+  @override
+  String get message => '`$debugName` did not complete within $duration';
+
   @override
   String toString() =>
       'FlutterRustBridgeTimeoutException(debugName=$debugName, duration=$duration, stackTrace=$stackTrace)';
 }
 
+/// Exception when a task is cancelled using [CancelableFuture.cancel]
+@immutable
+class FlutterRustBridgeCancelledException {
+  /// debugName of the task, usually the ffi function name
+  final String debugName;
+
+  const FlutterRustBridgeCancelledException(this.debugName);
+
+  @override
+  String toString() =>
+      'FlutterRustBridgeCancelledException(debugName=$debugName)';
+}
+
 bool _listEquals<T>(List<T>? a, List<T>? b) {
   if (a == null) return b == null;
   if (b == null || a.length != b.length) return false;
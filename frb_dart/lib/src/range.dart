@@ -0,0 +1,53 @@
+import 'package:meta/meta.dart';
+
+/// A Rust `std::ops::Range`, or a `std::ops::RangeInclusive` if [inclusive].
+///
+/// Rust rejects a range whose [start] is after its [end] when receiving it.
+@immutable
+class RustRange<T extends num> {
+  final T start;
+  final T end;
+
+  /// Whether [end] is part of the range, as in `start..=end`.
+  final bool inclusive;
+
+  const RustRange(this.start, this.end, {this.inclusive = false});
+
+  bool contains(num value) =>
+      start <= value && (inclusive ? value <= end : value < end);
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is RustRange &&
+          other.start == start &&
+          other.end == end &&
+          other.inclusive == inclusive;
+
+  @override
+  int get hashCode => Object.hash(start, end, inclusive);
+
+  @override
+  String toString() => '$start..${inclusive ? '=' : ''}$end';
+}
+
+/// The bounds of [raw], to be sent to a Rust `RangeInclusive` if [inclusive],
+/// or to a `Range` otherwise.
+///
+/// Throws an [ArgumentError] if [raw] is not of the expected kind.
+List<T> api2wireRange<T extends num>(RustRange<T> raw,
+    {required bool inclusive}) {
+  if (raw.inclusive != inclusive) {
+    throw ArgumentError.value(raw, 'raw',
+        'expected a${inclusive ? 'n inclusive' : ' half-open'} range');
+  }
+  return [raw.start, raw.end];
+}
+
+/// Decodes the 2 [bounds] sent by Rust, which are `BigInt`s for 64-bit
+/// integers on the web.
+RustRange<T> wire2apiRange<T extends num>(List<Object> bounds,
+    {required bool inclusive}) {
+  T bound(Object raw) => (raw is BigInt ? raw.toInt() : raw) as T;
+  return RustRange(bound(bounds[0]), bound(bounds[1]), inclusive: inclusive);
+}
@@ -0,0 +1,137 @@
+import 'package:meta/meta.dart';
+// Positional wrappers for Rust tuples, since Dart records are not available yet.
+
+/// A Rust tuple with 2 elements.
+@immutable
+class Tuple2<T1, T2> {
+  final T1 item1;
+  final T2 item2;
+
+  const Tuple2(this.item1, this.item2);
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is Tuple2 &&
+          runtimeType == other.runtimeType &&
+          item1 == other.item1 &&
+          item2 == other.item2;
+
+  @override
+  int get hashCode => Object.hash(item1, item2);
+
+  @override
+  String toString() => '($item1, $item2)';
+}
+
+/// A Rust tuple with 3 elements.
+@immutable
+class Tuple3<T1, T2, T3> {
+  final T1 item1;
+  final T2 item2;
+  final T3 item3;
+
+  const Tuple3(this.item1, this.item2, this.item3);
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is Tuple3 &&
+          runtimeType == other.runtimeType &&
+          item1 == other.item1 &&
+          item2 == other.item2 &&
+          item3 == other.item3;
+
+  @override
+  int get hashCode => Object.hash(item1, item2, item3);
+
+  @override
+  String toString() => '($item1, $item2, $item3)';
+}
+
+/// A Rust tuple with 4 elements.
+@immutable
+class Tuple4<T1, T2, T3, T4> {
+  final T1 item1;
+  final T2 item2;
+  final T3 item3;
+  final T4 item4;
+
+  const Tuple4(this.item1, this.item2, this.item3, this.item4);
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is Tuple4 &&
+          runtimeType == other.runtimeType &&
+          item1 == other.item1 &&
+          item2 == other.item2 &&
+          item3 == other.item3 &&
+          item4 == other.item4;
+
+  @override
+  int get hashCode => Object.hash(item1, item2, item3, item4);
+
+  @override
+  String toString() => '($item1, $item2, $item3, $item4)';
+}
+
+/// A Rust tuple with 5 elements.
+@immutable
+class Tuple5<T1, T2, T3, T4, T5> {
+  final T1 item1;
+  final T2 item2;
+  final T3 item3;
+  final T4 item4;
+  final T5 item5;
+
+  const Tuple5(this.item1, this.item2, this.item3, this.item4, this.item5);
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is Tuple5 &&
+          runtimeType == other.runtimeType &&
+          item1 == other.item1 &&
+          item2 == other.item2 &&
+          item3 == other.item3 &&
+          item4 == other.item4 &&
+          item5 == other.item5;
+
+  @override
+  int get hashCode => Object.hash(item1, item2, item3, item4, item5);
+
+  @override
+  String toString() => '($item1, $item2, $item3, $item4, $item5)';
+}
+
+/// A Rust tuple with 6 elements.
+@immutable
+class Tuple6<T1, T2, T3, T4, T5, T6> {
+  final T1 item1;
+  final T2 item2;
+  final T3 item3;
+  final T4 item4;
+  final T5 item5;
+  final T6 item6;
+
+  const Tuple6(this.item1, this.item2, this.item3, this.item4, this.item5, this.item6);
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is Tuple6 &&
+          runtimeType == other.runtimeType &&
+          item1 == other.item1 &&
+          item2 == other.item2 &&
+          item3 == other.item3 &&
+          item4 == other.item4 &&
+          item5 == other.item5 &&
+          item6 == other.item6;
+
+  @override
+  int get hashCode => Object.hash(item1, item2, item3, item4, item5, item6);
+
+  @override
+  String toString() => '($item1, $item2, $item3, $item4, $item5, $item6)';
+}
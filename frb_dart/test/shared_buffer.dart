@@ -0,0 +1,68 @@
+@TestOn('vm')
+import 'dart:ffi';
+
+import 'package:test/test.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge.dart';
+
+final _libc = DynamicLibrary.process();
+final _malloc = _libc.lookupFunction<Pointer<Void> Function(IntPtr),
+    Pointer<Void> Function(int)>('malloc');
+final _free = _libc.lookupFunction<Void Function(Pointer<Void>),
+    void Function(Pointer<Void>)>('free');
+
+/// Allocates the bytes of the buffers like Rust does, without the Rust library.
+class _Wire extends FlutterRustBridgeWireBase {
+  final dropped = <int>[];
+
+  @override
+  // ignore: non_constant_identifier_names
+  int new_shared_buffer(int len) => _malloc(len < 1 ? 1 : len).address;
+
+  @override
+  // ignore: non_constant_identifier_names
+  void drop_shared_buffer(int address) {
+    dropped.add(address);
+    _free(Pointer.fromAddress(address));
+  }
+}
+
+void main() {
+  test('fill writes into the buffer', () {
+    final wire = _Wire();
+    final buffer = SharedBuffer.fill(wire, 4, (bytes) {
+      bytes.setAll(0, [1, 2, 3]);
+      bytes[3] = 4;
+    });
+    expect(buffer.bytes, [1, 2, 3, 4]);
+    buffer.dispose();
+    expect(wire.dropped, hasLength(1));
+  });
+
+  test('writes after fill returns are rejected', () {
+    final wire = _Wire();
+    late SharedBufferWriter kept;
+    final buffer = SharedBuffer.fill(wire, 2, (bytes) {
+      expect(bytes.isSealed, false);
+      kept = bytes..fillRange(0, 2, 7);
+    });
+    expect(kept.isSealed, true);
+    expect(() => kept[0] = 1, throwsStateError);
+    expect(() => kept.setAll(0, [1, 1]), throwsStateError);
+    expect(() => kept[0], throwsStateError);
+    expect(buffer.bytes, [7, 7]);
+    buffer.dispose();
+  });
+
+  test('a throwing fill frees the bytes', () {
+    final wire = _Wire();
+    late SharedBufferWriter kept;
+    expect(
+        () => SharedBuffer.fill(wire, 2, (bytes) {
+              kept = bytes;
+              throw const FormatException();
+            }),
+        throwsFormatException);
+    expect(wire.dropped, hasLength(1));
+    expect(() => kept[0] = 1, throwsStateError);
+  });
+}
@@ -8,27 +8,59 @@ import 'dart:async';
 import 'package:meta/meta.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge.dart';
 import 'package:uuid/uuid.dart';
+import 'package:decimal/decimal.dart';
 import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
 import 'package:meta/meta.dart' as meta;
+import 'version.dart';
+import 'money.dart';
 import 'package:collection/collection.dart';
 
 part 'bridge_definitions.freezed.dart';
 
+const int maxCounters = 1000;
+
+const String greeting = 'Hello, world!';
+
+const double defaultRatio = 1.5;
+
 abstract class FlutterRustBridgeExampleSingleBlockTest {
+  /// Runs once, before the first call of any other function.
+  Future<void> initApp({dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kInitAppConstMeta;
+
+  Future<int> initCount({dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kInitCountConstMeta;
+
+  Future<Tuple3<int, String, double>> getConstants({dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kGetConstantsConstMeta;
+
   /// Documentation on a simple adder function.
   Future<int> simpleAdder({required int a, required int b, dynamic hint});
 
   FlutterRustBridgeTaskConstMeta get kSimpleAdderConstMeta;
 
+  Future<int> simpleAdderAsync({required int a, required int b, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kSimpleAdderAsyncConstMeta;
+
   int simpleAdderSync({required int a, required int b, dynamic hint});
 
   FlutterRustBridgeTaskConstMeta get kSimpleAdderSyncConstMeta;
 
-  ///
+  double scale({required double x, required double factor, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kScaleConstMeta;
+
+  double sumVector({required F32Array3 v, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kSumVectorConstMeta;
+
   /// Multiline comments are fine,
   /// but they are not preferred in Rust nor in Dart.
   /// Newlines are preserved.
-  ///
   Future<int> primitiveTypes(
       {required int myI32, required int myI64, required double myF64, required bool myBool, dynamic hint});
 
@@ -59,6 +91,74 @@ abstract class FlutterRustBridgeExampleSingleBlockTest {
 
   FlutterRustBridgeTaskConstMeta get kHandleStringSyncConstMeta;
 
+  Future<String> nextChar({required String c, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kNextCharConstMeta;
+
+  Future<String?> handleChars({required List<String> chars, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kHandleCharsConstMeta;
+
+  Future<int> divideBy({required int n, required int by, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kDivideByConstMeta;
+
+  Future<int?> nextNonZero({required int n, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kNextNonZeroConstMeta;
+
+  Future<String> greet({String name = 'world', int times = 1, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kGreetConstMeta;
+
+  Future<Instant> instantNow({dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kInstantNowConstMeta;
+
+  Future<Instant> instantAfter({required Instant start, required int millis, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kInstantAfterConstMeta;
+
+  Future<DateTime> oneDayLater({required DateTime time, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kOneDayLaterConstMeta;
+
+  Future<RustRange<int>> shiftRange({required RustRange<int> range, required int by, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kShiftRangeConstMeta;
+
+  Future<double> clampRange({required RustRange<double> range, required double value, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kClampRangeConstMeta;
+
+  Future<String> describeSize({required MySize size, required String unit, required List<String> labels, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kDescribeSizeConstMeta;
+
+  Stream<String> splitWords({required String text, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kSplitWordsConstMeta;
+
+  Future<String> handleCowStr({required String s, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kHandleCowStrConstMeta;
+
+  Future<Uint8List> handleCowBytes({required Uint8List bytes, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kHandleCowBytesConstMeta;
+
+  Future<String> handlePath({required String path, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kHandlePathConstMeta;
+
+  Future<IpAddress> handleSocketAddr({required SocketAddress addr, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kHandleSocketAddrConstMeta;
+
+  Future<SocketAddress> nextPort({required SocketAddress addr, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kNextPortConstMeta;
+
   Future<void> handleReturnUnit({dynamic hint});
 
   FlutterRustBridgeTaskConstMeta get kHandleReturnUnitConstMeta;
@@ -71,6 +171,26 @@ abstract class FlutterRustBridgeExampleSingleBlockTest {
 
   FlutterRustBridgeTaskConstMeta get kHandleVecU8ConstMeta;
 
+  Future<Uint8List> handleBytes({required Uint8List bytes, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kHandleBytesConstMeta;
+
+  Future<int> checksum({required Uint8List data, required Uint16List weights, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kChecksumConstMeta;
+
+  Future<int> countInSharedBuffer({required SharedBuffer buffer, required int byte, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kCountInSharedBufferConstMeta;
+
+  Future<Frame> nextFrame({required Frame frame, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kNextFrameConstMeta;
+
+  Future<Uint64List> prefixSums({required Uint64List items, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kPrefixSumsConstMeta;
+
   Uint8List handleVecU8Sync({required Uint8List v, dynamic hint});
 
   FlutterRustBridgeTaskConstMeta get kHandleVecU8SyncConstMeta;
@@ -107,6 +227,10 @@ abstract class FlutterRustBridgeExampleSingleBlockTest {
 
   FlutterRustBridgeTaskConstMeta get kHandleNewtypeSyncConstMeta;
 
+  Future<PairString> handleGenericStruct({required PairI32 pair, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kHandleGenericStructConstMeta;
+
   Future<List<MySize>> handleListOfStruct({required List<MySize> l, dynamic hint});
 
   FlutterRustBridgeTaskConstMeta get kHandleListOfStructConstMeta;
@@ -115,6 +239,10 @@ abstract class FlutterRustBridgeExampleSingleBlockTest {
 
   FlutterRustBridgeTaskConstMeta get kHandleListOfStructSyncConstMeta;
 
+  Future<List<List<List<String>>>> handleNestedVec({required List<Int32List> rows, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kHandleNestedVecConstMeta;
+
   Future<List<String>> handleStringList({required List<String> names, dynamic hint});
 
   FlutterRustBridgeTaskConstMeta get kHandleStringListConstMeta;
@@ -139,6 +267,10 @@ abstract class FlutterRustBridgeExampleSingleBlockTest {
 
   FlutterRustBridgeTaskConstMeta get kHandleSyncReturnConstMeta;
 
+  /// Same as [handleSyncReturn], except that the error is returned in the [SyncResult]
+  /// instead of being thrown.
+  SyncResult<Uint8List> handleSyncReturnResult({required String mode, dynamic hint});
+
   Stream<String> handleStream({required String arg, dynamic hint});
 
   FlutterRustBridgeTaskConstMeta get kHandleStreamConstMeta;
@@ -151,6 +283,53 @@ abstract class FlutterRustBridgeExampleSingleBlockTest {
 
   FlutterRustBridgeTaskConstMeta get kReturnErrConstMeta;
 
+  Future<int> returnCustomError({required bool fail, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kReturnCustomErrorConstMeta;
+
+  Future<void> returnCustomErrorUnit({dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kReturnCustomErrorUnitConstMeta;
+
+  Future<Weekdays?> lookupWeekday({required int index, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kLookupWeekdayConstMeta;
+
+  /// The values and errors read like each other, which must not confuse Dart.
+  Future<String> lookupString({required String key, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kLookupStringConstMeta;
+
+  Future<String> readSetting({required String key, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kReadSettingConstMeta;
+
+  Future<int> parseSetting({required String value, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kParseSettingConstMeta;
+
+  int returnCustomErrorSync({required bool fail, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kReturnCustomErrorSyncConstMeta;
+
+  /// Same as [returnCustomErrorSync], except that the error is returned in the [SyncResult]
+  /// instead of being thrown.
+  SyncResult<int> returnCustomErrorSyncResult({required bool fail, dynamic hint});
+
+  /// Counts until Dart cancels the call, or gives up after a few seconds.
+  CancelableFuture<int> countUntilCancelled({dynamic hint, Duration? timeout});
+
+  FlutterRustBridgeTaskConstMeta get kCountUntilCancelledConstMeta;
+
+  /// Spawns `workers` threads, which stop together with the call once Dart cancels it.
+  CancelableFuture<void> runWorkers({required int workers, dynamic hint, Duration? timeout});
+
+  FlutterRustBridgeTaskConstMeta get kRunWorkersConstMeta;
+
+  Future<int> stoppedWorkers({dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kStoppedWorkersConstMeta;
+
   Future<int> returnPanic({dynamic hint});
 
   FlutterRustBridgeTaskConstMeta get kReturnPanicConstMeta;
@@ -195,6 +374,14 @@ abstract class FlutterRustBridgeExampleSingleBlockTest {
 
   FlutterRustBridgeTaskConstMeta get kHandleEnumParameterConstMeta;
 
+  Future<int> httpStatusCode({required HttpStatus status, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kHttpStatusCodeConstMeta;
+
+  Future<HttpStatus?> httpStatusOf({required int code, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kHttpStatusOfConstMeta;
+
   Future<void> handleCustomizedStruct({required Customized val, dynamic hint});
 
   FlutterRustBridgeTaskConstMeta get kHandleCustomizedStructConstMeta;
@@ -227,6 +414,14 @@ abstract class FlutterRustBridgeExampleSingleBlockTest {
 
   FlutterRustBridgeTaskConstMeta get kGetMessageConstMeta;
 
+  Future<ApplicationMessage> getFallibleMessage({required int index, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kGetFallibleMessageConstMeta;
+
+  Future<bool> isExitMessage({required ApplicationMessage message, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kIsExitMessageConstMeta;
+
   Future<Numbers> repeatNumber({required int num, required int times, dynamic hint});
 
   FlutterRustBridgeTaskConstMeta get kRepeatNumberConstMeta;
@@ -283,6 +478,32 @@ abstract class FlutterRustBridgeExampleSingleBlockTest {
 
   FlutterRustBridgeTaskConstMeta get kHandleStreamSinkAt3ConstMeta;
 
+  Future<void> callBackWithNumbers({required int max, required void Function(int) callback, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kCallBackWithNumbersConstMeta;
+
+  Stream<int> handleStreamClosedByDrop({required int max, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kHandleStreamClosedByDropConstMeta;
+
+  /// Returns the first of the numbers, and sends the next ones to the stream.
+  Future<StreamWithInitial<int, int>> countFrom({required int from, required int count, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kCountFromConstMeta;
+
+  /// Parses each line of `input`, until an empty one, which ends the stream with an error.
+  Stream<int> parseNumbers({required String input, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kParseNumbersConstMeta;
+
+  Stream<int> handleBroadcastStream({required int max, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kHandleBroadcastStreamConstMeta;
+
+  Stream<int> handleStreamClosedByThreads({required int threads, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kHandleStreamClosedByThreadsConstMeta;
+
   Future<SumWith> getSumStruct({dynamic hint});
 
   FlutterRustBridgeTaskConstMeta get kGetSumStructConstMeta;
@@ -291,6 +512,58 @@ abstract class FlutterRustBridgeExampleSingleBlockTest {
 
   FlutterRustBridgeTaskConstMeta get kGetSumArrayConstMeta;
 
+  Future<Document> renameDocument({required Document document, required String title, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kRenameDocumentConstMeta;
+
+  Future<Renamed> fetchRenamed({required int id, required Pace pace, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kFetchRenamedConstMeta;
+
+  Future<Progress> advance({required Progress progress, required int by, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kAdvanceConstMeta;
+
+  Future<ProductId> nextProductId({required ProductId id, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kNextProductIdConstMeta;
+
+  Future<GridCell> transposeCell({required GridCell cell, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kTransposeCellConstMeta;
+
+  Future<Money> addTax({required Money price, required int percent, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kAddTaxConstMeta;
+
+  Future<Version> bumpMinor({required Version version, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kBumpMinorConstMeta;
+
+  Future<Session> openSession({required String user, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kOpenSessionConstMeta;
+
+  Future<String> describeSession({required Session session, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kDescribeSessionConstMeta;
+
+  Future<Sample> sampleAt({required int index, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kSampleAtConstMeta;
+
+  Future<Sample> shiftSample({required Sample sample, required double dx, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kShiftSampleConstMeta;
+
+  Future<String> tagLabel({required Tag tag, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kTagLabelConstMeta;
+
+  Future<String?> headerName({required Uint8List raw, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kHeaderNameConstMeta;
+
   Future<Measure?> multiplyByTen({required Measure measure, dynamic hint});
 
   FlutterRustBridgeTaskConstMeta get kMultiplyByTenConstMeta;
@@ -307,6 +580,14 @@ abstract class FlutterRustBridgeExampleSingleBlockTest {
 
   FlutterRustBridgeTaskConstMeta get kHandleBigBuffersConstMeta;
 
+  Future<BigInt> handleI128({required BigInt value, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kHandleI128ConstMeta;
+
+  Future<BigInt> handleU128({required BigInt value, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kHandleU128ConstMeta;
+
   Future<DateTime> datetimeUtc({required DateTime d, dynamic hint});
 
   FlutterRustBridgeTaskConstMeta get kDatetimeUtcConstMeta;
@@ -327,6 +608,10 @@ abstract class FlutterRustBridgeExampleSingleBlockTest {
 
   FlutterRustBridgeTaskConstMeta get kDurationConstMeta;
 
+  Future<DateTime> datetimeFixedOffset({required DateTime d, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kDatetimeFixedOffsetConstMeta;
+
   Future<TestChrono> testChrono({dynamic hint});
 
   FlutterRustBridgeTaskConstMeta get kTestChronoConstMeta;
@@ -351,6 +636,14 @@ abstract class FlutterRustBridgeExampleSingleBlockTest {
 
   FlutterRustBridgeTaskConstMeta get kHandleNestedUuidsConstMeta;
 
+  Future<Decimal> handleDecimal({required Decimal amount, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kHandleDecimalConstMeta;
+
+  Future<Decimal> sumDecimals({required List<Decimal> amounts, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kSumDecimalsConstMeta;
+
   Future<MessageId> newMsgid({required U8Array32 id, dynamic hint});
 
   FlutterRustBridgeTaskConstMeta get kNewMsgidConstMeta;
@@ -383,6 +676,18 @@ abstract class FlutterRustBridgeExampleSingleBlockTest {
 
   FlutterRustBridgeTaskConstMeta get kLastNumberConstMeta;
 
+  Future<U8Array4> repeatByte4({required int byte, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kRepeatByte4ConstMeta;
+
+  Future<U8Array8> repeatByte8({required int byte, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kRepeatByte8ConstMeta;
+
+  Future<F64Array16> transpose({required F64Array16 matrix, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kTransposeConstMeta;
+
   Future<TestIdArray2> nestedId({required TestIdArray4 id, dynamic hint});
 
   FlutterRustBridgeTaskConstMeta get kNestedIdConstMeta;
@@ -454,10 +759,34 @@ abstract class FlutterRustBridgeExampleSingleBlockTest {
 
   FlutterRustBridgeTaskConstMeta get kRunEnumOpaqueConstMeta;
 
+  Future<BoxDartDebug> createTraitObject({dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kCreateTraitObjectConstMeta;
+
+  Future<BoxDartDebug> createImplTrait({dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kCreateImplTraitConstMeta;
+
+  Future<String> runTraitObject({required BoxDartDebug obj, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kRunTraitObjectConstMeta;
+
   Future<String> runOpaque({required HideData opaque, dynamic hint});
 
   FlutterRustBridgeTaskConstMeta get kRunOpaqueConstMeta;
 
+  Future<HideData> createSharedOpaque({dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kCreateSharedOpaqueConstMeta;
+
+  Future<String> runSharedOpaque({required HideData opaque, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kRunSharedOpaqueConstMeta;
+
+  Future<int> sharedOpaqueAddress({required HideData opaque, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kSharedOpaqueAddressConstMeta;
+
   Future<String> runOpaqueWithDelay({required HideData opaque, dynamic hint});
 
   FlutterRustBridgeTaskConstMeta get kRunOpaqueWithDelayConstMeta;
@@ -506,18 +835,34 @@ abstract class FlutterRustBridgeExampleSingleBlockTest {
 
   FlutterRustBridgeTaskConstMeta get kSyncOptionConstMeta;
 
+  /// Same as [syncOption], except that the error is returned in the [SyncResult]
+  /// instead of being thrown.
+  SyncResult<String?> syncOptionResult({dynamic hint});
+
   String? syncOptionNull({dynamic hint});
 
   FlutterRustBridgeTaskConstMeta get kSyncOptionNullConstMeta;
 
+  /// Same as [syncOptionNull], except that the error is returned in the [SyncResult]
+  /// instead of being thrown.
+  SyncResult<String?> syncOptionNullResult({dynamic hint});
+
   HideData? syncOptionRustOpaque({dynamic hint});
 
   FlutterRustBridgeTaskConstMeta get kSyncOptionRustOpaqueConstMeta;
 
+  /// Same as [syncOptionRustOpaque], except that the error is returned in the [SyncResult]
+  /// instead of being thrown.
+  SyncResult<HideData?> syncOptionRustOpaqueResult({dynamic hint});
+
   Object? syncOptionDartOpaque({required Object opaque, dynamic hint});
 
   FlutterRustBridgeTaskConstMeta get kSyncOptionDartOpaqueConstMeta;
 
+  /// Same as [syncOptionDartOpaque], except that the error is returned in the [SyncResult]
+  /// instead of being thrown.
+  SyncResult<Object?> syncOptionDartOpaqueResult({required Object opaque, dynamic hint});
+
   void syncVoid({dynamic hint});
 
   FlutterRustBridgeTaskConstMeta get kSyncVoidConstMeta;
@@ -572,6 +917,15 @@ abstract class FlutterRustBridgeExampleSingleBlockTest {
 
   FlutterRustBridgeTaskConstMeta get kFrbSyncGeneratorTestConstMeta;
 
+  /// A handle to `counter` which does not keep it alive.
+  Future<WeakCounter> observeCounter({required Counter counter, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kObserveCounterConstMeta;
+
+  Future<bool> isCounterAlive({required WeakCounter counter, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kIsCounterAliveConstMeta;
+
   Future<int> handleTypeAliasId({required int input, dynamic hint});
 
   FlutterRustBridgeTaskConstMeta get kHandleTypeAliasIdConstMeta;
@@ -588,6 +942,47 @@ abstract class FlutterRustBridgeExampleSingleBlockTest {
 
   FlutterRustBridgeTaskConstMeta get kEmptyStructConstMeta;
 
+  Future<Map<String, int>> handleHashMap({required Map<String, int> map, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kHandleHashMapConstMeta;
+
+  Future<Map<int, Attribute>> handleHashMapOfStruct({required Map<int, Attribute> map, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kHandleHashMapOfStructConstMeta;
+
+  Future<Map<Weekdays, int>> countWeekdays({required List<Weekdays> days, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kCountWeekdaysConstMeta;
+
+  Future<Map<int, String>> indexWords({required List<String> words, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kIndexWordsConstMeta;
+
+  Future<Map<CollidingKey, int>> handleCollidingKeys({required Map<CollidingKey, int> map, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kHandleCollidingKeysConstMeta;
+
+  Future<Set<String>> handleBtreeSet({required Set<String> set, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kHandleBtreeSetConstMeta;
+
+  Future<Map<int, String>> handleBtreeMap({required Map<int, String> map, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kHandleBtreeMapConstMeta;
+
+  Future<Map<String, List<Attribute>>> groupAttributes({required List<Attribute> attributes, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kGroupAttributesConstMeta;
+
+  Future<Tuple3<bool, String, int>> handleTuple({required Tuple3<int, String, bool> tuple, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kHandleTupleConstMeta;
+
+  Future<Tuple2<Tuple2<String, bool>, int>> handleNestedTuple(
+      {required Tuple2<int, Tuple2<String, bool>> tuple, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kHandleNestedTupleConstMeta;
+
   Future<int> sumMethodSumWith({required SumWith that, required int y, required int z, dynamic hint});
 
   FlutterRustBridgeTaskConstMeta get kSumMethodSumWithConstMeta;
@@ -622,100 +1017,791 @@ abstract class FlutterRustBridgeExampleSingleBlockTest {
 
   FlutterRustBridgeTaskConstMeta get kHandleSomeStaticStreamSinkSingleArgStaticMethodConcatenateWithConstMeta;
 
-  DropFnType get dropOpaqueBoxDartDebug;
-  ShareFnType get shareOpaqueBoxDartDebug;
-  OpaqueTypeFinalizer get BoxDartDebugFinalizer;
+  Future<Counter> startStaticMethodCounter({required int from, dynamic hint});
 
-  DropFnType get dropOpaqueFrbOpaqueReturn;
-  ShareFnType get shareOpaqueFrbOpaqueReturn;
-  OpaqueTypeFinalizer get FrbOpaqueReturnFinalizer;
+  FlutterRustBridgeTaskConstMeta get kStartStaticMethodCounterConstMeta;
 
-  DropFnType get dropOpaqueFrbOpaqueSyncReturn;
-  ShareFnType get shareOpaqueFrbOpaqueSyncReturn;
-  OpaqueTypeFinalizer get FrbOpaqueSyncReturnFinalizer;
+  Future<int> incrementMethodCounter({required Counter that, required int by, dynamic hint});
 
-  DropFnType get dropOpaqueHideData;
-  ShareFnType get shareOpaqueHideData;
-  OpaqueTypeFinalizer get HideDataFinalizer;
+  FlutterRustBridgeTaskConstMeta get kIncrementMethodCounterConstMeta;
 
-  DropFnType get dropOpaqueI32;
-  ShareFnType get shareOpaqueI32;
-  OpaqueTypeFinalizer get I32Finalizer;
+  Future<String> labelMethodCounter({required Counter that, dynamic hint});
 
-  DropFnType get dropOpaqueMutexHideData;
-  ShareFnType get shareOpaqueMutexHideData;
-  OpaqueTypeFinalizer get MutexHideDataFinalizer;
+  FlutterRustBridgeTaskConstMeta get kLabelMethodCounterConstMeta;
 
-  DropFnType get dropOpaqueNonSendHideData;
-  ShareFnType get shareOpaqueNonSendHideData;
-  OpaqueTypeFinalizer get NonSendHideDataFinalizer;
+  Future<void> resetMethodCounter({required Counter that, required int to, dynamic hint});
 
-  DropFnType get dropOpaqueRwLockHideData;
-  ShareFnType get shareOpaqueRwLockHideData;
-  OpaqueTypeFinalizer get RwLockHideDataFinalizer;
-}
+  FlutterRustBridgeTaskConstMeta get kResetMethodCounterConstMeta;
 
-@sealed
-class BoxDartDebug extends FrbOpaque {
-  final FlutterRustBridgeExampleSingleBlockTest bridge;
-  BoxDartDebug.fromRaw(int ptr, int size, this.bridge) : super.unsafe(ptr, size);
-  @override
-  DropFnType get dropFn => bridge.dropOpaqueBoxDartDebug;
+  /// The numbers from the count down to zero, computed as Dart asks for them.
+  Stream<int> countDownMethodCounter({required Counter that, dynamic hint});
 
-  @override
-  ShareFnType get shareFn => bridge.shareOpaqueBoxDartDebug;
+  FlutterRustBridgeTaskConstMeta get kCountDownMethodCounterConstMeta;
 
-  @override
-  OpaqueTypeFinalizer get staticFinalizer => bridge.BoxDartDebugFinalizer;
-}
+  Future<int> incrementLaterMethodCounter({required Counter that, required int by, dynamic hint});
 
-class EnumOpaqueArray5 extends NonGrowableListView<EnumOpaque> {
-  static const arraySize = 5;
-  EnumOpaqueArray5(List<EnumOpaque> inner)
-      : assert(inner.length == arraySize),
-        super(inner);
-  EnumOpaqueArray5.unchecked(List<EnumOpaque> inner) : super(inner);
-  EnumOpaqueArray5.init(EnumOpaque fill) : super(List<EnumOpaque>.filled(arraySize, fill));
-}
+  FlutterRustBridgeTaskConstMeta get kIncrementLaterMethodCounterConstMeta;
 
-@sealed
-class FrbOpaqueReturn extends FrbOpaque {
-  final FlutterRustBridgeExampleSingleBlockTest bridge;
-  FrbOpaqueReturn.fromRaw(int ptr, int size, this.bridge) : super.unsafe(ptr, size);
-  @override
-  DropFnType get dropFn => bridge.dropOpaqueFrbOpaqueReturn;
+  /// Receives the count after each increment, until the handle is disposed.
+  Stream<int> updatesMethodCounter({required Counter that, dynamic hint});
 
-  @override
-  ShareFnType get shareFn => bridge.shareOpaqueFrbOpaqueReturn;
+  FlutterRustBridgeTaskConstMeta get kUpdatesMethodCounterConstMeta;
 
-  @override
-  OpaqueTypeFinalizer get staticFinalizer => bridge.FrbOpaqueReturnFinalizer;
-}
+  Future<int> finishMethodCounter({required Counter that, dynamic hint});
 
-@sealed
-class FrbOpaqueSyncReturn extends FrbOpaque {
-  final FlutterRustBridgeExampleSingleBlockTest bridge;
-  FrbOpaqueSyncReturn.fromRaw(int ptr, int size, this.bridge) : super.unsafe(ptr, size);
-  @override
-  DropFnType get dropFn => bridge.dropOpaqueFrbOpaqueSyncReturn;
+  FlutterRustBridgeTaskConstMeta get kFinishMethodCounterConstMeta;
 
-  @override
-  ShareFnType get shareFn => bridge.shareOpaqueFrbOpaqueSyncReturn;
+  Future<Rooms> openStaticMethodRooms({dynamic hint});
 
-  @override
-  OpaqueTypeFinalizer get staticFinalizer => bridge.FrbOpaqueSyncReturnFinalizer;
-}
+  FlutterRustBridgeTaskConstMeta get kOpenStaticMethodRoomsConstMeta;
 
-@sealed
-class HideData extends FrbOpaque {
-  final FlutterRustBridgeExampleSingleBlockTest bridge;
+  Stream<String> joinMethodRooms({required Rooms that, required String room, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kJoinMethodRoomsConstMeta;
+
+  /// Returns the number of subscribers who received the message.
+  Future<int> postMethodRooms({required Rooms that, required String room, required String message, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kPostMethodRoomsConstMeta;
+
+  Future<int> closeRoomMethodRooms({required Rooms that, required String room, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kCloseRoomMethodRoomsConstMeta;
+
+  Future<Greeter> namedStaticMethodGreeter({required String name, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kNamedStaticMethodGreeterConstMeta;
+
+  Future<String> describeMethodCounter({required Counter that, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kDescribeMethodCounterConstMeta;
+
+  Future<String> shoutMethodCounter({required Counter that, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kShoutMethodCounterConstMeta;
+
+  Future<String> describeMethodGreeter({required Greeter that, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kDescribeMethodGreeterConstMeta;
+
+  Future<String> shoutMethodGreeter({required Greeter that, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kShoutMethodGreeterConstMeta;
+
+  /// Calls the function identified by `id`, one of `triple`, `negate`, or throws if there is none.
+  Future<int> dispatch({required String id, required int x, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kDispatchConstMeta;
+
+  /// Pulls the next item of an iterator returned by Rust, or `null` once it is exhausted.
+  Future<String?> nextDartIteratorString({required DartIteratorString iterator, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kNextDartIteratorStringConstMeta;
+
+  /// Pulls the next item of an iterator returned by Rust, or `null` once it is exhausted.
+  Future<int?> nextDartIteratorI32({required DartIteratorI32 iterator, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kNextDartIteratorI32ConstMeta;
+
+  /// An event after each successful call of a `#[frb(notifies)]` method of this object.
+  Stream<void> changesMethodCounter({required Counter that, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kChangesMethodCounterConstMeta;
+
+  /// Starts the worker pool and runs the `#[frb(init)]` function, if any, so that the first real call does not wait for them. Calling it again does nothing.
+  Future<void> warmUp({dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kWarmUpConstMeta;
+
+  /// A new handle to the object of a weak handle, or `null` if it has been dropped.
+  Future<Counter?> upgradeWeakCounter({required WeakCounter weak, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kUpgradeWeakCounterConstMeta;
+
+  /// The time elapsed from `earlier` to `that`, or zero if `earlier` is the later one.
+  Future<Duration> instantDurationSince({required Instant that, required Instant earlier, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kInstantDurationSinceConstMeta;
+
+  /// Runs [handleStream] synchronously and returns all its items, for tests. Throws if the stream is still open once the Rust function returns.
+  List<String> handleStreamDrain({required String arg, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kHandleStreamDrainConstMeta;
+
+  /// Runs [handleStreamOfStruct] synchronously and returns all its items, for tests. Throws if the stream is still open once the Rust function returns.
+  List<MyStreamEntry> handleStreamOfStructDrain({dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kHandleStreamOfStructDrainConstMeta;
+
+  /// Runs [registerEventListener] synchronously and returns all its items, for tests. Throws if the stream is still open once the Rust function returns.
+  List<Event> registerEventListenerDrain({dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kRegisterEventListenerDrainConstMeta;
+
+  /// Runs [handleStreamSinkAt1] synchronously and returns all its items, for tests. Throws if the stream is still open once the Rust function returns.
+  List<Log> handleStreamSinkAt1Drain({required int key, required int max, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kHandleStreamSinkAt1DrainConstMeta;
+
+  /// Runs [handleStreamSinkAt2] synchronously and returns all its items, for tests. Throws if the stream is still open once the Rust function returns.
+  List<Log> handleStreamSinkAt2Drain({required int key, required int max, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kHandleStreamSinkAt2DrainConstMeta;
+
+  /// Runs [handleStreamSinkAt3] synchronously and returns all its items, for tests. Throws if the stream is still open once the Rust function returns.
+  List<Log> handleStreamSinkAt3Drain({required int key, required int max, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kHandleStreamSinkAt3DrainConstMeta;
+
+  /// Runs [handleStreamClosedByDrop] synchronously and returns all its items, for tests. Throws if the stream is still open once the Rust function returns.
+  List<int> handleStreamClosedByDropDrain({required int max, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kHandleStreamClosedByDropDrainConstMeta;
+
+  /// Returns the first of the numbers, and sends the next ones to the stream.
+  ///
+  /// Runs [countFrom] synchronously and returns all its items, for tests. Throws if the stream is still open once the Rust function returns.
+  List<int> countFromDrain({required int from, required int count, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kCountFromDrainConstMeta;
+
+  /// Parses each line of `input`, until an empty one, which ends the stream with an error.
+  ///
+  /// Runs [parseNumbers] synchronously and returns all its items, for tests. Throws if the stream is still open once the Rust function returns.
+  List<int> parseNumbersDrain({required String input, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kParseNumbersDrainConstMeta;
+
+  /// Runs [handleBroadcastStream] synchronously and returns all its items, for tests. Throws if the stream is still open once the Rust function returns.
+  List<int> handleBroadcastStreamDrain({required int max, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kHandleBroadcastStreamDrainConstMeta;
+
+  /// Runs [handleStreamClosedByThreads] synchronously and returns all its items, for tests. Throws if the stream is still open once the Rust function returns.
+  List<int> handleStreamClosedByThreadsDrain({required int threads, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kHandleStreamClosedByThreadsDrainConstMeta;
+
+  DropFnType get dropOpaqueBoxDartDebug;
+  ShareFnType get shareOpaqueBoxDartDebug;
+  ShareFnType get sendOpaqueBoxDartDebug;
+  OpaqueTypeFinalizer get BoxDartDebugFinalizer;
+
+  DropFnType get dropOpaqueCounter;
+  ShareFnType get shareOpaqueCounter;
+  ShareFnType get sendOpaqueCounter;
+  OpaqueTypeFinalizer get CounterFinalizer;
+
+  DropFnType get dropOpaqueDartIteratorI32;
+  ShareFnType get shareOpaqueDartIteratorI32;
+  ShareFnType get sendOpaqueDartIteratorI32;
+  OpaqueTypeFinalizer get DartIteratorI32Finalizer;
+
+  DropFnType get dropOpaqueDartIteratorString;
+  ShareFnType get shareOpaqueDartIteratorString;
+  ShareFnType get sendOpaqueDartIteratorString;
+  OpaqueTypeFinalizer get DartIteratorStringFinalizer;
+
+  DropFnType get dropOpaqueFrbOpaqueReturn;
+  ShareFnType get shareOpaqueFrbOpaqueReturn;
+  ShareFnType get sendOpaqueFrbOpaqueReturn;
+  OpaqueTypeFinalizer get FrbOpaqueReturnFinalizer;
+
+  DropFnType get dropOpaqueFrbOpaqueSyncReturn;
+  ShareFnType get shareOpaqueFrbOpaqueSyncReturn;
+  ShareFnType get sendOpaqueFrbOpaqueSyncReturn;
+  OpaqueTypeFinalizer get FrbOpaqueSyncReturnFinalizer;
+
+  DropFnType get dropOpaqueGreeter;
+  ShareFnType get shareOpaqueGreeter;
+  ShareFnType get sendOpaqueGreeter;
+  OpaqueTypeFinalizer get GreeterFinalizer;
+
+  DropFnType get dropOpaqueHideData;
+  ShareFnType get shareOpaqueHideData;
+  ShareFnType get sendOpaqueHideData;
+  OpaqueTypeFinalizer get HideDataFinalizer;
+
+  DropFnType get dropOpaqueI32;
+  ShareFnType get shareOpaqueI32;
+  ShareFnType get sendOpaqueI32;
+  OpaqueTypeFinalizer get I32Finalizer;
+
+  DropFnType get dropOpaqueInstant;
+  ShareFnType get shareOpaqueInstant;
+  ShareFnType get sendOpaqueInstant;
+  OpaqueTypeFinalizer get InstantFinalizer;
+
+  DropFnType get dropOpaqueMutexHideData;
+  ShareFnType get shareOpaqueMutexHideData;
+  ShareFnType get sendOpaqueMutexHideData;
+  OpaqueTypeFinalizer get MutexHideDataFinalizer;
+
+  DropFnType get dropOpaqueNonSendHideData;
+  ShareFnType get shareOpaqueNonSendHideData;
+  ShareFnType get sendOpaqueNonSendHideData;
+  OpaqueTypeFinalizer get NonSendHideDataFinalizer;
+
+  DropFnType get dropOpaqueRooms;
+  ShareFnType get shareOpaqueRooms;
+  ShareFnType get sendOpaqueRooms;
+  OpaqueTypeFinalizer get RoomsFinalizer;
+
+  DropFnType get dropOpaqueRwLockHideData;
+  ShareFnType get shareOpaqueRwLockHideData;
+  ShareFnType get sendOpaqueRwLockHideData;
+  OpaqueTypeFinalizer get RwLockHideDataFinalizer;
+
+  DropFnType get dropOpaqueWeakCounter;
+  ShareFnType get shareOpaqueWeakCounter;
+  ShareFnType get sendOpaqueWeakCounter;
+  OpaqueTypeFinalizer get WeakCounterFinalizer;
+
+  /// Copies [data] once into memory owned by Rust, which any number of calls can then borrow.
+  SharedBuffer createSharedBuffer(List<int> data);
+
+  /// Lets [fill] write [length] bytes directly into memory owned by Rust, without any copy.
+  SharedBuffer allocateSharedBuffer(int length, void Function(SharedBufferWriter bytes) fill);
+}
+
+/// Every function of [FlutterRustBridgeExampleSingleBlockTest], see [FlutterRustBridgeTaskConstMeta.function].
+enum FlutterRustBridgeExampleSingleBlockTestFunction {
+  initApp,
+  initCount,
+  getConstants,
+  simpleAdder,
+  simpleAdderAsync,
+  simpleAdderSync,
+  scale,
+  sumVector,
+  primitiveTypes,
+  primitiveOptionalTypes,
+  primitiveTypesSync,
+  primitiveU32,
+  primitiveU32Sync,
+  handleString,
+  handleStringSync,
+  nextChar,
+  handleChars,
+  divideBy,
+  nextNonZero,
+  greet,
+  instantNow,
+  instantAfter,
+  oneDayLater,
+  shiftRange,
+  clampRange,
+  describeSize,
+  splitWords,
+  handleCowStr,
+  handleCowBytes,
+  handlePath,
+  handleSocketAddr,
+  nextPort,
+  handleReturnUnit,
+  handleReturnUnitSync,
+  handleVecU8,
+  handleBytes,
+  checksum,
+  countInSharedBuffer,
+  nextFrame,
+  prefixSums,
+  handleVecU8Sync,
+  handleVecOfPrimitive,
+  handleVecOfPrimitiveSync,
+  handleZeroCopyVecOfPrimitive,
+  handleZeroCopyVecOfPrimitiveSync,
+  handleStruct,
+  handleStructSync,
+  handleNewtype,
+  handleNewtypeSync,
+  handleGenericStruct,
+  handleListOfStruct,
+  handleListOfStructSync,
+  handleNestedVec,
+  handleStringList,
+  handleStringListSync,
+  handleComplexStruct,
+  handleComplexStructSync,
+  handleNestedStruct,
+  handleSyncReturn,
+  handleStream,
+  handleStreamOfStruct,
+  returnErr,
+  returnCustomError,
+  returnCustomErrorUnit,
+  lookupWeekday,
+  lookupString,
+  readSetting,
+  parseSetting,
+  returnCustomErrorSync,
+  countUntilCancelled,
+  runWorkers,
+  stoppedWorkers,
+  returnPanic,
+  handleOptionalReturn,
+  handleOptionalStruct,
+  handleOptionalIncrement,
+  handleIncrementBoxedOptional,
+  handleOptionBoxArguments,
+  printNote,
+  handleReturnEnum,
+  handleEnumParameter,
+  httpStatusCode,
+  httpStatusOf,
+  handleCustomizedStruct,
+  handleEnumStruct,
+  useImportedStruct,
+  useImportedEnum,
+  getAppSettings,
+  getFallibleAppSettings,
+  isAppEmbedded,
+  getMessage,
+  getFallibleMessage,
+  isExitMessage,
+  repeatNumber,
+  repeatSequence,
+  firstNumber,
+  firstSequence,
+  getArray,
+  getComplexArray,
+  getUsize,
+  nextUserId,
+  registerEventListener,
+  closeEventListener,
+  createEvent,
+  handleStreamSinkAt1,
+  handleStreamSinkAt2,
+  handleStreamSinkAt3,
+  callBackWithNumbers,
+  handleStreamClosedByDrop,
+  countFrom,
+  parseNumbers,
+  handleBroadcastStream,
+  handleStreamClosedByThreads,
+  getSumStruct,
+  getSumArray,
+  renameDocument,
+  fetchRenamed,
+  advance,
+  nextProductId,
+  transposeCell,
+  addTax,
+  bumpMinor,
+  openSession,
+  describeSession,
+  sampleAt,
+  shiftSample,
+  tagLabel,
+  headerName,
+  multiplyByTen,
+  callOldModuleSystem,
+  callNewModuleSystem,
+  handleBigBuffers,
+  handleI128,
+  handleU128,
+  datetimeUtc,
+  datetimeLocal,
+  naivedatetime,
+  optionalEmptyDatetimeUtc,
+  duration,
+  datetimeFixedOffset,
+  testChrono,
+  testPreciseChrono,
+  howLongDoesItTake,
+  handleUuid,
+  handleUuids,
+  handleNestedUuids,
+  handleDecimal,
+  sumDecimals,
+  newMsgid,
+  useMsgid,
+  boxedBlob,
+  useBoxedBlob,
+  returnBoxedFeedId,
+  returnBoxedRawFeedId,
+  testId,
+  lastNumber,
+  repeatByte4,
+  repeatByte8,
+  transpose,
+  nestedId,
+  syncAcceptDartOpaque,
+  asyncAcceptDartOpaque,
+  loopBack,
+  loopBackOption,
+  loopBackArray,
+  loopBackVec,
+  loopBackOptionGet,
+  loopBackArrayGet,
+  loopBackVecGet,
+  unwrapDartOpaque,
+  panicUnwrapDartOpaque,
+  createOpaque,
+  createOptionOpaque,
+  syncCreateOpaque,
+  createArrayOpaqueEnum,
+  runEnumOpaque,
+  createTraitObject,
+  createImplTrait,
+  runTraitObject,
+  runOpaque,
+  createSharedOpaque,
+  runSharedOpaque,
+  sharedOpaqueAddress,
+  runOpaqueWithDelay,
+  opaqueArray,
+  createSyncOpaque,
+  syncCreateSyncOpaque,
+  syncRunOpaque,
+  opaqueArrayRun,
+  opaqueVec,
+  opaqueVecRun,
+  createNestedOpaque,
+  syncLoopback,
+  syncOptionLoopback,
+  syncOption,
+  syncOptionNull,
+  syncOptionRustOpaque,
+  syncOptionDartOpaque,
+  syncVoid,
+  runNestedOpaque,
+  createNestedDartOpaque,
+  getNestedDartOpaque,
+  createEnumDartOpaque,
+  getEnumDartOpaque,
+  setStaticDartOpaque,
+  dropStaticDartOpaque,
+  unwrapRustOpaque,
+  returnNonDroppableDartOpaque,
+  frbGeneratorTest,
+  frbSyncGeneratorTest,
+  observeCounter,
+  isCounterAlive,
+  handleTypeAliasId,
+  handleTypeNestAliasId,
+  handleTypeAliasModel,
+  emptyStruct,
+  handleHashMap,
+  handleHashMapOfStruct,
+  countWeekdays,
+  indexWords,
+  handleCollidingKeys,
+  handleBtreeSet,
+  handleBtreeMap,
+  groupAttributes,
+  handleTuple,
+  handleNestedTuple,
+  sumMethodSumWith,
+  newStaticMethodConcatenateWith,
+  concatenateMethodConcatenateWith,
+  concatenateStaticStaticMethodConcatenateWith,
+  handleSomeStreamSinkMethodConcatenateWith,
+  handleSomeStreamSinkAt1MethodConcatenateWith,
+  handleSomeStaticStreamSinkStaticMethodConcatenateWith,
+  handleSomeStaticStreamSinkSingleArgStaticMethodConcatenateWith,
+  startStaticMethodCounter,
+  incrementMethodCounter,
+  labelMethodCounter,
+  resetMethodCounter,
+  countDownMethodCounter,
+  incrementLaterMethodCounter,
+  updatesMethodCounter,
+  finishMethodCounter,
+  openStaticMethodRooms,
+  joinMethodRooms,
+  postMethodRooms,
+  closeRoomMethodRooms,
+  namedStaticMethodGreeter,
+  describeMethodCounter,
+  shoutMethodCounter,
+  describeMethodGreeter,
+  shoutMethodGreeter,
+  dispatch,
+  nextDartIteratorString,
+  nextDartIteratorI32,
+  changesMethodCounter,
+  warmUp,
+  upgradeWeakCounter,
+  instantDurationSince,
+  handleStreamDrain,
+  handleStreamOfStructDrain,
+  registerEventListenerDrain,
+  handleStreamSinkAt1Drain,
+  handleStreamSinkAt2Drain,
+  handleStreamSinkAt3Drain,
+  handleStreamClosedByDropDrain,
+  countFromDrain,
+  parseNumbersDrain,
+  handleBroadcastStreamDrain,
+  handleStreamClosedByThreadsDrain
+}
+
+@sealed
+class BoxDartDebug extends FrbOpaque {
+  final FlutterRustBridgeExampleSingleBlockTest bridge;
+  BoxDartDebug.fromRaw(int ptr, int size, this.bridge) : super.unsafe(ptr, size);
+
+  /// Creates another handle to the same Rust object, which stays alive until every handle is disposed.
+  BoxDartDebug clone() => BoxDartDebug.fromRaw(shareRaw(), externalSize, bridge);
+
+  /// Turns a token made by [toSendable], possibly in another isolate, into a handle.
+  factory BoxDartDebug.fromSendable(SendableOpaque token, FlutterRustBridgeExampleSingleBlockTest bridge) =>
+      BoxDartDebug.fromRaw(token.takeAddress(BoxDartDebug), token.externalSize, bridge);
+  @override
+  DropFnType get dropFn => bridge.dropOpaqueBoxDartDebug;
+
+  @override
+  ShareFnType get shareFn => bridge.shareOpaqueBoxDartDebug;
+
+  @override
+  ShareFnType get sendFn => bridge.sendOpaqueBoxDartDebug;
+
+  @override
+  OpaqueTypeFinalizer get staticFinalizer => bridge.BoxDartDebugFinalizer;
+}
+
+@sealed
+class Counter extends FrbOpaque implements Describe {
+  final FlutterRustBridgeExampleSingleBlockTest bridge;
+  Counter.fromRaw(int ptr, int size, this.bridge) : super.unsafe(ptr, size);
+
+  /// Creates another handle to the same Rust object, which stays alive until every handle is disposed.
+  Counter clone() => Counter.fromRaw(shareRaw(), externalSize, bridge);
+
+  /// Turns a token made by [toSendable], possibly in another isolate, into a handle.
+  factory Counter.fromSendable(SendableOpaque token, FlutterRustBridgeExampleSingleBlockTest bridge) =>
+      Counter.fromRaw(token.takeAddress(Counter), token.externalSize, bridge);
+  @override
+  DropFnType get dropFn => bridge.dropOpaqueCounter;
+
+  @override
+  ShareFnType get shareFn => bridge.shareOpaqueCounter;
+
+  @override
+  ShareFnType get sendFn => bridge.sendOpaqueCounter;
+
+  @override
+  OpaqueTypeFinalizer get staticFinalizer => bridge.CounterFinalizer;
+
+  static Future<Counter> start(
+          {required FlutterRustBridgeExampleSingleBlockTest bridge, required int from, dynamic hint}) =>
+      bridge.startStaticMethodCounter(from: from, hint: hint);
+
+  Future<int> increment({required int by, dynamic hint}) => bridge.incrementMethodCounter(
+        that: this,
+        by: by,
+      );
+
+  Future<String> label({dynamic hint}) => bridge.labelMethodCounter(
+        that: this,
+      );
+
+  Future<void> reset({required int to, dynamic hint}) => bridge.resetMethodCounter(
+        that: this,
+        to: to,
+      );
+
+  Stream<int> countDown({dynamic hint}) => bindStream(bridge.countDownMethodCounter(
+        that: this,
+      ));
+
+  Future<int> incrementLater({required int by, dynamic hint}) => bridge.incrementLaterMethodCounter(
+        that: this,
+        by: by,
+      );
+
+  Stream<int> updates({dynamic hint}) => bindStream(
+      bridge.updatesMethodCounter(
+        that: this,
+      ),
+      broadcast: false);
+
+  Future<int> finish({dynamic hint}) => bridge.finishMethodCounter(
+        that: this..move = true,
+      );
+
+  @override
+  Future<String> describe({dynamic hint}) => bridge.describeMethodCounter(
+        that: this,
+      );
+
+  @override
+  Future<String> shout({dynamic hint}) => bridge.shoutMethodCounter(
+        that: this,
+      );
+
+  Stream<void> changes({dynamic hint}) => bindStream(
+      bridge.changesMethodCounter(
+        that: this,
+      ),
+      broadcast: false);
+}
+
+@sealed
+class DartIteratorI32 extends FrbOpaque {
+  final FlutterRustBridgeExampleSingleBlockTest bridge;
+  DartIteratorI32.fromRaw(int ptr, int size, this.bridge) : super.unsafe(ptr, size);
+
+  /// Creates another handle to the same Rust object, which stays alive until every handle is disposed.
+  DartIteratorI32 clone() => DartIteratorI32.fromRaw(shareRaw(), externalSize, bridge);
+
+  /// Turns a token made by [toSendable], possibly in another isolate, into a handle.
+  factory DartIteratorI32.fromSendable(SendableOpaque token, FlutterRustBridgeExampleSingleBlockTest bridge) =>
+      DartIteratorI32.fromRaw(token.takeAddress(DartIteratorI32), token.externalSize, bridge);
+  @override
+  DropFnType get dropFn => bridge.dropOpaqueDartIteratorI32;
+
+  @override
+  ShareFnType get shareFn => bridge.shareOpaqueDartIteratorI32;
+
+  @override
+  ShareFnType get sendFn => bridge.sendOpaqueDartIteratorI32;
+
+  @override
+  OpaqueTypeFinalizer get staticFinalizer => bridge.DartIteratorI32Finalizer;
+}
+
+@sealed
+class DartIteratorString extends FrbOpaque {
+  final FlutterRustBridgeExampleSingleBlockTest bridge;
+  DartIteratorString.fromRaw(int ptr, int size, this.bridge) : super.unsafe(ptr, size);
+
+  /// Creates another handle to the same Rust object, which stays alive until every handle is disposed.
+  DartIteratorString clone() => DartIteratorString.fromRaw(shareRaw(), externalSize, bridge);
+
+  /// Turns a token made by [toSendable], possibly in another isolate, into a handle.
+  factory DartIteratorString.fromSendable(SendableOpaque token, FlutterRustBridgeExampleSingleBlockTest bridge) =>
+      DartIteratorString.fromRaw(token.takeAddress(DartIteratorString), token.externalSize, bridge);
+  @override
+  DropFnType get dropFn => bridge.dropOpaqueDartIteratorString;
+
+  @override
+  ShareFnType get shareFn => bridge.shareOpaqueDartIteratorString;
+
+  @override
+  ShareFnType get sendFn => bridge.sendOpaqueDartIteratorString;
+
+  @override
+  OpaqueTypeFinalizer get staticFinalizer => bridge.DartIteratorStringFinalizer;
+}
+
+class EnumOpaqueArray5 extends NonGrowableListView<EnumOpaque> {
+  static const arraySize = 5;
+  EnumOpaqueArray5(List<EnumOpaque> inner)
+      : super(inner.length == arraySize
+            ? inner
+            : throw ArgumentError.value(inner, 'inner', 'expected length $arraySize but see ${inner.length}'));
+  EnumOpaqueArray5.unchecked(List<EnumOpaque> inner) : super(inner);
+  EnumOpaqueArray5.init(EnumOpaque fill) : super(List<EnumOpaque>.filled(arraySize, fill));
+}
+
+@sealed
+class FrbOpaqueReturn extends FrbOpaque {
+  final FlutterRustBridgeExampleSingleBlockTest bridge;
+  FrbOpaqueReturn.fromRaw(int ptr, int size, this.bridge) : super.unsafe(ptr, size);
+
+  /// Creates another handle to the same Rust object, which stays alive until every handle is disposed.
+  FrbOpaqueReturn clone() => FrbOpaqueReturn.fromRaw(shareRaw(), externalSize, bridge);
+
+  /// Turns a token made by [toSendable], possibly in another isolate, into a handle.
+  factory FrbOpaqueReturn.fromSendable(SendableOpaque token, FlutterRustBridgeExampleSingleBlockTest bridge) =>
+      FrbOpaqueReturn.fromRaw(token.takeAddress(FrbOpaqueReturn), token.externalSize, bridge);
+  @override
+  DropFnType get dropFn => bridge.dropOpaqueFrbOpaqueReturn;
+
+  @override
+  ShareFnType get shareFn => bridge.shareOpaqueFrbOpaqueReturn;
+
+  @override
+  ShareFnType get sendFn => bridge.sendOpaqueFrbOpaqueReturn;
+
+  @override
+  OpaqueTypeFinalizer get staticFinalizer => bridge.FrbOpaqueReturnFinalizer;
+}
+
+@sealed
+class FrbOpaqueSyncReturn extends FrbOpaque {
+  final FlutterRustBridgeExampleSingleBlockTest bridge;
+  FrbOpaqueSyncReturn.fromRaw(int ptr, int size, this.bridge) : super.unsafe(ptr, size);
+
+  /// Creates another handle to the same Rust object, which stays alive until every handle is disposed.
+  FrbOpaqueSyncReturn clone() => FrbOpaqueSyncReturn.fromRaw(shareRaw(), externalSize, bridge);
+
+  /// Turns a token made by [toSendable], possibly in another isolate, into a handle.
+  factory FrbOpaqueSyncReturn.fromSendable(SendableOpaque token, FlutterRustBridgeExampleSingleBlockTest bridge) =>
+      FrbOpaqueSyncReturn.fromRaw(token.takeAddress(FrbOpaqueSyncReturn), token.externalSize, bridge);
+  @override
+  DropFnType get dropFn => bridge.dropOpaqueFrbOpaqueSyncReturn;
+
+  @override
+  ShareFnType get shareFn => bridge.shareOpaqueFrbOpaqueSyncReturn;
+
+  @override
+  ShareFnType get sendFn => bridge.sendOpaqueFrbOpaqueSyncReturn;
+
+  @override
+  OpaqueTypeFinalizer get staticFinalizer => bridge.FrbOpaqueSyncReturnFinalizer;
+}
+
+@sealed
+class Greeter extends FrbOpaque implements Describe {
+  final FlutterRustBridgeExampleSingleBlockTest bridge;
+  Greeter.fromRaw(int ptr, int size, this.bridge) : super.unsafe(ptr, size);
+
+  /// Creates another handle to the same Rust object, which stays alive until every handle is disposed.
+  Greeter clone() => Greeter.fromRaw(shareRaw(), externalSize, bridge);
+
+  /// Turns a token made by [toSendable], possibly in another isolate, into a handle.
+  factory Greeter.fromSendable(SendableOpaque token, FlutterRustBridgeExampleSingleBlockTest bridge) =>
+      Greeter.fromRaw(token.takeAddress(Greeter), token.externalSize, bridge);
+  @override
+  DropFnType get dropFn => bridge.dropOpaqueGreeter;
+
+  @override
+  ShareFnType get shareFn => bridge.shareOpaqueGreeter;
+
+  @override
+  ShareFnType get sendFn => bridge.sendOpaqueGreeter;
+
+  @override
+  OpaqueTypeFinalizer get staticFinalizer => bridge.GreeterFinalizer;
+
+  static Future<Greeter> named(
+          {required FlutterRustBridgeExampleSingleBlockTest bridge, required String name, dynamic hint}) =>
+      bridge.namedStaticMethodGreeter(name: name, hint: hint);
+
+  @override
+  Future<String> describe({dynamic hint}) => bridge.describeMethodGreeter(
+        that: this,
+      );
+
+  @override
+  Future<String> shout({dynamic hint}) => bridge.shoutMethodGreeter(
+        that: this,
+      );
+}
+
+@sealed
+class HideData extends FrbOpaque {
+  final FlutterRustBridgeExampleSingleBlockTest bridge;
   HideData.fromRaw(int ptr, int size, this.bridge) : super.unsafe(ptr, size);
+
+  /// Creates another handle to the same Rust object, which stays alive until every handle is disposed.
+  HideData clone() => HideData.fromRaw(shareRaw(), externalSize, bridge);
+
+  /// Turns a token made by [toSendable], possibly in another isolate, into a handle.
+  factory HideData.fromSendable(SendableOpaque token, FlutterRustBridgeExampleSingleBlockTest bridge) =>
+      HideData.fromRaw(token.takeAddress(HideData), token.externalSize, bridge);
   @override
   DropFnType get dropFn => bridge.dropOpaqueHideData;
 
   @override
   ShareFnType get shareFn => bridge.shareOpaqueHideData;
 
+  @override
+  ShareFnType get sendFn => bridge.sendOpaqueHideData;
+
   @override
   OpaqueTypeFinalizer get staticFinalizer => bridge.HideDataFinalizer;
 }
@@ -723,8 +1809,9 @@ class HideData extends FrbOpaque {
 class HideDataArray2 extends NonGrowableListView<HideData> {
   static const arraySize = 2;
   HideDataArray2(List<HideData> inner)
-      : assert(inner.length == arraySize),
-        super(inner);
+      : super(inner.length == arraySize
+            ? inner
+            : throw ArgumentError.value(inner, 'inner', 'expected length $arraySize but see ${inner.length}'));
   HideDataArray2.unchecked(List<HideData> inner) : super(inner);
   HideDataArray2.init(HideData fill) : super(List<HideData>.filled(arraySize, fill));
 }
@@ -733,26 +1820,74 @@ class HideDataArray2 extends NonGrowableListView<HideData> {
 class I32 extends FrbOpaque {
   final FlutterRustBridgeExampleSingleBlockTest bridge;
   I32.fromRaw(int ptr, int size, this.bridge) : super.unsafe(ptr, size);
+
+  /// Creates another handle to the same Rust object, which stays alive until every handle is disposed.
+  I32 clone() => I32.fromRaw(shareRaw(), externalSize, bridge);
+
+  /// Turns a token made by [toSendable], possibly in another isolate, into a handle.
+  factory I32.fromSendable(SendableOpaque token, FlutterRustBridgeExampleSingleBlockTest bridge) =>
+      I32.fromRaw(token.takeAddress(I32), token.externalSize, bridge);
   @override
   DropFnType get dropFn => bridge.dropOpaqueI32;
 
   @override
   ShareFnType get shareFn => bridge.shareOpaqueI32;
 
+  @override
+  ShareFnType get sendFn => bridge.sendOpaqueI32;
+
   @override
   OpaqueTypeFinalizer get staticFinalizer => bridge.I32Finalizer;
 }
 
+@sealed
+class Instant extends FrbOpaque {
+  final FlutterRustBridgeExampleSingleBlockTest bridge;
+  Instant.fromRaw(int ptr, int size, this.bridge) : super.unsafe(ptr, size);
+
+  /// Creates another handle to the same Rust object, which stays alive until every handle is disposed.
+  Instant clone() => Instant.fromRaw(shareRaw(), externalSize, bridge);
+
+  /// Turns a token made by [toSendable], possibly in another isolate, into a handle.
+  factory Instant.fromSendable(SendableOpaque token, FlutterRustBridgeExampleSingleBlockTest bridge) =>
+      Instant.fromRaw(token.takeAddress(Instant), token.externalSize, bridge);
+  @override
+  DropFnType get dropFn => bridge.dropOpaqueInstant;
+
+  @override
+  ShareFnType get shareFn => bridge.shareOpaqueInstant;
+
+  @override
+  ShareFnType get sendFn => bridge.sendOpaqueInstant;
+
+  @override
+  OpaqueTypeFinalizer get staticFinalizer => bridge.InstantFinalizer;
+
+  /// The time elapsed from `earlier` to `that`, or zero if `earlier` is the later one.
+  Future<Duration> durationSince({required Instant earlier, dynamic hint}) =>
+      bridge.instantDurationSince(that: this, earlier: earlier, hint: hint);
+}
+
 @sealed
 class MutexHideData extends FrbOpaque {
   final FlutterRustBridgeExampleSingleBlockTest bridge;
   MutexHideData.fromRaw(int ptr, int size, this.bridge) : super.unsafe(ptr, size);
+
+  /// Creates another handle to the same Rust object, which stays alive until every handle is disposed.
+  MutexHideData clone() => MutexHideData.fromRaw(shareRaw(), externalSize, bridge);
+
+  /// Turns a token made by [toSendable], possibly in another isolate, into a handle.
+  factory MutexHideData.fromSendable(SendableOpaque token, FlutterRustBridgeExampleSingleBlockTest bridge) =>
+      MutexHideData.fromRaw(token.takeAddress(MutexHideData), token.externalSize, bridge);
   @override
   DropFnType get dropFn => bridge.dropOpaqueMutexHideData;
 
   @override
   ShareFnType get shareFn => bridge.shareOpaqueMutexHideData;
 
+  @override
+  ShareFnType get sendFn => bridge.sendOpaqueMutexHideData;
+
   @override
   OpaqueTypeFinalizer get staticFinalizer => bridge.MutexHideDataFinalizer;
 }
@@ -761,12 +1896,22 @@ class MutexHideData extends FrbOpaque {
 class NonSendHideData extends FrbOpaque {
   final FlutterRustBridgeExampleSingleBlockTest bridge;
   NonSendHideData.fromRaw(int ptr, int size, this.bridge) : super.unsafe(ptr, size);
+
+  /// Creates another handle to the same Rust object, which stays alive until every handle is disposed.
+  NonSendHideData clone() => NonSendHideData.fromRaw(shareRaw(), externalSize, bridge);
+
+  /// Turns a token made by [toSendable], possibly in another isolate, into a handle.
+  factory NonSendHideData.fromSendable(SendableOpaque token, FlutterRustBridgeExampleSingleBlockTest bridge) =>
+      NonSendHideData.fromRaw(token.takeAddress(NonSendHideData), token.externalSize, bridge);
   @override
   DropFnType get dropFn => bridge.dropOpaqueNonSendHideData;
 
   @override
   ShareFnType get shareFn => bridge.shareOpaqueNonSendHideData;
 
+  @override
+  ShareFnType get sendFn => bridge.sendOpaqueNonSendHideData;
+
   @override
   OpaqueTypeFinalizer get staticFinalizer => bridge.NonSendHideDataFinalizer;
 }
@@ -774,8 +1919,9 @@ class NonSendHideData extends FrbOpaque {
 class ObjectArray1 extends NonGrowableListView<Object> {
   static const arraySize = 1;
   ObjectArray1(List<Object> inner)
-      : assert(inner.length == arraySize),
-        super(inner);
+      : super(inner.length == arraySize
+            ? inner
+            : throw ArgumentError.value(inner, 'inner', 'expected length $arraySize but see ${inner.length}'));
   ObjectArray1.unchecked(List<Object> inner) : super(inner);
   ObjectArray1.init(Object fill) : super(List<Object>.filled(arraySize, fill));
 }
@@ -783,22 +1929,78 @@ class ObjectArray1 extends NonGrowableListView<Object> {
 class PointArray2 extends NonGrowableListView<Point> {
   static const arraySize = 2;
   PointArray2(List<Point> inner)
-      : assert(inner.length == arraySize),
-        super(inner);
+      : super(inner.length == arraySize
+            ? inner
+            : throw ArgumentError.value(inner, 'inner', 'expected length $arraySize but see ${inner.length}'));
   PointArray2.unchecked(List<Point> inner) : super(inner);
   PointArray2.init(Point fill) : super(List<Point>.filled(arraySize, fill));
 }
 
+@sealed
+class Rooms extends FrbOpaque {
+  final FlutterRustBridgeExampleSingleBlockTest bridge;
+  Rooms.fromRaw(int ptr, int size, this.bridge) : super.unsafe(ptr, size);
+
+  /// Creates another handle to the same Rust object, which stays alive until every handle is disposed.
+  Rooms clone() => Rooms.fromRaw(shareRaw(), externalSize, bridge);
+
+  /// Turns a token made by [toSendable], possibly in another isolate, into a handle.
+  factory Rooms.fromSendable(SendableOpaque token, FlutterRustBridgeExampleSingleBlockTest bridge) =>
+      Rooms.fromRaw(token.takeAddress(Rooms), token.externalSize, bridge);
+  @override
+  DropFnType get dropFn => bridge.dropOpaqueRooms;
+
+  @override
+  ShareFnType get shareFn => bridge.shareOpaqueRooms;
+
+  @override
+  ShareFnType get sendFn => bridge.sendOpaqueRooms;
+
+  @override
+  OpaqueTypeFinalizer get staticFinalizer => bridge.RoomsFinalizer;
+
+  static Future<Rooms> open({required FlutterRustBridgeExampleSingleBlockTest bridge, dynamic hint}) =>
+      bridge.openStaticMethodRooms(hint: hint);
+
+  Stream<String> join({required String room, dynamic hint}) => bindStream(
+      bridge.joinMethodRooms(
+        that: this,
+        room: room,
+      ),
+      broadcast: false);
+
+  Future<int> post({required String room, required String message, dynamic hint}) => bridge.postMethodRooms(
+        that: this,
+        room: room,
+        message: message,
+      );
+
+  Future<int> closeRoom({required String room, dynamic hint}) => bridge.closeRoomMethodRooms(
+        that: this,
+        room: room,
+      );
+}
+
 @sealed
 class RwLockHideData extends FrbOpaque {
   final FlutterRustBridgeExampleSingleBlockTest bridge;
   RwLockHideData.fromRaw(int ptr, int size, this.bridge) : super.unsafe(ptr, size);
+
+  /// Creates another handle to the same Rust object, which stays alive until every handle is disposed.
+  RwLockHideData clone() => RwLockHideData.fromRaw(shareRaw(), externalSize, bridge);
+
+  /// Turns a token made by [toSendable], possibly in another isolate, into a handle.
+  factory RwLockHideData.fromSendable(SendableOpaque token, FlutterRustBridgeExampleSingleBlockTest bridge) =>
+      RwLockHideData.fromRaw(token.takeAddress(RwLockHideData), token.externalSize, bridge);
   @override
   DropFnType get dropFn => bridge.dropOpaqueRwLockHideData;
 
   @override
   ShareFnType get shareFn => bridge.shareOpaqueRwLockHideData;
 
+  @override
+  ShareFnType get sendFn => bridge.sendOpaqueRwLockHideData;
+
   @override
   OpaqueTypeFinalizer get staticFinalizer => bridge.RwLockHideDataFinalizer;
 }
@@ -806,8 +2008,9 @@ class RwLockHideData extends FrbOpaque {
 class SumWithArray3 extends NonGrowableListView<SumWith> {
   static const arraySize = 3;
   SumWithArray3(List<SumWith> inner)
-      : assert(inner.length == arraySize),
-        super(inner);
+      : super(inner.length == arraySize
+            ? inner
+            : throw ArgumentError.value(inner, 'inner', 'expected length $arraySize but see ${inner.length}'));
   SumWithArray3.unchecked(List<SumWith> inner) : super(inner);
   SumWithArray3.init(SumWith fill) : super(List<SumWith>.filled(arraySize, fill));
 }
@@ -815,8 +2018,9 @@ class SumWithArray3 extends NonGrowableListView<SumWith> {
 class TestIdArray2 extends NonGrowableListView<TestId> {
   static const arraySize = 2;
   TestIdArray2(List<TestId> inner)
-      : assert(inner.length == arraySize),
-        super(inner);
+      : super(inner.length == arraySize
+            ? inner
+            : throw ArgumentError.value(inner, 'inner', 'expected length $arraySize but see ${inner.length}'));
   TestIdArray2.unchecked(List<TestId> inner) : super(inner);
   TestIdArray2.init(TestId fill) : super(List<TestId>.filled(arraySize, fill));
 }
@@ -824,26 +2028,92 @@ class TestIdArray2 extends NonGrowableListView<TestId> {
 class TestIdArray4 extends NonGrowableListView<TestId> {
   static const arraySize = 4;
   TestIdArray4(List<TestId> inner)
-      : assert(inner.length == arraySize),
-        super(inner);
+      : super(inner.length == arraySize
+            ? inner
+            : throw ArgumentError.value(inner, 'inner', 'expected length $arraySize but see ${inner.length}'));
   TestIdArray4.unchecked(List<TestId> inner) : super(inner);
   TestIdArray4.init(TestId fill) : super(List<TestId>.filled(arraySize, fill));
 }
 
+@sealed
+class WeakCounter extends FrbOpaque {
+  final FlutterRustBridgeExampleSingleBlockTest bridge;
+  WeakCounter.fromRaw(int ptr, int size, this.bridge) : super.unsafe(ptr, size);
+
+  /// Creates another handle to the same Rust object, which stays alive until every handle is disposed.
+  WeakCounter clone() => WeakCounter.fromRaw(shareRaw(), externalSize, bridge);
+
+  /// Turns a token made by [toSendable], possibly in another isolate, into a handle.
+  factory WeakCounter.fromSendable(SendableOpaque token, FlutterRustBridgeExampleSingleBlockTest bridge) =>
+      WeakCounter.fromRaw(token.takeAddress(WeakCounter), token.externalSize, bridge);
+  @override
+  DropFnType get dropFn => bridge.dropOpaqueWeakCounter;
+
+  @override
+  ShareFnType get shareFn => bridge.shareOpaqueWeakCounter;
+
+  @override
+  ShareFnType get sendFn => bridge.sendOpaqueWeakCounter;
+
+  @override
+  OpaqueTypeFinalizer get staticFinalizer => bridge.WeakCounterFinalizer;
+
+  /// A new handle to the object of a weak handle, or `null` if it has been dropped.
+  Future<Counter?> upgrade({dynamic hint}) => bridge.upgradeWeakCounter(weak: this, hint: hint);
+}
+
 class ApplicationEnv {
   final List<ApplicationEnvVar> vars;
-  ApplicationEnv({
+
+  const ApplicationEnv({
     required this.vars,
   });
+
+  ApplicationEnv copyWith({
+    List<ApplicationEnvVar>? vars,
+  }) =>
+      ApplicationEnv(
+        vars: vars ?? this.vars,
+      );
+
+  factory ApplicationEnv.fromJson(Map<String, dynamic> json) => ApplicationEnv(
+        vars: (json['vars'] as List<dynamic>)
+            .map((v0) => ApplicationEnvVar.fromJson(v0 as Map<String, dynamic>))
+            .toList(),
+      );
+
+  Map<String, dynamic> toJson() => {
+        'vars': vars.map((v0) => v0.toJson()).toList(),
+      };
 }
 
 class ApplicationEnvVar {
   final String field0;
   final bool field1;
-  ApplicationEnvVar({
+
+  const ApplicationEnvVar({
     required this.field0,
     required this.field1,
   });
+
+  ApplicationEnvVar copyWith({
+    String? field0,
+    bool? field1,
+  }) =>
+      ApplicationEnvVar(
+        field0: field0 ?? this.field0,
+        field1: field1 ?? this.field1,
+      );
+
+  factory ApplicationEnvVar.fromJson(Map<String, dynamic> json) => ApplicationEnvVar(
+        field0: json['field0'] as String,
+        field1: json['field1'] as bool,
+      );
+
+  Map<String, dynamic> toJson() => {
+        'field0': field0,
+        'field1': field1,
+      };
 }
 
 @freezed
@@ -856,6 +2126,51 @@ class ApplicationMessage with _$ApplicationMessage {
     required int y,
   }) = ApplicationMessage_RenderPixel;
   const factory ApplicationMessage.exit() = ApplicationMessage_Exit;
+
+  const ApplicationMessage._();
+
+  static ApplicationMessage fromJson(Map<String, dynamic> json) {
+    final content = json['content'];
+    switch (json['tag']) {
+      case 'DisplayMessage':
+        return ApplicationMessage.displayMessage(
+          content['field0'] as String,
+        );
+      case 'RenderPixel':
+        return ApplicationMessage.renderPixel(
+          x: content['x'] as int,
+          y: content['y'] as int,
+        );
+      case 'Exit':
+        return ApplicationMessage.exit();
+      default:
+        throw FormatException('Unknown variant of ApplicationMessage', json['tag']);
+    }
+  }
+
+  Map<String, dynamic> toJson() {
+    final self = this;
+    if (self is ApplicationMessage_DisplayMessage)
+      return {
+        'tag': 'DisplayMessage',
+        'content': {
+          'field0': self.field0,
+        },
+      };
+    if (self is ApplicationMessage_RenderPixel)
+      return {
+        'tag': 'RenderPixel',
+        'content': {
+          'x': self.x,
+          'y': self.y,
+        },
+      };
+    if (self is ApplicationMessage_Exit)
+      return {
+        'tag': 'Exit',
+      };
+    throw StateError('unreachable');
+  }
 }
 
 enum ApplicationMode {
@@ -863,52 +2178,160 @@ enum ApplicationMode {
   Embedded,
 }
 
+extension ApplicationModeJson on ApplicationMode {
+  String toJson() => name;
+}
+
 class ApplicationSettings {
   final String name;
   final String version;
   final ApplicationMode mode;
   final ApplicationEnv env;
   final ApplicationEnv? envOptional;
-  ApplicationSettings({
+
+  const ApplicationSettings({
     required this.name,
     required this.version,
     required this.mode,
     required this.env,
     this.envOptional,
   });
+
+  ApplicationSettings copyWith({
+    String? name,
+    String? version,
+    ApplicationMode? mode,
+    ApplicationEnv? env,
+    Object? envOptional = copyWithUnset,
+  }) =>
+      ApplicationSettings(
+        name: name ?? this.name,
+        version: version ?? this.version,
+        mode: mode ?? this.mode,
+        env: env ?? this.env,
+        envOptional: identical(envOptional, copyWithUnset) ? this.envOptional : envOptional as ApplicationEnv?,
+      );
+
+  factory ApplicationSettings.fromJson(Map<String, dynamic> json) => ApplicationSettings(
+        name: json['name'] as String,
+        version: json['version'] as String,
+        mode: ApplicationMode.values.byName(json['mode'] as String),
+        env: ApplicationEnv.fromJson(json['env'] as Map<String, dynamic>),
+        envOptional:
+            json['env_optional'] == null ? null : ApplicationEnv.fromJson(json['env_optional'] as Map<String, dynamic>),
+      );
+
+  Map<String, dynamic> toJson() => {
+        'name': name,
+        'version': version,
+        'mode': mode.name,
+        'env': env.toJson(),
+        'env_optional': envOptional == null ? null : envOptional!.toJson(),
+      };
 }
 
 class Attribute {
   final String key;
   final String value;
-  Attribute({
+
+  const Attribute({
     required this.key,
     required this.value,
   });
+
+  Attribute copyWith({
+    String? key,
+    String? value,
+  }) =>
+      Attribute(
+        key: key ?? this.key,
+        value: value ?? this.value,
+      );
+
+  factory Attribute.fromJson(Map<String, dynamic> json) => Attribute(
+        key: json['key'] as String,
+        value: json['value'] as String,
+      );
+
+  Map<String, dynamic> toJson() => {
+        'key': key,
+        'value': value,
+      };
 }
 
 class BigBuffers {
   final Int64List int64;
   final Uint64List uint64;
+
   BigBuffers({
     required this.int64,
     required this.uint64,
   });
+
+  BigBuffers copyWith({
+    Int64List? int64,
+    Uint64List? uint64,
+  }) =>
+      BigBuffers(
+        int64: int64 ?? this.int64,
+        uint64: uint64 ?? this.uint64,
+      );
 }
 
 class Blob {
   final U8Array1600 field0;
+
   Blob({
     required this.field0,
   });
+
+  Blob copyWith({
+    U8Array1600? field0,
+  }) =>
+      Blob(
+        field0: field0 ?? this.field0,
+      );
+
+  factory Blob.fromJson(Map<String, dynamic> json) => Blob(
+        field0: U8Array1600(base64Decode(json['field0'] as String)),
+      );
+
+  Map<String, dynamic> toJson() => {
+        'field0': base64Encode(field0),
+      };
+}
+
+/// A key whose values all have the same hash.
+class CollidingKey {
+  final String field0;
+
+  const CollidingKey({
+    required this.field0,
+  });
+
+  CollidingKey copyWith({
+    String? field0,
+  }) =>
+      CollidingKey(
+        field0: field0 ?? this.field0,
+      );
+
+  factory CollidingKey.fromJson(Map<String, dynamic> json) => CollidingKey(
+        field0: json['field0'] as String,
+      );
+
+  Map<String, dynamic> toJson() => {
+        'field0': field0,
+      };
 }
 
 class ConcatenateWith {
   final FlutterRustBridgeExampleSingleBlockTest bridge;
   final String a;
-  ConcatenateWith({
-    required this.bridge,
+
+  const ConcatenateWith({
     required this.a,
+    required this.bridge,
   });
 
   static Future<ConcatenateWith> newConcatenateWith(
@@ -948,24 +2371,112 @@ class ConcatenateWith {
   static Stream<int> handleSomeStaticStreamSinkSingleArg(
           {required FlutterRustBridgeExampleSingleBlockTest bridge, dynamic hint}) =>
       bridge.handleSomeStaticStreamSinkSingleArgStaticMethodConcatenateWith(hint: hint);
+
+  ConcatenateWith copyWith({
+    String? a,
+  }) =>
+      ConcatenateWith(
+        a: a ?? this.a,
+        bridge: bridge,
+      );
+}
+
+@freezed
+class CustomError with _$CustomError {
+  const factory CustomError.fail({
+    required String message,
+    required String backtrace,
+  }) = CustomError_Fail;
+  const factory CustomError.retry({
+    required int afterMs,
+  }) = CustomError_Retry;
+
+  const CustomError._();
+
+  static CustomError fromJson(Map<String, dynamic> json) {
+    final content = json['content'];
+    switch (json['tag']) {
+      case 'Fail':
+        return CustomError.fail(
+          message: content['message'] as String,
+          backtrace: content['backtrace'] as String,
+        );
+      case 'Retry':
+        return CustomError.retry(
+          afterMs: content['after_ms'] as int,
+        );
+      default:
+        throw FormatException('Unknown variant of CustomError', json['tag']);
+    }
+  }
+
+  Map<String, dynamic> toJson() {
+    final self = this;
+    if (self is CustomError_Fail)
+      return {
+        'tag': 'Fail',
+        'content': {
+          'message': self.message,
+          'backtrace': self.backtrace,
+        },
+      };
+    if (self is CustomError_Retry)
+      return {
+        'tag': 'Retry',
+        'content': {
+          'after_ms': self.afterMs,
+        },
+      };
+    throw StateError('unreachable');
+  }
 }
 
 class Customized {
   final String finalField;
   String? nonFinalField;
+
   Customized({
     required this.finalField,
     this.nonFinalField,
   });
+
+  Customized copyWith({
+    String? finalField,
+    Object? nonFinalField = copyWithUnset,
+  }) =>
+      Customized(
+        finalField: finalField ?? this.finalField,
+        nonFinalField: identical(nonFinalField, copyWithUnset) ? this.nonFinalField : nonFinalField as String?,
+      );
+
+  factory Customized.fromJson(Map<String, dynamic> json) => Customized(
+        finalField: json['final_field'] as String,
+        nonFinalField: json['non_final_field'] == null ? null : json['non_final_field'] as String,
+      );
+
+  Map<String, dynamic> toJson() => {
+        'final_field': finalField,
+        'non_final_field': nonFinalField == null ? null : nonFinalField!,
+      };
 }
 
 class DartOpaqueNested {
   final Object first;
   final Object second;
+
   DartOpaqueNested({
     required this.first,
     required this.second,
   });
+
+  DartOpaqueNested copyWith({
+    Object? first,
+    Object? second,
+  }) =>
+      DartOpaqueNested(
+        first: first ?? this.first,
+        second: second ?? this.second,
+      );
 }
 
 @freezed
@@ -974,6 +2485,73 @@ class Distance with _$Distance {
   const factory Distance.map(
     double field0,
   ) = Distance_Map;
+
+  const Distance._();
+
+  static Distance fromJson(Map<String, dynamic> json) {
+    final content = json['content'];
+    switch (json['tag']) {
+      case 'Unknown':
+        return Distance.unknown();
+      case 'Map':
+        return Distance.map(
+          (content['field0'] as num).toDouble(),
+        );
+      default:
+        throw FormatException('Unknown variant of Distance', json['tag']);
+    }
+  }
+
+  Map<String, dynamic> toJson() {
+    final self = this;
+    if (self is Distance_Unknown)
+      return {
+        'tag': 'Unknown',
+      };
+    if (self is Distance_Map)
+      return {
+        'tag': 'Map',
+        'content': {
+          'field0': self.field0,
+        },
+      };
+    throw StateError('unreachable');
+  }
+}
+
+class Document {
+  final int id;
+  final String createdBy;
+  final String title;
+
+  const Document({
+    required this.id,
+    required this.createdBy,
+    required this.title,
+  });
+
+  Document copyWith({
+    int? id,
+    String? createdBy,
+    String? title,
+  }) =>
+      Document(
+        id: id ?? this.id,
+        createdBy: createdBy ?? this.createdBy,
+        title: title ?? this.title,
+      );
+
+  factory Document.fromJson(Map<String, dynamic> json) => Document(
+        id: json['id'] as int,
+        createdBy: json['created_by'] as String,
+        title: json['title'] as String,
+      );
+
+  Map<String, dynamic> toJson() => {
+        'id': id,
+        'created_by': createdBy,
+        'title': title,
+      };
 }
 
 class Element {
@@ -981,16 +2559,56 @@ class Element {
   final String? text;
   final List<Attribute>? attributes;
   final List<Element>? children;
-  Element({
+
+  const Element({
     this.tag,
     this.text,
     this.attributes,
     this.children,
   });
+
+  Element copyWith({
+    Object? tag = copyWithUnset,
+    Object? text = copyWithUnset,
+    Object? attributes = copyWithUnset,
+    Object? children = copyWithUnset,
+  }) =>
+      Element(
+        tag: identical(tag, copyWithUnset) ? this.tag : tag as String?,
+        text: identical(text, copyWithUnset) ? this.text : text as String?,
+        attributes: identical(attributes, copyWithUnset) ? this.attributes : attributes as List<Attribute>?,
+        children: identical(children, copyWithUnset) ? this.children : children as List<Element>?,
+      );
+
+  factory Element.fromJson(Map<String, dynamic> json) => Element(
+        tag: json['tag'] == null ? null : json['tag'] as String,
+        text: json['text'] == null ? null : json['text'] as String,
+        attributes: json['attributes'] == null
+            ? null
+            : (json['attributes'] as List<dynamic>)
+                .map((v0) => Attribute.fromJson(v0 as Map<String, dynamic>))
+                .toList(),
+        children: json['children'] == null
+            ? null
+            : (json['children'] as List<dynamic>).map((v0) => Element.fromJson(v0 as Map<String, dynamic>)).toList(),
+      );
+
+  Map<String, dynamic> toJson() => {
+        'tag': tag == null ? null : tag!,
+        'text': text == null ? null : text!,
+        'attributes': attributes == null ? null : attributes!.map((v0) => v0.toJson()).toList(),
+        'children': children == null ? null : children!.map((v0) => v0.toJson()).toList(),
+      };
 }
 
 class Empty {
   const Empty();
+
+  Empty copyWith() => Empty();
+
+  factory Empty.fromJson(Map<String, dynamic> json) => Empty();
+
+  Map<String, dynamic> toJson() => {};
 }
 
 @freezed
@@ -1028,6 +2646,17 @@ class Event with _$Event {
     required String address,
     required String payload,
   }) = _Event;
+  const Event._();
+
+  static Event fromJson(Map<String, dynamic> json) => Event(
+        address: json['address'] as String,
+        payload: json['payload'] as String,
+      );
+
+  Map<String, dynamic> toJson() => {
+        'address': address,
+        'payload': payload,
+      };
 }
 
 class ExoticOptionals {
@@ -1045,6 +2674,7 @@ class ExoticOptionals {
   final List<Attribute?> attributesNullable;
   final List<Attribute?>? nullableAttributes;
   final NewTypeInt? newtypeint;
+
   ExoticOptionals({
     this.int32,
     this.int64,
@@ -1061,13 +2691,110 @@ class ExoticOptionals {
     this.nullableAttributes,
     this.newtypeint,
   });
+
+  ExoticOptionals copyWith({
+    Object? int32 = copyWithUnset,
+    Object? int64 = copyWithUnset,
+    Object? float64 = copyWithUnset,
+    Object? boolean = copyWithUnset,
+    Object? zerocopy = copyWithUnset,
+    Object? int8List = copyWithUnset,
+    Object? uint8List = copyWithUnset,
+    Object? int32List = copyWithUnset,
+    Object? float32List = copyWithUnset,
+    Object? float64List = copyWithUnset,
+    Object? attributes = copyWithUnset,
+    List<Attribute?>? attributesNullable,
+    Object? nullableAttributes = copyWithUnset,
+    Object? newtypeint = copyWithUnset,
+  }) =>
+      ExoticOptionals(
+        int32: identical(int32, copyWithUnset) ? this.int32 : int32 as int?,
+        int64: identical(int64, copyWithUnset) ? this.int64 : int64 as int?,
+        float64: identical(float64, copyWithUnset) ? this.float64 : float64 as double?,
+        boolean: identical(boolean, copyWithUnset) ? this.boolean : boolean as bool?,
+        zerocopy: identical(zerocopy, copyWithUnset) ? this.zerocopy : zerocopy as Uint8List?,
+        int8List: identical(int8List, copyWithUnset) ? this.int8List : int8List as Int8List?,
+        uint8List: identical(uint8List, copyWithUnset) ? this.uint8List : uint8List as Uint8List?,
+        int32List: identical(int32List, copyWithUnset) ? this.int32List : int32List as Int32List?,
+        float32List: identical(float32List, copyWithUnset) ? this.float32List : float32List as Float32List?,
+        float64List: identical(float64List, copyWithUnset) ? this.float64List : float64List as Float64List?,
+        attributes: identical(attributes, copyWithUnset) ? this.attributes : attributes as List<Attribute>?,
+        attributesNullable: attributesNullable ?? this.attributesNullable,
+        nullableAttributes: identical(nullableAttributes, copyWithUnset)
+            ? this.nullableAttributes
+            : nullableAttributes as List<Attribute?>?,
+        newtypeint: identical(newtypeint, copyWithUnset) ? this.newtypeint : newtypeint as NewTypeInt?,
+      );
+
+  factory ExoticOptionals.fromJson(Map<String, dynamic> json) => ExoticOptionals(
+        int32: json['int32'] == null ? null : json['int32'] as int,
+        int64: json['int64'] == null ? null : json['int64'] as int,
+        float64: json['float64'] == null ? null : (json['float64'] as num).toDouble(),
+        boolean: json['boolean'] == null ? null : json['boolean'] as bool,
+        zerocopy: json['zerocopy'] == null ? null : base64Decode(json['zerocopy'] as String),
+        int8List: json['int8list'] == null ? null : Int8List.fromList((json['int8list'] as List<dynamic>).cast<int>()),
+        uint8List: json['uint8list'] == null ? null : base64Decode(json['uint8list'] as String),
+        int32List:
+            json['int32list'] == null ? null : Int32List.fromList((json['int32list'] as List<dynamic>).cast<int>()),
+        float32List: json['float32list'] == null
+            ? null
+            : Float32List.fromList((json['float32list'] as List<dynamic>).map((v) => (v as num).toDouble()).toList()),
+        float64List: json['float64list'] == null
+            ? null
+            : Float64List.fromList((json['float64list'] as List<dynamic>).map((v) => (v as num).toDouble()).toList()),
+        attributes: json['attributes'] == null
+            ? null
+            : (json['attributes'] as List<dynamic>)
+                .map((v0) => Attribute.fromJson(v0 as Map<String, dynamic>))
+                .toList(),
+        attributesNullable: (json['attributes_nullable'] as List<dynamic>)
+            .map((v0) => v0 == null ? null : Attribute.fromJson(v0 as Map<String, dynamic>))
+            .toList(),
+        nullableAttributes: json['nullable_attributes'] == null
+            ? null
+            : (json['nullable_attributes'] as List<dynamic>)
+                .map((v0) => v0 == null ? null : Attribute.fromJson(v0 as Map<String, dynamic>))
+                .toList(),
+        newtypeint: json['newtypeint'] == null ? null : NewTypeInt.fromJson(json['newtypeint'] as Map<String, dynamic>),
+      );
+
+  Map<String, dynamic> toJson() => {
+        'int32': int32 == null ? null : int32!,
+        'int64': int64 == null ? null : int64!,
+        'float64': float64 == null ? null : float64!,
+        'boolean': boolean == null ? null : boolean!,
+        'zerocopy': zerocopy == null ? null : base64Encode(zerocopy!),
+        'int8list': int8List == null ? null : int8List!.toList(),
+        'uint8list': uint8List == null ? null : base64Encode(uint8List!),
+        'int32list': int32List == null ? null : int32List!.toList(),
+        'float32list': float32List == null ? null : float32List!.toList(),
+        'float64list': float64List == null ? null : float64List!.toList(),
+        'attributes': attributes == null ? null : attributes!.map((v0) => v0.toJson()).toList(),
+        'attributes_nullable': attributesNullable.map((v0) => v0 == null ? null : v0!.toJson()).toList(),
+        'nullable_attributes': nullableAttributes == null
+            ? null
+            : nullableAttributes!.map((v0) => v0 == null ? null : v0!.toJson()).toList(),
+        'newtypeint': newtypeint == null ? null : newtypeint!.toJson(),
+      };
+}
+
+class F32Array3 extends NonGrowableListView<double> {
+  static const arraySize = 3;
+  F32Array3(Float32List inner)
+      : super(inner.length == arraySize
+            ? inner
+            : throw ArgumentError.value(inner, 'inner', 'expected length $arraySize but see ${inner.length}'));
+  F32Array3.unchecked(Float32List inner) : super(inner);
+  F32Array3.init() : super(Float32List(arraySize));
 }
 
 class F64Array16 extends NonGrowableListView<double> {
   static const arraySize = 16;
   F64Array16(Float64List inner)
-      : assert(inner.length == arraySize),
-        super(inner);
+      : super(inner.length == arraySize
+            ? inner
+            : throw ArgumentError.value(inner, 'inner', 'expected length $arraySize but see ${inner.length}'));
   F64Array16.unchecked(Float64List inner) : super(inner);
   F64Array16.init() : super(Float64List(arraySize));
 }
@@ -1077,35 +2804,182 @@ class FeatureChrono {
   final DateTime local;
   final Duration duration;
   final DateTime naive;
+
   FeatureChrono({
     required this.utc,
     required this.local,
     required this.duration,
     required this.naive,
   });
+
+  FeatureChrono copyWith({
+    DateTime? utc,
+    DateTime? local,
+    Duration? duration,
+    DateTime? naive,
+  }) =>
+      FeatureChrono(
+        utc: utc ?? this.utc,
+        local: local ?? this.local,
+        duration: duration ?? this.duration,
+        naive: naive ?? this.naive,
+      );
+
+  factory FeatureChrono.fromJson(Map<String, dynamic> json) => FeatureChrono(
+        utc: DateTime.parse(json['utc'] as String),
+        local: DateTime.parse(json['local'] as String),
+        duration: Duration(microseconds: json['duration'] as int),
+        naive: DateTime.parse(json['naive'] as String),
+      );
+
+  Map<String, dynamic> toJson() => {
+        'utc': utc.toIso8601String(),
+        'local': local.toIso8601String(),
+        'duration': duration.inMicroseconds,
+        'naive': naive.toIso8601String(),
+      };
 }
 
 class FeatureUuid {
   final UuidValue one;
   final List<UuidValue> many;
+
   FeatureUuid({
     required this.one,
     required this.many,
   });
+
+  FeatureUuid copyWith({
+    UuidValue? one,
+    List<UuidValue>? many,
+  }) =>
+      FeatureUuid(
+        one: one ?? this.one,
+        many: many ?? this.many,
+      );
+
+  factory FeatureUuid.fromJson(Map<String, dynamic> json) => FeatureUuid(
+        one: UuidValue(json['one'] as String),
+        many: (json['many'] as List<dynamic>).map((v0) => UuidValue(v0 as String)).toList(),
+      );
+
+  Map<String, dynamic> toJson() => {
+        'one': one.uuid,
+        'many': many.map((v0) => v0.uuid).toList(),
+      };
+}
+
+class FeedId {
+  final U8Array8 field0;
+
+  FeedId({
+    required this.field0,
+  });
+
+  FeedId copyWith({
+    U8Array8? field0,
+  }) =>
+      FeedId(
+        field0: field0 ?? this.field0,
+      );
+
+  factory FeedId.fromJson(Map<String, dynamic> json) => FeedId(
+        field0: U8Array8(base64Decode(json['field0'] as String)),
+      );
+
+  Map<String, dynamic> toJson() => {
+        'field0': base64Encode(field0),
+      };
+}
+
+class Frame {
+  final int index;
+  final Uint8List pixels;
+  final Float32List? depths;
+
+  Frame({
+    required this.index,
+    required this.pixels,
+    this.depths,
+  });
+
+  Frame copyWith({
+    int? index,
+    Uint8List? pixels,
+    Object? depths = copyWithUnset,
+  }) =>
+      Frame(
+        index: index ?? this.index,
+        pixels: pixels ?? this.pixels,
+        depths: identical(depths, copyWithUnset) ? this.depths : depths as Float32List?,
+      );
+
+  factory Frame.fromJson(Map<String, dynamic> json) => Frame(
+        index: json['index'] as int,
+        pixels: base64Decode(json['pixels'] as String),
+        depths: json['depths'] == null
+            ? null
+            : Float32List.fromList((json['depths'] as List<dynamic>).map((v) => (v as num).toDouble()).toList()),
+      );
+
+  Map<String, dynamic> toJson() => {
+        'index': index,
+        'pixels': base64Encode(pixels),
+        'depths': depths == null ? null : depths!.toList(),
+      };
+}
+
+class GridCell {
+  final int row;
+  final int column;
+
+  const GridCell(
+    this.row,
+    this.column,
+  );
+
+  GridCell copyWith({
+    int? row,
+    int? column,
+  }) =>
+      GridCell(
+        row ?? this.row,
+        column ?? this.column,
+      );
+
+  factory GridCell.fromJson(Map<String, dynamic> json) => GridCell(
+        json['row'] as int,
+        json['column'] as int,
+      );
+
+  Map<String, dynamic> toJson() => {
+        'row': row,
+        'column': column,
+      };
+}
+
+/// Enums with explicit discriminants, which are sent instead of the indices.
+enum HttpStatus {
+  Ok,
+  NotFound,
+  MethodNotAllowed,
+}
+
+extension HttpStatusJson on HttpStatus {
+  String toJson() => name;
 }
 
-class FeedId {
-  final U8Array8 field0;
-  FeedId({
-    required this.field0,
-  });
+extension HttpStatusDiscriminant on HttpStatus {
+  /// The discriminant of the variant in Rust.
+  int get discriminant => const [200, 404, 405][index];
 }
 
 class I32Array2 extends NonGrowableListView<int> {
   static const arraySize = 2;
   I32Array2(Int32List inner)
-      : assert(inner.length == arraySize),
-        super(inner);
+      : super(inner.length == arraySize
+            ? inner
+            : throw ArgumentError.value(inner, 'inner', 'expected length $arraySize but see ${inner.length}'));
   I32Array2.unchecked(Int32List inner) : super(inner);
   I32Array2.init() : super(Int32List(arraySize));
 }
@@ -1135,24 +3009,148 @@ class KitchenSink with _$KitchenSink {
   const factory KitchenSink.enums(
     Weekdays field0,
   ) = KitchenSink_Enums;
+
+  const KitchenSink._();
+
+  static KitchenSink fromJson(Map<String, dynamic> json) {
+    final content = json['content'];
+    switch (json['tag']) {
+      case 'Empty':
+        return KitchenSink.empty();
+      case 'Primitives':
+        return KitchenSink.primitives(
+          int32: content['int32'] as int,
+          float64: (content['float64'] as num).toDouble(),
+          boolean: content['boolean'] as bool,
+        );
+      case 'Nested':
+        return KitchenSink.nested(
+          KitchenSink.fromJson(content['field0'] as Map<String, dynamic>),
+          content['field1'] as int,
+        );
+      case 'Optional':
+        return KitchenSink.optional(
+          content['field0'] == null ? null : content['field0'] as int,
+          content['field1'] == null ? null : content['field1'] as int,
+        );
+      case 'Buffer':
+        return KitchenSink.buffer(
+          base64Decode(content['field0'] as String),
+        );
+      case 'Enums':
+        return KitchenSink.enums(
+          Weekdays.values.byName(content['field0'] as String),
+        );
+      default:
+        throw FormatException('Unknown variant of KitchenSink', json['tag']);
+    }
+  }
+
+  Map<String, dynamic> toJson() {
+    final self = this;
+    if (self is KitchenSink_Empty)
+      return {
+        'tag': 'Empty',
+      };
+    if (self is KitchenSink_Primitives)
+      return {
+        'tag': 'Primitives',
+        'content': {
+          'int32': self.int32,
+          'float64': self.float64,
+          'boolean': self.boolean,
+        },
+      };
+    if (self is KitchenSink_Nested)
+      return {
+        'tag': 'Nested',
+        'content': {
+          'field0': self.field0.toJson(),
+          'field1': self.field1,
+        },
+      };
+    if (self is KitchenSink_Optional)
+      return {
+        'tag': 'Optional',
+        'content': {
+          'field0': self.field0 == null ? null : self.field0!,
+          'field1': self.field1 == null ? null : self.field1!,
+        },
+      };
+    if (self is KitchenSink_Buffer)
+      return {
+        'tag': 'Buffer',
+        'content': {
+          'field0': base64Encode(self.field0),
+        },
+      };
+    if (self is KitchenSink_Enums)
+      return {
+        'tag': 'Enums',
+        'content': {
+          'field0': self.field0.name,
+        },
+      };
+    throw StateError('unreachable');
+  }
 }
 
 class Log {
   final int key;
   final int value;
-  Log({
+
+  const Log({
     required this.key,
     required this.value,
   });
+
+  Log copyWith({
+    int? key,
+    int? value,
+  }) =>
+      Log(
+        key: key ?? this.key,
+        value: value ?? this.value,
+      );
+
+  factory Log.fromJson(Map<String, dynamic> json) => Log(
+        key: json['key'] as int,
+        value: json['value'] as int,
+      );
+
+  Map<String, dynamic> toJson() => {
+        'key': key,
+        'value': value,
+      };
 }
 
 class Log2 {
   final int key;
   final String value;
-  Log2({
+
+  const Log2({
     required this.key,
     required this.value,
   });
+
+  Log2 copyWith({
+    int? key,
+    String? value,
+  }) =>
+      Log2(
+        key: key ?? this.key,
+        value: value ?? this.value,
+      );
+
+  factory Log2.fromJson(Map<String, dynamic> json) => Log2(
+        key: json['key'] as int,
+        value: json['value'] as String,
+      );
+
+  Map<String, dynamic> toJson() => {
+        'key': key,
+        'value': value,
+      };
 }
 
 @freezed
@@ -1163,13 +3161,110 @@ class Measure with _$Measure {
   const factory Measure.distance(
     Distance field0,
   ) = Measure_Distance;
+
+  const Measure._();
+
+  static Measure fromJson(Map<String, dynamic> json) {
+    final content = json['content'];
+    switch (json['tag']) {
+      case 'Speed':
+        return Measure.speed(
+          Speed.fromJson(content['field0'] as Map<String, dynamic>),
+        );
+      case 'Distance':
+        return Measure.distance(
+          Distance.fromJson(content['field0'] as Map<String, dynamic>),
+        );
+      default:
+        throw FormatException('Unknown variant of Measure', json['tag']);
+    }
+  }
+
+  Map<String, dynamic> toJson() {
+    final self = this;
+    if (self is Measure_Speed)
+      return {
+        'tag': 'Speed',
+        'content': {
+          'field0': self.field0.toJson(),
+        },
+      };
+    if (self is Measure_Distance)
+      return {
+        'tag': 'Distance',
+        'content': {
+          'field0': self.field0.toJson(),
+        },
+      };
+    throw StateError('unreachable');
+  }
 }
 
 class MessageId {
   final U8Array32 field0;
+
   MessageId({
     required this.field0,
   });
+
+  MessageId copyWith({
+    U8Array32? field0,
+  }) =>
+      MessageId(
+        field0: field0 ?? this.field0,
+      );
+
+  factory MessageId.fromJson(Map<String, dynamic> json) => MessageId(
+        field0: U8Array32(base64Decode(json['field0'] as String)),
+      );
+
+  Map<String, dynamic> toJson() => {
+        'field0': base64Encode(field0),
+      };
+}
+
+class Metadata {
+  final int id;
+  final String createdBy;
+
+  const Metadata({
+    required this.id,
+    required this.createdBy,
+  });
+
+  Metadata copyWith({
+    int? id,
+    String? createdBy,
+  }) =>
+      Metadata(
+        id: id ?? this.id,
+        createdBy: createdBy ?? this.createdBy,
+      );
+
+  factory Metadata.fromJson(Map<String, dynamic> json) => Metadata(
+        id: json['id'] as int,
+        createdBy: json['created_by'] as String,
+      );
+
+  Map<String, dynamic> toJson() => {
+        'id': id,
+        'created_by': createdBy,
+      };
+}
+
+class MoneyRaw {
+  final int cents;
+
+  const MoneyRaw({
+    required this.cents,
+  });
+
+  MoneyRaw copyWith({
+    int? cents,
+  }) =>
+      MoneyRaw(
+        cents: cents ?? this.cents,
+      );
 }
 
 enum MyEnum {
@@ -1177,36 +3272,112 @@ enum MyEnum {
   True,
 }
 
+extension MyEnumJson on MyEnum {
+  String toJson() => name;
+}
+
 class MyNestedStruct {
   final MyTreeNode treeNode;
   final Weekdays weekday;
+
   MyNestedStruct({
     required this.treeNode,
     required this.weekday,
   });
+
+  MyNestedStruct copyWith({
+    MyTreeNode? treeNode,
+    Weekdays? weekday,
+  }) =>
+      MyNestedStruct(
+        treeNode: treeNode ?? this.treeNode,
+        weekday: weekday ?? this.weekday,
+      );
+
+  factory MyNestedStruct.fromJson(Map<String, dynamic> json) => MyNestedStruct(
+        treeNode: MyTreeNode.fromJson(json['tree_node'] as Map<String, dynamic>),
+        weekday: Weekdays.values.byName(json['weekday'] as String),
+      );
+
+  Map<String, dynamic> toJson() => {
+        'tree_node': treeNode.toJson(),
+        'weekday': weekday.name,
+      };
 }
 
 class MySize {
   final int width;
   final int height;
-  MySize({
+
+  const MySize({
     required this.width,
     required this.height,
   });
+
+  MySize copyWith({
+    int? width,
+    int? height,
+  }) =>
+      MySize(
+        width: width ?? this.width,
+        height: height ?? this.height,
+      );
+
+  factory MySize.fromJson(Map<String, dynamic> json) => MySize(
+        width: json['width'] as int,
+        height: json['height'] as int,
+      );
+
+  Map<String, dynamic> toJson() => {
+        'width': width,
+        'height': height,
+      };
 }
 
 class MyStreamEntry {
   final String hello;
-  MyStreamEntry({
+
+  const MyStreamEntry({
     required this.hello,
   });
+
+  MyStreamEntry copyWith({
+    String? hello,
+  }) =>
+      MyStreamEntry(
+        hello: hello ?? this.hello,
+      );
+
+  factory MyStreamEntry.fromJson(Map<String, dynamic> json) => MyStreamEntry(
+        hello: json['hello'] as String,
+      );
+
+  Map<String, dynamic> toJson() => {
+        'hello': hello,
+      };
 }
 
 class MyStruct {
   final bool content;
-  MyStruct({
+
+  const MyStruct({
     required this.content,
   });
+
+  MyStruct copyWith({
+    bool? content,
+  }) =>
+      MyStruct(
+        content: content ?? this.content,
+      );
+
+  factory MyStruct.fromJson(Map<String, dynamic> json) => MyStruct(
+        content: json['content'] as bool,
+      );
+
+  Map<String, dynamic> toJson() => {
+        'content': content,
+      };
 }
 
 class MyTreeNode {
@@ -1214,75 +3385,496 @@ class MyTreeNode {
   final Uint8List valueVecU8;
   final bool valueBoolean;
   final List<MyTreeNode> children;
+
   MyTreeNode({
     required this.valueI32,
     required this.valueVecU8,
     required this.valueBoolean,
     required this.children,
   });
+
+  MyTreeNode copyWith({
+    int? valueI32,
+    Uint8List? valueVecU8,
+    bool? valueBoolean,
+    List<MyTreeNode>? children,
+  }) =>
+      MyTreeNode(
+        valueI32: valueI32 ?? this.valueI32,
+        valueVecU8: valueVecU8 ?? this.valueVecU8,
+        valueBoolean: valueBoolean ?? this.valueBoolean,
+        children: children ?? this.children,
+      );
+
+  factory MyTreeNode.fromJson(Map<String, dynamic> json) => MyTreeNode(
+        valueI32: json['value_i32'] as int,
+        valueVecU8: base64Decode(json['value_vec_u8'] as String),
+        valueBoolean: json['value_boolean'] as bool,
+        children:
+            (json['children'] as List<dynamic>).map((v0) => MyTreeNode.fromJson(v0 as Map<String, dynamic>)).toList(),
+      );
+
+  Map<String, dynamic> toJson() => {
+        'value_i32': valueI32,
+        'value_vec_u8': base64Encode(valueVecU8),
+        'value_boolean': valueBoolean,
+        'children': children.map((v0) => v0.toJson()).toList(),
+      };
 }
 
 class NewSimpleStruct {
   final int field;
-  NewSimpleStruct({
+
+  const NewSimpleStruct({
     required this.field,
   });
+
+  NewSimpleStruct copyWith({
+    int? field,
+  }) =>
+      NewSimpleStruct(
+        field: field ?? this.field,
+      );
+
+  factory NewSimpleStruct.fromJson(Map<String, dynamic> json) => NewSimpleStruct(
+        field: json['field'] as int,
+      );
+
+  Map<String, dynamic> toJson() => {
+        'field': field,
+      };
 }
 
 class NewTypeInt {
   final int field0;
-  NewTypeInt({
+
+  const NewTypeInt({
     required this.field0,
   });
+
+  NewTypeInt copyWith({
+    int? field0,
+  }) =>
+      NewTypeInt(
+        field0: field0 ?? this.field0,
+      );
+
+  factory NewTypeInt.fromJson(Map<String, dynamic> json) => NewTypeInt(
+        field0: json['field0'] as int,
+      );
+
+  Map<String, dynamic> toJson() => {
+        'field0': field0,
+      };
 }
 
 class Note {
   final Weekdays day;
   final String body;
-  Note({
+
+  const Note({
     required this.day,
     required this.body,
   });
+
+  Note copyWith({
+    Weekdays? day,
+    String? body,
+  }) =>
+      Note(
+        day: day ?? this.day,
+        body: body ?? this.body,
+      );
+
+  factory Note.fromJson(Map<String, dynamic> json) => Note(
+        day: Weekdays.values.byName(json['day'] as String),
+        body: json['body'] as String,
+      );
+
+  Map<String, dynamic> toJson() => {
+        'day': day.name,
+        'body': body,
+      };
 }
 
 class Numbers {
   final Int32List field0;
+
   Numbers({
     required this.field0,
   });
+
+  Numbers copyWith({
+    Int32List? field0,
+  }) =>
+      Numbers(
+        field0: field0 ?? this.field0,
+      );
+
+  factory Numbers.fromJson(Map<String, dynamic> json) => Numbers(
+        field0: Int32List.fromList((json['field0'] as List<dynamic>).cast<int>()),
+      );
+
+  Map<String, dynamic> toJson() => {
+        'field0': field0.toList(),
+      };
 }
 
 class OldSimpleStruct {
   final int field;
-  OldSimpleStruct({
+
+  const OldSimpleStruct({
     required this.field,
   });
+
+  OldSimpleStruct copyWith({
+    int? field,
+  }) =>
+      OldSimpleStruct(
+        field: field ?? this.field,
+      );
+
+  factory OldSimpleStruct.fromJson(Map<String, dynamic> json) => OldSimpleStruct(
+        field: json['field'] as int,
+      );
+
+  Map<String, dynamic> toJson() => {
+        'field': field,
+      };
 }
 
 /// [`HideData`] has private fields.
 class OpaqueNested {
   final HideData first;
   final HideData second;
+
   OpaqueNested({
     required this.first,
     required this.second,
   });
+
+  OpaqueNested copyWith({
+    HideData? first,
+    HideData? second,
+  }) =>
+      OpaqueNested(
+        first: first ?? this.first,
+        second: second ?? this.second,
+      );
+}
+
+enum Pace {
+  fast,
+  Slow,
+}
+
+extension PaceJson on Pace {
+  String toJson() => name;
+}
+
+class PairI32 {
+  final int first;
+  final Int32List second;
+
+  PairI32({
+    required this.first,
+    required this.second,
+  });
+
+  PairI32 copyWith({
+    int? first,
+    Int32List? second,
+  }) =>
+      PairI32(
+        first: first ?? this.first,
+        second: second ?? this.second,
+      );
+
+  factory PairI32.fromJson(Map<String, dynamic> json) => PairI32(
+        first: json['first'] as int,
+        second: Int32List.fromList((json['second'] as List<dynamic>).cast<int>()),
+      );
+
+  Map<String, dynamic> toJson() => {
+        'first': first,
+        'second': second.toList(),
+      };
+}
+
+class PairString {
+  final String first;
+  final List<String> second;
+
+  const PairString({
+    required this.first,
+    required this.second,
+  });
+
+  PairString copyWith({
+    String? first,
+    List<String>? second,
+  }) =>
+      PairString(
+        first: first ?? this.first,
+        second: second ?? this.second,
+      );
+
+  factory PairString.fromJson(Map<String, dynamic> json) => PairString(
+        first: json['first'] as String,
+        second: (json['second'] as List<dynamic>).map((v0) => v0 as String).toList(),
+      );
+
+  Map<String, dynamic> toJson() => {
+        'first': first,
+        'second': second.map((v0) => v0).toList(),
+      };
+}
+
+@freezed
+class ParseError with _$ParseError {
+  const factory ParseError.empty() = ParseError_Empty;
+  const factory ParseError.notANumber({
+    required String line,
+  }) = ParseError_NotANumber;
+
+  const ParseError._();
+
+  static ParseError fromJson(Map<String, dynamic> json) {
+    final content = json['content'];
+    switch (json['tag']) {
+      case 'Empty':
+        return ParseError.empty();
+      case 'NotANumber':
+        return ParseError.notANumber(
+          line: content['line'] as String,
+        );
+      default:
+        throw FormatException('Unknown variant of ParseError', json['tag']);
+    }
+  }
+
+  Map<String, dynamic> toJson() {
+    final self = this;
+    if (self is ParseError_Empty)
+      return {
+        'tag': 'Empty',
+      };
+    if (self is ParseError_NotANumber)
+      return {
+        'tag': 'NotANumber',
+        'content': {
+          'line': self.line,
+        },
+      };
+    throw StateError('unreachable');
+  }
 }
 
 class Point {
   final double x;
   final double y;
-  Point({
+
+  const Point({
+    required this.x,
+    required this.y,
+  });
+
+  Point copyWith({
+    double? x,
+    double? y,
+  }) =>
+      Point(
+        x: x ?? this.x,
+        y: y ?? this.y,
+      );
+
+  factory Point.fromJson(Map<String, dynamic> json) => Point(
+        x: (json['x'] as num).toDouble(),
+        y: (json['y'] as num).toDouble(),
+      );
+
+  Map<String, dynamic> toJson() => {
+        'x': x,
+        'y': y,
+      };
+}
+
+class ProductId {
+  final int field0;
+
+  const ProductId(
+    this.field0,
+  );
+
+  ProductId copyWith({
+    int? field0,
+  }) =>
+      ProductId(
+        field0 ?? this.field0,
+      );
+
+  factory ProductId.fromJson(Map<String, dynamic> json) => ProductId(
+        json['field0'] as int,
+      );
+
+  Map<String, dynamic> toJson() => {
+        'field0': field0,
+      };
+}
+
+class Progress {
+  final int percentage;
+
+  Progress({
+    required this.percentage,
+  }) : assert(percentage <= 100, 'Invalid Progress: percentage <= 100');
+
+  Progress copyWith({
+    int? percentage,
+  }) =>
+      Progress(
+        percentage: percentage ?? this.percentage,
+      );
+
+  factory Progress.fromJson(Map<String, dynamic> json) => Progress(
+        percentage: json['percentage'] as int,
+      );
+
+  Map<String, dynamic> toJson() => {
+        'percentage': percentage,
+      };
+}
+
+class Renamed {
+  final int identifier;
+  final Pace pace;
+
+  const Renamed({
+    required this.identifier,
+    required this.pace,
+  });
+
+  Renamed copyWith({
+    int? identifier,
+    Pace? pace,
+  }) =>
+      Renamed(
+        identifier: identifier ?? this.identifier,
+        pace: pace ?? this.pace,
+      );
+
+  factory Renamed.fromJson(Map<String, dynamic> json) => Renamed(
+        identifier: json['id'] as int,
+        pace: Pace.values.byName(json['pace'] as String),
+      );
+
+  Map<String, dynamic> toJson() => {
+        'id': identifier,
+        'pace': pace.name,
+      };
+}
+
+/// Sent to Dart as one block of bytes.
+class Sample {
+  final int index;
+  final double x;
+  final double y;
+  final bool valid;
+  final int level;
+
+  const Sample({
+    required this.index,
     required this.x,
     required this.y,
+    required this.valid,
+    required this.level,
   });
+
+  Sample copyWith({
+    int? index,
+    double? x,
+    double? y,
+    bool? valid,
+    int? level,
+  }) =>
+      Sample(
+        index: index ?? this.index,
+        x: x ?? this.x,
+        y: y ?? this.y,
+        valid: valid ?? this.valid,
+        level: level ?? this.level,
+      );
+
+  factory Sample.fromJson(Map<String, dynamic> json) => Sample(
+        index: json['index'] as int,
+        x: (json['x'] as num).toDouble(),
+        y: (json['y'] as num).toDouble(),
+        valid: json['valid'] as bool,
+        level: json['level'] as int,
+      );
+
+  Map<String, dynamic> toJson() => {
+        'index': index,
+        'x': x,
+        'y': y,
+        'valid': valid,
+        'level': level,
+      };
 }
 
 class Sequences {
   final Int32List field0;
+
   Sequences({
     required this.field0,
   });
+
+  Sequences copyWith({
+    Int32List? field0,
+  }) =>
+      Sequences(
+        field0: field0 ?? this.field0,
+      );
+
+  factory Sequences.fromJson(Map<String, dynamic> json) => Sequences(
+        field0: Int32List.fromList((json['field0'] as List<dynamic>).cast<int>()),
+      );
+
+  Map<String, dynamic> toJson() => {
+        'field0': field0.toList(),
+      };
+}
+
+class Session {
+  final String user;
+
+  const Session({
+    required this.user,
+  });
+
+  Session copyWith({
+    String? user,
+  }) =>
+      Session(
+        user: user ?? this.user,
+      );
+
+  factory Session.fromJson(Map<String, dynamic> json) => Session(
+        user: json['user'] as String,
+      );
+
+  Map<String, dynamic> toJson() => {
+        'user': user,
+      };
+}
+
+enum SettingError {
+  NotFound,
+  Invalid,
+}
+
+extension SettingErrorJson on SettingError {
+  String toJson() => name;
 }
 
 @freezed
@@ -1291,14 +3883,47 @@ class Speed with _$Speed {
   const factory Speed.gps(
     double field0,
   ) = Speed_GPS;
+
+  const Speed._();
+
+  static Speed fromJson(Map<String, dynamic> json) {
+    final content = json['content'];
+    switch (json['tag']) {
+      case 'Unknown':
+        return Speed.unknown();
+      case 'GPS':
+        return Speed.gps(
+          (content['field0'] as num).toDouble(),
+        );
+      default:
+        throw FormatException('Unknown variant of Speed', json['tag']);
+    }
+  }
+
+  Map<String, dynamic> toJson() {
+    final self = this;
+    if (self is Speed_Unknown)
+      return {
+        'tag': 'Unknown',
+      };
+    if (self is Speed_GPS)
+      return {
+        'tag': 'GPS',
+        'content': {
+          'field0': self.field0,
+        },
+      };
+    throw StateError('unreachable');
+  }
 }
 
 class SumWith {
   final FlutterRustBridgeExampleSingleBlockTest bridge;
   final int x;
-  SumWith({
-    required this.bridge,
+
+  const SumWith({
     required this.x,
+    required this.bridge,
   });
 
   Future<int> sum({required int y, required int z, dynamic hint}) => bridge.sumMethodSumWith(
@@ -1306,24 +3931,114 @@ class SumWith {
         y: y,
         z: z,
       );
+
+  SumWith copyWith({
+    int? x,
+  }) =>
+      SumWith(
+        x: x ?? this.x,
+        bridge: bridge,
+      );
+}
+
+/// All its fields have constant Dart values, so its Dart constructor is `const`.
+class Tag {
+  final String name;
+  final int? color;
+  final List<String> aliases;
+  final Weekdays day;
+
+  const Tag({
+    required this.name,
+    this.color,
+    required this.aliases,
+    required this.day,
+  });
+
+  Tag copyWith({
+    String? name,
+    Object? color = copyWithUnset,
+    List<String>? aliases,
+    Weekdays? day,
+  }) =>
+      Tag(
+        name: name ?? this.name,
+        color: identical(color, copyWithUnset) ? this.color : color as int?,
+        aliases: aliases ?? this.aliases,
+        day: day ?? this.day,
+      );
+
+  factory Tag.fromJson(Map<String, dynamic> json) => Tag(
+        name: json['name'] as String,
+        color: json['color'] == null ? null : json['color'] as int,
+        aliases: (json['aliases'] as List<dynamic>).cast<String>(),
+        day: Weekdays.values.byName(json['day'] as String),
+      );
+
+  Map<String, dynamic> toJson() => {
+        'name': name,
+        'color': color == null ? null : color!,
+        'aliases': aliases,
+        'day': day.name,
+      };
 }
 
 class TestChrono {
   final DateTime? dt;
   final DateTime? dt2;
   final Duration? du;
+
   TestChrono({
     this.dt,
     this.dt2,
     this.du,
   });
+
+  TestChrono copyWith({
+    Object? dt = copyWithUnset,
+    Object? dt2 = copyWithUnset,
+    Object? du = copyWithUnset,
+  }) =>
+      TestChrono(
+        dt: identical(dt, copyWithUnset) ? this.dt : dt as DateTime?,
+        dt2: identical(dt2, copyWithUnset) ? this.dt2 : dt2 as DateTime?,
+        du: identical(du, copyWithUnset) ? this.du : du as Duration?,
+      );
+
+  factory TestChrono.fromJson(Map<String, dynamic> json) => TestChrono(
+        dt: json['dt'] == null ? null : DateTime.parse(json['dt'] as String),
+        dt2: json['dt2'] == null ? null : DateTime.parse(json['dt2'] as String),
+        du: json['du'] == null ? null : Duration(microseconds: json['du'] as int),
+      );
+
+  Map<String, dynamic> toJson() => {
+        'dt': dt == null ? null : dt!.toIso8601String(),
+        'dt2': dt2 == null ? null : dt2!.toIso8601String(),
+        'du': du == null ? null : du!.inMicroseconds,
+      };
 }
 
 class TestId {
   final I32Array2 field0;
+
   TestId({
     required this.field0,
   });
+
+  TestId copyWith({
+    I32Array2? field0,
+  }) =>
+      TestId(
+        field0: field0 ?? this.field0,
+      );
+
+  factory TestId.fromJson(Map<String, dynamic> json) => TestId(
+        field0: I32Array2(Int32List.fromList((json['field0'] as List<dynamic>).cast<int>())),
+      );
+
+  Map<String, dynamic> toJson() => {
+        'field0': field0.toList(),
+      };
 }
 
 class TestModel {
@@ -1331,19 +4046,48 @@ class TestModel {
   final String name;
   final MyEnum aliasEnum;
   final MyStruct aliasStruct;
-  TestModel({
+
+  const TestModel({
     required this.id,
     required this.name,
     required this.aliasEnum,
     required this.aliasStruct,
   });
+
+  TestModel copyWith({
+    int? id,
+    String? name,
+    MyEnum? aliasEnum,
+    MyStruct? aliasStruct,
+  }) =>
+      TestModel(
+        id: id ?? this.id,
+        name: name ?? this.name,
+        aliasEnum: aliasEnum ?? this.aliasEnum,
+        aliasStruct: aliasStruct ?? this.aliasStruct,
+      );
+
+  factory TestModel.fromJson(Map<String, dynamic> json) => TestModel(
+        id: json['id'] as int,
+        name: json['name'] as String,
+        aliasEnum: MyEnum.values.byName(json['alias_enum'] as String),
+        aliasStruct: MyStruct.fromJson(json['alias_struct'] as Map<String, dynamic>),
+      );
+
+  Map<String, dynamic> toJson() => {
+        'id': id,
+        'name': name,
+        'alias_enum': aliasEnum.name,
+        'alias_struct': aliasStruct.toJson(),
+      };
 }
 
 class U8Array1600 extends NonGrowableListView<int> {
   static const arraySize = 1600;
   U8Array1600(Uint8List inner)
-      : assert(inner.length == arraySize),
-        super(inner);
+      : super(inner.length == arraySize
+            ? inner
+            : throw ArgumentError.value(inner, 'inner', 'expected length $arraySize but see ${inner.length}'));
   U8Array1600.unchecked(Uint8List inner) : super(inner);
   U8Array1600.init() : super(Uint8List(arraySize));
 }
@@ -1351,17 +4095,29 @@ class U8Array1600 extends NonGrowableListView<int> {
 class U8Array32 extends NonGrowableListView<int> {
   static const arraySize = 32;
   U8Array32(Uint8List inner)
-      : assert(inner.length == arraySize),
-        super(inner);
+      : super(inner.length == arraySize
+            ? inner
+            : throw ArgumentError.value(inner, 'inner', 'expected length $arraySize but see ${inner.length}'));
   U8Array32.unchecked(Uint8List inner) : super(inner);
   U8Array32.init() : super(Uint8List(arraySize));
 }
 
+class U8Array4 extends NonGrowableListView<int> {
+  static const arraySize = 4;
+  U8Array4(Uint8List inner)
+      : super(inner.length == arraySize
+            ? inner
+            : throw ArgumentError.value(inner, 'inner', 'expected length $arraySize but see ${inner.length}'));
+  U8Array4.unchecked(Uint8List inner) : super(inner);
+  U8Array4.init() : super(Uint8List(arraySize));
+}
+
 class U8Array5 extends NonGrowableListView<int> {
   static const arraySize = 5;
   U8Array5(Uint8List inner)
-      : assert(inner.length == arraySize),
-        super(inner);
+      : super(inner.length == arraySize
+            ? inner
+            : throw ArgumentError.value(inner, 'inner', 'expected length $arraySize but see ${inner.length}'));
   U8Array5.unchecked(Uint8List inner) : super(inner);
   U8Array5.init() : super(Uint8List(arraySize));
 }
@@ -1369,8 +4125,9 @@ class U8Array5 extends NonGrowableListView<int> {
 class U8Array8 extends NonGrowableListView<int> {
   static const arraySize = 8;
   U8Array8(Uint8List inner)
-      : assert(inner.length == arraySize),
-        super(inner);
+      : super(inner.length == arraySize
+            ? inner
+            : throw ArgumentError.value(inner, 'inner', 'expected length $arraySize but see ${inner.length}'));
   U8Array8.unchecked(Uint8List inner) : super(inner);
   U8Array8.init() : super(Uint8List(arraySize));
 }
@@ -1382,6 +4139,15 @@ class UserId with _$UserId {
   const factory UserId({
     required int value,
   }) = _UserId;
+  const UserId._();
+
+  static UserId fromJson(Map<String, dynamic> json) => UserId(
+        value: json['value'] as int,
+      );
+
+  Map<String, dynamic> toJson() => {
+        'value': value,
+      };
 }
 
 class VecOfPrimitivePack {
@@ -1395,6 +4161,7 @@ class VecOfPrimitivePack {
   final Int64List int64List;
   final Float32List float32List;
   final Float64List float64List;
+
   VecOfPrimitivePack({
     required this.int8List,
     required this.uint8List,
@@ -1407,6 +4174,31 @@ class VecOfPrimitivePack {
     required this.float32List,
     required this.float64List,
   });
+
+  VecOfPrimitivePack copyWith({
+    Int8List? int8List,
+    Uint8List? uint8List,
+    Int16List? int16List,
+    Uint16List? uint16List,
+    Uint32List? uint32List,
+    Int32List? int32List,
+    Uint64List? uint64List,
+    Int64List? int64List,
+    Float32List? float32List,
+    Float64List? float64List,
+  }) =>
+      VecOfPrimitivePack(
+        int8List: int8List ?? this.int8List,
+        uint8List: uint8List ?? this.uint8List,
+        int16List: int16List ?? this.int16List,
+        uint16List: uint16List ?? this.uint16List,
+        uint32List: uint32List ?? this.uint32List,
+        int32List: int32List ?? this.int32List,
+        uint64List: uint64List ?? this.uint64List,
+        int64List: int64List ?? this.int64List,
+        float32List: float32List ?? this.float32List,
+        float64List: float64List ?? this.float64List,
+      );
 }
 
 /// Simple enums.
@@ -1422,6 +4214,10 @@ enum Weekdays {
   Sunday,
 }
 
+extension WeekdaysJson on Weekdays {
+  String toJson() => name;
+}
+
 class ZeroCopyVecOfPrimitivePack {
   final Int8List int8List;
   final Uint8List uint8List;
@@ -1433,6 +4229,7 @@ class ZeroCopyVecOfPrimitivePack {
   final Int64List int64List;
   final Float32List float32List;
   final Float64List float64List;
+
   ZeroCopyVecOfPrimitivePack({
     required this.int8List,
     required this.uint8List,
@@ -1445,4 +4242,36 @@ class ZeroCopyVecOfPrimitivePack {
     required this.float32List,
     required this.float64List,
   });
+
+  ZeroCopyVecOfPrimitivePack copyWith({
+    Int8List? int8List,
+    Uint8List? uint8List,
+    Int16List? int16List,
+    Uint16List? uint16List,
+    Uint32List? uint32List,
+    Int32List? int32List,
+    Uint64List? uint64List,
+    Int64List? int64List,
+    Float32List? float32List,
+    Float64List? float64List,
+  }) =>
+      ZeroCopyVecOfPrimitivePack(
+        int8List: int8List ?? this.int8List,
+        uint8List: uint8List ?? this.uint8List,
+        int16List: int16List ?? this.int16List,
+        uint16List: uint16List ?? this.uint16List,
+        uint32List: uint32List ?? this.uint32List,
+        int32List: int32List ?? this.int32List,
+        uint64List: uint64List ?? this.uint64List,
+        int64List: int64List ?? this.int64List,
+        float32List: float32List ?? this.float32List,
+        float64List: float64List ?? this.float64List,
+      );
+}
+
+/// Implemented by opaque types, so that Dart can use their handles alike.
+abstract class Describe {
+  Future<String> describe({dynamic hint});
+
+  Future<String> shout({dynamic hint});
 }
@@ -112,8 +112,8 @@ class __$$ApplicationMessage_DisplayMessageCopyWithImpl<$Res>
 
 /// @nodoc
 
-class _$ApplicationMessage_DisplayMessage implements ApplicationMessage_DisplayMessage {
-  const _$ApplicationMessage_DisplayMessage(this.field0);
+class _$ApplicationMessage_DisplayMessage extends ApplicationMessage_DisplayMessage {
+  const _$ApplicationMessage_DisplayMessage(this.field0) : super._();
 
   @override
   final String field0;
@@ -209,8 +209,9 @@ class _$ApplicationMessage_DisplayMessage implements ApplicationMessage_DisplayM
   }
 }
 
-abstract class ApplicationMessage_DisplayMessage implements ApplicationMessage {
+abstract class ApplicationMessage_DisplayMessage extends ApplicationMessage {
   const factory ApplicationMessage_DisplayMessage(final String field0) = _$ApplicationMessage_DisplayMessage;
+  const ApplicationMessage_DisplayMessage._() : super._();
 
   String get field0;
   @JsonKey(ignore: true)
@@ -256,8 +257,8 @@ class __$$ApplicationMessage_RenderPixelCopyWithImpl<$Res>
 
 /// @nodoc
 
-class _$ApplicationMessage_RenderPixel implements ApplicationMessage_RenderPixel {
-  const _$ApplicationMessage_RenderPixel({required this.x, required this.y});
+class _$ApplicationMessage_RenderPixel extends ApplicationMessage_RenderPixel {
+  const _$ApplicationMessage_RenderPixel({required this.x, required this.y}) : super._();
 
   @override
   final int x;
@@ -356,9 +357,10 @@ class _$ApplicationMessage_RenderPixel implements ApplicationMessage_RenderPixel
   }
 }
 
-abstract class ApplicationMessage_RenderPixel implements ApplicationMessage {
+abstract class ApplicationMessage_RenderPixel extends ApplicationMessage {
   const factory ApplicationMessage_RenderPixel({required final int x, required final int y}) =
       _$ApplicationMessage_RenderPixel;
+  const ApplicationMessage_RenderPixel._() : super._();
 
   int get x;
   int get y;
@@ -385,8 +387,8 @@ class __$$ApplicationMessage_ExitCopyWithImpl<$Res>
 
 /// @nodoc
 
-class _$ApplicationMessage_Exit implements ApplicationMessage_Exit {
-  const _$ApplicationMessage_Exit();
+class _$ApplicationMessage_Exit extends ApplicationMessage_Exit {
+  const _$ApplicationMessage_Exit() : super._();
 
   @override
   String toString() {
@@ -470,8 +472,336 @@ class _$ApplicationMessage_Exit implements ApplicationMessage_Exit {
   }
 }
 
-abstract class ApplicationMessage_Exit implements ApplicationMessage {
+abstract class ApplicationMessage_Exit extends ApplicationMessage {
   const factory ApplicationMessage_Exit() = _$ApplicationMessage_Exit;
+  const ApplicationMessage_Exit._() : super._();
+}
+
+/// @nodoc
+mixin _$CustomError {
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(String message, String backtrace) fail,
+    required TResult Function(int afterMs) retry,
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(String message, String backtrace)? fail,
+    TResult? Function(int afterMs)? retry,
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(String message, String backtrace)? fail,
+    TResult Function(int afterMs)? retry,
+    required TResult orElse(),
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(CustomError_Fail value) fail,
+    required TResult Function(CustomError_Retry value) retry,
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(CustomError_Fail value)? fail,
+    TResult? Function(CustomError_Retry value)? retry,
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(CustomError_Fail value)? fail,
+    TResult Function(CustomError_Retry value)? retry,
+    required TResult orElse(),
+  }) =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class $CustomErrorCopyWith<$Res> {
+  factory $CustomErrorCopyWith(CustomError value, $Res Function(CustomError) then) =
+      _$CustomErrorCopyWithImpl<$Res, CustomError>;
+}
+
+/// @nodoc
+class _$CustomErrorCopyWithImpl<$Res, $Val extends CustomError> implements $CustomErrorCopyWith<$Res> {
+  _$CustomErrorCopyWithImpl(this._value, this._then);
+
+  // ignore: unused_field
+  final $Val _value;
+  // ignore: unused_field
+  final $Res Function($Val) _then;
+}
+
+/// @nodoc
+abstract class _$$CustomError_FailCopyWith<$Res> {
+  factory _$$CustomError_FailCopyWith(_$CustomError_Fail value, $Res Function(_$CustomError_Fail) then) =
+      __$$CustomError_FailCopyWithImpl<$Res>;
+  @useResult
+  $Res call({String message, String backtrace});
+}
+
+/// @nodoc
+class __$$CustomError_FailCopyWithImpl<$Res> extends _$CustomErrorCopyWithImpl<$Res, _$CustomError_Fail>
+    implements _$$CustomError_FailCopyWith<$Res> {
+  __$$CustomError_FailCopyWithImpl(_$CustomError_Fail _value, $Res Function(_$CustomError_Fail) _then)
+      : super(_value, _then);
+
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? message = null,
+    Object? backtrace = null,
+  }) {
+    return _then(_$CustomError_Fail(
+      message: null == message
+          ? _value.message
+          : message // ignore: cast_nullable_to_non_nullable
+              as String,
+      backtrace: null == backtrace
+          ? _value.backtrace
+          : backtrace // ignore: cast_nullable_to_non_nullable
+              as String,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$CustomError_Fail extends CustomError_Fail {
+  const _$CustomError_Fail({required this.message, required this.backtrace}) : super._();
+
+  @override
+  final String message;
+  @override
+  final String backtrace;
+
+  @override
+  String toString() {
+    return 'CustomError.fail(message: $message, backtrace: $backtrace)';
+  }
+
+  @override
+  bool operator ==(dynamic other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$CustomError_Fail &&
+            (identical(other.message, message) || other.message == message) &&
+            (identical(other.backtrace, backtrace) || other.backtrace == backtrace));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, message, backtrace);
+
+  @JsonKey(ignore: true)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$CustomError_FailCopyWith<_$CustomError_Fail> get copyWith =>
+      __$$CustomError_FailCopyWithImpl<_$CustomError_Fail>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(String message, String backtrace) fail,
+    required TResult Function(int afterMs) retry,
+  }) {
+    return fail(message, backtrace);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(String message, String backtrace)? fail,
+    TResult? Function(int afterMs)? retry,
+  }) {
+    return fail?.call(message, backtrace);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(String message, String backtrace)? fail,
+    TResult Function(int afterMs)? retry,
+    required TResult orElse(),
+  }) {
+    if (fail != null) {
+      return fail(message, backtrace);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(CustomError_Fail value) fail,
+    required TResult Function(CustomError_Retry value) retry,
+  }) {
+    return fail(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(CustomError_Fail value)? fail,
+    TResult? Function(CustomError_Retry value)? retry,
+  }) {
+    return fail?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(CustomError_Fail value)? fail,
+    TResult Function(CustomError_Retry value)? retry,
+    required TResult orElse(),
+  }) {
+    if (fail != null) {
+      return fail(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class CustomError_Fail extends CustomError {
+  const factory CustomError_Fail({required final String message, required final String backtrace}) = _$CustomError_Fail;
+  const CustomError_Fail._() : super._();
+
+  String get message;
+  String get backtrace;
+  @JsonKey(ignore: true)
+  _$$CustomError_FailCopyWith<_$CustomError_Fail> get copyWith => throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$CustomError_RetryCopyWith<$Res> {
+  factory _$$CustomError_RetryCopyWith(_$CustomError_Retry value, $Res Function(_$CustomError_Retry) then) =
+      __$$CustomError_RetryCopyWithImpl<$Res>;
+  @useResult
+  $Res call({int afterMs});
+}
+
+/// @nodoc
+class __$$CustomError_RetryCopyWithImpl<$Res> extends _$CustomErrorCopyWithImpl<$Res, _$CustomError_Retry>
+    implements _$$CustomError_RetryCopyWith<$Res> {
+  __$$CustomError_RetryCopyWithImpl(_$CustomError_Retry _value, $Res Function(_$CustomError_Retry) _then)
+      : super(_value, _then);
+
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? afterMs = null,
+  }) {
+    return _then(_$CustomError_Retry(
+      afterMs: null == afterMs
+          ? _value.afterMs
+          : afterMs // ignore: cast_nullable_to_non_nullable
+              as int,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$CustomError_Retry extends CustomError_Retry {
+  const _$CustomError_Retry({required this.afterMs}) : super._();
+
+  @override
+  final int afterMs;
+
+  @override
+  String toString() {
+    return 'CustomError.retry(afterMs: $afterMs)';
+  }
+
+  @override
+  bool operator ==(dynamic other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$CustomError_Retry &&
+            (identical(other.afterMs, afterMs) || other.afterMs == afterMs));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, afterMs);
+
+  @JsonKey(ignore: true)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$CustomError_RetryCopyWith<_$CustomError_Retry> get copyWith =>
+      __$$CustomError_RetryCopyWithImpl<_$CustomError_Retry>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(String message, String backtrace) fail,
+    required TResult Function(int afterMs) retry,
+  }) {
+    return retry(afterMs);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(String message, String backtrace)? fail,
+    TResult? Function(int afterMs)? retry,
+  }) {
+    return retry?.call(afterMs);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(String message, String backtrace)? fail,
+    TResult Function(int afterMs)? retry,
+    required TResult orElse(),
+  }) {
+    if (retry != null) {
+      return retry(afterMs);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(CustomError_Fail value) fail,
+    required TResult Function(CustomError_Retry value) retry,
+  }) {
+    return retry(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(CustomError_Fail value)? fail,
+    TResult? Function(CustomError_Retry value)? retry,
+  }) {
+    return retry?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(CustomError_Fail value)? fail,
+    TResult Function(CustomError_Retry value)? retry,
+    required TResult orElse(),
+  }) {
+    if (retry != null) {
+      return retry(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class CustomError_Retry extends CustomError {
+  const factory CustomError_Retry({required final int afterMs}) = _$CustomError_Retry;
+  const CustomError_Retry._() : super._();
+
+  int get afterMs;
+  @JsonKey(ignore: true)
+  _$$CustomError_RetryCopyWith<_$CustomError_Retry> get copyWith => throw _privateConstructorUsedError;
 }
 
 /// @nodoc
@@ -546,8 +876,8 @@ class __$$Distance_UnknownCopyWithImpl<$Res> extends _$DistanceCopyWithImpl<$Res
 
 /// @nodoc
 
-class _$Distance_Unknown implements Distance_Unknown {
-  const _$Distance_Unknown();
+class _$Distance_Unknown extends Distance_Unknown {
+  const _$Distance_Unknown() : super._();
 
   @override
   String toString() {
@@ -625,8 +955,9 @@ class _$Distance_Unknown implements Distance_Unknown {
   }
 }
 
-abstract class Distance_Unknown implements Distance {
+abstract class Distance_Unknown extends Distance {
   const factory Distance_Unknown() = _$Distance_Unknown;
+  const Distance_Unknown._() : super._();
 }
 
 /// @nodoc
@@ -658,8 +989,8 @@ class __$$Distance_MapCopyWithImpl<$Res> extends _$DistanceCopyWithImpl<$Res, _$
 
 /// @nodoc
 
-class _$Distance_Map implements Distance_Map {
-  const _$Distance_Map(this.field0);
+class _$Distance_Map extends Distance_Map {
+  const _$Distance_Map(this.field0) : super._();
 
   @override
   final double field0;
@@ -749,8 +1080,9 @@ class _$Distance_Map implements Distance_Map {
   }
 }
 
-abstract class Distance_Map implements Distance {
+abstract class Distance_Map extends Distance {
   const factory Distance_Map(final double field0) = _$Distance_Map;
+  const Distance_Map._() : super._();
 
   double get field0;
   @JsonKey(ignore: true)
@@ -1970,8 +2302,8 @@ class __$$_EventCopyWithImpl<$Res> extends _$EventCopyWithImpl<$Res, _$_Event> i
 
 /// @nodoc
 
-class _$_Event implements _Event {
-  const _$_Event({required this.address, required this.payload});
+class _$_Event extends _Event {
+  const _$_Event({required this.address, required this.payload}) : super._();
 
   @override
   final String address;
@@ -2001,8 +2333,9 @@ class _$_Event implements _Event {
   _$$_EventCopyWith<_$_Event> get copyWith => __$$_EventCopyWithImpl<_$_Event>(this, _$identity);
 }
 
-abstract class _Event implements Event {
+abstract class _Event extends Event {
   const factory _Event({required final String address, required final String payload}) = _$_Event;
+  const _Event._() : super._();
 
   @override
   String get address;
@@ -2110,8 +2443,8 @@ class __$$KitchenSink_EmptyCopyWithImpl<$Res> extends _$KitchenSinkCopyWithImpl<
 
 /// @nodoc
 
-class _$KitchenSink_Empty implements KitchenSink_Empty {
-  const _$KitchenSink_Empty();
+class _$KitchenSink_Empty extends KitchenSink_Empty {
+  const _$KitchenSink_Empty() : super._();
 
   @override
   String toString() {
@@ -2213,8 +2546,9 @@ class _$KitchenSink_Empty implements KitchenSink_Empty {
   }
 }
 
-abstract class KitchenSink_Empty implements KitchenSink {
+abstract class KitchenSink_Empty extends KitchenSink {
   const factory KitchenSink_Empty() = _$KitchenSink_Empty;
+  const KitchenSink_Empty._() : super._();
 }
 
 /// @nodoc
@@ -2258,8 +2592,8 @@ class __$$KitchenSink_PrimitivesCopyWithImpl<$Res> extends _$KitchenSinkCopyWith
 
 /// @nodoc
 
-class _$KitchenSink_Primitives implements KitchenSink_Primitives {
-  const _$KitchenSink_Primitives({required this.int32, required this.float64, required this.boolean});
+class _$KitchenSink_Primitives extends KitchenSink_Primitives {
+  const _$KitchenSink_Primitives({required this.int32, required this.float64, required this.boolean}) : super._();
 
   /// Dart field comment
   @override
@@ -2380,11 +2714,12 @@ class _$KitchenSink_Primitives implements KitchenSink_Primitives {
   }
 }
 
-abstract class KitchenSink_Primitives implements KitchenSink {
+abstract class KitchenSink_Primitives extends KitchenSink {
   const factory KitchenSink_Primitives(
       {required final int int32,
       required final double float64,
       required final bool boolean}) = _$KitchenSink_Primitives;
+  const KitchenSink_Primitives._() : super._();
 
   /// Dart field comment
   int get int32;
@@ -2439,8 +2774,8 @@ class __$$KitchenSink_NestedCopyWithImpl<$Res> extends _$KitchenSinkCopyWithImpl
 
 /// @nodoc
 
-class _$KitchenSink_Nested implements KitchenSink_Nested {
-  const _$KitchenSink_Nested(this.field0, this.field1);
+class _$KitchenSink_Nested extends KitchenSink_Nested {
+  const _$KitchenSink_Nested(this.field0, this.field1) : super._();
 
   @override
   final KitchenSink field0;
@@ -2557,8 +2892,9 @@ class _$KitchenSink_Nested implements KitchenSink_Nested {
   }
 }
 
-abstract class KitchenSink_Nested implements KitchenSink {
+abstract class KitchenSink_Nested extends KitchenSink {
   const factory KitchenSink_Nested(final KitchenSink field0, final int field1) = _$KitchenSink_Nested;
+  const KitchenSink_Nested._() : super._();
 
   KitchenSink get field0;
   int get field1;
@@ -2601,8 +2937,8 @@ class __$$KitchenSink_OptionalCopyWithImpl<$Res> extends _$KitchenSinkCopyWithIm
 
 /// @nodoc
 
-class _$KitchenSink_Optional implements KitchenSink_Optional {
-  const _$KitchenSink_Optional([this.field0, this.field1]);
+class _$KitchenSink_Optional extends KitchenSink_Optional {
+  const _$KitchenSink_Optional([this.field0, this.field1]) : super._();
 
   /// Comment on anonymous field
   @override
@@ -2720,8 +3056,9 @@ class _$KitchenSink_Optional implements KitchenSink_Optional {
   }
 }
 
-abstract class KitchenSink_Optional implements KitchenSink {
+abstract class KitchenSink_Optional extends KitchenSink {
   const factory KitchenSink_Optional([final int? field0, final int? field1]) = _$KitchenSink_Optional;
+  const KitchenSink_Optional._() : super._();
 
   /// Comment on anonymous field
   int? get field0;
@@ -2760,8 +3097,8 @@ class __$$KitchenSink_BufferCopyWithImpl<$Res> extends _$KitchenSinkCopyWithImpl
 
 /// @nodoc
 
-class _$KitchenSink_Buffer implements KitchenSink_Buffer {
-  const _$KitchenSink_Buffer(this.field0);
+class _$KitchenSink_Buffer extends KitchenSink_Buffer {
+  const _$KitchenSink_Buffer(this.field0) : super._();
 
   @override
   final Uint8List field0;
@@ -2875,8 +3212,9 @@ class _$KitchenSink_Buffer implements KitchenSink_Buffer {
   }
 }
 
-abstract class KitchenSink_Buffer implements KitchenSink {
+abstract class KitchenSink_Buffer extends KitchenSink {
   const factory KitchenSink_Buffer(final Uint8List field0) = _$KitchenSink_Buffer;
+  const KitchenSink_Buffer._() : super._();
 
   Uint8List get field0;
   @JsonKey(ignore: true)
@@ -2913,8 +3251,8 @@ class __$$KitchenSink_EnumsCopyWithImpl<$Res> extends _$KitchenSinkCopyWithImpl<
 
 /// @nodoc
 
-class _$KitchenSink_Enums implements KitchenSink_Enums {
-  const _$KitchenSink_Enums(this.field0);
+class _$KitchenSink_Enums extends KitchenSink_Enums {
+  const _$KitchenSink_Enums(this.field0) : super._();
 
   @override
   final Weekdays field0;
@@ -3028,8 +3366,9 @@ class _$KitchenSink_Enums implements KitchenSink_Enums {
   }
 }
 
-abstract class KitchenSink_Enums implements KitchenSink {
+abstract class KitchenSink_Enums extends KitchenSink {
   const factory KitchenSink_Enums(final Weekdays field0) = _$KitchenSink_Enums;
+  const KitchenSink_Enums._() : super._();
 
   Weekdays get field0;
   @JsonKey(ignore: true)
@@ -3133,8 +3472,8 @@ class __$$Measure_SpeedCopyWithImpl<$Res> extends _$MeasureCopyWithImpl<$Res, _$
 
 /// @nodoc
 
-class _$Measure_Speed implements Measure_Speed {
-  const _$Measure_Speed(this.field0);
+class _$Measure_Speed extends Measure_Speed {
+  const _$Measure_Speed(this.field0) : super._();
 
   @override
   final Speed field0;
@@ -3224,8 +3563,9 @@ class _$Measure_Speed implements Measure_Speed {
   }
 }
 
-abstract class Measure_Speed implements Measure {
+abstract class Measure_Speed extends Measure {
   const factory Measure_Speed(final Speed field0) = _$Measure_Speed;
+  const Measure_Speed._() : super._();
 
   @override
   Speed get field0;
@@ -3273,8 +3613,8 @@ class __$$Measure_DistanceCopyWithImpl<$Res> extends _$MeasureCopyWithImpl<$Res,
 
 /// @nodoc
 
-class _$Measure_Distance implements Measure_Distance {
-  const _$Measure_Distance(this.field0);
+class _$Measure_Distance extends Measure_Distance {
+  const _$Measure_Distance(this.field0) : super._();
 
   @override
   final Distance field0;
@@ -3364,8 +3704,9 @@ class _$Measure_Distance implements Measure_Distance {
   }
 }
 
-abstract class Measure_Distance implements Measure {
+abstract class Measure_Distance extends Measure {
   const factory Measure_Distance(final Distance field0) = _$Measure_Distance;
+  const Measure_Distance._() : super._();
 
   @override
   Distance get field0;
@@ -3373,6 +3714,293 @@ abstract class Measure_Distance implements Measure {
   _$$Measure_DistanceCopyWith<_$Measure_Distance> get copyWith => throw _privateConstructorUsedError;
 }
 
+/// @nodoc
+mixin _$ParseError {
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function() empty,
+    required TResult Function(String line) notANumber,
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function()? empty,
+    TResult? Function(String line)? notANumber,
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function()? empty,
+    TResult Function(String line)? notANumber,
+    required TResult orElse(),
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(ParseError_Empty value) empty,
+    required TResult Function(ParseError_NotANumber value) notANumber,
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(ParseError_Empty value)? empty,
+    TResult? Function(ParseError_NotANumber value)? notANumber,
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(ParseError_Empty value)? empty,
+    TResult Function(ParseError_NotANumber value)? notANumber,
+    required TResult orElse(),
+  }) =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class $ParseErrorCopyWith<$Res> {
+  factory $ParseErrorCopyWith(ParseError value, $Res Function(ParseError) then) =
+      _$ParseErrorCopyWithImpl<$Res, ParseError>;
+}
+
+/// @nodoc
+class _$ParseErrorCopyWithImpl<$Res, $Val extends ParseError> implements $ParseErrorCopyWith<$Res> {
+  _$ParseErrorCopyWithImpl(this._value, this._then);
+
+  // ignore: unused_field
+  final $Val _value;
+  // ignore: unused_field
+  final $Res Function($Val) _then;
+}
+
+/// @nodoc
+abstract class _$$ParseError_EmptyCopyWith<$Res> {
+  factory _$$ParseError_EmptyCopyWith(_$ParseError_Empty value, $Res Function(_$ParseError_Empty) then) =
+      __$$ParseError_EmptyCopyWithImpl<$Res>;
+}
+
+/// @nodoc
+class __$$ParseError_EmptyCopyWithImpl<$Res> extends _$ParseErrorCopyWithImpl<$Res, _$ParseError_Empty>
+    implements _$$ParseError_EmptyCopyWith<$Res> {
+  __$$ParseError_EmptyCopyWithImpl(_$ParseError_Empty _value, $Res Function(_$ParseError_Empty) _then)
+      : super(_value, _then);
+}
+
+/// @nodoc
+
+class _$ParseError_Empty extends ParseError_Empty {
+  const _$ParseError_Empty() : super._();
+
+  @override
+  String toString() {
+    return 'ParseError.empty()';
+  }
+
+  @override
+  bool operator ==(dynamic other) {
+    return identical(this, other) || (other.runtimeType == runtimeType && other is _$ParseError_Empty);
+  }
+
+  @override
+  int get hashCode => runtimeType.hashCode;
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function() empty,
+    required TResult Function(String line) notANumber,
+  }) {
+    return empty();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function()? empty,
+    TResult? Function(String line)? notANumber,
+  }) {
+    return empty?.call();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function()? empty,
+    TResult Function(String line)? notANumber,
+    required TResult orElse(),
+  }) {
+    if (empty != null) {
+      return empty();
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(ParseError_Empty value) empty,
+    required TResult Function(ParseError_NotANumber value) notANumber,
+  }) {
+    return empty(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(ParseError_Empty value)? empty,
+    TResult? Function(ParseError_NotANumber value)? notANumber,
+  }) {
+    return empty?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(ParseError_Empty value)? empty,
+    TResult Function(ParseError_NotANumber value)? notANumber,
+    required TResult orElse(),
+  }) {
+    if (empty != null) {
+      return empty(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class ParseError_Empty extends ParseError {
+  const factory ParseError_Empty() = _$ParseError_Empty;
+  const ParseError_Empty._() : super._();
+}
+
+/// @nodoc
+abstract class _$$ParseError_NotANumberCopyWith<$Res> {
+  factory _$$ParseError_NotANumberCopyWith(_$ParseError_NotANumber value, $Res Function(_$ParseError_NotANumber) then) =
+      __$$ParseError_NotANumberCopyWithImpl<$Res>;
+  @useResult
+  $Res call({String line});
+}
+
+/// @nodoc
+class __$$ParseError_NotANumberCopyWithImpl<$Res> extends _$ParseErrorCopyWithImpl<$Res, _$ParseError_NotANumber>
+    implements _$$ParseError_NotANumberCopyWith<$Res> {
+  __$$ParseError_NotANumberCopyWithImpl(_$ParseError_NotANumber _value, $Res Function(_$ParseError_NotANumber) _then)
+      : super(_value, _then);
+
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? line = null,
+  }) {
+    return _then(_$ParseError_NotANumber(
+      line: null == line
+          ? _value.line
+          : line // ignore: cast_nullable_to_non_nullable
+              as String,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$ParseError_NotANumber extends ParseError_NotANumber {
+  const _$ParseError_NotANumber({required this.line}) : super._();
+
+  @override
+  final String line;
+
+  @override
+  String toString() {
+    return 'ParseError.notANumber(line: $line)';
+  }
+
+  @override
+  bool operator ==(dynamic other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$ParseError_NotANumber &&
+            (identical(other.line, line) || other.line == line));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, line);
+
+  @JsonKey(ignore: true)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$ParseError_NotANumberCopyWith<_$ParseError_NotANumber> get copyWith =>
+      __$$ParseError_NotANumberCopyWithImpl<_$ParseError_NotANumber>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function() empty,
+    required TResult Function(String line) notANumber,
+  }) {
+    return notANumber(line);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function()? empty,
+    TResult? Function(String line)? notANumber,
+  }) {
+    return notANumber?.call(line);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function()? empty,
+    TResult Function(String line)? notANumber,
+    required TResult orElse(),
+  }) {
+    if (notANumber != null) {
+      return notANumber(line);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(ParseError_Empty value) empty,
+    required TResult Function(ParseError_NotANumber value) notANumber,
+  }) {
+    return notANumber(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(ParseError_Empty value)? empty,
+    TResult? Function(ParseError_NotANumber value)? notANumber,
+  }) {
+    return notANumber?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(ParseError_Empty value)? empty,
+    TResult Function(ParseError_NotANumber value)? notANumber,
+    required TResult orElse(),
+  }) {
+    if (notANumber != null) {
+      return notANumber(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class ParseError_NotANumber extends ParseError {
+  const factory ParseError_NotANumber({required final String line}) = _$ParseError_NotANumber;
+  const ParseError_NotANumber._() : super._();
+
+  String get line;
+  @JsonKey(ignore: true)
+  _$$ParseError_NotANumberCopyWith<_$ParseError_NotANumber> get copyWith => throw _privateConstructorUsedError;
+}
+
 /// @nodoc
 mixin _$Speed {
   @optionalTypeArgs
@@ -3444,8 +4072,8 @@ class __$$Speed_UnknownCopyWithImpl<$Res> extends _$SpeedCopyWithImpl<$Res, _$Sp
 
 /// @nodoc
 
-class _$Speed_Unknown implements Speed_Unknown {
-  const _$Speed_Unknown();
+class _$Speed_Unknown extends Speed_Unknown {
+  const _$Speed_Unknown() : super._();
 
   @override
   String toString() {
@@ -3523,8 +4151,9 @@ class _$Speed_Unknown implements Speed_Unknown {
   }
 }
 
-abstract class Speed_Unknown implements Speed {
+abstract class Speed_Unknown extends Speed {
   const factory Speed_Unknown() = _$Speed_Unknown;
+  const Speed_Unknown._() : super._();
 }
 
 /// @nodoc
@@ -3555,8 +4184,8 @@ class __$$Speed_GPSCopyWithImpl<$Res> extends _$SpeedCopyWithImpl<$Res, _$Speed_
 
 /// @nodoc
 
-class _$Speed_GPS implements Speed_GPS {
-  const _$Speed_GPS(this.field0);
+class _$Speed_GPS extends Speed_GPS {
+  const _$Speed_GPS(this.field0) : super._();
 
   @override
   final double field0;
@@ -3645,8 +4274,9 @@ class _$Speed_GPS implements Speed_GPS {
   }
 }
 
-abstract class Speed_GPS implements Speed {
+abstract class Speed_GPS extends Speed {
   const factory Speed_GPS(final double field0) = _$Speed_GPS;
+  const Speed_GPS._() : super._();
 
   double get field0;
   @JsonKey(ignore: true)
@@ -3719,8 +4349,8 @@ class __$$_UserIdCopyWithImpl<$Res> extends _$UserIdCopyWithImpl<$Res, _$_UserId
 
 /// @nodoc
 
-class _$_UserId implements _UserId {
-  const _$_UserId({required this.value});
+class _$_UserId extends _UserId {
+  const _$_UserId({required this.value}) : super._();
 
   @override
   final int value;
@@ -3747,8 +4377,9 @@ class _$_UserId implements _UserId {
   _$$_UserIdCopyWith<_$_UserId> get copyWith => __$$_UserIdCopyWithImpl<_$_UserId>(this, _$identity);
 }
 
-abstract class _UserId implements UserId {
+abstract class _UserId extends UserId {
   const factory _UserId({required final int value}) = _$_UserId;
+  const _UserId._() : super._();
 
   @override
   int get value;
@@ -8,6 +8,7 @@ import 'dart:async';
 import 'package:meta/meta.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge.dart';
 import 'package:uuid/uuid.dart';
+import 'package:decimal/decimal.dart';
 import 'bridge_generated.io.dart' if (dart.library.html) 'bridge_generated.web.dart';
 
 class FlutterRustBridgeExampleSingleBlockTestImpl implements FlutterRustBridgeExampleSingleBlockTest {
@@ -19,6 +20,54 @@ class FlutterRustBridgeExampleSingleBlockTestImpl implements FlutterRustBridgeEx
   factory FlutterRustBridgeExampleSingleBlockTestImpl.wasm(FutureOr<WasmModule> module) =>
       FlutterRustBridgeExampleSingleBlockTestImpl(module as ExternalLibrary);
   FlutterRustBridgeExampleSingleBlockTestImpl.raw(this._platform);
+  Future<void> initApp({dynamic hint}) {
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_init_app(port_),
+      parseSuccessData: _wire2api_unit,
+      constMeta: kInitAppConstMeta,
+      argValues: [],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kInitAppConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "init_app",
+        argNames: [],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.initApp,
+      );
+
+  Future<int> initCount({dynamic hint}) {
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_init_count(port_),
+      parseSuccessData: _wire2api_i32,
+      constMeta: kInitCountConstMeta,
+      argValues: [],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kInitCountConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "init_count",
+        argNames: [],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.initCount,
+      );
+
+  Future<Tuple3<int, String, double>> getConstants({dynamic hint}) {
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_get_constants(port_),
+      parseSuccessData: _wire2api_tuple3_u32_String_f64,
+      constMeta: kGetConstantsConstMeta,
+      argValues: [],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kGetConstantsConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "get_constants",
+        argNames: [],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.getConstants,
+      );
+
   Future<int> simpleAdder({required int a, required int b, dynamic hint}) {
     var arg0 = api2wire_i32(a);
     var arg1 = api2wire_i32(b);
@@ -34,6 +83,25 @@ class FlutterRustBridgeExampleSingleBlockTestImpl implements FlutterRustBridgeEx
   FlutterRustBridgeTaskConstMeta get kSimpleAdderConstMeta => const FlutterRustBridgeTaskConstMeta(
         debugName: "simple_adder",
         argNames: ["a", "b"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.simpleAdder,
+      );
+
+  Future<int> simpleAdderAsync({required int a, required int b, dynamic hint}) {
+    var arg0 = api2wire_i32(a);
+    var arg1 = api2wire_i32(b);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_simple_adder_async(port_, arg0, arg1),
+      parseSuccessData: _wire2api_i32,
+      constMeta: kSimpleAdderAsyncConstMeta,
+      argValues: [a, b],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kSimpleAdderAsyncConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "simple_adder_async",
+        argNames: ["a", "b"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.simpleAdderAsync,
       );
 
   int simpleAdderSync({required int a, required int b, dynamic hint}) {
@@ -51,6 +119,30 @@ class FlutterRustBridgeExampleSingleBlockTestImpl implements FlutterRustBridgeEx
   FlutterRustBridgeTaskConstMeta get kSimpleAdderSyncConstMeta => const FlutterRustBridgeTaskConstMeta(
         debugName: "simple_adder_sync",
         argNames: ["a", "b"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.simpleAdderSync,
+      );
+
+  double scale({required double x, required double factor, dynamic hint}) {
+    var arg0 = api2wire_f64(x);
+    var arg1 = api2wire_f64(factor);
+    return _wire2api_f64(_platform.inner.wire_scale(arg0, arg1));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kScaleConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "scale",
+        argNames: ["x", "factor"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.scale,
+      );
+
+  double sumVector({required F32Array3 v, dynamic hint}) {
+    var arg0 = _platform.api2wire_f32_array_3(v);
+    return _wire2api_f32(_platform.inner.wire_sum_vector(arg0));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kSumVectorConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "sum_vector",
+        argNames: ["v"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.sumVector,
       );
 
   Future<int> primitiveTypes(
@@ -71,6 +163,7 @@ class FlutterRustBridgeExampleSingleBlockTestImpl implements FlutterRustBridgeEx
   FlutterRustBridgeTaskConstMeta get kPrimitiveTypesConstMeta => const FlutterRustBridgeTaskConstMeta(
         debugName: "primitive_types",
         argNames: ["myI32", "myI64", "myF64", "myBool"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.primitiveTypes,
       );
 
   Future<int?> primitiveOptionalTypes({int? myI32, int? myI64, double? myF64, bool? myBool, dynamic hint}) {
@@ -90,6 +183,7 @@ class FlutterRustBridgeExampleSingleBlockTestImpl implements FlutterRustBridgeEx
   FlutterRustBridgeTaskConstMeta get kPrimitiveOptionalTypesConstMeta => const FlutterRustBridgeTaskConstMeta(
         debugName: "primitive_optional_types",
         argNames: ["myI32", "myI64", "myF64", "myBool"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.primitiveOptionalTypes,
       );
 
   int primitiveTypesSync(
@@ -110,6 +204,7 @@ class FlutterRustBridgeExampleSingleBlockTestImpl implements FlutterRustBridgeEx
   FlutterRustBridgeTaskConstMeta get kPrimitiveTypesSyncConstMeta => const FlutterRustBridgeTaskConstMeta(
         debugName: "primitive_types_sync",
         argNames: ["myI32", "myI64", "myF64", "myBool"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.primitiveTypesSync,
       );
 
   Future<int> primitiveU32({required int myU32, dynamic hint}) {
@@ -126,6 +221,7 @@ class FlutterRustBridgeExampleSingleBlockTestImpl implements FlutterRustBridgeEx
   FlutterRustBridgeTaskConstMeta get kPrimitiveU32ConstMeta => const FlutterRustBridgeTaskConstMeta(
         debugName: "primitive_u32",
         argNames: ["myU32"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.primitiveU32,
       );
 
   int primitiveU32Sync({required int myU32, dynamic hint}) {
@@ -142,6 +238,7 @@ class FlutterRustBridgeExampleSingleBlockTestImpl implements FlutterRustBridgeEx
   FlutterRustBridgeTaskConstMeta get kPrimitiveU32SyncConstMeta => const FlutterRustBridgeTaskConstMeta(
         debugName: "primitive_u32_sync",
         argNames: ["myU32"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.primitiveU32Sync,
       );
 
   Future<String> handleString({required String s, dynamic hint}) {
@@ -158,6 +255,7 @@ class FlutterRustBridgeExampleSingleBlockTestImpl implements FlutterRustBridgeEx
   FlutterRustBridgeTaskConstMeta get kHandleStringConstMeta => const FlutterRustBridgeTaskConstMeta(
         debugName: "handle_string",
         argNames: ["s"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.handleString,
       );
 
   String handleStringSync({required String s, dynamic hint}) {
@@ -174,6 +272,305 @@ class FlutterRustBridgeExampleSingleBlockTestImpl implements FlutterRustBridgeEx
   FlutterRustBridgeTaskConstMeta get kHandleStringSyncConstMeta => const FlutterRustBridgeTaskConstMeta(
         debugName: "handle_string_sync",
         argNames: ["s"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.handleStringSync,
+      );
+
+  Future<String> nextChar({required String c, dynamic hint}) {
+    var arg0 = api2wire_char(c);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_next_char(port_, arg0),
+      parseSuccessData: _wire2api_char,
+      constMeta: kNextCharConstMeta,
+      argValues: [c],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kNextCharConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "next_char",
+        argNames: ["c"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.nextChar,
+      );
+
+  Future<String?> handleChars({required List<String> chars, dynamic hint}) {
+    var arg0 = _platform.api2wire_list_char(chars);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_handle_chars(port_, arg0),
+      parseSuccessData: _wire2api_opt_char,
+      constMeta: kHandleCharsConstMeta,
+      argValues: [chars],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kHandleCharsConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "handle_chars",
+        argNames: ["chars"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.handleChars,
+      );
+
+  Future<int> divideBy({required int n, required int by, dynamic hint}) {
+    var arg0 = api2wire_u32(n);
+    var arg1 = api2wire_NonZero_u32(by);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_divide_by(port_, arg0, arg1),
+      parseSuccessData: _wire2api_u32,
+      constMeta: kDivideByConstMeta,
+      argValues: [n, by],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kDivideByConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "divide_by",
+        argNames: ["n", "by"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.divideBy,
+      );
+
+  Future<int?> nextNonZero({required int n, dynamic hint}) {
+    var arg0 = api2wire_NonZero_u64(n);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_next_non_zero(port_, arg0),
+      parseSuccessData: _wire2api_opt_NonZero_u64,
+      constMeta: kNextNonZeroConstMeta,
+      argValues: [n],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kNextNonZeroConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "next_non_zero",
+        argNames: ["n"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.nextNonZero,
+      );
+
+  Future<String> greet({String name = 'world', int times = 1, dynamic hint}) {
+    var arg0 = _platform.api2wire_String(name);
+    var arg1 = api2wire_u32(times);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_greet(port_, arg0, arg1),
+      parseSuccessData: _wire2api_String,
+      constMeta: kGreetConstMeta,
+      argValues: [name, times],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kGreetConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "greet",
+        argNames: ["name", "times"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.greet,
+      );
+
+  Future<Instant> instantNow({dynamic hint}) {
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_instant_now(port_),
+      parseSuccessData: _wire2api_std_Instant,
+      constMeta: kInstantNowConstMeta,
+      argValues: [],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kInstantNowConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "instant_now",
+        argNames: [],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.instantNow,
+      );
+
+  Future<Instant> instantAfter({required Instant start, required int millis, dynamic hint}) {
+    var arg0 = _platform.api2wire_std_Instant(start);
+    var arg1 = api2wire_u64(millis);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_instant_after(port_, arg0, arg1),
+      parseSuccessData: _wire2api_std_Instant,
+      constMeta: kInstantAfterConstMeta,
+      argValues: [start, millis],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kInstantAfterConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "instant_after",
+        argNames: ["start", "millis"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.instantAfter,
+      );
+
+  Future<DateTime> oneDayLater({required DateTime time, dynamic hint}) {
+    var arg0 = _platform.api2wire_SystemTime(time);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_one_day_later(port_, arg0),
+      parseSuccessData: _wire2api_SystemTime,
+      constMeta: kOneDayLaterConstMeta,
+      argValues: [time],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kOneDayLaterConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "one_day_later",
+        argNames: ["time"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.oneDayLater,
+      );
+
+  Future<RustRange<int>> shiftRange({required RustRange<int> range, required int by, dynamic hint}) {
+    var arg0 = _platform.api2wire_Range_u32(range);
+    var arg1 = api2wire_u32(by);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_shift_range(port_, arg0, arg1),
+      parseSuccessData: _wire2api_RangeInclusive_u32,
+      constMeta: kShiftRangeConstMeta,
+      argValues: [range, by],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kShiftRangeConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "shift_range",
+        argNames: ["range", "by"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.shiftRange,
+      );
+
+  Future<double> clampRange({required RustRange<double> range, required double value, dynamic hint}) {
+    var arg0 = _platform.api2wire_RangeInclusive_f64(range);
+    var arg1 = api2wire_f64(value);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_clamp_range(port_, arg0, arg1),
+      parseSuccessData: _wire2api_f64,
+      constMeta: kClampRangeConstMeta,
+      argValues: [range, value],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kClampRangeConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "clamp_range",
+        argNames: ["range", "value"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.clampRange,
+      );
+
+  Future<String> describeSize(
+      {required MySize size, required String unit, required List<String> labels, dynamic hint}) {
+    var arg0 = _platform.api2wire_box_autoadd_my_size(size);
+    var arg1 = _platform.api2wire_String(unit);
+    var arg2 = _platform.api2wire_StringList(labels);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_describe_size(port_, arg0, arg1, arg2),
+      parseSuccessData: _wire2api_String,
+      constMeta: kDescribeSizeConstMeta,
+      argValues: [size, unit, labels],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kDescribeSizeConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "describe_size",
+        argNames: ["size", "unit", "labels"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.describeSize,
+      );
+
+  Stream<String> splitWords({required String text, dynamic hint}) {
+    var arg0 = _platform.api2wire_String(text);
+    return _platform.executeIterator<DartIteratorString, String>(
+        FlutterRustBridgeTask(
+          callFfi: (port_) => _platform.inner.wire_split_words(port_, arg0),
+          parseSuccessData: _wire2api_DartIteratorString,
+          constMeta: kSplitWordsConstMeta,
+          argValues: [text],
+          hint: hint,
+        ),
+        next: (iterator) => nextDartIteratorString(iterator: iterator));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kSplitWordsConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "split_words",
+        argNames: ["text"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.splitWords,
+      );
+
+  Future<String> handleCowStr({required String s, dynamic hint}) {
+    var arg0 = _platform.api2wire_Cow_String(s);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_handle_cow_str(port_, arg0),
+      parseSuccessData: _wire2api_Cow_String,
+      constMeta: kHandleCowStrConstMeta,
+      argValues: [s],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kHandleCowStrConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "handle_cow_str",
+        argNames: ["s"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.handleCowStr,
+      );
+
+  Future<Uint8List> handleCowBytes({required Uint8List bytes, dynamic hint}) {
+    var arg0 = _platform.api2wire_Cow_Bytes(bytes);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_handle_cow_bytes(port_, arg0),
+      parseSuccessData: _wire2api_Cow_Bytes,
+      constMeta: kHandleCowBytesConstMeta,
+      argValues: [bytes],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kHandleCowBytesConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "handle_cow_bytes",
+        argNames: ["bytes"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.handleCowBytes,
+      );
+
+  Future<String> handlePath({required String path, dynamic hint}) {
+    var arg0 = _platform.api2wire_PathRef(path);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_handle_path(port_, arg0),
+      parseSuccessData: _wire2api_PathBuf,
+      constMeta: kHandlePathConstMeta,
+      argValues: [path],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kHandlePathConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "handle_path",
+        argNames: ["path"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.handlePath,
+      );
+
+  Future<IpAddress> handleSocketAddr({required SocketAddress addr, dynamic hint}) {
+    var arg0 = _platform.api2wire_SocketAddr(addr);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_handle_socket_addr(port_, arg0),
+      parseSuccessData: _wire2api_IpAddr,
+      constMeta: kHandleSocketAddrConstMeta,
+      argValues: [addr],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kHandleSocketAddrConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "handle_socket_addr",
+        argNames: ["addr"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.handleSocketAddr,
+      );
+
+  Future<SocketAddress> nextPort({required SocketAddress addr, dynamic hint}) {
+    var arg0 = _platform.api2wire_SocketAddr(addr);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_next_port(port_, arg0),
+      parseSuccessData: _wire2api_SocketAddr,
+      constMeta: kNextPortConstMeta,
+      argValues: [addr],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kNextPortConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "next_port",
+        argNames: ["addr"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.nextPort,
       );
 
   Future<void> handleReturnUnit({dynamic hint}) {
@@ -189,6 +586,7 @@ class FlutterRustBridgeExampleSingleBlockTestImpl implements FlutterRustBridgeEx
   FlutterRustBridgeTaskConstMeta get kHandleReturnUnitConstMeta => const FlutterRustBridgeTaskConstMeta(
         debugName: "handle_return_unit",
         argNames: [],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.handleReturnUnit,
       );
 
   void handleReturnUnitSync({dynamic hint}) {
@@ -204,6 +602,7 @@ class FlutterRustBridgeExampleSingleBlockTestImpl implements FlutterRustBridgeEx
   FlutterRustBridgeTaskConstMeta get kHandleReturnUnitSyncConstMeta => const FlutterRustBridgeTaskConstMeta(
         debugName: "handle_return_unit_sync",
         argNames: [],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.handleReturnUnitSync,
       );
 
   Future<Uint8List> handleVecU8({required Uint8List v, dynamic hint}) {
@@ -220,6 +619,94 @@ class FlutterRustBridgeExampleSingleBlockTestImpl implements FlutterRustBridgeEx
   FlutterRustBridgeTaskConstMeta get kHandleVecU8ConstMeta => const FlutterRustBridgeTaskConstMeta(
         debugName: "handle_vec_u8",
         argNames: ["v"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.handleVecU8,
+      );
+
+  Future<Uint8List> handleBytes({required Uint8List bytes, dynamic hint}) {
+    var arg0 = _platform.api2wire_Bytes(bytes);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_handle_bytes(port_, arg0),
+      parseSuccessData: _wire2api_Bytes,
+      constMeta: kHandleBytesConstMeta,
+      argValues: [bytes],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kHandleBytesConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "handle_bytes",
+        argNames: ["bytes"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.handleBytes,
+      );
+
+  Future<int> checksum({required Uint8List data, required Uint16List weights, dynamic hint}) {
+    var arg0 = _platform.api2wire_SliceRef_u8(data);
+    var arg1 = _platform.api2wire_SliceRef_u16(weights);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_checksum(port_, arg0, arg1),
+      parseSuccessData: _wire2api_u32,
+      constMeta: kChecksumConstMeta,
+      argValues: [data, weights],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kChecksumConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "checksum",
+        argNames: ["data", "weights"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.checksum,
+      );
+
+  Future<int> countInSharedBuffer({required SharedBuffer buffer, required int byte, dynamic hint}) {
+    var arg0 = _platform.api2wire_SharedBuffer(buffer);
+    var arg1 = api2wire_u8(byte);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_count_in_shared_buffer(port_, arg0, arg1),
+      parseSuccessData: _wire2api_u32,
+      constMeta: kCountInSharedBufferConstMeta,
+      argValues: [buffer, byte],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kCountInSharedBufferConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "count_in_shared_buffer",
+        argNames: ["buffer", "byte"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.countInSharedBuffer,
+      );
+
+  Future<Frame> nextFrame({required Frame frame, dynamic hint}) {
+    var arg0 = _platform.api2wire_box_autoadd_frame(frame);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_next_frame(port_, arg0),
+      parseSuccessData: _wire2api_frame,
+      constMeta: kNextFrameConstMeta,
+      argValues: [frame],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kNextFrameConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "next_frame",
+        argNames: ["frame"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.nextFrame,
+      );
+
+  Future<Uint64List> prefixSums({required Uint64List items, dynamic hint}) {
+    var arg0 = _platform.api2wire_usize_list(items);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_prefix_sums(port_, arg0),
+      parseSuccessData: _wire2api_usize_list,
+      constMeta: kPrefixSumsConstMeta,
+      argValues: [items],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kPrefixSumsConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "prefix_sums",
+        argNames: ["items"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.prefixSums,
       );
 
   Uint8List handleVecU8Sync({required Uint8List v, dynamic hint}) {
@@ -236,6 +723,7 @@ class FlutterRustBridgeExampleSingleBlockTestImpl implements FlutterRustBridgeEx
   FlutterRustBridgeTaskConstMeta get kHandleVecU8SyncConstMeta => const FlutterRustBridgeTaskConstMeta(
         debugName: "handle_vec_u8_sync",
         argNames: ["v"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.handleVecU8Sync,
       );
 
   Future<VecOfPrimitivePack> handleVecOfPrimitive({required int n, dynamic hint}) {
@@ -252,6 +740,7 @@ class FlutterRustBridgeExampleSingleBlockTestImpl implements FlutterRustBridgeEx
   FlutterRustBridgeTaskConstMeta get kHandleVecOfPrimitiveConstMeta => const FlutterRustBridgeTaskConstMeta(
         debugName: "handle_vec_of_primitive",
         argNames: ["n"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.handleVecOfPrimitive,
       );
 
   VecOfPrimitivePack handleVecOfPrimitiveSync({required int n, dynamic hint}) {
@@ -268,6 +757,7 @@ class FlutterRustBridgeExampleSingleBlockTestImpl implements FlutterRustBridgeEx
   FlutterRustBridgeTaskConstMeta get kHandleVecOfPrimitiveSyncConstMeta => const FlutterRustBridgeTaskConstMeta(
         debugName: "handle_vec_of_primitive_sync",
         argNames: ["n"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.handleVecOfPrimitiveSync,
       );
 
   Future<ZeroCopyVecOfPrimitivePack> handleZeroCopyVecOfPrimitive({required int n, dynamic hint}) {
@@ -284,6 +774,7 @@ class FlutterRustBridgeExampleSingleBlockTestImpl implements FlutterRustBridgeEx
   FlutterRustBridgeTaskConstMeta get kHandleZeroCopyVecOfPrimitiveConstMeta => const FlutterRustBridgeTaskConstMeta(
         debugName: "handle_zero_copy_vec_of_primitive",
         argNames: ["n"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.handleZeroCopyVecOfPrimitive,
       );
 
   ZeroCopyVecOfPrimitivePack handleZeroCopyVecOfPrimitiveSync({required int n, dynamic hint}) {
@@ -300,6 +791,7 @@ class FlutterRustBridgeExampleSingleBlockTestImpl implements FlutterRustBridgeEx
   FlutterRustBridgeTaskConstMeta get kHandleZeroCopyVecOfPrimitiveSyncConstMeta => const FlutterRustBridgeTaskConstMeta(
         debugName: "handle_zero_copy_vec_of_primitive_sync",
         argNames: ["n"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.handleZeroCopyVecOfPrimitiveSync,
       );
 
   Future<MySize> handleStruct({required MySize arg, required MySize boxed, dynamic hint}) {
@@ -317,6 +809,7 @@ class FlutterRustBridgeExampleSingleBlockTestImpl implements FlutterRustBridgeEx
   FlutterRustBridgeTaskConstMeta get kHandleStructConstMeta => const FlutterRustBridgeTaskConstMeta(
         debugName: "handle_struct",
         argNames: ["arg", "boxed"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.handleStruct,
       );
 
   MySize handleStructSync({required MySize arg, required MySize boxed, dynamic hint}) {
@@ -334,6 +827,7 @@ class FlutterRustBridgeExampleSingleBlockTestImpl implements FlutterRustBridgeEx
   FlutterRustBridgeTaskConstMeta get kHandleStructSyncConstMeta => const FlutterRustBridgeTaskConstMeta(
         debugName: "handle_struct_sync",
         argNames: ["arg", "boxed"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.handleStructSync,
       );
 
   Future<NewTypeInt> handleNewtype({required NewTypeInt arg, dynamic hint}) {
@@ -350,6 +844,7 @@ class FlutterRustBridgeExampleSingleBlockTestImpl implements FlutterRustBridgeEx
   FlutterRustBridgeTaskConstMeta get kHandleNewtypeConstMeta => const FlutterRustBridgeTaskConstMeta(
         debugName: "handle_newtype",
         argNames: ["arg"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.handleNewtype,
       );
 
   NewTypeInt handleNewtypeSync({required NewTypeInt arg, dynamic hint}) {
@@ -366,6 +861,24 @@ class FlutterRustBridgeExampleSingleBlockTestImpl implements FlutterRustBridgeEx
   FlutterRustBridgeTaskConstMeta get kHandleNewtypeSyncConstMeta => const FlutterRustBridgeTaskConstMeta(
         debugName: "handle_newtype_sync",
         argNames: ["arg"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.handleNewtypeSync,
+      );
+
+  Future<PairString> handleGenericStruct({required PairI32 pair, dynamic hint}) {
+    var arg0 = _platform.api2wire_box_autoadd_pair_i_32(pair);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_handle_generic_struct(port_, arg0),
+      parseSuccessData: _wire2api_pair_string,
+      constMeta: kHandleGenericStructConstMeta,
+      argValues: [pair],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kHandleGenericStructConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "handle_generic_struct",
+        argNames: ["pair"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.handleGenericStruct,
       );
 
   Future<List<MySize>> handleListOfStruct({required List<MySize> l, dynamic hint}) {
@@ -382,6 +895,7 @@ class FlutterRustBridgeExampleSingleBlockTestImpl implements FlutterRustBridgeEx
   FlutterRustBridgeTaskConstMeta get kHandleListOfStructConstMeta => const FlutterRustBridgeTaskConstMeta(
         debugName: "handle_list_of_struct",
         argNames: ["l"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.handleListOfStruct,
       );
 
   List<MySize> handleListOfStructSync({required List<MySize> l, dynamic hint}) {
@@ -398,6 +912,24 @@ class FlutterRustBridgeExampleSingleBlockTestImpl implements FlutterRustBridgeEx
   FlutterRustBridgeTaskConstMeta get kHandleListOfStructSyncConstMeta => const FlutterRustBridgeTaskConstMeta(
         debugName: "handle_list_of_struct_sync",
         argNames: ["l"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.handleListOfStructSync,
+      );
+
+  Future<List<List<List<String>>>> handleNestedVec({required List<Int32List> rows, dynamic hint}) {
+    var arg0 = _platform.api2wire_list_int_32_list(rows);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_handle_nested_vec(port_, arg0),
+      parseSuccessData: _wire2api_list_list_StringList,
+      constMeta: kHandleNestedVecConstMeta,
+      argValues: [rows],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kHandleNestedVecConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "handle_nested_vec",
+        argNames: ["rows"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.handleNestedVec,
       );
 
   Future<List<String>> handleStringList({required List<String> names, dynamic hint}) {
@@ -414,6 +946,7 @@ class FlutterRustBridgeExampleSingleBlockTestImpl implements FlutterRustBridgeEx
   FlutterRustBridgeTaskConstMeta get kHandleStringListConstMeta => const FlutterRustBridgeTaskConstMeta(
         debugName: "handle_string_list",
         argNames: ["names"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.handleStringList,
       );
 
   List<String> handleStringListSync({required List<String> names, dynamic hint}) {
@@ -430,6 +963,7 @@ class FlutterRustBridgeExampleSingleBlockTestImpl implements FlutterRustBridgeEx
   FlutterRustBridgeTaskConstMeta get kHandleStringListSyncConstMeta => const FlutterRustBridgeTaskConstMeta(
         debugName: "handle_string_list_sync",
         argNames: ["names"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.handleStringListSync,
       );
 
   Future<MyTreeNode> handleComplexStruct({required MyTreeNode s, dynamic hint}) {
@@ -446,6 +980,7 @@ class FlutterRustBridgeExampleSingleBlockTestImpl implements FlutterRustBridgeEx
   FlutterRustBridgeTaskConstMeta get kHandleComplexStructConstMeta => const FlutterRustBridgeTaskConstMeta(
         debugName: "handle_complex_struct",
         argNames: ["s"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.handleComplexStruct,
       );
 
   MyTreeNode handleComplexStructSync({required MyTreeNode s, dynamic hint}) {
@@ -462,6 +997,7 @@ class FlutterRustBridgeExampleSingleBlockTestImpl implements FlutterRustBridgeEx
   FlutterRustBridgeTaskConstMeta get kHandleComplexStructSyncConstMeta => const FlutterRustBridgeTaskConstMeta(
         debugName: "handle_complex_struct_sync",
         argNames: ["s"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.handleComplexStructSync,
       );
 
   Future<MyNestedStruct> handleNestedStruct({required MyNestedStruct s, dynamic hint}) {
@@ -478,6 +1014,7 @@ class FlutterRustBridgeExampleSingleBlockTestImpl implements FlutterRustBridgeEx
   FlutterRustBridgeTaskConstMeta get kHandleNestedStructConstMeta => const FlutterRustBridgeTaskConstMeta(
         debugName: "handle_nested_struct",
         argNames: ["s"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.handleNestedStruct,
       );
 
   Uint8List handleSyncReturn({required String mode, dynamic hint}) {
@@ -491,9 +1028,21 @@ class FlutterRustBridgeExampleSingleBlockTestImpl implements FlutterRustBridgeEx
     ));
   }
 
+  SyncResult<Uint8List> handleSyncReturnResult({required String mode, dynamic hint}) {
+    var arg0 = _platform.api2wire_String(mode);
+    return _platform.executeSyncResult(FlutterRustBridgeSyncTask(
+      callFfi: () => _platform.inner.wire_handle_sync_return(arg0),
+      parseSuccessData: _wire2api_uint_8_list,
+      constMeta: kHandleSyncReturnConstMeta,
+      argValues: [mode],
+      hint: hint,
+    ));
+  }
+
   FlutterRustBridgeTaskConstMeta get kHandleSyncReturnConstMeta => const FlutterRustBridgeTaskConstMeta(
         debugName: "handle_sync_return",
         argNames: ["mode"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.handleSyncReturn,
       );
 
   Stream<String> handleStream({required String arg, dynamic hint}) {
@@ -510,6 +1059,7 @@ class FlutterRustBridgeExampleSingleBlockTestImpl implements FlutterRustBridgeEx
   FlutterRustBridgeTaskConstMeta get kHandleStreamConstMeta => const FlutterRustBridgeTaskConstMeta(
         debugName: "handle_stream",
         argNames: ["arg"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.handleStream,
       );
 
   Stream<MyStreamEntry> handleStreamOfStruct({dynamic hint}) {
@@ -525,6 +1075,7 @@ class FlutterRustBridgeExampleSingleBlockTestImpl implements FlutterRustBridgeEx
   FlutterRustBridgeTaskConstMeta get kHandleStreamOfStructConstMeta => const FlutterRustBridgeTaskConstMeta(
         debugName: "handle_stream_of_struct",
         argNames: [],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.handleStreamOfStruct,
       );
 
   Future<int> returnErr({dynamic hint}) {
@@ -540,38 +1091,241 @@ class FlutterRustBridgeExampleSingleBlockTestImpl implements FlutterRustBridgeEx
   FlutterRustBridgeTaskConstMeta get kReturnErrConstMeta => const FlutterRustBridgeTaskConstMeta(
         debugName: "return_err",
         argNames: [],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.returnErr,
       );
 
-  Future<int> returnPanic({dynamic hint}) {
+  Future<int> returnCustomError({required bool fail, dynamic hint}) {
+    var arg0 = fail;
     return _platform.executeNormal(FlutterRustBridgeTask(
-      callFfi: (port_) => _platform.inner.wire_return_panic(port_),
-      parseSuccessData: _wire2api_i32,
-      constMeta: kReturnPanicConstMeta,
-      argValues: [],
+      callFfi: (port_) => _platform.inner.wire_return_custom_error(port_, arg0),
+      parseSuccessData: _wire2api_u32,
+      parseErrorData: _wire2api_custom_error,
+      constMeta: kReturnCustomErrorConstMeta,
+      argValues: [fail],
       hint: hint,
     ));
   }
 
-  FlutterRustBridgeTaskConstMeta get kReturnPanicConstMeta => const FlutterRustBridgeTaskConstMeta(
-        debugName: "return_panic",
-        argNames: [],
+  FlutterRustBridgeTaskConstMeta get kReturnCustomErrorConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "return_custom_error",
+        argNames: ["fail"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.returnCustomError,
       );
 
-  Future<double?> handleOptionalReturn({required double left, required double right, dynamic hint}) {
-    var arg0 = api2wire_f64(left);
-    var arg1 = api2wire_f64(right);
+  Future<void> returnCustomErrorUnit({dynamic hint}) {
     return _platform.executeNormal(FlutterRustBridgeTask(
-      callFfi: (port_) => _platform.inner.wire_handle_optional_return(port_, arg0, arg1),
-      parseSuccessData: _wire2api_opt_box_autoadd_f64,
-      constMeta: kHandleOptionalReturnConstMeta,
-      argValues: [left, right],
+      callFfi: (port_) => _platform.inner.wire_return_custom_error_unit(port_),
+      parseSuccessData: _wire2api_unit,
+      parseErrorData: _wire2api_custom_error,
+      constMeta: kReturnCustomErrorUnitConstMeta,
+      argValues: [],
       hint: hint,
     ));
   }
 
-  FlutterRustBridgeTaskConstMeta get kHandleOptionalReturnConstMeta => const FlutterRustBridgeTaskConstMeta(
-        debugName: "handle_optional_return",
+  FlutterRustBridgeTaskConstMeta get kReturnCustomErrorUnitConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "return_custom_error_unit",
+        argNames: [],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.returnCustomErrorUnit,
+      );
+
+  Future<Weekdays?> lookupWeekday({required int index, dynamic hint}) {
+    var arg0 = api2wire_u32(index);
+    return _platform.executeSyncAsFuture(FlutterRustBridgeSyncTask(
+      callFfi: () => _platform.inner.wire_lookup_weekday(arg0),
+      parseSuccessData: _wire2api_opt_box_autoadd_weekdays,
+      parseErrorData: _wire2api_custom_error,
+      constMeta: kLookupWeekdayConstMeta,
+      argValues: [index],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kLookupWeekdayConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "lookup_weekday",
+        argNames: ["index"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.lookupWeekday,
+      );
+
+  Future<String> lookupString({required String key, dynamic hint}) {
+    var arg0 = _platform.api2wire_String(key);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_lookup_string(port_, arg0),
+      parseSuccessData: _wire2api_String,
+      parseErrorData: _wire2api_String,
+      constMeta: kLookupStringConstMeta,
+      argValues: [key],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kLookupStringConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "lookup_string",
+        argNames: ["key"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.lookupString,
+      );
+
+  Future<String> readSetting({required String key, dynamic hint}) {
+    var arg0 = _platform.api2wire_String(key);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_read_setting(port_, arg0),
+      parseSuccessData: _wire2api_String,
+      parseErrorData: (d) {
+        final e = _wire2api_tuple2_setting_error_String(d);
+        return FrbCodedException(e.item1, e.item2);
+      },
+      constMeta: kReadSettingConstMeta,
+      argValues: [key],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kReadSettingConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "read_setting",
+        argNames: ["key"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.readSetting,
+      );
+
+  Future<int> parseSetting({required String value, dynamic hint}) {
+    var arg0 = _platform.api2wire_String(value);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_parse_setting(port_, arg0),
+      parseSuccessData: _wire2api_u32,
+      parseErrorData: (d) {
+        final e = _wire2api_tuple3_setting_error_String_custom_error(d);
+        return FrbCodedException(e.item1, e.item2, e.item3);
+      },
+      constMeta: kParseSettingConstMeta,
+      argValues: [value],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kParseSettingConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "parse_setting",
+        argNames: ["value"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.parseSetting,
+      );
+
+  int returnCustomErrorSync({required bool fail, dynamic hint}) {
+    var arg0 = fail;
+    return _platform.executeSync(FlutterRustBridgeSyncTask(
+      callFfi: () => _platform.inner.wire_return_custom_error_sync(arg0),
+      parseSuccessData: _wire2api_u32,
+      parseErrorData: _wire2api_custom_error,
+      constMeta: kReturnCustomErrorSyncConstMeta,
+      argValues: [fail],
+      hint: hint,
+    ));
+  }
+
+  SyncResult<int> returnCustomErrorSyncResult({required bool fail, dynamic hint}) {
+    var arg0 = fail;
+    return _platform.executeSyncResult(FlutterRustBridgeSyncTask(
+      callFfi: () => _platform.inner.wire_return_custom_error_sync(arg0),
+      parseSuccessData: _wire2api_u32,
+      parseErrorData: _wire2api_custom_error,
+      constMeta: kReturnCustomErrorSyncConstMeta,
+      argValues: [fail],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kReturnCustomErrorSyncConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "return_custom_error_sync",
+        argNames: ["fail"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.returnCustomErrorSync,
+      );
+
+  CancelableFuture<int> countUntilCancelled({dynamic hint, Duration? timeout}) {
+    final cancelId_ = _platform.createCancelId();
+    return _platform.executeCancelable(
+        cancelId_,
+        FlutterRustBridgeTask(
+          callFfi: (port_) => _platform.inner.wire_count_until_cancelled(port_, cancelId_),
+          parseSuccessData: _wire2api_u32,
+          constMeta: kCountUntilCancelledConstMeta,
+          argValues: [],
+          hint: hint,
+        ),
+        timeout: timeout);
+  }
+
+  FlutterRustBridgeTaskConstMeta get kCountUntilCancelledConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "count_until_cancelled",
+        argNames: [],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.countUntilCancelled,
+      );
+
+  CancelableFuture<void> runWorkers({required int workers, dynamic hint, Duration? timeout}) {
+    var arg0 = api2wire_u32(workers);
+    final cancelId_ = _platform.createCancelId();
+    return _platform.executeCancelable(
+        cancelId_,
+        FlutterRustBridgeTask(
+          callFfi: (port_) => _platform.inner.wire_run_workers(port_, cancelId_, arg0),
+          parseSuccessData: _wire2api_unit,
+          constMeta: kRunWorkersConstMeta,
+          argValues: [workers],
+          hint: hint,
+        ),
+        timeout: timeout);
+  }
+
+  FlutterRustBridgeTaskConstMeta get kRunWorkersConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "run_workers",
+        argNames: ["workers"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.runWorkers,
+      );
+
+  Future<int> stoppedWorkers({dynamic hint}) {
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_stopped_workers(port_),
+      parseSuccessData: _wire2api_i32,
+      constMeta: kStoppedWorkersConstMeta,
+      argValues: [],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kStoppedWorkersConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "stopped_workers",
+        argNames: [],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.stoppedWorkers,
+      );
+
+  Future<int> returnPanic({dynamic hint}) {
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_return_panic(port_),
+      parseSuccessData: _wire2api_i32,
+      constMeta: kReturnPanicConstMeta,
+      argValues: [],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kReturnPanicConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "return_panic",
+        argNames: [],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.returnPanic,
+      );
+
+  Future<double?> handleOptionalReturn({required double left, required double right, dynamic hint}) {
+    var arg0 = api2wire_f64(left);
+    var arg1 = api2wire_f64(right);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_handle_optional_return(port_, arg0, arg1),
+      parseSuccessData: _wire2api_opt_box_autoadd_f64,
+      constMeta: kHandleOptionalReturnConstMeta,
+      argValues: [left, right],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kHandleOptionalReturnConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "handle_optional_return",
         argNames: ["left", "right"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.handleOptionalReturn,
       );
 
   Future<Element?> handleOptionalStruct({String? document, dynamic hint}) {
@@ -588,6 +1342,7 @@ class FlutterRustBridgeExampleSingleBlockTestImpl implements FlutterRustBridgeEx
   FlutterRustBridgeTaskConstMeta get kHandleOptionalStructConstMeta => const FlutterRustBridgeTaskConstMeta(
         debugName: "handle_optional_struct",
         argNames: ["document"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.handleOptionalStruct,
       );
 
   Future<ExoticOptionals?> handleOptionalIncrement({ExoticOptionals? opt, dynamic hint}) {
@@ -604,6 +1359,7 @@ class FlutterRustBridgeExampleSingleBlockTestImpl implements FlutterRustBridgeEx
   FlutterRustBridgeTaskConstMeta get kHandleOptionalIncrementConstMeta => const FlutterRustBridgeTaskConstMeta(
         debugName: "handle_optional_increment",
         argNames: ["opt"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.handleOptionalIncrement,
       );
 
   Future<double> handleIncrementBoxedOptional({double? opt, dynamic hint}) {
@@ -620,6 +1376,7 @@ class FlutterRustBridgeExampleSingleBlockTestImpl implements FlutterRustBridgeEx
   FlutterRustBridgeTaskConstMeta get kHandleIncrementBoxedOptionalConstMeta => const FlutterRustBridgeTaskConstMeta(
         debugName: "handle_increment_boxed_optional",
         argNames: ["opt"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.handleIncrementBoxedOptional,
       );
 
   Future<String> handleOptionBoxArguments(
@@ -651,6 +1408,7 @@ class FlutterRustBridgeExampleSingleBlockTestImpl implements FlutterRustBridgeEx
   FlutterRustBridgeTaskConstMeta get kHandleOptionBoxArgumentsConstMeta => const FlutterRustBridgeTaskConstMeta(
         debugName: "handle_option_box_arguments",
         argNames: ["i8Box", "u8Box", "i32Box", "i64Box", "f64Box", "boolbox", "structbox"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.handleOptionBoxArguments,
       );
 
   Future<Uint8List> printNote({required Note note, dynamic hint}) {
@@ -667,6 +1425,7 @@ class FlutterRustBridgeExampleSingleBlockTestImpl implements FlutterRustBridgeEx
   FlutterRustBridgeTaskConstMeta get kPrintNoteConstMeta => const FlutterRustBridgeTaskConstMeta(
         debugName: "print_note",
         argNames: ["note"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.printNote,
       );
 
   Future<Weekdays?> handleReturnEnum({required String input, dynamic hint}) {
@@ -683,6 +1442,7 @@ class FlutterRustBridgeExampleSingleBlockTestImpl implements FlutterRustBridgeEx
   FlutterRustBridgeTaskConstMeta get kHandleReturnEnumConstMeta => const FlutterRustBridgeTaskConstMeta(
         debugName: "handle_return_enum",
         argNames: ["input"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.handleReturnEnum,
       );
 
   Future<Weekdays> handleEnumParameter({required Weekdays weekday, dynamic hint}) {
@@ -699,6 +1459,41 @@ class FlutterRustBridgeExampleSingleBlockTestImpl implements FlutterRustBridgeEx
   FlutterRustBridgeTaskConstMeta get kHandleEnumParameterConstMeta => const FlutterRustBridgeTaskConstMeta(
         debugName: "handle_enum_parameter",
         argNames: ["weekday"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.handleEnumParameter,
+      );
+
+  Future<int> httpStatusCode({required HttpStatus status, dynamic hint}) {
+    var arg0 = api2wire_http_status(status);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_http_status_code(port_, arg0),
+      parseSuccessData: _wire2api_i32,
+      constMeta: kHttpStatusCodeConstMeta,
+      argValues: [status],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kHttpStatusCodeConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "http_status_code",
+        argNames: ["status"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.httpStatusCode,
+      );
+
+  Future<HttpStatus?> httpStatusOf({required int code, dynamic hint}) {
+    var arg0 = api2wire_i32(code);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_http_status_of(port_, arg0),
+      parseSuccessData: _wire2api_opt_box_autoadd_http_status,
+      constMeta: kHttpStatusOfConstMeta,
+      argValues: [code],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kHttpStatusOfConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "http_status_of",
+        argNames: ["code"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.httpStatusOf,
       );
 
   Future<void> handleCustomizedStruct({required Customized val, dynamic hint}) {
@@ -715,6 +1510,7 @@ class FlutterRustBridgeExampleSingleBlockTestImpl implements FlutterRustBridgeEx
   FlutterRustBridgeTaskConstMeta get kHandleCustomizedStructConstMeta => const FlutterRustBridgeTaskConstMeta(
         debugName: "handle_customized_struct",
         argNames: ["val"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.handleCustomizedStruct,
       );
 
   Future<KitchenSink> handleEnumStruct({required KitchenSink val, dynamic hint}) {
@@ -731,6 +1527,7 @@ class FlutterRustBridgeExampleSingleBlockTestImpl implements FlutterRustBridgeEx
   FlutterRustBridgeTaskConstMeta get kHandleEnumStructConstMeta => const FlutterRustBridgeTaskConstMeta(
         debugName: "handle_enum_struct",
         argNames: ["val"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.handleEnumStruct,
       );
 
   Future<bool> useImportedStruct({required MyStruct myStruct, dynamic hint}) {
@@ -747,6 +1544,7 @@ class FlutterRustBridgeExampleSingleBlockTestImpl implements FlutterRustBridgeEx
   FlutterRustBridgeTaskConstMeta get kUseImportedStructConstMeta => const FlutterRustBridgeTaskConstMeta(
         debugName: "use_imported_struct",
         argNames: ["myStruct"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.useImportedStruct,
       );
 
   Future<bool> useImportedEnum({required MyEnum myEnum, dynamic hint}) {
@@ -763,6 +1561,7 @@ class FlutterRustBridgeExampleSingleBlockTestImpl implements FlutterRustBridgeEx
   FlutterRustBridgeTaskConstMeta get kUseImportedEnumConstMeta => const FlutterRustBridgeTaskConstMeta(
         debugName: "use_imported_enum",
         argNames: ["myEnum"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.useImportedEnum,
       );
 
   Future<ApplicationSettings> getAppSettings({dynamic hint}) {
@@ -778,6 +1577,7 @@ class FlutterRustBridgeExampleSingleBlockTestImpl implements FlutterRustBridgeEx
   FlutterRustBridgeTaskConstMeta get kGetAppSettingsConstMeta => const FlutterRustBridgeTaskConstMeta(
         debugName: "get_app_settings",
         argNames: [],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.getAppSettings,
       );
 
   Future<ApplicationSettings> getFallibleAppSettings({dynamic hint}) {
@@ -793,6 +1593,7 @@ class FlutterRustBridgeExampleSingleBlockTestImpl implements FlutterRustBridgeEx
   FlutterRustBridgeTaskConstMeta get kGetFallibleAppSettingsConstMeta => const FlutterRustBridgeTaskConstMeta(
         debugName: "get_fallible_app_settings",
         argNames: [],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.getFallibleAppSettings,
       );
 
   Future<bool> isAppEmbedded({required ApplicationSettings appSettings, dynamic hint}) {
@@ -809,6 +1610,7 @@ class FlutterRustBridgeExampleSingleBlockTestImpl implements FlutterRustBridgeEx
   FlutterRustBridgeTaskConstMeta get kIsAppEmbeddedConstMeta => const FlutterRustBridgeTaskConstMeta(
         debugName: "is_app_embedded",
         argNames: ["appSettings"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.isAppEmbedded,
       );
 
   Future<ApplicationMessage> getMessage({dynamic hint}) {
@@ -824,6 +1626,41 @@ class FlutterRustBridgeExampleSingleBlockTestImpl implements FlutterRustBridgeEx
   FlutterRustBridgeTaskConstMeta get kGetMessageConstMeta => const FlutterRustBridgeTaskConstMeta(
         debugName: "get_message",
         argNames: [],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.getMessage,
+      );
+
+  Future<ApplicationMessage> getFallibleMessage({required int index, dynamic hint}) {
+    var arg0 = api2wire_usize(index);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_get_fallible_message(port_, arg0),
+      parseSuccessData: _wire2api_application_message,
+      constMeta: kGetFallibleMessageConstMeta,
+      argValues: [index],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kGetFallibleMessageConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "get_fallible_message",
+        argNames: ["index"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.getFallibleMessage,
+      );
+
+  Future<bool> isExitMessage({required ApplicationMessage message, dynamic hint}) {
+    var arg0 = _platform.api2wire_box_autoadd_application_message(message);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_is_exit_message(port_, arg0),
+      parseSuccessData: _wire2api_bool,
+      constMeta: kIsExitMessageConstMeta,
+      argValues: [message],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kIsExitMessageConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "is_exit_message",
+        argNames: ["message"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.isExitMessage,
       );
 
   Future<Numbers> repeatNumber({required int num, required int times, dynamic hint}) {
@@ -841,6 +1678,7 @@ class FlutterRustBridgeExampleSingleBlockTestImpl implements FlutterRustBridgeEx
   FlutterRustBridgeTaskConstMeta get kRepeatNumberConstMeta => const FlutterRustBridgeTaskConstMeta(
         debugName: "repeat_number",
         argNames: ["num", "times"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.repeatNumber,
       );
 
   Future<Sequences> repeatSequence({required int seq, required int times, dynamic hint}) {
@@ -858,6 +1696,7 @@ class FlutterRustBridgeExampleSingleBlockTestImpl implements FlutterRustBridgeEx
   FlutterRustBridgeTaskConstMeta get kRepeatSequenceConstMeta => const FlutterRustBridgeTaskConstMeta(
         debugName: "repeat_sequence",
         argNames: ["seq", "times"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.repeatSequence,
       );
 
   Future<int?> firstNumber({required Numbers nums, dynamic hint}) {
@@ -874,6 +1713,7 @@ class FlutterRustBridgeExampleSingleBlockTestImpl implements FlutterRustBridgeEx
   FlutterRustBridgeTaskConstMeta get kFirstNumberConstMeta => const FlutterRustBridgeTaskConstMeta(
         debugName: "first_number",
         argNames: ["nums"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.firstNumber,
       );
 
   Future<int?> firstSequence({required Sequences seqs, dynamic hint}) {
@@ -890,6 +1730,7 @@ class FlutterRustBridgeExampleSingleBlockTestImpl implements FlutterRustBridgeEx
   FlutterRustBridgeTaskConstMeta get kFirstSequenceConstMeta => const FlutterRustBridgeTaskConstMeta(
         debugName: "first_sequence",
         argNames: ["seqs"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.firstSequence,
       );
 
   Future<U8Array5> getArray({dynamic hint}) {
@@ -905,6 +1746,7 @@ class FlutterRustBridgeExampleSingleBlockTestImpl implements FlutterRustBridgeEx
   FlutterRustBridgeTaskConstMeta get kGetArrayConstMeta => const FlutterRustBridgeTaskConstMeta(
         debugName: "get_array",
         argNames: [],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.getArray,
       );
 
   Future<PointArray2> getComplexArray({dynamic hint}) {
@@ -920,6 +1762,7 @@ class FlutterRustBridgeExampleSingleBlockTestImpl implements FlutterRustBridgeEx
   FlutterRustBridgeTaskConstMeta get kGetComplexArrayConstMeta => const FlutterRustBridgeTaskConstMeta(
         debugName: "get_complex_array",
         argNames: [],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.getComplexArray,
       );
 
   Future<int> getUsize({required int u, dynamic hint}) {
@@ -936,6 +1779,7 @@ class FlutterRustBridgeExampleSingleBlockTestImpl implements FlutterRustBridgeEx
   FlutterRustBridgeTaskConstMeta get kGetUsizeConstMeta => const FlutterRustBridgeTaskConstMeta(
         debugName: "get_usize",
         argNames: ["u"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.getUsize,
       );
 
   Future<UserId> nextUserId({required UserId userId, dynamic hint}) {
@@ -952,6 +1796,7 @@ class FlutterRustBridgeExampleSingleBlockTestImpl implements FlutterRustBridgeEx
   FlutterRustBridgeTaskConstMeta get kNextUserIdConstMeta => const FlutterRustBridgeTaskConstMeta(
         debugName: "next_user_id",
         argNames: ["userId"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.nextUserId,
       );
 
   Stream<Event> registerEventListener({dynamic hint}) {
@@ -967,6 +1812,7 @@ class FlutterRustBridgeExampleSingleBlockTestImpl implements FlutterRustBridgeEx
   FlutterRustBridgeTaskConstMeta get kRegisterEventListenerConstMeta => const FlutterRustBridgeTaskConstMeta(
         debugName: "register_event_listener",
         argNames: [],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.registerEventListener,
       );
 
   Future<void> closeEventListener({dynamic hint}) {
@@ -982,6 +1828,7 @@ class FlutterRustBridgeExampleSingleBlockTestImpl implements FlutterRustBridgeEx
   FlutterRustBridgeTaskConstMeta get kCloseEventListenerConstMeta => const FlutterRustBridgeTaskConstMeta(
         debugName: "close_event_listener",
         argNames: [],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.closeEventListener,
       );
 
   Future<void> createEvent({required String address, required String payload, dynamic hint}) {
@@ -999,6 +1846,7 @@ class FlutterRustBridgeExampleSingleBlockTestImpl implements FlutterRustBridgeEx
   FlutterRustBridgeTaskConstMeta get kCreateEventConstMeta => const FlutterRustBridgeTaskConstMeta(
         debugName: "create_event",
         argNames: ["address", "payload"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.createEvent,
       );
 
   Stream<Log> handleStreamSinkAt1({required int key, required int max, dynamic hint}) {
@@ -1016,6 +1864,7 @@ class FlutterRustBridgeExampleSingleBlockTestImpl implements FlutterRustBridgeEx
   FlutterRustBridgeTaskConstMeta get kHandleStreamSinkAt1ConstMeta => const FlutterRustBridgeTaskConstMeta(
         debugName: "handle_stream_sink_at_1",
         argNames: ["key", "max"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.handleStreamSinkAt1,
       );
 
   Stream<Log> handleStreamSinkAt2({required int key, required int max, dynamic hint}) {
@@ -1033,6 +1882,7 @@ class FlutterRustBridgeExampleSingleBlockTestImpl implements FlutterRustBridgeEx
   FlutterRustBridgeTaskConstMeta get kHandleStreamSinkAt2ConstMeta => const FlutterRustBridgeTaskConstMeta(
         debugName: "handle_stream_sink_at_2",
         argNames: ["key", "max"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.handleStreamSinkAt2,
       );
 
   Stream<Log> handleStreamSinkAt3({required int key, required int max, dynamic hint}) {
@@ -1050,6 +1900,116 @@ class FlutterRustBridgeExampleSingleBlockTestImpl implements FlutterRustBridgeEx
   FlutterRustBridgeTaskConstMeta get kHandleStreamSinkAt3ConstMeta => const FlutterRustBridgeTaskConstMeta(
         debugName: "handle_stream_sink_at_3",
         argNames: ["key", "max"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.handleStreamSinkAt3,
+      );
+
+  Future<void> callBackWithNumbers({required int max, required void Function(int) callback, dynamic hint}) {
+    var arg0 = api2wire_u32(max);
+    final callbackPort_ = _platform.createCallbackPort<int>(callback, _wire2api_u32);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_call_back_with_numbers(port_, callbackPort_, arg0),
+      parseSuccessData: _wire2api_unit,
+      constMeta: kCallBackWithNumbersConstMeta,
+      argValues: [max],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kCallBackWithNumbersConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "call_back_with_numbers",
+        argNames: ["max"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.callBackWithNumbers,
+      );
+
+  Stream<int> handleStreamClosedByDrop({required int max, dynamic hint}) {
+    var arg0 = api2wire_u32(max);
+    return _platform.executeStream(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_handle_stream_closed_by_drop(port_, arg0),
+      parseSuccessData: _wire2api_u32,
+      constMeta: kHandleStreamClosedByDropConstMeta,
+      argValues: [max],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kHandleStreamClosedByDropConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "handle_stream_closed_by_drop",
+        argNames: ["max"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.handleStreamClosedByDrop,
+      );
+
+  Future<StreamWithInitial<int, int>> countFrom({required int from, required int count, dynamic hint}) {
+    var arg0 = api2wire_u32(from);
+    var arg1 = api2wire_u32(count);
+    return _platform.executeStreamWithInitial(
+        FlutterRustBridgeTask(
+          callFfi: (port_) => _platform.inner.wire_count_from(port_, arg0, arg1),
+          parseSuccessData: _wire2api_u32,
+          constMeta: kCountFromConstMeta,
+          argValues: [from, count],
+          hint: hint,
+        ),
+        parseInitial: _wire2api_u32);
+  }
+
+  FlutterRustBridgeTaskConstMeta get kCountFromConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "count_from",
+        argNames: ["from", "count"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.countFrom,
+      );
+
+  Stream<int> parseNumbers({required String input, dynamic hint}) {
+    var arg0 = _platform.api2wire_String(input);
+    return _platform.executeStream(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_parse_numbers(port_, arg0),
+      parseSuccessData: _wire2api_u32,
+      parseErrorData: _wire2api_parse_error,
+      constMeta: kParseNumbersConstMeta,
+      argValues: [input],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kParseNumbersConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "parse_numbers",
+        argNames: ["input"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.parseNumbers,
+      );
+
+  Stream<int> handleBroadcastStream({required int max, dynamic hint}) {
+    var arg0 = api2wire_u32(max);
+    return _platform.executeStream(
+        FlutterRustBridgeTask(
+          callFfi: (port_) => _platform.inner.wire_handle_broadcast_stream(port_, arg0),
+          parseSuccessData: _wire2api_u32,
+          constMeta: kHandleBroadcastStreamConstMeta,
+          argValues: [max],
+          hint: hint,
+        ),
+        broadcast: true);
+  }
+
+  FlutterRustBridgeTaskConstMeta get kHandleBroadcastStreamConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "handle_broadcast_stream",
+        argNames: ["max"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.handleBroadcastStream,
+      );
+
+  Stream<int> handleStreamClosedByThreads({required int threads, dynamic hint}) {
+    var arg0 = api2wire_u32(threads);
+    return _platform.executeStream(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_handle_stream_closed_by_threads(port_, arg0),
+      parseSuccessData: _wire2api_u32,
+      constMeta: kHandleStreamClosedByThreadsConstMeta,
+      argValues: [threads],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kHandleStreamClosedByThreadsConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "handle_stream_closed_by_threads",
+        argNames: ["threads"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.handleStreamClosedByThreads,
       );
 
   Future<SumWith> getSumStruct({dynamic hint}) {
@@ -1065,6 +2025,7 @@ class FlutterRustBridgeExampleSingleBlockTestImpl implements FlutterRustBridgeEx
   FlutterRustBridgeTaskConstMeta get kGetSumStructConstMeta => const FlutterRustBridgeTaskConstMeta(
         debugName: "get_sum_struct",
         argNames: [],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.getSumStruct,
       );
 
   Future<SumWithArray3> getSumArray({required int a, required int b, required int c, dynamic hint}) {
@@ -1083,162 +2044,450 @@ class FlutterRustBridgeExampleSingleBlockTestImpl implements FlutterRustBridgeEx
   FlutterRustBridgeTaskConstMeta get kGetSumArrayConstMeta => const FlutterRustBridgeTaskConstMeta(
         debugName: "get_sum_array",
         argNames: ["a", "b", "c"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.getSumArray,
       );
 
-  Future<Measure?> multiplyByTen({required Measure measure, dynamic hint}) {
-    var arg0 = _platform.api2wire_box_autoadd_measure(measure);
+  Future<Document> renameDocument({required Document document, required String title, dynamic hint}) {
+    var arg0 = _platform.api2wire_box_autoadd_document(document);
+    var arg1 = _platform.api2wire_String(title);
     return _platform.executeNormal(FlutterRustBridgeTask(
-      callFfi: (port_) => _platform.inner.wire_multiply_by_ten(port_, arg0),
-      parseSuccessData: _wire2api_opt_box_autoadd_measure,
-      constMeta: kMultiplyByTenConstMeta,
-      argValues: [measure],
+      callFfi: (port_) => _platform.inner.wire_rename_document(port_, arg0, arg1),
+      parseSuccessData: _wire2api_document,
+      constMeta: kRenameDocumentConstMeta,
+      argValues: [document, title],
       hint: hint,
     ));
   }
 
-  FlutterRustBridgeTaskConstMeta get kMultiplyByTenConstMeta => const FlutterRustBridgeTaskConstMeta(
-        debugName: "multiply_by_ten",
-        argNames: ["measure"],
+  FlutterRustBridgeTaskConstMeta get kRenameDocumentConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "rename_document",
+        argNames: ["document", "title"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.renameDocument,
       );
 
-  Future<OldSimpleStruct> callOldModuleSystem({dynamic hint}) {
+  Future<Renamed> fetchRenamed({required int id, required Pace pace, dynamic hint}) {
+    var arg0 = api2wire_u32(id);
+    var arg1 = api2wire_pace(pace);
     return _platform.executeNormal(FlutterRustBridgeTask(
-      callFfi: (port_) => _platform.inner.wire_call_old_module_system(port_),
-      parseSuccessData: _wire2api_old_simple_struct,
-      constMeta: kCallOldModuleSystemConstMeta,
-      argValues: [],
+      callFfi: (port_) => _platform.inner.wire_get_renamed(port_, arg0, arg1),
+      parseSuccessData: _wire2api_renamed,
+      constMeta: kFetchRenamedConstMeta,
+      argValues: [id, pace],
       hint: hint,
     ));
   }
 
-  FlutterRustBridgeTaskConstMeta get kCallOldModuleSystemConstMeta => const FlutterRustBridgeTaskConstMeta(
-        debugName: "call_old_module_system",
-        argNames: [],
+  FlutterRustBridgeTaskConstMeta get kFetchRenamedConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "get_renamed",
+        argNames: ["id", "pace"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.fetchRenamed,
       );
 
-  Future<NewSimpleStruct> callNewModuleSystem({dynamic hint}) {
+  Future<Progress> advance({required Progress progress, required int by, dynamic hint}) {
+    var arg0 = _platform.api2wire_box_autoadd_progress(progress);
+    var arg1 = api2wire_u8(by);
     return _platform.executeNormal(FlutterRustBridgeTask(
-      callFfi: (port_) => _platform.inner.wire_call_new_module_system(port_),
-      parseSuccessData: _wire2api_new_simple_struct,
-      constMeta: kCallNewModuleSystemConstMeta,
-      argValues: [],
+      callFfi: (port_) => _platform.inner.wire_advance(port_, arg0, arg1),
+      parseSuccessData: _wire2api_progress,
+      constMeta: kAdvanceConstMeta,
+      argValues: [progress, by],
       hint: hint,
     ));
   }
 
-  FlutterRustBridgeTaskConstMeta get kCallNewModuleSystemConstMeta => const FlutterRustBridgeTaskConstMeta(
-        debugName: "call_new_module_system",
-        argNames: [],
+  FlutterRustBridgeTaskConstMeta get kAdvanceConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "advance",
+        argNames: ["progress", "by"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.advance,
       );
 
-  Future<BigBuffers> handleBigBuffers({dynamic hint}) {
+  Future<ProductId> nextProductId({required ProductId id, dynamic hint}) {
+    var arg0 = _platform.api2wire_box_autoadd_product_id(id);
     return _platform.executeNormal(FlutterRustBridgeTask(
-      callFfi: (port_) => _platform.inner.wire_handle_big_buffers(port_),
-      parseSuccessData: _wire2api_big_buffers,
-      constMeta: kHandleBigBuffersConstMeta,
-      argValues: [],
+      callFfi: (port_) => _platform.inner.wire_next_product_id(port_, arg0),
+      parseSuccessData: _wire2api_product_id,
+      constMeta: kNextProductIdConstMeta,
+      argValues: [id],
       hint: hint,
     ));
   }
 
-  FlutterRustBridgeTaskConstMeta get kHandleBigBuffersConstMeta => const FlutterRustBridgeTaskConstMeta(
-        debugName: "handle_big_buffers",
-        argNames: [],
+  FlutterRustBridgeTaskConstMeta get kNextProductIdConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "next_product_id",
+        argNames: ["id"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.nextProductId,
       );
 
-  Future<DateTime> datetimeUtc({required DateTime d, dynamic hint}) {
-    var arg0 = _platform.api2wire_Chrono_Utc(d);
+  Future<GridCell> transposeCell({required GridCell cell, dynamic hint}) {
+    var arg0 = _platform.api2wire_box_autoadd_grid_cell(cell);
     return _platform.executeNormal(FlutterRustBridgeTask(
-      callFfi: (port_) => _platform.inner.wire_datetime_utc(port_, arg0),
-      parseSuccessData: _wire2api_Chrono_Utc,
-      constMeta: kDatetimeUtcConstMeta,
-      argValues: [d],
+      callFfi: (port_) => _platform.inner.wire_transpose_cell(port_, arg0),
+      parseSuccessData: _wire2api_grid_cell,
+      constMeta: kTransposeCellConstMeta,
+      argValues: [cell],
       hint: hint,
     ));
   }
 
-  FlutterRustBridgeTaskConstMeta get kDatetimeUtcConstMeta => const FlutterRustBridgeTaskConstMeta(
-        debugName: "datetime_utc",
-        argNames: ["d"],
+  FlutterRustBridgeTaskConstMeta get kTransposeCellConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "transpose_cell",
+        argNames: ["cell"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.transposeCell,
       );
 
-  Future<DateTime> datetimeLocal({required DateTime d, dynamic hint}) {
-    var arg0 = _platform.api2wire_Chrono_Local(d);
+  Future<Money> addTax({required Money price, required int percent, dynamic hint}) {
+    var arg0 = _platform.api2wire_box_autoadd_money(price);
+    var arg1 = api2wire_u32(percent);
     return _platform.executeNormal(FlutterRustBridgeTask(
-      callFfi: (port_) => _platform.inner.wire_datetime_local(port_, arg0),
-      parseSuccessData: _wire2api_Chrono_Local,
-      constMeta: kDatetimeLocalConstMeta,
-      argValues: [d],
+      callFfi: (port_) => _platform.inner.wire_add_tax(port_, arg0, arg1),
+      parseSuccessData: _wire2api_money,
+      constMeta: kAddTaxConstMeta,
+      argValues: [price, percent],
       hint: hint,
     ));
   }
 
-  FlutterRustBridgeTaskConstMeta get kDatetimeLocalConstMeta => const FlutterRustBridgeTaskConstMeta(
-        debugName: "datetime_local",
-        argNames: ["d"],
+  FlutterRustBridgeTaskConstMeta get kAddTaxConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "add_tax",
+        argNames: ["price", "percent"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.addTax,
       );
 
-  Future<DateTime> naivedatetime({required DateTime d, dynamic hint}) {
-    var arg0 = _platform.api2wire_Chrono_Naive(d);
+  Future<Version> bumpMinor({required Version version, dynamic hint}) {
+    var arg0 = _platform.api2wire_Codec_Version(version);
     return _platform.executeNormal(FlutterRustBridgeTask(
-      callFfi: (port_) => _platform.inner.wire_naivedatetime(port_, arg0),
-      parseSuccessData: _wire2api_Chrono_Naive,
-      constMeta: kNaivedatetimeConstMeta,
-      argValues: [d],
+      callFfi: (port_) => _platform.inner.wire_bump_minor(port_, arg0),
+      parseSuccessData: _wire2api_Codec_Version,
+      constMeta: kBumpMinorConstMeta,
+      argValues: [version],
       hint: hint,
     ));
   }
 
-  FlutterRustBridgeTaskConstMeta get kNaivedatetimeConstMeta => const FlutterRustBridgeTaskConstMeta(
-        debugName: "naivedatetime",
-        argNames: ["d"],
+  FlutterRustBridgeTaskConstMeta get kBumpMinorConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "bump_minor",
+        argNames: ["version"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.bumpMinor,
       );
 
-  Future<DateTime?> optionalEmptyDatetimeUtc({DateTime? d, dynamic hint}) {
-    var arg0 = _platform.api2wire_opt_box_autoadd_Chrono_Utc(d);
+  Future<Session> openSession({required String user, dynamic hint}) {
+    var arg0 = _platform.api2wire_String(user);
     return _platform.executeNormal(FlutterRustBridgeTask(
-      callFfi: (port_) => _platform.inner.wire_optional_empty_datetime_utc(port_, arg0),
-      parseSuccessData: _wire2api_opt_box_autoadd_Chrono_Utc,
-      constMeta: kOptionalEmptyDatetimeUtcConstMeta,
-      argValues: [d],
+      callFfi: (port_) => _platform.inner.wire_open_session(port_, arg0),
+      parseSuccessData: _wire2api_session,
+      constMeta: kOpenSessionConstMeta,
+      argValues: [user],
       hint: hint,
     ));
   }
 
-  FlutterRustBridgeTaskConstMeta get kOptionalEmptyDatetimeUtcConstMeta => const FlutterRustBridgeTaskConstMeta(
-        debugName: "optional_empty_datetime_utc",
-        argNames: ["d"],
+  FlutterRustBridgeTaskConstMeta get kOpenSessionConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "open_session",
+        argNames: ["user"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.openSession,
       );
 
-  Future<Duration> duration({required Duration d, dynamic hint}) {
-    var arg0 = _platform.api2wire_Chrono_Duration(d);
+  Future<String> describeSession({required Session session, dynamic hint}) {
+    var arg0 = _platform.api2wire_box_autoadd_session(session);
     return _platform.executeNormal(FlutterRustBridgeTask(
-      callFfi: (port_) => _platform.inner.wire_duration(port_, arg0),
-      parseSuccessData: _wire2api_Chrono_Duration,
-      constMeta: kDurationConstMeta,
-      argValues: [d],
+      callFfi: (port_) => _platform.inner.wire_describe_session(port_, arg0),
+      parseSuccessData: _wire2api_String,
+      constMeta: kDescribeSessionConstMeta,
+      argValues: [session],
       hint: hint,
     ));
   }
 
-  FlutterRustBridgeTaskConstMeta get kDurationConstMeta => const FlutterRustBridgeTaskConstMeta(
-        debugName: "duration",
-        argNames: ["d"],
+  FlutterRustBridgeTaskConstMeta get kDescribeSessionConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "describe_session",
+        argNames: ["session"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.describeSession,
       );
 
-  Future<TestChrono> testChrono({dynamic hint}) {
+  Future<Sample> sampleAt({required int index, dynamic hint}) {
+    var arg0 = api2wire_u32(index);
     return _platform.executeNormal(FlutterRustBridgeTask(
-      callFfi: (port_) => _platform.inner.wire_test_chrono(port_),
-      parseSuccessData: _wire2api_test_chrono,
-      constMeta: kTestChronoConstMeta,
-      argValues: [],
+      callFfi: (port_) => _platform.inner.wire_sample_at(port_, arg0),
+      parseSuccessData: _wire2api_sample,
+      constMeta: kSampleAtConstMeta,
+      argValues: [index],
       hint: hint,
     ));
   }
 
-  FlutterRustBridgeTaskConstMeta get kTestChronoConstMeta => const FlutterRustBridgeTaskConstMeta(
+  FlutterRustBridgeTaskConstMeta get kSampleAtConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "sample_at",
+        argNames: ["index"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.sampleAt,
+      );
+
+  Future<Sample> shiftSample({required Sample sample, required double dx, dynamic hint}) {
+    var arg0 = _platform.api2wire_box_autoadd_sample(sample);
+    var arg1 = api2wire_f64(dx);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_shift_sample(port_, arg0, arg1),
+      parseSuccessData: _wire2api_sample,
+      constMeta: kShiftSampleConstMeta,
+      argValues: [sample, dx],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kShiftSampleConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "shift_sample",
+        argNames: ["sample", "dx"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.shiftSample,
+      );
+
+  Future<String> tagLabel({required Tag tag, dynamic hint}) {
+    var arg0 = _platform.api2wire_box_autoadd_tag(tag);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_tag_label(port_, arg0),
+      parseSuccessData: _wire2api_String,
+      constMeta: kTagLabelConstMeta,
+      argValues: [tag],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kTagLabelConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "tag_label",
+        argNames: ["tag"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.tagLabel,
+      );
+
+  Future<String?> headerName({required Uint8List raw, dynamic hint}) {
+    var arg0 = _platform.api2wire_uint_8_list(raw);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_header_name(port_, arg0),
+      parseSuccessData: _wire2api_opt_String,
+      constMeta: kHeaderNameConstMeta,
+      argValues: [raw],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kHeaderNameConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "header_name",
+        argNames: ["raw"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.headerName,
+      );
+
+  Future<Measure?> multiplyByTen({required Measure measure, dynamic hint}) {
+    var arg0 = _platform.api2wire_box_autoadd_measure(measure);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_multiply_by_ten(port_, arg0),
+      parseSuccessData: _wire2api_opt_box_autoadd_measure,
+      constMeta: kMultiplyByTenConstMeta,
+      argValues: [measure],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kMultiplyByTenConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "multiply_by_ten",
+        argNames: ["measure"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.multiplyByTen,
+      );
+
+  Future<OldSimpleStruct> callOldModuleSystem({dynamic hint}) {
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_call_old_module_system(port_),
+      parseSuccessData: _wire2api_old_simple_struct,
+      constMeta: kCallOldModuleSystemConstMeta,
+      argValues: [],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kCallOldModuleSystemConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "call_old_module_system",
+        argNames: [],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.callOldModuleSystem,
+      );
+
+  Future<NewSimpleStruct> callNewModuleSystem({dynamic hint}) {
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_call_new_module_system(port_),
+      parseSuccessData: _wire2api_new_simple_struct,
+      constMeta: kCallNewModuleSystemConstMeta,
+      argValues: [],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kCallNewModuleSystemConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "call_new_module_system",
+        argNames: [],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.callNewModuleSystem,
+      );
+
+  Future<BigBuffers> handleBigBuffers({dynamic hint}) {
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_handle_big_buffers(port_),
+      parseSuccessData: _wire2api_big_buffers,
+      constMeta: kHandleBigBuffersConstMeta,
+      argValues: [],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kHandleBigBuffersConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "handle_big_buffers",
+        argNames: [],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.handleBigBuffers,
+      );
+
+  Future<BigInt> handleI128({required BigInt value, dynamic hint}) {
+    var arg0 = _platform.api2wire_i128(value);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_handle_i128(port_, arg0),
+      parseSuccessData: _wire2api_i128,
+      constMeta: kHandleI128ConstMeta,
+      argValues: [value],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kHandleI128ConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "handle_i128",
+        argNames: ["value"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.handleI128,
+      );
+
+  Future<BigInt> handleU128({required BigInt value, dynamic hint}) {
+    var arg0 = _platform.api2wire_u128(value);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_handle_u128(port_, arg0),
+      parseSuccessData: _wire2api_u128,
+      constMeta: kHandleU128ConstMeta,
+      argValues: [value],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kHandleU128ConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "handle_u128",
+        argNames: ["value"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.handleU128,
+      );
+
+  Future<DateTime> datetimeUtc({required DateTime d, dynamic hint}) {
+    var arg0 = _platform.api2wire_Chrono_Utc(d);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_datetime_utc(port_, arg0),
+      parseSuccessData: _wire2api_Chrono_Utc,
+      constMeta: kDatetimeUtcConstMeta,
+      argValues: [d],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kDatetimeUtcConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "datetime_utc",
+        argNames: ["d"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.datetimeUtc,
+      );
+
+  Future<DateTime> datetimeLocal({required DateTime d, dynamic hint}) {
+    var arg0 = _platform.api2wire_Chrono_Local(d);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_datetime_local(port_, arg0),
+      parseSuccessData: _wire2api_Chrono_Local,
+      constMeta: kDatetimeLocalConstMeta,
+      argValues: [d],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kDatetimeLocalConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "datetime_local",
+        argNames: ["d"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.datetimeLocal,
+      );
+
+  Future<DateTime> naivedatetime({required DateTime d, dynamic hint}) {
+    var arg0 = _platform.api2wire_Chrono_Naive(d);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_naivedatetime(port_, arg0),
+      parseSuccessData: _wire2api_Chrono_Naive,
+      constMeta: kNaivedatetimeConstMeta,
+      argValues: [d],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kNaivedatetimeConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "naivedatetime",
+        argNames: ["d"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.naivedatetime,
+      );
+
+  Future<DateTime?> optionalEmptyDatetimeUtc({DateTime? d, dynamic hint}) {
+    var arg0 = _platform.api2wire_opt_box_autoadd_Chrono_Utc(d);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_optional_empty_datetime_utc(port_, arg0),
+      parseSuccessData: _wire2api_opt_box_autoadd_Chrono_Utc,
+      constMeta: kOptionalEmptyDatetimeUtcConstMeta,
+      argValues: [d],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kOptionalEmptyDatetimeUtcConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "optional_empty_datetime_utc",
+        argNames: ["d"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.optionalEmptyDatetimeUtc,
+      );
+
+  Future<Duration> duration({required Duration d, dynamic hint}) {
+    var arg0 = _platform.api2wire_Chrono_Duration(d);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_duration(port_, arg0),
+      parseSuccessData: _wire2api_Chrono_Duration,
+      constMeta: kDurationConstMeta,
+      argValues: [d],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kDurationConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "duration",
+        argNames: ["d"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.duration,
+      );
+
+  Future<DateTime> datetimeFixedOffset({required DateTime d, dynamic hint}) {
+    var arg0 = _platform.api2wire_Chrono_FixedOffset(d);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_datetime_fixed_offset(port_, arg0),
+      parseSuccessData: _wire2api_Chrono_FixedOffset,
+      constMeta: kDatetimeFixedOffsetConstMeta,
+      argValues: [d],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kDatetimeFixedOffsetConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "datetime_fixed_offset",
+        argNames: ["d"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.datetimeFixedOffset,
+      );
+
+  Future<TestChrono> testChrono({dynamic hint}) {
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_test_chrono(port_),
+      parseSuccessData: _wire2api_test_chrono,
+      constMeta: kTestChronoConstMeta,
+      argValues: [],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kTestChronoConstMeta => const FlutterRustBridgeTaskConstMeta(
         debugName: "test_chrono",
         argNames: [],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.testChrono,
       );
 
   Future<TestChrono> testPreciseChrono({dynamic hint}) {
@@ -1254,6 +2503,7 @@ class FlutterRustBridgeExampleSingleBlockTestImpl implements FlutterRustBridgeEx
   FlutterRustBridgeTaskConstMeta get kTestPreciseChronoConstMeta => const FlutterRustBridgeTaskConstMeta(
         debugName: "test_precise_chrono",
         argNames: [],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.testPreciseChrono,
       );
 
   Future<Duration> howLongDoesItTake({required FeatureChrono mine, dynamic hint}) {
@@ -1270,6 +2520,7 @@ class FlutterRustBridgeExampleSingleBlockTestImpl implements FlutterRustBridgeEx
   FlutterRustBridgeTaskConstMeta get kHowLongDoesItTakeConstMeta => const FlutterRustBridgeTaskConstMeta(
         debugName: "how_long_does_it_take",
         argNames: ["mine"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.howLongDoesItTake,
       );
 
   Future<UuidValue> handleUuid({required UuidValue id, dynamic hint}) {
@@ -1286,6 +2537,7 @@ class FlutterRustBridgeExampleSingleBlockTestImpl implements FlutterRustBridgeEx
   FlutterRustBridgeTaskConstMeta get kHandleUuidConstMeta => const FlutterRustBridgeTaskConstMeta(
         debugName: "handle_uuid",
         argNames: ["id"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.handleUuid,
       );
 
   Future<List<UuidValue>> handleUuids({required List<UuidValue> ids, dynamic hint}) {
@@ -1302,6 +2554,7 @@ class FlutterRustBridgeExampleSingleBlockTestImpl implements FlutterRustBridgeEx
   FlutterRustBridgeTaskConstMeta get kHandleUuidsConstMeta => const FlutterRustBridgeTaskConstMeta(
         debugName: "handle_uuids",
         argNames: ["ids"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.handleUuids,
       );
 
   Future<FeatureUuid> handleNestedUuids({required FeatureUuid ids, dynamic hint}) {
@@ -1318,6 +2571,41 @@ class FlutterRustBridgeExampleSingleBlockTestImpl implements FlutterRustBridgeEx
   FlutterRustBridgeTaskConstMeta get kHandleNestedUuidsConstMeta => const FlutterRustBridgeTaskConstMeta(
         debugName: "handle_nested_uuids",
         argNames: ["ids"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.handleNestedUuids,
+      );
+
+  Future<Decimal> handleDecimal({required Decimal amount, dynamic hint}) {
+    var arg0 = _platform.api2wire_Decimal(amount);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_handle_decimal(port_, arg0),
+      parseSuccessData: _wire2api_Decimal,
+      constMeta: kHandleDecimalConstMeta,
+      argValues: [amount],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kHandleDecimalConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "handle_decimal",
+        argNames: ["amount"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.handleDecimal,
+      );
+
+  Future<Decimal> sumDecimals({required List<Decimal> amounts, dynamic hint}) {
+    var arg0 = _platform.api2wire_list_Decimal(amounts);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_sum_decimals(port_, arg0),
+      parseSuccessData: _wire2api_Decimal,
+      constMeta: kSumDecimalsConstMeta,
+      argValues: [amounts],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kSumDecimalsConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "sum_decimals",
+        argNames: ["amounts"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.sumDecimals,
       );
 
   Future<MessageId> newMsgid({required U8Array32 id, dynamic hint}) {
@@ -1334,6 +2622,7 @@ class FlutterRustBridgeExampleSingleBlockTestImpl implements FlutterRustBridgeEx
   FlutterRustBridgeTaskConstMeta get kNewMsgidConstMeta => const FlutterRustBridgeTaskConstMeta(
         debugName: "new_msgid",
         argNames: ["id"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.newMsgid,
       );
 
   Future<U8Array32> useMsgid({required MessageId id, dynamic hint}) {
@@ -1350,6 +2639,7 @@ class FlutterRustBridgeExampleSingleBlockTestImpl implements FlutterRustBridgeEx
   FlutterRustBridgeTaskConstMeta get kUseMsgidConstMeta => const FlutterRustBridgeTaskConstMeta(
         debugName: "use_msgid",
         argNames: ["id"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.useMsgid,
       );
 
   Future<Blob> boxedBlob({required U8Array1600 blob, dynamic hint}) {
@@ -1366,6 +2656,7 @@ class FlutterRustBridgeExampleSingleBlockTestImpl implements FlutterRustBridgeEx
   FlutterRustBridgeTaskConstMeta get kBoxedBlobConstMeta => const FlutterRustBridgeTaskConstMeta(
         debugName: "boxed_blob",
         argNames: ["blob"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.boxedBlob,
       );
 
   Future<U8Array1600> useBoxedBlob({required Blob blob, dynamic hint}) {
@@ -1382,6 +2673,7 @@ class FlutterRustBridgeExampleSingleBlockTestImpl implements FlutterRustBridgeEx
   FlutterRustBridgeTaskConstMeta get kUseBoxedBlobConstMeta => const FlutterRustBridgeTaskConstMeta(
         debugName: "use_boxed_blob",
         argNames: ["blob"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.useBoxedBlob,
       );
 
   Future<FeedId> returnBoxedFeedId({required U8Array8 id, dynamic hint}) {
@@ -1398,6 +2690,7 @@ class FlutterRustBridgeExampleSingleBlockTestImpl implements FlutterRustBridgeEx
   FlutterRustBridgeTaskConstMeta get kReturnBoxedFeedIdConstMeta => const FlutterRustBridgeTaskConstMeta(
         debugName: "return_boxed_feed_id",
         argNames: ["id"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.returnBoxedFeedId,
       );
 
   Future<U8Array8> returnBoxedRawFeedId({required FeedId id, dynamic hint}) {
@@ -1414,6 +2707,7 @@ class FlutterRustBridgeExampleSingleBlockTestImpl implements FlutterRustBridgeEx
   FlutterRustBridgeTaskConstMeta get kReturnBoxedRawFeedIdConstMeta => const FlutterRustBridgeTaskConstMeta(
         debugName: "return_boxed_raw_feed_id",
         argNames: ["id"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.returnBoxedRawFeedId,
       );
 
   Future<TestId> testId({required TestId id, dynamic hint}) {
@@ -1430,6 +2724,7 @@ class FlutterRustBridgeExampleSingleBlockTestImpl implements FlutterRustBridgeEx
   FlutterRustBridgeTaskConstMeta get kTestIdConstMeta => const FlutterRustBridgeTaskConstMeta(
         debugName: "test_id",
         argNames: ["id"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.testId,
       );
 
   Future<double> lastNumber({required F64Array16 array, dynamic hint}) {
@@ -1446,6 +2741,58 @@ class FlutterRustBridgeExampleSingleBlockTestImpl implements FlutterRustBridgeEx
   FlutterRustBridgeTaskConstMeta get kLastNumberConstMeta => const FlutterRustBridgeTaskConstMeta(
         debugName: "last_number",
         argNames: ["array"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.lastNumber,
+      );
+
+  Future<U8Array4> repeatByte4({required int byte, dynamic hint}) {
+    var arg0 = api2wire_u8(byte);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_repeat_byte_4(port_, arg0),
+      parseSuccessData: _wire2api_u8_array_4,
+      constMeta: kRepeatByte4ConstMeta,
+      argValues: [byte],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kRepeatByte4ConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "repeat_byte_4",
+        argNames: ["byte"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.repeatByte4,
+      );
+
+  Future<U8Array8> repeatByte8({required int byte, dynamic hint}) {
+    var arg0 = api2wire_u8(byte);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_repeat_byte_8(port_, arg0),
+      parseSuccessData: _wire2api_u8_array_8,
+      constMeta: kRepeatByte8ConstMeta,
+      argValues: [byte],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kRepeatByte8ConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "repeat_byte_8",
+        argNames: ["byte"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.repeatByte8,
+      );
+
+  Future<F64Array16> transpose({required F64Array16 matrix, dynamic hint}) {
+    var arg0 = _platform.api2wire_f64_array_16(matrix);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_transpose(port_, arg0),
+      parseSuccessData: _wire2api_f64_array_16,
+      constMeta: kTransposeConstMeta,
+      argValues: [matrix],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kTransposeConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "transpose",
+        argNames: ["matrix"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.transpose,
       );
 
   Future<TestIdArray2> nestedId({required TestIdArray4 id, dynamic hint}) {
@@ -1462,6 +2809,7 @@ class FlutterRustBridgeExampleSingleBlockTestImpl implements FlutterRustBridgeEx
   FlutterRustBridgeTaskConstMeta get kNestedIdConstMeta => const FlutterRustBridgeTaskConstMeta(
         debugName: "nested_id",
         argNames: ["id"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.nestedId,
       );
 
   String syncAcceptDartOpaque({required Object opaque, dynamic hint}) {
@@ -1478,6 +2826,7 @@ class FlutterRustBridgeExampleSingleBlockTestImpl implements FlutterRustBridgeEx
   FlutterRustBridgeTaskConstMeta get kSyncAcceptDartOpaqueConstMeta => const FlutterRustBridgeTaskConstMeta(
         debugName: "sync_accept_dart_opaque",
         argNames: ["opaque"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.syncAcceptDartOpaque,
       );
 
   Future<String> asyncAcceptDartOpaque({required Object opaque, dynamic hint}) {
@@ -1494,6 +2843,7 @@ class FlutterRustBridgeExampleSingleBlockTestImpl implements FlutterRustBridgeEx
   FlutterRustBridgeTaskConstMeta get kAsyncAcceptDartOpaqueConstMeta => const FlutterRustBridgeTaskConstMeta(
         debugName: "async_accept_dart_opaque",
         argNames: ["opaque"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.asyncAcceptDartOpaque,
       );
 
   Future<Object> loopBack({required Object opaque, dynamic hint}) {
@@ -1510,6 +2860,7 @@ class FlutterRustBridgeExampleSingleBlockTestImpl implements FlutterRustBridgeEx
   FlutterRustBridgeTaskConstMeta get kLoopBackConstMeta => const FlutterRustBridgeTaskConstMeta(
         debugName: "loop_back",
         argNames: ["opaque"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.loopBack,
       );
 
   Future<Object?> loopBackOption({required Object opaque, dynamic hint}) {
@@ -1526,6 +2877,7 @@ class FlutterRustBridgeExampleSingleBlockTestImpl implements FlutterRustBridgeEx
   FlutterRustBridgeTaskConstMeta get kLoopBackOptionConstMeta => const FlutterRustBridgeTaskConstMeta(
         debugName: "loop_back_option",
         argNames: ["opaque"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.loopBackOption,
       );
 
   Future<ObjectArray1> loopBackArray({required Object opaque, dynamic hint}) {
@@ -1542,6 +2894,7 @@ class FlutterRustBridgeExampleSingleBlockTestImpl implements FlutterRustBridgeEx
   FlutterRustBridgeTaskConstMeta get kLoopBackArrayConstMeta => const FlutterRustBridgeTaskConstMeta(
         debugName: "loop_back_array",
         argNames: ["opaque"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.loopBackArray,
       );
 
   Future<List<Object>> loopBackVec({required Object opaque, dynamic hint}) {
@@ -1558,6 +2911,7 @@ class FlutterRustBridgeExampleSingleBlockTestImpl implements FlutterRustBridgeEx
   FlutterRustBridgeTaskConstMeta get kLoopBackVecConstMeta => const FlutterRustBridgeTaskConstMeta(
         debugName: "loop_back_vec",
         argNames: ["opaque"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.loopBackVec,
       );
 
   Future<void> loopBackOptionGet({Object? opaque, dynamic hint}) {
@@ -1574,6 +2928,7 @@ class FlutterRustBridgeExampleSingleBlockTestImpl implements FlutterRustBridgeEx
   FlutterRustBridgeTaskConstMeta get kLoopBackOptionGetConstMeta => const FlutterRustBridgeTaskConstMeta(
         debugName: "loop_back_option_get",
         argNames: ["opaque"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.loopBackOptionGet,
       );
 
   Future<void> loopBackArrayGet({required ObjectArray1 opaque, dynamic hint}) {
@@ -1590,6 +2945,7 @@ class FlutterRustBridgeExampleSingleBlockTestImpl implements FlutterRustBridgeEx
   FlutterRustBridgeTaskConstMeta get kLoopBackArrayGetConstMeta => const FlutterRustBridgeTaskConstMeta(
         debugName: "loop_back_array_get",
         argNames: ["opaque"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.loopBackArrayGet,
       );
 
   Future<void> loopBackVecGet({required List<Object> opaque, dynamic hint}) {
@@ -1606,6 +2962,7 @@ class FlutterRustBridgeExampleSingleBlockTestImpl implements FlutterRustBridgeEx
   FlutterRustBridgeTaskConstMeta get kLoopBackVecGetConstMeta => const FlutterRustBridgeTaskConstMeta(
         debugName: "loop_back_vec_get",
         argNames: ["opaque"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.loopBackVecGet,
       );
 
   String unwrapDartOpaque({required Object opaque, dynamic hint}) {
@@ -1622,6 +2979,7 @@ class FlutterRustBridgeExampleSingleBlockTestImpl implements FlutterRustBridgeEx
   FlutterRustBridgeTaskConstMeta get kUnwrapDartOpaqueConstMeta => const FlutterRustBridgeTaskConstMeta(
         debugName: "unwrap_dart_opaque",
         argNames: ["opaque"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.unwrapDartOpaque,
       );
 
   Future<void> panicUnwrapDartOpaque({required Object opaque, dynamic hint}) {
@@ -1638,6 +2996,7 @@ class FlutterRustBridgeExampleSingleBlockTestImpl implements FlutterRustBridgeEx
   FlutterRustBridgeTaskConstMeta get kPanicUnwrapDartOpaqueConstMeta => const FlutterRustBridgeTaskConstMeta(
         debugName: "panic_unwrap_dart_opaque",
         argNames: ["opaque"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.panicUnwrapDartOpaque,
       );
 
   Future<HideData> createOpaque({dynamic hint}) {
@@ -1653,6 +3012,7 @@ class FlutterRustBridgeExampleSingleBlockTestImpl implements FlutterRustBridgeEx
   FlutterRustBridgeTaskConstMeta get kCreateOpaqueConstMeta => const FlutterRustBridgeTaskConstMeta(
         debugName: "create_opaque",
         argNames: [],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.createOpaque,
       );
 
   Future<HideData?> createOptionOpaque({HideData? opaque, dynamic hint}) {
@@ -1669,6 +3029,7 @@ class FlutterRustBridgeExampleSingleBlockTestImpl implements FlutterRustBridgeEx
   FlutterRustBridgeTaskConstMeta get kCreateOptionOpaqueConstMeta => const FlutterRustBridgeTaskConstMeta(
         debugName: "create_option_opaque",
         argNames: ["opaque"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.createOptionOpaque,
       );
 
   HideData syncCreateOpaque({dynamic hint}) {
@@ -1684,6 +3045,7 @@ class FlutterRustBridgeExampleSingleBlockTestImpl implements FlutterRustBridgeEx
   FlutterRustBridgeTaskConstMeta get kSyncCreateOpaqueConstMeta => const FlutterRustBridgeTaskConstMeta(
         debugName: "sync_create_opaque",
         argNames: [],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.syncCreateOpaque,
       );
 
   Future<EnumOpaqueArray5> createArrayOpaqueEnum({dynamic hint}) {
@@ -1699,6 +3061,7 @@ class FlutterRustBridgeExampleSingleBlockTestImpl implements FlutterRustBridgeEx
   FlutterRustBridgeTaskConstMeta get kCreateArrayOpaqueEnumConstMeta => const FlutterRustBridgeTaskConstMeta(
         debugName: "create_array_opaque_enum",
         argNames: [],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.createArrayOpaqueEnum,
       );
 
   Future<String> runEnumOpaque({required EnumOpaque opaque, dynamic hint}) {
@@ -1715,38 +3078,140 @@ class FlutterRustBridgeExampleSingleBlockTestImpl implements FlutterRustBridgeEx
   FlutterRustBridgeTaskConstMeta get kRunEnumOpaqueConstMeta => const FlutterRustBridgeTaskConstMeta(
         debugName: "run_enum_opaque",
         argNames: ["opaque"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.runEnumOpaque,
       );
 
-  Future<String> runOpaque({required HideData opaque, dynamic hint}) {
-    var arg0 = _platform.api2wire_HideData(opaque);
+  Future<BoxDartDebug> createTraitObject({dynamic hint}) {
     return _platform.executeNormal(FlutterRustBridgeTask(
-      callFfi: (port_) => _platform.inner.wire_run_opaque(port_, arg0),
-      parseSuccessData: _wire2api_String,
-      constMeta: kRunOpaqueConstMeta,
-      argValues: [opaque],
+      callFfi: (port_) => _platform.inner.wire_create_trait_object(port_),
+      parseSuccessData: _wire2api_BoxDyn_BoxDartDebug,
+      constMeta: kCreateTraitObjectConstMeta,
+      argValues: [],
       hint: hint,
     ));
   }
 
-  FlutterRustBridgeTaskConstMeta get kRunOpaqueConstMeta => const FlutterRustBridgeTaskConstMeta(
-        debugName: "run_opaque",
-        argNames: ["opaque"],
+  FlutterRustBridgeTaskConstMeta get kCreateTraitObjectConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "create_trait_object",
+        argNames: [],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.createTraitObject,
       );
 
-  Future<String> runOpaqueWithDelay({required HideData opaque, dynamic hint}) {
-    var arg0 = _platform.api2wire_HideData(opaque);
+  Future<BoxDartDebug> createImplTrait({dynamic hint}) {
     return _platform.executeNormal(FlutterRustBridgeTask(
-      callFfi: (port_) => _platform.inner.wire_run_opaque_with_delay(port_, arg0),
-      parseSuccessData: _wire2api_String,
-      constMeta: kRunOpaqueWithDelayConstMeta,
-      argValues: [opaque],
+      callFfi: (port_) => _platform.inner.wire_create_impl_trait(port_),
+      parseSuccessData: _wire2api_Impl_BoxDartDebug,
+      constMeta: kCreateImplTraitConstMeta,
+      argValues: [],
       hint: hint,
     ));
   }
 
-  FlutterRustBridgeTaskConstMeta get kRunOpaqueWithDelayConstMeta => const FlutterRustBridgeTaskConstMeta(
-        debugName: "run_opaque_with_delay",
-        argNames: ["opaque"],
+  FlutterRustBridgeTaskConstMeta get kCreateImplTraitConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "create_impl_trait",
+        argNames: [],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.createImplTrait,
+      );
+
+  Future<String> runTraitObject({required BoxDartDebug obj, dynamic hint}) {
+    var arg0 = _platform.api2wire_DynRef_BoxDartDebug(obj);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_run_trait_object(port_, arg0),
+      parseSuccessData: _wire2api_String,
+      constMeta: kRunTraitObjectConstMeta,
+      argValues: [obj],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kRunTraitObjectConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "run_trait_object",
+        argNames: ["obj"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.runTraitObject,
+      );
+
+  Future<String> runOpaque({required HideData opaque, dynamic hint}) {
+    var arg0 = _platform.api2wire_HideData(opaque);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_run_opaque(port_, arg0),
+      parseSuccessData: _wire2api_String,
+      constMeta: kRunOpaqueConstMeta,
+      argValues: [opaque],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kRunOpaqueConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "run_opaque",
+        argNames: ["opaque"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.runOpaque,
+      );
+
+  Future<HideData> createSharedOpaque({dynamic hint}) {
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_create_shared_opaque(port_),
+      parseSuccessData: _wire2api_Arc_HideData,
+      constMeta: kCreateSharedOpaqueConstMeta,
+      argValues: [],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kCreateSharedOpaqueConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "create_shared_opaque",
+        argNames: [],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.createSharedOpaque,
+      );
+
+  Future<String> runSharedOpaque({required HideData opaque, dynamic hint}) {
+    var arg0 = _platform.api2wire_Arc_HideData(opaque);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_run_shared_opaque(port_, arg0),
+      parseSuccessData: _wire2api_String,
+      constMeta: kRunSharedOpaqueConstMeta,
+      argValues: [opaque],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kRunSharedOpaqueConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "run_shared_opaque",
+        argNames: ["opaque"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.runSharedOpaque,
+      );
+
+  Future<int> sharedOpaqueAddress({required HideData opaque, dynamic hint}) {
+    var arg0 = _platform.api2wire_Arc_HideData(opaque);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_shared_opaque_address(port_, arg0),
+      parseSuccessData: _wire2api_usize,
+      constMeta: kSharedOpaqueAddressConstMeta,
+      argValues: [opaque],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kSharedOpaqueAddressConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "shared_opaque_address",
+        argNames: ["opaque"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.sharedOpaqueAddress,
+      );
+
+  Future<String> runOpaqueWithDelay({required HideData opaque, dynamic hint}) {
+    var arg0 = _platform.api2wire_HideData(opaque);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_run_opaque_with_delay(port_, arg0),
+      parseSuccessData: _wire2api_String,
+      constMeta: kRunOpaqueWithDelayConstMeta,
+      argValues: [opaque],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kRunOpaqueWithDelayConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "run_opaque_with_delay",
+        argNames: ["opaque"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.runOpaqueWithDelay,
       );
 
   Future<HideDataArray2> opaqueArray({dynamic hint}) {
@@ -1762,6 +3227,7 @@ class FlutterRustBridgeExampleSingleBlockTestImpl implements FlutterRustBridgeEx
   FlutterRustBridgeTaskConstMeta get kOpaqueArrayConstMeta => const FlutterRustBridgeTaskConstMeta(
         debugName: "opaque_array",
         argNames: [],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.opaqueArray,
       );
 
   Future<NonSendHideData> createSyncOpaque({dynamic hint}) {
@@ -1777,6 +3243,7 @@ class FlutterRustBridgeExampleSingleBlockTestImpl implements FlutterRustBridgeEx
   FlutterRustBridgeTaskConstMeta get kCreateSyncOpaqueConstMeta => const FlutterRustBridgeTaskConstMeta(
         debugName: "create_sync_opaque",
         argNames: [],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.createSyncOpaque,
       );
 
   NonSendHideData syncCreateSyncOpaque({dynamic hint}) {
@@ -1792,6 +3259,7 @@ class FlutterRustBridgeExampleSingleBlockTestImpl implements FlutterRustBridgeEx
   FlutterRustBridgeTaskConstMeta get kSyncCreateSyncOpaqueConstMeta => const FlutterRustBridgeTaskConstMeta(
         debugName: "sync_create_sync_opaque",
         argNames: [],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.syncCreateSyncOpaque,
       );
 
   String syncRunOpaque({required NonSendHideData opaque, dynamic hint}) {
@@ -1808,6 +3276,7 @@ class FlutterRustBridgeExampleSingleBlockTestImpl implements FlutterRustBridgeEx
   FlutterRustBridgeTaskConstMeta get kSyncRunOpaqueConstMeta => const FlutterRustBridgeTaskConstMeta(
         debugName: "sync_run_opaque",
         argNames: ["opaque"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.syncRunOpaque,
       );
 
   Future<void> opaqueArrayRun({required HideDataArray2 data, dynamic hint}) {
@@ -1824,6 +3293,7 @@ class FlutterRustBridgeExampleSingleBlockTestImpl implements FlutterRustBridgeEx
   FlutterRustBridgeTaskConstMeta get kOpaqueArrayRunConstMeta => const FlutterRustBridgeTaskConstMeta(
         debugName: "opaque_array_run",
         argNames: ["data"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.opaqueArrayRun,
       );
 
   Future<List<HideData>> opaqueVec({dynamic hint}) {
@@ -1839,6 +3309,7 @@ class FlutterRustBridgeExampleSingleBlockTestImpl implements FlutterRustBridgeEx
   FlutterRustBridgeTaskConstMeta get kOpaqueVecConstMeta => const FlutterRustBridgeTaskConstMeta(
         debugName: "opaque_vec",
         argNames: [],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.opaqueVec,
       );
 
   Future<void> opaqueVecRun({required List<HideData> data, dynamic hint}) {
@@ -1855,6 +3326,7 @@ class FlutterRustBridgeExampleSingleBlockTestImpl implements FlutterRustBridgeEx
   FlutterRustBridgeTaskConstMeta get kOpaqueVecRunConstMeta => const FlutterRustBridgeTaskConstMeta(
         debugName: "opaque_vec_run",
         argNames: ["data"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.opaqueVecRun,
       );
 
   Future<OpaqueNested> createNestedOpaque({dynamic hint}) {
@@ -1870,6 +3342,7 @@ class FlutterRustBridgeExampleSingleBlockTestImpl implements FlutterRustBridgeEx
   FlutterRustBridgeTaskConstMeta get kCreateNestedOpaqueConstMeta => const FlutterRustBridgeTaskConstMeta(
         debugName: "create_nested_opaque",
         argNames: [],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.createNestedOpaque,
       );
 
   Object syncLoopback({required Object opaque, dynamic hint}) {
@@ -1886,6 +3359,7 @@ class FlutterRustBridgeExampleSingleBlockTestImpl implements FlutterRustBridgeEx
   FlutterRustBridgeTaskConstMeta get kSyncLoopbackConstMeta => const FlutterRustBridgeTaskConstMeta(
         debugName: "sync_loopback",
         argNames: ["opaque"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.syncLoopback,
       );
 
   Object? syncOptionLoopback({Object? opaque, dynamic hint}) {
@@ -1902,6 +3376,7 @@ class FlutterRustBridgeExampleSingleBlockTestImpl implements FlutterRustBridgeEx
   FlutterRustBridgeTaskConstMeta get kSyncOptionLoopbackConstMeta => const FlutterRustBridgeTaskConstMeta(
         debugName: "sync_option_loopback",
         argNames: ["opaque"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.syncOptionLoopback,
       );
 
   String? syncOption({dynamic hint}) {
@@ -1914,9 +3389,20 @@ class FlutterRustBridgeExampleSingleBlockTestImpl implements FlutterRustBridgeEx
     ));
   }
 
+  SyncResult<String?> syncOptionResult({dynamic hint}) {
+    return _platform.executeSyncResult(FlutterRustBridgeSyncTask(
+      callFfi: () => _platform.inner.wire_sync_option(),
+      parseSuccessData: _wire2api_opt_String,
+      constMeta: kSyncOptionConstMeta,
+      argValues: [],
+      hint: hint,
+    ));
+  }
+
   FlutterRustBridgeTaskConstMeta get kSyncOptionConstMeta => const FlutterRustBridgeTaskConstMeta(
         debugName: "sync_option",
         argNames: [],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.syncOption,
       );
 
   String? syncOptionNull({dynamic hint}) {
@@ -1929,9 +3415,20 @@ class FlutterRustBridgeExampleSingleBlockTestImpl implements FlutterRustBridgeEx
     ));
   }
 
+  SyncResult<String?> syncOptionNullResult({dynamic hint}) {
+    return _platform.executeSyncResult(FlutterRustBridgeSyncTask(
+      callFfi: () => _platform.inner.wire_sync_option_null(),
+      parseSuccessData: _wire2api_opt_String,
+      constMeta: kSyncOptionNullConstMeta,
+      argValues: [],
+      hint: hint,
+    ));
+  }
+
   FlutterRustBridgeTaskConstMeta get kSyncOptionNullConstMeta => const FlutterRustBridgeTaskConstMeta(
         debugName: "sync_option_null",
         argNames: [],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.syncOptionNull,
       );
 
   HideData? syncOptionRustOpaque({dynamic hint}) {
@@ -1944,9 +3441,20 @@ class FlutterRustBridgeExampleSingleBlockTestImpl implements FlutterRustBridgeEx
     ));
   }
 
+  SyncResult<HideData?> syncOptionRustOpaqueResult({dynamic hint}) {
+    return _platform.executeSyncResult(FlutterRustBridgeSyncTask(
+      callFfi: () => _platform.inner.wire_sync_option_rust_opaque(),
+      parseSuccessData: _wire2api_opt_box_autoadd_HideData,
+      constMeta: kSyncOptionRustOpaqueConstMeta,
+      argValues: [],
+      hint: hint,
+    ));
+  }
+
   FlutterRustBridgeTaskConstMeta get kSyncOptionRustOpaqueConstMeta => const FlutterRustBridgeTaskConstMeta(
         debugName: "sync_option_rust_opaque",
         argNames: [],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.syncOptionRustOpaque,
       );
 
   Object? syncOptionDartOpaque({required Object opaque, dynamic hint}) {
@@ -1960,9 +3468,21 @@ class FlutterRustBridgeExampleSingleBlockTestImpl implements FlutterRustBridgeEx
     ));
   }
 
+  SyncResult<Object?> syncOptionDartOpaqueResult({required Object opaque, dynamic hint}) {
+    var arg0 = _platform.api2wire_DartOpaque(opaque);
+    return _platform.executeSyncResult(FlutterRustBridgeSyncTask(
+      callFfi: () => _platform.inner.wire_sync_option_dart_opaque(arg0),
+      parseSuccessData: _wire2api_opt_box_autoadd_DartOpaque,
+      constMeta: kSyncOptionDartOpaqueConstMeta,
+      argValues: [opaque],
+      hint: hint,
+    ));
+  }
+
   FlutterRustBridgeTaskConstMeta get kSyncOptionDartOpaqueConstMeta => const FlutterRustBridgeTaskConstMeta(
         debugName: "sync_option_dart_opaque",
         argNames: ["opaque"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.syncOptionDartOpaque,
       );
 
   void syncVoid({dynamic hint}) {
@@ -1978,6 +3498,7 @@ class FlutterRustBridgeExampleSingleBlockTestImpl implements FlutterRustBridgeEx
   FlutterRustBridgeTaskConstMeta get kSyncVoidConstMeta => const FlutterRustBridgeTaskConstMeta(
         debugName: "sync_void",
         argNames: [],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.syncVoid,
       );
 
   Future<void> runNestedOpaque({required OpaqueNested opaque, dynamic hint}) {
@@ -1994,6 +3515,7 @@ class FlutterRustBridgeExampleSingleBlockTestImpl implements FlutterRustBridgeEx
   FlutterRustBridgeTaskConstMeta get kRunNestedOpaqueConstMeta => const FlutterRustBridgeTaskConstMeta(
         debugName: "run_nested_opaque",
         argNames: ["opaque"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.runNestedOpaque,
       );
 
   Future<DartOpaqueNested> createNestedDartOpaque({required Object opaque1, required Object opaque2, dynamic hint}) {
@@ -2011,6 +3533,7 @@ class FlutterRustBridgeExampleSingleBlockTestImpl implements FlutterRustBridgeEx
   FlutterRustBridgeTaskConstMeta get kCreateNestedDartOpaqueConstMeta => const FlutterRustBridgeTaskConstMeta(
         debugName: "create_nested_dart_opaque",
         argNames: ["opaque1", "opaque2"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.createNestedDartOpaque,
       );
 
   Future<void> getNestedDartOpaque({required DartOpaqueNested opaque, dynamic hint}) {
@@ -2027,6 +3550,7 @@ class FlutterRustBridgeExampleSingleBlockTestImpl implements FlutterRustBridgeEx
   FlutterRustBridgeTaskConstMeta get kGetNestedDartOpaqueConstMeta => const FlutterRustBridgeTaskConstMeta(
         debugName: "get_nested_dart_opaque",
         argNames: ["opaque"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.getNestedDartOpaque,
       );
 
   Future<EnumDartOpaque> createEnumDartOpaque({required Object opaque, dynamic hint}) {
@@ -2043,6 +3567,7 @@ class FlutterRustBridgeExampleSingleBlockTestImpl implements FlutterRustBridgeEx
   FlutterRustBridgeTaskConstMeta get kCreateEnumDartOpaqueConstMeta => const FlutterRustBridgeTaskConstMeta(
         debugName: "create_enum_dart_opaque",
         argNames: ["opaque"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.createEnumDartOpaque,
       );
 
   Future<void> getEnumDartOpaque({required EnumDartOpaque opaque, dynamic hint}) {
@@ -2059,6 +3584,7 @@ class FlutterRustBridgeExampleSingleBlockTestImpl implements FlutterRustBridgeEx
   FlutterRustBridgeTaskConstMeta get kGetEnumDartOpaqueConstMeta => const FlutterRustBridgeTaskConstMeta(
         debugName: "get_enum_dart_opaque",
         argNames: ["opaque"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.getEnumDartOpaque,
       );
 
   Future<void> setStaticDartOpaque({required Object opaque, dynamic hint}) {
@@ -2075,6 +3601,7 @@ class FlutterRustBridgeExampleSingleBlockTestImpl implements FlutterRustBridgeEx
   FlutterRustBridgeTaskConstMeta get kSetStaticDartOpaqueConstMeta => const FlutterRustBridgeTaskConstMeta(
         debugName: "set_static_dart_opaque",
         argNames: ["opaque"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.setStaticDartOpaque,
       );
 
   Future<void> dropStaticDartOpaque({dynamic hint}) {
@@ -2090,6 +3617,7 @@ class FlutterRustBridgeExampleSingleBlockTestImpl implements FlutterRustBridgeEx
   FlutterRustBridgeTaskConstMeta get kDropStaticDartOpaqueConstMeta => const FlutterRustBridgeTaskConstMeta(
         debugName: "drop_static_dart_opaque",
         argNames: [],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.dropStaticDartOpaque,
       );
 
   Future<String> unwrapRustOpaque({required HideData opaque, dynamic hint}) {
@@ -2106,6 +3634,7 @@ class FlutterRustBridgeExampleSingleBlockTestImpl implements FlutterRustBridgeEx
   FlutterRustBridgeTaskConstMeta get kUnwrapRustOpaqueConstMeta => const FlutterRustBridgeTaskConstMeta(
         debugName: "unwrap_rust_opaque",
         argNames: ["opaque"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.unwrapRustOpaque,
       );
 
   Object returnNonDroppableDartOpaque({required Object opaque, dynamic hint}) {
@@ -2122,6 +3651,7 @@ class FlutterRustBridgeExampleSingleBlockTestImpl implements FlutterRustBridgeEx
   FlutterRustBridgeTaskConstMeta get kReturnNonDroppableDartOpaqueConstMeta => const FlutterRustBridgeTaskConstMeta(
         debugName: "return_non_droppable_dart_opaque",
         argNames: ["opaque"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.returnNonDroppableDartOpaque,
       );
 
   Future<FrbOpaqueReturn> frbGeneratorTest({dynamic hint}) {
@@ -2137,6 +3667,7 @@ class FlutterRustBridgeExampleSingleBlockTestImpl implements FlutterRustBridgeEx
   FlutterRustBridgeTaskConstMeta get kFrbGeneratorTestConstMeta => const FlutterRustBridgeTaskConstMeta(
         debugName: "frb_generator_test",
         argNames: [],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.frbGeneratorTest,
       );
 
   FrbOpaqueSyncReturn frbSyncGeneratorTest({dynamic hint}) {
@@ -2152,10 +3683,45 @@ class FlutterRustBridgeExampleSingleBlockTestImpl implements FlutterRustBridgeEx
   FlutterRustBridgeTaskConstMeta get kFrbSyncGeneratorTestConstMeta => const FlutterRustBridgeTaskConstMeta(
         debugName: "frb_sync_generator_test",
         argNames: [],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.frbSyncGeneratorTest,
+      );
+
+  Future<WeakCounter> observeCounter({required Counter counter, dynamic hint}) {
+    var arg0 = _platform.api2wire_Counter(counter);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_observe_counter(port_, arg0),
+      parseSuccessData: _wire2api_Weak_Counter,
+      constMeta: kObserveCounterConstMeta,
+      argValues: [counter],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kObserveCounterConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "observe_counter",
+        argNames: ["counter"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.observeCounter,
+      );
+
+  Future<bool> isCounterAlive({required WeakCounter counter, dynamic hint}) {
+    var arg0 = _platform.api2wire_Weak_Counter(counter);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_is_counter_alive(port_, arg0),
+      parseSuccessData: _wire2api_bool,
+      constMeta: kIsCounterAliveConstMeta,
+      argValues: [counter],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kIsCounterAliveConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "is_counter_alive",
+        argNames: ["counter"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.isCounterAlive,
       );
 
   Future<int> handleTypeAliasId({required int input, dynamic hint}) {
-    var arg0 = _platform.api2wire_u64(input);
+    var arg0 = api2wire_u64(input);
     return _platform.executeNormal(FlutterRustBridgeTask(
       callFfi: (port_) => _platform.inner.wire_handle_type_alias_id(port_, arg0),
       parseSuccessData: _wire2api_u64,
@@ -2168,10 +3734,11 @@ class FlutterRustBridgeExampleSingleBlockTestImpl implements FlutterRustBridgeEx
   FlutterRustBridgeTaskConstMeta get kHandleTypeAliasIdConstMeta => const FlutterRustBridgeTaskConstMeta(
         debugName: "handle_type_alias_id",
         argNames: ["input"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.handleTypeAliasId,
       );
 
   Future<int> handleTypeNestAliasId({required int input, dynamic hint}) {
-    var arg0 = _platform.api2wire_u64(input);
+    var arg0 = api2wire_u64(input);
     return _platform.executeNormal(FlutterRustBridgeTask(
       callFfi: (port_) => _platform.inner.wire_handle_type_nest_alias_id(port_, arg0),
       parseSuccessData: _wire2api_u64,
@@ -2184,10 +3751,11 @@ class FlutterRustBridgeExampleSingleBlockTestImpl implements FlutterRustBridgeEx
   FlutterRustBridgeTaskConstMeta get kHandleTypeNestAliasIdConstMeta => const FlutterRustBridgeTaskConstMeta(
         debugName: "handle_type_nest_alias_id",
         argNames: ["input"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.handleTypeNestAliasId,
       );
 
   Future<TestModel> handleTypeAliasModel({required int input, dynamic hint}) {
-    var arg0 = _platform.api2wire_u64(input);
+    var arg0 = api2wire_u64(input);
     return _platform.executeNormal(FlutterRustBridgeTask(
       callFfi: (port_) => _platform.inner.wire_handle_type_alias_model(port_, arg0),
       parseSuccessData: _wire2api_test_model,
@@ -2200,6 +3768,7 @@ class FlutterRustBridgeExampleSingleBlockTestImpl implements FlutterRustBridgeEx
   FlutterRustBridgeTaskConstMeta get kHandleTypeAliasModelConstMeta => const FlutterRustBridgeTaskConstMeta(
         debugName: "handle_type_alias_model",
         argNames: ["input"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.handleTypeAliasModel,
       );
 
   Future<Empty> emptyStruct({required Empty empty, dynamic hint}) {
@@ -2216,6 +3785,178 @@ class FlutterRustBridgeExampleSingleBlockTestImpl implements FlutterRustBridgeEx
   FlutterRustBridgeTaskConstMeta get kEmptyStructConstMeta => const FlutterRustBridgeTaskConstMeta(
         debugName: "empty_struct",
         argNames: ["empty"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.emptyStruct,
+      );
+
+  Future<Map<String, int>> handleHashMap({required Map<String, int> map, dynamic hint}) {
+    var arg0 = _platform.api2wire_hash_map_String_i32(map);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_handle_hash_map(port_, arg0),
+      parseSuccessData: _wire2api_hash_map_String_i32,
+      constMeta: kHandleHashMapConstMeta,
+      argValues: [map],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kHandleHashMapConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "handle_hash_map",
+        argNames: ["map"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.handleHashMap,
+      );
+
+  Future<Map<int, Attribute>> handleHashMapOfStruct({required Map<int, Attribute> map, dynamic hint}) {
+    var arg0 = _platform.api2wire_hash_map_u32_attribute(map);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_handle_hash_map_of_struct(port_, arg0),
+      parseSuccessData: _wire2api_hash_map_u32_attribute,
+      constMeta: kHandleHashMapOfStructConstMeta,
+      argValues: [map],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kHandleHashMapOfStructConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "handle_hash_map_of_struct",
+        argNames: ["map"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.handleHashMapOfStruct,
+      );
+
+  Future<Map<Weekdays, int>> countWeekdays({required List<Weekdays> days, dynamic hint}) {
+    var arg0 = _platform.api2wire_list_weekdays(days);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_count_weekdays(port_, arg0),
+      parseSuccessData: _wire2api_hash_map_weekdays_u32,
+      constMeta: kCountWeekdaysConstMeta,
+      argValues: [days],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kCountWeekdaysConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "count_weekdays",
+        argNames: ["days"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.countWeekdays,
+      );
+
+  Future<Map<int, String>> indexWords({required List<String> words, dynamic hint}) {
+    var arg0 = _platform.api2wire_StringList(words);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_index_words(port_, arg0),
+      parseSuccessData: _wire2api_hash_map_usize_String,
+      constMeta: kIndexWordsConstMeta,
+      argValues: [words],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kIndexWordsConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "index_words",
+        argNames: ["words"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.indexWords,
+      );
+
+  Future<Map<CollidingKey, int>> handleCollidingKeys({required Map<CollidingKey, int> map, dynamic hint}) {
+    var arg0 = _platform.api2wire_hash_map_colliding_key_u32(map);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_handle_colliding_keys(port_, arg0),
+      parseSuccessData: _wire2api_hash_map_colliding_key_u32,
+      constMeta: kHandleCollidingKeysConstMeta,
+      argValues: [map],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kHandleCollidingKeysConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "handle_colliding_keys",
+        argNames: ["map"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.handleCollidingKeys,
+      );
+
+  Future<Set<String>> handleBtreeSet({required Set<String> set, dynamic hint}) {
+    var arg0 = _platform.api2wire_btree_set_String(set);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_handle_btree_set(port_, arg0),
+      parseSuccessData: _wire2api_btree_set_String,
+      constMeta: kHandleBtreeSetConstMeta,
+      argValues: [set],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kHandleBtreeSetConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "handle_btree_set",
+        argNames: ["set"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.handleBtreeSet,
+      );
+
+  Future<Map<int, String>> handleBtreeMap({required Map<int, String> map, dynamic hint}) {
+    var arg0 = _platform.api2wire_btree_map_i32_String(map);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_handle_btree_map(port_, arg0),
+      parseSuccessData: _wire2api_btree_map_i32_String,
+      constMeta: kHandleBtreeMapConstMeta,
+      argValues: [map],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kHandleBtreeMapConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "handle_btree_map",
+        argNames: ["map"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.handleBtreeMap,
+      );
+
+  Future<Map<String, List<Attribute>>> groupAttributes({required List<Attribute> attributes, dynamic hint}) {
+    var arg0 = _platform.api2wire_list_attribute(attributes);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_group_attributes(port_, arg0),
+      parseSuccessData: _wire2api_btree_map_String_list_attribute,
+      constMeta: kGroupAttributesConstMeta,
+      argValues: [attributes],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kGroupAttributesConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "group_attributes",
+        argNames: ["attributes"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.groupAttributes,
+      );
+
+  Future<Tuple3<bool, String, int>> handleTuple({required Tuple3<int, String, bool> tuple, dynamic hint}) {
+    var arg0 = _platform.api2wire_box_autoadd_tuple3_i32_String_bool(tuple);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_handle_tuple(port_, arg0),
+      parseSuccessData: _wire2api_tuple3_bool_String_i32,
+      constMeta: kHandleTupleConstMeta,
+      argValues: [tuple],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kHandleTupleConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "handle_tuple",
+        argNames: ["tuple"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.handleTuple,
+      );
+
+  Future<Tuple2<Tuple2<String, bool>, int>> handleNestedTuple(
+      {required Tuple2<int, Tuple2<String, bool>> tuple, dynamic hint}) {
+    var arg0 = _platform.api2wire_box_autoadd_tuple2_i32_tuple2_String_bool(tuple);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_handle_nested_tuple(port_, arg0),
+      parseSuccessData: _wire2api_tuple2_tuple2_String_bool_i32,
+      constMeta: kHandleNestedTupleConstMeta,
+      argValues: [tuple],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kHandleNestedTupleConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "handle_nested_tuple",
+        argNames: ["tuple"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.handleNestedTuple,
       );
 
   Future<int> sumMethodSumWith({required SumWith that, required int y, required int z, dynamic hint}) {
@@ -2234,6 +3975,7 @@ class FlutterRustBridgeExampleSingleBlockTestImpl implements FlutterRustBridgeEx
   FlutterRustBridgeTaskConstMeta get kSumMethodSumWithConstMeta => const FlutterRustBridgeTaskConstMeta(
         debugName: "sum__method__SumWith",
         argNames: ["that", "y", "z"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.sumMethodSumWith,
       );
 
   Future<ConcatenateWith> newStaticMethodConcatenateWith({required String a, dynamic hint}) {
@@ -2250,6 +3992,7 @@ class FlutterRustBridgeExampleSingleBlockTestImpl implements FlutterRustBridgeEx
   FlutterRustBridgeTaskConstMeta get kNewStaticMethodConcatenateWithConstMeta => const FlutterRustBridgeTaskConstMeta(
         debugName: "new__static_method__ConcatenateWith",
         argNames: ["a"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.newStaticMethodConcatenateWith,
       );
 
   Future<String> concatenateMethodConcatenateWith({required ConcatenateWith that, required String b, dynamic hint}) {
@@ -2267,6 +4010,7 @@ class FlutterRustBridgeExampleSingleBlockTestImpl implements FlutterRustBridgeEx
   FlutterRustBridgeTaskConstMeta get kConcatenateMethodConcatenateWithConstMeta => const FlutterRustBridgeTaskConstMeta(
         debugName: "concatenate__method__ConcatenateWith",
         argNames: ["that", "b"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.concatenateMethodConcatenateWith,
       );
 
   Future<String> concatenateStaticStaticMethodConcatenateWith({required String a, required String b, dynamic hint}) {
@@ -2285,6 +4029,7 @@ class FlutterRustBridgeExampleSingleBlockTestImpl implements FlutterRustBridgeEx
       const FlutterRustBridgeTaskConstMeta(
         debugName: "concatenate_static__static_method__ConcatenateWith",
         argNames: ["a", "b"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.concatenateStaticStaticMethodConcatenateWith,
       );
 
   Stream<Log2> handleSomeStreamSinkMethodConcatenateWith(
@@ -2306,6 +4051,7 @@ class FlutterRustBridgeExampleSingleBlockTestImpl implements FlutterRustBridgeEx
       const FlutterRustBridgeTaskConstMeta(
         debugName: "handle_some_stream_sink__method__ConcatenateWith",
         argNames: ["that", "key", "max"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.handleSomeStreamSinkMethodConcatenateWith,
       );
 
   Stream<int> handleSomeStreamSinkAt1MethodConcatenateWith({required ConcatenateWith that, dynamic hint}) {
@@ -2323,6 +4069,7 @@ class FlutterRustBridgeExampleSingleBlockTestImpl implements FlutterRustBridgeEx
       const FlutterRustBridgeTaskConstMeta(
         debugName: "handle_some_stream_sink_at_1__method__ConcatenateWith",
         argNames: ["that"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.handleSomeStreamSinkAt1MethodConcatenateWith,
       );
 
   Stream<Log2> handleSomeStaticStreamSinkStaticMethodConcatenateWith(
@@ -2343,6 +4090,7 @@ class FlutterRustBridgeExampleSingleBlockTestImpl implements FlutterRustBridgeEx
       const FlutterRustBridgeTaskConstMeta(
         debugName: "handle_some_static_stream_sink__static_method__ConcatenateWith",
         argNames: ["key", "max"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.handleSomeStaticStreamSinkStaticMethodConcatenateWith,
       );
 
   Stream<int> handleSomeStaticStreamSinkSingleArgStaticMethodConcatenateWith({dynamic hint}) {
@@ -2360,51 +4108,728 @@ class FlutterRustBridgeExampleSingleBlockTestImpl implements FlutterRustBridgeEx
       const FlutterRustBridgeTaskConstMeta(
         debugName: "handle_some_static_stream_sink_single_arg__static_method__ConcatenateWith",
         argNames: [],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction
+            .handleSomeStaticStreamSinkSingleArgStaticMethodConcatenateWith,
       );
 
-  DropFnType get dropOpaqueBoxDartDebug => _platform.inner.drop_opaque_BoxDartDebug;
-  ShareFnType get shareOpaqueBoxDartDebug => _platform.inner.share_opaque_BoxDartDebug;
-  OpaqueTypeFinalizer get BoxDartDebugFinalizer => _platform.BoxDartDebugFinalizer;
+  Future<Counter> startStaticMethodCounter({required int from, dynamic hint}) {
+    var arg0 = api2wire_i32(from);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_start__static_method__Counter(port_, arg0),
+      parseSuccessData: _wire2api_Counter,
+      constMeta: kStartStaticMethodCounterConstMeta,
+      argValues: [from],
+      hint: hint,
+    ));
+  }
 
-  DropFnType get dropOpaqueFrbOpaqueReturn => _platform.inner.drop_opaque_FrbOpaqueReturn;
-  ShareFnType get shareOpaqueFrbOpaqueReturn => _platform.inner.share_opaque_FrbOpaqueReturn;
-  OpaqueTypeFinalizer get FrbOpaqueReturnFinalizer => _platform.FrbOpaqueReturnFinalizer;
+  FlutterRustBridgeTaskConstMeta get kStartStaticMethodCounterConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "start__static_method__Counter",
+        argNames: ["from"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.startStaticMethodCounter,
+      );
 
-  DropFnType get dropOpaqueFrbOpaqueSyncReturn => _platform.inner.drop_opaque_FrbOpaqueSyncReturn;
-  ShareFnType get shareOpaqueFrbOpaqueSyncReturn => _platform.inner.share_opaque_FrbOpaqueSyncReturn;
-  OpaqueTypeFinalizer get FrbOpaqueSyncReturnFinalizer => _platform.FrbOpaqueSyncReturnFinalizer;
+  Future<int> incrementMethodCounter({required Counter that, required int by, dynamic hint}) {
+    var arg0 = _platform.api2wire_Counter(that);
+    var arg1 = api2wire_i32(by);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_increment__method__Counter(port_, arg0, arg1),
+      parseSuccessData: _wire2api_i32,
+      constMeta: kIncrementMethodCounterConstMeta,
+      argValues: [that, by],
+      hint: hint,
+    ));
+  }
 
-  DropFnType get dropOpaqueHideData => _platform.inner.drop_opaque_HideData;
-  ShareFnType get shareOpaqueHideData => _platform.inner.share_opaque_HideData;
-  OpaqueTypeFinalizer get HideDataFinalizer => _platform.HideDataFinalizer;
+  FlutterRustBridgeTaskConstMeta get kIncrementMethodCounterConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "increment__method__Counter",
+        argNames: ["that", "by"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.incrementMethodCounter,
+      );
 
-  DropFnType get dropOpaqueI32 => _platform.inner.drop_opaque_I32;
-  ShareFnType get shareOpaqueI32 => _platform.inner.share_opaque_I32;
-  OpaqueTypeFinalizer get I32Finalizer => _platform.I32Finalizer;
+  Future<String> labelMethodCounter({required Counter that, dynamic hint}) {
+    var arg0 = _platform.api2wire_Counter(that);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_label__method__Counter(port_, arg0),
+      parseSuccessData: _wire2api_String,
+      constMeta: kLabelMethodCounterConstMeta,
+      argValues: [that],
+      hint: hint,
+    ));
+  }
 
-  DropFnType get dropOpaqueMutexHideData => _platform.inner.drop_opaque_MutexHideData;
-  ShareFnType get shareOpaqueMutexHideData => _platform.inner.share_opaque_MutexHideData;
-  OpaqueTypeFinalizer get MutexHideDataFinalizer => _platform.MutexHideDataFinalizer;
+  FlutterRustBridgeTaskConstMeta get kLabelMethodCounterConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "label__method__Counter",
+        argNames: ["that"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.labelMethodCounter,
+      );
 
-  DropFnType get dropOpaqueNonSendHideData => _platform.inner.drop_opaque_NonSendHideData;
-  ShareFnType get shareOpaqueNonSendHideData => _platform.inner.share_opaque_NonSendHideData;
-  OpaqueTypeFinalizer get NonSendHideDataFinalizer => _platform.NonSendHideDataFinalizer;
+  Future<void> resetMethodCounter({required Counter that, required int to, dynamic hint}) {
+    var arg0 = _platform.api2wire_Counter(that);
+    var arg1 = api2wire_i32(to);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_reset__method__Counter(port_, arg0, arg1),
+      parseSuccessData: _wire2api_unit,
+      constMeta: kResetMethodCounterConstMeta,
+      argValues: [that, to],
+      hint: hint,
+    ));
+  }
 
-  DropFnType get dropOpaqueRwLockHideData => _platform.inner.drop_opaque_RwLockHideData;
-  ShareFnType get shareOpaqueRwLockHideData => _platform.inner.share_opaque_RwLockHideData;
-  OpaqueTypeFinalizer get RwLockHideDataFinalizer => _platform.RwLockHideDataFinalizer;
+  FlutterRustBridgeTaskConstMeta get kResetMethodCounterConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "reset__method__Counter",
+        argNames: ["that", "to"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.resetMethodCounter,
+      );
 
-  void dispose() {
-    _platform.dispose();
+  Stream<int> countDownMethodCounter({required Counter that, dynamic hint}) {
+    var arg0 = _platform.api2wire_Counter(that);
+    return _platform.executeIterator<DartIteratorI32, int>(
+        FlutterRustBridgeTask(
+          callFfi: (port_) => _platform.inner.wire_count_down__method__Counter(port_, arg0),
+          parseSuccessData: _wire2api_DartIteratorI32,
+          constMeta: kCountDownMethodCounterConstMeta,
+          argValues: [that],
+          hint: hint,
+        ),
+        next: (iterator) => nextDartIteratorI32(iterator: iterator));
   }
-// Section: wire2api
 
-  BoxDartDebug _wire2api_BoxDartDebug(dynamic raw) {
-    return BoxDartDebug.fromRaw(raw[0], raw[1], this);
+  FlutterRustBridgeTaskConstMeta get kCountDownMethodCounterConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "count_down__method__Counter",
+        argNames: ["that"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.countDownMethodCounter,
+      );
+
+  Future<int> incrementLaterMethodCounter({required Counter that, required int by, dynamic hint}) {
+    var arg0 = _platform.api2wire_Counter(that);
+    var arg1 = api2wire_i32(by);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_increment_later__method__Counter(port_, arg0, arg1),
+      parseSuccessData: _wire2api_i32,
+      constMeta: kIncrementLaterMethodCounterConstMeta,
+      argValues: [that, by],
+      hint: hint,
+    ));
   }
 
-  Duration _wire2api_Chrono_Duration(dynamic raw) {
-    return wire2apiDuration(_wire2api_i64(raw));
+  FlutterRustBridgeTaskConstMeta get kIncrementLaterMethodCounterConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "increment_later__method__Counter",
+        argNames: ["that", "by"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.incrementLaterMethodCounter,
+      );
+
+  Stream<int> updatesMethodCounter({required Counter that, dynamic hint}) {
+    var arg0 = _platform.api2wire_Counter(that);
+    return _platform.executeStream(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_updates__method__Counter(port_, arg0),
+      parseSuccessData: _wire2api_i32,
+      constMeta: kUpdatesMethodCounterConstMeta,
+      argValues: [that],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kUpdatesMethodCounterConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "updates__method__Counter",
+        argNames: ["that"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.updatesMethodCounter,
+      );
+
+  Future<int> finishMethodCounter({required Counter that, dynamic hint}) {
+    var arg0 = _platform.api2wire_Counter(that);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_finish__method__Counter(port_, arg0),
+      parseSuccessData: _wire2api_i32,
+      constMeta: kFinishMethodCounterConstMeta,
+      argValues: [that],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kFinishMethodCounterConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "finish__method__Counter",
+        argNames: ["that"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.finishMethodCounter,
+      );
+
+  Future<Rooms> openStaticMethodRooms({dynamic hint}) {
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_open__static_method__Rooms(port_),
+      parseSuccessData: _wire2api_Rooms,
+      constMeta: kOpenStaticMethodRoomsConstMeta,
+      argValues: [],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kOpenStaticMethodRoomsConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "open__static_method__Rooms",
+        argNames: [],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.openStaticMethodRooms,
+      );
+
+  Stream<String> joinMethodRooms({required Rooms that, required String room, dynamic hint}) {
+    var arg0 = _platform.api2wire_Rooms(that);
+    var arg1 = _platform.api2wire_String(room);
+    return _platform.executeStream(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_join__method__Rooms(port_, arg0, arg1),
+      parseSuccessData: _wire2api_String,
+      constMeta: kJoinMethodRoomsConstMeta,
+      argValues: [that, room],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kJoinMethodRoomsConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "join__method__Rooms",
+        argNames: ["that", "room"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.joinMethodRooms,
+      );
+
+  Future<int> postMethodRooms({required Rooms that, required String room, required String message, dynamic hint}) {
+    var arg0 = _platform.api2wire_Rooms(that);
+    var arg1 = _platform.api2wire_String(room);
+    var arg2 = _platform.api2wire_String(message);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_post__method__Rooms(port_, arg0, arg1, arg2),
+      parseSuccessData: _wire2api_u32,
+      constMeta: kPostMethodRoomsConstMeta,
+      argValues: [that, room, message],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kPostMethodRoomsConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "post__method__Rooms",
+        argNames: ["that", "room", "message"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.postMethodRooms,
+      );
+
+  Future<int> closeRoomMethodRooms({required Rooms that, required String room, dynamic hint}) {
+    var arg0 = _platform.api2wire_Rooms(that);
+    var arg1 = _platform.api2wire_String(room);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_close_room__method__Rooms(port_, arg0, arg1),
+      parseSuccessData: _wire2api_u32,
+      constMeta: kCloseRoomMethodRoomsConstMeta,
+      argValues: [that, room],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kCloseRoomMethodRoomsConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "close_room__method__Rooms",
+        argNames: ["that", "room"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.closeRoomMethodRooms,
+      );
+
+  Future<Greeter> namedStaticMethodGreeter({required String name, dynamic hint}) {
+    var arg0 = _platform.api2wire_String(name);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_named__static_method__Greeter(port_, arg0),
+      parseSuccessData: _wire2api_Greeter,
+      constMeta: kNamedStaticMethodGreeterConstMeta,
+      argValues: [name],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kNamedStaticMethodGreeterConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "named__static_method__Greeter",
+        argNames: ["name"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.namedStaticMethodGreeter,
+      );
+
+  Future<String> describeMethodCounter({required Counter that, dynamic hint}) {
+    var arg0 = _platform.api2wire_Counter(that);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_describe__method__Counter(port_, arg0),
+      parseSuccessData: _wire2api_String,
+      constMeta: kDescribeMethodCounterConstMeta,
+      argValues: [that],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kDescribeMethodCounterConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "describe__method__Counter",
+        argNames: ["that"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.describeMethodCounter,
+      );
+
+  Future<String> shoutMethodCounter({required Counter that, dynamic hint}) {
+    var arg0 = _platform.api2wire_Counter(that);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_shout__method__Counter(port_, arg0),
+      parseSuccessData: _wire2api_String,
+      constMeta: kShoutMethodCounterConstMeta,
+      argValues: [that],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kShoutMethodCounterConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "shout__method__Counter",
+        argNames: ["that"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.shoutMethodCounter,
+      );
+
+  Future<String> describeMethodGreeter({required Greeter that, dynamic hint}) {
+    var arg0 = _platform.api2wire_Greeter(that);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_describe__method__Greeter(port_, arg0),
+      parseSuccessData: _wire2api_String,
+      constMeta: kDescribeMethodGreeterConstMeta,
+      argValues: [that],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kDescribeMethodGreeterConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "describe__method__Greeter",
+        argNames: ["that"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.describeMethodGreeter,
+      );
+
+  Future<String> shoutMethodGreeter({required Greeter that, dynamic hint}) {
+    var arg0 = _platform.api2wire_Greeter(that);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_shout__method__Greeter(port_, arg0),
+      parseSuccessData: _wire2api_String,
+      constMeta: kShoutMethodGreeterConstMeta,
+      argValues: [that],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kShoutMethodGreeterConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "shout__method__Greeter",
+        argNames: ["that"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.shoutMethodGreeter,
+      );
+
+  Future<int> dispatch({required String id, required int x, dynamic hint}) {
+    var arg0 = _platform.api2wire_String(id);
+    var arg1 = api2wire_i32(x);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_dispatch(port_, arg0, arg1),
+      parseSuccessData: _wire2api_i32,
+      constMeta: kDispatchConstMeta,
+      argValues: [id, x],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kDispatchConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "dispatch",
+        argNames: ["id", "x"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.dispatch,
+      );
+
+  Future<String?> nextDartIteratorString({required DartIteratorString iterator, dynamic hint}) {
+    var arg0 = _platform.api2wire_DartIteratorString(iterator);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_next_dart_iterator_string(port_, arg0),
+      parseSuccessData: _wire2api_opt_String,
+      constMeta: kNextDartIteratorStringConstMeta,
+      argValues: [iterator],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kNextDartIteratorStringConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "next_dart_iterator_string",
+        argNames: ["iterator"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.nextDartIteratorString,
+      );
+
+  Future<int?> nextDartIteratorI32({required DartIteratorI32 iterator, dynamic hint}) {
+    var arg0 = _platform.api2wire_DartIteratorI32(iterator);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_next_dart_iterator_i_32(port_, arg0),
+      parseSuccessData: _wire2api_opt_box_autoadd_i32,
+      constMeta: kNextDartIteratorI32ConstMeta,
+      argValues: [iterator],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kNextDartIteratorI32ConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "next_dart_iterator_i_32",
+        argNames: ["iterator"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.nextDartIteratorI32,
+      );
+
+  Stream<void> changesMethodCounter({required Counter that, dynamic hint}) {
+    var arg0 = _platform.api2wire_Counter(that);
+    return _platform.executeStream(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_changes__method__Counter(port_, arg0),
+      parseSuccessData: _wire2api_unit,
+      constMeta: kChangesMethodCounterConstMeta,
+      argValues: [that],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kChangesMethodCounterConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "changes__method__Counter",
+        argNames: ["that"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.changesMethodCounter,
+      );
+
+  Future<void> warmUp({dynamic hint}) {
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_warm_up(port_),
+      parseSuccessData: _wire2api_unit,
+      constMeta: kWarmUpConstMeta,
+      argValues: [],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kWarmUpConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "warm_up",
+        argNames: [],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.warmUp,
+      );
+
+  Future<Counter?> upgradeWeakCounter({required WeakCounter weak, dynamic hint}) {
+    var arg0 = _platform.api2wire_WeakCounter(weak);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_upgrade_weak_counter(port_, arg0),
+      parseSuccessData: _wire2api_opt_box_autoadd_Counter,
+      constMeta: kUpgradeWeakCounterConstMeta,
+      argValues: [weak],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kUpgradeWeakCounterConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "upgrade_weak_counter",
+        argNames: ["weak"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.upgradeWeakCounter,
+      );
+
+  Future<Duration> instantDurationSince({required Instant that, required Instant earlier, dynamic hint}) {
+    var arg0 = _platform.api2wire_Instant(that);
+    var arg1 = _platform.api2wire_Instant(earlier);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_instant_duration_since(port_, arg0, arg1),
+      parseSuccessData: _wire2api_Chrono_Duration,
+      constMeta: kInstantDurationSinceConstMeta,
+      argValues: [that, earlier],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kInstantDurationSinceConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "instant_duration_since",
+        argNames: ["that", "earlier"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.instantDurationSince,
+      );
+
+  List<String> handleStreamDrain({required String arg, dynamic hint}) {
+    var arg0 = _platform.api2wire_String(arg);
+    return _platform.executeSyncDrain(FlutterRustBridgeSyncTask(
+      callFfi: () => _platform.inner.wire_handle_stream_drain(arg0),
+      parseSuccessData: _wire2api_String,
+      constMeta: kHandleStreamDrainConstMeta,
+      argValues: [arg],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kHandleStreamDrainConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "handle_stream",
+        argNames: ["arg"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.handleStreamDrain,
+      );
+
+  List<MyStreamEntry> handleStreamOfStructDrain({dynamic hint}) {
+    return _platform.executeSyncDrain(FlutterRustBridgeSyncTask(
+      callFfi: () => _platform.inner.wire_handle_stream_of_struct_drain(),
+      parseSuccessData: _wire2api_my_stream_entry,
+      constMeta: kHandleStreamOfStructDrainConstMeta,
+      argValues: [],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kHandleStreamOfStructDrainConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "handle_stream_of_struct",
+        argNames: [],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.handleStreamOfStructDrain,
+      );
+
+  List<Event> registerEventListenerDrain({dynamic hint}) {
+    return _platform.executeSyncDrain(FlutterRustBridgeSyncTask(
+      callFfi: () => _platform.inner.wire_register_event_listener_drain(),
+      parseSuccessData: _wire2api_event,
+      constMeta: kRegisterEventListenerDrainConstMeta,
+      argValues: [],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kRegisterEventListenerDrainConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "register_event_listener",
+        argNames: [],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.registerEventListenerDrain,
+      );
+
+  List<Log> handleStreamSinkAt1Drain({required int key, required int max, dynamic hint}) {
+    var arg0 = api2wire_u32(key);
+    var arg1 = api2wire_u32(max);
+    return _platform.executeSyncDrain(FlutterRustBridgeSyncTask(
+      callFfi: () => _platform.inner.wire_handle_stream_sink_at_1_drain(arg0, arg1),
+      parseSuccessData: _wire2api_log,
+      constMeta: kHandleStreamSinkAt1DrainConstMeta,
+      argValues: [key, max],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kHandleStreamSinkAt1DrainConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "handle_stream_sink_at_1",
+        argNames: ["key", "max"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.handleStreamSinkAt1Drain,
+      );
+
+  List<Log> handleStreamSinkAt2Drain({required int key, required int max, dynamic hint}) {
+    var arg0 = api2wire_u32(key);
+    var arg1 = api2wire_u32(max);
+    return _platform.executeSyncDrain(FlutterRustBridgeSyncTask(
+      callFfi: () => _platform.inner.wire_handle_stream_sink_at_2_drain(arg0, arg1),
+      parseSuccessData: _wire2api_log,
+      constMeta: kHandleStreamSinkAt2DrainConstMeta,
+      argValues: [key, max],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kHandleStreamSinkAt2DrainConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "handle_stream_sink_at_2",
+        argNames: ["key", "max"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.handleStreamSinkAt2Drain,
+      );
+
+  List<Log> handleStreamSinkAt3Drain({required int key, required int max, dynamic hint}) {
+    var arg0 = api2wire_u32(key);
+    var arg1 = api2wire_u32(max);
+    return _platform.executeSyncDrain(FlutterRustBridgeSyncTask(
+      callFfi: () => _platform.inner.wire_handle_stream_sink_at_3_drain(arg0, arg1),
+      parseSuccessData: _wire2api_log,
+      constMeta: kHandleStreamSinkAt3DrainConstMeta,
+      argValues: [key, max],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kHandleStreamSinkAt3DrainConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "handle_stream_sink_at_3",
+        argNames: ["key", "max"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.handleStreamSinkAt3Drain,
+      );
+
+  List<int> handleStreamClosedByDropDrain({required int max, dynamic hint}) {
+    var arg0 = api2wire_u32(max);
+    return _platform.executeSyncDrain(FlutterRustBridgeSyncTask(
+      callFfi: () => _platform.inner.wire_handle_stream_closed_by_drop_drain(arg0),
+      parseSuccessData: _wire2api_u32,
+      constMeta: kHandleStreamClosedByDropDrainConstMeta,
+      argValues: [max],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kHandleStreamClosedByDropDrainConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "handle_stream_closed_by_drop",
+        argNames: ["max"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.handleStreamClosedByDropDrain,
+      );
+
+  List<int> countFromDrain({required int from, required int count, dynamic hint}) {
+    var arg0 = api2wire_u32(from);
+    var arg1 = api2wire_u32(count);
+    return _platform.executeSyncDrain(FlutterRustBridgeSyncTask(
+      callFfi: () => _platform.inner.wire_count_from_drain(arg0, arg1),
+      parseSuccessData: _wire2api_u32,
+      constMeta: kCountFromDrainConstMeta,
+      argValues: [from, count],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kCountFromDrainConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "count_from",
+        argNames: ["from", "count"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.countFromDrain,
+      );
+
+  List<int> parseNumbersDrain({required String input, dynamic hint}) {
+    var arg0 = _platform.api2wire_String(input);
+    return _platform.executeSyncDrain(FlutterRustBridgeSyncTask(
+      callFfi: () => _platform.inner.wire_parse_numbers_drain(arg0),
+      parseSuccessData: _wire2api_u32,
+      parseErrorData: _wire2api_parse_error,
+      constMeta: kParseNumbersDrainConstMeta,
+      argValues: [input],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kParseNumbersDrainConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "parse_numbers",
+        argNames: ["input"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.parseNumbersDrain,
+      );
+
+  List<int> handleBroadcastStreamDrain({required int max, dynamic hint}) {
+    var arg0 = api2wire_u32(max);
+    return _platform.executeSyncDrain(FlutterRustBridgeSyncTask(
+      callFfi: () => _platform.inner.wire_handle_broadcast_stream_drain(arg0),
+      parseSuccessData: _wire2api_u32,
+      constMeta: kHandleBroadcastStreamDrainConstMeta,
+      argValues: [max],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kHandleBroadcastStreamDrainConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "handle_broadcast_stream",
+        argNames: ["max"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.handleBroadcastStreamDrain,
+      );
+
+  List<int> handleStreamClosedByThreadsDrain({required int threads, dynamic hint}) {
+    var arg0 = api2wire_u32(threads);
+    return _platform.executeSyncDrain(FlutterRustBridgeSyncTask(
+      callFfi: () => _platform.inner.wire_handle_stream_closed_by_threads_drain(arg0),
+      parseSuccessData: _wire2api_u32,
+      constMeta: kHandleStreamClosedByThreadsDrainConstMeta,
+      argValues: [threads],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kHandleStreamClosedByThreadsDrainConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "handle_stream_closed_by_threads",
+        argNames: ["threads"],
+        function: FlutterRustBridgeExampleSingleBlockTestFunction.handleStreamClosedByThreadsDrain,
+      );
+
+  DropFnType get dropOpaqueBoxDartDebug => _platform.inner.drop_opaque_BoxDartDebug;
+  ShareFnType get shareOpaqueBoxDartDebug => _platform.inner.share_opaque_BoxDartDebug;
+  ShareFnType get sendOpaqueBoxDartDebug => _platform.inner.send_opaque_BoxDartDebug;
+  OpaqueTypeFinalizer get BoxDartDebugFinalizer => _platform.BoxDartDebugFinalizer;
+
+  DropFnType get dropOpaqueCounter => _platform.inner.drop_opaque_Counter;
+  ShareFnType get shareOpaqueCounter => _platform.inner.share_opaque_Counter;
+  ShareFnType get sendOpaqueCounter => _platform.inner.send_opaque_Counter;
+  OpaqueTypeFinalizer get CounterFinalizer => _platform.CounterFinalizer;
+
+  DropFnType get dropOpaqueDartIteratorI32 => _platform.inner.drop_opaque_DartIteratorI32;
+  ShareFnType get shareOpaqueDartIteratorI32 => _platform.inner.share_opaque_DartIteratorI32;
+  ShareFnType get sendOpaqueDartIteratorI32 => _platform.inner.send_opaque_DartIteratorI32;
+  OpaqueTypeFinalizer get DartIteratorI32Finalizer => _platform.DartIteratorI32Finalizer;
+
+  DropFnType get dropOpaqueDartIteratorString => _platform.inner.drop_opaque_DartIteratorString;
+  ShareFnType get shareOpaqueDartIteratorString => _platform.inner.share_opaque_DartIteratorString;
+  ShareFnType get sendOpaqueDartIteratorString => _platform.inner.send_opaque_DartIteratorString;
+  OpaqueTypeFinalizer get DartIteratorStringFinalizer => _platform.DartIteratorStringFinalizer;
+
+  DropFnType get dropOpaqueFrbOpaqueReturn => _platform.inner.drop_opaque_FrbOpaqueReturn;
+  ShareFnType get shareOpaqueFrbOpaqueReturn => _platform.inner.share_opaque_FrbOpaqueReturn;
+  ShareFnType get sendOpaqueFrbOpaqueReturn => _platform.inner.send_opaque_FrbOpaqueReturn;
+  OpaqueTypeFinalizer get FrbOpaqueReturnFinalizer => _platform.FrbOpaqueReturnFinalizer;
+
+  DropFnType get dropOpaqueFrbOpaqueSyncReturn => _platform.inner.drop_opaque_FrbOpaqueSyncReturn;
+  ShareFnType get shareOpaqueFrbOpaqueSyncReturn => _platform.inner.share_opaque_FrbOpaqueSyncReturn;
+  ShareFnType get sendOpaqueFrbOpaqueSyncReturn => _platform.inner.send_opaque_FrbOpaqueSyncReturn;
+  OpaqueTypeFinalizer get FrbOpaqueSyncReturnFinalizer => _platform.FrbOpaqueSyncReturnFinalizer;
+
+  DropFnType get dropOpaqueGreeter => _platform.inner.drop_opaque_Greeter;
+  ShareFnType get shareOpaqueGreeter => _platform.inner.share_opaque_Greeter;
+  ShareFnType get sendOpaqueGreeter => _platform.inner.send_opaque_Greeter;
+  OpaqueTypeFinalizer get GreeterFinalizer => _platform.GreeterFinalizer;
+
+  DropFnType get dropOpaqueHideData => _platform.inner.drop_opaque_HideData;
+  ShareFnType get shareOpaqueHideData => _platform.inner.share_opaque_HideData;
+  ShareFnType get sendOpaqueHideData => _platform.inner.send_opaque_HideData;
+  OpaqueTypeFinalizer get HideDataFinalizer => _platform.HideDataFinalizer;
+
+  DropFnType get dropOpaqueI32 => _platform.inner.drop_opaque_I32;
+  ShareFnType get shareOpaqueI32 => _platform.inner.share_opaque_I32;
+  ShareFnType get sendOpaqueI32 => _platform.inner.send_opaque_I32;
+  OpaqueTypeFinalizer get I32Finalizer => _platform.I32Finalizer;
+
+  DropFnType get dropOpaqueInstant => _platform.inner.drop_opaque_Instant;
+  ShareFnType get shareOpaqueInstant => _platform.inner.share_opaque_Instant;
+  ShareFnType get sendOpaqueInstant => _platform.inner.send_opaque_Instant;
+  OpaqueTypeFinalizer get InstantFinalizer => _platform.InstantFinalizer;
+
+  DropFnType get dropOpaqueMutexHideData => _platform.inner.drop_opaque_MutexHideData;
+  ShareFnType get shareOpaqueMutexHideData => _platform.inner.share_opaque_MutexHideData;
+  ShareFnType get sendOpaqueMutexHideData => _platform.inner.send_opaque_MutexHideData;
+  OpaqueTypeFinalizer get MutexHideDataFinalizer => _platform.MutexHideDataFinalizer;
+
+  DropFnType get dropOpaqueNonSendHideData => _platform.inner.drop_opaque_NonSendHideData;
+  ShareFnType get shareOpaqueNonSendHideData => _platform.inner.share_opaque_NonSendHideData;
+  ShareFnType get sendOpaqueNonSendHideData => _platform.inner.send_opaque_NonSendHideData;
+  OpaqueTypeFinalizer get NonSendHideDataFinalizer => _platform.NonSendHideDataFinalizer;
+
+  DropFnType get dropOpaqueRooms => _platform.inner.drop_opaque_Rooms;
+  ShareFnType get shareOpaqueRooms => _platform.inner.share_opaque_Rooms;
+  ShareFnType get sendOpaqueRooms => _platform.inner.send_opaque_Rooms;
+  OpaqueTypeFinalizer get RoomsFinalizer => _platform.RoomsFinalizer;
+
+  DropFnType get dropOpaqueRwLockHideData => _platform.inner.drop_opaque_RwLockHideData;
+  ShareFnType get shareOpaqueRwLockHideData => _platform.inner.share_opaque_RwLockHideData;
+  ShareFnType get sendOpaqueRwLockHideData => _platform.inner.send_opaque_RwLockHideData;
+  OpaqueTypeFinalizer get RwLockHideDataFinalizer => _platform.RwLockHideDataFinalizer;
+
+  DropFnType get dropOpaqueWeakCounter => _platform.inner.drop_opaque_WeakCounter;
+  ShareFnType get shareOpaqueWeakCounter => _platform.inner.share_opaque_WeakCounter;
+  ShareFnType get sendOpaqueWeakCounter => _platform.inner.send_opaque_WeakCounter;
+  OpaqueTypeFinalizer get WeakCounterFinalizer => _platform.WeakCounterFinalizer;
+
+  SharedBuffer createSharedBuffer(List<int> data) => SharedBuffer.copy(_platform.inner, data);
+
+  SharedBuffer allocateSharedBuffer(int length, void Function(SharedBufferWriter bytes) fill) =>
+      SharedBuffer.fill(_platform.inner, length, fill);
+
+  void dispose() {
+    _platform.dispose();
+  }
+// Section: wire2api
+
+  HideData _wire2api_Arc_HideData(dynamic raw) {
+    return _wire2api_HideData(raw);
+  }
+
+  String _wire2api_Backtrace(dynamic raw) {
+    return raw as String;
+  }
+
+  BoxDartDebug _wire2api_BoxDartDebug(dynamic raw) {
+    return BoxDartDebug.fromRaw(raw[0], raw[1], this);
+  }
+
+  BoxDartDebug _wire2api_BoxDyn_BoxDartDebug(dynamic raw) {
+    return _wire2api_BoxDartDebug(raw);
+  }
+
+  Uint8List _wire2api_Bytes(dynamic raw) {
+    return raw as Uint8List;
+  }
+
+  Duration _wire2api_Chrono_Duration(dynamic raw) {
+    return wire2apiDuration(_wire2api_i64(raw));
+  }
+
+  DateTime _wire2api_Chrono_FixedOffset(dynamic raw) {
+    return wire2apiTimestamp(ts: _wire2api_i64(raw), isUtc: true);
   }
 
   DateTime _wire2api_Chrono_Local(dynamic raw) {
@@ -2419,10 +4844,38 @@ class FlutterRustBridgeExampleSingleBlockTestImpl implements FlutterRustBridgeEx
     return wire2apiTimestamp(ts: _wire2api_i64(raw), isUtc: true);
   }
 
+  Version _wire2api_Codec_Version(dynamic raw) {
+    return VersionCodec.decode(_wire2api_uint_8_list(raw));
+  }
+
+  Counter _wire2api_Counter(dynamic raw) {
+    return Counter.fromRaw(raw[0], raw[1], this);
+  }
+
+  Uint8List _wire2api_Cow_Bytes(dynamic raw) {
+    return raw as Uint8List;
+  }
+
+  String _wire2api_Cow_String(dynamic raw) {
+    return raw as String;
+  }
+
+  DartIteratorI32 _wire2api_DartIteratorI32(dynamic raw) {
+    return DartIteratorI32.fromRaw(raw[0], raw[1], this);
+  }
+
+  DartIteratorString _wire2api_DartIteratorString(dynamic raw) {
+    return DartIteratorString.fromRaw(raw[0], raw[1], this);
+  }
+
   Object _wire2api_DartOpaque(dynamic raw) {
     return _platform.inner.get_dart_object(raw);
   }
 
+  Decimal _wire2api_Decimal(dynamic raw) {
+    return Decimal.parse(raw as String);
+  }
+
   EnumOpaqueArray5 _wire2api_EnumOpaque_array_5(dynamic raw) {
     return EnumOpaqueArray5((raw as List<dynamic>).map(_wire2api_enum_opaque).toList());
   }
@@ -2435,6 +4888,10 @@ class FlutterRustBridgeExampleSingleBlockTestImpl implements FlutterRustBridgeEx
     return FrbOpaqueSyncReturn.fromRaw(raw[0], raw[1], this);
   }
 
+  Greeter _wire2api_Greeter(dynamic raw) {
+    return Greeter.fromRaw(raw[0], raw[1], this);
+  }
+
   HideData _wire2api_HideData(dynamic raw) {
     return HideData.fromRaw(raw[0], raw[1], this);
   }
@@ -2447,6 +4904,18 @@ class FlutterRustBridgeExampleSingleBlockTestImpl implements FlutterRustBridgeEx
     return I32.fromRaw(raw[0], raw[1], this);
   }
 
+  BoxDartDebug _wire2api_Impl_BoxDartDebug(dynamic raw) {
+    return _wire2api_BoxDartDebug(raw);
+  }
+
+  Instant _wire2api_Instant(dynamic raw) {
+    return Instant.fromRaw(raw[0], raw[1], this);
+  }
+
+  IpAddress _wire2api_IpAddr(dynamic raw) {
+    return wire2apiIpAddress(_wire2api_uint_8_list(raw));
+  }
+
   MutexHideData _wire2api_MutexHideData(dynamic raw) {
     return MutexHideData.fromRaw(raw[0], raw[1], this);
   }
@@ -2455,18 +4924,38 @@ class FlutterRustBridgeExampleSingleBlockTestImpl implements FlutterRustBridgeEx
     return NonSendHideData.fromRaw(raw[0], raw[1], this);
   }
 
+  int _wire2api_NonZero_u64(dynamic raw) {
+    return _wire2api_u64(raw);
+  }
+
   ObjectArray1 _wire2api_Object_array_1(dynamic raw) {
     return ObjectArray1((raw as List<dynamic>).map(_wire2api_DartOpaque).toList());
   }
 
+  String _wire2api_PathBuf(dynamic raw) {
+    return String.fromCharCodes(_wire2api_uint_16_list(raw));
+  }
+
   PointArray2 _wire2api_Point_array_2(dynamic raw) {
     return PointArray2((raw as List<dynamic>).map(_wire2api_point).toList());
   }
 
+  RustRange<int> _wire2api_RangeInclusive_u32(dynamic raw) {
+    return wire2apiRange(_wire2api_uint_32_list(raw), inclusive: true);
+  }
+
+  Rooms _wire2api_Rooms(dynamic raw) {
+    return Rooms.fromRaw(raw[0], raw[1], this);
+  }
+
   RwLockHideData _wire2api_RwLockHideData(dynamic raw) {
     return RwLockHideData.fromRaw(raw[0], raw[1], this);
   }
 
+  SocketAddress _wire2api_SocketAddr(dynamic raw) {
+    return wire2apiSocketAddress(_wire2api_uint_8_list(raw));
+  }
+
   String _wire2api_String(dynamic raw) {
     return raw as String;
   }
@@ -2479,12 +4968,16 @@ class FlutterRustBridgeExampleSingleBlockTestImpl implements FlutterRustBridgeEx
     return SumWithArray3((raw as List<dynamic>).map(_wire2api_sum_with).toList());
   }
 
+  DateTime _wire2api_SystemTime(dynamic raw) {
+    return wire2apiTimestamp(ts: _wire2api_i64(raw), isUtc: true);
+  }
+
   TestIdArray2 _wire2api_TestId_array_2(dynamic raw) {
     return TestIdArray2((raw as List<dynamic>).map(_wire2api_test_id).toList());
   }
 
   UuidValue _wire2api_Uuid(dynamic raw) {
-    return UuidValue.fromByteList(_wire2api_uint_8_list(raw));
+    return wire2apiUuid(_wire2api_uint_8_list(raw));
   }
 
   List<UuidValue> _wire2api_Uuids(dynamic raw) {
@@ -2492,6 +4985,14 @@ class FlutterRustBridgeExampleSingleBlockTestImpl implements FlutterRustBridgeEx
     return wire2apiUuids(bytes);
   }
 
+  WeakCounter _wire2api_WeakCounter(dynamic raw) {
+    return WeakCounter.fromRaw(raw[0], raw[1], this);
+  }
+
+  WeakCounter _wire2api_Weak_Counter(dynamic raw) {
+    return _wire2api_WeakCounter(raw);
+  }
+
   Float32List _wire2api_ZeroCopyBuffer_Float32List(dynamic raw) {
     return raw as Float32List;
   }
@@ -2629,6 +5130,10 @@ class FlutterRustBridgeExampleSingleBlockTestImpl implements FlutterRustBridgeEx
     return _wire2api_Chrono_Utc(raw);
   }
 
+  Counter _wire2api_box_autoadd_Counter(dynamic raw) {
+    return _wire2api_Counter(raw);
+  }
+
   Object _wire2api_box_autoadd_DartOpaque(dynamic raw) {
     return _wire2api_DartOpaque(raw);
   }
@@ -2661,6 +5166,10 @@ class FlutterRustBridgeExampleSingleBlockTestImpl implements FlutterRustBridgeEx
     return raw as double;
   }
 
+  HttpStatus _wire2api_box_autoadd_http_status(dynamic raw) {
+    return _wire2api_http_status(raw);
+  }
+
   int _wire2api_box_autoadd_i32(dynamic raw) {
     return raw as int;
   }
@@ -2701,6 +5210,40 @@ class FlutterRustBridgeExampleSingleBlockTestImpl implements FlutterRustBridgeEx
     return _wire2api_u8_array_8(raw);
   }
 
+  Map<String, List<Attribute>> _wire2api_btree_map_String_list_attribute(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return Map.fromIterables(
+      (arr[0] as List<dynamic>).map(_wire2api_String),
+      (arr[1] as List<dynamic>).map(_wire2api_list_attribute),
+    );
+  }
+
+  Map<int, String> _wire2api_btree_map_i32_String(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return Map.fromIterables(
+      (arr[0] as List<dynamic>).map(_wire2api_i32),
+      (arr[1] as List<dynamic>).map(_wire2api_String),
+    );
+  }
+
+  Set<String> _wire2api_btree_set_String(dynamic raw) {
+    return (raw as List<dynamic>).map(_wire2api_String).toSet();
+  }
+
+  String _wire2api_char(dynamic raw) {
+    return String.fromCharCode(raw as int);
+  }
+
+  CollidingKey _wire2api_colliding_key(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 1) throw Exception('unexpected arr length: expect 1 but see ${arr.length}');
+    return CollidingKey(
+      field0: _wire2api_String(arr[0]),
+    );
+  }
+
   ConcatenateWith _wire2api_concatenate_with(dynamic raw) {
     final arr = raw as List<dynamic>;
     if (arr.length != 1) throw Exception('unexpected arr length: expect 1 but see ${arr.length}');
@@ -2710,6 +5253,22 @@ class FlutterRustBridgeExampleSingleBlockTestImpl implements FlutterRustBridgeEx
     );
   }
 
+  CustomError _wire2api_custom_error(dynamic raw) {
+    switch (raw[0]) {
+      case 0:
+        return CustomError_Fail(
+          message: _wire2api_String(raw[1]),
+          backtrace: _wire2api_Backtrace(raw[2]),
+        );
+      case 1:
+        return CustomError_Retry(
+          afterMs: _wire2api_u32(raw[1]),
+        );
+      default:
+        throw Exception("unreachable");
+    }
+  }
+
   DartOpaqueNested _wire2api_dart_opaque_nested(dynamic raw) {
     final arr = raw as List<dynamic>;
     if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
@@ -2732,6 +5291,17 @@ class FlutterRustBridgeExampleSingleBlockTestImpl implements FlutterRustBridgeEx
     }
   }
 
+  Document _wire2api_document(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    final metadata = _wire2api_metadata(arr[0]);
+    return Document(
+      id: metadata.id,
+      createdBy: metadata.createdBy,
+      title: _wire2api_String(arr[1]),
+    );
+  }
+
   Element _wire2api_element(dynamic raw) {
     final arr = raw as List<dynamic>;
     if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
@@ -2829,6 +5399,10 @@ class FlutterRustBridgeExampleSingleBlockTestImpl implements FlutterRustBridgeEx
     return raw as double;
   }
 
+  F64Array16 _wire2api_f64_array_16(dynamic raw) {
+    return F64Array16(_wire2api_float_64_list(raw));
+  }
+
   FeatureUuid _wire2api_feature_uuid(dynamic raw) {
     final arr = raw as List<dynamic>;
     if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
@@ -2854,6 +5428,87 @@ class FlutterRustBridgeExampleSingleBlockTestImpl implements FlutterRustBridgeEx
     return raw as Float64List;
   }
 
+  Frame _wire2api_frame(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return Frame(
+      index: _wire2api_u32(arr[0]),
+      pixels: _wire2api_uint_8_list(arr[1]),
+      depths: _wire2api_opt_float_32_list(arr[2]),
+    );
+  }
+
+  GridCell _wire2api_grid_cell(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return GridCell(
+      _wire2api_i32(arr[0]),
+      _wire2api_i32(arr[1]),
+    );
+  }
+
+  Map<String, int> _wire2api_hash_map_String_i32(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return Map.fromIterables(
+      (arr[0] as List<dynamic>).map(_wire2api_String),
+      (arr[1] as List<dynamic>).map(_wire2api_i32),
+    );
+  }
+
+  Map<CollidingKey, int> _wire2api_hash_map_colliding_key_u32(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return Map.fromIterables(
+      (arr[0] as List<dynamic>).map(_wire2api_colliding_key),
+      (arr[1] as List<dynamic>).map(_wire2api_u32),
+    );
+  }
+
+  Map<int, Attribute> _wire2api_hash_map_u32_attribute(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return Map.fromIterables(
+      (arr[0] as List<dynamic>).map(_wire2api_u32),
+      (arr[1] as List<dynamic>).map(_wire2api_attribute),
+    );
+  }
+
+  Map<int, String> _wire2api_hash_map_usize_String(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return Map.fromIterables(
+      (arr[0] as List<dynamic>).map(_wire2api_usize),
+      (arr[1] as List<dynamic>).map(_wire2api_String),
+    );
+  }
+
+  Map<Weekdays, int> _wire2api_hash_map_weekdays_u32(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return Map.fromIterables(
+      (arr[0] as List<dynamic>).map(_wire2api_weekdays),
+      (arr[1] as List<dynamic>).map(_wire2api_u32),
+    );
+  }
+
+  HttpStatus _wire2api_http_status(dynamic raw) {
+    switch (raw as int) {
+      case 200:
+        return HttpStatus.Ok;
+      case 404:
+        return HttpStatus.NotFound;
+      case 405:
+        return HttpStatus.MethodNotAllowed;
+      default:
+        throw ArgumentError.value(raw, 'raw', 'Unknown discriminant of HttpStatus');
+    }
+  }
+
+  BigInt _wire2api_i128(dynamic raw) {
+    return wire2apiInt128(_wire2api_uint_8_list(raw), signed: true);
+  }
+
   int _wire2api_i16(dynamic raw) {
     return raw as int;
   }
@@ -2928,7 +5583,30 @@ class FlutterRustBridgeExampleSingleBlockTestImpl implements FlutterRustBridgeEx
   }
 
   List<HideData> _wire2api_list_HideData(dynamic raw) {
-    return (raw as List<dynamic>).map(_wire2api_HideData).toList();
+    final list = raw as List<dynamic>;
+    final ans = <HideData>[];
+    try {
+      for (final item in list) {
+        ans.add(_wire2api_HideData(item));
+      }
+    } catch (_) {
+      for (final handle in ans) {
+        handle.dispose();
+      }
+      for (final item in list.skip(ans.length + 1)) {
+        dropOpaqueHideData(FrbOpaqueBase.initPtr(item[0]));
+      }
+      rethrow;
+    }
+    return ans;
+  }
+
+  List<String> _wire2api_list_String(dynamic raw) {
+    return (raw as List<dynamic>).map(_wire2api_String).toList();
+  }
+
+  List<List<String>> _wire2api_list_StringList(dynamic raw) {
+    return (raw as List<dynamic>).map(_wire2api_StringList).toList();
   }
 
   List<ApplicationEnvVar> _wire2api_list_application_env_var(dynamic raw) {
@@ -2939,6 +5617,10 @@ class FlutterRustBridgeExampleSingleBlockTestImpl implements FlutterRustBridgeEx
     return (raw as List<dynamic>).map(_wire2api_attribute).toList();
   }
 
+  List<CollidingKey> _wire2api_list_colliding_key(dynamic raw) {
+    return (raw as List<dynamic>).map(_wire2api_colliding_key).toList();
+  }
+
   List<Element> _wire2api_list_element(dynamic raw) {
     return (raw as List<dynamic>).map(_wire2api_element).toList();
   }
@@ -2947,6 +5629,14 @@ class FlutterRustBridgeExampleSingleBlockTestImpl implements FlutterRustBridgeEx
     return (raw as List<dynamic>).map(_wire2api_enum_opaque).toList();
   }
 
+  List<List<List<String>>> _wire2api_list_list_StringList(dynamic raw) {
+    return (raw as List<dynamic>).map(_wire2api_list_StringList).toList();
+  }
+
+  List<List<Attribute>> _wire2api_list_list_attribute(dynamic raw) {
+    return (raw as List<dynamic>).map(_wire2api_list_attribute).toList();
+  }
+
   List<MySize> _wire2api_list_my_size(dynamic raw) {
     return (raw as List<dynamic>).map(_wire2api_my_size).toList();
   }
@@ -2971,6 +5661,10 @@ class FlutterRustBridgeExampleSingleBlockTestImpl implements FlutterRustBridgeEx
     return (raw as List<dynamic>).map(_wire2api_test_id).toList();
   }
 
+  List<Weekdays> _wire2api_list_weekdays(dynamic raw) {
+    return (raw as List<dynamic>).map(_wire2api_weekdays).toList();
+  }
+
   Log _wire2api_log(dynamic raw) {
     final arr = raw as List<dynamic>;
     if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
@@ -3012,6 +5706,23 @@ class FlutterRustBridgeExampleSingleBlockTestImpl implements FlutterRustBridgeEx
     );
   }
 
+  Metadata _wire2api_metadata(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return Metadata(
+      id: _wire2api_u32(arr[0]),
+      createdBy: _wire2api_String(arr[1]),
+    );
+  }
+
+  Money _wire2api_money(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 1) throw Exception('unexpected arr length: expect 1 but see ${arr.length}');
+    return moneyFromRaw(MoneyRaw(
+      cents: _wire2api_i64(arr[0]),
+    ));
+  }
+
   MyEnum _wire2api_my_enum(dynamic raw) {
     return MyEnum.values[raw];
   }
@@ -3102,6 +5813,10 @@ class FlutterRustBridgeExampleSingleBlockTestImpl implements FlutterRustBridgeEx
     );
   }
 
+  int? _wire2api_opt_NonZero_u64(dynamic raw) {
+    return raw == null ? null : _wire2api_NonZero_u64(raw);
+  }
+
   String? _wire2api_opt_String(dynamic raw) {
     return raw == null ? null : _wire2api_String(raw);
   }
@@ -3122,6 +5837,10 @@ class FlutterRustBridgeExampleSingleBlockTestImpl implements FlutterRustBridgeEx
     return raw == null ? null : _wire2api_box_autoadd_Chrono_Utc(raw);
   }
 
+  Counter? _wire2api_opt_box_autoadd_Counter(dynamic raw) {
+    return raw == null ? null : _wire2api_box_autoadd_Counter(raw);
+  }
+
   Object? _wire2api_opt_box_autoadd_DartOpaque(dynamic raw) {
     return raw == null ? null : _wire2api_box_autoadd_DartOpaque(raw);
   }
@@ -3154,6 +5873,10 @@ class FlutterRustBridgeExampleSingleBlockTestImpl implements FlutterRustBridgeEx
     return raw == null ? null : _wire2api_box_autoadd_f64(raw);
   }
 
+  HttpStatus? _wire2api_opt_box_autoadd_http_status(dynamic raw) {
+    return raw == null ? null : _wire2api_box_autoadd_http_status(raw);
+  }
+
   int? _wire2api_opt_box_autoadd_i32(dynamic raw) {
     return raw == null ? null : _wire2api_box_autoadd_i32(raw);
   }
@@ -3174,6 +5897,10 @@ class FlutterRustBridgeExampleSingleBlockTestImpl implements FlutterRustBridgeEx
     return raw == null ? null : _wire2api_box_autoadd_weekdays(raw);
   }
 
+  String? _wire2api_opt_char(dynamic raw) {
+    return raw == null ? null : _wire2api_char(raw);
+  }
+
   Float32List? _wire2api_opt_float_32_list(dynamic raw) {
     return raw == null ? null : _wire2api_float_32_list(raw);
   }
@@ -3206,6 +5933,32 @@ class FlutterRustBridgeExampleSingleBlockTestImpl implements FlutterRustBridgeEx
     return raw == null ? null : _wire2api_uint_8_list(raw);
   }
 
+  Pace _wire2api_pace(dynamic raw) {
+    return Pace.values[raw];
+  }
+
+  PairString _wire2api_pair_string(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return PairString(
+      first: _wire2api_String(arr[0]),
+      second: _wire2api_list_String(arr[1]),
+    );
+  }
+
+  ParseError _wire2api_parse_error(dynamic raw) {
+    switch (raw[0]) {
+      case 0:
+        return ParseError_Empty();
+      case 1:
+        return ParseError_NotANumber(
+          line: _wire2api_String(raw[1]),
+        );
+      default:
+        throw Exception("unreachable");
+    }
+  }
+
   Point _wire2api_point(dynamic raw) {
     final arr = raw as List<dynamic>;
     if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
@@ -3215,6 +5968,44 @@ class FlutterRustBridgeExampleSingleBlockTestImpl implements FlutterRustBridgeEx
     );
   }
 
+  ProductId _wire2api_product_id(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 1) throw Exception('unexpected arr length: expect 1 but see ${arr.length}');
+    return ProductId(
+      _wire2api_u32(arr[0]),
+    );
+  }
+
+  Progress _wire2api_progress(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 1) throw Exception('unexpected arr length: expect 1 but see ${arr.length}');
+    return Progress(
+      percentage: _wire2api_u8(arr[0]),
+    );
+  }
+
+  Renamed _wire2api_renamed(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return Renamed(
+      identifier: _wire2api_u32(arr[0]),
+      pace: _wire2api_pace(arr[1]),
+    );
+  }
+
+  Sample _wire2api_sample(dynamic raw) {
+    final bytes = raw as Uint8List;
+    if (bytes.length != 24) throw Exception('unexpected bytes length: expect 24 but see ${bytes.length}');
+    final data = ByteData.sublistView(bytes);
+    return Sample(
+      index: data.getUint32(0, Endian.little),
+      x: data.getFloat64(8, Endian.little),
+      y: data.getFloat32(16, Endian.little),
+      valid: data.getUint8(20) != 0,
+      level: data.getInt8(21),
+    );
+  }
+
   Sequences _wire2api_sequences(dynamic raw) {
     final arr = raw as List<dynamic>;
     if (arr.length != 1) throw Exception('unexpected arr length: expect 1 but see ${arr.length}');
@@ -3223,6 +6014,18 @@ class FlutterRustBridgeExampleSingleBlockTestImpl implements FlutterRustBridgeEx
     );
   }
 
+  Session _wire2api_session(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 1) throw Exception('unexpected arr length: expect 1 but see ${arr.length}');
+    return Session(
+      user: _wire2api_String(arr[0]),
+    );
+  }
+
+  SettingError _wire2api_setting_error(dynamic raw) {
+    return SettingError.values[raw];
+  }
+
   Speed _wire2api_speed(dynamic raw) {
     switch (raw[0]) {
       case 0:
@@ -3236,6 +6039,10 @@ class FlutterRustBridgeExampleSingleBlockTestImpl implements FlutterRustBridgeEx
     }
   }
 
+  Instant _wire2api_std_Instant(dynamic raw) {
+    return _wire2api_Instant(raw);
+  }
+
   SumWith _wire2api_sum_with(dynamic raw) {
     final arr = raw as List<dynamic>;
     if (arr.length != 1) throw Exception('unexpected arr length: expect 1 but see ${arr.length}');
@@ -3274,6 +6081,67 @@ class FlutterRustBridgeExampleSingleBlockTestImpl implements FlutterRustBridgeEx
     );
   }
 
+  Tuple2<String, bool> _wire2api_tuple2_String_bool(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return Tuple2(
+      _wire2api_String(arr[0]),
+      _wire2api_bool(arr[1]),
+    );
+  }
+
+  Tuple2<SettingError, String> _wire2api_tuple2_setting_error_String(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return Tuple2(
+      _wire2api_setting_error(arr[0]),
+      _wire2api_String(arr[1]),
+    );
+  }
+
+  Tuple2<Tuple2<String, bool>, int> _wire2api_tuple2_tuple2_String_bool_i32(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return Tuple2(
+      _wire2api_tuple2_String_bool(arr[0]),
+      _wire2api_i32(arr[1]),
+    );
+  }
+
+  Tuple3<bool, String, int> _wire2api_tuple3_bool_String_i32(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return Tuple3(
+      _wire2api_bool(arr[0]),
+      _wire2api_String(arr[1]),
+      _wire2api_i32(arr[2]),
+    );
+  }
+
+  Tuple3<SettingError, String, CustomError> _wire2api_tuple3_setting_error_String_custom_error(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return Tuple3(
+      _wire2api_setting_error(arr[0]),
+      _wire2api_String(arr[1]),
+      _wire2api_custom_error(arr[2]),
+    );
+  }
+
+  Tuple3<int, String, double> _wire2api_tuple3_u32_String_f64(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return Tuple3(
+      _wire2api_u32(arr[0]),
+      _wire2api_String(arr[1]),
+      _wire2api_f64(arr[2]),
+    );
+  }
+
+  BigInt _wire2api_u128(dynamic raw) {
+    return wire2apiInt128(_wire2api_uint_8_list(raw), signed: false);
+  }
+
   int _wire2api_u16(dynamic raw) {
     return raw as int;
   }
@@ -3298,6 +6166,10 @@ class FlutterRustBridgeExampleSingleBlockTestImpl implements FlutterRustBridgeEx
     return U8Array32(_wire2api_uint_8_list(raw));
   }
 
+  U8Array4 _wire2api_u8_array_4(dynamic raw) {
+    return U8Array4(_wire2api_uint_8_list(raw));
+  }
+
   U8Array5 _wire2api_u8_array_5(dynamic raw) {
     return U8Array5(_wire2api_uint_8_list(raw));
   }
@@ -3338,6 +6210,10 @@ class FlutterRustBridgeExampleSingleBlockTestImpl implements FlutterRustBridgeEx
     return castInt(raw);
   }
 
+  Uint64List _wire2api_usize_list(dynamic raw) {
+    return _wire2api_uint_64_list(raw);
+  }
+
   VecOfPrimitivePack _wire2api_vec_of_primitive_pack(dynamic raw) {
     final arr = raw as List<dynamic>;
     if (arr.length != 10) throw Exception('unexpected arr length: expect 10 but see ${arr.length}');
@@ -3379,6 +6255,16 @@ class FlutterRustBridgeExampleSingleBlockTestImpl implements FlutterRustBridgeEx
 
 // Section: api2wire
 
+@protected
+int api2wire_NonZero_u32(int raw) {
+  return api2wire_u32(raw);
+}
+
+@protected
+int api2wire_NonZero_u64(int raw) {
+  return api2wire_u64(raw);
+}
+
 @protected
 int api2wire_application_mode(ApplicationMode raw) {
   return api2wire_i32(raw.index);
@@ -3389,6 +6275,11 @@ bool api2wire_bool(bool raw) {
   return raw;
 }
 
+@protected
+int api2wire_char(String raw) {
+  return api2wire_u32(api2wireChar(raw));
+}
+
 @protected
 double api2wire_f32(double raw) {
   return raw;
@@ -3399,6 +6290,11 @@ double api2wire_f64(double raw) {
   return raw;
 }
 
+@protected
+int api2wire_http_status(HttpStatus raw) {
+  return api2wire_i32(raw.discriminant);
+}
+
 @protected
 int api2wire_i32(int raw) {
   return raw;
@@ -3414,6 +6310,16 @@ int api2wire_my_enum(MyEnum raw) {
   return api2wire_i32(raw.index);
 }
 
+@protected
+int api2wire_pace(Pace raw) {
+  return api2wire_i32(raw.index);
+}
+
+@protected
+int api2wire_u16(int raw) {
+  return raw;
+}
+
 @protected
 int api2wire_u32(int raw) {
   return raw;
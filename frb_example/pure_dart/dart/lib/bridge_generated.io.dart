@@ -8,6 +8,7 @@ import 'dart:async';
 import 'package:meta/meta.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge.dart';
 import 'package:uuid/uuid.dart';
+import 'package:decimal/decimal.dart';
 import 'bridge_generated.dart';
 export 'bridge_generated.dart';
 import 'dart:ffi' as ffi;
@@ -19,6 +20,11 @@ class FlutterRustBridgeExampleSingleBlockTestPlatform
 
 // Section: api2wire
 
+  @protected
+  wire_HideData api2wire_Arc_HideData(HideData raw) {
+    return api2wire_HideData(raw);
+  }
+
   @protected
   wire_BoxDartDebug api2wire_BoxDartDebug(BoxDartDebug raw) {
     final ptr = inner.new_BoxDartDebug();
@@ -26,11 +32,21 @@ class FlutterRustBridgeExampleSingleBlockTestPlatform
     return ptr;
   }
 
+  @protected
+  ffi.Pointer<wire_uint_8_list> api2wire_Bytes(Uint8List raw) {
+    return api2wire_uint_8_list(raw);
+  }
+
   @protected
   int api2wire_Chrono_Duration(Duration raw) {
     return api2wire_i64(raw.inMicroseconds);
   }
 
+  @protected
+  int api2wire_Chrono_FixedOffset(DateTime raw) {
+    return api2wire_i64(raw.microsecondsSinceEpoch);
+  }
+
   @protected
   int api2wire_Chrono_Local(DateTime raw) {
     return api2wire_i64(raw.microsecondsSinceEpoch);
@@ -46,6 +62,42 @@ class FlutterRustBridgeExampleSingleBlockTestPlatform
     return api2wire_i64(raw.microsecondsSinceEpoch);
   }
 
+  @protected
+  ffi.Pointer<wire_uint_8_list> api2wire_Codec_Version(Version raw) {
+    return api2wire_uint_8_list(VersionCodec.encode(raw));
+  }
+
+  @protected
+  wire_Counter api2wire_Counter(Counter raw) {
+    final ptr = inner.new_Counter();
+    _api_fill_to_wire_Counter(raw, ptr);
+    return ptr;
+  }
+
+  @protected
+  ffi.Pointer<wire_uint_8_list> api2wire_Cow_Bytes(Uint8List raw) {
+    return api2wire_uint_8_list(raw);
+  }
+
+  @protected
+  ffi.Pointer<wire_uint_8_list> api2wire_Cow_String(String raw) {
+    return api2wire_String(raw);
+  }
+
+  @protected
+  wire_DartIteratorI32 api2wire_DartIteratorI32(DartIteratorI32 raw) {
+    final ptr = inner.new_DartIteratorI32();
+    _api_fill_to_wire_DartIteratorI32(raw, ptr);
+    return ptr;
+  }
+
+  @protected
+  wire_DartIteratorString api2wire_DartIteratorString(DartIteratorString raw) {
+    final ptr = inner.new_DartIteratorString();
+    _api_fill_to_wire_DartIteratorString(raw, ptr);
+    return ptr;
+  }
+
   @protected
   wire_DartOpaque api2wire_DartOpaque(Object raw) {
     inner.dartApi.initApi();
@@ -54,6 +106,23 @@ class FlutterRustBridgeExampleSingleBlockTestPlatform
     return ptr;
   }
 
+  @protected
+  ffi.Pointer<wire_uint_8_list> api2wire_Decimal(Decimal raw) {
+    return api2wire_String(raw.toString());
+  }
+
+  @protected
+  wire_BoxDartDebug api2wire_DynRef_BoxDartDebug(BoxDartDebug raw) {
+    return api2wire_BoxDartDebug(raw);
+  }
+
+  @protected
+  wire_Greeter api2wire_Greeter(Greeter raw) {
+    final ptr = inner.new_Greeter();
+    _api_fill_to_wire_Greeter(raw, ptr);
+    return ptr;
+  }
+
   @protected
   wire_HideData api2wire_HideData(HideData raw) {
     final ptr = inner.new_HideData();
@@ -73,6 +142,13 @@ class FlutterRustBridgeExampleSingleBlockTestPlatform
     return ptr;
   }
 
+  @protected
+  wire_Instant api2wire_Instant(Instant raw) {
+    final ptr = inner.new_Instant();
+    _api_fill_to_wire_Instant(raw, ptr);
+    return ptr;
+  }
+
   @protected
   wire_MutexHideData api2wire_MutexHideData(MutexHideData raw) {
     final ptr = inner.new_MutexHideData();
@@ -92,6 +168,28 @@ class FlutterRustBridgeExampleSingleBlockTestPlatform
     return api2wire_list_DartOpaque(raw);
   }
 
+  @protected
+  ffi.Pointer<wire_uint_16_list> api2wire_PathRef(String raw) {
+    return api2wire_uint_16_list(Uint16List.fromList(raw.codeUnits));
+  }
+
+  @protected
+  ffi.Pointer<wire_float_64_list> api2wire_RangeInclusive_f64(RustRange<double> raw) {
+    return api2wire_float_64_list(Float64List.fromList(api2wireRange(raw, inclusive: true)));
+  }
+
+  @protected
+  ffi.Pointer<wire_uint_32_list> api2wire_Range_u32(RustRange<int> raw) {
+    return api2wire_uint_32_list(Uint32List.fromList(api2wireRange(raw, inclusive: false)));
+  }
+
+  @protected
+  wire_Rooms api2wire_Rooms(Rooms raw) {
+    final ptr = inner.new_Rooms();
+    _api_fill_to_wire_Rooms(raw, ptr);
+    return ptr;
+  }
+
   @protected
   wire_RwLockHideData api2wire_RwLockHideData(RwLockHideData raw) {
     final ptr = inner.new_RwLockHideData();
@@ -99,6 +197,26 @@ class FlutterRustBridgeExampleSingleBlockTestPlatform
     return ptr;
   }
 
+  @protected
+  int api2wire_SharedBuffer(SharedBuffer raw) {
+    return api2wire_usize(raw.address);
+  }
+
+  @protected
+  ffi.Pointer<wire_uint_16_list> api2wire_SliceRef_u16(Uint16List raw) {
+    return api2wire_uint_16_list(raw);
+  }
+
+  @protected
+  ffi.Pointer<wire_uint_8_list> api2wire_SliceRef_u8(Uint8List raw) {
+    return api2wire_uint_8_list(raw);
+  }
+
+  @protected
+  ffi.Pointer<wire_uint_8_list> api2wire_SocketAddr(SocketAddress raw) {
+    return api2wire_uint_8_list(api2wireSocketAddress(raw));
+  }
+
   @protected
   ffi.Pointer<wire_uint_8_list> api2wire_String(String raw) {
     return api2wire_uint_8_list(utf8.encoder.convert(raw));
@@ -113,6 +231,11 @@ class FlutterRustBridgeExampleSingleBlockTestPlatform
     return ans;
   }
 
+  @protected
+  int api2wire_SystemTime(DateTime raw) {
+    return api2wire_i64(raw.microsecondsSinceEpoch);
+  }
+
   @protected
   ffi.Pointer<wire_list_test_id> api2wire_TestId_array_4(TestIdArray4 raw) {
     return api2wire_list_test_id(raw);
@@ -128,6 +251,18 @@ class FlutterRustBridgeExampleSingleBlockTestPlatform
     return api2wire_uint_8_list(api2wireConcatenateBytes(raw));
   }
 
+  @protected
+  wire_WeakCounter api2wire_WeakCounter(WeakCounter raw) {
+    final ptr = inner.new_WeakCounter();
+    _api_fill_to_wire_WeakCounter(raw, ptr);
+    return ptr;
+  }
+
+  @protected
+  wire_WeakCounter api2wire_Weak_Counter(WeakCounter raw) {
+    return api2wire_WeakCounter(raw);
+  }
+
   @protected
   ffi.Pointer<wire_uint_8_list> api2wire_ZeroCopyBuffer_Uint8List(Uint8List raw) {
     return api2wire_uint_8_list(raw);
@@ -166,6 +301,13 @@ class FlutterRustBridgeExampleSingleBlockTestPlatform
     return ptr;
   }
 
+  @protected
+  ffi.Pointer<wire_ApplicationMessage> api2wire_box_autoadd_application_message(ApplicationMessage raw) {
+    final ptr = inner.new_box_autoadd_application_message_0();
+    _api_fill_to_wire_application_message(raw, ptr.ref);
+    return ptr;
+  }
+
   @protected
   ffi.Pointer<wire_ApplicationSettings> api2wire_box_autoadd_application_settings(ApplicationSettings raw) {
     final ptr = inner.new_box_autoadd_application_settings_0();
@@ -206,6 +348,13 @@ class FlutterRustBridgeExampleSingleBlockTestPlatform
     return ptr;
   }
 
+  @protected
+  ffi.Pointer<wire_Document> api2wire_box_autoadd_document(Document raw) {
+    final ptr = inner.new_box_autoadd_document_0();
+    _api_fill_to_wire_document(raw, ptr.ref);
+    return ptr;
+  }
+
   @protected
   ffi.Pointer<wire_Empty> api2wire_box_autoadd_empty(Empty raw) {
     final ptr = inner.new_box_autoadd_empty_0();
@@ -259,6 +408,20 @@ class FlutterRustBridgeExampleSingleBlockTestPlatform
     return ptr;
   }
 
+  @protected
+  ffi.Pointer<wire_Frame> api2wire_box_autoadd_frame(Frame raw) {
+    final ptr = inner.new_box_autoadd_frame_0();
+    _api_fill_to_wire_frame(raw, ptr.ref);
+    return ptr;
+  }
+
+  @protected
+  ffi.Pointer<wire_GridCell> api2wire_box_autoadd_grid_cell(GridCell raw) {
+    final ptr = inner.new_box_autoadd_grid_cell_0();
+    _api_fill_to_wire_grid_cell(raw, ptr.ref);
+    return ptr;
+  }
+
   @protected
   ffi.Pointer<ffi.Int32> api2wire_box_autoadd_i32(int raw) {
     return inner.new_box_autoadd_i32_0(api2wire_i32(raw));
@@ -290,6 +453,13 @@ class FlutterRustBridgeExampleSingleBlockTestPlatform
     return ptr;
   }
 
+  @protected
+  ffi.Pointer<wire_Money> api2wire_box_autoadd_money(Money raw) {
+    final ptr = inner.new_box_autoadd_money_0();
+    _api_fill_to_wire_money(raw, ptr.ref);
+    return ptr;
+  }
+
   @protected
   ffi.Pointer<wire_MyNestedStruct> api2wire_box_autoadd_my_nested_struct(MyNestedStruct raw) {
     final ptr = inner.new_box_autoadd_my_nested_struct_0();
@@ -346,6 +516,34 @@ class FlutterRustBridgeExampleSingleBlockTestPlatform
     return ptr;
   }
 
+  @protected
+  ffi.Pointer<wire_PairI32> api2wire_box_autoadd_pair_i_32(PairI32 raw) {
+    final ptr = inner.new_box_autoadd_pair_i_32_0();
+    _api_fill_to_wire_pair_i_32(raw, ptr.ref);
+    return ptr;
+  }
+
+  @protected
+  ffi.Pointer<wire_ProductId> api2wire_box_autoadd_product_id(ProductId raw) {
+    final ptr = inner.new_box_autoadd_product_id_0();
+    _api_fill_to_wire_product_id(raw, ptr.ref);
+    return ptr;
+  }
+
+  @protected
+  ffi.Pointer<wire_Progress> api2wire_box_autoadd_progress(Progress raw) {
+    final ptr = inner.new_box_autoadd_progress_0();
+    _api_fill_to_wire_progress(raw, ptr.ref);
+    return ptr;
+  }
+
+  @protected
+  ffi.Pointer<wire_Sample> api2wire_box_autoadd_sample(Sample raw) {
+    final ptr = inner.new_box_autoadd_sample_0();
+    _api_fill_to_wire_sample(raw, ptr.ref);
+    return ptr;
+  }
+
   @protected
   ffi.Pointer<wire_Sequences> api2wire_box_autoadd_sequences(Sequences raw) {
     final ptr = inner.new_box_autoadd_sequences_0();
@@ -353,6 +551,13 @@ class FlutterRustBridgeExampleSingleBlockTestPlatform
     return ptr;
   }
 
+  @protected
+  ffi.Pointer<wire_Session> api2wire_box_autoadd_session(Session raw) {
+    final ptr = inner.new_box_autoadd_session_0();
+    _api_fill_to_wire_session(raw, ptr.ref);
+    return ptr;
+  }
+
   @protected
   ffi.Pointer<wire_SumWith> api2wire_box_autoadd_sum_with(SumWith raw) {
     final ptr = inner.new_box_autoadd_sum_with_0();
@@ -360,6 +565,13 @@ class FlutterRustBridgeExampleSingleBlockTestPlatform
     return ptr;
   }
 
+  @protected
+  ffi.Pointer<wire_Tag> api2wire_box_autoadd_tag(Tag raw) {
+    final ptr = inner.new_box_autoadd_tag_0();
+    _api_fill_to_wire_tag(raw, ptr.ref);
+    return ptr;
+  }
+
   @protected
   ffi.Pointer<wire_TestId> api2wire_box_autoadd_test_id(TestId raw) {
     final ptr = inner.new_box_autoadd_test_id_0();
@@ -367,6 +579,26 @@ class FlutterRustBridgeExampleSingleBlockTestPlatform
     return ptr;
   }
 
+  @protected
+  ffi.Pointer<wire_tuple2_i32_tuple2_String_bool> api2wire_box_autoadd_tuple2_i32_tuple2_String_bool(
+      Tuple2<int, Tuple2<String, bool>> raw) {
+    final ptr = inner.new_box_autoadd_tuple2_i32_tuple2_String_bool_0();
+    _api_fill_to_wire_tuple2_i32_tuple2_String_bool(raw, ptr.ref);
+    return ptr;
+  }
+
+  @protected
+  ffi.Pointer<wire_tuple3_i32_String_bool> api2wire_box_autoadd_tuple3_i32_String_bool(Tuple3<int, String, bool> raw) {
+    final ptr = inner.new_box_autoadd_tuple3_i32_String_bool_0();
+    _api_fill_to_wire_tuple3_i32_String_bool(raw, ptr.ref);
+    return ptr;
+  }
+
+  @protected
+  ffi.Pointer<ffi.Uint32> api2wire_box_autoadd_u32(int raw) {
+    return inner.new_box_autoadd_u32_0(api2wire_u32(raw));
+  }
+
   @protected
   ffi.Pointer<wire_UserId> api2wire_box_autoadd_user_id(UserId raw) {
     final ptr = inner.new_box_autoadd_user_id_0();
@@ -456,6 +688,26 @@ class FlutterRustBridgeExampleSingleBlockTestPlatform
     return inner.new_box_weekdays_0(api2wire_weekdays(raw));
   }
 
+  @protected
+  ffi.Pointer<wire_btree_map_i32_String> api2wire_btree_map_i32_String(Map<int, String> raw) {
+    final ans = inner.new_btree_map_i32_String_0();
+    ans.ref.keys = api2wire_int_32_list(Int32List.fromList(raw.keys.toList()));
+    ans.ref.values = api2wire_StringList(raw.values.toList());
+    return ans;
+  }
+
+  @protected
+  ffi.Pointer<wire_StringList> api2wire_btree_set_String(Set<String> raw) {
+    return api2wire_StringList(raw.toList());
+  }
+
+  @protected
+  ffi.Pointer<wire_float_32_list> api2wire_f32_array_3(F32Array3 raw) {
+    final ans = inner.new_float_32_list_0(3);
+    ans.ref.ptr.asTypedList(3).setAll(0, raw);
+    return ans;
+  }
+
   @protected
   ffi.Pointer<wire_float_64_list> api2wire_f64_array_16(F64Array16 raw) {
     final ans = inner.new_float_64_list_0(16);
@@ -477,6 +729,35 @@ class FlutterRustBridgeExampleSingleBlockTestPlatform
     return ans;
   }
 
+  @protected
+  ffi.Pointer<wire_hash_map_String_i32> api2wire_hash_map_String_i32(Map<String, int> raw) {
+    final ans = inner.new_hash_map_String_i32_0();
+    ans.ref.keys = api2wire_StringList(raw.keys.toList());
+    ans.ref.values = api2wire_int_32_list(Int32List.fromList(raw.values.toList()));
+    return ans;
+  }
+
+  @protected
+  ffi.Pointer<wire_hash_map_colliding_key_u32> api2wire_hash_map_colliding_key_u32(Map<CollidingKey, int> raw) {
+    final ans = inner.new_hash_map_colliding_key_u32_0();
+    ans.ref.keys = api2wire_list_colliding_key(raw.keys.toList());
+    ans.ref.values = api2wire_uint_32_list(Uint32List.fromList(raw.values.toList()));
+    return ans;
+  }
+
+  @protected
+  ffi.Pointer<wire_hash_map_u32_attribute> api2wire_hash_map_u32_attribute(Map<int, Attribute> raw) {
+    final ans = inner.new_hash_map_u32_attribute_0();
+    ans.ref.keys = api2wire_uint_32_list(Uint32List.fromList(raw.keys.toList()));
+    ans.ref.values = api2wire_list_attribute(raw.values.toList());
+    return ans;
+  }
+
+  @protected
+  ffi.Pointer<wire_uint_8_list> api2wire_i128(BigInt raw) {
+    return api2wire_uint_8_list(api2wireInt128(raw, signed: true));
+  }
+
   @protected
   ffi.Pointer<wire_int_32_list> api2wire_i32_array_2(I32Array2 raw) {
     final ans = inner.new_int_32_list_0(2);
@@ -512,6 +793,15 @@ class FlutterRustBridgeExampleSingleBlockTestPlatform
     return ans;
   }
 
+  @protected
+  ffi.Pointer<wire_list_Decimal> api2wire_list_Decimal(List<Decimal> raw) {
+    final ans = inner.new_list_Decimal_0(raw.length);
+    for (var i = 0; i < raw.length; ++i) {
+      ans.ref.ptr[i] = api2wire_Decimal(raw[i]);
+    }
+    return ans;
+  }
+
   @protected
   ffi.Pointer<wire_list_HideData> api2wire_list_HideData(List<HideData> raw) {
     final ans = inner.new_list_HideData_0(raw.length);
@@ -539,6 +829,33 @@ class FlutterRustBridgeExampleSingleBlockTestPlatform
     return ans;
   }
 
+  @protected
+  ffi.Pointer<wire_list_char> api2wire_list_char(List<String> raw) {
+    final ans = inner.new_list_char_0(raw.length);
+    for (var i = 0; i < raw.length; ++i) {
+      ans.ref.ptr[i] = api2wire_char(raw[i]);
+    }
+    return ans;
+  }
+
+  @protected
+  ffi.Pointer<wire_list_colliding_key> api2wire_list_colliding_key(List<CollidingKey> raw) {
+    final ans = inner.new_list_colliding_key_0(raw.length);
+    for (var i = 0; i < raw.length; ++i) {
+      _api_fill_to_wire_colliding_key(raw[i], ans.ref.ptr[i]);
+    }
+    return ans;
+  }
+
+  @protected
+  ffi.Pointer<wire_list_int_32_list> api2wire_list_int_32_list(List<Int32List> raw) {
+    final ans = inner.new_list_int_32_list_0(raw.length);
+    for (var i = 0; i < raw.length; ++i) {
+      ans.ref.ptr[i] = api2wire_int_32_list(raw[i]);
+    }
+    return ans;
+  }
+
   @protected
   ffi.Pointer<wire_list_my_size> api2wire_list_my_size(List<MySize> raw) {
     final ans = inner.new_list_my_size_0(raw.length);
@@ -575,6 +892,15 @@ class FlutterRustBridgeExampleSingleBlockTestPlatform
     return ans;
   }
 
+  @protected
+  ffi.Pointer<wire_list_weekdays> api2wire_list_weekdays(List<Weekdays> raw) {
+    final ans = inner.new_list_weekdays_0(raw.length);
+    for (var i = 0; i < raw.length; ++i) {
+      ans.ref.ptr[i] = api2wire_weekdays(raw[i]);
+    }
+    return ans;
+  }
+
   @protected
   ffi.Pointer<wire_uint_8_list> api2wire_opt_String(String? raw) {
     return raw == null ? ffi.nullptr : api2wire_String(raw);
@@ -640,6 +966,11 @@ class FlutterRustBridgeExampleSingleBlockTestPlatform
     return raw == null ? ffi.nullptr : api2wire_box_autoadd_new_type_int(raw);
   }
 
+  @protected
+  ffi.Pointer<ffi.Uint32> api2wire_opt_box_autoadd_u32(int? raw) {
+    return raw == null ? ffi.nullptr : api2wire_box_autoadd_u32(raw);
+  }
+
   @protected
   ffi.Pointer<ffi.Bool> api2wire_opt_box_bool(bool? raw) {
     return raw == null ? ffi.nullptr : api2wire_box_bool(raw);
@@ -710,6 +1041,16 @@ class FlutterRustBridgeExampleSingleBlockTestPlatform
     return raw == null ? ffi.nullptr : api2wire_uint_8_list(raw);
   }
 
+  @protected
+  wire_Instant api2wire_std_Instant(Instant raw) {
+    return api2wire_Instant(raw);
+  }
+
+  @protected
+  ffi.Pointer<wire_uint_8_list> api2wire_u128(BigInt raw) {
+    return api2wire_uint_8_list(api2wireInt128(raw, signed: false));
+  }
+
   @protected
   int api2wire_u64(int raw) {
     return raw;
@@ -736,6 +1077,27 @@ class FlutterRustBridgeExampleSingleBlockTestPlatform
     return ans;
   }
 
+  @protected
+  ffi.Pointer<wire_uint_16_list> api2wire_uint_16_list(Uint16List raw) {
+    final ans = inner.new_uint_16_list_0(raw.length);
+    ans.ref.ptr.asTypedList(raw.length).setAll(0, raw);
+    return ans;
+  }
+
+  @protected
+  ffi.Pointer<wire_uint_32_list> api2wire_uint_32_list(Uint32List raw) {
+    final ans = inner.new_uint_32_list_0(raw.length);
+    ans.ref.ptr.asTypedList(raw.length).setAll(0, raw);
+    return ans;
+  }
+
+  @protected
+  ffi.Pointer<wire_uint_64_list> api2wire_uint_64_list(Uint64List raw) {
+    final ans = inner.new_uint_64_list_0(raw.length);
+    ans.ref.ptr.asTypedList(raw.length).setAll(0, raw.inner);
+    return ans;
+  }
+
   @protected
   ffi.Pointer<wire_uint_8_list> api2wire_uint_8_list(Uint8List raw) {
     final ans = inner.new_uint_8_list_0(raw.length);
@@ -743,36 +1105,72 @@ class FlutterRustBridgeExampleSingleBlockTestPlatform
     return ans;
   }
 
+  @protected
+  ffi.Pointer<wire_uint_64_list> api2wire_usize_list(Uint64List raw) {
+    return api2wire_uint_64_list(raw);
+  }
+
 // Section: finalizer
 
   late final OpaqueTypeFinalizer _BoxDartDebugFinalizer = OpaqueTypeFinalizer(inner._drop_opaque_BoxDartDebugPtr);
   OpaqueTypeFinalizer get BoxDartDebugFinalizer => _BoxDartDebugFinalizer;
+  late final OpaqueTypeFinalizer _CounterFinalizer = OpaqueTypeFinalizer(inner._drop_opaque_CounterPtr);
+  OpaqueTypeFinalizer get CounterFinalizer => _CounterFinalizer;
+  late final OpaqueTypeFinalizer _DartIteratorI32Finalizer = OpaqueTypeFinalizer(inner._drop_opaque_DartIteratorI32Ptr);
+  OpaqueTypeFinalizer get DartIteratorI32Finalizer => _DartIteratorI32Finalizer;
+  late final OpaqueTypeFinalizer _DartIteratorStringFinalizer =
+      OpaqueTypeFinalizer(inner._drop_opaque_DartIteratorStringPtr);
+  OpaqueTypeFinalizer get DartIteratorStringFinalizer => _DartIteratorStringFinalizer;
   late final OpaqueTypeFinalizer _FrbOpaqueReturnFinalizer = OpaqueTypeFinalizer(inner._drop_opaque_FrbOpaqueReturnPtr);
   OpaqueTypeFinalizer get FrbOpaqueReturnFinalizer => _FrbOpaqueReturnFinalizer;
   late final OpaqueTypeFinalizer _FrbOpaqueSyncReturnFinalizer =
       OpaqueTypeFinalizer(inner._drop_opaque_FrbOpaqueSyncReturnPtr);
   OpaqueTypeFinalizer get FrbOpaqueSyncReturnFinalizer => _FrbOpaqueSyncReturnFinalizer;
+  late final OpaqueTypeFinalizer _GreeterFinalizer = OpaqueTypeFinalizer(inner._drop_opaque_GreeterPtr);
+  OpaqueTypeFinalizer get GreeterFinalizer => _GreeterFinalizer;
   late final OpaqueTypeFinalizer _HideDataFinalizer = OpaqueTypeFinalizer(inner._drop_opaque_HideDataPtr);
   OpaqueTypeFinalizer get HideDataFinalizer => _HideDataFinalizer;
   late final OpaqueTypeFinalizer _I32Finalizer = OpaqueTypeFinalizer(inner._drop_opaque_I32Ptr);
   OpaqueTypeFinalizer get I32Finalizer => _I32Finalizer;
+  late final OpaqueTypeFinalizer _InstantFinalizer = OpaqueTypeFinalizer(inner._drop_opaque_InstantPtr);
+  OpaqueTypeFinalizer get InstantFinalizer => _InstantFinalizer;
   late final OpaqueTypeFinalizer _MutexHideDataFinalizer = OpaqueTypeFinalizer(inner._drop_opaque_MutexHideDataPtr);
   OpaqueTypeFinalizer get MutexHideDataFinalizer => _MutexHideDataFinalizer;
   late final OpaqueTypeFinalizer _NonSendHideDataFinalizer = OpaqueTypeFinalizer(inner._drop_opaque_NonSendHideDataPtr);
   OpaqueTypeFinalizer get NonSendHideDataFinalizer => _NonSendHideDataFinalizer;
+  late final OpaqueTypeFinalizer _RoomsFinalizer = OpaqueTypeFinalizer(inner._drop_opaque_RoomsPtr);
+  OpaqueTypeFinalizer get RoomsFinalizer => _RoomsFinalizer;
   late final OpaqueTypeFinalizer _RwLockHideDataFinalizer = OpaqueTypeFinalizer(inner._drop_opaque_RwLockHideDataPtr);
   OpaqueTypeFinalizer get RwLockHideDataFinalizer => _RwLockHideDataFinalizer;
+  late final OpaqueTypeFinalizer _WeakCounterFinalizer = OpaqueTypeFinalizer(inner._drop_opaque_WeakCounterPtr);
+  OpaqueTypeFinalizer get WeakCounterFinalizer => _WeakCounterFinalizer;
 // Section: api_fill_to_wire
 
   void _api_fill_to_wire_BoxDartDebug(BoxDartDebug apiObj, wire_BoxDartDebug wireObj) {
     wireObj.ptr = apiObj.shareOrMove();
   }
 
+  void _api_fill_to_wire_Counter(Counter apiObj, wire_Counter wireObj) {
+    wireObj.ptr = apiObj.shareOrMove();
+  }
+
+  void _api_fill_to_wire_DartIteratorI32(DartIteratorI32 apiObj, wire_DartIteratorI32 wireObj) {
+    wireObj.ptr = apiObj.shareOrMove();
+  }
+
+  void _api_fill_to_wire_DartIteratorString(DartIteratorString apiObj, wire_DartIteratorString wireObj) {
+    wireObj.ptr = apiObj.shareOrMove();
+  }
+
   void _api_fill_to_wire_DartOpaque(Object apiObj, wire_DartOpaque wireObj) {
     wireObj.handle = inner.new_dart_opaque(apiObj);
     wireObj.port = dropPort;
   }
 
+  void _api_fill_to_wire_Greeter(Greeter apiObj, wire_Greeter wireObj) {
+    wireObj.ptr = apiObj.shareOrMove();
+  }
+
   void _api_fill_to_wire_HideData(HideData apiObj, wire_HideData wireObj) {
     wireObj.ptr = apiObj.shareOrMove();
   }
@@ -781,6 +1179,10 @@ class FlutterRustBridgeExampleSingleBlockTestPlatform
     wireObj.ptr = apiObj.shareOrMove();
   }
 
+  void _api_fill_to_wire_Instant(Instant apiObj, wire_Instant wireObj) {
+    wireObj.ptr = apiObj.shareOrMove();
+  }
+
   void _api_fill_to_wire_MutexHideData(MutexHideData apiObj, wire_MutexHideData wireObj) {
     wireObj.ptr = apiObj.shareOrMove();
   }
@@ -789,10 +1191,18 @@ class FlutterRustBridgeExampleSingleBlockTestPlatform
     wireObj.ptr = apiObj.shareOrMove();
   }
 
+  void _api_fill_to_wire_Rooms(Rooms apiObj, wire_Rooms wireObj) {
+    wireObj.ptr = apiObj.shareOrMove();
+  }
+
   void _api_fill_to_wire_RwLockHideData(RwLockHideData apiObj, wire_RwLockHideData wireObj) {
     wireObj.ptr = apiObj.shareOrMove();
   }
 
+  void _api_fill_to_wire_WeakCounter(WeakCounter apiObj, wire_WeakCounter wireObj) {
+    wireObj.ptr = apiObj.shareOrMove();
+  }
+
   void _api_fill_to_wire_application_env(ApplicationEnv apiObj, wire_ApplicationEnv wireObj) {
     wireObj.vars = api2wire_list_application_env_var(apiObj.vars);
   }
@@ -802,6 +1212,29 @@ class FlutterRustBridgeExampleSingleBlockTestPlatform
     wireObj.field1 = api2wire_bool(apiObj.field1);
   }
 
+  void _api_fill_to_wire_application_message(ApplicationMessage apiObj, wire_ApplicationMessage wireObj) {
+    if (apiObj is ApplicationMessage_DisplayMessage) {
+      var pre_field0 = api2wire_String(apiObj.field0);
+      wireObj.tag = 0;
+      wireObj.kind = inner.inflate_ApplicationMessage_DisplayMessage();
+      wireObj.kind.ref.DisplayMessage.ref.field0 = pre_field0;
+      return;
+    }
+    if (apiObj is ApplicationMessage_RenderPixel) {
+      var pre_x = api2wire_i32(apiObj.x);
+      var pre_y = api2wire_i32(apiObj.y);
+      wireObj.tag = 1;
+      wireObj.kind = inner.inflate_ApplicationMessage_RenderPixel();
+      wireObj.kind.ref.RenderPixel.ref.x = pre_x;
+      wireObj.kind.ref.RenderPixel.ref.y = pre_y;
+      return;
+    }
+    if (apiObj is ApplicationMessage_Exit) {
+      wireObj.tag = 2;
+      return;
+    }
+  }
+
   void _api_fill_to_wire_application_settings(ApplicationSettings apiObj, wire_ApplicationSettings wireObj) {
     wireObj.name = api2wire_String(apiObj.name);
     wireObj.version = api2wire_String(apiObj.version);
@@ -835,6 +1268,11 @@ class FlutterRustBridgeExampleSingleBlockTestPlatform
     _api_fill_to_wire_application_env(apiObj, wireObj.ref);
   }
 
+  void _api_fill_to_wire_box_autoadd_application_message(
+      ApplicationMessage apiObj, ffi.Pointer<wire_ApplicationMessage> wireObj) {
+    _api_fill_to_wire_application_message(apiObj, wireObj.ref);
+  }
+
   void _api_fill_to_wire_box_autoadd_application_settings(
       ApplicationSettings apiObj, ffi.Pointer<wire_ApplicationSettings> wireObj) {
     _api_fill_to_wire_application_settings(apiObj, wireObj.ref);
@@ -858,7 +1296,11 @@ class FlutterRustBridgeExampleSingleBlockTestPlatform
     _api_fill_to_wire_dart_opaque_nested(apiObj, wireObj.ref);
   }
 
-  void _api_fill_to_wire_box_autoadd_enum_dart_opaque(EnumDartOpaque apiObj, ffi.Pointer<wire_EnumDartOpaque> wireObj) {
+  void _api_fill_to_wire_box_autoadd_document(Document apiObj, ffi.Pointer<wire_Document> wireObj) {
+    _api_fill_to_wire_document(apiObj, wireObj.ref);
+  }
+
+  void _api_fill_to_wire_box_autoadd_enum_dart_opaque(EnumDartOpaque apiObj, ffi.Pointer<wire_EnumDartOpaque> wireObj) {
     _api_fill_to_wire_enum_dart_opaque(apiObj, wireObj.ref);
   }
 
@@ -883,6 +1325,14 @@ class FlutterRustBridgeExampleSingleBlockTestPlatform
     _api_fill_to_wire_feed_id(apiObj, wireObj.ref);
   }
 
+  void _api_fill_to_wire_box_autoadd_frame(Frame apiObj, ffi.Pointer<wire_Frame> wireObj) {
+    _api_fill_to_wire_frame(apiObj, wireObj.ref);
+  }
+
+  void _api_fill_to_wire_box_autoadd_grid_cell(GridCell apiObj, ffi.Pointer<wire_GridCell> wireObj) {
+    _api_fill_to_wire_grid_cell(apiObj, wireObj.ref);
+  }
+
   void _api_fill_to_wire_box_autoadd_kitchen_sink(KitchenSink apiObj, ffi.Pointer<wire_KitchenSink> wireObj) {
     _api_fill_to_wire_kitchen_sink(apiObj, wireObj.ref);
   }
@@ -895,6 +1345,10 @@ class FlutterRustBridgeExampleSingleBlockTestPlatform
     _api_fill_to_wire_message_id(apiObj, wireObj.ref);
   }
 
+  void _api_fill_to_wire_box_autoadd_money(Money apiObj, ffi.Pointer<wire_Money> wireObj) {
+    _api_fill_to_wire_money(apiObj, wireObj.ref);
+  }
+
   void _api_fill_to_wire_box_autoadd_my_nested_struct(MyNestedStruct apiObj, ffi.Pointer<wire_MyNestedStruct> wireObj) {
     _api_fill_to_wire_my_nested_struct(apiObj, wireObj.ref);
   }
@@ -927,18 +1381,52 @@ class FlutterRustBridgeExampleSingleBlockTestPlatform
     _api_fill_to_wire_opaque_nested(apiObj, wireObj.ref);
   }
 
+  void _api_fill_to_wire_box_autoadd_pair_i_32(PairI32 apiObj, ffi.Pointer<wire_PairI32> wireObj) {
+    _api_fill_to_wire_pair_i_32(apiObj, wireObj.ref);
+  }
+
+  void _api_fill_to_wire_box_autoadd_product_id(ProductId apiObj, ffi.Pointer<wire_ProductId> wireObj) {
+    _api_fill_to_wire_product_id(apiObj, wireObj.ref);
+  }
+
+  void _api_fill_to_wire_box_autoadd_progress(Progress apiObj, ffi.Pointer<wire_Progress> wireObj) {
+    _api_fill_to_wire_progress(apiObj, wireObj.ref);
+  }
+
+  void _api_fill_to_wire_box_autoadd_sample(Sample apiObj, ffi.Pointer<wire_Sample> wireObj) {
+    _api_fill_to_wire_sample(apiObj, wireObj.ref);
+  }
+
   void _api_fill_to_wire_box_autoadd_sequences(Sequences apiObj, ffi.Pointer<wire_Sequences> wireObj) {
     _api_fill_to_wire_sequences(apiObj, wireObj.ref);
   }
 
+  void _api_fill_to_wire_box_autoadd_session(Session apiObj, ffi.Pointer<wire_Session> wireObj) {
+    _api_fill_to_wire_session(apiObj, wireObj.ref);
+  }
+
   void _api_fill_to_wire_box_autoadd_sum_with(SumWith apiObj, ffi.Pointer<wire_SumWith> wireObj) {
     _api_fill_to_wire_sum_with(apiObj, wireObj.ref);
   }
 
+  void _api_fill_to_wire_box_autoadd_tag(Tag apiObj, ffi.Pointer<wire_Tag> wireObj) {
+    _api_fill_to_wire_tag(apiObj, wireObj.ref);
+  }
+
   void _api_fill_to_wire_box_autoadd_test_id(TestId apiObj, ffi.Pointer<wire_TestId> wireObj) {
     _api_fill_to_wire_test_id(apiObj, wireObj.ref);
   }
 
+  void _api_fill_to_wire_box_autoadd_tuple2_i32_tuple2_String_bool(
+      Tuple2<int, Tuple2<String, bool>> apiObj, ffi.Pointer<wire_tuple2_i32_tuple2_String_bool> wireObj) {
+    _api_fill_to_wire_tuple2_i32_tuple2_String_bool(apiObj, wireObj.ref);
+  }
+
+  void _api_fill_to_wire_box_autoadd_tuple3_i32_String_bool(
+      Tuple3<int, String, bool> apiObj, ffi.Pointer<wire_tuple3_i32_String_bool> wireObj) {
+    _api_fill_to_wire_tuple3_i32_String_bool(apiObj, wireObj.ref);
+  }
+
   void _api_fill_to_wire_box_autoadd_user_id(UserId apiObj, ffi.Pointer<wire_UserId> wireObj) {
     _api_fill_to_wire_user_id(apiObj, wireObj.ref);
   }
@@ -971,6 +1459,10 @@ class FlutterRustBridgeExampleSingleBlockTestPlatform
     wireObj = api2wire_u8_array_1600(apiObj);
   }
 
+  void _api_fill_to_wire_colliding_key(CollidingKey apiObj, wire_CollidingKey wireObj) {
+    wireObj.field0 = api2wire_String(apiObj.field0);
+  }
+
   void _api_fill_to_wire_concatenate_with(ConcatenateWith apiObj, wire_ConcatenateWith wireObj) {
     wireObj.a = api2wire_String(apiObj.a);
   }
@@ -999,6 +1491,16 @@ class FlutterRustBridgeExampleSingleBlockTestPlatform
     }
   }
 
+  void _api_fill_to_wire_document(Document apiObj, wire_Document wireObj) {
+    _api_fill_to_wire_metadata(
+        Metadata(
+          id: apiObj.id,
+          createdBy: apiObj.createdBy,
+        ),
+        wireObj.metadata);
+    wireObj.title = api2wire_String(apiObj.title);
+  }
+
   void _api_fill_to_wire_empty(Empty apiObj, wire_Empty wireObj) {}
 
   void _api_fill_to_wire_enum_dart_opaque(EnumDartOpaque apiObj, wire_EnumDartOpaque wireObj) {
@@ -1089,6 +1591,17 @@ class FlutterRustBridgeExampleSingleBlockTestPlatform
     wireObj.field0 = api2wire_u8_array_8(apiObj.field0);
   }
 
+  void _api_fill_to_wire_frame(Frame apiObj, wire_Frame wireObj) {
+    wireObj.index = api2wire_u32(apiObj.index);
+    wireObj.pixels = api2wire_uint_8_list(apiObj.pixels);
+    wireObj.depths = api2wire_opt_float_32_list(apiObj.depths);
+  }
+
+  void _api_fill_to_wire_grid_cell(GridCell apiObj, wire_GridCell wireObj) {
+    wireObj.row = api2wire_i32(apiObj.row);
+    wireObj.column = api2wire_i32(apiObj.column);
+  }
+
   void _api_fill_to_wire_kitchen_sink(KitchenSink apiObj, wire_KitchenSink wireObj) {
     if (apiObj is KitchenSink_Empty) {
       wireObj.tag = 0;
@@ -1160,6 +1673,16 @@ class FlutterRustBridgeExampleSingleBlockTestPlatform
     wireObj.field0 = api2wire_u8_array_32(apiObj.field0);
   }
 
+  void _api_fill_to_wire_metadata(Metadata apiObj, wire_Metadata wireObj) {
+    wireObj.id = api2wire_u32(apiObj.id);
+    wireObj.created_by = api2wire_String(apiObj.createdBy);
+  }
+
+  void _api_fill_to_wire_money(Money apiObj, wire_Money wireObj) {
+    final fields = moneyToRaw(apiObj);
+    wireObj.cents = api2wire_i64(fields.cents);
+  }
+
   void _api_fill_to_wire_my_nested_struct(MyNestedStruct apiObj, wire_MyNestedStruct wireObj) {
     _api_fill_to_wire_my_tree_node(apiObj.treeNode, wireObj.tree_node);
     wireObj.weekday = api2wire_weekdays(apiObj.weekday);
@@ -1229,10 +1752,35 @@ class FlutterRustBridgeExampleSingleBlockTestPlatform
     if (apiObj != null) _api_fill_to_wire_box_exotic_optionals(apiObj, wireObj);
   }
 
+  void _api_fill_to_wire_pair_i_32(PairI32 apiObj, wire_PairI32 wireObj) {
+    wireObj.first = api2wire_i32(apiObj.first);
+    wireObj.second = api2wire_int_32_list(apiObj.second);
+  }
+
+  void _api_fill_to_wire_product_id(ProductId apiObj, wire_ProductId wireObj) {
+    wireObj.field0 = api2wire_u32(apiObj.field0);
+  }
+
+  void _api_fill_to_wire_progress(Progress apiObj, wire_Progress wireObj) {
+    wireObj.percentage = api2wire_u8(apiObj.percentage);
+  }
+
+  void _api_fill_to_wire_sample(Sample apiObj, wire_Sample wireObj) {
+    wireObj.index = api2wire_u32(apiObj.index);
+    wireObj.x = api2wire_f64(apiObj.x);
+    wireObj.y = api2wire_f32(apiObj.y);
+    wireObj.valid = api2wire_bool(apiObj.valid);
+    wireObj.level = api2wire_i8(apiObj.level);
+  }
+
   void _api_fill_to_wire_sequences(Sequences apiObj, wire_Sequences wireObj) {
     wireObj.field0 = api2wire_int_32_list(apiObj.field0);
   }
 
+  void _api_fill_to_wire_session(Session apiObj, wire_Session wireObj) {
+    wireObj.user = api2wire_String(apiObj.user);
+  }
+
   void _api_fill_to_wire_speed(Speed apiObj, wire_Speed wireObj) {
     if (apiObj is Speed_Unknown) {
       wireObj.tag = 0;
@@ -1251,10 +1799,34 @@ class FlutterRustBridgeExampleSingleBlockTestPlatform
     wireObj.x = api2wire_u32(apiObj.x);
   }
 
+  void _api_fill_to_wire_tag(Tag apiObj, wire_Tag wireObj) {
+    wireObj.name = api2wire_String(apiObj.name);
+    wireObj.color = api2wire_opt_box_autoadd_u32(apiObj.color);
+    wireObj.aliases = api2wire_StringList(apiObj.aliases);
+    wireObj.day = api2wire_weekdays(apiObj.day);
+  }
+
   void _api_fill_to_wire_test_id(TestId apiObj, wire_TestId wireObj) {
     wireObj.field0 = api2wire_i32_array_2(apiObj.field0);
   }
 
+  void _api_fill_to_wire_tuple2_String_bool(Tuple2<String, bool> apiObj, wire_tuple2_String_bool wireObj) {
+    wireObj.field0 = api2wire_String(apiObj.item1);
+    wireObj.field1 = api2wire_bool(apiObj.item2);
+  }
+
+  void _api_fill_to_wire_tuple2_i32_tuple2_String_bool(
+      Tuple2<int, Tuple2<String, bool>> apiObj, wire_tuple2_i32_tuple2_String_bool wireObj) {
+    wireObj.field0 = api2wire_i32(apiObj.item1);
+    _api_fill_to_wire_tuple2_String_bool(apiObj.item2, wireObj.field1);
+  }
+
+  void _api_fill_to_wire_tuple3_i32_String_bool(Tuple3<int, String, bool> apiObj, wire_tuple3_i32_String_bool wireObj) {
+    wireObj.field0 = api2wire_i32(apiObj.item1);
+    wireObj.field1 = api2wire_String(apiObj.item2);
+    wireObj.field2 = api2wire_bool(apiObj.item3);
+  }
+
   void _api_fill_to_wire_user_id(UserId apiObj, wire_UserId wireObj) {
     wireObj.value = api2wire_u32(apiObj.value);
   }
@@ -1339,6 +1911,80 @@ class FlutterRustBridgeExampleSingleBlockTestWire implements FlutterRustBridgeWi
       _lookup<ffi.NativeFunction<ffi.IntPtr Function(ffi.Pointer<ffi.Void>)>>('init_frb_dart_api_dl');
   late final _init_frb_dart_api_dl = _init_frb_dart_api_dlPtr.asFunction<int Function(ffi.Pointer<ffi.Void>)>();
 
+  int new_cancel_id() {
+    return _new_cancel_id();
+  }
+
+  late final _new_cancel_idPtr = _lookup<ffi.NativeFunction<ffi.Int32 Function()>>('new_cancel_id');
+  late final _new_cancel_id = _new_cancel_idPtr.asFunction<int Function()>();
+
+  void cancel_task(
+    int id,
+  ) {
+    return _cancel_task(
+      id,
+    );
+  }
+
+  late final _cancel_taskPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int32)>>('cancel_task');
+  late final _cancel_task = _cancel_taskPtr.asFunction<void Function(int)>();
+
+  int new_shared_buffer(
+    int len,
+  ) {
+    return _new_shared_buffer(
+      len,
+    );
+  }
+
+  late final _new_shared_bufferPtr =
+      _lookup<ffi.NativeFunction<ffi.UintPtr Function(ffi.UintPtr)>>('new_shared_buffer');
+  late final _new_shared_buffer = _new_shared_bufferPtr.asFunction<int Function(int)>();
+
+  void drop_shared_buffer(
+    int address,
+  ) {
+    return _drop_shared_buffer(
+      address,
+    );
+  }
+
+  late final _drop_shared_bufferPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.UintPtr)>>('drop_shared_buffer');
+  late final _drop_shared_buffer = _drop_shared_bufferPtr.asFunction<void Function(int)>();
+
+  void wire_init_app(
+    int port_,
+  ) {
+    return _wire_init_app(
+      port_,
+    );
+  }
+
+  late final _wire_init_appPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64)>>('wire_init_app');
+  late final _wire_init_app = _wire_init_appPtr.asFunction<void Function(int)>();
+
+  void wire_init_count(
+    int port_,
+  ) {
+    return _wire_init_count(
+      port_,
+    );
+  }
+
+  late final _wire_init_countPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64)>>('wire_init_count');
+  late final _wire_init_count = _wire_init_countPtr.asFunction<void Function(int)>();
+
+  void wire_get_constants(
+    int port_,
+  ) {
+    return _wire_get_constants(
+      port_,
+    );
+  }
+
+  late final _wire_get_constantsPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64)>>('wire_get_constants');
+  late final _wire_get_constants = _wire_get_constantsPtr.asFunction<void Function(int)>();
+
   void wire_simple_adder(
     int port_,
     int a,
@@ -1355,6 +2001,22 @@ class FlutterRustBridgeExampleSingleBlockTestWire implements FlutterRustBridgeWi
       _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Int32, ffi.Int32)>>('wire_simple_adder');
   late final _wire_simple_adder = _wire_simple_adderPtr.asFunction<void Function(int, int, int)>();
 
+  void wire_simple_adder_async(
+    int port_,
+    int a,
+    int b,
+  ) {
+    return _wire_simple_adder_async(
+      port_,
+      a,
+      b,
+    );
+  }
+
+  late final _wire_simple_adder_asyncPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Int32, ffi.Int32)>>('wire_simple_adder_async');
+  late final _wire_simple_adder_async = _wire_simple_adder_asyncPtr.asFunction<void Function(int, int, int)>();
+
   WireSyncReturn wire_simple_adder_sync(
     int a,
     int b,
@@ -1369,6 +2031,31 @@ class FlutterRustBridgeExampleSingleBlockTestWire implements FlutterRustBridgeWi
       _lookup<ffi.NativeFunction<WireSyncReturn Function(ffi.Int32, ffi.Int32)>>('wire_simple_adder_sync');
   late final _wire_simple_adder_sync = _wire_simple_adder_syncPtr.asFunction<WireSyncReturn Function(int, int)>();
 
+  double wire_scale(
+    double x,
+    double factor,
+  ) {
+    return _wire_scale(
+      x,
+      factor,
+    );
+  }
+
+  late final _wire_scalePtr = _lookup<ffi.NativeFunction<ffi.Double Function(ffi.Double, ffi.Double)>>('wire_scale');
+  late final _wire_scale = _wire_scalePtr.asFunction<double Function(double, double)>();
+
+  double wire_sum_vector(
+    ffi.Pointer<wire_float_32_list> v,
+  ) {
+    return _wire_sum_vector(
+      v,
+    );
+  }
+
+  late final _wire_sum_vectorPtr =
+      _lookup<ffi.NativeFunction<ffi.Float Function(ffi.Pointer<wire_float_32_list>)>>('wire_sum_vector');
+  late final _wire_sum_vector = _wire_sum_vectorPtr.asFunction<double Function(ffi.Pointer<wire_float_32_list>)>();
+
   void wire_primitive_types(
     int port_,
     int my_i32,
@@ -1488,1942 +2175,3699 @@ class FlutterRustBridgeExampleSingleBlockTestWire implements FlutterRustBridgeWi
   late final _wire_handle_string_sync =
       _wire_handle_string_syncPtr.asFunction<WireSyncReturn Function(ffi.Pointer<wire_uint_8_list>)>();
 
-  void wire_handle_return_unit(
+  void wire_next_char(
     int port_,
+    int c,
   ) {
-    return _wire_handle_return_unit(
+    return _wire_next_char(
       port_,
+      c,
     );
   }
 
-  late final _wire_handle_return_unitPtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64)>>('wire_handle_return_unit');
-  late final _wire_handle_return_unit = _wire_handle_return_unitPtr.asFunction<void Function(int)>();
-
-  WireSyncReturn wire_handle_return_unit_sync() {
-    return _wire_handle_return_unit_sync();
-  }
-
-  late final _wire_handle_return_unit_syncPtr =
-      _lookup<ffi.NativeFunction<WireSyncReturn Function()>>('wire_handle_return_unit_sync');
-  late final _wire_handle_return_unit_sync = _wire_handle_return_unit_syncPtr.asFunction<WireSyncReturn Function()>();
+  late final _wire_next_charPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Uint32)>>('wire_next_char');
+  late final _wire_next_char = _wire_next_charPtr.asFunction<void Function(int, int)>();
 
-  void wire_handle_vec_u8(
+  void wire_handle_chars(
     int port_,
-    ffi.Pointer<wire_uint_8_list> v,
+    ffi.Pointer<wire_list_char> chars,
   ) {
-    return _wire_handle_vec_u8(
+    return _wire_handle_chars(
       port_,
-      v,
+      chars,
     );
   }
 
-  late final _wire_handle_vec_u8Ptr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_uint_8_list>)>>('wire_handle_vec_u8');
-  late final _wire_handle_vec_u8 =
-      _wire_handle_vec_u8Ptr.asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>)>();
+  late final _wire_handle_charsPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_list_char>)>>('wire_handle_chars');
+  late final _wire_handle_chars = _wire_handle_charsPtr.asFunction<void Function(int, ffi.Pointer<wire_list_char>)>();
 
-  WireSyncReturn wire_handle_vec_u8_sync(
-    ffi.Pointer<wire_uint_8_list> v,
+  void wire_divide_by(
+    int port_,
+    int n,
+    int by,
   ) {
-    return _wire_handle_vec_u8_sync(
-      v,
+    return _wire_divide_by(
+      port_,
+      n,
+      by,
     );
   }
 
-  late final _wire_handle_vec_u8_syncPtr =
-      _lookup<ffi.NativeFunction<WireSyncReturn Function(ffi.Pointer<wire_uint_8_list>)>>('wire_handle_vec_u8_sync');
-  late final _wire_handle_vec_u8_sync =
-      _wire_handle_vec_u8_syncPtr.asFunction<WireSyncReturn Function(ffi.Pointer<wire_uint_8_list>)>();
+  late final _wire_divide_byPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Uint32, ffi.Uint32)>>('wire_divide_by');
+  late final _wire_divide_by = _wire_divide_byPtr.asFunction<void Function(int, int, int)>();
 
-  void wire_handle_vec_of_primitive(
+  void wire_next_non_zero(
     int port_,
     int n,
   ) {
-    return _wire_handle_vec_of_primitive(
+    return _wire_next_non_zero(
       port_,
       n,
     );
   }
 
-  late final _wire_handle_vec_of_primitivePtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Int32)>>('wire_handle_vec_of_primitive');
-  late final _wire_handle_vec_of_primitive = _wire_handle_vec_of_primitivePtr.asFunction<void Function(int, int)>();
+  late final _wire_next_non_zeroPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Uint64)>>('wire_next_non_zero');
+  late final _wire_next_non_zero = _wire_next_non_zeroPtr.asFunction<void Function(int, int)>();
 
-  WireSyncReturn wire_handle_vec_of_primitive_sync(
-    int n,
+  void wire_greet(
+    int port_,
+    ffi.Pointer<wire_uint_8_list> name,
+    int times,
   ) {
-    return _wire_handle_vec_of_primitive_sync(
-      n,
+    return _wire_greet(
+      port_,
+      name,
+      times,
     );
   }
 
-  late final _wire_handle_vec_of_primitive_syncPtr =
-      _lookup<ffi.NativeFunction<WireSyncReturn Function(ffi.Int32)>>('wire_handle_vec_of_primitive_sync');
-  late final _wire_handle_vec_of_primitive_sync =
-      _wire_handle_vec_of_primitive_syncPtr.asFunction<WireSyncReturn Function(int)>();
+  late final _wire_greetPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_uint_8_list>, ffi.Uint32)>>(
+          'wire_greet');
+  late final _wire_greet = _wire_greetPtr.asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>, int)>();
 
-  void wire_handle_zero_copy_vec_of_primitive(
+  void wire_instant_now(
     int port_,
-    int n,
   ) {
-    return _wire_handle_zero_copy_vec_of_primitive(
+    return _wire_instant_now(
       port_,
-      n,
     );
   }
 
-  late final _wire_handle_zero_copy_vec_of_primitivePtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Int32)>>('wire_handle_zero_copy_vec_of_primitive');
-  late final _wire_handle_zero_copy_vec_of_primitive =
-      _wire_handle_zero_copy_vec_of_primitivePtr.asFunction<void Function(int, int)>();
+  late final _wire_instant_nowPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64)>>('wire_instant_now');
+  late final _wire_instant_now = _wire_instant_nowPtr.asFunction<void Function(int)>();
 
-  WireSyncReturn wire_handle_zero_copy_vec_of_primitive_sync(
-    int n,
+  void wire_instant_after(
+    int port_,
+    wire_Instant start,
+    int millis,
   ) {
-    return _wire_handle_zero_copy_vec_of_primitive_sync(
-      n,
+    return _wire_instant_after(
+      port_,
+      start,
+      millis,
     );
   }
 
-  late final _wire_handle_zero_copy_vec_of_primitive_syncPtr =
-      _lookup<ffi.NativeFunction<WireSyncReturn Function(ffi.Int32)>>('wire_handle_zero_copy_vec_of_primitive_sync');
-  late final _wire_handle_zero_copy_vec_of_primitive_sync =
-      _wire_handle_zero_copy_vec_of_primitive_syncPtr.asFunction<WireSyncReturn Function(int)>();
+  late final _wire_instant_afterPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, wire_Instant, ffi.Uint64)>>('wire_instant_after');
+  late final _wire_instant_after = _wire_instant_afterPtr.asFunction<void Function(int, wire_Instant, int)>();
 
-  void wire_handle_struct(
+  void wire_one_day_later(
     int port_,
-    ffi.Pointer<wire_MySize> arg,
-    ffi.Pointer<wire_MySize> boxed,
+    int time,
   ) {
-    return _wire_handle_struct(
+    return _wire_one_day_later(
       port_,
-      arg,
-      boxed,
+      time,
     );
   }
 
-  late final _wire_handle_structPtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_MySize>, ffi.Pointer<wire_MySize>)>>(
-          'wire_handle_struct');
-  late final _wire_handle_struct =
-      _wire_handle_structPtr.asFunction<void Function(int, ffi.Pointer<wire_MySize>, ffi.Pointer<wire_MySize>)>();
+  late final _wire_one_day_laterPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Int64)>>('wire_one_day_later');
+  late final _wire_one_day_later = _wire_one_day_laterPtr.asFunction<void Function(int, int)>();
 
-  WireSyncReturn wire_handle_struct_sync(
-    ffi.Pointer<wire_MySize> arg,
-    ffi.Pointer<wire_MySize> boxed,
+  void wire_shift_range(
+    int port_,
+    ffi.Pointer<wire_uint_32_list> range,
+    int by,
   ) {
-    return _wire_handle_struct_sync(
-      arg,
-      boxed,
+    return _wire_shift_range(
+      port_,
+      range,
+      by,
     );
   }
 
-  late final _wire_handle_struct_syncPtr =
-      _lookup<ffi.NativeFunction<WireSyncReturn Function(ffi.Pointer<wire_MySize>, ffi.Pointer<wire_MySize>)>>(
-          'wire_handle_struct_sync');
-  late final _wire_handle_struct_sync = _wire_handle_struct_syncPtr
-      .asFunction<WireSyncReturn Function(ffi.Pointer<wire_MySize>, ffi.Pointer<wire_MySize>)>();
+  late final _wire_shift_rangePtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_uint_32_list>, ffi.Uint32)>>(
+          'wire_shift_range');
+  late final _wire_shift_range =
+      _wire_shift_rangePtr.asFunction<void Function(int, ffi.Pointer<wire_uint_32_list>, int)>();
 
-  void wire_handle_newtype(
+  void wire_clamp_range(
     int port_,
-    ffi.Pointer<wire_NewTypeInt> arg,
+    ffi.Pointer<wire_float_64_list> range,
+    double value,
   ) {
-    return _wire_handle_newtype(
+    return _wire_clamp_range(
       port_,
-      arg,
+      range,
+      value,
     );
   }
 
-  late final _wire_handle_newtypePtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_NewTypeInt>)>>('wire_handle_newtype');
-  late final _wire_handle_newtype =
-      _wire_handle_newtypePtr.asFunction<void Function(int, ffi.Pointer<wire_NewTypeInt>)>();
+  late final _wire_clamp_rangePtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_float_64_list>, ffi.Double)>>(
+          'wire_clamp_range');
+  late final _wire_clamp_range =
+      _wire_clamp_rangePtr.asFunction<void Function(int, ffi.Pointer<wire_float_64_list>, double)>();
 
-  WireSyncReturn wire_handle_newtype_sync(
-    ffi.Pointer<wire_NewTypeInt> arg,
+  void wire_describe_size(
+    int port_,
+    ffi.Pointer<wire_MySize> size,
+    ffi.Pointer<wire_uint_8_list> unit,
+    ffi.Pointer<wire_StringList> labels,
   ) {
-    return _wire_handle_newtype_sync(
-      arg,
+    return _wire_describe_size(
+      port_,
+      size,
+      unit,
+      labels,
     );
   }
 
-  late final _wire_handle_newtype_syncPtr =
-      _lookup<ffi.NativeFunction<WireSyncReturn Function(ffi.Pointer<wire_NewTypeInt>)>>('wire_handle_newtype_sync');
-  late final _wire_handle_newtype_sync =
-      _wire_handle_newtype_syncPtr.asFunction<WireSyncReturn Function(ffi.Pointer<wire_NewTypeInt>)>();
+  late final _wire_describe_sizePtr = _lookup<
+      ffi.NativeFunction<
+          ffi.Void Function(ffi.Int64, ffi.Pointer<wire_MySize>, ffi.Pointer<wire_uint_8_list>,
+              ffi.Pointer<wire_StringList>)>>('wire_describe_size');
+  late final _wire_describe_size = _wire_describe_sizePtr.asFunction<
+      void Function(int, ffi.Pointer<wire_MySize>, ffi.Pointer<wire_uint_8_list>, ffi.Pointer<wire_StringList>)>();
 
-  void wire_handle_list_of_struct(
+  void wire_split_words(
     int port_,
-    ffi.Pointer<wire_list_my_size> l,
+    ffi.Pointer<wire_uint_8_list> text,
   ) {
-    return _wire_handle_list_of_struct(
+    return _wire_split_words(
       port_,
-      l,
+      text,
     );
   }
 
-  late final _wire_handle_list_of_structPtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_list_my_size>)>>(
-          'wire_handle_list_of_struct');
-  late final _wire_handle_list_of_struct =
-      _wire_handle_list_of_structPtr.asFunction<void Function(int, ffi.Pointer<wire_list_my_size>)>();
+  late final _wire_split_wordsPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_uint_8_list>)>>('wire_split_words');
+  late final _wire_split_words = _wire_split_wordsPtr.asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>)>();
 
-  WireSyncReturn wire_handle_list_of_struct_sync(
-    ffi.Pointer<wire_list_my_size> l,
+  void wire_handle_cow_str(
+    int port_,
+    ffi.Pointer<wire_uint_8_list> s,
   ) {
-    return _wire_handle_list_of_struct_sync(
-      l,
+    return _wire_handle_cow_str(
+      port_,
+      s,
     );
   }
 
-  late final _wire_handle_list_of_struct_syncPtr =
-      _lookup<ffi.NativeFunction<WireSyncReturn Function(ffi.Pointer<wire_list_my_size>)>>(
-          'wire_handle_list_of_struct_sync');
-  late final _wire_handle_list_of_struct_sync =
-      _wire_handle_list_of_struct_syncPtr.asFunction<WireSyncReturn Function(ffi.Pointer<wire_list_my_size>)>();
+  late final _wire_handle_cow_strPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_uint_8_list>)>>('wire_handle_cow_str');
+  late final _wire_handle_cow_str =
+      _wire_handle_cow_strPtr.asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>)>();
 
-  void wire_handle_string_list(
+  void wire_handle_cow_bytes(
     int port_,
-    ffi.Pointer<wire_StringList> names,
+    ffi.Pointer<wire_uint_8_list> bytes,
   ) {
-    return _wire_handle_string_list(
+    return _wire_handle_cow_bytes(
       port_,
-      names,
+      bytes,
     );
   }
 
-  late final _wire_handle_string_listPtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_StringList>)>>(
-          'wire_handle_string_list');
-  late final _wire_handle_string_list =
-      _wire_handle_string_listPtr.asFunction<void Function(int, ffi.Pointer<wire_StringList>)>();
+  late final _wire_handle_cow_bytesPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_uint_8_list>)>>('wire_handle_cow_bytes');
+  late final _wire_handle_cow_bytes =
+      _wire_handle_cow_bytesPtr.asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>)>();
 
-  WireSyncReturn wire_handle_string_list_sync(
-    ffi.Pointer<wire_StringList> names,
+  void wire_handle_path(
+    int port_,
+    ffi.Pointer<wire_uint_16_list> path,
   ) {
-    return _wire_handle_string_list_sync(
-      names,
+    return _wire_handle_path(
+      port_,
+      path,
     );
   }
 
-  late final _wire_handle_string_list_syncPtr =
-      _lookup<ffi.NativeFunction<WireSyncReturn Function(ffi.Pointer<wire_StringList>)>>(
-          'wire_handle_string_list_sync');
-  late final _wire_handle_string_list_sync =
-      _wire_handle_string_list_syncPtr.asFunction<WireSyncReturn Function(ffi.Pointer<wire_StringList>)>();
+  late final _wire_handle_pathPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_uint_16_list>)>>('wire_handle_path');
+  late final _wire_handle_path = _wire_handle_pathPtr.asFunction<void Function(int, ffi.Pointer<wire_uint_16_list>)>();
 
-  void wire_handle_complex_struct(
+  void wire_handle_socket_addr(
     int port_,
-    ffi.Pointer<wire_MyTreeNode> s,
+    ffi.Pointer<wire_uint_8_list> addr,
   ) {
-    return _wire_handle_complex_struct(
+    return _wire_handle_socket_addr(
       port_,
-      s,
+      addr,
     );
   }
 
-  late final _wire_handle_complex_structPtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_MyTreeNode>)>>(
-          'wire_handle_complex_struct');
-  late final _wire_handle_complex_struct =
-      _wire_handle_complex_structPtr.asFunction<void Function(int, ffi.Pointer<wire_MyTreeNode>)>();
+  late final _wire_handle_socket_addrPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_uint_8_list>)>>(
+          'wire_handle_socket_addr');
+  late final _wire_handle_socket_addr =
+      _wire_handle_socket_addrPtr.asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>)>();
 
-  WireSyncReturn wire_handle_complex_struct_sync(
-    ffi.Pointer<wire_MyTreeNode> s,
+  void wire_next_port(
+    int port_,
+    ffi.Pointer<wire_uint_8_list> addr,
   ) {
-    return _wire_handle_complex_struct_sync(
-      s,
+    return _wire_next_port(
+      port_,
+      addr,
     );
   }
 
-  late final _wire_handle_complex_struct_syncPtr =
-      _lookup<ffi.NativeFunction<WireSyncReturn Function(ffi.Pointer<wire_MyTreeNode>)>>(
-          'wire_handle_complex_struct_sync');
-  late final _wire_handle_complex_struct_sync =
-      _wire_handle_complex_struct_syncPtr.asFunction<WireSyncReturn Function(ffi.Pointer<wire_MyTreeNode>)>();
+  late final _wire_next_portPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_uint_8_list>)>>('wire_next_port');
+  late final _wire_next_port = _wire_next_portPtr.asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>)>();
 
-  void wire_handle_nested_struct(
+  void wire_handle_return_unit(
     int port_,
-    ffi.Pointer<wire_MyNestedStruct> s,
   ) {
-    return _wire_handle_nested_struct(
+    return _wire_handle_return_unit(
       port_,
-      s,
     );
   }
 
-  late final _wire_handle_nested_structPtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_MyNestedStruct>)>>(
-          'wire_handle_nested_struct');
-  late final _wire_handle_nested_struct =
-      _wire_handle_nested_structPtr.asFunction<void Function(int, ffi.Pointer<wire_MyNestedStruct>)>();
+  late final _wire_handle_return_unitPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64)>>('wire_handle_return_unit');
+  late final _wire_handle_return_unit = _wire_handle_return_unitPtr.asFunction<void Function(int)>();
 
-  WireSyncReturn wire_handle_sync_return(
-    ffi.Pointer<wire_uint_8_list> mode,
-  ) {
-    return _wire_handle_sync_return(
-      mode,
-    );
+  WireSyncReturn wire_handle_return_unit_sync() {
+    return _wire_handle_return_unit_sync();
   }
 
-  late final _wire_handle_sync_returnPtr =
-      _lookup<ffi.NativeFunction<WireSyncReturn Function(ffi.Pointer<wire_uint_8_list>)>>('wire_handle_sync_return');
-  late final _wire_handle_sync_return =
-      _wire_handle_sync_returnPtr.asFunction<WireSyncReturn Function(ffi.Pointer<wire_uint_8_list>)>();
+  late final _wire_handle_return_unit_syncPtr =
+      _lookup<ffi.NativeFunction<WireSyncReturn Function()>>('wire_handle_return_unit_sync');
+  late final _wire_handle_return_unit_sync = _wire_handle_return_unit_syncPtr.asFunction<WireSyncReturn Function()>();
 
-  void wire_handle_stream(
+  void wire_handle_vec_u8(
     int port_,
-    ffi.Pointer<wire_uint_8_list> arg,
+    ffi.Pointer<wire_uint_8_list> v,
   ) {
-    return _wire_handle_stream(
+    return _wire_handle_vec_u8(
       port_,
-      arg,
+      v,
     );
   }
 
-  late final _wire_handle_streamPtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_uint_8_list>)>>('wire_handle_stream');
-  late final _wire_handle_stream =
-      _wire_handle_streamPtr.asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>)>();
+  late final _wire_handle_vec_u8Ptr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_uint_8_list>)>>('wire_handle_vec_u8');
+  late final _wire_handle_vec_u8 =
+      _wire_handle_vec_u8Ptr.asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>)>();
 
-  void wire_handle_stream_of_struct(
+  void wire_handle_bytes(
     int port_,
+    ffi.Pointer<wire_uint_8_list> bytes,
   ) {
-    return _wire_handle_stream_of_struct(
+    return _wire_handle_bytes(
       port_,
+      bytes,
     );
   }
 
-  late final _wire_handle_stream_of_structPtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64)>>('wire_handle_stream_of_struct');
-  late final _wire_handle_stream_of_struct = _wire_handle_stream_of_structPtr.asFunction<void Function(int)>();
+  late final _wire_handle_bytesPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_uint_8_list>)>>('wire_handle_bytes');
+  late final _wire_handle_bytes = _wire_handle_bytesPtr.asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>)>();
 
-  void wire_return_err(
+  void wire_checksum(
     int port_,
+    ffi.Pointer<wire_uint_8_list> data,
+    ffi.Pointer<wire_uint_16_list> weights,
   ) {
-    return _wire_return_err(
+    return _wire_checksum(
       port_,
+      data,
+      weights,
     );
   }
 
-  late final _wire_return_errPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64)>>('wire_return_err');
-  late final _wire_return_err = _wire_return_errPtr.asFunction<void Function(int)>();
+  late final _wire_checksumPtr = _lookup<
+      ffi.NativeFunction<
+          ffi.Void Function(
+              ffi.Int64, ffi.Pointer<wire_uint_8_list>, ffi.Pointer<wire_uint_16_list>)>>('wire_checksum');
+  late final _wire_checksum =
+      _wire_checksumPtr.asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>, ffi.Pointer<wire_uint_16_list>)>();
 
-  void wire_return_panic(
+  void wire_count_in_shared_buffer(
     int port_,
+    int buffer,
+    int byte,
   ) {
-    return _wire_return_panic(
+    return _wire_count_in_shared_buffer(
       port_,
+      buffer,
+      byte,
     );
   }
 
-  late final _wire_return_panicPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64)>>('wire_return_panic');
-  late final _wire_return_panic = _wire_return_panicPtr.asFunction<void Function(int)>();
+  late final _wire_count_in_shared_bufferPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.UintPtr, ffi.Uint8)>>('wire_count_in_shared_buffer');
+  late final _wire_count_in_shared_buffer = _wire_count_in_shared_bufferPtr.asFunction<void Function(int, int, int)>();
 
-  void wire_handle_optional_return(
+  void wire_next_frame(
     int port_,
-    double left,
-    double right,
+    ffi.Pointer<wire_Frame> frame,
   ) {
-    return _wire_handle_optional_return(
+    return _wire_next_frame(
       port_,
-      left,
-      right,
+      frame,
     );
   }
 
-  late final _wire_handle_optional_returnPtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Double, ffi.Double)>>('wire_handle_optional_return');
-  late final _wire_handle_optional_return =
-      _wire_handle_optional_returnPtr.asFunction<void Function(int, double, double)>();
+  late final _wire_next_framePtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_Frame>)>>('wire_next_frame');
+  late final _wire_next_frame = _wire_next_framePtr.asFunction<void Function(int, ffi.Pointer<wire_Frame>)>();
 
-  void wire_handle_optional_struct(
+  void wire_prefix_sums(
     int port_,
-    ffi.Pointer<wire_uint_8_list> document,
+    ffi.Pointer<wire_uint_64_list> items,
   ) {
-    return _wire_handle_optional_struct(
+    return _wire_prefix_sums(
       port_,
-      document,
+      items,
     );
   }
 
-  late final _wire_handle_optional_structPtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_uint_8_list>)>>(
-          'wire_handle_optional_struct');
-  late final _wire_handle_optional_struct =
-      _wire_handle_optional_structPtr.asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>)>();
+  late final _wire_prefix_sumsPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_uint_64_list>)>>('wire_prefix_sums');
+  late final _wire_prefix_sums = _wire_prefix_sumsPtr.asFunction<void Function(int, ffi.Pointer<wire_uint_64_list>)>();
 
-  void wire_handle_optional_increment(
-    int port_,
-    ffi.Pointer<wire_ExoticOptionals> opt,
+  WireSyncReturn wire_handle_vec_u8_sync(
+    ffi.Pointer<wire_uint_8_list> v,
   ) {
-    return _wire_handle_optional_increment(
-      port_,
-      opt,
+    return _wire_handle_vec_u8_sync(
+      v,
     );
   }
 
-  late final _wire_handle_optional_incrementPtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_ExoticOptionals>)>>(
-          'wire_handle_optional_increment');
-  late final _wire_handle_optional_increment =
-      _wire_handle_optional_incrementPtr.asFunction<void Function(int, ffi.Pointer<wire_ExoticOptionals>)>();
+  late final _wire_handle_vec_u8_syncPtr =
+      _lookup<ffi.NativeFunction<WireSyncReturn Function(ffi.Pointer<wire_uint_8_list>)>>('wire_handle_vec_u8_sync');
+  late final _wire_handle_vec_u8_sync =
+      _wire_handle_vec_u8_syncPtr.asFunction<WireSyncReturn Function(ffi.Pointer<wire_uint_8_list>)>();
 
-  void wire_handle_increment_boxed_optional(
+  void wire_handle_vec_of_primitive(
     int port_,
-    ffi.Pointer<ffi.Double> opt,
+    int n,
   ) {
-    return _wire_handle_increment_boxed_optional(
+    return _wire_handle_vec_of_primitive(
       port_,
-      opt,
+      n,
     );
   }
 
-  late final _wire_handle_increment_boxed_optionalPtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<ffi.Double>)>>(
-          'wire_handle_increment_boxed_optional');
-  late final _wire_handle_increment_boxed_optional =
-      _wire_handle_increment_boxed_optionalPtr.asFunction<void Function(int, ffi.Pointer<ffi.Double>)>();
+  late final _wire_handle_vec_of_primitivePtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Int32)>>('wire_handle_vec_of_primitive');
+  late final _wire_handle_vec_of_primitive = _wire_handle_vec_of_primitivePtr.asFunction<void Function(int, int)>();
 
-  void wire_handle_option_box_arguments(
-    int port_,
-    ffi.Pointer<ffi.Int8> i8box,
-    ffi.Pointer<ffi.Uint8> u8box,
-    ffi.Pointer<ffi.Int32> i32box,
-    ffi.Pointer<ffi.Int64> i64box,
-    ffi.Pointer<ffi.Double> f64box,
-    ffi.Pointer<ffi.Bool> boolbox,
-    ffi.Pointer<wire_ExoticOptionals> structbox,
+  WireSyncReturn wire_handle_vec_of_primitive_sync(
+    int n,
   ) {
-    return _wire_handle_option_box_arguments(
-      port_,
-      i8box,
-      u8box,
-      i32box,
-      i64box,
-      f64box,
-      boolbox,
-      structbox,
+    return _wire_handle_vec_of_primitive_sync(
+      n,
     );
   }
 
-  late final _wire_handle_option_box_argumentsPtr = _lookup<
-      ffi.NativeFunction<
-          ffi.Void Function(
-              ffi.Int64,
-              ffi.Pointer<ffi.Int8>,
-              ffi.Pointer<ffi.Uint8>,
-              ffi.Pointer<ffi.Int32>,
-              ffi.Pointer<ffi.Int64>,
-              ffi.Pointer<ffi.Double>,
-              ffi.Pointer<ffi.Bool>,
-              ffi.Pointer<wire_ExoticOptionals>)>>('wire_handle_option_box_arguments');
-  late final _wire_handle_option_box_arguments = _wire_handle_option_box_argumentsPtr.asFunction<
-      void Function(int, ffi.Pointer<ffi.Int8>, ffi.Pointer<ffi.Uint8>, ffi.Pointer<ffi.Int32>, ffi.Pointer<ffi.Int64>,
-          ffi.Pointer<ffi.Double>, ffi.Pointer<ffi.Bool>, ffi.Pointer<wire_ExoticOptionals>)>();
+  late final _wire_handle_vec_of_primitive_syncPtr =
+      _lookup<ffi.NativeFunction<WireSyncReturn Function(ffi.Int32)>>('wire_handle_vec_of_primitive_sync');
+  late final _wire_handle_vec_of_primitive_sync =
+      _wire_handle_vec_of_primitive_syncPtr.asFunction<WireSyncReturn Function(int)>();
 
-  void wire_print_note(
+  void wire_handle_zero_copy_vec_of_primitive(
     int port_,
-    ffi.Pointer<wire_Note> note,
+    int n,
   ) {
-    return _wire_print_note(
+    return _wire_handle_zero_copy_vec_of_primitive(
       port_,
-      note,
+      n,
     );
   }
 
-  late final _wire_print_notePtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_Note>)>>('wire_print_note');
-  late final _wire_print_note = _wire_print_notePtr.asFunction<void Function(int, ffi.Pointer<wire_Note>)>();
+  late final _wire_handle_zero_copy_vec_of_primitivePtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Int32)>>('wire_handle_zero_copy_vec_of_primitive');
+  late final _wire_handle_zero_copy_vec_of_primitive =
+      _wire_handle_zero_copy_vec_of_primitivePtr.asFunction<void Function(int, int)>();
 
-  void wire_handle_return_enum(
-    int port_,
-    ffi.Pointer<wire_uint_8_list> input,
+  WireSyncReturn wire_handle_zero_copy_vec_of_primitive_sync(
+    int n,
   ) {
-    return _wire_handle_return_enum(
-      port_,
-      input,
+    return _wire_handle_zero_copy_vec_of_primitive_sync(
+      n,
     );
   }
 
-  late final _wire_handle_return_enumPtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_uint_8_list>)>>(
-          'wire_handle_return_enum');
-  late final _wire_handle_return_enum =
-      _wire_handle_return_enumPtr.asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>)>();
+  late final _wire_handle_zero_copy_vec_of_primitive_syncPtr =
+      _lookup<ffi.NativeFunction<WireSyncReturn Function(ffi.Int32)>>('wire_handle_zero_copy_vec_of_primitive_sync');
+  late final _wire_handle_zero_copy_vec_of_primitive_sync =
+      _wire_handle_zero_copy_vec_of_primitive_syncPtr.asFunction<WireSyncReturn Function(int)>();
 
-  void wire_handle_enum_parameter(
+  void wire_handle_struct(
     int port_,
-    int weekday,
+    ffi.Pointer<wire_MySize> arg,
+    ffi.Pointer<wire_MySize> boxed,
   ) {
-    return _wire_handle_enum_parameter(
+    return _wire_handle_struct(
       port_,
-      weekday,
+      arg,
+      boxed,
     );
   }
 
-  late final _wire_handle_enum_parameterPtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Int32)>>('wire_handle_enum_parameter');
-  late final _wire_handle_enum_parameter = _wire_handle_enum_parameterPtr.asFunction<void Function(int, int)>();
+  late final _wire_handle_structPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_MySize>, ffi.Pointer<wire_MySize>)>>(
+          'wire_handle_struct');
+  late final _wire_handle_struct =
+      _wire_handle_structPtr.asFunction<void Function(int, ffi.Pointer<wire_MySize>, ffi.Pointer<wire_MySize>)>();
 
-  void wire_handle_customized_struct(
-    int port_,
-    ffi.Pointer<wire_Customized> val,
-  ) {
-    return _wire_handle_customized_struct(
-      port_,
-      val,
+  WireSyncReturn wire_handle_struct_sync(
+    ffi.Pointer<wire_MySize> arg,
+    ffi.Pointer<wire_MySize> boxed,
+  ) {
+    return _wire_handle_struct_sync(
+      arg,
+      boxed,
     );
   }
 
-  late final _wire_handle_customized_structPtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_Customized>)>>(
-          'wire_handle_customized_struct');
-  late final _wire_handle_customized_struct =
-      _wire_handle_customized_structPtr.asFunction<void Function(int, ffi.Pointer<wire_Customized>)>();
+  late final _wire_handle_struct_syncPtr =
+      _lookup<ffi.NativeFunction<WireSyncReturn Function(ffi.Pointer<wire_MySize>, ffi.Pointer<wire_MySize>)>>(
+          'wire_handle_struct_sync');
+  late final _wire_handle_struct_sync = _wire_handle_struct_syncPtr
+      .asFunction<WireSyncReturn Function(ffi.Pointer<wire_MySize>, ffi.Pointer<wire_MySize>)>();
 
-  void wire_handle_enum_struct(
+  void wire_handle_newtype(
     int port_,
-    ffi.Pointer<wire_KitchenSink> val,
+    ffi.Pointer<wire_NewTypeInt> arg,
   ) {
-    return _wire_handle_enum_struct(
+    return _wire_handle_newtype(
       port_,
-      val,
+      arg,
     );
   }
 
-  late final _wire_handle_enum_structPtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_KitchenSink>)>>(
-          'wire_handle_enum_struct');
-  late final _wire_handle_enum_struct =
-      _wire_handle_enum_structPtr.asFunction<void Function(int, ffi.Pointer<wire_KitchenSink>)>();
+  late final _wire_handle_newtypePtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_NewTypeInt>)>>('wire_handle_newtype');
+  late final _wire_handle_newtype =
+      _wire_handle_newtypePtr.asFunction<void Function(int, ffi.Pointer<wire_NewTypeInt>)>();
 
-  void wire_use_imported_struct(
-    int port_,
-    ffi.Pointer<wire_MyStruct> my_struct,
+  WireSyncReturn wire_handle_newtype_sync(
+    ffi.Pointer<wire_NewTypeInt> arg,
   ) {
-    return _wire_use_imported_struct(
-      port_,
-      my_struct,
+    return _wire_handle_newtype_sync(
+      arg,
     );
   }
 
-  late final _wire_use_imported_structPtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_MyStruct>)>>('wire_use_imported_struct');
-  late final _wire_use_imported_struct =
-      _wire_use_imported_structPtr.asFunction<void Function(int, ffi.Pointer<wire_MyStruct>)>();
+  late final _wire_handle_newtype_syncPtr =
+      _lookup<ffi.NativeFunction<WireSyncReturn Function(ffi.Pointer<wire_NewTypeInt>)>>('wire_handle_newtype_sync');
+  late final _wire_handle_newtype_sync =
+      _wire_handle_newtype_syncPtr.asFunction<WireSyncReturn Function(ffi.Pointer<wire_NewTypeInt>)>();
 
-  void wire_use_imported_enum(
+  void wire_handle_generic_struct(
     int port_,
-    int my_enum,
+    ffi.Pointer<wire_PairI32> pair,
   ) {
-    return _wire_use_imported_enum(
+    return _wire_handle_generic_struct(
       port_,
-      my_enum,
+      pair,
     );
   }
 
-  late final _wire_use_imported_enumPtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Int32)>>('wire_use_imported_enum');
-  late final _wire_use_imported_enum = _wire_use_imported_enumPtr.asFunction<void Function(int, int)>();
+  late final _wire_handle_generic_structPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_PairI32>)>>(
+          'wire_handle_generic_struct');
+  late final _wire_handle_generic_struct =
+      _wire_handle_generic_structPtr.asFunction<void Function(int, ffi.Pointer<wire_PairI32>)>();
 
-  void wire_get_app_settings(
+  void wire_handle_list_of_struct(
     int port_,
+    ffi.Pointer<wire_list_my_size> l,
   ) {
-    return _wire_get_app_settings(
+    return _wire_handle_list_of_struct(
       port_,
+      l,
     );
   }
 
-  late final _wire_get_app_settingsPtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64)>>('wire_get_app_settings');
-  late final _wire_get_app_settings = _wire_get_app_settingsPtr.asFunction<void Function(int)>();
+  late final _wire_handle_list_of_structPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_list_my_size>)>>(
+          'wire_handle_list_of_struct');
+  late final _wire_handle_list_of_struct =
+      _wire_handle_list_of_structPtr.asFunction<void Function(int, ffi.Pointer<wire_list_my_size>)>();
 
-  void wire_get_fallible_app_settings(
-    int port_,
+  WireSyncReturn wire_handle_list_of_struct_sync(
+    ffi.Pointer<wire_list_my_size> l,
   ) {
-    return _wire_get_fallible_app_settings(
-      port_,
+    return _wire_handle_list_of_struct_sync(
+      l,
     );
   }
 
-  late final _wire_get_fallible_app_settingsPtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64)>>('wire_get_fallible_app_settings');
-  late final _wire_get_fallible_app_settings = _wire_get_fallible_app_settingsPtr.asFunction<void Function(int)>();
+  late final _wire_handle_list_of_struct_syncPtr =
+      _lookup<ffi.NativeFunction<WireSyncReturn Function(ffi.Pointer<wire_list_my_size>)>>(
+          'wire_handle_list_of_struct_sync');
+  late final _wire_handle_list_of_struct_sync =
+      _wire_handle_list_of_struct_syncPtr.asFunction<WireSyncReturn Function(ffi.Pointer<wire_list_my_size>)>();
 
-  void wire_is_app_embedded(
+  void wire_handle_nested_vec(
     int port_,
-    ffi.Pointer<wire_ApplicationSettings> app_settings,
+    ffi.Pointer<wire_list_int_32_list> rows,
   ) {
-    return _wire_is_app_embedded(
+    return _wire_handle_nested_vec(
       port_,
-      app_settings,
+      rows,
     );
   }
 
-  late final _wire_is_app_embeddedPtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_ApplicationSettings>)>>(
-          'wire_is_app_embedded');
-  late final _wire_is_app_embedded =
-      _wire_is_app_embeddedPtr.asFunction<void Function(int, ffi.Pointer<wire_ApplicationSettings>)>();
+  late final _wire_handle_nested_vecPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_list_int_32_list>)>>(
+          'wire_handle_nested_vec');
+  late final _wire_handle_nested_vec =
+      _wire_handle_nested_vecPtr.asFunction<void Function(int, ffi.Pointer<wire_list_int_32_list>)>();
 
-  void wire_get_message(
+  void wire_handle_string_list(
     int port_,
+    ffi.Pointer<wire_StringList> names,
   ) {
-    return _wire_get_message(
+    return _wire_handle_string_list(
       port_,
+      names,
     );
   }
 
-  late final _wire_get_messagePtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64)>>('wire_get_message');
-  late final _wire_get_message = _wire_get_messagePtr.asFunction<void Function(int)>();
+  late final _wire_handle_string_listPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_StringList>)>>(
+          'wire_handle_string_list');
+  late final _wire_handle_string_list =
+      _wire_handle_string_listPtr.asFunction<void Function(int, ffi.Pointer<wire_StringList>)>();
 
-  void wire_repeat_number(
-    int port_,
-    int num,
-    int times,
+  WireSyncReturn wire_handle_string_list_sync(
+    ffi.Pointer<wire_StringList> names,
   ) {
-    return _wire_repeat_number(
-      port_,
-      num,
-      times,
+    return _wire_handle_string_list_sync(
+      names,
     );
   }
 
-  late final _wire_repeat_numberPtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Int32, ffi.UintPtr)>>('wire_repeat_number');
-  late final _wire_repeat_number = _wire_repeat_numberPtr.asFunction<void Function(int, int, int)>();
+  late final _wire_handle_string_list_syncPtr =
+      _lookup<ffi.NativeFunction<WireSyncReturn Function(ffi.Pointer<wire_StringList>)>>(
+          'wire_handle_string_list_sync');
+  late final _wire_handle_string_list_sync =
+      _wire_handle_string_list_syncPtr.asFunction<WireSyncReturn Function(ffi.Pointer<wire_StringList>)>();
 
-  void wire_repeat_sequence(
+  void wire_handle_complex_struct(
     int port_,
-    int seq,
-    int times,
+    ffi.Pointer<wire_MyTreeNode> s,
   ) {
-    return _wire_repeat_sequence(
+    return _wire_handle_complex_struct(
       port_,
-      seq,
-      times,
+      s,
     );
   }
 
-  late final _wire_repeat_sequencePtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Int32, ffi.UintPtr)>>('wire_repeat_sequence');
-  late final _wire_repeat_sequence = _wire_repeat_sequencePtr.asFunction<void Function(int, int, int)>();
+  late final _wire_handle_complex_structPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_MyTreeNode>)>>(
+          'wire_handle_complex_struct');
+  late final _wire_handle_complex_struct =
+      _wire_handle_complex_structPtr.asFunction<void Function(int, ffi.Pointer<wire_MyTreeNode>)>();
 
-  void wire_first_number(
-    int port_,
-    ffi.Pointer<wire_Numbers> nums,
+  WireSyncReturn wire_handle_complex_struct_sync(
+    ffi.Pointer<wire_MyTreeNode> s,
   ) {
-    return _wire_first_number(
-      port_,
-      nums,
+    return _wire_handle_complex_struct_sync(
+      s,
     );
   }
 
-  late final _wire_first_numberPtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_Numbers>)>>('wire_first_number');
-  late final _wire_first_number = _wire_first_numberPtr.asFunction<void Function(int, ffi.Pointer<wire_Numbers>)>();
+  late final _wire_handle_complex_struct_syncPtr =
+      _lookup<ffi.NativeFunction<WireSyncReturn Function(ffi.Pointer<wire_MyTreeNode>)>>(
+          'wire_handle_complex_struct_sync');
+  late final _wire_handle_complex_struct_sync =
+      _wire_handle_complex_struct_syncPtr.asFunction<WireSyncReturn Function(ffi.Pointer<wire_MyTreeNode>)>();
 
-  void wire_first_sequence(
+  void wire_handle_nested_struct(
     int port_,
-    ffi.Pointer<wire_Sequences> seqs,
+    ffi.Pointer<wire_MyNestedStruct> s,
   ) {
-    return _wire_first_sequence(
+    return _wire_handle_nested_struct(
       port_,
-      seqs,
+      s,
     );
   }
 
-  late final _wire_first_sequencePtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_Sequences>)>>('wire_first_sequence');
-  late final _wire_first_sequence =
-      _wire_first_sequencePtr.asFunction<void Function(int, ffi.Pointer<wire_Sequences>)>();
+  late final _wire_handle_nested_structPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_MyNestedStruct>)>>(
+          'wire_handle_nested_struct');
+  late final _wire_handle_nested_struct =
+      _wire_handle_nested_structPtr.asFunction<void Function(int, ffi.Pointer<wire_MyNestedStruct>)>();
 
-  void wire_get_array(
-    int port_,
+  WireSyncReturn wire_handle_sync_return(
+    ffi.Pointer<wire_uint_8_list> mode,
   ) {
-    return _wire_get_array(
-      port_,
+    return _wire_handle_sync_return(
+      mode,
     );
   }
 
-  late final _wire_get_arrayPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64)>>('wire_get_array');
-  late final _wire_get_array = _wire_get_arrayPtr.asFunction<void Function(int)>();
+  late final _wire_handle_sync_returnPtr =
+      _lookup<ffi.NativeFunction<WireSyncReturn Function(ffi.Pointer<wire_uint_8_list>)>>('wire_handle_sync_return');
+  late final _wire_handle_sync_return =
+      _wire_handle_sync_returnPtr.asFunction<WireSyncReturn Function(ffi.Pointer<wire_uint_8_list>)>();
 
-  void wire_get_complex_array(
+  void wire_handle_stream(
     int port_,
+    ffi.Pointer<wire_uint_8_list> arg,
   ) {
-    return _wire_get_complex_array(
+    return _wire_handle_stream(
       port_,
+      arg,
     );
   }
 
-  late final _wire_get_complex_arrayPtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64)>>('wire_get_complex_array');
-  late final _wire_get_complex_array = _wire_get_complex_arrayPtr.asFunction<void Function(int)>();
+  late final _wire_handle_streamPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_uint_8_list>)>>('wire_handle_stream');
+  late final _wire_handle_stream =
+      _wire_handle_streamPtr.asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>)>();
 
-  void wire_get_usize(
+  void wire_handle_stream_of_struct(
     int port_,
-    int u,
   ) {
-    return _wire_get_usize(
+    return _wire_handle_stream_of_struct(
       port_,
-      u,
     );
   }
 
-  late final _wire_get_usizePtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.UintPtr)>>('wire_get_usize');
-  late final _wire_get_usize = _wire_get_usizePtr.asFunction<void Function(int, int)>();
+  late final _wire_handle_stream_of_structPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64)>>('wire_handle_stream_of_struct');
+  late final _wire_handle_stream_of_struct = _wire_handle_stream_of_structPtr.asFunction<void Function(int)>();
 
-  void wire_next_user_id(
+  void wire_return_err(
     int port_,
-    ffi.Pointer<wire_UserId> user_id,
   ) {
-    return _wire_next_user_id(
+    return _wire_return_err(
       port_,
-      user_id,
     );
   }
 
-  late final _wire_next_user_idPtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_UserId>)>>('wire_next_user_id');
-  late final _wire_next_user_id = _wire_next_user_idPtr.asFunction<void Function(int, ffi.Pointer<wire_UserId>)>();
+  late final _wire_return_errPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64)>>('wire_return_err');
+  late final _wire_return_err = _wire_return_errPtr.asFunction<void Function(int)>();
 
-  void wire_register_event_listener(
+  void wire_return_custom_error(
     int port_,
+    bool fail,
   ) {
-    return _wire_register_event_listener(
+    return _wire_return_custom_error(
       port_,
+      fail,
     );
   }
 
-  late final _wire_register_event_listenerPtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64)>>('wire_register_event_listener');
-  late final _wire_register_event_listener = _wire_register_event_listenerPtr.asFunction<void Function(int)>();
+  late final _wire_return_custom_errorPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Bool)>>('wire_return_custom_error');
+  late final _wire_return_custom_error = _wire_return_custom_errorPtr.asFunction<void Function(int, bool)>();
 
-  void wire_close_event_listener(
+  void wire_return_custom_error_unit(
     int port_,
   ) {
-    return _wire_close_event_listener(
+    return _wire_return_custom_error_unit(
       port_,
     );
   }
 
-  late final _wire_close_event_listenerPtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64)>>('wire_close_event_listener');
-  late final _wire_close_event_listener = _wire_close_event_listenerPtr.asFunction<void Function(int)>();
+  late final _wire_return_custom_error_unitPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64)>>('wire_return_custom_error_unit');
+  late final _wire_return_custom_error_unit = _wire_return_custom_error_unitPtr.asFunction<void Function(int)>();
 
-  void wire_create_event(
-    int port_,
-    ffi.Pointer<wire_uint_8_list> address,
-    ffi.Pointer<wire_uint_8_list> payload,
+  WireSyncReturn wire_lookup_weekday(
+    int index,
   ) {
-    return _wire_create_event(
-      port_,
-      address,
-      payload,
+    return _wire_lookup_weekday(
+      index,
     );
   }
 
-  late final _wire_create_eventPtr = _lookup<
-      ffi.NativeFunction<
-          ffi.Void Function(
-              ffi.Int64, ffi.Pointer<wire_uint_8_list>, ffi.Pointer<wire_uint_8_list>)>>('wire_create_event');
-  late final _wire_create_event = _wire_create_eventPtr
-      .asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>, ffi.Pointer<wire_uint_8_list>)>();
+  late final _wire_lookup_weekdayPtr =
+      _lookup<ffi.NativeFunction<WireSyncReturn Function(ffi.Uint32)>>('wire_lookup_weekday');
+  late final _wire_lookup_weekday = _wire_lookup_weekdayPtr.asFunction<WireSyncReturn Function(int)>();
 
-  void wire_handle_stream_sink_at_1(
+  void wire_lookup_string(
     int port_,
-    int key,
-    int max,
+    ffi.Pointer<wire_uint_8_list> key,
   ) {
-    return _wire_handle_stream_sink_at_1(
+    return _wire_lookup_string(
       port_,
       key,
-      max,
     );
   }
 
-  late final _wire_handle_stream_sink_at_1Ptr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Uint32, ffi.Uint32)>>('wire_handle_stream_sink_at_1');
-  late final _wire_handle_stream_sink_at_1 =
-      _wire_handle_stream_sink_at_1Ptr.asFunction<void Function(int, int, int)>();
+  late final _wire_lookup_stringPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_uint_8_list>)>>('wire_lookup_string');
+  late final _wire_lookup_string =
+      _wire_lookup_stringPtr.asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>)>();
 
-  void wire_handle_stream_sink_at_2(
+  void wire_read_setting(
     int port_,
-    int key,
-    int max,
+    ffi.Pointer<wire_uint_8_list> key,
   ) {
-    return _wire_handle_stream_sink_at_2(
+    return _wire_read_setting(
       port_,
       key,
-      max,
     );
   }
 
-  late final _wire_handle_stream_sink_at_2Ptr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Uint32, ffi.Uint32)>>('wire_handle_stream_sink_at_2');
-  late final _wire_handle_stream_sink_at_2 =
-      _wire_handle_stream_sink_at_2Ptr.asFunction<void Function(int, int, int)>();
+  late final _wire_read_settingPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_uint_8_list>)>>('wire_read_setting');
+  late final _wire_read_setting = _wire_read_settingPtr.asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>)>();
 
-  void wire_handle_stream_sink_at_3(
+  void wire_parse_setting(
     int port_,
-    int key,
-    int max,
+    ffi.Pointer<wire_uint_8_list> value,
   ) {
-    return _wire_handle_stream_sink_at_3(
+    return _wire_parse_setting(
       port_,
-      key,
-      max,
+      value,
     );
   }
 
-  late final _wire_handle_stream_sink_at_3Ptr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Uint32, ffi.Uint32)>>('wire_handle_stream_sink_at_3');
-  late final _wire_handle_stream_sink_at_3 =
-      _wire_handle_stream_sink_at_3Ptr.asFunction<void Function(int, int, int)>();
+  late final _wire_parse_settingPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_uint_8_list>)>>('wire_parse_setting');
+  late final _wire_parse_setting =
+      _wire_parse_settingPtr.asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>)>();
 
-  void wire_get_sum_struct(
-    int port_,
+  WireSyncReturn wire_return_custom_error_sync(
+    bool fail,
   ) {
-    return _wire_get_sum_struct(
-      port_,
+    return _wire_return_custom_error_sync(
+      fail,
     );
   }
 
-  late final _wire_get_sum_structPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64)>>('wire_get_sum_struct');
-  late final _wire_get_sum_struct = _wire_get_sum_structPtr.asFunction<void Function(int)>();
+  late final _wire_return_custom_error_syncPtr =
+      _lookup<ffi.NativeFunction<WireSyncReturn Function(ffi.Bool)>>('wire_return_custom_error_sync');
+  late final _wire_return_custom_error_sync =
+      _wire_return_custom_error_syncPtr.asFunction<WireSyncReturn Function(bool)>();
 
-  void wire_get_sum_array(
+  void wire_count_until_cancelled(
     int port_,
-    int a,
-    int b,
-    int c,
+    int cancel_id_,
   ) {
-    return _wire_get_sum_array(
+    return _wire_count_until_cancelled(
       port_,
-      a,
-      b,
-      c,
+      cancel_id_,
     );
   }
 
-  late final _wire_get_sum_arrayPtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Uint32, ffi.Uint32, ffi.Uint32)>>(
-          'wire_get_sum_array');
-  late final _wire_get_sum_array = _wire_get_sum_arrayPtr.asFunction<void Function(int, int, int, int)>();
+  late final _wire_count_until_cancelledPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Int32)>>('wire_count_until_cancelled');
+  late final _wire_count_until_cancelled = _wire_count_until_cancelledPtr.asFunction<void Function(int, int)>();
 
-  void wire_multiply_by_ten(
+  void wire_run_workers(
     int port_,
-    ffi.Pointer<wire_Measure> measure,
+    int cancel_id_,
+    int workers,
   ) {
-    return _wire_multiply_by_ten(
+    return _wire_run_workers(
       port_,
-      measure,
+      cancel_id_,
+      workers,
     );
   }
 
-  late final _wire_multiply_by_tenPtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_Measure>)>>('wire_multiply_by_ten');
-  late final _wire_multiply_by_ten =
-      _wire_multiply_by_tenPtr.asFunction<void Function(int, ffi.Pointer<wire_Measure>)>();
+  late final _wire_run_workersPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Int32, ffi.Uint32)>>('wire_run_workers');
+  late final _wire_run_workers = _wire_run_workersPtr.asFunction<void Function(int, int, int)>();
 
-  void wire_call_old_module_system(
+  void wire_stopped_workers(
     int port_,
   ) {
-    return _wire_call_old_module_system(
+    return _wire_stopped_workers(
       port_,
     );
   }
 
-  late final _wire_call_old_module_systemPtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64)>>('wire_call_old_module_system');
-  late final _wire_call_old_module_system = _wire_call_old_module_systemPtr.asFunction<void Function(int)>();
+  late final _wire_stopped_workersPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64)>>('wire_stopped_workers');
+  late final _wire_stopped_workers = _wire_stopped_workersPtr.asFunction<void Function(int)>();
 
-  void wire_call_new_module_system(
+  void wire_return_panic(
     int port_,
   ) {
-    return _wire_call_new_module_system(
+    return _wire_return_panic(
       port_,
     );
   }
 
-  late final _wire_call_new_module_systemPtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64)>>('wire_call_new_module_system');
-  late final _wire_call_new_module_system = _wire_call_new_module_systemPtr.asFunction<void Function(int)>();
+  late final _wire_return_panicPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64)>>('wire_return_panic');
+  late final _wire_return_panic = _wire_return_panicPtr.asFunction<void Function(int)>();
 
-  void wire_handle_big_buffers(
+  void wire_handle_optional_return(
     int port_,
+    double left,
+    double right,
   ) {
-    return _wire_handle_big_buffers(
+    return _wire_handle_optional_return(
       port_,
+      left,
+      right,
     );
   }
 
-  late final _wire_handle_big_buffersPtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64)>>('wire_handle_big_buffers');
-  late final _wire_handle_big_buffers = _wire_handle_big_buffersPtr.asFunction<void Function(int)>();
+  late final _wire_handle_optional_returnPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Double, ffi.Double)>>('wire_handle_optional_return');
+  late final _wire_handle_optional_return =
+      _wire_handle_optional_returnPtr.asFunction<void Function(int, double, double)>();
 
-  void wire_datetime_utc(
+  void wire_handle_optional_struct(
     int port_,
-    int d,
+    ffi.Pointer<wire_uint_8_list> document,
   ) {
-    return _wire_datetime_utc(
+    return _wire_handle_optional_struct(
       port_,
-      d,
+      document,
     );
   }
 
-  late final _wire_datetime_utcPtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Int64)>>('wire_datetime_utc');
-  late final _wire_datetime_utc = _wire_datetime_utcPtr.asFunction<void Function(int, int)>();
+  late final _wire_handle_optional_structPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_uint_8_list>)>>(
+          'wire_handle_optional_struct');
+  late final _wire_handle_optional_struct =
+      _wire_handle_optional_structPtr.asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>)>();
 
-  void wire_datetime_local(
+  void wire_handle_optional_increment(
     int port_,
-    int d,
+    ffi.Pointer<wire_ExoticOptionals> opt,
   ) {
-    return _wire_datetime_local(
+    return _wire_handle_optional_increment(
       port_,
-      d,
+      opt,
     );
   }
 
-  late final _wire_datetime_localPtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Int64)>>('wire_datetime_local');
-  late final _wire_datetime_local = _wire_datetime_localPtr.asFunction<void Function(int, int)>();
+  late final _wire_handle_optional_incrementPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_ExoticOptionals>)>>(
+          'wire_handle_optional_increment');
+  late final _wire_handle_optional_increment =
+      _wire_handle_optional_incrementPtr.asFunction<void Function(int, ffi.Pointer<wire_ExoticOptionals>)>();
 
-  void wire_naivedatetime(
+  void wire_handle_increment_boxed_optional(
     int port_,
-    int d,
+    ffi.Pointer<ffi.Double> opt,
   ) {
-    return _wire_naivedatetime(
+    return _wire_handle_increment_boxed_optional(
       port_,
-      d,
+      opt,
     );
   }
 
-  late final _wire_naivedatetimePtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Int64)>>('wire_naivedatetime');
-  late final _wire_naivedatetime = _wire_naivedatetimePtr.asFunction<void Function(int, int)>();
+  late final _wire_handle_increment_boxed_optionalPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<ffi.Double>)>>(
+          'wire_handle_increment_boxed_optional');
+  late final _wire_handle_increment_boxed_optional =
+      _wire_handle_increment_boxed_optionalPtr.asFunction<void Function(int, ffi.Pointer<ffi.Double>)>();
 
-  void wire_optional_empty_datetime_utc(
+  void wire_handle_option_box_arguments(
     int port_,
-    ffi.Pointer<ffi.Int64> d,
+    ffi.Pointer<ffi.Int8> i8box,
+    ffi.Pointer<ffi.Uint8> u8box,
+    ffi.Pointer<ffi.Int32> i32box,
+    ffi.Pointer<ffi.Int64> i64box,
+    ffi.Pointer<ffi.Double> f64box,
+    ffi.Pointer<ffi.Bool> boolbox,
+    ffi.Pointer<wire_ExoticOptionals> structbox,
   ) {
-    return _wire_optional_empty_datetime_utc(
+    return _wire_handle_option_box_arguments(
       port_,
-      d,
+      i8box,
+      u8box,
+      i32box,
+      i64box,
+      f64box,
+      boolbox,
+      structbox,
     );
   }
 
-  late final _wire_optional_empty_datetime_utcPtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<ffi.Int64>)>>(
-          'wire_optional_empty_datetime_utc');
-  late final _wire_optional_empty_datetime_utc =
-      _wire_optional_empty_datetime_utcPtr.asFunction<void Function(int, ffi.Pointer<ffi.Int64>)>();
+  late final _wire_handle_option_box_argumentsPtr = _lookup<
+      ffi.NativeFunction<
+          ffi.Void Function(
+              ffi.Int64,
+              ffi.Pointer<ffi.Int8>,
+              ffi.Pointer<ffi.Uint8>,
+              ffi.Pointer<ffi.Int32>,
+              ffi.Pointer<ffi.Int64>,
+              ffi.Pointer<ffi.Double>,
+              ffi.Pointer<ffi.Bool>,
+              ffi.Pointer<wire_ExoticOptionals>)>>('wire_handle_option_box_arguments');
+  late final _wire_handle_option_box_arguments = _wire_handle_option_box_argumentsPtr.asFunction<
+      void Function(int, ffi.Pointer<ffi.Int8>, ffi.Pointer<ffi.Uint8>, ffi.Pointer<ffi.Int32>, ffi.Pointer<ffi.Int64>,
+          ffi.Pointer<ffi.Double>, ffi.Pointer<ffi.Bool>, ffi.Pointer<wire_ExoticOptionals>)>();
 
-  void wire_duration(
+  void wire_print_note(
     int port_,
-    int d,
+    ffi.Pointer<wire_Note> note,
   ) {
-    return _wire_duration(
+    return _wire_print_note(
       port_,
-      d,
+      note,
     );
   }
 
-  late final _wire_durationPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Int64)>>('wire_duration');
-  late final _wire_duration = _wire_durationPtr.asFunction<void Function(int, int)>();
+  late final _wire_print_notePtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_Note>)>>('wire_print_note');
+  late final _wire_print_note = _wire_print_notePtr.asFunction<void Function(int, ffi.Pointer<wire_Note>)>();
 
-  void wire_test_chrono(
+  void wire_handle_return_enum(
     int port_,
+    ffi.Pointer<wire_uint_8_list> input,
   ) {
-    return _wire_test_chrono(
+    return _wire_handle_return_enum(
       port_,
+      input,
     );
   }
 
-  late final _wire_test_chronoPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64)>>('wire_test_chrono');
-  late final _wire_test_chrono = _wire_test_chronoPtr.asFunction<void Function(int)>();
+  late final _wire_handle_return_enumPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_uint_8_list>)>>(
+          'wire_handle_return_enum');
+  late final _wire_handle_return_enum =
+      _wire_handle_return_enumPtr.asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>)>();
 
-  void wire_test_precise_chrono(
+  void wire_handle_enum_parameter(
     int port_,
+    int weekday,
   ) {
-    return _wire_test_precise_chrono(
+    return _wire_handle_enum_parameter(
       port_,
+      weekday,
     );
   }
 
-  late final _wire_test_precise_chronoPtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64)>>('wire_test_precise_chrono');
-  late final _wire_test_precise_chrono = _wire_test_precise_chronoPtr.asFunction<void Function(int)>();
+  late final _wire_handle_enum_parameterPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Int32)>>('wire_handle_enum_parameter');
+  late final _wire_handle_enum_parameter = _wire_handle_enum_parameterPtr.asFunction<void Function(int, int)>();
 
-  void wire_how_long_does_it_take(
+  void wire_http_status_code(
     int port_,
-    ffi.Pointer<wire_FeatureChrono> mine,
+    int status,
   ) {
-    return _wire_how_long_does_it_take(
+    return _wire_http_status_code(
       port_,
-      mine,
+      status,
     );
   }
 
-  late final _wire_how_long_does_it_takePtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_FeatureChrono>)>>(
-          'wire_how_long_does_it_take');
-  late final _wire_how_long_does_it_take =
-      _wire_how_long_does_it_takePtr.asFunction<void Function(int, ffi.Pointer<wire_FeatureChrono>)>();
+  late final _wire_http_status_codePtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Int32)>>('wire_http_status_code');
+  late final _wire_http_status_code = _wire_http_status_codePtr.asFunction<void Function(int, int)>();
 
-  void wire_handle_uuid(
+  void wire_http_status_of(
     int port_,
-    ffi.Pointer<wire_uint_8_list> id,
+    int code,
   ) {
-    return _wire_handle_uuid(
+    return _wire_http_status_of(
       port_,
-      id,
+      code,
     );
   }
 
-  late final _wire_handle_uuidPtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_uint_8_list>)>>('wire_handle_uuid');
-  late final _wire_handle_uuid = _wire_handle_uuidPtr.asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>)>();
+  late final _wire_http_status_ofPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Int32)>>('wire_http_status_of');
+  late final _wire_http_status_of = _wire_http_status_ofPtr.asFunction<void Function(int, int)>();
 
-  void wire_handle_uuids(
+  void wire_handle_customized_struct(
     int port_,
-    ffi.Pointer<wire_uint_8_list> ids,
+    ffi.Pointer<wire_Customized> val,
   ) {
-    return _wire_handle_uuids(
+    return _wire_handle_customized_struct(
       port_,
-      ids,
+      val,
     );
   }
 
-  late final _wire_handle_uuidsPtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_uint_8_list>)>>('wire_handle_uuids');
-  late final _wire_handle_uuids = _wire_handle_uuidsPtr.asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>)>();
+  late final _wire_handle_customized_structPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_Customized>)>>(
+          'wire_handle_customized_struct');
+  late final _wire_handle_customized_struct =
+      _wire_handle_customized_structPtr.asFunction<void Function(int, ffi.Pointer<wire_Customized>)>();
 
-  void wire_handle_nested_uuids(
+  void wire_handle_enum_struct(
     int port_,
-    ffi.Pointer<wire_FeatureUuid> ids,
+    ffi.Pointer<wire_KitchenSink> val,
   ) {
-    return _wire_handle_nested_uuids(
+    return _wire_handle_enum_struct(
       port_,
-      ids,
+      val,
     );
   }
 
-  late final _wire_handle_nested_uuidsPtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_FeatureUuid>)>>(
-          'wire_handle_nested_uuids');
+  late final _wire_handle_enum_structPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_KitchenSink>)>>(
+          'wire_handle_enum_struct');
+  late final _wire_handle_enum_struct =
+      _wire_handle_enum_structPtr.asFunction<void Function(int, ffi.Pointer<wire_KitchenSink>)>();
+
+  void wire_use_imported_struct(
+    int port_,
+    ffi.Pointer<wire_MyStruct> my_struct,
+  ) {
+    return _wire_use_imported_struct(
+      port_,
+      my_struct,
+    );
+  }
+
+  late final _wire_use_imported_structPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_MyStruct>)>>('wire_use_imported_struct');
+  late final _wire_use_imported_struct =
+      _wire_use_imported_structPtr.asFunction<void Function(int, ffi.Pointer<wire_MyStruct>)>();
+
+  void wire_use_imported_enum(
+    int port_,
+    int my_enum,
+  ) {
+    return _wire_use_imported_enum(
+      port_,
+      my_enum,
+    );
+  }
+
+  late final _wire_use_imported_enumPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Int32)>>('wire_use_imported_enum');
+  late final _wire_use_imported_enum = _wire_use_imported_enumPtr.asFunction<void Function(int, int)>();
+
+  void wire_get_app_settings(
+    int port_,
+  ) {
+    return _wire_get_app_settings(
+      port_,
+    );
+  }
+
+  late final _wire_get_app_settingsPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64)>>('wire_get_app_settings');
+  late final _wire_get_app_settings = _wire_get_app_settingsPtr.asFunction<void Function(int)>();
+
+  void wire_get_fallible_app_settings(
+    int port_,
+  ) {
+    return _wire_get_fallible_app_settings(
+      port_,
+    );
+  }
+
+  late final _wire_get_fallible_app_settingsPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64)>>('wire_get_fallible_app_settings');
+  late final _wire_get_fallible_app_settings = _wire_get_fallible_app_settingsPtr.asFunction<void Function(int)>();
+
+  void wire_is_app_embedded(
+    int port_,
+    ffi.Pointer<wire_ApplicationSettings> app_settings,
+  ) {
+    return _wire_is_app_embedded(
+      port_,
+      app_settings,
+    );
+  }
+
+  late final _wire_is_app_embeddedPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_ApplicationSettings>)>>(
+          'wire_is_app_embedded');
+  late final _wire_is_app_embedded =
+      _wire_is_app_embeddedPtr.asFunction<void Function(int, ffi.Pointer<wire_ApplicationSettings>)>();
+
+  void wire_get_message(
+    int port_,
+  ) {
+    return _wire_get_message(
+      port_,
+    );
+  }
+
+  late final _wire_get_messagePtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64)>>('wire_get_message');
+  late final _wire_get_message = _wire_get_messagePtr.asFunction<void Function(int)>();
+
+  void wire_get_fallible_message(
+    int port_,
+    int index,
+  ) {
+    return _wire_get_fallible_message(
+      port_,
+      index,
+    );
+  }
+
+  late final _wire_get_fallible_messagePtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.UintPtr)>>('wire_get_fallible_message');
+  late final _wire_get_fallible_message = _wire_get_fallible_messagePtr.asFunction<void Function(int, int)>();
+
+  void wire_is_exit_message(
+    int port_,
+    ffi.Pointer<wire_ApplicationMessage> message,
+  ) {
+    return _wire_is_exit_message(
+      port_,
+      message,
+    );
+  }
+
+  late final _wire_is_exit_messagePtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_ApplicationMessage>)>>(
+          'wire_is_exit_message');
+  late final _wire_is_exit_message =
+      _wire_is_exit_messagePtr.asFunction<void Function(int, ffi.Pointer<wire_ApplicationMessage>)>();
+
+  void wire_repeat_number(
+    int port_,
+    int num,
+    int times,
+  ) {
+    return _wire_repeat_number(
+      port_,
+      num,
+      times,
+    );
+  }
+
+  late final _wire_repeat_numberPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Int32, ffi.UintPtr)>>('wire_repeat_number');
+  late final _wire_repeat_number = _wire_repeat_numberPtr.asFunction<void Function(int, int, int)>();
+
+  void wire_repeat_sequence(
+    int port_,
+    int seq,
+    int times,
+  ) {
+    return _wire_repeat_sequence(
+      port_,
+      seq,
+      times,
+    );
+  }
+
+  late final _wire_repeat_sequencePtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Int32, ffi.UintPtr)>>('wire_repeat_sequence');
+  late final _wire_repeat_sequence = _wire_repeat_sequencePtr.asFunction<void Function(int, int, int)>();
+
+  void wire_first_number(
+    int port_,
+    ffi.Pointer<wire_Numbers> nums,
+  ) {
+    return _wire_first_number(
+      port_,
+      nums,
+    );
+  }
+
+  late final _wire_first_numberPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_Numbers>)>>('wire_first_number');
+  late final _wire_first_number = _wire_first_numberPtr.asFunction<void Function(int, ffi.Pointer<wire_Numbers>)>();
+
+  void wire_first_sequence(
+    int port_,
+    ffi.Pointer<wire_Sequences> seqs,
+  ) {
+    return _wire_first_sequence(
+      port_,
+      seqs,
+    );
+  }
+
+  late final _wire_first_sequencePtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_Sequences>)>>('wire_first_sequence');
+  late final _wire_first_sequence =
+      _wire_first_sequencePtr.asFunction<void Function(int, ffi.Pointer<wire_Sequences>)>();
+
+  void wire_get_array(
+    int port_,
+  ) {
+    return _wire_get_array(
+      port_,
+    );
+  }
+
+  late final _wire_get_arrayPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64)>>('wire_get_array');
+  late final _wire_get_array = _wire_get_arrayPtr.asFunction<void Function(int)>();
+
+  void wire_get_complex_array(
+    int port_,
+  ) {
+    return _wire_get_complex_array(
+      port_,
+    );
+  }
+
+  late final _wire_get_complex_arrayPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64)>>('wire_get_complex_array');
+  late final _wire_get_complex_array = _wire_get_complex_arrayPtr.asFunction<void Function(int)>();
+
+  void wire_get_usize(
+    int port_,
+    int u,
+  ) {
+    return _wire_get_usize(
+      port_,
+      u,
+    );
+  }
+
+  late final _wire_get_usizePtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.UintPtr)>>('wire_get_usize');
+  late final _wire_get_usize = _wire_get_usizePtr.asFunction<void Function(int, int)>();
+
+  void wire_next_user_id(
+    int port_,
+    ffi.Pointer<wire_UserId> user_id,
+  ) {
+    return _wire_next_user_id(
+      port_,
+      user_id,
+    );
+  }
+
+  late final _wire_next_user_idPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_UserId>)>>('wire_next_user_id');
+  late final _wire_next_user_id = _wire_next_user_idPtr.asFunction<void Function(int, ffi.Pointer<wire_UserId>)>();
+
+  void wire_register_event_listener(
+    int port_,
+  ) {
+    return _wire_register_event_listener(
+      port_,
+    );
+  }
+
+  late final _wire_register_event_listenerPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64)>>('wire_register_event_listener');
+  late final _wire_register_event_listener = _wire_register_event_listenerPtr.asFunction<void Function(int)>();
+
+  void wire_close_event_listener(
+    int port_,
+  ) {
+    return _wire_close_event_listener(
+      port_,
+    );
+  }
+
+  late final _wire_close_event_listenerPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64)>>('wire_close_event_listener');
+  late final _wire_close_event_listener = _wire_close_event_listenerPtr.asFunction<void Function(int)>();
+
+  void wire_create_event(
+    int port_,
+    ffi.Pointer<wire_uint_8_list> address,
+    ffi.Pointer<wire_uint_8_list> payload,
+  ) {
+    return _wire_create_event(
+      port_,
+      address,
+      payload,
+    );
+  }
+
+  late final _wire_create_eventPtr = _lookup<
+      ffi.NativeFunction<
+          ffi.Void Function(
+              ffi.Int64, ffi.Pointer<wire_uint_8_list>, ffi.Pointer<wire_uint_8_list>)>>('wire_create_event');
+  late final _wire_create_event = _wire_create_eventPtr
+      .asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>, ffi.Pointer<wire_uint_8_list>)>();
+
+  void wire_handle_stream_sink_at_1(
+    int port_,
+    int key,
+    int max,
+  ) {
+    return _wire_handle_stream_sink_at_1(
+      port_,
+      key,
+      max,
+    );
+  }
+
+  late final _wire_handle_stream_sink_at_1Ptr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Uint32, ffi.Uint32)>>('wire_handle_stream_sink_at_1');
+  late final _wire_handle_stream_sink_at_1 =
+      _wire_handle_stream_sink_at_1Ptr.asFunction<void Function(int, int, int)>();
+
+  void wire_handle_stream_sink_at_2(
+    int port_,
+    int key,
+    int max,
+  ) {
+    return _wire_handle_stream_sink_at_2(
+      port_,
+      key,
+      max,
+    );
+  }
+
+  late final _wire_handle_stream_sink_at_2Ptr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Uint32, ffi.Uint32)>>('wire_handle_stream_sink_at_2');
+  late final _wire_handle_stream_sink_at_2 =
+      _wire_handle_stream_sink_at_2Ptr.asFunction<void Function(int, int, int)>();
+
+  void wire_handle_stream_sink_at_3(
+    int port_,
+    int key,
+    int max,
+  ) {
+    return _wire_handle_stream_sink_at_3(
+      port_,
+      key,
+      max,
+    );
+  }
+
+  late final _wire_handle_stream_sink_at_3Ptr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Uint32, ffi.Uint32)>>('wire_handle_stream_sink_at_3');
+  late final _wire_handle_stream_sink_at_3 =
+      _wire_handle_stream_sink_at_3Ptr.asFunction<void Function(int, int, int)>();
+
+  void wire_call_back_with_numbers(
+    int port_,
+    int callback,
+    int max,
+  ) {
+    return _wire_call_back_with_numbers(
+      port_,
+      callback,
+      max,
+    );
+  }
+
+  late final _wire_call_back_with_numbersPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Int64, ffi.Uint32)>>('wire_call_back_with_numbers');
+  late final _wire_call_back_with_numbers = _wire_call_back_with_numbersPtr.asFunction<void Function(int, int, int)>();
+
+  void wire_handle_stream_closed_by_drop(
+    int port_,
+    int max,
+  ) {
+    return _wire_handle_stream_closed_by_drop(
+      port_,
+      max,
+    );
+  }
+
+  late final _wire_handle_stream_closed_by_dropPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Uint32)>>('wire_handle_stream_closed_by_drop');
+  late final _wire_handle_stream_closed_by_drop =
+      _wire_handle_stream_closed_by_dropPtr.asFunction<void Function(int, int)>();
+
+  void wire_count_from(
+    int port_,
+    int from,
+    int count,
+  ) {
+    return _wire_count_from(
+      port_,
+      from,
+      count,
+    );
+  }
+
+  late final _wire_count_fromPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Uint32, ffi.Uint32)>>('wire_count_from');
+  late final _wire_count_from = _wire_count_fromPtr.asFunction<void Function(int, int, int)>();
+
+  void wire_parse_numbers(
+    int port_,
+    ffi.Pointer<wire_uint_8_list> input,
+  ) {
+    return _wire_parse_numbers(
+      port_,
+      input,
+    );
+  }
+
+  late final _wire_parse_numbersPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_uint_8_list>)>>('wire_parse_numbers');
+  late final _wire_parse_numbers =
+      _wire_parse_numbersPtr.asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>)>();
+
+  void wire_handle_broadcast_stream(
+    int port_,
+    int max,
+  ) {
+    return _wire_handle_broadcast_stream(
+      port_,
+      max,
+    );
+  }
+
+  late final _wire_handle_broadcast_streamPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Uint32)>>('wire_handle_broadcast_stream');
+  late final _wire_handle_broadcast_stream = _wire_handle_broadcast_streamPtr.asFunction<void Function(int, int)>();
+
+  void wire_handle_stream_closed_by_threads(
+    int port_,
+    int threads,
+  ) {
+    return _wire_handle_stream_closed_by_threads(
+      port_,
+      threads,
+    );
+  }
+
+  late final _wire_handle_stream_closed_by_threadsPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Uint32)>>('wire_handle_stream_closed_by_threads');
+  late final _wire_handle_stream_closed_by_threads =
+      _wire_handle_stream_closed_by_threadsPtr.asFunction<void Function(int, int)>();
+
+  void wire_get_sum_struct(
+    int port_,
+  ) {
+    return _wire_get_sum_struct(
+      port_,
+    );
+  }
+
+  late final _wire_get_sum_structPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64)>>('wire_get_sum_struct');
+  late final _wire_get_sum_struct = _wire_get_sum_structPtr.asFunction<void Function(int)>();
+
+  void wire_get_sum_array(
+    int port_,
+    int a,
+    int b,
+    int c,
+  ) {
+    return _wire_get_sum_array(
+      port_,
+      a,
+      b,
+      c,
+    );
+  }
+
+  late final _wire_get_sum_arrayPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Uint32, ffi.Uint32, ffi.Uint32)>>(
+          'wire_get_sum_array');
+  late final _wire_get_sum_array = _wire_get_sum_arrayPtr.asFunction<void Function(int, int, int, int)>();
+
+  void wire_rename_document(
+    int port_,
+    ffi.Pointer<wire_Document> document,
+    ffi.Pointer<wire_uint_8_list> title,
+  ) {
+    return _wire_rename_document(
+      port_,
+      document,
+      title,
+    );
+  }
+
+  late final _wire_rename_documentPtr = _lookup<
+          ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_Document>, ffi.Pointer<wire_uint_8_list>)>>(
+      'wire_rename_document');
+  late final _wire_rename_document = _wire_rename_documentPtr
+      .asFunction<void Function(int, ffi.Pointer<wire_Document>, ffi.Pointer<wire_uint_8_list>)>();
+
+  void wire_get_renamed(
+    int port_,
+    int id,
+    int pace,
+  ) {
+    return _wire_get_renamed(
+      port_,
+      id,
+      pace,
+    );
+  }
+
+  late final _wire_get_renamedPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Uint32, ffi.Int32)>>('wire_get_renamed');
+  late final _wire_get_renamed = _wire_get_renamedPtr.asFunction<void Function(int, int, int)>();
+
+  void wire_advance(
+    int port_,
+    ffi.Pointer<wire_Progress> progress,
+    int by,
+  ) {
+    return _wire_advance(
+      port_,
+      progress,
+      by,
+    );
+  }
+
+  late final _wire_advancePtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_Progress>, ffi.Uint8)>>('wire_advance');
+  late final _wire_advance = _wire_advancePtr.asFunction<void Function(int, ffi.Pointer<wire_Progress>, int)>();
+
+  void wire_next_product_id(
+    int port_,
+    ffi.Pointer<wire_ProductId> id,
+  ) {
+    return _wire_next_product_id(
+      port_,
+      id,
+    );
+  }
+
+  late final _wire_next_product_idPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_ProductId>)>>('wire_next_product_id');
+  late final _wire_next_product_id =
+      _wire_next_product_idPtr.asFunction<void Function(int, ffi.Pointer<wire_ProductId>)>();
+
+  void wire_transpose_cell(
+    int port_,
+    ffi.Pointer<wire_GridCell> cell,
+  ) {
+    return _wire_transpose_cell(
+      port_,
+      cell,
+    );
+  }
+
+  late final _wire_transpose_cellPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_GridCell>)>>('wire_transpose_cell');
+  late final _wire_transpose_cell =
+      _wire_transpose_cellPtr.asFunction<void Function(int, ffi.Pointer<wire_GridCell>)>();
+
+  void wire_add_tax(
+    int port_,
+    ffi.Pointer<wire_Money> price,
+    int percent,
+  ) {
+    return _wire_add_tax(
+      port_,
+      price,
+      percent,
+    );
+  }
+
+  late final _wire_add_taxPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_Money>, ffi.Uint32)>>('wire_add_tax');
+  late final _wire_add_tax = _wire_add_taxPtr.asFunction<void Function(int, ffi.Pointer<wire_Money>, int)>();
+
+  void wire_bump_minor(
+    int port_,
+    ffi.Pointer<wire_uint_8_list> version,
+  ) {
+    return _wire_bump_minor(
+      port_,
+      version,
+    );
+  }
+
+  late final _wire_bump_minorPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_uint_8_list>)>>('wire_bump_minor');
+  late final _wire_bump_minor = _wire_bump_minorPtr.asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>)>();
+
+  void wire_open_session(
+    int port_,
+    ffi.Pointer<wire_uint_8_list> user,
+  ) {
+    return _wire_open_session(
+      port_,
+      user,
+    );
+  }
+
+  late final _wire_open_sessionPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_uint_8_list>)>>('wire_open_session');
+  late final _wire_open_session = _wire_open_sessionPtr.asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>)>();
+
+  void wire_describe_session(
+    int port_,
+    ffi.Pointer<wire_Session> session,
+  ) {
+    return _wire_describe_session(
+      port_,
+      session,
+    );
+  }
+
+  late final _wire_describe_sessionPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_Session>)>>('wire_describe_session');
+  late final _wire_describe_session =
+      _wire_describe_sessionPtr.asFunction<void Function(int, ffi.Pointer<wire_Session>)>();
+
+  void wire_sample_at(
+    int port_,
+    int index,
+  ) {
+    return _wire_sample_at(
+      port_,
+      index,
+    );
+  }
+
+  late final _wire_sample_atPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Uint32)>>('wire_sample_at');
+  late final _wire_sample_at = _wire_sample_atPtr.asFunction<void Function(int, int)>();
+
+  void wire_shift_sample(
+    int port_,
+    ffi.Pointer<wire_Sample> sample,
+    double dx,
+  ) {
+    return _wire_shift_sample(
+      port_,
+      sample,
+      dx,
+    );
+  }
+
+  late final _wire_shift_samplePtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_Sample>, ffi.Double)>>(
+          'wire_shift_sample');
+  late final _wire_shift_sample =
+      _wire_shift_samplePtr.asFunction<void Function(int, ffi.Pointer<wire_Sample>, double)>();
+
+  void wire_tag_label(
+    int port_,
+    ffi.Pointer<wire_Tag> tag,
+  ) {
+    return _wire_tag_label(
+      port_,
+      tag,
+    );
+  }
+
+  late final _wire_tag_labelPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_Tag>)>>('wire_tag_label');
+  late final _wire_tag_label = _wire_tag_labelPtr.asFunction<void Function(int, ffi.Pointer<wire_Tag>)>();
+
+  void wire_header_name(
+    int port_,
+    ffi.Pointer<wire_uint_8_list> raw,
+  ) {
+    return _wire_header_name(
+      port_,
+      raw,
+    );
+  }
+
+  late final _wire_header_namePtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_uint_8_list>)>>('wire_header_name');
+  late final _wire_header_name = _wire_header_namePtr.asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>)>();
+
+  void wire_multiply_by_ten(
+    int port_,
+    ffi.Pointer<wire_Measure> measure,
+  ) {
+    return _wire_multiply_by_ten(
+      port_,
+      measure,
+    );
+  }
+
+  late final _wire_multiply_by_tenPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_Measure>)>>('wire_multiply_by_ten');
+  late final _wire_multiply_by_ten =
+      _wire_multiply_by_tenPtr.asFunction<void Function(int, ffi.Pointer<wire_Measure>)>();
+
+  void wire_call_old_module_system(
+    int port_,
+  ) {
+    return _wire_call_old_module_system(
+      port_,
+    );
+  }
+
+  late final _wire_call_old_module_systemPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64)>>('wire_call_old_module_system');
+  late final _wire_call_old_module_system = _wire_call_old_module_systemPtr.asFunction<void Function(int)>();
+
+  void wire_call_new_module_system(
+    int port_,
+  ) {
+    return _wire_call_new_module_system(
+      port_,
+    );
+  }
+
+  late final _wire_call_new_module_systemPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64)>>('wire_call_new_module_system');
+  late final _wire_call_new_module_system = _wire_call_new_module_systemPtr.asFunction<void Function(int)>();
+
+  void wire_handle_big_buffers(
+    int port_,
+  ) {
+    return _wire_handle_big_buffers(
+      port_,
+    );
+  }
+
+  late final _wire_handle_big_buffersPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64)>>('wire_handle_big_buffers');
+  late final _wire_handle_big_buffers = _wire_handle_big_buffersPtr.asFunction<void Function(int)>();
+
+  void wire_handle_i128(
+    int port_,
+    ffi.Pointer<wire_uint_8_list> value,
+  ) {
+    return _wire_handle_i128(
+      port_,
+      value,
+    );
+  }
+
+  late final _wire_handle_i128Ptr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_uint_8_list>)>>('wire_handle_i128');
+  late final _wire_handle_i128 = _wire_handle_i128Ptr.asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>)>();
+
+  void wire_handle_u128(
+    int port_,
+    ffi.Pointer<wire_uint_8_list> value,
+  ) {
+    return _wire_handle_u128(
+      port_,
+      value,
+    );
+  }
+
+  late final _wire_handle_u128Ptr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_uint_8_list>)>>('wire_handle_u128');
+  late final _wire_handle_u128 = _wire_handle_u128Ptr.asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>)>();
+
+  void wire_datetime_utc(
+    int port_,
+    int d,
+  ) {
+    return _wire_datetime_utc(
+      port_,
+      d,
+    );
+  }
+
+  late final _wire_datetime_utcPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Int64)>>('wire_datetime_utc');
+  late final _wire_datetime_utc = _wire_datetime_utcPtr.asFunction<void Function(int, int)>();
+
+  void wire_datetime_local(
+    int port_,
+    int d,
+  ) {
+    return _wire_datetime_local(
+      port_,
+      d,
+    );
+  }
+
+  late final _wire_datetime_localPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Int64)>>('wire_datetime_local');
+  late final _wire_datetime_local = _wire_datetime_localPtr.asFunction<void Function(int, int)>();
+
+  void wire_naivedatetime(
+    int port_,
+    int d,
+  ) {
+    return _wire_naivedatetime(
+      port_,
+      d,
+    );
+  }
+
+  late final _wire_naivedatetimePtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Int64)>>('wire_naivedatetime');
+  late final _wire_naivedatetime = _wire_naivedatetimePtr.asFunction<void Function(int, int)>();
+
+  void wire_optional_empty_datetime_utc(
+    int port_,
+    ffi.Pointer<ffi.Int64> d,
+  ) {
+    return _wire_optional_empty_datetime_utc(
+      port_,
+      d,
+    );
+  }
+
+  late final _wire_optional_empty_datetime_utcPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<ffi.Int64>)>>(
+          'wire_optional_empty_datetime_utc');
+  late final _wire_optional_empty_datetime_utc =
+      _wire_optional_empty_datetime_utcPtr.asFunction<void Function(int, ffi.Pointer<ffi.Int64>)>();
+
+  void wire_duration(
+    int port_,
+    int d,
+  ) {
+    return _wire_duration(
+      port_,
+      d,
+    );
+  }
+
+  late final _wire_durationPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Int64)>>('wire_duration');
+  late final _wire_duration = _wire_durationPtr.asFunction<void Function(int, int)>();
+
+  void wire_datetime_fixed_offset(
+    int port_,
+    int d,
+  ) {
+    return _wire_datetime_fixed_offset(
+      port_,
+      d,
+    );
+  }
+
+  late final _wire_datetime_fixed_offsetPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Int64)>>('wire_datetime_fixed_offset');
+  late final _wire_datetime_fixed_offset = _wire_datetime_fixed_offsetPtr.asFunction<void Function(int, int)>();
+
+  void wire_test_chrono(
+    int port_,
+  ) {
+    return _wire_test_chrono(
+      port_,
+    );
+  }
+
+  late final _wire_test_chronoPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64)>>('wire_test_chrono');
+  late final _wire_test_chrono = _wire_test_chronoPtr.asFunction<void Function(int)>();
+
+  void wire_test_precise_chrono(
+    int port_,
+  ) {
+    return _wire_test_precise_chrono(
+      port_,
+    );
+  }
+
+  late final _wire_test_precise_chronoPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64)>>('wire_test_precise_chrono');
+  late final _wire_test_precise_chrono = _wire_test_precise_chronoPtr.asFunction<void Function(int)>();
+
+  void wire_how_long_does_it_take(
+    int port_,
+    ffi.Pointer<wire_FeatureChrono> mine,
+  ) {
+    return _wire_how_long_does_it_take(
+      port_,
+      mine,
+    );
+  }
+
+  late final _wire_how_long_does_it_takePtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_FeatureChrono>)>>(
+          'wire_how_long_does_it_take');
+  late final _wire_how_long_does_it_take =
+      _wire_how_long_does_it_takePtr.asFunction<void Function(int, ffi.Pointer<wire_FeatureChrono>)>();
+
+  void wire_handle_uuid(
+    int port_,
+    ffi.Pointer<wire_uint_8_list> id,
+  ) {
+    return _wire_handle_uuid(
+      port_,
+      id,
+    );
+  }
+
+  late final _wire_handle_uuidPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_uint_8_list>)>>('wire_handle_uuid');
+  late final _wire_handle_uuid = _wire_handle_uuidPtr.asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>)>();
+
+  void wire_handle_uuids(
+    int port_,
+    ffi.Pointer<wire_uint_8_list> ids,
+  ) {
+    return _wire_handle_uuids(
+      port_,
+      ids,
+    );
+  }
+
+  late final _wire_handle_uuidsPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_uint_8_list>)>>('wire_handle_uuids');
+  late final _wire_handle_uuids = _wire_handle_uuidsPtr.asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>)>();
+
+  void wire_handle_nested_uuids(
+    int port_,
+    ffi.Pointer<wire_FeatureUuid> ids,
+  ) {
+    return _wire_handle_nested_uuids(
+      port_,
+      ids,
+    );
+  }
+
+  late final _wire_handle_nested_uuidsPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_FeatureUuid>)>>(
+          'wire_handle_nested_uuids');
   late final _wire_handle_nested_uuids =
       _wire_handle_nested_uuidsPtr.asFunction<void Function(int, ffi.Pointer<wire_FeatureUuid>)>();
 
-  void wire_new_msgid(
+  void wire_handle_decimal(
+    int port_,
+    ffi.Pointer<wire_uint_8_list> amount,
+  ) {
+    return _wire_handle_decimal(
+      port_,
+      amount,
+    );
+  }
+
+  late final _wire_handle_decimalPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_uint_8_list>)>>('wire_handle_decimal');
+  late final _wire_handle_decimal =
+      _wire_handle_decimalPtr.asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>)>();
+
+  void wire_sum_decimals(
+    int port_,
+    ffi.Pointer<wire_list_Decimal> amounts,
+  ) {
+    return _wire_sum_decimals(
+      port_,
+      amounts,
+    );
+  }
+
+  late final _wire_sum_decimalsPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_list_Decimal>)>>('wire_sum_decimals');
+  late final _wire_sum_decimals =
+      _wire_sum_decimalsPtr.asFunction<void Function(int, ffi.Pointer<wire_list_Decimal>)>();
+
+  void wire_new_msgid(
+    int port_,
+    ffi.Pointer<wire_uint_8_list> id,
+  ) {
+    return _wire_new_msgid(
+      port_,
+      id,
+    );
+  }
+
+  late final _wire_new_msgidPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_uint_8_list>)>>('wire_new_msgid');
+  late final _wire_new_msgid = _wire_new_msgidPtr.asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>)>();
+
+  void wire_use_msgid(
+    int port_,
+    ffi.Pointer<wire_MessageId> id,
+  ) {
+    return _wire_use_msgid(
+      port_,
+      id,
+    );
+  }
+
+  late final _wire_use_msgidPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_MessageId>)>>('wire_use_msgid');
+  late final _wire_use_msgid = _wire_use_msgidPtr.asFunction<void Function(int, ffi.Pointer<wire_MessageId>)>();
+
+  void wire_boxed_blob(
+    int port_,
+    ffi.Pointer<wire_uint_8_list> blob,
+  ) {
+    return _wire_boxed_blob(
+      port_,
+      blob,
+    );
+  }
+
+  late final _wire_boxed_blobPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_uint_8_list>)>>('wire_boxed_blob');
+  late final _wire_boxed_blob = _wire_boxed_blobPtr.asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>)>();
+
+  void wire_use_boxed_blob(
+    int port_,
+    ffi.Pointer<wire_Blob> blob,
+  ) {
+    return _wire_use_boxed_blob(
+      port_,
+      blob,
+    );
+  }
+
+  late final _wire_use_boxed_blobPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_Blob>)>>('wire_use_boxed_blob');
+  late final _wire_use_boxed_blob = _wire_use_boxed_blobPtr.asFunction<void Function(int, ffi.Pointer<wire_Blob>)>();
+
+  void wire_return_boxed_feed_id(
+    int port_,
+    ffi.Pointer<wire_uint_8_list> id,
+  ) {
+    return _wire_return_boxed_feed_id(
+      port_,
+      id,
+    );
+  }
+
+  late final _wire_return_boxed_feed_idPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_uint_8_list>)>>(
+          'wire_return_boxed_feed_id');
+  late final _wire_return_boxed_feed_id =
+      _wire_return_boxed_feed_idPtr.asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>)>();
+
+  void wire_return_boxed_raw_feed_id(
+    int port_,
+    ffi.Pointer<wire_FeedId> id,
+  ) {
+    return _wire_return_boxed_raw_feed_id(
+      port_,
+      id,
+    );
+  }
+
+  late final _wire_return_boxed_raw_feed_idPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_FeedId>)>>(
+          'wire_return_boxed_raw_feed_id');
+  late final _wire_return_boxed_raw_feed_id =
+      _wire_return_boxed_raw_feed_idPtr.asFunction<void Function(int, ffi.Pointer<wire_FeedId>)>();
+
+  void wire_test_id(
+    int port_,
+    ffi.Pointer<wire_TestId> id,
+  ) {
+    return _wire_test_id(
+      port_,
+      id,
+    );
+  }
+
+  late final _wire_test_idPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_TestId>)>>('wire_test_id');
+  late final _wire_test_id = _wire_test_idPtr.asFunction<void Function(int, ffi.Pointer<wire_TestId>)>();
+
+  void wire_last_number(
+    int port_,
+    ffi.Pointer<wire_float_64_list> array,
+  ) {
+    return _wire_last_number(
+      port_,
+      array,
+    );
+  }
+
+  late final _wire_last_numberPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_float_64_list>)>>('wire_last_number');
+  late final _wire_last_number = _wire_last_numberPtr.asFunction<void Function(int, ffi.Pointer<wire_float_64_list>)>();
+
+  void wire_repeat_byte_4(
+    int port_,
+    int byte,
+  ) {
+    return _wire_repeat_byte_4(
+      port_,
+      byte,
+    );
+  }
+
+  late final _wire_repeat_byte_4Ptr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Uint8)>>('wire_repeat_byte_4');
+  late final _wire_repeat_byte_4 = _wire_repeat_byte_4Ptr.asFunction<void Function(int, int)>();
+
+  void wire_repeat_byte_8(
+    int port_,
+    int byte,
+  ) {
+    return _wire_repeat_byte_8(
+      port_,
+      byte,
+    );
+  }
+
+  late final _wire_repeat_byte_8Ptr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Uint8)>>('wire_repeat_byte_8');
+  late final _wire_repeat_byte_8 = _wire_repeat_byte_8Ptr.asFunction<void Function(int, int)>();
+
+  void wire_transpose(
+    int port_,
+    ffi.Pointer<wire_float_64_list> matrix,
+  ) {
+    return _wire_transpose(
+      port_,
+      matrix,
+    );
+  }
+
+  late final _wire_transposePtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_float_64_list>)>>('wire_transpose');
+  late final _wire_transpose = _wire_transposePtr.asFunction<void Function(int, ffi.Pointer<wire_float_64_list>)>();
+
+  void wire_nested_id(
+    int port_,
+    ffi.Pointer<wire_list_test_id> id,
+  ) {
+    return _wire_nested_id(
+      port_,
+      id,
+    );
+  }
+
+  late final _wire_nested_idPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_list_test_id>)>>('wire_nested_id');
+  late final _wire_nested_id = _wire_nested_idPtr.asFunction<void Function(int, ffi.Pointer<wire_list_test_id>)>();
+
+  WireSyncReturn wire_sync_accept_dart_opaque(
+    wire_DartOpaque opaque,
+  ) {
+    return _wire_sync_accept_dart_opaque(
+      opaque,
+    );
+  }
+
+  late final _wire_sync_accept_dart_opaquePtr =
+      _lookup<ffi.NativeFunction<WireSyncReturn Function(wire_DartOpaque)>>('wire_sync_accept_dart_opaque');
+  late final _wire_sync_accept_dart_opaque =
+      _wire_sync_accept_dart_opaquePtr.asFunction<WireSyncReturn Function(wire_DartOpaque)>();
+
+  void wire_async_accept_dart_opaque(
+    int port_,
+    wire_DartOpaque opaque,
+  ) {
+    return _wire_async_accept_dart_opaque(
+      port_,
+      opaque,
+    );
+  }
+
+  late final _wire_async_accept_dart_opaquePtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, wire_DartOpaque)>>('wire_async_accept_dart_opaque');
+  late final _wire_async_accept_dart_opaque =
+      _wire_async_accept_dart_opaquePtr.asFunction<void Function(int, wire_DartOpaque)>();
+
+  void wire_loop_back(
+    int port_,
+    wire_DartOpaque opaque,
+  ) {
+    return _wire_loop_back(
+      port_,
+      opaque,
+    );
+  }
+
+  late final _wire_loop_backPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, wire_DartOpaque)>>('wire_loop_back');
+  late final _wire_loop_back = _wire_loop_backPtr.asFunction<void Function(int, wire_DartOpaque)>();
+
+  void wire_loop_back_option(
+    int port_,
+    wire_DartOpaque opaque,
+  ) {
+    return _wire_loop_back_option(
+      port_,
+      opaque,
+    );
+  }
+
+  late final _wire_loop_back_optionPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, wire_DartOpaque)>>('wire_loop_back_option');
+  late final _wire_loop_back_option = _wire_loop_back_optionPtr.asFunction<void Function(int, wire_DartOpaque)>();
+
+  void wire_loop_back_array(
+    int port_,
+    wire_DartOpaque opaque,
+  ) {
+    return _wire_loop_back_array(
+      port_,
+      opaque,
+    );
+  }
+
+  late final _wire_loop_back_arrayPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, wire_DartOpaque)>>('wire_loop_back_array');
+  late final _wire_loop_back_array = _wire_loop_back_arrayPtr.asFunction<void Function(int, wire_DartOpaque)>();
+
+  void wire_loop_back_vec(
+    int port_,
+    wire_DartOpaque opaque,
+  ) {
+    return _wire_loop_back_vec(
+      port_,
+      opaque,
+    );
+  }
+
+  late final _wire_loop_back_vecPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, wire_DartOpaque)>>('wire_loop_back_vec');
+  late final _wire_loop_back_vec = _wire_loop_back_vecPtr.asFunction<void Function(int, wire_DartOpaque)>();
+
+  void wire_loop_back_option_get(
+    int port_,
+    ffi.Pointer<wire_DartOpaque> opaque,
+  ) {
+    return _wire_loop_back_option_get(
+      port_,
+      opaque,
+    );
+  }
+
+  late final _wire_loop_back_option_getPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_DartOpaque>)>>(
+          'wire_loop_back_option_get');
+  late final _wire_loop_back_option_get =
+      _wire_loop_back_option_getPtr.asFunction<void Function(int, ffi.Pointer<wire_DartOpaque>)>();
+
+  void wire_loop_back_array_get(
+    int port_,
+    ffi.Pointer<wire_list_DartOpaque> opaque,
+  ) {
+    return _wire_loop_back_array_get(
+      port_,
+      opaque,
+    );
+  }
+
+  late final _wire_loop_back_array_getPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_list_DartOpaque>)>>(
+          'wire_loop_back_array_get');
+  late final _wire_loop_back_array_get =
+      _wire_loop_back_array_getPtr.asFunction<void Function(int, ffi.Pointer<wire_list_DartOpaque>)>();
+
+  void wire_loop_back_vec_get(
+    int port_,
+    ffi.Pointer<wire_list_DartOpaque> opaque,
+  ) {
+    return _wire_loop_back_vec_get(
+      port_,
+      opaque,
+    );
+  }
+
+  late final _wire_loop_back_vec_getPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_list_DartOpaque>)>>(
+          'wire_loop_back_vec_get');
+  late final _wire_loop_back_vec_get =
+      _wire_loop_back_vec_getPtr.asFunction<void Function(int, ffi.Pointer<wire_list_DartOpaque>)>();
+
+  WireSyncReturn wire_unwrap_dart_opaque(
+    wire_DartOpaque opaque,
+  ) {
+    return _wire_unwrap_dart_opaque(
+      opaque,
+    );
+  }
+
+  late final _wire_unwrap_dart_opaquePtr =
+      _lookup<ffi.NativeFunction<WireSyncReturn Function(wire_DartOpaque)>>('wire_unwrap_dart_opaque');
+  late final _wire_unwrap_dart_opaque =
+      _wire_unwrap_dart_opaquePtr.asFunction<WireSyncReturn Function(wire_DartOpaque)>();
+
+  void wire_panic_unwrap_dart_opaque(
+    int port_,
+    wire_DartOpaque opaque,
+  ) {
+    return _wire_panic_unwrap_dart_opaque(
+      port_,
+      opaque,
+    );
+  }
+
+  late final _wire_panic_unwrap_dart_opaquePtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, wire_DartOpaque)>>('wire_panic_unwrap_dart_opaque');
+  late final _wire_panic_unwrap_dart_opaque =
+      _wire_panic_unwrap_dart_opaquePtr.asFunction<void Function(int, wire_DartOpaque)>();
+
+  void wire_create_opaque(
+    int port_,
+  ) {
+    return _wire_create_opaque(
+      port_,
+    );
+  }
+
+  late final _wire_create_opaquePtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64)>>('wire_create_opaque');
+  late final _wire_create_opaque = _wire_create_opaquePtr.asFunction<void Function(int)>();
+
+  void wire_create_option_opaque(
+    int port_,
+    ffi.Pointer<wire_HideData> opaque,
+  ) {
+    return _wire_create_option_opaque(
+      port_,
+      opaque,
+    );
+  }
+
+  late final _wire_create_option_opaquePtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_HideData>)>>(
+          'wire_create_option_opaque');
+  late final _wire_create_option_opaque =
+      _wire_create_option_opaquePtr.asFunction<void Function(int, ffi.Pointer<wire_HideData>)>();
+
+  WireSyncReturn wire_sync_create_opaque() {
+    return _wire_sync_create_opaque();
+  }
+
+  late final _wire_sync_create_opaquePtr =
+      _lookup<ffi.NativeFunction<WireSyncReturn Function()>>('wire_sync_create_opaque');
+  late final _wire_sync_create_opaque = _wire_sync_create_opaquePtr.asFunction<WireSyncReturn Function()>();
+
+  void wire_create_array_opaque_enum(
+    int port_,
+  ) {
+    return _wire_create_array_opaque_enum(
+      port_,
+    );
+  }
+
+  late final _wire_create_array_opaque_enumPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64)>>('wire_create_array_opaque_enum');
+  late final _wire_create_array_opaque_enum = _wire_create_array_opaque_enumPtr.asFunction<void Function(int)>();
+
+  void wire_run_enum_opaque(
+    int port_,
+    ffi.Pointer<wire_EnumOpaque> opaque,
+  ) {
+    return _wire_run_enum_opaque(
+      port_,
+      opaque,
+    );
+  }
+
+  late final _wire_run_enum_opaquePtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_EnumOpaque>)>>('wire_run_enum_opaque');
+  late final _wire_run_enum_opaque =
+      _wire_run_enum_opaquePtr.asFunction<void Function(int, ffi.Pointer<wire_EnumOpaque>)>();
+
+  void wire_create_trait_object(
+    int port_,
+  ) {
+    return _wire_create_trait_object(
+      port_,
+    );
+  }
+
+  late final _wire_create_trait_objectPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64)>>('wire_create_trait_object');
+  late final _wire_create_trait_object = _wire_create_trait_objectPtr.asFunction<void Function(int)>();
+
+  void wire_create_impl_trait(
+    int port_,
+  ) {
+    return _wire_create_impl_trait(
+      port_,
+    );
+  }
+
+  late final _wire_create_impl_traitPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64)>>('wire_create_impl_trait');
+  late final _wire_create_impl_trait = _wire_create_impl_traitPtr.asFunction<void Function(int)>();
+
+  void wire_run_trait_object(
+    int port_,
+    wire_BoxDartDebug obj,
+  ) {
+    return _wire_run_trait_object(
+      port_,
+      obj,
+    );
+  }
+
+  late final _wire_run_trait_objectPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, wire_BoxDartDebug)>>('wire_run_trait_object');
+  late final _wire_run_trait_object = _wire_run_trait_objectPtr.asFunction<void Function(int, wire_BoxDartDebug)>();
+
+  void wire_run_opaque(
+    int port_,
+    wire_HideData opaque,
+  ) {
+    return _wire_run_opaque(
+      port_,
+      opaque,
+    );
+  }
+
+  late final _wire_run_opaquePtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, wire_HideData)>>('wire_run_opaque');
+  late final _wire_run_opaque = _wire_run_opaquePtr.asFunction<void Function(int, wire_HideData)>();
+
+  void wire_create_shared_opaque(
+    int port_,
+  ) {
+    return _wire_create_shared_opaque(
+      port_,
+    );
+  }
+
+  late final _wire_create_shared_opaquePtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64)>>('wire_create_shared_opaque');
+  late final _wire_create_shared_opaque = _wire_create_shared_opaquePtr.asFunction<void Function(int)>();
+
+  void wire_run_shared_opaque(
+    int port_,
+    wire_HideData opaque,
+  ) {
+    return _wire_run_shared_opaque(
+      port_,
+      opaque,
+    );
+  }
+
+  late final _wire_run_shared_opaquePtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, wire_HideData)>>('wire_run_shared_opaque');
+  late final _wire_run_shared_opaque = _wire_run_shared_opaquePtr.asFunction<void Function(int, wire_HideData)>();
+
+  void wire_shared_opaque_address(
+    int port_,
+    wire_HideData opaque,
+  ) {
+    return _wire_shared_opaque_address(
+      port_,
+      opaque,
+    );
+  }
+
+  late final _wire_shared_opaque_addressPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, wire_HideData)>>('wire_shared_opaque_address');
+  late final _wire_shared_opaque_address =
+      _wire_shared_opaque_addressPtr.asFunction<void Function(int, wire_HideData)>();
+
+  void wire_run_opaque_with_delay(
+    int port_,
+    wire_HideData opaque,
+  ) {
+    return _wire_run_opaque_with_delay(
+      port_,
+      opaque,
+    );
+  }
+
+  late final _wire_run_opaque_with_delayPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, wire_HideData)>>('wire_run_opaque_with_delay');
+  late final _wire_run_opaque_with_delay =
+      _wire_run_opaque_with_delayPtr.asFunction<void Function(int, wire_HideData)>();
+
+  void wire_opaque_array(
+    int port_,
+  ) {
+    return _wire_opaque_array(
+      port_,
+    );
+  }
+
+  late final _wire_opaque_arrayPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64)>>('wire_opaque_array');
+  late final _wire_opaque_array = _wire_opaque_arrayPtr.asFunction<void Function(int)>();
+
+  void wire_create_sync_opaque(
+    int port_,
+  ) {
+    return _wire_create_sync_opaque(
+      port_,
+    );
+  }
+
+  late final _wire_create_sync_opaquePtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64)>>('wire_create_sync_opaque');
+  late final _wire_create_sync_opaque = _wire_create_sync_opaquePtr.asFunction<void Function(int)>();
+
+  WireSyncReturn wire_sync_create_sync_opaque() {
+    return _wire_sync_create_sync_opaque();
+  }
+
+  late final _wire_sync_create_sync_opaquePtr =
+      _lookup<ffi.NativeFunction<WireSyncReturn Function()>>('wire_sync_create_sync_opaque');
+  late final _wire_sync_create_sync_opaque = _wire_sync_create_sync_opaquePtr.asFunction<WireSyncReturn Function()>();
+
+  WireSyncReturn wire_sync_run_opaque(
+    wire_NonSendHideData opaque,
+  ) {
+    return _wire_sync_run_opaque(
+      opaque,
+    );
+  }
+
+  late final _wire_sync_run_opaquePtr =
+      _lookup<ffi.NativeFunction<WireSyncReturn Function(wire_NonSendHideData)>>('wire_sync_run_opaque');
+  late final _wire_sync_run_opaque =
+      _wire_sync_run_opaquePtr.asFunction<WireSyncReturn Function(wire_NonSendHideData)>();
+
+  void wire_opaque_array_run(
     int port_,
-    ffi.Pointer<wire_uint_8_list> id,
+    ffi.Pointer<wire_list_HideData> data,
   ) {
-    return _wire_new_msgid(
+    return _wire_opaque_array_run(
       port_,
-      id,
+      data,
     );
   }
 
-  late final _wire_new_msgidPtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_uint_8_list>)>>('wire_new_msgid');
-  late final _wire_new_msgid = _wire_new_msgidPtr.asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>)>();
+  late final _wire_opaque_array_runPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_list_HideData>)>>(
+          'wire_opaque_array_run');
+  late final _wire_opaque_array_run =
+      _wire_opaque_array_runPtr.asFunction<void Function(int, ffi.Pointer<wire_list_HideData>)>();
 
-  void wire_use_msgid(
+  void wire_opaque_vec(
     int port_,
-    ffi.Pointer<wire_MessageId> id,
   ) {
-    return _wire_use_msgid(
+    return _wire_opaque_vec(
       port_,
-      id,
     );
   }
 
-  late final _wire_use_msgidPtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_MessageId>)>>('wire_use_msgid');
-  late final _wire_use_msgid = _wire_use_msgidPtr.asFunction<void Function(int, ffi.Pointer<wire_MessageId>)>();
+  late final _wire_opaque_vecPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64)>>('wire_opaque_vec');
+  late final _wire_opaque_vec = _wire_opaque_vecPtr.asFunction<void Function(int)>();
 
-  void wire_boxed_blob(
+  void wire_opaque_vec_run(
     int port_,
-    ffi.Pointer<wire_uint_8_list> blob,
+    ffi.Pointer<wire_list_HideData> data,
   ) {
-    return _wire_boxed_blob(
+    return _wire_opaque_vec_run(
       port_,
-      blob,
+      data,
     );
   }
 
-  late final _wire_boxed_blobPtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_uint_8_list>)>>('wire_boxed_blob');
-  late final _wire_boxed_blob = _wire_boxed_blobPtr.asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>)>();
+  late final _wire_opaque_vec_runPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_list_HideData>)>>('wire_opaque_vec_run');
+  late final _wire_opaque_vec_run =
+      _wire_opaque_vec_runPtr.asFunction<void Function(int, ffi.Pointer<wire_list_HideData>)>();
 
-  void wire_use_boxed_blob(
+  void wire_create_nested_opaque(
     int port_,
-    ffi.Pointer<wire_Blob> blob,
   ) {
-    return _wire_use_boxed_blob(
+    return _wire_create_nested_opaque(
       port_,
-      blob,
     );
   }
 
-  late final _wire_use_boxed_blobPtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_Blob>)>>('wire_use_boxed_blob');
-  late final _wire_use_boxed_blob = _wire_use_boxed_blobPtr.asFunction<void Function(int, ffi.Pointer<wire_Blob>)>();
+  late final _wire_create_nested_opaquePtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64)>>('wire_create_nested_opaque');
+  late final _wire_create_nested_opaque = _wire_create_nested_opaquePtr.asFunction<void Function(int)>();
 
-  void wire_return_boxed_feed_id(
+  WireSyncReturn wire_sync_loopback(
+    wire_DartOpaque opaque,
+  ) {
+    return _wire_sync_loopback(
+      opaque,
+    );
+  }
+
+  late final _wire_sync_loopbackPtr =
+      _lookup<ffi.NativeFunction<WireSyncReturn Function(wire_DartOpaque)>>('wire_sync_loopback');
+  late final _wire_sync_loopback = _wire_sync_loopbackPtr.asFunction<WireSyncReturn Function(wire_DartOpaque)>();
+
+  WireSyncReturn wire_sync_option_loopback(
+    ffi.Pointer<wire_DartOpaque> opaque,
+  ) {
+    return _wire_sync_option_loopback(
+      opaque,
+    );
+  }
+
+  late final _wire_sync_option_loopbackPtr =
+      _lookup<ffi.NativeFunction<WireSyncReturn Function(ffi.Pointer<wire_DartOpaque>)>>('wire_sync_option_loopback');
+  late final _wire_sync_option_loopback =
+      _wire_sync_option_loopbackPtr.asFunction<WireSyncReturn Function(ffi.Pointer<wire_DartOpaque>)>();
+
+  WireSyncReturn wire_sync_option() {
+    return _wire_sync_option();
+  }
+
+  late final _wire_sync_optionPtr = _lookup<ffi.NativeFunction<WireSyncReturn Function()>>('wire_sync_option');
+  late final _wire_sync_option = _wire_sync_optionPtr.asFunction<WireSyncReturn Function()>();
+
+  WireSyncReturn wire_sync_option_null() {
+    return _wire_sync_option_null();
+  }
+
+  late final _wire_sync_option_nullPtr =
+      _lookup<ffi.NativeFunction<WireSyncReturn Function()>>('wire_sync_option_null');
+  late final _wire_sync_option_null = _wire_sync_option_nullPtr.asFunction<WireSyncReturn Function()>();
+
+  WireSyncReturn wire_sync_option_rust_opaque() {
+    return _wire_sync_option_rust_opaque();
+  }
+
+  late final _wire_sync_option_rust_opaquePtr =
+      _lookup<ffi.NativeFunction<WireSyncReturn Function()>>('wire_sync_option_rust_opaque');
+  late final _wire_sync_option_rust_opaque = _wire_sync_option_rust_opaquePtr.asFunction<WireSyncReturn Function()>();
+
+  WireSyncReturn wire_sync_option_dart_opaque(
+    wire_DartOpaque opaque,
+  ) {
+    return _wire_sync_option_dart_opaque(
+      opaque,
+    );
+  }
+
+  late final _wire_sync_option_dart_opaquePtr =
+      _lookup<ffi.NativeFunction<WireSyncReturn Function(wire_DartOpaque)>>('wire_sync_option_dart_opaque');
+  late final _wire_sync_option_dart_opaque =
+      _wire_sync_option_dart_opaquePtr.asFunction<WireSyncReturn Function(wire_DartOpaque)>();
+
+  WireSyncReturn wire_sync_void() {
+    return _wire_sync_void();
+  }
+
+  late final _wire_sync_voidPtr = _lookup<ffi.NativeFunction<WireSyncReturn Function()>>('wire_sync_void');
+  late final _wire_sync_void = _wire_sync_voidPtr.asFunction<WireSyncReturn Function()>();
+
+  void wire_run_nested_opaque(
     int port_,
-    ffi.Pointer<wire_uint_8_list> id,
+    ffi.Pointer<wire_OpaqueNested> opaque,
   ) {
-    return _wire_return_boxed_feed_id(
+    return _wire_run_nested_opaque(
       port_,
-      id,
+      opaque,
     );
   }
 
-  late final _wire_return_boxed_feed_idPtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_uint_8_list>)>>(
-          'wire_return_boxed_feed_id');
-  late final _wire_return_boxed_feed_id =
-      _wire_return_boxed_feed_idPtr.asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>)>();
+  late final _wire_run_nested_opaquePtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_OpaqueNested>)>>(
+          'wire_run_nested_opaque');
+  late final _wire_run_nested_opaque =
+      _wire_run_nested_opaquePtr.asFunction<void Function(int, ffi.Pointer<wire_OpaqueNested>)>();
 
-  void wire_return_boxed_raw_feed_id(
+  void wire_create_nested_dart_opaque(
     int port_,
-    ffi.Pointer<wire_FeedId> id,
+    wire_DartOpaque opaque1,
+    wire_DartOpaque opaque2,
+  ) {
+    return _wire_create_nested_dart_opaque(
+      port_,
+      opaque1,
+      opaque2,
+    );
+  }
+
+  late final _wire_create_nested_dart_opaquePtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, wire_DartOpaque, wire_DartOpaque)>>(
+          'wire_create_nested_dart_opaque');
+  late final _wire_create_nested_dart_opaque =
+      _wire_create_nested_dart_opaquePtr.asFunction<void Function(int, wire_DartOpaque, wire_DartOpaque)>();
+
+  void wire_get_nested_dart_opaque(
+    int port_,
+    ffi.Pointer<wire_DartOpaqueNested> opaque,
+  ) {
+    return _wire_get_nested_dart_opaque(
+      port_,
+      opaque,
+    );
+  }
+
+  late final _wire_get_nested_dart_opaquePtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_DartOpaqueNested>)>>(
+          'wire_get_nested_dart_opaque');
+  late final _wire_get_nested_dart_opaque =
+      _wire_get_nested_dart_opaquePtr.asFunction<void Function(int, ffi.Pointer<wire_DartOpaqueNested>)>();
+
+  void wire_create_enum_dart_opaque(
+    int port_,
+    wire_DartOpaque opaque,
+  ) {
+    return _wire_create_enum_dart_opaque(
+      port_,
+      opaque,
+    );
+  }
+
+  late final _wire_create_enum_dart_opaquePtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, wire_DartOpaque)>>('wire_create_enum_dart_opaque');
+  late final _wire_create_enum_dart_opaque =
+      _wire_create_enum_dart_opaquePtr.asFunction<void Function(int, wire_DartOpaque)>();
+
+  void wire_get_enum_dart_opaque(
+    int port_,
+    ffi.Pointer<wire_EnumDartOpaque> opaque,
+  ) {
+    return _wire_get_enum_dart_opaque(
+      port_,
+      opaque,
+    );
+  }
+
+  late final _wire_get_enum_dart_opaquePtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_EnumDartOpaque>)>>(
+          'wire_get_enum_dart_opaque');
+  late final _wire_get_enum_dart_opaque =
+      _wire_get_enum_dart_opaquePtr.asFunction<void Function(int, ffi.Pointer<wire_EnumDartOpaque>)>();
+
+  void wire_set_static_dart_opaque(
+    int port_,
+    wire_DartOpaque opaque,
+  ) {
+    return _wire_set_static_dart_opaque(
+      port_,
+      opaque,
+    );
+  }
+
+  late final _wire_set_static_dart_opaquePtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, wire_DartOpaque)>>('wire_set_static_dart_opaque');
+  late final _wire_set_static_dart_opaque =
+      _wire_set_static_dart_opaquePtr.asFunction<void Function(int, wire_DartOpaque)>();
+
+  void wire_drop_static_dart_opaque(
+    int port_,
+  ) {
+    return _wire_drop_static_dart_opaque(
+      port_,
+    );
+  }
+
+  late final _wire_drop_static_dart_opaquePtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64)>>('wire_drop_static_dart_opaque');
+  late final _wire_drop_static_dart_opaque = _wire_drop_static_dart_opaquePtr.asFunction<void Function(int)>();
+
+  void wire_unwrap_rust_opaque(
+    int port_,
+    wire_HideData opaque,
+  ) {
+    return _wire_unwrap_rust_opaque(
+      port_,
+      opaque,
+    );
+  }
+
+  late final _wire_unwrap_rust_opaquePtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, wire_HideData)>>('wire_unwrap_rust_opaque');
+  late final _wire_unwrap_rust_opaque = _wire_unwrap_rust_opaquePtr.asFunction<void Function(int, wire_HideData)>();
+
+  WireSyncReturn wire_return_non_droppable_dart_opaque(
+    wire_DartOpaque opaque,
   ) {
-    return _wire_return_boxed_raw_feed_id(
-      port_,
-      id,
+    return _wire_return_non_droppable_dart_opaque(
+      opaque,
     );
   }
 
-  late final _wire_return_boxed_raw_feed_idPtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_FeedId>)>>(
-          'wire_return_boxed_raw_feed_id');
-  late final _wire_return_boxed_raw_feed_id =
-      _wire_return_boxed_raw_feed_idPtr.asFunction<void Function(int, ffi.Pointer<wire_FeedId>)>();
+  late final _wire_return_non_droppable_dart_opaquePtr =
+      _lookup<ffi.NativeFunction<WireSyncReturn Function(wire_DartOpaque)>>('wire_return_non_droppable_dart_opaque');
+  late final _wire_return_non_droppable_dart_opaque =
+      _wire_return_non_droppable_dart_opaquePtr.asFunction<WireSyncReturn Function(wire_DartOpaque)>();
 
-  void wire_test_id(
+  void wire_frb_generator_test(
     int port_,
-    ffi.Pointer<wire_TestId> id,
   ) {
-    return _wire_test_id(
+    return _wire_frb_generator_test(
       port_,
-      id,
     );
   }
 
-  late final _wire_test_idPtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_TestId>)>>('wire_test_id');
-  late final _wire_test_id = _wire_test_idPtr.asFunction<void Function(int, ffi.Pointer<wire_TestId>)>();
+  late final _wire_frb_generator_testPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64)>>('wire_frb_generator_test');
+  late final _wire_frb_generator_test = _wire_frb_generator_testPtr.asFunction<void Function(int)>();
 
-  void wire_last_number(
+  WireSyncReturn wire_frb_sync_generator_test() {
+    return _wire_frb_sync_generator_test();
+  }
+
+  late final _wire_frb_sync_generator_testPtr =
+      _lookup<ffi.NativeFunction<WireSyncReturn Function()>>('wire_frb_sync_generator_test');
+  late final _wire_frb_sync_generator_test = _wire_frb_sync_generator_testPtr.asFunction<WireSyncReturn Function()>();
+
+  void wire_observe_counter(
     int port_,
-    ffi.Pointer<wire_float_64_list> array,
+    wire_Counter counter,
   ) {
-    return _wire_last_number(
+    return _wire_observe_counter(
       port_,
-      array,
+      counter,
     );
   }
 
-  late final _wire_last_numberPtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_float_64_list>)>>('wire_last_number');
-  late final _wire_last_number = _wire_last_numberPtr.asFunction<void Function(int, ffi.Pointer<wire_float_64_list>)>();
+  late final _wire_observe_counterPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, wire_Counter)>>('wire_observe_counter');
+  late final _wire_observe_counter = _wire_observe_counterPtr.asFunction<void Function(int, wire_Counter)>();
 
-  void wire_nested_id(
+  void wire_is_counter_alive(
     int port_,
-    ffi.Pointer<wire_list_test_id> id,
+    wire_WeakCounter counter,
   ) {
-    return _wire_nested_id(
+    return _wire_is_counter_alive(
       port_,
-      id,
+      counter,
     );
   }
 
-  late final _wire_nested_idPtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_list_test_id>)>>('wire_nested_id');
-  late final _wire_nested_id = _wire_nested_idPtr.asFunction<void Function(int, ffi.Pointer<wire_list_test_id>)>();
+  late final _wire_is_counter_alivePtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, wire_WeakCounter)>>('wire_is_counter_alive');
+  late final _wire_is_counter_alive = _wire_is_counter_alivePtr.asFunction<void Function(int, wire_WeakCounter)>();
 
-  WireSyncReturn wire_sync_accept_dart_opaque(
-    wire_DartOpaque opaque,
+  void wire_handle_type_alias_id(
+    int port_,
+    int input,
   ) {
-    return _wire_sync_accept_dart_opaque(
-      opaque,
+    return _wire_handle_type_alias_id(
+      port_,
+      input,
     );
   }
 
-  late final _wire_sync_accept_dart_opaquePtr =
-      _lookup<ffi.NativeFunction<WireSyncReturn Function(wire_DartOpaque)>>('wire_sync_accept_dart_opaque');
-  late final _wire_sync_accept_dart_opaque =
-      _wire_sync_accept_dart_opaquePtr.asFunction<WireSyncReturn Function(wire_DartOpaque)>();
+  late final _wire_handle_type_alias_idPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Uint64)>>('wire_handle_type_alias_id');
+  late final _wire_handle_type_alias_id = _wire_handle_type_alias_idPtr.asFunction<void Function(int, int)>();
 
-  void wire_async_accept_dart_opaque(
+  void wire_handle_type_nest_alias_id(
     int port_,
-    wire_DartOpaque opaque,
+    int input,
   ) {
-    return _wire_async_accept_dart_opaque(
+    return _wire_handle_type_nest_alias_id(
       port_,
-      opaque,
+      input,
     );
   }
 
-  late final _wire_async_accept_dart_opaquePtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, wire_DartOpaque)>>('wire_async_accept_dart_opaque');
-  late final _wire_async_accept_dart_opaque =
-      _wire_async_accept_dart_opaquePtr.asFunction<void Function(int, wire_DartOpaque)>();
+  late final _wire_handle_type_nest_alias_idPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Uint64)>>('wire_handle_type_nest_alias_id');
+  late final _wire_handle_type_nest_alias_id = _wire_handle_type_nest_alias_idPtr.asFunction<void Function(int, int)>();
 
-  void wire_loop_back(
+  void wire_handle_type_alias_model(
     int port_,
-    wire_DartOpaque opaque,
+    int input,
   ) {
-    return _wire_loop_back(
+    return _wire_handle_type_alias_model(
       port_,
-      opaque,
+      input,
     );
   }
 
-  late final _wire_loop_backPtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, wire_DartOpaque)>>('wire_loop_back');
-  late final _wire_loop_back = _wire_loop_backPtr.asFunction<void Function(int, wire_DartOpaque)>();
+  late final _wire_handle_type_alias_modelPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Uint64)>>('wire_handle_type_alias_model');
+  late final _wire_handle_type_alias_model = _wire_handle_type_alias_modelPtr.asFunction<void Function(int, int)>();
 
-  void wire_loop_back_option(
+  void wire_empty_struct(
     int port_,
-    wire_DartOpaque opaque,
+    ffi.Pointer<wire_Empty> empty,
   ) {
-    return _wire_loop_back_option(
+    return _wire_empty_struct(
       port_,
-      opaque,
+      empty,
     );
   }
 
-  late final _wire_loop_back_optionPtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, wire_DartOpaque)>>('wire_loop_back_option');
-  late final _wire_loop_back_option = _wire_loop_back_optionPtr.asFunction<void Function(int, wire_DartOpaque)>();
+  late final _wire_empty_structPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_Empty>)>>('wire_empty_struct');
+  late final _wire_empty_struct = _wire_empty_structPtr.asFunction<void Function(int, ffi.Pointer<wire_Empty>)>();
 
-  void wire_loop_back_array(
+  void wire_handle_hash_map(
     int port_,
-    wire_DartOpaque opaque,
+    ffi.Pointer<wire_hash_map_String_i32> map,
   ) {
-    return _wire_loop_back_array(
+    return _wire_handle_hash_map(
       port_,
-      opaque,
+      map,
     );
   }
 
-  late final _wire_loop_back_arrayPtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, wire_DartOpaque)>>('wire_loop_back_array');
-  late final _wire_loop_back_array = _wire_loop_back_arrayPtr.asFunction<void Function(int, wire_DartOpaque)>();
+  late final _wire_handle_hash_mapPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_hash_map_String_i32>)>>(
+          'wire_handle_hash_map');
+  late final _wire_handle_hash_map =
+      _wire_handle_hash_mapPtr.asFunction<void Function(int, ffi.Pointer<wire_hash_map_String_i32>)>();
 
-  void wire_loop_back_vec(
+  void wire_handle_hash_map_of_struct(
     int port_,
-    wire_DartOpaque opaque,
+    ffi.Pointer<wire_hash_map_u32_attribute> map,
   ) {
-    return _wire_loop_back_vec(
+    return _wire_handle_hash_map_of_struct(
       port_,
-      opaque,
+      map,
     );
   }
 
-  late final _wire_loop_back_vecPtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, wire_DartOpaque)>>('wire_loop_back_vec');
-  late final _wire_loop_back_vec = _wire_loop_back_vecPtr.asFunction<void Function(int, wire_DartOpaque)>();
+  late final _wire_handle_hash_map_of_structPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_hash_map_u32_attribute>)>>(
+          'wire_handle_hash_map_of_struct');
+  late final _wire_handle_hash_map_of_struct =
+      _wire_handle_hash_map_of_structPtr.asFunction<void Function(int, ffi.Pointer<wire_hash_map_u32_attribute>)>();
 
-  void wire_loop_back_option_get(
+  void wire_count_weekdays(
     int port_,
-    ffi.Pointer<wire_DartOpaque> opaque,
+    ffi.Pointer<wire_list_weekdays> days,
   ) {
-    return _wire_loop_back_option_get(
+    return _wire_count_weekdays(
       port_,
-      opaque,
+      days,
     );
   }
 
-  late final _wire_loop_back_option_getPtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_DartOpaque>)>>(
-          'wire_loop_back_option_get');
-  late final _wire_loop_back_option_get =
-      _wire_loop_back_option_getPtr.asFunction<void Function(int, ffi.Pointer<wire_DartOpaque>)>();
+  late final _wire_count_weekdaysPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_list_weekdays>)>>('wire_count_weekdays');
+  late final _wire_count_weekdays =
+      _wire_count_weekdaysPtr.asFunction<void Function(int, ffi.Pointer<wire_list_weekdays>)>();
 
-  void wire_loop_back_array_get(
+  void wire_index_words(
     int port_,
-    ffi.Pointer<wire_list_DartOpaque> opaque,
+    ffi.Pointer<wire_StringList> words,
   ) {
-    return _wire_loop_back_array_get(
+    return _wire_index_words(
       port_,
-      opaque,
+      words,
     );
   }
 
-  late final _wire_loop_back_array_getPtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_list_DartOpaque>)>>(
-          'wire_loop_back_array_get');
-  late final _wire_loop_back_array_get =
-      _wire_loop_back_array_getPtr.asFunction<void Function(int, ffi.Pointer<wire_list_DartOpaque>)>();
+  late final _wire_index_wordsPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_StringList>)>>('wire_index_words');
+  late final _wire_index_words = _wire_index_wordsPtr.asFunction<void Function(int, ffi.Pointer<wire_StringList>)>();
 
-  void wire_loop_back_vec_get(
+  void wire_handle_colliding_keys(
     int port_,
-    ffi.Pointer<wire_list_DartOpaque> opaque,
+    ffi.Pointer<wire_hash_map_colliding_key_u32> map,
   ) {
-    return _wire_loop_back_vec_get(
+    return _wire_handle_colliding_keys(
       port_,
-      opaque,
+      map,
     );
   }
 
-  late final _wire_loop_back_vec_getPtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_list_DartOpaque>)>>(
-          'wire_loop_back_vec_get');
-  late final _wire_loop_back_vec_get =
-      _wire_loop_back_vec_getPtr.asFunction<void Function(int, ffi.Pointer<wire_list_DartOpaque>)>();
+  late final _wire_handle_colliding_keysPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_hash_map_colliding_key_u32>)>>(
+          'wire_handle_colliding_keys');
+  late final _wire_handle_colliding_keys =
+      _wire_handle_colliding_keysPtr.asFunction<void Function(int, ffi.Pointer<wire_hash_map_colliding_key_u32>)>();
 
-  WireSyncReturn wire_unwrap_dart_opaque(
-    wire_DartOpaque opaque,
+  void wire_handle_btree_set(
+    int port_,
+    ffi.Pointer<wire_StringList> set,
   ) {
-    return _wire_unwrap_dart_opaque(
-      opaque,
+    return _wire_handle_btree_set(
+      port_,
+      set,
     );
   }
 
-  late final _wire_unwrap_dart_opaquePtr =
-      _lookup<ffi.NativeFunction<WireSyncReturn Function(wire_DartOpaque)>>('wire_unwrap_dart_opaque');
-  late final _wire_unwrap_dart_opaque =
-      _wire_unwrap_dart_opaquePtr.asFunction<WireSyncReturn Function(wire_DartOpaque)>();
+  late final _wire_handle_btree_setPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_StringList>)>>('wire_handle_btree_set');
+  late final _wire_handle_btree_set =
+      _wire_handle_btree_setPtr.asFunction<void Function(int, ffi.Pointer<wire_StringList>)>();
 
-  void wire_panic_unwrap_dart_opaque(
+  void wire_handle_btree_map(
     int port_,
-    wire_DartOpaque opaque,
+    ffi.Pointer<wire_btree_map_i32_String> map,
   ) {
-    return _wire_panic_unwrap_dart_opaque(
+    return _wire_handle_btree_map(
       port_,
-      opaque,
+      map,
     );
   }
 
-  late final _wire_panic_unwrap_dart_opaquePtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, wire_DartOpaque)>>('wire_panic_unwrap_dart_opaque');
-  late final _wire_panic_unwrap_dart_opaque =
-      _wire_panic_unwrap_dart_opaquePtr.asFunction<void Function(int, wire_DartOpaque)>();
+  late final _wire_handle_btree_mapPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_btree_map_i32_String>)>>(
+          'wire_handle_btree_map');
+  late final _wire_handle_btree_map =
+      _wire_handle_btree_mapPtr.asFunction<void Function(int, ffi.Pointer<wire_btree_map_i32_String>)>();
 
-  void wire_create_opaque(
+  void wire_group_attributes(
     int port_,
+    ffi.Pointer<wire_list_attribute> attributes,
   ) {
-    return _wire_create_opaque(
+    return _wire_group_attributes(
       port_,
+      attributes,
     );
   }
 
-  late final _wire_create_opaquePtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64)>>('wire_create_opaque');
-  late final _wire_create_opaque = _wire_create_opaquePtr.asFunction<void Function(int)>();
+  late final _wire_group_attributesPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_list_attribute>)>>(
+          'wire_group_attributes');
+  late final _wire_group_attributes =
+      _wire_group_attributesPtr.asFunction<void Function(int, ffi.Pointer<wire_list_attribute>)>();
 
-  void wire_create_option_opaque(
+  void wire_handle_tuple(
     int port_,
-    ffi.Pointer<wire_HideData> opaque,
+    ffi.Pointer<wire_tuple3_i32_String_bool> tuple,
   ) {
-    return _wire_create_option_opaque(
+    return _wire_handle_tuple(
       port_,
-      opaque,
+      tuple,
     );
   }
 
-  late final _wire_create_option_opaquePtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_HideData>)>>(
-          'wire_create_option_opaque');
-  late final _wire_create_option_opaque =
-      _wire_create_option_opaquePtr.asFunction<void Function(int, ffi.Pointer<wire_HideData>)>();
-
-  WireSyncReturn wire_sync_create_opaque() {
-    return _wire_sync_create_opaque();
-  }
-
-  late final _wire_sync_create_opaquePtr =
-      _lookup<ffi.NativeFunction<WireSyncReturn Function()>>('wire_sync_create_opaque');
-  late final _wire_sync_create_opaque = _wire_sync_create_opaquePtr.asFunction<WireSyncReturn Function()>();
+  late final _wire_handle_tuplePtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_tuple3_i32_String_bool>)>>(
+          'wire_handle_tuple');
+  late final _wire_handle_tuple =
+      _wire_handle_tuplePtr.asFunction<void Function(int, ffi.Pointer<wire_tuple3_i32_String_bool>)>();
 
-  void wire_create_array_opaque_enum(
+  void wire_handle_nested_tuple(
     int port_,
+    ffi.Pointer<wire_tuple2_i32_tuple2_String_bool> tuple,
   ) {
-    return _wire_create_array_opaque_enum(
+    return _wire_handle_nested_tuple(
       port_,
+      tuple,
     );
   }
 
-  late final _wire_create_array_opaque_enumPtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64)>>('wire_create_array_opaque_enum');
-  late final _wire_create_array_opaque_enum = _wire_create_array_opaque_enumPtr.asFunction<void Function(int)>();
+  late final _wire_handle_nested_tuplePtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_tuple2_i32_tuple2_String_bool>)>>(
+          'wire_handle_nested_tuple');
+  late final _wire_handle_nested_tuple =
+      _wire_handle_nested_tuplePtr.asFunction<void Function(int, ffi.Pointer<wire_tuple2_i32_tuple2_String_bool>)>();
 
-  void wire_run_enum_opaque(
+  void wire_sum__method__SumWith(
     int port_,
-    ffi.Pointer<wire_EnumOpaque> opaque,
+    ffi.Pointer<wire_SumWith> that,
+    int y,
+    int z,
   ) {
-    return _wire_run_enum_opaque(
+    return _wire_sum__method__SumWith(
       port_,
-      opaque,
+      that,
+      y,
+      z,
     );
   }
 
-  late final _wire_run_enum_opaquePtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_EnumOpaque>)>>('wire_run_enum_opaque');
-  late final _wire_run_enum_opaque =
-      _wire_run_enum_opaquePtr.asFunction<void Function(int, ffi.Pointer<wire_EnumOpaque>)>();
+  late final _wire_sum__method__SumWithPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_SumWith>, ffi.Uint32, ffi.Uint32)>>(
+          'wire_sum__method__SumWith');
+  late final _wire_sum__method__SumWith =
+      _wire_sum__method__SumWithPtr.asFunction<void Function(int, ffi.Pointer<wire_SumWith>, int, int)>();
 
-  void wire_run_opaque(
+  void wire_new__static_method__ConcatenateWith(
     int port_,
-    wire_HideData opaque,
+    ffi.Pointer<wire_uint_8_list> a,
   ) {
-    return _wire_run_opaque(
+    return _wire_new__static_method__ConcatenateWith(
       port_,
-      opaque,
+      a,
     );
   }
 
-  late final _wire_run_opaquePtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, wire_HideData)>>('wire_run_opaque');
-  late final _wire_run_opaque = _wire_run_opaquePtr.asFunction<void Function(int, wire_HideData)>();
+  late final _wire_new__static_method__ConcatenateWithPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_uint_8_list>)>>(
+          'wire_new__static_method__ConcatenateWith');
+  late final _wire_new__static_method__ConcatenateWith =
+      _wire_new__static_method__ConcatenateWithPtr.asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>)>();
 
-  void wire_run_opaque_with_delay(
+  void wire_concatenate__method__ConcatenateWith(
     int port_,
-    wire_HideData opaque,
+    ffi.Pointer<wire_ConcatenateWith> that,
+    ffi.Pointer<wire_uint_8_list> b,
   ) {
-    return _wire_run_opaque_with_delay(
+    return _wire_concatenate__method__ConcatenateWith(
       port_,
-      opaque,
+      that,
+      b,
     );
   }
 
-  late final _wire_run_opaque_with_delayPtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, wire_HideData)>>('wire_run_opaque_with_delay');
-  late final _wire_run_opaque_with_delay =
-      _wire_run_opaque_with_delayPtr.asFunction<void Function(int, wire_HideData)>();
+  late final _wire_concatenate__method__ConcatenateWithPtr = _lookup<
+      ffi.NativeFunction<
+          ffi.Void Function(ffi.Int64, ffi.Pointer<wire_ConcatenateWith>,
+              ffi.Pointer<wire_uint_8_list>)>>('wire_concatenate__method__ConcatenateWith');
+  late final _wire_concatenate__method__ConcatenateWith = _wire_concatenate__method__ConcatenateWithPtr
+      .asFunction<void Function(int, ffi.Pointer<wire_ConcatenateWith>, ffi.Pointer<wire_uint_8_list>)>();
 
-  void wire_opaque_array(
+  void wire_concatenate_static__static_method__ConcatenateWith(
     int port_,
+    ffi.Pointer<wire_uint_8_list> a,
+    ffi.Pointer<wire_uint_8_list> b,
   ) {
-    return _wire_opaque_array(
+    return _wire_concatenate_static__static_method__ConcatenateWith(
       port_,
+      a,
+      b,
     );
   }
 
-  late final _wire_opaque_arrayPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64)>>('wire_opaque_array');
-  late final _wire_opaque_array = _wire_opaque_arrayPtr.asFunction<void Function(int)>();
+  late final _wire_concatenate_static__static_method__ConcatenateWithPtr = _lookup<
+      ffi.NativeFunction<
+          ffi.Void Function(ffi.Int64, ffi.Pointer<wire_uint_8_list>,
+              ffi.Pointer<wire_uint_8_list>)>>('wire_concatenate_static__static_method__ConcatenateWith');
+  late final _wire_concatenate_static__static_method__ConcatenateWith =
+      _wire_concatenate_static__static_method__ConcatenateWithPtr
+          .asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>, ffi.Pointer<wire_uint_8_list>)>();
 
-  void wire_create_sync_opaque(
+  void wire_handle_some_stream_sink__method__ConcatenateWith(
     int port_,
+    ffi.Pointer<wire_ConcatenateWith> that,
+    int key,
+    int max,
   ) {
-    return _wire_create_sync_opaque(
+    return _wire_handle_some_stream_sink__method__ConcatenateWith(
       port_,
+      that,
+      key,
+      max,
     );
   }
 
-  late final _wire_create_sync_opaquePtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64)>>('wire_create_sync_opaque');
-  late final _wire_create_sync_opaque = _wire_create_sync_opaquePtr.asFunction<void Function(int)>();
-
-  WireSyncReturn wire_sync_create_sync_opaque() {
-    return _wire_sync_create_sync_opaque();
-  }
-
-  late final _wire_sync_create_sync_opaquePtr =
-      _lookup<ffi.NativeFunction<WireSyncReturn Function()>>('wire_sync_create_sync_opaque');
-  late final _wire_sync_create_sync_opaque = _wire_sync_create_sync_opaquePtr.asFunction<WireSyncReturn Function()>();
+  late final _wire_handle_some_stream_sink__method__ConcatenateWithPtr = _lookup<
+          ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_ConcatenateWith>, ffi.Uint32, ffi.Uint32)>>(
+      'wire_handle_some_stream_sink__method__ConcatenateWith');
+  late final _wire_handle_some_stream_sink__method__ConcatenateWith =
+      _wire_handle_some_stream_sink__method__ConcatenateWithPtr
+          .asFunction<void Function(int, ffi.Pointer<wire_ConcatenateWith>, int, int)>();
 
-  WireSyncReturn wire_sync_run_opaque(
-    wire_NonSendHideData opaque,
+  void wire_handle_some_stream_sink_at_1__method__ConcatenateWith(
+    int port_,
+    ffi.Pointer<wire_ConcatenateWith> that,
   ) {
-    return _wire_sync_run_opaque(
-      opaque,
+    return _wire_handle_some_stream_sink_at_1__method__ConcatenateWith(
+      port_,
+      that,
     );
   }
 
-  late final _wire_sync_run_opaquePtr =
-      _lookup<ffi.NativeFunction<WireSyncReturn Function(wire_NonSendHideData)>>('wire_sync_run_opaque');
-  late final _wire_sync_run_opaque =
-      _wire_sync_run_opaquePtr.asFunction<WireSyncReturn Function(wire_NonSendHideData)>();
+  late final _wire_handle_some_stream_sink_at_1__method__ConcatenateWithPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_ConcatenateWith>)>>(
+          'wire_handle_some_stream_sink_at_1__method__ConcatenateWith');
+  late final _wire_handle_some_stream_sink_at_1__method__ConcatenateWith =
+      _wire_handle_some_stream_sink_at_1__method__ConcatenateWithPtr
+          .asFunction<void Function(int, ffi.Pointer<wire_ConcatenateWith>)>();
 
-  void wire_opaque_array_run(
+  void wire_handle_some_static_stream_sink__static_method__ConcatenateWith(
     int port_,
-    ffi.Pointer<wire_list_HideData> data,
+    int key,
+    int max,
   ) {
-    return _wire_opaque_array_run(
+    return _wire_handle_some_static_stream_sink__static_method__ConcatenateWith(
       port_,
-      data,
+      key,
+      max,
     );
   }
 
-  late final _wire_opaque_array_runPtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_list_HideData>)>>(
-          'wire_opaque_array_run');
-  late final _wire_opaque_array_run =
-      _wire_opaque_array_runPtr.asFunction<void Function(int, ffi.Pointer<wire_list_HideData>)>();
+  late final _wire_handle_some_static_stream_sink__static_method__ConcatenateWithPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Uint32, ffi.Uint32)>>(
+          'wire_handle_some_static_stream_sink__static_method__ConcatenateWith');
+  late final _wire_handle_some_static_stream_sink__static_method__ConcatenateWith =
+      _wire_handle_some_static_stream_sink__static_method__ConcatenateWithPtr
+          .asFunction<void Function(int, int, int)>();
 
-  void wire_opaque_vec(
+  void wire_handle_some_static_stream_sink_single_arg__static_method__ConcatenateWith(
     int port_,
   ) {
-    return _wire_opaque_vec(
+    return _wire_handle_some_static_stream_sink_single_arg__static_method__ConcatenateWith(
       port_,
     );
   }
 
-  late final _wire_opaque_vecPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64)>>('wire_opaque_vec');
-  late final _wire_opaque_vec = _wire_opaque_vecPtr.asFunction<void Function(int)>();
+  late final _wire_handle_some_static_stream_sink_single_arg__static_method__ConcatenateWithPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64)>>(
+          'wire_handle_some_static_stream_sink_single_arg__static_method__ConcatenateWith');
+  late final _wire_handle_some_static_stream_sink_single_arg__static_method__ConcatenateWith =
+      _wire_handle_some_static_stream_sink_single_arg__static_method__ConcatenateWithPtr
+          .asFunction<void Function(int)>();
 
-  void wire_opaque_vec_run(
+  void wire_start__static_method__Counter(
     int port_,
-    ffi.Pointer<wire_list_HideData> data,
+    int from,
   ) {
-    return _wire_opaque_vec_run(
+    return _wire_start__static_method__Counter(
       port_,
-      data,
+      from,
     );
   }
 
-  late final _wire_opaque_vec_runPtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_list_HideData>)>>('wire_opaque_vec_run');
-  late final _wire_opaque_vec_run =
-      _wire_opaque_vec_runPtr.asFunction<void Function(int, ffi.Pointer<wire_list_HideData>)>();
+  late final _wire_start__static_method__CounterPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Int32)>>('wire_start__static_method__Counter');
+  late final _wire_start__static_method__Counter =
+      _wire_start__static_method__CounterPtr.asFunction<void Function(int, int)>();
 
-  void wire_create_nested_opaque(
+  void wire_increment__method__Counter(
     int port_,
+    wire_Counter that,
+    int by,
   ) {
-    return _wire_create_nested_opaque(
+    return _wire_increment__method__Counter(
       port_,
+      that,
+      by,
     );
   }
 
-  late final _wire_create_nested_opaquePtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64)>>('wire_create_nested_opaque');
-  late final _wire_create_nested_opaque = _wire_create_nested_opaquePtr.asFunction<void Function(int)>();
+  late final _wire_increment__method__CounterPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, wire_Counter, ffi.Int32)>>(
+          'wire_increment__method__Counter');
+  late final _wire_increment__method__Counter =
+      _wire_increment__method__CounterPtr.asFunction<void Function(int, wire_Counter, int)>();
 
-  WireSyncReturn wire_sync_loopback(
-    wire_DartOpaque opaque,
+  void wire_label__method__Counter(
+    int port_,
+    wire_Counter that,
   ) {
-    return _wire_sync_loopback(
-      opaque,
+    return _wire_label__method__Counter(
+      port_,
+      that,
     );
   }
 
-  late final _wire_sync_loopbackPtr =
-      _lookup<ffi.NativeFunction<WireSyncReturn Function(wire_DartOpaque)>>('wire_sync_loopback');
-  late final _wire_sync_loopback = _wire_sync_loopbackPtr.asFunction<WireSyncReturn Function(wire_DartOpaque)>();
+  late final _wire_label__method__CounterPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, wire_Counter)>>('wire_label__method__Counter');
+  late final _wire_label__method__Counter =
+      _wire_label__method__CounterPtr.asFunction<void Function(int, wire_Counter)>();
 
-  WireSyncReturn wire_sync_option_loopback(
-    ffi.Pointer<wire_DartOpaque> opaque,
+  void wire_reset__method__Counter(
+    int port_,
+    wire_Counter that,
+    int to,
   ) {
-    return _wire_sync_option_loopback(
-      opaque,
+    return _wire_reset__method__Counter(
+      port_,
+      that,
+      to,
     );
   }
 
-  late final _wire_sync_option_loopbackPtr =
-      _lookup<ffi.NativeFunction<WireSyncReturn Function(ffi.Pointer<wire_DartOpaque>)>>('wire_sync_option_loopback');
-  late final _wire_sync_option_loopback =
-      _wire_sync_option_loopbackPtr.asFunction<WireSyncReturn Function(ffi.Pointer<wire_DartOpaque>)>();
-
-  WireSyncReturn wire_sync_option() {
-    return _wire_sync_option();
-  }
-
-  late final _wire_sync_optionPtr = _lookup<ffi.NativeFunction<WireSyncReturn Function()>>('wire_sync_option');
-  late final _wire_sync_option = _wire_sync_optionPtr.asFunction<WireSyncReturn Function()>();
-
-  WireSyncReturn wire_sync_option_null() {
-    return _wire_sync_option_null();
-  }
-
-  late final _wire_sync_option_nullPtr =
-      _lookup<ffi.NativeFunction<WireSyncReturn Function()>>('wire_sync_option_null');
-  late final _wire_sync_option_null = _wire_sync_option_nullPtr.asFunction<WireSyncReturn Function()>();
-
-  WireSyncReturn wire_sync_option_rust_opaque() {
-    return _wire_sync_option_rust_opaque();
-  }
-
-  late final _wire_sync_option_rust_opaquePtr =
-      _lookup<ffi.NativeFunction<WireSyncReturn Function()>>('wire_sync_option_rust_opaque');
-  late final _wire_sync_option_rust_opaque = _wire_sync_option_rust_opaquePtr.asFunction<WireSyncReturn Function()>();
+  late final _wire_reset__method__CounterPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, wire_Counter, ffi.Int32)>>('wire_reset__method__Counter');
+  late final _wire_reset__method__Counter =
+      _wire_reset__method__CounterPtr.asFunction<void Function(int, wire_Counter, int)>();
 
-  WireSyncReturn wire_sync_option_dart_opaque(
-    wire_DartOpaque opaque,
+  void wire_count_down__method__Counter(
+    int port_,
+    wire_Counter that,
   ) {
-    return _wire_sync_option_dart_opaque(
-      opaque,
+    return _wire_count_down__method__Counter(
+      port_,
+      that,
     );
   }
 
-  late final _wire_sync_option_dart_opaquePtr =
-      _lookup<ffi.NativeFunction<WireSyncReturn Function(wire_DartOpaque)>>('wire_sync_option_dart_opaque');
-  late final _wire_sync_option_dart_opaque =
-      _wire_sync_option_dart_opaquePtr.asFunction<WireSyncReturn Function(wire_DartOpaque)>();
+  late final _wire_count_down__method__CounterPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, wire_Counter)>>('wire_count_down__method__Counter');
+  late final _wire_count_down__method__Counter =
+      _wire_count_down__method__CounterPtr.asFunction<void Function(int, wire_Counter)>();
 
-  WireSyncReturn wire_sync_void() {
-    return _wire_sync_void();
+  void wire_increment_later__method__Counter(
+    int port_,
+    wire_Counter that,
+    int by,
+  ) {
+    return _wire_increment_later__method__Counter(
+      port_,
+      that,
+      by,
+    );
   }
 
-  late final _wire_sync_voidPtr = _lookup<ffi.NativeFunction<WireSyncReturn Function()>>('wire_sync_void');
-  late final _wire_sync_void = _wire_sync_voidPtr.asFunction<WireSyncReturn Function()>();
+  late final _wire_increment_later__method__CounterPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, wire_Counter, ffi.Int32)>>(
+          'wire_increment_later__method__Counter');
+  late final _wire_increment_later__method__Counter =
+      _wire_increment_later__method__CounterPtr.asFunction<void Function(int, wire_Counter, int)>();
 
-  void wire_run_nested_opaque(
+  void wire_updates__method__Counter(
     int port_,
-    ffi.Pointer<wire_OpaqueNested> opaque,
+    wire_Counter that,
   ) {
-    return _wire_run_nested_opaque(
+    return _wire_updates__method__Counter(
       port_,
-      opaque,
+      that,
     );
   }
 
-  late final _wire_run_nested_opaquePtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_OpaqueNested>)>>(
-          'wire_run_nested_opaque');
-  late final _wire_run_nested_opaque =
-      _wire_run_nested_opaquePtr.asFunction<void Function(int, ffi.Pointer<wire_OpaqueNested>)>();
+  late final _wire_updates__method__CounterPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, wire_Counter)>>('wire_updates__method__Counter');
+  late final _wire_updates__method__Counter =
+      _wire_updates__method__CounterPtr.asFunction<void Function(int, wire_Counter)>();
 
-  void wire_create_nested_dart_opaque(
+  void wire_finish__method__Counter(
     int port_,
-    wire_DartOpaque opaque1,
-    wire_DartOpaque opaque2,
+    wire_Counter that,
   ) {
-    return _wire_create_nested_dart_opaque(
+    return _wire_finish__method__Counter(
       port_,
-      opaque1,
-      opaque2,
+      that,
     );
   }
 
-  late final _wire_create_nested_dart_opaquePtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, wire_DartOpaque, wire_DartOpaque)>>(
-          'wire_create_nested_dart_opaque');
-  late final _wire_create_nested_dart_opaque =
-      _wire_create_nested_dart_opaquePtr.asFunction<void Function(int, wire_DartOpaque, wire_DartOpaque)>();
+  late final _wire_finish__method__CounterPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, wire_Counter)>>('wire_finish__method__Counter');
+  late final _wire_finish__method__Counter =
+      _wire_finish__method__CounterPtr.asFunction<void Function(int, wire_Counter)>();
 
-  void wire_get_nested_dart_opaque(
+  void wire_open__static_method__Rooms(
     int port_,
-    ffi.Pointer<wire_DartOpaqueNested> opaque,
   ) {
-    return _wire_get_nested_dart_opaque(
+    return _wire_open__static_method__Rooms(
       port_,
-      opaque,
     );
   }
 
-  late final _wire_get_nested_dart_opaquePtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_DartOpaqueNested>)>>(
-          'wire_get_nested_dart_opaque');
-  late final _wire_get_nested_dart_opaque =
-      _wire_get_nested_dart_opaquePtr.asFunction<void Function(int, ffi.Pointer<wire_DartOpaqueNested>)>();
+  late final _wire_open__static_method__RoomsPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64)>>('wire_open__static_method__Rooms');
+  late final _wire_open__static_method__Rooms = _wire_open__static_method__RoomsPtr.asFunction<void Function(int)>();
 
-  void wire_create_enum_dart_opaque(
+  void wire_join__method__Rooms(
     int port_,
-    wire_DartOpaque opaque,
+    wire_Rooms that,
+    ffi.Pointer<wire_uint_8_list> room,
   ) {
-    return _wire_create_enum_dart_opaque(
+    return _wire_join__method__Rooms(
       port_,
-      opaque,
+      that,
+      room,
     );
   }
 
-  late final _wire_create_enum_dart_opaquePtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, wire_DartOpaque)>>('wire_create_enum_dart_opaque');
-  late final _wire_create_enum_dart_opaque =
-      _wire_create_enum_dart_opaquePtr.asFunction<void Function(int, wire_DartOpaque)>();
+  late final _wire_join__method__RoomsPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, wire_Rooms, ffi.Pointer<wire_uint_8_list>)>>(
+          'wire_join__method__Rooms');
+  late final _wire_join__method__Rooms =
+      _wire_join__method__RoomsPtr.asFunction<void Function(int, wire_Rooms, ffi.Pointer<wire_uint_8_list>)>();
 
-  void wire_get_enum_dart_opaque(
+  void wire_post__method__Rooms(
     int port_,
-    ffi.Pointer<wire_EnumDartOpaque> opaque,
+    wire_Rooms that,
+    ffi.Pointer<wire_uint_8_list> room,
+    ffi.Pointer<wire_uint_8_list> message,
   ) {
-    return _wire_get_enum_dart_opaque(
+    return _wire_post__method__Rooms(
       port_,
-      opaque,
+      that,
+      room,
+      message,
     );
   }
 
-  late final _wire_get_enum_dart_opaquePtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_EnumDartOpaque>)>>(
-          'wire_get_enum_dart_opaque');
-  late final _wire_get_enum_dart_opaque =
-      _wire_get_enum_dart_opaquePtr.asFunction<void Function(int, ffi.Pointer<wire_EnumDartOpaque>)>();
+  late final _wire_post__method__RoomsPtr = _lookup<
+      ffi.NativeFunction<
+          ffi.Void Function(ffi.Int64, wire_Rooms, ffi.Pointer<wire_uint_8_list>,
+              ffi.Pointer<wire_uint_8_list>)>>('wire_post__method__Rooms');
+  late final _wire_post__method__Rooms = _wire_post__method__RoomsPtr
+      .asFunction<void Function(int, wire_Rooms, ffi.Pointer<wire_uint_8_list>, ffi.Pointer<wire_uint_8_list>)>();
 
-  void wire_set_static_dart_opaque(
+  void wire_close_room__method__Rooms(
     int port_,
-    wire_DartOpaque opaque,
+    wire_Rooms that,
+    ffi.Pointer<wire_uint_8_list> room,
   ) {
-    return _wire_set_static_dart_opaque(
+    return _wire_close_room__method__Rooms(
       port_,
-      opaque,
+      that,
+      room,
     );
   }
 
-  late final _wire_set_static_dart_opaquePtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, wire_DartOpaque)>>('wire_set_static_dart_opaque');
-  late final _wire_set_static_dart_opaque =
-      _wire_set_static_dart_opaquePtr.asFunction<void Function(int, wire_DartOpaque)>();
+  late final _wire_close_room__method__RoomsPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, wire_Rooms, ffi.Pointer<wire_uint_8_list>)>>(
+          'wire_close_room__method__Rooms');
+  late final _wire_close_room__method__Rooms =
+      _wire_close_room__method__RoomsPtr.asFunction<void Function(int, wire_Rooms, ffi.Pointer<wire_uint_8_list>)>();
 
-  void wire_drop_static_dart_opaque(
+  void wire_named__static_method__Greeter(
     int port_,
+    ffi.Pointer<wire_uint_8_list> name,
   ) {
-    return _wire_drop_static_dart_opaque(
+    return _wire_named__static_method__Greeter(
       port_,
+      name,
     );
   }
 
-  late final _wire_drop_static_dart_opaquePtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64)>>('wire_drop_static_dart_opaque');
-  late final _wire_drop_static_dart_opaque = _wire_drop_static_dart_opaquePtr.asFunction<void Function(int)>();
+  late final _wire_named__static_method__GreeterPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_uint_8_list>)>>(
+          'wire_named__static_method__Greeter');
+  late final _wire_named__static_method__Greeter =
+      _wire_named__static_method__GreeterPtr.asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>)>();
 
-  void wire_unwrap_rust_opaque(
+  void wire_describe__method__Counter(
     int port_,
-    wire_HideData opaque,
+    wire_Counter that,
   ) {
-    return _wire_unwrap_rust_opaque(
+    return _wire_describe__method__Counter(
       port_,
-      opaque,
+      that,
     );
   }
 
-  late final _wire_unwrap_rust_opaquePtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, wire_HideData)>>('wire_unwrap_rust_opaque');
-  late final _wire_unwrap_rust_opaque = _wire_unwrap_rust_opaquePtr.asFunction<void Function(int, wire_HideData)>();
+  late final _wire_describe__method__CounterPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, wire_Counter)>>('wire_describe__method__Counter');
+  late final _wire_describe__method__Counter =
+      _wire_describe__method__CounterPtr.asFunction<void Function(int, wire_Counter)>();
 
-  WireSyncReturn wire_return_non_droppable_dart_opaque(
-    wire_DartOpaque opaque,
+  void wire_shout__method__Counter(
+    int port_,
+    wire_Counter that,
   ) {
-    return _wire_return_non_droppable_dart_opaque(
-      opaque,
+    return _wire_shout__method__Counter(
+      port_,
+      that,
     );
-  }
-
-  late final _wire_return_non_droppable_dart_opaquePtr =
-      _lookup<ffi.NativeFunction<WireSyncReturn Function(wire_DartOpaque)>>('wire_return_non_droppable_dart_opaque');
-  late final _wire_return_non_droppable_dart_opaque =
-      _wire_return_non_droppable_dart_opaquePtr.asFunction<WireSyncReturn Function(wire_DartOpaque)>();
+  }
 
-  void wire_frb_generator_test(
+  late final _wire_shout__method__CounterPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, wire_Counter)>>('wire_shout__method__Counter');
+  late final _wire_shout__method__Counter =
+      _wire_shout__method__CounterPtr.asFunction<void Function(int, wire_Counter)>();
+
+  void wire_describe__method__Greeter(
     int port_,
+    wire_Greeter that,
   ) {
-    return _wire_frb_generator_test(
+    return _wire_describe__method__Greeter(
       port_,
+      that,
     );
   }
 
-  late final _wire_frb_generator_testPtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64)>>('wire_frb_generator_test');
-  late final _wire_frb_generator_test = _wire_frb_generator_testPtr.asFunction<void Function(int)>();
+  late final _wire_describe__method__GreeterPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, wire_Greeter)>>('wire_describe__method__Greeter');
+  late final _wire_describe__method__Greeter =
+      _wire_describe__method__GreeterPtr.asFunction<void Function(int, wire_Greeter)>();
 
-  WireSyncReturn wire_frb_sync_generator_test() {
-    return _wire_frb_sync_generator_test();
+  void wire_shout__method__Greeter(
+    int port_,
+    wire_Greeter that,
+  ) {
+    return _wire_shout__method__Greeter(
+      port_,
+      that,
+    );
   }
 
-  late final _wire_frb_sync_generator_testPtr =
-      _lookup<ffi.NativeFunction<WireSyncReturn Function()>>('wire_frb_sync_generator_test');
-  late final _wire_frb_sync_generator_test = _wire_frb_sync_generator_testPtr.asFunction<WireSyncReturn Function()>();
+  late final _wire_shout__method__GreeterPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, wire_Greeter)>>('wire_shout__method__Greeter');
+  late final _wire_shout__method__Greeter =
+      _wire_shout__method__GreeterPtr.asFunction<void Function(int, wire_Greeter)>();
 
-  void wire_handle_type_alias_id(
+  void wire_dispatch(
     int port_,
-    int input,
+    ffi.Pointer<wire_uint_8_list> id,
+    int x,
   ) {
-    return _wire_handle_type_alias_id(
+    return _wire_dispatch(
       port_,
-      input,
+      id,
+      x,
     );
   }
 
-  late final _wire_handle_type_alias_idPtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Uint64)>>('wire_handle_type_alias_id');
-  late final _wire_handle_type_alias_id = _wire_handle_type_alias_idPtr.asFunction<void Function(int, int)>();
+  late final _wire_dispatchPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_uint_8_list>, ffi.Int32)>>(
+          'wire_dispatch');
+  late final _wire_dispatch = _wire_dispatchPtr.asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>, int)>();
 
-  void wire_handle_type_nest_alias_id(
+  void wire_next_dart_iterator_string(
     int port_,
-    int input,
+    wire_DartIteratorString iterator,
   ) {
-    return _wire_handle_type_nest_alias_id(
+    return _wire_next_dart_iterator_string(
       port_,
-      input,
+      iterator,
     );
   }
 
-  late final _wire_handle_type_nest_alias_idPtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Uint64)>>('wire_handle_type_nest_alias_id');
-  late final _wire_handle_type_nest_alias_id = _wire_handle_type_nest_alias_idPtr.asFunction<void Function(int, int)>();
+  late final _wire_next_dart_iterator_stringPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, wire_DartIteratorString)>>(
+          'wire_next_dart_iterator_string');
+  late final _wire_next_dart_iterator_string =
+      _wire_next_dart_iterator_stringPtr.asFunction<void Function(int, wire_DartIteratorString)>();
 
-  void wire_handle_type_alias_model(
+  void wire_next_dart_iterator_i_32(
     int port_,
-    int input,
+    wire_DartIteratorI32 iterator,
   ) {
-    return _wire_handle_type_alias_model(
+    return _wire_next_dart_iterator_i_32(
       port_,
-      input,
+      iterator,
     );
   }
 
-  late final _wire_handle_type_alias_modelPtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Uint64)>>('wire_handle_type_alias_model');
-  late final _wire_handle_type_alias_model = _wire_handle_type_alias_modelPtr.asFunction<void Function(int, int)>();
+  late final _wire_next_dart_iterator_i_32Ptr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, wire_DartIteratorI32)>>('wire_next_dart_iterator_i_32');
+  late final _wire_next_dart_iterator_i_32 =
+      _wire_next_dart_iterator_i_32Ptr.asFunction<void Function(int, wire_DartIteratorI32)>();
 
-  void wire_empty_struct(
+  void wire_changes__method__Counter(
     int port_,
-    ffi.Pointer<wire_Empty> empty,
+    wire_Counter that,
   ) {
-    return _wire_empty_struct(
+    return _wire_changes__method__Counter(
       port_,
-      empty,
+      that,
     );
   }
 
-  late final _wire_empty_structPtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_Empty>)>>('wire_empty_struct');
-  late final _wire_empty_struct = _wire_empty_structPtr.asFunction<void Function(int, ffi.Pointer<wire_Empty>)>();
+  late final _wire_changes__method__CounterPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, wire_Counter)>>('wire_changes__method__Counter');
+  late final _wire_changes__method__Counter =
+      _wire_changes__method__CounterPtr.asFunction<void Function(int, wire_Counter)>();
 
-  void wire_sum__method__SumWith(
+  void wire_warm_up(
     int port_,
-    ffi.Pointer<wire_SumWith> that,
-    int y,
-    int z,
   ) {
-    return _wire_sum__method__SumWith(
+    return _wire_warm_up(
       port_,
-      that,
-      y,
-      z,
     );
   }
 
-  late final _wire_sum__method__SumWithPtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_SumWith>, ffi.Uint32, ffi.Uint32)>>(
-          'wire_sum__method__SumWith');
-  late final _wire_sum__method__SumWith =
-      _wire_sum__method__SumWithPtr.asFunction<void Function(int, ffi.Pointer<wire_SumWith>, int, int)>();
+  late final _wire_warm_upPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64)>>('wire_warm_up');
+  late final _wire_warm_up = _wire_warm_upPtr.asFunction<void Function(int)>();
 
-  void wire_new__static_method__ConcatenateWith(
+  void wire_upgrade_weak_counter(
     int port_,
-    ffi.Pointer<wire_uint_8_list> a,
+    wire_WeakCounter weak,
   ) {
-    return _wire_new__static_method__ConcatenateWith(
+    return _wire_upgrade_weak_counter(
       port_,
-      a,
+      weak,
     );
   }
 
-  late final _wire_new__static_method__ConcatenateWithPtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_uint_8_list>)>>(
-          'wire_new__static_method__ConcatenateWith');
-  late final _wire_new__static_method__ConcatenateWith =
-      _wire_new__static_method__ConcatenateWithPtr.asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>)>();
+  late final _wire_upgrade_weak_counterPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, wire_WeakCounter)>>('wire_upgrade_weak_counter');
+  late final _wire_upgrade_weak_counter =
+      _wire_upgrade_weak_counterPtr.asFunction<void Function(int, wire_WeakCounter)>();
 
-  void wire_concatenate__method__ConcatenateWith(
+  void wire_instant_duration_since(
     int port_,
-    ffi.Pointer<wire_ConcatenateWith> that,
-    ffi.Pointer<wire_uint_8_list> b,
+    wire_Instant that,
+    wire_Instant earlier,
   ) {
-    return _wire_concatenate__method__ConcatenateWith(
+    return _wire_instant_duration_since(
       port_,
       that,
-      b,
+      earlier,
     );
   }
 
-  late final _wire_concatenate__method__ConcatenateWithPtr = _lookup<
-      ffi.NativeFunction<
-          ffi.Void Function(ffi.Int64, ffi.Pointer<wire_ConcatenateWith>,
-              ffi.Pointer<wire_uint_8_list>)>>('wire_concatenate__method__ConcatenateWith');
-  late final _wire_concatenate__method__ConcatenateWith = _wire_concatenate__method__ConcatenateWithPtr
-      .asFunction<void Function(int, ffi.Pointer<wire_ConcatenateWith>, ffi.Pointer<wire_uint_8_list>)>();
+  late final _wire_instant_duration_sincePtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, wire_Instant, wire_Instant)>>(
+          'wire_instant_duration_since');
+  late final _wire_instant_duration_since =
+      _wire_instant_duration_sincePtr.asFunction<void Function(int, wire_Instant, wire_Instant)>();
 
-  void wire_concatenate_static__static_method__ConcatenateWith(
-    int port_,
-    ffi.Pointer<wire_uint_8_list> a,
-    ffi.Pointer<wire_uint_8_list> b,
+  WireSyncReturn wire_handle_stream_drain(
+    ffi.Pointer<wire_uint_8_list> arg,
   ) {
-    return _wire_concatenate_static__static_method__ConcatenateWith(
-      port_,
-      a,
-      b,
+    return _wire_handle_stream_drain(
+      arg,
     );
   }
 
-  late final _wire_concatenate_static__static_method__ConcatenateWithPtr = _lookup<
-      ffi.NativeFunction<
-          ffi.Void Function(ffi.Int64, ffi.Pointer<wire_uint_8_list>,
-              ffi.Pointer<wire_uint_8_list>)>>('wire_concatenate_static__static_method__ConcatenateWith');
-  late final _wire_concatenate_static__static_method__ConcatenateWith =
-      _wire_concatenate_static__static_method__ConcatenateWithPtr
-          .asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>, ffi.Pointer<wire_uint_8_list>)>();
+  late final _wire_handle_stream_drainPtr =
+      _lookup<ffi.NativeFunction<WireSyncReturn Function(ffi.Pointer<wire_uint_8_list>)>>('wire_handle_stream_drain');
+  late final _wire_handle_stream_drain =
+      _wire_handle_stream_drainPtr.asFunction<WireSyncReturn Function(ffi.Pointer<wire_uint_8_list>)>();
 
-  void wire_handle_some_stream_sink__method__ConcatenateWith(
-    int port_,
-    ffi.Pointer<wire_ConcatenateWith> that,
+  WireSyncReturn wire_handle_stream_of_struct_drain() {
+    return _wire_handle_stream_of_struct_drain();
+  }
+
+  late final _wire_handle_stream_of_struct_drainPtr =
+      _lookup<ffi.NativeFunction<WireSyncReturn Function()>>('wire_handle_stream_of_struct_drain');
+  late final _wire_handle_stream_of_struct_drain =
+      _wire_handle_stream_of_struct_drainPtr.asFunction<WireSyncReturn Function()>();
+
+  WireSyncReturn wire_register_event_listener_drain() {
+    return _wire_register_event_listener_drain();
+  }
+
+  late final _wire_register_event_listener_drainPtr =
+      _lookup<ffi.NativeFunction<WireSyncReturn Function()>>('wire_register_event_listener_drain');
+  late final _wire_register_event_listener_drain =
+      _wire_register_event_listener_drainPtr.asFunction<WireSyncReturn Function()>();
+
+  WireSyncReturn wire_handle_stream_sink_at_1_drain(
     int key,
     int max,
   ) {
-    return _wire_handle_some_stream_sink__method__ConcatenateWith(
-      port_,
-      that,
+    return _wire_handle_stream_sink_at_1_drain(
       key,
       max,
     );
   }
 
-  late final _wire_handle_some_stream_sink__method__ConcatenateWithPtr = _lookup<
-          ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_ConcatenateWith>, ffi.Uint32, ffi.Uint32)>>(
-      'wire_handle_some_stream_sink__method__ConcatenateWith');
-  late final _wire_handle_some_stream_sink__method__ConcatenateWith =
-      _wire_handle_some_stream_sink__method__ConcatenateWithPtr
-          .asFunction<void Function(int, ffi.Pointer<wire_ConcatenateWith>, int, int)>();
+  late final _wire_handle_stream_sink_at_1_drainPtr =
+      _lookup<ffi.NativeFunction<WireSyncReturn Function(ffi.Uint32, ffi.Uint32)>>(
+          'wire_handle_stream_sink_at_1_drain');
+  late final _wire_handle_stream_sink_at_1_drain =
+      _wire_handle_stream_sink_at_1_drainPtr.asFunction<WireSyncReturn Function(int, int)>();
 
-  void wire_handle_some_stream_sink_at_1__method__ConcatenateWith(
-    int port_,
-    ffi.Pointer<wire_ConcatenateWith> that,
+  WireSyncReturn wire_handle_stream_sink_at_2_drain(
+    int key,
+    int max,
   ) {
-    return _wire_handle_some_stream_sink_at_1__method__ConcatenateWith(
-      port_,
-      that,
+    return _wire_handle_stream_sink_at_2_drain(
+      key,
+      max,
     );
   }
 
-  late final _wire_handle_some_stream_sink_at_1__method__ConcatenateWithPtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_ConcatenateWith>)>>(
-          'wire_handle_some_stream_sink_at_1__method__ConcatenateWith');
-  late final _wire_handle_some_stream_sink_at_1__method__ConcatenateWith =
-      _wire_handle_some_stream_sink_at_1__method__ConcatenateWithPtr
-          .asFunction<void Function(int, ffi.Pointer<wire_ConcatenateWith>)>();
+  late final _wire_handle_stream_sink_at_2_drainPtr =
+      _lookup<ffi.NativeFunction<WireSyncReturn Function(ffi.Uint32, ffi.Uint32)>>(
+          'wire_handle_stream_sink_at_2_drain');
+  late final _wire_handle_stream_sink_at_2_drain =
+      _wire_handle_stream_sink_at_2_drainPtr.asFunction<WireSyncReturn Function(int, int)>();
 
-  void wire_handle_some_static_stream_sink__static_method__ConcatenateWith(
-    int port_,
+  WireSyncReturn wire_handle_stream_sink_at_3_drain(
     int key,
     int max,
   ) {
-    return _wire_handle_some_static_stream_sink__static_method__ConcatenateWith(
-      port_,
+    return _wire_handle_stream_sink_at_3_drain(
       key,
       max,
     );
   }
 
-  late final _wire_handle_some_static_stream_sink__static_method__ConcatenateWithPtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Uint32, ffi.Uint32)>>(
-          'wire_handle_some_static_stream_sink__static_method__ConcatenateWith');
-  late final _wire_handle_some_static_stream_sink__static_method__ConcatenateWith =
-      _wire_handle_some_static_stream_sink__static_method__ConcatenateWithPtr
-          .asFunction<void Function(int, int, int)>();
+  late final _wire_handle_stream_sink_at_3_drainPtr =
+      _lookup<ffi.NativeFunction<WireSyncReturn Function(ffi.Uint32, ffi.Uint32)>>(
+          'wire_handle_stream_sink_at_3_drain');
+  late final _wire_handle_stream_sink_at_3_drain =
+      _wire_handle_stream_sink_at_3_drainPtr.asFunction<WireSyncReturn Function(int, int)>();
 
-  void wire_handle_some_static_stream_sink_single_arg__static_method__ConcatenateWith(
-    int port_,
+  WireSyncReturn wire_handle_stream_closed_by_drop_drain(
+    int max,
   ) {
-    return _wire_handle_some_static_stream_sink_single_arg__static_method__ConcatenateWith(
-      port_,
+    return _wire_handle_stream_closed_by_drop_drain(
+      max,
     );
   }
 
-  late final _wire_handle_some_static_stream_sink_single_arg__static_method__ConcatenateWithPtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64)>>(
-          'wire_handle_some_static_stream_sink_single_arg__static_method__ConcatenateWith');
-  late final _wire_handle_some_static_stream_sink_single_arg__static_method__ConcatenateWith =
-      _wire_handle_some_static_stream_sink_single_arg__static_method__ConcatenateWithPtr
-          .asFunction<void Function(int)>();
+  late final _wire_handle_stream_closed_by_drop_drainPtr =
+      _lookup<ffi.NativeFunction<WireSyncReturn Function(ffi.Uint32)>>('wire_handle_stream_closed_by_drop_drain');
+  late final _wire_handle_stream_closed_by_drop_drain =
+      _wire_handle_stream_closed_by_drop_drainPtr.asFunction<WireSyncReturn Function(int)>();
+
+  WireSyncReturn wire_count_from_drain(
+    int from,
+    int count,
+  ) {
+    return _wire_count_from_drain(
+      from,
+      count,
+    );
+  }
+
+  late final _wire_count_from_drainPtr =
+      _lookup<ffi.NativeFunction<WireSyncReturn Function(ffi.Uint32, ffi.Uint32)>>('wire_count_from_drain');
+  late final _wire_count_from_drain = _wire_count_from_drainPtr.asFunction<WireSyncReturn Function(int, int)>();
+
+  WireSyncReturn wire_parse_numbers_drain(
+    ffi.Pointer<wire_uint_8_list> input,
+  ) {
+    return _wire_parse_numbers_drain(
+      input,
+    );
+  }
+
+  late final _wire_parse_numbers_drainPtr =
+      _lookup<ffi.NativeFunction<WireSyncReturn Function(ffi.Pointer<wire_uint_8_list>)>>('wire_parse_numbers_drain');
+  late final _wire_parse_numbers_drain =
+      _wire_parse_numbers_drainPtr.asFunction<WireSyncReturn Function(ffi.Pointer<wire_uint_8_list>)>();
+
+  WireSyncReturn wire_handle_broadcast_stream_drain(
+    int max,
+  ) {
+    return _wire_handle_broadcast_stream_drain(
+      max,
+    );
+  }
+
+  late final _wire_handle_broadcast_stream_drainPtr =
+      _lookup<ffi.NativeFunction<WireSyncReturn Function(ffi.Uint32)>>('wire_handle_broadcast_stream_drain');
+  late final _wire_handle_broadcast_stream_drain =
+      _wire_handle_broadcast_stream_drainPtr.asFunction<WireSyncReturn Function(int)>();
+
+  WireSyncReturn wire_handle_stream_closed_by_threads_drain(
+    int threads,
+  ) {
+    return _wire_handle_stream_closed_by_threads_drain(
+      threads,
+    );
+  }
+
+  late final _wire_handle_stream_closed_by_threads_drainPtr =
+      _lookup<ffi.NativeFunction<WireSyncReturn Function(ffi.Uint32)>>('wire_handle_stream_closed_by_threads_drain');
+  late final _wire_handle_stream_closed_by_threads_drain =
+      _wire_handle_stream_closed_by_threads_drainPtr.asFunction<WireSyncReturn Function(int)>();
 
   wire_BoxDartDebug new_BoxDartDebug() {
     return _new_BoxDartDebug();
@@ -3432,6 +5876,29 @@ class FlutterRustBridgeExampleSingleBlockTestWire implements FlutterRustBridgeWi
   late final _new_BoxDartDebugPtr = _lookup<ffi.NativeFunction<wire_BoxDartDebug Function()>>('new_BoxDartDebug');
   late final _new_BoxDartDebug = _new_BoxDartDebugPtr.asFunction<wire_BoxDartDebug Function()>();
 
+  wire_Counter new_Counter() {
+    return _new_Counter();
+  }
+
+  late final _new_CounterPtr = _lookup<ffi.NativeFunction<wire_Counter Function()>>('new_Counter');
+  late final _new_Counter = _new_CounterPtr.asFunction<wire_Counter Function()>();
+
+  wire_DartIteratorI32 new_DartIteratorI32() {
+    return _new_DartIteratorI32();
+  }
+
+  late final _new_DartIteratorI32Ptr =
+      _lookup<ffi.NativeFunction<wire_DartIteratorI32 Function()>>('new_DartIteratorI32');
+  late final _new_DartIteratorI32 = _new_DartIteratorI32Ptr.asFunction<wire_DartIteratorI32 Function()>();
+
+  wire_DartIteratorString new_DartIteratorString() {
+    return _new_DartIteratorString();
+  }
+
+  late final _new_DartIteratorStringPtr =
+      _lookup<ffi.NativeFunction<wire_DartIteratorString Function()>>('new_DartIteratorString');
+  late final _new_DartIteratorString = _new_DartIteratorStringPtr.asFunction<wire_DartIteratorString Function()>();
+
   wire_DartOpaque new_DartOpaque() {
     return _new_DartOpaque();
   }
@@ -3439,6 +5906,13 @@ class FlutterRustBridgeExampleSingleBlockTestWire implements FlutterRustBridgeWi
   late final _new_DartOpaquePtr = _lookup<ffi.NativeFunction<wire_DartOpaque Function()>>('new_DartOpaque');
   late final _new_DartOpaque = _new_DartOpaquePtr.asFunction<wire_DartOpaque Function()>();
 
+  wire_Greeter new_Greeter() {
+    return _new_Greeter();
+  }
+
+  late final _new_GreeterPtr = _lookup<ffi.NativeFunction<wire_Greeter Function()>>('new_Greeter');
+  late final _new_Greeter = _new_GreeterPtr.asFunction<wire_Greeter Function()>();
+
   wire_HideData new_HideData() {
     return _new_HideData();
   }
@@ -3453,6 +5927,13 @@ class FlutterRustBridgeExampleSingleBlockTestWire implements FlutterRustBridgeWi
   late final _new_I32Ptr = _lookup<ffi.NativeFunction<wire_I32 Function()>>('new_I32');
   late final _new_I32 = _new_I32Ptr.asFunction<wire_I32 Function()>();
 
+  wire_Instant new_Instant() {
+    return _new_Instant();
+  }
+
+  late final _new_InstantPtr = _lookup<ffi.NativeFunction<wire_Instant Function()>>('new_Instant');
+  late final _new_Instant = _new_InstantPtr.asFunction<wire_Instant Function()>();
+
   wire_MutexHideData new_MutexHideData() {
     return _new_MutexHideData();
   }
@@ -3468,6 +5949,13 @@ class FlutterRustBridgeExampleSingleBlockTestWire implements FlutterRustBridgeWi
       _lookup<ffi.NativeFunction<wire_NonSendHideData Function()>>('new_NonSendHideData');
   late final _new_NonSendHideData = _new_NonSendHideDataPtr.asFunction<wire_NonSendHideData Function()>();
 
+  wire_Rooms new_Rooms() {
+    return _new_Rooms();
+  }
+
+  late final _new_RoomsPtr = _lookup<ffi.NativeFunction<wire_Rooms Function()>>('new_Rooms');
+  late final _new_Rooms = _new_RoomsPtr.asFunction<wire_Rooms Function()>();
+
   wire_RwLockHideData new_RwLockHideData() {
     return _new_RwLockHideData();
   }
@@ -3487,6 +5975,13 @@ class FlutterRustBridgeExampleSingleBlockTestWire implements FlutterRustBridgeWi
       _lookup<ffi.NativeFunction<ffi.Pointer<wire_StringList> Function(ffi.Int32)>>('new_StringList_0');
   late final _new_StringList_0 = _new_StringList_0Ptr.asFunction<ffi.Pointer<wire_StringList> Function(int)>();
 
+  wire_WeakCounter new_WeakCounter() {
+    return _new_WeakCounter();
+  }
+
+  late final _new_WeakCounterPtr = _lookup<ffi.NativeFunction<wire_WeakCounter Function()>>('new_WeakCounter');
+  late final _new_WeakCounter = _new_WeakCounterPtr.asFunction<wire_WeakCounter Function()>();
+
   ffi.Pointer<wire_ApplicationEnv> new_box_application_env_0() {
     return _new_box_application_env_0();
   }
@@ -3536,6 +6031,16 @@ class FlutterRustBridgeExampleSingleBlockTestWire implements FlutterRustBridgeWi
   late final _new_box_autoadd_application_env_0 =
       _new_box_autoadd_application_env_0Ptr.asFunction<ffi.Pointer<wire_ApplicationEnv> Function()>();
 
+  ffi.Pointer<wire_ApplicationMessage> new_box_autoadd_application_message_0() {
+    return _new_box_autoadd_application_message_0();
+  }
+
+  late final _new_box_autoadd_application_message_0Ptr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_ApplicationMessage> Function()>>(
+          'new_box_autoadd_application_message_0');
+  late final _new_box_autoadd_application_message_0 =
+      _new_box_autoadd_application_message_0Ptr.asFunction<ffi.Pointer<wire_ApplicationMessage> Function()>();
+
   ffi.Pointer<wire_ApplicationSettings> new_box_autoadd_application_settings_0() {
     return _new_box_autoadd_application_settings_0();
   }
@@ -3595,6 +6100,15 @@ class FlutterRustBridgeExampleSingleBlockTestWire implements FlutterRustBridgeWi
   late final _new_box_autoadd_dart_opaque_nested_0 =
       _new_box_autoadd_dart_opaque_nested_0Ptr.asFunction<ffi.Pointer<wire_DartOpaqueNested> Function()>();
 
+  ffi.Pointer<wire_Document> new_box_autoadd_document_0() {
+    return _new_box_autoadd_document_0();
+  }
+
+  late final _new_box_autoadd_document_0Ptr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_Document> Function()>>('new_box_autoadd_document_0');
+  late final _new_box_autoadd_document_0 =
+      _new_box_autoadd_document_0Ptr.asFunction<ffi.Pointer<wire_Document> Function()>();
+
   ffi.Pointer<wire_Empty> new_box_autoadd_empty_0() {
     return _new_box_autoadd_empty_0();
   }
@@ -3669,6 +6183,23 @@ class FlutterRustBridgeExampleSingleBlockTestWire implements FlutterRustBridgeWi
   late final _new_box_autoadd_feed_id_0 =
       _new_box_autoadd_feed_id_0Ptr.asFunction<ffi.Pointer<wire_FeedId> Function()>();
 
+  ffi.Pointer<wire_Frame> new_box_autoadd_frame_0() {
+    return _new_box_autoadd_frame_0();
+  }
+
+  late final _new_box_autoadd_frame_0Ptr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_Frame> Function()>>('new_box_autoadd_frame_0');
+  late final _new_box_autoadd_frame_0 = _new_box_autoadd_frame_0Ptr.asFunction<ffi.Pointer<wire_Frame> Function()>();
+
+  ffi.Pointer<wire_GridCell> new_box_autoadd_grid_cell_0() {
+    return _new_box_autoadd_grid_cell_0();
+  }
+
+  late final _new_box_autoadd_grid_cell_0Ptr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_GridCell> Function()>>('new_box_autoadd_grid_cell_0');
+  late final _new_box_autoadd_grid_cell_0 =
+      _new_box_autoadd_grid_cell_0Ptr.asFunction<ffi.Pointer<wire_GridCell> Function()>();
+
   ffi.Pointer<ffi.Int32> new_box_autoadd_i32_0(
     int value,
   ) {
@@ -3720,6 +6251,14 @@ class FlutterRustBridgeExampleSingleBlockTestWire implements FlutterRustBridgeWi
   late final _new_box_autoadd_message_id_0 =
       _new_box_autoadd_message_id_0Ptr.asFunction<ffi.Pointer<wire_MessageId> Function()>();
 
+  ffi.Pointer<wire_Money> new_box_autoadd_money_0() {
+    return _new_box_autoadd_money_0();
+  }
+
+  late final _new_box_autoadd_money_0Ptr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_Money> Function()>>('new_box_autoadd_money_0');
+  late final _new_box_autoadd_money_0 = _new_box_autoadd_money_0Ptr.asFunction<ffi.Pointer<wire_Money> Function()>();
+
   ffi.Pointer<wire_MyNestedStruct> new_box_autoadd_my_nested_struct_0() {
     return _new_box_autoadd_my_nested_struct_0();
   }
@@ -3791,6 +6330,41 @@ class FlutterRustBridgeExampleSingleBlockTestWire implements FlutterRustBridgeWi
   late final _new_box_autoadd_opaque_nested_0 =
       _new_box_autoadd_opaque_nested_0Ptr.asFunction<ffi.Pointer<wire_OpaqueNested> Function()>();
 
+  ffi.Pointer<wire_PairI32> new_box_autoadd_pair_i_32_0() {
+    return _new_box_autoadd_pair_i_32_0();
+  }
+
+  late final _new_box_autoadd_pair_i_32_0Ptr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_PairI32> Function()>>('new_box_autoadd_pair_i_32_0');
+  late final _new_box_autoadd_pair_i_32_0 =
+      _new_box_autoadd_pair_i_32_0Ptr.asFunction<ffi.Pointer<wire_PairI32> Function()>();
+
+  ffi.Pointer<wire_ProductId> new_box_autoadd_product_id_0() {
+    return _new_box_autoadd_product_id_0();
+  }
+
+  late final _new_box_autoadd_product_id_0Ptr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_ProductId> Function()>>('new_box_autoadd_product_id_0');
+  late final _new_box_autoadd_product_id_0 =
+      _new_box_autoadd_product_id_0Ptr.asFunction<ffi.Pointer<wire_ProductId> Function()>();
+
+  ffi.Pointer<wire_Progress> new_box_autoadd_progress_0() {
+    return _new_box_autoadd_progress_0();
+  }
+
+  late final _new_box_autoadd_progress_0Ptr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_Progress> Function()>>('new_box_autoadd_progress_0');
+  late final _new_box_autoadd_progress_0 =
+      _new_box_autoadd_progress_0Ptr.asFunction<ffi.Pointer<wire_Progress> Function()>();
+
+  ffi.Pointer<wire_Sample> new_box_autoadd_sample_0() {
+    return _new_box_autoadd_sample_0();
+  }
+
+  late final _new_box_autoadd_sample_0Ptr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_Sample> Function()>>('new_box_autoadd_sample_0');
+  late final _new_box_autoadd_sample_0 = _new_box_autoadd_sample_0Ptr.asFunction<ffi.Pointer<wire_Sample> Function()>();
+
   ffi.Pointer<wire_Sequences> new_box_autoadd_sequences_0() {
     return _new_box_autoadd_sequences_0();
   }
@@ -3800,6 +6374,15 @@ class FlutterRustBridgeExampleSingleBlockTestWire implements FlutterRustBridgeWi
   late final _new_box_autoadd_sequences_0 =
       _new_box_autoadd_sequences_0Ptr.asFunction<ffi.Pointer<wire_Sequences> Function()>();
 
+  ffi.Pointer<wire_Session> new_box_autoadd_session_0() {
+    return _new_box_autoadd_session_0();
+  }
+
+  late final _new_box_autoadd_session_0Ptr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_Session> Function()>>('new_box_autoadd_session_0');
+  late final _new_box_autoadd_session_0 =
+      _new_box_autoadd_session_0Ptr.asFunction<ffi.Pointer<wire_Session> Function()>();
+
   ffi.Pointer<wire_SumWith> new_box_autoadd_sum_with_0() {
     return _new_box_autoadd_sum_with_0();
   }
@@ -3809,6 +6392,14 @@ class FlutterRustBridgeExampleSingleBlockTestWire implements FlutterRustBridgeWi
   late final _new_box_autoadd_sum_with_0 =
       _new_box_autoadd_sum_with_0Ptr.asFunction<ffi.Pointer<wire_SumWith> Function()>();
 
+  ffi.Pointer<wire_Tag> new_box_autoadd_tag_0() {
+    return _new_box_autoadd_tag_0();
+  }
+
+  late final _new_box_autoadd_tag_0Ptr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_Tag> Function()>>('new_box_autoadd_tag_0');
+  late final _new_box_autoadd_tag_0 = _new_box_autoadd_tag_0Ptr.asFunction<ffi.Pointer<wire_Tag> Function()>();
+
   ffi.Pointer<wire_TestId> new_box_autoadd_test_id_0() {
     return _new_box_autoadd_test_id_0();
   }
@@ -3818,6 +6409,38 @@ class FlutterRustBridgeExampleSingleBlockTestWire implements FlutterRustBridgeWi
   late final _new_box_autoadd_test_id_0 =
       _new_box_autoadd_test_id_0Ptr.asFunction<ffi.Pointer<wire_TestId> Function()>();
 
+  ffi.Pointer<wire_tuple2_i32_tuple2_String_bool> new_box_autoadd_tuple2_i32_tuple2_String_bool_0() {
+    return _new_box_autoadd_tuple2_i32_tuple2_String_bool_0();
+  }
+
+  late final _new_box_autoadd_tuple2_i32_tuple2_String_bool_0Ptr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_tuple2_i32_tuple2_String_bool> Function()>>(
+          'new_box_autoadd_tuple2_i32_tuple2_String_bool_0');
+  late final _new_box_autoadd_tuple2_i32_tuple2_String_bool_0 = _new_box_autoadd_tuple2_i32_tuple2_String_bool_0Ptr
+      .asFunction<ffi.Pointer<wire_tuple2_i32_tuple2_String_bool> Function()>();
+
+  ffi.Pointer<wire_tuple3_i32_String_bool> new_box_autoadd_tuple3_i32_String_bool_0() {
+    return _new_box_autoadd_tuple3_i32_String_bool_0();
+  }
+
+  late final _new_box_autoadd_tuple3_i32_String_bool_0Ptr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_tuple3_i32_String_bool> Function()>>(
+          'new_box_autoadd_tuple3_i32_String_bool_0');
+  late final _new_box_autoadd_tuple3_i32_String_bool_0 =
+      _new_box_autoadd_tuple3_i32_String_bool_0Ptr.asFunction<ffi.Pointer<wire_tuple3_i32_String_bool> Function()>();
+
+  ffi.Pointer<ffi.Uint32> new_box_autoadd_u32_0(
+    int value,
+  ) {
+    return _new_box_autoadd_u32_0(
+      value,
+    );
+  }
+
+  late final _new_box_autoadd_u32_0Ptr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<ffi.Uint32> Function(ffi.Uint32)>>('new_box_autoadd_u32_0');
+  late final _new_box_autoadd_u32_0 = _new_box_autoadd_u32_0Ptr.asFunction<ffi.Pointer<ffi.Uint32> Function(int)>();
+
   ffi.Pointer<wire_UserId> new_box_autoadd_user_id_0() {
     return _new_box_autoadd_user_id_0();
   }
@@ -3957,6 +6580,15 @@ class FlutterRustBridgeExampleSingleBlockTestWire implements FlutterRustBridgeWi
       _lookup<ffi.NativeFunction<ffi.Pointer<ffi.Int32> Function(ffi.Int32)>>('new_box_weekdays_0');
   late final _new_box_weekdays_0 = _new_box_weekdays_0Ptr.asFunction<ffi.Pointer<ffi.Int32> Function(int)>();
 
+  ffi.Pointer<wire_btree_map_i32_String> new_btree_map_i32_String_0() {
+    return _new_btree_map_i32_String_0();
+  }
+
+  late final _new_btree_map_i32_String_0Ptr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_btree_map_i32_String> Function()>>('new_btree_map_i32_String_0');
+  late final _new_btree_map_i32_String_0 =
+      _new_btree_map_i32_String_0Ptr.asFunction<ffi.Pointer<wire_btree_map_i32_String> Function()>();
+
   ffi.Pointer<wire_float_32_list> new_float_32_list_0(
     int len,
   ) {
@@ -3977,9 +6609,37 @@ class FlutterRustBridgeExampleSingleBlockTestWire implements FlutterRustBridgeWi
     );
   }
 
-  late final _new_float_64_list_0Ptr =
-      _lookup<ffi.NativeFunction<ffi.Pointer<wire_float_64_list> Function(ffi.Int32)>>('new_float_64_list_0');
-  late final _new_float_64_list_0 = _new_float_64_list_0Ptr.asFunction<ffi.Pointer<wire_float_64_list> Function(int)>();
+  late final _new_float_64_list_0Ptr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_float_64_list> Function(ffi.Int32)>>('new_float_64_list_0');
+  late final _new_float_64_list_0 = _new_float_64_list_0Ptr.asFunction<ffi.Pointer<wire_float_64_list> Function(int)>();
+
+  ffi.Pointer<wire_hash_map_String_i32> new_hash_map_String_i32_0() {
+    return _new_hash_map_String_i32_0();
+  }
+
+  late final _new_hash_map_String_i32_0Ptr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_hash_map_String_i32> Function()>>('new_hash_map_String_i32_0');
+  late final _new_hash_map_String_i32_0 =
+      _new_hash_map_String_i32_0Ptr.asFunction<ffi.Pointer<wire_hash_map_String_i32> Function()>();
+
+  ffi.Pointer<wire_hash_map_colliding_key_u32> new_hash_map_colliding_key_u32_0() {
+    return _new_hash_map_colliding_key_u32_0();
+  }
+
+  late final _new_hash_map_colliding_key_u32_0Ptr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_hash_map_colliding_key_u32> Function()>>(
+          'new_hash_map_colliding_key_u32_0');
+  late final _new_hash_map_colliding_key_u32_0 =
+      _new_hash_map_colliding_key_u32_0Ptr.asFunction<ffi.Pointer<wire_hash_map_colliding_key_u32> Function()>();
+
+  ffi.Pointer<wire_hash_map_u32_attribute> new_hash_map_u32_attribute_0() {
+    return _new_hash_map_u32_attribute_0();
+  }
+
+  late final _new_hash_map_u32_attribute_0Ptr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_hash_map_u32_attribute> Function()>>('new_hash_map_u32_attribute_0');
+  late final _new_hash_map_u32_attribute_0 =
+      _new_hash_map_u32_attribute_0Ptr.asFunction<ffi.Pointer<wire_hash_map_u32_attribute> Function()>();
 
   ffi.Pointer<wire_int_32_list> new_int_32_list_0(
     int len,
@@ -4018,6 +6678,18 @@ class FlutterRustBridgeExampleSingleBlockTestWire implements FlutterRustBridgeWi
   late final _new_list_DartOpaque_0 =
       _new_list_DartOpaque_0Ptr.asFunction<ffi.Pointer<wire_list_DartOpaque> Function(int)>();
 
+  ffi.Pointer<wire_list_Decimal> new_list_Decimal_0(
+    int len,
+  ) {
+    return _new_list_Decimal_0(
+      len,
+    );
+  }
+
+  late final _new_list_Decimal_0Ptr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_list_Decimal> Function(ffi.Int32)>>('new_list_Decimal_0');
+  late final _new_list_Decimal_0 = _new_list_Decimal_0Ptr.asFunction<ffi.Pointer<wire_list_Decimal> Function(int)>();
+
   ffi.Pointer<wire_list_HideData> new_list_HideData_0(
     int len,
   ) {
@@ -4057,6 +6729,44 @@ class FlutterRustBridgeExampleSingleBlockTestWire implements FlutterRustBridgeWi
   late final _new_list_attribute_0 =
       _new_list_attribute_0Ptr.asFunction<ffi.Pointer<wire_list_attribute> Function(int)>();
 
+  ffi.Pointer<wire_list_char> new_list_char_0(
+    int len,
+  ) {
+    return _new_list_char_0(
+      len,
+    );
+  }
+
+  late final _new_list_char_0Ptr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_list_char> Function(ffi.Int32)>>('new_list_char_0');
+  late final _new_list_char_0 = _new_list_char_0Ptr.asFunction<ffi.Pointer<wire_list_char> Function(int)>();
+
+  ffi.Pointer<wire_list_colliding_key> new_list_colliding_key_0(
+    int len,
+  ) {
+    return _new_list_colliding_key_0(
+      len,
+    );
+  }
+
+  late final _new_list_colliding_key_0Ptr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_list_colliding_key> Function(ffi.Int32)>>('new_list_colliding_key_0');
+  late final _new_list_colliding_key_0 =
+      _new_list_colliding_key_0Ptr.asFunction<ffi.Pointer<wire_list_colliding_key> Function(int)>();
+
+  ffi.Pointer<wire_list_int_32_list> new_list_int_32_list_0(
+    int len,
+  ) {
+    return _new_list_int_32_list_0(
+      len,
+    );
+  }
+
+  late final _new_list_int_32_list_0Ptr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_list_int_32_list> Function(ffi.Int32)>>('new_list_int_32_list_0');
+  late final _new_list_int_32_list_0 =
+      _new_list_int_32_list_0Ptr.asFunction<ffi.Pointer<wire_list_int_32_list> Function(int)>();
+
   ffi.Pointer<wire_list_my_size> new_list_my_size_0(
     int len,
   ) {
@@ -4108,6 +6818,54 @@ class FlutterRustBridgeExampleSingleBlockTestWire implements FlutterRustBridgeWi
       _lookup<ffi.NativeFunction<ffi.Pointer<wire_list_test_id> Function(ffi.Int32)>>('new_list_test_id_0');
   late final _new_list_test_id_0 = _new_list_test_id_0Ptr.asFunction<ffi.Pointer<wire_list_test_id> Function(int)>();
 
+  ffi.Pointer<wire_list_weekdays> new_list_weekdays_0(
+    int len,
+  ) {
+    return _new_list_weekdays_0(
+      len,
+    );
+  }
+
+  late final _new_list_weekdays_0Ptr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_list_weekdays> Function(ffi.Int32)>>('new_list_weekdays_0');
+  late final _new_list_weekdays_0 = _new_list_weekdays_0Ptr.asFunction<ffi.Pointer<wire_list_weekdays> Function(int)>();
+
+  ffi.Pointer<wire_uint_16_list> new_uint_16_list_0(
+    int len,
+  ) {
+    return _new_uint_16_list_0(
+      len,
+    );
+  }
+
+  late final _new_uint_16_list_0Ptr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_uint_16_list> Function(ffi.Int32)>>('new_uint_16_list_0');
+  late final _new_uint_16_list_0 = _new_uint_16_list_0Ptr.asFunction<ffi.Pointer<wire_uint_16_list> Function(int)>();
+
+  ffi.Pointer<wire_uint_32_list> new_uint_32_list_0(
+    int len,
+  ) {
+    return _new_uint_32_list_0(
+      len,
+    );
+  }
+
+  late final _new_uint_32_list_0Ptr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_uint_32_list> Function(ffi.Int32)>>('new_uint_32_list_0');
+  late final _new_uint_32_list_0 = _new_uint_32_list_0Ptr.asFunction<ffi.Pointer<wire_uint_32_list> Function(int)>();
+
+  ffi.Pointer<wire_uint_64_list> new_uint_64_list_0(
+    int len,
+  ) {
+    return _new_uint_64_list_0(
+      len,
+    );
+  }
+
+  late final _new_uint_64_list_0Ptr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_uint_64_list> Function(ffi.Int32)>>('new_uint_64_list_0');
+  late final _new_uint_64_list_0 = _new_uint_64_list_0Ptr.asFunction<ffi.Pointer<wire_uint_64_list> Function(int)>();
+
   ffi.Pointer<wire_uint_8_list> new_uint_8_list_0(
     int len,
   ) {
@@ -4146,6 +6904,138 @@ class FlutterRustBridgeExampleSingleBlockTestWire implements FlutterRustBridgeWi
   late final _share_opaque_BoxDartDebug =
       _share_opaque_BoxDartDebugPtr.asFunction<ffi.Pointer<ffi.Void> Function(ffi.Pointer<ffi.Void>)>();
 
+  ffi.Pointer<ffi.Void> send_opaque_BoxDartDebug(
+    ffi.Pointer<ffi.Void> ptr,
+  ) {
+    return _send_opaque_BoxDartDebug(
+      ptr,
+    );
+  }
+
+  late final _send_opaque_BoxDartDebugPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<ffi.Void> Function(ffi.Pointer<ffi.Void>)>>('send_opaque_BoxDartDebug');
+  late final _send_opaque_BoxDartDebug =
+      _send_opaque_BoxDartDebugPtr.asFunction<ffi.Pointer<ffi.Void> Function(ffi.Pointer<ffi.Void>)>();
+
+  void drop_opaque_Counter(
+    ffi.Pointer<ffi.Void> ptr,
+  ) {
+    return _drop_opaque_Counter(
+      ptr,
+    );
+  }
+
+  late final _drop_opaque_CounterPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>('drop_opaque_Counter');
+  late final _drop_opaque_Counter = _drop_opaque_CounterPtr.asFunction<void Function(ffi.Pointer<ffi.Void>)>();
+
+  ffi.Pointer<ffi.Void> share_opaque_Counter(
+    ffi.Pointer<ffi.Void> ptr,
+  ) {
+    return _share_opaque_Counter(
+      ptr,
+    );
+  }
+
+  late final _share_opaque_CounterPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<ffi.Void> Function(ffi.Pointer<ffi.Void>)>>('share_opaque_Counter');
+  late final _share_opaque_Counter =
+      _share_opaque_CounterPtr.asFunction<ffi.Pointer<ffi.Void> Function(ffi.Pointer<ffi.Void>)>();
+
+  ffi.Pointer<ffi.Void> send_opaque_Counter(
+    ffi.Pointer<ffi.Void> ptr,
+  ) {
+    return _send_opaque_Counter(
+      ptr,
+    );
+  }
+
+  late final _send_opaque_CounterPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<ffi.Void> Function(ffi.Pointer<ffi.Void>)>>('send_opaque_Counter');
+  late final _send_opaque_Counter =
+      _send_opaque_CounterPtr.asFunction<ffi.Pointer<ffi.Void> Function(ffi.Pointer<ffi.Void>)>();
+
+  void drop_opaque_DartIteratorI32(
+    ffi.Pointer<ffi.Void> ptr,
+  ) {
+    return _drop_opaque_DartIteratorI32(
+      ptr,
+    );
+  }
+
+  late final _drop_opaque_DartIteratorI32Ptr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>('drop_opaque_DartIteratorI32');
+  late final _drop_opaque_DartIteratorI32 =
+      _drop_opaque_DartIteratorI32Ptr.asFunction<void Function(ffi.Pointer<ffi.Void>)>();
+
+  ffi.Pointer<ffi.Void> share_opaque_DartIteratorI32(
+    ffi.Pointer<ffi.Void> ptr,
+  ) {
+    return _share_opaque_DartIteratorI32(
+      ptr,
+    );
+  }
+
+  late final _share_opaque_DartIteratorI32Ptr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<ffi.Void> Function(ffi.Pointer<ffi.Void>)>>(
+          'share_opaque_DartIteratorI32');
+  late final _share_opaque_DartIteratorI32 =
+      _share_opaque_DartIteratorI32Ptr.asFunction<ffi.Pointer<ffi.Void> Function(ffi.Pointer<ffi.Void>)>();
+
+  ffi.Pointer<ffi.Void> send_opaque_DartIteratorI32(
+    ffi.Pointer<ffi.Void> ptr,
+  ) {
+    return _send_opaque_DartIteratorI32(
+      ptr,
+    );
+  }
+
+  late final _send_opaque_DartIteratorI32Ptr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<ffi.Void> Function(ffi.Pointer<ffi.Void>)>>('send_opaque_DartIteratorI32');
+  late final _send_opaque_DartIteratorI32 =
+      _send_opaque_DartIteratorI32Ptr.asFunction<ffi.Pointer<ffi.Void> Function(ffi.Pointer<ffi.Void>)>();
+
+  void drop_opaque_DartIteratorString(
+    ffi.Pointer<ffi.Void> ptr,
+  ) {
+    return _drop_opaque_DartIteratorString(
+      ptr,
+    );
+  }
+
+  late final _drop_opaque_DartIteratorStringPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>('drop_opaque_DartIteratorString');
+  late final _drop_opaque_DartIteratorString =
+      _drop_opaque_DartIteratorStringPtr.asFunction<void Function(ffi.Pointer<ffi.Void>)>();
+
+  ffi.Pointer<ffi.Void> share_opaque_DartIteratorString(
+    ffi.Pointer<ffi.Void> ptr,
+  ) {
+    return _share_opaque_DartIteratorString(
+      ptr,
+    );
+  }
+
+  late final _share_opaque_DartIteratorStringPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<ffi.Void> Function(ffi.Pointer<ffi.Void>)>>(
+          'share_opaque_DartIteratorString');
+  late final _share_opaque_DartIteratorString =
+      _share_opaque_DartIteratorStringPtr.asFunction<ffi.Pointer<ffi.Void> Function(ffi.Pointer<ffi.Void>)>();
+
+  ffi.Pointer<ffi.Void> send_opaque_DartIteratorString(
+    ffi.Pointer<ffi.Void> ptr,
+  ) {
+    return _send_opaque_DartIteratorString(
+      ptr,
+    );
+  }
+
+  late final _send_opaque_DartIteratorStringPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<ffi.Void> Function(ffi.Pointer<ffi.Void>)>>(
+          'send_opaque_DartIteratorString');
+  late final _send_opaque_DartIteratorString =
+      _send_opaque_DartIteratorStringPtr.asFunction<ffi.Pointer<ffi.Void> Function(ffi.Pointer<ffi.Void>)>();
+
   void drop_opaque_FrbOpaqueReturn(
     ffi.Pointer<ffi.Void> ptr,
   ) {
@@ -4173,6 +7063,19 @@ class FlutterRustBridgeExampleSingleBlockTestWire implements FlutterRustBridgeWi
   late final _share_opaque_FrbOpaqueReturn =
       _share_opaque_FrbOpaqueReturnPtr.asFunction<ffi.Pointer<ffi.Void> Function(ffi.Pointer<ffi.Void>)>();
 
+  ffi.Pointer<ffi.Void> send_opaque_FrbOpaqueReturn(
+    ffi.Pointer<ffi.Void> ptr,
+  ) {
+    return _send_opaque_FrbOpaqueReturn(
+      ptr,
+    );
+  }
+
+  late final _send_opaque_FrbOpaqueReturnPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<ffi.Void> Function(ffi.Pointer<ffi.Void>)>>('send_opaque_FrbOpaqueReturn');
+  late final _send_opaque_FrbOpaqueReturn =
+      _send_opaque_FrbOpaqueReturnPtr.asFunction<ffi.Pointer<ffi.Void> Function(ffi.Pointer<ffi.Void>)>();
+
   void drop_opaque_FrbOpaqueSyncReturn(
     ffi.Pointer<ffi.Void> ptr,
   ) {
@@ -4200,6 +7103,58 @@ class FlutterRustBridgeExampleSingleBlockTestWire implements FlutterRustBridgeWi
   late final _share_opaque_FrbOpaqueSyncReturn =
       _share_opaque_FrbOpaqueSyncReturnPtr.asFunction<ffi.Pointer<ffi.Void> Function(ffi.Pointer<ffi.Void>)>();
 
+  ffi.Pointer<ffi.Void> send_opaque_FrbOpaqueSyncReturn(
+    ffi.Pointer<ffi.Void> ptr,
+  ) {
+    return _send_opaque_FrbOpaqueSyncReturn(
+      ptr,
+    );
+  }
+
+  late final _send_opaque_FrbOpaqueSyncReturnPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<ffi.Void> Function(ffi.Pointer<ffi.Void>)>>(
+          'send_opaque_FrbOpaqueSyncReturn');
+  late final _send_opaque_FrbOpaqueSyncReturn =
+      _send_opaque_FrbOpaqueSyncReturnPtr.asFunction<ffi.Pointer<ffi.Void> Function(ffi.Pointer<ffi.Void>)>();
+
+  void drop_opaque_Greeter(
+    ffi.Pointer<ffi.Void> ptr,
+  ) {
+    return _drop_opaque_Greeter(
+      ptr,
+    );
+  }
+
+  late final _drop_opaque_GreeterPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>('drop_opaque_Greeter');
+  late final _drop_opaque_Greeter = _drop_opaque_GreeterPtr.asFunction<void Function(ffi.Pointer<ffi.Void>)>();
+
+  ffi.Pointer<ffi.Void> share_opaque_Greeter(
+    ffi.Pointer<ffi.Void> ptr,
+  ) {
+    return _share_opaque_Greeter(
+      ptr,
+    );
+  }
+
+  late final _share_opaque_GreeterPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<ffi.Void> Function(ffi.Pointer<ffi.Void>)>>('share_opaque_Greeter');
+  late final _share_opaque_Greeter =
+      _share_opaque_GreeterPtr.asFunction<ffi.Pointer<ffi.Void> Function(ffi.Pointer<ffi.Void>)>();
+
+  ffi.Pointer<ffi.Void> send_opaque_Greeter(
+    ffi.Pointer<ffi.Void> ptr,
+  ) {
+    return _send_opaque_Greeter(
+      ptr,
+    );
+  }
+
+  late final _send_opaque_GreeterPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<ffi.Void> Function(ffi.Pointer<ffi.Void>)>>('send_opaque_Greeter');
+  late final _send_opaque_Greeter =
+      _send_opaque_GreeterPtr.asFunction<ffi.Pointer<ffi.Void> Function(ffi.Pointer<ffi.Void>)>();
+
   void drop_opaque_HideData(
     ffi.Pointer<ffi.Void> ptr,
   ) {
@@ -4225,6 +7180,19 @@ class FlutterRustBridgeExampleSingleBlockTestWire implements FlutterRustBridgeWi
   late final _share_opaque_HideData =
       _share_opaque_HideDataPtr.asFunction<ffi.Pointer<ffi.Void> Function(ffi.Pointer<ffi.Void>)>();
 
+  ffi.Pointer<ffi.Void> send_opaque_HideData(
+    ffi.Pointer<ffi.Void> ptr,
+  ) {
+    return _send_opaque_HideData(
+      ptr,
+    );
+  }
+
+  late final _send_opaque_HideDataPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<ffi.Void> Function(ffi.Pointer<ffi.Void>)>>('send_opaque_HideData');
+  late final _send_opaque_HideData =
+      _send_opaque_HideDataPtr.asFunction<ffi.Pointer<ffi.Void> Function(ffi.Pointer<ffi.Void>)>();
+
   void drop_opaque_I32(
     ffi.Pointer<ffi.Void> ptr,
   ) {
@@ -4250,6 +7218,56 @@ class FlutterRustBridgeExampleSingleBlockTestWire implements FlutterRustBridgeWi
   late final _share_opaque_I32 =
       _share_opaque_I32Ptr.asFunction<ffi.Pointer<ffi.Void> Function(ffi.Pointer<ffi.Void>)>();
 
+  ffi.Pointer<ffi.Void> send_opaque_I32(
+    ffi.Pointer<ffi.Void> ptr,
+  ) {
+    return _send_opaque_I32(
+      ptr,
+    );
+  }
+
+  late final _send_opaque_I32Ptr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<ffi.Void> Function(ffi.Pointer<ffi.Void>)>>('send_opaque_I32');
+  late final _send_opaque_I32 = _send_opaque_I32Ptr.asFunction<ffi.Pointer<ffi.Void> Function(ffi.Pointer<ffi.Void>)>();
+
+  void drop_opaque_Instant(
+    ffi.Pointer<ffi.Void> ptr,
+  ) {
+    return _drop_opaque_Instant(
+      ptr,
+    );
+  }
+
+  late final _drop_opaque_InstantPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>('drop_opaque_Instant');
+  late final _drop_opaque_Instant = _drop_opaque_InstantPtr.asFunction<void Function(ffi.Pointer<ffi.Void>)>();
+
+  ffi.Pointer<ffi.Void> share_opaque_Instant(
+    ffi.Pointer<ffi.Void> ptr,
+  ) {
+    return _share_opaque_Instant(
+      ptr,
+    );
+  }
+
+  late final _share_opaque_InstantPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<ffi.Void> Function(ffi.Pointer<ffi.Void>)>>('share_opaque_Instant');
+  late final _share_opaque_Instant =
+      _share_opaque_InstantPtr.asFunction<ffi.Pointer<ffi.Void> Function(ffi.Pointer<ffi.Void>)>();
+
+  ffi.Pointer<ffi.Void> send_opaque_Instant(
+    ffi.Pointer<ffi.Void> ptr,
+  ) {
+    return _send_opaque_Instant(
+      ptr,
+    );
+  }
+
+  late final _send_opaque_InstantPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<ffi.Void> Function(ffi.Pointer<ffi.Void>)>>('send_opaque_Instant');
+  late final _send_opaque_Instant =
+      _send_opaque_InstantPtr.asFunction<ffi.Pointer<ffi.Void> Function(ffi.Pointer<ffi.Void>)>();
+
   void drop_opaque_MutexHideData(
     ffi.Pointer<ffi.Void> ptr,
   ) {
@@ -4276,32 +7294,96 @@ class FlutterRustBridgeExampleSingleBlockTestWire implements FlutterRustBridgeWi
   late final _share_opaque_MutexHideData =
       _share_opaque_MutexHideDataPtr.asFunction<ffi.Pointer<ffi.Void> Function(ffi.Pointer<ffi.Void>)>();
 
-  void drop_opaque_NonSendHideData(
+  ffi.Pointer<ffi.Void> send_opaque_MutexHideData(
+    ffi.Pointer<ffi.Void> ptr,
+  ) {
+    return _send_opaque_MutexHideData(
+      ptr,
+    );
+  }
+
+  late final _send_opaque_MutexHideDataPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<ffi.Void> Function(ffi.Pointer<ffi.Void>)>>('send_opaque_MutexHideData');
+  late final _send_opaque_MutexHideData =
+      _send_opaque_MutexHideDataPtr.asFunction<ffi.Pointer<ffi.Void> Function(ffi.Pointer<ffi.Void>)>();
+
+  void drop_opaque_NonSendHideData(
+    ffi.Pointer<ffi.Void> ptr,
+  ) {
+    return _drop_opaque_NonSendHideData(
+      ptr,
+    );
+  }
+
+  late final _drop_opaque_NonSendHideDataPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>('drop_opaque_NonSendHideData');
+  late final _drop_opaque_NonSendHideData =
+      _drop_opaque_NonSendHideDataPtr.asFunction<void Function(ffi.Pointer<ffi.Void>)>();
+
+  ffi.Pointer<ffi.Void> share_opaque_NonSendHideData(
+    ffi.Pointer<ffi.Void> ptr,
+  ) {
+    return _share_opaque_NonSendHideData(
+      ptr,
+    );
+  }
+
+  late final _share_opaque_NonSendHideDataPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<ffi.Void> Function(ffi.Pointer<ffi.Void>)>>(
+          'share_opaque_NonSendHideData');
+  late final _share_opaque_NonSendHideData =
+      _share_opaque_NonSendHideDataPtr.asFunction<ffi.Pointer<ffi.Void> Function(ffi.Pointer<ffi.Void>)>();
+
+  ffi.Pointer<ffi.Void> send_opaque_NonSendHideData(
+    ffi.Pointer<ffi.Void> ptr,
+  ) {
+    return _send_opaque_NonSendHideData(
+      ptr,
+    );
+  }
+
+  late final _send_opaque_NonSendHideDataPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<ffi.Void> Function(ffi.Pointer<ffi.Void>)>>('send_opaque_NonSendHideData');
+  late final _send_opaque_NonSendHideData =
+      _send_opaque_NonSendHideDataPtr.asFunction<ffi.Pointer<ffi.Void> Function(ffi.Pointer<ffi.Void>)>();
+
+  void drop_opaque_Rooms(
+    ffi.Pointer<ffi.Void> ptr,
+  ) {
+    return _drop_opaque_Rooms(
+      ptr,
+    );
+  }
+
+  late final _drop_opaque_RoomsPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>('drop_opaque_Rooms');
+  late final _drop_opaque_Rooms = _drop_opaque_RoomsPtr.asFunction<void Function(ffi.Pointer<ffi.Void>)>();
+
+  ffi.Pointer<ffi.Void> share_opaque_Rooms(
     ffi.Pointer<ffi.Void> ptr,
   ) {
-    return _drop_opaque_NonSendHideData(
+    return _share_opaque_Rooms(
       ptr,
     );
   }
 
-  late final _drop_opaque_NonSendHideDataPtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>('drop_opaque_NonSendHideData');
-  late final _drop_opaque_NonSendHideData =
-      _drop_opaque_NonSendHideDataPtr.asFunction<void Function(ffi.Pointer<ffi.Void>)>();
+  late final _share_opaque_RoomsPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<ffi.Void> Function(ffi.Pointer<ffi.Void>)>>('share_opaque_Rooms');
+  late final _share_opaque_Rooms =
+      _share_opaque_RoomsPtr.asFunction<ffi.Pointer<ffi.Void> Function(ffi.Pointer<ffi.Void>)>();
 
-  ffi.Pointer<ffi.Void> share_opaque_NonSendHideData(
+  ffi.Pointer<ffi.Void> send_opaque_Rooms(
     ffi.Pointer<ffi.Void> ptr,
   ) {
-    return _share_opaque_NonSendHideData(
+    return _send_opaque_Rooms(
       ptr,
     );
   }
 
-  late final _share_opaque_NonSendHideDataPtr =
-      _lookup<ffi.NativeFunction<ffi.Pointer<ffi.Void> Function(ffi.Pointer<ffi.Void>)>>(
-          'share_opaque_NonSendHideData');
-  late final _share_opaque_NonSendHideData =
-      _share_opaque_NonSendHideDataPtr.asFunction<ffi.Pointer<ffi.Void> Function(ffi.Pointer<ffi.Void>)>();
+  late final _send_opaque_RoomsPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<ffi.Void> Function(ffi.Pointer<ffi.Void>)>>('send_opaque_Rooms');
+  late final _send_opaque_Rooms =
+      _send_opaque_RoomsPtr.asFunction<ffi.Pointer<ffi.Void> Function(ffi.Pointer<ffi.Void>)>();
 
   void drop_opaque_RwLockHideData(
     ffi.Pointer<ffi.Void> ptr,
@@ -4329,6 +7411,77 @@ class FlutterRustBridgeExampleSingleBlockTestWire implements FlutterRustBridgeWi
   late final _share_opaque_RwLockHideData =
       _share_opaque_RwLockHideDataPtr.asFunction<ffi.Pointer<ffi.Void> Function(ffi.Pointer<ffi.Void>)>();
 
+  ffi.Pointer<ffi.Void> send_opaque_RwLockHideData(
+    ffi.Pointer<ffi.Void> ptr,
+  ) {
+    return _send_opaque_RwLockHideData(
+      ptr,
+    );
+  }
+
+  late final _send_opaque_RwLockHideDataPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<ffi.Void> Function(ffi.Pointer<ffi.Void>)>>('send_opaque_RwLockHideData');
+  late final _send_opaque_RwLockHideData =
+      _send_opaque_RwLockHideDataPtr.asFunction<ffi.Pointer<ffi.Void> Function(ffi.Pointer<ffi.Void>)>();
+
+  void drop_opaque_WeakCounter(
+    ffi.Pointer<ffi.Void> ptr,
+  ) {
+    return _drop_opaque_WeakCounter(
+      ptr,
+    );
+  }
+
+  late final _drop_opaque_WeakCounterPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>('drop_opaque_WeakCounter');
+  late final _drop_opaque_WeakCounter = _drop_opaque_WeakCounterPtr.asFunction<void Function(ffi.Pointer<ffi.Void>)>();
+
+  ffi.Pointer<ffi.Void> share_opaque_WeakCounter(
+    ffi.Pointer<ffi.Void> ptr,
+  ) {
+    return _share_opaque_WeakCounter(
+      ptr,
+    );
+  }
+
+  late final _share_opaque_WeakCounterPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<ffi.Void> Function(ffi.Pointer<ffi.Void>)>>('share_opaque_WeakCounter');
+  late final _share_opaque_WeakCounter =
+      _share_opaque_WeakCounterPtr.asFunction<ffi.Pointer<ffi.Void> Function(ffi.Pointer<ffi.Void>)>();
+
+  ffi.Pointer<ffi.Void> send_opaque_WeakCounter(
+    ffi.Pointer<ffi.Void> ptr,
+  ) {
+    return _send_opaque_WeakCounter(
+      ptr,
+    );
+  }
+
+  late final _send_opaque_WeakCounterPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<ffi.Void> Function(ffi.Pointer<ffi.Void>)>>('send_opaque_WeakCounter');
+  late final _send_opaque_WeakCounter =
+      _send_opaque_WeakCounterPtr.asFunction<ffi.Pointer<ffi.Void> Function(ffi.Pointer<ffi.Void>)>();
+
+  ffi.Pointer<ApplicationMessageKind> inflate_ApplicationMessage_DisplayMessage() {
+    return _inflate_ApplicationMessage_DisplayMessage();
+  }
+
+  late final _inflate_ApplicationMessage_DisplayMessagePtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<ApplicationMessageKind> Function()>>(
+          'inflate_ApplicationMessage_DisplayMessage');
+  late final _inflate_ApplicationMessage_DisplayMessage =
+      _inflate_ApplicationMessage_DisplayMessagePtr.asFunction<ffi.Pointer<ApplicationMessageKind> Function()>();
+
+  ffi.Pointer<ApplicationMessageKind> inflate_ApplicationMessage_RenderPixel() {
+    return _inflate_ApplicationMessage_RenderPixel();
+  }
+
+  late final _inflate_ApplicationMessage_RenderPixelPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<ApplicationMessageKind> Function()>>(
+          'inflate_ApplicationMessage_RenderPixel');
+  late final _inflate_ApplicationMessage_RenderPixel =
+      _inflate_ApplicationMessage_RenderPixelPtr.asFunction<ffi.Pointer<ApplicationMessageKind> Function()>();
+
   ffi.Pointer<DistanceKind> inflate_Distance_Map() {
     return _inflate_Distance_Map();
   }
@@ -4484,6 +7637,13 @@ class FlutterRustBridgeExampleSingleBlockTestWire implements FlutterRustBridgeWi
 
 class _Dart_Handle extends ffi.Opaque {}
 
+class wire_float_32_list extends ffi.Struct {
+  external ffi.Pointer<ffi.Float> ptr;
+
+  @ffi.Int32()
+  external int len;
+}
+
 class wire_uint_8_list extends ffi.Struct {
   external ffi.Pointer<ffi.Uint8> ptr;
 
@@ -4491,6 +7651,31 @@ class wire_uint_8_list extends ffi.Struct {
   external int len;
 }
 
+class wire_list_char extends ffi.Struct {
+  external ffi.Pointer<ffi.Uint32> ptr;
+
+  @ffi.Int32()
+  external int len;
+}
+
+class wire_Instant extends ffi.Struct {
+  external ffi.Pointer<ffi.Void> ptr;
+}
+
+class wire_uint_32_list extends ffi.Struct {
+  external ffi.Pointer<ffi.Uint32> ptr;
+
+  @ffi.Int32()
+  external int len;
+}
+
+class wire_float_64_list extends ffi.Struct {
+  external ffi.Pointer<ffi.Double> ptr;
+
+  @ffi.Int32()
+  external int len;
+}
+
 class wire_MySize extends ffi.Struct {
   @ffi.Int32()
   external int width;
@@ -4499,11 +7684,55 @@ class wire_MySize extends ffi.Struct {
   external int height;
 }
 
+class wire_StringList extends ffi.Struct {
+  external ffi.Pointer<ffi.Pointer<wire_uint_8_list>> ptr;
+
+  @ffi.Int32()
+  external int len;
+}
+
+class wire_uint_16_list extends ffi.Struct {
+  external ffi.Pointer<ffi.Uint16> ptr;
+
+  @ffi.Int32()
+  external int len;
+}
+
+class wire_Frame extends ffi.Struct {
+  @ffi.Uint32()
+  external int index;
+
+  external ffi.Pointer<wire_uint_8_list> pixels;
+
+  external ffi.Pointer<wire_float_32_list> depths;
+}
+
+class wire_uint_64_list extends ffi.Struct {
+  external ffi.Pointer<ffi.Uint64> ptr;
+
+  @ffi.Int32()
+  external int len;
+}
+
 class wire_NewTypeInt extends ffi.Struct {
   @ffi.Int64()
   external int field0;
 }
 
+class wire_int_32_list extends ffi.Struct {
+  external ffi.Pointer<ffi.Int32> ptr;
+
+  @ffi.Int32()
+  external int len;
+}
+
+class wire_PairI32 extends ffi.Struct {
+  @ffi.Int32()
+  external int first;
+
+  external ffi.Pointer<wire_int_32_list> second;
+}
+
 class wire_list_my_size extends ffi.Struct {
   external ffi.Pointer<wire_MySize> ptr;
 
@@ -4511,8 +7740,8 @@ class wire_list_my_size extends ffi.Struct {
   external int len;
 }
 
-class wire_StringList extends ffi.Struct {
-  external ffi.Pointer<ffi.Pointer<wire_uint_8_list>> ptr;
+class wire_list_int_32_list extends ffi.Struct {
+  external ffi.Pointer<ffi.Pointer<wire_int_32_list>> ptr;
 
   @ffi.Int32()
   external int len;
@@ -4551,27 +7780,6 @@ class wire_int_8_list extends ffi.Struct {
   external int len;
 }
 
-class wire_int_32_list extends ffi.Struct {
-  external ffi.Pointer<ffi.Int32> ptr;
-
-  @ffi.Int32()
-  external int len;
-}
-
-class wire_float_32_list extends ffi.Struct {
-  external ffi.Pointer<ffi.Float> ptr;
-
-  @ffi.Int32()
-  external int len;
-}
-
-class wire_float_64_list extends ffi.Struct {
-  external ffi.Pointer<ffi.Double> ptr;
-
-  @ffi.Int32()
-  external int len;
-}
-
 class wire_Attribute extends ffi.Struct {
   external ffi.Pointer<wire_uint_8_list> key;
 
@@ -4726,6 +7934,35 @@ class wire_ApplicationSettings extends ffi.Struct {
   external ffi.Pointer<wire_ApplicationEnv> env_optional;
 }
 
+class wire_ApplicationMessage_DisplayMessage extends ffi.Struct {
+  external ffi.Pointer<wire_uint_8_list> field0;
+}
+
+class wire_ApplicationMessage_RenderPixel extends ffi.Struct {
+  @ffi.Int32()
+  external int x;
+
+  @ffi.Int32()
+  external int y;
+}
+
+class wire_ApplicationMessage_Exit extends ffi.Opaque {}
+
+class ApplicationMessageKind extends ffi.Union {
+  external ffi.Pointer<wire_ApplicationMessage_DisplayMessage> DisplayMessage;
+
+  external ffi.Pointer<wire_ApplicationMessage_RenderPixel> RenderPixel;
+
+  external ffi.Pointer<wire_ApplicationMessage_Exit> Exit;
+}
+
+class wire_ApplicationMessage extends ffi.Struct {
+  @ffi.Int32()
+  external int tag;
+
+  external ffi.Pointer<ApplicationMessageKind> kind;
+}
+
 class wire_Numbers extends ffi.Struct {
   external ffi.Pointer<wire_int_32_list> field0;
 }
@@ -4739,6 +7976,74 @@ class wire_UserId extends ffi.Struct {
   external int value;
 }
 
+class wire_Metadata extends ffi.Struct {
+  @ffi.Uint32()
+  external int id;
+
+  external ffi.Pointer<wire_uint_8_list> created_by;
+}
+
+class wire_Document extends ffi.Struct {
+  external wire_Metadata metadata;
+
+  external ffi.Pointer<wire_uint_8_list> title;
+}
+
+class wire_Progress extends ffi.Struct {
+  @ffi.Uint8()
+  external int percentage;
+}
+
+class wire_ProductId extends ffi.Struct {
+  @ffi.Uint32()
+  external int field0;
+}
+
+class wire_GridCell extends ffi.Struct {
+  @ffi.Int32()
+  external int row;
+
+  @ffi.Int32()
+  external int column;
+}
+
+class wire_Money extends ffi.Struct {
+  @ffi.Int64()
+  external int cents;
+}
+
+class wire_Session extends ffi.Struct {
+  external ffi.Pointer<wire_uint_8_list> user;
+}
+
+class wire_Sample extends ffi.Struct {
+  @ffi.Uint32()
+  external int index;
+
+  @ffi.Double()
+  external double x;
+
+  @ffi.Float()
+  external double y;
+
+  @ffi.Bool()
+  external bool valid;
+
+  @ffi.Int8()
+  external int level;
+}
+
+class wire_Tag extends ffi.Struct {
+  external ffi.Pointer<wire_uint_8_list> name;
+
+  external ffi.Pointer<ffi.Uint32> color;
+
+  external ffi.Pointer<wire_StringList> aliases;
+
+  @ffi.Int32()
+  external int day;
+}
+
 class wire_Speed_Unknown extends ffi.Opaque {}
 
 class wire_Speed_GPS extends ffi.Struct {
@@ -4820,6 +8125,13 @@ class wire_FeatureUuid extends ffi.Struct {
   external ffi.Pointer<wire_uint_8_list> many;
 }
 
+class wire_list_Decimal extends ffi.Struct {
+  external ffi.Pointer<ffi.Pointer<wire_uint_8_list>> ptr;
+
+  @ffi.Int32()
+  external int len;
+}
+
 class wire_MessageId extends ffi.Struct {
   external ffi.Pointer<wire_uint_8_list> field0;
 }
@@ -4962,8 +8274,82 @@ class wire_EnumDartOpaque extends ffi.Struct {
   external ffi.Pointer<EnumDartOpaqueKind> kind;
 }
 
+class wire_Counter extends ffi.Struct {
+  external ffi.Pointer<ffi.Void> ptr;
+}
+
+class wire_WeakCounter extends ffi.Struct {
+  external ffi.Pointer<ffi.Void> ptr;
+}
+
 class wire_Empty extends ffi.Opaque {}
 
+class wire_hash_map_String_i32 extends ffi.Struct {
+  external ffi.Pointer<wire_StringList> keys;
+
+  external ffi.Pointer<wire_int_32_list> values;
+}
+
+class wire_hash_map_u32_attribute extends ffi.Struct {
+  external ffi.Pointer<wire_uint_32_list> keys;
+
+  external ffi.Pointer<wire_list_attribute> values;
+}
+
+class wire_list_weekdays extends ffi.Struct {
+  external ffi.Pointer<ffi.Int32> ptr;
+
+  @ffi.Int32()
+  external int len;
+}
+
+class wire_CollidingKey extends ffi.Struct {
+  external ffi.Pointer<wire_uint_8_list> field0;
+}
+
+class wire_list_colliding_key extends ffi.Struct {
+  external ffi.Pointer<wire_CollidingKey> ptr;
+
+  @ffi.Int32()
+  external int len;
+}
+
+class wire_hash_map_colliding_key_u32 extends ffi.Struct {
+  external ffi.Pointer<wire_list_colliding_key> keys;
+
+  external ffi.Pointer<wire_uint_32_list> values;
+}
+
+class wire_btree_map_i32_String extends ffi.Struct {
+  external ffi.Pointer<wire_int_32_list> keys;
+
+  external ffi.Pointer<wire_StringList> values;
+}
+
+class wire_tuple3_i32_String_bool extends ffi.Struct {
+  @ffi.Int32()
+  external int field0;
+
+  external ffi.Pointer<wire_uint_8_list> field1;
+
+  @ffi.Bool()
+  external bool field2;
+}
+
+class wire_tuple2_String_bool extends ffi.Struct {
+  external ffi.Pointer<wire_uint_8_list> field0;
+
+  @ffi.Bool()
+  external bool field1;
+}
+
+class wire_tuple2_i32_tuple2_String_bool extends ffi.Struct {
+  @ffi.Int32()
+  external int field0;
+
+  external wire_tuple2_String_bool field1;
+}
+
 class wire_SumWith extends ffi.Struct {
   @ffi.Uint32()
   external int x;
@@ -4973,5 +8359,21 @@ class wire_ConcatenateWith extends ffi.Struct {
   external ffi.Pointer<wire_uint_8_list> a;
 }
 
+class wire_Rooms extends ffi.Struct {
+  external ffi.Pointer<ffi.Void> ptr;
+}
+
+class wire_Greeter extends ffi.Struct {
+  external ffi.Pointer<ffi.Void> ptr;
+}
+
+class wire_DartIteratorString extends ffi.Struct {
+  external ffi.Pointer<ffi.Void> ptr;
+}
+
+class wire_DartIteratorI32 extends ffi.Struct {
+  external ffi.Pointer<ffi.Void> ptr;
+}
+
 typedef DartPostCObjectFnType = ffi.Pointer<ffi.NativeFunction<ffi.Bool Function(DartPort, ffi.Pointer<ffi.Void>)>>;
 typedef DartPort = ffi.Int64;
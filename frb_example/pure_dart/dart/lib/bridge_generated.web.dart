@@ -8,6 +8,7 @@ import 'dart:async';
 import 'package:meta/meta.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge.dart';
 import 'package:uuid/uuid.dart';
+import 'package:decimal/decimal.dart';
 import 'bridge_generated.dart';
 export 'bridge_generated.dart';
 
@@ -21,16 +22,31 @@ class FlutterRustBridgeExampleSingleBlockTestPlatform
 
 // Section: api2wire
 
+  @protected
+  Object api2wire_Arc_HideData(HideData raw) {
+    return api2wire_HideData(raw);
+  }
+
   @protected
   Object api2wire_BoxDartDebug(BoxDartDebug raw) {
     return raw.shareOrMove();
   }
 
+  @protected
+  Uint8List api2wire_Bytes(Uint8List raw) {
+    return api2wire_uint_8_list(raw);
+  }
+
   @protected
   Object api2wire_Chrono_Duration(Duration raw) {
     return api2wire_i64(raw.inMilliseconds);
   }
 
+  @protected
+  Object api2wire_Chrono_FixedOffset(DateTime raw) {
+    return api2wire_i64(raw.millisecondsSinceEpoch);
+  }
+
   @protected
   Object api2wire_Chrono_Local(DateTime raw) {
     return api2wire_i64(raw.millisecondsSinceEpoch);
@@ -46,11 +62,56 @@ class FlutterRustBridgeExampleSingleBlockTestPlatform
     return api2wire_i64(raw.millisecondsSinceEpoch);
   }
 
+  @protected
+  Uint8List api2wire_Codec_Version(Version raw) {
+    return api2wire_uint_8_list(VersionCodec.encode(raw));
+  }
+
+  @protected
+  Object api2wire_Counter(Counter raw) {
+    return raw.shareOrMove();
+  }
+
+  @protected
+  Uint8List api2wire_Cow_Bytes(Uint8List raw) {
+    return api2wire_uint_8_list(raw);
+  }
+
+  @protected
+  String api2wire_Cow_String(String raw) {
+    return api2wire_String(raw);
+  }
+
+  @protected
+  Object api2wire_DartIteratorI32(DartIteratorI32 raw) {
+    return raw.shareOrMove();
+  }
+
+  @protected
+  Object api2wire_DartIteratorString(DartIteratorString raw) {
+    return raw.shareOrMove();
+  }
+
   @protected
   Object api2wire_DartOpaque(Object raw) {
     return [raw, dropPort];
   }
 
+  @protected
+  String api2wire_Decimal(Decimal raw) {
+    return api2wire_String(raw.toString());
+  }
+
+  @protected
+  Object api2wire_DynRef_BoxDartDebug(BoxDartDebug raw) {
+    return api2wire_BoxDartDebug(raw);
+  }
+
+  @protected
+  Object api2wire_Greeter(Greeter raw) {
+    return raw.shareOrMove();
+  }
+
   @protected
   Object api2wire_HideData(HideData raw) {
     return raw.shareOrMove();
@@ -66,6 +127,11 @@ class FlutterRustBridgeExampleSingleBlockTestPlatform
     return raw.shareOrMove();
   }
 
+  @protected
+  Object api2wire_Instant(Instant raw) {
+    return raw.shareOrMove();
+  }
+
   @protected
   Object api2wire_MutexHideData(MutexHideData raw) {
     return raw.shareOrMove();
@@ -81,11 +147,51 @@ class FlutterRustBridgeExampleSingleBlockTestPlatform
     return api2wire_list_DartOpaque(raw);
   }
 
+  @protected
+  Uint16List api2wire_PathRef(String raw) {
+    return api2wire_uint_16_list(Uint16List.fromList(raw.codeUnits));
+  }
+
+  @protected
+  Float64List api2wire_RangeInclusive_f64(RustRange<double> raw) {
+    return api2wire_float_64_list(Float64List.fromList(api2wireRange(raw, inclusive: true)));
+  }
+
+  @protected
+  Uint32List api2wire_Range_u32(RustRange<int> raw) {
+    return api2wire_uint_32_list(Uint32List.fromList(api2wireRange(raw, inclusive: false)));
+  }
+
+  @protected
+  Object api2wire_Rooms(Rooms raw) {
+    return raw.shareOrMove();
+  }
+
   @protected
   Object api2wire_RwLockHideData(RwLockHideData raw) {
     return raw.shareOrMove();
   }
 
+  @protected
+  int api2wire_SharedBuffer(SharedBuffer raw) {
+    return api2wire_usize(raw.address);
+  }
+
+  @protected
+  Uint16List api2wire_SliceRef_u16(Uint16List raw) {
+    return api2wire_uint_16_list(raw);
+  }
+
+  @protected
+  Uint8List api2wire_SliceRef_u8(Uint8List raw) {
+    return api2wire_uint_8_list(raw);
+  }
+
+  @protected
+  Uint8List api2wire_SocketAddr(SocketAddress raw) {
+    return api2wire_uint_8_list(api2wireSocketAddress(raw));
+  }
+
   @protected
   String api2wire_String(String raw) {
     return raw;
@@ -96,6 +202,11 @@ class FlutterRustBridgeExampleSingleBlockTestPlatform
     return raw;
   }
 
+  @protected
+  Object api2wire_SystemTime(DateTime raw) {
+    return api2wire_i64(raw.millisecondsSinceEpoch);
+  }
+
   @protected
   List<dynamic> api2wire_TestId_array_4(TestIdArray4 raw) {
     return api2wire_list_test_id(raw);
@@ -111,6 +222,16 @@ class FlutterRustBridgeExampleSingleBlockTestPlatform
     return api2wire_uint_8_list(api2wireConcatenateBytes(raw));
   }
 
+  @protected
+  Object api2wire_WeakCounter(WeakCounter raw) {
+    return raw.shareOrMove();
+  }
+
+  @protected
+  Object api2wire_Weak_Counter(WeakCounter raw) {
+    return api2wire_WeakCounter(raw);
+  }
+
   @protected
   Uint8List api2wire_ZeroCopyBuffer_Uint8List(Uint8List raw) {
     return api2wire_uint_8_list(raw);
@@ -126,6 +247,21 @@ class FlutterRustBridgeExampleSingleBlockTestPlatform
     return [api2wire_String(raw.field0), api2wire_bool(raw.field1)];
   }
 
+  @protected
+  List<dynamic> api2wire_application_message(ApplicationMessage raw) {
+    if (raw is ApplicationMessage_DisplayMessage) {
+      return [0, api2wire_String(raw.field0)];
+    }
+    if (raw is ApplicationMessage_RenderPixel) {
+      return [1, api2wire_i32(raw.x), api2wire_i32(raw.y)];
+    }
+    if (raw is ApplicationMessage_Exit) {
+      return [2];
+    }
+
+    throw Exception('unreachable');
+  }
+
   @protected
   List<dynamic> api2wire_application_settings(ApplicationSettings raw) {
     return [
@@ -172,6 +308,11 @@ class FlutterRustBridgeExampleSingleBlockTestPlatform
     return api2wire_application_env(raw);
   }
 
+  @protected
+  List<dynamic> api2wire_box_autoadd_application_message(ApplicationMessage raw) {
+    return api2wire_application_message(raw);
+  }
+
   @protected
   List<dynamic> api2wire_box_autoadd_application_settings(ApplicationSettings raw) {
     return api2wire_application_settings(raw);
@@ -202,6 +343,11 @@ class FlutterRustBridgeExampleSingleBlockTestPlatform
     return api2wire_dart_opaque_nested(raw);
   }
 
+  @protected
+  List<dynamic> api2wire_box_autoadd_document(Document raw) {
+    return api2wire_document(raw);
+  }
+
   @protected
   List<dynamic> api2wire_box_autoadd_empty(Empty raw) {
     return api2wire_empty(raw);
@@ -242,6 +388,16 @@ class FlutterRustBridgeExampleSingleBlockTestPlatform
     return api2wire_feed_id(raw);
   }
 
+  @protected
+  List<dynamic> api2wire_box_autoadd_frame(Frame raw) {
+    return api2wire_frame(raw);
+  }
+
+  @protected
+  List<dynamic> api2wire_box_autoadd_grid_cell(GridCell raw) {
+    return api2wire_grid_cell(raw);
+  }
+
   @protected
   int api2wire_box_autoadd_i32(int raw) {
     return api2wire_i32(raw);
@@ -267,6 +423,11 @@ class FlutterRustBridgeExampleSingleBlockTestPlatform
     return api2wire_message_id(raw);
   }
 
+  @protected
+  List<dynamic> api2wire_box_autoadd_money(Money raw) {
+    return api2wire_money(raw);
+  }
+
   @protected
   List<dynamic> api2wire_box_autoadd_my_nested_struct(MyNestedStruct raw) {
     return api2wire_my_nested_struct(raw);
@@ -307,21 +468,66 @@ class FlutterRustBridgeExampleSingleBlockTestPlatform
     return api2wire_opaque_nested(raw);
   }
 
+  @protected
+  List<dynamic> api2wire_box_autoadd_pair_i_32(PairI32 raw) {
+    return api2wire_pair_i_32(raw);
+  }
+
+  @protected
+  List<dynamic> api2wire_box_autoadd_product_id(ProductId raw) {
+    return api2wire_product_id(raw);
+  }
+
+  @protected
+  List<dynamic> api2wire_box_autoadd_progress(Progress raw) {
+    return api2wire_progress(raw);
+  }
+
+  @protected
+  List<dynamic> api2wire_box_autoadd_sample(Sample raw) {
+    return api2wire_sample(raw);
+  }
+
   @protected
   List<dynamic> api2wire_box_autoadd_sequences(Sequences raw) {
     return api2wire_sequences(raw);
   }
 
+  @protected
+  List<dynamic> api2wire_box_autoadd_session(Session raw) {
+    return api2wire_session(raw);
+  }
+
   @protected
   List<dynamic> api2wire_box_autoadd_sum_with(SumWith raw) {
     return api2wire_sum_with(raw);
   }
 
+  @protected
+  List<dynamic> api2wire_box_autoadd_tag(Tag raw) {
+    return api2wire_tag(raw);
+  }
+
   @protected
   List<dynamic> api2wire_box_autoadd_test_id(TestId raw) {
     return api2wire_test_id(raw);
   }
 
+  @protected
+  List<dynamic> api2wire_box_autoadd_tuple2_i32_tuple2_String_bool(Tuple2<int, Tuple2<String, bool>> raw) {
+    return api2wire_tuple2_i32_tuple2_String_bool(raw);
+  }
+
+  @protected
+  List<dynamic> api2wire_box_autoadd_tuple3_i32_String_bool(Tuple3<int, String, bool> raw) {
+    return api2wire_tuple3_i32_String_bool(raw);
+  }
+
+  @protected
+  int api2wire_box_autoadd_u32(int raw) {
+    return api2wire_u32(raw);
+  }
+
   @protected
   List<dynamic> api2wire_box_autoadd_user_id(UserId raw) {
     return api2wire_user_id(raw);
@@ -397,6 +603,21 @@ class FlutterRustBridgeExampleSingleBlockTestPlatform
     return api2wire_weekdays(raw);
   }
 
+  @protected
+  List<dynamic> api2wire_btree_map_i32_String(Map<int, String> raw) {
+    return [api2wire_int_32_list(Int32List.fromList(raw.keys.toList())), api2wire_StringList(raw.values.toList())];
+  }
+
+  @protected
+  List<String> api2wire_btree_set_String(Set<String> raw) {
+    return api2wire_StringList(raw.toList());
+  }
+
+  @protected
+  List<dynamic> api2wire_colliding_key(CollidingKey raw) {
+    return [api2wire_String(raw.field0)];
+  }
+
   @protected
   List<dynamic> api2wire_concatenate_with(ConcatenateWith raw) {
     return [api2wire_String(raw.a)];
@@ -424,6 +645,17 @@ class FlutterRustBridgeExampleSingleBlockTestPlatform
     throw Exception('unreachable');
   }
 
+  @protected
+  List<dynamic> api2wire_document(Document raw) {
+    return [
+      api2wire_metadata(Metadata(
+        id: raw.id,
+        createdBy: raw.createdBy,
+      )),
+      api2wire_String(raw.title)
+    ];
+  }
+
   @protected
   List<dynamic> api2wire_empty(Empty raw) {
     return [];
@@ -482,6 +714,11 @@ class FlutterRustBridgeExampleSingleBlockTestPlatform
     ];
   }
 
+  @protected
+  Float32List api2wire_f32_array_3(F32Array3 raw) {
+    return Float32List.fromList(raw);
+  }
+
   @protected
   Float64List api2wire_f64_array_16(F64Array16 raw) {
     return Float64List.fromList(raw);
@@ -517,6 +754,42 @@ class FlutterRustBridgeExampleSingleBlockTestPlatform
     return raw;
   }
 
+  @protected
+  List<dynamic> api2wire_frame(Frame raw) {
+    return [api2wire_u32(raw.index), api2wire_uint_8_list(raw.pixels), api2wire_opt_float_32_list(raw.depths)];
+  }
+
+  @protected
+  List<dynamic> api2wire_grid_cell(GridCell raw) {
+    return [api2wire_i32(raw.row), api2wire_i32(raw.column)];
+  }
+
+  @protected
+  List<dynamic> api2wire_hash_map_String_i32(Map<String, int> raw) {
+    return [api2wire_StringList(raw.keys.toList()), api2wire_int_32_list(Int32List.fromList(raw.values.toList()))];
+  }
+
+  @protected
+  List<dynamic> api2wire_hash_map_colliding_key_u32(Map<CollidingKey, int> raw) {
+    return [
+      api2wire_list_colliding_key(raw.keys.toList()),
+      api2wire_uint_32_list(Uint32List.fromList(raw.values.toList()))
+    ];
+  }
+
+  @protected
+  List<dynamic> api2wire_hash_map_u32_attribute(Map<int, Attribute> raw) {
+    return [
+      api2wire_uint_32_list(Uint32List.fromList(raw.keys.toList())),
+      api2wire_list_attribute(raw.values.toList())
+    ];
+  }
+
+  @protected
+  Uint8List api2wire_i128(BigInt raw) {
+    return api2wire_uint_8_list(api2wireInt128(raw, signed: true));
+  }
+
   @protected
   Int32List api2wire_i32_array_2(I32Array2 raw) {
     return Int32List.fromList(raw);
@@ -566,6 +839,11 @@ class FlutterRustBridgeExampleSingleBlockTestPlatform
     return raw.map(api2wire_DartOpaque).toList();
   }
 
+  @protected
+  List<dynamic> api2wire_list_Decimal(List<Decimal> raw) {
+    return raw.map(api2wire_Decimal).toList();
+  }
+
   @protected
   List<dynamic> api2wire_list_HideData(List<HideData> raw) {
     return raw.map(api2wire_HideData).toList();
@@ -581,6 +859,21 @@ class FlutterRustBridgeExampleSingleBlockTestPlatform
     return raw.map(api2wire_attribute).toList();
   }
 
+  @protected
+  List<dynamic> api2wire_list_char(List<String> raw) {
+    return raw.map(api2wire_char).toList();
+  }
+
+  @protected
+  List<dynamic> api2wire_list_colliding_key(List<CollidingKey> raw) {
+    return raw.map(api2wire_colliding_key).toList();
+  }
+
+  @protected
+  List<dynamic> api2wire_list_int_32_list(List<Int32List> raw) {
+    return raw.map(api2wire_int_32_list).toList();
+  }
+
   @protected
   List<dynamic> api2wire_list_my_size(List<MySize> raw) {
     return raw.map(api2wire_my_size).toList();
@@ -601,6 +894,11 @@ class FlutterRustBridgeExampleSingleBlockTestPlatform
     return raw.map(api2wire_test_id).toList();
   }
 
+  @protected
+  List<dynamic> api2wire_list_weekdays(List<Weekdays> raw) {
+    return raw.map(api2wire_weekdays).toList();
+  }
+
   @protected
   List<dynamic> api2wire_measure(Measure raw) {
     if (raw is Measure_Speed) {
@@ -618,6 +916,17 @@ class FlutterRustBridgeExampleSingleBlockTestPlatform
     return [api2wire_u8_array_32(raw.field0)];
   }
 
+  @protected
+  List<dynamic> api2wire_metadata(Metadata raw) {
+    return [api2wire_u32(raw.id), api2wire_String(raw.createdBy)];
+  }
+
+  @protected
+  List<dynamic> api2wire_money(Money raw) {
+    final fields = moneyToRaw(raw);
+    return [api2wire_i64(fields.cents)];
+  }
+
   @protected
   List<dynamic> api2wire_my_nested_struct(MyNestedStruct raw) {
     return [api2wire_my_tree_node(raw.treeNode), api2wire_weekdays(raw.weekday)];
@@ -728,6 +1037,11 @@ class FlutterRustBridgeExampleSingleBlockTestPlatform
     return raw == null ? null : api2wire_box_autoadd_new_type_int(raw);
   }
 
+  @protected
+  int? api2wire_opt_box_autoadd_u32(int? raw) {
+    return raw == null ? null : api2wire_box_autoadd_u32(raw);
+  }
+
   @protected
   bool? api2wire_opt_box_bool(bool? raw) {
     return raw == null ? null : api2wire_box_bool(raw);
@@ -798,11 +1112,42 @@ class FlutterRustBridgeExampleSingleBlockTestPlatform
     return raw == null ? null : api2wire_uint_8_list(raw);
   }
 
+  @protected
+  List<dynamic> api2wire_pair_i_32(PairI32 raw) {
+    return [api2wire_i32(raw.first), api2wire_int_32_list(raw.second)];
+  }
+
+  @protected
+  List<dynamic> api2wire_product_id(ProductId raw) {
+    return [api2wire_u32(raw.field0)];
+  }
+
+  @protected
+  List<dynamic> api2wire_progress(Progress raw) {
+    return [api2wire_u8(raw.percentage)];
+  }
+
+  @protected
+  List<dynamic> api2wire_sample(Sample raw) {
+    return [
+      api2wire_u32(raw.index),
+      api2wire_f64(raw.x),
+      api2wire_f32(raw.y),
+      api2wire_bool(raw.valid),
+      api2wire_i8(raw.level)
+    ];
+  }
+
   @protected
   List<dynamic> api2wire_sequences(Sequences raw) {
     return [api2wire_int_32_list(raw.field0)];
   }
 
+  @protected
+  List<dynamic> api2wire_session(Session raw) {
+    return [api2wire_String(raw.user)];
+  }
+
   @protected
   List<dynamic> api2wire_speed(Speed raw) {
     if (raw is Speed_Unknown) {
@@ -815,16 +1160,51 @@ class FlutterRustBridgeExampleSingleBlockTestPlatform
     throw Exception('unreachable');
   }
 
+  @protected
+  Object api2wire_std_Instant(Instant raw) {
+    return api2wire_Instant(raw);
+  }
+
   @protected
   List<dynamic> api2wire_sum_with(SumWith raw) {
     return [api2wire_u32(raw.x)];
   }
 
+  @protected
+  List<dynamic> api2wire_tag(Tag raw) {
+    return [
+      api2wire_String(raw.name),
+      api2wire_opt_box_autoadd_u32(raw.color),
+      api2wire_StringList(raw.aliases),
+      api2wire_weekdays(raw.day)
+    ];
+  }
+
   @protected
   List<dynamic> api2wire_test_id(TestId raw) {
     return [api2wire_i32_array_2(raw.field0)];
   }
 
+  @protected
+  List<dynamic> api2wire_tuple2_String_bool(Tuple2<String, bool> raw) {
+    return [api2wire_String(raw.item1), api2wire_bool(raw.item2)];
+  }
+
+  @protected
+  List<dynamic> api2wire_tuple2_i32_tuple2_String_bool(Tuple2<int, Tuple2<String, bool>> raw) {
+    return [api2wire_i32(raw.item1), api2wire_tuple2_String_bool(raw.item2)];
+  }
+
+  @protected
+  List<dynamic> api2wire_tuple3_i32_String_bool(Tuple3<int, String, bool> raw) {
+    return [api2wire_i32(raw.item1), api2wire_String(raw.item2), api2wire_bool(raw.item3)];
+  }
+
+  @protected
+  Uint8List api2wire_u128(BigInt raw) {
+    return api2wire_uint_8_list(api2wireInt128(raw, signed: false));
+  }
+
   @protected
   Object api2wire_u64(int raw) {
     return castNativeBigInt(raw);
@@ -845,6 +1225,21 @@ class FlutterRustBridgeExampleSingleBlockTestPlatform
     return Uint8List.fromList(raw);
   }
 
+  @protected
+  Uint16List api2wire_uint_16_list(Uint16List raw) {
+    return raw;
+  }
+
+  @protected
+  Uint32List api2wire_uint_32_list(Uint32List raw) {
+    return raw;
+  }
+
+  @protected
+  Uint64List api2wire_uint_64_list(Uint64List raw) {
+    return raw;
+  }
+
   @protected
   Uint8List api2wire_uint_8_list(Uint8List raw) {
     return raw;
@@ -855,30 +1250,52 @@ class FlutterRustBridgeExampleSingleBlockTestPlatform
     return [api2wire_u32(raw.value)];
   }
 
+  @protected
+  Uint64List api2wire_usize_list(Uint64List raw) {
+    return api2wire_uint_64_list(raw);
+  }
+
 // Section: finalizer
 
   late final Finalizer<PlatformPointer> _BoxDartDebugFinalizer =
       Finalizer<PlatformPointer>(inner.drop_opaque_BoxDartDebug);
   Finalizer<PlatformPointer> get BoxDartDebugFinalizer => _BoxDartDebugFinalizer;
+  late final Finalizer<PlatformPointer> _CounterFinalizer = Finalizer<PlatformPointer>(inner.drop_opaque_Counter);
+  Finalizer<PlatformPointer> get CounterFinalizer => _CounterFinalizer;
+  late final Finalizer<PlatformPointer> _DartIteratorI32Finalizer =
+      Finalizer<PlatformPointer>(inner.drop_opaque_DartIteratorI32);
+  Finalizer<PlatformPointer> get DartIteratorI32Finalizer => _DartIteratorI32Finalizer;
+  late final Finalizer<PlatformPointer> _DartIteratorStringFinalizer =
+      Finalizer<PlatformPointer>(inner.drop_opaque_DartIteratorString);
+  Finalizer<PlatformPointer> get DartIteratorStringFinalizer => _DartIteratorStringFinalizer;
   late final Finalizer<PlatformPointer> _FrbOpaqueReturnFinalizer =
       Finalizer<PlatformPointer>(inner.drop_opaque_FrbOpaqueReturn);
   Finalizer<PlatformPointer> get FrbOpaqueReturnFinalizer => _FrbOpaqueReturnFinalizer;
   late final Finalizer<PlatformPointer> _FrbOpaqueSyncReturnFinalizer =
       Finalizer<PlatformPointer>(inner.drop_opaque_FrbOpaqueSyncReturn);
   Finalizer<PlatformPointer> get FrbOpaqueSyncReturnFinalizer => _FrbOpaqueSyncReturnFinalizer;
+  late final Finalizer<PlatformPointer> _GreeterFinalizer = Finalizer<PlatformPointer>(inner.drop_opaque_Greeter);
+  Finalizer<PlatformPointer> get GreeterFinalizer => _GreeterFinalizer;
   late final Finalizer<PlatformPointer> _HideDataFinalizer = Finalizer<PlatformPointer>(inner.drop_opaque_HideData);
   Finalizer<PlatformPointer> get HideDataFinalizer => _HideDataFinalizer;
   late final Finalizer<PlatformPointer> _I32Finalizer = Finalizer<PlatformPointer>(inner.drop_opaque_I32);
   Finalizer<PlatformPointer> get I32Finalizer => _I32Finalizer;
+  late final Finalizer<PlatformPointer> _InstantFinalizer = Finalizer<PlatformPointer>(inner.drop_opaque_Instant);
+  Finalizer<PlatformPointer> get InstantFinalizer => _InstantFinalizer;
   late final Finalizer<PlatformPointer> _MutexHideDataFinalizer =
       Finalizer<PlatformPointer>(inner.drop_opaque_MutexHideData);
   Finalizer<PlatformPointer> get MutexHideDataFinalizer => _MutexHideDataFinalizer;
   late final Finalizer<PlatformPointer> _NonSendHideDataFinalizer =
       Finalizer<PlatformPointer>(inner.drop_opaque_NonSendHideData);
   Finalizer<PlatformPointer> get NonSendHideDataFinalizer => _NonSendHideDataFinalizer;
+  late final Finalizer<PlatformPointer> _RoomsFinalizer = Finalizer<PlatformPointer>(inner.drop_opaque_Rooms);
+  Finalizer<PlatformPointer> get RoomsFinalizer => _RoomsFinalizer;
   late final Finalizer<PlatformPointer> _RwLockHideDataFinalizer =
       Finalizer<PlatformPointer>(inner.drop_opaque_RwLockHideData);
   Finalizer<PlatformPointer> get RwLockHideDataFinalizer => _RwLockHideDataFinalizer;
+  late final Finalizer<PlatformPointer> _WeakCounterFinalizer =
+      Finalizer<PlatformPointer>(inner.drop_opaque_WeakCounter);
+  Finalizer<PlatformPointer> get WeakCounterFinalizer => _WeakCounterFinalizer;
 }
 
 // Section: WASM wire module
@@ -891,11 +1308,23 @@ external FlutterRustBridgeExampleSingleBlockTestWasmModule get wasmModule;
 class FlutterRustBridgeExampleSingleBlockTestWasmModule implements WasmModule {
   external Object /* Promise */ call([String? moduleName]);
   external FlutterRustBridgeExampleSingleBlockTestWasmModule bind(dynamic thisArg, String moduleName);
+  external dynamic /* void */ wire_init_app(NativePortType port_);
+
+  external dynamic /* void */ wire_init_count(NativePortType port_);
+
+  external dynamic /* void */ wire_get_constants(NativePortType port_);
+
   external dynamic /* void */ wire_simple_adder(NativePortType port_, int a, int b);
 
+  external dynamic /* void */ wire_simple_adder_async(NativePortType port_, int a, int b);
+
   external dynamic /* int */ wire_simple_adder_sync(int a, int b);
 
-  external dynamic /* void */ wire_primitive_types(
+  external dynamic /* double */ wire_scale(double x, double factor);
+
+  external dynamic /* double */ wire_sum_vector(Float32List v);
+
+  external dynamic /* void */ wire_primitive_types(
       NativePortType port_, int my_i32, Object my_i64, double my_f64, bool my_bool);
 
   external dynamic /* void */ wire_primitive_optional_types(
@@ -911,12 +1340,57 @@ class FlutterRustBridgeExampleSingleBlockTestWasmModule implements WasmModule {
 
   external dynamic /* String */ wire_handle_string_sync(String s);
 
+  external dynamic /* void */ wire_next_char(NativePortType port_, int c);
+
+  external dynamic /* void */ wire_handle_chars(NativePortType port_, List<dynamic> chars);
+
+  external dynamic /* void */ wire_divide_by(NativePortType port_, int n, int by);
+
+  external dynamic /* void */ wire_next_non_zero(NativePortType port_, Object n);
+
+  external dynamic /* void */ wire_greet(NativePortType port_, String name, int times);
+
+  external dynamic /* void */ wire_instant_now(NativePortType port_);
+
+  external dynamic /* void */ wire_instant_after(NativePortType port_, Object start, Object millis);
+
+  external dynamic /* void */ wire_one_day_later(NativePortType port_, Object time);
+
+  external dynamic /* void */ wire_shift_range(NativePortType port_, Uint32List range, int by);
+
+  external dynamic /* void */ wire_clamp_range(NativePortType port_, Float64List range, double value);
+
+  external dynamic /* void */ wire_describe_size(
+      NativePortType port_, List<dynamic> size, String unit, List<String> labels);
+
+  external dynamic /* void */ wire_split_words(NativePortType port_, String text);
+
+  external dynamic /* void */ wire_handle_cow_str(NativePortType port_, String s);
+
+  external dynamic /* void */ wire_handle_cow_bytes(NativePortType port_, Uint8List bytes);
+
+  external dynamic /* void */ wire_handle_path(NativePortType port_, Uint16List path);
+
+  external dynamic /* void */ wire_handle_socket_addr(NativePortType port_, Uint8List addr);
+
+  external dynamic /* void */ wire_next_port(NativePortType port_, Uint8List addr);
+
   external dynamic /* void */ wire_handle_return_unit(NativePortType port_);
 
   external dynamic /* void */ wire_handle_return_unit_sync();
 
   external dynamic /* void */ wire_handle_vec_u8(NativePortType port_, Uint8List v);
 
+  external dynamic /* void */ wire_handle_bytes(NativePortType port_, Uint8List bytes);
+
+  external dynamic /* void */ wire_checksum(NativePortType port_, Uint8List data, Uint16List weights);
+
+  external dynamic /* void */ wire_count_in_shared_buffer(NativePortType port_, int buffer, int byte);
+
+  external dynamic /* void */ wire_next_frame(NativePortType port_, List<dynamic> frame);
+
+  external dynamic /* void */ wire_prefix_sums(NativePortType port_, Uint64List items);
+
   external dynamic /* Uint8List */ wire_handle_vec_u8_sync(Uint8List v);
 
   external dynamic /* void */ wire_handle_vec_of_primitive(NativePortType port_, int n);
@@ -935,10 +1409,14 @@ class FlutterRustBridgeExampleSingleBlockTestWasmModule implements WasmModule {
 
   external dynamic /* List<dynamic> */ wire_handle_newtype_sync(List<dynamic> arg);
 
+  external dynamic /* void */ wire_handle_generic_struct(NativePortType port_, List<dynamic> pair);
+
   external dynamic /* void */ wire_handle_list_of_struct(NativePortType port_, List<dynamic> l);
 
   external dynamic /* List<dynamic> */ wire_handle_list_of_struct_sync(List<dynamic> l);
 
+  external dynamic /* void */ wire_handle_nested_vec(NativePortType port_, List<dynamic> rows);
+
   external dynamic /* void */ wire_handle_string_list(NativePortType port_, List<String> names);
 
   external dynamic /* List<String> */ wire_handle_string_list_sync(List<String> names);
@@ -957,6 +1435,26 @@ class FlutterRustBridgeExampleSingleBlockTestWasmModule implements WasmModule {
 
   external dynamic /* void */ wire_return_err(NativePortType port_);
 
+  external dynamic /* void */ wire_return_custom_error(NativePortType port_, bool fail);
+
+  external dynamic /* void */ wire_return_custom_error_unit(NativePortType port_);
+
+  external dynamic /* int? */ wire_lookup_weekday(int index);
+
+  external dynamic /* void */ wire_lookup_string(NativePortType port_, String key);
+
+  external dynamic /* void */ wire_read_setting(NativePortType port_, String key);
+
+  external dynamic /* void */ wire_parse_setting(NativePortType port_, String value);
+
+  external dynamic /* int */ wire_return_custom_error_sync(bool fail);
+
+  external dynamic /* void */ wire_count_until_cancelled(NativePortType port_, int cancel_id_);
+
+  external dynamic /* void */ wire_run_workers(NativePortType port_, int cancel_id_, int workers);
+
+  external dynamic /* void */ wire_stopped_workers(NativePortType port_);
+
   external dynamic /* void */ wire_return_panic(NativePortType port_);
 
   external dynamic /* void */ wire_handle_optional_return(NativePortType port_, double left, double right);
@@ -976,6 +1474,10 @@ class FlutterRustBridgeExampleSingleBlockTestWasmModule implements WasmModule {
 
   external dynamic /* void */ wire_handle_enum_parameter(NativePortType port_, int weekday);
 
+  external dynamic /* void */ wire_http_status_code(NativePortType port_, int status);
+
+  external dynamic /* void */ wire_http_status_of(NativePortType port_, int code);
+
   external dynamic /* void */ wire_handle_customized_struct(NativePortType port_, List<dynamic> val);
 
   external dynamic /* void */ wire_handle_enum_struct(NativePortType port_, List<dynamic> val);
@@ -992,6 +1494,10 @@ class FlutterRustBridgeExampleSingleBlockTestWasmModule implements WasmModule {
 
   external dynamic /* void */ wire_get_message(NativePortType port_);
 
+  external dynamic /* void */ wire_get_fallible_message(NativePortType port_, int index);
+
+  external dynamic /* void */ wire_is_exit_message(NativePortType port_, List<dynamic> message);
+
   external dynamic /* void */ wire_repeat_number(NativePortType port_, int num, int times);
 
   external dynamic /* void */ wire_repeat_sequence(NativePortType port_, int seq, int times);
@@ -1020,10 +1526,48 @@ class FlutterRustBridgeExampleSingleBlockTestWasmModule implements WasmModule {
 
   external dynamic /* void */ wire_handle_stream_sink_at_3(NativePortType port_, int key, int max);
 
+  external dynamic /* void */ wire_call_back_with_numbers(NativePortType port_, NativePortType callback, int max);
+
+  external dynamic /* void */ wire_handle_stream_closed_by_drop(NativePortType port_, int max);
+
+  external dynamic /* void */ wire_count_from(NativePortType port_, int from, int count);
+
+  external dynamic /* void */ wire_parse_numbers(NativePortType port_, String input);
+
+  external dynamic /* void */ wire_handle_broadcast_stream(NativePortType port_, int max);
+
+  external dynamic /* void */ wire_handle_stream_closed_by_threads(NativePortType port_, int threads);
+
   external dynamic /* void */ wire_get_sum_struct(NativePortType port_);
 
   external dynamic /* void */ wire_get_sum_array(NativePortType port_, int a, int b, int c);
 
+  external dynamic /* void */ wire_rename_document(NativePortType port_, List<dynamic> document, String title);
+
+  external dynamic /* void */ wire_get_renamed(NativePortType port_, int id, int pace);
+
+  external dynamic /* void */ wire_advance(NativePortType port_, List<dynamic> progress, int by);
+
+  external dynamic /* void */ wire_next_product_id(NativePortType port_, List<dynamic> id);
+
+  external dynamic /* void */ wire_transpose_cell(NativePortType port_, List<dynamic> cell);
+
+  external dynamic /* void */ wire_add_tax(NativePortType port_, List<dynamic> price, int percent);
+
+  external dynamic /* void */ wire_bump_minor(NativePortType port_, Uint8List version);
+
+  external dynamic /* void */ wire_open_session(NativePortType port_, String user);
+
+  external dynamic /* void */ wire_describe_session(NativePortType port_, List<dynamic> session);
+
+  external dynamic /* void */ wire_sample_at(NativePortType port_, int index);
+
+  external dynamic /* void */ wire_shift_sample(NativePortType port_, List<dynamic> sample, double dx);
+
+  external dynamic /* void */ wire_tag_label(NativePortType port_, List<dynamic> tag);
+
+  external dynamic /* void */ wire_header_name(NativePortType port_, Uint8List raw);
+
   external dynamic /* void */ wire_multiply_by_ten(NativePortType port_, List<dynamic> measure);
 
   external dynamic /* void */ wire_call_old_module_system(NativePortType port_);
@@ -1032,6 +1576,10 @@ class FlutterRustBridgeExampleSingleBlockTestWasmModule implements WasmModule {
 
   external dynamic /* void */ wire_handle_big_buffers(NativePortType port_);
 
+  external dynamic /* void */ wire_handle_i128(NativePortType port_, Uint8List value);
+
+  external dynamic /* void */ wire_handle_u128(NativePortType port_, Uint8List value);
+
   external dynamic /* void */ wire_datetime_utc(NativePortType port_, Object d);
 
   external dynamic /* void */ wire_datetime_local(NativePortType port_, Object d);
@@ -1042,6 +1590,8 @@ class FlutterRustBridgeExampleSingleBlockTestWasmModule implements WasmModule {
 
   external dynamic /* void */ wire_duration(NativePortType port_, Object d);
 
+  external dynamic /* void */ wire_datetime_fixed_offset(NativePortType port_, Object d);
+
   external dynamic /* void */ wire_test_chrono(NativePortType port_);
 
   external dynamic /* void */ wire_test_precise_chrono(NativePortType port_);
@@ -1054,6 +1604,10 @@ class FlutterRustBridgeExampleSingleBlockTestWasmModule implements WasmModule {
 
   external dynamic /* void */ wire_handle_nested_uuids(NativePortType port_, List<dynamic> ids);
 
+  external dynamic /* void */ wire_handle_decimal(NativePortType port_, String amount);
+
+  external dynamic /* void */ wire_sum_decimals(NativePortType port_, List<dynamic> amounts);
+
   external dynamic /* void */ wire_new_msgid(NativePortType port_, Uint8List id);
 
   external dynamic /* void */ wire_use_msgid(NativePortType port_, List<dynamic> id);
@@ -1070,6 +1624,12 @@ class FlutterRustBridgeExampleSingleBlockTestWasmModule implements WasmModule {
 
   external dynamic /* void */ wire_last_number(NativePortType port_, Float64List array);
 
+  external dynamic /* void */ wire_repeat_byte_4(NativePortType port_, int byte);
+
+  external dynamic /* void */ wire_repeat_byte_8(NativePortType port_, int byte);
+
+  external dynamic /* void */ wire_transpose(NativePortType port_, Float64List matrix);
+
   external dynamic /* void */ wire_nested_id(NativePortType port_, List<dynamic> id);
 
   external dynamic /* String */ wire_sync_accept_dart_opaque(Object opaque);
@@ -1104,8 +1664,20 @@ class FlutterRustBridgeExampleSingleBlockTestWasmModule implements WasmModule {
 
   external dynamic /* void */ wire_run_enum_opaque(NativePortType port_, List<dynamic> opaque);
 
+  external dynamic /* void */ wire_create_trait_object(NativePortType port_);
+
+  external dynamic /* void */ wire_create_impl_trait(NativePortType port_);
+
+  external dynamic /* void */ wire_run_trait_object(NativePortType port_, Object obj);
+
   external dynamic /* void */ wire_run_opaque(NativePortType port_, Object opaque);
 
+  external dynamic /* void */ wire_create_shared_opaque(NativePortType port_);
+
+  external dynamic /* void */ wire_run_shared_opaque(NativePortType port_, Object opaque);
+
+  external dynamic /* void */ wire_shared_opaque_address(NativePortType port_, Object opaque);
+
   external dynamic /* void */ wire_run_opaque_with_delay(NativePortType port_, Object opaque);
 
   external dynamic /* void */ wire_opaque_array(NativePortType port_);
@@ -1160,6 +1732,10 @@ class FlutterRustBridgeExampleSingleBlockTestWasmModule implements WasmModule {
 
   external dynamic /* Object */ wire_frb_sync_generator_test();
 
+  external dynamic /* void */ wire_observe_counter(NativePortType port_, Object counter);
+
+  external dynamic /* void */ wire_is_counter_alive(NativePortType port_, Object counter);
+
   external dynamic /* void */ wire_handle_type_alias_id(NativePortType port_, Object input);
 
   external dynamic /* void */ wire_handle_type_nest_alias_id(NativePortType port_, Object input);
@@ -1168,6 +1744,26 @@ class FlutterRustBridgeExampleSingleBlockTestWasmModule implements WasmModule {
 
   external dynamic /* void */ wire_empty_struct(NativePortType port_, List<dynamic> empty);
 
+  external dynamic /* void */ wire_handle_hash_map(NativePortType port_, List<dynamic> map);
+
+  external dynamic /* void */ wire_handle_hash_map_of_struct(NativePortType port_, List<dynamic> map);
+
+  external dynamic /* void */ wire_count_weekdays(NativePortType port_, List<dynamic> days);
+
+  external dynamic /* void */ wire_index_words(NativePortType port_, List<String> words);
+
+  external dynamic /* void */ wire_handle_colliding_keys(NativePortType port_, List<dynamic> map);
+
+  external dynamic /* void */ wire_handle_btree_set(NativePortType port_, List<String> set);
+
+  external dynamic /* void */ wire_handle_btree_map(NativePortType port_, List<dynamic> map);
+
+  external dynamic /* void */ wire_group_attributes(NativePortType port_, List<dynamic> attributes);
+
+  external dynamic /* void */ wire_handle_tuple(NativePortType port_, List<dynamic> tuple);
+
+  external dynamic /* void */ wire_handle_nested_tuple(NativePortType port_, List<dynamic> tuple);
+
   external dynamic /* void */ wire_sum__method__SumWith(NativePortType port_, List<dynamic> that, int y, int z);
 
   external dynamic /* void */ wire_new__static_method__ConcatenateWith(NativePortType port_, String a);
@@ -1190,37 +1786,165 @@ class FlutterRustBridgeExampleSingleBlockTestWasmModule implements WasmModule {
   external dynamic /* void */ wire_handle_some_static_stream_sink_single_arg__static_method__ConcatenateWith(
       NativePortType port_);
 
+  external dynamic /* void */ wire_start__static_method__Counter(NativePortType port_, int from);
+
+  external dynamic /* void */ wire_increment__method__Counter(NativePortType port_, Object that, int by);
+
+  external dynamic /* void */ wire_label__method__Counter(NativePortType port_, Object that);
+
+  external dynamic /* void */ wire_reset__method__Counter(NativePortType port_, Object that, int to);
+
+  external dynamic /* void */ wire_count_down__method__Counter(NativePortType port_, Object that);
+
+  external dynamic /* void */ wire_increment_later__method__Counter(NativePortType port_, Object that, int by);
+
+  external dynamic /* void */ wire_updates__method__Counter(NativePortType port_, Object that);
+
+  external dynamic /* void */ wire_finish__method__Counter(NativePortType port_, Object that);
+
+  external dynamic /* void */ wire_open__static_method__Rooms(NativePortType port_);
+
+  external dynamic /* void */ wire_join__method__Rooms(NativePortType port_, Object that, String room);
+
+  external dynamic /* void */ wire_post__method__Rooms(NativePortType port_, Object that, String room, String message);
+
+  external dynamic /* void */ wire_close_room__method__Rooms(NativePortType port_, Object that, String room);
+
+  external dynamic /* void */ wire_named__static_method__Greeter(NativePortType port_, String name);
+
+  external dynamic /* void */ wire_describe__method__Counter(NativePortType port_, Object that);
+
+  external dynamic /* void */ wire_shout__method__Counter(NativePortType port_, Object that);
+
+  external dynamic /* void */ wire_describe__method__Greeter(NativePortType port_, Object that);
+
+  external dynamic /* void */ wire_shout__method__Greeter(NativePortType port_, Object that);
+
+  external dynamic /* void */ wire_dispatch(NativePortType port_, String id, int x);
+
+  external dynamic /* void */ wire_next_dart_iterator_string(NativePortType port_, Object iterator);
+
+  external dynamic /* void */ wire_next_dart_iterator_i_32(NativePortType port_, Object iterator);
+
+  external dynamic /* void */ wire_changes__method__Counter(NativePortType port_, Object that);
+
+  external dynamic /* void */ wire_warm_up(NativePortType port_);
+
+  external dynamic /* void */ wire_upgrade_weak_counter(NativePortType port_, Object weak);
+
+  external dynamic /* void */ wire_instant_duration_since(NativePortType port_, Object that, Object earlier);
+
+  external dynamic /* String */ wire_handle_stream_drain(String arg);
+
+  external dynamic /* List<dynamic> */ wire_handle_stream_of_struct_drain();
+
+  external dynamic /* List<dynamic> */ wire_register_event_listener_drain();
+
+  external dynamic /* List<dynamic> */ wire_handle_stream_sink_at_1_drain(int key, int max);
+
+  external dynamic /* List<dynamic> */ wire_handle_stream_sink_at_2_drain(int key, int max);
+
+  external dynamic /* List<dynamic> */ wire_handle_stream_sink_at_3_drain(int key, int max);
+
+  external dynamic /* int */ wire_handle_stream_closed_by_drop_drain(int max);
+
+  external dynamic /* int */ wire_count_from_drain(int from, int count);
+
+  external dynamic /* int */ wire_parse_numbers_drain(String input);
+
+  external dynamic /* int */ wire_handle_broadcast_stream_drain(int max);
+
+  external dynamic /* int */ wire_handle_stream_closed_by_threads_drain(int threads);
+
   external dynamic /*  */ drop_opaque_BoxDartDebug(ptr);
 
   external int /* *const c_void */ share_opaque_BoxDartDebug(ptr);
 
+  external int /* *const c_void */ send_opaque_BoxDartDebug(ptr);
+
+  external dynamic /*  */ drop_opaque_Counter(ptr);
+
+  external int /* *const c_void */ share_opaque_Counter(ptr);
+
+  external int /* *const c_void */ send_opaque_Counter(ptr);
+
+  external dynamic /*  */ drop_opaque_DartIteratorI32(ptr);
+
+  external int /* *const c_void */ share_opaque_DartIteratorI32(ptr);
+
+  external int /* *const c_void */ send_opaque_DartIteratorI32(ptr);
+
+  external dynamic /*  */ drop_opaque_DartIteratorString(ptr);
+
+  external int /* *const c_void */ share_opaque_DartIteratorString(ptr);
+
+  external int /* *const c_void */ send_opaque_DartIteratorString(ptr);
+
   external dynamic /*  */ drop_opaque_FrbOpaqueReturn(ptr);
 
   external int /* *const c_void */ share_opaque_FrbOpaqueReturn(ptr);
 
+  external int /* *const c_void */ send_opaque_FrbOpaqueReturn(ptr);
+
   external dynamic /*  */ drop_opaque_FrbOpaqueSyncReturn(ptr);
 
   external int /* *const c_void */ share_opaque_FrbOpaqueSyncReturn(ptr);
 
+  external int /* *const c_void */ send_opaque_FrbOpaqueSyncReturn(ptr);
+
+  external dynamic /*  */ drop_opaque_Greeter(ptr);
+
+  external int /* *const c_void */ share_opaque_Greeter(ptr);
+
+  external int /* *const c_void */ send_opaque_Greeter(ptr);
+
   external dynamic /*  */ drop_opaque_HideData(ptr);
 
   external int /* *const c_void */ share_opaque_HideData(ptr);
 
+  external int /* *const c_void */ send_opaque_HideData(ptr);
+
   external dynamic /*  */ drop_opaque_I32(ptr);
 
   external int /* *const c_void */ share_opaque_I32(ptr);
 
+  external int /* *const c_void */ send_opaque_I32(ptr);
+
+  external dynamic /*  */ drop_opaque_Instant(ptr);
+
+  external int /* *const c_void */ share_opaque_Instant(ptr);
+
+  external int /* *const c_void */ send_opaque_Instant(ptr);
+
   external dynamic /*  */ drop_opaque_MutexHideData(ptr);
 
   external int /* *const c_void */ share_opaque_MutexHideData(ptr);
 
+  external int /* *const c_void */ send_opaque_MutexHideData(ptr);
+
   external dynamic /*  */ drop_opaque_NonSendHideData(ptr);
 
   external int /* *const c_void */ share_opaque_NonSendHideData(ptr);
 
+  external int /* *const c_void */ send_opaque_NonSendHideData(ptr);
+
+  external dynamic /*  */ drop_opaque_Rooms(ptr);
+
+  external int /* *const c_void */ share_opaque_Rooms(ptr);
+
+  external int /* *const c_void */ send_opaque_Rooms(ptr);
+
   external dynamic /*  */ drop_opaque_RwLockHideData(ptr);
 
   external int /* *const c_void */ share_opaque_RwLockHideData(ptr);
+
+  external int /* *const c_void */ send_opaque_RwLockHideData(ptr);
+
+  external dynamic /*  */ drop_opaque_WeakCounter(ptr);
+
+  external int /* *const c_void */ share_opaque_WeakCounter(ptr);
+
+  external int /* *const c_void */ send_opaque_WeakCounter(ptr);
 }
 
 // Section: WASM wire connector
@@ -1230,10 +1954,22 @@ class FlutterRustBridgeExampleSingleBlockTestWire
   FlutterRustBridgeExampleSingleBlockTestWire(FutureOr<WasmModule> module)
       : super(WasmModule.cast<FlutterRustBridgeExampleSingleBlockTestWasmModule>(module));
 
+  void wire_init_app(NativePortType port_) => wasmModule.wire_init_app(port_);
+
+  void wire_init_count(NativePortType port_) => wasmModule.wire_init_count(port_);
+
+  void wire_get_constants(NativePortType port_) => wasmModule.wire_get_constants(port_);
+
   void wire_simple_adder(NativePortType port_, int a, int b) => wasmModule.wire_simple_adder(port_, a, b);
 
+  void wire_simple_adder_async(NativePortType port_, int a, int b) => wasmModule.wire_simple_adder_async(port_, a, b);
+
   dynamic /* int */ wire_simple_adder_sync(int a, int b) => wasmModule.wire_simple_adder_sync(a, b);
 
+  dynamic /* double */ wire_scale(double x, double factor) => wasmModule.wire_scale(x, factor);
+
+  dynamic /* double */ wire_sum_vector(Float32List v) => wasmModule.wire_sum_vector(v);
+
   void wire_primitive_types(NativePortType port_, int my_i32, Object my_i64, double my_f64, bool my_bool) =>
       wasmModule.wire_primitive_types(port_, my_i32, my_i64, my_f64, my_bool);
 
@@ -1252,12 +1988,62 @@ class FlutterRustBridgeExampleSingleBlockTestWire
 
   dynamic /* String */ wire_handle_string_sync(String s) => wasmModule.wire_handle_string_sync(s);
 
+  void wire_next_char(NativePortType port_, int c) => wasmModule.wire_next_char(port_, c);
+
+  void wire_handle_chars(NativePortType port_, List<dynamic> chars) => wasmModule.wire_handle_chars(port_, chars);
+
+  void wire_divide_by(NativePortType port_, int n, int by) => wasmModule.wire_divide_by(port_, n, by);
+
+  void wire_next_non_zero(NativePortType port_, Object n) => wasmModule.wire_next_non_zero(port_, n);
+
+  void wire_greet(NativePortType port_, String name, int times) => wasmModule.wire_greet(port_, name, times);
+
+  void wire_instant_now(NativePortType port_) => wasmModule.wire_instant_now(port_);
+
+  void wire_instant_after(NativePortType port_, Object start, Object millis) =>
+      wasmModule.wire_instant_after(port_, start, millis);
+
+  void wire_one_day_later(NativePortType port_, Object time) => wasmModule.wire_one_day_later(port_, time);
+
+  void wire_shift_range(NativePortType port_, Uint32List range, int by) =>
+      wasmModule.wire_shift_range(port_, range, by);
+
+  void wire_clamp_range(NativePortType port_, Float64List range, double value) =>
+      wasmModule.wire_clamp_range(port_, range, value);
+
+  void wire_describe_size(NativePortType port_, List<dynamic> size, String unit, List<String> labels) =>
+      wasmModule.wire_describe_size(port_, size, unit, labels);
+
+  void wire_split_words(NativePortType port_, String text) => wasmModule.wire_split_words(port_, text);
+
+  void wire_handle_cow_str(NativePortType port_, String s) => wasmModule.wire_handle_cow_str(port_, s);
+
+  void wire_handle_cow_bytes(NativePortType port_, Uint8List bytes) => wasmModule.wire_handle_cow_bytes(port_, bytes);
+
+  void wire_handle_path(NativePortType port_, Uint16List path) => wasmModule.wire_handle_path(port_, path);
+
+  void wire_handle_socket_addr(NativePortType port_, Uint8List addr) => wasmModule.wire_handle_socket_addr(port_, addr);
+
+  void wire_next_port(NativePortType port_, Uint8List addr) => wasmModule.wire_next_port(port_, addr);
+
   void wire_handle_return_unit(NativePortType port_) => wasmModule.wire_handle_return_unit(port_);
 
   dynamic /* void */ wire_handle_return_unit_sync() => wasmModule.wire_handle_return_unit_sync();
 
   void wire_handle_vec_u8(NativePortType port_, Uint8List v) => wasmModule.wire_handle_vec_u8(port_, v);
 
+  void wire_handle_bytes(NativePortType port_, Uint8List bytes) => wasmModule.wire_handle_bytes(port_, bytes);
+
+  void wire_checksum(NativePortType port_, Uint8List data, Uint16List weights) =>
+      wasmModule.wire_checksum(port_, data, weights);
+
+  void wire_count_in_shared_buffer(NativePortType port_, int buffer, int byte) =>
+      wasmModule.wire_count_in_shared_buffer(port_, buffer, byte);
+
+  void wire_next_frame(NativePortType port_, List<dynamic> frame) => wasmModule.wire_next_frame(port_, frame);
+
+  void wire_prefix_sums(NativePortType port_, Uint64List items) => wasmModule.wire_prefix_sums(port_, items);
+
   dynamic /* Uint8List */ wire_handle_vec_u8_sync(Uint8List v) => wasmModule.wire_handle_vec_u8_sync(v);
 
   void wire_handle_vec_of_primitive(NativePortType port_, int n) => wasmModule.wire_handle_vec_of_primitive(port_, n);
@@ -1281,12 +2067,18 @@ class FlutterRustBridgeExampleSingleBlockTestWire
 
   dynamic /* List<dynamic> */ wire_handle_newtype_sync(List<dynamic> arg) => wasmModule.wire_handle_newtype_sync(arg);
 
+  void wire_handle_generic_struct(NativePortType port_, List<dynamic> pair) =>
+      wasmModule.wire_handle_generic_struct(port_, pair);
+
   void wire_handle_list_of_struct(NativePortType port_, List<dynamic> l) =>
       wasmModule.wire_handle_list_of_struct(port_, l);
 
   dynamic /* List<dynamic> */ wire_handle_list_of_struct_sync(List<dynamic> l) =>
       wasmModule.wire_handle_list_of_struct_sync(l);
 
+  void wire_handle_nested_vec(NativePortType port_, List<dynamic> rows) =>
+      wasmModule.wire_handle_nested_vec(port_, rows);
+
   void wire_handle_string_list(NativePortType port_, List<String> names) =>
       wasmModule.wire_handle_string_list(port_, names);
 
@@ -1310,6 +2102,28 @@ class FlutterRustBridgeExampleSingleBlockTestWire
 
   void wire_return_err(NativePortType port_) => wasmModule.wire_return_err(port_);
 
+  void wire_return_custom_error(NativePortType port_, bool fail) => wasmModule.wire_return_custom_error(port_, fail);
+
+  void wire_return_custom_error_unit(NativePortType port_) => wasmModule.wire_return_custom_error_unit(port_);
+
+  dynamic /* int? */ wire_lookup_weekday(int index) => wasmModule.wire_lookup_weekday(index);
+
+  void wire_lookup_string(NativePortType port_, String key) => wasmModule.wire_lookup_string(port_, key);
+
+  void wire_read_setting(NativePortType port_, String key) => wasmModule.wire_read_setting(port_, key);
+
+  void wire_parse_setting(NativePortType port_, String value) => wasmModule.wire_parse_setting(port_, value);
+
+  dynamic /* int */ wire_return_custom_error_sync(bool fail) => wasmModule.wire_return_custom_error_sync(fail);
+
+  void wire_count_until_cancelled(NativePortType port_, int cancel_id_) =>
+      wasmModule.wire_count_until_cancelled(port_, cancel_id_);
+
+  void wire_run_workers(NativePortType port_, int cancel_id_, int workers) =>
+      wasmModule.wire_run_workers(port_, cancel_id_, workers);
+
+  void wire_stopped_workers(NativePortType port_) => wasmModule.wire_stopped_workers(port_);
+
   void wire_return_panic(NativePortType port_) => wasmModule.wire_return_panic(port_);
 
   void wire_handle_optional_return(NativePortType port_, double left, double right) =>
@@ -1335,6 +2149,10 @@ class FlutterRustBridgeExampleSingleBlockTestWire
   void wire_handle_enum_parameter(NativePortType port_, int weekday) =>
       wasmModule.wire_handle_enum_parameter(port_, weekday);
 
+  void wire_http_status_code(NativePortType port_, int status) => wasmModule.wire_http_status_code(port_, status);
+
+  void wire_http_status_of(NativePortType port_, int code) => wasmModule.wire_http_status_of(port_, code);
+
   void wire_handle_customized_struct(NativePortType port_, List<dynamic> val) =>
       wasmModule.wire_handle_customized_struct(port_, val);
 
@@ -1355,6 +2173,11 @@ class FlutterRustBridgeExampleSingleBlockTestWire
 
   void wire_get_message(NativePortType port_) => wasmModule.wire_get_message(port_);
 
+  void wire_get_fallible_message(NativePortType port_, int index) => wasmModule.wire_get_fallible_message(port_, index);
+
+  void wire_is_exit_message(NativePortType port_, List<dynamic> message) =>
+      wasmModule.wire_is_exit_message(port_, message);
+
   void wire_repeat_number(NativePortType port_, int num, int times) => wasmModule.wire_repeat_number(port_, num, times);
 
   void wire_repeat_sequence(NativePortType port_, int seq, int times) =>
@@ -1388,10 +2211,57 @@ class FlutterRustBridgeExampleSingleBlockTestWire
   void wire_handle_stream_sink_at_3(NativePortType port_, int key, int max) =>
       wasmModule.wire_handle_stream_sink_at_3(port_, key, max);
 
+  void wire_call_back_with_numbers(NativePortType port_, NativePortType callback, int max) =>
+      wasmModule.wire_call_back_with_numbers(port_, callback, max);
+
+  void wire_handle_stream_closed_by_drop(NativePortType port_, int max) =>
+      wasmModule.wire_handle_stream_closed_by_drop(port_, max);
+
+  void wire_count_from(NativePortType port_, int from, int count) => wasmModule.wire_count_from(port_, from, count);
+
+  void wire_parse_numbers(NativePortType port_, String input) => wasmModule.wire_parse_numbers(port_, input);
+
+  void wire_handle_broadcast_stream(NativePortType port_, int max) =>
+      wasmModule.wire_handle_broadcast_stream(port_, max);
+
+  void wire_handle_stream_closed_by_threads(NativePortType port_, int threads) =>
+      wasmModule.wire_handle_stream_closed_by_threads(port_, threads);
+
   void wire_get_sum_struct(NativePortType port_) => wasmModule.wire_get_sum_struct(port_);
 
   void wire_get_sum_array(NativePortType port_, int a, int b, int c) => wasmModule.wire_get_sum_array(port_, a, b, c);
 
+  void wire_rename_document(NativePortType port_, List<dynamic> document, String title) =>
+      wasmModule.wire_rename_document(port_, document, title);
+
+  void wire_get_renamed(NativePortType port_, int id, int pace) => wasmModule.wire_get_renamed(port_, id, pace);
+
+  void wire_advance(NativePortType port_, List<dynamic> progress, int by) =>
+      wasmModule.wire_advance(port_, progress, by);
+
+  void wire_next_product_id(NativePortType port_, List<dynamic> id) => wasmModule.wire_next_product_id(port_, id);
+
+  void wire_transpose_cell(NativePortType port_, List<dynamic> cell) => wasmModule.wire_transpose_cell(port_, cell);
+
+  void wire_add_tax(NativePortType port_, List<dynamic> price, int percent) =>
+      wasmModule.wire_add_tax(port_, price, percent);
+
+  void wire_bump_minor(NativePortType port_, Uint8List version) => wasmModule.wire_bump_minor(port_, version);
+
+  void wire_open_session(NativePortType port_, String user) => wasmModule.wire_open_session(port_, user);
+
+  void wire_describe_session(NativePortType port_, List<dynamic> session) =>
+      wasmModule.wire_describe_session(port_, session);
+
+  void wire_sample_at(NativePortType port_, int index) => wasmModule.wire_sample_at(port_, index);
+
+  void wire_shift_sample(NativePortType port_, List<dynamic> sample, double dx) =>
+      wasmModule.wire_shift_sample(port_, sample, dx);
+
+  void wire_tag_label(NativePortType port_, List<dynamic> tag) => wasmModule.wire_tag_label(port_, tag);
+
+  void wire_header_name(NativePortType port_, Uint8List raw) => wasmModule.wire_header_name(port_, raw);
+
   void wire_multiply_by_ten(NativePortType port_, List<dynamic> measure) =>
       wasmModule.wire_multiply_by_ten(port_, measure);
 
@@ -1401,6 +2271,10 @@ class FlutterRustBridgeExampleSingleBlockTestWire
 
   void wire_handle_big_buffers(NativePortType port_) => wasmModule.wire_handle_big_buffers(port_);
 
+  void wire_handle_i128(NativePortType port_, Uint8List value) => wasmModule.wire_handle_i128(port_, value);
+
+  void wire_handle_u128(NativePortType port_, Uint8List value) => wasmModule.wire_handle_u128(port_, value);
+
   void wire_datetime_utc(NativePortType port_, Object d) => wasmModule.wire_datetime_utc(port_, d);
 
   void wire_datetime_local(NativePortType port_, Object d) => wasmModule.wire_datetime_local(port_, d);
@@ -1412,6 +2286,8 @@ class FlutterRustBridgeExampleSingleBlockTestWire
 
   void wire_duration(NativePortType port_, Object d) => wasmModule.wire_duration(port_, d);
 
+  void wire_datetime_fixed_offset(NativePortType port_, Object d) => wasmModule.wire_datetime_fixed_offset(port_, d);
+
   void wire_test_chrono(NativePortType port_) => wasmModule.wire_test_chrono(port_);
 
   void wire_test_precise_chrono(NativePortType port_) => wasmModule.wire_test_precise_chrono(port_);
@@ -1426,6 +2302,10 @@ class FlutterRustBridgeExampleSingleBlockTestWire
   void wire_handle_nested_uuids(NativePortType port_, List<dynamic> ids) =>
       wasmModule.wire_handle_nested_uuids(port_, ids);
 
+  void wire_handle_decimal(NativePortType port_, String amount) => wasmModule.wire_handle_decimal(port_, amount);
+
+  void wire_sum_decimals(NativePortType port_, List<dynamic> amounts) => wasmModule.wire_sum_decimals(port_, amounts);
+
   void wire_new_msgid(NativePortType port_, Uint8List id) => wasmModule.wire_new_msgid(port_, id);
 
   void wire_use_msgid(NativePortType port_, List<dynamic> id) => wasmModule.wire_use_msgid(port_, id);
@@ -1443,6 +2323,12 @@ class FlutterRustBridgeExampleSingleBlockTestWire
 
   void wire_last_number(NativePortType port_, Float64List array) => wasmModule.wire_last_number(port_, array);
 
+  void wire_repeat_byte_4(NativePortType port_, int byte) => wasmModule.wire_repeat_byte_4(port_, byte);
+
+  void wire_repeat_byte_8(NativePortType port_, int byte) => wasmModule.wire_repeat_byte_8(port_, byte);
+
+  void wire_transpose(NativePortType port_, Float64List matrix) => wasmModule.wire_transpose(port_, matrix);
+
   void wire_nested_id(NativePortType port_, List<dynamic> id) => wasmModule.wire_nested_id(port_, id);
 
   dynamic /* String */ wire_sync_accept_dart_opaque(Object opaque) => wasmModule.wire_sync_accept_dart_opaque(opaque);
@@ -1484,8 +2370,21 @@ class FlutterRustBridgeExampleSingleBlockTestWire
   void wire_run_enum_opaque(NativePortType port_, List<dynamic> opaque) =>
       wasmModule.wire_run_enum_opaque(port_, opaque);
 
+  void wire_create_trait_object(NativePortType port_) => wasmModule.wire_create_trait_object(port_);
+
+  void wire_create_impl_trait(NativePortType port_) => wasmModule.wire_create_impl_trait(port_);
+
+  void wire_run_trait_object(NativePortType port_, Object obj) => wasmModule.wire_run_trait_object(port_, obj);
+
   void wire_run_opaque(NativePortType port_, Object opaque) => wasmModule.wire_run_opaque(port_, opaque);
 
+  void wire_create_shared_opaque(NativePortType port_) => wasmModule.wire_create_shared_opaque(port_);
+
+  void wire_run_shared_opaque(NativePortType port_, Object opaque) => wasmModule.wire_run_shared_opaque(port_, opaque);
+
+  void wire_shared_opaque_address(NativePortType port_, Object opaque) =>
+      wasmModule.wire_shared_opaque_address(port_, opaque);
+
   void wire_run_opaque_with_delay(NativePortType port_, Object opaque) =>
       wasmModule.wire_run_opaque_with_delay(port_, opaque);
 
@@ -1549,6 +2448,10 @@ class FlutterRustBridgeExampleSingleBlockTestWire
 
   dynamic /* Object */ wire_frb_sync_generator_test() => wasmModule.wire_frb_sync_generator_test();
 
+  void wire_observe_counter(NativePortType port_, Object counter) => wasmModule.wire_observe_counter(port_, counter);
+
+  void wire_is_counter_alive(NativePortType port_, Object counter) => wasmModule.wire_is_counter_alive(port_, counter);
+
   void wire_handle_type_alias_id(NativePortType port_, Object input) =>
       wasmModule.wire_handle_type_alias_id(port_, input);
 
@@ -1560,6 +2463,30 @@ class FlutterRustBridgeExampleSingleBlockTestWire
 
   void wire_empty_struct(NativePortType port_, List<dynamic> empty) => wasmModule.wire_empty_struct(port_, empty);
 
+  void wire_handle_hash_map(NativePortType port_, List<dynamic> map) => wasmModule.wire_handle_hash_map(port_, map);
+
+  void wire_handle_hash_map_of_struct(NativePortType port_, List<dynamic> map) =>
+      wasmModule.wire_handle_hash_map_of_struct(port_, map);
+
+  void wire_count_weekdays(NativePortType port_, List<dynamic> days) => wasmModule.wire_count_weekdays(port_, days);
+
+  void wire_index_words(NativePortType port_, List<String> words) => wasmModule.wire_index_words(port_, words);
+
+  void wire_handle_colliding_keys(NativePortType port_, List<dynamic> map) =>
+      wasmModule.wire_handle_colliding_keys(port_, map);
+
+  void wire_handle_btree_set(NativePortType port_, List<String> set) => wasmModule.wire_handle_btree_set(port_, set);
+
+  void wire_handle_btree_map(NativePortType port_, List<dynamic> map) => wasmModule.wire_handle_btree_map(port_, map);
+
+  void wire_group_attributes(NativePortType port_, List<dynamic> attributes) =>
+      wasmModule.wire_group_attributes(port_, attributes);
+
+  void wire_handle_tuple(NativePortType port_, List<dynamic> tuple) => wasmModule.wire_handle_tuple(port_, tuple);
+
+  void wire_handle_nested_tuple(NativePortType port_, List<dynamic> tuple) =>
+      wasmModule.wire_handle_nested_tuple(port_, tuple);
+
   void wire_sum__method__SumWith(NativePortType port_, List<dynamic> that, int y, int z) =>
       wasmModule.wire_sum__method__SumWith(port_, that, y, z);
 
@@ -1585,35 +2512,189 @@ class FlutterRustBridgeExampleSingleBlockTestWire
   void wire_handle_some_static_stream_sink_single_arg__static_method__ConcatenateWith(NativePortType port_) =>
       wasmModule.wire_handle_some_static_stream_sink_single_arg__static_method__ConcatenateWith(port_);
 
+  void wire_start__static_method__Counter(NativePortType port_, int from) =>
+      wasmModule.wire_start__static_method__Counter(port_, from);
+
+  void wire_increment__method__Counter(NativePortType port_, Object that, int by) =>
+      wasmModule.wire_increment__method__Counter(port_, that, by);
+
+  void wire_label__method__Counter(NativePortType port_, Object that) =>
+      wasmModule.wire_label__method__Counter(port_, that);
+
+  void wire_reset__method__Counter(NativePortType port_, Object that, int to) =>
+      wasmModule.wire_reset__method__Counter(port_, that, to);
+
+  void wire_count_down__method__Counter(NativePortType port_, Object that) =>
+      wasmModule.wire_count_down__method__Counter(port_, that);
+
+  void wire_increment_later__method__Counter(NativePortType port_, Object that, int by) =>
+      wasmModule.wire_increment_later__method__Counter(port_, that, by);
+
+  void wire_updates__method__Counter(NativePortType port_, Object that) =>
+      wasmModule.wire_updates__method__Counter(port_, that);
+
+  void wire_finish__method__Counter(NativePortType port_, Object that) =>
+      wasmModule.wire_finish__method__Counter(port_, that);
+
+  void wire_open__static_method__Rooms(NativePortType port_) => wasmModule.wire_open__static_method__Rooms(port_);
+
+  void wire_join__method__Rooms(NativePortType port_, Object that, String room) =>
+      wasmModule.wire_join__method__Rooms(port_, that, room);
+
+  void wire_post__method__Rooms(NativePortType port_, Object that, String room, String message) =>
+      wasmModule.wire_post__method__Rooms(port_, that, room, message);
+
+  void wire_close_room__method__Rooms(NativePortType port_, Object that, String room) =>
+      wasmModule.wire_close_room__method__Rooms(port_, that, room);
+
+  void wire_named__static_method__Greeter(NativePortType port_, String name) =>
+      wasmModule.wire_named__static_method__Greeter(port_, name);
+
+  void wire_describe__method__Counter(NativePortType port_, Object that) =>
+      wasmModule.wire_describe__method__Counter(port_, that);
+
+  void wire_shout__method__Counter(NativePortType port_, Object that) =>
+      wasmModule.wire_shout__method__Counter(port_, that);
+
+  void wire_describe__method__Greeter(NativePortType port_, Object that) =>
+      wasmModule.wire_describe__method__Greeter(port_, that);
+
+  void wire_shout__method__Greeter(NativePortType port_, Object that) =>
+      wasmModule.wire_shout__method__Greeter(port_, that);
+
+  void wire_dispatch(NativePortType port_, String id, int x) => wasmModule.wire_dispatch(port_, id, x);
+
+  void wire_next_dart_iterator_string(NativePortType port_, Object iterator) =>
+      wasmModule.wire_next_dart_iterator_string(port_, iterator);
+
+  void wire_next_dart_iterator_i_32(NativePortType port_, Object iterator) =>
+      wasmModule.wire_next_dart_iterator_i_32(port_, iterator);
+
+  void wire_changes__method__Counter(NativePortType port_, Object that) =>
+      wasmModule.wire_changes__method__Counter(port_, that);
+
+  void wire_warm_up(NativePortType port_) => wasmModule.wire_warm_up(port_);
+
+  void wire_upgrade_weak_counter(NativePortType port_, Object weak) =>
+      wasmModule.wire_upgrade_weak_counter(port_, weak);
+
+  void wire_instant_duration_since(NativePortType port_, Object that, Object earlier) =>
+      wasmModule.wire_instant_duration_since(port_, that, earlier);
+
+  dynamic /* String */ wire_handle_stream_drain(String arg) => wasmModule.wire_handle_stream_drain(arg);
+
+  dynamic /* List<dynamic> */ wire_handle_stream_of_struct_drain() => wasmModule.wire_handle_stream_of_struct_drain();
+
+  dynamic /* List<dynamic> */ wire_register_event_listener_drain() => wasmModule.wire_register_event_listener_drain();
+
+  dynamic /* List<dynamic> */ wire_handle_stream_sink_at_1_drain(int key, int max) =>
+      wasmModule.wire_handle_stream_sink_at_1_drain(key, max);
+
+  dynamic /* List<dynamic> */ wire_handle_stream_sink_at_2_drain(int key, int max) =>
+      wasmModule.wire_handle_stream_sink_at_2_drain(key, max);
+
+  dynamic /* List<dynamic> */ wire_handle_stream_sink_at_3_drain(int key, int max) =>
+      wasmModule.wire_handle_stream_sink_at_3_drain(key, max);
+
+  dynamic /* int */ wire_handle_stream_closed_by_drop_drain(int max) =>
+      wasmModule.wire_handle_stream_closed_by_drop_drain(max);
+
+  dynamic /* int */ wire_count_from_drain(int from, int count) => wasmModule.wire_count_from_drain(from, count);
+
+  dynamic /* int */ wire_parse_numbers_drain(String input) => wasmModule.wire_parse_numbers_drain(input);
+
+  dynamic /* int */ wire_handle_broadcast_stream_drain(int max) => wasmModule.wire_handle_broadcast_stream_drain(max);
+
+  dynamic /* int */ wire_handle_stream_closed_by_threads_drain(int threads) =>
+      wasmModule.wire_handle_stream_closed_by_threads_drain(threads);
+
   dynamic /*  */ drop_opaque_BoxDartDebug(ptr) => wasmModule.drop_opaque_BoxDartDebug(ptr);
 
   int /* *const c_void */ share_opaque_BoxDartDebug(ptr) => wasmModule.share_opaque_BoxDartDebug(ptr);
 
+  int /* *const c_void */ send_opaque_BoxDartDebug(ptr) => wasmModule.send_opaque_BoxDartDebug(ptr);
+
+  dynamic /*  */ drop_opaque_Counter(ptr) => wasmModule.drop_opaque_Counter(ptr);
+
+  int /* *const c_void */ share_opaque_Counter(ptr) => wasmModule.share_opaque_Counter(ptr);
+
+  int /* *const c_void */ send_opaque_Counter(ptr) => wasmModule.send_opaque_Counter(ptr);
+
+  dynamic /*  */ drop_opaque_DartIteratorI32(ptr) => wasmModule.drop_opaque_DartIteratorI32(ptr);
+
+  int /* *const c_void */ share_opaque_DartIteratorI32(ptr) => wasmModule.share_opaque_DartIteratorI32(ptr);
+
+  int /* *const c_void */ send_opaque_DartIteratorI32(ptr) => wasmModule.send_opaque_DartIteratorI32(ptr);
+
+  dynamic /*  */ drop_opaque_DartIteratorString(ptr) => wasmModule.drop_opaque_DartIteratorString(ptr);
+
+  int /* *const c_void */ share_opaque_DartIteratorString(ptr) => wasmModule.share_opaque_DartIteratorString(ptr);
+
+  int /* *const c_void */ send_opaque_DartIteratorString(ptr) => wasmModule.send_opaque_DartIteratorString(ptr);
+
   dynamic /*  */ drop_opaque_FrbOpaqueReturn(ptr) => wasmModule.drop_opaque_FrbOpaqueReturn(ptr);
 
   int /* *const c_void */ share_opaque_FrbOpaqueReturn(ptr) => wasmModule.share_opaque_FrbOpaqueReturn(ptr);
 
+  int /* *const c_void */ send_opaque_FrbOpaqueReturn(ptr) => wasmModule.send_opaque_FrbOpaqueReturn(ptr);
+
   dynamic /*  */ drop_opaque_FrbOpaqueSyncReturn(ptr) => wasmModule.drop_opaque_FrbOpaqueSyncReturn(ptr);
 
   int /* *const c_void */ share_opaque_FrbOpaqueSyncReturn(ptr) => wasmModule.share_opaque_FrbOpaqueSyncReturn(ptr);
 
+  int /* *const c_void */ send_opaque_FrbOpaqueSyncReturn(ptr) => wasmModule.send_opaque_FrbOpaqueSyncReturn(ptr);
+
+  dynamic /*  */ drop_opaque_Greeter(ptr) => wasmModule.drop_opaque_Greeter(ptr);
+
+  int /* *const c_void */ share_opaque_Greeter(ptr) => wasmModule.share_opaque_Greeter(ptr);
+
+  int /* *const c_void */ send_opaque_Greeter(ptr) => wasmModule.send_opaque_Greeter(ptr);
+
   dynamic /*  */ drop_opaque_HideData(ptr) => wasmModule.drop_opaque_HideData(ptr);
 
   int /* *const c_void */ share_opaque_HideData(ptr) => wasmModule.share_opaque_HideData(ptr);
 
+  int /* *const c_void */ send_opaque_HideData(ptr) => wasmModule.send_opaque_HideData(ptr);
+
   dynamic /*  */ drop_opaque_I32(ptr) => wasmModule.drop_opaque_I32(ptr);
 
   int /* *const c_void */ share_opaque_I32(ptr) => wasmModule.share_opaque_I32(ptr);
 
+  int /* *const c_void */ send_opaque_I32(ptr) => wasmModule.send_opaque_I32(ptr);
+
+  dynamic /*  */ drop_opaque_Instant(ptr) => wasmModule.drop_opaque_Instant(ptr);
+
+  int /* *const c_void */ share_opaque_Instant(ptr) => wasmModule.share_opaque_Instant(ptr);
+
+  int /* *const c_void */ send_opaque_Instant(ptr) => wasmModule.send_opaque_Instant(ptr);
+
   dynamic /*  */ drop_opaque_MutexHideData(ptr) => wasmModule.drop_opaque_MutexHideData(ptr);
 
   int /* *const c_void */ share_opaque_MutexHideData(ptr) => wasmModule.share_opaque_MutexHideData(ptr);
 
+  int /* *const c_void */ send_opaque_MutexHideData(ptr) => wasmModule.send_opaque_MutexHideData(ptr);
+
   dynamic /*  */ drop_opaque_NonSendHideData(ptr) => wasmModule.drop_opaque_NonSendHideData(ptr);
 
   int /* *const c_void */ share_opaque_NonSendHideData(ptr) => wasmModule.share_opaque_NonSendHideData(ptr);
 
+  int /* *const c_void */ send_opaque_NonSendHideData(ptr) => wasmModule.send_opaque_NonSendHideData(ptr);
+
+  dynamic /*  */ drop_opaque_Rooms(ptr) => wasmModule.drop_opaque_Rooms(ptr);
+
+  int /* *const c_void */ share_opaque_Rooms(ptr) => wasmModule.share_opaque_Rooms(ptr);
+
+  int /* *const c_void */ send_opaque_Rooms(ptr) => wasmModule.send_opaque_Rooms(ptr);
+
   dynamic /*  */ drop_opaque_RwLockHideData(ptr) => wasmModule.drop_opaque_RwLockHideData(ptr);
 
   int /* *const c_void */ share_opaque_RwLockHideData(ptr) => wasmModule.share_opaque_RwLockHideData(ptr);
+
+  int /* *const c_void */ send_opaque_RwLockHideData(ptr) => wasmModule.send_opaque_RwLockHideData(ptr);
+
+  dynamic /*  */ drop_opaque_WeakCounter(ptr) => wasmModule.drop_opaque_WeakCounter(ptr);
+
+  int /* *const c_void */ share_opaque_WeakCounter(ptr) => wasmModule.share_opaque_WeakCounter(ptr);
+
+  int /* *const c_void */ send_opaque_WeakCounter(ptr) => wasmModule.send_opaque_WeakCounter(ptr);
 }
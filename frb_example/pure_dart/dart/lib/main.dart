@@ -1,10 +1,16 @@
+import 'dart:async';
+import 'dart:convert';
 import 'dart:developer';
 
+import 'package:decimal/decimal.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge.dart';
 import 'package:test/test.dart';
 import 'package:uuid/uuid.dart';
 import 'ffi.io.dart' if (dart.library.html) 'ffi.web.dart';
 import 'bridge_definitions.dart';
+import 'money.dart';
+import 'version.dart';
+import 'sendable.io.dart' if (dart.library.html) 'sendable.web.dart';
 
 const isWeb = bool.fromEnvironment('dart.library.html');
 
@@ -26,10 +32,58 @@ void main(List<String> args) async {
     expect(await api.simpleAdder(a: 42, b: 100), 142);
   });
 
+  test('dart call warmUp', () async {
+    await api.warmUp();
+    // Later calls return right away, and the init function still ran once.
+    await api.warmUp();
+    expect(await api.initCount(), 1);
+  });
+
+  test('dart call initApp', () async {
+    // Other calls already ran it, so calling it again does nothing.
+    expect(await api.initCount(), 1);
+    await api.initApp();
+    expect(await api.initCount(), 1);
+  });
+
+  test('constants', () async {
+    expect(maxCounters, 1000);
+    expect(greeting, 'Hello, world!');
+    expect(defaultRatio, 1.5);
+    expect(await api.getConstants(), Tuple3(maxCounters, greeting, defaultRatio));
+  });
+
+  test('dart call simpleAdderAsync', () async {
+    expect(await api.simpleAdderAsync(a: 42, b: 100), 184);
+  });
+
   test('dart call simpleAdderSync', () {
     expect(api.simpleAdderSync(a: 42, b: 100), 142);
   });
 
+  test('dart call scale', () {
+    expect(api.scale(x: 2, factor: 1.5), 3);
+  });
+
+  test('dart call sumVector', () {
+    expect(api.sumVector(v: F32Array3(Float32List.fromList([1, 2, 3.5]))), 6.5);
+  });
+
+  test('call observer', () async {
+    final calls = <String>[];
+    FlutterRustBridgeBase.callObserver = (constMeta) {
+      calls.add('start ${constMeta.debugName}');
+      return (error) => calls.add('end ${constMeta.debugName}');
+    };
+    try {
+      expect(await api.simpleAdder(a: 42, b: 100), 142);
+      expect(api.simpleAdderSync(a: 42, b: 100), 142);
+    } finally {
+      FlutterRustBridgeBase.callObserver = null;
+    }
+    expect(calls, ['start simple_adder', 'end simple_adder', 'start simple_adder_sync', 'end simple_adder_sync']);
+  });
+
   test('dart call primitiveTypes', () async {
     expect(
         await api.primitiveTypes(myI32: 123, myI64: 10000000000000, myF64: 12345678901234567890.123, myBool: true), 42);
@@ -74,11 +128,158 @@ void main(List<String> args) async {
     expect(api.handleStringSync(s: "Hello\u0000world!"), isWeb ? "Hello\u0000world!Hello\u0000world!" : "");
   });
 
+  test('dart call nextChar', () async {
+    expect(await api.nextChar(c: 'a'), 'b');
+    // Characters outside of the Basic Multilingual Plane are two UTF-16 code units in Dart.
+    expect(await api.nextChar(c: '😀'), '😁');
+    expect(() => api.nextChar(c: 'ab'), throwsArgumentError);
+    expect(() => api.nextChar(c: ''), throwsArgumentError);
+  });
+
+  test('dart call handleChars', () async {
+    expect(await api.handleChars(chars: ['x', 'é', 'b']), 'é');
+    expect(await api.handleChars(chars: []), isNull);
+  });
+
+  test('dart call divideBy', () async {
+    expect(await api.divideBy(n: 7, by: 2), 3);
+    await expectLater(api.divideBy(n: 7, by: 0), throwsA(isA<FfiException>()));
+  });
+
+  test('dart call nextNonZero', () async {
+    expect(await api.nextNonZero(n: 1), 2);
+  });
+
+  test('dart call nextNonZero wrapping to zero', () async {
+    // u64::MAX wraps around to zero, which is `None`.
+    expect(await api.nextNonZero(n: -1), isNull);
+  }, skip: skipWeb('u64::MAX is not -1 on the web.'));
+
+  test('dart call greet', () async {
+    expect(await api.greet(), 'Hello, world!');
+    expect(await api.greet(name: 'Dart'), 'Hello, Dart!');
+    expect(await api.greet(times: 2), 'Hello, world!Hello, world!');
+  });
+
+  test('dart call oneDayLater', () async {
+    final time = DateTime.utc(2022, 09, 10, 20, 48, 53, 123);
+    final later = await api.oneDayLater(time: time);
+    expect(later.isUtc, true);
+    expect(later, DateTime.utc(2022, 09, 11, 20, 48, 53, 123));
+    // Times before the Unix epoch work too.
+    expect(await api.oneDayLater(time: DateTime.utc(1969, 12, 30)), DateTime.utc(1969, 12, 31));
+    // A local time is the same instant.
+    final local = DateTime(2022, 09, 10, 12);
+    expect(await api.oneDayLater(time: local), local.add(const Duration(days: 1)).toUtc());
+  });
+
+  test('dart call instantAfter', () async {
+    final start = await api.instantNow();
+    final later = await api.instantAfter(start: start, millis: 1500);
+    expect(await later.durationSince(earlier: start), const Duration(milliseconds: 1500));
+    expect(await start.durationSince(earlier: later), Duration.zero);
+    start.dispose();
+    later.dispose();
+  }, skip: skipWeb('Instant::now is not supported on the web.'));
+
+  test('dart call shiftRange', () async {
+    final shifted = await api.shiftRange(range: const RustRange(1, 4), by: 10);
+    expect(shifted, const RustRange(11, 13, inclusive: true));
+    expect(shifted.contains(13), true);
+    expect(() => api.shiftRange(range: const RustRange(1, 4, inclusive: true), by: 1), throwsArgumentError);
+    await expectLater(api.shiftRange(range: const RustRange(4, 1), by: 1), throwsA(isA<FfiException>()));
+  });
+
+  test('dart call clampRange', () async {
+    expect(await api.clampRange(range: const RustRange(0.5, 1.5, inclusive: true), value: 3), 1.5);
+    expect(await api.clampRange(range: const RustRange(0.5, 1.5, inclusive: true), value: 1), 1);
+  });
+
+  test('dart call describeSize', () async {
+    expect(await api.describeSize(size: MySize(width: 2, height: 3), unit: 'px', labels: ['a', 'b']), '2x3px a,b');
+  });
+
+  test('dart call splitWords', () async {
+    expect(await api.splitWords(text: 'a bc  d').toList(), ['a', 'bc', 'd']);
+    expect(await api.splitWords(text: '').toList(), isEmpty);
+    // Cancelling before the end disposes the iterator.
+    expect(await api.splitWords(text: 'a b c').first, 'a');
+  });
+
+  test('dart call handleCowStr', () async {
+    expect(await api.handleCowStr(s: 'hello'), 'HELLO');
+    expect(await api.handleCowStr(s: ''), 'empty');
+  });
+  test('dart call handleCowBytes', () async {
+    expect(await api.handleCowBytes(bytes: Uint8List.fromList([1, 2])), Uint8List.fromList([1, 2]));
+  });
+  test('dart call handlePath', () async {
+    expect(await api.handlePath(path: 'notes'), 'notes.txt');
+  });
+  test('dart call handlePath with a non-Unicode path', () async {
+    // A byte which is not valid UTF-8 is represented by an unpaired surrogate.
+    expect(await api.handlePath(path: 'caf\uDCE9'), 'caf\uDCE9.txt');
+  }, skip: skipWeb('Paths are always Unicode on the web.'));
+  test('dart call handleSocketAddr', () async {
+    final ip = await api.handleSocketAddr(addr: SocketAddress(IpAddress.parse('127.0.0.1'), 80));
+    expect(ip, IpAddress.parse('127.0.0.1'));
+    expect(ip.type, IpAddressType.v4);
+  });
+  test('dart call nextPort', () async {
+    final addr = SocketAddress(IpAddress.parse('fe80::1'), 8080, scopeId: 2);
+    expect(await api.nextPort(addr: addr), SocketAddress(IpAddress.parse('fe80::1'), 8081, scopeId: 2));
+  });
+  test('dart call checksum', () async {
+    final data = Uint8List.fromList([1, 2, 3]);
+    expect(await api.checksum(data: data, weights: Uint16List.fromList([10, 100])), 1 * 10 + 2 * 100 + 3 * 10);
+    // The borrowed list is neither consumed nor modified.
+    expect(data, [1, 2, 3]);
+    expect(await api.checksum(data: data, weights: Uint16List.fromList([1])), 6);
+  });
+
+  test('dart call countInSharedBuffer', () async {
+    final buffer = api.createSharedBuffer([1, 2, 2, 3, 2]);
+    expect(buffer.bytes, [1, 2, 2, 3, 2]);
+    expect(await api.countInSharedBuffer(buffer: buffer, byte: 2), 3);
+    expect(await api.countInSharedBuffer(buffer: buffer, byte: 4), 0);
+    buffer.dispose();
+    expect(() => api.countInSharedBuffer(buffer: buffer, byte: 2), throwsStateError);
+  }, skip: skipWeb('SharedBuffer is not supported on the web.'));
+
+  test('dart call allocateSharedBuffer', () async {
+    final buffer = api.allocateSharedBuffer(1000, (bytes) {
+      bytes.fillRange(0, 1000, 7);
+      bytes[0] = 1;
+    });
+    final count = api.countInSharedBuffer(buffer: buffer, byte: 7);
+    // The call keeps the bytes borrowed until it completes.
+    buffer.dispose();
+    expect(await count, 999);
+    expect(() => api.allocateSharedBuffer(1, (_) => throw const FormatException()), throwsFormatException);
+  }, skip: skipWeb('SharedBuffer is not supported on the web.'));
+
+  test('dart call nextFrame', () async {
+    final frame = await api.nextFrame(
+        frame: Frame(index: 1, pixels: Uint8List.fromList([0, 255]), depths: Float32List.fromList([1.5])));
+    expect(frame.index, 2);
+    expect(frame.pixels, [1, 0]);
+    expect(frame.depths, [3.0]);
+    expect((await api.nextFrame(frame: Frame(index: 0, pixels: Uint8List(0)))).depths, isNull);
+  });
+
+  test('dart call prefixSums', () async {
+    expect(await api.prefixSums(items: Uint64List.fromList([1, 2, 3])), Uint64List.fromList([1, 3, 6]));
+    expect(await api.prefixSums(items: Uint64List(0)), Uint64List(0));
+  });
+
   test('dart call handleVecU8', () async {
     final len = 100000;
     expect(await api.handleVecU8(v: Uint8List.fromList(List.filled(len, 127))),
         Uint8List.fromList(List.filled(len * 2, 127)));
   });
+  test('dart call handleBytes', () async {
+    expect(await api.handleBytes(bytes: Uint8List.fromList([1, 2, 3])), Uint8List.fromList([2, 3]));
+  });
   test('dart call handleVecU8Sync', () {
     final len = 100000;
     expect(api.handleVecU8Sync(v: Uint8List.fromList(List.filled(len, 127))),
@@ -165,6 +366,13 @@ void main(List<String> args) async {
     expect(newtypeResp.field0, 84);
   });
 
+  test('dart call handleGenericStruct', () async {
+    final pair = await api.handleGenericStruct(pair: PairI32(first: 1, second: Int32List.fromList([2, 3])));
+    expect(pair, isA<PairString>());
+    expect(pair.first, '1');
+    expect(pair.second, ['2', '3']);
+  });
+
   test('dart call handleListOfStruct', () async {
     final listOfStructResp =
         await api.handleListOfStruct(l: [MySize(width: 42, height: 100), MySize(width: 420, height: 1000)]);
@@ -184,6 +392,20 @@ void main(List<String> args) async {
     expect(listOfStructResp[3].width, 420);
   });
 
+  test('dart call handleNestedVec', () async {
+    final nested = await api.handleNestedVec(rows: [
+      Int32List.fromList([1, 2]),
+      Int32List(0),
+    ]);
+    expect(nested, [
+      [
+        ['1'],
+        ['2', '2']
+      ],
+      []
+    ]);
+  });
+
   test('dart call handleStringList', () async {
     final names = await api.handleStringList(names: ['Steve', 'Bob', 'Alex']);
     expect(names, ['Steve', 'Bob', 'Alex']);
@@ -233,6 +455,7 @@ void main(List<String> args) async {
         fail("exception not thrown");
       } on FfiException catch (e) {
         print('dart catch e: $e');
+        expect(e is FrbPanicException, mode == 'PANIC');
       }
     }
   });
@@ -273,6 +496,81 @@ void main(List<String> args) async {
     testHandleStream(api.handleStreamSinkAt3);
   });
 
+  test('dart call call_back_with_numbers', () async {
+    final received = <int>[];
+    final done = Completer<void>();
+    await api.callBackWithNumbers(
+        max: 3,
+        callback: (i) {
+          received.add(i);
+          if (received.length == 3) done.complete();
+        });
+    await done.future;
+    expect(received, [0, 1, 2]);
+  });
+
+  test('dart call handle_stream_closed_by_drop', () async {
+    expect(await api.handleStreamClosedByDrop(max: 3).toList(), [0, 1, 2]);
+  });
+
+  test('dart listen twice to handle_stream_closed_by_drop', () async {
+    final stream = api.handleStreamClosedByDrop(max: 1);
+    final items = stream.toList();
+    expect(() => stream.listen(null), throwsStateError);
+    expect(await items, [0]);
+  });
+
+  test('dart call count_from', () async {
+    final counting = await api.countFrom(from: 5, count: 3);
+    expect(counting.initial, 5);
+    expect(await counting.updates.toList(), [6, 7]);
+    await expectLater(api.countFrom(from: 5, count: 0), throwsA(isA<FfiException>()));
+  });
+
+  test('dart call count_from observes cancellation', () async {
+    final calls = <String>[];
+    FlutterRustBridgeBase.callObserver = (constMeta) => (error) => calls.add('end ${constMeta.debugName}');
+    try {
+      final counting = await api.countFrom(from: 0, count: 1000);
+      expect(await counting.updates.first, 1);
+    } finally {
+      FlutterRustBridgeBase.callObserver = null;
+    }
+    expect(calls, ['end count_from']);
+  });
+
+  test('dart call parseNumbersDrain', () {
+    expect(api.parseNumbersDrain(input: '1\n2'), [1, 2]);
+    expect(() => api.parseNumbersDrain(input: '1\nx'), throwsA(isA<ParseError_NotANumber>()));
+  }, skip: skipWeb('Streams cannot be drained on the web.'));
+
+  test('dart call handle_broadcast_stream', () async {
+    final stream = api.handleBroadcastStream(max: 3);
+    expect(stream.isBroadcast, true);
+    expect(await Future.wait([stream.toList(), stream.toList()]), [
+      [0, 1, 2],
+      [0, 1, 2]
+    ]);
+  });
+
+  test('dart call parse_numbers', () async {
+    final events = [];
+    await api.parseNumbers(input: '1\nx\n2\n\n3').handleError(events.add).forEach(events.add);
+    expect(events, hasLength(4));
+    expect(events[0], 1);
+    expect((events[1] as ParseError_NotANumber).line, 'x');
+    expect(events[2], 2);
+    // The fatal error is followed by the end of the stream, so `3` is never parsed.
+    expect(events[3], isA<ParseError_Empty>());
+  });
+
+  test('dart call handle_stream_closed_by_threads', () async {
+    // Whichever thread closes the stream first ends it, and the later items are dropped.
+    final items = await api.handleStreamClosedByThreads(threads: 4).toList();
+    expect(items, isNotEmpty);
+    expect(items.length, lessThanOrEqualTo(4));
+  });
+
   test('dart call returnErr', () async {
     try {
       await api.returnErr();
@@ -282,13 +580,104 @@ void main(List<String> args) async {
     }
   });
 
+  test('dart call returnCustomError', () async {
+    await expectLater(api.returnCustomError(fail: false),
+        throwsA(isA<CustomError_Retry>().having((e) => e.afterMs, 'afterMs', 100)));
+    await expectLater(
+        api.returnCustomError(fail: true),
+        throwsA(isA<CustomError_Fail>()
+            .having((e) => e.message, 'message', 'deliberately failed')
+            .having((e) => e.backtrace, 'backtrace', isA<String>())));
+    await expectLater(api.returnCustomErrorUnit(), throwsA(isA<CustomError_Retry>()));
+  });
+
+  test('dart call returnCustomErrorSync', () {
+    expect(api.returnCustomErrorSync(fail: false), 1);
+    expect(() => api.returnCustomErrorSync(fail: true),
+        throwsA(isA<CustomError_Retry>().having((e) => e.afterMs, 'afterMs', 10)));
+  });
+
+  test('dart call lookupWeekday', () async {
+    var completed = false;
+    final future = api.lookupWeekday(index: 0)..then((_) => completed = true);
+    // The future completes without waiting for a message from another thread.
+    await Future.microtask(() {});
+    expect(completed, true);
+    expect(await future, Weekdays.Monday);
+    expect(await api.lookupWeekday(index: 1), isNull);
+    await expectLater(api.lookupWeekday(index: 7),
+        throwsA(isA<CustomError_Retry>().having((e) => e.afterMs, 'afterMs', 7)));
+  });
+
+  test('dart call lookupString', () async {
+    expect(await api.lookupString(key: 'found'), 'Error: not found');
+    await expectLater(api.lookupString(key: 'missing'), throwsA('Ok: missing'));
+  });
+
+  test('dart call readSetting', () async {
+    expect(await api.readSetting(key: 'theme'), 'dark');
+    await expectLater(
+        api.readSetting(key: 'font'),
+        throwsA(isA<FrbCodedException<SettingError>>()
+            .having((e) => e.code, 'code', SettingError.NotFound)
+            .having((e) => e.message, 'message', 'No setting font')
+            .having((e) => e.source, 'source', null)));
+  });
+
+  test('dart call parseSetting', () async {
+    expect(await api.parseSetting(value: '12'), 12);
+    await expectLater(
+        api.parseSetting(value: 'x'),
+        throwsA(isA<FrbCodedException<SettingError>>()
+            .having((e) => e.code, 'code', SettingError.Invalid)
+            .having((e) => e.source, 'source', isA<CustomError_Fail>().having((e) => e.message, 'message', 'x'))));
+  });
+
+  test('dart call returnCustomErrorSyncResult', () {
+    final ok = api.returnCustomErrorSyncResult(fail: false);
+    expect(ok.isOk, true);
+    expect(ok.value, 1);
+    final failed = api.returnCustomErrorSyncResult(fail: true);
+    expect(failed.isOk, false);
+    expect(failed.error, isA<CustomError_Retry>().having((e) => e.afterMs, 'afterMs', 10));
+    expect(() => failed.value, throwsStateError);
+  });
+
+  test('dart call handleSyncReturnResult', () {
+    expect(api.handleSyncReturnResult(mode: 'NORMAL').value, List.filled(100, 42));
+    expect(api.handleSyncReturnResult(mode: 'RESULT_ERR').error, isA<FfiException>());
+    expect(api.handleSyncReturnResult(mode: 'PANIC').error, isA<FrbPanicException>());
+  });
+
+  test('dart call countUntilCancelled', () async {
+    final future = api.countUntilCancelled();
+    future.cancel();
+    await expectLater(future, throwsA(isA<FlutterRustBridgeCancelledException>()));
+  });
+
+  test('dart call runWorkers', () async {
+    final stopped = await api.stoppedWorkers();
+    final future = api.runWorkers(workers: 3);
+    future.cancel();
+    await expectLater(future, throwsA(isA<FlutterRustBridgeCancelledException>()));
+    while (await api.stoppedWorkers() < stopped + 3) {
+      await Future.delayed(const Duration(milliseconds: 10));
+    }
+  }, skip: skipWeb('Threads cannot be spawned on the web.'));
+
+  test('dart call with timeout', () async {
+    await expectLater(api.countUntilCancelled(timeout: const Duration(milliseconds: 50)),
+        throwsA(isA<FlutterRustBridgeTimeoutException>()));
+  });
+
   test('dart call returnPanic', () async {
     try {
       await api.returnPanic();
       fail("exception not thrown");
     } catch (e) {
       print('dart catch e: $e');
-      expect(e, isA<FfiException>());
+      expect(e, isA<FrbPanicException>());
+      expect((e as FrbPanicException).code, 'PANIC_ERROR');
     }
   });
 
@@ -343,6 +732,19 @@ void main(List<String> args) async {
     }
   });
 
+  test('optional lists and lists of optionals are told apart', () async {
+    final ret = await api.handleOptionalIncrement(
+        opt: ExoticOptionals(
+            attributesNullable: [null, Attribute(key: 'a', value: '1')],
+            nullableAttributes: [Attribute(key: 'b', value: '2'), null]));
+    // `Option<Vec<Attribute>>`: `null` became a list with the pushed item.
+    expect(ret!.attributes?.map((a) => a.key), ['some-attrib']);
+    // `Vec<Option<Attribute>>`: each item keeps its own presence.
+    expect(ret.attributesNullable.map((a) => a?.key), [null, 'a', null]);
+    // `Option<Vec<Option<Attribute>>>`: both levels at once.
+    expect(ret.nullableAttributes?.map((a) => a?.key), ['b', null, null]);
+  });
+
   test('dart call handleIncrementBoxedOptional', () async {
     {
       expect(await api.handleIncrementBoxedOptional(), 42);
@@ -379,6 +781,14 @@ void main(List<String> args) async {
     expect(await api.handleEnumParameter(weekday: Weekdays.Saturday), Weekdays.Saturday);
   });
 
+  test('enum with explicit discriminants', () async {
+    expect(HttpStatus.NotFound.discriminant, 404);
+    expect(HttpStatus.MethodNotAllowed.discriminant, 405);
+    expect(await api.httpStatusCode(status: HttpStatus.MethodNotAllowed), 405);
+    expect(await api.httpStatusOf(code: 404), HttpStatus.NotFound);
+    expect(await api.httpStatusOf(code: 1), null);
+  });
+
   test('dart call handleEnumStruct', () async {
     expect(await api.handleEnumStruct(val: KitchenSink_Empty()), KitchenSink_Empty());
     expect(
@@ -458,6 +868,17 @@ void main(List<String> args) async {
     await Future.delayed(Duration(seconds: 1));
   });
 
+  test('dart call getFallibleMessage()', () async {
+    final message = await api.getFallibleMessage(index: 0);
+    expect((message as ApplicationMessage_DisplayMessage).field0, 'A');
+    await expectLater(api.getFallibleMessage(index: 3), throwsA(isA<FfiException>()));
+  });
+
+  test('dart call isExitMessage()', () async {
+    expect(await api.isExitMessage(message: ApplicationMessage.exit()), true);
+    expect(await api.isExitMessage(message: ApplicationMessage.renderPixel(x: 1, y: 2)), false);
+  });
+
   test('dart call repeatNumber()', () async {
     var numbers = await api.repeatNumber(num: 1, times: 10);
     expect(numbers.field0.toList(), Int32List.fromList([1, 1, 1, 1, 1, 1, 1, 1, 1, 1]));
@@ -535,6 +956,86 @@ void main(List<String> args) async {
     expect(concatenated2, equals("hello world"));
   });
 
+  test('dart call renameDocument', () async {
+    final document = await api.renameDocument(
+        document: Document(id: 1, createdBy: 'Alice', title: 'Draft'), title: 'Final');
+    expect(document.id, 2);
+    expect(document.createdBy, 'Alice');
+    expect(document.title, 'Final');
+  });
+
+  test('dart call fetchRenamed', () async {
+    final renamed = await api.fetchRenamed(id: 1, pace: Pace.fast);
+    expect(renamed.identifier, 2);
+    expect(renamed.pace, Pace.Slow);
+  });
+
+  test('dart call advance', () async {
+    expect((await api.advance(progress: Progress(percentage: 50), by: 20)).percentage, 70);
+    expect((await api.advance(progress: Progress(percentage: 90), by: 20)).percentage, 100);
+  });
+
+  test('struct assertion', () {
+    expect(() => Progress(percentage: 101), throwsA(isA<AssertionError>()));
+  }, skip: releaseMode ? 'Assertions are disabled in release mode.' : null);
+
+  test('dart call nextProductId', () async {
+    expect((await api.nextProductId(id: ProductId(41))).field0, 42);
+  });
+
+  test('dart call transposeCell', () async {
+    final cell = await api.transposeCell(cell: GridCell(1, 2));
+    expect(cell.row, 2);
+    expect(cell.column, 1);
+  });
+
+  test('dart call addTax', () async {
+    final price = await api.addTax(price: const Money.fromCents(1000), percent: 20);
+    expect(price, const Money.fromCents(1200));
+    expect(price.toString(), '12.00');
+  });
+
+  test('dart call bumpMinor', () async {
+    expect(await api.bumpMinor(version: const Version(1, 4, 2)), const Version(1, 5, 0));
+  });
+
+  test('skipped fields', () async {
+    final session = await api.openSession(user: 'ann');
+    expect(session.user, 'ann');
+    // The skipped fields take their default values when the struct comes back.
+    expect(await api.describeSession(session: session), 'ann (0 retries) as guest');
+  });
+
+  test('struct sent as bytes', () async {
+    final sample = await api.sampleAt(index: 3);
+    expect(sample.index, 3);
+    expect(sample.x, 1.5);
+    expect(sample.y, -3);
+    expect(sample.valid, false);
+    expect(sample.level, -3);
+    final shifted = await api.shiftSample(sample: sample, dx: 1);
+    expect(shifted.x, 2.5);
+    expect(shifted.level, -3);
+  });
+
+  test('dart call dispatch', () async {
+    expect(await api.dispatch(id: 'triple', x: 2), 6);
+    expect(await api.dispatch(id: 'negate', x: 2), -2);
+    await expectLater(api.dispatch(id: 'triple', x: 1 << 30), throwsA(isA<FfiException>()));
+    await expectLater(api.dispatch(id: 'negateV2', x: 2), throwsA(isA<FfiException>()));
+  });
+
+  test('const constructor', () async {
+    const tag = Tag(name: 'urgent', color: 0xff0000, aliases: ['asap'], day: Weekdays.Monday);
+    expect(identical(tag, const Tag(name: 'urgent', color: 0xff0000, aliases: ['asap'], day: Weekdays.Monday)), true);
+    expect(await api.tagLabel(tag: tag), 'urgent (asap) on Monday, color Some(16711680)');
+  });
+
+  test('dart call headerName', () async {
+    expect(await api.headerName(raw: Uint8List.fromList('Host:example.com'.codeUnits)), 'Host');
+    expect(await api.headerName(raw: Uint8List.fromList('Host'.codeUnits)), null);
+  });
+
   test('SumWith test', () async {
     final SumWith sumWith = SumWith(bridge: api, x: 3);
     final int sum = await sumWith.sum(y: 1, z: 5);
@@ -617,6 +1118,111 @@ void main(List<String> args) async {
     expect(output, isA<Empty>());
   });
 
+  group('copyWith', () {
+    test('replaces the given fields', () async {
+      final size = await api.handleStruct(arg: MySize(width: 1, height: 2), boxed: MySize(width: 3, height: 4));
+      final wider = size.copyWith(width: 10);
+      expect(wider.width, 10);
+      expect(wider.height, 6);
+      expect(size.width, 4);
+    });
+    test('keeps or clears optional fields', () {
+      final element = Element(tag: 'div', text: 'hello');
+      expect(element.copyWith().tag, 'div');
+      expect(element.copyWith(text: 'bye').tag, 'div');
+      expect(element.copyWith(text: 'bye').text, 'bye');
+      expect(element.copyWith(tag: null).tag, isNull);
+      expect(element.copyWith(tag: null).text, 'hello');
+    });
+  });
+
+  group('json', () {
+    test('struct', () async {
+      final size = await api.handleStruct(arg: MySize(width: 1, height: 2), boxed: MySize(width: 3, height: 4));
+      expect(size.toJson(), {'width': 4, 'height': 6});
+      final back = MySize.fromJson(jsonDecode(jsonEncode(size.toJson())));
+      expect(back.width, 4);
+      expect(back.height, 6);
+    });
+    test('enum', () {
+      expect(Weekdays.Friday.toJson(), 'Friday');
+      expect(Weekdays.values.byName(jsonDecode(jsonEncode(Weekdays.Sunday.toJson()))), Weekdays.Sunday);
+    });
+    test('enum with data', () async {
+      final measure = await api.multiplyByTen(measure: Measure.distance(Distance.map(1.5)));
+      final json = jsonDecode(jsonEncode(measure!.toJson()));
+      expect(json, {
+        'tag': 'Distance',
+        'content': {
+          'field0': {
+            'tag': 'Map',
+            'content': {'field0': 15.0}
+          }
+        }
+      });
+      expect(Measure.fromJson(json), measure);
+      expect(() => Measure.fromJson({'tag': 'Time'}), throwsFormatException);
+    });
+  });
+
+  group('map', () {
+    test('HashMap', () async {
+      final map = await api.handleHashMap(map: {'a': 1, 'b': 2});
+      expect(map, {'a': 2, 'b': 4});
+    });
+    test('HashMap of struct', () async {
+      final map = await api.handleHashMapOfStruct(map: {1: Attribute(key: 'id', value: 'root')});
+      expect(map.keys, [2]);
+      expect(map[2]!.key, 'root');
+      expect(map[2]!.value, 'id');
+    });
+    test('HashMap with enum keys', () async {
+      final counts = await api.countWeekdays(days: [Weekdays.Monday, Weekdays.Friday, Weekdays.Monday]);
+      expect(counts, {Weekdays.Monday: 2, Weekdays.Friday: 1});
+      expect(counts[Weekdays.Monday], 2);
+    });
+    test('HashMap with usize keys', () async {
+      expect(await api.indexWords(words: ['a', 'b']), {0: 'a', 1: 'b'});
+    });
+    test('HashMap with colliding keys', () async {
+      final map = await api.handleCollidingKeys(map: {
+        CollidingKey(field0: 'a'): 1,
+        CollidingKey(field0: 'b'): 2,
+        CollidingKey(field0: 'c'): 3,
+      });
+      // The keys are compared by identity, so look them up by their content.
+      expect(map.map((key, value) => MapEntry(key.field0, value)), {'A': 1, 'B': 2, 'C': 3});
+    });
+    test('BTreeSet', () async {
+      final set = await api.handleBtreeSet(set: {'c', 'a', 'b'});
+      expect(set.toList(), ['a', 'b', 'c']);
+    });
+    test('BTreeMap', () async {
+      final map = await api.handleBtreeMap(map: {3: 'c', 1: 'a', 2: 'b'});
+      expect(map.keys.toList(), [1, 2, 3]);
+      expect(map.values.toList(), ['a', 'b', 'c']);
+    });
+    test('BTreeMap of Vec of struct', () async {
+      final groups = await api.groupAttributes(attributes: [
+        Attribute(key: 'class', value: 'a'),
+        Attribute(key: 'id', value: 'root'),
+        Attribute(key: 'class', value: 'b'),
+      ]);
+      expect(groups.keys.toList(), ['class', 'id']);
+      expect(groups['class']!.map((attribute) => attribute.value), ['a', 'b']);
+      expect(groups['id']!.map((attribute) => attribute.value), ['root']);
+    });
+  });
+
+  group('tuple', () {
+    test('tuple', () async {
+      expect(await api.handleTuple(tuple: Tuple3(1, 'a', true)), Tuple3(true, 'a', 1));
+    });
+    test('nested tuple', () async {
+      expect(await api.handleNestedTuple(tuple: Tuple2(1, Tuple2('a', true))), Tuple2(Tuple2('a', true), 1));
+    });
+  });
+
   group('Platform-specific support', () {
     test('Int64List', () {
       final list = Int64List.fromList([-1, -2, -3, -4, -5]);
@@ -640,6 +1246,17 @@ void main(List<String> args) async {
       expect(list.int64[1], BigInt.parse('9223372036854775807'));
       expect(list.uint64[0], BigInt.parse('0xFFFFFFFFFFFFFFFF'), reason: 'uint64');
     });
+    test('i128 and u128', () async {
+      final i128Min = -(BigInt.one << 127);
+      final u128Max = (BigInt.one << 128) - BigInt.one;
+      expect(await api.handleI128(value: i128Min), i128Min + BigInt.one);
+      expect(await api.handleI128(value: -BigInt.one), BigInt.zero);
+      expect(await api.handleU128(value: u128Max), u128Max - BigInt.one);
+    });
+    test('i128 and u128 out of range', () async {
+      expect(() => api.handleI128(value: BigInt.one << 127), throwsArgumentError);
+      expect(() => api.handleU128(value: -BigInt.one), throwsArgumentError);
+    });
   });
 
   group('chrono feature', () {
@@ -679,6 +1296,14 @@ void main(List<String> args) async {
       expect(resp.millisecondsSinceEpoch, date.millisecondsSinceEpoch);
       expect(resp.microsecondsSinceEpoch, date.microsecondsSinceEpoch);
     });
+    test('DateTime<FixedOffset>', () async {
+      final date = DateTime.utc(2022, 09, 10, 20, 48, 53, 123);
+      final resp = await api.datetimeFixedOffset(d: date);
+      // The +02:00 offset set by Rust is lost, but the instant is kept.
+      expect(resp.isUtc, true);
+      expect(resp.hour, date.hour);
+      expect(resp.millisecondsSinceEpoch, date.millisecondsSinceEpoch);
+    });
     test('Empty DateTime', () async {
       final resp = await api.optionalEmptyDatetimeUtc(d: null);
       expect(resp, null);
@@ -741,6 +1366,27 @@ void main(List<String> args) async {
       expect(wrapper.one, outputs.one);
       expect(wrapper.many, outputs.many);
     });
+    test('invalid uuid buffers', () {
+      expect(() => wire2apiUuid(Uint8List(15)), throwsFormatException);
+      expect(() => wire2apiUuids(Uint8List(17)), throwsFormatException);
+    });
+  });
+
+  group('rust_decimal feature', () {
+    test('Decimal', () async {
+      expect(await api.handleDecimal(amount: Decimal.parse('1234.5678')), Decimal.parse('-2469.1356'));
+      expect(await api.handleDecimal(amount: Decimal.parse('-0.0000000000000000000000000001')),
+          Decimal.parse('0.0000000000000000000000000002'));
+    });
+    test('Vec<Decimal>', () async {
+      // 0.1 + 0.2 is exact, since no float is used on the way.
+      expect(await api.sumDecimals(amounts: [Decimal.parse('0.1'), Decimal.parse('0.2')]), Decimal.parse('0.3'));
+      expect(await api.sumDecimals(amounts: []), Decimal.zero);
+    });
+    test('Decimal with too many digits', () async {
+      await expectLater(
+          api.handleDecimal(amount: Decimal.parse('0.00000000000000000000000000001')), throwsA(isA<FfiException>()));
+    });
   });
 
   group('array feature', () {
@@ -784,6 +1430,21 @@ void main(List<String> args) async {
       final lastNumber = await api.lastNumber(array: array);
       expect(lastNumber, 88.888);
     });
+    test('Transpose', () async {
+      final matrix = F64Array16(Float64List.fromList(List.generate(16, (i) => i.toDouble())));
+      final transposed = await api.transpose(matrix: matrix);
+      expect(transposed[1], 4.0);
+      expect(transposed[4], 1.0);
+      expect(transposed[15], 15.0);
+    });
+    test('instantiated const generics', () async {
+      expect(await api.repeatByte4(byte: 7), [7, 7, 7, 7]);
+      final U8Array8 eight = await api.repeatByte8(byte: 1);
+      expect(eight, List.filled(8, 1));
+    });
+    test('array of wrong length', () {
+      expect(() => F64Array16(Float64List(3)), throwsArgumentError);
+    });
     test('NestedId', () async {
       final id0 = TestId(field0: I32Array2.init());
       id0.field0[1] = 10;
@@ -858,6 +1519,162 @@ void main(List<String> args) async {
       (await futureData).dispose();
     });
 
+    test('arena', () async {
+      final arena = OpaqueArena();
+      final a = (await api.createOpaque()).ownedBy(arena);
+      final b = (await api.createOpaque()).ownedBy(arena);
+      a.dispose();
+      arena.dispose();
+      expect(a.isStale(), isTrue);
+      expect(b.isStale(), isTrue);
+      arena.dispose();
+      expect(() => arena.add(api.syncCreateOpaque()), throwsStateError);
+    });
+
+    test('trait objects', () async {
+      final BoxDartDebug boxed = await api.createTraitObject();
+      final BoxDartDebug impl = await api.createImplTrait();
+      expect(await api.runTraitObject(obj: boxed), '42');
+      expect(await api.runTraitObject(obj: impl), startsWith('HideData'));
+      boxed.dispose();
+      impl.dispose();
+    });
+
+    test('shared', () async {
+      final a = await api.createSharedOpaque();
+      final b = a.clone();
+      expect(await api.sharedOpaqueAddress(opaque: b), await api.sharedOpaqueAddress(opaque: a));
+      a.dispose();
+      expect(a.isStale(), isTrue);
+      expect(b.isStale(), isFalse);
+      expect(await api.runSharedOpaque(opaque: b), startsWith("content - "));
+      b.dispose();
+    });
+
+    test('sendable', () async {
+      final data = await api.createOpaque();
+      expect(await runOpaqueInIsolate(dylibPath, data.toSendable()), startsWith("content - "));
+      // The handle of this isolate is still usable.
+      expect(await api.runOpaque(opaque: data), startsWith("content - "));
+      data.dispose();
+    }, skip: skipWeb('Isolates do not share the memory of the Rust library on the web.'));
+
+    test('sendable of a type which is not Sync', () async {
+      final data = await api.createSyncOpaque();
+      expect(() => data.toSendable(), throwsUnsupportedError);
+      data.dispose();
+    }, skip: skipWeb('Isolates do not share the memory of the Rust library on the web.'));
+
+    test('methods', () async {
+      final counter = await Counter.start(bridge: api, from: 1);
+      expect(await counter.increment(by: 2), 3);
+      expect(await counter.increment(by: 3), 6);
+      expect(await counter.label(), 'from 1');
+      expect(counter.isStale(), isFalse);
+      expect(await counter.finish(), 6);
+      expect(counter.isStale(), isTrue);
+    });
+
+    test('streams of a method', () async {
+      final counter = await Counter.start(bridge: api, from: 0);
+      final first = <int>[];
+      final second = <int>[];
+      final firstDone = Completer<void>();
+      counter.updates().listen(first.add, onDone: firstDone.complete);
+      counter.updates().listen(second.add);
+      // Let the sinks subscribe before incrementing.
+      await Future.delayed(const Duration(milliseconds: 100));
+      await counter.increment(by: 1);
+      await counter.increment(by: 2);
+      await Future.delayed(const Duration(milliseconds: 100));
+      expect(first, [1, 3]);
+      expect(second, [1, 3]);
+      counter.dispose();
+      await firstDone.future;
+    });
+
+    test('change notifications', () async {
+      final counter = await Counter.start(bridge: api, from: 1);
+      var changes = 0;
+      counter.changes().listen((_) => changes++);
+      // Let the sink subscribe before resetting.
+      await Future.delayed(const Duration(milliseconds: 100));
+      await counter.reset(to: 5);
+      await expectLater(counter.reset(to: -1), throwsA(isA<FfiException>()));
+      await Future.delayed(const Duration(milliseconds: 100));
+      expect(changes, 1);
+      expect(await counter.finish(), 5);
+    });
+
+    test('trait as an interface', () async {
+      final List<Describe> items = [
+        await Counter.start(bridge: api, from: 2),
+        await Greeter.named(bridge: api, name: 'ann'),
+      ];
+      expect(await Future.wait(items.map((item) => item.describe())), ['counter from 2 at 2', 'greeter of ann']);
+      expect(await Future.wait(items.map((item) => item.shout())), ['COUNTER FROM 2 AT 2', 'hello, ann!']);
+    });
+
+    test('weak handle', () async {
+      final counter = await Counter.start(bridge: api, from: 1);
+      final weak = await api.observeCounter(counter: counter);
+      final strong = await weak.upgrade();
+      expect(await strong!.increment(by: 1), 2);
+      strong.dispose();
+      expect(await api.isCounterAlive(counter: weak), isTrue);
+      counter.dispose();
+      expect(await weak.upgrade(), isNull);
+      expect(await api.isCounterAlive(counter: weak), isFalse);
+      weak.dispose();
+    });
+
+    test('streams per key', () async {
+      final rooms = await Rooms.open(bridge: api);
+      final rust = rooms.join(room: 'rust').toList();
+      final dart = rooms.join(room: 'dart').toList();
+      // Let the sinks subscribe before posting.
+      await Future.delayed(const Duration(milliseconds: 100));
+      expect(await rooms.post(room: 'rust', message: 'hi'), 1);
+      expect(await rooms.post(room: 'flutter', message: 'hello'), 0);
+      expect(await rooms.closeRoom(room: 'rust'), 1);
+      expect(await rust, ['hi']);
+      rooms.dispose();
+      expect(await dart, isEmpty);
+    });
+
+    test('iterator method', () async {
+      final counter = await Counter.start(bridge: api, from: 3);
+      expect(await counter.countDown().toList(), [3, 2, 1, 0]);
+      counter.dispose();
+    });
+
+    test('async method', () async {
+      final counter = await Counter.start(bridge: api, from: 1);
+      expect(await counter.incrementLater(by: 2), 3);
+      final later = counter.incrementLater(by: 3);
+      // The object stays alive until the future completes.
+      counter.dispose();
+      expect(await later, 6);
+    });
+
+    test('consuming method on a shared object', () async {
+      final counter = await Counter.start(bridge: api, from: 1);
+      final shared = counter.clone();
+      await expectLater(counter.finish(), throwsA(isA<FfiException>()));
+      expect(await shared.increment(by: 1), 2);
+      shared.dispose();
+    });
+
+    test('option', () async {
+      expect(await api.createOptionOpaque(), isNull);
+      var data = await api.createOpaque();
+      var back = await api.createOptionOpaque(opaque: data);
+      expect(back, isNotNull);
+      expect(await api.runOpaque(opaque: back!), startsWith("content - "));
+      back.dispose();
+      data.dispose();
+    });
+
     test('simple call', () async {
       var opaque = await api.createOpaque();
       var hideData = await api.runOpaque(opaque: opaque);
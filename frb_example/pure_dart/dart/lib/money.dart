@@ -0,0 +1,21 @@
+import 'bridge_definitions.dart';
+
+/// A hand-written class, which the bindings use in place of the generated [MoneyRaw].
+class Money {
+  final int cents;
+
+  const Money.fromCents(this.cents);
+
+  @override
+  bool operator ==(Object other) => other is Money && other.cents == cents;
+
+  @override
+  int get hashCode => cents.hashCode;
+
+  @override
+  String toString() => '${cents ~/ 100}.${(cents % 100).toString().padLeft(2, '0')}';
+}
+
+Money moneyFromRaw(MoneyRaw raw) => Money.fromCents(raw.cents);
+
+MoneyRaw moneyToRaw(Money money) => MoneyRaw(cents: money.cents);
@@ -0,0 +1,21 @@
+import 'dart:isolate';
+
+import 'package:flutter_rust_bridge/flutter_rust_bridge.dart';
+
+import 'bridge_definitions.dart';
+import 'ffi.io.dart';
+
+/// Runs the [HideData] of [token] in a new isolate, which loads its own API instance.
+Future<String> runOpaqueInIsolate(String dylibPath, SendableOpaque token) async {
+  final port = ReceivePort();
+  await Isolate.spawn(_runOpaque, [port.sendPort, dylibPath, token]);
+  return await port.first as String;
+}
+
+Future<void> _runOpaque(List<Object> args) async {
+  final sendPort = args[0] as SendPort;
+  final api = initializeExternalLibrary(args[1] as String);
+  final opaque = HideData.fromSendable(args[2] as SendableOpaque, api);
+  sendPort.send(await api.runOpaque(opaque: opaque));
+  opaque.dispose();
+}
@@ -0,0 +1,4 @@
+import 'package:flutter_rust_bridge/flutter_rust_bridge.dart';
+
+Future<String> runOpaqueInIsolate(String dylibPath, SendableOpaque token) =>
+    throw UnsupportedError('Isolates do not share the memory of the Rust library on the web.');
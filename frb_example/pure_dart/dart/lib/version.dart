@@ -0,0 +1,35 @@
+import 'dart:typed_data';
+
+class Version {
+  final int major;
+  final int minor;
+  final int patch;
+
+  const Version(this.major, this.minor, this.patch);
+
+  @override
+  bool operator ==(Object other) =>
+      other is Version && other.major == major && other.minor == minor && other.patch == patch;
+
+  @override
+  int get hashCode => Object.hash(major, minor, patch);
+
+  @override
+  String toString() => '$major.$minor.$patch';
+}
+
+/// Sends a [Version] as the big-endian bytes of its parts, like `crate::codecs::version`.
+class VersionCodec {
+  static Uint8List encode(Version version) {
+    final bytes = ByteData(6)
+      ..setUint16(0, version.major)
+      ..setUint16(2, version.minor)
+      ..setUint16(4, version.patch);
+    return bytes.buffer.asUint8List();
+  }
+
+  static Version decode(Uint8List bytes) {
+    final data = ByteData.sublistView(bytes);
+    return Version(data.getUint16(0), data.getUint16(2), data.getUint16(4));
+  }
+}
@@ -21,6 +21,9 @@ fn main() {
         wasm: true,
         dart_decl_output: Some("../dart/lib/bridge_definitions.dart".into()),
         dart_format_line_length: 120,
+        dart_json: true,
+        dart_copy_with: true,
+        drain_streams: true,
         // for other options use defaults
         ..Default::default()
     };
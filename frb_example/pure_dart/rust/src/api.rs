@@ -1,12 +1,17 @@
 #![allow(unused_variables)]
 
+use std::borrow::Cow;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
 use std::fmt::Debug;
+use std::hash::{Hash, Hasher};
+use std::net::{IpAddr, SocketAddr};
 use std::ops::Deref;
+use std::path::{Path, PathBuf};
 use std::sync::atomic::{AtomicI32, Ordering};
-use std::sync::Arc;
+use std::sync::{Arc, Weak};
 pub use std::sync::{Mutex, RwLock};
 use std::thread::sleep;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
 use anyhow::{anyhow, Result};
 
@@ -31,15 +36,66 @@ extern "C" fn on_dylib_start() {
         .try_init();
 }
 
+static INIT_COUNT: AtomicI32 = AtomicI32::new(0);
+
+/// Runs once, before the first call of any other function.
+#[frb(init)]
+pub fn init_app() {
+    INIT_COUNT.fetch_add(1, Ordering::SeqCst);
+}
+
+pub fn init_count() -> i32 {
+    INIT_COUNT.load(Ordering::SeqCst)
+}
+
+pub const MAX_COUNTERS: u32 = 1000;
+pub static GREETING: &str = "Hello, world!";
+#[frb(dart_name = "defaultRatio")]
+pub const RATIO: f64 = 1.5;
+
+pub fn get_constants() -> (u32, String, f64) {
+    (MAX_COUNTERS, GREETING.to_owned(), RATIO)
+}
+
 /// Documentation on a simple adder function.
 pub fn simple_adder(a: i32, b: i32) -> i32 {
     a + b
 }
 
+async fn double_later(x: i32) -> i32 {
+    std::future::ready(x * 2).await
+}
+
+pub async fn simple_adder_async(a: i32, b: i32) -> Result<i32> {
+    Ok(double_later(a).await + b)
+}
+
+#[frb(dispatchable)]
+pub fn triple(x: i32) -> Result<i32> {
+    x.checked_mul(3)
+        .ok_or_else(|| anyhow!("{} cannot be tripled", x))
+}
+
+#[frb(dispatchable)]
+#[frb(dart_name = "negate")]
+pub fn negate_v2(x: i32) -> Result<i32> {
+    Ok(-x)
+}
+
 pub fn simple_adder_sync(a: i32, b: i32) -> SyncReturn<i32> {
     SyncReturn(a + b)
 }
 
+#[frb(no_isolate)]
+pub fn scale(x: f64, factor: f64) -> f64 {
+    x * factor
+}
+
+#[frb(no_isolate)]
+pub fn sum_vector(v: [f32; 3]) -> f32 {
+    v.iter().sum()
+}
+
 /**
  Multiline comments are fine,
  but they are not preferred in Rust nor in Dart.
@@ -116,6 +172,90 @@ pub fn handle_string_sync(s: String) -> SyncReturn<String> {
 }
 
 #[allow(clippy::unused_unit)]
+pub fn next_char(c: char) -> char {
+    char::from_u32(c as u32 + 1).unwrap_or(char::REPLACEMENT_CHARACTER)
+}
+
+pub fn handle_chars(chars: Vec<char>) -> Option<char> {
+    chars.into_iter().max()
+}
+
+pub fn divide_by(n: u32, by: std::num::NonZeroU32) -> u32 {
+    n / by
+}
+
+pub fn next_non_zero(n: std::num::NonZeroU64) -> Option<std::num::NonZeroU64> {
+    std::num::NonZeroU64::new(n.get().wrapping_add(1))
+}
+
+#[frb]
+pub fn greet(#[frb(default = "'world'")] name: String, #[frb(default = "1")] times: u32) -> String {
+    format!("Hello, {}!", name).repeat(times as usize)
+}
+
+pub fn instant_now() -> Instant {
+    Instant::now()
+}
+
+pub fn instant_after(start: Instant, millis: u64) -> Instant {
+    start + Duration::from_millis(millis)
+}
+
+pub fn one_day_later(time: std::time::SystemTime) -> std::time::SystemTime {
+    time + std::time::Duration::from_secs(24 * 3600)
+}
+
+pub fn shift_range(range: std::ops::Range<u32>, by: u32) -> std::ops::RangeInclusive<u32> {
+    range.start + by..=range.end + by - 1
+}
+
+pub fn clamp_range(range: std::ops::RangeInclusive<f64>, value: f64) -> f64 {
+    value.clamp(*range.start(), *range.end())
+}
+
+pub fn describe_size(size: &MySize, unit: &str, labels: &[String]) -> String {
+    format!(
+        "{}x{}{} {}",
+        size.width,
+        size.height,
+        unit,
+        labels.join(",")
+    )
+}
+
+pub fn split_words(text: String) -> impl Iterator<Item = String> + Send {
+    text.split_whitespace()
+        .map(ToOwned::to_owned)
+        .collect::<Vec<_>>()
+        .into_iter()
+}
+
+pub fn handle_cow_str(s: Cow<'_, str>) -> Cow<'static, str> {
+    if s.is_empty() {
+        Cow::Borrowed("empty")
+    } else {
+        Cow::Owned(s.to_uppercase())
+    }
+}
+
+pub fn handle_cow_bytes(bytes: Cow<'_, [u8]>) -> Cow<'_, [u8]> {
+    bytes
+}
+
+pub fn handle_path(path: &Path) -> PathBuf {
+    path.with_extension("txt")
+}
+
+pub fn handle_socket_addr(addr: SocketAddr) -> IpAddr {
+    addr.ip()
+}
+
+pub fn next_port(addr: SocketAddr) -> SocketAddr {
+    let mut next = addr;
+    next.set_port(addr.port() + 1);
+    next
+}
+
 pub fn handle_return_unit() -> () {
     info!("handle_return_unit()");
 }
@@ -131,6 +271,54 @@ pub fn handle_vec_u8(v: Vec<u8>) -> Vec<u8> {
     v.repeat(2)
 }
 
+pub fn handle_bytes(bytes: bytes::Bytes) -> bytes::Bytes {
+    bytes.slice(1..)
+}
+
+pub fn checksum(data: &[u8], weights: &[u16]) -> u32 {
+    data.iter()
+        .zip(weights.iter().cycle())
+        .map(|(&byte, &weight)| byte as u32 * weight as u32)
+        .sum()
+}
+
+pub fn count_in_shared_buffer(buffer: SharedBuffer, byte: u8) -> u32 {
+    buffer.iter().filter(|&&b| b == byte).count() as u32
+}
+
+#[frb]
+pub struct Frame {
+    pub index: u32,
+    #[frb(zero_copy)]
+    pub pixels: Vec<u8>,
+    #[frb(zero_copy)]
+    pub depths: Option<Vec<f32>>,
+}
+
+pub fn next_frame(frame: Frame) -> Frame {
+    Frame {
+        index: frame.index + 1,
+        pixels: frame
+            .pixels
+            .into_iter()
+            .map(|p| p.wrapping_add(1))
+            .collect(),
+        depths: frame
+            .depths
+            .map(|depths| depths.into_iter().map(|d| d * 2.).collect()),
+    }
+}
+
+pub fn prefix_sums(items: Vec<usize>) -> Vec<usize> {
+    items
+        .into_iter()
+        .scan(0, |sum, item| {
+            *sum += item;
+            Some(*sum)
+        })
+        .collect()
+}
+
 pub fn handle_vec_u8_sync(v: Vec<u8>) -> SyncReturn<Vec<u8>> {
     info!("handle_vec_u8_sync(first few elements: {:?})", &v[..5]);
     SyncReturn(v.repeat(2))
@@ -257,6 +445,18 @@ pub fn handle_newtype_sync(arg: NewTypeInt) -> SyncReturn<NewTypeInt> {
     SyncReturn(NewTypeInt(arg.0 * 2))
 }
 
+pub struct Pair<T> {
+    pub first: T,
+    pub second: Vec<T>,
+}
+
+pub fn handle_generic_struct(pair: Pair<i32>) -> Pair<String> {
+    Pair {
+        first: pair.first.to_string(),
+        second: pair.second.iter().map(ToString::to_string).collect(),
+    }
+}
+
 pub fn handle_list_of_struct(mut l: Vec<MySize>) -> Vec<MySize> {
     info!("handle_list_of_struct({:?})", &l);
     let mut ans = l.clone();
@@ -271,6 +471,16 @@ pub fn handle_list_of_struct_sync(mut l: Vec<MySize>) -> SyncReturn<Vec<MySize>>
     SyncReturn(ans)
 }
 
+pub fn handle_nested_vec(rows: Vec<Vec<i32>>) -> Vec<Vec<Vec<String>>> {
+    rows.into_iter()
+        .map(|row| {
+            row.into_iter()
+                .map(|i| vec![i.to_string(); i as usize])
+                .collect()
+        })
+        .collect()
+}
+
 pub fn handle_string_list(names: Vec<String>) -> Vec<String> {
     for name in &names {
         info!("Hello, {}", name);
@@ -371,6 +581,128 @@ pub fn return_err() -> Result<i32> {
     ))
 }
 
+#[derive(Debug)]
+pub enum CustomError {
+    Fail {
+        message: String,
+        backtrace: std::backtrace::Backtrace,
+    },
+    Retry {
+        after_ms: u32,
+    },
+}
+
+pub fn return_custom_error(fail: bool) -> Result<u32, CustomError> {
+    if fail {
+        Err(CustomError::Fail {
+            message: "deliberately failed".to_owned(),
+            backtrace: std::backtrace::Backtrace::capture(),
+        })
+    } else {
+        Err(CustomError::Retry { after_ms: 100 })
+    }
+}
+
+pub fn return_custom_error_unit() -> Result<(), CustomError> {
+    Err(CustomError::Retry { after_ms: 0 })
+}
+
+#[frb(sync)]
+pub fn lookup_weekday(index: u32) -> Result<Option<Weekdays>, CustomError> {
+    match index {
+        0 => Ok(Some(Weekdays::Monday)),
+        1..=6 => Ok(None),
+        _ => Err(CustomError::Retry { after_ms: index }),
+    }
+}
+
+/// The values and errors read like each other, which must not confuse Dart.
+pub fn lookup_string(key: String) -> Result<String, String> {
+    match key.as_str() {
+        "found" => Ok("Error: not found".to_owned()),
+        _ => Err(format!("Ok: {}", key)),
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum SettingError {
+    NotFound,
+    Invalid,
+}
+
+pub fn read_setting(key: String) -> Result<String, (SettingError, String)> {
+    match key.as_str() {
+        "theme" => Ok("dark".to_owned()),
+        _ => Err((SettingError::NotFound, format!("No setting {}", key))),
+    }
+}
+
+pub fn parse_setting(value: String) -> Result<u32, (SettingError, String, CustomError)> {
+    value.parse().map_err(|_| {
+        (
+            SettingError::Invalid,
+            format!("{} is not a number", value),
+            CustomError::Fail {
+                message: value.clone(),
+                backtrace: std::backtrace::Backtrace::capture(),
+            },
+        )
+    })
+}
+
+pub fn return_custom_error_sync(fail: bool) -> Result<SyncReturn<u32>, CustomError> {
+    if fail {
+        Err(CustomError::Retry { after_ms: 10 })
+    } else {
+        Ok(SyncReturn(1))
+    }
+}
+
+/// Counts until Dart cancels the call, or gives up after a few seconds.
+pub fn count_until_cancelled(token: CancellationToken) -> Result<u32> {
+    for count in 0..500 {
+        if token.is_cancelled() {
+            return Ok(count);
+        }
+        sleep(Duration::from_millis(10));
+    }
+    Err(anyhow!("count_until_cancelled() was not cancelled"))
+}
+
+static STOPPED_WORKERS: AtomicI32 = AtomicI32::new(0);
+
+/// Waits for the current call to be cancelled, without being handed its token.
+fn wait_until_cancelled() {
+    let token = CancellationToken::current().expect("called from a cancellable function");
+    while !token.is_cancelled() {
+        sleep(Duration::from_millis(10));
+    }
+}
+
+/// Spawns `workers` threads, which stop together with the call once Dart cancels it.
+pub fn run_workers(workers: u32, token: CancellationToken) -> Result<()> {
+    let handles = (0..workers)
+        .map(|_| {
+            let token = token.child();
+            std::thread::spawn(move || {
+                while !token.is_cancelled() {
+                    sleep(Duration::from_millis(10));
+                }
+                STOPPED_WORKERS.fetch_add(1, Ordering::SeqCst);
+            })
+        })
+        .collect::<Vec<_>>();
+    wait_until_cancelled();
+    for handle in handles {
+        handle.join().unwrap();
+    }
+    Ok(())
+}
+
+pub fn stopped_workers() -> i32 {
+    STOPPED_WORKERS.load(Ordering::SeqCst)
+}
+
 pub fn return_panic() -> i32 {
     panic!("return_panic() is called, thus deliberately panic")
 }
@@ -506,7 +838,7 @@ pub fn handle_option_box_arguments(
 }
 
 /// Simple enums.
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum Weekdays {
     Monday,
     Tuesday,
@@ -547,6 +879,27 @@ pub fn handle_enum_parameter(weekday: Weekdays) -> Weekdays {
     weekday
 }
 
+/// Enums with explicit discriminants, which are sent instead of the indices.
+#[derive(Debug, Clone, Copy)]
+pub enum HttpStatus {
+    Ok = 200,
+    NotFound = 404,
+    MethodNotAllowed,
+}
+
+pub fn http_status_code(status: HttpStatus) -> i32 {
+    status as i32
+}
+
+pub fn http_status_of(code: i32) -> Option<HttpStatus> {
+    match code {
+        200 => Some(HttpStatus::Ok),
+        404 => Some(HttpStatus::NotFound),
+        405 => Some(HttpStatus::MethodNotAllowed),
+        _ => None,
+    }
+}
+
 #[frb]
 #[derive(Debug, Clone)]
 pub struct Customized {
@@ -691,6 +1044,17 @@ pub fn get_message() -> ApplicationMessage {
     external_lib::poll_messages()[1].clone()
 }
 
+pub fn get_fallible_message(index: usize) -> anyhow::Result<ApplicationMessage> {
+    external_lib::poll_messages()
+        .get(index)
+        .cloned()
+        .ok_or_else(|| anyhow!("no message at {}", index))
+}
+
+pub fn is_exit_message(message: ApplicationMessage) -> bool {
+    matches!(message, ApplicationMessage::Exit)
+}
+
 #[frb(mirror(Numbers, Sequences))]
 pub struct _Numbers(pub Vec<i32>);
 
@@ -801,6 +1165,71 @@ pub fn handle_stream_sink_at_3(sink: StreamSink<Log>, key: u32, max: u32) {
     handle_stream_sink_at_1(key, max, sink)
 }
 
+pub fn call_back_with_numbers(max: u32, callback: impl Fn(u32) + Send + 'static) {
+    spawn!(|| {
+        for i in 0..max {
+            callback(i);
+        }
+    });
+}
+
+pub fn handle_stream_closed_by_drop(sink: StreamSink<u32>, max: u32) {
+    spawn!(|| {
+        for i in 0..max {
+            let _ = sink.add(i);
+        }
+        // Dropping the last clone of the sink closes the stream.
+    });
+}
+
+/// Returns the first of the numbers, and sends the next ones to the stream.
+pub fn count_from(sink: StreamSink<u32>, from: u32, count: u32) -> Result<u32> {
+    if count == 0 {
+        return Err(anyhow!("Nothing to count"));
+    }
+    spawn!(|| {
+        for i in 1..count {
+            let _ = sink.add(from + i);
+        }
+    });
+    Ok(from)
+}
+
+#[derive(Debug, Clone)]
+pub enum ParseError {
+    Empty,
+    NotANumber { line: String },
+}
+
+/// Parses each line of `input`, until an empty one, which ends the stream with an error.
+pub fn parse_numbers(sink: StreamSink<Result<u32, ParseError>>, input: String) {
+    for line in input.lines() {
+        if line.is_empty() {
+            sink.close_with_error(ParseError::Empty);
+            return;
+        }
+        sink.add_result(line.parse().map_err(|_| ParseError::NotANumber {
+            line: line.to_owned(),
+        }));
+    }
+    sink.close();
+}
+
+#[frb(broadcast)]
+pub fn handle_broadcast_stream(sink: StreamSink<u32>, max: u32) {
+    handle_stream_closed_by_drop(sink, max)
+}
+
+pub fn handle_stream_closed_by_threads(sink: StreamSink<u32>, threads: u32) {
+    for i in 0..threads {
+        let sink = sink.clone();
+        spawn!(|| {
+            let _ = sink.add(i);
+            sink.close();
+        });
+    }
+}
+
 pub struct SumWith {
     pub x: u32,
 }
@@ -819,6 +1248,210 @@ pub fn get_sum_array(a: u32, b: u32, c: u32) -> [SumWith; 3] {
     [SumWith { x: a }, SumWith { x: b }, SumWith { x: c }]
 }
 
+#[frb]
+pub struct Metadata {
+    pub id: u32,
+    pub created_by: String,
+}
+
+#[frb]
+pub struct Document {
+    #[frb(flatten)]
+    pub metadata: Metadata,
+    pub title: String,
+}
+
+pub fn rename_document(document: Document, title: String) -> Document {
+    Document {
+        metadata: Metadata {
+            id: document.metadata.id + 1,
+            ..document.metadata
+        },
+        title,
+    }
+}
+
+#[frb]
+pub struct Renamed {
+    #[frb(dart_name = "identifier")]
+    pub id: u32,
+    pub pace: Pace,
+}
+
+#[frb]
+pub enum Pace {
+    #[frb(dart_name = "fast")]
+    Quick,
+    Slow,
+}
+
+#[frb(dart_name = "fetchRenamed")]
+pub fn get_renamed(id: u32, pace: Pace) -> Renamed {
+    let pace = match pace {
+        Pace::Quick => Pace::Slow,
+        Pace::Slow => Pace::Quick,
+    };
+    Renamed { id: id + 1, pace }
+}
+
+#[frb(assert = "percentage <= 100")]
+pub struct Progress {
+    pub percentage: u8,
+}
+
+pub fn advance(progress: Progress, by: u8) -> Progress {
+    Progress {
+        percentage: progress.percentage.saturating_add(by).min(100),
+    }
+}
+
+#[frb(positional)]
+pub struct ProductId(pub u32);
+
+#[frb(positional)]
+pub struct GridCell {
+    pub row: i32,
+    pub column: i32,
+}
+
+pub fn next_product_id(id: ProductId) -> ProductId {
+    ProductId(id.0 + 1)
+}
+
+pub fn transpose_cell(cell: GridCell) -> GridCell {
+    GridCell {
+        row: cell.column,
+        column: cell.row,
+    }
+}
+
+#[frb(dart_type = ("Money" import "money.dart", from_raw = "moneyFromRaw", to_raw = "moneyToRaw"))]
+pub struct Money {
+    pub cents: i64,
+}
+
+pub fn add_tax(price: Money, percent: u32) -> Money {
+    Money {
+        cents: price.cents + price.cents * percent as i64 / 100,
+    }
+}
+
+#[frb(custom_codec(rust = "crate::codecs::version", dart = "VersionCodec" import "version.dart"))]
+pub struct Version {
+    pub major: u16,
+    pub minor: u16,
+    pub patch: u16,
+}
+
+pub fn bump_minor(version: Version) -> Version {
+    Version {
+        minor: version.minor + 1,
+        patch: 0,
+        ..version
+    }
+}
+
+#[frb]
+pub struct Session {
+    pub user: String,
+    #[frb(skip)]
+    pub retries: u32,
+    #[frb(skip = "Mutex::new(vec![\"guest\".to_owned()])")]
+    pub roles: Mutex<Vec<String>>,
+}
+
+pub fn open_session(user: String) -> Session {
+    Session {
+        user,
+        retries: 3,
+        roles: Mutex::new(vec!["admin".to_owned()]),
+    }
+}
+
+pub fn describe_session(session: Session) -> String {
+    format!(
+        "{} ({} retries) as {}",
+        session.user,
+        session.retries,
+        session.roles.lock().unwrap().join(", ")
+    )
+}
+
+/// Sent to Dart as one block of bytes.
+#[frb(repr_c)]
+#[derive(Debug, Clone, Copy)]
+pub struct Sample {
+    pub index: u32,
+    pub x: f64,
+    pub y: f32,
+    pub valid: bool,
+    pub level: i8,
+}
+
+pub fn sample_at(index: u32) -> Sample {
+    Sample {
+        index,
+        x: index as f64 / 2.,
+        y: -(index as f32),
+        valid: index % 2 == 0,
+        level: -3,
+    }
+}
+
+pub fn shift_sample(sample: Sample, dx: f64) -> Sample {
+    Sample {
+        x: sample.x + dx,
+        ..sample
+    }
+}
+
+/// All its fields have constant Dart values, so its Dart constructor is `const`.
+pub struct Tag {
+    pub name: String,
+    pub color: Option<u32>,
+    pub aliases: Vec<String>,
+    pub day: Weekdays,
+}
+
+pub fn tag_label(tag: Tag) -> String {
+    format!(
+        "{} ({}) on {:?}, color {:?}",
+        tag.name,
+        tag.aliases.join(", "),
+        tag.day,
+        tag.color
+    )
+}
+
+#[frb(ignore)]
+pub const HEADER_SEPARATOR: u8 = b':';
+
+/// Only used by the Rust code, so it may borrow, which Dart cannot.
+#[frb(ignore)]
+pub struct Header<'a> {
+    pub name: &'a [u8],
+    pub value: &'a [u8],
+}
+
+impl<'a> Header<'a> {
+    pub fn name(&self) -> String {
+        String::from_utf8_lossy(self.name).into_owned()
+    }
+}
+
+#[frb(ignore)]
+pub fn parse_header(raw: &[u8]) -> Option<Header<'_>> {
+    let separator = raw.iter().position(|&b| b == HEADER_SEPARATOR)?;
+    Some(Header {
+        name: &raw[..separator],
+        value: &raw[separator + 1..],
+    })
+}
+
+pub fn header_name(raw: Vec<u8>) -> Option<String> {
+    parse_header(&raw).map(|header| header.name())
+}
+
 pub struct ConcatenateWith {
     pub a: String,
 }
@@ -937,6 +1570,14 @@ pub fn handle_big_buffers() -> BigBuffers {
     }
 }
 
+pub fn handle_i128(value: i128) -> i128 {
+    value + 1
+}
+
+pub fn handle_u128(value: u128) -> u128 {
+    value - 1
+}
+
 pub fn datetime_utc(d: chrono::DateTime<chrono::Utc>) -> chrono::DateTime<chrono::Utc> {
     use chrono::Datelike;
     use chrono::Timelike;
@@ -997,6 +1638,13 @@ pub fn duration(d: chrono::Duration) -> chrono::Duration {
     d
 }
 
+pub fn datetime_fixed_offset(
+    d: chrono::DateTime<chrono::FixedOffset>,
+) -> chrono::DateTime<chrono::FixedOffset> {
+    assert_eq!(&d.offset().local_minus_utc(), &0);
+    d.with_timezone(&chrono::FixedOffset::east_opt(2 * 3600).unwrap())
+}
+
 pub struct TestChrono {
     pub dt: Option<chrono::DateTime<chrono::Utc>>,
     pub dt2: Option<chrono::NaiveDateTime>,
@@ -1068,6 +1716,14 @@ pub fn handle_nested_uuids(ids: FeatureUuid) -> anyhow::Result<FeatureUuid> {
     Ok(ids)
 }
 
+pub fn handle_decimal(amount: rust_decimal::Decimal) -> rust_decimal::Decimal {
+    -amount * rust_decimal::Decimal::TWO
+}
+
+pub fn sum_decimals(amounts: Vec<rust_decimal::Decimal>) -> rust_decimal::Decimal {
+    amounts.into_iter().sum()
+}
+
 pub struct MessageId(pub [u8; 32]);
 
 pub fn new_msgid(id: [u8; 32]) -> MessageId {
@@ -1106,6 +1762,19 @@ pub fn last_number(array: [f64; 16]) -> f64 {
     array[15]
 }
 
+#[frb(instantiate(N = 4), instantiate(N = 8))]
+pub fn repeat_byte<const N: usize>(byte: u8) -> [u8; N] {
+    [byte; N]
+}
+
+pub fn transpose(matrix: [f64; 16]) -> [f64; 16] {
+    let mut transposed = [0.0; 16];
+    for (i, value) in matrix.iter().enumerate() {
+        transposed[i % 4 * 4 + i / 4] = *value;
+    }
+    transposed
+}
+
 pub fn nested_id(id: [TestId; 4]) -> [TestId; 2] {
     match id {
         [first, .., last] => [first, last],
@@ -1220,10 +1889,34 @@ pub fn run_enum_opaque(opaque: EnumOpaque) -> String {
     }
 }
 
+pub fn create_trait_object() -> Box<dyn DartDebug> {
+    Box::new(42)
+}
+
+pub fn create_impl_trait() -> impl DartDebug {
+    HideData::new()
+}
+
+pub fn run_trait_object(obj: &dyn DartDebug) -> String {
+    format!("{:?}", obj)
+}
+
 pub fn run_opaque(opaque: RustOpaque<HideData>) -> String {
     opaque.hide_data()
 }
 
+pub fn create_shared_opaque() -> Arc<HideData> {
+    Arc::new(HideData::new())
+}
+
+pub fn run_shared_opaque(opaque: Arc<HideData>) -> String {
+    opaque.hide_data()
+}
+
+pub fn shared_opaque_address(opaque: Arc<HideData>) -> usize {
+    Arc::as_ptr(&opaque) as usize
+}
+
 pub fn run_opaque_with_delay(opaque: RustOpaque<HideData>) -> String {
     sleep(Duration::from_millis(1000));
     opaque.hide_data()
@@ -1366,6 +2059,136 @@ pub fn frb_sync_generator_test() -> SyncReturn<RustOpaque<FrbOpaqueSyncReturn>>
     SyncReturn(RustOpaque::new(FrbOpaqueSyncReturn))
 }
 
+/// An opaque type whose methods are called on its Dart handle.
+pub struct Counter {
+    label: String,
+    count: AtomicI32,
+    updates: Mutex<Vec<StreamSink<i32>>>,
+}
+
+impl Counter {
+    pub fn start(from: i32) -> RustOpaque<Counter> {
+        RustOpaque::new(Counter {
+            label: format!("from {}", from),
+            count: AtomicI32::new(from),
+            updates: Mutex::new(Vec::new()),
+        })
+    }
+
+    pub fn increment(&self, by: i32) -> i32 {
+        let count = self.count.fetch_add(by, Ordering::SeqCst) + by;
+        // Forget the streams which were closed, e.g. by disposing their handle.
+        (self.updates.lock().unwrap()).retain(|sink| sink.add(count));
+        count
+    }
+
+    pub fn label(&self) -> &str {
+        &self.label
+    }
+
+    #[frb(notifies)]
+    pub fn reset(&self, to: i32) -> Result<()> {
+        if to < 0 {
+            return Err(anyhow!("Cannot reset a counter to {}", to));
+        }
+        self.count.store(to, Ordering::SeqCst);
+        Ok(())
+    }
+
+    /// The numbers from the count down to zero, computed as Dart asks for them.
+    pub fn count_down(&self) -> impl Iterator<Item = i32> + Send + '_ {
+        (0..=self.count.load(Ordering::SeqCst)).rev()
+    }
+
+    pub async fn increment_later(&self, by: i32) -> i32 {
+        std::future::ready(()).await;
+        self.increment(by)
+    }
+
+    /// Receives the count after each increment, until the handle is disposed.
+    pub fn updates(&self, sink: StreamSink<i32>) {
+        self.updates.lock().unwrap().push(sink);
+    }
+
+    pub fn finish(self) -> i32 {
+        self.count.into_inner()
+    }
+}
+
+/// Chat rooms, whose messages Dart subscribes to per room.
+pub struct Rooms {
+    messages: StreamRegistry<String, String>,
+}
+
+impl Rooms {
+    pub fn open() -> RustOpaque<Rooms> {
+        RustOpaque::new(Rooms {
+            messages: StreamRegistry::new(),
+        })
+    }
+
+    pub fn join(&self, room: String, sink: StreamSink<String>) {
+        self.messages.subscribe(room, sink);
+    }
+
+    /// Returns the number of subscribers who received the message.
+    pub fn post(&self, room: String, message: String) -> u32 {
+        self.messages.add(&room, message) as u32
+    }
+
+    pub fn close_room(&self, room: String) -> u32 {
+        self.messages.close(&room) as u32
+    }
+}
+
+/// A handle to `counter` which does not keep it alive.
+pub fn observe_counter(counter: RustOpaque<Counter>) -> Weak<Counter> {
+    Arc::downgrade(&counter.into_arc())
+}
+
+pub fn is_counter_alive(counter: Weak<Counter>) -> bool {
+    counter.strong_count() > 0
+}
+
+/// Implemented by opaque types, so that Dart can use their handles alike.
+pub trait Describe {
+    fn describe(&self) -> String;
+
+    fn shout(&self) -> String {
+        self.describe().to_uppercase()
+    }
+}
+
+impl Describe for Counter {
+    fn describe(&self) -> String {
+        format!(
+            "counter {} at {}",
+            self.label,
+            self.count.load(Ordering::SeqCst)
+        )
+    }
+}
+
+pub struct Greeter {
+    name: String,
+}
+
+impl Greeter {
+    pub fn named(name: String) -> RustOpaque<Greeter> {
+        RustOpaque::new(Greeter { name })
+    }
+}
+
+impl Describe for Greeter {
+    fn describe(&self) -> String {
+        format!("greeter of {}", self.name)
+    }
+
+    fn shout(&self) -> String {
+        format!("hello, {}!", self.name)
+    }
+}
+
 pub fn handle_type_alias_id(input: Id) -> Id {
     input
 }
@@ -1395,3 +2218,83 @@ pub struct Empty {}
 pub fn empty_struct(empty: Empty) -> Empty {
     empty
 }
+
+// HashMap example
+pub fn handle_hash_map(map: HashMap<String, i32>) -> HashMap<String, i32> {
+    map.into_iter()
+        .map(|(key, value)| (key, value * 2))
+        .collect()
+}
+
+pub fn handle_hash_map_of_struct(map: HashMap<u32, Attribute>) -> HashMap<u32, Attribute> {
+    map.into_iter()
+        .map(|(id, Attribute { key, value })| {
+            (
+                id + 1,
+                Attribute {
+                    key: value,
+                    value: key,
+                },
+            )
+        })
+        .collect()
+}
+
+pub fn count_weekdays(days: Vec<Weekdays>) -> HashMap<Weekdays, u32> {
+    let mut counts = HashMap::new();
+    for day in days {
+        *counts.entry(day).or_default() += 1;
+    }
+    counts
+}
+
+pub fn index_words(words: Vec<String>) -> HashMap<usize, String> {
+    words.into_iter().enumerate().collect()
+}
+
+/// A key whose values all have the same hash.
+#[derive(Debug, PartialEq, Eq)]
+pub struct CollidingKey(pub String);
+
+impl Hash for CollidingKey {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        0.hash(state);
+    }
+}
+
+pub fn handle_colliding_keys(map: HashMap<CollidingKey, u32>) -> HashMap<CollidingKey, u32> {
+    map.into_iter()
+        .map(|(CollidingKey(key), value)| (CollidingKey(key.to_uppercase()), value))
+        .collect()
+}
+
+// BTreeMap and BTreeSet example
+pub fn handle_btree_set(set: BTreeSet<String>) -> BTreeSet<String> {
+    set
+}
+
+pub fn handle_btree_map(map: BTreeMap<i32, String>) -> BTreeMap<i32, String> {
+    map
+}
+
+pub fn group_attributes(attributes: Vec<Attribute>) -> BTreeMap<String, Vec<Attribute>> {
+    let mut groups = BTreeMap::<String, Vec<Attribute>>::new();
+    for attribute in attributes {
+        groups
+            .entry(attribute.key.clone())
+            .or_default()
+            .push(attribute);
+    }
+    groups
+}
+
+// Tuple example
+pub fn handle_tuple(tuple: (i32, String, bool)) -> (bool, String, i32) {
+    let (a, b, c) = tuple;
+    (c, b, a)
+}
+
+pub fn handle_nested_tuple(tuple: (i32, (String, bool))) -> ((String, bool), i32) {
+    let (a, b) = tuple;
+    (b, a)
+}
@@ -1,16 +1,46 @@
 use super::*;
 // Section: wire functions
 
+#[no_mangle]
+pub extern "C" fn wire_init_app(port_: i64) {
+    wire_init_app_impl(port_)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_init_count(port_: i64) {
+    wire_init_count_impl(port_)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_get_constants(port_: i64) {
+    wire_get_constants_impl(port_)
+}
+
 #[no_mangle]
 pub extern "C" fn wire_simple_adder(port_: i64, a: i32, b: i32) {
     wire_simple_adder_impl(port_, a, b)
 }
 
+#[no_mangle]
+pub extern "C" fn wire_simple_adder_async(port_: i64, a: i32, b: i32) {
+    wire_simple_adder_async_impl(port_, a, b)
+}
+
 #[no_mangle]
 pub extern "C" fn wire_simple_adder_sync(a: i32, b: i32) -> support::WireSyncReturn {
     wire_simple_adder_sync_impl(a, b)
 }
 
+#[no_mangle]
+pub extern "C" fn wire_scale(x: f64, factor: f64) -> f64 {
+    wire_scale_impl(x, factor)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_sum_vector(v: *mut wire_float_32_list) -> f32 {
+    wire_sum_vector_impl(v)
+}
+
 #[no_mangle]
 pub extern "C" fn wire_primitive_types(
     port_: i64,
@@ -63,6 +93,96 @@ pub extern "C" fn wire_handle_string_sync(s: *mut wire_uint_8_list) -> support::
     wire_handle_string_sync_impl(s)
 }
 
+#[no_mangle]
+pub extern "C" fn wire_next_char(port_: i64, c: u32) {
+    wire_next_char_impl(port_, c)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_handle_chars(port_: i64, chars: *mut wire_list_char) {
+    wire_handle_chars_impl(port_, chars)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_divide_by(port_: i64, n: u32, by: u32) {
+    wire_divide_by_impl(port_, n, by)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_next_non_zero(port_: i64, n: u64) {
+    wire_next_non_zero_impl(port_, n)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_greet(port_: i64, name: *mut wire_uint_8_list, times: u32) {
+    wire_greet_impl(port_, name, times)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_instant_now(port_: i64) {
+    wire_instant_now_impl(port_)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_instant_after(port_: i64, start: wire_Instant, millis: u64) {
+    wire_instant_after_impl(port_, start, millis)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_one_day_later(port_: i64, time: i64) {
+    wire_one_day_later_impl(port_, time)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_shift_range(port_: i64, range: *mut wire_uint_32_list, by: u32) {
+    wire_shift_range_impl(port_, range, by)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_clamp_range(port_: i64, range: *mut wire_float_64_list, value: f64) {
+    wire_clamp_range_impl(port_, range, value)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_describe_size(
+    port_: i64,
+    size: *mut wire_MySize,
+    unit: *mut wire_uint_8_list,
+    labels: *mut wire_StringList,
+) {
+    wire_describe_size_impl(port_, size, unit, labels)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_split_words(port_: i64, text: *mut wire_uint_8_list) {
+    wire_split_words_impl(port_, text)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_handle_cow_str(port_: i64, s: *mut wire_uint_8_list) {
+    wire_handle_cow_str_impl(port_, s)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_handle_cow_bytes(port_: i64, bytes: *mut wire_uint_8_list) {
+    wire_handle_cow_bytes_impl(port_, bytes)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_handle_path(port_: i64, path: *mut wire_uint_16_list) {
+    wire_handle_path_impl(port_, path)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_handle_socket_addr(port_: i64, addr: *mut wire_uint_8_list) {
+    wire_handle_socket_addr_impl(port_, addr)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_next_port(port_: i64, addr: *mut wire_uint_8_list) {
+    wire_next_port_impl(port_, addr)
+}
+
 #[no_mangle]
 pub extern "C" fn wire_handle_return_unit(port_: i64) {
     wire_handle_return_unit_impl(port_)
@@ -78,6 +198,35 @@ pub extern "C" fn wire_handle_vec_u8(port_: i64, v: *mut wire_uint_8_list) {
     wire_handle_vec_u8_impl(port_, v)
 }
 
+#[no_mangle]
+pub extern "C" fn wire_handle_bytes(port_: i64, bytes: *mut wire_uint_8_list) {
+    wire_handle_bytes_impl(port_, bytes)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_checksum(
+    port_: i64,
+    data: *mut wire_uint_8_list,
+    weights: *mut wire_uint_16_list,
+) {
+    wire_checksum_impl(port_, data, weights)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_count_in_shared_buffer(port_: i64, buffer: usize, byte: u8) {
+    wire_count_in_shared_buffer_impl(port_, buffer, byte)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_next_frame(port_: i64, frame: *mut wire_Frame) {
+    wire_next_frame_impl(port_, frame)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_prefix_sums(port_: i64, items: *mut wire_uint_64_list) {
+    wire_prefix_sums_impl(port_, items)
+}
+
 #[no_mangle]
 pub extern "C" fn wire_handle_vec_u8_sync(v: *mut wire_uint_8_list) -> support::WireSyncReturn {
     wire_handle_vec_u8_sync_impl(v)
@@ -126,6 +275,11 @@ pub extern "C" fn wire_handle_newtype_sync(arg: *mut wire_NewTypeInt) -> support
     wire_handle_newtype_sync_impl(arg)
 }
 
+#[no_mangle]
+pub extern "C" fn wire_handle_generic_struct(port_: i64, pair: *mut wire_PairI32) {
+    wire_handle_generic_struct_impl(port_, pair)
+}
+
 #[no_mangle]
 pub extern "C" fn wire_handle_list_of_struct(port_: i64, l: *mut wire_list_my_size) {
     wire_handle_list_of_struct_impl(port_, l)
@@ -138,6 +292,11 @@ pub extern "C" fn wire_handle_list_of_struct_sync(
     wire_handle_list_of_struct_sync_impl(l)
 }
 
+#[no_mangle]
+pub extern "C" fn wire_handle_nested_vec(port_: i64, rows: *mut wire_list_int_32_list) {
+    wire_handle_nested_vec_impl(port_, rows)
+}
+
 #[no_mangle]
 pub extern "C" fn wire_handle_string_list(port_: i64, names: *mut wire_StringList) {
     wire_handle_string_list_impl(port_, names)
@@ -187,6 +346,56 @@ pub extern "C" fn wire_return_err(port_: i64) {
     wire_return_err_impl(port_)
 }
 
+#[no_mangle]
+pub extern "C" fn wire_return_custom_error(port_: i64, fail: bool) {
+    wire_return_custom_error_impl(port_, fail)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_return_custom_error_unit(port_: i64) {
+    wire_return_custom_error_unit_impl(port_)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_lookup_weekday(index: u32) -> support::WireSyncReturn {
+    wire_lookup_weekday_impl(index)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_lookup_string(port_: i64, key: *mut wire_uint_8_list) {
+    wire_lookup_string_impl(port_, key)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_read_setting(port_: i64, key: *mut wire_uint_8_list) {
+    wire_read_setting_impl(port_, key)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_parse_setting(port_: i64, value: *mut wire_uint_8_list) {
+    wire_parse_setting_impl(port_, value)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_return_custom_error_sync(fail: bool) -> support::WireSyncReturn {
+    wire_return_custom_error_sync_impl(fail)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_count_until_cancelled(port_: i64, cancel_id_: i32) {
+    wire_count_until_cancelled_impl(port_, cancel_id_)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_run_workers(port_: i64, cancel_id_: i32, workers: u32) {
+    wire_run_workers_impl(port_, cancel_id_, workers)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_stopped_workers(port_: i64) {
+    wire_stopped_workers_impl(port_)
+}
+
 #[no_mangle]
 pub extern "C" fn wire_return_panic(port_: i64) {
     wire_return_panic_impl(port_)
@@ -243,6 +452,16 @@ pub extern "C" fn wire_handle_enum_parameter(port_: i64, weekday: i32) {
     wire_handle_enum_parameter_impl(port_, weekday)
 }
 
+#[no_mangle]
+pub extern "C" fn wire_http_status_code(port_: i64, status: i32) {
+    wire_http_status_code_impl(port_, status)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_http_status_of(port_: i64, code: i32) {
+    wire_http_status_of_impl(port_, code)
+}
+
 #[no_mangle]
 pub extern "C" fn wire_handle_customized_struct(port_: i64, val: *mut wire_Customized) {
     wire_handle_customized_struct_impl(port_, val)
@@ -283,6 +502,16 @@ pub extern "C" fn wire_get_message(port_: i64) {
     wire_get_message_impl(port_)
 }
 
+#[no_mangle]
+pub extern "C" fn wire_get_fallible_message(port_: i64, index: usize) {
+    wire_get_fallible_message_impl(port_, index)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_is_exit_message(port_: i64, message: *mut wire_ApplicationMessage) {
+    wire_is_exit_message_impl(port_, message)
+}
+
 #[no_mangle]
 pub extern "C" fn wire_repeat_number(port_: i64, num: i32, times: usize) {
     wire_repeat_number_impl(port_, num, times)
@@ -357,6 +586,36 @@ pub extern "C" fn wire_handle_stream_sink_at_3(port_: i64, key: u32, max: u32) {
     wire_handle_stream_sink_at_3_impl(port_, key, max)
 }
 
+#[no_mangle]
+pub extern "C" fn wire_call_back_with_numbers(port_: i64, callback: i64, max: u32) {
+    wire_call_back_with_numbers_impl(port_, callback, max)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_handle_stream_closed_by_drop(port_: i64, max: u32) {
+    wire_handle_stream_closed_by_drop_impl(port_, max)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_count_from(port_: i64, from: u32, count: u32) {
+    wire_count_from_impl(port_, from, count)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_parse_numbers(port_: i64, input: *mut wire_uint_8_list) {
+    wire_parse_numbers_impl(port_, input)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_handle_broadcast_stream(port_: i64, max: u32) {
+    wire_handle_broadcast_stream_impl(port_, max)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_handle_stream_closed_by_threads(port_: i64, threads: u32) {
+    wire_handle_stream_closed_by_threads_impl(port_, threads)
+}
+
 #[no_mangle]
 pub extern "C" fn wire_get_sum_struct(port_: i64) {
     wire_get_sum_struct_impl(port_)
@@ -367,6 +626,75 @@ pub extern "C" fn wire_get_sum_array(port_: i64, a: u32, b: u32, c: u32) {
     wire_get_sum_array_impl(port_, a, b, c)
 }
 
+#[no_mangle]
+pub extern "C" fn wire_rename_document(
+    port_: i64,
+    document: *mut wire_Document,
+    title: *mut wire_uint_8_list,
+) {
+    wire_rename_document_impl(port_, document, title)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_get_renamed(port_: i64, id: u32, pace: i32) {
+    wire_get_renamed_impl(port_, id, pace)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_advance(port_: i64, progress: *mut wire_Progress, by: u8) {
+    wire_advance_impl(port_, progress, by)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_next_product_id(port_: i64, id: *mut wire_ProductId) {
+    wire_next_product_id_impl(port_, id)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_transpose_cell(port_: i64, cell: *mut wire_GridCell) {
+    wire_transpose_cell_impl(port_, cell)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_add_tax(port_: i64, price: *mut wire_Money, percent: u32) {
+    wire_add_tax_impl(port_, price, percent)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_bump_minor(port_: i64, version: *mut wire_uint_8_list) {
+    wire_bump_minor_impl(port_, version)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_open_session(port_: i64, user: *mut wire_uint_8_list) {
+    wire_open_session_impl(port_, user)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_describe_session(port_: i64, session: *mut wire_Session) {
+    wire_describe_session_impl(port_, session)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_sample_at(port_: i64, index: u32) {
+    wire_sample_at_impl(port_, index)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_shift_sample(port_: i64, sample: *mut wire_Sample, dx: f64) {
+    wire_shift_sample_impl(port_, sample, dx)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_tag_label(port_: i64, tag: *mut wire_Tag) {
+    wire_tag_label_impl(port_, tag)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_header_name(port_: i64, raw: *mut wire_uint_8_list) {
+    wire_header_name_impl(port_, raw)
+}
+
 #[no_mangle]
 pub extern "C" fn wire_multiply_by_ten(port_: i64, measure: *mut wire_Measure) {
     wire_multiply_by_ten_impl(port_, measure)
@@ -387,6 +715,16 @@ pub extern "C" fn wire_handle_big_buffers(port_: i64) {
     wire_handle_big_buffers_impl(port_)
 }
 
+#[no_mangle]
+pub extern "C" fn wire_handle_i128(port_: i64, value: *mut wire_uint_8_list) {
+    wire_handle_i128_impl(port_, value)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_handle_u128(port_: i64, value: *mut wire_uint_8_list) {
+    wire_handle_u128_impl(port_, value)
+}
+
 #[no_mangle]
 pub extern "C" fn wire_datetime_utc(port_: i64, d: i64) {
     wire_datetime_utc_impl(port_, d)
@@ -412,6 +750,11 @@ pub extern "C" fn wire_duration(port_: i64, d: i64) {
     wire_duration_impl(port_, d)
 }
 
+#[no_mangle]
+pub extern "C" fn wire_datetime_fixed_offset(port_: i64, d: i64) {
+    wire_datetime_fixed_offset_impl(port_, d)
+}
+
 #[no_mangle]
 pub extern "C" fn wire_test_chrono(port_: i64) {
     wire_test_chrono_impl(port_)
@@ -442,6 +785,16 @@ pub extern "C" fn wire_handle_nested_uuids(port_: i64, ids: *mut wire_FeatureUui
     wire_handle_nested_uuids_impl(port_, ids)
 }
 
+#[no_mangle]
+pub extern "C" fn wire_handle_decimal(port_: i64, amount: *mut wire_uint_8_list) {
+    wire_handle_decimal_impl(port_, amount)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_sum_decimals(port_: i64, amounts: *mut wire_list_Decimal) {
+    wire_sum_decimals_impl(port_, amounts)
+}
+
 #[no_mangle]
 pub extern "C" fn wire_new_msgid(port_: i64, id: *mut wire_uint_8_list) {
     wire_new_msgid_impl(port_, id)
@@ -482,6 +835,21 @@ pub extern "C" fn wire_last_number(port_: i64, array: *mut wire_float_64_list) {
     wire_last_number_impl(port_, array)
 }
 
+#[no_mangle]
+pub extern "C" fn wire_repeat_byte_4(port_: i64, byte: u8) {
+    wire_repeat_byte_4_impl(port_, byte)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_repeat_byte_8(port_: i64, byte: u8) {
+    wire_repeat_byte_8_impl(port_, byte)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_transpose(port_: i64, matrix: *mut wire_float_64_list) {
+    wire_transpose_impl(port_, matrix)
+}
+
 #[no_mangle]
 pub extern "C" fn wire_nested_id(port_: i64, id: *mut wire_list_test_id) {
     wire_nested_id_impl(port_, id)
@@ -567,11 +935,41 @@ pub extern "C" fn wire_run_enum_opaque(port_: i64, opaque: *mut wire_EnumOpaque)
     wire_run_enum_opaque_impl(port_, opaque)
 }
 
+#[no_mangle]
+pub extern "C" fn wire_create_trait_object(port_: i64) {
+    wire_create_trait_object_impl(port_)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_create_impl_trait(port_: i64) {
+    wire_create_impl_trait_impl(port_)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_run_trait_object(port_: i64, obj: wire_BoxDartDebug) {
+    wire_run_trait_object_impl(port_, obj)
+}
+
 #[no_mangle]
 pub extern "C" fn wire_run_opaque(port_: i64, opaque: wire_HideData) {
     wire_run_opaque_impl(port_, opaque)
 }
 
+#[no_mangle]
+pub extern "C" fn wire_create_shared_opaque(port_: i64) {
+    wire_create_shared_opaque_impl(port_)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_run_shared_opaque(port_: i64, opaque: wire_HideData) {
+    wire_run_shared_opaque_impl(port_, opaque)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_shared_opaque_address(port_: i64, opaque: wire_HideData) {
+    wire_shared_opaque_address_impl(port_, opaque)
+}
+
 #[no_mangle]
 pub extern "C" fn wire_run_opaque_with_delay(port_: i64, opaque: wire_HideData) {
     wire_run_opaque_with_delay_impl(port_, opaque)
@@ -715,6 +1113,16 @@ pub extern "C" fn wire_frb_sync_generator_test() -> support::WireSyncReturn {
     wire_frb_sync_generator_test_impl()
 }
 
+#[no_mangle]
+pub extern "C" fn wire_observe_counter(port_: i64, counter: wire_Counter) {
+    wire_observe_counter_impl(port_, counter)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_is_counter_alive(port_: i64, counter: wire_WeakCounter) {
+    wire_is_counter_alive_impl(port_, counter)
+}
+
 #[no_mangle]
 pub extern "C" fn wire_handle_type_alias_id(port_: i64, input: u64) {
     wire_handle_type_alias_id_impl(port_, input)
@@ -736,26 +1144,85 @@ pub extern "C" fn wire_empty_struct(port_: i64, empty: *mut wire_Empty) {
 }
 
 #[no_mangle]
-pub extern "C" fn wire_sum__method__SumWith(port_: i64, that: *mut wire_SumWith, y: u32, z: u32) {
-    wire_sum__method__SumWith_impl(port_, that, y, z)
+pub extern "C" fn wire_handle_hash_map(port_: i64, map: *mut wire_hash_map_String_i32) {
+    wire_handle_hash_map_impl(port_, map)
 }
 
 #[no_mangle]
-pub extern "C" fn wire_new__static_method__ConcatenateWith(port_: i64, a: *mut wire_uint_8_list) {
-    wire_new__static_method__ConcatenateWith_impl(port_, a)
+pub extern "C" fn wire_handle_hash_map_of_struct(
+    port_: i64,
+    map: *mut wire_hash_map_u32_attribute,
+) {
+    wire_handle_hash_map_of_struct_impl(port_, map)
 }
 
 #[no_mangle]
-pub extern "C" fn wire_concatenate__method__ConcatenateWith(
-    port_: i64,
-    that: *mut wire_ConcatenateWith,
-    b: *mut wire_uint_8_list,
-) {
-    wire_concatenate__method__ConcatenateWith_impl(port_, that, b)
+pub extern "C" fn wire_count_weekdays(port_: i64, days: *mut wire_list_weekdays) {
+    wire_count_weekdays_impl(port_, days)
 }
 
 #[no_mangle]
-pub extern "C" fn wire_concatenate_static__static_method__ConcatenateWith(
+pub extern "C" fn wire_index_words(port_: i64, words: *mut wire_StringList) {
+    wire_index_words_impl(port_, words)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_handle_colliding_keys(
+    port_: i64,
+    map: *mut wire_hash_map_colliding_key_u32,
+) {
+    wire_handle_colliding_keys_impl(port_, map)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_handle_btree_set(port_: i64, set: *mut wire_StringList) {
+    wire_handle_btree_set_impl(port_, set)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_handle_btree_map(port_: i64, map: *mut wire_btree_map_i32_String) {
+    wire_handle_btree_map_impl(port_, map)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_group_attributes(port_: i64, attributes: *mut wire_list_attribute) {
+    wire_group_attributes_impl(port_, attributes)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_handle_tuple(port_: i64, tuple: *mut wire_tuple3_i32_String_bool) {
+    wire_handle_tuple_impl(port_, tuple)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_handle_nested_tuple(
+    port_: i64,
+    tuple: *mut wire_tuple2_i32_tuple2_String_bool,
+) {
+    wire_handle_nested_tuple_impl(port_, tuple)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_sum__method__SumWith(port_: i64, that: *mut wire_SumWith, y: u32, z: u32) {
+    wire_sum__method__SumWith_impl(port_, that, y, z)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_new__static_method__ConcatenateWith(port_: i64, a: *mut wire_uint_8_list) {
+    wire_new__static_method__ConcatenateWith_impl(port_, a)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_concatenate__method__ConcatenateWith(
+    port_: i64,
+    that: *mut wire_ConcatenateWith,
+    b: *mut wire_uint_8_list,
+) {
+    wire_concatenate__method__ConcatenateWith_impl(port_, that, b)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_concatenate_static__static_method__ConcatenateWith(
     port_: i64,
     a: *mut wire_uint_8_list,
     b: *mut wire_uint_8_list,
@@ -797,6 +1264,211 @@ pub extern "C" fn wire_handle_some_static_stream_sink_single_arg__static_method_
     wire_handle_some_static_stream_sink_single_arg__static_method__ConcatenateWith_impl(port_)
 }
 
+#[no_mangle]
+pub extern "C" fn wire_start__static_method__Counter(port_: i64, from: i32) {
+    wire_start__static_method__Counter_impl(port_, from)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_increment__method__Counter(port_: i64, that: wire_Counter, by: i32) {
+    wire_increment__method__Counter_impl(port_, that, by)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_label__method__Counter(port_: i64, that: wire_Counter) {
+    wire_label__method__Counter_impl(port_, that)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_reset__method__Counter(port_: i64, that: wire_Counter, to: i32) {
+    wire_reset__method__Counter_impl(port_, that, to)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_count_down__method__Counter(port_: i64, that: wire_Counter) {
+    wire_count_down__method__Counter_impl(port_, that)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_increment_later__method__Counter(port_: i64, that: wire_Counter, by: i32) {
+    wire_increment_later__method__Counter_impl(port_, that, by)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_updates__method__Counter(port_: i64, that: wire_Counter) {
+    wire_updates__method__Counter_impl(port_, that)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_finish__method__Counter(port_: i64, that: wire_Counter) {
+    wire_finish__method__Counter_impl(port_, that)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_open__static_method__Rooms(port_: i64) {
+    wire_open__static_method__Rooms_impl(port_)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_join__method__Rooms(
+    port_: i64,
+    that: wire_Rooms,
+    room: *mut wire_uint_8_list,
+) {
+    wire_join__method__Rooms_impl(port_, that, room)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_post__method__Rooms(
+    port_: i64,
+    that: wire_Rooms,
+    room: *mut wire_uint_8_list,
+    message: *mut wire_uint_8_list,
+) {
+    wire_post__method__Rooms_impl(port_, that, room, message)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_close_room__method__Rooms(
+    port_: i64,
+    that: wire_Rooms,
+    room: *mut wire_uint_8_list,
+) {
+    wire_close_room__method__Rooms_impl(port_, that, room)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_named__static_method__Greeter(port_: i64, name: *mut wire_uint_8_list) {
+    wire_named__static_method__Greeter_impl(port_, name)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_describe__method__Counter(port_: i64, that: wire_Counter) {
+    wire_describe__method__Counter_impl(port_, that)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_shout__method__Counter(port_: i64, that: wire_Counter) {
+    wire_shout__method__Counter_impl(port_, that)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_describe__method__Greeter(port_: i64, that: wire_Greeter) {
+    wire_describe__method__Greeter_impl(port_, that)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_shout__method__Greeter(port_: i64, that: wire_Greeter) {
+    wire_shout__method__Greeter_impl(port_, that)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_dispatch(port_: i64, id: *mut wire_uint_8_list, x: i32) {
+    wire_dispatch_impl(port_, id, x)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_next_dart_iterator_string(port_: i64, iterator: wire_DartIteratorString) {
+    wire_next_dart_iterator_string_impl(port_, iterator)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_next_dart_iterator_i_32(port_: i64, iterator: wire_DartIteratorI32) {
+    wire_next_dart_iterator_i_32_impl(port_, iterator)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_changes__method__Counter(port_: i64, that: wire_Counter) {
+    wire_changes__method__Counter_impl(port_, that)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_warm_up(port_: i64) {
+    wire_warm_up_impl(port_)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_upgrade_weak_counter(port_: i64, weak: wire_WeakCounter) {
+    wire_upgrade_weak_counter_impl(port_, weak)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_instant_duration_since(
+    port_: i64,
+    that: wire_Instant,
+    earlier: wire_Instant,
+) {
+    wire_instant_duration_since_impl(port_, that, earlier)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_handle_stream_drain(arg: *mut wire_uint_8_list) -> support::WireSyncReturn {
+    wire_handle_stream_drain_impl(arg)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_handle_stream_of_struct_drain() -> support::WireSyncReturn {
+    wire_handle_stream_of_struct_drain_impl()
+}
+
+#[no_mangle]
+pub extern "C" fn wire_register_event_listener_drain() -> support::WireSyncReturn {
+    wire_register_event_listener_drain_impl()
+}
+
+#[no_mangle]
+pub extern "C" fn wire_handle_stream_sink_at_1_drain(
+    key: u32,
+    max: u32,
+) -> support::WireSyncReturn {
+    wire_handle_stream_sink_at_1_drain_impl(key, max)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_handle_stream_sink_at_2_drain(
+    key: u32,
+    max: u32,
+) -> support::WireSyncReturn {
+    wire_handle_stream_sink_at_2_drain_impl(key, max)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_handle_stream_sink_at_3_drain(
+    key: u32,
+    max: u32,
+) -> support::WireSyncReturn {
+    wire_handle_stream_sink_at_3_drain_impl(key, max)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_handle_stream_closed_by_drop_drain(max: u32) -> support::WireSyncReturn {
+    wire_handle_stream_closed_by_drop_drain_impl(max)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_count_from_drain(from: u32, count: u32) -> support::WireSyncReturn {
+    wire_count_from_drain_impl(from, count)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_parse_numbers_drain(
+    input: *mut wire_uint_8_list,
+) -> support::WireSyncReturn {
+    wire_parse_numbers_drain_impl(input)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_handle_broadcast_stream_drain(max: u32) -> support::WireSyncReturn {
+    wire_handle_broadcast_stream_drain_impl(max)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_handle_stream_closed_by_threads_drain(
+    threads: u32,
+) -> support::WireSyncReturn {
+    wire_handle_stream_closed_by_threads_drain_impl(threads)
+}
+
 // Section: allocate functions
 
 #[no_mangle]
@@ -804,11 +1476,31 @@ pub extern "C" fn new_BoxDartDebug() -> wire_BoxDartDebug {
     wire_BoxDartDebug::new_with_null_ptr()
 }
 
+#[no_mangle]
+pub extern "C" fn new_Counter() -> wire_Counter {
+    wire_Counter::new_with_null_ptr()
+}
+
+#[no_mangle]
+pub extern "C" fn new_DartIteratorI32() -> wire_DartIteratorI32 {
+    wire_DartIteratorI32::new_with_null_ptr()
+}
+
+#[no_mangle]
+pub extern "C" fn new_DartIteratorString() -> wire_DartIteratorString {
+    wire_DartIteratorString::new_with_null_ptr()
+}
+
 #[no_mangle]
 pub extern "C" fn new_DartOpaque() -> wire_DartOpaque {
     wire_DartOpaque::new_with_null_ptr()
 }
 
+#[no_mangle]
+pub extern "C" fn new_Greeter() -> wire_Greeter {
+    wire_Greeter::new_with_null_ptr()
+}
+
 #[no_mangle]
 pub extern "C" fn new_HideData() -> wire_HideData {
     wire_HideData::new_with_null_ptr()
@@ -819,6 +1511,11 @@ pub extern "C" fn new_I32() -> wire_I32 {
     wire_I32::new_with_null_ptr()
 }
 
+#[no_mangle]
+pub extern "C" fn new_Instant() -> wire_Instant {
+    wire_Instant::new_with_null_ptr()
+}
+
 #[no_mangle]
 pub extern "C" fn new_MutexHideData() -> wire_MutexHideData {
     wire_MutexHideData::new_with_null_ptr()
@@ -829,6 +1526,11 @@ pub extern "C" fn new_NonSendHideData() -> wire_NonSendHideData {
     wire_NonSendHideData::new_with_null_ptr()
 }
 
+#[no_mangle]
+pub extern "C" fn new_Rooms() -> wire_Rooms {
+    wire_Rooms::new_with_null_ptr()
+}
+
 #[no_mangle]
 pub extern "C" fn new_RwLockHideData() -> wire_RwLockHideData {
     wire_RwLockHideData::new_with_null_ptr()
@@ -843,6 +1545,11 @@ pub extern "C" fn new_StringList_0(len: i32) -> *mut wire_StringList {
     support::new_leak_box_ptr(wrap)
 }
 
+#[no_mangle]
+pub extern "C" fn new_WeakCounter() -> wire_WeakCounter {
+    wire_WeakCounter::new_with_null_ptr()
+}
+
 #[no_mangle]
 pub extern "C" fn new_box_application_env_0() -> *mut wire_ApplicationEnv {
     support::new_leak_box_ptr(wire_ApplicationEnv::new_with_null_ptr())
@@ -868,6 +1575,11 @@ pub extern "C" fn new_box_autoadd_application_env_0() -> *mut wire_ApplicationEn
     support::new_leak_box_ptr(wire_ApplicationEnv::new_with_null_ptr())
 }
 
+#[no_mangle]
+pub extern "C" fn new_box_autoadd_application_message_0() -> *mut wire_ApplicationMessage {
+    support::new_leak_box_ptr(wire_ApplicationMessage::new_with_null_ptr())
+}
+
 #[no_mangle]
 pub extern "C" fn new_box_autoadd_application_settings_0() -> *mut wire_ApplicationSettings {
     support::new_leak_box_ptr(wire_ApplicationSettings::new_with_null_ptr())
@@ -898,6 +1610,11 @@ pub extern "C" fn new_box_autoadd_dart_opaque_nested_0() -> *mut wire_DartOpaque
     support::new_leak_box_ptr(wire_DartOpaqueNested::new_with_null_ptr())
 }
 
+#[no_mangle]
+pub extern "C" fn new_box_autoadd_document_0() -> *mut wire_Document {
+    support::new_leak_box_ptr(wire_Document::new_with_null_ptr())
+}
+
 #[no_mangle]
 pub extern "C" fn new_box_autoadd_empty_0() -> *mut wire_Empty {
     support::new_leak_box_ptr(wire_Empty::new_with_null_ptr())
@@ -938,6 +1655,16 @@ pub extern "C" fn new_box_autoadd_feed_id_0() -> *mut wire_FeedId {
     support::new_leak_box_ptr(wire_FeedId::new_with_null_ptr())
 }
 
+#[no_mangle]
+pub extern "C" fn new_box_autoadd_frame_0() -> *mut wire_Frame {
+    support::new_leak_box_ptr(wire_Frame::new_with_null_ptr())
+}
+
+#[no_mangle]
+pub extern "C" fn new_box_autoadd_grid_cell_0() -> *mut wire_GridCell {
+    support::new_leak_box_ptr(wire_GridCell::new_with_null_ptr())
+}
+
 #[no_mangle]
 pub extern "C" fn new_box_autoadd_i32_0(value: i32) -> *mut i32 {
     support::new_leak_box_ptr(value)
@@ -963,6 +1690,11 @@ pub extern "C" fn new_box_autoadd_message_id_0() -> *mut wire_MessageId {
     support::new_leak_box_ptr(wire_MessageId::new_with_null_ptr())
 }
 
+#[no_mangle]
+pub extern "C" fn new_box_autoadd_money_0() -> *mut wire_Money {
+    support::new_leak_box_ptr(wire_Money::new_with_null_ptr())
+}
+
 #[no_mangle]
 pub extern "C" fn new_box_autoadd_my_nested_struct_0() -> *mut wire_MyNestedStruct {
     support::new_leak_box_ptr(wire_MyNestedStruct::new_with_null_ptr())
@@ -1003,21 +1735,67 @@ pub extern "C" fn new_box_autoadd_opaque_nested_0() -> *mut wire_OpaqueNested {
     support::new_leak_box_ptr(wire_OpaqueNested::new_with_null_ptr())
 }
 
+#[no_mangle]
+pub extern "C" fn new_box_autoadd_pair_i_32_0() -> *mut wire_PairI32 {
+    support::new_leak_box_ptr(wire_PairI32::new_with_null_ptr())
+}
+
+#[no_mangle]
+pub extern "C" fn new_box_autoadd_product_id_0() -> *mut wire_ProductId {
+    support::new_leak_box_ptr(wire_ProductId::new_with_null_ptr())
+}
+
+#[no_mangle]
+pub extern "C" fn new_box_autoadd_progress_0() -> *mut wire_Progress {
+    support::new_leak_box_ptr(wire_Progress::new_with_null_ptr())
+}
+
+#[no_mangle]
+pub extern "C" fn new_box_autoadd_sample_0() -> *mut wire_Sample {
+    support::new_leak_box_ptr(wire_Sample::new_with_null_ptr())
+}
+
 #[no_mangle]
 pub extern "C" fn new_box_autoadd_sequences_0() -> *mut wire_Sequences {
     support::new_leak_box_ptr(wire_Sequences::new_with_null_ptr())
 }
 
+#[no_mangle]
+pub extern "C" fn new_box_autoadd_session_0() -> *mut wire_Session {
+    support::new_leak_box_ptr(wire_Session::new_with_null_ptr())
+}
+
 #[no_mangle]
 pub extern "C" fn new_box_autoadd_sum_with_0() -> *mut wire_SumWith {
     support::new_leak_box_ptr(wire_SumWith::new_with_null_ptr())
 }
 
+#[no_mangle]
+pub extern "C" fn new_box_autoadd_tag_0() -> *mut wire_Tag {
+    support::new_leak_box_ptr(wire_Tag::new_with_null_ptr())
+}
+
 #[no_mangle]
 pub extern "C" fn new_box_autoadd_test_id_0() -> *mut wire_TestId {
     support::new_leak_box_ptr(wire_TestId::new_with_null_ptr())
 }
 
+#[no_mangle]
+pub extern "C" fn new_box_autoadd_tuple2_i32_tuple2_String_bool_0(
+) -> *mut wire_tuple2_i32_tuple2_String_bool {
+    support::new_leak_box_ptr(wire_tuple2_i32_tuple2_String_bool::new_with_null_ptr())
+}
+
+#[no_mangle]
+pub extern "C" fn new_box_autoadd_tuple3_i32_String_bool_0() -> *mut wire_tuple3_i32_String_bool {
+    support::new_leak_box_ptr(wire_tuple3_i32_String_bool::new_with_null_ptr())
+}
+
+#[no_mangle]
+pub extern "C" fn new_box_autoadd_u32_0(value: u32) -> *mut u32 {
+    support::new_leak_box_ptr(value)
+}
+
 #[no_mangle]
 pub extern "C" fn new_box_autoadd_user_id_0() -> *mut wire_UserId {
     support::new_leak_box_ptr(wire_UserId::new_with_null_ptr())
@@ -1088,6 +1866,11 @@ pub extern "C" fn new_box_weekdays_0(value: i32) -> *mut i32 {
     support::new_leak_box_ptr(value)
 }
 
+#[no_mangle]
+pub extern "C" fn new_btree_map_i32_String_0() -> *mut wire_btree_map_i32_String {
+    support::new_leak_box_ptr(wire_btree_map_i32_String::new_with_null_ptr())
+}
+
 #[no_mangle]
 pub extern "C" fn new_float_32_list_0(len: i32) -> *mut wire_float_32_list {
     let ans = wire_float_32_list {
@@ -1106,6 +1889,21 @@ pub extern "C" fn new_float_64_list_0(len: i32) -> *mut wire_float_64_list {
     support::new_leak_box_ptr(ans)
 }
 
+#[no_mangle]
+pub extern "C" fn new_hash_map_String_i32_0() -> *mut wire_hash_map_String_i32 {
+    support::new_leak_box_ptr(wire_hash_map_String_i32::new_with_null_ptr())
+}
+
+#[no_mangle]
+pub extern "C" fn new_hash_map_colliding_key_u32_0() -> *mut wire_hash_map_colliding_key_u32 {
+    support::new_leak_box_ptr(wire_hash_map_colliding_key_u32::new_with_null_ptr())
+}
+
+#[no_mangle]
+pub extern "C" fn new_hash_map_u32_attribute_0() -> *mut wire_hash_map_u32_attribute {
+    support::new_leak_box_ptr(wire_hash_map_u32_attribute::new_with_null_ptr())
+}
+
 #[no_mangle]
 pub extern "C" fn new_int_32_list_0(len: i32) -> *mut wire_int_32_list {
     let ans = wire_int_32_list {
@@ -1133,6 +1931,15 @@ pub extern "C" fn new_list_DartOpaque_0(len: i32) -> *mut wire_list_DartOpaque {
     support::new_leak_box_ptr(wrap)
 }
 
+#[no_mangle]
+pub extern "C" fn new_list_Decimal_0(len: i32) -> *mut wire_list_Decimal {
+    let wrap = wire_list_Decimal {
+        ptr: support::new_leak_vec_ptr(<*mut wire_uint_8_list>::new_with_null_ptr(), len),
+        len,
+    };
+    support::new_leak_box_ptr(wrap)
+}
+
 #[no_mangle]
 pub extern "C" fn new_list_HideData_0(len: i32) -> *mut wire_list_HideData {
     let wrap = wire_list_HideData {
@@ -1161,16 +1968,43 @@ pub extern "C" fn new_list_attribute_0(len: i32) -> *mut wire_list_attribute {
 }
 
 #[no_mangle]
-pub extern "C" fn new_list_my_size_0(len: i32) -> *mut wire_list_my_size {
-    let wrap = wire_list_my_size {
-        ptr: support::new_leak_vec_ptr(<wire_MySize>::new_with_null_ptr(), len),
+pub extern "C" fn new_list_char_0(len: i32) -> *mut wire_list_char {
+    let wrap = wire_list_char {
+        ptr: support::new_leak_vec_ptr(<u32>::default(), len),
         len,
     };
     support::new_leak_box_ptr(wrap)
 }
 
 #[no_mangle]
-pub extern "C" fn new_list_my_tree_node_0(len: i32) -> *mut wire_list_my_tree_node {
+pub extern "C" fn new_list_colliding_key_0(len: i32) -> *mut wire_list_colliding_key {
+    let wrap = wire_list_colliding_key {
+        ptr: support::new_leak_vec_ptr(<wire_CollidingKey>::new_with_null_ptr(), len),
+        len,
+    };
+    support::new_leak_box_ptr(wrap)
+}
+
+#[no_mangle]
+pub extern "C" fn new_list_int_32_list_0(len: i32) -> *mut wire_list_int_32_list {
+    let wrap = wire_list_int_32_list {
+        ptr: support::new_leak_vec_ptr(<*mut wire_int_32_list>::new_with_null_ptr(), len),
+        len,
+    };
+    support::new_leak_box_ptr(wrap)
+}
+
+#[no_mangle]
+pub extern "C" fn new_list_my_size_0(len: i32) -> *mut wire_list_my_size {
+    let wrap = wire_list_my_size {
+        ptr: support::new_leak_vec_ptr(<wire_MySize>::new_with_null_ptr(), len),
+        len,
+    };
+    support::new_leak_box_ptr(wrap)
+}
+
+#[no_mangle]
+pub extern "C" fn new_list_my_tree_node_0(len: i32) -> *mut wire_list_my_tree_node {
     let wrap = wire_list_my_tree_node {
         ptr: support::new_leak_vec_ptr(<wire_MyTreeNode>::new_with_null_ptr(), len),
         len,
@@ -1198,6 +2032,42 @@ pub extern "C" fn new_list_test_id_0(len: i32) -> *mut wire_list_test_id {
     support::new_leak_box_ptr(wrap)
 }
 
+#[no_mangle]
+pub extern "C" fn new_list_weekdays_0(len: i32) -> *mut wire_list_weekdays {
+    let wrap = wire_list_weekdays {
+        ptr: support::new_leak_vec_ptr(<i32>::default(), len),
+        len,
+    };
+    support::new_leak_box_ptr(wrap)
+}
+
+#[no_mangle]
+pub extern "C" fn new_uint_16_list_0(len: i32) -> *mut wire_uint_16_list {
+    let ans = wire_uint_16_list {
+        ptr: support::new_leak_vec_ptr(Default::default(), len),
+        len,
+    };
+    support::new_leak_box_ptr(ans)
+}
+
+#[no_mangle]
+pub extern "C" fn new_uint_32_list_0(len: i32) -> *mut wire_uint_32_list {
+    let ans = wire_uint_32_list {
+        ptr: support::new_leak_vec_ptr(Default::default(), len),
+        len,
+    };
+    support::new_leak_box_ptr(ans)
+}
+
+#[no_mangle]
+pub extern "C" fn new_uint_64_list_0(len: i32) -> *mut wire_uint_64_list {
+    let ans = wire_uint_64_list {
+        ptr: support::new_leak_vec_ptr(Default::default(), len),
+        len,
+    };
+    support::new_leak_box_ptr(ans)
+}
+
 #[no_mangle]
 pub extern "C" fn new_uint_8_list_0(len: i32) -> *mut wire_uint_8_list {
     let ans = wire_uint_8_list {
@@ -1224,6 +2094,95 @@ pub extern "C" fn share_opaque_BoxDartDebug(ptr: *const c_void) -> *const c_void
     }
 }
 
+#[no_mangle]
+pub extern "C" fn send_opaque_BoxDartDebug(ptr: *const c_void) -> *const c_void {
+    if !flutter_rust_bridge::is_send_sync!(Box<dyn DartDebug>) {
+        return core::ptr::null();
+    }
+    unsafe {
+        Arc::<Box<dyn DartDebug>>::increment_strong_count(ptr as _);
+        ptr
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn drop_opaque_Counter(ptr: *const c_void) {
+    unsafe {
+        Arc::<Counter>::decrement_strong_count(ptr as _);
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn share_opaque_Counter(ptr: *const c_void) -> *const c_void {
+    unsafe {
+        Arc::<Counter>::increment_strong_count(ptr as _);
+        ptr
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn send_opaque_Counter(ptr: *const c_void) -> *const c_void {
+    if !flutter_rust_bridge::is_send_sync!(Counter) {
+        return core::ptr::null();
+    }
+    unsafe {
+        Arc::<Counter>::increment_strong_count(ptr as _);
+        ptr
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn drop_opaque_DartIteratorI32(ptr: *const c_void) {
+    unsafe {
+        Arc::<DartIterator<i32>>::decrement_strong_count(ptr as _);
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn share_opaque_DartIteratorI32(ptr: *const c_void) -> *const c_void {
+    unsafe {
+        Arc::<DartIterator<i32>>::increment_strong_count(ptr as _);
+        ptr
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn send_opaque_DartIteratorI32(ptr: *const c_void) -> *const c_void {
+    if !flutter_rust_bridge::is_send_sync!(DartIterator<i32>) {
+        return core::ptr::null();
+    }
+    unsafe {
+        Arc::<DartIterator<i32>>::increment_strong_count(ptr as _);
+        ptr
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn drop_opaque_DartIteratorString(ptr: *const c_void) {
+    unsafe {
+        Arc::<DartIterator<String>>::decrement_strong_count(ptr as _);
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn share_opaque_DartIteratorString(ptr: *const c_void) -> *const c_void {
+    unsafe {
+        Arc::<DartIterator<String>>::increment_strong_count(ptr as _);
+        ptr
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn send_opaque_DartIteratorString(ptr: *const c_void) -> *const c_void {
+    if !flutter_rust_bridge::is_send_sync!(DartIterator<String>) {
+        return core::ptr::null();
+    }
+    unsafe {
+        Arc::<DartIterator<String>>::increment_strong_count(ptr as _);
+        ptr
+    }
+}
+
 #[no_mangle]
 pub extern "C" fn drop_opaque_FrbOpaqueReturn(ptr: *const c_void) {
     unsafe {
@@ -1239,6 +2198,17 @@ pub extern "C" fn share_opaque_FrbOpaqueReturn(ptr: *const c_void) -> *const c_v
     }
 }
 
+#[no_mangle]
+pub extern "C" fn send_opaque_FrbOpaqueReturn(ptr: *const c_void) -> *const c_void {
+    if !flutter_rust_bridge::is_send_sync!(FrbOpaqueReturn) {
+        return core::ptr::null();
+    }
+    unsafe {
+        Arc::<FrbOpaqueReturn>::increment_strong_count(ptr as _);
+        ptr
+    }
+}
+
 #[no_mangle]
 pub extern "C" fn drop_opaque_FrbOpaqueSyncReturn(ptr: *const c_void) {
     unsafe {
@@ -1254,6 +2224,43 @@ pub extern "C" fn share_opaque_FrbOpaqueSyncReturn(ptr: *const c_void) -> *const
     }
 }
 
+#[no_mangle]
+pub extern "C" fn send_opaque_FrbOpaqueSyncReturn(ptr: *const c_void) -> *const c_void {
+    if !flutter_rust_bridge::is_send_sync!(FrbOpaqueSyncReturn) {
+        return core::ptr::null();
+    }
+    unsafe {
+        Arc::<FrbOpaqueSyncReturn>::increment_strong_count(ptr as _);
+        ptr
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn drop_opaque_Greeter(ptr: *const c_void) {
+    unsafe {
+        Arc::<Greeter>::decrement_strong_count(ptr as _);
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn share_opaque_Greeter(ptr: *const c_void) -> *const c_void {
+    unsafe {
+        Arc::<Greeter>::increment_strong_count(ptr as _);
+        ptr
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn send_opaque_Greeter(ptr: *const c_void) -> *const c_void {
+    if !flutter_rust_bridge::is_send_sync!(Greeter) {
+        return core::ptr::null();
+    }
+    unsafe {
+        Arc::<Greeter>::increment_strong_count(ptr as _);
+        ptr
+    }
+}
+
 #[no_mangle]
 pub extern "C" fn drop_opaque_HideData(ptr: *const c_void) {
     unsafe {
@@ -1269,6 +2276,17 @@ pub extern "C" fn share_opaque_HideData(ptr: *const c_void) -> *const c_void {
     }
 }
 
+#[no_mangle]
+pub extern "C" fn send_opaque_HideData(ptr: *const c_void) -> *const c_void {
+    if !flutter_rust_bridge::is_send_sync!(HideData) {
+        return core::ptr::null();
+    }
+    unsafe {
+        Arc::<HideData>::increment_strong_count(ptr as _);
+        ptr
+    }
+}
+
 #[no_mangle]
 pub extern "C" fn drop_opaque_I32(ptr: *const c_void) {
     unsafe {
@@ -1284,6 +2302,43 @@ pub extern "C" fn share_opaque_I32(ptr: *const c_void) -> *const c_void {
     }
 }
 
+#[no_mangle]
+pub extern "C" fn send_opaque_I32(ptr: *const c_void) -> *const c_void {
+    if !flutter_rust_bridge::is_send_sync!(i32) {
+        return core::ptr::null();
+    }
+    unsafe {
+        Arc::<i32>::increment_strong_count(ptr as _);
+        ptr
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn drop_opaque_Instant(ptr: *const c_void) {
+    unsafe {
+        Arc::<std::time::Instant>::decrement_strong_count(ptr as _);
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn share_opaque_Instant(ptr: *const c_void) -> *const c_void {
+    unsafe {
+        Arc::<std::time::Instant>::increment_strong_count(ptr as _);
+        ptr
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn send_opaque_Instant(ptr: *const c_void) -> *const c_void {
+    if !flutter_rust_bridge::is_send_sync!(std::time::Instant) {
+        return core::ptr::null();
+    }
+    unsafe {
+        Arc::<std::time::Instant>::increment_strong_count(ptr as _);
+        ptr
+    }
+}
+
 #[no_mangle]
 pub extern "C" fn drop_opaque_MutexHideData(ptr: *const c_void) {
     unsafe {
@@ -1299,6 +2354,17 @@ pub extern "C" fn share_opaque_MutexHideData(ptr: *const c_void) -> *const c_voi
     }
 }
 
+#[no_mangle]
+pub extern "C" fn send_opaque_MutexHideData(ptr: *const c_void) -> *const c_void {
+    if !flutter_rust_bridge::is_send_sync!(Mutex<HideData>) {
+        return core::ptr::null();
+    }
+    unsafe {
+        Arc::<Mutex<HideData>>::increment_strong_count(ptr as _);
+        ptr
+    }
+}
+
 #[no_mangle]
 pub extern "C" fn drop_opaque_NonSendHideData(ptr: *const c_void) {
     unsafe {
@@ -1314,6 +2380,43 @@ pub extern "C" fn share_opaque_NonSendHideData(ptr: *const c_void) -> *const c_v
     }
 }
 
+#[no_mangle]
+pub extern "C" fn send_opaque_NonSendHideData(ptr: *const c_void) -> *const c_void {
+    if !flutter_rust_bridge::is_send_sync!(NonSendHideData) {
+        return core::ptr::null();
+    }
+    unsafe {
+        Arc::<NonSendHideData>::increment_strong_count(ptr as _);
+        ptr
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn drop_opaque_Rooms(ptr: *const c_void) {
+    unsafe {
+        Arc::<Rooms>::decrement_strong_count(ptr as _);
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn share_opaque_Rooms(ptr: *const c_void) -> *const c_void {
+    unsafe {
+        Arc::<Rooms>::increment_strong_count(ptr as _);
+        ptr
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn send_opaque_Rooms(ptr: *const c_void) -> *const c_void {
+    if !flutter_rust_bridge::is_send_sync!(Rooms) {
+        return core::ptr::null();
+    }
+    unsafe {
+        Arc::<Rooms>::increment_strong_count(ptr as _);
+        ptr
+    }
+}
+
 #[no_mangle]
 pub extern "C" fn drop_opaque_RwLockHideData(ptr: *const c_void) {
     unsafe {
@@ -1329,24 +2432,118 @@ pub extern "C" fn share_opaque_RwLockHideData(ptr: *const c_void) -> *const c_vo
     }
 }
 
+#[no_mangle]
+pub extern "C" fn send_opaque_RwLockHideData(ptr: *const c_void) -> *const c_void {
+    if !flutter_rust_bridge::is_send_sync!(RwLock<HideData>) {
+        return core::ptr::null();
+    }
+    unsafe {
+        Arc::<RwLock<HideData>>::increment_strong_count(ptr as _);
+        ptr
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn drop_opaque_WeakCounter(ptr: *const c_void) {
+    unsafe {
+        Arc::<std::sync::Weak<Counter>>::decrement_strong_count(ptr as _);
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn share_opaque_WeakCounter(ptr: *const c_void) -> *const c_void {
+    unsafe {
+        Arc::<std::sync::Weak<Counter>>::increment_strong_count(ptr as _);
+        ptr
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn send_opaque_WeakCounter(ptr: *const c_void) -> *const c_void {
+    if !flutter_rust_bridge::is_send_sync!(std::sync::Weak<Counter>) {
+        return core::ptr::null();
+    }
+    unsafe {
+        Arc::<std::sync::Weak<Counter>>::increment_strong_count(ptr as _);
+        ptr
+    }
+}
+
 // Section: impl Wire2Api
 
+impl Wire2Api<std::sync::Arc<HideData>> for wire_HideData {
+    fn wire2api(self) -> std::sync::Arc<HideData> {
+        let opaque: RustOpaque<HideData> = self.wire2api();
+        opaque.into_arc()
+    }
+}
 impl Wire2Api<RustOpaque<Box<dyn DartDebug>>> for wire_BoxDartDebug {
     fn wire2api(self) -> RustOpaque<Box<dyn DartDebug>> {
         unsafe { support::opaque_from_dart(self.ptr as _) }
     }
 }
+impl Wire2Api<bytes::Bytes> for *mut wire_uint_8_list {
+    fn wire2api(self) -> bytes::Bytes {
+        let vec: Vec<u8> = self.wire2api();
+        wire2api_bytes(vec)
+    }
+}
 impl Wire2Api<chrono::Duration> for i64 {
     fn wire2api(self) -> chrono::Duration {
         chrono::Duration::microseconds(self)
     }
 }
 
+impl Wire2Api<Version> for *mut wire_uint_8_list {
+    fn wire2api(self) -> Version {
+        let bytes: Vec<u8> = self.wire2api();
+        crate::codecs::version::decode(&bytes)
+    }
+}
+impl Wire2Api<RustOpaque<Counter>> for wire_Counter {
+    fn wire2api(self) -> RustOpaque<Counter> {
+        unsafe { support::opaque_from_dart(self.ptr as _) }
+    }
+}
+impl Wire2Api<std::borrow::Cow<'static, [u8]>> for *mut wire_uint_8_list {
+    fn wire2api(self) -> std::borrow::Cow<'static, [u8]> {
+        let vec: Vec<u8> = self.wire2api();
+        std::borrow::Cow::Owned(vec)
+    }
+}
+impl Wire2Api<std::borrow::Cow<'static, str>> for *mut wire_uint_8_list {
+    fn wire2api(self) -> std::borrow::Cow<'static, str> {
+        let s: String = self.wire2api();
+        std::borrow::Cow::Owned(s)
+    }
+}
+impl Wire2Api<RustOpaque<DartIterator<i32>>> for wire_DartIteratorI32 {
+    fn wire2api(self) -> RustOpaque<DartIterator<i32>> {
+        unsafe { support::opaque_from_dart(self.ptr as _) }
+    }
+}
+impl Wire2Api<RustOpaque<DartIterator<String>>> for wire_DartIteratorString {
+    fn wire2api(self) -> RustOpaque<DartIterator<String>> {
+        unsafe { support::opaque_from_dart(self.ptr as _) }
+    }
+}
 impl Wire2Api<DartOpaque> for wire_DartOpaque {
     fn wire2api(self) -> DartOpaque {
         unsafe { DartOpaque::new(self.handle as _, self.port) }
     }
 }
+impl Wire2Api<rust_decimal::Decimal> for *mut wire_uint_8_list {
+    fn wire2api(self) -> rust_decimal::Decimal {
+        let s: String = self.wire2api();
+        wire2api_decimal(s)
+    }
+}
+
+impl Wire2Api<RustOpaque<Greeter>> for wire_Greeter {
+    fn wire2api(self) -> RustOpaque<Greeter> {
+        unsafe { support::opaque_from_dart(self.ptr as _) }
+    }
+}
 impl Wire2Api<RustOpaque<HideData>> for wire_HideData {
     fn wire2api(self) -> RustOpaque<HideData> {
         unsafe { support::opaque_from_dart(self.ptr as _) }
@@ -1363,25 +2560,77 @@ impl Wire2Api<RustOpaque<i32>> for wire_I32 {
         unsafe { support::opaque_from_dart(self.ptr as _) }
     }
 }
+impl Wire2Api<RustOpaque<std::time::Instant>> for wire_Instant {
+    fn wire2api(self) -> RustOpaque<std::time::Instant> {
+        unsafe { support::opaque_from_dart(self.ptr as _) }
+    }
+}
 impl Wire2Api<RustOpaque<Mutex<HideData>>> for wire_MutexHideData {
     fn wire2api(self) -> RustOpaque<Mutex<HideData>> {
         unsafe { support::opaque_from_dart(self.ptr as _) }
     }
 }
-impl Wire2Api<RustOpaque<NonSendHideData>> for wire_NonSendHideData {
-    fn wire2api(self) -> RustOpaque<NonSendHideData> {
+impl Wire2Api<RustOpaque<NonSendHideData>> for wire_NonSendHideData {
+    fn wire2api(self) -> RustOpaque<NonSendHideData> {
+        unsafe { support::opaque_from_dart(self.ptr as _) }
+    }
+}
+impl Wire2Api<std::num::NonZeroU32> for u32 {
+    fn wire2api(self) -> std::num::NonZeroU32 {
+        let value: u32 = self.wire2api();
+        wire2api_non_zero(value)
+    }
+}
+impl Wire2Api<std::num::NonZeroU64> for u64 {
+    fn wire2api(self) -> std::num::NonZeroU64 {
+        let value: u64 = self.wire2api();
+        wire2api_non_zero(value)
+    }
+}
+impl Wire2Api<[DartOpaque; 1]> for *mut wire_list_DartOpaque {
+    fn wire2api(self) -> [DartOpaque; 1] {
+        let vec: Vec<DartOpaque> = self.wire2api();
+        support::from_vec_to_array(vec)
+    }
+}
+impl Wire2Api<std::path::PathBuf> for *mut wire_uint_16_list {
+    fn wire2api(self) -> std::path::PathBuf {
+        let units: Vec<u16> = self.wire2api();
+        wire2api_path(units)
+    }
+}
+impl Wire2Api<std::ops::RangeInclusive<f64>> for *mut wire_float_64_list {
+    fn wire2api(self) -> std::ops::RangeInclusive<f64> {
+        let bounds: Vec<f64> = self.wire2api();
+        wire2api_range_inclusive(bounds)
+    }
+}
+impl Wire2Api<std::ops::Range<u32>> for *mut wire_uint_32_list {
+    fn wire2api(self) -> std::ops::Range<u32> {
+        let bounds: Vec<u32> = self.wire2api();
+        wire2api_range(bounds)
+    }
+}
+impl Wire2Api<RustOpaque<Rooms>> for wire_Rooms {
+    fn wire2api(self) -> RustOpaque<Rooms> {
+        unsafe { support::opaque_from_dart(self.ptr as _) }
+    }
+}
+impl Wire2Api<RustOpaque<RwLock<HideData>>> for wire_RwLockHideData {
+    fn wire2api(self) -> RustOpaque<RwLock<HideData>> {
         unsafe { support::opaque_from_dart(self.ptr as _) }
     }
 }
-impl Wire2Api<[DartOpaque; 1]> for *mut wire_list_DartOpaque {
-    fn wire2api(self) -> [DartOpaque; 1] {
-        let vec: Vec<DartOpaque> = self.wire2api();
-        support::from_vec_to_array(vec)
+impl Wire2Api<flutter_rust_bridge::SharedBuffer> for usize {
+    fn wire2api(self) -> flutter_rust_bridge::SharedBuffer {
+        flutter_rust_bridge::SharedBuffer::from_wire(self)
     }
 }
-impl Wire2Api<RustOpaque<RwLock<HideData>>> for wire_RwLockHideData {
-    fn wire2api(self) -> RustOpaque<RwLock<HideData>> {
-        unsafe { support::opaque_from_dart(self.ptr as _) }
+
+impl Wire2Api<std::net::SocketAddr> for *mut wire_uint_8_list {
+    fn wire2api(self) -> std::net::SocketAddr {
+        let bytes: Vec<u8> = self.wire2api();
+        wire2api_socket_addr(bytes)
     }
 }
 impl Wire2Api<String> for *mut wire_uint_8_list {
@@ -1399,6 +2648,11 @@ impl Wire2Api<Vec<String>> for *mut wire_StringList {
         vec.into_iter().map(Wire2Api::wire2api).collect()
     }
 }
+impl Wire2Api<std::time::SystemTime> for i64 {
+    fn wire2api(self) -> std::time::SystemTime {
+        wire2api_system_time(self)
+    }
+}
 impl Wire2Api<[TestId; 4]> for *mut wire_list_test_id {
     fn wire2api(self) -> [TestId; 4] {
         let vec: Vec<TestId> = self.wire2api();
@@ -1417,6 +2671,17 @@ impl Wire2Api<Vec<uuid::Uuid>> for *mut wire_uint_8_list {
         wire2api_uuids(multiple)
     }
 }
+impl Wire2Api<RustOpaque<std::sync::Weak<Counter>>> for wire_WeakCounter {
+    fn wire2api(self) -> RustOpaque<std::sync::Weak<Counter>> {
+        unsafe { support::opaque_from_dart(self.ptr as _) }
+    }
+}
+impl Wire2Api<std::sync::Weak<Counter>> for wire_WeakCounter {
+    fn wire2api(self) -> std::sync::Weak<Counter> {
+        let opaque: RustOpaque<std::sync::Weak<Counter>> = self.wire2api();
+        std::sync::Weak::clone(&opaque)
+    }
+}
 impl Wire2Api<ZeroCopyBuffer<Vec<u8>>> for *mut wire_uint_8_list {
     fn wire2api(self) -> ZeroCopyBuffer<Vec<u8>> {
         ZeroCopyBuffer(self.wire2api())
@@ -1434,6 +2699,27 @@ impl Wire2Api<ApplicationEnvVar> for wire_ApplicationEnvVar {
         ApplicationEnvVar(self.field0.wire2api(), self.field1.wire2api())
     }
 }
+impl Wire2Api<ApplicationMessage> for wire_ApplicationMessage {
+    fn wire2api(self) -> ApplicationMessage {
+        match self.tag {
+            0 => unsafe {
+                let ans = support::box_from_leak_ptr(self.kind);
+                let ans = support::box_from_leak_ptr(ans.DisplayMessage);
+                ApplicationMessage::DisplayMessage(ans.field0.wire2api())
+            },
+            1 => unsafe {
+                let ans = support::box_from_leak_ptr(self.kind);
+                let ans = support::box_from_leak_ptr(ans.RenderPixel);
+                ApplicationMessage::RenderPixel {
+                    x: ans.x.wire2api(),
+                    y: ans.y.wire2api(),
+                }
+            },
+            2 => ApplicationMessage::Exit,
+            _ => unreachable!(),
+        }
+    }
+}
 
 impl Wire2Api<ApplicationSettings> for wire_ApplicationSettings {
     fn wire2api(self) -> ApplicationSettings {
@@ -1490,6 +2776,12 @@ impl Wire2Api<ApplicationEnv> for *mut wire_ApplicationEnv {
         Wire2Api::<ApplicationEnv>::wire2api(*wrap).into()
     }
 }
+impl Wire2Api<ApplicationMessage> for *mut wire_ApplicationMessage {
+    fn wire2api(self) -> ApplicationMessage {
+        let wrap = unsafe { support::box_from_leak_ptr(self) };
+        Wire2Api::<ApplicationMessage>::wire2api(*wrap).into()
+    }
+}
 impl Wire2Api<ApplicationSettings> for *mut wire_ApplicationSettings {
     fn wire2api(self) -> ApplicationSettings {
         let wrap = unsafe { support::box_from_leak_ptr(self) };
@@ -1525,6 +2817,12 @@ impl Wire2Api<DartOpaqueNested> for *mut wire_DartOpaqueNested {
         Wire2Api::<DartOpaqueNested>::wire2api(*wrap).into()
     }
 }
+impl Wire2Api<Document> for *mut wire_Document {
+    fn wire2api(self) -> Document {
+        let wrap = unsafe { support::box_from_leak_ptr(self) };
+        Wire2Api::<Document>::wire2api(*wrap).into()
+    }
+}
 impl Wire2Api<Empty> for *mut wire_Empty {
     fn wire2api(self) -> Empty {
         let wrap = unsafe { support::box_from_leak_ptr(self) };
@@ -1572,6 +2870,18 @@ impl Wire2Api<FeedId> for *mut wire_FeedId {
         Wire2Api::<FeedId>::wire2api(*wrap).into()
     }
 }
+impl Wire2Api<Frame> for *mut wire_Frame {
+    fn wire2api(self) -> Frame {
+        let wrap = unsafe { support::box_from_leak_ptr(self) };
+        Wire2Api::<Frame>::wire2api(*wrap).into()
+    }
+}
+impl Wire2Api<GridCell> for *mut wire_GridCell {
+    fn wire2api(self) -> GridCell {
+        let wrap = unsafe { support::box_from_leak_ptr(self) };
+        Wire2Api::<GridCell>::wire2api(*wrap).into()
+    }
+}
 impl Wire2Api<i32> for *mut i32 {
     fn wire2api(self) -> i32 {
         unsafe { *support::box_from_leak_ptr(self) }
@@ -1600,6 +2910,12 @@ impl Wire2Api<MessageId> for *mut wire_MessageId {
         Wire2Api::<MessageId>::wire2api(*wrap).into()
     }
 }
+impl Wire2Api<Money> for *mut wire_Money {
+    fn wire2api(self) -> Money {
+        let wrap = unsafe { support::box_from_leak_ptr(self) };
+        Wire2Api::<Money>::wire2api(*wrap).into()
+    }
+}
 impl Wire2Api<MyNestedStruct> for *mut wire_MyNestedStruct {
     fn wire2api(self) -> MyNestedStruct {
         let wrap = unsafe { support::box_from_leak_ptr(self) };
@@ -1648,24 +2964,77 @@ impl Wire2Api<OpaqueNested> for *mut wire_OpaqueNested {
         Wire2Api::<OpaqueNested>::wire2api(*wrap).into()
     }
 }
+impl Wire2Api<Pair<i32>> for *mut wire_PairI32 {
+    fn wire2api(self) -> Pair<i32> {
+        let wrap = unsafe { support::box_from_leak_ptr(self) };
+        Wire2Api::<Pair<i32>>::wire2api(*wrap).into()
+    }
+}
+impl Wire2Api<ProductId> for *mut wire_ProductId {
+    fn wire2api(self) -> ProductId {
+        let wrap = unsafe { support::box_from_leak_ptr(self) };
+        Wire2Api::<ProductId>::wire2api(*wrap).into()
+    }
+}
+impl Wire2Api<Progress> for *mut wire_Progress {
+    fn wire2api(self) -> Progress {
+        let wrap = unsafe { support::box_from_leak_ptr(self) };
+        Wire2Api::<Progress>::wire2api(*wrap).into()
+    }
+}
+impl Wire2Api<Sample> for *mut wire_Sample {
+    fn wire2api(self) -> Sample {
+        let wrap = unsafe { support::box_from_leak_ptr(self) };
+        Wire2Api::<Sample>::wire2api(*wrap).into()
+    }
+}
 impl Wire2Api<Sequences> for *mut wire_Sequences {
     fn wire2api(self) -> Sequences {
         let wrap = unsafe { support::box_from_leak_ptr(self) };
         Wire2Api::<Sequences>::wire2api(*wrap).into()
     }
 }
+impl Wire2Api<Session> for *mut wire_Session {
+    fn wire2api(self) -> Session {
+        let wrap = unsafe { support::box_from_leak_ptr(self) };
+        Wire2Api::<Session>::wire2api(*wrap).into()
+    }
+}
 impl Wire2Api<SumWith> for *mut wire_SumWith {
     fn wire2api(self) -> SumWith {
         let wrap = unsafe { support::box_from_leak_ptr(self) };
         Wire2Api::<SumWith>::wire2api(*wrap).into()
     }
 }
+impl Wire2Api<Tag> for *mut wire_Tag {
+    fn wire2api(self) -> Tag {
+        let wrap = unsafe { support::box_from_leak_ptr(self) };
+        Wire2Api::<Tag>::wire2api(*wrap).into()
+    }
+}
 impl Wire2Api<TestId> for *mut wire_TestId {
     fn wire2api(self) -> TestId {
         let wrap = unsafe { support::box_from_leak_ptr(self) };
         Wire2Api::<TestId>::wire2api(*wrap).into()
     }
 }
+impl Wire2Api<(i32, (String, bool))> for *mut wire_tuple2_i32_tuple2_String_bool {
+    fn wire2api(self) -> (i32, (String, bool)) {
+        let wrap = unsafe { support::box_from_leak_ptr(self) };
+        Wire2Api::<(i32, (String, bool))>::wire2api(*wrap).into()
+    }
+}
+impl Wire2Api<(i32, String, bool)> for *mut wire_tuple3_i32_String_bool {
+    fn wire2api(self) -> (i32, String, bool) {
+        let wrap = unsafe { support::box_from_leak_ptr(self) };
+        Wire2Api::<(i32, String, bool)>::wire2api(*wrap).into()
+    }
+}
+impl Wire2Api<u32> for *mut u32 {
+    fn wire2api(self) -> u32 {
+        unsafe { *support::box_from_leak_ptr(self) }
+    }
+}
 impl Wire2Api<UserId> for *mut wire_UserId {
     fn wire2api(self) -> UserId {
         let wrap = unsafe { support::box_from_leak_ptr(self) };
@@ -1749,6 +3118,30 @@ impl Wire2Api<Box<Weekdays>> for *mut i32 {
         Wire2Api::<Weekdays>::wire2api(*wrap).into()
     }
 }
+impl Wire2Api<std::collections::BTreeMap<i32, String>> for *mut wire_btree_map_i32_String {
+    fn wire2api(self) -> std::collections::BTreeMap<i32, String> {
+        let wrap = unsafe { support::box_from_leak_ptr(self) };
+        let keys: Vec<i32> = wrap.keys.wire2api();
+        let values: Vec<String> = wrap.values.wire2api();
+        keys.into_iter().zip(values).collect()
+    }
+}
+impl Wire2Api<std::collections::BTreeSet<String>> for *mut wire_StringList {
+    fn wire2api(self) -> std::collections::BTreeSet<String> {
+        let vec: Vec<String> = self.wire2api();
+        vec.into_iter().collect()
+    }
+}
+impl Wire2Api<char> for u32 {
+    fn wire2api(self) -> char {
+        wire2api_char(self)
+    }
+}
+impl Wire2Api<CollidingKey> for wire_CollidingKey {
+    fn wire2api(self) -> CollidingKey {
+        CollidingKey(self.field0.wire2api())
+    }
+}
 impl Wire2Api<ConcatenateWith> for wire_ConcatenateWith {
     fn wire2api(self) -> ConcatenateWith {
         ConcatenateWith {
@@ -1785,6 +3178,14 @@ impl Wire2Api<Distance> for wire_Distance {
         }
     }
 }
+impl Wire2Api<Document> for wire_Document {
+    fn wire2api(self) -> Document {
+        Document {
+            metadata: self.metadata.wire2api(),
+            title: self.title.wire2api(),
+        }
+    }
+}
 impl Wire2Api<Empty> for wire_Empty {
     fn wire2api(self) -> Empty {
         Empty {}
@@ -1860,10 +3261,23 @@ impl Wire2Api<ExoticOptionals> for wire_ExoticOptionals {
     }
 }
 
+impl Wire2Api<[f32; 3]> for *mut wire_float_32_list {
+    fn wire2api(self) -> [f32; 3] {
+        unsafe {
+            let wrap = support::box_from_leak_ptr(self);
+            let vec = support::vec_from_leak_ptr(wrap.ptr, wrap.len);
+            support::from_slice_to_array(&vec)
+        }
+    }
+}
+
 impl Wire2Api<[f64; 16]> for *mut wire_float_64_list {
     fn wire2api(self) -> [f64; 16] {
-        let vec: Vec<f64> = self.wire2api();
-        support::from_vec_to_array(vec)
+        unsafe {
+            let wrap = support::box_from_leak_ptr(self);
+            let vec = support::vec_from_leak_ptr(wrap.ptr, wrap.len);
+            support::from_slice_to_array(&vec)
+        }
     }
 }
 impl Wire2Api<FeatureChrono> for wire_FeatureChrono {
@@ -1905,11 +3319,64 @@ impl Wire2Api<Vec<f64>> for *mut wire_float_64_list {
         }
     }
 }
+impl Wire2Api<Frame> for wire_Frame {
+    fn wire2api(self) -> Frame {
+        Frame {
+            index: self.index.wire2api(),
+            pixels: self.pixels.wire2api(),
+            depths: self.depths.wire2api(),
+        }
+    }
+}
+impl Wire2Api<GridCell> for wire_GridCell {
+    fn wire2api(self) -> GridCell {
+        GridCell {
+            row: self.row.wire2api(),
+            column: self.column.wire2api(),
+        }
+    }
+}
+impl Wire2Api<std::collections::HashMap<String, i32>> for *mut wire_hash_map_String_i32 {
+    fn wire2api(self) -> std::collections::HashMap<String, i32> {
+        let wrap = unsafe { support::box_from_leak_ptr(self) };
+        let keys: Vec<String> = wrap.keys.wire2api();
+        let values: Vec<i32> = wrap.values.wire2api();
+        keys.into_iter().zip(values).collect()
+    }
+}
+impl Wire2Api<std::collections::HashMap<CollidingKey, u32>>
+    for *mut wire_hash_map_colliding_key_u32
+{
+    fn wire2api(self) -> std::collections::HashMap<CollidingKey, u32> {
+        let wrap = unsafe { support::box_from_leak_ptr(self) };
+        let keys: Vec<CollidingKey> = wrap.keys.wire2api();
+        let values: Vec<u32> = wrap.values.wire2api();
+        keys.into_iter().zip(values).collect()
+    }
+}
+impl Wire2Api<std::collections::HashMap<u32, Attribute>> for *mut wire_hash_map_u32_attribute {
+    fn wire2api(self) -> std::collections::HashMap<u32, Attribute> {
+        let wrap = unsafe { support::box_from_leak_ptr(self) };
+        let keys: Vec<u32> = wrap.keys.wire2api();
+        let values: Vec<Attribute> = wrap.values.wire2api();
+        keys.into_iter().zip(values).collect()
+    }
+}
+
+impl Wire2Api<i128> for *mut wire_uint_8_list {
+    fn wire2api(self) -> i128 {
+        let bytes: Vec<u8> = self.wire2api();
+        wire2api_i128(bytes)
+    }
+}
 
 impl Wire2Api<[i32; 2]> for *mut wire_int_32_list {
     fn wire2api(self) -> [i32; 2] {
-        let vec: Vec<i32> = self.wire2api();
-        support::from_vec_to_array(vec)
+        unsafe {
+            let wrap = support::box_from_leak_ptr(self);
+            let vec = support::vec_from_leak_ptr(wrap.ptr, wrap.len);
+            support::from_slice_to_array(&vec)
+        }
     }
 }
 
@@ -1975,6 +3442,15 @@ impl Wire2Api<Vec<DartOpaque>> for *mut wire_list_DartOpaque {
         vec.into_iter().map(Wire2Api::wire2api).collect()
     }
 }
+impl Wire2Api<Vec<rust_decimal::Decimal>> for *mut wire_list_Decimal {
+    fn wire2api(self) -> Vec<rust_decimal::Decimal> {
+        let vec = unsafe {
+            let wrap = support::box_from_leak_ptr(self);
+            support::vec_from_leak_ptr(wrap.ptr, wrap.len)
+        };
+        vec.into_iter().map(Wire2Api::wire2api).collect()
+    }
+}
 impl Wire2Api<Vec<RustOpaque<HideData>>> for *mut wire_list_HideData {
     fn wire2api(self) -> Vec<RustOpaque<HideData>> {
         let vec = unsafe {
@@ -2002,6 +3478,33 @@ impl Wire2Api<Vec<Attribute>> for *mut wire_list_attribute {
         vec.into_iter().map(Wire2Api::wire2api).collect()
     }
 }
+impl Wire2Api<Vec<char>> for *mut wire_list_char {
+    fn wire2api(self) -> Vec<char> {
+        let vec = unsafe {
+            let wrap = support::box_from_leak_ptr(self);
+            support::vec_from_leak_ptr(wrap.ptr, wrap.len)
+        };
+        vec.into_iter().map(Wire2Api::wire2api).collect()
+    }
+}
+impl Wire2Api<Vec<CollidingKey>> for *mut wire_list_colliding_key {
+    fn wire2api(self) -> Vec<CollidingKey> {
+        let vec = unsafe {
+            let wrap = support::box_from_leak_ptr(self);
+            support::vec_from_leak_ptr(wrap.ptr, wrap.len)
+        };
+        vec.into_iter().map(Wire2Api::wire2api).collect()
+    }
+}
+impl Wire2Api<Vec<Vec<i32>>> for *mut wire_list_int_32_list {
+    fn wire2api(self) -> Vec<Vec<i32>> {
+        let vec = unsafe {
+            let wrap = support::box_from_leak_ptr(self);
+            support::vec_from_leak_ptr(wrap.ptr, wrap.len)
+        };
+        vec.into_iter().map(Wire2Api::wire2api).collect()
+    }
+}
 impl Wire2Api<Vec<MySize>> for *mut wire_list_my_size {
     fn wire2api(self) -> Vec<MySize> {
         let vec = unsafe {
@@ -2038,6 +3541,15 @@ impl Wire2Api<Vec<TestId>> for *mut wire_list_test_id {
         vec.into_iter().map(Wire2Api::wire2api).collect()
     }
 }
+impl Wire2Api<Vec<Weekdays>> for *mut wire_list_weekdays {
+    fn wire2api(self) -> Vec<Weekdays> {
+        let vec = unsafe {
+            let wrap = support::box_from_leak_ptr(self);
+            support::vec_from_leak_ptr(wrap.ptr, wrap.len)
+        };
+        vec.into_iter().map(Wire2Api::wire2api).collect()
+    }
+}
 impl Wire2Api<Measure> for wire_Measure {
     fn wire2api(self) -> Measure {
         match self.tag {
@@ -2060,6 +3572,21 @@ impl Wire2Api<MessageId> for wire_MessageId {
         MessageId(self.field0.wire2api())
     }
 }
+impl Wire2Api<Metadata> for wire_Metadata {
+    fn wire2api(self) -> Metadata {
+        Metadata {
+            id: self.id.wire2api(),
+            created_by: self.created_by.wire2api(),
+        }
+    }
+}
+impl Wire2Api<Money> for wire_Money {
+    fn wire2api(self) -> Money {
+        Money {
+            cents: self.cents.wire2api(),
+        }
+    }
+}
 
 impl Wire2Api<MyNestedStruct> for wire_MyNestedStruct {
     fn wire2api(self) -> MyNestedStruct {
@@ -2121,11 +3648,51 @@ impl Wire2Api<OpaqueNested> for wire_OpaqueNested {
     }
 }
 
+impl Wire2Api<Pair<i32>> for wire_PairI32 {
+    fn wire2api(self) -> Pair<i32> {
+        Pair::<i32> {
+            first: self.first.wire2api(),
+            second: self.second.wire2api(),
+        }
+    }
+}
+impl Wire2Api<ProductId> for wire_ProductId {
+    fn wire2api(self) -> ProductId {
+        ProductId(self.field0.wire2api())
+    }
+}
+impl Wire2Api<Progress> for wire_Progress {
+    fn wire2api(self) -> Progress {
+        Progress {
+            percentage: self.percentage.wire2api(),
+        }
+    }
+}
+impl Wire2Api<Sample> for wire_Sample {
+    fn wire2api(self) -> Sample {
+        Sample {
+            index: self.index.wire2api(),
+            x: self.x.wire2api(),
+            y: self.y.wire2api(),
+            valid: self.valid.wire2api(),
+            level: self.level.wire2api(),
+        }
+    }
+}
 impl Wire2Api<Sequences> for wire_Sequences {
     fn wire2api(self) -> Sequences {
         Sequences(self.field0.wire2api())
     }
 }
+impl Wire2Api<Session> for wire_Session {
+    fn wire2api(self) -> Session {
+        Session {
+            user: self.user.wire2api(),
+            retries: Default::default(),
+            roles: Mutex::new(vec!["guest".to_owned()]),
+        }
+    }
+}
 impl Wire2Api<Speed> for wire_Speed {
     fn wire2api(self) -> Speed {
         match self.tag {
@@ -2139,6 +3706,12 @@ impl Wire2Api<Speed> for wire_Speed {
         }
     }
 }
+impl Wire2Api<std::time::Instant> for wire_Instant {
+    fn wire2api(self) -> std::time::Instant {
+        let opaque: RustOpaque<std::time::Instant> = self.wire2api();
+        *opaque
+    }
+}
 impl Wire2Api<SumWith> for wire_SumWith {
     fn wire2api(self) -> SumWith {
         SumWith {
@@ -2146,28 +3719,96 @@ impl Wire2Api<SumWith> for wire_SumWith {
         }
     }
 }
+impl Wire2Api<Tag> for wire_Tag {
+    fn wire2api(self) -> Tag {
+        Tag {
+            name: self.name.wire2api(),
+            color: self.color.wire2api(),
+            aliases: self.aliases.wire2api(),
+            day: self.day.wire2api(),
+        }
+    }
+}
 impl Wire2Api<TestId> for wire_TestId {
     fn wire2api(self) -> TestId {
         TestId(self.field0.wire2api())
     }
 }
+impl Wire2Api<(String, bool)> for wire_tuple2_String_bool {
+    fn wire2api(self) -> (String, bool) {
+        (self.field0.wire2api(), self.field1.wire2api())
+    }
+}
+impl Wire2Api<(i32, (String, bool))> for wire_tuple2_i32_tuple2_String_bool {
+    fn wire2api(self) -> (i32, (String, bool)) {
+        (self.field0.wire2api(), self.field1.wire2api())
+    }
+}
+impl Wire2Api<(i32, String, bool)> for wire_tuple3_i32_String_bool {
+    fn wire2api(self) -> (i32, String, bool) {
+        (
+            self.field0.wire2api(),
+            self.field1.wire2api(),
+            self.field2.wire2api(),
+        )
+    }
+}
+impl Wire2Api<u128> for *mut wire_uint_8_list {
+    fn wire2api(self) -> u128 {
+        let bytes: Vec<u8> = self.wire2api();
+        wire2api_u128(bytes)
+    }
+}
 
 impl Wire2Api<[u8; 1600]> for *mut wire_uint_8_list {
     fn wire2api(self) -> [u8; 1600] {
-        let vec: Vec<u8> = self.wire2api();
-        support::from_vec_to_array(vec)
+        unsafe {
+            let wrap = support::box_from_leak_ptr(self);
+            let vec = support::vec_from_leak_ptr(wrap.ptr, wrap.len);
+            support::from_slice_to_array(&vec)
+        }
     }
 }
 impl Wire2Api<[u8; 32]> for *mut wire_uint_8_list {
     fn wire2api(self) -> [u8; 32] {
-        let vec: Vec<u8> = self.wire2api();
-        support::from_vec_to_array(vec)
+        unsafe {
+            let wrap = support::box_from_leak_ptr(self);
+            let vec = support::vec_from_leak_ptr(wrap.ptr, wrap.len);
+            support::from_slice_to_array(&vec)
+        }
     }
 }
 impl Wire2Api<[u8; 8]> for *mut wire_uint_8_list {
     fn wire2api(self) -> [u8; 8] {
-        let vec: Vec<u8> = self.wire2api();
-        support::from_vec_to_array(vec)
+        unsafe {
+            let wrap = support::box_from_leak_ptr(self);
+            let vec = support::vec_from_leak_ptr(wrap.ptr, wrap.len);
+            support::from_slice_to_array(&vec)
+        }
+    }
+}
+impl Wire2Api<Vec<u16>> for *mut wire_uint_16_list {
+    fn wire2api(self) -> Vec<u16> {
+        unsafe {
+            let wrap = support::box_from_leak_ptr(self);
+            support::vec_from_leak_ptr(wrap.ptr, wrap.len)
+        }
+    }
+}
+impl Wire2Api<Vec<u32>> for *mut wire_uint_32_list {
+    fn wire2api(self) -> Vec<u32> {
+        unsafe {
+            let wrap = support::box_from_leak_ptr(self);
+            support::vec_from_leak_ptr(wrap.ptr, wrap.len)
+        }
+    }
+}
+impl Wire2Api<Vec<u64>> for *mut wire_uint_64_list {
+    fn wire2api(self) -> Vec<u64> {
+        unsafe {
+            let wrap = support::box_from_leak_ptr(self);
+            support::vec_from_leak_ptr(wrap.ptr, wrap.len)
+        }
     }
 }
 impl Wire2Api<Vec<u8>> for *mut wire_uint_8_list {
@@ -2186,6 +3827,13 @@ impl Wire2Api<UserId> for wire_UserId {
     }
 }
 
+impl Wire2Api<Vec<usize>> for *mut wire_uint_64_list {
+    fn wire2api(self) -> Vec<usize> {
+        let list: Vec<u64> = self.wire2api();
+        wire2api_size_list(list)
+    }
+}
+
 // Section: wire structs
 
 #[repr(C)]
@@ -2194,6 +3842,24 @@ pub struct wire_BoxDartDebug {
     ptr: *const core::ffi::c_void,
 }
 
+#[repr(C)]
+#[derive(Clone)]
+pub struct wire_Counter {
+    ptr: *const core::ffi::c_void,
+}
+
+#[repr(C)]
+#[derive(Clone)]
+pub struct wire_DartIteratorI32 {
+    ptr: *const core::ffi::c_void,
+}
+
+#[repr(C)]
+#[derive(Clone)]
+pub struct wire_DartIteratorString {
+    ptr: *const core::ffi::c_void,
+}
+
 #[repr(C)]
 #[derive(Clone)]
 pub struct wire_DartOpaque {
@@ -2201,6 +3867,12 @@ pub struct wire_DartOpaque {
     handle: usize,
 }
 
+#[repr(C)]
+#[derive(Clone)]
+pub struct wire_Greeter {
+    ptr: *const core::ffi::c_void,
+}
+
 #[repr(C)]
 #[derive(Clone)]
 pub struct wire_HideData {
@@ -2213,6 +3885,12 @@ pub struct wire_I32 {
     ptr: *const core::ffi::c_void,
 }
 
+#[repr(C)]
+#[derive(Clone)]
+pub struct wire_Instant {
+    ptr: *const core::ffi::c_void,
+}
+
 #[repr(C)]
 #[derive(Clone)]
 pub struct wire_MutexHideData {
@@ -2225,6 +3903,12 @@ pub struct wire_NonSendHideData {
     ptr: *const core::ffi::c_void,
 }
 
+#[repr(C)]
+#[derive(Clone)]
+pub struct wire_Rooms {
+    ptr: *const core::ffi::c_void,
+}
+
 #[repr(C)]
 #[derive(Clone)]
 pub struct wire_RwLockHideData {
@@ -2238,6 +3922,12 @@ pub struct wire_StringList {
     len: i32,
 }
 
+#[repr(C)]
+#[derive(Clone)]
+pub struct wire_WeakCounter {
+    ptr: *const core::ffi::c_void,
+}
+
 #[repr(C)]
 #[derive(Clone)]
 pub struct wire_ApplicationEnv {
@@ -2274,6 +3964,19 @@ pub struct wire_Blob {
     field0: *mut wire_uint_8_list,
 }
 
+#[repr(C)]
+#[derive(Clone)]
+pub struct wire_btree_map_i32_String {
+    keys: *mut wire_int_32_list,
+    values: *mut wire_StringList,
+}
+
+#[repr(C)]
+#[derive(Clone)]
+pub struct wire_CollidingKey {
+    field0: *mut wire_uint_8_list,
+}
+
 #[repr(C)]
 #[derive(Clone)]
 pub struct wire_ConcatenateWith {
@@ -2294,6 +3997,13 @@ pub struct wire_DartOpaqueNested {
     second: wire_DartOpaque,
 }
 
+#[repr(C)]
+#[derive(Clone)]
+pub struct wire_Document {
+    metadata: wire_Metadata,
+    title: *mut wire_uint_8_list,
+}
+
 #[repr(C)]
 #[derive(Clone)]
 pub struct wire_Empty {}
@@ -2353,6 +4063,42 @@ pub struct wire_float_64_list {
     len: i32,
 }
 
+#[repr(C)]
+#[derive(Clone)]
+pub struct wire_Frame {
+    index: u32,
+    pixels: *mut wire_uint_8_list,
+    depths: *mut wire_float_32_list,
+}
+
+#[repr(C)]
+#[derive(Clone)]
+pub struct wire_GridCell {
+    row: i32,
+    column: i32,
+}
+
+#[repr(C)]
+#[derive(Clone)]
+pub struct wire_hash_map_String_i32 {
+    keys: *mut wire_StringList,
+    values: *mut wire_int_32_list,
+}
+
+#[repr(C)]
+#[derive(Clone)]
+pub struct wire_hash_map_colliding_key_u32 {
+    keys: *mut wire_list_colliding_key,
+    values: *mut wire_uint_32_list,
+}
+
+#[repr(C)]
+#[derive(Clone)]
+pub struct wire_hash_map_u32_attribute {
+    keys: *mut wire_uint_32_list,
+    values: *mut wire_list_attribute,
+}
+
 #[repr(C)]
 #[derive(Clone)]
 pub struct wire_int_32_list {
@@ -2374,6 +4120,13 @@ pub struct wire_list_DartOpaque {
     len: i32,
 }
 
+#[repr(C)]
+#[derive(Clone)]
+pub struct wire_list_Decimal {
+    ptr: *mut *mut wire_uint_8_list,
+    len: i32,
+}
+
 #[repr(C)]
 #[derive(Clone)]
 pub struct wire_list_HideData {
@@ -2395,6 +4148,27 @@ pub struct wire_list_attribute {
     len: i32,
 }
 
+#[repr(C)]
+#[derive(Clone)]
+pub struct wire_list_char {
+    ptr: *mut u32,
+    len: i32,
+}
+
+#[repr(C)]
+#[derive(Clone)]
+pub struct wire_list_colliding_key {
+    ptr: *mut wire_CollidingKey,
+    len: i32,
+}
+
+#[repr(C)]
+#[derive(Clone)]
+pub struct wire_list_int_32_list {
+    ptr: *mut *mut wire_int_32_list,
+    len: i32,
+}
+
 #[repr(C)]
 #[derive(Clone)]
 pub struct wire_list_my_size {
@@ -2423,12 +4197,32 @@ pub struct wire_list_test_id {
     len: i32,
 }
 
+#[repr(C)]
+#[derive(Clone)]
+pub struct wire_list_weekdays {
+    ptr: *mut i32,
+    len: i32,
+}
+
 #[repr(C)]
 #[derive(Clone)]
 pub struct wire_MessageId {
     field0: *mut wire_uint_8_list,
 }
 
+#[repr(C)]
+#[derive(Clone)]
+pub struct wire_Metadata {
+    id: u32,
+    created_by: *mut wire_uint_8_list,
+}
+
+#[repr(C)]
+#[derive(Clone)]
+pub struct wire_Money {
+    cents: i64,
+}
+
 #[repr(C)]
 #[derive(Clone)]
 pub struct wire_MyNestedStruct {
@@ -2484,24 +4278,111 @@ pub struct wire_OpaqueNested {
     second: wire_HideData,
 }
 
+#[repr(C)]
+#[derive(Clone)]
+pub struct wire_PairI32 {
+    first: i32,
+    second: *mut wire_int_32_list,
+}
+
+#[repr(C)]
+#[derive(Clone)]
+pub struct wire_ProductId {
+    field0: u32,
+}
+
+#[repr(C)]
+#[derive(Clone)]
+pub struct wire_Progress {
+    percentage: u8,
+}
+
+#[repr(C)]
+#[derive(Clone)]
+pub struct wire_Sample {
+    index: u32,
+    x: f64,
+    y: f32,
+    valid: bool,
+    level: i8,
+}
+
 #[repr(C)]
 #[derive(Clone)]
 pub struct wire_Sequences {
     field0: *mut wire_int_32_list,
 }
 
+#[repr(C)]
+#[derive(Clone)]
+pub struct wire_Session {
+    user: *mut wire_uint_8_list,
+}
+
 #[repr(C)]
 #[derive(Clone)]
 pub struct wire_SumWith {
     x: u32,
 }
 
+#[repr(C)]
+#[derive(Clone)]
+pub struct wire_Tag {
+    name: *mut wire_uint_8_list,
+    color: *mut u32,
+    aliases: *mut wire_StringList,
+    day: i32,
+}
+
 #[repr(C)]
 #[derive(Clone)]
 pub struct wire_TestId {
     field0: *mut wire_int_32_list,
 }
 
+#[repr(C)]
+#[derive(Clone)]
+pub struct wire_tuple2_String_bool {
+    field0: *mut wire_uint_8_list,
+    field1: bool,
+}
+
+#[repr(C)]
+#[derive(Clone)]
+pub struct wire_tuple2_i32_tuple2_String_bool {
+    field0: i32,
+    field1: wire_tuple2_String_bool,
+}
+
+#[repr(C)]
+#[derive(Clone)]
+pub struct wire_tuple3_i32_String_bool {
+    field0: i32,
+    field1: *mut wire_uint_8_list,
+    field2: bool,
+}
+
+#[repr(C)]
+#[derive(Clone)]
+pub struct wire_uint_16_list {
+    ptr: *mut u16,
+    len: i32,
+}
+
+#[repr(C)]
+#[derive(Clone)]
+pub struct wire_uint_32_list {
+    ptr: *mut u32,
+    len: i32,
+}
+
+#[repr(C)]
+#[derive(Clone)]
+pub struct wire_uint_64_list {
+    ptr: *mut u64,
+    len: i32,
+}
+
 #[repr(C)]
 #[derive(Clone)]
 pub struct wire_uint_8_list {
@@ -2515,6 +4396,37 @@ pub struct wire_UserId {
     value: u32,
 }
 
+#[repr(C)]
+#[derive(Clone)]
+pub struct wire_ApplicationMessage {
+    tag: i32,
+    kind: *mut ApplicationMessageKind,
+}
+
+#[repr(C)]
+pub union ApplicationMessageKind {
+    DisplayMessage: *mut wire_ApplicationMessage_DisplayMessage,
+    RenderPixel: *mut wire_ApplicationMessage_RenderPixel,
+    Exit: *mut wire_ApplicationMessage_Exit,
+}
+
+#[repr(C)]
+#[derive(Clone)]
+pub struct wire_ApplicationMessage_DisplayMessage {
+    field0: *mut wire_uint_8_list,
+}
+
+#[repr(C)]
+#[derive(Clone)]
+pub struct wire_ApplicationMessage_RenderPixel {
+    x: i32,
+    y: i32,
+}
+
+#[repr(C)]
+#[derive(Clone)]
+pub struct wire_ApplicationMessage_Exit {}
+
 #[repr(C)]
 #[derive(Clone)]
 pub struct wire_Distance {
@@ -2731,11 +4643,71 @@ impl NewWithNullPtr for wire_BoxDartDebug {
     }
 }
 
+impl Default for wire_BoxDartDebug {
+    fn default() -> Self {
+        Self::new_with_null_ptr()
+    }
+}
+
+impl NewWithNullPtr for wire_Counter {
+    fn new_with_null_ptr() -> Self {
+        Self {
+            ptr: core::ptr::null(),
+        }
+    }
+}
+
+impl Default for wire_Counter {
+    fn default() -> Self {
+        Self::new_with_null_ptr()
+    }
+}
+
+impl NewWithNullPtr for wire_DartIteratorI32 {
+    fn new_with_null_ptr() -> Self {
+        Self {
+            ptr: core::ptr::null(),
+        }
+    }
+}
+
+impl Default for wire_DartIteratorI32 {
+    fn default() -> Self {
+        Self::new_with_null_ptr()
+    }
+}
+impl NewWithNullPtr for wire_DartIteratorString {
+    fn new_with_null_ptr() -> Self {
+        Self {
+            ptr: core::ptr::null(),
+        }
+    }
+}
+
+impl Default for wire_DartIteratorString {
+    fn default() -> Self {
+        Self::new_with_null_ptr()
+    }
+}
 impl NewWithNullPtr for wire_DartOpaque {
     fn new_with_null_ptr() -> Self {
         Self { port: 0, handle: 0 }
     }
 }
+
+impl NewWithNullPtr for wire_Greeter {
+    fn new_with_null_ptr() -> Self {
+        Self {
+            ptr: core::ptr::null(),
+        }
+    }
+}
+
+impl Default for wire_Greeter {
+    fn default() -> Self {
+        Self::new_with_null_ptr()
+    }
+}
 impl NewWithNullPtr for wire_HideData {
     fn new_with_null_ptr() -> Self {
         Self {
@@ -2744,6 +4716,12 @@ impl NewWithNullPtr for wire_HideData {
     }
 }
 
+impl Default for wire_HideData {
+    fn default() -> Self {
+        Self::new_with_null_ptr()
+    }
+}
+
 impl NewWithNullPtr for wire_I32 {
     fn new_with_null_ptr() -> Self {
         Self {
@@ -2751,22 +4729,80 @@ impl NewWithNullPtr for wire_I32 {
         }
     }
 }
-impl NewWithNullPtr for wire_MutexHideData {
+
+impl Default for wire_I32 {
+    fn default() -> Self {
+        Self::new_with_null_ptr()
+    }
+}
+impl NewWithNullPtr for wire_Instant {
+    fn new_with_null_ptr() -> Self {
+        Self {
+            ptr: core::ptr::null(),
+        }
+    }
+}
+
+impl Default for wire_Instant {
+    fn default() -> Self {
+        Self::new_with_null_ptr()
+    }
+}
+impl NewWithNullPtr for wire_MutexHideData {
+    fn new_with_null_ptr() -> Self {
+        Self {
+            ptr: core::ptr::null(),
+        }
+    }
+}
+
+impl Default for wire_MutexHideData {
+    fn default() -> Self {
+        Self::new_with_null_ptr()
+    }
+}
+impl NewWithNullPtr for wire_NonSendHideData {
+    fn new_with_null_ptr() -> Self {
+        Self {
+            ptr: core::ptr::null(),
+        }
+    }
+}
+
+impl Default for wire_NonSendHideData {
+    fn default() -> Self {
+        Self::new_with_null_ptr()
+    }
+}
+
+impl NewWithNullPtr for wire_Rooms {
+    fn new_with_null_ptr() -> Self {
+        Self {
+            ptr: core::ptr::null(),
+        }
+    }
+}
+
+impl Default for wire_Rooms {
+    fn default() -> Self {
+        Self::new_with_null_ptr()
+    }
+}
+impl NewWithNullPtr for wire_RwLockHideData {
     fn new_with_null_ptr() -> Self {
         Self {
             ptr: core::ptr::null(),
         }
     }
 }
-impl NewWithNullPtr for wire_NonSendHideData {
-    fn new_with_null_ptr() -> Self {
-        Self {
-            ptr: core::ptr::null(),
-        }
+
+impl Default for wire_RwLockHideData {
+    fn default() -> Self {
+        Self::new_with_null_ptr()
     }
 }
 
-impl NewWithNullPtr for wire_RwLockHideData {
+impl NewWithNullPtr for wire_WeakCounter {
     fn new_with_null_ptr() -> Self {
         Self {
             ptr: core::ptr::null(),
@@ -2774,6 +4810,12 @@ impl NewWithNullPtr for wire_RwLockHideData {
     }
 }
 
+impl Default for wire_WeakCounter {
+    fn default() -> Self {
+        Self::new_with_null_ptr()
+    }
+}
+
 impl NewWithNullPtr for wire_ApplicationEnv {
     fn new_with_null_ptr() -> Self {
         Self {
@@ -2803,6 +4845,34 @@ impl Default for wire_ApplicationEnvVar {
     }
 }
 
+impl NewWithNullPtr for wire_ApplicationMessage {
+    fn new_with_null_ptr() -> Self {
+        Self {
+            tag: -1,
+            kind: core::ptr::null_mut(),
+        }
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn inflate_ApplicationMessage_DisplayMessage() -> *mut ApplicationMessageKind {
+    support::new_leak_box_ptr(ApplicationMessageKind {
+        DisplayMessage: support::new_leak_box_ptr(wire_ApplicationMessage_DisplayMessage {
+            field0: core::ptr::null_mut(),
+        }),
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn inflate_ApplicationMessage_RenderPixel() -> *mut ApplicationMessageKind {
+    support::new_leak_box_ptr(ApplicationMessageKind {
+        RenderPixel: support::new_leak_box_ptr(wire_ApplicationMessage_RenderPixel {
+            x: Default::default(),
+            y: Default::default(),
+        }),
+    })
+}
+
 impl NewWithNullPtr for wire_ApplicationSettings {
     fn new_with_null_ptr() -> Self {
         Self {
@@ -2850,6 +4920,35 @@ impl Default for wire_Blob {
     }
 }
 
+impl NewWithNullPtr for wire_btree_map_i32_String {
+    fn new_with_null_ptr() -> Self {
+        Self {
+            keys: core::ptr::null_mut(),
+            values: core::ptr::null_mut(),
+        }
+    }
+}
+
+impl Default for wire_btree_map_i32_String {
+    fn default() -> Self {
+        Self::new_with_null_ptr()
+    }
+}
+
+impl NewWithNullPtr for wire_CollidingKey {
+    fn new_with_null_ptr() -> Self {
+        Self {
+            field0: core::ptr::null_mut(),
+        }
+    }
+}
+
+impl Default for wire_CollidingKey {
+    fn default() -> Self {
+        Self::new_with_null_ptr()
+    }
+}
+
 impl NewWithNullPtr for wire_ConcatenateWith {
     fn new_with_null_ptr() -> Self {
         Self {
@@ -2912,6 +5011,21 @@ pub extern "C" fn inflate_Distance_Map() -> *mut DistanceKind {
     })
 }
 
+impl NewWithNullPtr for wire_Document {
+    fn new_with_null_ptr() -> Self {
+        Self {
+            metadata: Default::default(),
+            title: core::ptr::null_mut(),
+        }
+    }
+}
+
+impl Default for wire_Document {
+    fn default() -> Self {
+        Self::new_with_null_ptr()
+    }
+}
+
 impl NewWithNullPtr for wire_Empty {
     fn new_with_null_ptr() -> Self {
         Self {}
@@ -3078,6 +5192,82 @@ impl Default for wire_FeedId {
     }
 }
 
+impl NewWithNullPtr for wire_Frame {
+    fn new_with_null_ptr() -> Self {
+        Self {
+            index: Default::default(),
+            pixels: core::ptr::null_mut(),
+            depths: core::ptr::null_mut(),
+        }
+    }
+}
+
+impl Default for wire_Frame {
+    fn default() -> Self {
+        Self::new_with_null_ptr()
+    }
+}
+
+impl NewWithNullPtr for wire_GridCell {
+    fn new_with_null_ptr() -> Self {
+        Self {
+            row: Default::default(),
+            column: Default::default(),
+        }
+    }
+}
+
+impl Default for wire_GridCell {
+    fn default() -> Self {
+        Self::new_with_null_ptr()
+    }
+}
+
+impl NewWithNullPtr for wire_hash_map_String_i32 {
+    fn new_with_null_ptr() -> Self {
+        Self {
+            keys: core::ptr::null_mut(),
+            values: core::ptr::null_mut(),
+        }
+    }
+}
+
+impl Default for wire_hash_map_String_i32 {
+    fn default() -> Self {
+        Self::new_with_null_ptr()
+    }
+}
+
+impl NewWithNullPtr for wire_hash_map_colliding_key_u32 {
+    fn new_with_null_ptr() -> Self {
+        Self {
+            keys: core::ptr::null_mut(),
+            values: core::ptr::null_mut(),
+        }
+    }
+}
+
+impl Default for wire_hash_map_colliding_key_u32 {
+    fn default() -> Self {
+        Self::new_with_null_ptr()
+    }
+}
+
+impl NewWithNullPtr for wire_hash_map_u32_attribute {
+    fn new_with_null_ptr() -> Self {
+        Self {
+            keys: core::ptr::null_mut(),
+            values: core::ptr::null_mut(),
+        }
+    }
+}
+
+impl Default for wire_hash_map_u32_attribute {
+    fn default() -> Self {
+        Self::new_with_null_ptr()
+    }
+}
+
 impl NewWithNullPtr for wire_KitchenSink {
     fn new_with_null_ptr() -> Self {
         Self {
@@ -3177,6 +5367,35 @@ impl Default for wire_MessageId {
     }
 }
 
+impl NewWithNullPtr for wire_Metadata {
+    fn new_with_null_ptr() -> Self {
+        Self {
+            id: Default::default(),
+            created_by: core::ptr::null_mut(),
+        }
+    }
+}
+
+impl Default for wire_Metadata {
+    fn default() -> Self {
+        Self::new_with_null_ptr()
+    }
+}
+
+impl NewWithNullPtr for wire_Money {
+    fn new_with_null_ptr() -> Self {
+        Self {
+            cents: Default::default(),
+        }
+    }
+}
+
+impl Default for wire_Money {
+    fn default() -> Self {
+        Self::new_with_null_ptr()
+    }
+}
+
 impl NewWithNullPtr for wire_MyNestedStruct {
     fn new_with_null_ptr() -> Self {
         Self {
@@ -3296,6 +5515,67 @@ impl Default for wire_OpaqueNested {
     }
 }
 
+impl NewWithNullPtr for wire_PairI32 {
+    fn new_with_null_ptr() -> Self {
+        Self {
+            first: Default::default(),
+            second: core::ptr::null_mut(),
+        }
+    }
+}
+
+impl Default for wire_PairI32 {
+    fn default() -> Self {
+        Self::new_with_null_ptr()
+    }
+}
+
+impl NewWithNullPtr for wire_ProductId {
+    fn new_with_null_ptr() -> Self {
+        Self {
+            field0: Default::default(),
+        }
+    }
+}
+
+impl Default for wire_ProductId {
+    fn default() -> Self {
+        Self::new_with_null_ptr()
+    }
+}
+
+impl NewWithNullPtr for wire_Progress {
+    fn new_with_null_ptr() -> Self {
+        Self {
+            percentage: Default::default(),
+        }
+    }
+}
+
+impl Default for wire_Progress {
+    fn default() -> Self {
+        Self::new_with_null_ptr()
+    }
+}
+
+impl NewWithNullPtr for wire_Sample {
+    fn new_with_null_ptr() -> Self {
+        Self {
+            index: Default::default(),
+            x: Default::default(),
+            y: Default::default(),
+            valid: Default::default(),
+            level: Default::default(),
+        }
+    }
+}
+
+impl Default for wire_Sample {
+    fn default() -> Self {
+        Self::new_with_null_ptr()
+    }
+}
+
 impl NewWithNullPtr for wire_Sequences {
     fn new_with_null_ptr() -> Self {
         Self {
@@ -3310,6 +5590,20 @@ impl Default for wire_Sequences {
     }
 }
 
+impl NewWithNullPtr for wire_Session {
+    fn new_with_null_ptr() -> Self {
+        Self {
+            user: core::ptr::null_mut(),
+        }
+    }
+}
+
+impl Default for wire_Session {
+    fn default() -> Self {
+        Self::new_with_null_ptr()
+    }
+}
+
 impl NewWithNullPtr for wire_Speed {
     fn new_with_null_ptr() -> Self {
         Self {
@@ -3342,6 +5636,23 @@ impl Default for wire_SumWith {
     }
 }
 
+impl NewWithNullPtr for wire_Tag {
+    fn new_with_null_ptr() -> Self {
+        Self {
+            name: core::ptr::null_mut(),
+            color: core::ptr::null_mut(),
+            aliases: core::ptr::null_mut(),
+            day: Default::default(),
+        }
+    }
+}
+
+impl Default for wire_Tag {
+    fn default() -> Self {
+        Self::new_with_null_ptr()
+    }
+}
+
 impl NewWithNullPtr for wire_TestId {
     fn new_with_null_ptr() -> Self {
         Self {
@@ -3356,6 +5667,52 @@ impl Default for wire_TestId {
     }
 }
 
+impl NewWithNullPtr for wire_tuple2_String_bool {
+    fn new_with_null_ptr() -> Self {
+        Self {
+            field0: core::ptr::null_mut(),
+            field1: Default::default(),
+        }
+    }
+}
+
+impl Default for wire_tuple2_String_bool {
+    fn default() -> Self {
+        Self::new_with_null_ptr()
+    }
+}
+
+impl NewWithNullPtr for wire_tuple2_i32_tuple2_String_bool {
+    fn new_with_null_ptr() -> Self {
+        Self {
+            field0: Default::default(),
+            field1: Default::default(),
+        }
+    }
+}
+
+impl Default for wire_tuple2_i32_tuple2_String_bool {
+    fn default() -> Self {
+        Self::new_with_null_ptr()
+    }
+}
+
+impl NewWithNullPtr for wire_tuple3_i32_String_bool {
+    fn new_with_null_ptr() -> Self {
+        Self {
+            field0: Default::default(),
+            field1: core::ptr::null_mut(),
+            field2: Default::default(),
+        }
+    }
+}
+
+impl Default for wire_tuple3_i32_String_bool {
+    fn default() -> Self {
+        Self::new_with_null_ptr()
+    }
+}
+
 impl NewWithNullPtr for wire_UserId {
     fn new_with_null_ptr() -> Self {
         Self {
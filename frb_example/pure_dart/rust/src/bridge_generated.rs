@@ -26,6 +26,45 @@ use crate::old_module_system::sub_module::OldSimpleStruct;
 
 // Section: wire functions
 
+fn wire_init_app_impl(port_: MessagePort) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "init_app",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            frb_init();
+            move |task_callback| Ok(())
+        },
+    )
+}
+fn wire_init_count_impl(port_: MessagePort) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "init_count",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            frb_init();
+            move |task_callback| Ok(init_count())
+        },
+    )
+}
+fn wire_get_constants_impl(port_: MessagePort) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "get_constants",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            frb_init();
+            move |task_callback| Ok(mirror_tuple3_u32_String_f64(get_constants()))
+        },
+    )
+}
 fn wire_simple_adder_impl(
     port_: MessagePort,
     a: impl Wire2Api<i32> + UnwindSafe,
@@ -38,12 +77,32 @@ fn wire_simple_adder_impl(
             mode: FfiCallMode::Normal,
         },
         move || {
+            frb_init();
             let api_a = a.wire2api();
             let api_b = b.wire2api();
             move |task_callback| Ok(simple_adder(api_a, api_b))
         },
     )
 }
+fn wire_simple_adder_async_impl(
+    port_: MessagePort,
+    a: impl Wire2Api<i32> + UnwindSafe,
+    b: impl Wire2Api<i32> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async(
+        WrapInfo {
+            debug_name: "simple_adder_async",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            frb_init();
+            let api_a = a.wire2api();
+            let api_b = b.wire2api();
+            move |task_callback| async move { simple_adder_async(api_a, api_b).await }
+        },
+    )
+}
 fn wire_simple_adder_sync_impl(
     a: impl Wire2Api<i32> + UnwindSafe,
     b: impl Wire2Api<i32> + UnwindSafe,
@@ -55,12 +114,31 @@ fn wire_simple_adder_sync_impl(
             mode: FfiCallMode::Sync,
         },
         move || {
+            frb_init();
             let api_a = a.wire2api();
             let api_b = b.wire2api();
             Ok(simple_adder_sync(api_a, api_b))
         },
     )
 }
+fn wire_scale_impl(
+    x: impl Wire2Api<f64> + UnwindSafe,
+    factor: impl Wire2Api<f64> + UnwindSafe,
+) -> f64 {
+    support::wrap_direct("scale", move || {
+        frb_init();
+        let api_x = x.wire2api();
+        let api_factor = factor.wire2api();
+        scale(api_x, api_factor)
+    })
+}
+fn wire_sum_vector_impl(v: impl Wire2Api<[f32; 3]> + UnwindSafe) -> f32 {
+    support::wrap_direct("sum_vector", move || {
+        frb_init();
+        let api_v = v.wire2api();
+        sum_vector(api_v)
+    })
+}
 fn wire_primitive_types_impl(
     port_: MessagePort,
     my_i32: impl Wire2Api<i32> + UnwindSafe,
@@ -75,6 +153,7 @@ fn wire_primitive_types_impl(
             mode: FfiCallMode::Normal,
         },
         move || {
+            frb_init();
             let api_my_i32 = my_i32.wire2api();
             let api_my_i64 = my_i64.wire2api();
             let api_my_f64 = my_f64.wire2api();
@@ -104,6 +183,7 @@ fn wire_primitive_optional_types_impl(
             mode: FfiCallMode::Normal,
         },
         move || {
+            frb_init();
             let api_my_i32 = my_i32.wire2api();
             let api_my_i64 = my_i64.wire2api();
             let api_my_f64 = my_f64.wire2api();
@@ -132,6 +212,7 @@ fn wire_primitive_types_sync_impl(
             mode: FfiCallMode::Sync,
         },
         move || {
+            frb_init();
             let api_my_i32 = my_i32.wire2api();
             let api_my_i64 = my_i64.wire2api();
             let api_my_f64 = my_f64.wire2api();
@@ -153,6 +234,7 @@ fn wire_primitive_u32_impl(port_: MessagePort, my_u32: impl Wire2Api<u32> + Unwi
             mode: FfiCallMode::Normal,
         },
         move || {
+            frb_init();
             let api_my_u32 = my_u32.wire2api();
             move |task_callback| Ok(primitive_u32(api_my_u32))
         },
@@ -168,6 +250,7 @@ fn wire_primitive_u32_sync_impl(
             mode: FfiCallMode::Sync,
         },
         move || {
+            frb_init();
             let api_my_u32 = my_u32.wire2api();
             Ok(primitive_u32_sync(api_my_u32))
         },
@@ -181,6 +264,7 @@ fn wire_handle_string_impl(port_: MessagePort, s: impl Wire2Api<String> + Unwind
             mode: FfiCallMode::Normal,
         },
         move || {
+            frb_init();
             let api_s = s.wire2api();
             move |task_callback| Ok(handle_string(api_s))
         },
@@ -194,1954 +278,4184 @@ fn wire_handle_string_sync_impl(s: impl Wire2Api<String> + UnwindSafe) -> suppor
             mode: FfiCallMode::Sync,
         },
         move || {
+            frb_init();
             let api_s = s.wire2api();
             Ok(handle_string_sync(api_s))
         },
     )
 }
-fn wire_handle_return_unit_impl(port_: MessagePort) {
+fn wire_next_char_impl(port_: MessagePort, c: impl Wire2Api<char> + UnwindSafe) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
-            debug_name: "handle_return_unit",
+            debug_name: "next_char",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
-        move || move |task_callback| Ok(handle_return_unit()),
-    )
-}
-fn wire_handle_return_unit_sync_impl() -> support::WireSyncReturn {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync(
-        WrapInfo {
-            debug_name: "handle_return_unit_sync",
-            port: None,
-            mode: FfiCallMode::Sync,
+        move || {
+            frb_init();
+            let api_c = c.wire2api();
+            move |task_callback| Ok(next_char(api_c) as u32)
         },
-        move || Ok(handle_return_unit_sync()),
     )
 }
-fn wire_handle_vec_u8_impl(port_: MessagePort, v: impl Wire2Api<Vec<u8>> + UnwindSafe) {
+fn wire_handle_chars_impl(port_: MessagePort, chars: impl Wire2Api<Vec<char>> + UnwindSafe) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
-            debug_name: "handle_vec_u8",
+            debug_name: "handle_chars",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
         move || {
-            let api_v = v.wire2api();
-            move |task_callback| Ok(handle_vec_u8(api_v))
+            frb_init();
+            let api_chars = chars.wire2api();
+            move |task_callback| Ok(handle_chars(api_chars).map(|v| v as u32))
         },
     )
 }
-fn wire_handle_vec_u8_sync_impl(v: impl Wire2Api<Vec<u8>> + UnwindSafe) -> support::WireSyncReturn {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync(
+fn wire_divide_by_impl(
+    port_: MessagePort,
+    n: impl Wire2Api<u32> + UnwindSafe,
+    by: impl Wire2Api<std::num::NonZeroU32> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
-            debug_name: "handle_vec_u8_sync",
-            port: None,
-            mode: FfiCallMode::Sync,
+            debug_name: "divide_by",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
         },
         move || {
-            let api_v = v.wire2api();
-            Ok(handle_vec_u8_sync(api_v))
+            frb_init();
+            let api_n = n.wire2api();
+            let api_by = by.wire2api();
+            move |task_callback| Ok(divide_by(api_n, api_by))
         },
     )
 }
-fn wire_handle_vec_of_primitive_impl(port_: MessagePort, n: impl Wire2Api<i32> + UnwindSafe) {
+fn wire_next_non_zero_impl(
+    port_: MessagePort,
+    n: impl Wire2Api<std::num::NonZeroU64> + UnwindSafe,
+) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
-            debug_name: "handle_vec_of_primitive",
+            debug_name: "next_non_zero",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
         move || {
+            frb_init();
             let api_n = n.wire2api();
-            move |task_callback| Ok(handle_vec_of_primitive(api_n))
+            move |task_callback| Ok(next_non_zero(api_n).map(|v| v.get()))
         },
     )
 }
-fn wire_handle_vec_of_primitive_sync_impl(
-    n: impl Wire2Api<i32> + UnwindSafe,
-) -> support::WireSyncReturn {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync(
+fn wire_greet_impl(
+    port_: MessagePort,
+    name: impl Wire2Api<String> + UnwindSafe,
+    times: impl Wire2Api<u32> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
-            debug_name: "handle_vec_of_primitive_sync",
-            port: None,
-            mode: FfiCallMode::Sync,
+            debug_name: "greet",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
         },
         move || {
-            let api_n = n.wire2api();
-            Ok(handle_vec_of_primitive_sync(api_n))
+            frb_init();
+            let api_name = name.wire2api();
+            let api_times = times.wire2api();
+            move |task_callback| Ok(greet(api_name, api_times))
         },
     )
 }
-fn wire_handle_zero_copy_vec_of_primitive_impl(
-    port_: MessagePort,
-    n: impl Wire2Api<i32> + UnwindSafe,
-) {
+fn wire_instant_now_impl(port_: MessagePort) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
-            debug_name: "handle_zero_copy_vec_of_primitive",
+            debug_name: "instant_now",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
         move || {
-            let api_n = n.wire2api();
-            move |task_callback| Ok(handle_zero_copy_vec_of_primitive(api_n))
+            frb_init();
+            move |task_callback| Ok(RustOpaque::new(instant_now()))
         },
     )
 }
-fn wire_handle_zero_copy_vec_of_primitive_sync_impl(
-    n: impl Wire2Api<i32> + UnwindSafe,
-) -> support::WireSyncReturn {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync(
+fn wire_instant_after_impl(
+    port_: MessagePort,
+    start: impl Wire2Api<std::time::Instant> + UnwindSafe,
+    millis: impl Wire2Api<u64> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
-            debug_name: "handle_zero_copy_vec_of_primitive_sync",
-            port: None,
-            mode: FfiCallMode::Sync,
+            debug_name: "instant_after",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
         },
         move || {
-            let api_n = n.wire2api();
-            Ok(handle_zero_copy_vec_of_primitive_sync(api_n))
+            frb_init();
+            let api_start = start.wire2api();
+            let api_millis = millis.wire2api();
+            move |task_callback| Ok(RustOpaque::new(instant_after(api_start, api_millis)))
         },
     )
 }
-fn wire_handle_struct_impl(
+fn wire_one_day_later_impl(
     port_: MessagePort,
-    arg: impl Wire2Api<MySize> + UnwindSafe,
-    boxed: impl Wire2Api<Box<MySize>> + UnwindSafe,
+    time: impl Wire2Api<std::time::SystemTime> + UnwindSafe,
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
-            debug_name: "handle_struct",
+            debug_name: "one_day_later",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
         move || {
-            let api_arg = arg.wire2api();
-            let api_boxed = boxed.wire2api();
-            move |task_callback| Ok(handle_struct(api_arg, api_boxed))
+            frb_init();
+            let api_time = time.wire2api();
+            move |task_callback| Ok(api2wire_system_time(one_day_later(api_time)))
         },
     )
 }
-fn wire_handle_struct_sync_impl(
-    arg: impl Wire2Api<MySize> + UnwindSafe,
-    boxed: impl Wire2Api<Box<MySize>> + UnwindSafe,
-) -> support::WireSyncReturn {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync(
+fn wire_shift_range_impl(
+    port_: MessagePort,
+    range: impl Wire2Api<std::ops::Range<u32>> + UnwindSafe,
+    by: impl Wire2Api<u32> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
-            debug_name: "handle_struct_sync",
-            port: None,
-            mode: FfiCallMode::Sync,
+            debug_name: "shift_range",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
         },
         move || {
-            let api_arg = arg.wire2api();
-            let api_boxed = boxed.wire2api();
-            Ok(handle_struct_sync(api_arg, api_boxed))
+            frb_init();
+            let api_range = range.wire2api();
+            let api_by = by.wire2api();
+            move |task_callback| {
+                Ok(api2wire_range_inclusive::<u32, _>(shift_range(
+                    api_range, api_by,
+                )))
+            }
         },
     )
 }
-fn wire_handle_newtype_impl(port_: MessagePort, arg: impl Wire2Api<NewTypeInt> + UnwindSafe) {
+fn wire_clamp_range_impl(
+    port_: MessagePort,
+    range: impl Wire2Api<std::ops::RangeInclusive<f64>> + UnwindSafe,
+    value: impl Wire2Api<f64> + UnwindSafe,
+) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
-            debug_name: "handle_newtype",
+            debug_name: "clamp_range",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
         move || {
-            let api_arg = arg.wire2api();
-            move |task_callback| Ok(handle_newtype(api_arg))
+            frb_init();
+            let api_range = range.wire2api();
+            let api_value = value.wire2api();
+            move |task_callback| Ok(clamp_range(api_range, api_value))
         },
     )
 }
-fn wire_handle_newtype_sync_impl(
-    arg: impl Wire2Api<NewTypeInt> + UnwindSafe,
-) -> support::WireSyncReturn {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync(
+fn wire_describe_size_impl(
+    port_: MessagePort,
+    size: impl Wire2Api<MySize> + UnwindSafe,
+    unit: impl Wire2Api<String> + UnwindSafe,
+    labels: impl Wire2Api<Vec<String>> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
-            debug_name: "handle_newtype_sync",
-            port: None,
-            mode: FfiCallMode::Sync,
+            debug_name: "describe_size",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
         },
         move || {
-            let api_arg = arg.wire2api();
-            Ok(handle_newtype_sync(api_arg))
+            frb_init();
+            let api_size = size.wire2api();
+            let api_unit = unit.wire2api();
+            let api_labels = labels.wire2api();
+            move |task_callback| Ok(describe_size(&api_size, &api_unit, &api_labels))
         },
     )
 }
-fn wire_handle_list_of_struct_impl(port_: MessagePort, l: impl Wire2Api<Vec<MySize>> + UnwindSafe) {
+fn wire_split_words_impl(port_: MessagePort, text: impl Wire2Api<String> + UnwindSafe) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
-            debug_name: "handle_list_of_struct",
+            debug_name: "split_words",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
         move || {
-            let api_l = l.wire2api();
-            move |task_callback| Ok(handle_list_of_struct(api_l))
+            frb_init();
+            let api_text = text.wire2api();
+            move |task_callback| Ok(RustOpaque::new(DartIterator::new(split_words(api_text))))
         },
     )
 }
-fn wire_handle_list_of_struct_sync_impl(
-    l: impl Wire2Api<Vec<MySize>> + UnwindSafe,
-) -> support::WireSyncReturn {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync(
+fn wire_handle_cow_str_impl(
+    port_: MessagePort,
+    s: impl Wire2Api<std::borrow::Cow<'static, str>> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
-            debug_name: "handle_list_of_struct_sync",
-            port: None,
-            mode: FfiCallMode::Sync,
+            debug_name: "handle_cow_str",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
         },
         move || {
-            let api_l = l.wire2api();
-            Ok(handle_list_of_struct_sync(api_l))
+            frb_init();
+            let api_s = s.wire2api();
+            move |task_callback| Ok(handle_cow_str(api_s).into_owned())
         },
     )
 }
-fn wire_handle_string_list_impl(
+fn wire_handle_cow_bytes_impl(
     port_: MessagePort,
-    names: impl Wire2Api<Vec<String>> + UnwindSafe,
+    bytes: impl Wire2Api<std::borrow::Cow<'static, [u8]>> + UnwindSafe,
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
-            debug_name: "handle_string_list",
+            debug_name: "handle_cow_bytes",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
         move || {
-            let api_names = names.wire2api();
-            move |task_callback| Ok(handle_string_list(api_names))
+            frb_init();
+            let api_bytes = bytes.wire2api();
+            move |task_callback| Ok(ZeroCopyBuffer(handle_cow_bytes(api_bytes).into_owned()))
         },
     )
 }
-fn wire_handle_string_list_sync_impl(
-    names: impl Wire2Api<Vec<String>> + UnwindSafe,
-) -> support::WireSyncReturn {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync(
+fn wire_handle_path_impl(port_: MessagePort, path: impl Wire2Api<std::path::PathBuf> + UnwindSafe) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
-            debug_name: "handle_string_list_sync",
-            port: None,
-            mode: FfiCallMode::Sync,
+            debug_name: "handle_path",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
         },
         move || {
-            let api_names = names.wire2api();
-            Ok(handle_string_list_sync(api_names))
+            frb_init();
+            let api_path = path.wire2api();
+            move |task_callback| Ok(api2wire_path(handle_path(&api_path)))
         },
     )
 }
-fn wire_handle_complex_struct_impl(port_: MessagePort, s: impl Wire2Api<MyTreeNode> + UnwindSafe) {
+fn wire_handle_socket_addr_impl(
+    port_: MessagePort,
+    addr: impl Wire2Api<std::net::SocketAddr> + UnwindSafe,
+) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
-            debug_name: "handle_complex_struct",
+            debug_name: "handle_socket_addr",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
         move || {
-            let api_s = s.wire2api();
-            move |task_callback| Ok(handle_complex_struct(api_s))
+            frb_init();
+            let api_addr = addr.wire2api();
+            move |task_callback| Ok(api2wire_ip_addr(handle_socket_addr(api_addr)))
         },
     )
 }
-fn wire_handle_complex_struct_sync_impl(
-    s: impl Wire2Api<MyTreeNode> + UnwindSafe,
-) -> support::WireSyncReturn {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync(
+fn wire_next_port_impl(port_: MessagePort, addr: impl Wire2Api<std::net::SocketAddr> + UnwindSafe) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
-            debug_name: "handle_complex_struct_sync",
-            port: None,
-            mode: FfiCallMode::Sync,
+            debug_name: "next_port",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
         },
         move || {
-            let api_s = s.wire2api();
-            Ok(handle_complex_struct_sync(api_s))
+            frb_init();
+            let api_addr = addr.wire2api();
+            move |task_callback| Ok(api2wire_socket_addr(next_port(api_addr)))
         },
     )
 }
-fn wire_handle_nested_struct_impl(
-    port_: MessagePort,
-    s: impl Wire2Api<MyNestedStruct> + UnwindSafe,
-) {
+fn wire_handle_return_unit_impl(port_: MessagePort) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
-            debug_name: "handle_nested_struct",
+            debug_name: "handle_return_unit",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
         move || {
-            let api_s = s.wire2api();
-            move |task_callback| Ok(handle_nested_struct(api_s))
+            frb_init();
+            move |task_callback| Ok(handle_return_unit())
         },
     )
 }
-fn wire_handle_sync_return_impl(
-    mode: impl Wire2Api<String> + UnwindSafe,
-) -> support::WireSyncReturn {
+fn wire_handle_return_unit_sync_impl() -> support::WireSyncReturn {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync(
         WrapInfo {
-            debug_name: "handle_sync_return",
+            debug_name: "handle_return_unit_sync",
             port: None,
             mode: FfiCallMode::Sync,
         },
         move || {
-            let api_mode = mode.wire2api();
-            handle_sync_return(api_mode)
+            frb_init();
+            Ok(handle_return_unit_sync())
         },
     )
 }
-fn wire_handle_stream_impl(port_: MessagePort, arg: impl Wire2Api<String> + UnwindSafe) {
+fn wire_handle_vec_u8_impl(port_: MessagePort, v: impl Wire2Api<Vec<u8>> + UnwindSafe) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
-            debug_name: "handle_stream",
+            debug_name: "handle_vec_u8",
             port: Some(port_),
-            mode: FfiCallMode::Stream,
+            mode: FfiCallMode::Normal,
         },
         move || {
-            let api_arg = arg.wire2api();
-            move |task_callback| Ok(handle_stream(task_callback.stream_sink(), api_arg))
+            frb_init();
+            let api_v = v.wire2api();
+            move |task_callback| Ok(handle_vec_u8(api_v))
         },
     )
 }
-fn wire_handle_stream_of_struct_impl(port_: MessagePort) {
+fn wire_handle_bytes_impl(port_: MessagePort, bytes: impl Wire2Api<bytes::Bytes> + UnwindSafe) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
-            debug_name: "handle_stream_of_struct",
+            debug_name: "handle_bytes",
             port: Some(port_),
-            mode: FfiCallMode::Stream,
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            frb_init();
+            let api_bytes = bytes.wire2api();
+            move |task_callback| Ok(api2wire_bytes(handle_bytes(api_bytes)))
         },
-        move || move |task_callback| Ok(handle_stream_of_struct(task_callback.stream_sink())),
     )
 }
-fn wire_return_err_impl(port_: MessagePort) {
+fn wire_checksum_impl(
+    port_: MessagePort,
+    data: impl Wire2Api<Vec<u8>> + UnwindSafe,
+    weights: impl Wire2Api<Vec<u16>> + UnwindSafe,
+) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
-            debug_name: "return_err",
+            debug_name: "checksum",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
-        move || move |task_callback| return_err(),
+        move || {
+            frb_init();
+            let api_data = data.wire2api();
+            let api_weights = weights.wire2api();
+            move |task_callback| Ok(checksum(&api_data, &api_weights))
+        },
     )
 }
-fn wire_return_panic_impl(port_: MessagePort) {
+fn wire_count_in_shared_buffer_impl(
+    port_: MessagePort,
+    buffer: impl Wire2Api<flutter_rust_bridge::SharedBuffer> + UnwindSafe,
+    byte: impl Wire2Api<u8> + UnwindSafe,
+) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
-            debug_name: "return_panic",
+            debug_name: "count_in_shared_buffer",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
-        move || move |task_callback| Ok(return_panic()),
+        move || {
+            frb_init();
+            let api_buffer = buffer.wire2api();
+            let api_byte = byte.wire2api();
+            move |task_callback| Ok(count_in_shared_buffer(api_buffer, api_byte))
+        },
     )
 }
-fn wire_handle_optional_return_impl(
-    port_: MessagePort,
-    left: impl Wire2Api<f64> + UnwindSafe,
-    right: impl Wire2Api<f64> + UnwindSafe,
-) {
+fn wire_next_frame_impl(port_: MessagePort, frame: impl Wire2Api<Frame> + UnwindSafe) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
-            debug_name: "handle_optional_return",
+            debug_name: "next_frame",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
         move || {
-            let api_left = left.wire2api();
-            let api_right = right.wire2api();
-            move |task_callback| Ok(handle_optional_return(api_left, api_right))
+            frb_init();
+            let api_frame = frame.wire2api();
+            move |task_callback| Ok(next_frame(api_frame))
         },
     )
 }
-fn wire_handle_optional_struct_impl(
-    port_: MessagePort,
-    document: impl Wire2Api<Option<String>> + UnwindSafe,
-) {
+fn wire_prefix_sums_impl(port_: MessagePort, items: impl Wire2Api<Vec<usize>> + UnwindSafe) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
-            debug_name: "handle_optional_struct",
+            debug_name: "prefix_sums",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
         move || {
-            let api_document = document.wire2api();
-            move |task_callback| Ok(handle_optional_struct(api_document))
+            frb_init();
+            let api_items = items.wire2api();
+            move |task_callback| Ok(api2wire_size_list::<u64, _>(prefix_sums(api_items)))
         },
     )
 }
-fn wire_handle_optional_increment_impl(
-    port_: MessagePort,
-    opt: impl Wire2Api<Option<ExoticOptionals>> + UnwindSafe,
-) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+fn wire_handle_vec_u8_sync_impl(v: impl Wire2Api<Vec<u8>> + UnwindSafe) -> support::WireSyncReturn {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync(
         WrapInfo {
-            debug_name: "handle_optional_increment",
-            port: Some(port_),
-            mode: FfiCallMode::Normal,
+            debug_name: "handle_vec_u8_sync",
+            port: None,
+            mode: FfiCallMode::Sync,
         },
         move || {
-            let api_opt = opt.wire2api();
-            move |task_callback| Ok(handle_optional_increment(api_opt))
+            frb_init();
+            let api_v = v.wire2api();
+            Ok(handle_vec_u8_sync(api_v))
         },
     )
 }
-fn wire_handle_increment_boxed_optional_impl(
-    port_: MessagePort,
-    opt: impl Wire2Api<Option<Box<f64>>> + UnwindSafe,
-) {
+fn wire_handle_vec_of_primitive_impl(port_: MessagePort, n: impl Wire2Api<i32> + UnwindSafe) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
-            debug_name: "handle_increment_boxed_optional",
+            debug_name: "handle_vec_of_primitive",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
         move || {
-            let api_opt = opt.wire2api();
-            move |task_callback| Ok(handle_increment_boxed_optional(api_opt))
+            frb_init();
+            let api_n = n.wire2api();
+            move |task_callback| Ok(handle_vec_of_primitive(api_n))
         },
     )
 }
-fn wire_handle_option_box_arguments_impl(
-    port_: MessagePort,
-    i8box: impl Wire2Api<Option<Box<i8>>> + UnwindSafe,
-    u8box: impl Wire2Api<Option<Box<u8>>> + UnwindSafe,
-    i32box: impl Wire2Api<Option<Box<i32>>> + UnwindSafe,
-    i64box: impl Wire2Api<Option<Box<i64>>> + UnwindSafe,
-    f64box: impl Wire2Api<Option<Box<f64>>> + UnwindSafe,
-    boolbox: impl Wire2Api<Option<Box<bool>>> + UnwindSafe,
-    structbox: impl Wire2Api<Option<Box<ExoticOptionals>>> + UnwindSafe,
-) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+fn wire_handle_vec_of_primitive_sync_impl(
+    n: impl Wire2Api<i32> + UnwindSafe,
+) -> support::WireSyncReturn {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync(
         WrapInfo {
-            debug_name: "handle_option_box_arguments",
-            port: Some(port_),
-            mode: FfiCallMode::Normal,
+            debug_name: "handle_vec_of_primitive_sync",
+            port: None,
+            mode: FfiCallMode::Sync,
         },
         move || {
-            let api_i8box = i8box.wire2api();
-            let api_u8box = u8box.wire2api();
-            let api_i32box = i32box.wire2api();
-            let api_i64box = i64box.wire2api();
-            let api_f64box = f64box.wire2api();
-            let api_boolbox = boolbox.wire2api();
-            let api_structbox = structbox.wire2api();
-            move |task_callback| {
-                Ok(handle_option_box_arguments(
-                    api_i8box,
-                    api_u8box,
-                    api_i32box,
-                    api_i64box,
-                    api_f64box,
-                    api_boolbox,
-                    api_structbox,
-                ))
-            }
+            frb_init();
+            let api_n = n.wire2api();
+            Ok(handle_vec_of_primitive_sync(api_n))
         },
     )
 }
-fn wire_print_note_impl(port_: MessagePort, note: impl Wire2Api<Note> + UnwindSafe) {
+fn wire_handle_zero_copy_vec_of_primitive_impl(
+    port_: MessagePort,
+    n: impl Wire2Api<i32> + UnwindSafe,
+) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
-            debug_name: "print_note",
+            debug_name: "handle_zero_copy_vec_of_primitive",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
         move || {
-            let api_note = note.wire2api();
-            move |task_callback| Ok(print_note(api_note))
+            frb_init();
+            let api_n = n.wire2api();
+            move |task_callback| Ok(handle_zero_copy_vec_of_primitive(api_n))
         },
     )
 }
-fn wire_handle_return_enum_impl(port_: MessagePort, input: impl Wire2Api<String> + UnwindSafe) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+fn wire_handle_zero_copy_vec_of_primitive_sync_impl(
+    n: impl Wire2Api<i32> + UnwindSafe,
+) -> support::WireSyncReturn {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync(
         WrapInfo {
-            debug_name: "handle_return_enum",
-            port: Some(port_),
-            mode: FfiCallMode::Normal,
+            debug_name: "handle_zero_copy_vec_of_primitive_sync",
+            port: None,
+            mode: FfiCallMode::Sync,
         },
         move || {
-            let api_input = input.wire2api();
-            move |task_callback| Ok(handle_return_enum(api_input))
+            frb_init();
+            let api_n = n.wire2api();
+            Ok(handle_zero_copy_vec_of_primitive_sync(api_n))
         },
     )
 }
-fn wire_handle_enum_parameter_impl(
+fn wire_handle_struct_impl(
     port_: MessagePort,
-    weekday: impl Wire2Api<Weekdays> + UnwindSafe,
+    arg: impl Wire2Api<MySize> + UnwindSafe,
+    boxed: impl Wire2Api<Box<MySize>> + UnwindSafe,
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
-            debug_name: "handle_enum_parameter",
+            debug_name: "handle_struct",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
         move || {
-            let api_weekday = weekday.wire2api();
-            move |task_callback| Ok(handle_enum_parameter(api_weekday))
+            frb_init();
+            let api_arg = arg.wire2api();
+            let api_boxed = boxed.wire2api();
+            move |task_callback| Ok(handle_struct(api_arg, api_boxed))
         },
     )
 }
-fn wire_handle_customized_struct_impl(
-    port_: MessagePort,
-    val: impl Wire2Api<Customized> + UnwindSafe,
-) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+fn wire_handle_struct_sync_impl(
+    arg: impl Wire2Api<MySize> + UnwindSafe,
+    boxed: impl Wire2Api<Box<MySize>> + UnwindSafe,
+) -> support::WireSyncReturn {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync(
         WrapInfo {
-            debug_name: "handle_customized_struct",
-            port: Some(port_),
-            mode: FfiCallMode::Normal,
+            debug_name: "handle_struct_sync",
+            port: None,
+            mode: FfiCallMode::Sync,
         },
         move || {
-            let api_val = val.wire2api();
-            move |task_callback| Ok(handle_customized_struct(api_val))
+            frb_init();
+            let api_arg = arg.wire2api();
+            let api_boxed = boxed.wire2api();
+            Ok(handle_struct_sync(api_arg, api_boxed))
         },
     )
 }
-fn wire_handle_enum_struct_impl(port_: MessagePort, val: impl Wire2Api<KitchenSink> + UnwindSafe) {
+fn wire_handle_newtype_impl(port_: MessagePort, arg: impl Wire2Api<NewTypeInt> + UnwindSafe) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
-            debug_name: "handle_enum_struct",
+            debug_name: "handle_newtype",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
         move || {
-            let api_val = val.wire2api();
-            move |task_callback| Ok(handle_enum_struct(api_val))
+            frb_init();
+            let api_arg = arg.wire2api();
+            move |task_callback| Ok(handle_newtype(api_arg))
         },
     )
 }
-fn wire_use_imported_struct_impl(
-    port_: MessagePort,
-    my_struct: impl Wire2Api<MyStruct> + UnwindSafe,
-) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+fn wire_handle_newtype_sync_impl(
+    arg: impl Wire2Api<NewTypeInt> + UnwindSafe,
+) -> support::WireSyncReturn {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync(
         WrapInfo {
-            debug_name: "use_imported_struct",
-            port: Some(port_),
-            mode: FfiCallMode::Normal,
+            debug_name: "handle_newtype_sync",
+            port: None,
+            mode: FfiCallMode::Sync,
         },
         move || {
-            let api_my_struct = my_struct.wire2api();
-            move |task_callback| Ok(use_imported_struct(api_my_struct))
+            frb_init();
+            let api_arg = arg.wire2api();
+            Ok(handle_newtype_sync(api_arg))
         },
     )
 }
-fn wire_use_imported_enum_impl(port_: MessagePort, my_enum: impl Wire2Api<MyEnum> + UnwindSafe) {
+fn wire_handle_generic_struct_impl(
+    port_: MessagePort,
+    pair: impl Wire2Api<Pair<i32>> + UnwindSafe,
+) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
-            debug_name: "use_imported_enum",
+            debug_name: "handle_generic_struct",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
         move || {
-            let api_my_enum = my_enum.wire2api();
-            move |task_callback| Ok(use_imported_enum(api_my_enum))
+            frb_init();
+            let api_pair = pair.wire2api();
+            move |task_callback| Ok(handle_generic_struct(api_pair))
         },
     )
 }
-fn wire_get_app_settings_impl(port_: MessagePort) {
+fn wire_handle_list_of_struct_impl(port_: MessagePort, l: impl Wire2Api<Vec<MySize>> + UnwindSafe) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
-            debug_name: "get_app_settings",
+            debug_name: "handle_list_of_struct",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
-        move || move |task_callback| Ok(mirror_ApplicationSettings(get_app_settings())),
+        move || {
+            frb_init();
+            let api_l = l.wire2api();
+            move |task_callback| Ok(handle_list_of_struct(api_l))
+        },
     )
 }
-fn wire_get_fallible_app_settings_impl(port_: MessagePort) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+fn wire_handle_list_of_struct_sync_impl(
+    l: impl Wire2Api<Vec<MySize>> + UnwindSafe,
+) -> support::WireSyncReturn {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync(
         WrapInfo {
-            debug_name: "get_fallible_app_settings",
-            port: Some(port_),
-            mode: FfiCallMode::Normal,
+            debug_name: "handle_list_of_struct_sync",
+            port: None,
+            mode: FfiCallMode::Sync,
+        },
+        move || {
+            frb_init();
+            let api_l = l.wire2api();
+            Ok(handle_list_of_struct_sync(api_l))
         },
-        move || move |task_callback| Ok(mirror_ApplicationSettings(get_fallible_app_settings()?)),
     )
 }
-fn wire_is_app_embedded_impl(
+fn wire_handle_nested_vec_impl(
     port_: MessagePort,
-    app_settings: impl Wire2Api<ApplicationSettings> + UnwindSafe,
+    rows: impl Wire2Api<Vec<Vec<i32>>> + UnwindSafe,
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
-            debug_name: "is_app_embedded",
+            debug_name: "handle_nested_vec",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
         move || {
-            let api_app_settings = app_settings.wire2api();
-            move |task_callback| Ok(is_app_embedded(api_app_settings))
-        },
-    )
-}
-fn wire_get_message_impl(port_: MessagePort) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
-        WrapInfo {
-            debug_name: "get_message",
-            port: Some(port_),
-            mode: FfiCallMode::Normal,
+            frb_init();
+            let api_rows = rows.wire2api();
+            move |task_callback| Ok(handle_nested_vec(api_rows))
         },
-        move || move |task_callback| Ok(mirror_ApplicationMessage(get_message())),
     )
 }
-fn wire_repeat_number_impl(
+fn wire_handle_string_list_impl(
     port_: MessagePort,
-    num: impl Wire2Api<i32> + UnwindSafe,
-    times: impl Wire2Api<usize> + UnwindSafe,
+    names: impl Wire2Api<Vec<String>> + UnwindSafe,
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
-            debug_name: "repeat_number",
+            debug_name: "handle_string_list",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
         move || {
-            let api_num = num.wire2api();
-            let api_times = times.wire2api();
-            move |task_callback| Ok(mirror_Numbers(repeat_number(api_num, api_times)))
+            frb_init();
+            let api_names = names.wire2api();
+            move |task_callback| Ok(handle_string_list(api_names))
         },
     )
 }
-fn wire_repeat_sequence_impl(
-    port_: MessagePort,
-    seq: impl Wire2Api<i32> + UnwindSafe,
-    times: impl Wire2Api<usize> + UnwindSafe,
-) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+fn wire_handle_string_list_sync_impl(
+    names: impl Wire2Api<Vec<String>> + UnwindSafe,
+) -> support::WireSyncReturn {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync(
         WrapInfo {
-            debug_name: "repeat_sequence",
-            port: Some(port_),
-            mode: FfiCallMode::Normal,
+            debug_name: "handle_string_list_sync",
+            port: None,
+            mode: FfiCallMode::Sync,
         },
         move || {
-            let api_seq = seq.wire2api();
-            let api_times = times.wire2api();
-            move |task_callback| Ok(mirror_Sequences(repeat_sequence(api_seq, api_times)))
+            frb_init();
+            let api_names = names.wire2api();
+            Ok(handle_string_list_sync(api_names))
         },
     )
 }
-fn wire_first_number_impl(port_: MessagePort, nums: impl Wire2Api<Numbers> + UnwindSafe) {
+fn wire_handle_complex_struct_impl(port_: MessagePort, s: impl Wire2Api<MyTreeNode> + UnwindSafe) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
-            debug_name: "first_number",
+            debug_name: "handle_complex_struct",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
         move || {
-            let api_nums = nums.wire2api();
-            move |task_callback| Ok(first_number(api_nums))
+            frb_init();
+            let api_s = s.wire2api();
+            move |task_callback| Ok(handle_complex_struct(api_s))
         },
     )
 }
-fn wire_first_sequence_impl(port_: MessagePort, seqs: impl Wire2Api<Sequences> + UnwindSafe) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+fn wire_handle_complex_struct_sync_impl(
+    s: impl Wire2Api<MyTreeNode> + UnwindSafe,
+) -> support::WireSyncReturn {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync(
         WrapInfo {
-            debug_name: "first_sequence",
-            port: Some(port_),
-            mode: FfiCallMode::Normal,
+            debug_name: "handle_complex_struct_sync",
+            port: None,
+            mode: FfiCallMode::Sync,
         },
         move || {
-            let api_seqs = seqs.wire2api();
-            move |task_callback| Ok(first_sequence(api_seqs))
+            frb_init();
+            let api_s = s.wire2api();
+            Ok(handle_complex_struct_sync(api_s))
         },
     )
 }
-fn wire_get_array_impl(port_: MessagePort) {
+fn wire_handle_nested_struct_impl(
+    port_: MessagePort,
+    s: impl Wire2Api<MyNestedStruct> + UnwindSafe,
+) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
-            debug_name: "get_array",
+            debug_name: "handle_nested_struct",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
-        move || move |task_callback| Ok(get_array()),
+        move || {
+            frb_init();
+            let api_s = s.wire2api();
+            move |task_callback| Ok(handle_nested_struct(api_s))
+        },
     )
 }
-fn wire_get_complex_array_impl(port_: MessagePort) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+fn wire_handle_sync_return_impl(
+    mode: impl Wire2Api<String> + UnwindSafe,
+) -> support::WireSyncReturn {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync(
         WrapInfo {
-            debug_name: "get_complex_array",
-            port: Some(port_),
-            mode: FfiCallMode::Normal,
+            debug_name: "handle_sync_return",
+            port: None,
+            mode: FfiCallMode::Sync,
+        },
+        move || {
+            frb_init();
+            let api_mode = mode.wire2api();
+            handle_sync_return(api_mode)
         },
-        move || move |task_callback| Ok(get_complex_array()),
     )
 }
-fn wire_get_usize_impl(port_: MessagePort, u: impl Wire2Api<usize> + UnwindSafe) {
+fn wire_handle_stream_impl(port_: MessagePort, arg: impl Wire2Api<String> + UnwindSafe) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
-            debug_name: "get_usize",
+            debug_name: "handle_stream",
             port: Some(port_),
-            mode: FfiCallMode::Normal,
+            mode: FfiCallMode::Stream,
         },
         move || {
-            let api_u = u.wire2api();
-            move |task_callback| Ok(get_usize(api_u))
+            frb_init();
+            let api_arg = arg.wire2api();
+            move |task_callback| Ok(handle_stream(task_callback.stream_sink(), api_arg))
         },
     )
 }
-fn wire_next_user_id_impl(port_: MessagePort, user_id: impl Wire2Api<UserId> + UnwindSafe) {
+fn wire_handle_stream_of_struct_impl(port_: MessagePort) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
-            debug_name: "next_user_id",
+            debug_name: "handle_stream_of_struct",
             port: Some(port_),
-            mode: FfiCallMode::Normal,
+            mode: FfiCallMode::Stream,
         },
         move || {
-            let api_user_id = user_id.wire2api();
-            move |task_callback| Ok(next_user_id(api_user_id))
+            frb_init();
+            move |task_callback| Ok(handle_stream_of_struct(task_callback.stream_sink()))
         },
     )
 }
-fn wire_register_event_listener_impl(port_: MessagePort) {
+fn wire_return_err_impl(port_: MessagePort) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
-            debug_name: "register_event_listener",
+            debug_name: "return_err",
             port: Some(port_),
-            mode: FfiCallMode::Stream,
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            frb_init();
+            move |task_callback| return_err()
         },
-        move || move |task_callback| register_event_listener(task_callback.stream_sink()),
     )
 }
-fn wire_close_event_listener_impl(port_: MessagePort) {
+fn wire_return_custom_error_impl(port_: MessagePort, fail: impl Wire2Api<bool> + UnwindSafe) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
-            debug_name: "close_event_listener",
+            debug_name: "return_custom_error",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
-        move || move |task_callback| Ok(close_event_listener()),
+        move || {
+            frb_init();
+            let api_fail = fail.wire2api();
+            move |task_callback| return_custom_error(api_fail).map_err(|e| support::custom_error(e))
+        },
     )
 }
-fn wire_create_event_impl(
-    port_: MessagePort,
-    address: impl Wire2Api<String> + UnwindSafe,
-    payload: impl Wire2Api<String> + UnwindSafe,
-) {
+fn wire_return_custom_error_unit_impl(port_: MessagePort) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
-            debug_name: "create_event",
+            debug_name: "return_custom_error_unit",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
         move || {
-            let api_address = address.wire2api();
-            let api_payload = payload.wire2api();
-            move |task_callback| Ok(create_event(api_address, api_payload))
+            frb_init();
+            move |task_callback| return_custom_error_unit().map_err(|e| support::custom_error(e))
         },
     )
 }
-fn wire_handle_stream_sink_at_1_impl(
-    port_: MessagePort,
-    key: impl Wire2Api<u32> + UnwindSafe,
-    max: impl Wire2Api<u32> + UnwindSafe,
-) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+fn wire_lookup_weekday_impl(index: impl Wire2Api<u32> + UnwindSafe) -> support::WireSyncReturn {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync(
         WrapInfo {
-            debug_name: "handle_stream_sink_at_1",
-            port: Some(port_),
-            mode: FfiCallMode::Stream,
+            debug_name: "lookup_weekday",
+            port: None,
+            mode: FfiCallMode::Sync,
         },
         move || {
-            let api_key = key.wire2api();
-            let api_max = max.wire2api();
-            move |task_callback| {
-                Ok(handle_stream_sink_at_1(
-                    api_key,
-                    api_max,
-                    task_callback.stream_sink(),
-                ))
-            }
+            frb_init();
+            let api_index = index.wire2api();
+            lookup_weekday(api_index)
+                .map(SyncReturn)
+                .map_err(|e| support::custom_error(e))
         },
     )
 }
-fn wire_handle_stream_sink_at_2_impl(
-    port_: MessagePort,
-    key: impl Wire2Api<u32> + UnwindSafe,
-    max: impl Wire2Api<u32> + UnwindSafe,
-) {
+fn wire_lookup_string_impl(port_: MessagePort, key: impl Wire2Api<String> + UnwindSafe) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
-            debug_name: "handle_stream_sink_at_2",
+            debug_name: "lookup_string",
             port: Some(port_),
-            mode: FfiCallMode::Stream,
+            mode: FfiCallMode::Normal,
         },
         move || {
+            frb_init();
             let api_key = key.wire2api();
-            let api_max = max.wire2api();
-            move |task_callback| {
-                Ok(handle_stream_sink_at_2(
-                    api_key,
-                    task_callback.stream_sink(),
-                    api_max,
-                ))
-            }
+            move |task_callback| lookup_string(api_key).map_err(|e| support::custom_error(e))
         },
     )
 }
-fn wire_handle_stream_sink_at_3_impl(
-    port_: MessagePort,
-    key: impl Wire2Api<u32> + UnwindSafe,
-    max: impl Wire2Api<u32> + UnwindSafe,
-) {
+fn wire_read_setting_impl(port_: MessagePort, key: impl Wire2Api<String> + UnwindSafe) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
-            debug_name: "handle_stream_sink_at_3",
+            debug_name: "read_setting",
             port: Some(port_),
-            mode: FfiCallMode::Stream,
+            mode: FfiCallMode::Normal,
         },
         move || {
+            frb_init();
             let api_key = key.wire2api();
-            let api_max = max.wire2api();
             move |task_callback| {
-                Ok(handle_stream_sink_at_3(
-                    task_callback.stream_sink(),
-                    api_key,
-                    api_max,
-                ))
+                read_setting(api_key)
+                    .map_err(|e| support::custom_error(mirror_tuple2_setting_error_String(e)))
             }
         },
     )
 }
-fn wire_get_sum_struct_impl(port_: MessagePort) {
+fn wire_parse_setting_impl(port_: MessagePort, value: impl Wire2Api<String> + UnwindSafe) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
-            debug_name: "get_sum_struct",
+            debug_name: "parse_setting",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
-        move || move |task_callback| Ok(get_sum_struct()),
+        move || {
+            frb_init();
+            let api_value = value.wire2api();
+            move |task_callback| {
+                parse_setting(api_value).map_err(|e| {
+                    support::custom_error(mirror_tuple3_setting_error_String_custom_error(e))
+                })
+            }
+        },
     )
 }
-fn wire_get_sum_array_impl(
-    port_: MessagePort,
-    a: impl Wire2Api<u32> + UnwindSafe,
-    b: impl Wire2Api<u32> + UnwindSafe,
-    c: impl Wire2Api<u32> + UnwindSafe,
-) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+fn wire_return_custom_error_sync_impl(
+    fail: impl Wire2Api<bool> + UnwindSafe,
+) -> support::WireSyncReturn {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync(
         WrapInfo {
-            debug_name: "get_sum_array",
-            port: Some(port_),
-            mode: FfiCallMode::Normal,
+            debug_name: "return_custom_error_sync",
+            port: None,
+            mode: FfiCallMode::Sync,
         },
         move || {
-            let api_a = a.wire2api();
-            let api_b = b.wire2api();
-            let api_c = c.wire2api();
-            move |task_callback| Ok(get_sum_array(api_a, api_b, api_c))
+            frb_init();
+            let api_fail = fail.wire2api();
+            return_custom_error_sync(api_fail).map_err(|e| support::custom_error(e))
         },
     )
 }
-fn wire_multiply_by_ten_impl(port_: MessagePort, measure: impl Wire2Api<Measure> + UnwindSafe) {
+fn wire_count_until_cancelled_impl(port_: MessagePort, cancel_id_: i32) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
-            debug_name: "multiply_by_ten",
+            debug_name: "count_until_cancelled",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
         move || {
-            let api_measure = measure.wire2api();
-            move |task_callback| Ok(multiply_by_ten(api_measure))
+            frb_init();
+            let cancellation_token = CancellationToken::register(cancel_id_);
+            move |task_callback| {
+                cancellation_token
+                    .clone()
+                    .scope(|| count_until_cancelled(cancellation_token))
+            }
         },
     )
 }
-fn wire_call_old_module_system_impl(port_: MessagePort) {
+fn wire_run_workers_impl(
+    port_: MessagePort,
+    cancel_id_: i32,
+    workers: impl Wire2Api<u32> + UnwindSafe,
+) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
-            debug_name: "call_old_module_system",
+            debug_name: "run_workers",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
-        move || move |task_callback| Ok(call_old_module_system()),
-    )
-}
-fn wire_call_new_module_system_impl(port_: MessagePort) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
-        WrapInfo {
-            debug_name: "call_new_module_system",
-            port: Some(port_),
-            mode: FfiCallMode::Normal,
+        move || {
+            frb_init();
+            let cancellation_token = CancellationToken::register(cancel_id_);
+            let api_workers = workers.wire2api();
+            move |task_callback| {
+                cancellation_token
+                    .clone()
+                    .scope(|| run_workers(api_workers, cancellation_token))
+            }
         },
-        move || move |task_callback| Ok(call_new_module_system()),
     )
 }
-fn wire_handle_big_buffers_impl(port_: MessagePort) {
+fn wire_stopped_workers_impl(port_: MessagePort) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
-            debug_name: "handle_big_buffers",
+            debug_name: "stopped_workers",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
-        move || move |task_callback| Ok(handle_big_buffers()),
+        move || {
+            frb_init();
+            move |task_callback| Ok(stopped_workers())
+        },
     )
 }
-fn wire_datetime_utc_impl(
-    port_: MessagePort,
-    d: impl Wire2Api<chrono::DateTime<chrono::Utc>> + UnwindSafe,
-) {
+fn wire_return_panic_impl(port_: MessagePort) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
-            debug_name: "datetime_utc",
+            debug_name: "return_panic",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
         move || {
-            let api_d = d.wire2api();
-            move |task_callback| Ok(datetime_utc(api_d))
+            frb_init();
+            move |task_callback| Ok(return_panic())
         },
     )
 }
-fn wire_datetime_local_impl(
+fn wire_handle_optional_return_impl(
     port_: MessagePort,
-    d: impl Wire2Api<chrono::DateTime<chrono::Local>> + UnwindSafe,
+    left: impl Wire2Api<f64> + UnwindSafe,
+    right: impl Wire2Api<f64> + UnwindSafe,
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
-            debug_name: "datetime_local",
+            debug_name: "handle_optional_return",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
         move || {
-            let api_d = d.wire2api();
-            move |task_callback| Ok(datetime_local(api_d))
+            frb_init();
+            let api_left = left.wire2api();
+            let api_right = right.wire2api();
+            move |task_callback| Ok(handle_optional_return(api_left, api_right))
         },
     )
 }
-fn wire_naivedatetime_impl(
+fn wire_handle_optional_struct_impl(
     port_: MessagePort,
-    d: impl Wire2Api<chrono::NaiveDateTime> + UnwindSafe,
+    document: impl Wire2Api<Option<String>> + UnwindSafe,
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
-            debug_name: "naivedatetime",
+            debug_name: "handle_optional_struct",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
         move || {
-            let api_d = d.wire2api();
-            move |task_callback| Ok(naivedatetime(api_d))
+            frb_init();
+            let api_document = document.wire2api();
+            move |task_callback| Ok(handle_optional_struct(api_document))
         },
     )
 }
-fn wire_optional_empty_datetime_utc_impl(
+fn wire_handle_optional_increment_impl(
     port_: MessagePort,
-    d: impl Wire2Api<Option<chrono::DateTime<chrono::Utc>>> + UnwindSafe,
+    opt: impl Wire2Api<Option<ExoticOptionals>> + UnwindSafe,
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
-            debug_name: "optional_empty_datetime_utc",
+            debug_name: "handle_optional_increment",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
         move || {
-            let api_d = d.wire2api();
-            move |task_callback| Ok(optional_empty_datetime_utc(api_d))
+            frb_init();
+            let api_opt = opt.wire2api();
+            move |task_callback| Ok(handle_optional_increment(api_opt))
         },
     )
 }
-fn wire_duration_impl(port_: MessagePort, d: impl Wire2Api<chrono::Duration> + UnwindSafe) {
+fn wire_handle_increment_boxed_optional_impl(
+    port_: MessagePort,
+    opt: impl Wire2Api<Option<Box<f64>>> + UnwindSafe,
+) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
-            debug_name: "duration",
+            debug_name: "handle_increment_boxed_optional",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
         move || {
-            let api_d = d.wire2api();
-            move |task_callback| Ok(duration(api_d))
+            frb_init();
+            let api_opt = opt.wire2api();
+            move |task_callback| Ok(handle_increment_boxed_optional(api_opt))
         },
     )
 }
-fn wire_test_chrono_impl(port_: MessagePort) {
+fn wire_handle_option_box_arguments_impl(
+    port_: MessagePort,
+    i8box: impl Wire2Api<Option<Box<i8>>> + UnwindSafe,
+    u8box: impl Wire2Api<Option<Box<u8>>> + UnwindSafe,
+    i32box: impl Wire2Api<Option<Box<i32>>> + UnwindSafe,
+    i64box: impl Wire2Api<Option<Box<i64>>> + UnwindSafe,
+    f64box: impl Wire2Api<Option<Box<f64>>> + UnwindSafe,
+    boolbox: impl Wire2Api<Option<Box<bool>>> + UnwindSafe,
+    structbox: impl Wire2Api<Option<Box<ExoticOptionals>>> + UnwindSafe,
+) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
-            debug_name: "test_chrono",
+            debug_name: "handle_option_box_arguments",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
-        move || move |task_callback| Ok(test_chrono()),
-    )
-}
-fn wire_test_precise_chrono_impl(port_: MessagePort) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
-        WrapInfo {
-            debug_name: "test_precise_chrono",
-            port: Some(port_),
-            mode: FfiCallMode::Normal,
+        move || {
+            frb_init();
+            let api_i8box = i8box.wire2api();
+            let api_u8box = u8box.wire2api();
+            let api_i32box = i32box.wire2api();
+            let api_i64box = i64box.wire2api();
+            let api_f64box = f64box.wire2api();
+            let api_boolbox = boolbox.wire2api();
+            let api_structbox = structbox.wire2api();
+            move |task_callback| {
+                Ok(handle_option_box_arguments(
+                    api_i8box,
+                    api_u8box,
+                    api_i32box,
+                    api_i64box,
+                    api_f64box,
+                    api_boolbox,
+                    api_structbox,
+                ))
+            }
         },
-        move || move |task_callback| Ok(test_precise_chrono()),
     )
 }
-fn wire_how_long_does_it_take_impl(
-    port_: MessagePort,
-    mine: impl Wire2Api<FeatureChrono> + UnwindSafe,
-) {
+fn wire_print_note_impl(port_: MessagePort, note: impl Wire2Api<Note> + UnwindSafe) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
-            debug_name: "how_long_does_it_take",
+            debug_name: "print_note",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
         move || {
-            let api_mine = mine.wire2api();
-            move |task_callback| how_long_does_it_take(api_mine)
+            frb_init();
+            let api_note = note.wire2api();
+            move |task_callback| Ok(print_note(api_note))
         },
     )
 }
-fn wire_handle_uuid_impl(port_: MessagePort, id: impl Wire2Api<uuid::Uuid> + UnwindSafe) {
+fn wire_handle_return_enum_impl(port_: MessagePort, input: impl Wire2Api<String> + UnwindSafe) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
-            debug_name: "handle_uuid",
+            debug_name: "handle_return_enum",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
         move || {
-            let api_id = id.wire2api();
-            move |task_callback| handle_uuid(api_id)
+            frb_init();
+            let api_input = input.wire2api();
+            move |task_callback| Ok(handle_return_enum(api_input))
         },
     )
 }
-fn wire_handle_uuids_impl(port_: MessagePort, ids: impl Wire2Api<Vec<uuid::Uuid>> + UnwindSafe) {
+fn wire_handle_enum_parameter_impl(
+    port_: MessagePort,
+    weekday: impl Wire2Api<Weekdays> + UnwindSafe,
+) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
-            debug_name: "handle_uuids",
+            debug_name: "handle_enum_parameter",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
         move || {
-            let api_ids = ids.wire2api();
-            move |task_callback| handle_uuids(api_ids)
+            frb_init();
+            let api_weekday = weekday.wire2api();
+            move |task_callback| Ok(handle_enum_parameter(api_weekday))
         },
     )
 }
-fn wire_handle_nested_uuids_impl(port_: MessagePort, ids: impl Wire2Api<FeatureUuid> + UnwindSafe) {
+fn wire_http_status_code_impl(port_: MessagePort, status: impl Wire2Api<HttpStatus> + UnwindSafe) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
-            debug_name: "handle_nested_uuids",
+            debug_name: "http_status_code",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
         move || {
-            let api_ids = ids.wire2api();
-            move |task_callback| handle_nested_uuids(api_ids)
+            frb_init();
+            let api_status = status.wire2api();
+            move |task_callback| Ok(http_status_code(api_status))
         },
     )
 }
-fn wire_new_msgid_impl(port_: MessagePort, id: impl Wire2Api<[u8; 32]> + UnwindSafe) {
+fn wire_http_status_of_impl(port_: MessagePort, code: impl Wire2Api<i32> + UnwindSafe) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
-            debug_name: "new_msgid",
+            debug_name: "http_status_of",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
         move || {
-            let api_id = id.wire2api();
-            move |task_callback| Ok(new_msgid(api_id))
+            frb_init();
+            let api_code = code.wire2api();
+            move |task_callback| Ok(http_status_of(api_code))
         },
     )
 }
-fn wire_use_msgid_impl(port_: MessagePort, id: impl Wire2Api<MessageId> + UnwindSafe) {
+fn wire_handle_customized_struct_impl(
+    port_: MessagePort,
+    val: impl Wire2Api<Customized> + UnwindSafe,
+) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
-            debug_name: "use_msgid",
+            debug_name: "handle_customized_struct",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
         move || {
-            let api_id = id.wire2api();
-            move |task_callback| Ok(use_msgid(api_id))
+            frb_init();
+            let api_val = val.wire2api();
+            move |task_callback| Ok(handle_customized_struct(api_val))
         },
     )
 }
-fn wire_boxed_blob_impl(port_: MessagePort, blob: impl Wire2Api<Box<[u8; 1600]>> + UnwindSafe) {
+fn wire_handle_enum_struct_impl(port_: MessagePort, val: impl Wire2Api<KitchenSink> + UnwindSafe) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
-            debug_name: "boxed_blob",
+            debug_name: "handle_enum_struct",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
         move || {
-            let api_blob = blob.wire2api();
-            move |task_callback| Ok(boxed_blob(api_blob))
+            frb_init();
+            let api_val = val.wire2api();
+            move |task_callback| Ok(handle_enum_struct(api_val))
         },
     )
 }
-fn wire_use_boxed_blob_impl(port_: MessagePort, blob: impl Wire2Api<Box<Blob>> + UnwindSafe) {
+fn wire_use_imported_struct_impl(
+    port_: MessagePort,
+    my_struct: impl Wire2Api<MyStruct> + UnwindSafe,
+) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
-            debug_name: "use_boxed_blob",
+            debug_name: "use_imported_struct",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
         move || {
-            let api_blob = blob.wire2api();
-            move |task_callback| Ok(use_boxed_blob(api_blob))
+            frb_init();
+            let api_my_struct = my_struct.wire2api();
+            move |task_callback| Ok(use_imported_struct(api_my_struct))
         },
     )
 }
-fn wire_return_boxed_feed_id_impl(port_: MessagePort, id: impl Wire2Api<[u8; 8]> + UnwindSafe) {
+fn wire_use_imported_enum_impl(port_: MessagePort, my_enum: impl Wire2Api<MyEnum> + UnwindSafe) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
-            debug_name: "return_boxed_feed_id",
+            debug_name: "use_imported_enum",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
         move || {
-            let api_id = id.wire2api();
-            move |task_callback| Ok((*return_boxed_feed_id(api_id)))
+            frb_init();
+            let api_my_enum = my_enum.wire2api();
+            move |task_callback| Ok(use_imported_enum(api_my_enum))
         },
     )
 }
-fn wire_return_boxed_raw_feed_id_impl(port_: MessagePort, id: impl Wire2Api<FeedId> + UnwindSafe) {
+fn wire_get_app_settings_impl(port_: MessagePort) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
-            debug_name: "return_boxed_raw_feed_id",
+            debug_name: "get_app_settings",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
         move || {
-            let api_id = id.wire2api();
-            move |task_callback| Ok((*return_boxed_raw_feed_id(api_id)))
+            frb_init();
+            move |task_callback| Ok(mirror_ApplicationSettings(get_app_settings()))
         },
     )
 }
-fn wire_test_id_impl(port_: MessagePort, id: impl Wire2Api<TestId> + UnwindSafe) {
+fn wire_get_fallible_app_settings_impl(port_: MessagePort) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
-            debug_name: "test_id",
+            debug_name: "get_fallible_app_settings",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
         move || {
-            let api_id = id.wire2api();
-            move |task_callback| Ok(test_id(api_id))
+            frb_init();
+            move |task_callback| Ok(mirror_ApplicationSettings(get_fallible_app_settings()?))
         },
     )
 }
-fn wire_last_number_impl(port_: MessagePort, array: impl Wire2Api<[f64; 16]> + UnwindSafe) {
+fn wire_is_app_embedded_impl(
+    port_: MessagePort,
+    app_settings: impl Wire2Api<ApplicationSettings> + UnwindSafe,
+) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
-            debug_name: "last_number",
+            debug_name: "is_app_embedded",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
         move || {
-            let api_array = array.wire2api();
-            move |task_callback| Ok(last_number(api_array))
+            frb_init();
+            let api_app_settings = app_settings.wire2api();
+            move |task_callback| Ok(is_app_embedded(api_app_settings))
         },
     )
 }
-fn wire_nested_id_impl(port_: MessagePort, id: impl Wire2Api<[TestId; 4]> + UnwindSafe) {
+fn wire_get_message_impl(port_: MessagePort) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
-            debug_name: "nested_id",
+            debug_name: "get_message",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
         move || {
-            let api_id = id.wire2api();
-            move |task_callback| Ok(nested_id(api_id))
+            frb_init();
+            move |task_callback| Ok(mirror_ApplicationMessage(get_message()))
         },
     )
 }
-fn wire_sync_accept_dart_opaque_impl(
-    opaque: impl Wire2Api<DartOpaque> + UnwindSafe,
-) -> support::WireSyncReturn {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync(
+fn wire_get_fallible_message_impl(port_: MessagePort, index: impl Wire2Api<usize> + UnwindSafe) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
-            debug_name: "sync_accept_dart_opaque",
-            port: None,
-            mode: FfiCallMode::Sync,
+            debug_name: "get_fallible_message",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
         },
         move || {
-            let api_opaque = opaque.wire2api();
-            Ok(sync_accept_dart_opaque(api_opaque))
+            frb_init();
+            let api_index = index.wire2api();
+            move |task_callback| Ok(mirror_ApplicationMessage(get_fallible_message(api_index)?))
         },
     )
 }
-fn wire_async_accept_dart_opaque_impl(
+fn wire_is_exit_message_impl(
     port_: MessagePort,
-    opaque: impl Wire2Api<DartOpaque> + UnwindSafe,
+    message: impl Wire2Api<ApplicationMessage> + UnwindSafe,
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
-            debug_name: "async_accept_dart_opaque",
+            debug_name: "is_exit_message",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
         move || {
-            let api_opaque = opaque.wire2api();
-            move |task_callback| Ok(async_accept_dart_opaque(api_opaque))
+            frb_init();
+            let api_message = message.wire2api();
+            move |task_callback| Ok(is_exit_message(api_message))
         },
     )
 }
-fn wire_loop_back_impl(port_: MessagePort, opaque: impl Wire2Api<DartOpaque> + UnwindSafe) {
+fn wire_repeat_number_impl(
+    port_: MessagePort,
+    num: impl Wire2Api<i32> + UnwindSafe,
+    times: impl Wire2Api<usize> + UnwindSafe,
+) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
-            debug_name: "loop_back",
+            debug_name: "repeat_number",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
         move || {
-            let api_opaque = opaque.wire2api();
-            move |task_callback| Ok(loop_back(api_opaque))
+            frb_init();
+            let api_num = num.wire2api();
+            let api_times = times.wire2api();
+            move |task_callback| Ok(mirror_Numbers(repeat_number(api_num, api_times)))
         },
     )
 }
-fn wire_loop_back_option_impl(port_: MessagePort, opaque: impl Wire2Api<DartOpaque> + UnwindSafe) {
+fn wire_repeat_sequence_impl(
+    port_: MessagePort,
+    seq: impl Wire2Api<i32> + UnwindSafe,
+    times: impl Wire2Api<usize> + UnwindSafe,
+) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
-            debug_name: "loop_back_option",
+            debug_name: "repeat_sequence",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
         move || {
-            let api_opaque = opaque.wire2api();
-            move |task_callback| Ok(loop_back_option(api_opaque))
+            frb_init();
+            let api_seq = seq.wire2api();
+            let api_times = times.wire2api();
+            move |task_callback| Ok(mirror_Sequences(repeat_sequence(api_seq, api_times)))
         },
     )
 }
-fn wire_loop_back_array_impl(port_: MessagePort, opaque: impl Wire2Api<DartOpaque> + UnwindSafe) {
+fn wire_first_number_impl(port_: MessagePort, nums: impl Wire2Api<Numbers> + UnwindSafe) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
-            debug_name: "loop_back_array",
+            debug_name: "first_number",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
         move || {
-            let api_opaque = opaque.wire2api();
-            move |task_callback| Ok(loop_back_array(api_opaque))
+            frb_init();
+            let api_nums = nums.wire2api();
+            move |task_callback| Ok(first_number(api_nums))
         },
     )
 }
-fn wire_loop_back_vec_impl(port_: MessagePort, opaque: impl Wire2Api<DartOpaque> + UnwindSafe) {
+fn wire_first_sequence_impl(port_: MessagePort, seqs: impl Wire2Api<Sequences> + UnwindSafe) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
-            debug_name: "loop_back_vec",
+            debug_name: "first_sequence",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
         move || {
-            let api_opaque = opaque.wire2api();
-            move |task_callback| Ok(loop_back_vec(api_opaque))
+            frb_init();
+            let api_seqs = seqs.wire2api();
+            move |task_callback| Ok(first_sequence(api_seqs))
         },
     )
 }
-fn wire_loop_back_option_get_impl(
-    port_: MessagePort,
-    opaque: impl Wire2Api<Option<DartOpaque>> + UnwindSafe,
-) {
+fn wire_get_array_impl(port_: MessagePort) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
-            debug_name: "loop_back_option_get",
+            debug_name: "get_array",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
         move || {
-            let api_opaque = opaque.wire2api();
-            move |task_callback| Ok(loop_back_option_get(api_opaque))
+            frb_init();
+            move |task_callback| Ok(get_array())
         },
     )
 }
-fn wire_loop_back_array_get_impl(
-    port_: MessagePort,
-    opaque: impl Wire2Api<[DartOpaque; 1]> + UnwindSafe,
-) {
+fn wire_get_complex_array_impl(port_: MessagePort) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
-            debug_name: "loop_back_array_get",
+            debug_name: "get_complex_array",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
         move || {
-            let api_opaque = opaque.wire2api();
-            move |task_callback| Ok(loop_back_array_get(api_opaque))
+            frb_init();
+            move |task_callback| Ok(get_complex_array())
         },
     )
 }
-fn wire_loop_back_vec_get_impl(
-    port_: MessagePort,
-    opaque: impl Wire2Api<Vec<DartOpaque>> + UnwindSafe,
-) {
+fn wire_get_usize_impl(port_: MessagePort, u: impl Wire2Api<usize> + UnwindSafe) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
-            debug_name: "loop_back_vec_get",
+            debug_name: "get_usize",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
         move || {
-            let api_opaque = opaque.wire2api();
-            move |task_callback| Ok(loop_back_vec_get(api_opaque))
+            frb_init();
+            let api_u = u.wire2api();
+            move |task_callback| Ok(get_usize(api_u))
         },
     )
 }
-fn wire_unwrap_dart_opaque_impl(
-    opaque: impl Wire2Api<DartOpaque> + UnwindSafe,
-) -> support::WireSyncReturn {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync(
+fn wire_next_user_id_impl(port_: MessagePort, user_id: impl Wire2Api<UserId> + UnwindSafe) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
-            debug_name: "unwrap_dart_opaque",
-            port: None,
-            mode: FfiCallMode::Sync,
+            debug_name: "next_user_id",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
         },
         move || {
-            let api_opaque = opaque.wire2api();
-            Ok(unwrap_dart_opaque(api_opaque))
+            frb_init();
+            let api_user_id = user_id.wire2api();
+            move |task_callback| Ok(next_user_id(api_user_id))
         },
     )
 }
-fn wire_panic_unwrap_dart_opaque_impl(
-    port_: MessagePort,
-    opaque: impl Wire2Api<DartOpaque> + UnwindSafe,
-) {
+fn wire_register_event_listener_impl(port_: MessagePort) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
-            debug_name: "panic_unwrap_dart_opaque",
+            debug_name: "register_event_listener",
             port: Some(port_),
-            mode: FfiCallMode::Normal,
+            mode: FfiCallMode::Stream,
         },
         move || {
-            let api_opaque = opaque.wire2api();
-            move |task_callback| Ok(panic_unwrap_dart_opaque(api_opaque))
+            frb_init();
+            move |task_callback| register_event_listener(task_callback.stream_sink())
         },
     )
 }
-fn wire_create_opaque_impl(port_: MessagePort) {
+fn wire_close_event_listener_impl(port_: MessagePort) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
-            debug_name: "create_opaque",
+            debug_name: "close_event_listener",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
-        move || move |task_callback| Ok(create_opaque()),
+        move || {
+            frb_init();
+            move |task_callback| Ok(close_event_listener())
+        },
     )
 }
-fn wire_create_option_opaque_impl(
+fn wire_create_event_impl(
     port_: MessagePort,
-    opaque: impl Wire2Api<Option<RustOpaque<HideData>>> + UnwindSafe,
+    address: impl Wire2Api<String> + UnwindSafe,
+    payload: impl Wire2Api<String> + UnwindSafe,
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
-            debug_name: "create_option_opaque",
+            debug_name: "create_event",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
         move || {
-            let api_opaque = opaque.wire2api();
-            move |task_callback| Ok(create_option_opaque(api_opaque))
+            frb_init();
+            let api_address = address.wire2api();
+            let api_payload = payload.wire2api();
+            move |task_callback| Ok(create_event(api_address, api_payload))
         },
     )
 }
-fn wire_sync_create_opaque_impl() -> support::WireSyncReturn {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync(
+fn wire_handle_stream_sink_at_1_impl(
+    port_: MessagePort,
+    key: impl Wire2Api<u32> + UnwindSafe,
+    max: impl Wire2Api<u32> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
-            debug_name: "sync_create_opaque",
-            port: None,
-            mode: FfiCallMode::Sync,
+            debug_name: "handle_stream_sink_at_1",
+            port: Some(port_),
+            mode: FfiCallMode::Stream,
+        },
+        move || {
+            frb_init();
+            let api_key = key.wire2api();
+            let api_max = max.wire2api();
+            move |task_callback| {
+                Ok(handle_stream_sink_at_1(
+                    api_key,
+                    api_max,
+                    task_callback.stream_sink(),
+                ))
+            }
         },
-        move || Ok(sync_create_opaque()),
     )
 }
-fn wire_create_array_opaque_enum_impl(port_: MessagePort) {
+fn wire_handle_stream_sink_at_2_impl(
+    port_: MessagePort,
+    key: impl Wire2Api<u32> + UnwindSafe,
+    max: impl Wire2Api<u32> + UnwindSafe,
+) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
-            debug_name: "create_array_opaque_enum",
+            debug_name: "handle_stream_sink_at_2",
             port: Some(port_),
-            mode: FfiCallMode::Normal,
+            mode: FfiCallMode::Stream,
+        },
+        move || {
+            frb_init();
+            let api_key = key.wire2api();
+            let api_max = max.wire2api();
+            move |task_callback| {
+                Ok(handle_stream_sink_at_2(
+                    api_key,
+                    task_callback.stream_sink(),
+                    api_max,
+                ))
+            }
         },
-        move || move |task_callback| Ok(create_array_opaque_enum()),
     )
 }
-fn wire_run_enum_opaque_impl(port_: MessagePort, opaque: impl Wire2Api<EnumOpaque> + UnwindSafe) {
+fn wire_handle_stream_sink_at_3_impl(
+    port_: MessagePort,
+    key: impl Wire2Api<u32> + UnwindSafe,
+    max: impl Wire2Api<u32> + UnwindSafe,
+) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
-            debug_name: "run_enum_opaque",
+            debug_name: "handle_stream_sink_at_3",
             port: Some(port_),
-            mode: FfiCallMode::Normal,
+            mode: FfiCallMode::Stream,
         },
         move || {
-            let api_opaque = opaque.wire2api();
-            move |task_callback| Ok(run_enum_opaque(api_opaque))
+            frb_init();
+            let api_key = key.wire2api();
+            let api_max = max.wire2api();
+            move |task_callback| {
+                Ok(handle_stream_sink_at_3(
+                    task_callback.stream_sink(),
+                    api_key,
+                    api_max,
+                ))
+            }
         },
     )
 }
-fn wire_run_opaque_impl(
+fn wire_call_back_with_numbers_impl(
     port_: MessagePort,
-    opaque: impl Wire2Api<RustOpaque<HideData>> + UnwindSafe,
+    callback: MessagePort,
+    max: impl Wire2Api<u32> + UnwindSafe,
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
-            debug_name: "run_opaque",
+            debug_name: "call_back_with_numbers",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
         move || {
-            let api_opaque = opaque.wire2api();
-            move |task_callback| Ok(run_opaque(api_opaque))
+            frb_init();
+            let api_callback = support::dart_callback::<u32>(callback);
+            let api_max = max.wire2api();
+            move |task_callback| Ok(call_back_with_numbers(api_max, api_callback))
         },
     )
 }
-fn wire_run_opaque_with_delay_impl(
+fn wire_handle_stream_closed_by_drop_impl(
     port_: MessagePort,
-    opaque: impl Wire2Api<RustOpaque<HideData>> + UnwindSafe,
+    max: impl Wire2Api<u32> + UnwindSafe,
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
-            debug_name: "run_opaque_with_delay",
+            debug_name: "handle_stream_closed_by_drop",
             port: Some(port_),
-            mode: FfiCallMode::Normal,
+            mode: FfiCallMode::Stream,
         },
         move || {
-            let api_opaque = opaque.wire2api();
-            move |task_callback| Ok(run_opaque_with_delay(api_opaque))
+            frb_init();
+            let api_max = max.wire2api();
+            move |task_callback| {
+                Ok(handle_stream_closed_by_drop(
+                    task_callback.stream_sink(),
+                    api_max,
+                ))
+            }
         },
     )
 }
-fn wire_opaque_array_impl(port_: MessagePort) {
+fn wire_count_from_impl(
+    port_: MessagePort,
+    from: impl Wire2Api<u32> + UnwindSafe,
+    count: impl Wire2Api<u32> + UnwindSafe,
+) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
-            debug_name: "opaque_array",
+            debug_name: "count_from",
             port: Some(port_),
-            mode: FfiCallMode::Normal,
+            mode: FfiCallMode::Stream,
+        },
+        move || {
+            frb_init();
+            let api_from = from.wire2api();
+            let api_count = count.wire2api();
+            move |task_callback| {
+                count_from(task_callback.stream_sink(), api_from, api_count).map(|initial| {
+                    task_callback.stream_initial(initial);
+                })
+            }
         },
-        move || move |task_callback| Ok(opaque_array()),
     )
 }
-fn wire_create_sync_opaque_impl(port_: MessagePort) {
+fn wire_parse_numbers_impl(port_: MessagePort, input: impl Wire2Api<String> + UnwindSafe) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
-            debug_name: "create_sync_opaque",
+            debug_name: "parse_numbers",
             port: Some(port_),
-            mode: FfiCallMode::Normal,
+            mode: FfiCallMode::Stream,
+        },
+        move || {
+            frb_init();
+            let api_input = input.wire2api();
+            move |task_callback| Ok(parse_numbers(task_callback.stream_sink(), api_input))
         },
-        move || move |task_callback| Ok(create_sync_opaque()),
     )
 }
-fn wire_sync_create_sync_opaque_impl() -> support::WireSyncReturn {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync(
+fn wire_handle_broadcast_stream_impl(port_: MessagePort, max: impl Wire2Api<u32> + UnwindSafe) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
-            debug_name: "sync_create_sync_opaque",
-            port: None,
-            mode: FfiCallMode::Sync,
+            debug_name: "handle_broadcast_stream",
+            port: Some(port_),
+            mode: FfiCallMode::Stream,
+        },
+        move || {
+            frb_init();
+            let api_max = max.wire2api();
+            move |task_callback| {
+                Ok(handle_broadcast_stream(
+                    task_callback.stream_sink(),
+                    api_max,
+                ))
+            }
         },
-        move || Ok(sync_create_sync_opaque()),
     )
 }
-fn wire_sync_run_opaque_impl(
-    opaque: impl Wire2Api<RustOpaque<NonSendHideData>> + UnwindSafe,
-) -> support::WireSyncReturn {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync(
+fn wire_handle_stream_closed_by_threads_impl(
+    port_: MessagePort,
+    threads: impl Wire2Api<u32> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
-            debug_name: "sync_run_opaque",
-            port: None,
-            mode: FfiCallMode::Sync,
+            debug_name: "handle_stream_closed_by_threads",
+            port: Some(port_),
+            mode: FfiCallMode::Stream,
         },
         move || {
-            let api_opaque = opaque.wire2api();
-            Ok(sync_run_opaque(api_opaque))
+            frb_init();
+            let api_threads = threads.wire2api();
+            move |task_callback| {
+                Ok(handle_stream_closed_by_threads(
+                    task_callback.stream_sink(),
+                    api_threads,
+                ))
+            }
         },
     )
 }
-fn wire_opaque_array_run_impl(
+fn wire_get_sum_struct_impl(port_: MessagePort) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "get_sum_struct",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            frb_init();
+            move |task_callback| Ok(get_sum_struct())
+        },
+    )
+}
+fn wire_get_sum_array_impl(
     port_: MessagePort,
-    data: impl Wire2Api<[RustOpaque<HideData>; 2]> + UnwindSafe,
+    a: impl Wire2Api<u32> + UnwindSafe,
+    b: impl Wire2Api<u32> + UnwindSafe,
+    c: impl Wire2Api<u32> + UnwindSafe,
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
-            debug_name: "opaque_array_run",
+            debug_name: "get_sum_array",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
         move || {
-            let api_data = data.wire2api();
-            move |task_callback| Ok(opaque_array_run(api_data))
+            frb_init();
+            let api_a = a.wire2api();
+            let api_b = b.wire2api();
+            let api_c = c.wire2api();
+            move |task_callback| Ok(get_sum_array(api_a, api_b, api_c))
         },
     )
 }
-fn wire_opaque_vec_impl(port_: MessagePort) {
+fn wire_rename_document_impl(
+    port_: MessagePort,
+    document: impl Wire2Api<Document> + UnwindSafe,
+    title: impl Wire2Api<String> + UnwindSafe,
+) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
-            debug_name: "opaque_vec",
+            debug_name: "rename_document",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
-        move || move |task_callback| Ok(opaque_vec()),
+        move || {
+            frb_init();
+            let api_document = document.wire2api();
+            let api_title = title.wire2api();
+            move |task_callback| Ok(rename_document(api_document, api_title))
+        },
     )
 }
-fn wire_opaque_vec_run_impl(
+fn wire_get_renamed_impl(
     port_: MessagePort,
-    data: impl Wire2Api<Vec<RustOpaque<HideData>>> + UnwindSafe,
+    id: impl Wire2Api<u32> + UnwindSafe,
+    pace: impl Wire2Api<Pace> + UnwindSafe,
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
-            debug_name: "opaque_vec_run",
+            debug_name: "get_renamed",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
         move || {
-            let api_data = data.wire2api();
-            move |task_callback| Ok(opaque_vec_run(api_data))
+            frb_init();
+            let api_id = id.wire2api();
+            let api_pace = pace.wire2api();
+            move |task_callback| Ok(get_renamed(api_id, api_pace))
         },
     )
 }
-fn wire_create_nested_opaque_impl(port_: MessagePort) {
+fn wire_advance_impl(
+    port_: MessagePort,
+    progress: impl Wire2Api<Progress> + UnwindSafe,
+    by: impl Wire2Api<u8> + UnwindSafe,
+) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
-            debug_name: "create_nested_opaque",
+            debug_name: "advance",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
-        move || move |task_callback| Ok(create_nested_opaque()),
+        move || {
+            frb_init();
+            let api_progress = progress.wire2api();
+            let api_by = by.wire2api();
+            move |task_callback| Ok(advance(api_progress, api_by))
+        },
     )
 }
-fn wire_sync_loopback_impl(
-    opaque: impl Wire2Api<DartOpaque> + UnwindSafe,
-) -> support::WireSyncReturn {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync(
+fn wire_next_product_id_impl(port_: MessagePort, id: impl Wire2Api<ProductId> + UnwindSafe) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
-            debug_name: "sync_loopback",
-            port: None,
-            mode: FfiCallMode::Sync,
+            debug_name: "next_product_id",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
         },
         move || {
-            let api_opaque = opaque.wire2api();
-            Ok(sync_loopback(api_opaque))
+            frb_init();
+            let api_id = id.wire2api();
+            move |task_callback| Ok(next_product_id(api_id))
         },
     )
 }
-fn wire_sync_option_loopback_impl(
-    opaque: impl Wire2Api<Option<DartOpaque>> + UnwindSafe,
-) -> support::WireSyncReturn {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync(
+fn wire_transpose_cell_impl(port_: MessagePort, cell: impl Wire2Api<GridCell> + UnwindSafe) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
-            debug_name: "sync_option_loopback",
-            port: None,
-            mode: FfiCallMode::Sync,
+            debug_name: "transpose_cell",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
         },
         move || {
-            let api_opaque = opaque.wire2api();
-            Ok(sync_option_loopback(api_opaque))
+            frb_init();
+            let api_cell = cell.wire2api();
+            move |task_callback| Ok(transpose_cell(api_cell))
         },
     )
 }
-fn wire_sync_option_impl() -> support::WireSyncReturn {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync(
+fn wire_add_tax_impl(
+    port_: MessagePort,
+    price: impl Wire2Api<Money> + UnwindSafe,
+    percent: impl Wire2Api<u32> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
-            debug_name: "sync_option",
-            port: None,
-            mode: FfiCallMode::Sync,
+            debug_name: "add_tax",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            frb_init();
+            let api_price = price.wire2api();
+            let api_percent = percent.wire2api();
+            move |task_callback| Ok(add_tax(api_price, api_percent))
         },
-        move || sync_option(),
     )
 }
-fn wire_sync_option_null_impl() -> support::WireSyncReturn {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync(
+fn wire_bump_minor_impl(port_: MessagePort, version: impl Wire2Api<Version> + UnwindSafe) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
-            debug_name: "sync_option_null",
-            port: None,
-            mode: FfiCallMode::Sync,
+            debug_name: "bump_minor",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            frb_init();
+            let api_version = version.wire2api();
+            move |task_callback| Ok(crate::codecs::version::encode(&bump_minor(api_version)))
         },
-        move || sync_option_null(),
     )
 }
-fn wire_sync_option_rust_opaque_impl() -> support::WireSyncReturn {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync(
+fn wire_open_session_impl(port_: MessagePort, user: impl Wire2Api<String> + UnwindSafe) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
-            debug_name: "sync_option_rust_opaque",
-            port: None,
-            mode: FfiCallMode::Sync,
+            debug_name: "open_session",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            frb_init();
+            let api_user = user.wire2api();
+            move |task_callback| Ok(open_session(api_user))
         },
-        move || sync_option_rust_opaque(),
     )
 }
-fn wire_sync_option_dart_opaque_impl(
-    opaque: impl Wire2Api<DartOpaque> + UnwindSafe,
-) -> support::WireSyncReturn {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync(
+fn wire_describe_session_impl(port_: MessagePort, session: impl Wire2Api<Session> + UnwindSafe) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
-            debug_name: "sync_option_dart_opaque",
-            port: None,
-            mode: FfiCallMode::Sync,
+            debug_name: "describe_session",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
         },
         move || {
-            let api_opaque = opaque.wire2api();
-            sync_option_dart_opaque(api_opaque)
+            frb_init();
+            let api_session = session.wire2api();
+            move |task_callback| Ok(describe_session(api_session))
         },
     )
 }
-fn wire_sync_void_impl() -> support::WireSyncReturn {
+fn wire_sample_at_impl(port_: MessagePort, index: impl Wire2Api<u32> + UnwindSafe) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "sample_at",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            frb_init();
+            let api_index = index.wire2api();
+            move |task_callback| Ok(sample_at(api_index))
+        },
+    )
+}
+fn wire_shift_sample_impl(
+    port_: MessagePort,
+    sample: impl Wire2Api<Sample> + UnwindSafe,
+    dx: impl Wire2Api<f64> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "shift_sample",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            frb_init();
+            let api_sample = sample.wire2api();
+            let api_dx = dx.wire2api();
+            move |task_callback| Ok(shift_sample(api_sample, api_dx))
+        },
+    )
+}
+fn wire_tag_label_impl(port_: MessagePort, tag: impl Wire2Api<Tag> + UnwindSafe) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "tag_label",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            frb_init();
+            let api_tag = tag.wire2api();
+            move |task_callback| Ok(tag_label(api_tag))
+        },
+    )
+}
+fn wire_header_name_impl(port_: MessagePort, raw: impl Wire2Api<Vec<u8>> + UnwindSafe) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "header_name",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            frb_init();
+            let api_raw = raw.wire2api();
+            move |task_callback| Ok(header_name(api_raw))
+        },
+    )
+}
+fn wire_multiply_by_ten_impl(port_: MessagePort, measure: impl Wire2Api<Measure> + UnwindSafe) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "multiply_by_ten",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            frb_init();
+            let api_measure = measure.wire2api();
+            move |task_callback| Ok(multiply_by_ten(api_measure))
+        },
+    )
+}
+fn wire_call_old_module_system_impl(port_: MessagePort) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "call_old_module_system",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            frb_init();
+            move |task_callback| Ok(call_old_module_system())
+        },
+    )
+}
+fn wire_call_new_module_system_impl(port_: MessagePort) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "call_new_module_system",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            frb_init();
+            move |task_callback| Ok(call_new_module_system())
+        },
+    )
+}
+fn wire_handle_big_buffers_impl(port_: MessagePort) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "handle_big_buffers",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            frb_init();
+            move |task_callback| Ok(handle_big_buffers())
+        },
+    )
+}
+fn wire_handle_i128_impl(port_: MessagePort, value: impl Wire2Api<i128> + UnwindSafe) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "handle_i128",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            frb_init();
+            let api_value = value.wire2api();
+            move |task_callback| Ok(handle_i128(api_value).to_le_bytes().to_vec())
+        },
+    )
+}
+fn wire_handle_u128_impl(port_: MessagePort, value: impl Wire2Api<u128> + UnwindSafe) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "handle_u128",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            frb_init();
+            let api_value = value.wire2api();
+            move |task_callback| Ok(handle_u128(api_value).to_le_bytes().to_vec())
+        },
+    )
+}
+fn wire_datetime_utc_impl(
+    port_: MessagePort,
+    d: impl Wire2Api<chrono::DateTime<chrono::Utc>> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "datetime_utc",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            frb_init();
+            let api_d = d.wire2api();
+            move |task_callback| Ok(datetime_utc(api_d))
+        },
+    )
+}
+fn wire_datetime_local_impl(
+    port_: MessagePort,
+    d: impl Wire2Api<chrono::DateTime<chrono::Local>> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "datetime_local",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            frb_init();
+            let api_d = d.wire2api();
+            move |task_callback| Ok(datetime_local(api_d))
+        },
+    )
+}
+fn wire_naivedatetime_impl(
+    port_: MessagePort,
+    d: impl Wire2Api<chrono::NaiveDateTime> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "naivedatetime",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            frb_init();
+            let api_d = d.wire2api();
+            move |task_callback| Ok(naivedatetime(api_d))
+        },
+    )
+}
+fn wire_optional_empty_datetime_utc_impl(
+    port_: MessagePort,
+    d: impl Wire2Api<Option<chrono::DateTime<chrono::Utc>>> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "optional_empty_datetime_utc",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            frb_init();
+            let api_d = d.wire2api();
+            move |task_callback| Ok(optional_empty_datetime_utc(api_d))
+        },
+    )
+}
+fn wire_duration_impl(port_: MessagePort, d: impl Wire2Api<chrono::Duration> + UnwindSafe) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "duration",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            frb_init();
+            let api_d = d.wire2api();
+            move |task_callback| Ok(duration(api_d))
+        },
+    )
+}
+fn wire_datetime_fixed_offset_impl(
+    port_: MessagePort,
+    d: impl Wire2Api<chrono::DateTime<chrono::FixedOffset>> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "datetime_fixed_offset",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            frb_init();
+            let api_d = d.wire2api();
+            move |task_callback| {
+                Ok(chrono::DateTime::<chrono::Utc>::from(
+                    datetime_fixed_offset(api_d),
+                ))
+            }
+        },
+    )
+}
+fn wire_test_chrono_impl(port_: MessagePort) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "test_chrono",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            frb_init();
+            move |task_callback| Ok(test_chrono())
+        },
+    )
+}
+fn wire_test_precise_chrono_impl(port_: MessagePort) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "test_precise_chrono",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            frb_init();
+            move |task_callback| Ok(test_precise_chrono())
+        },
+    )
+}
+fn wire_how_long_does_it_take_impl(
+    port_: MessagePort,
+    mine: impl Wire2Api<FeatureChrono> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "how_long_does_it_take",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            frb_init();
+            let api_mine = mine.wire2api();
+            move |task_callback| how_long_does_it_take(api_mine)
+        },
+    )
+}
+fn wire_handle_uuid_impl(port_: MessagePort, id: impl Wire2Api<uuid::Uuid> + UnwindSafe) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "handle_uuid",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            frb_init();
+            let api_id = id.wire2api();
+            move |task_callback| handle_uuid(api_id)
+        },
+    )
+}
+fn wire_handle_uuids_impl(port_: MessagePort, ids: impl Wire2Api<Vec<uuid::Uuid>> + UnwindSafe) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "handle_uuids",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            frb_init();
+            let api_ids = ids.wire2api();
+            move |task_callback| handle_uuids(api_ids)
+        },
+    )
+}
+fn wire_handle_nested_uuids_impl(port_: MessagePort, ids: impl Wire2Api<FeatureUuid> + UnwindSafe) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "handle_nested_uuids",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            frb_init();
+            let api_ids = ids.wire2api();
+            move |task_callback| handle_nested_uuids(api_ids)
+        },
+    )
+}
+fn wire_handle_decimal_impl(
+    port_: MessagePort,
+    amount: impl Wire2Api<rust_decimal::Decimal> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "handle_decimal",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            frb_init();
+            let api_amount = amount.wire2api();
+            move |task_callback| Ok(handle_decimal(api_amount).to_string())
+        },
+    )
+}
+fn wire_sum_decimals_impl(
+    port_: MessagePort,
+    amounts: impl Wire2Api<Vec<rust_decimal::Decimal>> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "sum_decimals",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            frb_init();
+            let api_amounts = amounts.wire2api();
+            move |task_callback| Ok(sum_decimals(api_amounts).to_string())
+        },
+    )
+}
+fn wire_new_msgid_impl(port_: MessagePort, id: impl Wire2Api<[u8; 32]> + UnwindSafe) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "new_msgid",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            frb_init();
+            let api_id = id.wire2api();
+            move |task_callback| Ok(new_msgid(api_id))
+        },
+    )
+}
+fn wire_use_msgid_impl(port_: MessagePort, id: impl Wire2Api<MessageId> + UnwindSafe) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "use_msgid",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            frb_init();
+            let api_id = id.wire2api();
+            move |task_callback| Ok(use_msgid(api_id))
+        },
+    )
+}
+fn wire_boxed_blob_impl(port_: MessagePort, blob: impl Wire2Api<Box<[u8; 1600]>> + UnwindSafe) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "boxed_blob",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            frb_init();
+            let api_blob = blob.wire2api();
+            move |task_callback| Ok(boxed_blob(api_blob))
+        },
+    )
+}
+fn wire_use_boxed_blob_impl(port_: MessagePort, blob: impl Wire2Api<Box<Blob>> + UnwindSafe) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "use_boxed_blob",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            frb_init();
+            let api_blob = blob.wire2api();
+            move |task_callback| Ok(use_boxed_blob(api_blob))
+        },
+    )
+}
+fn wire_return_boxed_feed_id_impl(port_: MessagePort, id: impl Wire2Api<[u8; 8]> + UnwindSafe) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "return_boxed_feed_id",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            frb_init();
+            let api_id = id.wire2api();
+            move |task_callback| Ok((*return_boxed_feed_id(api_id)))
+        },
+    )
+}
+fn wire_return_boxed_raw_feed_id_impl(port_: MessagePort, id: impl Wire2Api<FeedId> + UnwindSafe) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "return_boxed_raw_feed_id",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            frb_init();
+            let api_id = id.wire2api();
+            move |task_callback| Ok((*return_boxed_raw_feed_id(api_id)))
+        },
+    )
+}
+fn wire_test_id_impl(port_: MessagePort, id: impl Wire2Api<TestId> + UnwindSafe) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "test_id",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            frb_init();
+            let api_id = id.wire2api();
+            move |task_callback| Ok(test_id(api_id))
+        },
+    )
+}
+fn wire_last_number_impl(port_: MessagePort, array: impl Wire2Api<[f64; 16]> + UnwindSafe) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "last_number",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            frb_init();
+            let api_array = array.wire2api();
+            move |task_callback| Ok(last_number(api_array))
+        },
+    )
+}
+fn wire_repeat_byte_4_impl(port_: MessagePort, byte: impl Wire2Api<u8> + UnwindSafe) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "repeat_byte_4",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            frb_init();
+            let api_byte = byte.wire2api();
+            move |task_callback| Ok(repeat_byte::<4>(api_byte))
+        },
+    )
+}
+fn wire_repeat_byte_8_impl(port_: MessagePort, byte: impl Wire2Api<u8> + UnwindSafe) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "repeat_byte_8",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            frb_init();
+            let api_byte = byte.wire2api();
+            move |task_callback| Ok(repeat_byte::<8>(api_byte))
+        },
+    )
+}
+fn wire_transpose_impl(port_: MessagePort, matrix: impl Wire2Api<[f64; 16]> + UnwindSafe) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "transpose",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            frb_init();
+            let api_matrix = matrix.wire2api();
+            move |task_callback| Ok(transpose(api_matrix))
+        },
+    )
+}
+fn wire_nested_id_impl(port_: MessagePort, id: impl Wire2Api<[TestId; 4]> + UnwindSafe) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "nested_id",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            frb_init();
+            let api_id = id.wire2api();
+            move |task_callback| Ok(nested_id(api_id))
+        },
+    )
+}
+fn wire_sync_accept_dart_opaque_impl(
+    opaque: impl Wire2Api<DartOpaque> + UnwindSafe,
+) -> support::WireSyncReturn {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync(
+        WrapInfo {
+            debug_name: "sync_accept_dart_opaque",
+            port: None,
+            mode: FfiCallMode::Sync,
+        },
+        move || {
+            frb_init();
+            let api_opaque = opaque.wire2api();
+            Ok(sync_accept_dart_opaque(api_opaque))
+        },
+    )
+}
+fn wire_async_accept_dart_opaque_impl(
+    port_: MessagePort,
+    opaque: impl Wire2Api<DartOpaque> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "async_accept_dart_opaque",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            frb_init();
+            let api_opaque = opaque.wire2api();
+            move |task_callback| Ok(async_accept_dart_opaque(api_opaque))
+        },
+    )
+}
+fn wire_loop_back_impl(port_: MessagePort, opaque: impl Wire2Api<DartOpaque> + UnwindSafe) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "loop_back",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            frb_init();
+            let api_opaque = opaque.wire2api();
+            move |task_callback| Ok(loop_back(api_opaque))
+        },
+    )
+}
+fn wire_loop_back_option_impl(port_: MessagePort, opaque: impl Wire2Api<DartOpaque> + UnwindSafe) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "loop_back_option",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            frb_init();
+            let api_opaque = opaque.wire2api();
+            move |task_callback| Ok(loop_back_option(api_opaque))
+        },
+    )
+}
+fn wire_loop_back_array_impl(port_: MessagePort, opaque: impl Wire2Api<DartOpaque> + UnwindSafe) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "loop_back_array",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            frb_init();
+            let api_opaque = opaque.wire2api();
+            move |task_callback| Ok(loop_back_array(api_opaque))
+        },
+    )
+}
+fn wire_loop_back_vec_impl(port_: MessagePort, opaque: impl Wire2Api<DartOpaque> + UnwindSafe) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "loop_back_vec",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            frb_init();
+            let api_opaque = opaque.wire2api();
+            move |task_callback| Ok(loop_back_vec(api_opaque))
+        },
+    )
+}
+fn wire_loop_back_option_get_impl(
+    port_: MessagePort,
+    opaque: impl Wire2Api<Option<DartOpaque>> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "loop_back_option_get",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            frb_init();
+            let api_opaque = opaque.wire2api();
+            move |task_callback| Ok(loop_back_option_get(api_opaque))
+        },
+    )
+}
+fn wire_loop_back_array_get_impl(
+    port_: MessagePort,
+    opaque: impl Wire2Api<[DartOpaque; 1]> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "loop_back_array_get",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            frb_init();
+            let api_opaque = opaque.wire2api();
+            move |task_callback| Ok(loop_back_array_get(api_opaque))
+        },
+    )
+}
+fn wire_loop_back_vec_get_impl(
+    port_: MessagePort,
+    opaque: impl Wire2Api<Vec<DartOpaque>> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "loop_back_vec_get",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            frb_init();
+            let api_opaque = opaque.wire2api();
+            move |task_callback| Ok(loop_back_vec_get(api_opaque))
+        },
+    )
+}
+fn wire_unwrap_dart_opaque_impl(
+    opaque: impl Wire2Api<DartOpaque> + UnwindSafe,
+) -> support::WireSyncReturn {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync(
+        WrapInfo {
+            debug_name: "unwrap_dart_opaque",
+            port: None,
+            mode: FfiCallMode::Sync,
+        },
+        move || {
+            frb_init();
+            let api_opaque = opaque.wire2api();
+            Ok(unwrap_dart_opaque(api_opaque))
+        },
+    )
+}
+fn wire_panic_unwrap_dart_opaque_impl(
+    port_: MessagePort,
+    opaque: impl Wire2Api<DartOpaque> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "panic_unwrap_dart_opaque",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            frb_init();
+            let api_opaque = opaque.wire2api();
+            move |task_callback| Ok(panic_unwrap_dart_opaque(api_opaque))
+        },
+    )
+}
+fn wire_create_opaque_impl(port_: MessagePort) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "create_opaque",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            frb_init();
+            move |task_callback| Ok(create_opaque())
+        },
+    )
+}
+fn wire_create_option_opaque_impl(
+    port_: MessagePort,
+    opaque: impl Wire2Api<Option<RustOpaque<HideData>>> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "create_option_opaque",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            frb_init();
+            let api_opaque = opaque.wire2api();
+            move |task_callback| Ok(create_option_opaque(api_opaque))
+        },
+    )
+}
+fn wire_sync_create_opaque_impl() -> support::WireSyncReturn {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync(
+        WrapInfo {
+            debug_name: "sync_create_opaque",
+            port: None,
+            mode: FfiCallMode::Sync,
+        },
+        move || {
+            frb_init();
+            Ok(sync_create_opaque())
+        },
+    )
+}
+fn wire_create_array_opaque_enum_impl(port_: MessagePort) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "create_array_opaque_enum",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            frb_init();
+            move |task_callback| Ok(create_array_opaque_enum())
+        },
+    )
+}
+fn wire_run_enum_opaque_impl(port_: MessagePort, opaque: impl Wire2Api<EnumOpaque> + UnwindSafe) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "run_enum_opaque",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            frb_init();
+            let api_opaque = opaque.wire2api();
+            move |task_callback| Ok(run_enum_opaque(api_opaque))
+        },
+    )
+}
+fn wire_create_trait_object_impl(port_: MessagePort) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "create_trait_object",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            frb_init();
+            move |task_callback| Ok(RustOpaque::new(create_trait_object()))
+        },
+    )
+}
+fn wire_create_impl_trait_impl(port_: MessagePort) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "create_impl_trait",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            frb_init();
+            move |task_callback| {
+                Ok(RustOpaque::new(
+                    Box::new(create_impl_trait()) as Box<dyn DartDebug>
+                ))
+            }
+        },
+    )
+}
+fn wire_run_trait_object_impl(
+    port_: MessagePort,
+    obj: impl Wire2Api<RustOpaque<Box<dyn DartDebug>>> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "run_trait_object",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            frb_init();
+            let api_obj = obj.wire2api();
+            move |task_callback| Ok(run_trait_object(&**api_obj))
+        },
+    )
+}
+fn wire_run_opaque_impl(
+    port_: MessagePort,
+    opaque: impl Wire2Api<RustOpaque<HideData>> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "run_opaque",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            frb_init();
+            let api_opaque = opaque.wire2api();
+            move |task_callback| Ok(run_opaque(api_opaque))
+        },
+    )
+}
+fn wire_create_shared_opaque_impl(port_: MessagePort) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "create_shared_opaque",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            frb_init();
+            move |task_callback| Ok(RustOpaque::from(create_shared_opaque()))
+        },
+    )
+}
+fn wire_run_shared_opaque_impl(
+    port_: MessagePort,
+    opaque: impl Wire2Api<std::sync::Arc<HideData>> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "run_shared_opaque",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            frb_init();
+            let api_opaque = opaque.wire2api();
+            move |task_callback| Ok(run_shared_opaque(api_opaque))
+        },
+    )
+}
+fn wire_shared_opaque_address_impl(
+    port_: MessagePort,
+    opaque: impl Wire2Api<std::sync::Arc<HideData>> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "shared_opaque_address",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            frb_init();
+            let api_opaque = opaque.wire2api();
+            move |task_callback| Ok(shared_opaque_address(api_opaque))
+        },
+    )
+}
+fn wire_run_opaque_with_delay_impl(
+    port_: MessagePort,
+    opaque: impl Wire2Api<RustOpaque<HideData>> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "run_opaque_with_delay",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            frb_init();
+            let api_opaque = opaque.wire2api();
+            move |task_callback| Ok(run_opaque_with_delay(api_opaque))
+        },
+    )
+}
+fn wire_opaque_array_impl(port_: MessagePort) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "opaque_array",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            frb_init();
+            move |task_callback| Ok(opaque_array())
+        },
+    )
+}
+fn wire_create_sync_opaque_impl(port_: MessagePort) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "create_sync_opaque",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            frb_init();
+            move |task_callback| Ok(create_sync_opaque())
+        },
+    )
+}
+fn wire_sync_create_sync_opaque_impl() -> support::WireSyncReturn {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync(
+        WrapInfo {
+            debug_name: "sync_create_sync_opaque",
+            port: None,
+            mode: FfiCallMode::Sync,
+        },
+        move || {
+            frb_init();
+            Ok(sync_create_sync_opaque())
+        },
+    )
+}
+fn wire_sync_run_opaque_impl(
+    opaque: impl Wire2Api<RustOpaque<NonSendHideData>> + UnwindSafe,
+) -> support::WireSyncReturn {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync(
+        WrapInfo {
+            debug_name: "sync_run_opaque",
+            port: None,
+            mode: FfiCallMode::Sync,
+        },
+        move || {
+            frb_init();
+            let api_opaque = opaque.wire2api();
+            Ok(sync_run_opaque(api_opaque))
+        },
+    )
+}
+fn wire_opaque_array_run_impl(
+    port_: MessagePort,
+    data: impl Wire2Api<[RustOpaque<HideData>; 2]> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "opaque_array_run",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            frb_init();
+            let api_data = data.wire2api();
+            move |task_callback| Ok(opaque_array_run(api_data))
+        },
+    )
+}
+fn wire_opaque_vec_impl(port_: MessagePort) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "opaque_vec",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            frb_init();
+            move |task_callback| Ok(opaque_vec())
+        },
+    )
+}
+fn wire_opaque_vec_run_impl(
+    port_: MessagePort,
+    data: impl Wire2Api<Vec<RustOpaque<HideData>>> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "opaque_vec_run",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            frb_init();
+            let api_data = data.wire2api();
+            move |task_callback| Ok(opaque_vec_run(api_data))
+        },
+    )
+}
+fn wire_create_nested_opaque_impl(port_: MessagePort) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "create_nested_opaque",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            frb_init();
+            move |task_callback| Ok(create_nested_opaque())
+        },
+    )
+}
+fn wire_sync_loopback_impl(
+    opaque: impl Wire2Api<DartOpaque> + UnwindSafe,
+) -> support::WireSyncReturn {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync(
+        WrapInfo {
+            debug_name: "sync_loopback",
+            port: None,
+            mode: FfiCallMode::Sync,
+        },
+        move || {
+            frb_init();
+            let api_opaque = opaque.wire2api();
+            Ok(sync_loopback(api_opaque))
+        },
+    )
+}
+fn wire_sync_option_loopback_impl(
+    opaque: impl Wire2Api<Option<DartOpaque>> + UnwindSafe,
+) -> support::WireSyncReturn {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync(
+        WrapInfo {
+            debug_name: "sync_option_loopback",
+            port: None,
+            mode: FfiCallMode::Sync,
+        },
+        move || {
+            frb_init();
+            let api_opaque = opaque.wire2api();
+            Ok(sync_option_loopback(api_opaque))
+        },
+    )
+}
+fn wire_sync_option_impl() -> support::WireSyncReturn {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync(
+        WrapInfo {
+            debug_name: "sync_option",
+            port: None,
+            mode: FfiCallMode::Sync,
+        },
+        move || {
+            frb_init();
+            sync_option()
+        },
+    )
+}
+fn wire_sync_option_null_impl() -> support::WireSyncReturn {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync(
+        WrapInfo {
+            debug_name: "sync_option_null",
+            port: None,
+            mode: FfiCallMode::Sync,
+        },
+        move || {
+            frb_init();
+            sync_option_null()
+        },
+    )
+}
+fn wire_sync_option_rust_opaque_impl() -> support::WireSyncReturn {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync(
+        WrapInfo {
+            debug_name: "sync_option_rust_opaque",
+            port: None,
+            mode: FfiCallMode::Sync,
+        },
+        move || {
+            frb_init();
+            sync_option_rust_opaque()
+        },
+    )
+}
+fn wire_sync_option_dart_opaque_impl(
+    opaque: impl Wire2Api<DartOpaque> + UnwindSafe,
+) -> support::WireSyncReturn {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync(
+        WrapInfo {
+            debug_name: "sync_option_dart_opaque",
+            port: None,
+            mode: FfiCallMode::Sync,
+        },
+        move || {
+            frb_init();
+            let api_opaque = opaque.wire2api();
+            sync_option_dart_opaque(api_opaque)
+        },
+    )
+}
+fn wire_sync_void_impl() -> support::WireSyncReturn {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync(
+        WrapInfo {
+            debug_name: "sync_void",
+            port: None,
+            mode: FfiCallMode::Sync,
+        },
+        move || {
+            frb_init();
+            Ok(sync_void())
+        },
+    )
+}
+fn wire_run_nested_opaque_impl(
+    port_: MessagePort,
+    opaque: impl Wire2Api<OpaqueNested> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "run_nested_opaque",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            frb_init();
+            let api_opaque = opaque.wire2api();
+            move |task_callback| Ok(run_nested_opaque(api_opaque))
+        },
+    )
+}
+fn wire_create_nested_dart_opaque_impl(
+    port_: MessagePort,
+    opaque1: impl Wire2Api<DartOpaque> + UnwindSafe,
+    opaque2: impl Wire2Api<DartOpaque> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "create_nested_dart_opaque",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            frb_init();
+            let api_opaque1 = opaque1.wire2api();
+            let api_opaque2 = opaque2.wire2api();
+            move |task_callback| Ok(create_nested_dart_opaque(api_opaque1, api_opaque2))
+        },
+    )
+}
+fn wire_get_nested_dart_opaque_impl(
+    port_: MessagePort,
+    opaque: impl Wire2Api<DartOpaqueNested> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "get_nested_dart_opaque",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            frb_init();
+            let api_opaque = opaque.wire2api();
+            move |task_callback| Ok(get_nested_dart_opaque(api_opaque))
+        },
+    )
+}
+fn wire_create_enum_dart_opaque_impl(
+    port_: MessagePort,
+    opaque: impl Wire2Api<DartOpaque> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "create_enum_dart_opaque",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            frb_init();
+            let api_opaque = opaque.wire2api();
+            move |task_callback| Ok(create_enum_dart_opaque(api_opaque))
+        },
+    )
+}
+fn wire_get_enum_dart_opaque_impl(
+    port_: MessagePort,
+    opaque: impl Wire2Api<EnumDartOpaque> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "get_enum_dart_opaque",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            frb_init();
+            let api_opaque = opaque.wire2api();
+            move |task_callback| Ok(get_enum_dart_opaque(api_opaque))
+        },
+    )
+}
+fn wire_set_static_dart_opaque_impl(
+    port_: MessagePort,
+    opaque: impl Wire2Api<DartOpaque> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "set_static_dart_opaque",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            frb_init();
+            let api_opaque = opaque.wire2api();
+            move |task_callback| Ok(set_static_dart_opaque(api_opaque))
+        },
+    )
+}
+fn wire_drop_static_dart_opaque_impl(port_: MessagePort) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "drop_static_dart_opaque",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            frb_init();
+            move |task_callback| Ok(drop_static_dart_opaque())
+        },
+    )
+}
+fn wire_unwrap_rust_opaque_impl(
+    port_: MessagePort,
+    opaque: impl Wire2Api<RustOpaque<HideData>> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "unwrap_rust_opaque",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            frb_init();
+            let api_opaque = opaque.wire2api();
+            move |task_callback| unwrap_rust_opaque(api_opaque)
+        },
+    )
+}
+fn wire_return_non_droppable_dart_opaque_impl(
+    opaque: impl Wire2Api<DartOpaque> + UnwindSafe,
+) -> support::WireSyncReturn {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync(
+        WrapInfo {
+            debug_name: "return_non_droppable_dart_opaque",
+            port: None,
+            mode: FfiCallMode::Sync,
+        },
+        move || {
+            frb_init();
+            let api_opaque = opaque.wire2api();
+            Ok(return_non_droppable_dart_opaque(api_opaque))
+        },
+    )
+}
+fn wire_frb_generator_test_impl(port_: MessagePort) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "frb_generator_test",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            frb_init();
+            move |task_callback| Ok(frb_generator_test())
+        },
+    )
+}
+fn wire_frb_sync_generator_test_impl() -> support::WireSyncReturn {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync(
         WrapInfo {
-            debug_name: "sync_void",
-            port: None,
-            mode: FfiCallMode::Sync,
+            debug_name: "frb_sync_generator_test",
+            port: None,
+            mode: FfiCallMode::Sync,
+        },
+        move || {
+            frb_init();
+            Ok(frb_sync_generator_test())
+        },
+    )
+}
+fn wire_observe_counter_impl(
+    port_: MessagePort,
+    counter: impl Wire2Api<RustOpaque<Counter>> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "observe_counter",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            frb_init();
+            let api_counter = counter.wire2api();
+            move |task_callback| Ok(RustOpaque::new(observe_counter(api_counter)))
+        },
+    )
+}
+fn wire_is_counter_alive_impl(
+    port_: MessagePort,
+    counter: impl Wire2Api<std::sync::Weak<Counter>> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "is_counter_alive",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            frb_init();
+            let api_counter = counter.wire2api();
+            move |task_callback| Ok(is_counter_alive(api_counter))
+        },
+    )
+}
+fn wire_handle_type_alias_id_impl(port_: MessagePort, input: impl Wire2Api<u64> + UnwindSafe) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "handle_type_alias_id",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            frb_init();
+            let api_input = input.wire2api();
+            move |task_callback| Ok(handle_type_alias_id(api_input))
+        },
+    )
+}
+fn wire_handle_type_nest_alias_id_impl(port_: MessagePort, input: impl Wire2Api<u64> + UnwindSafe) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "handle_type_nest_alias_id",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            frb_init();
+            let api_input = input.wire2api();
+            move |task_callback| Ok(handle_type_nest_alias_id(api_input))
+        },
+    )
+}
+fn wire_handle_type_alias_model_impl(port_: MessagePort, input: impl Wire2Api<u64> + UnwindSafe) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "handle_type_alias_model",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            frb_init();
+            let api_input = input.wire2api();
+            move |task_callback| Ok(handle_type_alias_model(api_input))
+        },
+    )
+}
+fn wire_empty_struct_impl(port_: MessagePort, empty: impl Wire2Api<Empty> + UnwindSafe) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "empty_struct",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            frb_init();
+            let api_empty = empty.wire2api();
+            move |task_callback| Ok(empty_struct(api_empty))
+        },
+    )
+}
+fn wire_handle_hash_map_impl(
+    port_: MessagePort,
+    map: impl Wire2Api<std::collections::HashMap<String, i32>> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "handle_hash_map",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            frb_init();
+            let api_map = map.wire2api();
+            move |task_callback| Ok(mirror_hash_map_String_i32(handle_hash_map(api_map)))
+        },
+    )
+}
+fn wire_handle_hash_map_of_struct_impl(
+    port_: MessagePort,
+    map: impl Wire2Api<std::collections::HashMap<u32, Attribute>> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "handle_hash_map_of_struct",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            frb_init();
+            let api_map = map.wire2api();
+            move |task_callback| {
+                Ok(mirror_hash_map_u32_attribute(handle_hash_map_of_struct(
+                    api_map,
+                )))
+            }
+        },
+    )
+}
+fn wire_count_weekdays_impl(port_: MessagePort, days: impl Wire2Api<Vec<Weekdays>> + UnwindSafe) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "count_weekdays",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            frb_init();
+            let api_days = days.wire2api();
+            move |task_callback| Ok(mirror_hash_map_weekdays_u32(count_weekdays(api_days)))
+        },
+    )
+}
+fn wire_index_words_impl(port_: MessagePort, words: impl Wire2Api<Vec<String>> + UnwindSafe) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "index_words",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            frb_init();
+            let api_words = words.wire2api();
+            move |task_callback| Ok(mirror_hash_map_usize_String(index_words(api_words)))
+        },
+    )
+}
+fn wire_handle_colliding_keys_impl(
+    port_: MessagePort,
+    map: impl Wire2Api<std::collections::HashMap<CollidingKey, u32>> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "handle_colliding_keys",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            frb_init();
+            let api_map = map.wire2api();
+            move |task_callback| {
+                Ok(mirror_hash_map_colliding_key_u32(handle_colliding_keys(
+                    api_map,
+                )))
+            }
+        },
+    )
+}
+fn wire_handle_btree_set_impl(
+    port_: MessagePort,
+    set: impl Wire2Api<std::collections::BTreeSet<String>> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "handle_btree_set",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            frb_init();
+            let api_set = set.wire2api();
+            move |task_callback| Ok(mirror_btree_set_String(handle_btree_set(api_set)))
+        },
+    )
+}
+fn wire_handle_btree_map_impl(
+    port_: MessagePort,
+    map: impl Wire2Api<std::collections::BTreeMap<i32, String>> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "handle_btree_map",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            frb_init();
+            let api_map = map.wire2api();
+            move |task_callback| Ok(mirror_btree_map_i32_String(handle_btree_map(api_map)))
+        },
+    )
+}
+fn wire_group_attributes_impl(
+    port_: MessagePort,
+    attributes: impl Wire2Api<Vec<Attribute>> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "group_attributes",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            frb_init();
+            let api_attributes = attributes.wire2api();
+            move |task_callback| {
+                Ok(mirror_btree_map_String_list_attribute(group_attributes(
+                    api_attributes,
+                )))
+            }
+        },
+    )
+}
+fn wire_handle_tuple_impl(
+    port_: MessagePort,
+    tuple: impl Wire2Api<(i32, String, bool)> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "handle_tuple",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            frb_init();
+            let api_tuple = tuple.wire2api();
+            move |task_callback| Ok(mirror_tuple3_bool_String_i32(handle_tuple(api_tuple)))
+        },
+    )
+}
+fn wire_handle_nested_tuple_impl(
+    port_: MessagePort,
+    tuple: impl Wire2Api<(i32, (String, bool))> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "handle_nested_tuple",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            frb_init();
+            let api_tuple = tuple.wire2api();
+            move |task_callback| {
+                Ok(mirror_tuple2_tuple2_String_bool_i32(handle_nested_tuple(
+                    api_tuple,
+                )))
+            }
+        },
+    )
+}
+fn wire_sum__method__SumWith_impl(
+    port_: MessagePort,
+    that: impl Wire2Api<SumWith> + UnwindSafe,
+    y: impl Wire2Api<u32> + UnwindSafe,
+    z: impl Wire2Api<u32> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "sum__method__SumWith",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            frb_init();
+            let api_that = that.wire2api();
+            let api_y = y.wire2api();
+            let api_z = z.wire2api();
+            move |task_callback| Ok(SumWith::sum(&api_that, api_y, api_z))
+        },
+    )
+}
+fn wire_new__static_method__ConcatenateWith_impl(
+    port_: MessagePort,
+    a: impl Wire2Api<String> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "new__static_method__ConcatenateWith",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            frb_init();
+            let api_a = a.wire2api();
+            move |task_callback| Ok(ConcatenateWith::new(api_a))
+        },
+    )
+}
+fn wire_concatenate__method__ConcatenateWith_impl(
+    port_: MessagePort,
+    that: impl Wire2Api<ConcatenateWith> + UnwindSafe,
+    b: impl Wire2Api<String> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "concatenate__method__ConcatenateWith",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            frb_init();
+            let api_that = that.wire2api();
+            let api_b = b.wire2api();
+            move |task_callback| Ok(ConcatenateWith::concatenate(&api_that, api_b))
+        },
+    )
+}
+fn wire_concatenate_static__static_method__ConcatenateWith_impl(
+    port_: MessagePort,
+    a: impl Wire2Api<String> + UnwindSafe,
+    b: impl Wire2Api<String> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "concatenate_static__static_method__ConcatenateWith",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            frb_init();
+            let api_a = a.wire2api();
+            let api_b = b.wire2api();
+            move |task_callback| Ok(ConcatenateWith::concatenate_static(api_a, api_b))
+        },
+    )
+}
+fn wire_handle_some_stream_sink__method__ConcatenateWith_impl(
+    port_: MessagePort,
+    that: impl Wire2Api<ConcatenateWith> + UnwindSafe,
+    key: impl Wire2Api<u32> + UnwindSafe,
+    max: impl Wire2Api<u32> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "handle_some_stream_sink__method__ConcatenateWith",
+            port: Some(port_),
+            mode: FfiCallMode::Stream,
+        },
+        move || {
+            frb_init();
+            let api_that = that.wire2api();
+            let api_key = key.wire2api();
+            let api_max = max.wire2api();
+            move |task_callback| {
+                Ok(ConcatenateWith::handle_some_stream_sink(
+                    &api_that,
+                    api_key,
+                    api_max,
+                    task_callback.stream_sink(),
+                ))
+            }
+        },
+    )
+}
+fn wire_handle_some_stream_sink_at_1__method__ConcatenateWith_impl(
+    port_: MessagePort,
+    that: impl Wire2Api<ConcatenateWith> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "handle_some_stream_sink_at_1__method__ConcatenateWith",
+            port: Some(port_),
+            mode: FfiCallMode::Stream,
+        },
+        move || {
+            frb_init();
+            let api_that = that.wire2api();
+            move |task_callback| {
+                Ok(ConcatenateWith::handle_some_stream_sink_at_1(
+                    &api_that,
+                    task_callback.stream_sink(),
+                ))
+            }
+        },
+    )
+}
+fn wire_handle_some_static_stream_sink__static_method__ConcatenateWith_impl(
+    port_: MessagePort,
+    key: impl Wire2Api<u32> + UnwindSafe,
+    max: impl Wire2Api<u32> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "handle_some_static_stream_sink__static_method__ConcatenateWith",
+            port: Some(port_),
+            mode: FfiCallMode::Stream,
+        },
+        move || {
+            frb_init();
+            let api_key = key.wire2api();
+            let api_max = max.wire2api();
+            move |task_callback| {
+                Ok(ConcatenateWith::handle_some_static_stream_sink(
+                    api_key,
+                    api_max,
+                    task_callback.stream_sink(),
+                ))
+            }
+        },
+    )
+}
+fn wire_handle_some_static_stream_sink_single_arg__static_method__ConcatenateWith_impl(
+    port_: MessagePort,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "handle_some_static_stream_sink_single_arg__static_method__ConcatenateWith",
+            port: Some(port_),
+            mode: FfiCallMode::Stream,
+        },
+        move || {
+            frb_init();
+            move |task_callback| {
+                Ok(ConcatenateWith::handle_some_static_stream_sink_single_arg(
+                    task_callback.stream_sink(),
+                ))
+            }
+        },
+    )
+}
+fn wire_start__static_method__Counter_impl(
+    port_: MessagePort,
+    from: impl Wire2Api<i32> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "start__static_method__Counter",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            frb_init();
+            let api_from = from.wire2api();
+            move |task_callback| Ok(Counter::start(api_from))
+        },
+    )
+}
+fn wire_increment__method__Counter_impl(
+    port_: MessagePort,
+    that: impl Wire2Api<RustOpaque<Counter>> + UnwindSafe,
+    by: impl Wire2Api<i32> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "increment__method__Counter",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            frb_init();
+            let api_that = that.wire2api();
+            let api_by = by.wire2api();
+            move |task_callback| Ok(Counter::increment(&api_that, api_by))
+        },
+    )
+}
+fn wire_label__method__Counter_impl(
+    port_: MessagePort,
+    that: impl Wire2Api<RustOpaque<Counter>> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "label__method__Counter",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            frb_init();
+            let api_that = that.wire2api();
+            move |task_callback| Ok(Counter::label(&api_that).to_owned())
+        },
+    )
+}
+fn wire_reset__method__Counter_impl(
+    port_: MessagePort,
+    that: impl Wire2Api<RustOpaque<Counter>> + UnwindSafe,
+    to: impl Wire2Api<i32> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "reset__method__Counter",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            frb_init();
+            let api_that = that.wire2api();
+            let api_to = to.wire2api();
+            move |task_callback| {
+                let output = Counter::reset(&api_that, api_to);
+                if output.is_ok() {
+                    support::notify_changes(&api_that);
+                }
+                output
+            }
+        },
+    )
+}
+fn wire_count_down__method__Counter_impl(
+    port_: MessagePort,
+    that: impl Wire2Api<RustOpaque<Counter>> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "count_down__method__Counter",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            frb_init();
+            let api_that = that.wire2api();
+            move |task_callback| {
+                Ok(RustOpaque::new(DartIterator::borrowing(
+                    (api_that,),
+                    move |(api_that,)| Box::new(Counter::count_down(&api_that)),
+                )))
+            }
+        },
+    )
+}
+fn wire_increment_later__method__Counter_impl(
+    port_: MessagePort,
+    that: impl Wire2Api<RustOpaque<Counter>> + UnwindSafe,
+    by: impl Wire2Api<i32> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async(WrapInfo{ debug_name: "increment_later__method__Counter", port: Some(port_), mode: FfiCallMode::Normal }, move || {
+                            frb_init();let api_that = that.wire2api();let api_by = by.wire2api(); move |task_callback| async move { Ok(Counter::increment_later(&api_that, api_by).await) }
+                        })
+}
+fn wire_updates__method__Counter_impl(
+    port_: MessagePort,
+    that: impl Wire2Api<RustOpaque<Counter>> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "updates__method__Counter",
+            port: Some(port_),
+            mode: FfiCallMode::Stream,
+        },
+        move || {
+            frb_init();
+            let api_that = that.wire2api();
+            move |task_callback| Ok(Counter::updates(&api_that, task_callback.stream_sink()))
         },
-        move || Ok(sync_void()),
     )
 }
-fn wire_run_nested_opaque_impl(
+fn wire_finish__method__Counter_impl(
     port_: MessagePort,
-    opaque: impl Wire2Api<OpaqueNested> + UnwindSafe,
+    that: impl Wire2Api<RustOpaque<Counter>> + UnwindSafe,
 ) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(WrapInfo{ debug_name: "finish__method__Counter", port: Some(port_), mode: FfiCallMode::Normal }, move || {
+                            frb_init();let api_that = that.wire2api(); move |task_callback| Ok(Counter::finish(api_that.try_unwrap().unwrap_or_else(|_| panic!("`Counter::finish` takes `self`, but the object is still shared by other handles"))))
+                        })
+}
+fn wire_open__static_method__Rooms_impl(port_: MessagePort) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
-            debug_name: "run_nested_opaque",
+            debug_name: "open__static_method__Rooms",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
         move || {
-            let api_opaque = opaque.wire2api();
-            move |task_callback| Ok(run_nested_opaque(api_opaque))
+            frb_init();
+            move |task_callback| Ok(Rooms::open())
         },
     )
 }
-fn wire_create_nested_dart_opaque_impl(
+fn wire_join__method__Rooms_impl(
     port_: MessagePort,
-    opaque1: impl Wire2Api<DartOpaque> + UnwindSafe,
-    opaque2: impl Wire2Api<DartOpaque> + UnwindSafe,
+    that: impl Wire2Api<RustOpaque<Rooms>> + UnwindSafe,
+    room: impl Wire2Api<String> + UnwindSafe,
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
-            debug_name: "create_nested_dart_opaque",
+            debug_name: "join__method__Rooms",
             port: Some(port_),
-            mode: FfiCallMode::Normal,
+            mode: FfiCallMode::Stream,
         },
         move || {
-            let api_opaque1 = opaque1.wire2api();
-            let api_opaque2 = opaque2.wire2api();
-            move |task_callback| Ok(create_nested_dart_opaque(api_opaque1, api_opaque2))
+            frb_init();
+            let api_that = that.wire2api();
+            let api_room = room.wire2api();
+            move |task_callback| {
+                Ok(Rooms::join(
+                    &api_that,
+                    api_room,
+                    task_callback.stream_sink(),
+                ))
+            }
         },
     )
 }
-fn wire_get_nested_dart_opaque_impl(
+fn wire_post__method__Rooms_impl(
     port_: MessagePort,
-    opaque: impl Wire2Api<DartOpaqueNested> + UnwindSafe,
+    that: impl Wire2Api<RustOpaque<Rooms>> + UnwindSafe,
+    room: impl Wire2Api<String> + UnwindSafe,
+    message: impl Wire2Api<String> + UnwindSafe,
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
-            debug_name: "get_nested_dart_opaque",
+            debug_name: "post__method__Rooms",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
         move || {
-            let api_opaque = opaque.wire2api();
-            move |task_callback| Ok(get_nested_dart_opaque(api_opaque))
+            frb_init();
+            let api_that = that.wire2api();
+            let api_room = room.wire2api();
+            let api_message = message.wire2api();
+            move |task_callback| Ok(Rooms::post(&api_that, api_room, api_message))
         },
     )
 }
-fn wire_create_enum_dart_opaque_impl(
+fn wire_close_room__method__Rooms_impl(
     port_: MessagePort,
-    opaque: impl Wire2Api<DartOpaque> + UnwindSafe,
+    that: impl Wire2Api<RustOpaque<Rooms>> + UnwindSafe,
+    room: impl Wire2Api<String> + UnwindSafe,
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
-            debug_name: "create_enum_dart_opaque",
+            debug_name: "close_room__method__Rooms",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
         move || {
-            let api_opaque = opaque.wire2api();
-            move |task_callback| Ok(create_enum_dart_opaque(api_opaque))
+            frb_init();
+            let api_that = that.wire2api();
+            let api_room = room.wire2api();
+            move |task_callback| Ok(Rooms::close_room(&api_that, api_room))
         },
     )
 }
-fn wire_get_enum_dart_opaque_impl(
+fn wire_named__static_method__Greeter_impl(
     port_: MessagePort,
-    opaque: impl Wire2Api<EnumDartOpaque> + UnwindSafe,
+    name: impl Wire2Api<String> + UnwindSafe,
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
-            debug_name: "get_enum_dart_opaque",
+            debug_name: "named__static_method__Greeter",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
         move || {
-            let api_opaque = opaque.wire2api();
-            move |task_callback| Ok(get_enum_dart_opaque(api_opaque))
+            frb_init();
+            let api_name = name.wire2api();
+            move |task_callback| Ok(Greeter::named(api_name))
         },
     )
 }
-fn wire_set_static_dart_opaque_impl(
+fn wire_describe__method__Counter_impl(
     port_: MessagePort,
-    opaque: impl Wire2Api<DartOpaque> + UnwindSafe,
+    that: impl Wire2Api<RustOpaque<Counter>> + UnwindSafe,
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
-            debug_name: "set_static_dart_opaque",
+            debug_name: "describe__method__Counter",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
         move || {
-            let api_opaque = opaque.wire2api();
-            move |task_callback| Ok(set_static_dart_opaque(api_opaque))
+            frb_init();
+            let api_that = that.wire2api();
+            move |task_callback| Ok(Counter::describe(&api_that))
         },
     )
 }
-fn wire_drop_static_dart_opaque_impl(port_: MessagePort) {
+fn wire_shout__method__Counter_impl(
+    port_: MessagePort,
+    that: impl Wire2Api<RustOpaque<Counter>> + UnwindSafe,
+) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
-            debug_name: "drop_static_dart_opaque",
+            debug_name: "shout__method__Counter",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
-        move || move |task_callback| Ok(drop_static_dart_opaque()),
+        move || {
+            frb_init();
+            let api_that = that.wire2api();
+            move |task_callback| Ok(Counter::shout(&api_that))
+        },
     )
 }
-fn wire_unwrap_rust_opaque_impl(
+fn wire_describe__method__Greeter_impl(
     port_: MessagePort,
-    opaque: impl Wire2Api<RustOpaque<HideData>> + UnwindSafe,
+    that: impl Wire2Api<RustOpaque<Greeter>> + UnwindSafe,
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
-            debug_name: "unwrap_rust_opaque",
+            debug_name: "describe__method__Greeter",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
         move || {
-            let api_opaque = opaque.wire2api();
-            move |task_callback| unwrap_rust_opaque(api_opaque)
+            frb_init();
+            let api_that = that.wire2api();
+            move |task_callback| Ok(Greeter::describe(&api_that))
         },
     )
 }
-fn wire_return_non_droppable_dart_opaque_impl(
-    opaque: impl Wire2Api<DartOpaque> + UnwindSafe,
-) -> support::WireSyncReturn {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync(
+fn wire_shout__method__Greeter_impl(
+    port_: MessagePort,
+    that: impl Wire2Api<RustOpaque<Greeter>> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
-            debug_name: "return_non_droppable_dart_opaque",
-            port: None,
-            mode: FfiCallMode::Sync,
+            debug_name: "shout__method__Greeter",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
         },
         move || {
-            let api_opaque = opaque.wire2api();
-            Ok(return_non_droppable_dart_opaque(api_opaque))
+            frb_init();
+            let api_that = that.wire2api();
+            move |task_callback| Ok(Greeter::shout(&api_that))
         },
     )
 }
-fn wire_frb_generator_test_impl(port_: MessagePort) {
+fn wire_dispatch_impl(
+    port_: MessagePort,
+    id: impl Wire2Api<String> + UnwindSafe,
+    x: impl Wire2Api<i32> + UnwindSafe,
+) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
-            debug_name: "frb_generator_test",
+            debug_name: "dispatch",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
-        move || move |task_callback| Ok(frb_generator_test()),
-    )
-}
-fn wire_frb_sync_generator_test_impl() -> support::WireSyncReturn {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync(
-        WrapInfo {
-            debug_name: "frb_sync_generator_test",
-            port: None,
-            mode: FfiCallMode::Sync,
+        move || {
+            frb_init();
+            let api_id = id.wire2api();
+            let api_x = x.wire2api();
+            move |task_callback| match api_id.as_str() {
+                "triple" => triple(api_x),
+                "negate" => negate_v2(api_x),
+                id => Err(support::unknown_dispatch_id(id, &["triple", "negate"])),
+            }
         },
-        move || Ok(frb_sync_generator_test()),
     )
 }
-fn wire_handle_type_alias_id_impl(port_: MessagePort, input: impl Wire2Api<u64> + UnwindSafe) {
+fn wire_next_dart_iterator_string_impl(
+    port_: MessagePort,
+    iterator: impl Wire2Api<RustOpaque<DartIterator<String>>> + UnwindSafe,
+) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
-            debug_name: "handle_type_alias_id",
+            debug_name: "next_dart_iterator_string",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
         move || {
-            let api_input = input.wire2api();
-            move |task_callback| Ok(handle_type_alias_id(api_input))
+            frb_init();
+            let api_iterator = iterator.wire2api();
+            move |task_callback| Ok(api_iterator.next_item())
         },
     )
 }
-fn wire_handle_type_nest_alias_id_impl(port_: MessagePort, input: impl Wire2Api<u64> + UnwindSafe) {
+fn wire_next_dart_iterator_i_32_impl(
+    port_: MessagePort,
+    iterator: impl Wire2Api<RustOpaque<DartIterator<i32>>> + UnwindSafe,
+) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
-            debug_name: "handle_type_nest_alias_id",
+            debug_name: "next_dart_iterator_i_32",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
         move || {
-            let api_input = input.wire2api();
-            move |task_callback| Ok(handle_type_nest_alias_id(api_input))
+            frb_init();
+            let api_iterator = iterator.wire2api();
+            move |task_callback| Ok(api_iterator.next_item())
         },
     )
 }
-fn wire_handle_type_alias_model_impl(port_: MessagePort, input: impl Wire2Api<u64> + UnwindSafe) {
+fn wire_changes__method__Counter_impl(
+    port_: MessagePort,
+    that: impl Wire2Api<RustOpaque<Counter>> + UnwindSafe,
+) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
-            debug_name: "handle_type_alias_model",
+            debug_name: "changes__method__Counter",
             port: Some(port_),
-            mode: FfiCallMode::Normal,
+            mode: FfiCallMode::Stream,
         },
         move || {
-            let api_input = input.wire2api();
-            move |task_callback| Ok(handle_type_alias_model(api_input))
+            frb_init();
+            let api_that = that.wire2api();
+            move |task_callback| {
+                Ok(support::listen_changes(
+                    &api_that,
+                    task_callback.stream_sink(),
+                ))
+            }
         },
     )
 }
-fn wire_empty_struct_impl(port_: MessagePort, empty: impl Wire2Api<Empty> + UnwindSafe) {
+fn wire_warm_up_impl(port_: MessagePort) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
-            debug_name: "empty_struct",
+            debug_name: "warm_up",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
         move || {
-            let api_empty = empty.wire2api();
-            move |task_callback| Ok(empty_struct(api_empty))
+            frb_init();
+            move |task_callback| Ok(())
         },
     )
 }
-fn wire_sum__method__SumWith_impl(
+fn wire_upgrade_weak_counter_impl(
     port_: MessagePort,
-    that: impl Wire2Api<SumWith> + UnwindSafe,
-    y: impl Wire2Api<u32> + UnwindSafe,
-    z: impl Wire2Api<u32> + UnwindSafe,
+    weak: impl Wire2Api<RustOpaque<std::sync::Weak<Counter>>> + UnwindSafe,
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
-            debug_name: "sum__method__SumWith",
+            debug_name: "upgrade_weak_counter",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
         move || {
-            let api_that = that.wire2api();
-            let api_y = y.wire2api();
-            let api_z = z.wire2api();
-            move |task_callback| Ok(SumWith::sum(&api_that, api_y, api_z))
+            frb_init();
+            let api_weak = weak.wire2api();
+            move |task_callback| Ok(api_weak.upgrade().map(RustOpaque::from))
         },
     )
 }
-fn wire_new__static_method__ConcatenateWith_impl(
+fn wire_instant_duration_since_impl(
     port_: MessagePort,
-    a: impl Wire2Api<String> + UnwindSafe,
+    that: impl Wire2Api<RustOpaque<std::time::Instant>> + UnwindSafe,
+    earlier: impl Wire2Api<RustOpaque<std::time::Instant>> + UnwindSafe,
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
-            debug_name: "new__static_method__ConcatenateWith",
+            debug_name: "instant_duration_since",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
         move || {
-            let api_a = a.wire2api();
-            move |task_callback| Ok(ConcatenateWith::new(api_a))
+            frb_init();
+            let api_that = that.wire2api();
+            let api_earlier = earlier.wire2api();
+            move |task_callback| Ok(support::duration_since(&api_that, &api_earlier))
         },
     )
 }
-fn wire_concatenate__method__ConcatenateWith_impl(
-    port_: MessagePort,
-    that: impl Wire2Api<ConcatenateWith> + UnwindSafe,
-    b: impl Wire2Api<String> + UnwindSafe,
-) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+fn wire_handle_stream_drain_impl(
+    arg: impl Wire2Api<String> + UnwindSafe,
+) -> support::WireSyncReturn {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync(
         WrapInfo {
-            debug_name: "concatenate__method__ConcatenateWith",
-            port: Some(port_),
-            mode: FfiCallMode::Normal,
+            debug_name: "handle_stream_drain",
+            port: None,
+            mode: FfiCallMode::Sync,
         },
         move || {
-            let api_that = that.wire2api();
-            let api_b = b.wire2api();
-            move |task_callback| Ok(ConcatenateWith::concatenate(&api_that, api_b))
+            frb_init();
+            let api_arg = arg.wire2api();
+            let sink_ = StreamSink::buffered();
+            {
+                handle_stream(sink_.clone(), api_arg);
+                sink_.drain().map(SyncReturn)
+            }
         },
     )
 }
-fn wire_concatenate_static__static_method__ConcatenateWith_impl(
-    port_: MessagePort,
-    a: impl Wire2Api<String> + UnwindSafe,
-    b: impl Wire2Api<String> + UnwindSafe,
-) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+fn wire_handle_stream_of_struct_drain_impl() -> support::WireSyncReturn {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync(
         WrapInfo {
-            debug_name: "concatenate_static__static_method__ConcatenateWith",
-            port: Some(port_),
-            mode: FfiCallMode::Normal,
+            debug_name: "handle_stream_of_struct_drain",
+            port: None,
+            mode: FfiCallMode::Sync,
         },
         move || {
-            let api_a = a.wire2api();
-            let api_b = b.wire2api();
-            move |task_callback| Ok(ConcatenateWith::concatenate_static(api_a, api_b))
+            frb_init();
+            let sink_ = StreamSink::buffered();
+            {
+                handle_stream_of_struct(sink_.clone());
+                sink_.drain().map(SyncReturn)
+            }
         },
     )
 }
-fn wire_handle_some_stream_sink__method__ConcatenateWith_impl(
-    port_: MessagePort,
-    that: impl Wire2Api<ConcatenateWith> + UnwindSafe,
+fn wire_register_event_listener_drain_impl() -> support::WireSyncReturn {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync(
+        WrapInfo {
+            debug_name: "register_event_listener_drain",
+            port: None,
+            mode: FfiCallMode::Sync,
+        },
+        move || {
+            frb_init();
+            let sink_ = StreamSink::buffered();
+            register_event_listener(sink_.clone())
+                .and_then(|_| sink_.drain())
+                .map(SyncReturn)
+        },
+    )
+}
+fn wire_handle_stream_sink_at_1_drain_impl(
     key: impl Wire2Api<u32> + UnwindSafe,
     max: impl Wire2Api<u32> + UnwindSafe,
-) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+) -> support::WireSyncReturn {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync(
         WrapInfo {
-            debug_name: "handle_some_stream_sink__method__ConcatenateWith",
-            port: Some(port_),
-            mode: FfiCallMode::Stream,
+            debug_name: "handle_stream_sink_at_1_drain",
+            port: None,
+            mode: FfiCallMode::Sync,
         },
         move || {
-            let api_that = that.wire2api();
+            frb_init();
             let api_key = key.wire2api();
             let api_max = max.wire2api();
-            move |task_callback| {
-                Ok(ConcatenateWith::handle_some_stream_sink(
-                    &api_that,
-                    api_key,
-                    api_max,
-                    task_callback.stream_sink(),
-                ))
+            let sink_ = StreamSink::buffered();
+            {
+                handle_stream_sink_at_1(api_key, api_max, sink_.clone());
+                sink_.drain().map(SyncReturn)
             }
         },
     )
 }
-fn wire_handle_some_stream_sink_at_1__method__ConcatenateWith_impl(
-    port_: MessagePort,
-    that: impl Wire2Api<ConcatenateWith> + UnwindSafe,
-) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+fn wire_handle_stream_sink_at_2_drain_impl(
+    key: impl Wire2Api<u32> + UnwindSafe,
+    max: impl Wire2Api<u32> + UnwindSafe,
+) -> support::WireSyncReturn {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync(
+        WrapInfo {
+            debug_name: "handle_stream_sink_at_2_drain",
+            port: None,
+            mode: FfiCallMode::Sync,
+        },
+        move || {
+            frb_init();
+            let api_key = key.wire2api();
+            let api_max = max.wire2api();
+            let sink_ = StreamSink::buffered();
+            {
+                handle_stream_sink_at_2(api_key, sink_.clone(), api_max);
+                sink_.drain().map(SyncReturn)
+            }
+        },
+    )
+}
+fn wire_handle_stream_sink_at_3_drain_impl(
+    key: impl Wire2Api<u32> + UnwindSafe,
+    max: impl Wire2Api<u32> + UnwindSafe,
+) -> support::WireSyncReturn {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync(
+        WrapInfo {
+            debug_name: "handle_stream_sink_at_3_drain",
+            port: None,
+            mode: FfiCallMode::Sync,
+        },
+        move || {
+            frb_init();
+            let api_key = key.wire2api();
+            let api_max = max.wire2api();
+            let sink_ = StreamSink::buffered();
+            {
+                handle_stream_sink_at_3(sink_.clone(), api_key, api_max);
+                sink_.drain().map(SyncReturn)
+            }
+        },
+    )
+}
+fn wire_handle_stream_closed_by_drop_drain_impl(
+    max: impl Wire2Api<u32> + UnwindSafe,
+) -> support::WireSyncReturn {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync(
+        WrapInfo {
+            debug_name: "handle_stream_closed_by_drop_drain",
+            port: None,
+            mode: FfiCallMode::Sync,
+        },
+        move || {
+            frb_init();
+            let api_max = max.wire2api();
+            let sink_ = StreamSink::buffered();
+            {
+                handle_stream_closed_by_drop(sink_.clone(), api_max);
+                sink_.drain().map(SyncReturn)
+            }
+        },
+    )
+}
+fn wire_count_from_drain_impl(
+    from: impl Wire2Api<u32> + UnwindSafe,
+    count: impl Wire2Api<u32> + UnwindSafe,
+) -> support::WireSyncReturn {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync(
+        WrapInfo {
+            debug_name: "count_from_drain",
+            port: None,
+            mode: FfiCallMode::Sync,
+        },
+        move || {
+            frb_init();
+            let api_from = from.wire2api();
+            let api_count = count.wire2api();
+            let sink_ = StreamSink::buffered();
+            count_from(sink_.clone(), api_from, api_count)
+                .and_then(|_| sink_.drain())
+                .map(SyncReturn)
+        },
+    )
+}
+fn wire_parse_numbers_drain_impl(
+    input: impl Wire2Api<String> + UnwindSafe,
+) -> support::WireSyncReturn {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync(
         WrapInfo {
-            debug_name: "handle_some_stream_sink_at_1__method__ConcatenateWith",
-            port: Some(port_),
-            mode: FfiCallMode::Stream,
+            debug_name: "parse_numbers_drain",
+            port: None,
+            mode: FfiCallMode::Sync,
         },
         move || {
-            let api_that = that.wire2api();
-            move |task_callback| {
-                Ok(ConcatenateWith::handle_some_stream_sink_at_1(
-                    &api_that,
-                    task_callback.stream_sink(),
-                ))
+            frb_init();
+            let api_input = input.wire2api();
+            let sink_ = StreamSink::buffered();
+            {
+                parse_numbers(sink_.clone(), api_input);
+                sink_.drain().map(SyncReturn)
             }
         },
     )
 }
-fn wire_handle_some_static_stream_sink__static_method__ConcatenateWith_impl(
-    port_: MessagePort,
-    key: impl Wire2Api<u32> + UnwindSafe,
+fn wire_handle_broadcast_stream_drain_impl(
     max: impl Wire2Api<u32> + UnwindSafe,
-) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+) -> support::WireSyncReturn {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync(
         WrapInfo {
-            debug_name: "handle_some_static_stream_sink__static_method__ConcatenateWith",
-            port: Some(port_),
-            mode: FfiCallMode::Stream,
+            debug_name: "handle_broadcast_stream_drain",
+            port: None,
+            mode: FfiCallMode::Sync,
         },
         move || {
-            let api_key = key.wire2api();
+            frb_init();
             let api_max = max.wire2api();
-            move |task_callback| {
-                Ok(ConcatenateWith::handle_some_static_stream_sink(
-                    api_key,
-                    api_max,
-                    task_callback.stream_sink(),
-                ))
+            let sink_ = StreamSink::buffered();
+            {
+                handle_broadcast_stream(sink_.clone(), api_max);
+                sink_.drain().map(SyncReturn)
             }
         },
     )
 }
-fn wire_handle_some_static_stream_sink_single_arg__static_method__ConcatenateWith_impl(
-    port_: MessagePort,
-) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+fn wire_handle_stream_closed_by_threads_drain_impl(
+    threads: impl Wire2Api<u32> + UnwindSafe,
+) -> support::WireSyncReturn {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync(
         WrapInfo {
-            debug_name: "handle_some_static_stream_sink_single_arg__static_method__ConcatenateWith",
-            port: Some(port_),
-            mode: FfiCallMode::Stream,
+            debug_name: "handle_stream_closed_by_threads_drain",
+            port: None,
+            mode: FfiCallMode::Sync,
         },
         move || {
-            move |task_callback| {
-                Ok(ConcatenateWith::handle_some_static_stream_sink_single_arg(
-                    task_callback.stream_sink(),
-                ))
+            frb_init();
+            let api_threads = threads.wire2api();
+            let sink_ = StreamSink::buffered();
+            {
+                handle_stream_closed_by_threads(sink_.clone(), api_threads);
+                sink_.drain().map(SyncReturn)
             }
         },
     )
@@ -2163,15 +4477,32 @@ struct mirror_ApplicationMode(ApplicationMode);
 #[derive(Clone)]
 struct mirror_ApplicationSettings(ApplicationSettings);
 
+struct mirror_btree_map_String_list_attribute(std::collections::BTreeMap<String, Vec<Attribute>>);
+struct mirror_btree_map_i32_String(std::collections::BTreeMap<i32, String>);
+struct mirror_btree_set_String(std::collections::BTreeSet<String>);
+struct mirror_hash_map_String_i32(std::collections::HashMap<String, i32>);
+struct mirror_hash_map_colliding_key_u32(std::collections::HashMap<CollidingKey, u32>);
+struct mirror_hash_map_u32_attribute(std::collections::HashMap<u32, Attribute>);
+struct mirror_hash_map_usize_String(std::collections::HashMap<usize, String>);
+struct mirror_hash_map_weekdays_u32(std::collections::HashMap<Weekdays, u32>);
+
 #[derive(Clone)]
 struct mirror_Numbers(Numbers);
 
 #[derive(Clone)]
 struct mirror_Sequences(Sequences);
 
+struct mirror_tuple2_String_bool((String, bool));
+struct mirror_tuple2_setting_error_String((SettingError, String));
+struct mirror_tuple2_tuple2_String_bool_i32(((String, bool), i32));
+struct mirror_tuple3_bool_String_i32((bool, String, i32));
+struct mirror_tuple3_setting_error_String_custom_error((SettingError, String, CustomError));
+struct mirror_tuple3_u32_String_f64((u32, String, f64));
 // Section: static checks
 
 const _: fn() = || {
+    let _: fn(&Version) -> Vec<u8> = crate::codecs::version::encode;
+    let _: fn(&[u8]) -> Version = crate::codecs::version::decode;
     {
         let ApplicationEnv = None::<ApplicationEnv>.unwrap();
         let _: Vec<ApplicationEnvVar> = ApplicationEnv.vars;
@@ -2231,6 +4562,16 @@ where
     }
 }
 
+impl Wire2Api<chrono::DateTime<chrono::FixedOffset>> for i64 {
+    fn wire2api(self) -> chrono::DateTime<chrono::FixedOffset> {
+        let Timestamp { s, ns } = wire2api_timestamp(self);
+        chrono::DateTime::<chrono::FixedOffset>::from(chrono::DateTime::<chrono::Utc>::from_utc(
+            chrono::NaiveDateTime::from_timestamp_opt(s, ns)
+                .expect("invalid or out-of-range datetime"),
+            chrono::Utc,
+        ))
+    }
+}
 impl Wire2Api<chrono::DateTime<chrono::Local>> for i64 {
     fn wire2api(self) -> chrono::DateTime<chrono::Local> {
         let Timestamp { s, ns } = wire2api_timestamp(self);
@@ -2279,12 +4620,24 @@ impl Wire2Api<f32> for f32 {
         self
     }
 }
+
 impl Wire2Api<f64> for f64 {
     fn wire2api(self) -> f64 {
         self
     }
 }
 
+impl Wire2Api<HttpStatus> for i32 {
+    fn wire2api(self) -> HttpStatus {
+        match self {
+            200 => HttpStatus::Ok,
+            404 => HttpStatus::NotFound,
+            405 => HttpStatus::MethodNotAllowed,
+            _ => unreachable!("Invalid variant for HttpStatus: {}", self),
+        }
+    }
+}
+
 impl Wire2Api<i32> for i32 {
     fn wire2api(self) -> i32 {
         self
@@ -2312,6 +4665,21 @@ impl Wire2Api<MyEnum> for i32 {
     }
 }
 
+impl Wire2Api<Pace> for i32 {
+    fn wire2api(self) -> Pace {
+        match self {
+            0 => Pace::Quick,
+            1 => Pace::Slow,
+            _ => unreachable!("Invalid variant for Pace: {}", self),
+        }
+    }
+}
+
+impl Wire2Api<u16> for u16 {
+    fn wire2api(self) -> u16 {
+        self
+    }
+}
 impl Wire2Api<u32> for u32 {
     fn wire2api(self) -> u32 {
         self
@@ -2333,6 +4701,7 @@ impl Wire2Api<usize> for usize {
         self
     }
 }
+
 impl Wire2Api<Weekdays> for i32 {
     fn wire2api(self) -> Weekdays {
         match self {
@@ -2430,6 +4799,62 @@ impl support::IntoDart for Blob {
 }
 impl support::IntoDartExceptPrimitive for Blob {}
 
+impl support::IntoDart for mirror_btree_map_String_list_attribute {
+    fn into_dart(self) -> support::DartAbi {
+        let (keys, values): (Vec<_>, Vec<_>) = self.0.into_iter().unzip();
+        vec![
+            keys.into_iter()
+                .map(|k| k.into_dart())
+                .collect::<Vec<_>>()
+                .into_dart(),
+            values
+                .into_iter()
+                .map(|v| v.into_dart())
+                .collect::<Vec<_>>()
+                .into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for mirror_btree_map_String_list_attribute {}
+
+impl support::IntoDart for mirror_btree_map_i32_String {
+    fn into_dart(self) -> support::DartAbi {
+        let (keys, values): (Vec<_>, Vec<_>) = self.0.into_iter().unzip();
+        vec![
+            keys.into_iter()
+                .map(|k| k.into_dart())
+                .collect::<Vec<_>>()
+                .into_dart(),
+            values
+                .into_iter()
+                .map(|v| v.into_dart())
+                .collect::<Vec<_>>()
+                .into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for mirror_btree_map_i32_String {}
+
+impl support::IntoDart for mirror_btree_set_String {
+    fn into_dart(self) -> support::DartAbi {
+        self.0
+            .into_iter()
+            .map(|v| v.into_dart())
+            .collect::<Vec<_>>()
+            .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for mirror_btree_set_String {}
+
+impl support::IntoDart for CollidingKey {
+    fn into_dart(self) -> support::DartAbi {
+        vec![self.0.into_dart()].into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for CollidingKey {}
+
 impl support::IntoDart for ConcatenateWith {
     fn into_dart(self) -> support::DartAbi {
         vec![self.a.into_dart()].into_dart()
@@ -2437,6 +4862,20 @@ impl support::IntoDart for ConcatenateWith {
 }
 impl support::IntoDartExceptPrimitive for ConcatenateWith {}
 
+impl support::IntoDart for CustomError {
+    fn into_dart(self) -> support::DartAbi {
+        match self {
+            Self::Fail { message, backtrace } => vec![
+                0.into_dart(),
+                message.into_dart(),
+                backtrace.to_string().into_dart(),
+            ],
+            Self::Retry { after_ms } => vec![1.into_dart(), after_ms.into_dart()],
+        }
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for CustomError {}
 impl support::IntoDart for DartOpaqueNested {
     fn into_dart(self) -> support::DartAbi {
         vec![self.first.into_dart(), self.second.into_dart()].into_dart()
@@ -2454,6 +4893,13 @@ impl support::IntoDart for Distance {
     }
 }
 impl support::IntoDartExceptPrimitive for Distance {}
+impl support::IntoDart for Document {
+    fn into_dart(self) -> support::DartAbi {
+        vec![self.metadata.into_dart(), self.title.into_dart()].into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for Document {}
+
 impl support::IntoDart for Element {
     fn into_dart(self) -> support::DartAbi {
         vec![
@@ -2541,6 +4987,131 @@ impl support::IntoDart for FeedId {
 }
 impl support::IntoDartExceptPrimitive for FeedId {}
 
+impl support::IntoDart for Frame {
+    fn into_dart(self) -> support::DartAbi {
+        vec![
+            self.index.into_dart(),
+            ZeroCopyBuffer(self.pixels).into_dart(),
+            self.depths.map(ZeroCopyBuffer).into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for Frame {}
+
+impl support::IntoDart for GridCell {
+    fn into_dart(self) -> support::DartAbi {
+        vec![self.row.into_dart(), self.column.into_dart()].into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for GridCell {}
+
+impl support::IntoDart for mirror_hash_map_String_i32 {
+    fn into_dart(self) -> support::DartAbi {
+        let (keys, values): (Vec<_>, Vec<_>) = self.0.into_iter().unzip();
+        vec![
+            keys.into_iter()
+                .map(|k| k.into_dart())
+                .collect::<Vec<_>>()
+                .into_dart(),
+            values
+                .into_iter()
+                .map(|v| v.into_dart())
+                .collect::<Vec<_>>()
+                .into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for mirror_hash_map_String_i32 {}
+
+impl support::IntoDart for mirror_hash_map_colliding_key_u32 {
+    fn into_dart(self) -> support::DartAbi {
+        let (keys, values): (Vec<_>, Vec<_>) = self.0.into_iter().unzip();
+        vec![
+            keys.into_iter()
+                .map(|k| k.into_dart())
+                .collect::<Vec<_>>()
+                .into_dart(),
+            values
+                .into_iter()
+                .map(|v| v.into_dart())
+                .collect::<Vec<_>>()
+                .into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for mirror_hash_map_colliding_key_u32 {}
+
+impl support::IntoDart for mirror_hash_map_u32_attribute {
+    fn into_dart(self) -> support::DartAbi {
+        let (keys, values): (Vec<_>, Vec<_>) = self.0.into_iter().unzip();
+        vec![
+            keys.into_iter()
+                .map(|k| k.into_dart())
+                .collect::<Vec<_>>()
+                .into_dart(),
+            values
+                .into_iter()
+                .map(|v| v.into_dart())
+                .collect::<Vec<_>>()
+                .into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for mirror_hash_map_u32_attribute {}
+
+impl support::IntoDart for mirror_hash_map_usize_String {
+    fn into_dart(self) -> support::DartAbi {
+        let (keys, values): (Vec<_>, Vec<_>) = self.0.into_iter().unzip();
+        vec![
+            keys.into_iter()
+                .map(|k| k.into_dart())
+                .collect::<Vec<_>>()
+                .into_dart(),
+            values
+                .into_iter()
+                .map(|v| v.into_dart())
+                .collect::<Vec<_>>()
+                .into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for mirror_hash_map_usize_String {}
+
+impl support::IntoDart for mirror_hash_map_weekdays_u32 {
+    fn into_dart(self) -> support::DartAbi {
+        let (keys, values): (Vec<_>, Vec<_>) = self.0.into_iter().unzip();
+        vec![
+            keys.into_iter()
+                .map(|k| k.into_dart())
+                .collect::<Vec<_>>()
+                .into_dart(),
+            values
+                .into_iter()
+                .map(|v| v.into_dart())
+                .collect::<Vec<_>>()
+                .into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for mirror_hash_map_weekdays_u32 {}
+
+impl support::IntoDart for HttpStatus {
+    fn into_dart(self) -> support::DartAbi {
+        match self {
+            Self::Ok => 200,
+            Self::NotFound => 404,
+            Self::MethodNotAllowed => 405,
+        }
+        .into_dart()
+    }
+}
+
 impl support::IntoDart for KitchenSink {
     fn into_dart(self) -> support::DartAbi {
         match self {
@@ -2556,7 +5127,7 @@ impl support::IntoDart for KitchenSink {
                 boolean.into_dart(),
             ],
             Self::Nested(field0, field1) => {
-                vec![2.into_dart(), field0.into_dart(), field1.into_dart()]
+                vec![2.into_dart(), (*field0).into_dart(), field1.into_dart()]
             }
             Self::Optional(field0, field1) => {
                 vec![3.into_dart(), field0.into_dart(), field1.into_dart()]
@@ -2586,8 +5157,8 @@ impl support::IntoDartExceptPrimitive for Log2 {}
 impl support::IntoDart for Measure {
     fn into_dart(self) -> support::DartAbi {
         match self {
-            Self::Speed(field0) => vec![0.into_dart(), field0.into_dart()],
-            Self::Distance(field0) => vec![1.into_dart(), field0.into_dart()],
+            Self::Speed(field0) => vec![0.into_dart(), (*field0).into_dart()],
+            Self::Distance(field0) => vec![1.into_dart(), (*field0).into_dart()],
         }
         .into_dart()
     }
@@ -2600,6 +5171,20 @@ impl support::IntoDart for MessageId {
 }
 impl support::IntoDartExceptPrimitive for MessageId {}
 
+impl support::IntoDart for Metadata {
+    fn into_dart(self) -> support::DartAbi {
+        vec![self.id.into_dart(), self.created_by.into_dart()].into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for Metadata {}
+
+impl support::IntoDart for Money {
+    fn into_dart(self) -> support::DartAbi {
+        vec![self.cents.into_dart()].into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for Money {}
+
 impl support::IntoDart for MyEnum {
     fn into_dart(self) -> support::DartAbi {
         match self {
@@ -2685,6 +5270,32 @@ impl support::IntoDart for OpaqueNested {
 }
 impl support::IntoDartExceptPrimitive for OpaqueNested {}
 
+impl support::IntoDart for Pace {
+    fn into_dart(self) -> support::DartAbi {
+        match self {
+            Self::Quick => 0,
+            Self::Slow => 1,
+        }
+        .into_dart()
+    }
+}
+impl support::IntoDart for Pair<String> {
+    fn into_dart(self) -> support::DartAbi {
+        vec![self.first.into_dart(), self.second.into_dart()].into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for Pair<String> {}
+
+impl support::IntoDart for ParseError {
+    fn into_dart(self) -> support::DartAbi {
+        match self {
+            Self::Empty => vec![0.into_dart()],
+            Self::NotANumber { line } => vec![1.into_dart(), line.into_dart()],
+        }
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for ParseError {}
 impl support::IntoDart for Point {
     fn into_dart(self) -> support::DartAbi {
         vec![self.x.into_dart(), self.y.into_dart()].into_dart()
@@ -2692,6 +5303,43 @@ impl support::IntoDart for Point {
 }
 impl support::IntoDartExceptPrimitive for Point {}
 
+impl support::IntoDart for ProductId {
+    fn into_dart(self) -> support::DartAbi {
+        vec![self.0.into_dart()].into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for ProductId {}
+
+impl support::IntoDart for Progress {
+    fn into_dart(self) -> support::DartAbi {
+        vec![self.percentage.into_dart()].into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for Progress {}
+
+impl support::IntoDart for Renamed {
+    fn into_dart(self) -> support::DartAbi {
+        vec![self.id.into_dart(), self.pace.into_dart()].into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for Renamed {}
+
+impl support::IntoDart for Sample {
+    fn into_dart(self) -> support::DartAbi {
+        ZeroCopyBuffer({
+            let mut bytes = vec![0u8; 24];
+            bytes[0..4].copy_from_slice(&self.index.to_le_bytes());
+            bytes[8..16].copy_from_slice(&self.x.to_le_bytes());
+            bytes[16..20].copy_from_slice(&self.y.to_le_bytes());
+            bytes[20..21].copy_from_slice(&u8::from(self.valid).to_le_bytes());
+            bytes[21..22].copy_from_slice(&self.level.to_le_bytes());
+            bytes
+        })
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for Sample {}
+
 impl support::IntoDart for mirror_Sequences {
     fn into_dart(self) -> support::DartAbi {
         vec![self.0 .0.into_dart()].into_dart()
@@ -2699,6 +5347,22 @@ impl support::IntoDart for mirror_Sequences {
 }
 impl support::IntoDartExceptPrimitive for mirror_Sequences {}
 
+impl support::IntoDart for Session {
+    fn into_dart(self) -> support::DartAbi {
+        vec![self.user.into_dart()].into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for Session {}
+
+impl support::IntoDart for SettingError {
+    fn into_dart(self) -> support::DartAbi {
+        match self {
+            Self::NotFound => 0,
+            Self::Invalid => 1,
+        }
+        .into_dart()
+    }
+}
 impl support::IntoDart for Speed {
     fn into_dart(self) -> support::DartAbi {
         match self {
@@ -2709,6 +5373,7 @@ impl support::IntoDart for Speed {
     }
 }
 impl support::IntoDartExceptPrimitive for Speed {}
+
 impl support::IntoDart for SumWith {
     fn into_dart(self) -> support::DartAbi {
         vec![self.x.into_dart()].into_dart()
@@ -2748,6 +5413,58 @@ impl support::IntoDart for TestModel {
 }
 impl support::IntoDartExceptPrimitive for TestModel {}
 
+impl support::IntoDart for mirror_tuple2_String_bool {
+    fn into_dart(self) -> support::DartAbi {
+        let (field0, field1) = self.0;
+        vec![field0.into_dart(), field1.into_dart()].into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for mirror_tuple2_String_bool {}
+
+impl support::IntoDart for mirror_tuple2_setting_error_String {
+    fn into_dart(self) -> support::DartAbi {
+        let (field0, field1) = self.0;
+        vec![field0.into_dart(), field1.into_dart()].into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for mirror_tuple2_setting_error_String {}
+
+impl support::IntoDart for mirror_tuple2_tuple2_String_bool_i32 {
+    fn into_dart(self) -> support::DartAbi {
+        let (field0, field1) = self.0;
+        vec![
+            mirror_tuple2_String_bool(field0).into_dart(),
+            field1.into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for mirror_tuple2_tuple2_String_bool_i32 {}
+
+impl support::IntoDart for mirror_tuple3_bool_String_i32 {
+    fn into_dart(self) -> support::DartAbi {
+        let (field0, field1, field2) = self.0;
+        vec![field0.into_dart(), field1.into_dart(), field2.into_dart()].into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for mirror_tuple3_bool_String_i32 {}
+
+impl support::IntoDart for mirror_tuple3_setting_error_String_custom_error {
+    fn into_dart(self) -> support::DartAbi {
+        let (field0, field1, field2) = self.0;
+        vec![field0.into_dart(), field1.into_dart(), field2.into_dart()].into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for mirror_tuple3_setting_error_String_custom_error {}
+
+impl support::IntoDart for mirror_tuple3_u32_String_f64 {
+    fn into_dart(self) -> support::DartAbi {
+        let (field0, field1, field2) = self.0;
+        vec![field0.into_dart(), field1.into_dart(), field2.into_dart()].into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for mirror_tuple3_u32_String_f64 {}
+
 impl support::IntoDart for UserId {
     fn into_dart(self) -> support::DartAbi {
         vec![self.value.into_dart()].into_dart()
@@ -2807,6 +5524,12 @@ impl support::IntoDart for ZeroCopyVecOfPrimitivePack {
 }
 impl support::IntoDartExceptPrimitive for ZeroCopyVecOfPrimitivePack {}
 
+// Section: init
+
+fn frb_init() {
+    static INIT: std::sync::Once = std::sync::Once::new();
+    INIT.call_once(|| init_app());
+}
 // Section: executor
 
 support::lazy_static! {
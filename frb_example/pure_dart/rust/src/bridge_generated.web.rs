@@ -1,16 +1,46 @@
 use super::*;
 // Section: wire functions
 
+#[wasm_bindgen]
+pub fn wire_init_app(port_: MessagePort) {
+    wire_init_app_impl(port_)
+}
+
+#[wasm_bindgen]
+pub fn wire_init_count(port_: MessagePort) {
+    wire_init_count_impl(port_)
+}
+
+#[wasm_bindgen]
+pub fn wire_get_constants(port_: MessagePort) {
+    wire_get_constants_impl(port_)
+}
+
 #[wasm_bindgen]
 pub fn wire_simple_adder(port_: MessagePort, a: i32, b: i32) {
     wire_simple_adder_impl(port_, a, b)
 }
 
+#[wasm_bindgen]
+pub fn wire_simple_adder_async(port_: MessagePort, a: i32, b: i32) {
+    wire_simple_adder_async_impl(port_, a, b)
+}
+
 #[wasm_bindgen]
 pub fn wire_simple_adder_sync(a: i32, b: i32) -> support::WireSyncReturn {
     wire_simple_adder_sync_impl(a, b)
 }
 
+#[wasm_bindgen]
+pub fn wire_scale(x: f64, factor: f64) -> f64 {
+    wire_scale_impl(x, factor)
+}
+
+#[wasm_bindgen]
+pub fn wire_sum_vector(v: Box<[f32]>) -> f32 {
+    wire_sum_vector_impl(v)
+}
+
 #[wasm_bindgen]
 pub fn wire_primitive_types(
     port_: MessagePort,
@@ -63,6 +93,91 @@ pub fn wire_handle_string_sync(s: String) -> support::WireSyncReturn {
     wire_handle_string_sync_impl(s)
 }
 
+#[wasm_bindgen]
+pub fn wire_next_char(port_: MessagePort, c: u32) {
+    wire_next_char_impl(port_, c)
+}
+
+#[wasm_bindgen]
+pub fn wire_handle_chars(port_: MessagePort, chars: JsValue) {
+    wire_handle_chars_impl(port_, chars)
+}
+
+#[wasm_bindgen]
+pub fn wire_divide_by(port_: MessagePort, n: u32, by: u32) {
+    wire_divide_by_impl(port_, n, by)
+}
+
+#[wasm_bindgen]
+pub fn wire_next_non_zero(port_: MessagePort, n: u64) {
+    wire_next_non_zero_impl(port_, n)
+}
+
+#[wasm_bindgen]
+pub fn wire_greet(port_: MessagePort, name: String, times: u32) {
+    wire_greet_impl(port_, name, times)
+}
+
+#[wasm_bindgen]
+pub fn wire_instant_now(port_: MessagePort) {
+    wire_instant_now_impl(port_)
+}
+
+#[wasm_bindgen]
+pub fn wire_instant_after(port_: MessagePort, start: JsValue, millis: u64) {
+    wire_instant_after_impl(port_, start, millis)
+}
+
+#[wasm_bindgen]
+pub fn wire_one_day_later(port_: MessagePort, time: i64) {
+    wire_one_day_later_impl(port_, time)
+}
+
+#[wasm_bindgen]
+pub fn wire_shift_range(port_: MessagePort, range: Box<[u32]>, by: u32) {
+    wire_shift_range_impl(port_, range, by)
+}
+
+#[wasm_bindgen]
+pub fn wire_clamp_range(port_: MessagePort, range: Box<[f64]>, value: f64) {
+    wire_clamp_range_impl(port_, range, value)
+}
+
+#[wasm_bindgen]
+pub fn wire_describe_size(port_: MessagePort, size: JsValue, unit: String, labels: JsValue) {
+    wire_describe_size_impl(port_, size, unit, labels)
+}
+
+#[wasm_bindgen]
+pub fn wire_split_words(port_: MessagePort, text: String) {
+    wire_split_words_impl(port_, text)
+}
+
+#[wasm_bindgen]
+pub fn wire_handle_cow_str(port_: MessagePort, s: String) {
+    wire_handle_cow_str_impl(port_, s)
+}
+
+#[wasm_bindgen]
+pub fn wire_handle_cow_bytes(port_: MessagePort, bytes: Box<[u8]>) {
+    wire_handle_cow_bytes_impl(port_, bytes)
+}
+
+#[wasm_bindgen]
+pub fn wire_handle_path(port_: MessagePort, path: Box<[u16]>) {
+    wire_handle_path_impl(port_, path)
+}
+
+#[wasm_bindgen]
+pub fn wire_handle_socket_addr(port_: MessagePort, addr: Box<[u8]>) {
+    wire_handle_socket_addr_impl(port_, addr)
+}
+
+#[wasm_bindgen]
+pub fn wire_next_port(port_: MessagePort, addr: Box<[u8]>) {
+    wire_next_port_impl(port_, addr)
+}
+
 #[wasm_bindgen]
 pub fn wire_handle_return_unit(port_: MessagePort) {
     wire_handle_return_unit_impl(port_)
@@ -78,6 +193,31 @@ pub fn wire_handle_vec_u8(port_: MessagePort, v: Box<[u8]>) {
     wire_handle_vec_u8_impl(port_, v)
 }
 
+#[wasm_bindgen]
+pub fn wire_handle_bytes(port_: MessagePort, bytes: Box<[u8]>) {
+    wire_handle_bytes_impl(port_, bytes)
+}
+
+#[wasm_bindgen]
+pub fn wire_checksum(port_: MessagePort, data: Box<[u8]>, weights: Box<[u16]>) {
+    wire_checksum_impl(port_, data, weights)
+}
+
+#[wasm_bindgen]
+pub fn wire_count_in_shared_buffer(port_: MessagePort, buffer: usize, byte: u8) {
+    wire_count_in_shared_buffer_impl(port_, buffer, byte)
+}
+
+#[wasm_bindgen]
+pub fn wire_next_frame(port_: MessagePort, frame: JsValue) {
+    wire_next_frame_impl(port_, frame)
+}
+
+#[wasm_bindgen]
+pub fn wire_prefix_sums(port_: MessagePort, items: Box<[u64]>) {
+    wire_prefix_sums_impl(port_, items)
+}
+
 #[wasm_bindgen]
 pub fn wire_handle_vec_u8_sync(v: Box<[u8]>) -> support::WireSyncReturn {
     wire_handle_vec_u8_sync_impl(v)
@@ -123,6 +263,11 @@ pub fn wire_handle_newtype_sync(arg: JsValue) -> support::WireSyncReturn {
     wire_handle_newtype_sync_impl(arg)
 }
 
+#[wasm_bindgen]
+pub fn wire_handle_generic_struct(port_: MessagePort, pair: JsValue) {
+    wire_handle_generic_struct_impl(port_, pair)
+}
+
 #[wasm_bindgen]
 pub fn wire_handle_list_of_struct(port_: MessagePort, l: JsValue) {
     wire_handle_list_of_struct_impl(port_, l)
@@ -133,6 +278,11 @@ pub fn wire_handle_list_of_struct_sync(l: JsValue) -> support::WireSyncReturn {
     wire_handle_list_of_struct_sync_impl(l)
 }
 
+#[wasm_bindgen]
+pub fn wire_handle_nested_vec(port_: MessagePort, rows: JsValue) {
+    wire_handle_nested_vec_impl(port_, rows)
+}
+
 #[wasm_bindgen]
 pub fn wire_handle_string_list(port_: MessagePort, names: JsValue) {
     wire_handle_string_list_impl(port_, names)
@@ -178,6 +328,56 @@ pub fn wire_return_err(port_: MessagePort) {
     wire_return_err_impl(port_)
 }
 
+#[wasm_bindgen]
+pub fn wire_return_custom_error(port_: MessagePort, fail: bool) {
+    wire_return_custom_error_impl(port_, fail)
+}
+
+#[wasm_bindgen]
+pub fn wire_return_custom_error_unit(port_: MessagePort) {
+    wire_return_custom_error_unit_impl(port_)
+}
+
+#[wasm_bindgen]
+pub fn wire_lookup_weekday(index: u32) -> support::WireSyncReturn {
+    wire_lookup_weekday_impl(index)
+}
+
+#[wasm_bindgen]
+pub fn wire_lookup_string(port_: MessagePort, key: String) {
+    wire_lookup_string_impl(port_, key)
+}
+
+#[wasm_bindgen]
+pub fn wire_read_setting(port_: MessagePort, key: String) {
+    wire_read_setting_impl(port_, key)
+}
+
+#[wasm_bindgen]
+pub fn wire_parse_setting(port_: MessagePort, value: String) {
+    wire_parse_setting_impl(port_, value)
+}
+
+#[wasm_bindgen]
+pub fn wire_return_custom_error_sync(fail: bool) -> support::WireSyncReturn {
+    wire_return_custom_error_sync_impl(fail)
+}
+
+#[wasm_bindgen]
+pub fn wire_count_until_cancelled(port_: MessagePort, cancel_id_: i32) {
+    wire_count_until_cancelled_impl(port_, cancel_id_)
+}
+
+#[wasm_bindgen]
+pub fn wire_run_workers(port_: MessagePort, cancel_id_: i32, workers: u32) {
+    wire_run_workers_impl(port_, cancel_id_, workers)
+}
+
+#[wasm_bindgen]
+pub fn wire_stopped_workers(port_: MessagePort) {
+    wire_stopped_workers_impl(port_)
+}
+
 #[wasm_bindgen]
 pub fn wire_return_panic(port_: MessagePort) {
     wire_return_panic_impl(port_)
@@ -234,6 +434,16 @@ pub fn wire_handle_enum_parameter(port_: MessagePort, weekday: i32) {
     wire_handle_enum_parameter_impl(port_, weekday)
 }
 
+#[wasm_bindgen]
+pub fn wire_http_status_code(port_: MessagePort, status: i32) {
+    wire_http_status_code_impl(port_, status)
+}
+
+#[wasm_bindgen]
+pub fn wire_http_status_of(port_: MessagePort, code: i32) {
+    wire_http_status_of_impl(port_, code)
+}
+
 #[wasm_bindgen]
 pub fn wire_handle_customized_struct(port_: MessagePort, val: JsValue) {
     wire_handle_customized_struct_impl(port_, val)
@@ -274,6 +484,16 @@ pub fn wire_get_message(port_: MessagePort) {
     wire_get_message_impl(port_)
 }
 
+#[wasm_bindgen]
+pub fn wire_get_fallible_message(port_: MessagePort, index: usize) {
+    wire_get_fallible_message_impl(port_, index)
+}
+
+#[wasm_bindgen]
+pub fn wire_is_exit_message(port_: MessagePort, message: JsValue) {
+    wire_is_exit_message_impl(port_, message)
+}
+
 #[wasm_bindgen]
 pub fn wire_repeat_number(port_: MessagePort, num: i32, times: usize) {
     wire_repeat_number_impl(port_, num, times)
@@ -344,6 +564,36 @@ pub fn wire_handle_stream_sink_at_3(port_: MessagePort, key: u32, max: u32) {
     wire_handle_stream_sink_at_3_impl(port_, key, max)
 }
 
+#[wasm_bindgen]
+pub fn wire_call_back_with_numbers(port_: MessagePort, callback: MessagePort, max: u32) {
+    wire_call_back_with_numbers_impl(port_, callback, max)
+}
+
+#[wasm_bindgen]
+pub fn wire_handle_stream_closed_by_drop(port_: MessagePort, max: u32) {
+    wire_handle_stream_closed_by_drop_impl(port_, max)
+}
+
+#[wasm_bindgen]
+pub fn wire_count_from(port_: MessagePort, from: u32, count: u32) {
+    wire_count_from_impl(port_, from, count)
+}
+
+#[wasm_bindgen]
+pub fn wire_parse_numbers(port_: MessagePort, input: String) {
+    wire_parse_numbers_impl(port_, input)
+}
+
+#[wasm_bindgen]
+pub fn wire_handle_broadcast_stream(port_: MessagePort, max: u32) {
+    wire_handle_broadcast_stream_impl(port_, max)
+}
+
+#[wasm_bindgen]
+pub fn wire_handle_stream_closed_by_threads(port_: MessagePort, threads: u32) {
+    wire_handle_stream_closed_by_threads_impl(port_, threads)
+}
+
 #[wasm_bindgen]
 pub fn wire_get_sum_struct(port_: MessagePort) {
     wire_get_sum_struct_impl(port_)
@@ -354,6 +604,71 @@ pub fn wire_get_sum_array(port_: MessagePort, a: u32, b: u32, c: u32) {
     wire_get_sum_array_impl(port_, a, b, c)
 }
 
+#[wasm_bindgen]
+pub fn wire_rename_document(port_: MessagePort, document: JsValue, title: String) {
+    wire_rename_document_impl(port_, document, title)
+}
+
+#[wasm_bindgen]
+pub fn wire_get_renamed(port_: MessagePort, id: u32, pace: i32) {
+    wire_get_renamed_impl(port_, id, pace)
+}
+
+#[wasm_bindgen]
+pub fn wire_advance(port_: MessagePort, progress: JsValue, by: u8) {
+    wire_advance_impl(port_, progress, by)
+}
+
+#[wasm_bindgen]
+pub fn wire_next_product_id(port_: MessagePort, id: JsValue) {
+    wire_next_product_id_impl(port_, id)
+}
+
+#[wasm_bindgen]
+pub fn wire_transpose_cell(port_: MessagePort, cell: JsValue) {
+    wire_transpose_cell_impl(port_, cell)
+}
+
+#[wasm_bindgen]
+pub fn wire_add_tax(port_: MessagePort, price: JsValue, percent: u32) {
+    wire_add_tax_impl(port_, price, percent)
+}
+
+#[wasm_bindgen]
+pub fn wire_bump_minor(port_: MessagePort, version: Box<[u8]>) {
+    wire_bump_minor_impl(port_, version)
+}
+
+#[wasm_bindgen]
+pub fn wire_open_session(port_: MessagePort, user: String) {
+    wire_open_session_impl(port_, user)
+}
+
+#[wasm_bindgen]
+pub fn wire_describe_session(port_: MessagePort, session: JsValue) {
+    wire_describe_session_impl(port_, session)
+}
+
+#[wasm_bindgen]
+pub fn wire_sample_at(port_: MessagePort, index: u32) {
+    wire_sample_at_impl(port_, index)
+}
+
+#[wasm_bindgen]
+pub fn wire_shift_sample(port_: MessagePort, sample: JsValue, dx: f64) {
+    wire_shift_sample_impl(port_, sample, dx)
+}
+
+#[wasm_bindgen]
+pub fn wire_tag_label(port_: MessagePort, tag: JsValue) {
+    wire_tag_label_impl(port_, tag)
+}
+
+#[wasm_bindgen]
+pub fn wire_header_name(port_: MessagePort, raw: Box<[u8]>) {
+    wire_header_name_impl(port_, raw)
+}
+
 #[wasm_bindgen]
 pub fn wire_multiply_by_ten(port_: MessagePort, measure: JsValue) {
     wire_multiply_by_ten_impl(port_, measure)
@@ -374,6 +689,16 @@ pub fn wire_handle_big_buffers(port_: MessagePort) {
     wire_handle_big_buffers_impl(port_)
 }
 
+#[wasm_bindgen]
+pub fn wire_handle_i128(port_: MessagePort, value: Box<[u8]>) {
+    wire_handle_i128_impl(port_, value)
+}
+
+#[wasm_bindgen]
+pub fn wire_handle_u128(port_: MessagePort, value: Box<[u8]>) {
+    wire_handle_u128_impl(port_, value)
+}
+
 #[wasm_bindgen]
 pub fn wire_datetime_utc(port_: MessagePort, d: i64) {
     wire_datetime_utc_impl(port_, d)
@@ -399,6 +724,11 @@ pub fn wire_duration(port_: MessagePort, d: i64) {
     wire_duration_impl(port_, d)
 }
 
+#[wasm_bindgen]
+pub fn wire_datetime_fixed_offset(port_: MessagePort, d: i64) {
+    wire_datetime_fixed_offset_impl(port_, d)
+}
+
 #[wasm_bindgen]
 pub fn wire_test_chrono(port_: MessagePort) {
     wire_test_chrono_impl(port_)
@@ -429,6 +759,16 @@ pub fn wire_handle_nested_uuids(port_: MessagePort, ids: JsValue) {
     wire_handle_nested_uuids_impl(port_, ids)
 }
 
+#[wasm_bindgen]
+pub fn wire_handle_decimal(port_: MessagePort, amount: String) {
+    wire_handle_decimal_impl(port_, amount)
+}
+
+#[wasm_bindgen]
+pub fn wire_sum_decimals(port_: MessagePort, amounts: JsValue) {
+    wire_sum_decimals_impl(port_, amounts)
+}
+
 #[wasm_bindgen]
 pub fn wire_new_msgid(port_: MessagePort, id: Box<[u8]>) {
     wire_new_msgid_impl(port_, id)
@@ -469,6 +809,21 @@ pub fn wire_last_number(port_: MessagePort, array: Box<[f64]>) {
     wire_last_number_impl(port_, array)
 }
 
+#[wasm_bindgen]
+pub fn wire_repeat_byte_4(port_: MessagePort, byte: u8) {
+    wire_repeat_byte_4_impl(port_, byte)
+}
+
+#[wasm_bindgen]
+pub fn wire_repeat_byte_8(port_: MessagePort, byte: u8) {
+    wire_repeat_byte_8_impl(port_, byte)
+}
+
+#[wasm_bindgen]
+pub fn wire_transpose(port_: MessagePort, matrix: Box<[f64]>) {
+    wire_transpose_impl(port_, matrix)
+}
+
 #[wasm_bindgen]
 pub fn wire_nested_id(port_: MessagePort, id: JsValue) {
     wire_nested_id_impl(port_, id)
@@ -554,11 +909,41 @@ pub fn wire_run_enum_opaque(port_: MessagePort, opaque: JsValue) {
     wire_run_enum_opaque_impl(port_, opaque)
 }
 
+#[wasm_bindgen]
+pub fn wire_create_trait_object(port_: MessagePort) {
+    wire_create_trait_object_impl(port_)
+}
+
+#[wasm_bindgen]
+pub fn wire_create_impl_trait(port_: MessagePort) {
+    wire_create_impl_trait_impl(port_)
+}
+
+#[wasm_bindgen]
+pub fn wire_run_trait_object(port_: MessagePort, obj: JsValue) {
+    wire_run_trait_object_impl(port_, obj)
+}
+
 #[wasm_bindgen]
 pub fn wire_run_opaque(port_: MessagePort, opaque: JsValue) {
     wire_run_opaque_impl(port_, opaque)
 }
 
+#[wasm_bindgen]
+pub fn wire_create_shared_opaque(port_: MessagePort) {
+    wire_create_shared_opaque_impl(port_)
+}
+
+#[wasm_bindgen]
+pub fn wire_run_shared_opaque(port_: MessagePort, opaque: JsValue) {
+    wire_run_shared_opaque_impl(port_, opaque)
+}
+
+#[wasm_bindgen]
+pub fn wire_shared_opaque_address(port_: MessagePort, opaque: JsValue) {
+    wire_shared_opaque_address_impl(port_, opaque)
+}
+
 #[wasm_bindgen]
 pub fn wire_run_opaque_with_delay(port_: MessagePort, opaque: JsValue) {
     wire_run_opaque_with_delay_impl(port_, opaque)
@@ -694,6 +1079,16 @@ pub fn wire_frb_sync_generator_test() -> support::WireSyncReturn {
     wire_frb_sync_generator_test_impl()
 }
 
+#[wasm_bindgen]
+pub fn wire_observe_counter(port_: MessagePort, counter: JsValue) {
+    wire_observe_counter_impl(port_, counter)
+}
+
+#[wasm_bindgen]
+pub fn wire_is_counter_alive(port_: MessagePort, counter: JsValue) {
+    wire_is_counter_alive_impl(port_, counter)
+}
+
 #[wasm_bindgen]
 pub fn wire_handle_type_alias_id(port_: MessagePort, input: u64) {
     wire_handle_type_alias_id_impl(port_, input)
@@ -715,37 +1110,87 @@ pub fn wire_empty_struct(port_: MessagePort, empty: JsValue) {
 }
 
 #[wasm_bindgen]
-pub fn wire_sum__method__SumWith(port_: MessagePort, that: JsValue, y: u32, z: u32) {
-    wire_sum__method__SumWith_impl(port_, that, y, z)
+pub fn wire_handle_hash_map(port_: MessagePort, map: JsValue) {
+    wire_handle_hash_map_impl(port_, map)
 }
 
 #[wasm_bindgen]
-pub fn wire_new__static_method__ConcatenateWith(port_: MessagePort, a: String) {
-    wire_new__static_method__ConcatenateWith_impl(port_, a)
+pub fn wire_handle_hash_map_of_struct(port_: MessagePort, map: JsValue) {
+    wire_handle_hash_map_of_struct_impl(port_, map)
 }
 
 #[wasm_bindgen]
-pub fn wire_concatenate__method__ConcatenateWith(port_: MessagePort, that: JsValue, b: String) {
-    wire_concatenate__method__ConcatenateWith_impl(port_, that, b)
+pub fn wire_count_weekdays(port_: MessagePort, days: JsValue) {
+    wire_count_weekdays_impl(port_, days)
 }
 
 #[wasm_bindgen]
-pub fn wire_concatenate_static__static_method__ConcatenateWith(
-    port_: MessagePort,
-    a: String,
-    b: String,
-) {
-    wire_concatenate_static__static_method__ConcatenateWith_impl(port_, a, b)
+pub fn wire_index_words(port_: MessagePort, words: JsValue) {
+    wire_index_words_impl(port_, words)
 }
 
 #[wasm_bindgen]
-pub fn wire_handle_some_stream_sink__method__ConcatenateWith(
-    port_: MessagePort,
-    that: JsValue,
-    key: u32,
-    max: u32,
-) {
-    wire_handle_some_stream_sink__method__ConcatenateWith_impl(port_, that, key, max)
+pub fn wire_handle_colliding_keys(port_: MessagePort, map: JsValue) {
+    wire_handle_colliding_keys_impl(port_, map)
+}
+
+#[wasm_bindgen]
+pub fn wire_handle_btree_set(port_: MessagePort, set: JsValue) {
+    wire_handle_btree_set_impl(port_, set)
+}
+
+#[wasm_bindgen]
+pub fn wire_handle_btree_map(port_: MessagePort, map: JsValue) {
+    wire_handle_btree_map_impl(port_, map)
+}
+
+#[wasm_bindgen]
+pub fn wire_group_attributes(port_: MessagePort, attributes: JsValue) {
+    wire_group_attributes_impl(port_, attributes)
+}
+
+#[wasm_bindgen]
+pub fn wire_handle_tuple(port_: MessagePort, tuple: JsValue) {
+    wire_handle_tuple_impl(port_, tuple)
+}
+
+#[wasm_bindgen]
+pub fn wire_handle_nested_tuple(port_: MessagePort, tuple: JsValue) {
+    wire_handle_nested_tuple_impl(port_, tuple)
+}
+
+#[wasm_bindgen]
+pub fn wire_sum__method__SumWith(port_: MessagePort, that: JsValue, y: u32, z: u32) {
+    wire_sum__method__SumWith_impl(port_, that, y, z)
+}
+
+#[wasm_bindgen]
+pub fn wire_new__static_method__ConcatenateWith(port_: MessagePort, a: String) {
+    wire_new__static_method__ConcatenateWith_impl(port_, a)
+}
+
+#[wasm_bindgen]
+pub fn wire_concatenate__method__ConcatenateWith(port_: MessagePort, that: JsValue, b: String) {
+    wire_concatenate__method__ConcatenateWith_impl(port_, that, b)
+}
+
+#[wasm_bindgen]
+pub fn wire_concatenate_static__static_method__ConcatenateWith(
+    port_: MessagePort,
+    a: String,
+    b: String,
+) {
+    wire_concatenate_static__static_method__ConcatenateWith_impl(port_, a, b)
+}
+
+#[wasm_bindgen]
+pub fn wire_handle_some_stream_sink__method__ConcatenateWith(
+    port_: MessagePort,
+    that: JsValue,
+    key: u32,
+    max: u32,
+) {
+    wire_handle_some_stream_sink__method__ConcatenateWith_impl(port_, that, key, max)
 }
 
 #[wasm_bindgen]
@@ -772,6 +1217,181 @@ pub fn wire_handle_some_static_stream_sink_single_arg__static_method__Concatenat
     wire_handle_some_static_stream_sink_single_arg__static_method__ConcatenateWith_impl(port_)
 }
 
+#[wasm_bindgen]
+pub fn wire_start__static_method__Counter(port_: MessagePort, from: i32) {
+    wire_start__static_method__Counter_impl(port_, from)
+}
+
+#[wasm_bindgen]
+pub fn wire_increment__method__Counter(port_: MessagePort, that: JsValue, by: i32) {
+    wire_increment__method__Counter_impl(port_, that, by)
+}
+
+#[wasm_bindgen]
+pub fn wire_label__method__Counter(port_: MessagePort, that: JsValue) {
+    wire_label__method__Counter_impl(port_, that)
+}
+
+#[wasm_bindgen]
+pub fn wire_reset__method__Counter(port_: MessagePort, that: JsValue, to: i32) {
+    wire_reset__method__Counter_impl(port_, that, to)
+}
+
+#[wasm_bindgen]
+pub fn wire_count_down__method__Counter(port_: MessagePort, that: JsValue) {
+    wire_count_down__method__Counter_impl(port_, that)
+}
+
+#[wasm_bindgen]
+pub fn wire_increment_later__method__Counter(port_: MessagePort, that: JsValue, by: i32) {
+    wire_increment_later__method__Counter_impl(port_, that, by)
+}
+
+#[wasm_bindgen]
+pub fn wire_updates__method__Counter(port_: MessagePort, that: JsValue) {
+    wire_updates__method__Counter_impl(port_, that)
+}
+
+#[wasm_bindgen]
+pub fn wire_finish__method__Counter(port_: MessagePort, that: JsValue) {
+    wire_finish__method__Counter_impl(port_, that)
+}
+
+#[wasm_bindgen]
+pub fn wire_open__static_method__Rooms(port_: MessagePort) {
+    wire_open__static_method__Rooms_impl(port_)
+}
+
+#[wasm_bindgen]
+pub fn wire_join__method__Rooms(port_: MessagePort, that: JsValue, room: String) {
+    wire_join__method__Rooms_impl(port_, that, room)
+}
+
+#[wasm_bindgen]
+pub fn wire_post__method__Rooms(port_: MessagePort, that: JsValue, room: String, message: String) {
+    wire_post__method__Rooms_impl(port_, that, room, message)
+}
+
+#[wasm_bindgen]
+pub fn wire_close_room__method__Rooms(port_: MessagePort, that: JsValue, room: String) {
+    wire_close_room__method__Rooms_impl(port_, that, room)
+}
+
+#[wasm_bindgen]
+pub fn wire_named__static_method__Greeter(port_: MessagePort, name: String) {
+    wire_named__static_method__Greeter_impl(port_, name)
+}
+
+#[wasm_bindgen]
+pub fn wire_describe__method__Counter(port_: MessagePort, that: JsValue) {
+    wire_describe__method__Counter_impl(port_, that)
+}
+
+#[wasm_bindgen]
+pub fn wire_shout__method__Counter(port_: MessagePort, that: JsValue) {
+    wire_shout__method__Counter_impl(port_, that)
+}
+
+#[wasm_bindgen]
+pub fn wire_describe__method__Greeter(port_: MessagePort, that: JsValue) {
+    wire_describe__method__Greeter_impl(port_, that)
+}
+
+#[wasm_bindgen]
+pub fn wire_shout__method__Greeter(port_: MessagePort, that: JsValue) {
+    wire_shout__method__Greeter_impl(port_, that)
+}
+
+#[wasm_bindgen]
+pub fn wire_dispatch(port_: MessagePort, id: String, x: i32) {
+    wire_dispatch_impl(port_, id, x)
+}
+
+#[wasm_bindgen]
+pub fn wire_next_dart_iterator_string(port_: MessagePort, iterator: JsValue) {
+    wire_next_dart_iterator_string_impl(port_, iterator)
+}
+
+#[wasm_bindgen]
+pub fn wire_next_dart_iterator_i_32(port_: MessagePort, iterator: JsValue) {
+    wire_next_dart_iterator_i_32_impl(port_, iterator)
+}
+
+#[wasm_bindgen]
+pub fn wire_changes__method__Counter(port_: MessagePort, that: JsValue) {
+    wire_changes__method__Counter_impl(port_, that)
+}
+
+#[wasm_bindgen]
+pub fn wire_warm_up(port_: MessagePort) {
+    wire_warm_up_impl(port_)
+}
+
+#[wasm_bindgen]
+pub fn wire_upgrade_weak_counter(port_: MessagePort, weak: JsValue) {
+    wire_upgrade_weak_counter_impl(port_, weak)
+}
+
+#[wasm_bindgen]
+pub fn wire_instant_duration_since(port_: MessagePort, that: JsValue, earlier: JsValue) {
+    wire_instant_duration_since_impl(port_, that, earlier)
+}
+
+#[wasm_bindgen]
+pub fn wire_handle_stream_drain(arg: String) -> support::WireSyncReturn {
+    wire_handle_stream_drain_impl(arg)
+}
+
+#[wasm_bindgen]
+pub fn wire_handle_stream_of_struct_drain() -> support::WireSyncReturn {
+    wire_handle_stream_of_struct_drain_impl()
+}
+
+#[wasm_bindgen]
+pub fn wire_register_event_listener_drain() -> support::WireSyncReturn {
+    wire_register_event_listener_drain_impl()
+}
+
+#[wasm_bindgen]
+pub fn wire_handle_stream_sink_at_1_drain(key: u32, max: u32) -> support::WireSyncReturn {
+    wire_handle_stream_sink_at_1_drain_impl(key, max)
+}
+
+#[wasm_bindgen]
+pub fn wire_handle_stream_sink_at_2_drain(key: u32, max: u32) -> support::WireSyncReturn {
+    wire_handle_stream_sink_at_2_drain_impl(key, max)
+}
+
+#[wasm_bindgen]
+pub fn wire_handle_stream_sink_at_3_drain(key: u32, max: u32) -> support::WireSyncReturn {
+    wire_handle_stream_sink_at_3_drain_impl(key, max)
+}
+
+#[wasm_bindgen]
+pub fn wire_handle_stream_closed_by_drop_drain(max: u32) -> support::WireSyncReturn {
+    wire_handle_stream_closed_by_drop_drain_impl(max)
+}
+
+#[wasm_bindgen]
+pub fn wire_count_from_drain(from: u32, count: u32) -> support::WireSyncReturn {
+    wire_count_from_drain_impl(from, count)
+}
+
+#[wasm_bindgen]
+pub fn wire_parse_numbers_drain(input: String) -> support::WireSyncReturn {
+    wire_parse_numbers_drain_impl(input)
+}
+
+#[wasm_bindgen]
+pub fn wire_handle_broadcast_stream_drain(max: u32) -> support::WireSyncReturn {
+    wire_handle_broadcast_stream_drain_impl(max)
+}
+
+#[wasm_bindgen]
+pub fn wire_handle_stream_closed_by_threads_drain(threads: u32) -> support::WireSyncReturn {
+    wire_handle_stream_closed_by_threads_drain_impl(threads)
+}
+
 // Section: allocate functions
 
 // Section: related functions
@@ -791,126 +1411,472 @@ pub fn share_opaque_BoxDartDebug(ptr: *const c_void) -> *const c_void {
     }
 }
 
+#[wasm_bindgen]
+pub fn send_opaque_BoxDartDebug(ptr: *const c_void) -> *const c_void {
+    if !flutter_rust_bridge::is_send_sync!(Box<dyn DartDebug>) {
+        return core::ptr::null();
+    }
+    unsafe {
+        Arc::<Box<dyn DartDebug>>::increment_strong_count(ptr as _);
+        ptr
+    }
+}
+
+#[wasm_bindgen]
+pub fn drop_opaque_Counter(ptr: *const c_void) {
+    unsafe {
+        Arc::<Counter>::decrement_strong_count(ptr as _);
+    }
+}
+
+#[wasm_bindgen]
+pub fn share_opaque_Counter(ptr: *const c_void) -> *const c_void {
+    unsafe {
+        Arc::<Counter>::increment_strong_count(ptr as _);
+        ptr
+    }
+}
+
+#[wasm_bindgen]
+pub fn send_opaque_Counter(ptr: *const c_void) -> *const c_void {
+    if !flutter_rust_bridge::is_send_sync!(Counter) {
+        return core::ptr::null();
+    }
+    unsafe {
+        Arc::<Counter>::increment_strong_count(ptr as _);
+        ptr
+    }
+}
+
+#[wasm_bindgen]
+pub fn drop_opaque_DartIteratorI32(ptr: *const c_void) {
+    unsafe {
+        Arc::<DartIterator<i32>>::decrement_strong_count(ptr as _);
+    }
+}
+
+#[wasm_bindgen]
+pub fn share_opaque_DartIteratorI32(ptr: *const c_void) -> *const c_void {
+    unsafe {
+        Arc::<DartIterator<i32>>::increment_strong_count(ptr as _);
+        ptr
+    }
+}
+
+#[wasm_bindgen]
+pub fn send_opaque_DartIteratorI32(ptr: *const c_void) -> *const c_void {
+    if !flutter_rust_bridge::is_send_sync!(DartIterator<i32>) {
+        return core::ptr::null();
+    }
+    unsafe {
+        Arc::<DartIterator<i32>>::increment_strong_count(ptr as _);
+        ptr
+    }
+}
+
+#[wasm_bindgen]
+pub fn drop_opaque_DartIteratorString(ptr: *const c_void) {
+    unsafe {
+        Arc::<DartIterator<String>>::decrement_strong_count(ptr as _);
+    }
+}
+
+#[wasm_bindgen]
+pub fn share_opaque_DartIteratorString(ptr: *const c_void) -> *const c_void {
+    unsafe {
+        Arc::<DartIterator<String>>::increment_strong_count(ptr as _);
+        ptr
+    }
+}
+
+#[wasm_bindgen]
+pub fn send_opaque_DartIteratorString(ptr: *const c_void) -> *const c_void {
+    if !flutter_rust_bridge::is_send_sync!(DartIterator<String>) {
+        return core::ptr::null();
+    }
+    unsafe {
+        Arc::<DartIterator<String>>::increment_strong_count(ptr as _);
+        ptr
+    }
+}
+
 #[wasm_bindgen]
 pub fn drop_opaque_FrbOpaqueReturn(ptr: *const c_void) {
     unsafe {
-        Arc::<FrbOpaqueReturn>::decrement_strong_count(ptr as _);
+        Arc::<FrbOpaqueReturn>::decrement_strong_count(ptr as _);
+    }
+}
+
+#[wasm_bindgen]
+pub fn share_opaque_FrbOpaqueReturn(ptr: *const c_void) -> *const c_void {
+    unsafe {
+        Arc::<FrbOpaqueReturn>::increment_strong_count(ptr as _);
+        ptr
+    }
+}
+
+#[wasm_bindgen]
+pub fn send_opaque_FrbOpaqueReturn(ptr: *const c_void) -> *const c_void {
+    if !flutter_rust_bridge::is_send_sync!(FrbOpaqueReturn) {
+        return core::ptr::null();
+    }
+    unsafe {
+        Arc::<FrbOpaqueReturn>::increment_strong_count(ptr as _);
+        ptr
+    }
+}
+
+#[wasm_bindgen]
+pub fn drop_opaque_FrbOpaqueSyncReturn(ptr: *const c_void) {
+    unsafe {
+        Arc::<FrbOpaqueSyncReturn>::decrement_strong_count(ptr as _);
+    }
+}
+
+#[wasm_bindgen]
+pub fn share_opaque_FrbOpaqueSyncReturn(ptr: *const c_void) -> *const c_void {
+    unsafe {
+        Arc::<FrbOpaqueSyncReturn>::increment_strong_count(ptr as _);
+        ptr
+    }
+}
+
+#[wasm_bindgen]
+pub fn send_opaque_FrbOpaqueSyncReturn(ptr: *const c_void) -> *const c_void {
+    if !flutter_rust_bridge::is_send_sync!(FrbOpaqueSyncReturn) {
+        return core::ptr::null();
+    }
+    unsafe {
+        Arc::<FrbOpaqueSyncReturn>::increment_strong_count(ptr as _);
+        ptr
+    }
+}
+
+#[wasm_bindgen]
+pub fn drop_opaque_Greeter(ptr: *const c_void) {
+    unsafe {
+        Arc::<Greeter>::decrement_strong_count(ptr as _);
+    }
+}
+
+#[wasm_bindgen]
+pub fn share_opaque_Greeter(ptr: *const c_void) -> *const c_void {
+    unsafe {
+        Arc::<Greeter>::increment_strong_count(ptr as _);
+        ptr
+    }
+}
+
+#[wasm_bindgen]
+pub fn send_opaque_Greeter(ptr: *const c_void) -> *const c_void {
+    if !flutter_rust_bridge::is_send_sync!(Greeter) {
+        return core::ptr::null();
+    }
+    unsafe {
+        Arc::<Greeter>::increment_strong_count(ptr as _);
+        ptr
+    }
+}
+
+#[wasm_bindgen]
+pub fn drop_opaque_HideData(ptr: *const c_void) {
+    unsafe {
+        Arc::<HideData>::decrement_strong_count(ptr as _);
+    }
+}
+
+#[wasm_bindgen]
+pub fn share_opaque_HideData(ptr: *const c_void) -> *const c_void {
+    unsafe {
+        Arc::<HideData>::increment_strong_count(ptr as _);
+        ptr
+    }
+}
+
+#[wasm_bindgen]
+pub fn send_opaque_HideData(ptr: *const c_void) -> *const c_void {
+    if !flutter_rust_bridge::is_send_sync!(HideData) {
+        return core::ptr::null();
+    }
+    unsafe {
+        Arc::<HideData>::increment_strong_count(ptr as _);
+        ptr
+    }
+}
+
+#[wasm_bindgen]
+pub fn drop_opaque_I32(ptr: *const c_void) {
+    unsafe {
+        Arc::<i32>::decrement_strong_count(ptr as _);
+    }
+}
+
+#[wasm_bindgen]
+pub fn share_opaque_I32(ptr: *const c_void) -> *const c_void {
+    unsafe {
+        Arc::<i32>::increment_strong_count(ptr as _);
+        ptr
+    }
+}
+
+#[wasm_bindgen]
+pub fn send_opaque_I32(ptr: *const c_void) -> *const c_void {
+    if !flutter_rust_bridge::is_send_sync!(i32) {
+        return core::ptr::null();
+    }
+    unsafe {
+        Arc::<i32>::increment_strong_count(ptr as _);
+        ptr
+    }
+}
+
+#[wasm_bindgen]
+pub fn drop_opaque_Instant(ptr: *const c_void) {
+    unsafe {
+        Arc::<std::time::Instant>::decrement_strong_count(ptr as _);
+    }
+}
+
+#[wasm_bindgen]
+pub fn share_opaque_Instant(ptr: *const c_void) -> *const c_void {
+    unsafe {
+        Arc::<std::time::Instant>::increment_strong_count(ptr as _);
+        ptr
+    }
+}
+
+#[wasm_bindgen]
+pub fn send_opaque_Instant(ptr: *const c_void) -> *const c_void {
+    if !flutter_rust_bridge::is_send_sync!(std::time::Instant) {
+        return core::ptr::null();
+    }
+    unsafe {
+        Arc::<std::time::Instant>::increment_strong_count(ptr as _);
+        ptr
+    }
+}
+
+#[wasm_bindgen]
+pub fn drop_opaque_MutexHideData(ptr: *const c_void) {
+    unsafe {
+        Arc::<Mutex<HideData>>::decrement_strong_count(ptr as _);
+    }
+}
+
+#[wasm_bindgen]
+pub fn share_opaque_MutexHideData(ptr: *const c_void) -> *const c_void {
+    unsafe {
+        Arc::<Mutex<HideData>>::increment_strong_count(ptr as _);
+        ptr
     }
 }
 
 #[wasm_bindgen]
-pub fn share_opaque_FrbOpaqueReturn(ptr: *const c_void) -> *const c_void {
+pub fn send_opaque_MutexHideData(ptr: *const c_void) -> *const c_void {
+    if !flutter_rust_bridge::is_send_sync!(Mutex<HideData>) {
+        return core::ptr::null();
+    }
     unsafe {
-        Arc::<FrbOpaqueReturn>::increment_strong_count(ptr as _);
+        Arc::<Mutex<HideData>>::increment_strong_count(ptr as _);
         ptr
     }
 }
 
 #[wasm_bindgen]
-pub fn drop_opaque_FrbOpaqueSyncReturn(ptr: *const c_void) {
+pub fn drop_opaque_NonSendHideData(ptr: *const c_void) {
     unsafe {
-        Arc::<FrbOpaqueSyncReturn>::decrement_strong_count(ptr as _);
+        Arc::<NonSendHideData>::decrement_strong_count(ptr as _);
     }
 }
 
 #[wasm_bindgen]
-pub fn share_opaque_FrbOpaqueSyncReturn(ptr: *const c_void) -> *const c_void {
+pub fn share_opaque_NonSendHideData(ptr: *const c_void) -> *const c_void {
     unsafe {
-        Arc::<FrbOpaqueSyncReturn>::increment_strong_count(ptr as _);
+        Arc::<NonSendHideData>::increment_strong_count(ptr as _);
         ptr
     }
 }
 
 #[wasm_bindgen]
-pub fn drop_opaque_HideData(ptr: *const c_void) {
+pub fn send_opaque_NonSendHideData(ptr: *const c_void) -> *const c_void {
+    if !flutter_rust_bridge::is_send_sync!(NonSendHideData) {
+        return core::ptr::null();
+    }
     unsafe {
-        Arc::<HideData>::decrement_strong_count(ptr as _);
+        Arc::<NonSendHideData>::increment_strong_count(ptr as _);
+        ptr
     }
 }
 
 #[wasm_bindgen]
-pub fn share_opaque_HideData(ptr: *const c_void) -> *const c_void {
+pub fn drop_opaque_Rooms(ptr: *const c_void) {
     unsafe {
-        Arc::<HideData>::increment_strong_count(ptr as _);
-        ptr
+        Arc::<Rooms>::decrement_strong_count(ptr as _);
     }
 }
 
 #[wasm_bindgen]
-pub fn drop_opaque_I32(ptr: *const c_void) {
+pub fn share_opaque_Rooms(ptr: *const c_void) -> *const c_void {
     unsafe {
-        Arc::<i32>::decrement_strong_count(ptr as _);
+        Arc::<Rooms>::increment_strong_count(ptr as _);
+        ptr
     }
 }
 
 #[wasm_bindgen]
-pub fn share_opaque_I32(ptr: *const c_void) -> *const c_void {
+pub fn send_opaque_Rooms(ptr: *const c_void) -> *const c_void {
+    if !flutter_rust_bridge::is_send_sync!(Rooms) {
+        return core::ptr::null();
+    }
     unsafe {
-        Arc::<i32>::increment_strong_count(ptr as _);
+        Arc::<Rooms>::increment_strong_count(ptr as _);
         ptr
     }
 }
 
 #[wasm_bindgen]
-pub fn drop_opaque_MutexHideData(ptr: *const c_void) {
+pub fn drop_opaque_RwLockHideData(ptr: *const c_void) {
     unsafe {
-        Arc::<Mutex<HideData>>::decrement_strong_count(ptr as _);
+        Arc::<RwLock<HideData>>::decrement_strong_count(ptr as _);
     }
 }
 
 #[wasm_bindgen]
-pub fn share_opaque_MutexHideData(ptr: *const c_void) -> *const c_void {
+pub fn share_opaque_RwLockHideData(ptr: *const c_void) -> *const c_void {
     unsafe {
-        Arc::<Mutex<HideData>>::increment_strong_count(ptr as _);
+        Arc::<RwLock<HideData>>::increment_strong_count(ptr as _);
         ptr
     }
 }
 
 #[wasm_bindgen]
-pub fn drop_opaque_NonSendHideData(ptr: *const c_void) {
+pub fn send_opaque_RwLockHideData(ptr: *const c_void) -> *const c_void {
+    if !flutter_rust_bridge::is_send_sync!(RwLock<HideData>) {
+        return core::ptr::null();
+    }
     unsafe {
-        Arc::<NonSendHideData>::decrement_strong_count(ptr as _);
+        Arc::<RwLock<HideData>>::increment_strong_count(ptr as _);
+        ptr
     }
 }
 
 #[wasm_bindgen]
-pub fn share_opaque_NonSendHideData(ptr: *const c_void) -> *const c_void {
+pub fn drop_opaque_WeakCounter(ptr: *const c_void) {
     unsafe {
-        Arc::<NonSendHideData>::increment_strong_count(ptr as _);
-        ptr
+        Arc::<std::sync::Weak<Counter>>::decrement_strong_count(ptr as _);
     }
 }
 
 #[wasm_bindgen]
-pub fn drop_opaque_RwLockHideData(ptr: *const c_void) {
+pub fn share_opaque_WeakCounter(ptr: *const c_void) -> *const c_void {
     unsafe {
-        Arc::<RwLock<HideData>>::decrement_strong_count(ptr as _);
+        Arc::<std::sync::Weak<Counter>>::increment_strong_count(ptr as _);
+        ptr
     }
 }
 
 #[wasm_bindgen]
-pub fn share_opaque_RwLockHideData(ptr: *const c_void) -> *const c_void {
+pub fn send_opaque_WeakCounter(ptr: *const c_void) -> *const c_void {
+    if !flutter_rust_bridge::is_send_sync!(std::sync::Weak<Counter>) {
+        return core::ptr::null();
+    }
     unsafe {
-        Arc::<RwLock<HideData>>::increment_strong_count(ptr as _);
+        Arc::<std::sync::Weak<Counter>>::increment_strong_count(ptr as _);
         ptr
     }
 }
 
 // Section: impl Wire2Api
 
+impl Wire2Api<bytes::Bytes> for Box<[u8]> {
+    fn wire2api(self) -> bytes::Bytes {
+        let vec: Vec<u8> = self.wire2api();
+        wire2api_bytes(vec)
+    }
+}
 impl Wire2Api<chrono::Duration> for i64 {
     fn wire2api(self) -> chrono::Duration {
         chrono::Duration::milliseconds(self)
     }
 }
 
+impl Wire2Api<Version> for Box<[u8]> {
+    fn wire2api(self) -> Version {
+        let bytes: Vec<u8> = self.wire2api();
+        crate::codecs::version::decode(&bytes)
+    }
+}
+
+impl Wire2Api<std::borrow::Cow<'static, [u8]>> for Box<[u8]> {
+    fn wire2api(self) -> std::borrow::Cow<'static, [u8]> {
+        let vec: Vec<u8> = self.wire2api();
+        std::borrow::Cow::Owned(vec)
+    }
+}
+impl Wire2Api<std::borrow::Cow<'static, str>> for String {
+    fn wire2api(self) -> std::borrow::Cow<'static, str> {
+        let s: String = self.wire2api();
+        std::borrow::Cow::Owned(s)
+    }
+}
+
 impl Wire2Api<DartOpaque> for JsValue {
     fn wire2api(self) -> DartOpaque {
         let arr = self.dyn_into::<JsArray>().unwrap();
         unsafe { DartOpaque::new(arr.get(0), arr.get(1)) }
     }
 }
+impl Wire2Api<rust_decimal::Decimal> for String {
+    fn wire2api(self) -> rust_decimal::Decimal {
+        let s: String = self.wire2api();
+        wire2api_decimal(s)
+    }
+}
+
+impl Wire2Api<std::num::NonZeroU32> for u32 {
+    fn wire2api(self) -> std::num::NonZeroU32 {
+        let value: u32 = self.wire2api();
+        wire2api_non_zero(value)
+    }
+}
+impl Wire2Api<std::num::NonZeroU64> for u64 {
+    fn wire2api(self) -> std::num::NonZeroU64 {
+        let value: u64 = self.wire2api();
+        wire2api_non_zero(value)
+    }
+}
+
+impl Wire2Api<std::path::PathBuf> for Box<[u16]> {
+    fn wire2api(self) -> std::path::PathBuf {
+        let units: Vec<u16> = self.wire2api();
+        wire2api_path(units)
+    }
+}
+impl Wire2Api<std::ops::RangeInclusive<f64>> for Box<[f64]> {
+    fn wire2api(self) -> std::ops::RangeInclusive<f64> {
+        let bounds: Vec<f64> = self.wire2api();
+        wire2api_range_inclusive(bounds)
+    }
+}
+impl Wire2Api<std::ops::Range<u32>> for Box<[u32]> {
+    fn wire2api(self) -> std::ops::Range<u32> {
+        let bounds: Vec<u32> = self.wire2api();
+        wire2api_range(bounds)
+    }
+}
+
+impl Wire2Api<flutter_rust_bridge::SharedBuffer> for usize {
+    fn wire2api(self) -> flutter_rust_bridge::SharedBuffer {
+        flutter_rust_bridge::SharedBuffer::from_wire(self)
+    }
+}
 
+impl Wire2Api<std::net::SocketAddr> for Box<[u8]> {
+    fn wire2api(self) -> std::net::SocketAddr {
+        let bytes: Vec<u8> = self.wire2api();
+        wire2api_socket_addr(bytes)
+    }
+}
 impl Wire2Api<String> for String {
     fn wire2api(self) -> String {
         self
@@ -925,6 +1891,11 @@ impl Wire2Api<Vec<String>> for JsValue {
             .collect()
     }
 }
+impl Wire2Api<std::time::SystemTime> for i64 {
+    fn wire2api(self) -> std::time::SystemTime {
+        wire2api_system_time(self)
+    }
+}
 
 impl Wire2Api<uuid::Uuid> for Box<[u8]> {
     fn wire2api(self) -> uuid::Uuid {
@@ -938,6 +1909,7 @@ impl Wire2Api<Vec<uuid::Uuid>> for Box<[u8]> {
         wire2api_uuids(multiple)
     }
 }
+
 impl Wire2Api<ZeroCopyBuffer<Vec<u8>>> for Box<[u8]> {
     fn wire2api(self) -> ZeroCopyBuffer<Vec<u8>> {
         ZeroCopyBuffer(self.wire2api())
@@ -969,6 +1941,20 @@ impl Wire2Api<ApplicationEnvVar> for JsValue {
         ApplicationEnvVar(self_.get(0).wire2api(), self_.get(1).wire2api())
     }
 }
+impl Wire2Api<ApplicationMessage> for JsValue {
+    fn wire2api(self) -> ApplicationMessage {
+        let self_ = self.unchecked_into::<JsArray>();
+        match self_.get(0).unchecked_into_f64() as _ {
+            0 => ApplicationMessage::DisplayMessage(self_.get(1).wire2api()),
+            1 => ApplicationMessage::RenderPixel {
+                x: self_.get(1).wire2api(),
+                y: self_.get(2).wire2api(),
+            },
+            2 => ApplicationMessage::Exit,
+            _ => unreachable!(),
+        }
+    }
+}
 
 impl Wire2Api<ApplicationSettings> for JsValue {
     fn wire2api(self) -> ApplicationSettings {
@@ -1022,6 +2008,38 @@ impl Wire2Api<Box<[u8; 1600]>> for Box<[u8]> {
     }
 }
 
+impl Wire2Api<std::collections::BTreeMap<i32, String>> for JsValue {
+    fn wire2api(self) -> std::collections::BTreeMap<i32, String> {
+        let self_ = self.dyn_into::<JsArray>().unwrap();
+        assert_eq!(
+            self_.length(),
+            2,
+            "Expected 2 elements, got {}",
+            self_.length()
+        );
+        let keys: Vec<i32> = self_.get(0).wire2api();
+        let values: Vec<String> = self_.get(1).wire2api();
+        keys.into_iter().zip(values).collect()
+    }
+}
+
+impl Wire2Api<char> for u32 {
+    fn wire2api(self) -> char {
+        wire2api_char(self)
+    }
+}
+impl Wire2Api<CollidingKey> for JsValue {
+    fn wire2api(self) -> CollidingKey {
+        let self_ = self.dyn_into::<JsArray>().unwrap();
+        assert_eq!(
+            self_.length(),
+            1,
+            "Expected 1 elements, got {}",
+            self_.length()
+        );
+        CollidingKey(self_.get(0).wire2api())
+    }
+}
 impl Wire2Api<ConcatenateWith> for JsValue {
     fn wire2api(self) -> ConcatenateWith {
         let self_ = self.dyn_into::<JsArray>().unwrap();
@@ -1076,6 +2094,21 @@ impl Wire2Api<Distance> for JsValue {
         }
     }
 }
+impl Wire2Api<Document> for JsValue {
+    fn wire2api(self) -> Document {
+        let self_ = self.dyn_into::<JsArray>().unwrap();
+        assert_eq!(
+            self_.length(),
+            2,
+            "Expected 2 elements, got {}",
+            self_.length()
+        );
+        Document {
+            metadata: self_.get(0).wire2api(),
+            title: self_.get(1).wire2api(),
+        }
+    }
+}
 impl Wire2Api<Empty> for JsValue {
     fn wire2api(self) -> Empty {
         let self_ = self.dyn_into::<JsArray>().unwrap();
@@ -1139,10 +2172,15 @@ impl Wire2Api<ExoticOptionals> for JsValue {
     }
 }
 
+impl Wire2Api<[f32; 3]> for Box<[f32]> {
+    fn wire2api(self) -> [f32; 3] {
+        support::from_slice_to_array(&self)
+    }
+}
+
 impl Wire2Api<[f64; 16]> for Box<[f64]> {
     fn wire2api(self) -> [f64; 16] {
-        let vec: Vec<f64> = self.wire2api();
-        support::from_vec_to_array(vec)
+        support::from_slice_to_array(&self)
     }
 }
 impl Wire2Api<FeatureChrono> for JsValue {
@@ -1199,11 +2237,90 @@ impl Wire2Api<Vec<f64>> for Box<[f64]> {
         self.into_vec()
     }
 }
+impl Wire2Api<Frame> for JsValue {
+    fn wire2api(self) -> Frame {
+        let self_ = self.dyn_into::<JsArray>().unwrap();
+        assert_eq!(
+            self_.length(),
+            3,
+            "Expected 3 elements, got {}",
+            self_.length()
+        );
+        Frame {
+            index: self_.get(0).wire2api(),
+            pixels: self_.get(1).wire2api(),
+            depths: self_.get(2).wire2api(),
+        }
+    }
+}
+impl Wire2Api<GridCell> for JsValue {
+    fn wire2api(self) -> GridCell {
+        let self_ = self.dyn_into::<JsArray>().unwrap();
+        assert_eq!(
+            self_.length(),
+            2,
+            "Expected 2 elements, got {}",
+            self_.length()
+        );
+        GridCell {
+            row: self_.get(0).wire2api(),
+            column: self_.get(1).wire2api(),
+        }
+    }
+}
+impl Wire2Api<std::collections::HashMap<String, i32>> for JsValue {
+    fn wire2api(self) -> std::collections::HashMap<String, i32> {
+        let self_ = self.dyn_into::<JsArray>().unwrap();
+        assert_eq!(
+            self_.length(),
+            2,
+            "Expected 2 elements, got {}",
+            self_.length()
+        );
+        let keys: Vec<String> = self_.get(0).wire2api();
+        let values: Vec<i32> = self_.get(1).wire2api();
+        keys.into_iter().zip(values).collect()
+    }
+}
+impl Wire2Api<std::collections::HashMap<CollidingKey, u32>> for JsValue {
+    fn wire2api(self) -> std::collections::HashMap<CollidingKey, u32> {
+        let self_ = self.dyn_into::<JsArray>().unwrap();
+        assert_eq!(
+            self_.length(),
+            2,
+            "Expected 2 elements, got {}",
+            self_.length()
+        );
+        let keys: Vec<CollidingKey> = self_.get(0).wire2api();
+        let values: Vec<u32> = self_.get(1).wire2api();
+        keys.into_iter().zip(values).collect()
+    }
+}
+impl Wire2Api<std::collections::HashMap<u32, Attribute>> for JsValue {
+    fn wire2api(self) -> std::collections::HashMap<u32, Attribute> {
+        let self_ = self.dyn_into::<JsArray>().unwrap();
+        assert_eq!(
+            self_.length(),
+            2,
+            "Expected 2 elements, got {}",
+            self_.length()
+        );
+        let keys: Vec<u32> = self_.get(0).wire2api();
+        let values: Vec<Attribute> = self_.get(1).wire2api();
+        keys.into_iter().zip(values).collect()
+    }
+}
+
+impl Wire2Api<i128> for Box<[u8]> {
+    fn wire2api(self) -> i128 {
+        let bytes: Vec<u8> = self.wire2api();
+        wire2api_i128(bytes)
+    }
+}
 
 impl Wire2Api<[i32; 2]> for Box<[i32]> {
     fn wire2api(self) -> [i32; 2] {
-        let vec: Vec<i32> = self.wire2api();
-        support::from_vec_to_array(vec)
+        support::from_slice_to_array(&self)
     }
 }
 
@@ -1244,6 +2361,15 @@ impl Wire2Api<Vec<DartOpaque>> for JsValue {
             .collect()
     }
 }
+impl Wire2Api<Vec<rust_decimal::Decimal>> for JsValue {
+    fn wire2api(self) -> Vec<rust_decimal::Decimal> {
+        self.dyn_into::<JsArray>()
+            .unwrap()
+            .iter()
+            .map(Wire2Api::wire2api)
+            .collect()
+    }
+}
 impl Wire2Api<Vec<RustOpaque<HideData>>> for JsValue {
     fn wire2api(self) -> Vec<RustOpaque<HideData>> {
         self.dyn_into::<JsArray>()
@@ -1271,6 +2397,33 @@ impl Wire2Api<Vec<Attribute>> for JsValue {
             .collect()
     }
 }
+impl Wire2Api<Vec<char>> for JsValue {
+    fn wire2api(self) -> Vec<char> {
+        self.dyn_into::<JsArray>()
+            .unwrap()
+            .iter()
+            .map(Wire2Api::wire2api)
+            .collect()
+    }
+}
+impl Wire2Api<Vec<CollidingKey>> for JsValue {
+    fn wire2api(self) -> Vec<CollidingKey> {
+        self.dyn_into::<JsArray>()
+            .unwrap()
+            .iter()
+            .map(Wire2Api::wire2api)
+            .collect()
+    }
+}
+impl Wire2Api<Vec<Vec<i32>>> for JsValue {
+    fn wire2api(self) -> Vec<Vec<i32>> {
+        self.dyn_into::<JsArray>()
+            .unwrap()
+            .iter()
+            .map(Wire2Api::wire2api)
+            .collect()
+    }
+}
 impl Wire2Api<Vec<MySize>> for JsValue {
     fn wire2api(self) -> Vec<MySize> {
         self.dyn_into::<JsArray>()
@@ -1307,6 +2460,15 @@ impl Wire2Api<Vec<TestId>> for JsValue {
             .collect()
     }
 }
+impl Wire2Api<Vec<Weekdays>> for JsValue {
+    fn wire2api(self) -> Vec<Weekdays> {
+        self.dyn_into::<JsArray>()
+            .unwrap()
+            .iter()
+            .map(Wire2Api::wire2api)
+            .collect()
+    }
+}
 impl Wire2Api<Measure> for JsValue {
     fn wire2api(self) -> Measure {
         let self_ = self.unchecked_into::<JsArray>();
@@ -1329,6 +2491,35 @@ impl Wire2Api<MessageId> for JsValue {
         MessageId(self_.get(0).wire2api())
     }
 }
+impl Wire2Api<Metadata> for JsValue {
+    fn wire2api(self) -> Metadata {
+        let self_ = self.dyn_into::<JsArray>().unwrap();
+        assert_eq!(
+            self_.length(),
+            2,
+            "Expected 2 elements, got {}",
+            self_.length()
+        );
+        Metadata {
+            id: self_.get(0).wire2api(),
+            created_by: self_.get(1).wire2api(),
+        }
+    }
+}
+impl Wire2Api<Money> for JsValue {
+    fn wire2api(self) -> Money {
+        let self_ = self.dyn_into::<JsArray>().unwrap();
+        assert_eq!(
+            self_.length(),
+            1,
+            "Expected 1 elements, got {}",
+            self_.length()
+        );
+        Money {
+            cents: self_.get(0).wire2api(),
+        }
+    }
+}
 
 impl Wire2Api<MyNestedStruct> for JsValue {
     fn wire2api(self) -> MyNestedStruct {
@@ -1515,19 +2706,79 @@ impl Wire2Api<Option<Vec<i8>>> for Option<Box<[i8]>> {
         self.map(Wire2Api::wire2api)
     }
 }
-impl Wire2Api<Option<Vec<Attribute>>> for JsValue {
-    fn wire2api(self) -> Option<Vec<Attribute>> {
-        (!self.is_undefined() && !self.is_null()).then(|| self.wire2api())
+impl Wire2Api<Option<Vec<Attribute>>> for JsValue {
+    fn wire2api(self) -> Option<Vec<Attribute>> {
+        (!self.is_undefined() && !self.is_null()).then(|| self.wire2api())
+    }
+}
+impl Wire2Api<Option<Vec<Option<Attribute>>>> for JsValue {
+    fn wire2api(self) -> Option<Vec<Option<Attribute>>> {
+        (!self.is_undefined() && !self.is_null()).then(|| self.wire2api())
+    }
+}
+impl Wire2Api<Option<Vec<u8>>> for Option<Box<[u8]>> {
+    fn wire2api(self) -> Option<Vec<u8>> {
+        self.map(Wire2Api::wire2api)
+    }
+}
+
+impl Wire2Api<Pair<i32>> for JsValue {
+    fn wire2api(self) -> Pair<i32> {
+        let self_ = self.dyn_into::<JsArray>().unwrap();
+        assert_eq!(
+            self_.length(),
+            2,
+            "Expected 2 elements, got {}",
+            self_.length()
+        );
+        Pair::<i32> {
+            first: self_.get(0).wire2api(),
+            second: self_.get(1).wire2api(),
+        }
+    }
+}
+impl Wire2Api<ProductId> for JsValue {
+    fn wire2api(self) -> ProductId {
+        let self_ = self.dyn_into::<JsArray>().unwrap();
+        assert_eq!(
+            self_.length(),
+            1,
+            "Expected 1 elements, got {}",
+            self_.length()
+        );
+        ProductId(self_.get(0).wire2api())
     }
 }
-impl Wire2Api<Option<Vec<Option<Attribute>>>> for JsValue {
-    fn wire2api(self) -> Option<Vec<Option<Attribute>>> {
-        (!self.is_undefined() && !self.is_null()).then(|| self.wire2api())
+impl Wire2Api<Progress> for JsValue {
+    fn wire2api(self) -> Progress {
+        let self_ = self.dyn_into::<JsArray>().unwrap();
+        assert_eq!(
+            self_.length(),
+            1,
+            "Expected 1 elements, got {}",
+            self_.length()
+        );
+        Progress {
+            percentage: self_.get(0).wire2api(),
+        }
     }
 }
-impl Wire2Api<Option<Vec<u8>>> for Option<Box<[u8]>> {
-    fn wire2api(self) -> Option<Vec<u8>> {
-        self.map(Wire2Api::wire2api)
+impl Wire2Api<Sample> for JsValue {
+    fn wire2api(self) -> Sample {
+        let self_ = self.dyn_into::<JsArray>().unwrap();
+        assert_eq!(
+            self_.length(),
+            5,
+            "Expected 5 elements, got {}",
+            self_.length()
+        );
+        Sample {
+            index: self_.get(0).wire2api(),
+            x: self_.get(1).wire2api(),
+            y: self_.get(2).wire2api(),
+            valid: self_.get(3).wire2api(),
+            level: self_.get(4).wire2api(),
+        }
     }
 }
 impl Wire2Api<Sequences> for JsValue {
@@ -1542,6 +2793,22 @@ impl Wire2Api<Sequences> for JsValue {
         Sequences(self_.get(0).wire2api())
     }
 }
+impl Wire2Api<Session> for JsValue {
+    fn wire2api(self) -> Session {
+        let self_ = self.dyn_into::<JsArray>().unwrap();
+        assert_eq!(
+            self_.length(),
+            1,
+            "Expected 1 elements, got {}",
+            self_.length()
+        );
+        Session {
+            user: self_.get(0).wire2api(),
+            retries: Default::default(),
+            roles: Mutex::new(vec!["guest".to_owned()]),
+        }
+    }
+}
 impl Wire2Api<Speed> for JsValue {
     fn wire2api(self) -> Speed {
         let self_ = self.unchecked_into::<JsArray>();
@@ -1552,6 +2819,7 @@ impl Wire2Api<Speed> for JsValue {
         }
     }
 }
+
 impl Wire2Api<SumWith> for JsValue {
     fn wire2api(self) -> SumWith {
         let self_ = self.dyn_into::<JsArray>().unwrap();
@@ -1566,6 +2834,23 @@ impl Wire2Api<SumWith> for JsValue {
         }
     }
 }
+impl Wire2Api<Tag> for JsValue {
+    fn wire2api(self) -> Tag {
+        let self_ = self.dyn_into::<JsArray>().unwrap();
+        assert_eq!(
+            self_.length(),
+            4,
+            "Expected 4 elements, got {}",
+            self_.length()
+        );
+        Tag {
+            name: self_.get(0).wire2api(),
+            color: self_.get(1).wire2api(),
+            aliases: self_.get(2).wire2api(),
+            day: self_.get(3).wire2api(),
+        }
+    }
+}
 impl Wire2Api<TestId> for JsValue {
     fn wire2api(self) -> TestId {
         let self_ = self.dyn_into::<JsArray>().unwrap();
@@ -1578,23 +2863,81 @@ impl Wire2Api<TestId> for JsValue {
         TestId(self_.get(0).wire2api())
     }
 }
+impl Wire2Api<(String, bool)> for JsValue {
+    fn wire2api(self) -> (String, bool) {
+        let self_ = self.dyn_into::<JsArray>().unwrap();
+        assert_eq!(
+            self_.length(),
+            2,
+            "Expected 2 elements, got {}",
+            self_.length()
+        );
+        (self_.get(0).wire2api(), self_.get(1).wire2api())
+    }
+}
+impl Wire2Api<(i32, (String, bool))> for JsValue {
+    fn wire2api(self) -> (i32, (String, bool)) {
+        let self_ = self.dyn_into::<JsArray>().unwrap();
+        assert_eq!(
+            self_.length(),
+            2,
+            "Expected 2 elements, got {}",
+            self_.length()
+        );
+        (self_.get(0).wire2api(), self_.get(1).wire2api())
+    }
+}
+impl Wire2Api<(i32, String, bool)> for JsValue {
+    fn wire2api(self) -> (i32, String, bool) {
+        let self_ = self.dyn_into::<JsArray>().unwrap();
+        assert_eq!(
+            self_.length(),
+            3,
+            "Expected 3 elements, got {}",
+            self_.length()
+        );
+        (
+            self_.get(0).wire2api(),
+            self_.get(1).wire2api(),
+            self_.get(2).wire2api(),
+        )
+    }
+}
+impl Wire2Api<u128> for Box<[u8]> {
+    fn wire2api(self) -> u128 {
+        let bytes: Vec<u8> = self.wire2api();
+        wire2api_u128(bytes)
+    }
+}
 
 impl Wire2Api<[u8; 1600]> for Box<[u8]> {
     fn wire2api(self) -> [u8; 1600] {
-        let vec: Vec<u8> = self.wire2api();
-        support::from_vec_to_array(vec)
+        support::from_slice_to_array(&self)
     }
 }
 impl Wire2Api<[u8; 32]> for Box<[u8]> {
     fn wire2api(self) -> [u8; 32] {
-        let vec: Vec<u8> = self.wire2api();
-        support::from_vec_to_array(vec)
+        support::from_slice_to_array(&self)
     }
 }
 impl Wire2Api<[u8; 8]> for Box<[u8]> {
     fn wire2api(self) -> [u8; 8] {
-        let vec: Vec<u8> = self.wire2api();
-        support::from_vec_to_array(vec)
+        support::from_slice_to_array(&self)
+    }
+}
+impl Wire2Api<Vec<u16>> for Box<[u16]> {
+    fn wire2api(self) -> Vec<u16> {
+        self.into_vec()
+    }
+}
+impl Wire2Api<Vec<u32>> for Box<[u32]> {
+    fn wire2api(self) -> Vec<u32> {
+        self.into_vec()
+    }
+}
+impl Wire2Api<Vec<u64>> for Box<[u64]> {
+    fn wire2api(self) -> Vec<u64> {
+        self.into_vec()
     }
 }
 impl Wire2Api<Vec<u8>> for Box<[u8]> {
@@ -1617,8 +2960,21 @@ impl Wire2Api<UserId> for JsValue {
     }
 }
 
+impl Wire2Api<Vec<usize>> for Box<[u64]> {
+    fn wire2api(self) -> Vec<usize> {
+        let list: Vec<u64> = self.wire2api();
+        wire2api_size_list(list)
+    }
+}
+
 // Section: impl Wire2Api for JsValue
 
+impl Wire2Api<std::sync::Arc<HideData>> for JsValue {
+    fn wire2api(self) -> std::sync::Arc<HideData> {
+        let opaque: RustOpaque<HideData> = self.wire2api();
+        opaque.into_arc()
+    }
+}
 impl Wire2Api<RustOpaque<Box<dyn DartDebug>>> for JsValue {
     fn wire2api(self) -> RustOpaque<Box<dyn DartDebug>> {
         #[cfg(target_pointer_width = "64")]
@@ -1629,11 +2985,24 @@ impl Wire2Api<RustOpaque<Box<dyn DartDebug>>> for JsValue {
         unsafe { support::opaque_from_dart((self.as_f64().unwrap() as usize) as _) }
     }
 }
+impl Wire2Api<bytes::Bytes> for JsValue {
+    fn wire2api(self) -> bytes::Bytes {
+        self.unchecked_into::<js_sys::Uint8Array>()
+            .to_vec()
+            .into_boxed_slice()
+            .wire2api()
+    }
+}
 impl Wire2Api<chrono::Duration> for JsValue {
     fn wire2api(self) -> chrono::Duration {
         Wire2Api::<i64>::wire2api(self).wire2api()
     }
 }
+impl Wire2Api<chrono::DateTime<chrono::FixedOffset>> for JsValue {
+    fn wire2api(self) -> chrono::DateTime<chrono::FixedOffset> {
+        Wire2Api::<i64>::wire2api(self).wire2api()
+    }
+}
 impl Wire2Api<chrono::DateTime<chrono::Local>> for JsValue {
     fn wire2api(self) -> chrono::DateTime<chrono::Local> {
         Wire2Api::<i64>::wire2api(self).wire2api()
@@ -1649,6 +3018,66 @@ impl Wire2Api<chrono::DateTime<chrono::Utc>> for JsValue {
         Wire2Api::<i64>::wire2api(self).wire2api()
     }
 }
+impl Wire2Api<Version> for JsValue {
+    fn wire2api(self) -> Version {
+        crate::codecs::version::decode(&self.unchecked_into::<js_sys::Uint8Array>().to_vec())
+    }
+}
+impl Wire2Api<RustOpaque<Counter>> for JsValue {
+    fn wire2api(self) -> RustOpaque<Counter> {
+        #[cfg(target_pointer_width = "64")]
+        {
+            compile_error!("64-bit pointers are not supported.");
+        }
+
+        unsafe { support::opaque_from_dart((self.as_f64().unwrap() as usize) as _) }
+    }
+}
+impl Wire2Api<std::borrow::Cow<'static, [u8]>> for JsValue {
+    fn wire2api(self) -> std::borrow::Cow<'static, [u8]> {
+        std::borrow::Cow::Owned(self.unchecked_into::<js_sys::Uint8Array>().to_vec())
+    }
+}
+impl Wire2Api<std::borrow::Cow<'static, str>> for JsValue {
+    fn wire2api(self) -> std::borrow::Cow<'static, str> {
+        std::borrow::Cow::Owned(self.as_string().expect("non-UTF-8 string, or not a string"))
+    }
+}
+impl Wire2Api<RustOpaque<DartIterator<i32>>> for JsValue {
+    fn wire2api(self) -> RustOpaque<DartIterator<i32>> {
+        #[cfg(target_pointer_width = "64")]
+        {
+            compile_error!("64-bit pointers are not supported.");
+        }
+
+        unsafe { support::opaque_from_dart((self.as_f64().unwrap() as usize) as _) }
+    }
+}
+impl Wire2Api<RustOpaque<DartIterator<String>>> for JsValue {
+    fn wire2api(self) -> RustOpaque<DartIterator<String>> {
+        #[cfg(target_pointer_width = "64")]
+        {
+            compile_error!("64-bit pointers are not supported.");
+        }
+
+        unsafe { support::opaque_from_dart((self.as_f64().unwrap() as usize) as _) }
+    }
+}
+impl Wire2Api<rust_decimal::Decimal> for JsValue {
+    fn wire2api(self) -> rust_decimal::Decimal {
+        wire2api_decimal(self.as_string().expect("non-UTF-8 string, or not a string"))
+    }
+}
+impl Wire2Api<RustOpaque<Greeter>> for JsValue {
+    fn wire2api(self) -> RustOpaque<Greeter> {
+        #[cfg(target_pointer_width = "64")]
+        {
+            compile_error!("64-bit pointers are not supported.");
+        }
+
+        unsafe { support::opaque_from_dart((self.as_f64().unwrap() as usize) as _) }
+    }
+}
 impl Wire2Api<RustOpaque<HideData>> for JsValue {
     fn wire2api(self) -> RustOpaque<HideData> {
         #[cfg(target_pointer_width = "64")]
@@ -1675,6 +3104,16 @@ impl Wire2Api<RustOpaque<i32>> for JsValue {
         unsafe { support::opaque_from_dart((self.as_f64().unwrap() as usize) as _) }
     }
 }
+impl Wire2Api<RustOpaque<std::time::Instant>> for JsValue {
+    fn wire2api(self) -> RustOpaque<std::time::Instant> {
+        #[cfg(target_pointer_width = "64")]
+        {
+            compile_error!("64-bit pointers are not supported.");
+        }
+
+        unsafe { support::opaque_from_dart((self.as_f64().unwrap() as usize) as _) }
+    }
+}
 impl Wire2Api<RustOpaque<Mutex<HideData>>> for JsValue {
     fn wire2api(self) -> RustOpaque<Mutex<HideData>> {
         #[cfg(target_pointer_width = "64")]
@@ -1695,12 +3134,51 @@ impl Wire2Api<RustOpaque<NonSendHideData>> for JsValue {
         unsafe { support::opaque_from_dart((self.as_f64().unwrap() as usize) as _) }
     }
 }
+impl Wire2Api<std::num::NonZeroU32> for JsValue {
+    fn wire2api(self) -> std::num::NonZeroU32 {
+        let value: u32 = self.wire2api();
+        wire2api_non_zero(value)
+    }
+}
+impl Wire2Api<std::num::NonZeroU64> for JsValue {
+    fn wire2api(self) -> std::num::NonZeroU64 {
+        let value: u64 = self.wire2api();
+        wire2api_non_zero(value)
+    }
+}
 impl Wire2Api<[DartOpaque; 1]> for JsValue {
     fn wire2api(self) -> [DartOpaque; 1] {
         let vec: Vec<DartOpaque> = self.wire2api();
         support::from_vec_to_array(vec)
     }
 }
+impl Wire2Api<std::path::PathBuf> for JsValue {
+    fn wire2api(self) -> std::path::PathBuf {
+        wire2api_path(self.unchecked_into::<js_sys::Uint16Array>().to_vec())
+    }
+}
+impl Wire2Api<std::ops::RangeInclusive<f64>> for JsValue {
+    fn wire2api(self) -> std::ops::RangeInclusive<f64> {
+        let bounds: Vec<f64> = self.wire2api();
+        wire2api_range_inclusive(bounds)
+    }
+}
+impl Wire2Api<std::ops::Range<u32>> for JsValue {
+    fn wire2api(self) -> std::ops::Range<u32> {
+        let bounds: Vec<u32> = self.wire2api();
+        wire2api_range(bounds)
+    }
+}
+impl Wire2Api<RustOpaque<Rooms>> for JsValue {
+    fn wire2api(self) -> RustOpaque<Rooms> {
+        #[cfg(target_pointer_width = "64")]
+        {
+            compile_error!("64-bit pointers are not supported.");
+        }
+
+        unsafe { support::opaque_from_dart((self.as_f64().unwrap() as usize) as _) }
+    }
+}
 impl Wire2Api<RustOpaque<RwLock<HideData>>> for JsValue {
     fn wire2api(self) -> RustOpaque<RwLock<HideData>> {
         #[cfg(target_pointer_width = "64")]
@@ -1711,11 +3189,21 @@ impl Wire2Api<RustOpaque<RwLock<HideData>>> for JsValue {
         unsafe { support::opaque_from_dart((self.as_f64().unwrap() as usize) as _) }
     }
 }
+impl Wire2Api<std::net::SocketAddr> for JsValue {
+    fn wire2api(self) -> std::net::SocketAddr {
+        wire2api_socket_addr(self.unchecked_into::<js_sys::Uint8Array>().to_vec())
+    }
+}
 impl Wire2Api<String> for JsValue {
     fn wire2api(self) -> String {
         self.as_string().expect("non-UTF-8 string, or not a string")
     }
 }
+impl Wire2Api<std::time::SystemTime> for JsValue {
+    fn wire2api(self) -> std::time::SystemTime {
+        Wire2Api::<i64>::wire2api(self).wire2api()
+    }
+}
 impl Wire2Api<[TestId; 4]> for JsValue {
     fn wire2api(self) -> [TestId; 4] {
         let vec: Vec<TestId> = self.wire2api();
@@ -1738,6 +3226,22 @@ impl Wire2Api<Vec<uuid::Uuid>> for JsValue {
             .wire2api()
     }
 }
+impl Wire2Api<RustOpaque<std::sync::Weak<Counter>>> for JsValue {
+    fn wire2api(self) -> RustOpaque<std::sync::Weak<Counter>> {
+        #[cfg(target_pointer_width = "64")]
+        {
+            compile_error!("64-bit pointers are not supported.");
+        }
+
+        unsafe { support::opaque_from_dart((self.as_f64().unwrap() as usize) as _) }
+    }
+}
+impl Wire2Api<std::sync::Weak<Counter>> for JsValue {
+    fn wire2api(self) -> std::sync::Weak<Counter> {
+        let opaque: RustOpaque<std::sync::Weak<Counter>> = self.wire2api();
+        std::sync::Weak::clone(&opaque)
+    }
+}
 impl Wire2Api<ZeroCopyBuffer<Vec<u8>>> for JsValue {
     fn wire2api(self) -> ZeroCopyBuffer<Vec<u8>> {
         ZeroCopyBuffer(self.wire2api())
@@ -1830,11 +3334,36 @@ impl Wire2Api<Box<Weekdays>> for JsValue {
         Box::new(ptr.wire2api())
     }
 }
+impl Wire2Api<std::collections::BTreeSet<String>> for JsValue {
+    fn wire2api(self) -> std::collections::BTreeSet<String> {
+        let vec: Vec<String> = self.wire2api();
+        vec.into_iter().collect()
+    }
+}
+impl Wire2Api<char> for JsValue {
+    fn wire2api(self) -> char {
+        wire2api_char(self.unchecked_into_f64() as u32)
+    }
+}
 impl Wire2Api<f32> for JsValue {
     fn wire2api(self) -> f32 {
         self.unchecked_into_f64() as _
     }
 }
+impl Wire2Api<[f32; 3]> for JsValue {
+    fn wire2api(self) -> [f32; 3] {
+        let buf = self.unchecked_into::<js_sys::Float32Array>();
+        assert_eq!(
+            buf.length() as usize,
+            3,
+            "Expected an array of length 3 but it was {}",
+            buf.length()
+        );
+        let mut ans = [Default::default(); 3];
+        buf.copy_to(&mut ans);
+        ans
+    }
+}
 impl Wire2Api<f64> for JsValue {
     fn wire2api(self) -> f64 {
         self.unchecked_into_f64() as _
@@ -1842,8 +3371,16 @@ impl Wire2Api<f64> for JsValue {
 }
 impl Wire2Api<[f64; 16]> for JsValue {
     fn wire2api(self) -> [f64; 16] {
-        let vec: Vec<f64> = self.wire2api();
-        support::from_vec_to_array(vec)
+        let buf = self.unchecked_into::<js_sys::Float64Array>();
+        assert_eq!(
+            buf.length() as usize,
+            16,
+            "Expected an array of length 16 but it was {}",
+            buf.length()
+        );
+        let mut ans = [Default::default(); 16];
+        buf.copy_to(&mut ans);
+        ans
     }
 }
 impl Wire2Api<Vec<f32>> for JsValue {
@@ -1860,6 +3397,19 @@ impl Wire2Api<Vec<f64>> for JsValue {
             .into()
     }
 }
+impl Wire2Api<HttpStatus> for JsValue {
+    fn wire2api(self) -> HttpStatus {
+        (self.unchecked_into_f64() as i32).wire2api()
+    }
+}
+impl Wire2Api<i128> for JsValue {
+    fn wire2api(self) -> i128 {
+        self.unchecked_into::<js_sys::Uint8Array>()
+            .to_vec()
+            .into_boxed_slice()
+            .wire2api()
+    }
+}
 impl Wire2Api<i32> for JsValue {
     fn wire2api(self) -> i32 {
         self.unchecked_into_f64() as _
@@ -1867,8 +3417,16 @@ impl Wire2Api<i32> for JsValue {
 }
 impl Wire2Api<[i32; 2]> for JsValue {
     fn wire2api(self) -> [i32; 2] {
-        let vec: Vec<i32> = self.wire2api();
-        support::from_vec_to_array(vec)
+        let buf = self.unchecked_into::<js_sys::Int32Array>();
+        assert_eq!(
+            buf.length() as usize,
+            2,
+            "Expected an array of length 2 but it was {}",
+            buf.length()
+        );
+        let mut ans = [Default::default(); 2];
+        buf.copy_to(&mut ans);
+        ans
     }
 }
 impl Wire2Api<i64> for JsValue {
@@ -1931,6 +3489,11 @@ impl Wire2Api<Option<i64>> for JsValue {
         (!self.is_undefined() && !self.is_null()).then(|| self.wire2api())
     }
 }
+impl Wire2Api<Option<u32>> for JsValue {
+    fn wire2api(self) -> Option<u32> {
+        (!self.is_undefined() && !self.is_null()).then(|| self.wire2api())
+    }
+}
 impl Wire2Api<Option<Box<bool>>> for JsValue {
     fn wire2api(self) -> Option<Box<bool>> {
         (!self.is_undefined() && !self.is_null()).then(|| self.wire2api())
@@ -1986,6 +3549,30 @@ impl Wire2Api<Option<Vec<u8>>> for JsValue {
         (!self.is_undefined() && !self.is_null()).then(|| self.wire2api())
     }
 }
+impl Wire2Api<Pace> for JsValue {
+    fn wire2api(self) -> Pace {
+        (self.unchecked_into_f64() as i32).wire2api()
+    }
+}
+impl Wire2Api<std::time::Instant> for JsValue {
+    fn wire2api(self) -> std::time::Instant {
+        let opaque: RustOpaque<std::time::Instant> = self.wire2api();
+        *opaque
+    }
+}
+impl Wire2Api<u128> for JsValue {
+    fn wire2api(self) -> u128 {
+        self.unchecked_into::<js_sys::Uint8Array>()
+            .to_vec()
+            .into_boxed_slice()
+            .wire2api()
+    }
+}
+impl Wire2Api<u16> for JsValue {
+    fn wire2api(self) -> u16 {
+        self.unchecked_into_f64() as _
+    }
+}
 impl Wire2Api<u32> for JsValue {
     fn wire2api(self) -> u32 {
         self.unchecked_into_f64() as _
@@ -2003,20 +3590,61 @@ impl Wire2Api<u8> for JsValue {
 }
 impl Wire2Api<[u8; 1600]> for JsValue {
     fn wire2api(self) -> [u8; 1600] {
-        let vec: Vec<u8> = self.wire2api();
-        support::from_vec_to_array(vec)
+        let buf = self.unchecked_into::<js_sys::Uint8Array>();
+        assert_eq!(
+            buf.length() as usize,
+            1600,
+            "Expected an array of length 1600 but it was {}",
+            buf.length()
+        );
+        let mut ans = [Default::default(); 1600];
+        buf.copy_to(&mut ans);
+        ans
     }
 }
 impl Wire2Api<[u8; 32]> for JsValue {
     fn wire2api(self) -> [u8; 32] {
-        let vec: Vec<u8> = self.wire2api();
-        support::from_vec_to_array(vec)
+        let buf = self.unchecked_into::<js_sys::Uint8Array>();
+        assert_eq!(
+            buf.length() as usize,
+            32,
+            "Expected an array of length 32 but it was {}",
+            buf.length()
+        );
+        let mut ans = [Default::default(); 32];
+        buf.copy_to(&mut ans);
+        ans
     }
 }
 impl Wire2Api<[u8; 8]> for JsValue {
     fn wire2api(self) -> [u8; 8] {
-        let vec: Vec<u8> = self.wire2api();
-        support::from_vec_to_array(vec)
+        let buf = self.unchecked_into::<js_sys::Uint8Array>();
+        assert_eq!(
+            buf.length() as usize,
+            8,
+            "Expected an array of length 8 but it was {}",
+            buf.length()
+        );
+        let mut ans = [Default::default(); 8];
+        buf.copy_to(&mut ans);
+        ans
+    }
+}
+impl Wire2Api<Vec<u16>> for JsValue {
+    fn wire2api(self) -> Vec<u16> {
+        self.unchecked_into::<js_sys::Uint16Array>().to_vec().into()
+    }
+}
+impl Wire2Api<Vec<u32>> for JsValue {
+    fn wire2api(self) -> Vec<u32> {
+        self.unchecked_into::<js_sys::Uint32Array>().to_vec().into()
+    }
+}
+impl Wire2Api<Vec<u64>> for JsValue {
+    fn wire2api(self) -> Vec<u64> {
+        let buf = self.dyn_into::<js_sys::BigUint64Array>().unwrap();
+        let buf = js_sys::Uint8Array::new(&buf.buffer());
+        support::slice_from_byte_buffer(buf.to_vec()).into()
     }
 }
 impl Wire2Api<Vec<u8>> for JsValue {
@@ -2029,6 +3657,12 @@ impl Wire2Api<usize> for JsValue {
         self.unchecked_into_f64() as _
     }
 }
+impl Wire2Api<Vec<usize>> for JsValue {
+    fn wire2api(self) -> Vec<usize> {
+        let list: Vec<u64> = self.wire2api();
+        wire2api_size_list(list)
+    }
+}
 impl Wire2Api<Weekdays> for JsValue {
     fn wire2api(self) -> Weekdays {
         (self.unchecked_into_f64() as i32).wire2api()
@@ -0,0 +1,3 @@
+//! The hand-written codecs of the types sent as bytes, see `#[frb(custom_codec(..))]`.
+
+pub mod version;
@@ -0,0 +1,17 @@
+use crate::api::Version;
+
+pub fn encode(version: &Version) -> Vec<u8> {
+    [version.major, version.minor, version.patch]
+        .iter()
+        .flat_map(|part| part.to_be_bytes())
+        .collect()
+}
+
+pub fn decode(bytes: &[u8]) -> Version {
+    let part = |i: usize| u16::from_be_bytes([bytes[2 * i], bytes[2 * i + 1]]);
+    Version {
+        major: part(0),
+        minor: part(1),
+        patch: part(2),
+    }
+}
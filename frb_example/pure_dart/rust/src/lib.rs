@@ -1,5 +1,6 @@
 mod api;
 mod bridge_generated;
+mod codecs;
 mod data;
 mod new_module_system;
 mod old_module_system;
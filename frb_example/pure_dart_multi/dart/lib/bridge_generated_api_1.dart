@@ -7,12 +7,14 @@ import 'dart:async';
 import 'package:meta/meta.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge.dart';
 import 'package:uuid/uuid.dart';
+import 'package:decimal/decimal.dart';
 
 import 'dart:convert';
 import 'dart:async';
 import 'package:meta/meta.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge.dart';
 import 'package:uuid/uuid.dart';
+import 'package:decimal/decimal.dart';
 import 'bridge_generated_api_1.io.dart' if (dart.library.html) 'bridge_generated_api_1.web.dart';
 
 abstract class ApiClass1 {
@@ -20,8 +22,16 @@ abstract class ApiClass1 {
   Future<int> simpleAdder1({required int a, required int b, dynamic hint});
 
   FlutterRustBridgeTaskConstMeta get kSimpleAdder1ConstMeta;
+
+  /// Starts the worker pool and runs the `#[frb(init)]` function, if any, so that the first real call does not wait for them. Calling it again does nothing.
+  Future<void> warmUp({dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kWarmUpConstMeta;
 }
 
+/// Every function of [ApiClass1], see [FlutterRustBridgeTaskConstMeta.function].
+enum ApiClass1Function { simpleAdder1, warmUp }
+
 class ApiClass1Impl implements ApiClass1 {
   final ApiClass1Platform _platform;
   factory ApiClass1Impl(ExternalLibrary dylib) => ApiClass1Impl.raw(ApiClass1Platform(dylib));
@@ -44,6 +54,23 @@ class ApiClass1Impl implements ApiClass1 {
   FlutterRustBridgeTaskConstMeta get kSimpleAdder1ConstMeta => const FlutterRustBridgeTaskConstMeta(
         debugName: "simple_adder_1",
         argNames: ["a", "b"],
+        function: ApiClass1Function.simpleAdder1,
+      );
+
+  Future<void> warmUp({dynamic hint}) {
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_warm_up(port_),
+      parseSuccessData: _wire2api_unit,
+      constMeta: kWarmUpConstMeta,
+      argValues: [],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kWarmUpConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "warm_up",
+        argNames: [],
+        function: ApiClass1Function.warmUp,
       );
 
   void dispose() {
@@ -54,6 +81,10 @@ class ApiClass1Impl implements ApiClass1 {
   int _wire2api_i32(dynamic raw) {
     return raw as int;
   }
+
+  void _wire2api_unit(dynamic raw) {
+    return;
+  }
 }
 
 // Section: api2wire
@@ -7,6 +7,7 @@ import 'dart:async';
 import 'package:meta/meta.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge.dart';
 import 'package:uuid/uuid.dart';
+import 'package:decimal/decimal.dart';
 import 'bridge_generated_api_1.dart';
 export 'bridge_generated_api_1.dart';
 import 'dart:ffi' as ffi;
@@ -99,6 +100,47 @@ class ApiClass1Wire implements FlutterRustBridgeWireBase {
       _lookup<ffi.NativeFunction<ffi.IntPtr Function(ffi.Pointer<ffi.Void>)>>('init_frb_dart_api_dl');
   late final _init_frb_dart_api_dl = _init_frb_dart_api_dlPtr.asFunction<int Function(ffi.Pointer<ffi.Void>)>();
 
+  int new_cancel_id() {
+    return _new_cancel_id();
+  }
+
+  late final _new_cancel_idPtr = _lookup<ffi.NativeFunction<ffi.Int32 Function()>>('new_cancel_id');
+  late final _new_cancel_id = _new_cancel_idPtr.asFunction<int Function()>();
+
+  void cancel_task(
+    int id,
+  ) {
+    return _cancel_task(
+      id,
+    );
+  }
+
+  late final _cancel_taskPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int32)>>('cancel_task');
+  late final _cancel_task = _cancel_taskPtr.asFunction<void Function(int)>();
+
+  int new_shared_buffer(
+    int len,
+  ) {
+    return _new_shared_buffer(
+      len,
+    );
+  }
+
+  late final _new_shared_bufferPtr =
+      _lookup<ffi.NativeFunction<ffi.UintPtr Function(ffi.UintPtr)>>('new_shared_buffer');
+  late final _new_shared_buffer = _new_shared_bufferPtr.asFunction<int Function(int)>();
+
+  void drop_shared_buffer(
+    int address,
+  ) {
+    return _drop_shared_buffer(
+      address,
+    );
+  }
+
+  late final _drop_shared_bufferPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.UintPtr)>>('drop_shared_buffer');
+  late final _drop_shared_buffer = _drop_shared_bufferPtr.asFunction<void Function(int)>();
+
   void wire_simple_adder_1(
     int port_,
     int a,
@@ -115,6 +157,17 @@ class ApiClass1Wire implements FlutterRustBridgeWireBase {
       _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Int32, ffi.Int32)>>('wire_simple_adder_1');
   late final _wire_simple_adder_1 = _wire_simple_adder_1Ptr.asFunction<void Function(int, int, int)>();
 
+  void wire_warm_up(
+    int port_,
+  ) {
+    return _wire_warm_up(
+      port_,
+    );
+  }
+
+  late final _wire_warm_upPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64)>>('wire_warm_up');
+  late final _wire_warm_up = _wire_warm_upPtr.asFunction<void Function(int)>();
+
   void free_WireSyncReturn(
     WireSyncReturn ptr,
   ) {
@@ -7,6 +7,7 @@ import 'dart:async';
 import 'package:meta/meta.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge.dart';
 import 'package:uuid/uuid.dart';
+import 'package:decimal/decimal.dart';
 import 'bridge_generated_api_1.dart';
 export 'bridge_generated_api_1.dart';
 
@@ -32,6 +33,8 @@ class ApiClass1WasmModule implements WasmModule {
   external Object /* Promise */ call([String? moduleName]);
   external ApiClass1WasmModule bind(dynamic thisArg, String moduleName);
   external dynamic /* void */ wire_simple_adder_1(NativePortType port_, int a, int b);
+
+  external dynamic /* void */ wire_warm_up(NativePortType port_);
 }
 
 // Section: WASM wire connector
@@ -40,4 +43,6 @@ class ApiClass1Wire extends FlutterRustBridgeWasmWireBase<ApiClass1WasmModule> {
   ApiClass1Wire(FutureOr<WasmModule> module) : super(WasmModule.cast<ApiClass1WasmModule>(module));
 
   void wire_simple_adder_1(NativePortType port_, int a, int b) => wasmModule.wire_simple_adder_1(port_, a, b);
+
+  void wire_warm_up(NativePortType port_) => wasmModule.wire_warm_up(port_);
 }
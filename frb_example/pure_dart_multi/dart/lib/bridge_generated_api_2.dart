@@ -7,12 +7,14 @@ import 'dart:async';
 import 'package:meta/meta.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge.dart';
 import 'package:uuid/uuid.dart';
+import 'package:decimal/decimal.dart';
 
 import 'dart:convert';
 import 'dart:async';
 import 'package:meta/meta.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge.dart';
 import 'package:uuid/uuid.dart';
+import 'package:decimal/decimal.dart';
 import 'bridge_generated_api_2.io.dart' if (dart.library.html) 'bridge_generated_api_2.web.dart';
 
 abstract class ApiClass2 {
@@ -20,8 +22,16 @@ abstract class ApiClass2 {
   Future<int> simpleAdder2({required int a, required int b, dynamic hint});
 
   FlutterRustBridgeTaskConstMeta get kSimpleAdder2ConstMeta;
+
+  /// Starts the worker pool and runs the `#[frb(init)]` function, if any, so that the first real call does not wait for them. Calling it again does nothing.
+  Future<void> warmUp({dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kWarmUpConstMeta;
 }
 
+/// Every function of [ApiClass2], see [FlutterRustBridgeTaskConstMeta.function].
+enum ApiClass2Function { simpleAdder2, warmUp }
+
 class ApiClass2Impl implements ApiClass2 {
   final ApiClass2Platform _platform;
   factory ApiClass2Impl(ExternalLibrary dylib) => ApiClass2Impl.raw(ApiClass2Platform(dylib));
@@ -44,6 +54,23 @@ class ApiClass2Impl implements ApiClass2 {
   FlutterRustBridgeTaskConstMeta get kSimpleAdder2ConstMeta => const FlutterRustBridgeTaskConstMeta(
         debugName: "simple_adder_2",
         argNames: ["a", "b"],
+        function: ApiClass2Function.simpleAdder2,
+      );
+
+  Future<void> warmUp({dynamic hint}) {
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_warm_up_1(port_),
+      parseSuccessData: _wire2api_unit,
+      constMeta: kWarmUpConstMeta,
+      argValues: [],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kWarmUpConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "warm_up_1",
+        argNames: [],
+        function: ApiClass2Function.warmUp,
       );
 
   void dispose() {
@@ -54,6 +81,10 @@ class ApiClass2Impl implements ApiClass2 {
   int _wire2api_i32(dynamic raw) {
     return raw as int;
   }
+
+  void _wire2api_unit(dynamic raw) {
+    return;
+  }
 }
 
 // Section: api2wire
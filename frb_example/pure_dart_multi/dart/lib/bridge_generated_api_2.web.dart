@@ -7,6 +7,7 @@ import 'dart:async';
 import 'package:meta/meta.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge.dart';
 import 'package:uuid/uuid.dart';
+import 'package:decimal/decimal.dart';
 import 'bridge_generated_api_2.dart';
 export 'bridge_generated_api_2.dart';
 
@@ -32,6 +33,8 @@ class ApiClass2WasmModule implements WasmModule {
   external Object /* Promise */ call([String? moduleName]);
   external ApiClass2WasmModule bind(dynamic thisArg, String moduleName);
   external dynamic /* void */ wire_simple_adder_2(NativePortType port_, int a, int b);
+
+  external dynamic /* void */ wire_warm_up_1(NativePortType port_);
 }
 
 // Section: WASM wire connector
@@ -40,4 +43,6 @@ class ApiClass2Wire extends FlutterRustBridgeWasmWireBase<ApiClass2WasmModule> {
   ApiClass2Wire(FutureOr<WasmModule> module) : super(WasmModule.cast<ApiClass2WasmModule>(module));
 
   void wire_simple_adder_2(NativePortType port_, int a, int b) => wasmModule.wire_simple_adder_2(port_, a, b);
+
+  void wire_warm_up_1(NativePortType port_) => wasmModule.wire_warm_up_1(port_);
 }
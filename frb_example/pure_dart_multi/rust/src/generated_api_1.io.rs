@@ -6,6 +6,11 @@ pub extern "C" fn wire_simple_adder_1(port_: i64, a: i32, b: i32) {
     wire_simple_adder_1_impl(port_, a, b)
 }
 
+#[no_mangle]
+pub extern "C" fn wire_warm_up(port_: i64) {
+    wire_warm_up_impl(port_)
+}
+
 // Section: allocate functions
 
 // Section: related functions
@@ -6,6 +6,11 @@ pub fn wire_simple_adder_1(port_: MessagePort, a: i32, b: i32) {
     wire_simple_adder_1_impl(port_, a, b)
 }
 
+#[wasm_bindgen]
+pub fn wire_warm_up(port_: MessagePort) {
+    wire_warm_up_impl(port_)
+}
+
 // Section: allocate functions
 
 // Section: related functions
@@ -39,6 +39,16 @@ fn wire_simple_adder_2_impl(
         },
     )
 }
+fn wire_warm_up_1_impl(port_: MessagePort) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "warm_up_1",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || move |task_callback| Ok(()),
+    )
+}
 // Section: wrapper structs
 
 // Section: static checks
@@ -51,6 +51,14 @@ uintptr_t new_dart_opaque(Dart_Handle handle);
 
 intptr_t init_frb_dart_api_dl(void *obj);
 
+int32_t new_cancel_id(void);
+
+void cancel_task(int32_t id);
+
+uintptr_t new_shared_buffer(uintptr_t len);
+
+void drop_shared_buffer(uintptr_t address);
+
 void wire_draw_mandelbrot(int64_t port_,
                           struct wire_Size *image_size,
                           struct wire_Point *zoom_point,
@@ -79,6 +87,8 @@ void wire_off_topic_deliberately_return_error(int64_t port_);
 
 void wire_off_topic_deliberately_panic(int64_t port_);
 
+void wire_warm_up(int64_t port_);
+
 struct wire_Point *new_box_autoadd_point_0(void);
 
 struct wire_Size *new_box_autoadd_size_0(void);
@@ -107,6 +117,7 @@ static int64_t dummy_method_to_enforce_bundling(void) {
     dummy_var ^= ((int64_t) (void*) wire_off_topic_memory_test_output_complex_struct);
     dummy_var ^= ((int64_t) (void*) wire_off_topic_deliberately_return_error);
     dummy_var ^= ((int64_t) (void*) wire_off_topic_deliberately_panic);
+    dummy_var ^= ((int64_t) (void*) wire_warm_up);
     dummy_var ^= ((int64_t) (void*) new_box_autoadd_point_0);
     dummy_var ^= ((int64_t) (void*) new_box_autoadd_size_0);
     dummy_var ^= ((int64_t) (void*) new_box_autoadd_tree_node_0);
@@ -118,5 +129,9 @@ static int64_t dummy_method_to_enforce_bundling(void) {
     dummy_var ^= ((int64_t) (void*) get_dart_object);
     dummy_var ^= ((int64_t) (void*) drop_dart_object);
     dummy_var ^= ((int64_t) (void*) new_dart_opaque);
+    dummy_var ^= ((int64_t) (void*) new_cancel_id);
+    dummy_var ^= ((int64_t) (void*) cancel_task);
+    dummy_var ^= ((int64_t) (void*) new_shared_buffer);
+    dummy_var ^= ((int64_t) (void*) drop_shared_buffer);
     return dummy_var;
 }
\ No newline at end of file
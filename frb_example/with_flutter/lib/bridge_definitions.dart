@@ -8,6 +8,7 @@ import 'dart:async';
 import 'package:meta/meta.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge.dart';
 import 'package:uuid/uuid.dart';
+import 'package:decimal/decimal.dart';
 
 abstract class FlutterRustBridgeExample {
   Future<Uint8List> drawMandelbrot(
@@ -62,11 +63,34 @@ abstract class FlutterRustBridgeExample {
   Future<int> offTopicDeliberatelyPanic({dynamic hint});
 
   FlutterRustBridgeTaskConstMeta get kOffTopicDeliberatelyPanicConstMeta;
+
+  /// Starts the worker pool and runs the `#[frb(init)]` function, if any, so that the first real call does not wait for them. Calling it again does nothing.
+  Future<void> warmUp({dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kWarmUpConstMeta;
+}
+
+/// Every function of [FlutterRustBridgeExample], see [FlutterRustBridgeTaskConstMeta.function].
+enum FlutterRustBridgeExampleFunction {
+  drawMandelbrot,
+  passingComplexStructs,
+  returningStructsWithBoxedFields,
+  offTopicMemoryTestInputArray,
+  offTopicMemoryTestOutputZeroCopyBuffer,
+  offTopicMemoryTestOutputVecU8,
+  offTopicMemoryTestInputVecOfObject,
+  offTopicMemoryTestOutputVecOfObject,
+  offTopicMemoryTestInputComplexStruct,
+  offTopicMemoryTestOutputComplexStruct,
+  offTopicDeliberatelyReturnError,
+  offTopicDeliberatelyPanic,
+  warmUp
 }
 
 class BoxedPoint {
   final Point point;
-  BoxedPoint({
+
+  const BoxedPoint({
     required this.point,
   });
 }
@@ -74,7 +98,8 @@ class BoxedPoint {
 class Point {
   final double x;
   final double y;
-  Point({
+
+  const Point({
     required this.x,
     required this.y,
   });
@@ -83,7 +108,8 @@ class Point {
 class Size {
   final int width;
   final int height;
-  Size({
+
+  const Size({
     required this.width,
     required this.height,
   });
@@ -92,7 +118,8 @@ class Size {
 class TreeNode {
   final String name;
   final List<TreeNode> children;
-  TreeNode({
+
+  const TreeNode({
     required this.name,
     required this.children,
   });
@@ -8,6 +8,7 @@ import 'dart:async';
 import 'package:meta/meta.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge.dart';
 import 'package:uuid/uuid.dart';
+import 'package:decimal/decimal.dart';
 import 'bridge_generated.io.dart' if (dart.library.html) 'bridge_generated.web.dart';
 
 class FlutterRustBridgeExampleImpl implements FlutterRustBridgeExample {
@@ -41,6 +42,7 @@ class FlutterRustBridgeExampleImpl implements FlutterRustBridgeExample {
   FlutterRustBridgeTaskConstMeta get kDrawMandelbrotConstMeta => const FlutterRustBridgeTaskConstMeta(
         debugName: "draw_mandelbrot",
         argNames: ["imageSize", "zoomPoint", "scale", "numThreads"],
+        function: FlutterRustBridgeExampleFunction.drawMandelbrot,
       );
 
   Future<String> passingComplexStructs({required TreeNode root, dynamic hint}) {
@@ -57,6 +59,7 @@ class FlutterRustBridgeExampleImpl implements FlutterRustBridgeExample {
   FlutterRustBridgeTaskConstMeta get kPassingComplexStructsConstMeta => const FlutterRustBridgeTaskConstMeta(
         debugName: "passing_complex_structs",
         argNames: ["root"],
+        function: FlutterRustBridgeExampleFunction.passingComplexStructs,
       );
 
   Future<BoxedPoint> returningStructsWithBoxedFields({dynamic hint}) {
@@ -72,6 +75,7 @@ class FlutterRustBridgeExampleImpl implements FlutterRustBridgeExample {
   FlutterRustBridgeTaskConstMeta get kReturningStructsWithBoxedFieldsConstMeta => const FlutterRustBridgeTaskConstMeta(
         debugName: "returning_structs_with_boxed_fields",
         argNames: [],
+        function: FlutterRustBridgeExampleFunction.returningStructsWithBoxedFields,
       );
 
   Future<int> offTopicMemoryTestInputArray({required Uint8List input, dynamic hint}) {
@@ -88,6 +92,7 @@ class FlutterRustBridgeExampleImpl implements FlutterRustBridgeExample {
   FlutterRustBridgeTaskConstMeta get kOffTopicMemoryTestInputArrayConstMeta => const FlutterRustBridgeTaskConstMeta(
         debugName: "off_topic_memory_test_input_array",
         argNames: ["input"],
+        function: FlutterRustBridgeExampleFunction.offTopicMemoryTestInputArray,
       );
 
   Future<Uint8List> offTopicMemoryTestOutputZeroCopyBuffer({required int len, dynamic hint}) {
@@ -105,6 +110,7 @@ class FlutterRustBridgeExampleImpl implements FlutterRustBridgeExample {
       const FlutterRustBridgeTaskConstMeta(
         debugName: "off_topic_memory_test_output_zero_copy_buffer",
         argNames: ["len"],
+        function: FlutterRustBridgeExampleFunction.offTopicMemoryTestOutputZeroCopyBuffer,
       );
 
   Future<Uint8List> offTopicMemoryTestOutputVecU8({required int len, dynamic hint}) {
@@ -121,6 +127,7 @@ class FlutterRustBridgeExampleImpl implements FlutterRustBridgeExample {
   FlutterRustBridgeTaskConstMeta get kOffTopicMemoryTestOutputVecU8ConstMeta => const FlutterRustBridgeTaskConstMeta(
         debugName: "off_topic_memory_test_output_vec_u8",
         argNames: ["len"],
+        function: FlutterRustBridgeExampleFunction.offTopicMemoryTestOutputVecU8,
       );
 
   Future<int> offTopicMemoryTestInputVecOfObject({required List<Size> input, dynamic hint}) {
@@ -138,6 +145,7 @@ class FlutterRustBridgeExampleImpl implements FlutterRustBridgeExample {
       const FlutterRustBridgeTaskConstMeta(
         debugName: "off_topic_memory_test_input_vec_of_object",
         argNames: ["input"],
+        function: FlutterRustBridgeExampleFunction.offTopicMemoryTestInputVecOfObject,
       );
 
   Future<List<Size>> offTopicMemoryTestOutputVecOfObject({required int len, dynamic hint}) {
@@ -155,6 +163,7 @@ class FlutterRustBridgeExampleImpl implements FlutterRustBridgeExample {
       const FlutterRustBridgeTaskConstMeta(
         debugName: "off_topic_memory_test_output_vec_of_object",
         argNames: ["len"],
+        function: FlutterRustBridgeExampleFunction.offTopicMemoryTestOutputVecOfObject,
       );
 
   Future<int> offTopicMemoryTestInputComplexStruct({required TreeNode input, dynamic hint}) {
@@ -172,6 +181,7 @@ class FlutterRustBridgeExampleImpl implements FlutterRustBridgeExample {
       const FlutterRustBridgeTaskConstMeta(
         debugName: "off_topic_memory_test_input_complex_struct",
         argNames: ["input"],
+        function: FlutterRustBridgeExampleFunction.offTopicMemoryTestInputComplexStruct,
       );
 
   Future<TreeNode> offTopicMemoryTestOutputComplexStruct({required int len, dynamic hint}) {
@@ -189,6 +199,7 @@ class FlutterRustBridgeExampleImpl implements FlutterRustBridgeExample {
       const FlutterRustBridgeTaskConstMeta(
         debugName: "off_topic_memory_test_output_complex_struct",
         argNames: ["len"],
+        function: FlutterRustBridgeExampleFunction.offTopicMemoryTestOutputComplexStruct,
       );
 
   Future<int> offTopicDeliberatelyReturnError({dynamic hint}) {
@@ -204,6 +215,7 @@ class FlutterRustBridgeExampleImpl implements FlutterRustBridgeExample {
   FlutterRustBridgeTaskConstMeta get kOffTopicDeliberatelyReturnErrorConstMeta => const FlutterRustBridgeTaskConstMeta(
         debugName: "off_topic_deliberately_return_error",
         argNames: [],
+        function: FlutterRustBridgeExampleFunction.offTopicDeliberatelyReturnError,
       );
 
   Future<int> offTopicDeliberatelyPanic({dynamic hint}) {
@@ -219,6 +231,23 @@ class FlutterRustBridgeExampleImpl implements FlutterRustBridgeExample {
   FlutterRustBridgeTaskConstMeta get kOffTopicDeliberatelyPanicConstMeta => const FlutterRustBridgeTaskConstMeta(
         debugName: "off_topic_deliberately_panic",
         argNames: [],
+        function: FlutterRustBridgeExampleFunction.offTopicDeliberatelyPanic,
+      );
+
+  Future<void> warmUp({dynamic hint}) {
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_warm_up(port_),
+      parseSuccessData: _wire2api_unit,
+      constMeta: kWarmUpConstMeta,
+      argValues: [],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kWarmUpConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "warm_up",
+        argNames: [],
+        function: FlutterRustBridgeExampleFunction.warmUp,
       );
 
   void dispose() {
@@ -296,6 +325,10 @@ class FlutterRustBridgeExampleImpl implements FlutterRustBridgeExample {
   Uint8List _wire2api_uint_8_list(dynamic raw) {
     return raw as Uint8List;
   }
+
+  void _wire2api_unit(dynamic raw) {
+    return;
+  }
 }
 
 // Section: api2wire
@@ -8,6 +8,7 @@ import 'dart:async';
 import 'package:meta/meta.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge.dart';
 import 'package:uuid/uuid.dart';
+import 'package:decimal/decimal.dart';
 import 'bridge_generated.dart';
 export 'bridge_generated.dart';
 import 'dart:ffi' as ffi;
@@ -177,6 +178,47 @@ class FlutterRustBridgeExampleWire implements FlutterRustBridgeWireBase {
       _lookup<ffi.NativeFunction<ffi.IntPtr Function(ffi.Pointer<ffi.Void>)>>('init_frb_dart_api_dl');
   late final _init_frb_dart_api_dl = _init_frb_dart_api_dlPtr.asFunction<int Function(ffi.Pointer<ffi.Void>)>();
 
+  int new_cancel_id() {
+    return _new_cancel_id();
+  }
+
+  late final _new_cancel_idPtr = _lookup<ffi.NativeFunction<ffi.Int32 Function()>>('new_cancel_id');
+  late final _new_cancel_id = _new_cancel_idPtr.asFunction<int Function()>();
+
+  void cancel_task(
+    int id,
+  ) {
+    return _cancel_task(
+      id,
+    );
+  }
+
+  late final _cancel_taskPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int32)>>('cancel_task');
+  late final _cancel_task = _cancel_taskPtr.asFunction<void Function(int)>();
+
+  int new_shared_buffer(
+    int len,
+  ) {
+    return _new_shared_buffer(
+      len,
+    );
+  }
+
+  late final _new_shared_bufferPtr =
+      _lookup<ffi.NativeFunction<ffi.UintPtr Function(ffi.UintPtr)>>('new_shared_buffer');
+  late final _new_shared_buffer = _new_shared_bufferPtr.asFunction<int Function(int)>();
+
+  void drop_shared_buffer(
+    int address,
+  ) {
+    return _drop_shared_buffer(
+      address,
+    );
+  }
+
+  late final _drop_shared_bufferPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.UintPtr)>>('drop_shared_buffer');
+  late final _drop_shared_buffer = _drop_shared_bufferPtr.asFunction<void Function(int)>();
+
   void wire_draw_mandelbrot(
     int port_,
     ffi.Pointer<wire_Size> image_size,
@@ -366,6 +408,17 @@ class FlutterRustBridgeExampleWire implements FlutterRustBridgeWireBase {
   late final _wire_off_topic_deliberately_panic =
       _wire_off_topic_deliberately_panicPtr.asFunction<void Function(int)>();
 
+  void wire_warm_up(
+    int port_,
+  ) {
+    return _wire_warm_up(
+      port_,
+    );
+  }
+
+  late final _wire_warm_upPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64)>>('wire_warm_up');
+  late final _wire_warm_up = _wire_warm_upPtr.asFunction<void Function(int)>();
+
   ffi.Pointer<wire_Point> new_box_autoadd_point_0() {
     return _new_box_autoadd_point_0();
   }
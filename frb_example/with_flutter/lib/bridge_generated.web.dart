@@ -8,6 +8,7 @@ import 'dart:async';
 import 'package:meta/meta.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge.dart';
 import 'package:uuid/uuid.dart';
+import 'package:decimal/decimal.dart';
 import 'bridge_generated.dart';
 export 'bridge_generated.dart';
 
@@ -107,6 +108,8 @@ class FlutterRustBridgeExampleWasmModule implements WasmModule {
   external dynamic /* void */ wire_off_topic_deliberately_return_error(NativePortType port_);
 
   external dynamic /* void */ wire_off_topic_deliberately_panic(NativePortType port_);
+
+  external dynamic /* void */ wire_warm_up(NativePortType port_);
 }
 
 // Section: WASM wire connector
@@ -150,4 +153,6 @@ class FlutterRustBridgeExampleWire extends FlutterRustBridgeWasmWireBase<Flutter
       wasmModule.wire_off_topic_deliberately_return_error(port_);
 
   void wire_off_topic_deliberately_panic(NativePortType port_) => wasmModule.wire_off_topic_deliberately_panic(port_);
+
+  void wire_warm_up(NativePortType port_) => wasmModule.wire_warm_up(port_);
 }
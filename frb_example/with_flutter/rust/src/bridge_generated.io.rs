@@ -73,6 +73,11 @@ pub extern "C" fn wire_off_topic_deliberately_panic(port_: i64) {
     wire_off_topic_deliberately_panic_impl(port_)
 }
 
+#[no_mangle]
+pub extern "C" fn wire_warm_up(port_: i64) {
+    wire_warm_up_impl(port_)
+}
+
 // Section: allocate functions
 
 #[no_mangle]
@@ -203,6 +203,16 @@ fn wire_off_topic_deliberately_panic_impl(port_: MessagePort) {
         move || move |task_callback| Ok(off_topic_deliberately_panic()),
     )
 }
+fn wire_warm_up_impl(port_: MessagePort) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "warm_up",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || move |task_callback| Ok(()),
+    )
+}
 // Section: wrapper structs
 
 // Section: static checks
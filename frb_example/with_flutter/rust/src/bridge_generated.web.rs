@@ -67,6 +67,11 @@ pub fn wire_off_topic_deliberately_panic(port_: MessagePort) {
     wire_off_topic_deliberately_panic_impl(port_)
 }
 
+#[wasm_bindgen]
+pub fn wire_warm_up(port_: MessagePort) {
+    wire_warm_up_impl(port_)
+}
+
 // Section: allocate functions
 
 // Section: related functions
@@ -0,0 +1,324 @@
+//! Cooperative cancellation of Rust functions from Dart.
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicBool, AtomicI32, AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, Weak};
+use std::task::{Context, Poll, Waker};
+
+lazy_static::lazy_static! {
+    /// Tokens of the calls that are still running, by the id of each call, see [new_cancel_id].
+    static ref TOKENS: Mutex<HashMap<i32, Weak<TokenInner>>> = Default::default();
+}
+
+/// Allocated here rather than in Dart, whose counters would be per isolate.
+static NEXT_ID: AtomicI32 = AtomicI32::new(0);
+
+/// Identifies the futures of [CancellationToken::run_until_cancelled] among the wakers of a token.
+static NEXT_WAITER: AtomicU64 = AtomicU64::new(0);
+
+thread_local! {
+    /// The token of the call running on this thread, see [CancellationToken::current].
+    static CURRENT: RefCell<Option<CancellationToken>> = const { RefCell::new(None) };
+}
+
+/// Use this type as an argument of your function to allow Dart to cancel it.
+///
+/// The argument is not visible in Dart. Instead, the generated Dart function returns a
+/// `CancelableFuture`, whose `cancel()` method flips this token. Since Rust functions
+/// cannot be interrupted, the function has to check [CancellationToken::is_cancelled]
+/// from time to time and return early, while the Dart future is completed with a
+/// `FlutterRustBridgeCancelledException` right away.
+///
+/// While the function runs, its token is also available as [CancellationToken::current], so
+/// that the work it starts can be cancelled together with it, see [CancellationToken::child].
+#[derive(Clone)]
+pub struct CancellationToken {
+    inner: Arc<TokenInner>,
+}
+
+struct TokenInner {
+    /// [None] for the children, which Dart cannot reach directly.
+    id: Option<i32>,
+    cancelled: AtomicBool,
+    parent: Option<CancellationToken>,
+    /// The futures of [CancellationToken::run_until_cancelled] waiting on this token, by the
+    /// id of each future, which removes its waker once it completes or is dropped.
+    wakers: Mutex<HashMap<u64, Waker>>,
+}
+
+impl Drop for TokenInner {
+    fn drop(&mut self) {
+        let id = match self.id {
+            Some(id) => id,
+            None => return,
+        };
+        let mut tokens = TOKENS.lock().unwrap();
+        // The id may have been taken over by a newer call in the meantime, once the counter
+        // wrapped around.
+        if tokens.get(&id).is_some_and(|weak| weak.strong_count() == 0) {
+            tokens.remove(&id);
+        }
+    }
+}
+
+impl TokenInner {
+    fn cancel(&self) {
+        self.cancelled.store(true, Ordering::SeqCst);
+        for (_, waker) in self.wakers.lock().unwrap().drain() {
+            waker.wake();
+        }
+    }
+}
+
+impl CancellationToken {
+    /// Whether the call has been cancelled from Dart, or the token is a [child](Self::child)
+    /// of one that has.
+    pub fn is_cancelled(&self) -> bool {
+        self.inner.cancelled.load(Ordering::SeqCst)
+            || (self.inner.parent.as_ref()).is_some_and(CancellationToken::is_cancelled)
+    }
+
+    /// Create a token for the call identified by `id`, as given by [new_cancel_id], so that
+    /// [cancel_task] can reach it.
+    #[doc(hidden)]
+    pub fn register(id: i32) -> Self {
+        let token = Self::new(Some(id), None);
+        TOKENS
+            .lock()
+            .unwrap()
+            .insert(id, Arc::downgrade(&token.inner));
+        token
+    }
+
+    fn new(id: Option<i32>, parent: Option<CancellationToken>) -> Self {
+        Self {
+            inner: Arc::new(TokenInner {
+                id,
+                cancelled: AtomicBool::new(false),
+                parent,
+                wakers: Default::default(),
+            }),
+        }
+    }
+
+    /// A token which is cancelled together with this one, and can be [cancelled](Self::cancel)
+    /// on its own as well, e.g. to pass to a nested call or a spawned task.
+    pub fn child(&self) -> Self {
+        Self::new(None, Some(self.clone()))
+    }
+
+    /// Cancel this token and its children, as Dart does when the call is cancelled.
+    pub fn cancel(&self) {
+        self.inner.cancel();
+    }
+
+    /// The token of the cancellable function running on this thread, if any, which is set
+    /// while the function, including an `async` one, runs. Use it to reach the token from code
+    /// it does not flow into, since spawned threads or tasks do not inherit it: give them a
+    /// [child](Self::child) instead.
+    pub fn current() -> Option<Self> {
+        CURRENT.with(|current| current.borrow().clone())
+    }
+
+    /// Run `f` with this token as the [current](Self::current) one.
+    pub fn scope<R>(&self, f: impl FnOnce() -> R) -> R {
+        struct Restore(Option<CancellationToken>);
+        impl Drop for Restore {
+            fn drop(&mut self) {
+                let previous = self.0.take();
+                CURRENT.with(|current| *current.borrow_mut() = previous);
+            }
+        }
+        let _restore = Restore(CURRENT.with(|current| current.replace(Some(self.clone()))));
+        f()
+    }
+
+    /// Same as [scope](Self::scope), for each poll of `future`, which may happen on different
+    /// threads when it is spawned on a runtime.
+    pub fn scope_future<F: Future>(self, future: F) -> impl Future<Output = F::Output> {
+        let mut future = Box::pin(future);
+        std::future::poll_fn(move |cx| self.scope(|| future.as_mut().poll(cx)))
+    }
+
+    /// Poll `future` until it completes, or drop it as soon as the token is cancelled, in
+    /// which case [None] is returned.
+    pub async fn run_until_cancelled<F: Future>(&self, future: F) -> Option<F::Output> {
+        UntilCancelled {
+            token: self,
+            id: NEXT_WAITER.fetch_add(1, Ordering::Relaxed),
+            future: Box::pin(future),
+        }
+        .await
+    }
+
+    /// This token and its ancestors, whose cancellation also cancels this one.
+    fn ancestors(&self) -> impl Iterator<Item = &CancellationToken> {
+        std::iter::successors(Some(self), |token| token.inner.parent.as_ref())
+    }
+}
+
+struct UntilCancelled<'a, F> {
+    token: &'a CancellationToken,
+    id: u64,
+    future: Pin<Box<F>>,
+}
+
+impl<F> Drop for UntilCancelled<'_, F> {
+    fn drop(&mut self) {
+        for token in self.token.ancestors() {
+            token.inner.wakers.lock().unwrap().remove(&self.id);
+        }
+    }
+}
+
+impl<F: Future> Future for UntilCancelled<'_, F> {
+    type Output = Option<F::Output>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        if self.token.is_cancelled() {
+            return Poll::Ready(None);
+        }
+        if let Poll::Ready(output) = self.future.as_mut().poll(cx) {
+            return Poll::Ready(Some(output));
+        }
+        // Replaces the waker of the previous poll, which may belong to another task.
+        for token in self.token.ancestors() {
+            let mut wakers = token.inner.wakers.lock().unwrap();
+            match wakers.get(&self.id) {
+                Some(waker) if waker.will_wake(cx.waker()) => {}
+                _ => {
+                    wakers.insert(self.id, cx.waker().clone());
+                }
+            }
+        }
+        // Cancelled while registering the wakers.
+        if self.token.is_cancelled() {
+            return Poll::Ready(None);
+        }
+        Poll::Pending
+    }
+}
+
+/// A new id for a call, which Dart passes to the call and to [cancel_task].
+pub(crate) fn new_cancel_id() -> i32 {
+    NEXT_ID.fetch_add(1, Ordering::Relaxed)
+}
+
+/// Cancel the call identified by `id`. Does nothing if the call has already finished.
+pub(crate) fn cancel_task(id: i32) {
+    let token = TOKENS.lock().unwrap().get(&id).and_then(Weak::upgrade);
+    if let Some(token) = token {
+        token.cancel();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cancel_reaches_token() {
+        let id = new_cancel_id();
+        let token = CancellationToken::register(id);
+        assert!(!token.is_cancelled());
+        cancel_task(id);
+        assert!(token.is_cancelled());
+    }
+
+    #[test]
+    fn ids_are_not_reused() {
+        let ids = (0..2)
+            .map(|_| std::thread::spawn(new_cancel_id))
+            .map(|thread| thread.join().unwrap())
+            .collect::<Vec<_>>();
+        assert_ne!(ids[0], ids[1]);
+    }
+
+    #[test]
+    fn cancel_reaches_children() {
+        let id = new_cancel_id();
+        let token = CancellationToken::register(id);
+        let child = token.child();
+        let grandchild = child.child();
+        grandchild.cancel();
+        assert!(!child.is_cancelled());
+        cancel_task(id);
+        assert!(child.is_cancelled());
+        assert!(grandchild.is_cancelled());
+    }
+
+    #[test]
+    fn current_token_is_scoped() {
+        let id = new_cancel_id();
+        let token = CancellationToken::register(id);
+        assert!(CancellationToken::current().is_none());
+        token.scope(|| {
+            cancel_task(id);
+            assert!(CancellationToken::current().unwrap().is_cancelled());
+        });
+        assert!(CancellationToken::current().is_none());
+    }
+
+    #[test]
+    fn cancel_drops_pending_future() {
+        let id = new_cancel_id();
+        let token = CancellationToken::register(id);
+        let child = token.child();
+        let (sender, receiver) = std::sync::mpsc::channel();
+        std::thread::spawn(move || {
+            receiver.recv().unwrap();
+            cancel_task(id);
+        });
+        let output = crate::support::park_on(child.run_until_cancelled(async {
+            sender.send(()).unwrap();
+            std::future::pending::<()>().await
+        }));
+        assert_eq!(output, None);
+        let done = crate::support::park_on(token.run_until_cancelled(async { 1 }));
+        assert_eq!(done, None);
+    }
+
+    #[test]
+    fn current_token_is_scoped_to_polls() {
+        let token = CancellationToken::register(new_cancel_id());
+        let future = token.clone().scope_future(async {
+            std::future::ready(()).await;
+            CancellationToken::current().is_some()
+        });
+        assert!(CancellationToken::current().is_none());
+        assert!(crate::support::park_on(future));
+        assert!(CancellationToken::current().is_none());
+    }
+
+    #[test]
+    fn finished_future_removes_its_wakers() {
+        let token = CancellationToken::register(new_cancel_id());
+        let child = token.child();
+        for _ in 0..3 {
+            let mut polled = false;
+            let output =
+                crate::support::park_on(child.run_until_cancelled(std::future::poll_fn(|cx| {
+                    if std::mem::replace(&mut polled, true) {
+                        Poll::Ready(1)
+                    } else {
+                        cx.waker().wake_by_ref();
+                        Poll::Pending
+                    }
+                })));
+            assert_eq!(output, Some(1));
+        }
+        assert!(child.inner.wakers.lock().unwrap().is_empty());
+        assert!(token.inner.wakers.lock().unwrap().is_empty());
+    }
+
+    #[test]
+    fn cancel_after_finish_is_noop() {
+        let id = new_cancel_id();
+        drop(CancellationToken::register(id));
+        cancel_task(id);
+        assert!(!TOKENS.lock().unwrap().contains_key(&id));
+    }
+}
@@ -0,0 +1,52 @@
+//! The change streams of the opaque objects with `#[frb(notifies)]` methods.
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+use crate::{DartSafe, RustOpaque, StreamSink};
+
+lazy_static::lazy_static! {
+    /// The sinks of the change streams, by the address of the object they observe.
+    static ref LISTENERS: Mutex<HashMap<usize, Vec<Listener>>> = Default::default();
+}
+
+struct Listener {
+    /// Whether the object is still alive, since its address may be reused once it is dropped.
+    alive: Box<dyn Fn() -> bool + Send>,
+    sink: StreamSink<()>,
+}
+
+fn address<T: DartSafe>(object: &RustOpaque<T>) -> usize {
+    &**object as *const T as usize
+}
+
+/// Send an event to `sink` whenever a `#[frb(notifies)]` method of `object` returns
+/// successfully, until the object is dropped or the Dart stream is gone.
+pub fn listen_changes<T: DartSafe + Send + Sync + 'static>(
+    object: &RustOpaque<T>,
+    sink: StreamSink<()>,
+) {
+    let weak = object.downgrade();
+    let mut listeners = LISTENERS.lock().unwrap();
+    // The sinks of the dropped objects are dropped too, which closes their Dart streams.
+    listeners.retain(|_, sinks| {
+        sinks.retain(|listener| (listener.alive)());
+        !sinks.is_empty()
+    });
+    listeners
+        .entry(address(object))
+        .or_default()
+        .push(Listener {
+            alive: Box::new(move || weak.strong_count() > 0),
+            sink,
+        });
+}
+
+/// Send an event to the change streams of `object`.
+pub fn notify_changes<T: DartSafe>(object: &RustOpaque<T>) {
+    let mut listeners = LISTENERS.lock().unwrap();
+    if let Some(sinks) = listeners.get_mut(&address(object)) {
+        // A sink fails to send once its Dart stream is gone.
+        sinks.retain(|listener| (listener.alive)() && listener.sink.add(()));
+    }
+}
@@ -68,6 +68,34 @@ pub unsafe extern "C" fn drop_dart_object(ptr: usize) {
     Dart_DeletePersistentHandle_DL.expect("dart_api_dl has not been initialized")(ptr as _);
 }
 
+/// This function should never be called manually.
+/// It is called by Dart to identify a call it may cancel, see [crate::CancellationToken].
+#[no_mangle]
+pub extern "C" fn new_cancel_id() -> i32 {
+    crate::cancellation::new_cancel_id()
+}
+
+/// This function should never be called manually.
+/// It is called by Dart to cancel the call identified by `id`, see [crate::CancellationToken].
+#[no_mangle]
+pub extern "C" fn cancel_task(id: i32) {
+    crate::cancellation::cancel_task(id)
+}
+
+/// This function should never be called manually.
+/// It is called by Dart to allocate the bytes of a [crate::SharedBuffer].
+#[no_mangle]
+pub extern "C" fn new_shared_buffer(len: usize) -> usize {
+    crate::shared_buffer::new_shared_buffer(len)
+}
+
+/// This function should never be called manually.
+/// It is called by Dart to dispose a [crate::SharedBuffer].
+#[no_mangle]
+pub extern "C" fn drop_shared_buffer(address: usize) {
+    crate::shared_buffer::drop_shared_buffer(address)
+}
+
 /// # Safety
 ///
 /// This function should never be called manually.
@@ -51,12 +51,24 @@ use crate::DartSafe;
 #[cfg(feature = "uuid")]
 const UUID_SIZE_IN_BYTES: usize = 16;
 
+/// The bytes are taken as-is, so the layout is preserved whatever the uuid version.
+///
+/// # Panics
+///
+/// Panics with a descriptive message if the slice is not exactly 16 bytes long.
 #[cfg(feature = "uuid")]
 #[inline]
 pub fn wire2api_uuid_ref(id: &[u8]) -> uuid::Uuid {
     uuid::Uuid::from_bytes(
-        *<&[u8] as std::convert::TryInto<&[u8; UUID_SIZE_IN_BYTES]>>::try_into(id)
-            .expect("invalid uuid slice"),
+        *<&[u8] as std::convert::TryInto<&[u8; UUID_SIZE_IN_BYTES]>>::try_into(id).unwrap_or_else(
+            |_| {
+                panic!(
+                    "Invalid uuid slice: expected {} bytes but got {}",
+                    UUID_SIZE_IN_BYTES,
+                    id.len()
+                )
+            },
+        ),
     )
 }
 
@@ -69,12 +81,440 @@ pub fn wire2api_uuid(id: Vec<u8>) -> uuid::Uuid {
 #[cfg(feature = "uuid")]
 #[inline]
 pub fn wire2api_uuids(ids: Vec<u8>) -> Vec<uuid::Uuid> {
+    assert!(
+        ids.len() % UUID_SIZE_IN_BYTES == 0,
+        "Invalid uuids buffer: expected a multiple of {} bytes but got {}",
+        UUID_SIZE_IN_BYTES,
+        ids.len()
+    );
     ids.as_slice()
         .chunks(UUID_SIZE_IN_BYTES)
         .map(wire2api_uuid_ref)
         .collect::<Vec<uuid::Uuid>>()
 }
 
+/// Takes over the allocation of the received bytes.
+#[cfg(feature = "bytes")]
+#[inline]
+pub fn wire2api_bytes(vec: Vec<u8>) -> bytes::Bytes {
+    bytes::Bytes::from(vec)
+}
+
+/// Only the visible slice is sent, even if `bytes` was sliced out of a larger buffer.
+/// The allocation is handed over to Dart without copying when `bytes` is uniquely owned.
+#[cfg(feature = "bytes")]
+#[inline]
+pub fn api2wire_bytes(bytes: bytes::Bytes) -> ZeroCopyBuffer<Vec<u8>> {
+    ZeroCopyBuffer(Vec::from(bytes))
+}
+
+/// Parse the decimal sent by Dart as its canonical string, without going through a float.
+///
+/// Panics if it has more digits than a `Decimal` holds, i.e. 28 after the decimal point or
+/// 96 bits of mantissa, instead of silently rounding it.
+#[cfg(feature = "rust_decimal")]
+pub fn wire2api_decimal(s: String) -> rust_decimal::Decimal {
+    rust_decimal::Decimal::from_str_exact(&s)
+        .unwrap_or_else(|err| panic!("Invalid decimal `{}`: {}", s, err))
+}
+
+/// Rebuild a path from the UTF-16 code units of a Dart string, see [api2wire_path].
+#[inline]
+pub fn wire2api_path(units: Vec<u16>) -> std::path::PathBuf {
+    path::from_units(&units)
+}
+
+/// Paths are sent as the UTF-16 code units of a Dart string, so that paths that are not
+/// valid Unicode still round-trip unchanged:
+/// - On Windows, the code units of the path are taken as-is, unpaired surrogates included.
+/// - On Unix, each byte that is not part of valid UTF-8 becomes the unpaired surrogate
+///   `U+DC80 + byte`, as done by Python's `surrogateescape`.
+#[inline]
+pub fn api2wire_path(path: std::path::PathBuf) -> Vec<u16> {
+    path::to_units(path.as_os_str())
+}
+
+mod path {
+    use std::ffi::{OsStr, OsString};
+    use std::path::PathBuf;
+
+    #[cfg(windows)]
+    pub fn to_units(path: &OsStr) -> Vec<u16> {
+        use std::os::windows::ffi::OsStrExt;
+        path.encode_wide().collect()
+    }
+
+    #[cfg(windows)]
+    pub fn from_units(units: &[u16]) -> PathBuf {
+        use std::os::windows::ffi::OsStringExt;
+        OsString::from_wide(units).into()
+    }
+
+    #[cfg(unix)]
+    pub fn to_units(path: &OsStr) -> Vec<u16> {
+        use std::os::unix::ffi::OsStrExt;
+        let mut bytes = path.as_bytes();
+        let mut units = Vec::with_capacity(bytes.len());
+        loop {
+            match std::str::from_utf8(bytes) {
+                Ok(valid) => {
+                    units.extend(valid.encode_utf16());
+                    return units;
+                }
+                Err(err) => {
+                    let (valid, rest) = bytes.split_at(err.valid_up_to());
+                    units.extend(std::str::from_utf8(valid).unwrap().encode_utf16());
+                    let invalid = err.error_len().unwrap_or(rest.len());
+                    units.extend(rest[..invalid].iter().map(|&byte| 0xDC00 | u16::from(byte)));
+                    bytes = &rest[invalid..];
+                }
+            }
+        }
+    }
+
+    #[cfg(unix)]
+    pub fn from_units(units: &[u16]) -> PathBuf {
+        use std::os::unix::ffi::OsStringExt;
+        let mut bytes = Vec::with_capacity(units.len());
+        for decoded in char::decode_utf16(units.iter().copied()) {
+            let c = match decoded {
+                Ok(c) => c,
+                Err(err) => match err.unpaired_surrogate() {
+                    escaped @ 0xDC80..=0xDCFF => {
+                        bytes.push(escaped as u8);
+                        continue;
+                    }
+                    _ => char::REPLACEMENT_CHARACTER,
+                },
+            };
+            bytes.extend_from_slice(c.encode_utf8(&mut [0; 4]).as_bytes());
+        }
+        OsString::from_vec(bytes).into()
+    }
+
+    #[cfg(not(any(windows, unix)))]
+    pub fn to_units(path: &OsStr) -> Vec<u16> {
+        path.to_string_lossy().encode_utf16().collect()
+    }
+
+    #[cfg(not(any(windows, unix)))]
+    pub fn from_units(units: &[u16]) -> PathBuf {
+        OsString::from(String::from_utf16_lossy(units)).into()
+    }
+
+    #[cfg(test)]
+    #[cfg(unix)]
+    mod tests {
+        use std::ffi::OsStr;
+        use std::os::unix::ffi::OsStrExt;
+
+        #[test]
+        fn non_utf8_path_round_trips() {
+            let path = OsStr::from_bytes(b"/tmp/caf\xe9/\xff\xfe.txt");
+            let units = super::to_units(path);
+            assert_eq!(String::from_utf16_lossy(&units[..8]), "/tmp/caf");
+            assert_eq!(super::from_units(&units).as_os_str(), path);
+        }
+    }
+}
+
+const IP_V4: u8 = 4;
+const IP_V6: u8 = 6;
+
+/// Rebuild an IP address from the bytes sent by Dart, see [api2wire_ip_addr].
+///
+/// # Panics
+///
+/// Panics if the buffer is not a valid encoding of an address.
+#[inline]
+pub fn wire2api_ip_addr(bytes: Vec<u8>) -> std::net::IpAddr {
+    let (ip, rest) = split_ip_addr(&bytes);
+    assert!(
+        rest.is_empty(),
+        "Invalid ip address: {} trailing bytes",
+        rest.len()
+    );
+    ip
+}
+
+/// IP addresses are sent as a discriminant, `4` or `6`, followed by the raw octets
+/// of the address in network order.
+#[inline]
+pub fn api2wire_ip_addr(ip: std::net::IpAddr) -> Vec<u8> {
+    match ip {
+        std::net::IpAddr::V4(ip) => [&[IP_V4][..], &ip.octets()].concat(),
+        std::net::IpAddr::V6(ip) => [&[IP_V6][..], &ip.octets()].concat(),
+    }
+}
+
+/// Rebuild a socket address from the bytes sent by Dart, see [api2wire_socket_addr].
+///
+/// # Panics
+///
+/// Panics if the buffer is not a valid encoding of a socket address.
+pub fn wire2api_socket_addr(bytes: Vec<u8>) -> std::net::SocketAddr {
+    use std::net::{IpAddr, SocketAddr, SocketAddrV4, SocketAddrV6};
+
+    let (ip, rest) = split_ip_addr(&bytes);
+    let expected = if ip.is_ipv4() { 2 } else { 6 };
+    assert!(
+        rest.len() == expected,
+        "Invalid socket address: expected {} bytes after the ip but got {}",
+        expected,
+        rest.len()
+    );
+    let port = u16::from_be_bytes([rest[0], rest[1]]);
+    match ip {
+        IpAddr::V4(ip) => SocketAddr::V4(SocketAddrV4::new(ip, port)),
+        IpAddr::V6(ip) => {
+            let scope_id = u32::from_be_bytes([rest[2], rest[3], rest[4], rest[5]]);
+            SocketAddr::V6(SocketAddrV6::new(ip, port, 0, scope_id))
+        }
+    }
+}
+
+/// Socket addresses are sent as their ip, see [api2wire_ip_addr], followed by the port as
+/// 2 big-endian bytes and, for IPv6 only, the scope id as 4 big-endian bytes.
+/// The IPv6 flow info is dropped.
+#[inline]
+pub fn api2wire_socket_addr(addr: std::net::SocketAddr) -> Vec<u8> {
+    let mut bytes = api2wire_ip_addr(addr.ip());
+    bytes.extend_from_slice(&addr.port().to_be_bytes());
+    if let std::net::SocketAddr::V6(addr) = addr {
+        bytes.extend_from_slice(&addr.scope_id().to_be_bytes());
+    }
+    bytes
+}
+
+fn split_ip_addr(bytes: &[u8]) -> (std::net::IpAddr, &[u8]) {
+    use std::convert::TryInto;
+
+    match bytes.split_first() {
+        Some((&IP_V4, rest)) if rest.len() >= 4 => {
+            let (octets, rest) = rest.split_at(4);
+            let octets: [u8; 4] = octets.try_into().unwrap();
+            (octets.into(), rest)
+        }
+        Some((&IP_V6, rest)) if rest.len() >= 16 => {
+            let (octets, rest) = rest.split_at(16);
+            let octets: [u8; 16] = octets.try_into().unwrap();
+            (octets.into(), rest)
+        }
+        _ => panic!("Invalid ip address: {:?}", bytes),
+    }
+}
+
+/// Rebuild an [i128] from the 16 little-endian bytes sent by Dart.
+///
+/// # Panics
+///
+/// Panics if the buffer is not exactly 16 bytes long.
+#[inline]
+pub fn wire2api_i128(bytes: Vec<u8>) -> i128 {
+    i128::from_le_bytes(wire2api_int128_bytes(&bytes))
+}
+
+/// Rebuild a [u128] from the 16 little-endian bytes sent by Dart.
+///
+/// # Panics
+///
+/// Panics if the buffer is not exactly 16 bytes long.
+#[inline]
+pub fn wire2api_u128(bytes: Vec<u8>) -> u128 {
+    u128::from_le_bytes(wire2api_int128_bytes(&bytes))
+}
+
+/// Rebuild a [char] from the Unicode scalar value sent by Dart.
+///
+/// # Panics
+///
+/// Panics if the value is a surrogate or beyond `U+10FFFF`.
+#[inline]
+pub fn wire2api_char(value: u32) -> char {
+    char::from_u32(value).unwrap_or_else(|| {
+        panic!(
+            "Invalid char: U+{:04X} is not a Unicode scalar value",
+            value
+        )
+    })
+}
+
+/// Rebuild a `std::num::NonZero*` integer from the value sent by Dart.
+///
+/// # Panics
+///
+/// Panics if the value is zero.
+#[inline]
+pub fn wire2api_non_zero<T, N: std::convert::TryFrom<T>>(value: T) -> N {
+    N::try_from(value).unwrap_or_else(|_| {
+        panic!(
+            "Invalid {}: expected a non-zero value but got 0",
+            std::any::type_name::<N>()
+        )
+    })
+}
+
+/// Convert the two bounds of a `Range` or a `RangeInclusive`, sent as a list of `W`.
+///
+/// Panics if there are not two bounds, if one does not fit in `T`, or if the start is
+/// after the end, rather than building a range which would silently be empty.
+fn wire2api_bounds<W, T>(bounds: Vec<W>) -> (T, T)
+where
+    W: Copy + std::fmt::Debug,
+    T: std::convert::TryFrom<W> + PartialOrd + std::fmt::Debug,
+{
+    if bounds.len() != 2 {
+        panic!("Invalid range: expected 2 bounds but got {}", bounds.len());
+    }
+    let bound = |value: W| {
+        T::try_from(value).unwrap_or_else(|_| {
+            panic!(
+                "Invalid range: {:?} does not fit in {}",
+                value,
+                std::any::type_name::<T>()
+            )
+        })
+    };
+    let (start, end) = (bound(bounds[0]), bound(bounds[1]));
+    // Also rejects a NaN bound, which is not comparable.
+    if !matches!(
+        start.partial_cmp(&end),
+        Some(std::cmp::Ordering::Less | std::cmp::Ordering::Equal)
+    ) {
+        panic!(
+            "Invalid range: the start {:?} is after the end {:?}",
+            start, end
+        );
+    }
+    (start, end)
+}
+
+/// Build the `start..end` received from Dart, see [wire2api_bounds] for when it panics.
+pub fn wire2api_range<W, T>(bounds: Vec<W>) -> std::ops::Range<T>
+where
+    W: Copy + std::fmt::Debug,
+    T: std::convert::TryFrom<W> + PartialOrd + std::fmt::Debug,
+{
+    let (start, end) = wire2api_bounds(bounds);
+    start..end
+}
+
+/// Build the `start..=end` received from Dart, see [wire2api_bounds] for when it panics.
+pub fn wire2api_range_inclusive<W, T>(bounds: Vec<W>) -> std::ops::RangeInclusive<T>
+where
+    W: Copy + std::fmt::Debug,
+    T: std::convert::TryFrom<W> + PartialOrd + std::fmt::Debug,
+{
+    let (start, end) = wire2api_bounds(bounds);
+    start..=end
+}
+
+/// The bounds of `range`, as the list element type `W` of its wire.
+pub fn api2wire_range<W: std::convert::TryFrom<T>, T>(range: std::ops::Range<T>) -> Vec<W> {
+    api2wire_bounds(range.start, range.end)
+}
+
+/// Same as [api2wire_range], for a `RangeInclusive`.
+pub fn api2wire_range_inclusive<W: std::convert::TryFrom<T>, T>(
+    range: std::ops::RangeInclusive<T>,
+) -> Vec<W> {
+    let (start, end) = range.into_inner();
+    api2wire_bounds(start, end)
+}
+
+// `W` is a 64-bit integer for pointer-sized bounds, and the same type otherwise.
+fn api2wire_bounds<W: std::convert::TryFrom<T>, T>(start: T, end: T) -> Vec<W> {
+    let bound = |value| {
+        W::try_from(value).unwrap_or_else(|_| unreachable!("pointer-sized integers fit in 64 bits"))
+    };
+    vec![bound(start), bound(end)]
+}
+
+/// Convert a `Vec<usize>` or a `Vec<isize>`, sent as a list of 64-bit integers `W`.
+///
+/// Panics if an item does not fit in `T`, which can only happen on 32-bit platforms.
+pub fn wire2api_size_list<W, T>(list: Vec<W>) -> Vec<T>
+where
+    W: Copy + std::fmt::Debug,
+    T: std::convert::TryFrom<W>,
+{
+    list.into_iter()
+        .map(|value| {
+            T::try_from(value).unwrap_or_else(|_| {
+                panic!(
+                    "Invalid list: {:?} does not fit in {}",
+                    value,
+                    std::any::type_name::<T>()
+                )
+            })
+        })
+        .collect()
+}
+
+/// The items of a `Vec<usize>` or a `Vec<isize>`, as the 64-bit integers `W` of its wire.
+pub fn api2wire_size_list<W: std::convert::TryFrom<T>, T>(list: Vec<T>) -> Vec<W> {
+    list.into_iter()
+        .map(|value| {
+            W::try_from(value)
+                .unwrap_or_else(|_| unreachable!("pointer-sized integers fit in 64 bits"))
+        })
+        .collect()
+}
+
+/// Nanoseconds per unit of the timestamps exchanged with Dart: microseconds on native
+/// platforms, and milliseconds on the web, matching the precision of a Dart `DateTime`.
+#[cfg(not(wasm))]
+const NANOS_PER_TIMESTAMP_UNIT: i128 = 1_000;
+#[cfg(wasm)]
+const NANOS_PER_TIMESTAMP_UNIT: i128 = 1_000_000;
+
+const NANOS_PER_SECOND: u128 = 1_000_000_000;
+
+/// Rebuild a [std::time::SystemTime] from its signed timestamp since the Unix epoch,
+/// see [api2wire_system_time].
+///
+/// # Panics
+///
+/// Panics if the time cannot be represented by the platform.
+pub fn wire2api_system_time(ts: i64) -> std::time::SystemTime {
+    let nanos = i128::from(ts) * NANOS_PER_TIMESTAMP_UNIT;
+    let offset = std::time::Duration::new(
+        (nanos.unsigned_abs() / NANOS_PER_SECOND) as u64,
+        (nanos.unsigned_abs() % NANOS_PER_SECOND) as u32,
+    );
+    let time = if nanos >= 0 {
+        std::time::UNIX_EPOCH.checked_add(offset)
+    } else {
+        std::time::UNIX_EPOCH.checked_sub(offset)
+    };
+    time.unwrap_or_else(|| panic!("Invalid SystemTime: {} is out of range", ts))
+}
+
+/// Times are sent as the signed number of units since the Unix epoch, negative before it,
+/// rounded down to the precision of a Dart `DateTime`.
+///
+/// # Panics
+///
+/// Panics if the time is too far from the epoch to fit in an `i64`.
+pub fn api2wire_system_time(time: std::time::SystemTime) -> i64 {
+    let nanos = match time.duration_since(std::time::UNIX_EPOCH) {
+        Ok(after) => after.as_nanos() as i128,
+        Err(before) => -(before.duration().as_nanos() as i128),
+    };
+    std::convert::TryInto::try_into(nanos.div_euclid(NANOS_PER_TIMESTAMP_UNIT))
+        .unwrap_or_else(|_| panic!("Invalid SystemTime: {:?} is out of range", time))
+}
+
+#[inline]
+fn wire2api_int128_bytes(bytes: &[u8]) -> [u8; 16] {
+    std::convert::TryInto::try_into(bytes).unwrap_or_else(|_| {
+        panic!(
+            "Expected 16 bytes for a 128-bit integer but got {}",
+            bytes.len()
+        )
+    })
+}
+
 /// A wrapper to transfer ownership of T to Dart.
 ///
 /// This type is equivalent to an [`Option<Arc<T>>`]. The inner pointer may
@@ -161,6 +601,17 @@ impl<T: ?Sized + DartSafe> From<Arc<T>> for RustOpaque<T> {
     }
 }
 
+impl<T: ?Sized + DartSafe> RustOpaque<T> {
+    /// Take the underlying [Arc] out, keeping the reference counts held by Dart handles intact.
+    pub fn into_arc(self) -> Arc<T> {
+        self.ptr.expect("Use after free.")
+    }
+
+    pub(crate) fn downgrade(&self) -> std::sync::Weak<T> {
+        Arc::downgrade(self.ptr.as_ref().expect("Use after free."))
+    }
+}
+
 impl<T: DartSafe> RustOpaque<T> {
     pub fn new(value: T) -> Self {
         Self {
@@ -281,3 +732,170 @@ macro_rules! opaque_dyn {
         $crate::RustOpaque::new(::std::boxed::Box::new($ex))
     };
 }
+
+/// Probes whether `T` is `Send + Sync`, see [is_send_sync].
+#[doc(hidden)]
+pub struct SendSyncProbe<T: ?Sized>(pub std::marker::PhantomData<T>);
+
+#[doc(hidden)]
+pub trait IsSendSync {
+    fn is_send_sync(&self) -> bool {
+        true
+    }
+}
+
+impl<T: ?Sized + Send + Sync> IsSendSync for SendSyncProbe<T> {}
+
+#[doc(hidden)]
+pub trait IsNotSendSync {
+    fn is_send_sync(&self) -> bool {
+        false
+    }
+}
+
+impl<T: ?Sized> IsNotSendSync for &SendSyncProbe<T> {}
+
+/// Whether the concrete type `$ty` is `Send + Sync`, without requiring it, so that the
+/// generated code can refuse to send an opaque object to another isolate at runtime instead
+/// of rejecting all the other uses of a type which is not thread safe.
+///
+/// Method resolution picks [IsSendSync] when its bound holds, and falls back to
+/// [IsNotSendSync], which takes one more reference, otherwise.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! is_send_sync {
+    ($ty:ty) => {{
+        #[allow(unused_imports)]
+        use $crate::ffi::{IsNotSendSync as _, IsSendSync as _};
+        (&$crate::ffi::SendSyncProbe::<$ty>(::std::marker::PhantomData)).is_send_sync()
+    }};
+}
+
+#[cfg(test)]
+mod opaque_tests {
+    #[test]
+    fn is_send_sync_tells_thread_safe_types() {
+        assert!(is_send_sync!(std::sync::Mutex<String>));
+        assert!(!is_send_sync!(std::rc::Rc<String>));
+        assert!(!is_send_sync!(std::cell::Cell<u8>));
+    }
+}
+
+#[cfg(test)]
+mod net_tests {
+    #[test]
+    fn socket_addr_round_trips() {
+        for addr in ["1.2.3.4:80", "[fe80::1%3]:443"] {
+            let addr: std::net::SocketAddr = addr.parse().unwrap();
+            assert_eq!(
+                super::wire2api_socket_addr(super::api2wire_socket_addr(addr)),
+                addr
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod scalar_tests {
+    #[test]
+    #[should_panic(expected = "Invalid char: U+D800 is not a Unicode scalar value")]
+    fn wire2api_char_rejects_surrogates() {
+        assert_eq!(super::wire2api_char(0x1F600), '😀');
+        super::wire2api_char(0xD800);
+    }
+
+    #[test]
+    #[should_panic(expected = "expected a non-zero value but got 0")]
+    fn wire2api_non_zero_rejects_zero() {
+        let id: std::num::NonZeroU32 = super::wire2api_non_zero(7u32);
+        assert_eq!(id.get(), 7);
+        let _: std::num::NonZeroU32 = super::wire2api_non_zero(0u32);
+    }
+}
+
+#[cfg(test)]
+mod range_tests {
+    #[test]
+    fn ranges_round_trip() {
+        let range: std::ops::Range<usize> = super::wire2api_range(vec![2u64, 5]);
+        assert_eq!(range, 2..5);
+        assert_eq!(super::api2wire_range::<u64, _>(range), [2, 5]);
+        let range: std::ops::RangeInclusive<i32> = super::wire2api_range_inclusive(vec![-1, -1]);
+        assert_eq!(super::api2wire_range_inclusive::<i32, _>(range), [-1, -1]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid range: the start 5 is after the end 2")]
+    fn reversed_ranges_are_rejected() {
+        let _: std::ops::Range<usize> = super::wire2api_range(vec![5u64, 2]);
+    }
+}
+
+#[cfg(test)]
+mod size_list_tests {
+    #[test]
+    fn size_lists_round_trip() {
+        let list: Vec<isize> = super::wire2api_size_list(vec![-1i64, 0, 7]);
+        assert_eq!(list, [-1, 0, 7]);
+        assert_eq!(super::api2wire_size_list::<i64, _>(list), [-1, 0, 7]);
+    }
+}
+
+#[cfg(test)]
+mod time_tests {
+    use std::time::{Duration, UNIX_EPOCH};
+
+    #[test]
+    fn system_time_round_trips_before_the_epoch() {
+        for ts in [0, 1_500, -1, -1_500] {
+            let time = super::wire2api_system_time(ts);
+            assert_eq!(super::api2wire_system_time(time), ts);
+        }
+        // Sub-unit precision is rounded down, also before the epoch.
+        assert_eq!(
+            super::api2wire_system_time(UNIX_EPOCH - Duration::from_nanos(1)),
+            -1
+        );
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "uuid")]
+mod tests {
+    #[test]
+    fn wire2api_uuids_keeps_bytes() {
+        let a = uuid::Uuid::from_bytes([1; 16]);
+        let b = uuid::Uuid::from_bytes([0xab; 16]);
+        let bytes = [a.as_bytes().as_slice(), b.as_bytes().as_slice()].concat();
+        assert_eq!(super::wire2api_uuids(bytes), vec![a, b]);
+    }
+
+    #[test]
+    #[should_panic(expected = "expected 16 bytes but got 3")]
+    fn wire2api_uuid_rejects_invalid_length() {
+        super::wire2api_uuid(vec![1, 2, 3]);
+    }
+}
+
+#[cfg(all(test, feature = "rust_decimal"))]
+mod decimal_tests {
+    use super::wire2api_decimal;
+
+    #[test]
+    fn decimal_round_trips_exactly() {
+        for s in [
+            "0",
+            "-1.50",
+            "79228162514264337593543950335",
+            "-0.0000000000000000000000000001",
+        ] {
+            assert_eq!(wire2api_decimal(s.to_owned()).to_string(), s);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid decimal")]
+    fn decimal_rejects_what_it_cannot_hold() {
+        wire2api_decimal("0.00000000000000000000000000001".to_owned());
+    }
+}
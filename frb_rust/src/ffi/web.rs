@@ -1,3 +1,4 @@
+use std::cell::Cell;
 use std::iter::FromIterator;
 
 use super::DartAbi;
@@ -27,6 +28,8 @@ impl<T: DartSafe> IntoDartExceptPrimitive for RustOpaque<T> {}
 impl IntoDartExceptPrimitive for DartOpaque {}
 impl IntoDartExceptPrimitive for String {}
 impl<T: IntoDart> IntoDartExceptPrimitive for Option<T> {}
+// Lets lists be nested, e.g. `Vec<Vec<String>>`.
+impl<T: IntoDartExceptPrimitive> IntoDartExceptPrimitive for Vec<T> {}
 
 impl IntoDart for () {
     #[inline]
@@ -104,6 +107,7 @@ macro_rules! delegate_buffer {
                 <$buffer>::from(self.as_slice()).into()
             }
         }
+        impl IntoDartExceptPrimitive for $ty {}
     )*};
 }
 // Orphan rules disallow blanket implementations, so we have to manually delegate here.
@@ -122,14 +126,65 @@ delegate_buffer! {
     Vec<u32> => js_sys::Uint32Array
     Vec<f32> => js_sys::Float32Array
     Vec<f64> => js_sys::Float64Array
-    ZeroCopyBuffer<Vec<i8>> => js_sys::Int8Array
-    ZeroCopyBuffer<Vec<u8>> => js_sys::Uint8Array
-    ZeroCopyBuffer<Vec<i16>> => js_sys::Int16Array
-    ZeroCopyBuffer<Vec<u16>> => js_sys::Uint16Array
-    ZeroCopyBuffer<Vec<i32>> => js_sys::Int32Array
-    ZeroCopyBuffer<Vec<u32>> => js_sys::Uint32Array
-    ZeroCopyBuffer<Vec<f32>> => js_sys::Float32Array
-    ZeroCopyBuffer<Vec<f64>> => js_sys::Float64Array
+}
+
+thread_local! {
+    /// Typed arrays created from a [ZeroCopyBuffer], whose buffer can be transferred
+    /// instead of copied when posted to Dart.
+    static TRANSFERABLE: WeakSet = WeakSet::new();
+    /// Whether any typed array has been added to [TRANSFERABLE] since the last post,
+    /// so that other messages are not searched for nothing.
+    static HAS_TRANSFERABLE: Cell<bool> = Cell::new(false);
+}
+
+/// Mark a typed array created from a [ZeroCopyBuffer]. It must own its buffer: the WASM
+/// memory may be shared with other workers, so a view into it cannot be handed over.
+fn mark_transferable(array: &Object) {
+    TRANSFERABLE.with(|set| set.add(array));
+    HAS_TRANSFERABLE.with(|flag| flag.set(true));
+}
+
+/// The buffers of the typed arrays in `msg` created from a [ZeroCopyBuffer].
+fn transferables(msg: &JsValue) -> Array {
+    fn visit(value: &JsValue, set: &WeakSet, out: &Array) {
+        if let Some(array) = value.dyn_ref::<Array>() {
+            array.for_each(&mut |item, _, _| visit(&item, set, out));
+        } else if let Some(object) = value.dyn_ref::<Object>() {
+            if set.has(object) {
+                if let Ok(buffer) = Reflect::get(object, &"buffer".into()) {
+                    out.push(&buffer);
+                }
+            }
+        }
+    }
+    let out = Array::new();
+    if HAS_TRANSFERABLE.with(|flag| flag.replace(false)) {
+        TRANSFERABLE.with(|set| visit(msg, set, &out));
+    }
+    out
+}
+
+macro_rules! delegate_zero_copy_buffer {
+    ($( $ty:ty => $buffer:ty )*) => {$(
+        impl IntoDart for ZeroCopyBuffer<Vec<$ty>> {
+            #[inline]
+            fn into_dart(self) -> DartAbi {
+                let array = <$buffer>::from(self.as_slice());
+                mark_transferable(&array);
+                array.into()
+            }
+        }
+    )*};
+}
+delegate_zero_copy_buffer! {
+    i8 => js_sys::Int8Array
+    u8 => js_sys::Uint8Array
+    i16 => js_sys::Int16Array
+    u16 => js_sys::Uint16Array
+    i32 => js_sys::Int32Array
+    u32 => js_sys::Uint32Array
+    f32 => js_sys::Float32Array
+    f64 => js_sys::Float64Array
 }
 
 impl<T: IntoDartExceptPrimitive> IntoDart for Vec<T> {
@@ -202,24 +257,26 @@ macro_rules! delegate_big_buffers {
                 <$buffer>::new(&buf.buffer()).into()
             }
         }
+        impl IntoDartExceptPrimitive for $buf {}
     )*};
 }
 delegate_big_buffers! {
     Vec<i64> => BigInt64Array
     Vec<u64> => BigUint64Array
 }
-impl IntoDart for ZeroCopyBuffer<Vec<i64>> {
-    #[inline]
-    fn into_dart(self) -> DartAbi {
-        self.0.into_dart()
-    }
-}
-impl IntoDart for ZeroCopyBuffer<Vec<u64>> {
-    #[inline]
-    fn into_dart(self) -> DartAbi {
-        self.0.into_dart()
-    }
+macro_rules! delegate_zero_copy_big_buffers {
+    ($($ty:ty)*) => {$(
+        impl IntoDart for ZeroCopyBuffer<Vec<$ty>> {
+            #[inline]
+            fn into_dart(self) -> DartAbi {
+                let array = self.0.into_dart();
+                mark_transferable(array.unchecked_ref());
+                array
+            }
+        }
+    )*};
 }
+delegate_zero_copy_big_buffers!(i64 u64);
 
 #[derive(Clone)]
 pub struct Channel {
@@ -230,9 +287,19 @@ impl Channel {
     pub fn new(port: MessagePort) -> Self {
         Self { port }
     }
+    /// Post `msg` to Dart. The buffers of the [ZeroCopyBuffer]s in it are transferred
+    /// rather than copied, falling back to a copy if the port does not support it.
     pub fn post(&self, msg: impl IntoDart) -> bool {
-        self.port
-            .post_message(&msg.into_dart())
+        let msg = msg.into_dart();
+        let transfer = transferables(&msg);
+        let posted = if transfer.length() == 0 {
+            self.port.post_message(&msg)
+        } else {
+            self.port
+                .post_message_with_transfer(&msg, &transfer)
+                .or_else(|_| self.port.post_message(&msg))
+        };
+        posted
             .map_err(|err| {
                 crate::console_error!("post: {:?}", err);
             })
@@ -358,6 +425,14 @@ extern "C" {
     pub type PortLike;
     #[wasm_bindgen(method, catch, js_name = "postMessage")]
     pub fn post_message(this: &PortLike, value: &JsValue) -> Result<(), JsValue>;
+    /// Ports that do not support transfer, like [`BroadcastChannel`], ignore `transfer`
+    /// and copy the message instead.
+    #[wasm_bindgen(method, catch, js_name = "postMessage")]
+    pub fn post_message_with_transfer(
+        this: &PortLike,
+        value: &JsValue,
+        transfer: &JsValue,
+    ) -> Result<(), JsValue>;
     #[wasm_bindgen(method, catch)]
     pub fn close(this: &PortLike) -> Result<(), JsValue>;
 }
@@ -433,6 +508,20 @@ pub unsafe fn drop_dart_object(ptr: usize) {
     drop(support::box_from_leak_ptr::<JsValue>(ptr as _));
 }
 
+/// This function should never be called manually.
+/// It is called by Dart to identify a call it may cancel, see [crate::CancellationToken].
+#[wasm_bindgen]
+pub fn new_cancel_id() -> i32 {
+    crate::cancellation::new_cancel_id()
+}
+
+/// This function should never be called manually.
+/// It is called by Dart to cancel the call identified by `id`, see [crate::CancellationToken].
+#[wasm_bindgen]
+pub fn cancel_task(id: i32) {
+    crate::cancellation::cancel_task(id)
+}
+
 #[derive(Debug)]
 pub struct DartOpaqueBase {
     inner: Box<JsValue>,
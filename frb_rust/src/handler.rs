@@ -1,14 +1,25 @@
 //! Wrappers and executors for Rust functions.
 
 use std::any::Any;
+use std::backtrace::{Backtrace, BacktraceStatus};
+use std::cell::RefCell;
+use std::fmt;
+use std::future::Future;
 use std::panic;
 use std::panic::{RefUnwindSafe, UnwindSafe};
+#[cfg(not(wasm))]
+use std::pin::Pin;
+use std::sync::Once;
+#[cfg(not(wasm))]
+use std::task::{Context, Poll};
 
-use crate::ffi::{IntoDart, MessagePort};
+use crate::ffi::{DartAbi, IntoDart, MessagePort};
 use anyhow::Result;
 
 use crate::rust2dart::{Rust2Dart, TaskCallback};
 use crate::support::WireSyncReturn;
+#[cfg(not(wasm))]
+use crate::thread::AsyncRuntime;
 use crate::{spawn, SyncReturn};
 
 /// The types of return values for a particular Rust function.
@@ -50,6 +61,27 @@ pub trait Handler {
         TaskFn: FnOnce(TaskCallback) -> Result<TaskRet> + Send + UnwindSafe + 'static,
         TaskRet: IntoDart;
 
+    /// Same as [`wrap`][Handler::wrap], for an `async` Rust function, whose future is returned
+    /// by `TaskFn`.
+    ///
+    /// By default, the future is run to completion by the `TaskFn` given to
+    /// [`wrap`][Handler::wrap], see [block_on](crate::support::block_on).
+    fn wrap_async<PrepareFn, TaskFn, TaskFut, TaskRet>(
+        &self,
+        wrap_info: WrapInfo,
+        prepare: PrepareFn,
+    ) where
+        PrepareFn: FnOnce() -> TaskFn + UnwindSafe,
+        TaskFn: FnOnce(TaskCallback) -> TaskFut + Send + UnwindSafe + 'static,
+        TaskFut: Future<Output = Result<TaskRet>> + Send + 'static,
+        TaskRet: IntoDart,
+    {
+        self.wrap(wrap_info, move || {
+            let task = prepare();
+            move |task_callback| crate::support::block_on(task(task_callback))
+        })
+    }
+
     /// Same as [`wrap`][Handler::wrap], but the Rust function must return a [SyncReturn] and
     /// need not implement [Send].
     fn wrap_sync<SyncTaskFn, TaskRet>(
@@ -107,6 +139,7 @@ impl<E: Executor, EH: ErrorHandler> Handler for SimpleHandler<E, EH> {
         // as well. Then that new panic will go across language boundary and cause UB.
         // ref https://doc.rust-lang.org/nomicon/unwinding.html
         let _ = panic::catch_unwind(move || {
+            install_panic_backtrace_hook();
             let wrap_info2 = wrap_info.clone();
             if let Err(error) = panic::catch_unwind(move || {
                 let task = prepare();
@@ -118,6 +151,31 @@ impl<E: Executor, EH: ErrorHandler> Handler for SimpleHandler<E, EH> {
         });
     }
 
+    fn wrap_async<PrepareFn, TaskFn, TaskFut, TaskRet>(
+        &self,
+        wrap_info: WrapInfo,
+        prepare: PrepareFn,
+    ) where
+        PrepareFn: FnOnce() -> TaskFn + UnwindSafe,
+        TaskFn: FnOnce(TaskCallback) -> TaskFut + Send + UnwindSafe + 'static,
+        TaskFut: Future<Output = Result<TaskRet>> + Send + 'static,
+        TaskRet: IntoDart,
+    {
+        // NOTE This extra [catch_unwind] **SHOULD** be put outside **ALL** code!
+        // For reason, see comments in [wrap]
+        let _ = panic::catch_unwind(move || {
+            install_panic_backtrace_hook();
+            let wrap_info2 = wrap_info.clone();
+            if let Err(error) = panic::catch_unwind(move || {
+                let task = prepare();
+                self.executor.execute_async(wrap_info2, task);
+            }) {
+                self.error_handler
+                    .handle_error(wrap_info.port.unwrap(), Error::Panic(error));
+            }
+        });
+    }
+
     fn wrap_sync<SyncTaskFn, TaskRet>(
         &self,
         wrap_info: WrapInfo,
@@ -130,18 +188,17 @@ impl<E: Executor, EH: ErrorHandler> Handler for SimpleHandler<E, EH> {
         // NOTE This extra [catch_unwind] **SHOULD** be put outside **ALL** code!
         // For reason, see comments in [wrap]
         panic::catch_unwind(move || {
+            install_panic_backtrace_hook();
             let catch_unwind_result = panic::catch_unwind(move || {
                 match self.executor.execute_sync(wrap_info, sync_task) {
-                    Ok(data) => wire_sync_from_data(data.0, true),
-                    Err(err) => self
-                        .error_handler
-                        .handle_error_sync(Error::ResultError(err)),
+                    Ok(data) => wire_sync_from_data(data.0, SYNC_RETURN_SUCCESS),
+                    Err(err) => self.error_handler.handle_error_sync(err.into()),
                 }
             });
             catch_unwind_result
                 .unwrap_or_else(|error| self.error_handler.handle_error_sync(Error::Panic(error)))
         })
-        .unwrap_or_else(|_| wire_sync_from_data(None::<()>, false))
+        .unwrap_or_else(|_| wire_sync_from_data(None::<()>, SYNC_RETURN_ERROR))
     }
 }
 
@@ -157,6 +214,22 @@ pub trait Executor: RefUnwindSafe {
         TaskFn: FnOnce(TaskCallback) -> Result<TaskRet> + Send + UnwindSafe + 'static,
         TaskRet: IntoDart;
 
+    /// Same as [execute](Executor::execute), for an `async` Rust function, whose future is
+    /// returned by `task`.
+    ///
+    /// By default, the future is run to completion by the task given to
+    /// [execute](Executor::execute), see [block_on](crate::support::block_on).
+    fn execute_async<TaskFn, TaskFut, TaskRet>(&self, wrap_info: WrapInfo, task: TaskFn)
+    where
+        TaskFn: FnOnce(TaskCallback) -> TaskFut + Send + UnwindSafe + 'static,
+        TaskFut: Future<Output = Result<TaskRet>> + Send + 'static,
+        TaskRet: IntoDart,
+    {
+        self.execute(wrap_info, move |task_callback| {
+            crate::support::block_on(task(task_callback))
+        })
+    }
+
     /// Executes a Rust function that returns a [SyncReturn].
     fn execute_sync<SyncTaskFn, TaskRet>(
         &self,
@@ -180,6 +253,78 @@ impl<EH: ErrorHandler> ThreadPoolExecutor<EH> {
     pub fn new(error_handler: EH) -> Self {
         ThreadPoolExecutor { error_handler }
     }
+
+    /// Spawns the future of `task` on `runtime`, from where its output is sent to Dart once
+    /// it completes, so that no worker waits for it.
+    #[cfg(not(wasm))]
+    fn spawn_on<TaskFn, TaskFut, TaskRet>(
+        &self,
+        runtime: &dyn AsyncRuntime,
+        wrap_info: WrapInfo,
+        task: TaskFn,
+    ) where
+        TaskFn: FnOnce(TaskCallback) -> TaskFut + Send + UnwindSafe + 'static,
+        TaskFut: Future<Output = Result<TaskRet>> + Send + 'static,
+        TaskRet: IntoDart,
+    {
+        let eh = self.error_handler;
+        let WrapInfo { port, mode, .. } = wrap_info;
+        let port = port.expect("(async) runtime");
+        let rust2dart = Rust2Dart::new(port);
+        let future = task(TaskCallback::new(rust2dart.clone()));
+        runtime.spawn(Box::pin(async move {
+            match CatchUnwind(Box::pin(future)).await {
+                Ok(ret) => send_output(eh, port, &rust2dart, mode, ret.map(IntoDart::into_dart)),
+                Err(error) => eh.handle_error(port, Error::Panic(error)),
+            }
+        }));
+    }
+}
+
+/// Sends what a task returned to Dart, according to the [FfiCallMode] of its function.
+fn send_output<EH: ErrorHandler>(
+    error_handler: EH,
+    port: MessagePort,
+    rust2dart: &Rust2Dart,
+    mode: FfiCallMode,
+    output: Result<DartAbi>,
+) {
+    match output {
+        Ok(result) => {
+            match mode {
+                FfiCallMode::Normal => {
+                    rust2dart.success(result);
+                }
+                FfiCallMode::Stream => {
+                    // nothing - ignore the return value of a Stream-typed function
+                }
+                FfiCallMode::Sync => {
+                    panic!("FfiCallMode::Sync should not call execute, please call execute_sync instead")
+                }
+            }
+        }
+        Err(error) => {
+            error_handler.handle_error(port, error.into());
+        }
+    };
+}
+
+/// Catches the panics of a future while it is polled, like [panic::catch_unwind] does for a
+/// closure.
+#[cfg(not(wasm))]
+struct CatchUnwind<F>(Pin<Box<F>>);
+
+#[cfg(not(wasm))]
+impl<F: Future> Future for CatchUnwind<F> {
+    type Output = std::thread::Result<F::Output>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let future = self.0.as_mut();
+        match panic::catch_unwind(panic::AssertUnwindSafe(|| future.poll(cx))) {
+            Ok(poll) => poll.map(Ok),
+            Err(error) => Poll::Ready(Err(error)),
+        }
+    }
 }
 
 impl<EH: ErrorHandler> Executor for ThreadPoolExecutor<EH> {
@@ -202,24 +347,7 @@ impl<EH: ErrorHandler> Executor for ThreadPoolExecutor<EH> {
 
                 let ret = task(TaskCallback::new(rust2dart.clone())).map(IntoDart::into_dart);
 
-                match ret {
-                    Ok(result) => {
-                        match mode {
-                            FfiCallMode::Normal => {
-                                rust2dart.success(result);
-                            }
-                            FfiCallMode::Stream => {
-                                // nothing - ignore the return value of a Stream-typed function
-                            }
-                            FfiCallMode::Sync => {
-                                panic!("FfiCallMode::Sync should not call execute, please call execute_sync instead")
-                            }
-                        }
-                    }
-                    Err(error) => {
-                        eh2.handle_error(port2, Error::ResultError(error));
-                    }
-                };
+                send_output(eh2, port2, &rust2dart, mode, ret);
             });
 
             if let Err(error) = thread_result {
@@ -228,6 +356,23 @@ impl<EH: ErrorHandler> Executor for ThreadPoolExecutor<EH> {
         });
     }
 
+    /// Spawns the future on the runtime given to [set_async_runtime](crate::set_async_runtime)
+    /// if any, or else polls it on a worker, which is parked whenever it is pending.
+    #[cfg(not(wasm))]
+    fn execute_async<TaskFn, TaskFut, TaskRet>(&self, wrap_info: WrapInfo, task: TaskFn)
+    where
+        TaskFn: FnOnce(TaskCallback) -> TaskFut + Send + UnwindSafe + 'static,
+        TaskFut: Future<Output = Result<TaskRet>> + Send + 'static,
+        TaskRet: IntoDart,
+    {
+        match crate::thread::async_runtime() {
+            Some(runtime) => self.spawn_on(&*runtime, wrap_info, task),
+            None => self.execute(wrap_info, move |task_callback| {
+                crate::support::park_on(task(task_callback))
+            }),
+        }
+    }
+
     fn execute_sync<SyncTaskFn, TaskRet>(
         &self,
         _wrap_info: WrapInfo,
@@ -248,6 +393,17 @@ pub enum Error {
     ResultError(anyhow::Error),
     /// Exceptional errors from panicking.
     Panic(Box<dyn Any + Send>),
+    /// Errors of a user-defined type, see [CustomError].
+    CustomError(CustomError),
+}
+
+impl From<anyhow::Error> for Error {
+    fn from(error: anyhow::Error) -> Self {
+        match error.downcast::<CustomError>() {
+            Ok(custom) => Error::CustomError(custom),
+            Err(error) => Error::ResultError(error),
+        }
+    }
 }
 
 impl Error {
@@ -256,6 +412,7 @@ impl Error {
         match self {
             Error::ResultError(_) => "RESULT_ERROR",
             Error::Panic(_) => "PANIC_ERROR",
+            Error::CustomError(_) => "CUSTOM_ERROR",
         }
     }
 
@@ -271,10 +428,92 @@ impl Error {
                 },
             }
             .to_string(),
+            Error::CustomError(e) => e.to_string(),
         }
     }
 }
 
+thread_local! {
+    static PANIC_BACKTRACE: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+/// Chains a panic hook recording the backtrace of each panic, so that it can be sent to Dart
+/// along with the message. As usual, it is only captured if `RUST_BACKTRACE` or
+/// `RUST_LIB_BACKTRACE` is set.
+///
+/// The hook runs on the panicking thread, before unwinding, which is also the thread that
+/// catches the panic, be it the calling thread or a worker.
+fn install_panic_backtrace_hook() {
+    static INSTALL: Once = Once::new();
+    INSTALL.call_once(|| {
+        let previous = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            let backtrace = Backtrace::capture();
+            let backtrace =
+                (backtrace.status() == BacktraceStatus::Captured).then(|| backtrace.to_string());
+            PANIC_BACKTRACE.with(|it| *it.borrow_mut() = backtrace);
+            previous(info);
+        }));
+    });
+}
+
+/// Takes the backtrace of the last panic of the current thread, if one was captured.
+///
+/// Meant for [ErrorHandler]s handling an [Error::Panic], which are called on the thread
+/// that caught the panic.
+pub fn take_panic_backtrace() -> Option<String> {
+    PANIC_BACKTRACE.with(|it| it.borrow_mut().take())
+}
+
+/// An error of a user-defined type, which is sent to Dart as structured data
+/// instead of being flattened into a message.
+///
+/// It is carried inside an [anyhow::Error], so that [Executor]s keep their signatures,
+/// and is recovered by the conversion into [Error].
+pub struct CustomError {
+    data: Box<dyn BoxIntoDart + Send + Sync>,
+    type_name: &'static str,
+}
+
+impl CustomError {
+    pub fn new<T: IntoDart + Send + Sync + 'static>(data: T) -> Self {
+        Self {
+            data: Box::new(data),
+            type_name: std::any::type_name::<T>(),
+        }
+    }
+
+    pub fn into_dart(self) -> DartAbi {
+        self.data.box_into_dart()
+    }
+}
+
+impl fmt::Debug for CustomError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CustomError")
+            .field("type_name", &self.type_name)
+            .finish_non_exhaustive()
+    }
+}
+
+impl fmt::Display for CustomError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "custom error of type {}", self.type_name)
+    }
+}
+
+impl std::error::Error for CustomError {}
+
+trait BoxIntoDart {
+    fn box_into_dart(self: Box<Self>) -> DartAbi;
+}
+
+impl<T: IntoDart> BoxIntoDart for T {
+    fn box_into_dart(self: Box<Self>) -> DartAbi {
+        (*self).into_dart()
+    }
+}
+
 /// A handler model that sends back the error to a Dart `SendPort`.
 ///
 /// For example, instead of using the default [`ReportDartErrorHandler`],
@@ -294,16 +533,42 @@ pub struct ReportDartErrorHandler;
 
 impl ErrorHandler for ReportDartErrorHandler {
     fn handle_error(&self, port: MessagePort, error: Error) {
-        Rust2Dart::new(port).error(error.code().to_string(), error.message());
+        match error {
+            Error::CustomError(e) => Rust2Dart::new(port).custom_error(e.into_dart()),
+            error @ Error::Panic(_) => Rust2Dart::new(port).error_full(
+                error.code().to_string(),
+                error.message(),
+                take_panic_backtrace(),
+            ),
+            error => Rust2Dart::new(port).error(error.code().to_string(), error.message()),
+        };
     }
 
     fn handle_error_sync(&self, error: Error) -> WireSyncReturn {
-        wire_sync_from_data(format!("{}: {}", error.code(), error.message()), false)
+        match error {
+            Error::CustomError(e) => wire_sync_from_data(e.into_dart(), SYNC_RETURN_CUSTOM_ERROR),
+            error @ Error::Panic(_) => wire_sync_from_data(
+                vec![
+                    error.message().into_dart(),
+                    take_panic_backtrace().into_dart(),
+                ],
+                SYNC_RETURN_PANIC,
+            ),
+            error => wire_sync_from_data(
+                format!("{}: {}", error.code(), error.message()),
+                SYNC_RETURN_ERROR,
+            ),
+        }
     }
 }
 
-fn wire_sync_from_data<T: IntoDart>(data: T, success: bool) -> WireSyncReturn {
-    let sync_return = vec![data.into_dart(), success.into_dart()].into_dart();
+const SYNC_RETURN_SUCCESS: i32 = 0;
+const SYNC_RETURN_ERROR: i32 = 1;
+const SYNC_RETURN_CUSTOM_ERROR: i32 = 2;
+const SYNC_RETURN_PANIC: i32 = 3;
+
+fn wire_sync_from_data<T: IntoDart>(data: T, kind: i32) -> WireSyncReturn {
+    let sync_return = vec![data.into_dart(), kind.into_dart()].into_dart();
 
     #[cfg(not(wasm))]
     return crate::support::new_leak_box_ptr(sync_return);
@@ -311,3 +576,61 @@ fn wire_sync_from_data<T: IntoDart>(data: T, success: bool) -> WireSyncReturn {
     #[cfg(wasm)]
     return sync_return;
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn custom_error_survives_anyhow() {
+        let error: Error = crate::support::custom_error(42).into();
+        assert!(matches!(error, Error::CustomError(_)));
+        assert_eq!(error.code(), "CUSTOM_ERROR");
+
+        let error: Error = anyhow::anyhow!("oops").into();
+        assert!(matches!(error, Error::ResultError(_)));
+    }
+
+    #[test]
+    fn string_errors_are_not_taken_for_values() {
+        // An `Err` of `Result<String, String>` is told apart by its kind, never by its content.
+        let error: Error = crate::support::custom_error("SUCCESS: 42".to_owned()).into();
+        assert!(matches!(error, Error::CustomError(_)));
+        assert_eq!(error.code(), "CUSTOM_ERROR");
+    }
+
+    #[cfg(not(wasm))]
+    #[test]
+    fn async_tasks_are_spawned_on_the_runtime() {
+        struct ThreadRuntime;
+        impl AsyncRuntime for ThreadRuntime {
+            fn spawn(&self, future: Pin<Box<dyn Future<Output = ()> + Send + 'static>>) {
+                std::thread::Builder::new()
+                    .name("runtime".to_owned())
+                    .spawn(move || crate::support::park_on(future))
+                    .unwrap();
+            }
+
+            fn block_on(&self, future: Pin<Box<dyn Future<Output = ()> + '_>>) {
+                crate::support::park_on(future)
+            }
+        }
+
+        let (tx, rx) = std::sync::mpsc::channel();
+        let wrap_info = WrapInfo {
+            port: Some(0),
+            debug_name: "spawned",
+            mode: FfiCallMode::Normal,
+        };
+        ThreadPoolExecutor::new(ReportDartErrorHandler).spawn_on(
+            &ThreadRuntime,
+            wrap_info,
+            move |_| async move {
+                let thread = std::thread::current();
+                tx.send(thread.name().map(ToOwned::to_owned)).unwrap();
+                Ok(())
+            },
+        );
+        assert_eq!(rx.recv().unwrap().as_deref(), Some("runtime"));
+    }
+}
@@ -0,0 +1,82 @@
+//! The Rust side of the Dart streams pulling the items of a returned `impl Iterator`.
+
+use std::sync::{Mutex, PoisonError};
+
+use crate::DartSafe;
+
+/// The iterator returned by a function declared as `-> impl Iterator<Item = T>`, kept behind
+/// an opaque handle while Dart pulls its items one at a time.
+///
+/// The iterator is dropped once it is exhausted, or else together with the handle, which
+/// Dart disposes when the subscription to the stream is cancelled.
+pub struct DartIterator<T> {
+    // Declared first, so that it is dropped before the arguments it may borrow from.
+    iter: Mutex<Option<Box<dyn Iterator<Item = T> + Send>>>,
+    _owner: Option<Box<dyn DartSafe + Send + Sync>>,
+}
+
+impl<T> DartIterator<T> {
+    /// Keep an iterator which borrows nothing.
+    pub fn new(iter: impl Iterator<Item = T> + Send + 'static) -> Self {
+        Self {
+            iter: Mutex::new(Some(Box::new(iter))),
+            _owner: None,
+        }
+    }
+
+    /// Keep the iterator returned by `iter` together with `owner`, the arguments it borrows
+    /// from, such as the `RustOpaque` handle of the `&self` of a method.
+    pub fn borrowing<O: DartSafe + Send + Sync + 'static>(
+        owner: O,
+        iter: impl for<'a> FnOnce(&'a O) -> Box<dyn Iterator<Item = T> + Send + 'a>,
+    ) -> Self {
+        let owner = Box::new(owner);
+        let borrowed = iter(&*owner);
+        // SAFETY: The iterator only borrows from the heap allocation of `owner`, which does not
+        // move when the box does, and is dropped after the iterator, see the field order.
+        let iter = unsafe {
+            std::mem::transmute::<
+                Box<dyn Iterator<Item = T> + Send + '_>,
+                Box<dyn Iterator<Item = T> + Send + 'static>,
+            >(borrowed)
+        };
+        Self {
+            iter: Mutex::new(Some(iter)),
+            _owner: Some(owner),
+        }
+    }
+
+    /// Advance the iterator, which is dropped as soon as it returns `None`.
+    pub fn next_item(&self) -> Option<T> {
+        let mut iter = self.iter.lock().unwrap_or_else(PoisonError::into_inner);
+        let item = iter.as_mut()?.next();
+        if item.is_none() {
+            *iter = None;
+        }
+        item
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pulls_items_until_exhausted() {
+        let iter = DartIterator::new(vec![1, 2].into_iter());
+        assert_eq!(iter.next_item(), Some(1));
+        assert_eq!(iter.next_item(), Some(2));
+        assert_eq!(iter.next_item(), None);
+        assert_eq!(iter.next_item(), None);
+    }
+
+    #[test]
+    fn borrowed_items_outlive_the_call() {
+        let iter = DartIterator::borrowing((vec!["a".to_owned(), "b".to_owned()],), |(lines,)| {
+            Box::new(lines.iter().map(|line| line.to_uppercase()))
+        });
+        assert_eq!(iter.next_item().as_deref(), Some("A"));
+        assert_eq!(iter.next_item().as_deref(), Some("B"));
+        assert_eq!(iter.next_item(), None);
+    }
+}
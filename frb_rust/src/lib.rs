@@ -1,21 +1,59 @@
-use std::panic::{RefUnwindSafe, UnwindSafe};
+//! Without the default `std` feature, only the [wire] conversions are built, which need `alloc`
+//! and no `std`.
+#![cfg_attr(not(feature = "std"), no_std)]
 
+extern crate alloc;
+
+use core::panic::{RefUnwindSafe, UnwindSafe};
+
+#[cfg(feature = "std")]
+pub use cancellation::CancellationToken;
 pub use flutter_rust_bridge_macros::frb;
+#[cfg(feature = "std")]
 pub use handler::{FfiCallMode, Handler, WrapInfo};
+#[cfg(feature = "std")]
+pub use iterator::DartIterator;
+#[cfg(feature = "std")]
 pub use rust2dart::StreamSink;
+#[cfg(feature = "std")]
+pub use shared_buffer::SharedBuffer;
+#[cfg(feature = "std")]
+pub use stream_registry::StreamRegistry;
+#[cfg(feature = "std")]
+pub use thread::{set_async_runtime, AsyncRuntime};
 
+#[cfg(feature = "std")]
+pub mod cancellation;
+#[cfg(feature = "std")]
+mod changes;
+#[cfg(feature = "std")]
 pub mod ffi;
+#[cfg(feature = "std")]
 pub use ffi::*;
 
+#[cfg(feature = "std")]
 pub mod thread;
 
+#[cfg(feature = "std")]
 pub mod handler;
+#[cfg(feature = "std")]
+pub mod iterator;
+#[cfg(feature = "std")]
 #[macro_use]
 mod macros;
+#[cfg(feature = "std")]
 pub mod rust2dart;
+#[cfg(feature = "std")]
+pub mod shared_buffer;
+#[cfg(feature = "std")]
+pub mod stream_registry;
+#[cfg(feature = "std")]
 pub mod support;
+pub mod wire;
+#[cfg(feature = "std")]
+pub mod wire_size;
 
-#[cfg(wasm)]
+#[cfg(all(wasm, feature = "std"))]
 mod wasm_bindgen_src;
 
 /// Use this struct in return type of your function, in order to tell the code generator
@@ -1,6 +1,7 @@
 //! Manages receiving and sending values across the FFI boundary.
 
 use std::marker::PhantomData;
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
 
 /// The representation of a Dart object outside of the Dart heap.
 ///
@@ -17,6 +18,8 @@ pub struct Rust2Dart {
 const RUST2DART_ACTION_SUCCESS: i32 = 0;
 const RUST2DART_ACTION_ERROR: i32 = 1;
 const RUST2DART_ACTION_CLOSE_STREAM: i32 = 2;
+const RUST2DART_ACTION_CUSTOM_ERROR: i32 = 3;
+const RUST2DART_ACTION_STREAM_INITIAL: i32 = 4;
 
 // api signatures is similar to Flutter Android's callback https://api.flutter.dev/javadoc/io/flutter/plugin/common/MethodChannel.Result.html
 impl Rust2Dart {
@@ -29,10 +32,7 @@ impl Rust2Dart {
 
     /// Send a success message back to the specified port.
     pub fn success(&self, result: impl IntoDart) -> bool {
-        self.channel.post(vec![
-            RUST2DART_ACTION_SUCCESS.into_dart(),
-            result.into_dart(),
-        ])
+        self.channel.post(success_message(result))
     }
 
     /// Send an error back to the specified port.
@@ -55,13 +55,39 @@ impl Rust2Dart {
         ])
     }
 
+    /// Send an error of a user-defined type back to the specified port,
+    /// which Dart decodes into the corresponding Dart type.
+    pub fn custom_error(&self, error: impl IntoDart) -> bool {
+        self.channel.post(custom_error_message(error))
+    }
+
+    /// Send the value returned by a function along with its stream, which Dart
+    /// delivers apart from the items.
+    pub fn stream_initial(&self, value: impl IntoDart) -> bool {
+        self.channel.post(vec![
+            RUST2DART_ACTION_STREAM_INITIAL.into_dart(),
+            value.into_dart(),
+        ])
+    }
+
     /// Close the stream and ignore further messages.
     pub fn close_stream(&self) -> bool {
-        self.channel
-            .post(vec![RUST2DART_ACTION_CLOSE_STREAM.into_dart()])
+        self.channel.post(close_stream_message())
     }
 }
 
+fn success_message(result: impl IntoDart) -> Vec<DartAbi> {
+    vec![RUST2DART_ACTION_SUCCESS.into_dart(), result.into_dart()]
+}
+
+fn custom_error_message(error: impl IntoDart) -> Vec<DartAbi> {
+    vec![RUST2DART_ACTION_CUSTOM_ERROR.into_dart(), error.into_dart()]
+}
+
+fn close_stream_message() -> Vec<DartAbi> {
+    vec![RUST2DART_ACTION_CLOSE_STREAM.into_dart()]
+}
+
 /// A callback that receives the return value of Rust functions.
 pub struct TaskCallback {
     rust2dart: Rust2Dart,
@@ -74,9 +100,15 @@ impl TaskCallback {
     }
 
     /// Create a new [StreamSink] of the specified type.
-    pub fn stream_sink<T: IntoDart>(&self) -> StreamSink<T> {
+    pub fn stream_sink<T>(&self) -> StreamSink<T> {
         StreamSink::new(self.rust2dart.clone())
     }
+
+    /// Send the value returned by the function of a [StreamSink], see
+    /// [Rust2Dart::stream_initial].
+    pub fn stream_initial(&self, value: impl IntoDart) -> bool {
+        self.rust2dart.stream_initial(value)
+    }
 }
 
 /// A handle to a [`web_sys::BroadcastChannel`].
@@ -93,16 +125,111 @@ impl ChannelHandle {
 /// A sink to send asynchronous data back to Dart.
 /// Represented as a Dart
 /// [`Stream`](https://api.dart.dev/stable/dart-async/Stream-class.html).
-#[derive(Clone)]
-pub struct StreamSink<T: IntoDart> {
+///
+/// The Dart stream is closed by [StreamSink::close], or at the latest when the
+/// last clone of the sink is dropped.
+///
+/// For a `StreamSink<Result<T, E>>`, `Ok` values become data events and `Err` values
+/// become error events of the Dart stream, see [StreamSink::add_result].
+///
+/// The Dart listener receives the items in the order of the calls adding them, even when
+/// clones of the sink are used by several threads at once: each call encodes and posts its
+/// item before the next one starts. The events are delivered to the isolate which called
+/// the function taking the sink.
+pub struct StreamSink<T> {
+    inner: Arc<StreamSinkInner>,
+    _phantom_data: PhantomData<T>,
+}
+
+impl<T> Clone for StreamSink<T> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+            _phantom_data: PhantomData,
+        }
+    }
+}
+
+/// The state shared by all clones of a [StreamSink].
+struct StreamSinkInner {
+    target: SinkTarget,
+    /// Whether the stream is closed. It is locked while sending each message, so that the
+    /// messages are sent one at a time and none follows the close message.
+    closed: Mutex<bool>,
+}
+
+/// Where the messages of a [StreamSink] go.
+enum SinkTarget {
     #[cfg(not(wasm))]
-    rust2dart: Rust2Dart,
+    Port(Rust2Dart),
     #[cfg(wasm)]
-    handle: ChannelHandle,
-    _phantom_data: PhantomData<T>,
+    Port(ChannelHandle),
+    /// The messages of a [buffered](StreamSink::buffered) sink, kept until it is drained.
+    #[cfg(not(wasm))]
+    Buffer(Mutex<Buffer>),
+    /// Draining is not supported on the web, where the messages are dropped.
+    #[cfg(wasm)]
+    Buffer,
 }
 
-impl<T: IntoDart> StreamSink<T> {
+#[cfg(not(wasm))]
+#[derive(Default)]
+struct Buffer(Vec<DartAbi>);
+
+// SAFETY: The messages own their data, like when they are posted to Dart from any thread,
+// and they are only accessed behind the mutex.
+#[cfg(not(wasm))]
+unsafe impl Send for Buffer {}
+
+impl SinkTarget {
+    fn post(&self, message: Vec<DartAbi>) -> bool {
+        match self {
+            #[cfg(not(wasm))]
+            Self::Port(rust2dart) => rust2dart.channel.post(message),
+            #[cfg(wasm)]
+            Self::Port(handle) => Rust2Dart::new(handle.port()).channel.post(message),
+            #[cfg(not(wasm))]
+            Self::Buffer(buffer) => {
+                let mut buffer = buffer.lock().unwrap_or_else(PoisonError::into_inner);
+                buffer.0.push(message.into_dart());
+                true
+            }
+            #[cfg(wasm)]
+            Self::Buffer => false,
+        }
+    }
+}
+
+impl StreamSinkInner {
+    fn lock_closed(&self) -> MutexGuard<'_, bool> {
+        // A panic while encoding an item leaves the flag itself consistent.
+        self.closed.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Send a message, unless the stream is closed.
+    fn send(&self, message: impl FnOnce() -> Vec<DartAbi>) -> bool {
+        let closed = self.lock_closed();
+        !*closed && self.target.post(message())
+    }
+
+    fn close(&self) -> bool {
+        let mut closed = self.lock_closed();
+        // Only the first caller, on whichever thread, gets to send the close message.
+        if *closed {
+            return false;
+        }
+        *closed = true;
+        self.target.post(close_stream_message())
+    }
+}
+
+impl Drop for StreamSinkInner {
+    fn drop(&mut self) {
+        self.close();
+    }
+}
+
+impl<T> StreamSink<T> {
     /// Create a new sink from a port wrapper.
     pub fn new(rust2dart: Rust2Dart) -> Self {
         #[cfg(wasm)]
@@ -110,32 +237,101 @@ impl<T: IntoDart> StreamSink<T> {
             .channel
             .broadcast_name()
             .expect("Not a BroadcastChannel");
+        #[cfg(not(wasm))]
+        let target = SinkTarget::Port(rust2dart);
+        #[cfg(wasm)]
+        let target = SinkTarget::Port(ChannelHandle(name));
+        Self::with_target(target)
+    }
+
+    /// Create a sink keeping its messages until [drained](StreamSink::drain) instead of
+    /// sending them to Dart, for the `...Drain` functions generated by `--drain-streams`.
+    pub fn buffered() -> Self {
+        #[cfg(not(wasm))]
+        return Self::with_target(SinkTarget::Buffer(Mutex::default()));
+
+        #[cfg(wasm)]
+        Self::with_target(SinkTarget::Buffer)
+    }
+
+    fn with_target(target: SinkTarget) -> Self {
         Self {
-            #[cfg(not(wasm))]
-            rust2dart,
-            #[cfg(wasm)]
-            handle: ChannelHandle(name),
+            inner: Arc::new(StreamSinkInner {
+                target,
+                closed: Mutex::new(false),
+            }),
             _phantom_data: PhantomData,
         }
     }
 
-    fn rust2dart(&self) -> Rust2Dart {
-        #[cfg(not(wasm))]
-        return self.rust2dart.clone();
+    /// Close a [buffered](StreamSink::buffered) sink, and take its messages for Dart to decode
+    /// in one go.
+    ///
+    /// Fails if the stream is neither closed nor dropped by all the other clones of the sink, as
+    /// for a sink kept by another thread or a global: such a stream may be unbounded, so it is
+    /// reported instead of being waited for.
+    pub fn drain(self) -> anyhow::Result<Vec<DartAbi>> {
+        if !*self.inner.lock_closed() && Arc::strong_count(&self.inner) > 1 {
+            anyhow::bail!(
+                "The stream is still open after its function returned: only bounded streams, \
+                closed or dropped by then, can be drained"
+            );
+        }
+        self.inner.close();
+        match &self.inner.target {
+            #[cfg(not(wasm))]
+            SinkTarget::Buffer(buffer) => {
+                let mut buffer = buffer.lock().unwrap_or_else(PoisonError::into_inner);
+                Ok(std::mem::take(&mut buffer.0))
+            }
+            #[cfg(wasm)]
+            SinkTarget::Buffer => anyhow::bail!("Draining streams is not supported on the web"),
+            SinkTarget::Port(_) => anyhow::bail!("Only buffered sinks can be drained"),
+        }
+    }
 
-        #[cfg(wasm)]
-        Rust2Dart::new(self.handle.port())
+    /// Close the stream and ignore further messages. Returns false when
+    /// the stream could not be closed, or when it has already been closed.
+    ///
+    /// It is safe to call this from any thread, and from several clones of the sink:
+    /// the Dart stream receives exactly one done event.
+    pub fn close(&self) -> bool {
+        self.inner.close()
     }
+}
 
+impl<T: IntoDart> StreamSink<T> {
     /// Add data to the stream. Returns false when data could not be sent,
     /// or the stream has been closed.
     pub fn add(&self, value: T) -> bool {
-        self.rust2dart().success(value)
+        self.inner.send(|| success_message(value))
     }
+}
 
-    /// Close the stream and ignore further messages. Returns false when
-    /// the stream could not be closed, or when it has already been closed.
-    pub fn close(&self) -> bool {
-        self.rust2dart().close_stream()
+impl<T: IntoDart, E: IntoDart> StreamSink<Result<T, E>> {
+    /// Add an item to the stream: `Ok` values become data events and `Err` values become
+    /// error events, leaving the stream open for subsequent items.
+    /// Returns false when the item could not be sent, or the stream has been closed.
+    pub fn add_result(&self, result: Result<T, E>) -> bool {
+        match result {
+            Ok(value) => self.add_ok(value),
+            Err(error) => self.add_error(error),
+        }
+    }
+
+    /// Add a data event to the stream, same as `add_result(Ok(value))`.
+    pub fn add_ok(&self, value: T) -> bool {
+        self.inner.send(|| success_message(value))
+    }
+
+    /// Add a recoverable error event to the stream, same as `add_result(Err(error))`.
+    pub fn add_error(&self, error: E) -> bool {
+        self.inner.send(|| custom_error_message(error))
+    }
+
+    /// Add a fatal error event to the stream, then close it.
+    /// Returns false when the stream has already been closed.
+    pub fn close_with_error(&self, error: E) -> bool {
+        self.add_error(error) && self.close()
     }
 }
@@ -0,0 +1,92 @@
+//! Read-only bytes filled once by Dart, and then lent to any number of Rust calls.
+
+use std::collections::HashMap;
+use std::ops::Deref;
+use std::sync::{Arc, Mutex};
+
+lazy_static::lazy_static! {
+    /// The buffers whose Dart handle is not disposed yet, by the address of their bytes.
+    static ref BUFFERS: Mutex<HashMap<usize, Arc<Vec<u8>>>> = Default::default();
+}
+
+/// Use this type as an argument of your function to receive bytes that Dart registered once,
+/// instead of copying them at each call.
+///
+/// The Dart `SharedBuffer` handle owns the memory, which Rust allocates and Dart fills before
+/// it is ever passed to a function. Afterwards, Dart can only read it, so it never changes while
+/// Rust uses it. Each argument shares the bytes with the handle, and keeps them alive even if
+/// the handle is disposed in the meantime.
+#[derive(Clone, Debug)]
+pub struct SharedBuffer {
+    bytes: Arc<Vec<u8>>,
+}
+
+impl SharedBuffer {
+    /// Share the buffer registered at `address` by [new_shared_buffer].
+    #[doc(hidden)]
+    pub fn from_wire(address: usize) -> Self {
+        let bytes = BUFFERS.lock().unwrap().get(&address).cloned();
+        Self {
+            bytes: bytes.expect("SharedBuffer used after it was disposed"),
+        }
+    }
+}
+
+impl Deref for SharedBuffer {
+    type Target = [u8];
+
+    fn deref(&self) -> &[u8] {
+        &self.bytes
+    }
+}
+
+impl AsRef<[u8]> for SharedBuffer {
+    fn as_ref(&self) -> &[u8] {
+        &self.bytes
+    }
+}
+
+/// Allocate a zeroed buffer of `len` bytes for Dart to fill, and return its address.
+// Dart cannot write into the memory of a WASM module, so the web has no shared buffers.
+#[cfg_attr(wasm, allow(dead_code))]
+pub(crate) fn new_shared_buffer(len: usize) -> usize {
+    // At least one byte is allocated, so that each buffer has its own address.
+    let mut bytes = vec![0; len.max(1)];
+    bytes.truncate(len);
+    // Dart writes through this address, so it is taken from a unique borrow.
+    let address = bytes.as_mut_ptr() as usize;
+    BUFFERS.lock().unwrap().insert(address, Arc::new(bytes));
+    address
+}
+
+/// Release the Dart handle of the buffer at `address`. The bytes are freed once the
+/// arguments sharing them are dropped as well.
+#[cfg_attr(wasm, allow(dead_code))]
+pub(crate) fn drop_shared_buffer(address: usize) {
+    BUFFERS.lock().unwrap().remove(&address);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn arguments_outlive_the_handle() {
+        let address = new_shared_buffer(3);
+        let buffer = SharedBuffer::from_wire(address);
+        assert_eq!(&*buffer, &[0, 0, 0]);
+        drop_shared_buffer(address);
+        assert_eq!(buffer.len(), 3);
+        assert!(std::panic::catch_unwind(|| SharedBuffer::from_wire(address)).is_err());
+    }
+
+    #[test]
+    fn empty_buffers_are_distinct() {
+        let a = new_shared_buffer(0);
+        let b = new_shared_buffer(0);
+        assert_ne!(a, b);
+        assert!(SharedBuffer::from_wire(a).is_empty());
+        drop_shared_buffer(a);
+        drop_shared_buffer(b);
+    }
+}
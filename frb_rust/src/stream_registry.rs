@@ -0,0 +1,121 @@
+//! The Rust side of the Dart streams subscribed to per key, such as one per entity id.
+
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::sync::{Mutex, MutexGuard, PoisonError};
+
+use crate::{IntoDart, StreamSink};
+
+/// The streams of a Rust object, subscribed to by Dart for a given key, which are created on
+/// demand and receive the items added for their key.
+///
+/// Keep it in an opaque type, and take a sink in a method to let Dart subscribe:
+///
+/// ```rust,ignore
+/// pub struct Entities {
+///     updates: StreamRegistry<String, Update>,
+/// }
+///
+/// impl Entities {
+///     pub fn subscribe(&self, key: String, sink: StreamSink<Update>) {
+///         self.updates.subscribe(key, sink);
+///     }
+/// }
+/// ```
+///
+/// Each subscription to the same key gets its own stream, and every one of them receives the
+/// items. The streams are closed when the registry is dropped, e.g. together with the object
+/// once Dart disposes its last handle.
+pub struct StreamRegistry<K, T> {
+    sinks: Mutex<HashMap<K, Vec<StreamSink<T>>>>,
+}
+
+impl<K, T> Default for StreamRegistry<K, T> {
+    fn default() -> Self {
+        Self {
+            sinks: Mutex::new(HashMap::new()),
+        }
+    }
+}
+
+impl<K: Eq + Hash, T> StreamRegistry<K, T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn lock(&self) -> MutexGuard<'_, HashMap<K, Vec<StreamSink<T>>>> {
+        // The map of sinks stays consistent even if a thread panicked while sending.
+        self.sinks.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Add the stream of `sink` to the ones of `key`.
+    pub fn subscribe(&self, key: K, sink: StreamSink<T>) {
+        self.lock().entry(key).or_default().push(sink);
+    }
+
+    /// Close the streams of `key`. Returns the number of streams closed.
+    pub fn close(&self, key: &K) -> usize {
+        let sinks = self.lock().remove(key).unwrap_or_default();
+        sinks.iter().filter(|sink| sink.close()).count()
+    }
+
+    /// The number of streams of `key`, including the ones whose Dart subscription may have
+    /// been cancelled since the last item was added.
+    pub fn subscriber_count(&self, key: &K) -> usize {
+        self.lock().get(key).map_or(0, Vec::len)
+    }
+}
+
+impl<K: Eq + Hash, T: IntoDart + Clone> StreamRegistry<K, T> {
+    /// Send `item` to every stream of `key`, and forget the ones which are gone. Returns the
+    /// number of streams which received it.
+    pub fn add(&self, key: &K, item: T) -> usize {
+        let mut sinks = self.lock();
+        let subscribers = match sinks.get_mut(key) {
+            Some(subscribers) => subscribers,
+            None => return 0,
+        };
+        subscribers.retain(|sink| sink.add(item.clone()));
+        let count = subscribers.len();
+        if count == 0 {
+            sinks.remove(key);
+        }
+        count
+    }
+}
+
+#[cfg(all(test, not(wasm)))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn items_fan_out_to_the_streams_of_their_key() {
+        let registry = StreamRegistry::new();
+        let (a1, a2, b) = (
+            StreamSink::buffered(),
+            StreamSink::buffered(),
+            StreamSink::buffered(),
+        );
+        registry.subscribe("a", a1.clone());
+        registry.subscribe("a", a2.clone());
+        registry.subscribe("b", b.clone());
+        assert_eq!(registry.add(&"a", 1), 2);
+        assert_eq!(registry.add(&"c", 2), 0);
+        drop(registry);
+        // Each stream holds its items, followed by the close message.
+        assert_eq!(a1.drain().unwrap().len(), 2);
+        assert_eq!(a2.drain().unwrap().len(), 2);
+        assert_eq!(b.drain().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn closed_streams_are_forgotten() {
+        let registry = StreamRegistry::new();
+        let sink = StreamSink::buffered();
+        registry.subscribe(1, sink.clone());
+        assert_eq!(registry.close(&1), 1);
+        assert_eq!(registry.subscriber_count(&1), 0);
+        assert_eq!(registry.add(&1, 1), 0);
+        assert_eq!(sink.drain().unwrap().len(), 1);
+    }
+}
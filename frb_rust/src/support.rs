@@ -2,67 +2,118 @@
 //! These functions are *not* meant to be used by humans directly.
 #![doc(hidden)]
 
-use std::mem;
-
 pub use crate::ffi::*;
 pub use lazy_static::lazy_static;
 
+pub use crate::changes::{listen_changes, notify_changes};
+use crate::handler::CustomError;
 pub use crate::handler::DefaultHandler;
+use crate::rust2dart::{Rust2Dart, StreamSink};
+pub use crate::thread::with_worker_pool;
+pub use crate::wire::*;
+pub use crate::wire_size::{record_wire_size, wire_size_enabled, WireDirection};
 
-// ref https://stackoverflow.com/questions/39224904/how-to-expose-a-rust-vect-to-ffi
-pub fn new_leak_vec_ptr<T: Clone>(fill: T, length: i32) -> *mut T {
-    into_leak_vec_ptr(vec![fill; length as usize]).0
+/// Wrap an error returned by a user function, so that it reaches Dart as structured data.
+pub fn custom_error<T: IntoDart + Send + Sync + 'static>(error: T) -> anyhow::Error {
+    CustomError::new(error).into()
 }
 
-pub fn into_leak_vec_ptr<T: Clone>(mut v: Vec<T>) -> (*mut T, i32) {
-    v.shrink_to_fit();
-    assert!(v.len() == v.capacity());
-    let ptr = v.as_mut_ptr();
-    let len = v.len() as i32;
-    mem::forget(v);
-    (ptr, len)
+/// The error of the generated `dispatch` function when no `#[frb(dispatchable)]` function is
+/// identified by `id`.
+pub fn unknown_dispatch_id(id: &str, known: &[&str]) -> anyhow::Error {
+    anyhow::anyhow!(
+        "Unknown dispatchable function `{}`, expected one of: {}",
+        id,
+        known.join(", ")
+    )
 }
 
-/// # Safety
-/// Use it in pair with [new_leak_vec_ptr].
-pub unsafe fn vec_from_leak_ptr<T>(ptr: *mut T, len: i32) -> Vec<T> {
-    Vec::from_raw_parts(ptr, len as usize, len as usize)
+/// The time elapsed from `earlier` to `later`, for the `durationSince` method of the Dart
+/// handles of `Instant`. It is zero if `earlier` is actually the later one.
+#[cfg(feature = "chrono")]
+pub fn duration_since(
+    later: &std::time::Instant,
+    earlier: &std::time::Instant,
+) -> chrono::Duration {
+    chrono::Duration::from_std(later.saturating_duration_since(*earlier))
+        .unwrap_or_else(|_| chrono::Duration::max_value())
 }
 
-/// Convert [Vec<T>] to array length `N`.
-///
-/// # Panics
+/// Turn the port of a Dart closure, received as an `impl Fn(T)` argument, into a Rust closure.
 ///
-/// Panics if length of [Vec<T>] != `N`.
-pub fn from_vec_to_array<T, const N: usize>(v: Vec<T>) -> [T; N] {
-    core::convert::TryInto::try_into(v)
-        .unwrap_or_else(|v: Vec<T>| panic!("Expected a Vec of length {} but it was {}", N, v.len()))
+/// Each call posts `value` to the Dart isolate, where the closure runs on its event loop,
+/// so it returns right away and can be called from any thread. The Dart closure is kept
+/// alive until this closure and all its clones are dropped.
+pub fn dart_callback<T: IntoDart>(port: MessagePort) -> impl Fn(T) + Clone {
+    let sink = StreamSink::new(Rust2Dart::new(port));
+    move |value| {
+        sink.add(value);
+    }
 }
 
-// ref: doc of [Box::into_raw]
-pub fn new_leak_box_ptr<T>(t: T) -> *mut T {
-    let x: Box<T> = Box::new(t);
-    Box::into_raw(x)
+/// Run the future of an `async` user function to completion on the runtime given to
+/// [set_async_runtime](crate::thread::set_async_runtime), or else with [park_on], for the
+/// calls which cannot return before it completes. The other ones are given to
+/// [Handler::wrap_async](crate::handler::Handler::wrap_async) instead.
+pub fn block_on<F: std::future::Future>(future: F) -> F::Output {
+    block_on_with(crate::thread::async_runtime().as_deref(), future)
 }
 
-/// # Safety
-/// Use it in pair with [new_leak_box_ptr].
-pub unsafe fn box_from_leak_ptr<T>(ptr: *mut T) -> Box<T> {
-    Box::from_raw(ptr)
+pub(crate) fn block_on_with<F: std::future::Future>(
+    runtime: Option<&dyn crate::thread::AsyncRuntime>,
+    future: F,
+) -> F::Output {
+    match runtime {
+        Some(runtime) => {
+            let mut output = None;
+            runtime.block_on(Box::pin(async {
+                output = Some(future.await);
+            }));
+            output.expect("The async runtime returned before the future completed")
+        }
+        None => park_on(future),
+    }
 }
 
-/// Cast a byte buffer into a boxed slice of the target type without making any copies.
-/// Panics if the cast fails.
-pub fn slice_from_byte_buffer<T: bytemuck::Pod>(buffer: Vec<u8>) -> Box<[T]> {
-    let buf = Box::leak(buffer.into_boxed_slice());
-    match bytemuck::try_cast_slice_mut(buf) {
-        Ok(buf) => unsafe { Box::from_raw(buf) },
-        Err(err) => {
-            // clean up before panicking
-            unsafe { core::ptr::drop_in_place(buf) }
-            panic!("cast error: {}", err);
+/// Run a future to completion, parking the current thread, usually a worker of the pool,
+/// whenever it is pending.
+///
+/// No runtime is entered, so a future relying on one, e.g. the IO types of `tokio`, must be
+/// started on that runtime by the user function itself, such as with `Handle::spawn`.
+pub fn park_on<F: std::future::Future>(future: F) -> F::Output {
+    struct ThreadWaker(std::thread::Thread);
+
+    impl std::task::Wake for ThreadWaker {
+        fn wake(self: std::sync::Arc<Self>) {
+            self.0.unpark();
         }
     }
+
+    let waker = std::sync::Arc::new(ThreadWaker(std::thread::current())).into();
+    let mut context = std::task::Context::from_waker(&waker);
+    let mut future = Box::pin(future);
+    loop {
+        match future.as_mut().poll(&mut context) {
+            std::task::Poll::Ready(output) => return output,
+            // A wake-up between the poll and the park makes the park return immediately.
+            std::task::Poll::Pending => std::thread::park(),
+        }
+    }
+}
+
+/// Run a `#[frb(no_isolate)]` function, whose result is returned straight through the C ABI.
+///
+/// Without a port or a return buffer, a panic cannot be reported to Dart, and it must not
+/// unwind into Dart either, so the process is aborted after printing it.
+pub fn wrap_direct<T>(debug_name: &str, task: impl FnOnce() -> T + std::panic::UnwindSafe) -> T {
+    std::panic::catch_unwind(task).unwrap_or_else(|error| {
+        let message = crate::handler::Error::Panic(error).message();
+        #[cfg(wasm)]
+        crate::console_error!("{} panicked: {}", debug_name, message);
+        #[cfg(not(wasm))]
+        eprintln!("{} panicked: {}", debug_name, message);
+        std::process::abort()
+    })
 }
 
 #[cfg(not(wasm))]
@@ -1,3 +1,8 @@
+use parking_lot::RwLock;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+
 fn get_worker_count() -> usize {
     #[cfg(all(feature = "worker-max", feature = "worker-single"))]
     {
@@ -31,11 +36,47 @@ fn get_worker_count() -> usize {
     }
 }
 
+/// The worker pool of functions without `#[frb(worker_pool = "..")]`.
+pub const DEFAULT_WORKER_POOL: &str = "default";
+
+/// Runs the futures of the `async` user functions, see [set_async_runtime].
+pub trait AsyncRuntime: Send + Sync + 'static {
+    /// Runs `future` in the background, e.g. as a task of the runtime. The future sends the
+    /// output of the call to Dart by itself once it completes.
+    fn spawn(&self, future: Pin<Box<dyn Future<Output = ()> + Send + 'static>>);
+
+    /// Runs `future` to completion on the calling thread, for the calls which cannot return
+    /// before it does, e.g. the `#[frb(sync)]` ones.
+    fn block_on(&self, future: Pin<Box<dyn Future<Output = ()> + '_>>);
+}
+
+lazy_static::lazy_static! {
+    static ref ASYNC_RUNTIME: RwLock<Option<Arc<dyn AsyncRuntime>>> = Default::default();
+}
+
+/// Makes the `async` user functions run on `runtime`, e.g. a `tokio` runtime shared with the
+/// rest of the app, so that their futures can use its IO and timers. This is best called while
+/// initializing the app, before any such function is called.
+///
+/// The future of each call is then [spawned](AsyncRuntime::spawn) on `runtime`, which needs it
+/// to be [Send]. Without it, a future is polled by a worker thread, which is parked whenever the
+/// future is pending, and no runtime is entered.
+pub fn set_async_runtime(runtime: impl AsyncRuntime) {
+    *ASYNC_RUNTIME.write() = Some(Arc::new(runtime));
+}
+
+/// The runtime given to [set_async_runtime], if any.
+pub(crate) fn async_runtime() -> Option<Arc<dyn AsyncRuntime>> {
+    ASYNC_RUNTIME.read().clone()
+}
+
 #[cfg(not(wasm))]
 mod io {
     use super::*;
     use lazy_static::lazy_static;
     use parking_lot::Mutex;
+    use std::cell::Cell;
+    use std::collections::HashMap;
     use threadpool::ThreadPool;
 
     lazy_static! {
@@ -43,11 +84,61 @@ mod io {
             "frb_workerpool".into(),
             get_worker_count()
         ));
+        static ref NAMED_THREAD_POOLS: Mutex<HashMap<&'static str, ThreadPool>> =
+            Default::default();
+    }
+
+    thread_local! {
+        static CURRENT_POOL: Cell<&'static str> = const { Cell::new(DEFAULT_WORKER_POOL) };
+    }
+
+    fn with_pool<T>(name: &'static str, size: usize, f: impl FnOnce(&mut ThreadPool) -> T) -> T {
+        if name == DEFAULT_WORKER_POOL {
+            return f(&mut THREAD_POOL.lock());
+        }
+        let mut pools = NAMED_THREAD_POOLS.lock();
+        let pool = pools
+            .entry(name)
+            .or_insert_with(|| ThreadPool::with_name(format!("frb_workerpool_{name}"), size));
+        f(pool)
+    }
+
+    /// Runs `worker` in the pool selected by [with_worker_pool], see [spawn](crate::spawn).
+    pub fn execute(worker: impl FnOnce() + Send + 'static) {
+        with_pool(CURRENT_POOL.with(Cell::get), get_worker_count(), |pool| {
+            pool.execute(worker)
+        })
+    }
+
+    /// Runs `f`, during which the tasks spawned on this thread go to the worker pool `name`.
+    /// A pool is created the first time it is used, with the same number of threads as the
+    /// default one, unless [set_worker_pool_size] was called before.
+    pub fn with_worker_pool<T>(name: &'static str, f: impl FnOnce() -> T) -> T {
+        // Restores the previous pool even if `f` panics.
+        struct Reset(&'static str);
+        impl Drop for Reset {
+            fn drop(&mut self) {
+                CURRENT_POOL.with(|current| current.set(self.0));
+            }
+        }
+        let _reset = Reset(CURRENT_POOL.with(|current| current.replace(name)));
+        f()
+    }
+
+    /// Sets the number of threads of the worker pool `name`, e.g. [DEFAULT_WORKER_POOL] or the
+    /// one of `#[frb(worker_pool = "heavy")]`. This is best called while initializing the app,
+    /// before the pool runs any task.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` is 0.
+    pub fn set_worker_pool_size(name: &'static str, size: usize) {
+        with_pool(name, size, |pool| pool.set_num_threads(size))
     }
 }
 
 #[cfg(not(wasm))]
-pub use io::THREAD_POOL;
+pub use io::{execute, set_worker_pool_size, with_worker_pool, THREAD_POOL};
 
 #[cfg(wasm)]
 mod web {
@@ -59,7 +150,65 @@ mod web {
             get_worker_count(), script_path().unwrap())
                 .map_err(|err| crate::console_error!("Failed to spawn worker: {:?}", err)).ok()
     }
+
+    /// On the web, there is a single pool which spawns a new web worker whenever all of them
+    /// are busy, so long-running tasks cannot starve the others: `f` is simply called.
+    pub fn with_worker_pool<T>(_name: &'static str, f: impl FnOnce() -> T) -> T {
+        f()
+    }
+
+    /// Does nothing on the web, see [with_worker_pool].
+    pub fn set_worker_pool_size(_name: &'static str, _size: usize) {}
 }
 
 #[cfg(wasm)]
-pub use web::WORKER_POOL;
+pub use web::{set_worker_pool_size, with_worker_pool, WORKER_POOL};
+
+#[cfg(all(test, not(wasm)))]
+mod tests {
+    use super::*;
+    use std::sync::mpsc;
+
+    fn spawned_thread_name() -> String {
+        let (tx, rx) = mpsc::channel();
+        crate::spawn!(|| {
+            tx.send(std::thread::current().name().unwrap().to_owned())
+                .unwrap();
+        });
+        rx.recv().unwrap()
+    }
+
+    #[test]
+    fn named_pools_leave_the_default_one_alone() {
+        assert_eq!(spawned_thread_name(), "frb_workerpool");
+        assert_eq!(
+            with_worker_pool("heavy", spawned_thread_name),
+            "frb_workerpool_heavy"
+        );
+        assert_eq!(spawned_thread_name(), "frb_workerpool");
+    }
+
+    #[test]
+    fn blocking_calls_run_on_the_given_runtime() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        // Not given to `set_async_runtime`, which would affect the other tests.
+        #[derive(Default)]
+        struct CountingRuntime(AtomicUsize);
+        impl AsyncRuntime for CountingRuntime {
+            fn spawn(&self, _future: Pin<Box<dyn Future<Output = ()> + Send + 'static>>) {
+                unreachable!()
+            }
+
+            fn block_on(&self, future: Pin<Box<dyn Future<Output = ()> + '_>>) {
+                self.0.fetch_add(1, Ordering::SeqCst);
+                crate::support::park_on(future)
+            }
+        }
+
+        let runtime = CountingRuntime::default();
+        let output = crate::support::block_on_with(Some(&runtime), async { 40 + 2 });
+        assert_eq!(output, 42);
+        assert_eq!(runtime.0.load(Ordering::SeqCst), 1);
+    }
+}
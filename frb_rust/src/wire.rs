@@ -0,0 +1,93 @@
+//! The conversions between the wire format and the Rust types which only need `alloc`, so that
+//! they build without the `std` feature, see the `no_std` page of the book.
+
+use alloc::boxed::Box;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::mem;
+
+// ref https://stackoverflow.com/questions/39224904/how-to-expose-a-rust-vect-to-ffi
+pub fn new_leak_vec_ptr<T: Clone>(fill: T, length: i32) -> *mut T {
+    into_leak_vec_ptr(vec![fill; length as usize]).0
+}
+
+pub fn into_leak_vec_ptr<T: Clone>(mut v: Vec<T>) -> (*mut T, i32) {
+    v.shrink_to_fit();
+    assert!(v.len() == v.capacity());
+    let ptr = v.as_mut_ptr();
+    let len = v.len() as i32;
+    mem::forget(v);
+    (ptr, len)
+}
+
+/// # Safety
+/// Use it in pair with [new_leak_vec_ptr].
+pub unsafe fn vec_from_leak_ptr<T>(ptr: *mut T, len: i32) -> Vec<T> {
+    Vec::from_raw_parts(ptr, len as usize, len as usize)
+}
+
+/// Convert [Vec<T>] to array length `N`.
+///
+/// # Panics
+///
+/// Panics if length of [Vec<T>] != `N`.
+pub fn from_vec_to_array<T, const N: usize>(v: Vec<T>) -> [T; N] {
+    core::convert::TryInto::try_into(v)
+        .unwrap_or_else(|v: Vec<T>| panic!("Expected a Vec of length {} but it was {}", N, v.len()))
+}
+
+/// Copy a slice into an array of length `N` on the stack, without going through a [Vec<T>].
+///
+/// # Panics
+///
+/// Panics if length of the slice != `N`.
+pub fn from_slice_to_array<T: Copy, const N: usize>(s: &[T]) -> [T; N] {
+    core::convert::TryInto::try_into(s)
+        .unwrap_or_else(|_| panic!("Expected a slice of length {} but it was {}", N, s.len()))
+}
+
+// ref: doc of [Box::into_raw]
+pub fn new_leak_box_ptr<T>(t: T) -> *mut T {
+    let x: Box<T> = Box::new(t);
+    Box::into_raw(x)
+}
+
+/// # Safety
+/// Use it in pair with [new_leak_box_ptr].
+pub unsafe fn box_from_leak_ptr<T>(ptr: *mut T) -> Box<T> {
+    Box::from_raw(ptr)
+}
+
+/// Cast a byte buffer into a boxed slice of the target type without making any copies.
+/// Panics if the cast fails.
+pub fn slice_from_byte_buffer<T: bytemuck::Pod>(buffer: Vec<u8>) -> Box<[T]> {
+    let buf = Box::leak(buffer.into_boxed_slice());
+    match bytemuck::try_cast_slice_mut(buf) {
+        Ok(buf) => unsafe { Box::from_raw(buf) },
+        Err(err) => {
+            // clean up before panicking
+            unsafe { core::ptr::drop_in_place(buf) }
+            panic!("cast error: {}", err);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn leaked_vec_round_trips() {
+        let (ptr, len) = into_leak_vec_ptr(vec![1u8, 2, 3]);
+        assert_eq!(unsafe { vec_from_leak_ptr(ptr, len) }, [1, 2, 3]);
+        let ptr = new_leak_vec_ptr(0u16, 2);
+        assert_eq!(unsafe { vec_from_leak_ptr(ptr, 2) }, [0, 0]);
+    }
+
+    #[test]
+    fn arrays_check_their_length() {
+        assert_eq!(from_vec_to_array::<_, 2>(vec![1, 2]), [1, 2]);
+        assert_eq!(from_slice_to_array::<_, 1>(&[3]), [3]);
+        assert!(std::panic::catch_unwind(|| from_vec_to_array::<_, 3>(vec![1])).is_err());
+    }
+}
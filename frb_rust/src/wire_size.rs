@@ -0,0 +1,106 @@
+//! The bytes moved across the boundary by each call, recorded by the code generated with
+//! `--record-wire-size`.
+//!
+//! Only debug builds record them, unless the `wire-size` feature is enabled: otherwise
+//! [wire_size_enabled] is always `false`, and the generated code compiles out.
+
+use std::sync::RwLock;
+
+type Hook = Box<dyn Fn(&WireSize) + Send + Sync>;
+
+lazy_static::lazy_static! {
+    static ref HOOK: RwLock<Option<Hook>> = RwLock::new(None);
+}
+
+/// Which way a value crosses the boundary.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum WireDirection {
+    /// An argument, sent by Dart.
+    ToRust,
+    /// The return value, sent by Rust.
+    ToDart,
+}
+
+/// The size of one value moved by a call.
+///
+/// The bytes of strings, lists and maps are counted, including the ones nested in structs
+/// and enums, while any other value is counted as its size in Rust memory. It is therefore
+/// an estimate, meant to spot unexpectedly large values rather than to measure the exact
+/// encoding.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct WireSize {
+    /// The name of the function, as in the errors it reports.
+    pub function: &'static str,
+    pub direction: WireDirection,
+    /// The name of the argument, [None] for the return value.
+    pub value: Option<&'static str>,
+    pub bytes: usize,
+}
+
+/// Receive the sizes of all the calls, instead of having them logged at the debug level.
+pub fn set_wire_size_hook(hook: impl Fn(&WireSize) + Send + Sync + 'static) {
+    *HOOK.write().unwrap() = Some(Box::new(hook));
+}
+
+/// Whether the generated code should compute the sizes, which walks the values.
+#[doc(hidden)]
+#[inline]
+pub fn wire_size_enabled() -> bool {
+    cfg!(any(debug_assertions, feature = "wire-size"))
+        && (HOOK.read().unwrap().is_some() || log::log_enabled!(log::Level::Debug))
+}
+
+#[doc(hidden)]
+pub fn record_wire_size(
+    function: &'static str,
+    direction: WireDirection,
+    value: Option<&'static str>,
+    bytes: usize,
+) {
+    let size = WireSize {
+        function,
+        direction,
+        value,
+        bytes,
+    };
+    match &*HOOK.read().unwrap() {
+        Some(hook) => hook(&size),
+        None => log::debug!(
+            "{}: {} bytes {} in {}",
+            function,
+            bytes,
+            match direction {
+                WireDirection::ToRust => "to Rust",
+                WireDirection::ToDart => "to Dart",
+            },
+            value.map_or("the return value".to_owned(), |value| format!(
+                "`{}`",
+                value
+            )),
+        ),
+    }
+}
+
+#[cfg(all(test, debug_assertions))]
+mod tests {
+    use super::*;
+    use std::sync::{Arc, Mutex};
+
+    #[test]
+    fn the_hook_receives_the_sizes() {
+        let sizes = Arc::new(Mutex::new(Vec::new()));
+        let received = sizes.clone();
+        set_wire_size_hook(move |size| received.lock().unwrap().push(*size));
+        assert!(wire_size_enabled());
+        record_wire_size("upload", WireDirection::ToRust, Some("data"), 3);
+        assert_eq!(
+            *sizes.lock().unwrap(),
+            [WireSize {
+                function: "upload",
+                direction: WireDirection::ToRust,
+                value: Some("data"),
+                bytes: 3,
+            }]
+        );
+    }
+}